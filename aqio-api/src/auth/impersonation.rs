@@ -0,0 +1,278 @@
+// Support impersonation: an administrator gets a short-lived token that acts
+// as another user. The tokens are signed by the API itself (HS256), carry the
+// administrator in an `impersonator` claim and never grant admin rights. Every
+// request made with one is logged, and its responses are marked so the
+// frontend can show who is being viewed as.
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode};
+use serde::{Deserialize, Serialize};
+
+use aqio_core::{User, UserRole};
+
+use super::Claims;
+use crate::domain::{ApiError, ApiResult};
+
+/// Response header naming the administrator behind an impersonated request
+pub const IMPERSONATED_BY_HEADER: HeaderName = HeaderName::from_static("x-impersonated-by");
+
+// Admin routes stay out of reach while impersonating, which also rules out
+// starting another impersonation from inside one
+const ADMIN_PATH_PREFIX: &str = "/api/v1/admin";
+
+/// The administrator acting as the token's user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Impersonator {
+    pub sub: String,
+    pub email: String,
+    pub name: String,
+    /// Why the session was started, e.g. a support ticket
+    pub reason: String,
+}
+
+#[derive(Clone)]
+pub struct ImpersonationTokens {
+    secret: Vec<u8>,
+}
+
+// Keeps the signing secret out of logged auth configuration
+impl std::fmt::Debug for ImpersonationTokens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImpersonationTokens").finish_non_exhaustive()
+    }
+}
+
+impl ImpersonationTokens {
+    pub const DEFAULT_MINUTES: u32 = 30;
+    pub const MAX_MINUTES: u32 = 120;
+
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into().into_bytes(),
+        }
+    }
+
+    /// A token that lets `admin` act as `target` for `minutes`
+    pub fn issue(
+        &self,
+        admin: &Claims,
+        target: &User,
+        reason: &str,
+        minutes: u32,
+        now: DateTime<Utc>,
+    ) -> ApiResult<(String, DateTime<Utc>)> {
+        if !admin.is_admin() || admin.impersonator.is_some() {
+            return Err(ApiError::authorization("Only administrators can impersonate users"));
+        }
+        if admin.sub == target.keycloak_id {
+            return Err(ApiError::bad_request("You cannot impersonate yourself"));
+        }
+        if !target.is_active {
+            return Err(ApiError::bad_request("Deactivated accounts cannot be impersonated"));
+        }
+        let role = match target.role {
+            UserRole::Admin => return Err(ApiError::authorization("Administrators cannot be impersonated")),
+            UserRole::Organizer => "organizer",
+            UserRole::Participant => "participant",
+        };
+        if reason.trim().is_empty() {
+            return Err(ApiError::validation("reason", "Say why you need to act as this user"));
+        }
+        if !(1..=Self::MAX_MINUTES).contains(&minutes) {
+            return Err(ApiError::validation(
+                "minutes",
+                format!("Must be between 1 and {}", Self::MAX_MINUTES),
+            ));
+        }
+
+        let expires_at = now + Duration::minutes(minutes.into());
+        let claims = Claims {
+            sub: target.keycloak_id.clone(),
+            email: target.email.clone(),
+            name: target.name.clone(),
+            exp: expires_at.timestamp() as usize,
+            iat: now.timestamp() as usize,
+            roles: Some(vec![role.to_string()]),
+            impersonator: Some(Impersonator {
+                sub: admin.sub.clone(),
+                email: admin.email.clone(),
+                name: admin.name.clone(),
+                reason: reason.trim().to_string(),
+            }),
+        };
+        let token = encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(&self.secret))
+            .map_err(|e| ApiError::internal(format!("Failed to sign impersonation token: {}", e)))?;
+        Ok((token, expires_at))
+    }
+
+    /// The claims of a valid, unexpired impersonation token
+    pub fn verify(&self, token: &str) -> Option<Claims> {
+        decode::<Claims>(token, &DecodingKey::from_secret(&self.secret), &Validation::new(Algorithm::HS256))
+            .ok()
+            .map(|data| data.claims)
+            .filter(|claims| claims.impersonator.is_some())
+    }
+}
+
+/// Whether a bearer token was issued by `ImpersonationTokens` rather than the
+/// identity provider, which signs with RS256
+pub(crate) fn is_impersonation_token(token: &str) -> bool {
+    decode_header(token).is_ok_and(|header| header.alg == Algorithm::HS256)
+}
+
+/// Runs a request made with an impersonation token
+pub(crate) async fn serve(
+    tokens: Option<&ImpersonationTokens>,
+    token: &str,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let claims = tokens
+        .and_then(|tokens| tokens.verify(token))
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    let Some(impersonator) = claims.impersonator.clone() else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    if path.starts_with(ADMIN_PATH_PREFIX) {
+        tracing::warn!(
+            impersonator = %impersonator.email,
+            user = %claims.email,
+            "Refused {} {} while impersonating",
+            method,
+            path
+        );
+        return Err(StatusCode::FORBIDDEN);
+    }
+    tracing::info!(
+        impersonator = %impersonator.email,
+        user = %claims.email,
+        reason = %impersonator.reason,
+        "{} {} while impersonating",
+        method,
+        path
+    );
+
+    request.extensions_mut().insert(claims);
+    let mut response = next.run(request).await;
+    if let Ok(value) = HeaderValue::from_str(&impersonator.email) {
+        response.headers_mut().insert(IMPERSONATED_BY_HEADER, value);
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::mock::{MockAuthConfig, mock_auth_middleware};
+    use crate::testing::helpers::{TestUserBuilder, create_admin_claims, create_participant_claims};
+    use axum::{Extension, Router, body::Body, middleware, routing::get};
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_tokens_act_as_the_user_without_admin_rights() {
+        let tokens = ImpersonationTokens::new("secret");
+        let admin = create_admin_claims();
+        let user = TestUserBuilder::new().with_email("kari@example.no").build();
+        let now = Utc::now();
+
+        let (token, expires_at) = tokens.issue(&admin, &user, "Ticket #42", 30, now).unwrap();
+        assert_eq!(expires_at, now + Duration::minutes(30));
+        assert!(is_impersonation_token(&token));
+
+        let claims = tokens.verify(&token).unwrap();
+        assert_eq!(claims.sub, user.keycloak_id);
+        assert_eq!(claims.email, "kari@example.no");
+        assert!(!claims.is_admin());
+        assert_eq!(claims.impersonator.as_ref().unwrap().reason, "Ticket #42");
+
+        // Audited changes are attributed to the administrator
+        let actor = claims.audit_actor();
+        assert_eq!(actor.email, admin.email);
+        assert_eq!(actor.user_id.map(|id| id.to_string()), Some(admin.sub.clone()));
+
+        // Other secrets, and tokens that aren't ours, don't pass
+        assert!(ImpersonationTokens::new("other").verify(&token).is_none());
+        assert!(!is_impersonation_token("mock-dev-user"));
+    }
+
+    #[test]
+    fn test_only_admins_impersonate_and_never_other_admins() {
+        let tokens = ImpersonationTokens::new("secret");
+        let admin = create_admin_claims();
+        let user = TestUserBuilder::new().build();
+        let now = Utc::now();
+
+        assert!(matches!(
+            tokens.issue(&create_participant_claims(), &user, "Ticket #42", 30, now),
+            Err(ApiError::Authorization { .. })
+        ));
+        let other_admin = TestUserBuilder::new().admin().build();
+        assert!(matches!(
+            tokens.issue(&admin, &other_admin, "Ticket #42", 30, now),
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            tokens.issue(&admin, &user, " ", 30, now),
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            tokens.issue(&admin, &user, "Ticket #42", ImpersonationTokens::MAX_MINUTES + 1, now),
+            Err(ApiError::Validation { .. })
+        ));
+
+        // An impersonated session cannot start another one
+        let (token, _) = tokens.issue(&admin, &user, "Ticket #42", 30, now).unwrap();
+        let mut impersonated = tokens.verify(&token).unwrap();
+        impersonated.roles = Some(vec!["admin".to_string()]);
+        assert!(matches!(
+            tokens.issue(&impersonated, &TestUserBuilder::new().build(), "Ticket #43", 30, now),
+            Err(ApiError::Authorization { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_middleware_marks_responses_and_keeps_admin_routes_closed() {
+        let tokens = ImpersonationTokens::new("secret");
+        let user = TestUserBuilder::new().build();
+        let (token, _) = tokens
+            .issue(&create_admin_claims(), &user, "Ticket #42", 30, Utc::now())
+            .unwrap();
+        let app = |tokens: Option<ImpersonationTokens>| {
+            let mut config = MockAuthConfig::new(true);
+            if let Some(tokens) = tokens {
+                config = config.with_impersonation(tokens);
+            }
+            Router::new()
+                .route("/api/v1/users/me", get(|Extension(claims): Extension<Claims>| async move { claims.sub }))
+                .route("/api/v1/admin/audit-log", get(|| async { "entries" }))
+                .layer(middleware::from_fn_with_state(config, mock_auth_middleware))
+        };
+        let get_with_token = |uri: &str| {
+            Request::builder()
+                .uri(uri)
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let response = app(Some(tokens.clone())).oneshot(get_with_token("/api/v1/users/me")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[IMPERSONATED_BY_HEADER], "admin@example.com");
+
+        let response = app(Some(tokens)).oneshot(get_with_token("/api/v1/admin/audit-log")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // Without impersonation configured the token is just invalid
+        let response = app(None).oneshot(get_with_token("/api/v1/users/me")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
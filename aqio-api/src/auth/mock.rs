@@ -1,4 +1,4 @@
-use super::Claims;
+use super::{Claims, impersonation::{self, ImpersonationTokens}};
 use axum::{
     extract::{Request, State},
     http::{header::AUTHORIZATION, StatusCode},
@@ -9,11 +9,20 @@ use axum::{
 #[derive(Clone, Debug)]
 pub struct MockAuthConfig {
     pub enabled: bool,
+    pub impersonation: Option<ImpersonationTokens>,
 }
 
 impl MockAuthConfig {
     pub fn new(enabled: bool) -> Self {
-        Self { enabled }
+        Self {
+            enabled,
+            impersonation: None,
+        }
+    }
+
+    pub fn with_impersonation(mut self, tokens: ImpersonationTokens) -> Self {
+        self.impersonation = Some(tokens);
+        self
     }
 }
 
@@ -29,7 +38,18 @@ pub async fn mock_auth_middleware(
     let auth_header = request
         .headers()
         .get(AUTHORIZATION)
-        .and_then(|header| header.to_str().ok());
+        .and_then(|header| header.to_str().ok())
+        .map(str::to_string)
+        .or_else(|| super::stream_access_token(&request).map(|token| format!("Bearer {}", token)));
+
+    if let Some(token) = auth_header
+        .as_deref()
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .filter(|token| impersonation::is_impersonation_token(token))
+    {
+        let token = token.to_string();
+        return impersonation::serve(config.impersonation.as_ref(), &token, request, next).await;
+    }
 
     // For development, create a mock user if no auth header is provided
     let claims = if let Some(header) = auth_header {
@@ -89,6 +109,7 @@ fn create_mock_claims(user_id: &str) -> Claims {
         roles: Some(roles),
         exp: (chrono::Utc::now().timestamp() + 3600) as usize, // 1 hour from now
         iat: chrono::Utc::now().timestamp() as usize,
+        impersonator: None,
     }
 }
 
@@ -0,0 +1,593 @@
+// Runtime configuration read from the environment
+
+use std::collections::BTreeMap;
+use std::env;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use aqio_core::InvoiceParty;
+use aqio_database::FaultPlan;
+
+use crate::domain::company_registry::normalize_org_number;
+
+const DEFAULT_HOST: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+const DEFAULT_PORT: u16 = 3000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerConfig {
+    pub host: IpAddr,
+    pub port: u16,
+}
+
+impl ServerConfig {
+    /// Read `HOST` and `PORT`, defaulting to 127.0.0.1:3000
+    ///
+    /// Containers should set `HOST=0.0.0.0` so the API is reachable from outside.
+    pub fn from_env() -> anyhow::Result<Self> {
+        Self::from_vars(env::var("HOST").ok(), env::var("PORT").ok())
+    }
+
+    fn from_vars(host: Option<String>, port: Option<String>) -> anyhow::Result<Self> {
+        let host = match host {
+            Some(host) => host
+                .parse()
+                .map_err(|_| anyhow::anyhow!("HOST must be an IP address, got '{}'", host))?,
+            None => DEFAULT_HOST,
+        };
+
+        let port = match port {
+            Some(port) => port
+                .parse()
+                .map_err(|_| anyhow::anyhow!("PORT must be a number between 0 and 65535, got '{}'", port))?,
+            None => DEFAULT_PORT,
+        };
+
+        Ok(Self { host, port })
+    }
+
+    pub fn bind_addr(&self) -> SocketAddr {
+        SocketAddr::new(self.host, self.port)
+    }
+
+    /// Address to probe the server on from the same host or container
+    ///
+    /// A wildcard bind (0.0.0.0 / ::) isn't connectable, so use loopback instead.
+    pub fn local_addr(&self) -> SocketAddr {
+        let host = match self.host {
+            IpAddr::V4(ip) if ip.is_unspecified() => IpAddr::V4(Ipv4Addr::LOCALHOST),
+            IpAddr::V6(ip) if ip.is_unspecified() => IpAddr::V6(std::net::Ipv6Addr::LOCALHOST),
+            host => host,
+        };
+        SocketAddr::new(host, self.port)
+    }
+}
+
+/// How the frontend signs users in
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthMode {
+    /// Development logins at `/auth/login`
+    Mock,
+    Keycloak { realm_url: String, client_id: String },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Branding {
+    pub name: String,
+    pub logo_url: Option<String>,
+    /// `#rrggbb`
+    pub primary_color: Option<String>,
+}
+
+/// Settings the frontend reads at startup, so a deployment can change them
+/// without rebuilding the WASM bundle
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientConfig {
+    pub auth: AuthMode,
+    pub branding: Branding,
+    pub features: BTreeMap<String, bool>,
+    /// Lowest level of log line the frontend ships to the server; `None`
+    /// keeps its logs in the browser console
+    pub remote_log_level: Option<String>,
+}
+
+impl ClientConfig {
+    const LOG_LEVELS: [&str; 4] = ["error", "warn", "info", "debug"];
+
+    /// Read `BRAND_NAME`, `BRAND_LOGO_URL`, `BRAND_PRIMARY_COLOR`,
+    /// `FEATURE_FLAGS` and `CLIENT_LOG_LEVEL`
+    ///
+    /// `server_features` are the flags that follow from the server's own
+    /// settings. `FEATURE_FLAGS` adds to or overrides them as a comma-separated
+    /// list such as `events_map,live_polls=false`.
+    pub fn from_env(auth: AuthMode, server_features: &[(&str, bool)]) -> anyhow::Result<Self> {
+        Ok(Self {
+            remote_log_level: Self::remote_log_level(env::var("CLIENT_LOG_LEVEL").ok())?,
+            ..Self::from_vars(
+                auth,
+                server_features,
+                env::var("BRAND_NAME").ok(),
+                env::var("BRAND_LOGO_URL").ok(),
+                env::var("BRAND_PRIMARY_COLOR").ok(),
+                env::var("FEATURE_FLAGS").ok(),
+            )?
+        })
+    }
+
+    fn remote_log_level(level: Option<String>) -> anyhow::Result<Option<String>> {
+        match level.map(|level| level.trim().to_ascii_lowercase()).filter(|level| !level.is_empty()) {
+            Some(level) if Self::LOG_LEVELS.contains(&level.as_str()) => Ok(Some(level)),
+            Some(level) => anyhow::bail!("CLIENT_LOG_LEVEL must be error, warn, info or debug, got '{}'", level),
+            None => Ok(None),
+        }
+    }
+
+    fn from_vars(
+        auth: AuthMode,
+        server_features: &[(&str, bool)],
+        name: Option<String>,
+        logo_url: Option<String>,
+        primary_color: Option<String>,
+        feature_flags: Option<String>,
+    ) -> anyhow::Result<Self> {
+        let primary_color = match primary_color.filter(|color| !color.is_empty()) {
+            Some(color) if is_hex_color(&color) => Some(color.to_ascii_lowercase()),
+            Some(color) => anyhow::bail!("BRAND_PRIMARY_COLOR must be a color like #1e6fa8, got '{}'", color),
+            None => None,
+        };
+
+        let mut features: BTreeMap<String, bool> = server_features
+            .iter()
+            .map(|(name, enabled)| (name.to_string(), *enabled))
+            .collect();
+        for flag in feature_flags.as_deref().unwrap_or_default().split(',').map(str::trim).filter(|flag| !flag.is_empty()) {
+            let (name, enabled) = match flag.split_once('=') {
+                Some((name, "true")) => (name.trim(), true),
+                Some((name, "false")) => (name.trim(), false),
+                Some(_) => anyhow::bail!("FEATURE_FLAGS entries must be `name`, `name=true` or `name=false`, got '{}'", flag),
+                None => (flag, true),
+            };
+            features.insert(name.to_string(), enabled);
+        }
+
+        Ok(Self {
+            auth,
+            branding: Branding {
+                name: name.filter(|name| !name.trim().is_empty()).unwrap_or_else(|| "Aqio".to_string()),
+                logo_url: logo_url.filter(|url| !url.is_empty()),
+                primary_color,
+            },
+            features,
+            remote_log_level: None,
+        })
+    }
+}
+
+/// Who invoices for ticket orders are issued by and paid to
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoicingConfig {
+    pub seller: InvoiceParty,
+    /// 11 digits
+    pub bank_account: Option<String>,
+    pub payment_days: u32,
+}
+
+impl InvoicingConfig {
+    pub const DEFAULT_PAYMENT_DAYS: u32 = 14;
+
+    /// Read `INVOICE_SELLER_NAME`, `INVOICE_SELLER_ORG_NUMBER`,
+    /// `INVOICE_SELLER_ADDRESS`, `INVOICE_SELLER_POSTAL_CODE`,
+    /// `INVOICE_SELLER_CITY`, `INVOICE_SELLER_EMAIL`,
+    /// `INVOICE_SELLER_VAT_REGISTERED`, `INVOICE_BANK_ACCOUNT` and
+    /// `INVOICE_PAYMENT_DAYS`
+    ///
+    /// Invoicing is off (`None`) until the seller's name and organization
+    /// number are set.
+    pub fn from_env() -> anyhow::Result<Option<Self>> {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> anyhow::Result<Option<Self>> {
+        let var = |name: &str| var(name).map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+        let (Some(name), Some(org_number)) = (var("INVOICE_SELLER_NAME"), var("INVOICE_SELLER_ORG_NUMBER")) else {
+            return Ok(None);
+        };
+        let org_number = normalize_org_number(&org_number)
+            .map_err(|_| anyhow::anyhow!("INVOICE_SELLER_ORG_NUMBER must be a valid organization number, got '{}'", org_number))?;
+
+        let bank_account = match var("INVOICE_BANK_ACCOUNT") {
+            Some(account) => {
+                let digits: String = account.chars().filter(|c| !c.is_whitespace() && *c != '.').collect();
+                if digits.len() != 11 || !digits.chars().all(|c| c.is_ascii_digit()) {
+                    anyhow::bail!("INVOICE_BANK_ACCOUNT must be an account number of 11 digits, got '{}'", account);
+                }
+                Some(digits)
+            }
+            None => None,
+        };
+
+        let payment_days = match var("INVOICE_PAYMENT_DAYS") {
+            Some(days) => days
+                .parse()
+                .ok()
+                .filter(|days: &u32| *days > 0)
+                .ok_or_else(|| anyhow::anyhow!("INVOICE_PAYMENT_DAYS must be a positive number, got '{}'", days))?,
+            None => Self::DEFAULT_PAYMENT_DAYS,
+        };
+
+        Ok(Some(Self {
+            seller: InvoiceParty {
+                name,
+                org_number,
+                address: var("INVOICE_SELLER_ADDRESS"),
+                postal_code: var("INVOICE_SELLER_POSTAL_CODE"),
+                city: var("INVOICE_SELLER_CITY"),
+                country: "NO".to_string(),
+                email: var("INVOICE_SELLER_EMAIL"),
+                vat_registered: var("INVOICE_SELLER_VAT_REGISTERED").is_some_and(|value| value.eq_ignore_ascii_case("true")),
+            },
+            bank_account,
+            payment_days,
+        }))
+    }
+}
+
+/// Vipps MobilePay credentials for refunding ticket orders
+#[derive(Debug, Clone, PartialEq)]
+pub struct VippsConfig {
+    pub api_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub subscription_key: String,
+    pub merchant_serial_number: String,
+}
+
+impl VippsConfig {
+    pub const DEFAULT_API_URL: &'static str = "https://api.vipps.no";
+
+    /// Read `VIPPS_CLIENT_ID`, `VIPPS_CLIENT_SECRET`, `VIPPS_SUBSCRIPTION_KEY`,
+    /// `VIPPS_MERCHANT_SERIAL_NUMBER` and `VIPPS_API_URL`
+    ///
+    /// Refunds are off (`None`) when none of the credentials are set; some
+    /// but not all of them is an error.
+    pub fn from_env() -> anyhow::Result<Option<Self>> {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> anyhow::Result<Option<Self>> {
+        let var = |name: &str| var(name).map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+        let names = ["VIPPS_CLIENT_ID", "VIPPS_CLIENT_SECRET", "VIPPS_SUBSCRIPTION_KEY", "VIPPS_MERCHANT_SERIAL_NUMBER"];
+        let values: Vec<Option<String>> = names.iter().map(|name| var(name)).collect();
+        if values.iter().all(Option::is_none) {
+            return Ok(None);
+        }
+        let missing: Vec<&str> = names
+            .iter()
+            .zip(&values)
+            .filter(|(_, value)| value.is_none())
+            .map(|(name, _)| *name)
+            .collect();
+        if !missing.is_empty() {
+            anyhow::bail!("Vipps refunds need {} to be set as well", missing.join(", "));
+        }
+        let [client_id, client_secret, subscription_key, merchant_serial_number] =
+            <[Option<String>; 4]>::try_from(values).expect("one value per name").map(Option::unwrap_or_default);
+
+        Ok(Some(Self {
+            api_url: var("VIPPS_API_URL")
+                .unwrap_or_else(|| Self::DEFAULT_API_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            client_id,
+            client_secret,
+            subscription_key,
+            merchant_serial_number,
+        }))
+    }
+}
+
+/// Credentials scheduled exports are delivered with. Jobs only name the
+/// bucket or host; the keys stay in the server's environment.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportConfig {
+    pub s3: Option<S3Credentials>,
+    /// Private key for SFTP logins
+    pub sftp_key_path: Option<String>,
+    /// Known hosts file SFTP servers are checked against; the user's own when unset
+    pub sftp_known_hosts: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct S3Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+impl ExportConfig {
+    /// Read `EXPORT_S3_ACCESS_KEY_ID`, `EXPORT_S3_SECRET_ACCESS_KEY`,
+    /// `EXPORT_SFTP_KEY_PATH` and `EXPORT_SFTP_KNOWN_HOSTS`
+    ///
+    /// Exports to S3 fail until both S3 keys are set; one without the other
+    /// is an error. Exports over SFTP fail until the key path is set.
+    pub fn from_env() -> anyhow::Result<Self> {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        let var = |name: &str| var(name).map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+        let s3 = match (var("EXPORT_S3_ACCESS_KEY_ID"), var("EXPORT_S3_SECRET_ACCESS_KEY")) {
+            (Some(access_key_id), Some(secret_access_key)) => Some(S3Credentials { access_key_id, secret_access_key }),
+            (None, None) => None,
+            (Some(_), None) => anyhow::bail!("S3 exports need EXPORT_S3_SECRET_ACCESS_KEY to be set as well"),
+            (None, Some(_)) => anyhow::bail!("S3 exports need EXPORT_S3_ACCESS_KEY_ID to be set as well"),
+        };
+
+        Ok(Self {
+            s3,
+            sftp_key_path: var("EXPORT_SFTP_KEY_PATH"),
+            sftp_known_hosts: var("EXPORT_SFTP_KNOWN_HOSTS"),
+        })
+    }
+}
+
+/// Credentials CRM connectors sync with. Connectors only name the provider
+/// and, for Dynamics, the organization URL; the secrets stay in the server's
+/// environment.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrmConfig {
+    /// Private app access token for HubSpot
+    pub hubspot_access_token: Option<String>,
+    pub dynamics: Option<DynamicsCredentials>,
+}
+
+/// An Entra ID app registration with access to the Dynamics organizations
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicsCredentials {
+    pub tenant_id: String,
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+impl CrmConfig {
+    /// Read `HUBSPOT_ACCESS_TOKEN`, `DYNAMICS_TENANT_ID`, `DYNAMICS_CLIENT_ID`
+    /// and `DYNAMICS_CLIENT_SECRET`
+    ///
+    /// Connectors fail their syncs until their provider's credentials are
+    /// set. The three Dynamics variables go together; setting only some of
+    /// them is an error.
+    pub fn from_env() -> anyhow::Result<Self> {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        let var = |name: &str| var(name).map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+        let dynamics = match (var("DYNAMICS_TENANT_ID"), var("DYNAMICS_CLIENT_ID"), var("DYNAMICS_CLIENT_SECRET")) {
+            (Some(tenant_id), Some(client_id), Some(client_secret)) => {
+                Some(DynamicsCredentials { tenant_id, client_id, client_secret })
+            }
+            (None, None, None) => None,
+            _ => anyhow::bail!(
+                "Dynamics connectors need DYNAMICS_TENANT_ID, DYNAMICS_CLIENT_ID and DYNAMICS_CLIENT_SECRET together"
+            ),
+        };
+
+        Ok(Self {
+            hubspot_access_token: var("HUBSPOT_ACCESS_TOKEN"),
+            dynamics,
+        })
+    }
+}
+
+/// Faults to inject into repositories and outbound calls, so error handling
+/// and retries can be exercised on dev and staging servers
+///
+/// Read `FAULT_LATENCY_MS`, `FAULT_FAILURE_RATE`, `FAULT_CONFLICT_RATE` and
+/// `FAULT_TARGETS`. Nothing is injected (`None`) unless one of the first three
+/// is set.
+pub fn fault_plan_from_env() -> anyhow::Result<Option<FaultPlan>> {
+    fault_plan_from_vars(|name| env::var(name).ok())
+}
+
+fn fault_plan_from_vars(var: impl Fn(&str) -> Option<String>) -> anyhow::Result<Option<FaultPlan>> {
+    let var = |name: &str| var(name).map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+    let rate = |name: &str| match var(name) {
+        Some(rate) => rate
+            .parse()
+            .ok()
+            .filter(|rate: &f64| (0.0..=1.0).contains(rate))
+            .ok_or_else(|| anyhow::anyhow!("{} must be a share between 0 and 1, got '{}'", name, rate)),
+        None => Ok(0.0),
+    };
+
+    let max_latency = match var("FAULT_LATENCY_MS") {
+        Some(ms) => Duration::from_millis(
+            ms.parse()
+                .map_err(|_| anyhow::anyhow!("FAULT_LATENCY_MS must be a number of milliseconds, got '{}'", ms))?,
+        ),
+        None => Duration::ZERO,
+    };
+    let plan = FaultPlan {
+        max_latency,
+        failure_rate: rate("FAULT_FAILURE_RATE")?,
+        conflict_rate: rate("FAULT_CONFLICT_RATE")?,
+        targets: var("FAULT_TARGETS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|target| !target.is_empty())
+            .map(str::to_string)
+            .collect(),
+    };
+    Ok(Some(plan).filter(FaultPlan::is_active))
+}
+
+fn is_hex_color(color: &str) -> bool {
+    color.len() == 7 && color.starts_with('#') && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let config = ServerConfig::from_vars(None, None).unwrap();
+        assert_eq!(config.bind_addr(), "127.0.0.1:3000".parse().unwrap());
+    }
+
+    #[test]
+    fn test_container_bind_probes_loopback() {
+        let config = ServerConfig::from_vars(Some("0.0.0.0".into()), Some("8080".into())).unwrap();
+
+        assert_eq!(config.bind_addr(), "0.0.0.0:8080".parse().unwrap());
+        assert_eq!(config.local_addr(), "127.0.0.1:8080".parse().unwrap());
+
+        let ipv6 = ServerConfig::from_vars(Some("::".into()), None).unwrap();
+        assert_eq!(ipv6.local_addr(), "[::1]:3000".parse().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert!(ServerConfig::from_vars(Some("localhost".into()), None).is_err());
+        assert!(ServerConfig::from_vars(None, Some("99999".into())).is_err());
+    }
+
+    #[test]
+    fn test_client_config_flags_override_the_server_features() {
+        let config = ClientConfig::from_vars(
+            AuthMode::Mock,
+            &[("event_submissions", false), ("email_delivery", true)],
+            None,
+            Some(String::new()),
+            Some("#1E6FA8".into()),
+            Some(" events_map, email_delivery=false ,".into()),
+        )
+        .unwrap();
+
+        assert_eq!(config.branding.name, "Aqio");
+        assert_eq!(config.branding.logo_url, None);
+        assert_eq!(config.branding.primary_color.as_deref(), Some("#1e6fa8"));
+        let features: Vec<(&str, bool)> = config.features.iter().map(|(name, on)| (name.as_str(), *on)).collect();
+        assert_eq!(features, vec![("email_delivery", false), ("event_submissions", false), ("events_map", true)]);
+
+        let color = ClientConfig::from_vars(AuthMode::Mock, &[], None, None, Some("blue".into()), None);
+        assert!(color.is_err());
+        let flag = ClientConfig::from_vars(AuthMode::Mock, &[], None, None, None, Some("events_map=yes".into()));
+        assert!(flag.is_err());
+    }
+
+    #[test]
+    fn test_client_log_level() {
+        assert_eq!(ClientConfig::remote_log_level(None).unwrap(), None);
+        assert_eq!(ClientConfig::remote_log_level(Some(" ".into())).unwrap(), None);
+        assert_eq!(ClientConfig::remote_log_level(Some("Warn".into())).unwrap().as_deref(), Some("warn"));
+        assert!(ClientConfig::remote_log_level(Some("trace".into())).is_err());
+    }
+
+    fn vars<'a>(pairs: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| pairs.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+    }
+
+    #[test]
+    fn test_invoicing_needs_a_seller() {
+        assert_eq!(InvoicingConfig::from_vars(vars(&[("INVOICE_SELLER_NAME", "Aqio AS")])).unwrap(), None);
+
+        let config = InvoicingConfig::from_vars(vars(&[
+            ("INVOICE_SELLER_NAME", "Aqio AS"),
+            ("INVOICE_SELLER_ORG_NUMBER", "923 609 016"),
+            ("INVOICE_SELLER_CITY", " Bergen "),
+            ("INVOICE_BANK_ACCOUNT", "1503.20.80119"),
+        ]))
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.seller.org_number, "923609016");
+        assert_eq!(config.seller.city.as_deref(), Some("Bergen"));
+        assert_eq!(config.seller.address, None);
+        assert_eq!(config.bank_account.as_deref(), Some("15032080119"));
+        assert_eq!(config.payment_days, InvoicingConfig::DEFAULT_PAYMENT_DAYS);
+        assert!(!config.seller.vat_registered);
+
+        let (name, org_number) = (("INVOICE_SELLER_NAME", "Aqio AS"), ("INVOICE_SELLER_ORG_NUMBER", "923609016"));
+        let check_digit = InvoicingConfig::from_vars(vars(&[name, ("INVOICE_SELLER_ORG_NUMBER", "923609017")]));
+        assert!(check_digit.is_err());
+        let account = InvoicingConfig::from_vars(vars(&[name, org_number, ("INVOICE_BANK_ACCOUNT", "1503208011")]));
+        assert!(account.is_err());
+        let days = InvoicingConfig::from_vars(vars(&[name, org_number, ("INVOICE_PAYMENT_DAYS", "0")]));
+        assert!(days.is_err());
+        let vat = InvoicingConfig::from_vars(vars(&[name, org_number, ("INVOICE_SELLER_VAT_REGISTERED", "TRUE")]));
+        assert!(vat.unwrap().unwrap().seller.vat_registered);
+    }
+
+    #[test]
+    fn test_vipps_needs_every_credential() {
+        assert_eq!(VippsConfig::from_vars(vars(&[])).unwrap(), None);
+        let partial = VippsConfig::from_vars(vars(&[("VIPPS_CLIENT_ID", "aqio")]));
+        assert!(partial.unwrap_err().to_string().contains("VIPPS_MERCHANT_SERIAL_NUMBER"));
+
+        let config = VippsConfig::from_vars(vars(&[
+            ("VIPPS_CLIENT_ID", "aqio"),
+            ("VIPPS_CLIENT_SECRET", "secret"),
+            ("VIPPS_SUBSCRIPTION_KEY", "key"),
+            ("VIPPS_MERCHANT_SERIAL_NUMBER", " 123456 "),
+            ("VIPPS_API_URL", "https://apitest.vipps.no/"),
+        ]))
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.merchant_serial_number, "123456");
+        assert_eq!(config.api_url, "https://apitest.vipps.no");
+    }
+
+    #[test]
+    fn test_export_s3_keys_come_in_pairs() {
+        assert_eq!(ExportConfig::from_vars(vars(&[])).unwrap(), ExportConfig::default());
+        let partial = ExportConfig::from_vars(vars(&[("EXPORT_S3_ACCESS_KEY_ID", "AKIA")]));
+        assert!(partial.unwrap_err().to_string().contains("EXPORT_S3_SECRET_ACCESS_KEY"));
+
+        let config = ExportConfig::from_vars(vars(&[
+            ("EXPORT_S3_ACCESS_KEY_ID", "AKIA"),
+            ("EXPORT_S3_SECRET_ACCESS_KEY", " secret "),
+            ("EXPORT_SFTP_KEY_PATH", "/run/secrets/export_key"),
+        ]))
+        .unwrap();
+        assert_eq!(config.s3.unwrap().secret_access_key, "secret");
+        assert_eq!(config.sftp_key_path.as_deref(), Some("/run/secrets/export_key"));
+        assert_eq!(config.sftp_known_hosts, None);
+    }
+
+    #[test]
+    fn test_crm_dynamics_credentials_come_together() {
+        assert_eq!(CrmConfig::from_vars(vars(&[])).unwrap(), CrmConfig::default());
+        let partial = CrmConfig::from_vars(vars(&[("DYNAMICS_TENANT_ID", "contoso"), ("DYNAMICS_CLIENT_ID", "app")]));
+        assert!(partial.unwrap_err().to_string().contains("DYNAMICS_CLIENT_SECRET"));
+
+        let config = CrmConfig::from_vars(vars(&[
+            ("HUBSPOT_ACCESS_TOKEN", " pat-eu1-token "),
+            ("DYNAMICS_TENANT_ID", "contoso"),
+            ("DYNAMICS_CLIENT_ID", "app"),
+            ("DYNAMICS_CLIENT_SECRET", "secret"),
+        ]))
+        .unwrap();
+        assert_eq!(config.hubspot_access_token.as_deref(), Some("pat-eu1-token"));
+        assert_eq!(config.dynamics.unwrap().tenant_id, "contoso");
+    }
+
+    #[test]
+    fn test_fault_plan() {
+        assert_eq!(fault_plan_from_vars(vars(&[("FAULT_TARGETS", "events")])).unwrap(), None);
+
+        let plan = fault_plan_from_vars(vars(&[
+            ("FAULT_LATENCY_MS", "200"),
+            ("FAULT_FAILURE_RATE", " 0.05 "),
+            ("FAULT_TARGETS", "registrations, sendgrid,"),
+        ]))
+        .unwrap()
+        .unwrap();
+        assert_eq!(plan.max_latency, Duration::from_millis(200));
+        assert_eq!(plan.failure_rate, 0.05);
+        assert_eq!(plan.conflict_rate, 0.0);
+        assert_eq!(plan.targets, vec!["registrations", "sendgrid"]);
+
+        assert!(fault_plan_from_vars(vars(&[("FAULT_FAILURE_RATE", "5%")])).is_err());
+        assert!(fault_plan_from_vars(vars(&[("FAULT_CONFLICT_RATE", "1.5")])).is_err());
+        assert!(fault_plan_from_vars(vars(&[("FAULT_LATENCY_MS", "-1")])).is_err());
+    }
+}
@@ -0,0 +1,426 @@
+// Annual report of an organization: the events its members held in one
+// calendar year, their attendance next to the year before, the busiest
+// categories and what attendees said in the surveys. Served as JSON and as a
+// one-page A4 PDF in the standard Helvetica font, like certificates.
+
+use aqio_core::{OrganizationYear, SurveySatisfaction};
+use chrono::{DateTime, Utc};
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use uuid::Uuid;
+
+use crate::domain::certificates::{text_width, win_ansi};
+use crate::domain::past_events::{attendance_rate, change};
+
+/// Categories listed in the report, most check-ins first
+pub const TOP_CATEGORIES: usize = 5;
+
+// A4 portrait, in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 56.0;
+const RIGHT: f32 = PAGE_WIDTH - MARGIN;
+const ROW_HEIGHT: f32 = 18.0;
+
+const FONT: Name = Name(b"F1");
+const BOLD: Name = Name(b"F2");
+
+/// An organization's totals for one calendar year
+#[derive(Debug, Clone, PartialEq)]
+pub struct YearTotals {
+    pub year: i32,
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub cancelled: i64,
+    /// Share of registrations that checked in, in percent
+    pub attendance_rate: Option<f64>,
+}
+
+impl From<&OrganizationYear> for YearTotals {
+    fn from(year: &OrganizationYear) -> Self {
+        Self {
+            year: year.year,
+            events: year.events,
+            registrations: year.registrations,
+            checked_in: year.checked_in,
+            cancelled: year.cancelled,
+            attendance_rate: attendance_rate(year.registrations, year.checked_in),
+        }
+    }
+}
+
+/// One of the year's busiest categories
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryLine {
+    pub category_id: String,
+    pub category_name: Option<String>,
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub attendance_rate: Option<f64>,
+}
+
+/// Survey answers, averaged and rounded to one decimal
+#[derive(Debug, Clone, PartialEq)]
+pub struct Satisfaction {
+    pub responses: i64,
+    /// Overall rating, 1 to 5
+    pub average_rating: Option<f64>,
+    /// Respondents who would recommend the event, in percent
+    pub recommend_rate: Option<f64>,
+    /// Likelihood to attend again, 1 to 10
+    pub average_return_likelihood: Option<f64>,
+}
+
+impl From<&SurveySatisfaction> for Satisfaction {
+    fn from(survey: &SurveySatisfaction) -> Self {
+        Self {
+            responses: survey.responses,
+            average_rating: survey.average_rating.map(round),
+            recommend_rate: survey.recommend_share.map(|share| round(share * 100.0)),
+            average_return_likelihood: survey.average_return_likelihood.map(round),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnualReport {
+    pub company_id: Uuid,
+    pub company_name: String,
+    pub generated_at: DateTime<Utc>,
+    pub current: YearTotals,
+    pub previous: YearTotals,
+    /// Changes from the year before, in percent; `None` when the year before
+    /// had nothing to compare with
+    pub events_growth: Option<f64>,
+    pub registrations_growth: Option<f64>,
+    pub attendance_growth: Option<f64>,
+    pub top_categories: Vec<CategoryLine>,
+    pub satisfaction: Satisfaction,
+}
+
+impl AnnualReport {
+    /// `previous` is the calendar year before `current`
+    pub fn new(
+        company_name: String,
+        current: &OrganizationYear,
+        previous: &OrganizationYear,
+        generated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            company_id: current.company_id,
+            company_name,
+            generated_at,
+            current: YearTotals::from(current),
+            previous: YearTotals::from(previous),
+            events_growth: change(previous.events, current.events),
+            registrations_growth: change(previous.registrations, current.registrations),
+            attendance_growth: change(previous.checked_in, current.checked_in),
+            top_categories: current
+                .categories
+                .iter()
+                .take(TOP_CATEGORIES)
+                .map(|category| CategoryLine {
+                    category_id: category.category_id.clone(),
+                    category_name: category.category_name.clone(),
+                    events: category.events,
+                    registrations: category.registrations,
+                    checked_in: category.checked_in,
+                    attendance_rate: attendance_rate(category.registrations, category.checked_in),
+                })
+                .collect(),
+            satisfaction: Satisfaction::from(&current.satisfaction),
+        }
+    }
+
+    /// Download name, e.g. `annual-report-havbruk-as-2025.pdf`
+    pub fn filename(&self) -> String {
+        let slug = self
+            .company_name
+            .to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        if slug.is_empty() {
+            format!("annual-report-{}.pdf", self.current.year)
+        } else {
+            format!("annual-report-{}-{}.pdf", slug, self.current.year)
+        }
+    }
+}
+
+/// Lay out the report as a single-page PDF
+pub fn render_annual_report(report: &AnnualReport) -> Vec<u8> {
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let page_id = Ref::new(3);
+    let font_id = Ref::new(4);
+    let bold_id = Ref::new(5);
+    let content_id = Ref::new(6);
+    let info_id = Ref::new(7);
+
+    let (year, previous_year) = (report.current.year, report.previous.year);
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids([page_id]).count(1);
+    pdf.document_info(info_id)
+        .title(TextStr(&format!("Annual report {} - {}", year, report.company_name)))
+        .creator(TextStr("Aqio"));
+
+    let mut page = pdf.page(page_id);
+    page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT));
+    page.parent(page_tree_id);
+    page.contents(content_id);
+    page.resources().fonts().pair(FONT, font_id).pair(BOLD, bold_id);
+    page.finish();
+
+    pdf.type1_font(font_id)
+        .base_font(Name(b"Helvetica"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+    pdf.type1_font(bold_id)
+        .base_font(Name(b"Helvetica-Bold"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+
+    let mut content = Content::new();
+    let mut y = PAGE_HEIGHT - MARGIN - 24.0;
+
+    content.set_fill_rgb(0.11, 0.36, 0.55);
+    text_at(&mut content, BOLD, &format!("Annual report {}", year), 24.0, MARGIN, y);
+    y -= 26.0;
+    content.set_fill_gray(0.0);
+    text_at(&mut content, FONT, &clipped(&report.company_name, 16.0, RIGHT - MARGIN), 16.0, MARGIN, y);
+    y -= 18.0;
+    content.set_fill_gray(0.4);
+    let subtitle = format!(
+        "Events held in {}, compared with {}. Generated {}.",
+        year,
+        previous_year,
+        report.generated_at.format("%-d %B %Y")
+    );
+    text_at(&mut content, FONT, &subtitle, 9.0, MARGIN, y);
+
+    // Year against the year before
+    y -= 40.0;
+    heading(&mut content, "Events and attendance", y);
+    y -= 24.0;
+    let columns = [350.0, 440.0, RIGHT];
+    header_row(&mut content, &[&year.to_string(), &previous_year.to_string(), "Change"], &columns, y);
+    let (current, previous) = (&report.current, &report.previous);
+    let rows = [
+        ("Events held", current.events.to_string(), previous.events.to_string(), signed_percent(report.events_growth)),
+        (
+            "Registrations",
+            current.registrations.to_string(),
+            previous.registrations.to_string(),
+            signed_percent(report.registrations_growth),
+        ),
+        (
+            "Attendance (checked in)",
+            current.checked_in.to_string(),
+            previous.checked_in.to_string(),
+            signed_percent(report.attendance_growth),
+        ),
+        (
+            "Attendance rate",
+            percent(current.attendance_rate),
+            percent(previous.attendance_rate),
+            String::new(),
+        ),
+        ("Cancellations", current.cancelled.to_string(), previous.cancelled.to_string(), String::new()),
+    ];
+    for (label, this_year, last_year, growth) in &rows {
+        y -= ROW_HEIGHT;
+        row(&mut content, label, &[this_year, last_year, growth], &columns, y);
+    }
+
+    // Busiest categories
+    y -= 40.0;
+    heading(&mut content, "Top categories", y);
+    y -= 24.0;
+    if report.top_categories.is_empty() {
+        content.set_fill_gray(0.4);
+        text_at(&mut content, FONT, "No events this year.", 10.0, MARGIN, y);
+    } else {
+        let columns = [320.0, 390.0, 465.0, RIGHT];
+        header_row(&mut content, &["Events", "Registered", "Checked in", "Attendance"], &columns, y);
+        for category in &report.top_categories {
+            y -= ROW_HEIGHT;
+            let name = category.category_name.as_deref().unwrap_or(&category.category_id);
+            row(
+                &mut content,
+                &clipped(name, 10.0, columns[0] - MARGIN - 50.0),
+                &[
+                    &category.events.to_string(),
+                    &category.registrations.to_string(),
+                    &category.checked_in.to_string(),
+                    &percent(category.attendance_rate),
+                ],
+                &columns,
+                y,
+            );
+        }
+    }
+
+    // Survey answers
+    y -= 40.0;
+    heading(&mut content, "Survey satisfaction", y);
+    let satisfaction = &report.satisfaction;
+    if satisfaction.responses == 0 {
+        y -= 24.0;
+        content.set_fill_gray(0.4);
+        text_at(&mut content, FONT, "No survey responses for this year's events.", 10.0, MARGIN, y);
+    } else {
+        let columns = [RIGHT];
+        let rows = [
+            ("Responses", satisfaction.responses.to_string()),
+            ("Average rating", out_of(satisfaction.average_rating, 5)),
+            ("Would recommend", percent(satisfaction.recommend_rate)),
+            ("Likely to attend again", out_of(satisfaction.average_return_likelihood, 10)),
+        ];
+        y -= 24.0 - ROW_HEIGHT;
+        for (label, value) in &rows {
+            y -= ROW_HEIGHT;
+            row(&mut content, label, &[value], &columns, y);
+        }
+    }
+
+    pdf.stream(content_id, &content.finish());
+    pdf.finish()
+}
+
+fn text_at(content: &mut Content, font: Name, text: &str, size: f32, x: f32, y: f32) {
+    content.begin_text();
+    content.set_font(font, size);
+    content.next_line(x, y);
+    content.show(Str(&win_ansi(text)));
+    content.end_text();
+}
+
+fn heading(content: &mut Content, text: &str, y: f32) {
+    content.set_fill_rgb(0.11, 0.36, 0.55);
+    text_at(content, BOLD, text, 13.0, MARGIN, y);
+    content.set_stroke_rgb(0.11, 0.36, 0.55);
+    content.set_line_width(0.75);
+    content.move_to(MARGIN, y - 6.0);
+    content.line_to(RIGHT, y - 6.0);
+    content.stroke();
+}
+
+// Column titles, right-aligned on the columns' right edges
+fn header_row(content: &mut Content, titles: &[&str], columns: &[f32], y: f32) {
+    content.set_fill_gray(0.4);
+    for (title, right) in titles.iter().zip(columns) {
+        text_at(content, FONT, title, 9.0, right - text_width(title, 9.0), y);
+    }
+}
+
+fn row(content: &mut Content, label: &str, values: &[&str], columns: &[f32], y: f32) {
+    content.set_fill_gray(0.0);
+    text_at(content, FONT, label, 10.0, MARGIN, y);
+    for (value, right) in values.iter().zip(columns) {
+        text_at(content, FONT, value, 10.0, right - text_width(value, 10.0), y);
+    }
+}
+
+// Cut long names short so they don't run into the next column
+fn clipped(text: &str, size: f32, width: f32) -> String {
+    if text_width(text, size) <= width {
+        return text.to_string();
+    }
+    let mut clipped = text.to_string();
+    while !clipped.is_empty() && text_width(&clipped, size) + text_width("...", size) > width {
+        clipped.pop();
+    }
+    format!("{}...", clipped.trim_end())
+}
+
+fn percent(value: Option<f64>) -> String {
+    value.map(|value| format!("{:.1}%", value)).unwrap_or_else(|| "\u{2013}".to_string())
+}
+
+fn signed_percent(value: Option<f64>) -> String {
+    value.map(|value| format!("{:+.1}%", value)).unwrap_or_else(|| "\u{2013}".to_string())
+}
+
+fn out_of(value: Option<f64>, scale: u8) -> String {
+    value
+        .map(|value| format!("{:.1} of {}", value, scale))
+        .unwrap_or_else(|| "\u{2013}".to_string())
+}
+
+fn round(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aqio_core::CategoryAttendance;
+    use chrono::TimeZone;
+
+    fn year(year: i32, events: i64, registrations: i64, checked_in: i64, categories: usize) -> OrganizationYear {
+        OrganizationYear {
+            events,
+            registrations,
+            checked_in,
+            categories: (0..categories)
+                .map(|index| CategoryAttendance {
+                    category_id: format!("category-{}", index),
+                    category_name: (index > 0).then(|| format!("Category {}", index)),
+                    events: 1,
+                    registrations: 10,
+                    checked_in: 10 - index as i64,
+                })
+                .collect(),
+            ..OrganizationYear::empty(Uuid::nil(), year)
+        }
+    }
+
+    #[test]
+    fn test_report_compares_with_the_year_before() {
+        let mut current = year(2025, 12, 200, 150, 7);
+        current.satisfaction = SurveySatisfaction {
+            responses: 3,
+            average_rating: Some(4.333),
+            recommend_share: Some(2.0 / 3.0),
+            average_return_likelihood: None,
+        };
+        let generated_at = Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap();
+        let report = AnnualReport::new("Havbruk AS".to_string(), &current, &year(2024, 10, 0, 0, 0), generated_at);
+
+        assert_eq!(report.current.attendance_rate, Some(75.0));
+        assert_eq!(report.previous.attendance_rate, None);
+        assert_eq!(report.events_growth, Some(20.0));
+        assert_eq!(report.registrations_growth, None);
+        assert_eq!(report.top_categories.len(), TOP_CATEGORIES);
+        assert_eq!(report.top_categories[1].attendance_rate, Some(90.0));
+        assert_eq!(
+            report.satisfaction,
+            Satisfaction {
+                responses: 3,
+                average_rating: Some(4.3),
+                recommend_rate: Some(66.7),
+                average_return_likelihood: None,
+            }
+        );
+        assert_eq!(report.filename(), "annual-report-havbruk-as-2025.pdf");
+    }
+
+    #[test]
+    fn test_renders_pdf_with_and_without_events() {
+        let generated_at = Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap();
+        let name = "Sjømat Norge with a name far too long for one line of the report".to_string();
+        let busy = AnnualReport::new(name.clone(), &year(2025, 3, 40, 30, 2), &year(2024, 2, 20, 10, 1), generated_at);
+        let pdf = render_annual_report(&busy);
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(pdf.windows(b"Annual report 2025".len()).any(|window| window == b"Annual report 2025"));
+        assert!(pdf.windows(b"+50.0%".len()).any(|window| window == b"+50.0%"));
+
+        let quiet = AnnualReport::new(name, &year(2025, 0, 0, 0, 0), &year(2024, 0, 0, 0, 0), generated_at);
+        let pdf = render_annual_report(&quiet);
+        assert!(pdf.windows(b"No events this year.".len()).any(|window| window == b"No events this year."));
+        assert_eq!(clipped("Category", 10.0, 1.0), "...");
+    }
+}
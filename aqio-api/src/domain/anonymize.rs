@@ -0,0 +1,264 @@
+// Scrubbing personal data from event archives, so production data can be
+// loaded into dev and staging. Replacements are deterministic: the same
+// email or name always becomes the same fake one, so duplicates and
+// cross-references in the data survive anonymization.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::archive::EventArchive;
+
+/// A kind of personal data an archive can be scrubbed of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiField {
+    /// Invitee and registrant emails, replaced with `@example.invalid` addresses
+    Email,
+    /// Invitee, registrant, guest and expense recorder names
+    Name,
+    /// Registrant phone numbers, masked down to the last two digits
+    Phone,
+    /// Registrant company names
+    Company,
+    /// Free text people wrote about themselves: dietary and accessibility
+    /// needs, special requests, custom answers and invitation messages; cleared
+    Notes,
+}
+
+impl PiiField {
+    pub const ALL: [PiiField; 5] = [
+        PiiField::Email,
+        PiiField::Name,
+        PiiField::Phone,
+        PiiField::Company,
+        PiiField::Notes,
+    ];
+
+    fn parse(value: &str) -> Option<PiiField> {
+        match value.to_lowercase().as_str() {
+            "email" => Some(PiiField::Email),
+            "name" => Some(PiiField::Name),
+            "phone" => Some(PiiField::Phone),
+            "company" => Some(PiiField::Company),
+            "notes" => Some(PiiField::Notes),
+            _ => None,
+        }
+    }
+}
+
+/// Which fields to scrub; empty leaves the archive untouched
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Anonymizer {
+    fields: BTreeSet<PiiField>,
+}
+
+impl Anonymizer {
+    pub fn new(fields: impl IntoIterator<Item = PiiField>) -> Self {
+        Self {
+            fields: fields.into_iter().collect(),
+        }
+    }
+
+    /// A comma-separated list such as `email,name,phone`, or `all`
+    ///
+    /// Unknown names are an error rather than ignored, so a typo can't let
+    /// personal data through.
+    pub fn parse(list: &str) -> Result<Self, String> {
+        let mut fields = BTreeSet::new();
+        for name in list.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            if name.eq_ignore_ascii_case("all") {
+                fields.extend(PiiField::ALL);
+                continue;
+            }
+            let field = PiiField::parse(name).ok_or_else(|| {
+                format!("Unknown field '{}'; expected email, name, phone, company, notes or all", name)
+            })?;
+            fields.insert(field);
+        }
+        Ok(Self { fields })
+    }
+
+    /// Scrubs the fields of either
+    pub fn union(&self, other: &Anonymizer) -> Anonymizer {
+        Self::new(self.fields.union(&other.fields).copied())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = PiiField> + '_ {
+        self.fields.iter().copied()
+    }
+
+    fn scrubs(&self, field: PiiField) -> bool {
+        self.fields.contains(&field)
+    }
+
+    /// Scrubs the archive in place and records the fields in `anonymized`
+    pub fn apply(&self, archive: &mut EventArchive) {
+        if self.is_empty() {
+            return;
+        }
+        let email = self.scrubs(PiiField::Email);
+        let name = self.scrubs(PiiField::Name);
+        let phone = self.scrubs(PiiField::Phone);
+        let company = self.scrubs(PiiField::Company);
+        let notes = self.scrubs(PiiField::Notes);
+
+        for invitation in &mut archive.invitations {
+            if email {
+                invitation.invited_email = invitation.invited_email.as_deref().map(fake_email);
+            }
+            if name {
+                invitation.invited_name = invitation.invited_name.as_deref().map(fake_name);
+            }
+            if notes {
+                invitation.personal_message = None;
+            }
+        }
+
+        for registration in &mut archive.registrations {
+            if email {
+                registration.registrant_email = registration.registrant_email.as_deref().map(fake_email);
+            }
+            if name {
+                registration.registrant_name = registration.registrant_name.as_deref().map(fake_name);
+                for guest in &mut registration.guest_names {
+                    *guest = fake_name(guest);
+                }
+            }
+            if phone {
+                registration.registrant_phone = registration.registrant_phone.as_deref().map(mask_phone);
+            }
+            if company {
+                registration.registrant_company = registration
+                    .registrant_company
+                    .as_deref()
+                    .map(|value| format!("Company {}", pseudonym(value)));
+            }
+            if notes {
+                registration.dietary_restrictions = None;
+                registration.accessibility_needs = None;
+                registration.special_requests = None;
+                registration.custom_responses = None;
+            }
+        }
+
+        if name {
+            for expense in &mut archive.expenses {
+                expense.recorded_by_name = expense.recorded_by_name.as_deref().map(fake_name);
+            }
+        }
+
+        let mut anonymized: BTreeSet<PiiField> = archive.anonymized.iter().copied().collect();
+        anonymized.extend(self.fields());
+        archive.anonymized = anonymized.into_iter().collect();
+    }
+}
+
+/// Eight hex digits derived from the value, ignoring case and surrounding
+/// whitespace
+///
+/// FNV-1a rather than the std hasher, whose output may change between Rust
+/// releases; archives anonymized on different builds must still agree.
+fn pseudonym(value: &str) -> String {
+    let hash = value
+        .trim()
+        .to_lowercase()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    format!("{:08x}", (hash >> 32) as u32 ^ hash as u32)
+}
+
+// `.invalid` is reserved (RFC 2606), so these can never be delivered
+fn fake_email(email: &str) -> String {
+    format!("person-{}@example.invalid", pseudonym(email))
+}
+
+fn fake_name(name: &str) -> String {
+    format!("Person {}", pseudonym(name))
+}
+
+/// Every digit but the last two replaced with `*`, keeping the layout
+fn mask_phone(phone: &str) -> String {
+    let digits = phone.chars().filter(char::is_ascii_digit).count();
+    let mut seen = 0;
+    phone
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_digit() {
+                return c;
+            }
+            seen += 1;
+            if seen > digits.saturating_sub(2) { c } else { '*' }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::archive::ARCHIVE_FORMAT_VERSION;
+    use crate::testing::helpers::{TestEventBuilder, TestRegistrationBuilder};
+    use chrono::Utc;
+
+    fn archive_with_registrations(registrations: Vec<aqio_core::EventRegistration>) -> EventArchive {
+        EventArchive {
+            format_version: ARCHIVE_FORMAT_VERSION,
+            exported_at: Utc::now(),
+            anonymized: vec![],
+            event: TestEventBuilder::new().build(),
+            invitations: vec![],
+            registrations,
+            sponsors: vec![],
+            budget_lines: vec![],
+            expenses: vec![],
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_fields() {
+        assert_eq!(Anonymizer::parse(" Email, phone ,").unwrap(), Anonymizer::new([PiiField::Email, PiiField::Phone]));
+        assert_eq!(Anonymizer::parse("name,all").unwrap(), Anonymizer::new(PiiField::ALL));
+        assert!(Anonymizer::parse("").unwrap().is_empty());
+        assert!(Anonymizer::parse("email,adress").is_err());
+    }
+
+    #[test]
+    fn test_apply_scrubs_only_the_chosen_fields_deterministically() {
+        let mut first = TestRegistrationBuilder::new().with_email("Kari@Mowi.no").build();
+        first.registrant_name = Some("Kari Nordmann".to_string());
+        first.registrant_phone = Some("+47 912 34 567".to_string());
+        first.registrant_company = Some("Mowi".to_string());
+        first.dietary_restrictions = Some("Nut allergy".to_string());
+        first.guest_names = vec!["Ola Nordmann".to_string()];
+        let mut second = TestRegistrationBuilder::new().with_email(" kari@mowi.no").build();
+        second.registrant_name = Some("Kari Nordmann".to_string());
+
+        let mut archive = archive_with_registrations(vec![first, second]);
+        Anonymizer::new([PiiField::Email, PiiField::Name, PiiField::Phone]).apply(&mut archive);
+
+        let (first, second) = (&archive.registrations[0], &archive.registrations[1]);
+        let email = first.registrant_email.as_deref().unwrap();
+        assert!(email.starts_with("person-") && email.ends_with("@example.invalid"));
+        // The same person stays recognizable as the same person
+        assert_eq!(first.registrant_email, second.registrant_email);
+        assert_eq!(first.registrant_name, second.registrant_name);
+        assert_ne!(first.registrant_name.as_deref(), Some("Kari Nordmann"));
+        assert!(first.guest_names[0].starts_with("Person "));
+        assert_eq!(first.registrant_phone.as_deref(), Some("+** *** ** *67"));
+        // Not asked for
+        assert_eq!(first.registrant_company.as_deref(), Some("Mowi"));
+        assert_eq!(first.dietary_restrictions.as_deref(), Some("Nut allergy"));
+        assert_eq!(archive.anonymized, vec![PiiField::Email, PiiField::Name, PiiField::Phone]);
+
+        Anonymizer::new([PiiField::Notes]).apply(&mut archive);
+        assert_eq!(archive.registrations[0].dietary_restrictions, None);
+        assert_eq!(archive.anonymized.len(), 4);
+    }
+}
@@ -0,0 +1,266 @@
+// Event archives: an event with its invitations, registrations, sponsors and
+// budget as one JSON document, for moving an event between environments
+// (staging to production). Importing gives every row a new id and drops
+// references that can't be trusted in the target: users that don't exist
+// there, external contacts, invitation tokens and uploaded files.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use aqio_core::{BudgetLine, Event, EventExpense, EventInvitation, EventRegistration, EventSponsor, EventStatus};
+
+use crate::domain::anonymize::PiiField;
+
+/// Bumped whenever the archive layout changes incompatibly
+pub const ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+/// An event and everything hanging off it, as exported
+///
+/// Attachments, receipts and photos are left out: they are files, not rows.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventArchive {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    /// Personal data already scrubbed from the archive
+    #[serde(default)]
+    pub anonymized: Vec<PiiField>,
+    pub event: Event,
+    #[serde(default)]
+    pub invitations: Vec<EventInvitation>,
+    #[serde(default)]
+    pub registrations: Vec<EventRegistration>,
+    #[serde(default)]
+    pub sponsors: Vec<EventSponsor>,
+    #[serde(default)]
+    pub budget_lines: Vec<BudgetLine>,
+    #[serde(default)]
+    pub expenses: Vec<EventExpense>,
+}
+
+impl EventArchive {
+    /// A copy ready to be inserted next to the original: new ids throughout,
+    /// organized by `organizer_id` and back to a draft so nothing goes public
+    /// before it has been checked
+    ///
+    /// `known_users` are the users that exist in the target; references to
+    /// anyone else are cleared.
+    pub fn rekey(self, organizer_id: Uuid, known_users: &HashSet<Uuid>, now: DateTime<Utc>) -> EventArchive {
+        let known = |user_id: Option<Uuid>| user_id.filter(|id| known_users.contains(id));
+
+        let mut event = self.event;
+        event.id = Uuid::new_v4();
+        event.slug = Event::slug_for(&event.title, event.id);
+        event.organizer_id = organizer_id;
+        event.co_organizers.retain(|id| *id != organizer_id && known_users.contains(id));
+        event.status = EventStatus::Draft;
+        event.publish_at = None;
+        event.created_at = now;
+        event.updated_at = now;
+        let event_id = event.id;
+
+        let mut invitation_ids = HashMap::new();
+        let invitations = self
+            .invitations
+            .into_iter()
+            .map(|mut invitation| {
+                let id = Uuid::new_v4();
+                invitation_ids.insert(invitation.id, id);
+                invitation.id = id;
+                invitation.event_id = event_id;
+                invitation.invited_user_id = known(invitation.invited_user_id);
+                invitation.invited_contact_id = None;
+                invitation.inviter_id = organizer_id;
+                // Old links must not work against the new copy
+                invitation.invitation_token = Some(Uuid::new_v4().to_string());
+                invitation
+            })
+            .collect();
+
+        let registrations = self
+            .registrations
+            .into_iter()
+            .map(|mut registration| {
+                registration.id = Uuid::new_v4();
+                registration.event_id = event_id;
+                registration.invitation_id = registration
+                    .invitation_id
+                    .and_then(|id| invitation_ids.get(&id).copied());
+                registration.user_id = known(registration.user_id);
+                registration.external_contact_id = None;
+                registration
+            })
+            .collect();
+
+        let sponsors = self
+            .sponsors
+            .into_iter()
+            .map(|mut sponsor| {
+                sponsor.id = Uuid::new_v4();
+                sponsor.event_id = event_id;
+                sponsor
+            })
+            .collect();
+
+        let mut line_ids = HashMap::new();
+        let budget_lines = self
+            .budget_lines
+            .into_iter()
+            .map(|mut line| {
+                let id = Uuid::new_v4();
+                line_ids.insert(line.id, id);
+                line.id = id;
+                line.event_id = event_id;
+                line
+            })
+            .collect();
+
+        let expenses = self
+            .expenses
+            .into_iter()
+            .map(|mut expense| {
+                expense.id = Uuid::new_v4();
+                expense.event_id = event_id;
+                expense.budget_line_id = expense.budget_line_id.and_then(|id| line_ids.get(&id).copied());
+                expense.recorded_by = known(expense.recorded_by);
+                expense.receipt = None;
+                expense
+            })
+            .collect();
+
+        EventArchive {
+            format_version: self.format_version,
+            exported_at: self.exported_at,
+            anonymized: self.anonymized,
+            event,
+            invitations,
+            registrations,
+            sponsors,
+            budget_lines,
+            expenses,
+        }
+    }
+
+    /// Every user the archive refers to, to look up in the target
+    pub fn referenced_users(&self) -> Vec<Uuid> {
+        let users: HashSet<Uuid> = self
+            .event
+            .co_organizers
+            .iter()
+            .copied()
+            .chain(self.invitations.iter().filter_map(|i| i.invited_user_id))
+            .chain(self.registrations.iter().filter_map(|r| r.user_id))
+            .chain(self.expenses.iter().filter_map(|e| e.recorded_by))
+            .collect();
+        users.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::{TestEventBuilder, TestRegistrationBuilder};
+    use aqio_core::{ExpenseReceipt, InvitationMethod, InvitationStatus};
+
+    fn invitation(event_id: Uuid, invited_user_id: Option<Uuid>) -> EventInvitation {
+        let now = Utc::now();
+        EventInvitation {
+            id: Uuid::new_v4(),
+            event_id,
+            invited_user_id,
+            invited_contact_id: Some(Uuid::new_v4()),
+            invited_email: Some("kari@example.no".to_string()),
+            invited_name: Some("Kari".to_string()),
+            inviter_id: Uuid::new_v4(),
+            invitation_method: InvitationMethod::Email,
+            personal_message: None,
+            status: InvitationStatus::Accepted,
+            sent_at: Some(now),
+            opened_at: None,
+            clicked_at: None,
+            responded_at: Some(now),
+            delivery_failure: None,
+            delivery_failed_at: None,
+            invitation_token: Some("staging-token".to_string()),
+            expires_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_rekey_gives_new_ids_and_keeps_links() {
+        let known_user = Uuid::new_v4();
+        let importer = Uuid::new_v4();
+        let event = TestEventBuilder::new().with_title("Sjømat & Sånn").published().build();
+        let original_id = event.id;
+
+        let invitation = invitation(event.id, Some(known_user));
+        let mut registration = TestRegistrationBuilder::new().with_event(event.id).build();
+        registration.invitation_id = Some(invitation.id);
+        let line = BudgetLine::new(event.id, "Venue", 50_000);
+        let now = Utc::now();
+        let expense = EventExpense {
+            id: Uuid::new_v4(),
+            event_id: event.id,
+            budget_line_id: Some(line.id),
+            description: "Deposit".to_string(),
+            vendor: None,
+            amount: 10_000,
+            incurred_on: now.date_naive(),
+            receipt: Some(ExpenseReceipt {
+                original_filename: "deposit.pdf".to_string(),
+                storage_key: "events/x/receipts/deposit.pdf".to_string(),
+                file_size: 10,
+                mime_type: "application/pdf".to_string(),
+            }),
+            recorded_by: Some(Uuid::new_v4()),
+            recorded_by_name: Some("Ola".to_string()),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let archive = EventArchive {
+            format_version: ARCHIVE_FORMAT_VERSION,
+            exported_at: now,
+            anonymized: vec![],
+            event,
+            invitations: vec![invitation.clone()],
+            registrations: vec![registration.clone()],
+            sponsors: vec![],
+            budget_lines: vec![line.clone()],
+            expenses: vec![expense],
+        };
+        assert!(archive.referenced_users().contains(&known_user));
+
+        let copy = archive.rekey(importer, &HashSet::from([known_user]), now);
+
+        assert_ne!(copy.event.id, original_id);
+        assert!(copy.event.slug.starts_with("sjomat-sann-"));
+        assert_eq!(copy.event.organizer_id, importer);
+        assert!(matches!(copy.event.status, EventStatus::Draft));
+
+        let new_invitation = &copy.invitations[0];
+        assert_ne!(new_invitation.id, invitation.id);
+        assert_eq!(new_invitation.event_id, copy.event.id);
+        assert_eq!(new_invitation.invited_user_id, Some(known_user));
+        assert_eq!(new_invitation.invited_contact_id, None);
+        assert_ne!(new_invitation.invitation_token.as_deref(), Some("staging-token"));
+
+        // The registrant's account doesn't exist in the target
+        let new_registration = &copy.registrations[0];
+        assert_eq!(new_registration.invitation_id, Some(new_invitation.id));
+        assert_eq!(new_registration.user_id, None);
+        assert_eq!(new_registration.registrant_email, registration.registrant_email);
+
+        let new_expense = &copy.expenses[0];
+        assert_eq!(new_expense.budget_line_id, Some(copy.budget_lines[0].id));
+        assert_ne!(copy.budget_lines[0].id, line.id);
+        assert!(new_expense.receipt.is_none());
+        assert_eq!(new_expense.recorded_by, None);
+        assert_eq!(new_expense.recorded_by_name.as_deref(), Some("Ola"));
+    }
+}
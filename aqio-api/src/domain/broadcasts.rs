@@ -0,0 +1,87 @@
+// The email an organizer's broadcast becomes: their subject prefixed with the
+// event's title, and their message below a line saying who it is from. The
+// message is plain text; blank lines separate paragraphs in the HTML part.
+
+use aqio_core::{EmailMessage, Event, EventBroadcast};
+
+use crate::domain::digest::escape_html;
+use crate::domain::locale::Localization;
+use crate::domain::ownership::event_url;
+
+pub fn render_broadcast_email(
+    broadcast: &EventBroadcast,
+    event: &Event,
+    to: &str,
+    public_url: &str,
+    localization: &Localization,
+) -> EmailMessage {
+    let l = localization;
+    let intro = format!(
+        "{} {}:",
+        l.text("A message from the organizers of", "En melding fra arrangørene av"),
+        event.title
+    );
+    let message = broadcast.message.trim();
+    let link = event_url(public_url, event);
+
+    let text = format!("{}\n\n{}\n\n{}\n", intro, message, link);
+    let mut html = format!("<p>{}</p>\n", escape_html(&intro));
+    for paragraph in message.split("\n\n").map(str::trim).filter(|paragraph| !paragraph.is_empty()) {
+        let lines: Vec<String> = paragraph.lines().map(escape_html).collect();
+        html.push_str(&format!("<p>{}</p>\n", lines.join("<br>\n")));
+    }
+    html.push_str(&format!(
+        "<p><a href=\"{}\">{}</a></p>\n",
+        escape_html(&link),
+        l.text("Open the event", "Åpne arrangementet")
+    ));
+
+    EmailMessage {
+        to: to.to_string(),
+        subject: format!("[{}] {}", event.title, broadcast.subject.trim()),
+        text_body: text,
+        html_body: html,
+        from: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestEventBuilder;
+    use aqio_core::{BroadcastAudience, Locale};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_message_keeps_its_paragraphs_and_is_escaped() {
+        let event = TestEventBuilder::new().with_title("Salmon & Sea").build();
+        let broadcast = EventBroadcast::new(
+            event.id,
+            Uuid::new_v4(),
+            BroadcastAudience::Confirmed,
+            " Parking ",
+            "Use gate <B>.\nIt opens at 8.\n\nSee you!\n",
+            None,
+        );
+
+        let email = render_broadcast_email(&broadcast, &event, "kari@example.no", "https://aqio.no/", &Localization::default());
+
+        let link = format!("https://aqio.no/events/{}", event.id);
+        assert_eq!(email.to, "kari@example.no");
+        assert_eq!(email.subject, "[Salmon & Sea] Parking");
+        assert_eq!(
+            email.text_body,
+            format!(
+                "A message from the organizers of Salmon & Sea:\n\nUse gate <B>.\nIt opens at 8.\n\nSee you!\n\n{}\n",
+                link
+            )
+        );
+        assert!(email.html_body.contains("<p>Use gate &lt;B&gt;.<br>\nIt opens at 8.</p>\n<p>See you!</p>"));
+        assert!(email.html_body.contains("organizers of Salmon &amp; Sea"));
+
+        let norwegian = Localization { locale: Locale::Nb, ..Localization::default() };
+        let email = render_broadcast_email(&broadcast, &event, "kari@example.no", "https://aqio.no", &norwegian);
+        assert!(email.text_body.starts_with("En melding fra arrangørene av Salmon & Sea:"));
+        assert!(email.html_body.contains(&format!("<a href=\"{}\">Åpne arrangementet</a>", link)));
+    }
+}
@@ -0,0 +1,460 @@
+// iCalendar (RFC 5545) files for events, so visitors can add an event to their
+// own calendar. The description carries the event text followed by the
+// accommodation and travel information organizers have filled in, in the
+// visitor's language and time zone. Staff can also take the shifts they
+// signed up for into their calendar, and attendees get invites for the 1:1
+// meetings they arrange through matchmaking.
+
+use chrono::{DateTime, Utc};
+
+use aqio_core::{Event, EventShift, Meeting, MeetingStatus, User};
+
+use crate::domain::dto::{ParkingInfo, TransportMode, TravelInfo};
+use crate::domain::locale::Localization;
+
+// Longest content line allowed before it has to be folded, in octets
+const MAX_LINE_OCTETS: usize = 75;
+
+/// A single-event calendar; `page_url` is where the event's public page lives
+pub fn render_event_ics(
+    event: &Event,
+    travel: &TravelInfo,
+    page_url: &str,
+    now: DateTime<Utc>,
+    localization: &Localization,
+) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Aqio//Events//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        // Times stay in UTC; this only hints which zone to show them in
+        format!("X-WR-TIMEZONE:{}", localization.timezone.name()),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}@aqio", event.id),
+        format!("DTSTAMP:{}", timestamp(now)),
+        format!("DTSTART:{}", timestamp(event.start_date)),
+        format!("DTEND:{}", timestamp(event.end_date)),
+        format!("SUMMARY:{}", escape_text(&event.title)),
+    ];
+    let location: Vec<&str> = [event.location_name.as_deref(), event.address.as_deref()]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.trim().is_empty())
+        .collect();
+    if !location.is_empty() {
+        lines.push(format!("LOCATION:{}", escape_text(&location.join(", "))));
+    }
+    if let (Some(latitude), Some(longitude)) = (event.latitude, event.longitude) {
+        lines.push(format!("GEO:{};{}", latitude, longitude));
+    }
+    lines.push(format!("DESCRIPTION:{}", escape_text(&description(event, travel, page_url, localization))));
+    lines.push(format!("URL:{}", page_url));
+    lines.push("END:VEVENT".to_string());
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line)).collect::<Vec<_>>().join("")
+}
+
+/// A staff member's shifts as one calendar, each shift with the event it's at
+pub fn render_shifts_ics(shifts: &[(EventShift, Event)], now: DateTime<Utc>, localization: &Localization) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Aqio//Shifts//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(localization.text("My shifts", "Mine vakter"))),
+        format!("X-WR-TIMEZONE:{}", localization.timezone.name()),
+    ];
+    for (shift, event) in shifts {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:shift-{}@aqio", shift.id));
+        lines.push(format!("DTSTAMP:{}", timestamp(now)));
+        lines.push(format!("DTSTART:{}", timestamp(shift.starts_at)));
+        lines.push(format!("DTEND:{}", timestamp(shift.ends_at)));
+        lines.push(format!("SUMMARY:{}", escape_text(&format!("{} – {}", shift.role, event.title))));
+        let location: Vec<&str> = [event.location_name.as_deref(), event.address.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.trim().is_empty())
+            .collect();
+        if !location.is_empty() {
+            lines.push(format!("LOCATION:{}", escape_text(&location.join(", "))));
+        }
+        if let Some(description) = shift.description.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line)).collect::<Vec<_>>().join("")
+}
+
+/// An invite to a matchmaking meeting from the requester to the invitee.
+/// Declined and cancelled meetings give a cancellation, so calendars that
+/// imported the invite drop the meeting.
+pub fn render_meeting_invite(
+    meeting: &Meeting,
+    event: &Event,
+    requester: &User,
+    invitee: &User,
+    room: Option<&str>,
+    now: DateTime<Utc>,
+    localization: &Localization,
+) -> String {
+    let (method, status, answer, sequence) = match meeting.status {
+        MeetingStatus::Requested => ("REQUEST", "TENTATIVE", "NEEDS-ACTION", 0),
+        MeetingStatus::Accepted => ("REQUEST", "CONFIRMED", "ACCEPTED", 1),
+        MeetingStatus::Declined => ("CANCEL", "CANCELLED", "DECLINED", 1),
+        MeetingStatus::Cancelled => ("CANCEL", "CANCELLED", "NEEDS-ACTION", 1),
+    };
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Aqio//Meetings//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("METHOD:{}", method),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:meeting-{}@aqio", meeting.id),
+        format!("SEQUENCE:{}", sequence),
+        format!("DTSTAMP:{}", timestamp(now)),
+        format!("DTSTART:{}", timestamp(meeting.starts_at)),
+        format!("DTEND:{}", timestamp(meeting.ends_at)),
+        format!("STATUS:{}", status),
+        format!(
+            "SUMMARY:{}",
+            escape_text(&format!(
+                "{} {} & {} – {}",
+                localization.text("Meeting:", "Møte:"),
+                requester.name,
+                invitee.name,
+                event.title
+            ))
+        ),
+        format!("ORGANIZER;CN={}:mailto:{}", parameter(&requester.name), requester.email),
+        format!(
+            "ATTENDEE;CN={};ROLE=REQ-PARTICIPANT;PARTSTAT={}:mailto:{}",
+            parameter(&invitee.name),
+            answer,
+            invitee.email
+        ),
+    ];
+    let location: Vec<&str> = [room, event.location_name.as_deref()]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.trim().is_empty())
+        .collect();
+    if !location.is_empty() {
+        lines.push(format!("LOCATION:{}", escape_text(&location.join(", "))));
+    }
+    if let Some(message) = meeting.message.as_deref().map(str::trim).filter(|m| !m.is_empty()) {
+        lines.push(format!("DESCRIPTION:{}", escape_text(message)));
+    }
+    lines.push("END:VEVENT".to_string());
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line)).collect::<Vec<_>>().join("")
+}
+
+/// A name as a quoted parameter value; quotes aren't allowed inside one
+fn parameter(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "'"))
+}
+
+fn description(event: &Event, travel: &TravelInfo, page_url: &str, l: &Localization) -> String {
+    let when = format!(
+        "{}: {} – {}",
+        l.text("When", "Når"),
+        l.format_datetime(event.start_date),
+        l.format_datetime(event.end_date)
+    );
+    let mut sections = vec![when, event.description.trim().to_string()];
+    if let Some(travel) = travel_text(travel, l) {
+        sections.push(travel);
+    }
+    sections.push(format!("{}: {}", l.text("More information", "Mer informasjon"), page_url));
+    sections.retain(|section| !section.is_empty());
+    sections.join("\n\n")
+}
+
+/// The travel information as plain text, or `None` when there is none
+pub fn travel_text(travel: &TravelInfo, l: &Localization) -> Option<String> {
+    let mut sections = Vec::new();
+    if !travel.hotels.is_empty() {
+        let mut section = vec![l.text("Where to stay", "Overnatting").to_string()];
+        for hotel in &travel.hotels {
+            let mut line = format!("- {}", hotel.name);
+            if let Some(address) = &hotel.address {
+                line.push_str(&format!(", {}", address));
+            }
+            if let Some(minutes) = hotel.walking_minutes {
+                line.push_str(&format!(" ({} {})", minutes, l.text("min walk", "min å gå")));
+            }
+            if let Some(code) = &hotel.booking_code {
+                line.push_str(&format!(". {}: {}", l.text("Booking code", "Bookingkode"), code));
+            }
+            if let Some(notes) = &hotel.notes {
+                line.push_str(&format!(". {}", notes));
+            }
+            if let Some(url) = &hotel.website_url {
+                line.push_str(&format!(" {}", url));
+            }
+            section.push(line);
+        }
+        sections.push(section.join("\n"));
+    }
+    if !travel.transport.is_empty() {
+        let mut section = vec![l.text("Getting there", "Reise").to_string()];
+        for option in &travel.transport {
+            let mut line = format!("- {}: {}", mode_label(option.mode, l), option.title);
+            if let Some(details) = &option.details {
+                line.push_str(&format!(". {}", details));
+            }
+            if let Some(url) = &option.url {
+                line.push_str(&format!(" {}", url));
+            }
+            section.push(line);
+        }
+        sections.push(section.join("\n"));
+    }
+    if let Some(parking) = &travel.parking {
+        sections.push(format!("{}\n{}", l.text("Parking", "Parkering"), parking_text(parking, l)));
+    }
+    (!sections.is_empty()).then(|| sections.join("\n\n"))
+}
+
+fn mode_label(mode: TransportMode, l: &Localization) -> &'static str {
+    match mode {
+        TransportMode::Flight => l.text("Flight", "Fly"),
+        TransportMode::Train => l.text("Train", "Tog"),
+        TransportMode::Bus => l.text("Bus", "Buss"),
+        TransportMode::Ferry => l.text("Ferry", "Ferje"),
+        TransportMode::Taxi => l.text("Taxi", "Taxi"),
+        TransportMode::Shuttle => l.text("Shuttle", "Skyttelbuss"),
+        TransportMode::Other => l.text("Other", "Annet"),
+    }
+}
+
+fn parking_text(parking: &ParkingInfo, l: &Localization) -> String {
+    let mut parts = Vec::new();
+    if let Some(address) = &parking.address {
+        parts.push(address.clone());
+    }
+    parts.push(if parking.free_of_charge {
+        l.text("Free of charge", "Gratis")
+    } else {
+        l.text("Paid parking", "Betalingsparkering")
+    }
+    .to_string());
+    if parking.ev_charging {
+        parts.push(l.text("EV charging available", "Lading for elbil").to_string());
+    }
+    if let Some(notes) = &parking.notes {
+        parts.push(notes.clone());
+    }
+    format!("- {}", parts.join(". "))
+}
+
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+// TEXT values escape backslashes, separators and line breaks (RFC 5545 3.3.11)
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Long lines continue on the next line after a single space, never splitting a character
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_LINE_OCTETS * 3 + 2);
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space counts towards the continuation line
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dto::{Hotel, TransportMode, TransportOption};
+    use crate::testing::helpers::{TestEventBuilder, TestUserBuilder};
+    use chrono::TimeZone;
+
+    fn event() -> Event {
+        let mut event = TestEventBuilder::new().with_title("Havbruk & Sjømat; 2026").build();
+        event.description = "Two days on salmon farming.\nBring warm clothes.".to_string();
+        event.start_date = Utc.with_ymd_and_hms(2026, 3, 12, 8, 0, 0).unwrap();
+        event.end_date = Utc.with_ymd_and_hms(2026, 3, 13, 15, 0, 0).unwrap();
+        event.location_name = Some("Grieghallen".to_string());
+        event.address = Some("Edvard Griegs plass 1, Bergen".to_string());
+        event.latitude = None;
+        event.longitude = None;
+        event
+    }
+
+    fn travel() -> TravelInfo {
+        TravelInfo {
+            hotels: vec![Hotel {
+                name: "Hotel Norge".to_string(),
+                address: Some("Nedre Ole Bulls plass 4".to_string()),
+                website_url: None,
+                walking_minutes: Some(5),
+                booking_code: Some("AQIO26".to_string()),
+                notes: None,
+            }],
+            transport: vec![TransportOption {
+                mode: TransportMode::Bus,
+                title: "Flybussen from Flesland".to_string(),
+                details: Some("Every 10 minutes".to_string()),
+                url: None,
+            }],
+            parking: Some(ParkingInfo {
+                address: Some("Klostergarasjen".to_string()),
+                free_of_charge: false,
+                ev_charging: true,
+                notes: None,
+            }),
+        }
+    }
+
+    fn unfold(ics: &str) -> String {
+        ics.replace("\r\n ", "")
+    }
+
+    #[test]
+    fn test_renders_event_with_travel_information() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 5, 12, 0, 0).unwrap();
+        let event = event();
+        let ics = render_event_ics(&event, &travel(), "https://aqio.example/e/havbruk", now, &Localization::default());
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        let unfolded = unfold(&ics);
+        assert!(unfolded.contains(&format!("UID:{}@aqio\r\n", event.id)));
+        assert!(unfolded.contains("DTSTAMP:20260105T120000Z\r\n"));
+        assert!(unfolded.contains("DTSTART:20260312T080000Z\r\n"));
+        assert!(unfolded.contains("SUMMARY:Havbruk & Sjømat\\; 2026\r\n"));
+        assert!(unfolded.contains("LOCATION:Grieghallen\\, Edvard Griegs plass 1\\, Bergen\r\n"));
+        assert!(unfolded.contains("X-WR-TIMEZONE:Europe/Oslo\r\n"));
+        assert!(unfolded.contains("DESCRIPTION:When: Thu 12 Mar 2026\\, 09:00 CET – Fri 13 Mar 2026\\, 16:00 CET\\n\\nTwo days"));
+        assert!(unfolded.contains("Bring warm clothes.\\n\\nWhere to stay\\n- Hotel Norge\\, Nedre Ole Bulls plass 4 (5 min walk). Booking code: AQIO26"));
+        assert!(unfolded.contains("Getting there\\n- Bus: Flybussen from Flesland. Every 10 minutes"));
+        assert!(unfolded.contains("Parking\\n- Klostergarasjen. Paid parking. EV charging available"));
+        assert!(unfolded.contains("More information: https://aqio.example/e/havbruk"));
+    }
+
+    #[test]
+    fn test_folds_long_lines_without_splitting_characters() {
+        let line = format!("DESCRIPTION:{}", "ø".repeat(100));
+        let folded = fold(&line);
+        for physical in folded.split("\r\n").filter(|l| !l.is_empty()) {
+            assert!(physical.len() <= MAX_LINE_OCTETS);
+        }
+        assert_eq!(unfold(&folded), format!("{}\r\n", line));
+        assert!(travel_text(&TravelInfo::default(), &Localization::default()).is_none());
+    }
+
+    #[test]
+    fn test_renders_one_entry_per_shift() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 5, 12, 0, 0).unwrap();
+        let event = event();
+        let desk = EventShift {
+            description: Some("Hand out badges, check tickets".to_string()),
+            ..EventShift::new(
+                event.id,
+                "Registration desk",
+                event.start_date,
+                event.start_date + chrono::Duration::hours(3),
+                2,
+            )
+        };
+        let bar = EventShift::new(event.id, "Bar", event.end_date - chrono::Duration::hours(2), event.end_date, 1);
+        let ics = render_shifts_ics(&[(desk.clone(), event.clone()), (bar, event)], now, &Localization::default());
+
+        let unfolded = unfold(&ics);
+        assert_eq!(unfolded.matches("BEGIN:VEVENT").count(), 2);
+        assert!(unfolded.contains("X-WR-CALNAME:My shifts\r\n"));
+        assert!(unfolded.contains(&format!("UID:shift-{}@aqio\r\n", desk.id)));
+        assert!(unfolded.contains("DTSTART:20260312T080000Z\r\nDTEND:20260312T110000Z\r\n"));
+        assert!(unfolded.contains("SUMMARY:Registration desk – Havbruk & Sjømat\\; 2026\r\n"));
+        assert!(unfolded.contains("DESCRIPTION:Hand out badges\\, check tickets\r\n"));
+        assert!(unfolded.contains("SUMMARY:Bar – Havbruk & Sjømat\\; 2026\r\nLOCATION:Grieghallen"));
+    }
+
+    #[test]
+    fn test_meeting_invite_follows_the_meeting_status() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 5, 12, 0, 0).unwrap();
+        let event = event();
+        let kari = TestUserBuilder::new().with_name("Kari Nordmann").with_email("kari@example.no").build();
+        let ola = TestUserBuilder::new().with_name("Ola \"Nets\" Hansen").with_email("ola@example.no").build();
+        let meeting = Meeting {
+            message: Some("Sensors, for three sites".to_string()),
+            ..Meeting::new(
+                event.id,
+                kari.id,
+                ola.id,
+                event.start_date,
+                event.start_date + chrono::Duration::minutes(30),
+            )
+        };
+
+        let invite = unfold(&render_meeting_invite(&meeting, &event, &kari, &ola, Some("Room 2"), now, &Localization::default()));
+        assert!(invite.contains("METHOD:REQUEST\r\n"));
+        assert!(invite.contains(&format!("UID:meeting-{}@aqio\r\nSEQUENCE:0\r\n", meeting.id)));
+        assert!(invite.contains("DTSTART:20260312T080000Z\r\nDTEND:20260312T083000Z\r\nSTATUS:TENTATIVE\r\n"));
+        assert!(invite.contains("ORGANIZER;CN=\"Kari Nordmann\":mailto:kari@example.no\r\n"));
+        assert!(invite.contains("ATTENDEE;CN=\"Ola 'Nets' Hansen\";ROLE=REQ-PARTICIPANT;PARTSTAT=NEEDS-ACTION:mailto:ola@example.no\r\n"));
+        assert!(invite.contains("LOCATION:Room 2\\, Grieghallen\r\nDESCRIPTION:Sensors\\, for three sites\r\n"));
+
+        let cancelled = Meeting {
+            status: MeetingStatus::Cancelled,
+            ..meeting
+        };
+        let invite = unfold(&render_meeting_invite(&cancelled, &event, &kari, &ola, None, now, &Localization::default()));
+        assert!(invite.contains("METHOD:CANCEL\r\n"));
+        assert!(invite.contains("SEQUENCE:1\r\n"));
+        assert!(invite.contains("STATUS:CANCELLED\r\n"));
+    }
+
+    #[test]
+    fn test_renders_description_in_the_visitors_language_and_zone() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 5, 12, 0, 0).unwrap();
+        let norwegian = Localization {
+            locale: aqio_core::Locale::Nb,
+            timezone: chrono_tz::Europe::London,
+        };
+        let ics = render_event_ics(&event(), &travel(), "https://aqio.example/e/havbruk", now, &norwegian);
+
+        let unfolded = unfold(&ics);
+        assert!(unfolded.contains("X-WR-TIMEZONE:Europe/London\r\n"));
+        // The event itself is still in UTC
+        assert!(unfolded.contains("DTSTART:20260312T080000Z\r\n"));
+        assert!(unfolded.contains("Når: tor. 12. mars 2026 kl. 08:00 GMT – fre. 13. mars 2026 kl. 15:00 GMT"));
+        assert!(unfolded.contains("Overnatting\\n- Hotel Norge\\, Nedre Ole Bulls plass 4 (5 min å gå). Bookingkode: AQIO26"));
+        assert!(unfolded.contains("Reise\\n- Buss: Flybussen from Flesland"));
+        assert!(unfolded.contains("Parkering\\n- Klostergarasjen. Betalingsparkering. Lading for elbil"));
+        assert!(unfolded.contains("Mer informasjon: https://aqio.example/e/havbruk"));
+    }
+}
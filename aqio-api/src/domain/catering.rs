@@ -0,0 +1,270 @@
+// Catering report: dietary restrictions and accessibility needs across an
+// event's confirmed registrations. Answers are free text, so each one is split
+// into its parts and every part is matched against known needs ("vegetarisk"
+// and "Vegetarian" end up together); anything unrecognised keeps its own group.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use aqio_core::{EventRegistration, RegistrationStatus};
+
+// Answers that mean "nothing to report"
+const NO_NEEDS: &[&str] = &[
+    "none", "no", "nope", "n/a", "na", "nothing", "-", "no restrictions", "no allergies",
+    "ingen", "nei", "ingenting", "ingen allergier",
+];
+
+// First match wins, so more specific keywords come before the ones they contain
+const DIETARY_NEEDS: &[(&[&str], &str)] = &[
+    (&["vegan"], "Vegan"),
+    (&["vegetar"], "Vegetarian"),
+    (&["pescetar", "pescatar"], "Pescatarian"),
+    (&["gluten", "coeliac", "celiac", "cøliaki"], "Gluten-free"),
+    (&["lactose", "laktose", "dairy", "milk", "melk"], "Lactose/dairy-free"),
+    (&["shellfish", "skalldyr", "crustacean"], "Shellfish allergy"),
+    (&["fish", "fisk"], "Fish allergy"),
+    (&["nut", "nøtt"], "Nut allergy"),
+    (&["egg"], "Egg allergy"),
+    (&["halal"], "Halal"),
+    (&["kosher"], "Kosher"),
+    (&["pork", "svin"], "No pork"),
+    (&["diabet"], "Diabetic"),
+];
+
+const ACCESSIBILITY_NEEDS: &[(&[&str], &str)] = &[
+    (&["guide dog", "service dog", "assistance dog", "førerhund"], "Assistance animal"),
+    (
+        &["wheelchair", "rullestol", "step-free", "step free", "ramp", "mobility", "walker", "crutch", "krykke"],
+        "Wheelchair / step-free access",
+    ),
+    (&["hearing", "deaf", "hørsel", "teleslynge", "sign language", "tegnspråk"], "Hearing support"),
+    (&["visual", "vision", "blind", "sight", "svaksynt"], "Visual support"),
+];
+
+/// One need and how many confirmed registrations mentioned it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CateringGroup {
+    pub label: String,
+    pub registrations: usize,
+    /// Registrants plus the guests they bring; needs are given per registration
+    pub people: usize,
+    /// The answers as written, so nuances like "severe" aren't lost
+    pub answers: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CateringReport {
+    pub confirmed_registrations: usize,
+    pub guests: usize,
+    pub dietary: Vec<CateringGroup>,
+    pub accessibility: Vec<CateringGroup>,
+}
+
+impl CateringReport {
+    pub fn total_people(&self) -> usize {
+        self.confirmed_registrations + self.guests
+    }
+}
+
+/// Registered (with any waitlist offer accepted) or already checked in
+pub(crate) fn is_confirmed(registration: &EventRegistration) -> bool {
+    match registration.status {
+        RegistrationStatus::Registered => registration.promotion_expires_at.is_none(),
+        RegistrationStatus::Attended => true,
+        _ => false,
+    }
+}
+
+pub(crate) fn party_size(registration: &EventRegistration) -> usize {
+    1 + registration.guest_count.max(0) as usize
+}
+
+pub fn build_report(registrations: &[EventRegistration]) -> CateringReport {
+    let confirmed: Vec<&EventRegistration> = registrations.iter().filter(|r| is_confirmed(r)).collect();
+    CateringReport {
+        confirmed_registrations: confirmed.len(),
+        guests: confirmed.iter().map(|r| party_size(r) - 1).sum(),
+        dietary: group(&confirmed, |r| r.dietary_restrictions.as_deref(), DIETARY_NEEDS),
+        accessibility: group(&confirmed, |r| r.accessibility_needs.as_deref(), ACCESSIBILITY_NEEDS),
+    }
+}
+
+fn group(
+    registrations: &[&EventRegistration],
+    answer: impl Fn(&EventRegistration) -> Option<&str>,
+    known: &[(&[&str], &str)],
+) -> Vec<CateringGroup> {
+    // Keyed by the lowercased label so unrecognised answers differing only in case merge
+    let mut groups: BTreeMap<String, CateringGroup> = BTreeMap::new();
+    for registration in registrations {
+        let Some(text) = answer(registration).map(str::trim).filter(|text| !text.is_empty()) else {
+            continue;
+        };
+        let labels: BTreeSet<String> = parts(text).into_iter().map(|part| classify(&part, known)).collect();
+        for label in labels {
+            let entry = groups.entry(label.to_lowercase()).or_insert_with(|| CateringGroup {
+                label: label.clone(),
+                registrations: 0,
+                people: 0,
+                answers: Vec::new(),
+            });
+            entry.registrations += 1;
+            entry.people += party_size(registration);
+            if !entry.answers.iter().any(|existing| existing == text) {
+                entry.answers.push(text.to_string());
+            }
+        }
+    }
+
+    let mut groups: Vec<CateringGroup> = groups.into_values().collect();
+    groups.sort_by(|a, b| b.people.cmp(&a.people).then_with(|| a.label.cmp(&b.label)));
+    groups
+}
+
+/// The accessibility needs in a registration's answer, labelled like in the
+/// report; empty when it has none
+pub(crate) fn accessibility_needs(registration: &EventRegistration) -> Vec<String> {
+    let Some(text) = registration.accessibility_needs.as_deref() else {
+        return Vec::new();
+    };
+    let labels: BTreeSet<String> = parts(text).into_iter().map(|part| classify(&part, ACCESSIBILITY_NEEDS)).collect();
+    labels.into_iter().collect()
+}
+
+// "Vegetarian, gluten free and no nuts" -> ["vegetarian", "gluten free", "no nuts"]
+fn parts(answer: &str) -> Vec<String> {
+    let lower = answer.to_lowercase().replace(" and ", ",").replace(" og ", ",");
+    lower
+        .split([',', ';', '/', '+', '&', '\n'])
+        .map(|part| part.trim_matches(|c: char| c.is_whitespace() || matches!(c, '.' | '!' | '(' | ')')))
+        .filter(|part| !part.is_empty() && !NO_NEEDS.contains(part))
+        .map(str::to_string)
+        .collect()
+}
+
+fn classify(part: &str, known: &[(&[&str], &str)]) -> String {
+    known
+        .iter()
+        .find(|(keywords, _)| keywords.iter().any(|keyword| part.contains(keyword)))
+        .map(|(_, label)| label.to_string())
+        .unwrap_or_else(|| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+}
+
+const CSV_HEADER: &str = "section,need,registrations,people,answers";
+
+/// Quotes a field when it holds a separator, quote or line break
+fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// The report as CSV for the venue: a total row, then one row per need
+pub fn render_csv(report: &CateringReport) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+    csv.push_str(&format!(
+        "Total,Confirmed attendance,{},{},\r\n",
+        report.confirmed_registrations,
+        report.total_people()
+    ));
+    for (section, groups) in [("Dietary", &report.dietary), ("Accessibility", &report.accessibility)] {
+        for group in groups {
+            let row = [
+                section.to_string(),
+                field(&group.label),
+                group.registrations.to_string(),
+                group.people.to_string(),
+                field(&group.answers.join("; ")),
+            ];
+            csv.push_str(&row.join(","));
+            csv.push_str("\r\n");
+        }
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestRegistrationBuilder;
+
+    fn registration(dietary: Option<&str>, accessibility: Option<&str>, guests: i32) -> EventRegistration {
+        let mut registration = TestRegistrationBuilder::new().with_guests(guests, vec![]).build();
+        registration.dietary_restrictions = dietary.map(str::to_string);
+        registration.accessibility_needs = accessibility.map(str::to_string);
+        registration
+    }
+
+    #[test]
+    fn test_clusters_free_text_answers() {
+        let cancelled = EventRegistration {
+            dietary_restrictions: Some("Vegan".to_string()),
+            ..TestRegistrationBuilder::new().cancelled().build()
+        };
+        let mut pending_offer = registration(Some("Vegan"), None, 0);
+        pending_offer.promotion_expires_at = Some(chrono::Utc::now());
+        let attended = EventRegistration {
+            dietary_restrictions: Some("vegetarisk".to_string()),
+            ..TestRegistrationBuilder::new().attended().build()
+        };
+
+        let report = build_report(&[
+            registration(Some("Vegetarian, gluten free"), Some("Wheelchair user"), 2),
+            registration(Some("vegetarian and no nuts!"), None, 0),
+            attended,
+            registration(Some("None"), Some("  "), 1),
+            registration(Some("Low FODMAP"), Some("needs hearing loop"), 0),
+            registration(Some("low fodmap"), None, 0),
+            cancelled,
+            pending_offer,
+        ]);
+
+        assert_eq!(report.confirmed_registrations, 6);
+        assert_eq!(report.guests, 3);
+        assert_eq!(report.total_people(), 9);
+
+        let labels: Vec<(&str, usize, usize)> = report
+            .dietary
+            .iter()
+            .map(|group| (group.label.as_str(), group.registrations, group.people))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("Vegetarian", 3, 5),
+                ("Gluten-free", 1, 3),
+                ("Low fodmap", 2, 2),
+                ("Nut allergy", 1, 1),
+            ]
+        );
+        assert_eq!(report.dietary[0].answers, vec!["Vegetarian, gluten free", "vegetarian and no nuts!", "vegetarisk"]);
+
+        let accessibility: Vec<&str> = report.accessibility.iter().map(|group| group.label.as_str()).collect();
+        assert_eq!(accessibility, vec!["Wheelchair / step-free access", "Hearing support"]);
+    }
+
+    #[test]
+    fn test_renders_csv_with_totals_and_quoted_answers() {
+        let report = build_report(&[
+            registration(Some("Vegetarian, gluten free"), None, 1),
+            registration(Some("Says \"no onions\""), None, 0),
+        ]);
+
+        let csv = render_csv(&report);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[1], "Total,Confirmed attendance,2,3,");
+        assert_eq!(lines[2], "Dietary,Gluten-free,1,2,\"Vegetarian, gluten free\"");
+        assert_eq!(lines[3], "Dietary,Vegetarian,1,2,\"Vegetarian, gluten free\"");
+        assert_eq!(lines[4], "Dietary,\"Says \"\"no onions\"\"\",1,1,\"Says \"\"no onions\"\"\"");
+        assert_eq!(lines[5], "");
+    }
+}
@@ -0,0 +1,402 @@
+// Certificates of attendance: a one-page A4 PDF for checked-in attendees with
+// the organizer's signature and a QR code pointing at the public check.
+// Uses the standard Helvetica font, so nothing has to be embedded; text is
+// written in WinAnsi and characters outside it come out as '?'.
+
+use chrono::{DateTime, Datelike, Utc};
+use miniz_oxide::deflate::{CompressionLevel, compress_to_vec_zlib};
+use pdf_writer::{
+    Content, Filter, Finish, Name, Pdf, Rect, Ref, Str, TextStr,
+    types::{ActionType, AnnotationType},
+};
+use qrcode::{Color, QrCode};
+use uuid::Uuid;
+
+// A4 landscape, in points
+const PAGE_WIDTH: f32 = 842.0;
+const PAGE_HEIGHT: f32 = 595.0;
+const MARGIN: f32 = 36.0;
+// Widest line of centered text
+const TEXT_WIDTH: f32 = PAGE_WIDTH - 4.0 * MARGIN;
+
+// Signature images larger than this are refused rather than decoded
+const MAX_SIGNATURE_PIXELS: u32 = 4000;
+const MAX_SIGNATURE_DECODED_BYTES: usize = 32 * 1024 * 1024;
+
+const FONT: Name = Name(b"F1");
+const SIGNATURE: Name = Name(b"Im1");
+
+/// Everything printed on a certificate
+#[derive(Debug, Clone, PartialEq)]
+pub struct CertificateDetails {
+    pub registration_id: Uuid,
+    pub attendee_name: String,
+    pub event_title: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub location_name: Option<String>,
+    pub organizer_name: String,
+    pub checked_in_at: DateTime<Utc>,
+    /// Where the QR code and link on the certificate lead
+    pub verification_url: String,
+}
+
+impl CertificateDetails {
+    /// "12 March 2026", or a range when the event spans several days
+    pub fn date_label(&self) -> String {
+        let (start, end) = (self.start_date.date_naive(), self.end_date.date_naive());
+        if start == end {
+            start.format("%-d %B %Y").to_string()
+        } else if start.year() == end.year() {
+            format!("{} - {}", start.format("%-d %B"), end.format("%-d %B %Y"))
+        } else {
+            format!("{} - {}", start.format("%-d %B %Y"), end.format("%-d %B %Y"))
+        }
+    }
+
+    /// Download name, e.g. `certificate-annual-aquaculture-summit.pdf`
+    pub fn filename(&self) -> String {
+        let slug = self
+            .event_title
+            .to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        if slug.is_empty() {
+            "certificate.pdf".to_string()
+        } else {
+            format!("certificate-{}.pdf", slug)
+        }
+    }
+}
+
+/// The organizer's signature, decoded from PNG into 8-bit RGB plus alpha
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureImage {
+    pub width: u32,
+    pub height: u32,
+    rgb: Vec<u8>,
+    alpha: Option<Vec<u8>>,
+}
+
+impl SignatureImage {
+    pub fn from_png(bytes: &[u8]) -> Result<Self, String> {
+        let mut decoder = png::Decoder::new_with_limits(
+            std::io::Cursor::new(bytes),
+            png::Limits { bytes: MAX_SIGNATURE_DECODED_BYTES },
+        );
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(|e| format!("Not a readable PNG image: {}", e))?;
+
+        let (width, height) = {
+            let info = reader.info();
+            (info.width, info.height)
+        };
+        if width == 0 || height == 0 || width > MAX_SIGNATURE_PIXELS || height > MAX_SIGNATURE_PIXELS {
+            return Err(format!(
+                "Signature must be at most {0}x{0} pixels",
+                MAX_SIGNATURE_PIXELS
+            ));
+        }
+
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let frame = reader
+            .next_frame(&mut buffer)
+            .map_err(|e| format!("Not a readable PNG image: {}", e))?;
+        buffer.truncate(frame.buffer_size());
+
+        let (rgb, alpha) = match frame.color_type {
+            png::ColorType::Rgb => (buffer, None),
+            png::ColorType::Rgba => {
+                let rgb = buffer.chunks_exact(4).flat_map(|px| [px[0], px[1], px[2]]).collect();
+                let alpha = buffer.chunks_exact(4).map(|px| px[3]).collect();
+                (rgb, Some(alpha))
+            }
+            png::ColorType::Grayscale => (buffer.iter().flat_map(|&g| [g, g, g]).collect(), None),
+            png::ColorType::GrayscaleAlpha => {
+                let rgb = buffer.chunks_exact(2).flat_map(|px| [px[0], px[0], px[0]]).collect();
+                let alpha = buffer.chunks_exact(2).map(|px| px[1]).collect();
+                (rgb, Some(alpha))
+            }
+            // Palettes are expanded by `normalize_to_color8`
+            png::ColorType::Indexed => return Err("Unsupported PNG color type".to_string()),
+        };
+
+        Ok(Self { width, height, rgb, alpha })
+    }
+}
+
+/// Lay out the certificate as a single-page PDF
+pub fn render_certificate(details: &CertificateDetails, signature: Option<&SignatureImage>) -> Vec<u8> {
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let page_id = Ref::new(3);
+    let font_id = Ref::new(4);
+    let content_id = Ref::new(5);
+    let image_id = Ref::new(6);
+    let mask_id = Ref::new(7);
+    let info_id = Ref::new(8);
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids([page_id]).count(1);
+    pdf.document_info(info_id)
+        .title(TextStr(&format!("Certificate of attendance - {}", details.event_title)))
+        .creator(TextStr("Aqio"));
+
+    let qr_size = 96.0;
+    let qr_x = PAGE_WIDTH - 2.0 * MARGIN - qr_size;
+    let qr_y = 2.0 * MARGIN + 14.0;
+
+    let mut page = pdf.page(page_id);
+    page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT));
+    page.parent(page_tree_id);
+    page.contents(content_id);
+    {
+        let mut resources = page.resources();
+        resources.fonts().pair(FONT, font_id);
+        if signature.is_some() {
+            resources.x_objects().pair(SIGNATURE, image_id);
+        }
+    }
+    // The QR code doubles as a link in PDF viewers
+    page.annotations()
+        .push()
+        .subtype(AnnotationType::Link)
+        .rect(Rect::new(qr_x, qr_y, qr_x + qr_size, qr_y + qr_size))
+        .action()
+        .action_type(ActionType::Uri)
+        .uri(Str(details.verification_url.as_bytes()));
+    page.finish();
+
+    pdf.type1_font(font_id)
+        .base_font(Name(b"Helvetica"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+
+    let mut content = Content::new();
+
+    // Double frame
+    content.set_stroke_rgb(0.11, 0.36, 0.55);
+    content.set_line_width(3.0);
+    content.rect(MARGIN, MARGIN, PAGE_WIDTH - 2.0 * MARGIN, PAGE_HEIGHT - 2.0 * MARGIN);
+    content.stroke();
+    content.set_line_width(0.75);
+    content.rect(MARGIN + 8.0, MARGIN + 8.0, PAGE_WIDTH - 2.0 * MARGIN - 16.0, PAGE_HEIGHT - 2.0 * MARGIN - 16.0);
+    content.stroke();
+
+    content.set_fill_rgb(0.11, 0.36, 0.55);
+    centered_text(&mut content, "CERTIFICATE OF ATTENDANCE", 30.0, 470.0);
+    content.set_fill_gray(0.2);
+    centered_text(&mut content, "This is to certify that", 14.0, 420.0);
+    content.set_fill_gray(0.0);
+    centered_text(&mut content, &details.attendee_name, fitted_size(&details.attendee_name, 28.0), 378.0);
+    content.set_fill_gray(0.2);
+    centered_text(&mut content, "attended", 14.0, 340.0);
+    content.set_fill_gray(0.0);
+    centered_text(&mut content, &details.event_title, fitted_size(&details.event_title, 22.0), 302.0);
+    content.set_fill_gray(0.2);
+    let when_and_where = match &details.location_name {
+        Some(location) => format!("{}, {}", details.date_label(), location),
+        None => details.date_label(),
+    };
+    centered_text(&mut content, &when_and_where, fitted_size(&when_and_where, 13.0), 272.0);
+
+    // Signature block, bottom left
+    let line_x = 2.0 * MARGIN + 24.0;
+    let line_width = 220.0;
+    let line_y = 2.0 * MARGIN + 44.0;
+    if let Some(signature) = signature {
+        let (max_width, max_height) = (line_width, 70.0);
+        let scale = (max_width / signature.width as f32).min(max_height / signature.height as f32);
+        let (width, height) = (signature.width as f32 * scale, signature.height as f32 * scale);
+        content.save_state();
+        content.transform([width, 0.0, 0.0, height, line_x + (line_width - width) / 2.0, line_y + 4.0]);
+        content.x_object(SIGNATURE);
+        content.restore_state();
+    }
+    content.set_stroke_gray(0.3);
+    content.set_line_width(0.75);
+    content.move_to(line_x, line_y);
+    content.line_to(line_x + line_width, line_y);
+    content.stroke();
+    content.set_fill_gray(0.0);
+    text_at(&mut content, &details.organizer_name, 11.0, line_x + (line_width - text_width(&details.organizer_name, 11.0)) / 2.0, line_y - 16.0);
+    content.set_fill_gray(0.4);
+    text_at(&mut content, "Organizer", 9.0, line_x + (line_width - text_width("Organizer", 9.0)) / 2.0, line_y - 29.0);
+
+    // Verification QR code and id, bottom right
+    if let Ok(code) = QrCode::new(details.verification_url.as_bytes()) {
+        let modules = code.width();
+        let module_size = qr_size / modules as f32;
+        content.set_fill_gray(0.0);
+        for (index, color) in code.to_colors().into_iter().enumerate() {
+            if color == Color::Dark {
+                let (column, row) = (index % modules, index / modules);
+                content.rect(
+                    qr_x + column as f32 * module_size,
+                    qr_y + qr_size - (row + 1) as f32 * module_size,
+                    module_size,
+                    module_size,
+                );
+            }
+        }
+        content.fill_nonzero();
+    }
+    content.set_fill_gray(0.4);
+    let caption = "Scan to verify";
+    text_at(&mut content, caption, 8.0, qr_x + (qr_size - text_width(caption, 8.0)) / 2.0, qr_y - 11.0);
+    let id = details.registration_id.to_string();
+    text_at(&mut content, &id, 6.0, qr_x + (qr_size - text_width(&id, 6.0)) / 2.0, qr_y - 21.0);
+    content.set_fill_gray(0.4);
+    let issued = format!("Checked in {}", details.checked_in_at.format("%-d %B %Y"));
+    centered_text(&mut content, &issued, 9.0, MARGIN + 20.0);
+
+    pdf.stream(content_id, &content.finish());
+
+    if let Some(signature) = signature {
+        let level = CompressionLevel::DefaultLevel as u8;
+        let encoded = compress_to_vec_zlib(&signature.rgb, level);
+        let mut image = pdf.image_xobject(image_id, &encoded);
+        image.filter(Filter::FlateDecode);
+        image.width(signature.width as i32);
+        image.height(signature.height as i32);
+        image.color_space().device_rgb();
+        image.bits_per_component(8);
+        if signature.alpha.is_some() {
+            image.s_mask(mask_id);
+        }
+        image.finish();
+
+        if let Some(alpha) = &signature.alpha {
+            let encoded = compress_to_vec_zlib(alpha, level);
+            let mut mask = pdf.image_xobject(mask_id, &encoded);
+            mask.filter(Filter::FlateDecode);
+            mask.width(signature.width as i32);
+            mask.height(signature.height as i32);
+            mask.color_space().device_gray();
+            mask.bits_per_component(8);
+        }
+    }
+
+    pdf.finish()
+}
+
+fn text_at(content: &mut Content, text: &str, size: f32, x: f32, y: f32) {
+    content.begin_text();
+    content.set_font(FONT, size);
+    content.next_line(x, y);
+    content.show(Str(&win_ansi(text)));
+    content.end_text();
+}
+
+fn centered_text(content: &mut Content, text: &str, size: f32, y: f32) {
+    text_at(content, text, size, (PAGE_WIDTH - text_width(text, size)) / 2.0, y);
+}
+
+// Shrink long names and titles until they fit between the frames
+fn fitted_size(text: &str, preferred: f32) -> f32 {
+    let width = text_width(text, preferred);
+    if width <= TEXT_WIDTH {
+        preferred
+    } else {
+        (preferred * TEXT_WIDTH / width).max(8.0)
+    }
+}
+
+pub(crate) fn text_width(text: &str, size: f32) -> f32 {
+    text.chars().map(glyph_width).sum::<u32>() as f32 * size / 1000.0
+}
+
+// Helvetica advance widths (1/1000 em) from the standard AFM metrics
+fn glyph_width(c: char) -> u32 {
+    const ASCII: [u32; 95] = [
+        278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, // space - /
+        556, 556, 556, 556, 556, 556, 556, 556, 556, 556, // 0 - 9
+        278, 278, 584, 584, 584, 556, 1015, // : - @
+        667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, // A - M
+        722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, // N - Z
+        278, 278, 278, 469, 556, 333, // [ - `
+        556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, // a - m
+        556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, // n - z
+        334, 260, 334, 584, // { - ~
+    ];
+    match c {
+        ' '..='~' => ASCII[c as usize - 32],
+        'Æ' => 1000,
+        'æ' => 889,
+        'Ø' | 'Ö' | 'Ó' | 'Ô' => 778,
+        'ø' => 611,
+        c if c.is_uppercase() => 667,
+        _ => 556,
+    }
+}
+
+// WinAnsi matches Latin-1 from 0xA0 up; the en dash is the one extra worth keeping
+pub(crate) fn win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            ' '..='~' | '\u{A0}'..='\u{FF}' => c as u8,
+            '\u{2013}' => 0x96,
+            _ => b'?',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn details() -> CertificateDetails {
+        CertificateDetails {
+            registration_id: Uuid::new_v4(),
+            attendee_name: "Kari Nordmann".to_string(),
+            event_title: "Havbruk & Sjømat 2026".to_string(),
+            start_date: Utc.with_ymd_and_hms(2026, 3, 12, 9, 0, 0).unwrap(),
+            end_date: Utc.with_ymd_and_hms(2026, 3, 13, 16, 0, 0).unwrap(),
+            location_name: Some("Bergen".to_string()),
+            organizer_name: "Ola Hansen".to_string(),
+            checked_in_at: Utc.with_ymd_and_hms(2026, 3, 12, 8, 45, 0).unwrap(),
+            verification_url: "https://aqio.no/certificates/123".to_string(),
+        }
+    }
+
+    fn png(width: u32, height: u32, color: png::ColorType, pixels: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+        encoder.set_color(color);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header().unwrap().write_image_data(pixels).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_labels() {
+        let details = details();
+        assert_eq!(details.date_label(), "12 March - 13 March 2026");
+        assert_eq!(details.filename(), "certificate-havbruk-sj-mat-2026.pdf");
+        assert_eq!(win_ansi("Sjømat – 2026 ✓"), b"Sj\xF8mat \x96 2026 ?");
+    }
+
+    #[test]
+    fn test_renders_pdf_with_and_without_signature() {
+        let plain = render_certificate(&details(), None);
+        assert!(plain.starts_with(b"%PDF-"));
+
+        let signature = SignatureImage::from_png(&png(2, 1, png::ColorType::Rgba, &[0, 0, 0, 255, 255, 255, 255, 0])).unwrap();
+        assert_eq!((signature.width, signature.height), (2, 1));
+        assert_eq!(signature.alpha, Some(vec![255, 0]));
+        let signed = render_certificate(&details(), Some(&signature));
+        assert!(signed.len() > plain.len());
+        assert!(signed.windows(b"/SMask".len()).any(|window| window == b"/SMask"));
+    }
+
+    #[test]
+    fn test_signature_must_be_png() {
+        assert!(SignatureImage::from_png(b"GIF89a").is_err());
+        let gray = SignatureImage::from_png(&png(1, 1, png::ColorType::Grayscale, &[128])).unwrap();
+        assert_eq!(gray.rgb, vec![128, 128, 128]);
+        assert!(gray.alpha.is_none());
+    }
+}
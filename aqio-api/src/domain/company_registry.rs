@@ -0,0 +1,43 @@
+// Norwegian organization numbers. The last of the nine digits is a modulus 11
+// check digit over the first eight, so typos are caught before the business
+// register is asked, and without it when the register can't be reached.
+
+use crate::domain::errors::{ApiError, ApiResult};
+
+const WEIGHTS: [u32; 8] = [3, 2, 7, 6, 5, 4, 3, 2];
+
+/// `raw` as nine digits; spaces, as in `923 609 016`, are dropped
+pub fn normalize_org_number(raw: &str) -> ApiResult<String> {
+    let digits: String = raw.chars().filter(|c| !c.is_whitespace()).collect();
+    let invalid = || ApiError::validation("org_number", "Organization number must be nine digits with a valid check digit");
+
+    if digits.len() != 9 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let values: Vec<u32> = digits.chars().filter_map(|c| c.to_digit(10)).collect();
+    let sum: u32 = WEIGHTS.iter().zip(&values).map(|(weight, value)| weight * value).sum();
+    // A remainder of 1 would need check digit 10; such numbers are never issued
+    let check = match 11 - sum % 11 {
+        11 => 0,
+        10 => return Err(invalid()),
+        check => check,
+    };
+    if values[8] != check {
+        return Err(invalid());
+    }
+    Ok(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_org_numbers_are_checked_and_normalized() {
+        assert_eq!(normalize_org_number("923609016").unwrap(), "923609016");
+        assert_eq!(normalize_org_number(" 974 760 673 ").unwrap(), "974760673");
+        for raw in ["", "92360901", "9236090160", "92360901a", "923609017", "923-609-016"] {
+            assert!(normalize_org_number(raw).is_err(), "{}", raw);
+        }
+    }
+}
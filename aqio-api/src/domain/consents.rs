@@ -0,0 +1,194 @@
+// Consent report: which registrants accepted which of an event's consent
+// checkboxes, for organizers to show compliance. Answers given to an earlier
+// version of a checkbox's text are counted apart from answers to the current
+// one, since they agreed to something else.
+
+use std::collections::HashMap;
+
+use aqio_core::{EventConsent, EventRegistration, RegistrationConsent, RegistrationStatus};
+use uuid::Uuid;
+
+/// Who registered, as shown in the report
+#[derive(Debug, Clone, PartialEq)]
+pub struct Registrant {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+/// A registration and its answers, in the order they were given
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsentRow {
+    pub registration_id: Uuid,
+    pub registrant: Registrant,
+    pub status: RegistrationStatus,
+    pub answers: Vec<RegistrationConsent>,
+}
+
+/// Answers to one of the event's current checkboxes, over registrations that
+/// aren't cancelled
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsentTotals {
+    pub consent: EventConsent,
+    pub accepted: usize,
+    /// Accepted, but an earlier version of the text
+    pub accepted_earlier_version: usize,
+    pub declined: usize,
+    /// Registered before the checkbox was added
+    pub unanswered: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsentReport {
+    pub totals: Vec<ConsentTotals>,
+    pub rows: Vec<ConsentRow>,
+}
+
+/// `registrants` names the registrations with an account; guests name themselves
+pub fn build_report(
+    consents: &[EventConsent],
+    registrations: &[EventRegistration],
+    answers: Vec<RegistrationConsent>,
+    registrants: &HashMap<Uuid, Registrant>,
+) -> ConsentReport {
+    let mut by_registration: HashMap<Uuid, Vec<RegistrationConsent>> = HashMap::new();
+    for answer in answers {
+        by_registration.entry(answer.registration_id).or_default().push(answer);
+    }
+
+    let rows: Vec<ConsentRow> = registrations
+        .iter()
+        .map(|registration| ConsentRow {
+            registration_id: registration.id,
+            registrant: registrants.get(&registration.id).cloned().unwrap_or_else(|| Registrant {
+                name: registration.registrant_name.clone(),
+                email: registration.registrant_email.clone(),
+            }),
+            status: registration.status.clone(),
+            answers: by_registration.remove(&registration.id).unwrap_or_default(),
+        })
+        .collect();
+
+    let totals = consents
+        .iter()
+        .map(|consent| {
+            let mut totals = ConsentTotals {
+                consent: consent.clone(),
+                accepted: 0,
+                accepted_earlier_version: 0,
+                declined: 0,
+                unanswered: 0,
+            };
+            for row in rows.iter().filter(|row| row.status != RegistrationStatus::Cancelled) {
+                match row.answers.iter().find(|answer| answer.consent_id == consent.id) {
+                    Some(answer) if !answer.accepted => totals.declined += 1,
+                    Some(answer) if answer.version == consent.version => totals.accepted += 1,
+                    Some(_) => totals.accepted_earlier_version += 1,
+                    None => totals.unanswered += 1,
+                }
+            }
+            totals
+        })
+        .collect();
+
+    ConsentReport { totals, rows }
+}
+
+const CSV_HEADER: &str = "registration_id,name,email,status,consent,version,accepted,answered_at,text";
+
+/// Quotes a field when it holds a separator, quote or line break
+fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// The report as CSV: one row per answer, with the text that was agreed to.
+/// Registrations without answers get a single row with the consent columns empty.
+pub fn render_csv(report: &ConsentReport) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+    for row in &report.rows {
+        let registrant = [
+            row.registration_id.to_string(),
+            field(row.registrant.name.as_deref().unwrap_or_default()),
+            field(row.registrant.email.as_deref().unwrap_or_default()),
+            format!("{:?}", row.status),
+        ]
+        .join(",");
+        if row.answers.is_empty() {
+            csv.push_str(&format!("{},,,,,\r\n", registrant));
+        }
+        for answer in &row.answers {
+            csv.push_str(&format!(
+                "{},{:?},{},{},{},{}\r\n",
+                registrant,
+                answer.kind,
+                answer.version,
+                if answer.accepted { "yes" } else { "no" },
+                answer.answered_at.to_rfc3339(),
+                field(&answer.text),
+            ));
+        }
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestRegistrationBuilder;
+    use aqio_core::ConsentKind;
+    use chrono::Utc;
+
+    #[test]
+    fn test_counts_current_and_earlier_versions_apart() {
+        let event_id = Uuid::new_v4();
+        let mut photo = EventConsent::new(event_id, ConsentKind::Photo, "Photos, may be taken", true);
+        let marketing = EventConsent::new(event_id, ConsentKind::Marketing, "Send me news", false);
+        let early = TestRegistrationBuilder::new().with_event(event_id).build();
+        let late = TestRegistrationBuilder::new().with_event(event_id).build();
+        let cancelled = TestRegistrationBuilder::new().with_event(event_id).cancelled().build();
+        let before_checkboxes = TestRegistrationBuilder::new().with_event(event_id).build();
+
+        let now = Utc::now();
+        let mut answers = vec![
+            RegistrationConsent::new(early.id, &photo, true, now),
+            RegistrationConsent::new(early.id, &marketing, false, now),
+            RegistrationConsent::new(cancelled.id, &photo, true, now),
+        ];
+        photo.revise("Photos and video may be taken", true, now);
+        answers.push(RegistrationConsent::new(late.id, &photo, true, now));
+        answers.push(RegistrationConsent::new(late.id, &marketing, true, now));
+
+        let registrants = HashMap::from([(
+            early.id,
+            Registrant { name: Some("Kari Nordmann".to_string()), email: Some("kari@example.no".to_string()) },
+        )]);
+        let report = build_report(
+            &[photo.clone(), marketing.clone()],
+            &[early.clone(), late.clone(), cancelled, before_checkboxes],
+            answers,
+            &registrants,
+        );
+
+        let counts: Vec<(usize, usize, usize, usize)> = report
+            .totals
+            .iter()
+            .map(|t| (t.accepted, t.accepted_earlier_version, t.declined, t.unanswered))
+            .collect();
+        assert_eq!(counts, vec![(1, 1, 0, 1), (1, 0, 1, 1)]);
+        assert_eq!(report.rows.len(), 4);
+        assert_eq!(report.rows[0].registrant.name.as_deref(), Some("Kari Nordmann"));
+
+        let csv = render_csv(&report);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert!(lines[1].starts_with(&format!("{},Kari Nordmann,kari@example.no,Registered,Photo,1,yes,", early.id)));
+        assert!(lines[1].ends_with(",\"Photos, may be taken\""));
+        assert!(lines[2].contains(",Marketing,1,no,"));
+        assert!(lines[4].ends_with(",Send me news"));
+        assert!(lines[6].ends_with(",Registered,,,,,"));
+    }
+}
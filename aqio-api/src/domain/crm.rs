@@ -0,0 +1,156 @@
+// CRM sync: the property values a connector's field mappings ask for. Contacts
+// are keyed by lowercase email address and take the person's details from
+// their latest registration; registration records hold one registration each.
+// Empty values are left out, so a sync never blanks a property in the CRM.
+
+use std::collections::BTreeMap;
+
+use aqio_core::{CrmConnector, CrmRecordKind, CrmSourceField, Event, EventRegistration, User};
+
+use crate::domain::leads::LeadContact;
+
+/// A registration with what's needed to fill in its CRM records
+#[derive(Debug, Clone)]
+pub struct SyncSource<'a> {
+    pub registration: &'a EventRegistration,
+    pub event: Option<&'a Event>,
+    pub contact: LeadContact,
+}
+
+impl<'a> SyncSource<'a> {
+    pub fn new(registration: &'a EventRegistration, event: Option<&'a Event>, user: Option<&User>) -> Self {
+        Self {
+            registration,
+            event,
+            contact: LeadContact::of(registration, user),
+        }
+    }
+
+    /// The registrant's contact is keyed by this; `None` without an email address
+    pub fn contact_key(&self) -> Option<String> {
+        self.contact
+            .email
+            .as_deref()
+            .map(str::trim)
+            .filter(|email| !email.is_empty())
+            .map(str::to_lowercase)
+    }
+
+    pub fn value(&self, field: CrmSourceField) -> String {
+        let registration = self.registration;
+        let name = self.contact.name.as_deref().unwrap_or_default().trim();
+        match field {
+            CrmSourceField::Email => self.contact_key().unwrap_or_default(),
+            CrmSourceField::FullName => name.to_string(),
+            CrmSourceField::FirstName => split_name(name).0.to_string(),
+            CrmSourceField::LastName => split_name(name).1.to_string(),
+            CrmSourceField::Phone => self.contact.phone.clone().unwrap_or_default(),
+            CrmSourceField::Company => self.contact.company.clone().unwrap_or_default(),
+            CrmSourceField::EventId => registration.event_id.to_string(),
+            CrmSourceField::EventTitle => self.event.map(|event| event.title.clone()).unwrap_or_default(),
+            CrmSourceField::EventStart => self.event.map(|event| event.start_date.to_rfc3339()).unwrap_or_default(),
+            CrmSourceField::RegistrationId => registration.id.to_string(),
+            CrmSourceField::RegistrationStatus => format!("{:?}", registration.status),
+            CrmSourceField::RegisteredAt => registration.registered_at.to_rfc3339(),
+            CrmSourceField::CheckedInAt => registration.checked_in_at.map(|at| at.to_rfc3339()).unwrap_or_default(),
+            CrmSourceField::GuestCount => registration.guest_count.to_string(),
+        }
+    }
+
+    /// The values Aqio wants on this registration's record of `kind`.
+    /// Contacts always get the email address they are matched by.
+    pub fn properties(&self, connector: &CrmConnector, kind: CrmRecordKind) -> BTreeMap<String, String> {
+        let mut properties: BTreeMap<String, String> = connector
+            .mappings(kind)
+            .map(|mapping| (mapping.property.clone(), self.value(mapping.source)))
+            .filter(|(_, value)| !value.trim().is_empty())
+            .collect();
+        if let (CrmRecordKind::Contact, Some(email)) = (kind, self.contact_key()) {
+            properties.insert(connector.provider.email_property().to_string(), email);
+        }
+        properties
+    }
+}
+
+/// First and last name, split before the last word
+fn split_name(name: &str) -> (&str, &str) {
+    match name.rsplit_once(char::is_whitespace) {
+        Some((first, last)) => (first.trim_end(), last),
+        None => (name, ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aqio_core::{CrmFieldMapping, CrmProvider, RegistrationSource, RegistrationStatus};
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_properties_follow_the_mappings_and_skip_empty_values() {
+        let now = Utc::now();
+        let registration = EventRegistration {
+            id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            invitation_id: None,
+            user_id: None,
+            external_contact_id: None,
+            registrant_email: Some(" Kari.Nordmann@Example.org ".to_string()),
+            registrant_name: Some("Kari Anne  Nordmann".to_string()),
+            registrant_phone: None,
+            registrant_company: None,
+            status: RegistrationStatus::Attended,
+            registration_source: RegistrationSource::Direct,
+            guest_count: 2,
+            guest_names: vec![],
+            dietary_restrictions: None,
+            accessibility_needs: None,
+            special_requests: None,
+            custom_responses: None,
+            registered_at: now,
+            cancelled_at: None,
+            checked_in_at: None,
+            waitlist_position: None,
+            waitlist_added_at: None,
+            promotion_expires_at: None,
+            created_at: now,
+            updated_at: now,
+        };
+        let mut connector = CrmConnector::new("Medlemsregister", CrmProvider::Dynamics, Uuid::new_v4(), now);
+        connector.registration_object = Some("new_registrations".to_string());
+        connector.field_mappings.extend([
+            CrmFieldMapping {
+                record: CrmRecordKind::Contact,
+                source: CrmSourceField::Phone,
+                property: "telephone1".to_string(),
+            },
+            CrmFieldMapping {
+                record: CrmRecordKind::Registration,
+                source: CrmSourceField::RegistrationStatus,
+                property: "new_status".to_string(),
+            },
+            CrmFieldMapping {
+                record: CrmRecordKind::Registration,
+                source: CrmSourceField::GuestCount,
+                property: "new_guests".to_string(),
+            },
+        ]);
+        let source = SyncSource::new(&registration, None, None);
+
+        assert_eq!(source.contact_key().as_deref(), Some("kari.nordmann@example.org"));
+        let contact = source.properties(&connector, CrmRecordKind::Contact);
+        assert_eq!(
+            contact.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("emailaddress1".to_string(), "kari.nordmann@example.org".to_string()),
+                ("firstname".to_string(), "Kari Anne".to_string()),
+                ("lastname".to_string(), "Nordmann".to_string()),
+            ]
+        );
+        let record = source.properties(&connector, CrmRecordKind::Registration);
+        assert_eq!(record["new_status"], "Attended");
+        assert_eq!(record["new_guests"], "2");
+        assert_eq!(split_name("Kari"), ("Kari", ""));
+    }
+}
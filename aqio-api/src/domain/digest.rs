@@ -0,0 +1,184 @@
+// Digest emails: a user's pending notifications as one summary, grouped per
+// event with a link to each event. Every email has a plain-text and an HTML
+// body built from the same sections, in the user's language and time zone.
+
+use aqio_core::{DigestFrequency, DigestGroup, DigestItem, EmailMessage, Locale, NotificationDigest};
+
+use crate::domain::locale::Localization;
+
+/// The digest for the user at `to`; event links point into the frontend at `public_url`
+pub fn render_digest(
+    digest: &NotificationDigest,
+    to: &str,
+    user_name: &str,
+    public_url: &str,
+    localization: &Localization,
+) -> EmailMessage {
+    let l = localization;
+    let period = match digest.frequency {
+        DigestFrequency::Weekly => l.text("weekly", "ukentlige"),
+        _ => l.text("daily", "daglige"),
+    };
+    let updates = match digest.item_count() {
+        1 => format!("1 {}", l.text("update", "oppdatering")),
+        count => format!("{} {}", count, l.text("updates", "oppdateringer")),
+    };
+    let subject = match l.locale {
+        Locale::En => format!("Your {} Aqio digest: {}", period, updates),
+        Locale::Nb => format!("Ditt {} Aqio-sammendrag: {}", period, updates),
+    };
+    let intro = format!(
+        "{} {}, {}",
+        l.text("Hi", "Hei"),
+        user_name,
+        l.text("here is what happened since your last digest.", "her er det som har skjedd siden forrige sammendrag.")
+    );
+    let footer = l.text(
+        "You get this summary instead of separate emails. Change how often it comes in your notification settings.",
+        "Du får dette sammendraget i stedet for enkeltvise e-poster. Endre hvor ofte det kommer i varslingsinnstillingene dine.",
+    );
+
+    let mut text = format!("{}\n", intro);
+    let mut html = format!("<p>{}</p>\n", escape_html(&intro));
+    for group in &digest.groups {
+        let heading = group_heading(group, l);
+        let link = group.event_id.map(|id| format!("{}/events/{}", public_url.trim_end_matches('/'), id));
+
+        text.push_str(&format!("\n{}\n", heading));
+        html.push_str(&format!("<h2>{}</h2>\n<ul>\n", escape_html(&heading)));
+        for item in &group.items {
+            let line = format!("{} ({})", item_message(item, l), l.format_datetime(item.created_at));
+            text.push_str(&format!("- {}\n", line));
+            html.push_str(&format!("<li>{}</li>\n", escape_html(&line)));
+        }
+        html.push_str("</ul>\n");
+        if let Some(link) = link {
+            text.push_str(&format!("{}\n", link));
+            html.push_str(&format!(
+                "<p><a href=\"{}\">{}</a></p>\n",
+                escape_html(&link),
+                l.text("Open the event", "Åpne arrangementet")
+            ));
+        }
+    }
+    text.push_str(&format!("\n{}\n", footer));
+    html.push_str(&format!("<p><small>{}</small></p>\n", escape_html(footer)));
+
+    EmailMessage {
+        to: to.to_string(),
+        subject,
+        text_body: text,
+        html_body: html,
+        from: None,
+    }
+}
+
+fn group_heading(group: &DigestGroup, l: &Localization) -> String {
+    match (&group.event_title, group.event_id) {
+        (Some(title), _) => title.clone(),
+        (None, Some(_)) => l.text("An event", "Et arrangement").to_string(),
+        (None, None) => l.text("Other updates", "Andre oppdateringer").to_string(),
+    }
+}
+
+// Items store their English line; known kinds are translated when rendered
+fn item_message<'a>(item: &'a DigestItem, l: &Localization) -> &'a str {
+    let norwegian = match item.kind.as_str() {
+        "waitlist_moved" => "Du har rykket opp på ventelisten",
+        "promoted" => "En plass har blitt ledig for deg",
+        "promotion_expired" => "Tilbudet om plass gikk ut og gikk videre til nestemann",
+        "event_published" => "Arrangementet ble publisert",
+        _ => return &item.message,
+    };
+    match l.locale {
+        Locale::Nb => norwegian,
+        Locale::En => &item.message,
+    }
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_digest_lists_items_per_event_and_escapes_html() {
+        let user_id = Uuid::new_v4();
+        let event_id = Uuid::new_v4();
+        let item = |event_id: Option<Uuid>, title: Option<&str>, message: &str| {
+            let mut item = DigestItem::new(user_id, event_id, title.map(str::to_string), "waitlist_moved", message);
+            item.created_at = Utc.with_ymd_and_hms(2026, 3, 12, 8, 0, 0).unwrap();
+            item
+        };
+        let digest = NotificationDigest {
+            user_id,
+            frequency: DigestFrequency::Weekly,
+            groups: vec![
+                DigestGroup {
+                    event_id: Some(event_id),
+                    event_title: Some("Havbruk & Sjømat".to_string()),
+                    items: vec![
+                        item(Some(event_id), Some("Havbruk & Sjømat"), "You moved up the waitlist"),
+                        item(Some(event_id), Some("Havbruk & Sjømat"), "Your waitlist offer expired"),
+                    ],
+                },
+                DigestGroup {
+                    event_id: None,
+                    event_title: None,
+                    items: vec![item(None, None, "Something <else>")],
+                },
+            ],
+        };
+
+        let email = render_digest(&digest, "kari@example.no", "Kari", "https://aqio.no/", &Localization::default());
+
+        assert_eq!(email.to, "kari@example.no");
+        assert_eq!(email.subject, "Your weekly Aqio digest: 3 updates");
+        let link = format!("https://aqio.no/events/{}", event_id);
+        assert!(email.text_body.contains(&format!(
+            "Havbruk & Sjømat\n- You moved up the waitlist (Thu 12 Mar 2026, 09:00 CET)\n- Your waitlist offer expired (Thu 12 Mar 2026, 09:00 CET)\n{}\n",
+            link
+        )));
+        assert!(email.text_body.contains("\nOther updates\n- Something <else> (Thu 12 Mar 2026, 09:00 CET)\n"));
+        assert!(email.html_body.contains("<h2>Havbruk &amp; Sjømat</h2>"));
+        assert!(email.html_body.contains("<li>Something &lt;else&gt; (Thu 12 Mar 2026, 09:00 CET)</li>"));
+        assert!(email.html_body.contains(&format!("<a href=\"{}\">", link)));
+    }
+
+    #[test]
+    fn test_digest_in_norwegian_translates_known_items() {
+        let user_id = Uuid::new_v4();
+        let mut item = DigestItem::new(user_id, None, None, "event_published", "The event was published");
+        item.created_at = Utc.with_ymd_and_hms(2026, 3, 12, 8, 0, 0).unwrap();
+        let digest = NotificationDigest {
+            user_id,
+            frequency: DigestFrequency::Daily,
+            groups: vec![DigestGroup { event_id: None, event_title: None, items: vec![item] }],
+        };
+        let norwegian = Localization { locale: Locale::Nb, ..Localization::default() };
+
+        let email = render_digest(&digest, "kari@example.no", "Kari", "https://aqio.no", &norwegian);
+
+        assert_eq!(email.subject, "Ditt daglige Aqio-sammendrag: 1 oppdatering");
+        assert!(email.text_body.starts_with("Hei Kari,"));
+        assert!(email
+            .text_body
+            .contains("\nAndre oppdateringer\n- Arrangementet ble publisert (tor. 12. mars 2026 kl. 09:00 CET)\n"));
+    }
+}
@@ -25,6 +25,8 @@ pub struct CreateEventRequest {
     pub address: Option<String>,
     pub virtual_link: Option<String>,
     pub virtual_access_code: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
     pub is_private: Option<bool>,
     pub requires_approval: Option<bool>,
     pub max_attendees: Option<i32>,
@@ -39,6 +41,10 @@ pub struct CreateEventRequest {
     pub collect_accessibility_info: Option<bool>,
     pub image_url: Option<String>,
     pub custom_fields: Option<String>,
+    /// Publish the draft automatically at this time; must be before the event starts
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Users who share the organizer's rights, e.g. picked with `GET /users/search`
+    pub co_organizers: Option<Vec<Uuid>>,
 }
 
 impl CreateEventRequest {
@@ -59,6 +65,10 @@ impl CreateEventRequest {
             return Err(ApiError::validation("dates", "End date must be after start date"));
         }
 
+        if self.publish_at.is_some_and(|publish_at| publish_at >= self.start_date) {
+            return Err(ApiError::validation("publish_at", "The event must be published before it starts"));
+        }
+
         if let Some(max) = self.max_attendees {
             if max <= 0 {
                 return Err(ApiError::validation("max_attendees", "Maximum attendees must be positive"));
@@ -96,13 +106,26 @@ impl CreateEventRequest {
         Ok(())
     }
 
+    // The organizer isn't their own co-organizer, and repeats keep their first position
+    fn co_organizers_for(&self, organizer_id: Uuid) -> Vec<Uuid> {
+        let mut co_organizers: Vec<Uuid> = Vec::new();
+        for id in self.co_organizers.iter().flatten() {
+            if *id != organizer_id && !co_organizers.contains(id) {
+                co_organizers.push(*id);
+            }
+        }
+        co_organizers
+    }
+
     pub fn to_domain_event(&self, organizer_id: Uuid) -> ApiResult<Event> {
         self.validate()?;
 
         let now = Utc::now();
+        let id = Uuid::new_v4();
         Ok(Event {
-            id: Uuid::new_v4(),
+            id,
             title: self.title.clone(),
+            slug: Event::slug_for(&self.title, id),
             description: self.description.clone(),
             category_id: self.category_id.clone(),
             start_date: self.start_date,
@@ -113,8 +136,11 @@ impl CreateEventRequest {
             address: self.address.clone(),
             virtual_link: self.virtual_link.clone(),
             virtual_access_code: self.virtual_access_code.clone(),
+            latitude: self.latitude,
+            longitude: self.longitude,
+            region: None,
             organizer_id,
-            co_organizers: vec![], // Empty by default
+            co_organizers: self.co_organizers_for(organizer_id),
             is_private: self.is_private.unwrap_or(false),
             requires_approval: self.requires_approval.unwrap_or(false),
             max_attendees: self.max_attendees,
@@ -130,6 +156,7 @@ impl CreateEventRequest {
             image_url: self.image_url.clone(),
             custom_fields: self.custom_fields.clone(),
             status: EventStatus::Draft, // Events start as draft
+            publish_at: self.publish_at,
             created_at: now,
             updated_at: now,
         })
@@ -140,6 +167,7 @@ impl CreateEventRequest {
 pub struct EventResponse {
     pub id: Uuid,
     pub title: String,
+    pub slug: String,
     pub description: String,
     pub category_id: String,
     pub start_date: DateTime<Utc>,
@@ -149,6 +177,10 @@ pub struct EventResponse {
     pub location_name: Option<String>,
     pub address: Option<String>,
     pub virtual_link: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Municipality and county, when the address has a known postal code
+    pub region: Option<AddressRegion>,
     pub organizer_id: Uuid,
     pub co_organizers: Vec<Uuid>,
     pub is_private: bool,
@@ -166,15 +198,72 @@ pub struct EventResponse {
     pub image_url: Option<String>,
     pub custom_fields: Option<String>,
     pub status: EventStatus,
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Whether registration is possible right now; `Full` and `WaitlistOnly` are only
+    /// reported where registrations were counted (single events and detailed listings)
+    pub registration_state: RegistrationState,
+    /// Seconds until registration opens; absent when it isn't waiting for `registration_opens`
+    pub seconds_until_registration_opens: Option<i64>,
+    /// Seconds until registration closes; absent once it has closed
+    pub seconds_until_registration_closes: Option<i64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Files the caller may download; only included when fetching a single event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<AttachmentResponse>>,
+    /// Weather at the venue on the day; only included when fetching a single
+    /// physical or hybrid event that is under way or less than a week away
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forecast: Option<WeatherForecast>,
+}
+
+impl EventResponse {
+    /// The event with its registration state worked out from the seats already taken
+    pub fn with_confirmed_registrations(event: Event, confirmed: i64) -> Self {
+        Self::build(event, Some(confirmed), Utc::now())
+    }
+
+    pub fn with_attachments(mut self, attachments: Vec<EventAttachment>) -> Self {
+        self.attachments = Some(attachments.into_iter().map(AttachmentResponse::from).collect());
+        self
+    }
+
+    pub fn with_forecast(mut self, forecast: Option<WeatherForecast>) -> Self {
+        self.forecast = forecast;
+        self
+    }
+}
+
+// Seconds until registration opens (only while waiting for `registration_opens`)
+// and until it closes (until it has)
+fn registration_countdown(event: &Event, state: RegistrationState, now: DateTime<Utc>) -> (Option<i64>, Option<i64>) {
+    let opens = event
+        .registration_opens
+        .filter(|opens| state == RegistrationState::NotYetOpen && *opens > now)
+        .map(|opens| (opens - now).num_seconds());
+    let closes = (state != RegistrationState::Closed).then(|| (event.registration_closes_at() - now).num_seconds());
+    (opens, closes)
 }
 
 impl From<Event> for EventResponse {
     fn from(event: Event) -> Self {
+        Self::build(event, None, Utc::now())
+    }
+}
+
+impl EventResponse {
+    fn build(event: Event, confirmed: Option<i64>, now: DateTime<Utc>) -> Self {
+        let registration_state = event.registration_state(now, confirmed);
+        let (seconds_until_registration_opens, seconds_until_registration_closes) =
+            registration_countdown(&event, registration_state, now);
+
         Self {
+            registration_state,
+            seconds_until_registration_opens,
+            seconds_until_registration_closes,
             id: event.id,
             title: event.title,
+            slug: event.slug,
             description: event.description,
             category_id: event.category_id,
             start_date: event.start_date,
@@ -184,6 +273,9 @@ impl From<Event> for EventResponse {
             location_name: event.location_name,
             address: event.address,
             virtual_link: event.virtual_link,
+            latitude: event.latitude,
+            longitude: event.longitude,
+            region: event.region,
             organizer_id: event.organizer_id,
             co_organizers: event.co_organizers,
             is_private: event.is_private,
@@ -201,8 +293,186 @@ impl From<Event> for EventResponse {
             image_url: event.image_url,
             custom_fields: event.custom_fields,
             status: event.status,
+            publish_at: event.publish_at,
             created_at: event.created_at,
             updated_at: event.updated_at,
+            attachments: None,
+            forecast: None,
+        }
+    }
+}
+
+/// Compact event representation for calendar and list views
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventSummaryResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub category_id: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub timezone: String,
+    pub location_type: LocationType,
+    pub location_name: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub organizer_id: Uuid,
+    pub is_private: bool,
+    pub status: EventStatus,
+}
+
+impl From<Event> for EventSummaryResponse {
+    fn from(event: Event) -> Self {
+        Self {
+            id: event.id,
+            title: event.title,
+            category_id: event.category_id,
+            start_date: event.start_date,
+            end_date: event.end_date,
+            timezone: event.timezone,
+            location_type: event.location_type,
+            location_name: event.location_name,
+            latitude: event.latitude,
+            longitude: event.longitude,
+            organizer_id: event.organizer_id,
+            is_private: event.is_private,
+            status: event.status,
+        }
+    }
+}
+
+/// Public organizer info embedded in event listings (no contact details)
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OrganizerSummary {
+    pub id: Uuid,
+    pub name: String,
+}
+
+impl From<User> for OrganizerSummary {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            name: user.name,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct RegistrationCountsResponse {
+    pub registered: i64,
+    pub waitlisted: i64,
+    pub attended: i64,
+    pub cancelled: i64,
+    /// Registrations checked in at the door
+    pub checked_in: i64,
+    /// Remaining seats, or `None` if the event has no attendee limit
+    pub available_spots: Option<i64>,
+}
+
+/// What a registration button needs to know, small enough to poll
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventAvailabilityResponse {
+    pub event_id: Uuid,
+    pub registration_state: RegistrationState,
+    /// Seats left, or `None` if the event has no attendee limit
+    pub remaining_spots: Option<i64>,
+    pub waitlist_length: i64,
+    /// Seconds until registration opens; absent when it isn't waiting for `registration_opens`
+    pub seconds_until_registration_opens: Option<i64>,
+    /// Seconds until registration closes; absent once it has closed
+    pub seconds_until_registration_closes: Option<i64>,
+}
+
+impl EventAvailabilityResponse {
+    pub fn new(event: &Event, counts: &RegistrationCounts, now: DateTime<Utc>) -> Self {
+        let registration_state = event.registration_state(now, Some(counts.confirmed()));
+        let (seconds_until_registration_opens, seconds_until_registration_closes) =
+            registration_countdown(event, registration_state, now);
+
+        Self {
+            event_id: event.id,
+            registration_state,
+            remaining_spots: event
+                .max_attendees
+                .map(|max| (i64::from(max) - counts.confirmed()).max(0)),
+            waitlist_length: counts.waitlisted,
+            seconds_until_registration_opens,
+            seconds_until_registration_closes,
+        }
+    }
+}
+
+/// The draft event an archive was imported as, with what came along
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct EventArchiveQuery {
+    /// Comma-separated personal data to scrub: `email`, `name`, `phone`,
+    /// `company`, `notes`, or `all`
+    pub anonymize: Option<String>,
+}
+
+impl EventArchiveQuery {
+    pub fn anonymizer(&self) -> ApiResult<crate::domain::anonymize::Anonymizer> {
+        match self.anonymize.as_deref() {
+            Some(fields) => crate::domain::anonymize::Anonymizer::parse(fields)
+                .map_err(|message| ApiError::validation("anonymize", message)),
+            None => Ok(Default::default()),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventImportResponse {
+    pub event: EventResponse,
+    pub invitations: usize,
+    pub registrations: usize,
+    pub sponsors: usize,
+    pub budget_lines: usize,
+    pub expenses: usize,
+    /// Personal data scrubbed from the imported rows
+    pub anonymized: Vec<crate::domain::anonymize::PiiField>,
+}
+
+impl From<crate::domain::archive::EventArchive> for EventImportResponse {
+    fn from(archive: crate::domain::archive::EventArchive) -> Self {
+        Self {
+            anonymized: archive.anonymized,
+            invitations: archive.invitations.len(),
+            registrations: archive.registrations.len(),
+            sponsors: archive.sponsors.len(),
+            budget_lines: archive.budget_lines.len(),
+            expenses: archive.expenses.len(),
+            event: EventResponse::from(archive.event),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventWithDetailsResponse {
+    pub event: EventResponse,
+    pub category: Option<EventCategoryResponse>,
+    pub organizer: Option<OrganizerSummary>,
+    pub registration_counts: RegistrationCountsResponse,
+}
+
+impl From<EventWithDetails> for EventWithDetailsResponse {
+    fn from(details: EventWithDetails) -> Self {
+        let counts = details.registration_counts;
+        let available_spots = details
+            .event
+            .max_attendees
+            .map(|max| (max as i64 - counts.confirmed()).max(0));
+
+        Self {
+            event: EventResponse::with_confirmed_registrations(details.event, counts.confirmed()),
+            category: details.category.map(EventCategoryResponse::from),
+            organizer: details.organizer.map(OrganizerSummary::from),
+            registration_counts: RegistrationCountsResponse {
+                registered: counts.registered,
+                waitlisted: counts.waitlisted,
+                attended: counts.attended,
+                cancelled: counts.cancelled,
+                checked_in: details.checked_in,
+                available_spots,
+            },
         }
     }
 }
@@ -211,10 +481,26 @@ impl From<Event> for EventResponse {
 // Query Parameter DTOs
 // ============================================================================
 
+/// Response shape for event listings: `summary` returns `EventSummaryResponse`
+/// items, `full` (default) returns `EventResponse` items
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EventView {
+    Summary,
+    #[default]
+    Full,
+}
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct EventViewQuery {
+    pub view: Option<EventView>,
+}
+
 #[derive(Deserialize, Debug, ToSchema, IntoParams)]
 pub struct ListEventsQuery {
     pub page: Option<u32>,
     pub limit: Option<u32>,
+    pub view: Option<EventView>,
     pub title_contains: Option<String>,
     pub category_id: Option<String>,
     pub organizer_id: Option<Uuid>,
@@ -223,10 +509,45 @@ pub struct ListEventsQuery {
     pub location_type: Option<LocationType>,
     pub start_date_from: Option<DateTime<Utc>>,
     pub start_date_to: Option<DateTime<Utc>>,
+    /// Proximity search centre; requires `longitude` and `radius_km`
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub radius_km: Option<f64>,
+    /// Only events organized by members of this company
+    pub organizer_company_id: Option<Uuid>,
+    /// Only events this user is a co-organizer of
+    pub co_organizer_id: Option<Uuid>,
+    /// Custom event field to filter on; requires `field_value`
+    pub field_id: Option<Uuid>,
+    /// Value the custom field must have, ignoring case
+    pub field_value: Option<String>,
+    /// Only events in this county (fylke), e.g. `Vestland`
+    pub county: Option<String>,
+    /// Only events in this municipality (kommune)
+    pub municipality: Option<String>,
 }
 
 impl ListEventsQuery {
     pub fn to_filter_and_pagination(&self) -> ApiResult<(EventFilter, PaginationParams)> {
+        let near = match (self.latitude, self.longitude, self.radius_km) {
+            (Some(latitude), Some(longitude), Some(radius_km)) => Some(
+                GeoRadius::new(latitude, longitude, radius_km)
+                    .map_err(|e| ApiError::Domain { source: e })?,
+            ),
+            (None, None, None) => None,
+            _ => {
+                return Err(ApiError::validation(
+                    "radius_km",
+                    "latitude, longitude and radius_km must be given together",
+                ))
+            }
+        };
+        let field = match (self.field_id, &self.field_value) {
+            (Some(field_id), Some(value)) => Some(EventFieldMatch { field_id, value: value.clone() }),
+            (None, None) => None,
+            _ => return Err(ApiError::validation("field_value", "field_id and field_value must be given together")),
+        };
+
         let filter = EventFilter {
             title_contains: self.title_contains.clone(),
             category_id: self.category_id.clone(),
@@ -236,6 +557,12 @@ impl ListEventsQuery {
             location_type: self.location_type.clone(),
             start_date_from: self.start_date_from,
             start_date_to: self.start_date_to,
+            near,
+            organizer_company_id: self.organizer_company_id,
+            co_organizer_id: self.co_organizer_id,
+            field,
+            county: self.county.clone(),
+            municipality: self.municipality.clone(),
         };
 
         let page = self.page.unwrap_or(1);
@@ -286,6 +613,22 @@ pub struct PaginationInfo {
     pub total_pages: u32,
 }
 
+impl PaginationInfo {
+    pub fn from_paginated_result<T>(result: &PaginatedResult<T>) -> Self {
+        let page = (result.offset / result.limit + 1) as u32;
+        let total_pages = ((result.total_count as f64) / (result.limit as f64)).ceil() as u32;
+
+        Self {
+            page,
+            limit: result.limit,
+            total_count: result.total_count,
+            has_next: result.has_next,
+            has_prev: page > 1,
+            total_pages,
+        }
+    }
+}
+
 impl PaginatedEventResponse {
     pub fn from_paginated_result(result: PaginatedResult<Event>) -> Self {
         let page = (result.offset / result.limit + 1) as u32;
@@ -305,6 +648,40 @@ impl PaginatedEventResponse {
     }
 }
 
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PaginatedEventSummaryResponse {
+    pub items: Vec<EventSummaryResponse>,
+    pub pagination: PaginationInfo,
+}
+
+impl PaginatedEventSummaryResponse {
+    pub fn from_paginated_result(result: PaginatedResult<Event>) -> Self {
+        let pagination = PaginationInfo::from_paginated_result(&result);
+
+        Self {
+            items: result.items.into_iter().map(EventSummaryResponse::from).collect(),
+            pagination,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PaginatedEventWithDetailsResponse {
+    pub items: Vec<EventWithDetailsResponse>,
+    pub pagination: PaginationInfo,
+}
+
+impl PaginatedEventWithDetailsResponse {
+    pub fn from_paginated_result(result: PaginatedResult<EventWithDetails>) -> Self {
+        let pagination = PaginationInfo::from_paginated_result(&result);
+
+        Self {
+            items: result.items.into_iter().map(EventWithDetailsResponse::from).collect(),
+            pagination,
+        }
+    }
+}
+
 #[derive(Serialize, Debug, ToSchema)]
 pub struct ApiResponse<T> {
     pub success: bool,
@@ -362,6 +739,10 @@ impl CreateUserRequest {
             company_id: self.company_id,
             role: self.role.unwrap_or(UserRole::Participant),
             is_active: true,
+            timezone: None,
+            locale: None,
+            theme: None,
+            calendar_view: None,
             created_at: now,
             updated_at: now,
         })
@@ -375,6 +756,31 @@ pub struct UpdateUserRequest {
     pub company_id: Option<Option<Uuid>>,
     pub role: Option<UserRole>,
     pub is_active: Option<bool>,
+    /// IANA time zone such as `Europe/Oslo`; `null` goes back to the default
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<String>)]
+    pub timezone: Option<Option<String>>,
+    /// `null` goes back to the browser's language
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<Locale>)]
+    pub locale: Option<Option<Locale>>,
+    /// `null` follows the device's colour scheme
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<Theme>)]
+    pub theme: Option<Option<Theme>>,
+    /// View the web app's calendar opens on; `null` goes back to the month
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<CalendarView>)]
+    pub calendar_view: Option<Option<CalendarView>>,
+}
+
+// Tells an explicit `null` (`Some(None)`) apart from a missing field (`None`)
+fn nullable<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 impl UpdateUserRequest {
@@ -405,6 +811,27 @@ impl UpdateUserRequest {
             user.is_active = is_active;
         }
 
+        if let Some(timezone) = self.timezone {
+            if let Some(timezone) = &timezone {
+                if timezone.parse::<chrono_tz::Tz>().is_err() {
+                    return Err(ApiError::validation("timezone", "Unknown time zone, expected e.g. Europe/Oslo"));
+                }
+            }
+            user.timezone = timezone;
+        }
+
+        if let Some(locale) = self.locale {
+            user.locale = locale;
+        }
+
+        if let Some(theme) = self.theme {
+            user.theme = theme;
+        }
+
+        if let Some(calendar_view) = self.calendar_view {
+            user.calendar_view = calendar_view;
+        }
+
         user.updated_at = Utc::now();
         Ok(user)
     }
@@ -419,6 +846,10 @@ pub struct UserResponse {
     pub company_id: Option<Uuid>,
     pub role: UserRole,
     pub is_active: bool,
+    pub timezone: Option<String>,
+    pub locale: Option<Locale>,
+    pub theme: Option<Theme>,
+    pub calendar_view: Option<CalendarView>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -433,6 +864,10 @@ impl From<User> for UserResponse {
             company_id: user.company_id,
             role: user.role,
             is_active: user.is_active,
+            timezone: user.timezone,
+            locale: user.locale,
+            theme: user.theme,
+            calendar_view: user.calendar_view,
             created_at: user.created_at,
             updated_at: user.updated_at,
         }
@@ -466,489 +901,4759 @@ impl PaginatedUserResponse {
     }
 }
 
-// ============================================================================
-// Event Category DTOs
-// ============================================================================
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct UserSearchQuery {
+    /// Part of a name, email address or company name; at least 2 characters
+    pub q: String,
+    /// At most 25; defaults to 10
+    pub limit: Option<i64>,
+}
 
-#[derive(Deserialize, Debug, ToSchema)]
-pub struct CreateEventCategoryRequest {
-    pub id: String,
+/// A user offered by the co-organizer and invitee pickers
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UserSearchResult {
+    pub id: Uuid,
     pub name: String,
-    pub description: Option<String>,
-    pub color_hex: Option<String>,
-    pub icon_name: Option<String>,
-    pub is_active: Option<bool>,
+    pub email: String,
+    pub company_id: Option<Uuid>,
+    pub company_name: Option<String>,
 }
 
-impl CreateEventCategoryRequest {
-    pub fn to_domain_category(self) -> ApiResult<EventCategory> {
-        if self.id.trim().is_empty() {
-            return Err(ApiError::validation("id", "Category ID cannot be empty"));
-        }
-
-        if self.name.trim().is_empty() {
-            return Err(ApiError::validation("name", "Category name cannot be empty"));
+impl From<UserMatch> for UserSearchResult {
+    fn from(found: UserMatch) -> Self {
+        Self {
+            id: found.user.id,
+            name: found.user.name,
+            email: found.user.email,
+            company_id: found.user.company_id,
+            company_name: found.company_name,
         }
-
-        Ok(EventCategory {
-            id: self.id,
-            name: self.name,
-            description: self.description,
-            color_hex: self.color_hex,
-            icon_name: self.icon_name,
-            is_active: self.is_active.unwrap_or(true),
-            created_at: Utc::now(),
-        })
     }
 }
 
-#[derive(Deserialize, Debug, ToSchema)]
-pub struct UpdateEventCategoryRequest {
-    pub name: Option<String>,
-    pub description: Option<Option<String>>,
-    pub color_hex: Option<Option<String>>,
-    pub icon_name: Option<Option<String>>,
-    pub is_active: Option<bool>,
+/// What the signed-in user may do. Frontend guards use it to hide controls
+/// the API would refuse; the API checks again on every request.
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PermissionsResponse {
+    /// Permissions that hold everywhere
+    pub permissions: Vec<Permission>,
+    /// Permissions that hold only within the user's organization
+    pub organization: Option<OrganizationPermissionsResponse>,
+    /// Permissions access grants give the user for single events right now
+    pub events: Vec<EventPermissionsResponse>,
 }
 
-impl UpdateEventCategoryRequest {
-    pub fn apply_to_category(self, mut category: EventCategory) -> ApiResult<EventCategory> {
-        if let Some(name) = self.name {
-            if name.trim().is_empty() {
-                return Err(ApiError::validation("name", "Category name cannot be empty"));
-            }
-            category.name = name;
-        }
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OrganizationPermissionsResponse {
+    pub company_id: Uuid,
+    pub permissions: Vec<Permission>,
+}
 
-        if let Some(description) = self.description {
-            category.description = description;
-        }
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventPermissionsResponse {
+    pub event_id: Uuid,
+    pub permissions: Vec<Permission>,
+}
 
-        if let Some(color_hex) = self.color_hex {
-            category.color_hex = color_hex;
+impl From<&PermissionChecker> for PermissionsResponse {
+    fn from(checker: &PermissionChecker) -> Self {
+        Self {
+            permissions: checker.platform_permissions().iter().collect(),
+            organization: checker
+                .organization_permissions()
+                .map(|(company_id, permissions)| OrganizationPermissionsResponse {
+                    company_id,
+                    permissions: permissions.iter().collect(),
+                }),
+            events: checker
+                .event_permissions()
+                .map(|(event_id, permissions)| EventPermissionsResponse {
+                    event_id,
+                    permissions: permissions.iter().collect(),
+                })
+                .collect(),
         }
+    }
+}
 
-        if let Some(icon_name) = self.icon_name {
-            category.icon_name = icon_name;
-        }
+/// Creates or replaces an organization's custom role
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct OrganizationRoleRequest {
+    pub name: String,
+    pub description: Option<String>,
+    /// Codes such as `registration:check_in`; only those organizations may
+    /// hand out are accepted
+    pub permissions: Vec<Permission>,
+}
 
-        if let Some(is_active) = self.is_active {
-            category.is_active = is_active;
+impl From<OrganizationRoleRequest> for crate::domain::services::OrganizationRoleInput {
+    fn from(request: OrganizationRoleRequest) -> Self {
+        Self {
+            name: request.name,
+            description: request.description,
+            permissions: request.permissions,
         }
-
-        Ok(category)
     }
 }
 
 #[derive(Serialize, Debug, ToSchema)]
-pub struct EventCategoryResponse {
-    pub id: String,
+pub struct OrganizationRoleResponse {
+    pub id: Uuid,
+    pub company_id: Uuid,
     pub name: String,
     pub description: Option<String>,
-    pub color_hex: Option<String>,
-    pub icon_name: Option<String>,
-    pub is_active: bool,
+    pub permissions: Vec<Permission>,
     pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
-impl From<EventCategory> for EventCategoryResponse {
-    fn from(category: EventCategory) -> Self {
+impl From<OrganizationRole> for OrganizationRoleResponse {
+    fn from(role: OrganizationRole) -> Self {
         Self {
-            id: category.id,
-            name: category.name,
-            description: category.description,
-            color_hex: category.color_hex,
-            icon_name: category.icon_name,
-            is_active: category.is_active,
-            created_at: category.created_at,
+            id: role.id,
+            company_id: role.company_id,
+            name: role.name,
+            description: role.description,
+            permissions: role.permissions.iter().collect(),
+            created_at: role.created_at,
+            updated_at: role.updated_at,
         }
     }
 }
 
-// ============================================================================
-// Health Check DTOs
-// ============================================================================
-
-#[derive(Serialize, Debug, ToSchema)]
-pub struct HealthResponse {
-    pub status: String,
-    pub timestamp: DateTime<Utc>,
-    pub version: String,
-    pub services: HealthServices,
+/// Delegates access to an event for a limited time
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct AccessGrantRequest {
+    pub user_id: Uuid,
+    /// Any of `event:manage`, `registration:approve`,
+    /// `registration:check_in`, `registration:manage` and `invitation:send`
+    pub permissions: Vec<Permission>,
+    /// Defaults to now
+    pub starts_at: Option<DateTime<Utc>>,
+    /// At most 31 days after `starts_at`
+    pub expires_at: DateTime<Utc>,
 }
 
-#[derive(Serialize, Debug, ToSchema)]
-pub struct HealthServices {
-    pub database: ServiceHealth,
-    pub auth: ServiceHealth,
+impl From<AccessGrantRequest> for crate::domain::services::AccessGrantInput {
+    fn from(request: AccessGrantRequest) -> Self {
+        Self {
+            user_id: request.user_id,
+            permissions: request.permissions,
+            starts_at: request.starts_at,
+            expires_at: request.expires_at,
+        }
+    }
 }
 
 #[derive(Serialize, Debug, ToSchema)]
-pub struct ServiceHealth {
-    pub status: String,
-    pub details: Option<String>,
+pub struct AccessGrantResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub user_id: Uuid,
+    pub granted_by: Uuid,
+    pub permissions: Vec<Permission>,
+    pub starts_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Whether the grant holds right now
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
 }
 
-impl HealthResponse {
-    pub fn healthy() -> Self {
+impl From<AccessGrant> for AccessGrantResponse {
+    fn from(grant: AccessGrant) -> Self {
         Self {
-            status: "healthy".to_string(),
-            timestamp: Utc::now(),
-            version: env!("CARGO_PKG_VERSION").to_string(),
-            services: HealthServices {
-                database: ServiceHealth {
-                    status: "healthy".to_string(),
-                    details: None,
-                },
-                auth: ServiceHealth {
-                    status: "healthy".to_string(),
-                    details: None,
-                },
-            },
+            is_active: grant.is_active_at(Utc::now()),
+            id: grant.id,
+            event_id: grant.event_id,
+            user_id: grant.user_id,
+            granted_by: grant.granted_by,
+            permissions: grant.permissions.iter().collect(),
+            starts_at: grant.starts_at,
+            expires_at: grant.expires_at,
+            revoked_at: grant.revoked_at,
+            created_at: grant.created_at,
         }
     }
+}
 
-    pub fn unhealthy(details: impl Into<String>) -> Self {
+// ============================================================================
+// Scheduled Export DTOs
+// ============================================================================
+
+/// A scheduled export of registrations or events to an SFTP server or S3 bucket
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ExportJobRequest {
+    pub name: String,
+    pub entity: ExportEntity,
+    #[serde(default)]
+    pub filter: ExportFilter,
+    pub format: ExportFormat,
+    pub destination: ExportDestination,
+    /// Five-field cron expression such as `0 2 * * *`, or `@daily`
+    pub schedule: String,
+    /// IANA time zone the schedule is read in; Europe/Oslo when unset
+    pub timezone: Option<String>,
+    /// Defaults to true
+    pub is_active: Option<bool>,
+}
+
+impl From<ExportJobRequest> for crate::domain::services::ExportJobInput {
+    fn from(request: ExportJobRequest) -> Self {
         Self {
-            status: "unhealthy".to_string(),
-            timestamp: Utc::now(),
-            version: env!("CARGO_PKG_VERSION").to_string(),
-            services: HealthServices {
-                database: ServiceHealth {
-                    status: "unknown".to_string(),
-                    details: Some(details.into()),
-                },
-                auth: ServiceHealth {
-                    status: "unknown".to_string(),
-                    details: None,
-                },
-            },
+            name: request.name,
+            entity: request.entity,
+            filter: request.filter,
+            format: request.format,
+            destination: request.destination,
+            schedule: request.schedule,
+            timezone: request.timezone,
+            is_active: request.is_active.unwrap_or(true),
         }
     }
 }
 
 // ============================================================================
-// Invitation DTOs
+// CRM Connector DTOs
 // ============================================================================
 
+/// A connector that syncs registrations to HubSpot or Dynamics 365
 #[derive(Deserialize, Debug, ToSchema)]
-pub struct CreateInvitationRequest {
-    pub invited_user_id: Option<Uuid>,
-    pub invited_email: Option<String>,
-    pub invited_name: Option<String>,
-    pub invitation_method: InvitationMethod,
-    pub personal_message: Option<String>,
-    pub expires_at: Option<DateTime<Utc>>,
+pub struct CrmConnectorRequest {
+    pub name: String,
+    pub provider: CrmProvider,
+    /// Dynamics organization URL such as `https://contoso.crm4.dynamics.com`;
+    /// HubSpot's public API when unset, and the only one allowed for HubSpot
+    pub base_url: Option<String>,
+    /// Only sync registrations for events this company organizes
+    pub company_id: Option<Uuid>,
+    /// Object type (HubSpot) or entity set (Dynamics) to write one record
+    /// per registration to; contacts only when unset
+    pub registration_object: Option<String>,
+    /// Contacts' first and last names when unset
+    pub field_mappings: Option<Vec<CrmFieldMapping>>,
+    /// Defaults to CrmWins
+    pub conflict_policy: Option<CrmConflictPolicy>,
+    /// Between 5 and 1440; defaults to 60
+    pub sync_interval_minutes: Option<i32>,
+    /// Defaults to true
+    pub is_active: Option<bool>,
 }
 
-impl CreateInvitationRequest {
-    pub fn to_domain_invitation(&self, event_id: Uuid, inviter_id: Uuid) -> ApiResult<EventInvitation> {
-        let now = Utc::now();
-        let invitation = EventInvitation {
-            id: Uuid::new_v4(),
-            event_id,
-            invited_user_id: self.invited_user_id,
-            invited_contact_id: None,
-            invited_email: self.invited_email.clone(),
-            invited_name: self.invited_name.clone(),
-            inviter_id,
-            invitation_method: self.invitation_method.clone(),
-            personal_message: self.personal_message.clone(),
-            status: InvitationStatus::Pending,
-            sent_at: None,
-            opened_at: None,
-            responded_at: None,
-            invitation_token: None,
-            expires_at: self.expires_at,
-            created_at: now,
-            updated_at: now,
-        };
-
-        // Validate using domain rules
-        invitation
-            .validate_for_creation()
-            .map_err(|e| ApiError::Domain { source: e })?;
-
-        Ok(invitation)
+impl From<CrmConnectorRequest> for crate::domain::services::CrmConnectorInput {
+    fn from(request: CrmConnectorRequest) -> Self {
+        Self {
+            name: request.name,
+            provider: request.provider,
+            base_url: request.base_url,
+            company_id: request.company_id,
+            registration_object: request.registration_object,
+            field_mappings: request.field_mappings,
+            conflict_policy: request.conflict_policy,
+            sync_interval_minutes: request.sync_interval_minutes,
+            is_active: request.is_active.unwrap_or(true),
+        }
     }
 }
 
-#[derive(Deserialize, Debug, ToSchema)]
-pub struct UpdateInvitationStatusRequest {
-    pub status: InvitationStatus,
-}
-
+/// A connector's last run, its records by state and the ones that need a look
 #[derive(Serialize, Debug, ToSchema)]
-pub struct InvitationResponse {
-    pub id: Uuid,
-    pub event_id: Uuid,
-    pub invited_user_id: Option<Uuid>,
-    pub invited_email: Option<String>,
-    pub invited_name: Option<String>,
-    pub inviter_id: Uuid,
-    pub invitation_method: InvitationMethod,
-    pub personal_message: Option<String>,
-    pub status: InvitationStatus,
-    pub sent_at: Option<DateTime<Utc>>,
-    pub opened_at: Option<DateTime<Utc>>,
-    pub responded_at: Option<DateTime<Utc>>,
-    pub expires_at: Option<DateTime<Utc>>,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
+pub struct CrmConnectorStatusResponse {
+    pub connector: CrmConnector,
+    pub counts: CrmSyncCounts,
+    /// Records in conflict or failed, most recently touched first
+    pub problems: Vec<CrmSyncRecord>,
 }
 
-impl From<EventInvitation> for InvitationResponse {
-    fn from(inv: EventInvitation) -> Self {
+impl From<crate::domain::services::CrmConnectorStatus> for CrmConnectorStatusResponse {
+    fn from(status: crate::domain::services::CrmConnectorStatus) -> Self {
         Self {
-            id: inv.id,
-            event_id: inv.event_id,
-            invited_user_id: inv.invited_user_id,
-            invited_email: inv.invited_email,
-            invited_name: inv.invited_name,
-            inviter_id: inv.inviter_id,
-            invitation_method: inv.invitation_method,
-            personal_message: inv.personal_message,
-            status: inv.status,
-            sent_at: inv.sent_at,
-            opened_at: inv.opened_at,
-            responded_at: inv.responded_at,
-            expires_at: inv.expires_at,
-            created_at: inv.created_at,
-            updated_at: inv.updated_at,
+            connector: status.connector,
+            counts: status.counts,
+            problems: status.problems,
         }
     }
 }
 
 // ============================================================================
-// Event Registration DTOs
+// Event Category DTOs
 // ============================================================================
 
 #[derive(Deserialize, Debug, ToSchema)]
-pub struct CreateRegistrationRequest {
-    pub registrant_email: Option<String>,
-    pub registrant_name: Option<String>,
-    pub registrant_phone: Option<String>,
-    pub registrant_company: Option<String>,
-    pub guest_count: Option<i32>,
-    pub guest_names: Option<Vec<String>>,
-    pub dietary_restrictions: Option<String>,
-    pub accessibility_needs: Option<String>,
-    pub special_requests: Option<String>,
-    pub custom_responses: Option<String>,
+pub struct CreateEventCategoryRequest {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub color_hex: Option<String>,
+    pub icon_name: Option<String>,
+    pub is_active: Option<bool>,
 }
 
-impl CreateRegistrationRequest {
-    pub fn to_domain_registration(
-        &self,
-        event_id: Uuid,
-        user_id: Option<Uuid>,
-        invitation_id: Option<Uuid>,
-    ) -> ApiResult<EventRegistration> {
-        let now = Utc::now();
-        
-        // Validate required fields
-        if user_id.is_none() && self.registrant_email.is_none() {
-            return Err(ApiError::validation(
-                "registrant_email",
-                "Email is required for non-authenticated users",
-            ));
-        }
-
-        if user_id.is_none() && self.registrant_name.is_none() {
-            return Err(ApiError::validation(
-                "registrant_name", 
-                "Name is required for non-authenticated users",
-            ));
+impl CreateEventCategoryRequest {
+    pub fn to_domain_category(self) -> ApiResult<EventCategory> {
+        if self.id.trim().is_empty() {
+            return Err(ApiError::validation("id", "Category ID cannot be empty"));
         }
 
-        let guest_count = self.guest_count.unwrap_or(0);
-        if guest_count < 0 || guest_count > 10 {
-            return Err(ApiError::validation(
-                "guest_count",
-                "Guest count must be between 0 and 10",
-            ));
+        if self.name.trim().is_empty() {
+            return Err(ApiError::validation("name", "Category name cannot be empty"));
         }
 
-        let guest_names = self.guest_names.clone().unwrap_or_default();
-        if guest_names.len() != guest_count as usize {
-            return Err(ApiError::validation(
-                "guest_names",
-                "Number of guest names must match guest count",
-            ));
+        if let Some(color_hex) = &self.color_hex {
+            validate_color_hex(color_hex)?;
         }
 
-        Ok(EventRegistration {
-            id: Uuid::new_v4(),
-            event_id,
-            invitation_id,
-            user_id,
-            external_contact_id: None,
-            registrant_email: self.registrant_email.clone(),
-            registrant_name: self.registrant_name.clone(),
-            registrant_phone: self.registrant_phone.clone(),
-            registrant_company: self.registrant_company.clone(),
-            status: RegistrationStatus::Registered,
-            registration_source: if invitation_id.is_some() {
-                RegistrationSource::Invitation
-            } else {
-                RegistrationSource::Direct
-            },
-            guest_count,
-            guest_names,
-            dietary_restrictions: self.dietary_restrictions.clone(),
-            accessibility_needs: self.accessibility_needs.clone(),
-            special_requests: self.special_requests.clone(),
-            custom_responses: self.custom_responses.clone(),
-            registered_at: now,
-            cancelled_at: None,
-            checked_in_at: None,
-            waitlist_position: None,
-            waitlist_added_at: None,
-            created_at: now,
-            updated_at: now,
+        Ok(EventCategory {
+            id: self.id,
+            name: self.name,
+            description: self.description,
+            color_hex: self.color_hex,
+            icon_name: self.icon_name,
+            is_active: self.is_active.unwrap_or(true),
+            created_at: Utc::now(),
         })
     }
 }
 
 #[derive(Deserialize, Debug, ToSchema)]
-pub struct UpdateRegistrationRequest {
-    pub registrant_email: Option<String>,
-    pub registrant_name: Option<String>,
-    pub registrant_phone: Option<String>,
-    pub registrant_company: Option<String>,
-    pub guest_count: Option<i32>,
-    pub guest_names: Option<Vec<String>>,
-    pub dietary_restrictions: Option<String>,
-    pub accessibility_needs: Option<String>,
-    pub special_requests: Option<String>,
-    pub custom_responses: Option<String>,
+pub struct UpdateEventCategoryRequest {
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+    pub color_hex: Option<Option<String>>,
+    pub icon_name: Option<Option<String>>,
+    pub is_active: Option<bool>,
 }
 
-impl UpdateRegistrationRequest {
-    pub fn apply_to_registration(self, mut registration: EventRegistration) -> ApiResult<EventRegistration> {
-        if let Some(email) = self.registrant_email {
-            registration.registrant_email = Some(email);
+impl UpdateEventCategoryRequest {
+    pub fn apply_to_category(self, mut category: EventCategory) -> ApiResult<EventCategory> {
+        if let Some(name) = self.name {
+            if name.trim().is_empty() {
+                return Err(ApiError::validation("name", "Category name cannot be empty"));
+            }
+            category.name = name;
         }
 
-        if let Some(name) = self.registrant_name {
-            registration.registrant_name = Some(name);
+        if let Some(description) = self.description {
+            category.description = description;
         }
 
-        if let Some(phone) = self.registrant_phone {
-            registration.registrant_phone = Some(phone);
+        if let Some(color_hex) = self.color_hex {
+            if let Some(color_hex) = &color_hex {
+                validate_color_hex(color_hex)?;
+            }
+            category.color_hex = color_hex;
         }
 
-        if let Some(company) = self.registrant_company {
-            registration.registrant_company = Some(company);
+        if let Some(icon_name) = self.icon_name {
+            category.icon_name = icon_name;
         }
 
-        if let Some(guest_count) = self.guest_count {
-            if guest_count < 0 || guest_count > 10 {
-                return Err(ApiError::validation(
-                    "guest_count",
-                    "Guest count must be between 0 and 10",
-                ));
-            }
-            registration.guest_count = guest_count;
+        if let Some(is_active) = self.is_active {
+            category.is_active = is_active;
         }
 
-        if let Some(guest_names) = self.guest_names {
-            if guest_names.len() != registration.guest_count as usize {
-                return Err(ApiError::validation(
-                    "guest_names",
-                    "Number of guest names must match guest count",
-                ));
-            }
-            registration.guest_names = guest_names;
+        Ok(category)
+    }
+}
+
+// Category colors are rendered directly as CSS, so only `#RRGGBB` is accepted
+fn validate_color_hex(color_hex: &str) -> ApiResult<()> {
+    let valid = color_hex.len() == 7
+        && color_hex.starts_with('#')
+        && color_hex[1..].chars().all(|c| c.is_ascii_hexdigit());
+
+    if !valid {
+        return Err(ApiError::validation("color_hex", "Color must be a hex value like #3B82F6"));
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventCategoryResponse {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub color_hex: Option<String>,
+    pub icon_name: Option<String>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<EventCategory> for EventCategoryResponse {
+    fn from(category: EventCategory) -> Self {
+        Self {
+            id: category.id,
+            name: category.name,
+            description: category.description,
+            color_hex: category.color_hex,
+            icon_name: category.icon_name,
+            is_active: category.is_active,
+            created_at: category.created_at,
         }
+    }
+}
 
-        if let Some(dietary) = self.dietary_restrictions {
-            registration.dietary_restrictions = Some(dietary);
+// ============================================================================
+// Company DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct CompanyDirectoryQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    /// `salmon`, `trout` or `other`
+    pub industry_type: Option<String>,
+}
+
+impl CompanyDirectoryQuery {
+    pub fn to_filter_and_pagination(&self) -> ApiResult<(CompanyFilter, PaginationParams)> {
+        let industry_type = parse_industry_type(self.industry_type.as_deref(), None)?;
+
+        let pagination = PaginationQuery {
+            page: self.page,
+            limit: self.limit,
         }
+        .to_pagination_params()?;
 
-        if let Some(accessibility) = self.accessibility_needs {
-            registration.accessibility_needs = Some(accessibility);
+        Ok((CompanyFilter { industry_type }, pagination))
+    }
+}
+
+// Stricter than IndustryType's Deserialize, which takes anything unknown as `Other`
+fn parse_industry_type(industry_type: Option<&str>, other_description: Option<String>) -> ApiResult<Option<IndustryType>> {
+    Ok(match industry_type.map(str::to_lowercase).as_deref() {
+        None => None,
+        Some("salmon") => Some(IndustryType::Salmon),
+        Some("trout") => Some(IndustryType::Trout),
+        Some("other") => Some(IndustryType::Other(
+            other_description.map(|description| description.trim().to_string()).unwrap_or_default(),
+        )),
+        Some(other) => {
+            return Err(ApiError::validation(
+                "industry_type",
+                format!("Unknown industry type '{}'; expected salmon, trout or other", other),
+            ))
         }
+    })
+}
 
-        if let Some(requests) = self.special_requests {
-            registration.special_requests = Some(requests);
+/// A company to add. With an organization number, blank fields are filled in
+/// from the business register.
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct CreateCompanyRequest {
+    /// Nine digits; spaces are ignored
+    pub org_number: Option<String>,
+    /// Required unless the organization number is found in the register
+    pub name: Option<String>,
+    pub location: Option<String>,
+    /// `salmon`, `trout` or `other`; `other` with the register's industry when left out
+    pub industry_type: Option<String>,
+    /// Description of an `other` industry
+    pub industry_type_other: Option<String>,
+    pub website: Option<String>,
+    pub phone: Option<String>,
+}
+
+impl CreateCompanyRequest {
+    pub fn into_input(self) -> ApiResult<crate::domain::services::CompanyInput> {
+        Ok(crate::domain::services::CompanyInput {
+            industry_type: parse_industry_type(self.industry_type.as_deref(), self.industry_type_other)?,
+            org_number: self.org_number,
+            name: self.name,
+            location: self.location,
+            website: self.website,
+            phone: self.phone,
+        })
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CreatedCompanyResponse {
+    pub company: CompanyResponse,
+    /// Whether the organization number was confirmed in the business register;
+    /// false when none was given or the register couldn't be reached
+    pub registry_verified: bool,
+}
+
+impl From<crate::domain::services::CreatedCompany> for CreatedCompanyResponse {
+    fn from(created: crate::domain::services::CreatedCompany) -> Self {
+        Self {
+            company: CompanyResponse::from(created.entry),
+            registry_verified: created.registry_verified,
+        }
+    }
+}
+
+/// A company in the Norwegian business register
+#[derive(Serialize, Debug, ToSchema)]
+pub struct RegisteredCompanyResponse {
+    pub org_number: String,
+    pub name: String,
+    /// Business address on one line
+    pub address: Option<String>,
+    pub city: Option<String>,
+    pub website: Option<String>,
+    /// Primary NACE industry code, e.g. `03.211`
+    pub industry_code: Option<String>,
+    pub industry_description: Option<String>,
+    /// False once the company is bankrupt, being wound up or deleted
+    pub is_active: bool,
+}
+
+impl From<RegisteredCompany> for RegisteredCompanyResponse {
+    fn from(company: RegisteredCompany) -> Self {
+        Self {
+            org_number: company.org_number,
+            name: company.name,
+            address: company.address,
+            city: company.city,
+            website: company.website,
+            industry_code: company.industry_code,
+            industry_description: company.industry_description,
+            is_active: company.is_active,
         }
+    }
+}
 
-        if let Some(responses) = self.custom_responses {
-            registration.custom_responses = Some(responses);
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CompanyRegistryResponse {
+    /// The number looked up, as nine digits
+    pub org_number: String,
+    /// `found`, `not_found`, or `unavailable` when the register couldn't be
+    /// reached; the number's check digit is valid either way
+    pub status: String,
+    pub company: Option<RegisteredCompanyResponse>,
+}
+
+impl CompanyRegistryResponse {
+    pub fn new(org_number: String, lookup: crate::domain::services::RegistryLookup) -> Self {
+        use crate::domain::services::RegistryLookup;
+        let (status, company) = match lookup {
+            RegistryLookup::Found(company) => ("found", Some(RegisteredCompanyResponse::from(company))),
+            RegistryLookup::NotFound => ("not_found", None),
+            RegistryLookup::Unavailable => ("unavailable", None),
+        };
+        Self {
+            org_number,
+            status: status.to_string(),
+            company,
         }
+    }
+}
 
-        registration.updated_at = Utc::now();
-        Ok(registration)
+/// A company as listed in the directory
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CompanyResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub org_number: Option<String>,
+    pub location: Option<String>,
+    /// `salmon`, `trout` or `other`
+    pub industry_type: String,
+    /// Description of an `other` industry
+    pub industry_type_other: Option<String>,
+    pub website: Option<String>,
+    pub phone: Option<String>,
+    /// Municipality and county, when the location has a known postal code
+    pub region: Option<AddressRegion>,
+    /// Active users belonging to the company
+    pub member_count: i64,
+}
+
+impl From<CompanyDirectoryEntry> for CompanyResponse {
+    fn from(entry: CompanyDirectoryEntry) -> Self {
+        let company = entry.company;
+        Self {
+            id: company.id,
+            name: company.name,
+            org_number: company.org_number,
+            location: company.location,
+            industry_type: company.industry_type.as_str().to_string(),
+            industry_type_other: company
+                .industry_type
+                .other_description()
+                .filter(|description| !description.is_empty())
+                .map(str::to_string),
+            website: company.website,
+            phone: company.phone,
+            region: company.region,
+            member_count: entry.member_count,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PaginatedCompanyResponse {
+    pub items: Vec<CompanyResponse>,
+    pub pagination: PaginationInfo,
+}
+
+impl PaginatedCompanyResponse {
+    pub fn from_paginated_result(result: PaginatedResult<CompanyDirectoryEntry>) -> Self {
+        Self {
+            pagination: PaginationInfo::from_paginated_result(&result),
+            items: result.items.into_iter().map(CompanyResponse::from).collect(),
+        }
     }
 }
 
+// ============================================================================
+// Event Comment DTOs
+// ============================================================================
+
 #[derive(Deserialize, Debug, ToSchema)]
-pub struct UpdateRegistrationStatusRequest {
-    pub status: RegistrationStatus,
+pub struct CreateCommentRequest {
+    pub content: String,
+    /// Comment being replied to; omit to start a new thread
+    pub parent_comment_id: Option<Uuid>,
 }
 
+/// A comment in an event's discussion; the list is flat and oldest first, so
+/// clients nest replies by `parent_comment_id`
 #[derive(Serialize, Debug, ToSchema)]
-pub struct RegistrationResponse {
+pub struct CommentResponse {
     pub id: Uuid,
     pub event_id: Uuid,
-    pub invitation_id: Option<Uuid>,
-    pub user_id: Option<Uuid>,
-    pub registrant_email: Option<String>,
-    pub registrant_name: Option<String>,
-    pub registrant_phone: Option<String>,
-    pub registrant_company: Option<String>,
-    pub status: RegistrationStatus,
-    pub registration_source: RegistrationSource,
-    pub guest_count: i32,
-    pub guest_names: Vec<String>,
-    pub dietary_restrictions: Option<String>,
-    pub accessibility_needs: Option<String>,
-    pub special_requests: Option<String>,
-    pub custom_responses: Option<String>,
-    pub registered_at: DateTime<Utc>,
-    pub cancelled_at: Option<DateTime<Utc>>,
-    pub checked_in_at: Option<DateTime<Utc>>,
-    pub waitlist_position: Option<i32>,
-    pub waitlist_added_at: Option<DateTime<Utc>>,
+    pub parent_comment_id: Option<Uuid>,
+    pub author_id: Option<Uuid>,
+    pub author_name: Option<String>,
+    /// `None` once the comment is deleted or hidden by moderation
+    pub content: Option<String>,
+    pub is_deleted: bool,
+    /// Hidden after abuse reports
+    pub is_hidden: bool,
+    /// Whether the requesting user may delete the comment
+    pub can_delete: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-impl From<EventRegistration> for RegistrationResponse {
-    fn from(registration: EventRegistration) -> Self {
+impl From<crate::domain::services::ThreadComment> for CommentResponse {
+    fn from(thread_comment: crate::domain::services::ThreadComment) -> Self {
+        let comment = thread_comment.comment;
+        let is_deleted = comment.is_deleted();
+        let is_hidden = thread_comment.is_hidden;
         Self {
-            id: registration.id,
-            event_id: registration.event_id,
-            invitation_id: registration.invitation_id,
-            user_id: registration.user_id,
-            registrant_email: registration.registrant_email,
-            registrant_name: registration.registrant_name,
-            registrant_phone: registration.registrant_phone,
-            registrant_company: registration.registrant_company,
-            status: registration.status,
-            registration_source: registration.registration_source,
-            guest_count: registration.guest_count,
-            guest_names: registration.guest_names,
-            dietary_restrictions: registration.dietary_restrictions,
-            accessibility_needs: registration.accessibility_needs,
-            special_requests: registration.special_requests,
-            custom_responses: registration.custom_responses,
-            registered_at: registration.registered_at,
-            cancelled_at: registration.cancelled_at,
-            checked_in_at: registration.checked_in_at,
-            waitlist_position: registration.waitlist_position,
-            waitlist_added_at: registration.waitlist_added_at,
-            created_at: registration.created_at,
-            updated_at: registration.updated_at,
+            id: comment.id,
+            event_id: comment.event_id,
+            parent_comment_id: comment.parent_comment_id,
+            author_id: comment.author_id,
+            author_name: comment.author_name,
+            content: (!is_deleted && !is_hidden).then_some(comment.content),
+            is_deleted,
+            is_hidden,
+            can_delete: thread_comment.can_delete,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+        }
+    }
+}
+
+// ============================================================================
+// Live Q&A and Poll DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct AskQuestionRequest {
+    pub content: String,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct AnswerQuestionRequest {
+    pub answer: String,
+}
+
+/// An audience question with its upvotes and the organizer's answer
+#[derive(Serialize, Debug, ToSchema)]
+pub struct QuestionResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub author_name: Option<String>,
+    pub content: String,
+    pub upvote_count: i64,
+    /// Whether the requesting user has upvoted the question
+    pub has_upvoted: bool,
+    pub answer: Option<String>,
+    pub answered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<crate::domain::services::LiveQuestion> for QuestionResponse {
+    fn from(live: crate::domain::services::LiveQuestion) -> Self {
+        let question = live.question;
+        Self {
+            id: question.id,
+            event_id: question.event_id,
+            author_name: question.author_name,
+            content: question.content,
+            upvote_count: question.upvote_count,
+            has_upvoted: live.has_upvoted,
+            answer: question.answer,
+            answered_at: question.answered_at,
+            created_at: question.created_at,
         }
     }
 }
 
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct CreatePollRequest {
+    pub question: String,
+    /// Between 2 and 10 distinct options, in display order
+    pub options: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct PollVoteRequest {
+    pub option_id: Uuid,
+}
+
+/// A poll with its current results
 #[derive(Serialize, Debug, ToSchema)]
-pub struct EventRegistrationStatsResponse {
-    pub total_registered: usize,
-    pub total_attended: usize,
-    pub total_waitlisted: usize,
-    pub total_cancelled: usize,
-}
\ No newline at end of file
+pub struct PollResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub question: String,
+    pub options: Vec<PollOptionResponse>,
+    pub total_votes: i64,
+    pub is_closed: bool,
+    /// The option the requesting user voted for
+    pub voted_option_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Questions, polls and the viewer's permissions, loaded before following the live stream
+#[derive(Serialize, Debug, ToSchema)]
+pub struct LiveSessionResponse {
+    /// Questions, upvotes, answers and votes are only accepted while this is true
+    pub is_running: bool,
+    /// Whether the requesting user may answer questions and run polls
+    pub can_moderate: bool,
+    /// Most upvoted first
+    pub questions: Vec<QuestionResponse>,
+    /// Oldest first
+    pub polls: Vec<PollResponse>,
+}
+
+impl From<crate::domain::services::LiveSession> for LiveSessionResponse {
+    fn from(session: crate::domain::services::LiveSession) -> Self {
+        Self {
+            is_running: session.is_running,
+            can_moderate: session.can_moderate,
+            questions: session.questions.into_iter().map(QuestionResponse::from).collect(),
+            polls: session.polls.into_iter().map(PollResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PollOptionResponse {
+    pub id: Uuid,
+    pub label: String,
+    pub vote_count: i64,
+}
+
+impl From<crate::domain::services::LivePoll> for PollResponse {
+    fn from(live: crate::domain::services::LivePoll) -> Self {
+        let poll = live.poll;
+        Self {
+            id: poll.id,
+            event_id: poll.event_id,
+            total_votes: poll.total_votes(),
+            is_closed: poll.is_closed(),
+            question: poll.question,
+            options: poll
+                .options
+                .into_iter()
+                .map(|option| PollOptionResponse {
+                    id: option.id,
+                    label: option.label,
+                    vote_count: option.vote_count,
+                })
+                .collect(),
+            voted_option_id: live.voted_option_id,
+            created_at: poll.created_at,
+        }
+    }
+}
+
+// ============================================================================
+// Event Attachment DTOs
+// ============================================================================
+
+/// Multipart form for uploading an attachment; only `file` is required
+#[derive(Debug, ToSchema)]
+#[allow(dead_code)] // Documents the multipart fields; the handler reads them directly
+pub struct UploadAttachmentForm {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Guessed from the file's type when omitted
+    pub category: Option<AttachmentCategory>,
+    /// Defaults to `AttendeesOnly`
+    pub visibility: Option<AttachmentVisibility>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub filename: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub mime_type: String,
+    pub file_size: i64,
+    pub category: AttachmentCategory,
+    pub visibility: AttachmentVisibility,
+    pub uploader_name: Option<String>,
+    pub download_count: i64,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventAttachmentsResponse {
+    /// Whether the caller may upload and remove attachments
+    pub can_manage: bool,
+    pub attachments: Vec<AttachmentResponse>,
+}
+
+impl From<crate::domain::services::AttachmentLibrary> for EventAttachmentsResponse {
+    fn from(library: crate::domain::services::AttachmentLibrary) -> Self {
+        Self {
+            can_manage: library.can_manage,
+            attachments: library.attachments.into_iter().map(AttachmentResponse::from).collect(),
+        }
+    }
+}
+
+impl From<EventAttachment> for AttachmentResponse {
+    fn from(attachment: EventAttachment) -> Self {
+        Self {
+            id: attachment.id,
+            event_id: attachment.event_id,
+            filename: attachment.original_filename,
+            title: attachment.title,
+            description: attachment.description,
+            mime_type: attachment.mime_type,
+            file_size: attachment.file_size,
+            category: attachment.category,
+            visibility: attachment.visibility,
+            uploader_name: attachment.uploader_name,
+            download_count: attachment.download_count,
+            uploaded_at: attachment.uploaded_at,
+        }
+    }
+}
+
+// ============================================================================
+// Event Photo DTOs
+// ============================================================================
+
+/// Multipart form for adding a photo; only `file` is required
+#[derive(Debug, ToSchema)]
+#[allow(dead_code)] // Documents the multipart fields; the handler reads them directly
+pub struct UploadPhotoForm {
+    /// A JPEG, PNG, WebP or GIF image
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    pub caption: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PhotoResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub filename: String,
+    pub caption: Option<String>,
+    pub mime_type: String,
+    pub file_size: i64,
+    pub status: PhotoStatus,
+    pub uploader_name: Option<String>,
+    /// Whether the caller may remove the photo
+    pub can_remove: bool,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+impl PhotoResponse {
+    pub fn new(photo: EventPhoto, can_remove: bool) -> Self {
+        Self {
+            id: photo.id,
+            event_id: photo.event_id,
+            filename: photo.original_filename,
+            caption: photo.caption,
+            mime_type: photo.mime_type,
+            file_size: photo.file_size,
+            status: photo.status,
+            uploader_name: photo.uploader_name,
+            can_remove,
+            uploaded_at: photo.uploaded_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PhotoGalleryResponse {
+    pub attendee_uploads: bool,
+    pub requires_approval: bool,
+    pub is_public: bool,
+    /// Whether the caller may change the album and approve or reject photos
+    pub can_manage: bool,
+    /// Whether the caller may add photos now; nobody can before the event is over
+    pub can_upload: bool,
+    pub photos: Vec<PhotoResponse>,
+}
+
+impl From<crate::domain::services::PhotoGallery> for PhotoGalleryResponse {
+    fn from(gallery: crate::domain::services::PhotoGallery) -> Self {
+        let photos = gallery
+            .photos
+            .iter()
+            .map(|photo| PhotoResponse::new(photo.clone(), gallery.can_remove(photo)))
+            .collect();
+        Self {
+            attendee_uploads: gallery.album.attendee_uploads,
+            requires_approval: gallery.album.requires_approval,
+            is_public: gallery.album.is_public,
+            can_manage: gallery.can_manage,
+            can_upload: gallery.can_upload,
+            photos,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct PhotoAlbumRequest {
+    /// Whether attendees may add photos too
+    pub attendee_uploads: bool,
+    /// Whether attendees' photos wait for an organizer's approval
+    pub requires_approval: bool,
+    /// Whether approved photos are shown on the public event page
+    pub is_public: bool,
+}
+
+impl From<PhotoAlbumRequest> for crate::domain::services::AlbumSettings {
+    fn from(request: PhotoAlbumRequest) -> Self {
+        Self {
+            attendee_uploads: request.attendee_uploads,
+            requires_approval: request.requires_approval,
+            is_public: request.is_public,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ModeratePhotoRequest {
+    /// `Approved` or `Rejected`
+    pub status: PhotoStatus,
+}
+
+// ============================================================================
+// Event Budget DTOs
+// ============================================================================
+
+/// Creates or replaces a budget line; amounts are in øre
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct BudgetLineRequest {
+    pub name: String,
+    pub planned_amount: i64,
+    pub notes: Option<String>,
+}
+
+impl From<BudgetLineRequest> for crate::domain::services::BudgetLineInput {
+    fn from(request: BudgetLineRequest) -> Self {
+        Self {
+            name: request.name,
+            planned_amount: request.planned_amount,
+            notes: request.notes,
+        }
+    }
+}
+
+/// Records or replaces an expense; amounts are in øre
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ExpenseRequest {
+    /// Leave out for an expense that doesn't fit any budget line
+    pub budget_line_id: Option<Uuid>,
+    pub description: String,
+    pub vendor: Option<String>,
+    pub amount: i64,
+    pub incurred_on: chrono::NaiveDate,
+}
+
+impl From<ExpenseRequest> for crate::domain::services::ExpenseInput {
+    fn from(request: ExpenseRequest) -> Self {
+        Self {
+            budget_line_id: request.budget_line_id,
+            description: request.description,
+            vendor: request.vendor,
+            amount: request.amount,
+            incurred_on: request.incurred_on,
+        }
+    }
+}
+
+/// Multipart form for uploading a receipt (image or PDF)
+#[derive(Debug, ToSchema)]
+#[allow(dead_code)] // Documents the multipart field; the handler reads it directly
+pub struct UploadReceiptForm {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct BudgetLineResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub name: String,
+    pub planned_amount: i64,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<BudgetLine> for BudgetLineResponse {
+    fn from(line: BudgetLine) -> Self {
+        Self {
+            id: line.id,
+            event_id: line.event_id,
+            name: line.name,
+            planned_amount: line.planned_amount,
+            notes: line.notes,
+            created_at: line.created_at,
+            updated_at: line.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ReceiptResponse {
+    pub filename: String,
+    pub mime_type: String,
+    pub file_size: i64,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ExpenseResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub budget_line_id: Option<Uuid>,
+    pub description: String,
+    pub vendor: Option<String>,
+    pub amount: i64,
+    pub incurred_on: chrono::NaiveDate,
+    pub receipt: Option<ReceiptResponse>,
+    pub recorded_by_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<EventExpense> for ExpenseResponse {
+    fn from(expense: EventExpense) -> Self {
+        Self {
+            id: expense.id,
+            event_id: expense.event_id,
+            budget_line_id: expense.budget_line_id,
+            description: expense.description,
+            vendor: expense.vendor,
+            amount: expense.amount,
+            incurred_on: expense.incurred_on,
+            receipt: expense.receipt.map(|receipt| ReceiptResponse {
+                filename: receipt.original_filename,
+                mime_type: receipt.mime_type,
+                file_size: receipt.file_size,
+            }),
+            recorded_by_name: expense.recorded_by_name,
+            created_at: expense.created_at,
+            updated_at: expense.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct BudgetLineTotalsResponse {
+    pub budget_line_id: Uuid,
+    pub name: String,
+    pub planned: i64,
+    pub spent: i64,
+    /// Negative when the line is over budget
+    pub remaining: i64,
+}
+
+/// Planned against spent, in øre
+#[derive(Serialize, Debug, ToSchema)]
+pub struct BudgetSummaryResponse {
+    pub planned: i64,
+    pub spent: i64,
+    /// Negative when the event is over budget
+    pub remaining: i64,
+    pub over_budget: bool,
+    /// Spent on expenses not booked against any line
+    pub unassigned: i64,
+    pub expense_count: usize,
+    pub lines: Vec<BudgetLineTotalsResponse>,
+}
+
+impl From<BudgetSummary> for BudgetSummaryResponse {
+    fn from(summary: BudgetSummary) -> Self {
+        Self {
+            planned: summary.planned,
+            spent: summary.spent,
+            remaining: summary.remaining(),
+            over_budget: summary.is_over_budget(),
+            unassigned: summary.unassigned,
+            expense_count: summary.expense_count,
+            lines: summary
+                .lines
+                .into_iter()
+                .map(|line| BudgetLineTotalsResponse {
+                    remaining: line.remaining(),
+                    budget_line_id: line.budget_line_id,
+                    name: line.name,
+                    planned: line.planned,
+                    spent: line.spent,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventBudgetResponse {
+    /// Oldest first
+    pub lines: Vec<BudgetLineResponse>,
+    /// Oldest first by the date they were incurred
+    pub expenses: Vec<ExpenseResponse>,
+    pub summary: BudgetSummaryResponse,
+}
+
+impl From<crate::domain::services::EventBudget> for EventBudgetResponse {
+    fn from(budget: crate::domain::services::EventBudget) -> Self {
+        Self {
+            lines: budget.lines.into_iter().map(BudgetLineResponse::from).collect(),
+            expenses: budget.expenses.into_iter().map(ExpenseResponse::from).collect(),
+            summary: BudgetSummaryResponse::from(budget.summary),
+        }
+    }
+}
+
+// ============================================================================
+// Event Sponsor DTOs
+// ============================================================================
+
+/// Creates or replaces a sponsor
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct SponsorRequest {
+    pub name: String,
+    pub tier: SponsorTier,
+    /// Link to the sponsor's logo image (http or https)
+    pub logo_url: Option<String>,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    /// Position within the tier; new sponsors go last when left out
+    pub display_order: Option<i32>,
+}
+
+impl From<SponsorRequest> for crate::domain::services::SponsorInput {
+    fn from(request: SponsorRequest) -> Self {
+        Self {
+            name: request.name,
+            tier: request.tier,
+            logo_url: request.logo_url,
+            website_url: request.website_url,
+            description: request.description,
+            display_order: request.display_order,
+        }
+    }
+}
+
+/// Sets the display order to each sponsor's position in the list
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ReorderSponsorsRequest {
+    pub sponsor_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SponsorResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub name: String,
+    pub tier: SponsorTier,
+    pub logo_url: Option<String>,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    pub display_order: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<EventSponsor> for SponsorResponse {
+    fn from(sponsor: EventSponsor) -> Self {
+        Self {
+            id: sponsor.id,
+            event_id: sponsor.event_id,
+            name: sponsor.name,
+            tier: sponsor.tier,
+            logo_url: sponsor.logo_url,
+            website_url: sponsor.website_url,
+            description: sponsor.description,
+            display_order: sponsor.display_order,
+            created_at: sponsor.created_at,
+            updated_at: sponsor.updated_at,
+        }
+    }
+}
+
+// ============================================================================
+// Short Link DTOs
+// ============================================================================
+
+/// Creates a short link to the event's public page
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct CreateShortLinkRequest {
+    /// What the link is for, e.g. `LinkedIn post`
+    pub label: Option<String>,
+    pub utm_source: Option<String>,
+    pub utm_medium: Option<String>,
+    pub utm_campaign: Option<String>,
+    pub utm_content: Option<String>,
+}
+
+impl From<CreateShortLinkRequest> for crate::domain::services::ShortLinkInput {
+    fn from(request: CreateShortLinkRequest) -> Self {
+        Self {
+            label: request.label,
+            campaign: CampaignParameters {
+                source: request.utm_source,
+                medium: request.utm_medium,
+                campaign: request.utm_campaign,
+                content: request.utm_content,
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ShortLinkResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub code: String,
+    /// The address to share, e.g. `https://aqio.no/e/K7dx2Qm`
+    pub short_url: String,
+    pub label: Option<String>,
+    pub campaign: CampaignParameters,
+    pub clicks: i64,
+    pub last_clicked_at: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShortLinkResponse {
+    pub fn new(link: ShortLink, clicks: ShortLinkClicks, short_url: String) -> Self {
+        Self {
+            id: link.id,
+            event_id: link.event_id,
+            code: link.code,
+            short_url,
+            label: link.label,
+            campaign: link.campaign,
+            clicks: clicks.clicks,
+            last_clicked_at: clicks.last_clicked_at,
+            created_by: link.created_by,
+            created_at: link.created_at,
+        }
+    }
+}
+
+/// How a short link has been used
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ShortLinkStatsResponse {
+    pub link: ShortLinkResponse,
+    /// Clicks from invitations' personal copies of the link
+    pub invitation_clicks: i64,
+    /// Invitations whose personal copy was followed at least once
+    pub invitations_clicked: i64,
+    /// Days with clicks (UTC), oldest first
+    pub daily: Vec<DailyClicks>,
+    /// Referring sites, most clicks first; an empty host counts visitors
+    /// without a referrer
+    pub referrers: Vec<ReferrerClicks>,
+}
+
+impl ShortLinkStatsResponse {
+    pub fn new(link: ShortLink, stats: ShortLinkStats, short_url: String) -> Self {
+        let clicks = ShortLinkClicks {
+            link_id: link.id,
+            clicks: stats.clicks,
+            last_clicked_at: stats.last_clicked_at,
+        };
+        Self {
+            link: ShortLinkResponse::new(link, clicks, short_url),
+            invitation_clicks: stats.invitation_clicks,
+            invitations_clicked: stats.invitations_clicked,
+            daily: stats.daily,
+            referrers: stats.referrers,
+        }
+    }
+}
+
+/// An invitation's personal copy of a short link
+#[derive(Serialize, Debug, ToSchema)]
+pub struct InvitationShortLinkResponse {
+    pub invitation_id: Uuid,
+    pub url: String,
+}
+
+impl From<crate::domain::services::InvitationShortLink> for InvitationShortLinkResponse {
+    fn from(link: crate::domain::services::InvitationShortLink) -> Self {
+        Self {
+            invitation_id: link.invitation_id,
+            url: link.url,
+        }
+    }
+}
+
+/// Query of a followed short link
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct FollowShortLinkQuery {
+    /// Invitation token, set on invitations' personal copies of the link
+    pub i: Option<String>,
+    /// Page the visitor came from, passed on by the frontend when it sends
+    /// `/e/{code}` visitors here; the `Referer` header is used otherwise
+    pub r: Option<String>,
+}
+
+// ============================================================================
+// Overbooking DTOs
+// ============================================================================
+
+/// Sets the event's overbooking policy
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct SetOverbookingPolicyRequest {
+    /// Extra registrations accepted, in percent of the capacity; 0 to 50
+    pub percent: i32,
+    /// Registrations never go beyond this; at least the capacity
+    pub hard_ceiling: Option<i32>,
+}
+
+/// No-show rate of the organizer's recent past events and the overbooking
+/// it suggests
+#[derive(Serialize, Debug, ToSchema)]
+pub struct NoShowHistoryResponse {
+    /// Past events with registrations that were counted
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+    /// In percent; absent without registrations
+    pub no_show_rate: Option<f64>,
+    /// Absent with fewer than three past events
+    pub suggested_percent: Option<i32>,
+}
+
+impl From<NoShowHistory> for NoShowHistoryResponse {
+    fn from(history: NoShowHistory) -> Self {
+        Self {
+            events: history.events,
+            registrations: history.registrations,
+            checked_in: history.checked_in,
+            no_show_rate: history.no_show_rate().map(|rate| (rate * 10.0).round() / 10.0),
+            suggested_percent: history.suggested_percent(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OverbookingResponse {
+    pub event_id: Uuid,
+    /// `None` when the event isn't overbooked
+    pub policy: Option<OverbookingPolicy>,
+    /// The event's `max_attendees`
+    pub capacity: Option<i32>,
+    /// Registrations accepted before the waitlist starts
+    pub bookable_capacity: Option<i32>,
+    pub history: NoShowHistoryResponse,
+}
+
+impl OverbookingResponse {
+    pub fn new(event_id: Uuid, overview: crate::domain::services::OverbookingOverview) -> Self {
+        Self {
+            event_id,
+            policy: overview.policy,
+            capacity: overview.capacity,
+            bookable_capacity: overview.bookable_capacity,
+            history: overview.history.into(),
+        }
+    }
+}
+
+/// Realized attendance against the capacity
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OverbookingReportResponse {
+    pub event_id: Uuid,
+    pub overbooking_percent: Option<i32>,
+    pub capacity: Option<i32>,
+    pub bookable_capacity: Option<i32>,
+    /// Registrations that weren't cancelled or left on the waitlist
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub no_shows: i64,
+    /// Checked-in attendees in percent of the capacity
+    pub capacity_utilization: Option<f64>,
+    /// Attendees checked in beyond the capacity
+    pub over_capacity: i64,
+}
+
+impl OverbookingReportResponse {
+    pub fn new(event_id: Uuid, report: crate::domain::services::OverbookingReport) -> Self {
+        Self {
+            event_id,
+            overbooking_percent: report.policy.map(|policy| policy.percent),
+            capacity_utilization: report
+                .capacity
+                .filter(|capacity| *capacity > 0)
+                .map(|capacity| (report.checked_in as f64 * 1000.0 / capacity as f64).round() / 10.0),
+            capacity: report.capacity,
+            bookable_capacity: report.bookable_capacity,
+            registrations: report.registrations,
+            checked_in: report.checked_in,
+            no_shows: report.no_shows,
+            over_capacity: report.over_capacity,
+        }
+    }
+}
+
+// ============================================================================
+// Ticketing DTOs
+// ============================================================================
+
+/// A ticket type; `price` is in minor units of `currency` (øre, cents),
+/// VAT included
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct TicketTypeRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub price: i64,
+    /// NOK, EUR or USD
+    pub currency: Currency,
+    /// 0, 12, 15 or 25 percent; defaults to 0 for tickets not subject to VAT
+    pub vat_rate: Option<i32>,
+    /// Tickets for sale; absent for no limit
+    pub quantity: Option<i32>,
+    /// Defaults to on sale
+    pub is_active: Option<bool>,
+}
+
+impl From<TicketTypeRequest> for crate::domain::services::TicketTypeInput {
+    fn from(request: TicketTypeRequest) -> Self {
+        Self {
+            name: request.name,
+            description: request.description,
+            price: request.price,
+            currency: request.currency,
+            vat_rate: request.vat_rate.unwrap_or(0),
+            quantity: request.quantity,
+            is_active: request.is_active.unwrap_or(true),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct TicketTypeResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub price: i64,
+    pub currency: Currency,
+    pub vat_rate: i32,
+    pub quantity: Option<i32>,
+    pub is_active: bool,
+    pub sold: i64,
+    /// Absent when the type has no quantity limit
+    pub remaining: Option<i64>,
+}
+
+impl From<crate::domain::services::TicketTypeAvailability> for TicketTypeResponse {
+    fn from(availability: crate::domain::services::TicketTypeAvailability) -> Self {
+        let ticket_type = availability.ticket_type;
+        Self {
+            id: ticket_type.id,
+            event_id: ticket_type.event_id,
+            name: ticket_type.name,
+            description: ticket_type.description,
+            price: ticket_type.price,
+            currency: ticket_type.currency,
+            vat_rate: ticket_type.vat_rate,
+            quantity: ticket_type.quantity,
+            is_active: ticket_type.is_active,
+            sold: availability.sold,
+            remaining: availability.remaining,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct PurchaseTicketsRequest {
+    pub ticket_type_id: Uuid,
+    /// 1 to 10
+    pub quantity: i32,
+}
+
+/// Confirmed ticket sales of an event
+#[derive(Serialize, Debug, ToSchema)]
+pub struct TicketRevenueResponse {
+    pub event_id: Uuid,
+    /// One entry per currency sold in; amounts in its minor units
+    pub by_currency: Vec<CurrencyRevenue>,
+    pub orders: i64,
+    pub tickets: i64,
+    /// Everything in øre, at the exchange rate of each order's day
+    pub total_nok: i64,
+}
+
+impl TicketRevenueResponse {
+    pub fn new(event_id: Uuid, revenue: crate::domain::services::TicketRevenue) -> Self {
+        Self {
+            event_id,
+            by_currency: revenue.by_currency,
+            orders: revenue.orders,
+            tickets: revenue.tickets,
+            total_nok: revenue.total_nok,
+        }
+    }
+}
+
+// ============================================================================
+// Invoicing DTOs
+// ============================================================================
+
+/// The organization a ticket order is invoiced to
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct InvoiceRequest {
+    pub name: String,
+    /// Nine digits; also the organization's EHF address
+    pub org_number: String,
+    pub address: Option<String>,
+    pub postal_code: Option<String>,
+    pub city: Option<String>,
+    pub email: Option<String>,
+    /// E.g. a cost center or contact person
+    pub buyer_reference: String,
+}
+
+impl From<InvoiceRequest> for crate::domain::services::BillingDetails {
+    fn from(request: InvoiceRequest) -> Self {
+        Self {
+            name: request.name,
+            org_number: request.org_number,
+            address: request.address,
+            postal_code: request.postal_code,
+            city: request.city,
+            email: request.email,
+            buyer_reference: request.buyer_reference,
+        }
+    }
+}
+
+/// Which invoices to list
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct InvoiceListQuery {
+    /// Required unless the caller is an administrator
+    pub event_id: Option<Uuid>,
+    pub status: Option<InvoiceStatus>,
+    /// Only unpaid invoices past their due date
+    #[serde(default)]
+    pub overdue_only: bool,
+}
+
+impl From<InvoiceListQuery> for crate::domain::services::InvoiceQuery {
+    fn from(query: InvoiceListQuery) -> Self {
+        Self {
+            event_id: query.event_id,
+            status: query.status,
+            overdue_only: query.overdue_only,
+        }
+    }
+}
+
+/// An invoice; amounts are in minor units of `currency`
+#[derive(Serialize, Debug, ToSchema)]
+pub struct InvoiceResponse {
+    #[serde(flatten)]
+    pub invoice: Invoice,
+    pub is_overdue: bool,
+    /// 0 unless overdue
+    pub days_overdue: i64,
+}
+
+impl From<Invoice> for InvoiceResponse {
+    fn from(invoice: Invoice) -> Self {
+        let today = Utc::now().date_naive();
+        Self {
+            is_overdue: invoice.is_overdue(today),
+            days_overdue: invoice.days_overdue(today),
+            invoice,
+        }
+    }
+}
+
+// ============================================================================
+// Financial Report DTOs
+// ============================================================================
+
+/// Which month, and optionally which event, to report on
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct FinancialReportQuery {
+    pub year: i32,
+    /// 1 to 12
+    pub month: u32,
+    /// Required unless the caller is an administrator
+    pub event_id: Option<Uuid>,
+}
+
+/// Ticket sales and refunds in a month, in øre at each order's exchange rate
+#[derive(Serialize, Debug, ToSchema)]
+pub struct FinancialReportResponse {
+    pub year: i32,
+    pub month: u32,
+    /// Absent when the report covers every event
+    pub event_id: Option<Uuid>,
+    /// VAT included
+    pub sales_nok: i64,
+    pub sales_vat_nok: i64,
+    /// VAT included
+    pub refunds_nok: i64,
+    pub refunds_vat_nok: i64,
+    /// Sales less refunds, without VAT
+    pub net_revenue_nok: i64,
+    /// VAT on sales less VAT on refunds
+    pub vat_collected_nok: i64,
+    pub by_vat_rate: Vec<VatBreakdown>,
+}
+
+impl From<crate::domain::financial_report::FinancialReport> for FinancialReportResponse {
+    fn from(report: crate::domain::financial_report::FinancialReport) -> Self {
+        Self {
+            year: report.year,
+            month: report.month,
+            event_id: report.event_id,
+            sales_nok: report.sales_nok(),
+            sales_vat_nok: report.sales_vat_nok(),
+            refunds_nok: report.refunds_nok(),
+            refunds_vat_nok: report.refunds_vat_nok(),
+            net_revenue_nok: report.net_revenue_nok(),
+            vat_collected_nok: report.vat_collected_nok(),
+            by_vat_rate: report.by_vat_rate,
+        }
+    }
+}
+
+// ============================================================================
+// Refund DTOs
+// ============================================================================
+
+/// Refunds of a cancelled event's paid ticket orders, with a count per state
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventRefundsResponse {
+    pub event_id: Uuid,
+    pub pending: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// Oldest first
+    pub refunds: Vec<TicketRefund>,
+}
+
+impl EventRefundsResponse {
+    pub fn new(event_id: Uuid, refunds: Vec<TicketRefund>) -> Self {
+        let count = |status: RefundStatus| refunds.iter().filter(|refund| refund.status == status).count();
+        Self {
+            event_id,
+            pending: count(RefundStatus::Pending),
+            succeeded: count(RefundStatus::Succeeded),
+            failed: count(RefundStatus::Failed),
+            refunds,
+        }
+    }
+}
+
+// ============================================================================
+// Event Poster DTOs
+// ============================================================================
+
+/// Query of a poster download
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct PosterQuery {
+    /// Short link of the event for the QR code to use, so scans are counted
+    /// with its clicks; the QR code leads straight to the event page otherwise
+    pub short_link: Option<Uuid>,
+}
+
+// ============================================================================
+// Scheduling Rule DTOs
+// ============================================================================
+
+/// Replaces the organization's scheduling policy
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct SchedulingPolicyRequest {
+    /// Most events members may start on the same day; no limit when left out
+    pub max_events_per_day: Option<i32>,
+    pub allow_weekends: bool,
+    /// Days of notice required before an event starts
+    pub min_notice_days: Option<i32>,
+    pub enforcement: RuleEnforcement,
+}
+
+impl From<SchedulingPolicyRequest> for crate::domain::services::SchedulingPolicyInput {
+    fn from(request: SchedulingPolicyRequest) -> Self {
+        Self {
+            max_events_per_day: request.max_events_per_day,
+            allow_weekends: request.allow_weekends,
+            min_notice_days: request.min_notice_days,
+            enforcement: request.enforcement,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SchedulingPolicyResponse {
+    pub company_id: Uuid,
+    pub max_events_per_day: Option<i32>,
+    pub allow_weekends: bool,
+    pub min_notice_days: Option<i32>,
+    pub enforcement: RuleEnforcement,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<SchedulingPolicy> for SchedulingPolicyResponse {
+    fn from(policy: SchedulingPolicy) -> Self {
+        Self {
+            company_id: policy.company_id,
+            max_events_per_day: policy.max_events_per_day,
+            allow_weekends: policy.allow_weekends,
+            min_notice_days: policy.min_notice_days,
+            enforcement: policy.enforcement,
+            updated_at: policy.updated_at,
+        }
+    }
+}
+
+/// Creates or replaces a blackout period
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct BlackoutRequest {
+    pub name: String,
+    pub start_date: chrono::NaiveDate,
+    /// Last day of the period, inclusive
+    pub end_date: chrono::NaiveDate,
+    pub enforcement: RuleEnforcement,
+}
+
+impl From<BlackoutRequest> for crate::domain::services::BlackoutInput {
+    fn from(request: BlackoutRequest) -> Self {
+        Self {
+            name: request.name,
+            start_date: request.start_date,
+            end_date: request.end_date,
+            enforcement: request.enforcement,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct BlackoutResponse {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub name: String,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: chrono::NaiveDate,
+    pub enforcement: RuleEnforcement,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<BlackoutPeriod> for BlackoutResponse {
+    fn from(blackout: BlackoutPeriod) -> Self {
+        Self {
+            id: blackout.id,
+            company_id: blackout.company_id,
+            name: blackout.name,
+            start_date: blackout.start_date,
+            end_date: blackout.end_date,
+            enforcement: blackout.enforcement,
+            created_at: blackout.created_at,
+            updated_at: blackout.updated_at,
+        }
+    }
+}
+
+/// Dates to check against the caller's organization's scheduling rules
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ScheduleCheckRequest {
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    /// The event being moved, so it isn't counted against the daily limit
+    pub event_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ScheduleCheckResponse {
+    pub conflicts: Vec<ScheduleConflict>,
+    /// Whether any conflict keeps the event from being saved
+    pub blocked: bool,
+}
+
+impl From<Vec<ScheduleConflict>> for ScheduleCheckResponse {
+    fn from(conflicts: Vec<ScheduleConflict>) -> Self {
+        Self {
+            blocked: conflicts.iter().any(ScheduleConflict::is_blocking),
+            conflicts,
+        }
+    }
+}
+
+// ============================================================================
+// Custom Event Field DTOs
+// ============================================================================
+
+/// Creates or replaces a custom event field. The key and type are fixed once
+/// the field is created; send them unchanged when updating.
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct EventFieldRequest {
+    /// Lowercase letters, digits and underscores, e.g. `species_focus`
+    pub key: String,
+    pub label: String,
+    pub field_type: EventFieldType,
+    /// Choices of a `Select` field, in display order
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub is_required: bool,
+    /// After the organization's other fields when left out
+    pub position: Option<i32>,
+}
+
+impl From<EventFieldRequest> for crate::domain::services::EventFieldInput {
+    fn from(request: EventFieldRequest) -> Self {
+        Self {
+            key: request.key,
+            label: request.label,
+            field_type: request.field_type,
+            options: request.options,
+            is_required: request.is_required,
+            position: request.position,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventFieldResponse {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub key: String,
+    pub label: String,
+    pub field_type: EventFieldType,
+    pub options: Vec<String>,
+    pub is_required: bool,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<EventFieldDefinition> for EventFieldResponse {
+    fn from(definition: EventFieldDefinition) -> Self {
+        Self {
+            id: definition.id,
+            company_id: definition.company_id,
+            key: definition.key,
+            label: definition.label,
+            field_type: definition.field_type,
+            options: definition.options,
+            is_required: definition.is_required,
+            position: definition.position,
+            created_at: definition.created_at,
+            updated_at: definition.updated_at,
+        }
+    }
+}
+
+/// An event's custom field values, keyed by field key. Fields left out or
+/// blank are cleared.
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct EventFieldValuesRequest {
+    pub values: std::collections::HashMap<String, String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventFieldValueResponse {
+    pub field: EventFieldResponse,
+    /// In the field type's canonical form: numbers without trailing zeros,
+    /// dates as `YYYY-MM-DD`, booleans as `true` or `false`
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventFieldsResponse {
+    pub event_id: Uuid,
+    /// The organizer's organization, whose fields these are; `None` when the
+    /// organizer doesn't belong to one
+    pub company_id: Option<Uuid>,
+    pub fields: Vec<EventFieldValueResponse>,
+    /// Whether the caller may change the values
+    pub can_edit: bool,
+}
+
+impl From<crate::domain::services::EventFields> for EventFieldsResponse {
+    fn from(fields: crate::domain::services::EventFields) -> Self {
+        Self {
+            event_id: fields.event_id,
+            company_id: fields.company_id,
+            fields: fields
+                .fields
+                .into_iter()
+                .map(|entry| EventFieldValueResponse {
+                    field: EventFieldResponse::from(entry.definition),
+                    value: entry.value,
+                })
+                .collect(),
+            can_edit: fields.can_edit,
+        }
+    }
+}
+
+// ============================================================================
+// Storage Quota DTOs
+// ============================================================================
+
+/// Media the organization's events hold, against its quota
+#[derive(Serialize, Debug, ToSchema)]
+pub struct StorageUsageResponse {
+    pub company_id: Uuid,
+    pub attachment_bytes: i64,
+    pub receipt_bytes: i64,
+    pub photo_bytes: i64,
+    pub files: i64,
+    pub used_bytes: i64,
+    /// `null` when uploads are limited per file only
+    pub quota_bytes: Option<i64>,
+    pub remaining_bytes: Option<i64>,
+    /// Set by an administrator rather than the platform default
+    pub custom_quota: bool,
+}
+
+impl From<crate::domain::services::StorageReport> for StorageUsageResponse {
+    fn from(report: crate::domain::services::StorageReport) -> Self {
+        Self {
+            company_id: report.company_id,
+            attachment_bytes: report.usage.attachment_bytes,
+            receipt_bytes: report.usage.receipt_bytes,
+            photo_bytes: report.usage.photo_bytes,
+            files: report.usage.files,
+            used_bytes: report.usage.total_bytes(),
+            remaining_bytes: report.remaining_bytes(),
+            quota_bytes: report.quota_bytes,
+            custom_quota: report.custom_quota,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct StorageQuotaRequest {
+    /// `null` returns the organization to the platform default
+    pub quota_bytes: Option<i64>,
+}
+
+// ============================================================================
+// Sending Domain DTOs
+// ============================================================================
+
+/// Creates or replaces the organization's sending domain
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct SendingDomainRequest {
+    pub domain: String,
+    /// Sender address on `domain`
+    pub from_email: String,
+    pub from_name: Option<String>,
+    /// Defaults to `aqio`
+    pub dkim_selector: Option<String>,
+}
+
+impl From<SendingDomainRequest> for crate::domain::services::SendingDomainInput {
+    fn from(request: SendingDomainRequest) -> Self {
+        Self {
+            domain: request.domain,
+            from_email: request.from_email,
+            from_name: request.from_name,
+            dkim_selector: request.dkim_selector,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SendingDomainResponse {
+    pub company_id: Uuid,
+    pub domain: String,
+    pub from_email: String,
+    pub from_name: Option<String>,
+    pub dkim_selector: String,
+    pub status: DomainVerificationStatus,
+    /// Records to publish in the domain's DNS, with what the last check found
+    pub records: Vec<RequiredDnsRecord>,
+    pub last_checked_at: Option<DateTime<Utc>>,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SendingDomainResponse {
+    pub fn new(sending_domain: SendingDomain, platform: &MailPlatform) -> Self {
+        Self {
+            records: sending_domain.required_records(platform),
+            company_id: sending_domain.company_id,
+            domain: sending_domain.domain,
+            from_email: sending_domain.from_email,
+            from_name: sending_domain.from_name,
+            dkim_selector: sending_domain.dkim_selector,
+            status: sending_domain.status,
+            last_checked_at: sending_domain.last_checked_at,
+            verified_at: sending_domain.verified_at,
+            created_at: sending_domain.created_at,
+            updated_at: sending_domain.updated_at,
+        }
+    }
+}
+
+// ============================================================================
+// Certificate DTOs
+// ============================================================================
+
+/// Result of the public certificate check; the details are only filled in when `valid`
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CertificateVerificationResponse {
+    pub registration_id: Uuid,
+    /// Whether the registration exists and was checked in
+    pub valid: bool,
+    pub attendee_name: Option<String>,
+    pub event_title: Option<String>,
+    pub event_start_date: Option<DateTime<Utc>>,
+    pub event_end_date: Option<DateTime<Utc>>,
+    pub location_name: Option<String>,
+    pub organizer_name: Option<String>,
+    pub checked_in_at: Option<DateTime<Utc>>,
+}
+
+impl CertificateVerificationResponse {
+    pub fn new(registration_id: Uuid, details: Option<crate::domain::certificates::CertificateDetails>) -> Self {
+        match details {
+            Some(details) => Self {
+                registration_id,
+                valid: true,
+                attendee_name: Some(details.attendee_name),
+                event_title: Some(details.event_title),
+                event_start_date: Some(details.start_date),
+                event_end_date: Some(details.end_date),
+                location_name: details.location_name,
+                organizer_name: Some(details.organizer_name),
+                checked_in_at: Some(details.checked_in_at),
+            },
+            None => Self {
+                registration_id,
+                valid: false,
+                attendee_name: None,
+                event_title: None,
+                event_start_date: None,
+                event_end_date: None,
+                location_name: None,
+                organizer_name: None,
+                checked_in_at: None,
+            },
+        }
+    }
+}
+
+// ============================================================================
+// Catering Report DTOs
+// ============================================================================
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CateringGroupResponse {
+    /// The need, e.g. "Vegetarian", or the answer itself when it wasn't recognised
+    pub label: String,
+    pub registrations: usize,
+    /// Registrants plus their guests
+    pub people: usize,
+    /// The answers grouped under this need, as written
+    pub answers: Vec<String>,
+}
+
+impl From<crate::domain::catering::CateringGroup> for CateringGroupResponse {
+    fn from(group: crate::domain::catering::CateringGroup) -> Self {
+        Self {
+            label: group.label,
+            registrations: group.registrations,
+            people: group.people,
+            answers: group.answers,
+        }
+    }
+}
+
+/// Dietary and accessibility needs across confirmed registrations, most people first
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CateringReportResponse {
+    pub confirmed_registrations: usize,
+    pub guests: usize,
+    pub total_people: usize,
+    pub dietary: Vec<CateringGroupResponse>,
+    pub accessibility: Vec<CateringGroupResponse>,
+}
+
+impl From<crate::domain::catering::CateringReport> for CateringReportResponse {
+    fn from(report: crate::domain::catering::CateringReport) -> Self {
+        Self {
+            confirmed_registrations: report.confirmed_registrations,
+            guests: report.guests,
+            total_people: report.total_people(),
+            dietary: report.dietary.into_iter().map(CateringGroupResponse::from).collect(),
+            accessibility: report.accessibility.into_iter().map(CateringGroupResponse::from).collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Seating DTOs
+// ============================================================================
+
+/// Creates or replaces a table
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct SeatingTableRequest {
+    pub name: String,
+    /// Seats, counting each registrant and their guests
+    pub capacity: i32,
+    /// Reachable without steps, with room for a wheelchair
+    #[serde(default)]
+    pub accessible: bool,
+}
+
+impl From<SeatingTableRequest> for crate::domain::services::SeatingTableInput {
+    fn from(request: SeatingTableRequest) -> Self {
+        Self {
+            name: request.name,
+            capacity: request.capacity,
+            accessible: request.accessible,
+        }
+    }
+}
+
+/// Seats a registration and its guests at a table; `null` unseats them
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct SeatAssignmentRequest {
+    pub table_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SeatedPartyResponse {
+    pub registration_id: Uuid,
+    pub name: String,
+    pub company: Option<String>,
+    pub guest_names: Vec<String>,
+    /// The registrant plus their guests
+    pub seats: usize,
+    pub accessibility_needs: Vec<String>,
+}
+
+impl From<crate::domain::seating::SeatedParty> for SeatedPartyResponse {
+    fn from(party: crate::domain::seating::SeatedParty) -> Self {
+        Self {
+            registration_id: party.registration_id,
+            name: party.name,
+            company: party.company,
+            guest_names: party.guest_names,
+            seats: party.seats,
+            accessibility_needs: party.accessibility_needs,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PlannedTableResponse {
+    pub table: SeatingTable,
+    pub seats_taken: usize,
+    pub parties: Vec<SeatedPartyResponse>,
+}
+
+impl From<crate::domain::seating::PlannedTable> for PlannedTableResponse {
+    fn from(planned: crate::domain::seating::PlannedTable) -> Self {
+        Self {
+            seats_taken: planned.seats_taken(),
+            table: planned.table,
+            parties: planned.parties.into_iter().map(SeatedPartyResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SeatingWarningResponse {
+    /// `over_capacity`, `company_split` or `needs_accessible_table`
+    pub kind: String,
+    pub message: String,
+    /// The tables concerned, to highlight in the planner
+    pub table_ids: Vec<Uuid>,
+    pub registration_id: Option<Uuid>,
+}
+
+impl From<crate::domain::seating::SeatingWarning> for SeatingWarningResponse {
+    fn from(warning: crate::domain::seating::SeatingWarning) -> Self {
+        use crate::domain::seating::SeatingWarning;
+
+        let (table_ids, registration_id) = match &warning {
+            SeatingWarning::OverCapacity { table_id, .. } => (vec![*table_id], None),
+            SeatingWarning::CompanySplit { table_ids, .. } => (table_ids.clone(), None),
+            SeatingWarning::NeedsAccessibleTable { registration_id, table_id, .. } => {
+                (vec![*table_id], Some(*registration_id))
+            }
+        };
+        Self {
+            kind: warning.kind().to_string(),
+            message: warning.message(),
+            table_ids,
+            registration_id,
+        }
+    }
+}
+
+/// The tables in floor-plan order, who is still without a seat, and
+/// what the organizer may want to rearrange
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SeatingPlanResponse {
+    pub capacity: usize,
+    pub seated_people: usize,
+    pub unassigned_people: usize,
+    pub tables: Vec<PlannedTableResponse>,
+    pub unassigned: Vec<SeatedPartyResponse>,
+    pub warnings: Vec<SeatingWarningResponse>,
+}
+
+impl From<crate::domain::seating::SeatingPlan> for SeatingPlanResponse {
+    fn from(plan: crate::domain::seating::SeatingPlan) -> Self {
+        Self {
+            capacity: plan.capacity(),
+            seated_people: plan.seated_people(),
+            unassigned_people: plan.unassigned_people(),
+            tables: plan.tables.into_iter().map(PlannedTableResponse::from).collect(),
+            unassigned: plan.unassigned.into_iter().map(SeatedPartyResponse::from).collect(),
+            warnings: plan.warnings.into_iter().map(SeatingWarningResponse::from).collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Resource DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct ListResourcesQuery {
+    /// Also list retired resources; administrators only
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+/// Creates or replaces a resource
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ResourceRequest {
+    pub name: String,
+    pub description: Option<String>,
+    /// Where it's kept or moored
+    pub location: Option<String>,
+    /// Defaults to `true`; retired resources can't be booked
+    pub is_active: Option<bool>,
+}
+
+impl From<ResourceRequest> for crate::domain::services::ResourceInput {
+    fn from(request: ResourceRequest) -> Self {
+        Self {
+            name: request.name,
+            description: request.description,
+            location: request.location,
+            is_active: request.is_active.unwrap_or(true),
+        }
+    }
+}
+
+/// Replaces a resource's availability; no windows makes it bookable at any time
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ResourceAvailabilityRequest {
+    pub windows: Vec<AvailabilityWindow>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ResourceResponse {
+    pub resource: Resource,
+    pub windows: Vec<AvailabilityWindow>,
+}
+
+impl From<(Resource, Vec<AvailabilityWindow>)> for ResourceResponse {
+    fn from((resource, windows): (Resource, Vec<AvailabilityWindow>)) -> Self {
+        Self { resource, windows }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct ResourceBookingsQuery {
+    /// Defaults to now
+    pub from: Option<DateTime<Utc>>,
+    /// Defaults to 30 days after `from`
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ResourceConflictsRequest {
+    pub resource_ids: Vec<Uuid>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ResourceConflictsResponse {
+    /// Whether all the resources can be booked for the slot
+    pub available: bool,
+    pub conflicts: Vec<ResourceConflict>,
+}
+
+impl From<Vec<ResourceConflict>> for ResourceConflictsResponse {
+    fn from(conflicts: Vec<ResourceConflict>) -> Self {
+        Self {
+            available: conflicts.is_empty(),
+            conflicts,
+        }
+    }
+}
+
+/// Books resources for an event, by default for the whole event
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct BookResourcesRequest {
+    pub event_id: Uuid,
+    pub resource_ids: Vec<Uuid>,
+    /// Defaults to the event's start
+    pub starts_at: Option<DateTime<Utc>>,
+    /// Defaults to the event's end
+    pub ends_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct EventResourceBookingsQuery {
+    pub event_id: Uuid,
+}
+
+// ============================================================================
+// Shift DTOs
+// ============================================================================
+
+/// Creates or replaces a shift
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ShiftRequest {
+    /// What the staff on the shift do, e.g. "Registration desk"
+    pub role: String,
+    pub description: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// How many people the shift needs
+    pub headcount: i32,
+}
+
+impl From<ShiftRequest> for crate::domain::services::ShiftInput {
+    fn from(request: ShiftRequest) -> Self {
+        Self {
+            role: request.role,
+            description: request.description,
+            starts_at: request.starts_at,
+            ends_at: request.ends_at,
+            headcount: request.headcount,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ShiftStaffResponse {
+    pub user_id: Uuid,
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct StaffedShiftResponse {
+    pub shift: EventShift,
+    /// In signup order
+    pub staff: Vec<ShiftStaffResponse>,
+    /// People still needed to reach the headcount
+    pub missing: usize,
+}
+
+impl From<crate::domain::shifts::StaffedShift> for StaffedShiftResponse {
+    fn from(staffed: crate::domain::shifts::StaffedShift) -> Self {
+        Self {
+            missing: staffed.missing(),
+            shift: staffed.shift,
+            staff: staffed
+                .staff
+                .into_iter()
+                .map(|staff| ShiftStaffResponse {
+                    user_id: staff.user_id,
+                    name: staff.name,
+                    email: staff.email,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The event's shifts in time order, with how many people are still needed
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ShiftCoverageResponse {
+    pub needed: usize,
+    pub filled: usize,
+    pub missing: usize,
+    pub shifts: Vec<StaffedShiftResponse>,
+    /// The shifts still short of people, earliest first
+    pub gaps: Vec<Uuid>,
+}
+
+impl From<crate::domain::shifts::CoverageReport> for ShiftCoverageResponse {
+    fn from(report: crate::domain::shifts::CoverageReport) -> Self {
+        Self {
+            needed: report.needed(),
+            filled: report.filled(),
+            missing: report.missing(),
+            gaps: report.gaps().map(|staffed| staffed.shift.id).collect(),
+            shifts: report.shifts.into_iter().map(StaffedShiftResponse::from).collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Exhibitor DTOs
+// ============================================================================
+
+/// Creates or replaces a booth on the floor plan; positions and sizes are in
+/// metres from the hall's top-left corner
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct BoothRequest {
+    /// e.g. "B12"
+    pub number: String,
+    pub hall: Option<String>,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub depth: f64,
+}
+
+impl From<BoothRequest> for crate::domain::services::BoothInput {
+    fn from(request: BoothRequest) -> Self {
+        Self {
+            number: request.number,
+            hall: request.hall,
+            x: request.x,
+            y: request.y,
+            width: request.width,
+            depth: request.depth,
+        }
+    }
+}
+
+/// Registers or updates an exhibitor
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ExhibitorRequest {
+    pub company_name: String,
+    /// The registered company whose members scan leads at the stand
+    pub company_id: Option<Uuid>,
+    pub contact_name: Option<String>,
+    pub contact_email: Option<String>,
+    pub website_url: Option<String>,
+    /// Shown on the public exhibitor list
+    pub description: Option<String>,
+}
+
+impl From<ExhibitorRequest> for crate::domain::services::ExhibitorInput {
+    fn from(request: ExhibitorRequest) -> Self {
+        Self {
+            company_name: request.company_name,
+            company_id: request.company_id,
+            contact_name: request.contact_name,
+            contact_email: request.contact_email,
+            website_url: request.website_url,
+            description: request.description,
+        }
+    }
+}
+
+/// Gives the exhibitor a booth; a missing or null `booth_id` takes its booth away
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct AllocateBoothRequest {
+    pub booth_id: Option<Uuid>,
+}
+
+/// An exhibitor as listed on the public event page, without contact details
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PublicExhibitorResponse {
+    pub id: Uuid,
+    pub company_name: String,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    pub booth_number: Option<String>,
+    pub hall: Option<String>,
+}
+
+impl From<(Exhibitor, Option<Booth>)> for PublicExhibitorResponse {
+    fn from((exhibitor, booth): (Exhibitor, Option<Booth>)) -> Self {
+        let (booth_number, hall) = match booth {
+            Some(booth) => (Some(booth.number), booth.hall),
+            None => (None, None),
+        };
+        Self {
+            id: exhibitor.id,
+            company_name: exhibitor.company_name,
+            website_url: exhibitor.website_url,
+            description: exhibitor.description,
+            booth_number,
+            hall,
+        }
+    }
+}
+
+// ============================================================================
+// Lead DTOs
+// ============================================================================
+
+/// A scanned attendee badge
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ScanLeadRequest {
+    /// The badge's QR payload: the registration ID or a link ending in it
+    pub code: String,
+    pub notes: Option<String>,
+}
+
+/// A lead with how to reach the attendee
+#[derive(Serialize, Debug, ToSchema)]
+pub struct LeadResponse {
+    pub id: Uuid,
+    pub registration_id: Uuid,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub company: Option<String>,
+    pub notes: Option<String>,
+    pub scanned_by: Uuid,
+    pub scanned_at: DateTime<Utc>,
+}
+
+impl From<crate::domain::leads::CapturedLead> for LeadResponse {
+    fn from(captured: crate::domain::leads::CapturedLead) -> Self {
+        Self {
+            id: captured.lead.id,
+            registration_id: captured.lead.registration_id,
+            name: captured.contact.name,
+            email: captured.contact.email,
+            phone: captured.contact.phone,
+            company: captured.contact.company,
+            notes: captured.lead.notes,
+            scanned_by: captured.lead.scanned_by,
+            scanned_at: captured.lead.scanned_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ExhibitorLeadCountResponse {
+    pub exhibitor_id: Uuid,
+    pub company_name: String,
+    pub leads: usize,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HourlyLeadCountResponse {
+    /// Start of the hour, in UTC
+    pub hour: DateTime<Utc>,
+    pub leads: usize,
+}
+
+/// Lead scanning across the event's exhibitors
+#[derive(Serialize, Debug, ToSchema)]
+pub struct LeadAnalyticsResponse {
+    pub total: usize,
+    /// Attendees scanned by at least one exhibitor
+    pub unique_attendees: usize,
+    /// Every exhibitor, most leads first
+    pub by_exhibitor: Vec<ExhibitorLeadCountResponse>,
+    /// Hours with scans, earliest first
+    pub by_hour: Vec<HourlyLeadCountResponse>,
+}
+
+impl From<crate::domain::leads::LeadAnalytics> for LeadAnalyticsResponse {
+    fn from(analytics: crate::domain::leads::LeadAnalytics) -> Self {
+        Self {
+            total: analytics.total,
+            unique_attendees: analytics.unique_attendees,
+            by_exhibitor: analytics
+                .by_exhibitor
+                .into_iter()
+                .map(|count| ExhibitorLeadCountResponse {
+                    exhibitor_id: count.exhibitor_id,
+                    company_name: count.company_name,
+                    leads: count.leads,
+                })
+                .collect(),
+            by_hour: analytics
+                .by_hour
+                .into_iter()
+                .map(|count| HourlyLeadCountResponse {
+                    hour: count.hour,
+                    leads: count.leads,
+                })
+                .collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Matchmaking DTOs
+// ============================================================================
+
+/// Joins an event's matchmaking or updates the profile
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct MatchmakingProfileRequest {
+    /// What the attendee wants to talk about, e.g. "aquaculture sensors"
+    #[serde(default)]
+    pub interests: Vec<String>,
+    pub bio: Option<String>,
+    /// When the attendee is free for meetings, during the event
+    #[serde(default)]
+    pub availability: Vec<AvailabilityWindow>,
+}
+
+impl From<MatchmakingProfileRequest> for crate::domain::services::MatchmakingProfileInput {
+    fn from(request: MatchmakingProfileRequest) -> Self {
+        Self {
+            interests: request.interests,
+            bio: request.bio,
+            availability: request.availability,
+        }
+    }
+}
+
+/// Another attendee suggested for a meeting
+#[derive(Serialize, Debug, ToSchema)]
+pub struct MatchResponse {
+    pub user_id: Uuid,
+    pub name: String,
+    pub company: Option<String>,
+    pub bio: Option<String>,
+    pub interests: Vec<String>,
+    /// The interests both attendees have
+    pub shared_interests: Vec<String>,
+    pub availability: Vec<AvailabilityWindow>,
+}
+
+impl From<crate::domain::matchmaking::Match> for MatchResponse {
+    fn from(found: crate::domain::matchmaking::Match) -> Self {
+        Self {
+            user_id: found.profile.user_id,
+            name: found.name,
+            company: found.company,
+            bio: found.profile.bio,
+            interests: found.profile.interests,
+            shared_interests: found.shared_interests,
+            availability: found.profile.availability,
+        }
+    }
+}
+
+/// Replaces the resources the event's meetings are held in
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct MeetingRoomsRequest {
+    pub resource_ids: Vec<Uuid>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct RequestMeetingRequest {
+    pub invitee_id: Uuid,
+    pub message: Option<String>,
+}
+
+/// A meeting as listed for one of its attendees
+#[derive(Serialize, Debug, ToSchema)]
+pub struct MeetingResponse {
+    pub id: Uuid,
+    pub requester_id: Uuid,
+    pub requester_name: String,
+    pub invitee_id: Uuid,
+    pub invitee_name: String,
+    pub message: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub resource_id: Option<Uuid>,
+    /// The meeting room's name
+    pub room: Option<String>,
+    pub status: MeetingStatus,
+}
+
+impl From<crate::domain::matchmaking::MeetingDetails> for MeetingResponse {
+    fn from(details: crate::domain::matchmaking::MeetingDetails) -> Self {
+        Self {
+            id: details.meeting.id,
+            requester_id: details.meeting.requester_id,
+            requester_name: details.requester_name,
+            invitee_id: details.meeting.invitee_id,
+            invitee_name: details.invitee_name,
+            message: details.meeting.message,
+            starts_at: details.meeting.starts_at,
+            ends_at: details.meeting.ends_at,
+            resource_id: details.meeting.resource_id,
+            room: details.room,
+            status: details.meeting.status,
+        }
+    }
+}
+
+// ============================================================================
+// Consent DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ConfigureConsentRequest {
+    /// Shown next to the checkbox; changing it starts a new version
+    pub text: String,
+    #[serde(default)]
+    pub required: bool,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ConsentTotalsResponse {
+    pub consent: EventConsent,
+    pub accepted: usize,
+    /// Accepted an earlier version of the text
+    pub accepted_earlier_version: usize,
+    pub declined: usize,
+    /// Registered before the checkbox was added
+    pub unanswered: usize,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ConsentRowResponse {
+    pub registration_id: Uuid,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub status: RegistrationStatus,
+    pub answers: Vec<RegistrationConsent>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ConsentReportResponse {
+    /// Totals over registrations that aren't cancelled, per current checkbox
+    pub totals: Vec<ConsentTotalsResponse>,
+    pub registrations: Vec<ConsentRowResponse>,
+}
+
+impl From<crate::domain::consents::ConsentReport> for ConsentReportResponse {
+    fn from(report: crate::domain::consents::ConsentReport) -> Self {
+        Self {
+            totals: report
+                .totals
+                .into_iter()
+                .map(|totals| ConsentTotalsResponse {
+                    consent: totals.consent,
+                    accepted: totals.accepted,
+                    accepted_earlier_version: totals.accepted_earlier_version,
+                    declined: totals.declined,
+                    unanswered: totals.unanswered,
+                })
+                .collect(),
+            registrations: report
+                .rows
+                .into_iter()
+                .map(|row| ConsentRowResponse {
+                    registration_id: row.registration_id,
+                    name: row.registrant.name,
+                    email: row.registrant.email,
+                    status: row.status,
+                    answers: row.answers,
+                })
+                .collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Broadcast DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct BroadcastRequest {
+    /// All, Confirmed, Waitlisted or CheckedIn; everyone not cancelled by default
+    pub audience: Option<BroadcastAudience>,
+    pub subject: String,
+    /// Plain text; blank lines separate paragraphs
+    pub message: String,
+    /// Send later instead of right away; must be in the future
+    pub scheduled_for: Option<DateTime<Utc>>,
+}
+
+impl From<BroadcastRequest> for crate::domain::services::BroadcastInput {
+    fn from(request: BroadcastRequest) -> Self {
+        Self {
+            audience: request.audience.unwrap_or(BroadcastAudience::All),
+            subject: request.subject,
+            message: request.message,
+            scheduled_for: request.scheduled_for,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct BroadcastPreviewResponse {
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+    /// Registrants the broadcast would go to, one per email address
+    pub recipients: usize,
+    /// Of those, addresses skipped because they bounced or complained before
+    pub suppressed: usize,
+}
+
+impl From<crate::domain::services::BroadcastPreview> for BroadcastPreviewResponse {
+    fn from(preview: crate::domain::services::BroadcastPreview) -> Self {
+        Self {
+            subject: preview.email.subject,
+            text_body: preview.email.text_body,
+            html_body: preview.email.html_body,
+            recipients: preview.recipients,
+            suppressed: preview.suppressed,
+        }
+    }
+}
+
+// ============================================================================
+// Message DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ContactOrganizersRequest {
+    /// Plain text
+    pub body: String,
+    /// The broadcast being answered, if any
+    pub broadcast_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ReplyMessageRequest {
+    /// Plain text
+    pub body: String,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct MessageThreadResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_title: String,
+    pub attendee_id: Uuid,
+    pub attendee_name: String,
+    /// The caller is on the organizers' side of the thread
+    pub as_organizer: bool,
+    /// Messages the caller's side hasn't read
+    pub unread: i32,
+    pub last_message_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<crate::domain::services::InboxThread> for MessageThreadResponse {
+    fn from(inbox: crate::domain::services::InboxThread) -> Self {
+        let unread = inbox.unread();
+        let thread = inbox.thread;
+        Self {
+            id: thread.id,
+            event_id: thread.event_id,
+            event_title: inbox.event_title,
+            attendee_id: thread.attendee_id,
+            attendee_name: inbox.attendee_name,
+            as_organizer: inbox.as_organizer,
+            unread,
+            last_message_at: thread.last_message_at,
+            created_at: thread.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct MessageThreadDetailsResponse {
+    pub thread: MessageThreadResponse,
+    /// Oldest first
+    pub messages: Vec<ThreadMessage>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UnreadMessagesResponse {
+    pub unread: i64,
+}
+
+// ============================================================================
+// Past Event DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct PastEventsQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub title_contains: Option<String>,
+    pub category_id: Option<String>,
+    pub organizer_id: Option<Uuid>,
+    /// Only events that started in this calendar year
+    pub year: Option<i32>,
+}
+
+impl PastEventsQuery {
+    pub fn to_filter_and_pagination(&self) -> ApiResult<(PastEventFilter, PaginationParams)> {
+        let filter = PastEventFilter {
+            title_contains: self.title_contains.clone(),
+            category_id: self.category_id.clone(),
+            organizer_id: self.organizer_id,
+            from_year: self.year,
+            to_year: self.year,
+            visible_to: None,
+        };
+
+        let pagination = PaginationQuery {
+            page: self.page,
+            limit: self.limit,
+        }
+        .to_pagination_params()?;
+
+        Ok((filter, pagination))
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct AttendanceTrendsQuery {
+    /// `category` (the default), `organizer` or `county`
+    pub group_by: Option<ArchiveGrouping>,
+    pub category_id: Option<String>,
+    pub organizer_id: Option<Uuid>,
+    /// First and last calendar year to include
+    pub from_year: Option<i32>,
+    pub to_year: Option<i32>,
+}
+
+impl AttendanceTrendsQuery {
+    pub fn to_filter(&self) -> PastEventFilter {
+        PastEventFilter {
+            category_id: self.category_id.clone(),
+            organizer_id: self.organizer_id,
+            from_year: self.from_year,
+            to_year: self.to_year,
+            ..Default::default()
+        }
+    }
+}
+
+/// A past event with how its registrations turned out
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PastEventResponse {
+    pub event_id: Uuid,
+    pub title: String,
+    pub category_id: String,
+    pub category_name: Option<String>,
+    pub organizer_id: Uuid,
+    pub organizer_name: Option<String>,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub status: EventStatus,
+    /// Registrations that weren't cancelled or left on the waitlist
+    pub registrations: i64,
+    pub checked_in: i64,
+    /// Registrations that never checked in
+    pub no_shows: i64,
+    pub cancelled: i64,
+    /// Share of registrations that checked in, in percent
+    pub attendance_rate: Option<f64>,
+}
+
+impl From<EventSummary> for PastEventResponse {
+    fn from(summary: EventSummary) -> Self {
+        let counts = &summary.registration_counts;
+        let registrations = counts.registered + counts.attended;
+        Self {
+            registrations,
+            checked_in: summary.checked_in,
+            no_shows: (registrations - summary.checked_in).max(0),
+            cancelled: counts.cancelled,
+            attendance_rate: crate::domain::past_events::attendance_rate(registrations, summary.checked_in),
+            event_id: summary.event_id,
+            title: summary.title,
+            category_id: summary.category_id,
+            category_name: summary.category_name,
+            organizer_id: summary.organizer_id,
+            organizer_name: summary.organizer_name,
+            start_date: summary.start_date,
+            end_date: summary.end_date,
+            status: summary.status,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PaginatedPastEventResponse {
+    pub items: Vec<PastEventResponse>,
+    pub pagination: PaginationInfo,
+}
+
+impl PaginatedPastEventResponse {
+    pub fn from_paginated_result(result: PaginatedResult<EventSummary>) -> Self {
+        Self {
+            pagination: PaginationInfo::from_paginated_result(&result),
+            items: result.items.into_iter().map(PastEventResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct TrendYearResponse {
+    pub year: i32,
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub cancelled: i64,
+    /// Share of registrations that checked in, in percent
+    pub attendance_rate: Option<f64>,
+    /// Change from the calendar year before, in percent; `None` when there
+    /// were no events to compare with
+    pub registrations_change: Option<f64>,
+    pub checked_in_change: Option<f64>,
+}
+
+impl From<crate::domain::past_events::TrendYear> for TrendYearResponse {
+    fn from(year: crate::domain::past_events::TrendYear) -> Self {
+        Self {
+            year: year.year,
+            events: year.events,
+            registrations: year.registrations,
+            checked_in: year.checked_in,
+            cancelled: year.cancelled,
+            attendance_rate: year.attendance_rate,
+            registrations_change: year.registrations_change,
+            checked_in_change: year.checked_in_change,
+        }
+    }
+}
+
+/// One category's or organizer's past years, oldest first
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AttendanceTrendResponse {
+    /// Category ID or organizer user ID
+    pub group_id: String,
+    pub group_name: Option<String>,
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub attendance_rate: Option<f64>,
+    pub years: Vec<TrendYearResponse>,
+}
+
+impl From<crate::domain::past_events::AttendanceTrend> for AttendanceTrendResponse {
+    fn from(trend: crate::domain::past_events::AttendanceTrend) -> Self {
+        Self {
+            attendance_rate: crate::domain::past_events::attendance_rate(trend.registrations, trend.checked_in),
+            group_id: trend.group_id,
+            group_name: trend.group_name,
+            events: trend.events,
+            registrations: trend.registrations,
+            checked_in: trend.checked_in,
+            years: trend.years.into_iter().map(TrendYearResponse::from).collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Annual Report DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct AnnualReportQuery {
+    /// Calendar year to report on; the last full year when left out
+    pub year: Option<i32>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AnnualTotalsResponse {
+    pub year: i32,
+    pub events: i64,
+    /// Registrations that weren't cancelled or left on the waitlist
+    pub registrations: i64,
+    /// Registrations that checked in
+    pub attendance: i64,
+    pub cancelled: i64,
+    /// Share of registrations that checked in, in percent
+    pub attendance_rate: Option<f64>,
+}
+
+impl From<crate::domain::annual_report::YearTotals> for AnnualTotalsResponse {
+    fn from(totals: crate::domain::annual_report::YearTotals) -> Self {
+        Self {
+            year: totals.year,
+            events: totals.events,
+            registrations: totals.registrations,
+            attendance: totals.checked_in,
+            cancelled: totals.cancelled,
+            attendance_rate: totals.attendance_rate,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AnnualGrowthResponse {
+    /// Changes from the year before, in percent; `None` when the year before
+    /// had nothing to compare with
+    pub events: Option<f64>,
+    pub registrations: Option<f64>,
+    pub attendance: Option<f64>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ReportCategoryResponse {
+    pub category_id: String,
+    pub category_name: Option<String>,
+    pub events: i64,
+    pub registrations: i64,
+    pub attendance: i64,
+    pub attendance_rate: Option<f64>,
+}
+
+impl From<crate::domain::annual_report::CategoryLine> for ReportCategoryResponse {
+    fn from(category: crate::domain::annual_report::CategoryLine) -> Self {
+        Self {
+            category_id: category.category_id,
+            category_name: category.category_name,
+            events: category.events,
+            registrations: category.registrations,
+            attendance: category.checked_in,
+            attendance_rate: category.attendance_rate,
+        }
+    }
+}
+
+/// Survey answers for the year's events; averages are `None` without answers
+#[derive(Serialize, Debug, ToSchema)]
+pub struct SatisfactionResponse {
+    pub responses: i64,
+    /// Overall rating, 1 to 5
+    pub average_rating: Option<f64>,
+    /// Respondents who would recommend the event, in percent
+    pub recommend_rate: Option<f64>,
+    /// Likelihood to attend again, 1 to 10
+    pub average_return_likelihood: Option<f64>,
+}
+
+impl From<crate::domain::annual_report::Satisfaction> for SatisfactionResponse {
+    fn from(satisfaction: crate::domain::annual_report::Satisfaction) -> Self {
+        Self {
+            responses: satisfaction.responses,
+            average_rating: satisfaction.average_rating,
+            recommend_rate: satisfaction.recommend_rate,
+            average_return_likelihood: satisfaction.average_return_likelihood,
+        }
+    }
+}
+
+/// An organization's year: its members' past events, drafts and cancelled
+/// events left out, next to the year before
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AnnualReportResponse {
+    pub company_id: Uuid,
+    pub company_name: String,
+    pub generated_at: DateTime<Utc>,
+    pub current: AnnualTotalsResponse,
+    pub previous: AnnualTotalsResponse,
+    pub growth: AnnualGrowthResponse,
+    /// The categories with the most attendance, at most five
+    pub top_categories: Vec<ReportCategoryResponse>,
+    pub satisfaction: SatisfactionResponse,
+}
+
+impl From<crate::domain::annual_report::AnnualReport> for AnnualReportResponse {
+    fn from(report: crate::domain::annual_report::AnnualReport) -> Self {
+        Self {
+            company_id: report.company_id,
+            company_name: report.company_name,
+            generated_at: report.generated_at,
+            current: AnnualTotalsResponse::from(report.current),
+            previous: AnnualTotalsResponse::from(report.previous),
+            growth: AnnualGrowthResponse {
+                events: report.events_growth,
+                registrations: report.registrations_growth,
+                attendance: report.attendance_growth,
+            },
+            top_categories: report.top_categories.into_iter().map(ReportCategoryResponse::from).collect(),
+            satisfaction: SatisfactionResponse::from(report.satisfaction),
+        }
+    }
+}
+
+// ============================================================================
+// Health Check DTOs
+// ============================================================================
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HealthResponse {
+    pub status: String,
+    pub timestamp: DateTime<Utc>,
+    pub version: String,
+    pub services: HealthServices,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HealthServices {
+    pub database: ServiceHealth,
+    pub auth: ServiceHealth,
+    /// Circuit breakers of the external services called since startup
+    pub integrations: Vec<IntegrationHealth>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct IntegrationHealth {
+    /// e.g. `sendgrid`, `webhook:hooks.example.no` or `dns:cloudflare-dns.com`
+    pub destination: String,
+    /// `closed`, `open` (calls fail fast) or `half_open` (next call is a trial)
+    pub state: String,
+    pub consecutive_failures: u32,
+    pub open_until: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ServiceHealth {
+    pub status: String,
+    pub details: Option<String>,
+}
+
+impl HealthResponse {
+    pub fn healthy() -> Self {
+        Self {
+            status: "healthy".to_string(),
+            timestamp: Utc::now(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            services: HealthServices {
+                database: ServiceHealth {
+                    status: "healthy".to_string(),
+                    details: None,
+                },
+                auth: ServiceHealth {
+                    status: "healthy".to_string(),
+                    details: None,
+                },
+                integrations: Vec::new(),
+            },
+        }
+    }
+
+    pub fn unhealthy(details: impl Into<String>) -> Self {
+        Self {
+            status: "unhealthy".to_string(),
+            timestamp: Utc::now(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            services: HealthServices {
+                database: ServiceHealth {
+                    status: "unknown".to_string(),
+                    details: Some(details.into()),
+                },
+                auth: ServiceHealth {
+                    status: "unknown".to_string(),
+                    details: None,
+                },
+                integrations: Vec::new(),
+            },
+        }
+    }
+
+    /// Adds the integrations; an open breaker makes a healthy service `degraded`
+    pub fn with_integrations(mut self, integrations: Vec<IntegrationHealth>) -> Self {
+        if self.status == "healthy" && integrations.iter().any(|i| i.state == "open") {
+            self.status = "degraded".to_string();
+        }
+        self.services.integrations = integrations;
+        self
+    }
+}
+
+// ============================================================================
+// Invitation DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct CreateInvitationRequest {
+    pub invited_user_id: Option<Uuid>,
+    pub invited_email: Option<String>,
+    pub invited_name: Option<String>,
+    pub invitation_method: InvitationMethod,
+    pub personal_message: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl CreateInvitationRequest {
+    pub fn to_domain_invitation(&self, event_id: Uuid, inviter_id: Uuid) -> ApiResult<EventInvitation> {
+        let now = Utc::now();
+        let invitation = EventInvitation {
+            id: Uuid::new_v4(),
+            event_id,
+            invited_user_id: self.invited_user_id,
+            invited_contact_id: None,
+            invited_email: self.invited_email.clone(),
+            invited_name: self.invited_name.clone(),
+            inviter_id,
+            invitation_method: self.invitation_method.clone(),
+            personal_message: self.personal_message.clone(),
+            status: InvitationStatus::Pending,
+            sent_at: None,
+            opened_at: None,
+            clicked_at: None,
+            responded_at: None,
+            delivery_failure: None,
+            delivery_failed_at: None,
+            invitation_token: Some(InvitationService::new().generate_invitation_token()),
+            expires_at: self.expires_at,
+            created_at: now,
+            updated_at: now,
+        };
+
+        // Validate using domain rules
+        invitation
+            .validate_for_creation()
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        Ok(invitation)
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct UpdateInvitationStatusRequest {
+    pub status: InvitationStatus,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct InvitationResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub invited_user_id: Option<Uuid>,
+    pub invited_email: Option<String>,
+    pub invited_name: Option<String>,
+    pub inviter_id: Uuid,
+    pub invitation_method: InvitationMethod,
+    pub personal_message: Option<String>,
+    pub status: InvitationStatus,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub opened_at: Option<DateTime<Utc>>,
+    pub clicked_at: Option<DateTime<Utc>>,
+    pub responded_at: Option<DateTime<Utc>>,
+    /// Set once the email bounced or was reported as spam; it can't be resent
+    pub delivery_failure: Option<DeliveryFailure>,
+    pub delivery_failed_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<EventInvitation> for InvitationResponse {
+    fn from(inv: EventInvitation) -> Self {
+        Self {
+            id: inv.id,
+            event_id: inv.event_id,
+            invited_user_id: inv.invited_user_id,
+            invited_email: inv.invited_email,
+            invited_name: inv.invited_name,
+            inviter_id: inv.inviter_id,
+            invitation_method: inv.invitation_method,
+            personal_message: inv.personal_message,
+            status: inv.status,
+            sent_at: inv.sent_at,
+            opened_at: inv.opened_at,
+            clicked_at: inv.clicked_at,
+            responded_at: inv.responded_at,
+            delivery_failure: inv.delivery_failure,
+            delivery_failed_at: inv.delivery_failed_at,
+            expires_at: inv.expires_at,
+            created_at: inv.created_at,
+            updated_at: inv.updated_at,
+        }
+    }
+}
+
+/// URLs to embed in an invitation email so opens and clicks are recorded
+#[derive(Serialize, Debug, ToSchema)]
+pub struct InvitationTrackingResponse {
+    /// 1x1 image that records the first open
+    pub pixel_url: String,
+    /// Tracked link to the event's public page
+    pub event_url: String,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct TrackedLinkRequest {
+    /// Where the recipient should end up; http or https
+    pub url: String,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct TrackedLinkResponse {
+    pub url: String,
+}
+
+/// Query of a tracked link as it arrives from the recipient's browser
+#[derive(Deserialize, Debug)]
+pub struct TrackedLinkQuery {
+    pub url: String,
+    pub sig: String,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct InvitationFunnelResponse {
+    /// Invitations that weren't cancelled
+    pub invited: i64,
+    pub sent: i64,
+    pub opened: i64,
+    pub clicked: i64,
+    pub accepted: i64,
+    pub declined: i64,
+    /// Share of sent invitations that were opened, 0.0-1.0
+    pub open_rate: f64,
+    /// Share of sent invitations with a followed link, 0.0-1.0
+    pub click_rate: f64,
+    /// Share of sent invitations that were accepted, 0.0-1.0
+    pub acceptance_rate: f64,
+}
+
+impl From<InvitationFunnel> for InvitationFunnelResponse {
+    fn from(funnel: InvitationFunnel) -> Self {
+        let rate = |count: i64| {
+            if funnel.sent == 0 {
+                0.0
+            } else {
+                count as f64 / funnel.sent as f64
+            }
+        };
+        Self {
+            open_rate: rate(funnel.opened),
+            click_rate: rate(funnel.clicked),
+            acceptance_rate: rate(funnel.accepted),
+            invited: funnel.invited,
+            sent: funnel.sent,
+            opened: funnel.opened,
+            clicked: funnel.clicked,
+            accepted: funnel.accepted,
+            declined: funnel.declined,
+        }
+    }
+}
+
+// ============================================================================
+// Email Delivery DTOs
+// ============================================================================
+
+/// The shared secret configured as `EMAIL_WEBHOOK_SECRET`, appended to the
+/// webhook URL given to the mail provider
+#[derive(Deserialize, Debug, IntoParams)]
+pub struct EmailWebhookQuery {
+    pub token: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct DeliveryReportResponse {
+    /// Addresses that weren't suppressed before
+    pub suppressed: usize,
+    /// Invitations newly marked as undeliverable
+    pub invitations_marked: usize,
+}
+
+impl From<crate::domain::services::DeliveryReportOutcome> for DeliveryReportResponse {
+    fn from(outcome: crate::domain::services::DeliveryReportOutcome) -> Self {
+        Self {
+            suppressed: outcome.suppressed,
+            invitations_marked: outcome.invitations_marked,
+        }
+    }
+}
+
+// ============================================================================
+// Notification Preference DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct NotificationPreferencesRequest {
+    /// `Daily` or `Weekly` batches non-urgent notifications into one email;
+    /// `Immediate` turns the digest off
+    pub digest_frequency: DigestFrequency,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct NotificationPreferencesResponse {
+    pub digest_frequency: DigestFrequency,
+    pub last_digest_at: Option<DateTime<Utc>>,
+    /// Earliest time the next digest goes out; `None` without a digest
+    pub next_digest_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<NotificationPreferences> for NotificationPreferencesResponse {
+    fn from(preferences: NotificationPreferences) -> Self {
+        let next_digest_at = preferences
+            .digest_frequency
+            .period()
+            .map(|period| preferences.last_digest_at.map_or_else(Utc::now, |last| last + period));
+        Self {
+            digest_frequency: preferences.digest_frequency,
+            last_digest_at: preferences.last_digest_at,
+            next_digest_at,
+            updated_at: preferences.updated_at,
+        }
+    }
+}
+
+// ============================================================================
+// Event Registration DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct CreateRegistrationRequest {
+    pub registrant_email: Option<String>,
+    pub registrant_name: Option<String>,
+    pub registrant_phone: Option<String>,
+    pub registrant_company: Option<String>,
+    pub guest_count: Option<i32>,
+    pub guest_names: Option<Vec<String>>,
+    pub dietary_restrictions: Option<String>,
+    pub accessibility_needs: Option<String>,
+    pub special_requests: Option<String>,
+    pub custom_responses: Option<String>,
+    /// Answers to the event's consent checkboxes; one left out counts as declined
+    pub consents: Option<Vec<ConsentAnswerRequest>>,
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ConsentAnswerRequest {
+    pub kind: ConsentKind,
+    pub accepted: bool,
+    /// Version of the text shown; refused with 409 if it has changed since
+    pub version: Option<i32>,
+}
+
+impl CreateRegistrationRequest {
+    pub fn consent_answers(&self) -> Vec<crate::domain::services::ConsentAnswer> {
+        self.consents
+            .iter()
+            .flatten()
+            .map(|answer| crate::domain::services::ConsentAnswer {
+                kind: answer.kind,
+                accepted: answer.accepted,
+                version: answer.version,
+            })
+            .collect()
+    }
+
+    pub fn to_domain_registration(
+        &self,
+        event_id: Uuid,
+        user_id: Option<Uuid>,
+        invitation_id: Option<Uuid>,
+    ) -> ApiResult<EventRegistration> {
+        let now = Utc::now();
+        
+        // Validate required fields
+        if user_id.is_none() && self.registrant_email.is_none() {
+            return Err(ApiError::validation(
+                "registrant_email",
+                "Email is required for non-authenticated users",
+            ));
+        }
+
+        if user_id.is_none() && self.registrant_name.is_none() {
+            return Err(ApiError::validation(
+                "registrant_name", 
+                "Name is required for non-authenticated users",
+            ));
+        }
+
+        let guest_count = self.guest_count.unwrap_or(0);
+        if guest_count < 0 || guest_count > 10 {
+            return Err(ApiError::validation(
+                "guest_count",
+                "Guest count must be between 0 and 10",
+            ));
+        }
+
+        let guest_names = self.guest_names.clone().unwrap_or_default();
+        if guest_names.len() != guest_count as usize {
+            return Err(ApiError::validation(
+                "guest_names",
+                "Number of guest names must match guest count",
+            ));
+        }
+
+        Ok(EventRegistration {
+            id: Uuid::new_v4(),
+            event_id,
+            invitation_id,
+            user_id,
+            external_contact_id: None,
+            registrant_email: self.registrant_email.clone(),
+            registrant_name: self.registrant_name.clone(),
+            registrant_phone: self.registrant_phone.clone(),
+            registrant_company: self.registrant_company.clone(),
+            status: RegistrationStatus::Registered,
+            registration_source: if invitation_id.is_some() {
+                RegistrationSource::Invitation
+            } else {
+                RegistrationSource::Direct
+            },
+            guest_count,
+            guest_names,
+            dietary_restrictions: self.dietary_restrictions.clone(),
+            accessibility_needs: self.accessibility_needs.clone(),
+            special_requests: self.special_requests.clone(),
+            custom_responses: self.custom_responses.clone(),
+            registered_at: now,
+            cancelled_at: None,
+            checked_in_at: None,
+            waitlist_position: None,
+            waitlist_added_at: None,
+            promotion_expires_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct UpdateRegistrationRequest {
+    pub registrant_email: Option<String>,
+    pub registrant_name: Option<String>,
+    pub registrant_phone: Option<String>,
+    pub registrant_company: Option<String>,
+    pub guest_count: Option<i32>,
+    pub guest_names: Option<Vec<String>>,
+    pub dietary_restrictions: Option<String>,
+    pub accessibility_needs: Option<String>,
+    pub special_requests: Option<String>,
+    pub custom_responses: Option<String>,
+}
+
+impl UpdateRegistrationRequest {
+    pub fn apply_to_registration(self, mut registration: EventRegistration) -> ApiResult<EventRegistration> {
+        if let Some(email) = self.registrant_email {
+            registration.registrant_email = Some(email);
+        }
+
+        if let Some(name) = self.registrant_name {
+            registration.registrant_name = Some(name);
+        }
+
+        if let Some(phone) = self.registrant_phone {
+            registration.registrant_phone = Some(phone);
+        }
+
+        if let Some(company) = self.registrant_company {
+            registration.registrant_company = Some(company);
+        }
+
+        if let Some(guest_count) = self.guest_count {
+            if guest_count < 0 || guest_count > 10 {
+                return Err(ApiError::validation(
+                    "guest_count",
+                    "Guest count must be between 0 and 10",
+                ));
+            }
+            registration.guest_count = guest_count;
+        }
+
+        if let Some(guest_names) = self.guest_names {
+            if guest_names.len() != registration.guest_count as usize {
+                return Err(ApiError::validation(
+                    "guest_names",
+                    "Number of guest names must match guest count",
+                ));
+            }
+            registration.guest_names = guest_names;
+        }
+
+        if let Some(dietary) = self.dietary_restrictions {
+            registration.dietary_restrictions = Some(dietary);
+        }
+
+        if let Some(accessibility) = self.accessibility_needs {
+            registration.accessibility_needs = Some(accessibility);
+        }
+
+        if let Some(requests) = self.special_requests {
+            registration.special_requests = Some(requests);
+        }
+
+        if let Some(responses) = self.custom_responses {
+            registration.custom_responses = Some(responses);
+        }
+
+        registration.updated_at = Utc::now();
+        Ok(registration)
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct UpdateRegistrationStatusRequest {
+    pub status: RegistrationStatus,
+}
+
+/// Registrations of the same person to fold into the earliest of them
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct MergeRegistrationsRequest {
+    pub registration_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct RegistrationResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub invitation_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub registrant_email: Option<String>,
+    pub registrant_name: Option<String>,
+    pub registrant_phone: Option<String>,
+    pub registrant_company: Option<String>,
+    pub status: RegistrationStatus,
+    pub registration_source: RegistrationSource,
+    pub guest_count: i32,
+    pub guest_names: Vec<String>,
+    pub dietary_restrictions: Option<String>,
+    pub accessibility_needs: Option<String>,
+    pub special_requests: Option<String>,
+    pub custom_responses: Option<String>,
+    pub registered_at: DateTime<Utc>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub checked_in_at: Option<DateTime<Utc>>,
+    pub waitlist_position: Option<i32>,
+    pub waitlist_added_at: Option<DateTime<Utc>>,
+    /// Deadline for confirming a spot offered from the waitlist
+    pub promotion_expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<EventRegistration> for RegistrationResponse {
+    fn from(registration: EventRegistration) -> Self {
+        Self {
+            id: registration.id,
+            event_id: registration.event_id,
+            invitation_id: registration.invitation_id,
+            user_id: registration.user_id,
+            registrant_email: registration.registrant_email,
+            registrant_name: registration.registrant_name,
+            registrant_phone: registration.registrant_phone,
+            registrant_company: registration.registrant_company,
+            status: registration.status,
+            registration_source: registration.registration_source,
+            guest_count: registration.guest_count,
+            guest_names: registration.guest_names,
+            dietary_restrictions: registration.dietary_restrictions,
+            accessibility_needs: registration.accessibility_needs,
+            special_requests: registration.special_requests,
+            custom_responses: registration.custom_responses,
+            registered_at: registration.registered_at,
+            cancelled_at: registration.cancelled_at,
+            checked_in_at: registration.checked_in_at,
+            waitlist_position: registration.waitlist_position,
+            waitlist_added_at: registration.waitlist_added_at,
+            promotion_expires_at: registration.promotion_expires_at,
+            created_at: registration.created_at,
+            updated_at: registration.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventRegistrationStatsResponse {
+    pub total_registered: usize,
+    pub total_attended: usize,
+    pub total_waitlisted: usize,
+    pub total_cancelled: usize,
+    /// Budget totals; only included for the event's organizers and administrators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetSummaryResponse>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct WaitlistStatusResponse {
+    pub registration: RegistrationResponse,
+    /// 1-based position on the waitlist, absent once the registration has left it
+    pub position: Option<usize>,
+    pub waitlist_size: usize,
+}
+
+impl From<crate::domain::services::WaitlistStatus> for WaitlistStatusResponse {
+    fn from(status: crate::domain::services::WaitlistStatus) -> Self {
+        Self {
+            registration: RegistrationResponse::from(status.registration),
+            position: status.position,
+            waitlist_size: status.waitlist_size,
+        }
+    }
+}
+// ============================================================================
+// Public Event DTOs
+// ============================================================================
+
+/// One slot in the event program
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct AgendaItem {
+    /// Local time as entered by the organizer, e.g. "09:30"
+    pub time: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    pub speaker: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct Speaker {
+    pub name: String,
+    pub title: Option<String>,
+    pub company: Option<String>,
+    pub bio: Option<String>,
+    pub photo_url: Option<String>,
+}
+
+/// A hotel recommended to attendees
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct Hotel {
+    pub name: String,
+    pub address: Option<String>,
+    pub website_url: Option<String>,
+    /// Walking time to the venue
+    pub walking_minutes: Option<u32>,
+    /// Code that books a room from the event's block
+    pub booking_code: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TransportMode {
+    Flight,
+    Train,
+    Bus,
+    Ferry,
+    Taxi,
+    Shuttle,
+    Other,
+}
+
+/// One way of getting to the venue, e.g. the airport bus
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct TransportOption {
+    pub mode: TransportMode,
+    pub title: String,
+    pub details: Option<String>,
+    /// Timetable or booking page
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, ToSchema)]
+pub struct ParkingInfo {
+    pub address: Option<String>,
+    #[serde(default)]
+    pub free_of_charge: bool,
+    #[serde(default)]
+    pub ev_charging: bool,
+    pub notes: Option<String>,
+}
+
+/// Accommodation and travel information for attendees; every section is optional
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, ToSchema)]
+pub struct TravelInfo {
+    #[serde(default)]
+    pub hotels: Vec<Hotel>,
+    #[serde(default)]
+    pub transport: Vec<TransportOption>,
+    pub parking: Option<ParkingInfo>,
+}
+
+impl TravelInfo {
+    pub fn is_empty(&self) -> bool {
+        self.hotels.is_empty() && self.transport.is_empty() && self.parking.is_none()
+    }
+
+    pub fn from_custom_fields(custom_fields: Option<&str>) -> Self {
+        EventProgram::from_custom_fields(custom_fields).travel
+    }
+
+    /// `custom_fields` with the travel section replaced, keeping everything else in it
+    pub fn merge_into_custom_fields(&self, custom_fields: Option<&str>) -> ApiResult<Option<String>> {
+        let mut fields = match custom_fields.map(str::trim).filter(|json| !json.is_empty()) {
+            Some(json) => match serde_json::from_str::<serde_json::Value>(json) {
+                Ok(serde_json::Value::Object(fields)) => fields,
+                _ => {
+                    return Err(ApiError::validation(
+                        "custom_fields",
+                        "The event's custom fields aren't a JSON object, so travel information can't be added",
+                    ));
+                }
+            },
+            None => serde_json::Map::new(),
+        };
+        if self.is_empty() {
+            fields.remove("travel");
+        } else {
+            let travel = serde_json::to_value(self).map_err(|e| ApiError::internal(e.to_string()))?;
+            fields.insert("travel".to_string(), travel);
+        }
+        if fields.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::Value::Object(fields).to_string()))
+    }
+}
+
+/// Where an attendee travels to an event from
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct TravelOptionsQuery {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Journeys to suggest, 3 by default and at most 5
+    pub limit: Option<u32>,
+}
+
+/// Public transport journeys to an event's venue, ferries included
+#[derive(Serialize, Debug, ToSchema)]
+pub struct TravelOptionsResponse {
+    pub event_id: Uuid,
+    /// Journeys arrive by this time, shortly before the event starts; absent
+    /// once the event is under way and journeys leave now
+    pub arrive_by: Option<DateTime<Utc>>,
+    pub options: Vec<TravelOptionResponse>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct TravelOptionResponse {
+    #[serde(flatten)]
+    pub option: TravelOption,
+    pub uses_ferry: bool,
+    pub transfers: usize,
+}
+
+impl From<TravelOption> for TravelOptionResponse {
+    fn from(option: TravelOption) -> Self {
+        Self {
+            uses_ferry: option.uses_ferry(),
+            transfers: option.transfers(),
+            option,
+        }
+    }
+}
+
+// Program content organizers keep in the event's `custom_fields` JSON, e.g.
+// `{"agenda": [{"time": "09:00", "title": "Welcome"}], "speakers": [{"name": "..."}],
+// "travel": {"hotels": [{"name": "..."}]}}`
+#[derive(Deserialize, Default)]
+struct EventProgram {
+    #[serde(default)]
+    agenda: Vec<AgendaItem>,
+    #[serde(default)]
+    speakers: Vec<Speaker>,
+    #[serde(default)]
+    travel: TravelInfo,
+}
+
+impl EventProgram {
+    fn from_custom_fields(custom_fields: Option<&str>) -> Self {
+        custom_fields
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Event as shown to anonymous visitors on the public event page
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PublicEventResponse {
+    pub id: Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub category_id: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub timezone: String,
+    pub location_type: LocationType,
+    pub location_name: Option<String>,
+    pub address: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub image_url: Option<String>,
+    pub agenda: Vec<AgendaItem>,
+    pub speakers: Vec<Speaker>,
+    pub travel: TravelInfo,
+    pub allow_guests: bool,
+    pub max_guests_per_person: Option<i32>,
+    pub collect_dietary_info: bool,
+    pub collect_accessibility_info: bool,
+    pub registration_open: bool,
+    /// Remaining spots; absent when the event has no capacity limit
+    pub spots_left: Option<i32>,
+    pub waitlist_available: bool,
+    /// Public attachments only
+    pub attachments: Vec<AttachmentResponse>,
+    /// Highest tier first
+    pub sponsors: Vec<SponsorResponse>,
+    /// Approved photos, oldest first; empty unless the organizers made the album public
+    pub photos: Vec<PhotoResponse>,
+    /// By company name
+    pub exhibitors: Vec<PublicExhibitorResponse>,
+    /// schema.org `Event` markup (JSON-LD) for search engines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub structured_data: Option<serde_json::Value>,
+}
+
+impl PublicEventResponse {
+    pub fn new(
+        event: Event,
+        confirmed_registrations: usize,
+        attachments: Vec<EventAttachment>,
+        sponsors: Vec<EventSponsor>,
+        photos: Vec<EventPhoto>,
+    ) -> Self {
+        let event_service = EventService::new();
+        let program = EventProgram::from_custom_fields(event.custom_fields.as_deref());
+
+        Self {
+            registration_open: event_service.can_register_for_event(&event).is_ok(),
+            spots_left: event_service.calculate_available_spots(&event, confirmed_registrations),
+            waitlist_available: event.allow_waitlist,
+            id: event.id,
+            slug: event.slug,
+            title: event.title,
+            description: event.description,
+            category_id: event.category_id,
+            start_date: event.start_date,
+            end_date: event.end_date,
+            timezone: event.timezone,
+            location_type: event.location_type,
+            location_name: event.location_name,
+            address: event.address,
+            latitude: event.latitude,
+            longitude: event.longitude,
+            image_url: event.image_url,
+            agenda: program.agenda,
+            speakers: program.speakers,
+            travel: program.travel,
+            allow_guests: event.allow_guests,
+            max_guests_per_person: event.max_guests_per_person,
+            collect_dietary_info: event.collect_dietary_info,
+            collect_accessibility_info: event.collect_accessibility_info,
+            attachments: attachments
+                .into_iter()
+                .filter(EventAttachment::is_public)
+                .map(AttachmentResponse::from)
+                .collect(),
+            sponsors: sponsors.into_iter().map(SponsorResponse::from).collect(),
+            photos: photos
+                .into_iter()
+                .filter(EventPhoto::is_approved)
+                .map(|photo| PhotoResponse::new(photo, false))
+                .collect(),
+            exhibitors: Vec::new(),
+            structured_data: None,
+        }
+    }
+
+    pub fn with_exhibitors(mut self, exhibitors: Vec<(Exhibitor, Option<Booth>)>) -> Self {
+        self.exhibitors = exhibitors.into_iter().map(PublicExhibitorResponse::from).collect();
+        self
+    }
+
+    pub fn with_structured_data(mut self, structured_data: serde_json::Value) -> Self {
+        self.structured_data = Some(structured_data);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct GuestRegistrationResponse {
+    pub registration_id: Uuid,
+    pub status: RegistrationStatus,
+    pub waitlist_position: Option<i32>,
+}
+
+impl From<EventRegistration> for GuestRegistrationResponse {
+    fn from(registration: EventRegistration) -> Self {
+        Self {
+            registration_id: registration.id,
+            status: registration.status,
+            waitlist_position: registration.waitlist_position,
+        }
+    }
+}
+
+// ============================================================================
+// Client Error Report DTOs
+// ============================================================================
+
+/// An error the web client showed to a user, reported so it can be found in the logs
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ClientErrorReport {
+    /// Reference shown to the user, quoted when they contact support
+    pub reference: String,
+    pub message: String,
+    /// Client-side route the error happened on
+    pub route: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl ClientErrorReport {
+    pub fn validate(&self) -> ApiResult<()> {
+        if self.reference.trim().is_empty() {
+            return Err(ApiError::validation("reference", "Reference cannot be empty"));
+        }
+
+        if self.reference.len() > 64 {
+            return Err(ApiError::validation("reference", "Reference cannot exceed 64 characters"));
+        }
+
+        if self.message.trim().is_empty() {
+            return Err(ApiError::validation("message", "Message cannot be empty"));
+        }
+
+        if self.message.len() > 4000 {
+            return Err(ApiError::validation("message", "Message cannot exceed 4000 characters"));
+        }
+
+        if self.route.as_ref().is_some_and(|route| route.len() > 2000) {
+            return Err(ApiError::validation("route", "Route cannot exceed 2000 characters"));
+        }
+
+        if self.user_agent.as_ref().is_some_and(|agent| agent.len() > 500) {
+            return Err(ApiError::validation("user_agent", "User agent cannot exceed 500 characters"));
+        }
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Client Log DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ClientLogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+/// A log line written by the web client
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ClientLogEntry {
+    pub level: ClientLogLevel,
+    /// Module or area that logged it, e.g. `aqio::api`
+    pub target: String,
+    pub message: String,
+    /// `X-Request-Id` of the API call the line is about, matching the server's logs
+    pub request_id: Option<String>,
+    /// Client-side route the line was logged on
+    pub route: Option<String>,
+}
+
+/// Log lines the web client ships to the server, at the level set by `CLIENT_LOG_LEVEL`
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ClientLogBatch {
+    /// Version of the web client
+    pub release: String,
+    pub entries: Vec<ClientLogEntry>,
+}
+
+impl ClientLogBatch {
+    pub fn validate(&self) -> ApiResult<()> {
+        if self.release.len() > 64 {
+            return Err(ApiError::validation("release", "Release cannot exceed 64 characters"));
+        }
+
+        if self.entries.is_empty() {
+            return Err(ApiError::validation("entries", "At least one log entry is required"));
+        }
+
+        if self.entries.len() > 100 {
+            return Err(ApiError::validation("entries", "Cannot send more than 100 log entries at once"));
+        }
+
+        for entry in &self.entries {
+            if entry.target.len() > 200 {
+                return Err(ApiError::validation("target", "Target cannot exceed 200 characters"));
+            }
+
+            if entry.message.len() > 4000 {
+                return Err(ApiError::validation("message", "Message cannot exceed 4000 characters"));
+            }
+
+            if entry.request_id.as_ref().is_some_and(|id| id.len() > 64) {
+                return Err(ApiError::validation("request_id", "Request ID cannot exceed 64 characters"));
+            }
+
+            if entry.route.as_ref().is_some_and(|route| route.len() > 2000) {
+                return Err(ApiError::validation("route", "Route cannot exceed 2000 characters"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Web Vitals DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct WebVitalMeasurement {
+    pub metric: WebVitalMetric,
+    /// Milliseconds, except for the unitless layout shift
+    pub value: f64,
+}
+
+/// Measurements the web client took on one page
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct WebVitalsReport {
+    /// Version of the web app, e.g. "0.4.0"
+    pub release: String,
+    /// Client-side route; a query string is dropped
+    pub route: Option<String>,
+    pub measurements: Vec<WebVitalMeasurement>,
+}
+
+impl From<WebVitalsReport> for crate::domain::services::WebVitalsReportInput {
+    fn from(report: WebVitalsReport) -> Self {
+        Self {
+            release: report.release,
+            route: report.route,
+            measurements: report
+                .measurements
+                .into_iter()
+                .map(|measurement| (measurement.metric, measurement.value))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct WebVitalsQuery {
+    /// How many days back to summarize; defaults to 7
+    pub days: Option<i64>,
+}
+
+/// Percentiles of one metric reported by one release
+#[derive(Serialize, Debug, ToSchema)]
+pub struct WebVitalSummaryResponse {
+    pub release: String,
+    pub metric: WebVitalMetric,
+    pub samples: usize,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+    /// When the release reported its first sample
+    pub first_seen: DateTime<Utc>,
+}
+
+impl From<crate::domain::web_vitals::WebVitalSummary> for WebVitalSummaryResponse {
+    fn from(summary: crate::domain::web_vitals::WebVitalSummary) -> Self {
+        Self {
+            release: summary.release,
+            metric: summary.metric,
+            samples: summary.samples,
+            p50: summary.p50,
+            p75: summary.p75,
+            p95: summary.p95,
+            first_seen: summary.first_seen,
+        }
+    }
+}
+
+// ============================================================================
+// Client Configuration DTOs
+// ============================================================================
+
+/// How the frontend signs users in
+#[derive(Serialize, Debug, ToSchema)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum AuthModeResponse {
+    /// Development logins at `/auth/login?username=...`
+    Mock,
+    Keycloak { realm_url: String, client_id: String },
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct BrandingResponse {
+    pub name: String,
+    pub logo_url: Option<String>,
+    /// `#rrggbb`; the frontend keeps its own colors when `null`
+    pub primary_color: Option<String>,
+}
+
+/// Settings the frontend reads at startup
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ClientConfigResponse {
+    pub auth: AuthModeResponse,
+    pub branding: BrandingResponse,
+    /// Languages the API writes emails and calendar files in
+    pub locales: Vec<Locale>,
+    /// Used when neither the user nor the browser picks one of `locales`
+    pub default_locale: Locale,
+    /// Largest attachment accepted, in bytes
+    pub max_upload_bytes: usize,
+    /// Features switched on or off by name; the frontend decides what an
+    /// unknown or missing flag means
+    pub features: std::collections::BTreeMap<String, bool>,
+    /// Lowest level (`error`, `warn`, `info` or `debug`) of log line to send to
+    /// `/api/v1/telemetry/logs`; missing when the client should only log locally
+    pub remote_log_level: Option<String>,
+}
+
+impl From<&crate::config::ClientConfig> for ClientConfigResponse {
+    fn from(config: &crate::config::ClientConfig) -> Self {
+        Self {
+            auth: match &config.auth {
+                crate::config::AuthMode::Mock => AuthModeResponse::Mock,
+                crate::config::AuthMode::Keycloak { realm_url, client_id } => AuthModeResponse::Keycloak {
+                    realm_url: realm_url.clone(),
+                    client_id: client_id.clone(),
+                },
+            },
+            branding: BrandingResponse {
+                name: config.branding.name.clone(),
+                logo_url: config.branding.logo_url.clone(),
+                primary_color: config.branding.primary_color.clone(),
+            },
+            locales: Locale::ALL.to_vec(),
+            default_locale: Locale::default(),
+            max_upload_bytes: crate::domain::services::EventAttachmentApplicationService::MAX_FILE_BYTES,
+            features: config.features.clone(),
+            remote_log_level: config.remote_log_level.clone(),
+        }
+    }
+}
+
+// ============================================================================
+// Audit Log DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct AuditLogQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub table_name: Option<String>,
+    pub record_id: Option<String>,
+    pub user_id: Option<Uuid>,
+    pub action: Option<AuditAction>,
+}
+
+impl AuditLogQuery {
+    pub fn to_filter_and_pagination(&self) -> ApiResult<(AuditLogFilter, PaginationParams)> {
+        let filter = AuditLogFilter {
+            table_name: self.table_name.clone(),
+            record_id: self.record_id.clone(),
+            user_id: self.user_id,
+            action: self.action.clone(),
+        };
+
+        let pagination = PaginationQuery {
+            page: self.page,
+            limit: self.limit,
+        }
+        .to_pagination_params()?;
+
+        Ok((filter, pagination))
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct AuditLogEntryResponse {
+    pub id: Uuid,
+    pub table_name: String,
+    pub record_id: String,
+    pub action: AuditAction,
+    pub user_id: Option<Uuid>,
+    pub user_name: Option<String>,
+    pub user_email: Option<String>,
+    #[schema(value_type = Option<Object>)]
+    pub old_values: Option<serde_json::Value>,
+    #[schema(value_type = Option<Object>)]
+    pub new_values: Option<serde_json::Value>,
+    pub changed_fields: Vec<String>,
+    pub event_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuditLogEntry> for AuditLogEntryResponse {
+    fn from(entry: AuditLogEntry) -> Self {
+        // Snapshots are stored as JSON text; expose them as objects
+        let parse = |raw: Option<String>| raw.and_then(|raw| serde_json::from_str(&raw).ok());
+
+        Self {
+            id: entry.id,
+            table_name: entry.table_name,
+            record_id: entry.record_id,
+            action: entry.action,
+            user_id: entry.user_id,
+            user_name: entry.user_name,
+            user_email: entry.user_email,
+            old_values: parse(entry.old_values),
+            new_values: parse(entry.new_values),
+            changed_fields: entry.changed_fields,
+            event_id: entry.event_id,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PaginatedAuditLogResponse {
+    pub items: Vec<AuditLogEntryResponse>,
+    pub pagination: PaginationInfo,
+}
+
+impl PaginatedAuditLogResponse {
+    pub fn from_paginated_result(result: PaginatedResult<AuditLogEntry>) -> Self {
+        Self {
+            pagination: PaginationInfo::from_paginated_result(&result),
+            items: result.items.into_iter().map(AuditLogEntryResponse::from).collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Query Metrics DTOs
+// ============================================================================
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct QueryMethodStatsResponse {
+    pub repository: String,
+    pub method: String,
+    pub calls: u64,
+    pub errors: u64,
+    /// Calls slower than the threshold; each was logged with its parameters
+    pub slow_calls: u64,
+    pub total_ms: f64,
+    pub mean_ms: f64,
+    pub max_ms: f64,
+}
+
+impl From<aqio_database::QueryMethodStats> for QueryMethodStatsResponse {
+    fn from(stats: aqio_database::QueryMethodStats) -> Self {
+        let total_ms = stats.total_time.as_secs_f64() * 1000.0;
+        Self {
+            repository: stats.repository.to_string(),
+            method: stats.method.to_string(),
+            calls: stats.calls,
+            errors: stats.errors,
+            slow_calls: stats.slow_calls,
+            total_ms,
+            mean_ms: if stats.calls == 0 { 0.0 } else { total_ms / stats.calls as f64 },
+            max_ms: stats.max_time.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct QueryMetricsResponse {
+    pub slow_threshold_ms: u64,
+    /// Slow calls across all methods since startup
+    pub slow_calls: u64,
+    /// Methods called since startup, most total time first
+    pub methods: Vec<QueryMethodStatsResponse>,
+}
+
+impl From<&aqio_database::QueryMetrics> for QueryMetricsResponse {
+    fn from(metrics: &aqio_database::QueryMetrics) -> Self {
+        let mut methods: Vec<QueryMethodStatsResponse> =
+            metrics.snapshot().into_iter().map(QueryMethodStatsResponse::from).collect();
+        methods.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+        Self {
+            slow_threshold_ms: metrics.slow_threshold().as_millis() as u64,
+            slow_calls: methods.iter().map(|m| m.slow_calls).sum(),
+            methods,
+        }
+    }
+}
+
+// ============================================================================
+// Impersonation DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct StartImpersonationRequest {
+    pub user_id: Uuid,
+    /// Why support needs to act as the user; kept in the audit log
+    pub reason: String,
+    /// How long the token lasts, 30 minutes when left out
+    pub minutes: Option<u32>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ImpersonationResponse {
+    /// Bearer token that acts as the user; admin routes stay closed to it
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_at: DateTime<Utc>,
+    pub user: UserResponse,
+}
+
+// ============================================================================
+// Organization Invitation DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct CreateOrganizationInvitationRequest {
+    pub email: String,
+    /// `Participant` when left out; invitations can't make administrators
+    pub role: Option<UserRole>,
+}
+
+#[derive(Deserialize, Debug, ToSchema, IntoParams)]
+pub struct OrganizationInvitationQuery {
+    /// Only invitations to this company
+    pub company_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OrganizationInvitationResponse {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub company_name: String,
+    pub email: String,
+    pub role: UserRole,
+    pub status: OrganizationInvitationStatus,
+    pub invited_by: Option<Uuid>,
+    pub expires_at: DateTime<Utc>,
+    /// Pending and not expired, so the join link still works
+    pub is_open: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<crate::domain::services::OrganizationInvitationDetails> for OrganizationInvitationResponse {
+    fn from(details: crate::domain::services::OrganizationInvitationDetails) -> Self {
+        let invitation = details.invitation;
+        Self {
+            is_open: invitation.is_open(Utc::now()),
+            id: invitation.id,
+            company_id: invitation.company_id,
+            company_name: details.company_name,
+            email: invitation.email,
+            role: invitation.role,
+            status: invitation.status,
+            invited_by: invitation.invited_by,
+            expires_at: invitation.expires_at,
+            created_at: invitation.created_at,
+        }
+    }
+}
+
+// ============================================================================
+// Event Ownership Transfer DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct TransferOwnershipRequest {
+    /// An active organizer or administrator
+    pub new_owner_id: Uuid,
+    /// Shown to the proposed owner with the request
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct OwnershipTransferResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_title: String,
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+    pub requested_by: Uuid,
+    pub message: Option<String>,
+    pub status: OwnershipTransferStatus,
+    pub created_at: DateTime<Utc>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::domain::services::OwnershipTransferDetails> for OwnershipTransferResponse {
+    fn from(details: crate::domain::services::OwnershipTransferDetails) -> Self {
+        let transfer = details.transfer;
+        Self {
+            id: transfer.id,
+            event_id: transfer.event_id,
+            event_title: details.event_title,
+            from_user_id: transfer.from_user_id,
+            to_user_id: transfer.to_user_id,
+            requested_by: transfer.requested_by,
+            message: transfer.message,
+            status: transfer.status,
+            created_at: transfer.created_at,
+            responded_at: transfer.responded_at,
+        }
+    }
+}
+
+impl From<crate::domain::services::CompletedTransfer> for OwnershipTransferResponse {
+    fn from(completed: crate::domain::services::CompletedTransfer) -> Self {
+        Self::from(crate::domain::services::OwnershipTransferDetails {
+            transfer: completed.transfer,
+            event_title: completed.event.title,
+        })
+    }
+}
+
+// ============================================================================
+// Event Submission DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct ReviewEventSubmissionRequest {
+    /// Shown to the submitter; required when rejecting
+    pub comment: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct EventSubmissionResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_title: String,
+    pub event_slug: String,
+    pub event_status: EventStatus,
+    pub start_date: DateTime<Utc>,
+    pub submitted_by: Uuid,
+    pub submitter_name: String,
+    pub status: EventSubmissionStatus,
+    pub review_comment: Option<String>,
+    pub reviewed_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl From<crate::domain::services::EventSubmissionDetails> for EventSubmissionResponse {
+    fn from(details: crate::domain::services::EventSubmissionDetails) -> Self {
+        let submission = details.submission;
+        Self {
+            id: submission.id,
+            event_id: submission.event_id,
+            event_title: details.event.title,
+            event_slug: details.event.slug,
+            event_status: details.event.status,
+            start_date: details.event.start_date,
+            submitted_by: submission.submitted_by,
+            submitter_name: details.submitter_name,
+            status: submission.status,
+            review_comment: submission.review_comment,
+            reviewed_by: submission.reviewed_by,
+            created_at: submission.created_at,
+            reviewed_at: submission.reviewed_at,
+        }
+    }
+}
+
+// ============================================================================
+// Content Report DTOs
+// ============================================================================
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ReportContentRequest {
+    pub reason: ReportReason,
+    /// What is wrong with the content; shown to moderators only
+    pub details: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ContentReportResponse {
+    pub id: Uuid,
+    pub target: ReportTarget,
+    pub target_id: Uuid,
+    pub reporter_id: Uuid,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub status: ReportStatus,
+    pub action: Option<ModerationAction>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ContentReport> for ContentReportResponse {
+    fn from(report: ContentReport) -> Self {
+        Self {
+            id: report.id,
+            target: report.target,
+            target_id: report.target_id,
+            reporter_id: report.reporter_id,
+            reason: report.reason,
+            details: report.details,
+            status: report.status,
+            action: report.action,
+            created_at: report.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ReportedContentResponse {
+    pub target: ReportTarget,
+    pub target_id: Uuid,
+    /// Comment text, event title or user name
+    pub summary: String,
+    pub author_id: Option<Uuid>,
+    pub event_id: Option<Uuid>,
+    /// Hidden from other users pending review
+    pub hidden: bool,
+    pub report_count: usize,
+    /// Oldest first
+    pub reports: Vec<ContentReportResponse>,
+}
+
+impl From<crate::domain::services::ReportedContent> for ReportedContentResponse {
+    fn from(reported: crate::domain::services::ReportedContent) -> Self {
+        Self {
+            target: reported.target,
+            target_id: reported.target_id,
+            summary: reported.summary,
+            author_id: reported.author_id,
+            event_id: reported.event_id,
+            hidden: reported.hidden,
+            report_count: reported.reports.len(),
+            reports: reported.reports.into_iter().map(ContentReportResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ModerateContentRequest {
+    pub action: ModerationAction,
+    /// Quoted in the email to the author when warning or banning
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ModerationOutcomeResponse {
+    pub target: ReportTarget,
+    pub target_id: Uuid,
+    pub action: ModerationAction,
+    /// Open reports closed by the action
+    pub resolved_reports: u64,
+    pub hidden: bool,
+    /// The deactivated account when banning
+    pub banned_user_id: Option<Uuid>,
+}
+
+impl From<&crate::domain::services::ModerationOutcome> for ModerationOutcomeResponse {
+    fn from(outcome: &crate::domain::services::ModerationOutcome) -> Self {
+        Self {
+            target: outcome.target,
+            target_id: outcome.target_id,
+            action: outcome.action,
+            resolved_reports: outcome.resolved_reports,
+            hidden: outcome.hidden,
+            banned_user_id: outcome.banned.as_ref().map(|(_, user)| user.id),
+        }
+    }
+}
+
+// Contract tests against the fixtures the web client is tested with
+#[cfg(test)]
+#[path = "dto_contract_test.rs"]
+mod dto_contract_test;
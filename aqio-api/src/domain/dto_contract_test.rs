@@ -0,0 +1,183 @@
+// Contract tests: the responses the web client reads serialize the way the
+// fixtures in aqio-contract say. See aqio-contract for when fixtures may change.
+
+use aqio_contract::assert_provides;
+use aqio_core::*;
+use chrono::{DateTime, TimeZone, Utc};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use super::*;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+fn id(suffix: u32) -> Uuid {
+    Uuid::parse_str(&format!("550e8400-e29b-41d4-a716-4466554{:05}", suffix)).unwrap()
+}
+
+fn at(day: u32, hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, day, hour, 0, 0).unwrap()
+}
+
+fn variants<T: serde::Serialize>(all: &[T]) -> Value {
+    serde_json::to_value(all).unwrap()
+}
+
+#[test]
+fn test_user_contract() {
+    let user = UserResponse {
+        id: id(10),
+        keycloak_id: "admin-user".to_string(),
+        email: "admin@aqio.no".to_string(),
+        name: "Admin User".to_string(),
+        company_id: Some(id(1)),
+        role: UserRole::Organizer,
+        is_active: true,
+        timezone: Some("Europe/Oslo".to_string()),
+        locale: Some(Locale::Nb),
+        theme: Some(Theme::Dark),
+        calendar_view: Some(CalendarView::Week),
+        created_at: at(1, 9),
+        updated_at: at(2, 9),
+    };
+    assert_provides(VERSION, "user", &user);
+}
+
+#[test]
+fn test_category_contract() {
+    let category = EventCategoryResponse {
+        id: "conf".to_string(),
+        name: "Conference".to_string(),
+        description: Some("Large industry conferences and seminars".to_string()),
+        color_hex: Some("#3B82F6".to_string()),
+        icon_name: Some("presentation".to_string()),
+        is_active: true,
+        created_at: at(1, 9),
+    };
+    assert_provides(VERSION, "category", &category);
+}
+
+#[test]
+fn test_event_summary_contract() {
+    let event = EventSummaryResponse {
+        id: id(2),
+        title: "Aquaculture Tech Day".to_string(),
+        category_id: "conf".to_string(),
+        start_date: at(12, 7),
+        end_date: at(12, 15),
+        timezone: "Europe/Oslo".to_string(),
+        location_type: LocationType::Physical,
+        location_name: Some("Clarion Hotel Trondheim".to_string()),
+        latitude: Some(63.4365),
+        longitude: Some(10.4027),
+        organizer_id: id(10),
+        is_private: false,
+        status: EventStatus::Published,
+    };
+    assert_provides(VERSION, "event_summary", &event);
+}
+
+#[test]
+fn test_invitation_contract() {
+    let invitation = InvitationResponse {
+        id: id(20),
+        event_id: id(2),
+        invited_user_id: None,
+        invited_email: Some("kari@fjordlaks.no".to_string()),
+        invited_name: Some("Kari Nordmann".to_string()),
+        inviter_id: id(10),
+        invitation_method: InvitationMethod::Email,
+        personal_message: Some("Hope to see you there".to_string()),
+        status: InvitationStatus::Sent,
+        sent_at: Some(at(3, 10)),
+        opened_at: None,
+        clicked_at: None,
+        responded_at: None,
+        delivery_failure: Some(DeliveryFailure::Bounce),
+        delivery_failed_at: Some(at(3, 11)),
+        expires_at: None,
+        created_at: at(3, 10),
+        updated_at: at(3, 11),
+    };
+    assert_provides(VERSION, "invitation", &invitation);
+}
+
+#[test]
+fn test_registration_contract() {
+    let registration = RegistrationResponse {
+        id: id(30),
+        event_id: id(2),
+        invitation_id: Some(id(20)),
+        user_id: None,
+        registrant_email: Some("kari@fjordlaks.no".to_string()),
+        registrant_name: Some("Kari Nordmann".to_string()),
+        registrant_phone: None,
+        registrant_company: Some("Fjordlaks AS".to_string()),
+        status: RegistrationStatus::Waitlisted,
+        registration_source: RegistrationSource::Invitation,
+        guest_count: 1,
+        guest_names: vec!["Ola Nordmann".to_string()],
+        dietary_restrictions: Some("Vegetarian".to_string()),
+        accessibility_needs: None,
+        special_requests: None,
+        custom_responses: None,
+        registered_at: at(4, 8),
+        cancelled_at: None,
+        checked_in_at: None,
+        waitlist_position: Some(3),
+        waitlist_added_at: Some(at(4, 8)),
+        promotion_expires_at: Some(at(5, 8)),
+        created_at: at(4, 8),
+        updated_at: at(4, 8),
+    };
+    assert_provides(VERSION, "registration", &registration);
+}
+
+#[test]
+fn test_comment_contract() {
+    let comment = CommentResponse {
+        id: id(40),
+        event_id: id(2),
+        parent_comment_id: Some(id(41)),
+        author_id: Some(id(12)),
+        author_name: Some("Jane Smith".to_string()),
+        content: Some("Is there parking at the venue?".to_string()),
+        is_deleted: false,
+        is_hidden: false,
+        can_delete: true,
+        created_at: at(6, 14),
+        updated_at: at(6, 14),
+    };
+    assert_provides(VERSION, "comment", &comment);
+}
+
+// Every variant of the enums the web client matches on, as the API spells them
+#[test]
+fn test_enum_contract() {
+    let enums = json!({
+        "UserRole": variants(&[UserRole::Admin, UserRole::Organizer, UserRole::Participant]),
+        "EventStatus": variants(&[EventStatus::Draft, EventStatus::Published, EventStatus::Cancelled, EventStatus::Completed]),
+        "LocationType": variants(&[LocationType::Physical, LocationType::Virtual, LocationType::Hybrid]),
+        "InvitationStatus": variants(&[
+            InvitationStatus::Pending,
+            InvitationStatus::Sent,
+            InvitationStatus::Delivered,
+            InvitationStatus::Opened,
+            InvitationStatus::Accepted,
+            InvitationStatus::Declined,
+            InvitationStatus::Cancelled,
+        ]),
+        "DeliveryFailure": variants(&[DeliveryFailure::Bounce, DeliveryFailure::Complaint]),
+        "RegistrationStatus": variants(&[
+            RegistrationStatus::Registered,
+            RegistrationStatus::Waitlisted,
+            RegistrationStatus::Cancelled,
+            RegistrationStatus::Attended,
+            RegistrationStatus::NoShow,
+        ]),
+        "Locale": variants(&Locale::ALL),
+        "Theme": variants(&[Theme::Light, Theme::Dark]),
+        "CalendarView": variants(&[CalendarView::Month, CalendarView::Week, CalendarView::Day]),
+    });
+    assert_provides(VERSION, "enums", &enums);
+}
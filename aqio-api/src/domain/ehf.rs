@@ -0,0 +1,278 @@
+// EHF invoices: the Norwegian e-invoice format, which is PEPPOL BIS Billing
+// 3.0 (UBL 2.1). Public sector buyers must receive invoices this way, and
+// most accounting systems import them without anyone typing amounts in.
+// Prices include VAT: a line with a VAT rate is standard rated (category S)
+// with the VAT backed out of the total, and a line without one is outside
+// the scope of VAT (category O).
+
+use aqio_core::{Invoice, InvoiceParty};
+
+use crate::domain::digest::escape_html;
+
+const CUSTOMIZATION_ID: &str = "urn:cen.eu:en16931:2017#compliant#urn:fdc:peppol.eu:2017:poacc:billing:3.0";
+const PROFILE_ID: &str = "urn:fdc:peppol.eu:2017:poacc:billing:01:1.0";
+/// ICD of the Norwegian business register's organization numbers
+const ORG_NUMBER_SCHEME: &str = "0192";
+/// Commercial invoice
+const INVOICE_TYPE_CODE: &str = "380";
+/// Credit transfer to `bank_account`
+const PAYMENT_MEANS_CREDIT_TRANSFER: &str = "30";
+const EXEMPTION_REASON: &str = "Not subject to VAT";
+
+/// The invoice as an EHF (PEPPOL BIS Billing 3.0) XML document
+pub fn render_ehf(invoice: &Invoice) -> String {
+    let currency = invoice.currency.code();
+    let tag = |name: &str, minor_units: i64| {
+        format!("<cbc:{} currencyID=\"{}\">{}</cbc:{}>", name, currency, format_amount(minor_units), name)
+    };
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <Invoice xmlns=\"urn:oasis:names:specification:ubl:schema:xsd:Invoice-2\" \
+         xmlns:cac=\"urn:oasis:names:specification:ubl:schema:xsd:CommonAggregateComponents-2\" \
+         xmlns:cbc=\"urn:oasis:names:specification:ubl:schema:xsd:CommonBasicComponents-2\">\n",
+    );
+    xml.push_str(&format!("  <cbc:CustomizationID>{}</cbc:CustomizationID>\n", CUSTOMIZATION_ID));
+    xml.push_str(&format!("  <cbc:ProfileID>{}</cbc:ProfileID>\n", PROFILE_ID));
+    xml.push_str(&format!("  <cbc:ID>{}</cbc:ID>\n", invoice.invoice_number));
+    xml.push_str(&format!("  <cbc:IssueDate>{}</cbc:IssueDate>\n", invoice.issue_date.format("%Y-%m-%d")));
+    xml.push_str(&format!("  <cbc:DueDate>{}</cbc:DueDate>\n", invoice.due_date.format("%Y-%m-%d")));
+    xml.push_str(&format!("  <cbc:InvoiceTypeCode>{}</cbc:InvoiceTypeCode>\n", INVOICE_TYPE_CODE));
+    xml.push_str(&format!("  <cbc:DocumentCurrencyCode>{}</cbc:DocumentCurrencyCode>\n", currency));
+    xml.push_str(&format!("  <cbc:BuyerReference>{}</cbc:BuyerReference>\n", escape_html(&invoice.buyer_reference)));
+    xml.push_str("  <cac:AccountingSupplierParty>\n");
+    push_party(&mut xml, &invoice.seller);
+    xml.push_str("  </cac:AccountingSupplierParty>\n");
+    xml.push_str("  <cac:AccountingCustomerParty>\n");
+    push_party(&mut xml, &invoice.buyer);
+    xml.push_str("  </cac:AccountingCustomerParty>\n");
+
+    if let Some(account) = &invoice.bank_account {
+        xml.push_str("  <cac:PaymentMeans>\n");
+        xml.push_str(&format!(
+            "    <cbc:PaymentMeansCode>{}</cbc:PaymentMeansCode>\n",
+            PAYMENT_MEANS_CREDIT_TRANSFER
+        ));
+        xml.push_str(&format!("    <cbc:PaymentID>{}</cbc:PaymentID>\n", invoice.invoice_number));
+        xml.push_str("    <cac:PayeeFinancialAccount>\n");
+        xml.push_str(&format!("      <cbc:ID>{}</cbc:ID>\n", escape_html(account)));
+        xml.push_str("    </cac:PayeeFinancialAccount>\n");
+        xml.push_str("  </cac:PaymentMeans>\n");
+    }
+
+    let net = invoice.total - invoice.vat_amount;
+    xml.push_str("  <cac:TaxTotal>\n");
+    xml.push_str(&format!("    {}\n", tag("TaxAmount", invoice.vat_amount)));
+    xml.push_str("    <cac:TaxSubtotal>\n");
+    xml.push_str(&format!("      {}\n", tag("TaxableAmount", net)));
+    xml.push_str(&format!("      {}\n", tag("TaxAmount", invoice.vat_amount)));
+    xml.push_str("      <cac:TaxCategory>\n");
+    push_tax_category(&mut xml, invoice.vat_rate, "        ");
+    if invoice.vat_rate == 0 {
+        xml.push_str(&format!("        <cbc:TaxExemptionReason>{}</cbc:TaxExemptionReason>\n", EXEMPTION_REASON));
+    }
+    xml.push_str("        <cac:TaxScheme>\n          <cbc:ID>VAT</cbc:ID>\n        </cac:TaxScheme>\n");
+    xml.push_str("      </cac:TaxCategory>\n");
+    xml.push_str("    </cac:TaxSubtotal>\n");
+    xml.push_str("  </cac:TaxTotal>\n");
+
+    xml.push_str("  <cac:LegalMonetaryTotal>\n");
+    xml.push_str(&format!("    {}\n", tag("LineExtensionAmount", net)));
+    xml.push_str(&format!("    {}\n", tag("TaxExclusiveAmount", net)));
+    xml.push_str(&format!("    {}\n", tag("TaxInclusiveAmount", invoice.total)));
+    xml.push_str(&format!("    {}\n", tag("PayableAmount", invoice.total)));
+    xml.push_str("  </cac:LegalMonetaryTotal>\n");
+
+    xml.push_str("  <cac:InvoiceLine>\n");
+    xml.push_str("    <cbc:ID>1</cbc:ID>\n");
+    xml.push_str(&format!(
+        "    <cbc:InvoicedQuantity unitCode=\"EA\">{}</cbc:InvoicedQuantity>\n",
+        invoice.quantity
+    ));
+    xml.push_str(&format!("    {}\n", tag("LineExtensionAmount", net)));
+    xml.push_str("    <cac:Item>\n");
+    xml.push_str(&format!("      <cbc:Name>{}</cbc:Name>\n", escape_html(&invoice.description)));
+    xml.push_str("      <cac:ClassifiedTaxCategory>\n");
+    push_tax_category(&mut xml, invoice.vat_rate, "        ");
+    xml.push_str("        <cac:TaxScheme>\n          <cbc:ID>VAT</cbc:ID>\n        </cac:TaxScheme>\n");
+    xml.push_str("      </cac:ClassifiedTaxCategory>\n");
+    xml.push_str("    </cac:Item>\n");
+    xml.push_str("    <cac:Price>\n");
+    if invoice.vat_rate == 0 {
+        xml.push_str(&format!("      {}\n", tag("PriceAmount", invoice.unit_price)));
+    } else {
+        // The net unit price rarely comes out in whole øre, so price the
+        // whole quantity instead
+        xml.push_str(&format!("      {}\n", tag("PriceAmount", net)));
+        xml.push_str(&format!(
+            "      <cbc:BaseQuantity unitCode=\"EA\">{}</cbc:BaseQuantity>\n",
+            invoice.quantity
+        ));
+    }
+    xml.push_str("    </cac:Price>\n");
+    xml.push_str("  </cac:InvoiceLine>\n");
+    xml.push_str("</Invoice>\n");
+    xml
+}
+
+fn push_tax_category(xml: &mut String, vat_rate: i32, indent: &str) {
+    if vat_rate == 0 {
+        xml.push_str(&format!("{}<cbc:ID>O</cbc:ID>\n", indent));
+    } else {
+        xml.push_str(&format!("{}<cbc:ID>S</cbc:ID>\n", indent));
+        xml.push_str(&format!("{}<cbc:Percent>{}</cbc:Percent>\n", indent, vat_rate));
+    }
+}
+
+fn push_party(xml: &mut String, party: &InvoiceParty) {
+    let org_number = escape_html(&party.org_number);
+    xml.push_str("    <cac:Party>\n");
+    xml.push_str(&format!(
+        "      <cbc:EndpointID schemeID=\"{}\">{}</cbc:EndpointID>\n",
+        ORG_NUMBER_SCHEME, org_number
+    ));
+    xml.push_str(&format!(
+        "      <cac:PartyName>\n        <cbc:Name>{}</cbc:Name>\n      </cac:PartyName>\n",
+        escape_html(&party.name)
+    ));
+    xml.push_str("      <cac:PostalAddress>\n");
+    if let Some(address) = &party.address {
+        xml.push_str(&format!("        <cbc:StreetName>{}</cbc:StreetName>\n", escape_html(address)));
+    }
+    if let Some(city) = &party.city {
+        xml.push_str(&format!("        <cbc:CityName>{}</cbc:CityName>\n", escape_html(city)));
+    }
+    if let Some(postal_code) = &party.postal_code {
+        xml.push_str(&format!("        <cbc:PostalZone>{}</cbc:PostalZone>\n", escape_html(postal_code)));
+    }
+    xml.push_str(&format!(
+        "        <cac:Country>\n          <cbc:IdentificationCode>{}</cbc:IdentificationCode>\n        </cac:Country>\n",
+        escape_html(&party.country)
+    ));
+    xml.push_str("      </cac:PostalAddress>\n");
+    if party.vat_registered {
+        xml.push_str(&format!(
+            "      <cac:PartyTaxScheme>\n        <cbc:CompanyID>NO{}MVA</cbc:CompanyID>\n        \
+             <cac:TaxScheme>\n          <cbc:ID>VAT</cbc:ID>\n        </cac:TaxScheme>\n      </cac:PartyTaxScheme>\n",
+            org_number
+        ));
+    }
+    xml.push_str("      <cac:PartyLegalEntity>\n");
+    xml.push_str(&format!("        <cbc:RegistrationName>{}</cbc:RegistrationName>\n", escape_html(&party.name)));
+    xml.push_str(&format!(
+        "        <cbc:CompanyID schemeID=\"{}\">{}</cbc:CompanyID>\n",
+        ORG_NUMBER_SCHEME, org_number
+    ));
+    xml.push_str("      </cac:PartyLegalEntity>\n");
+    if let Some(email) = &party.email {
+        xml.push_str(&format!(
+            "      <cac:Contact>\n        <cbc:ElectronicMail>{}</cbc:ElectronicMail>\n      </cac:Contact>\n",
+            escape_html(email)
+        ));
+    }
+    xml.push_str("    </cac:Party>\n");
+}
+
+/// Minor units with two decimals, e.g. 150050 as `1500.50`
+pub(crate) fn format_amount(minor_units: i64) -> String {
+    let sign = if minor_units < 0 { "-" } else { "" };
+    let minor_units = minor_units.unsigned_abs();
+    format!("{}{}.{:02}", sign, minor_units / 100, minor_units % 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aqio_core::{Currency, InvoiceStatus};
+    use chrono::{NaiveDate, Utc};
+    use uuid::Uuid;
+
+    fn invoice() -> Invoice {
+        Invoice {
+            id: Uuid::new_v4(),
+            invoice_number: 1042,
+            order_id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            seller: InvoiceParty {
+                name: "Aqio AS".to_string(),
+                org_number: "923609016".to_string(),
+                address: Some("Strandkaien 2".to_string()),
+                postal_code: Some("5013".to_string()),
+                city: Some("Bergen".to_string()),
+                country: "NO".to_string(),
+                email: None,
+                vat_registered: false,
+            },
+            buyer: InvoiceParty {
+                name: "Havbruk & Sjømat AS".to_string(),
+                org_number: "974760673".to_string(),
+                country: "NO".to_string(),
+                email: Some("faktura@havbruk.no".to_string()),
+                ..Default::default()
+            },
+            buyer_reference: "Kostnadssted 4410".to_string(),
+            bank_account: Some("15032080119".to_string()),
+            currency: Currency::Eur,
+            description: "Early bird – Havbrukskonferansen 2026".to_string(),
+            quantity: 3,
+            unit_price: 45_050,
+            total: 135_150,
+            vat_rate: 0,
+            vat_amount: 0,
+            issue_date: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            due_date: NaiveDate::from_ymd_opt(2026, 3, 16).unwrap(),
+            status: InvoiceStatus::Issued,
+            paid_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_amounts_have_two_decimals() {
+        assert_eq!(format_amount(135_150), "1351.50");
+        assert_eq!(format_amount(5), "0.05");
+        assert_eq!(format_amount(-250), "-2.50");
+    }
+
+    #[test]
+    fn test_invoice_is_rendered_as_peppol_bis_billing() {
+        let xml = render_ehf(&invoice());
+
+        assert!(xml.contains(&format!("<cbc:CustomizationID>{}</cbc:CustomizationID>", CUSTOMIZATION_ID)));
+        assert!(xml.contains("<cbc:ID>1042</cbc:ID>"));
+        assert!(xml.contains("<cbc:DueDate>2026-03-16</cbc:DueDate>"));
+        assert!(xml.contains("<cbc:DocumentCurrencyCode>EUR</cbc:DocumentCurrencyCode>"));
+        assert!(xml.contains("<cbc:EndpointID schemeID=\"0192\">974760673</cbc:EndpointID>"));
+        assert!(xml.contains("<cbc:Name>Havbruk &amp; Sjømat AS</cbc:Name>"));
+        assert!(xml.contains("<cbc:PayableAmount currencyID=\"EUR\">1351.50</cbc:PayableAmount>"));
+        assert!(xml.contains("<cbc:PriceAmount currencyID=\"EUR\">450.50</cbc:PriceAmount>"));
+        assert!(xml.contains("<cbc:InvoicedQuantity unitCode=\"EA\">3</cbc:InvoicedQuantity>"));
+        assert!(xml.contains("<cbc:ID>15032080119</cbc:ID>"));
+        // The supplier party comes before the customer party
+        assert!(xml.find("923609016").unwrap() < xml.find("974760673").unwrap());
+
+        assert!(xml.contains("<cbc:ID>O</cbc:ID>"));
+        assert!(!xml.contains("PartyTaxScheme"));
+
+        let without_account = render_ehf(&Invoice { bank_account: None, ..invoice() });
+        assert!(!without_account.contains("PaymentMeans"));
+    }
+
+    #[test]
+    fn test_vat_is_backed_out_of_the_total() {
+        let mut invoice = invoice();
+        invoice.seller.vat_registered = true;
+        invoice.vat_rate = 25;
+        invoice.vat_amount = 27_030;
+        let xml = render_ehf(&invoice);
+
+        assert!(xml.contains("<cbc:CompanyID>NO923609016MVA</cbc:CompanyID>"));
+        assert!(xml.contains("<cbc:ID>S</cbc:ID>\n        <cbc:Percent>25</cbc:Percent>"));
+        assert!(xml.contains("<cbc:TaxableAmount currencyID=\"EUR\">1081.20</cbc:TaxableAmount>"));
+        assert!(xml.contains("<cbc:TaxAmount currencyID=\"EUR\">270.30</cbc:TaxAmount>"));
+        assert!(xml.contains("<cbc:TaxExclusiveAmount currencyID=\"EUR\">1081.20</cbc:TaxExclusiveAmount>"));
+        assert!(xml.contains("<cbc:PayableAmount currencyID=\"EUR\">1351.50</cbc:PayableAmount>"));
+        assert!(xml.contains("<cbc:BaseQuantity unitCode=\"EA\">3</cbc:BaseQuantity>"));
+        assert!(!xml.contains("TaxExemptionReason"));
+    }
+}
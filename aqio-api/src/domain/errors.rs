@@ -40,6 +40,12 @@ pub enum ApiError {
 
     #[error("Conflict: {message}")]
     Conflict { message: String },
+
+    #[error("Payload too large: {message}")]
+    PayloadTooLarge { message: String },
+
+    #[error("Insufficient storage: {message}")]
+    InsufficientStorage { message: String },
 }
 
 pub type ApiResult<T> = Result<T, ApiError>;
@@ -82,6 +88,18 @@ impl ApiError {
         }
     }
 
+    pub fn payload_too_large(message: impl Into<String>) -> Self {
+        Self::PayloadTooLarge {
+            message: message.into(),
+        }
+    }
+
+    pub fn insufficient_storage(message: impl Into<String>) -> Self {
+        Self::InsufficientStorage {
+            message: message.into(),
+        }
+    }
+
     pub fn internal(message: impl Into<String>) -> Self {
         Self::Internal {
             message: message.into(),
@@ -113,6 +131,8 @@ impl ApiError {
             Self::BadRequest { .. } => StatusCode::BAD_REQUEST,
             Self::NotFound { .. } => StatusCode::NOT_FOUND,
             Self::Conflict { .. } => StatusCode::CONFLICT,
+            Self::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::InsufficientStorage { .. } => StatusCode::INSUFFICIENT_STORAGE,
             Self::RateLimit => StatusCode::TOO_MANY_REQUESTS,
             Self::ExternalService { .. } => StatusCode::SERVICE_UNAVAILABLE,
             Self::Internal { .. } => StatusCode::INTERNAL_SERVER_ERROR,
@@ -137,6 +157,8 @@ impl ApiError {
             Self::BadRequest { .. } => "BAD_REQUEST",
             Self::NotFound { .. } => "NOT_FOUND",
             Self::Conflict { .. } => "CONFLICT",
+            Self::PayloadTooLarge { .. } => "PAYLOAD_TOO_LARGE",
+            Self::InsufficientStorage { .. } => "INSUFFICIENT_STORAGE",
             Self::RateLimit => "RATE_LIMIT_EXCEEDED",
             Self::ExternalService { .. } => "EXTERNAL_SERVICE_ERROR",
             Self::Internal { .. } => "INTERNAL_ERROR",
@@ -290,6 +312,7 @@ impl From<StatusCode> for ApiError {
             StatusCode::FORBIDDEN => Self::authorization("Access forbidden"),
             StatusCode::NOT_FOUND => Self::not_found("Resource not found"),
             StatusCode::CONFLICT => Self::conflict("Resource conflict"),
+            StatusCode::PAYLOAD_TOO_LARGE => Self::payload_too_large("Request body is too large"),
             StatusCode::TOO_MANY_REQUESTS => Self::RateLimit,
             StatusCode::INTERNAL_SERVER_ERROR => Self::internal("Internal server error"),
             _ => Self::internal(format!("HTTP error: {}", status)),
@@ -0,0 +1,179 @@
+// Custom event fields: the checks on what organizations define and on the
+// values organizers give them. Values are stored as text in one canonical form
+// per type, so the event list can filter with a plain comparison and "12.0",
+// "yes" or "salmon" find the events saved as "12", "true" and "Salmon".
+
+use aqio_core::{EventFieldDefinition, EventFieldType};
+use chrono::NaiveDate;
+
+use crate::domain::errors::{ApiError, ApiResult};
+
+pub const MAX_KEY_CHARS: usize = 40;
+pub const MAX_LABEL_CHARS: usize = 100;
+pub const MAX_OPTIONS: usize = 50;
+pub const MAX_OPTION_CHARS: usize = 100;
+pub const MAX_TEXT_CHARS: usize = 500;
+
+/// Keys are lowercase ASCII letters, digits and underscores, starting with a letter
+pub fn validate_key(key: &str) -> ApiResult<String> {
+    let key = key.trim();
+    let mut chars = key.chars();
+    let valid = chars.next().is_some_and(|first| first.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid || key.len() > MAX_KEY_CHARS {
+        return Err(ApiError::validation(
+            "key",
+            format!(
+                "Key must start with a lowercase letter and hold only lowercase letters, digits and underscores, at most {} characters",
+                MAX_KEY_CHARS
+            ),
+        ));
+    }
+    Ok(key.to_string())
+}
+
+/// A select field's choices, trimmed; other types take none
+pub fn validate_options(field_type: EventFieldType, options: Vec<String>) -> ApiResult<Vec<String>> {
+    let options: Vec<String> = options
+        .into_iter()
+        .map(|option| option.trim().to_string())
+        .filter(|option| !option.is_empty())
+        .collect();
+
+    if field_type != EventFieldType::Select {
+        if !options.is_empty() {
+            return Err(ApiError::validation("options", "Only select fields have options"));
+        }
+        return Ok(options);
+    }
+    if options.is_empty() || options.len() > MAX_OPTIONS {
+        return Err(ApiError::validation(
+            "options",
+            format!("A select field needs between 1 and {} options", MAX_OPTIONS),
+        ));
+    }
+    for (index, option) in options.iter().enumerate() {
+        if option.chars().count() > MAX_OPTION_CHARS {
+            return Err(ApiError::validation(
+                "options",
+                format!("Options can be at most {} characters", MAX_OPTION_CHARS),
+            ));
+        }
+        if options[..index].iter().any(|earlier| earlier.eq_ignore_ascii_case(option)) {
+            return Err(ApiError::validation("options", format!("'{}' is listed more than once", option)));
+        }
+    }
+    Ok(options)
+}
+
+/// `raw` in the field's canonical form; `None` when it's blank
+pub fn normalize_value(definition: &EventFieldDefinition, raw: &str) -> ApiResult<Option<String>> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Ok(None);
+    }
+    let invalid = |expected: &str| {
+        ApiError::validation(
+            definition.key.as_str(),
+            format!("{} must be {}", definition.label, expected),
+        )
+    };
+
+    let value = match definition.field_type {
+        EventFieldType::Text => {
+            if raw.chars().count() > MAX_TEXT_CHARS {
+                return Err(invalid(&format!("at most {} characters", MAX_TEXT_CHARS)));
+            }
+            raw.to_string()
+        }
+        EventFieldType::Number => {
+            let number: f64 = raw.parse().map_err(|_| invalid("a number"))?;
+            if !number.is_finite() {
+                return Err(invalid("a number"));
+            }
+            // Adding zero turns -0 into 0
+            (number + 0.0).to_string()
+        }
+        EventFieldType::Date => NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+            .map_err(|_| invalid("a date (YYYY-MM-DD)"))?
+            .format("%Y-%m-%d")
+            .to_string(),
+        EventFieldType::Boolean => match raw.to_lowercase().as_str() {
+            "true" | "yes" | "1" => "true".to_string(),
+            "false" | "no" | "0" => "false".to_string(),
+            _ => return Err(invalid("true or false")),
+        },
+        EventFieldType::Select => definition
+            .options
+            .iter()
+            .find(|option| option.to_lowercase() == raw.to_lowercase())
+            .cloned()
+            .ok_or_else(|| invalid(&format!("one of: {}", definition.options.join(", "))))?,
+    };
+    Ok(Some(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn field(field_type: EventFieldType, options: &[&str]) -> EventFieldDefinition {
+        EventFieldDefinition::new(
+            Uuid::new_v4(),
+            "field",
+            "Field",
+            field_type,
+            options.iter().map(|option| option.to_string()).collect(),
+            false,
+            0,
+        )
+    }
+
+    fn normalized(field_type: EventFieldType, raw: &str) -> Option<String> {
+        normalize_value(&field(field_type, &["Salmon", "Cod"]), raw).unwrap()
+    }
+
+    #[test]
+    fn test_values_are_put_in_canonical_form() {
+        assert_eq!(normalized(EventFieldType::Text, "  Lice limits "), Some("Lice limits".to_string()));
+        assert_eq!(normalized(EventFieldType::Text, "   "), None);
+        assert_eq!(normalized(EventFieldType::Number, "12.50"), Some("12.5".to_string()));
+        assert_eq!(normalized(EventFieldType::Number, "1e3"), Some("1000".to_string()));
+        assert_eq!(normalized(EventFieldType::Number, "-0"), Some("0".to_string()));
+        assert_eq!(normalized(EventFieldType::Date, "2026-06-01"), Some("2026-06-01".to_string()));
+        assert_eq!(normalized(EventFieldType::Boolean, "Yes"), Some("true".to_string()));
+        assert_eq!(normalized(EventFieldType::Boolean, "0"), Some("false".to_string()));
+        assert_eq!(normalized(EventFieldType::Select, "salmon"), Some("Salmon".to_string()));
+
+        let salmon = field(EventFieldType::Select, &["Salmon"]);
+        for (field_type, raw) in [
+            (EventFieldType::Number, "twelve"),
+            (EventFieldType::Number, "inf"),
+            (EventFieldType::Date, "01.06.2026"),
+            (EventFieldType::Boolean, "maybe"),
+        ] {
+            assert!(normalize_value(&field(field_type, &[]), raw).is_err(), "{:?} '{}'", field_type, raw);
+        }
+        assert!(normalize_value(&salmon, "Cod").is_err());
+        assert!(normalize_value(&field(EventFieldType::Text, &[]), &"x".repeat(MAX_TEXT_CHARS + 1)).is_err());
+    }
+
+    #[test]
+    fn test_keys_and_options_are_checked() {
+        assert_eq!(validate_key(" species_focus2 ").unwrap(), "species_focus2");
+        for key in ["", "2nd", "Species", "species-focus", "sjø"] {
+            assert!(validate_key(key).is_err(), "{}", key);
+        }
+        assert!(validate_key(&"k".repeat(MAX_KEY_CHARS + 1)).is_err());
+
+        assert_eq!(
+            validate_options(EventFieldType::Select, vec![" Salmon ".into(), "".into(), "Cod".into()]).unwrap(),
+            vec!["Salmon", "Cod"]
+        );
+        assert!(validate_options(EventFieldType::Select, Vec::new()).is_err());
+        assert!(validate_options(EventFieldType::Select, vec!["Cod".into(), "cod".into()]).is_err());
+        assert!(validate_options(EventFieldType::Text, vec!["Salmon".into()]).is_err());
+        assert!(validate_options(EventFieldType::Number, vec![" ".into()]).unwrap().is_empty());
+    }
+}
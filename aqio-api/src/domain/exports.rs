@@ -0,0 +1,219 @@
+// Scheduled exports: flat files of registrations or events that are pushed
+// to an external system, such as an association's CRM, on a schedule. CSV and
+// JSON share the same columns; JSON writes each row as an object keyed by
+// them, with empty cells as null.
+
+use std::collections::HashMap;
+
+use aqio_core::{Event, EventRegistration, ExportFormat, User};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::leads::LeadContact;
+
+pub const REGISTRATION_COLUMNS: &[&str] = &[
+    "registration_id",
+    "event_id",
+    "event_title",
+    "event_start",
+    "status",
+    "name",
+    "email",
+    "phone",
+    "company",
+    "guest_count",
+    "registered_at",
+    "checked_in_at",
+    "cancelled_at",
+    "updated_at",
+];
+
+pub const EVENT_COLUMNS: &[&str] = &[
+    "event_id",
+    "title",
+    "slug",
+    "status",
+    "start_date",
+    "end_date",
+    "timezone",
+    "location_name",
+    "address",
+    "max_attendees",
+    "is_private",
+    "updated_at",
+];
+
+/// Rows ready to be written in either format
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportTable {
+    pub columns: &'static [&'static str],
+    pub rows: Vec<Vec<String>>,
+}
+
+fn timestamp(at: Option<DateTime<Utc>>) -> String {
+    at.map(|at| at.to_rfc3339()).unwrap_or_default()
+}
+
+impl ExportTable {
+    /// One row per registration, in the order given. Registrations with an
+    /// account are named by the account; guests name themselves.
+    pub fn registrations(events: &[Event], registrations: &[EventRegistration], users: &HashMap<Uuid, User>) -> Self {
+        let events: HashMap<Uuid, &Event> = events.iter().map(|event| (event.id, event)).collect();
+        let rows = registrations
+            .iter()
+            .map(|registration| {
+                let event = events.get(&registration.event_id);
+                let user = registration.user_id.and_then(|id| users.get(&id));
+                let contact = LeadContact::of(registration, user);
+                vec![
+                    registration.id.to_string(),
+                    registration.event_id.to_string(),
+                    event.map(|event| event.title.clone()).unwrap_or_default(),
+                    timestamp(event.map(|event| event.start_date)),
+                    format!("{:?}", registration.status),
+                    contact.name.unwrap_or_default(),
+                    contact.email.unwrap_or_default(),
+                    contact.phone.unwrap_or_default(),
+                    contact.company.unwrap_or_default(),
+                    registration.guest_count.to_string(),
+                    timestamp(Some(registration.registered_at)),
+                    timestamp(registration.checked_in_at),
+                    timestamp(registration.cancelled_at),
+                    timestamp(Some(registration.updated_at)),
+                ]
+            })
+            .collect();
+        Self { columns: REGISTRATION_COLUMNS, rows }
+    }
+
+    /// One row per event, in the order given
+    pub fn events(events: &[Event]) -> Self {
+        let rows = events
+            .iter()
+            .map(|event| {
+                vec![
+                    event.id.to_string(),
+                    event.title.clone(),
+                    event.slug.clone(),
+                    format!("{:?}", event.status),
+                    timestamp(Some(event.start_date)),
+                    timestamp(Some(event.end_date)),
+                    event.timezone.clone(),
+                    event.location_name.clone().unwrap_or_default(),
+                    event.address.clone().unwrap_or_default(),
+                    event.max_attendees.map(|max| max.to_string()).unwrap_or_default(),
+                    event.is_private.to_string(),
+                    timestamp(Some(event.updated_at)),
+                ]
+            })
+            .collect();
+        Self { columns: EVENT_COLUMNS, rows }
+    }
+
+    pub fn render(&self, format: ExportFormat) -> Vec<u8> {
+        match format {
+            ExportFormat::Csv => self.render_csv().into_bytes(),
+            ExportFormat::Json => self.render_json().into_bytes(),
+        }
+    }
+
+    fn render_csv(&self) -> String {
+        let mut csv = self.columns.join(",");
+        csv.push_str("\r\n");
+        for row in &self.rows {
+            let fields: Vec<String> = row.iter().map(|value| field(value)).collect();
+            csv.push_str(&fields.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+
+    fn render_json(&self) -> String {
+        let rows: Vec<serde_json::Value> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let object: serde_json::Map<String, serde_json::Value> = self
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(column, value)| {
+                        let value = match value.is_empty() {
+                            true => serde_json::Value::Null,
+                            false => serde_json::Value::String(value.clone()),
+                        };
+                        (column.to_string(), value)
+                    })
+                    .collect();
+                serde_json::Value::Object(object)
+            })
+            .collect();
+        serde_json::Value::Array(rows).to_string()
+    }
+}
+
+/// Quotes a field when it holds a separator, quote or line break
+fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aqio_core::{RegistrationSource, RegistrationStatus};
+
+    fn registration(event_id: Uuid, name: &str) -> EventRegistration {
+        let now = Utc::now();
+        EventRegistration {
+            id: Uuid::new_v4(),
+            event_id,
+            invitation_id: None,
+            user_id: None,
+            external_contact_id: None,
+            registrant_email: Some("kari@example.org".to_string()),
+            registrant_name: Some(name.to_string()),
+            registrant_phone: None,
+            registrant_company: Some("Nordhordland Bondelag".to_string()),
+            status: RegistrationStatus::Registered,
+            registration_source: RegistrationSource::Direct,
+            guest_count: 1,
+            guest_names: vec![],
+            dietary_restrictions: None,
+            accessibility_needs: None,
+            special_requests: None,
+            custom_responses: None,
+            registered_at: now,
+            cancelled_at: None,
+            checked_in_at: None,
+            waitlist_position: None,
+            waitlist_added_at: None,
+            promotion_expires_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_csv_quotes_fields_and_json_keys_rows_by_column() {
+        let event_id = Uuid::new_v4();
+        let registrations = vec![registration(event_id, "Nordmann, Kari \"K\"")];
+        let table = ExportTable::registrations(&[], &registrations, &HashMap::new());
+
+        let csv = String::from_utf8(table.render(ExportFormat::Csv)).unwrap();
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], REGISTRATION_COLUMNS.join(","));
+        assert!(lines[1].contains(",Registered,\"Nordmann, Kari \"\"K\"\"\",kari@example.org,,Nordhordland Bondelag,1,"));
+        assert_eq!(lines.len(), 3);
+
+        let json: serde_json::Value = serde_json::from_slice(&table.render(ExportFormat::Json)).unwrap();
+        let row = &json.as_array().unwrap()[0];
+        assert_eq!(row["name"], "Nordmann, Kari \"K\"");
+        assert_eq!(row["event_id"], event_id.to_string());
+        assert!(row["phone"].is_null());
+        assert!(row["event_title"].is_null());
+    }
+}
@@ -0,0 +1,154 @@
+// Monthly financial report for accounting: ticket sales and refunds per VAT
+// rate, in NOK at the exchange rate of each order's day. Months run in
+// Norwegian time, so a sale at 00:30 on the 1st belongs to the new month.
+
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::Europe::Oslo;
+use uuid::Uuid;
+
+use aqio_core::VatBreakdown;
+
+use crate::domain::ehf::format_amount;
+
+const CSV_HEADER: &str = "VAT rate,Orders,Sales incl. VAT (NOK),VAT on sales (NOK),Refunded orders,Refunds incl. VAT (NOK),VAT on refunds (NOK),Net revenue excl. VAT (NOK),VAT collected (NOK)";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FinancialReport {
+    pub year: i32,
+    pub month: u32,
+    /// `None` when the report covers every event
+    pub event_id: Option<Uuid>,
+    /// Lowest rate first
+    pub by_vat_rate: Vec<VatBreakdown>,
+}
+
+impl FinancialReport {
+    pub fn sales_nok(&self) -> i64 {
+        self.by_vat_rate.iter().map(|line| line.sales_nok).sum()
+    }
+
+    pub fn sales_vat_nok(&self) -> i64 {
+        self.by_vat_rate.iter().map(|line| line.sales_vat_nok).sum()
+    }
+
+    pub fn refunds_nok(&self) -> i64 {
+        self.by_vat_rate.iter().map(|line| line.refunds_nok).sum()
+    }
+
+    pub fn refunds_vat_nok(&self) -> i64 {
+        self.by_vat_rate.iter().map(|line| line.refunds_vat_nok).sum()
+    }
+
+    /// Sales less refunds, without VAT
+    pub fn net_revenue_nok(&self) -> i64 {
+        self.by_vat_rate.iter().map(net_revenue).sum()
+    }
+
+    /// VAT on sales less VAT on refunds; what is owed for the month
+    pub fn vat_collected_nok(&self) -> i64 {
+        self.sales_vat_nok() - self.refunds_vat_nok()
+    }
+}
+
+fn net_revenue(line: &VatBreakdown) -> i64 {
+    (line.sales_nok - line.sales_vat_nok) - (line.refunds_nok - line.refunds_vat_nok)
+}
+
+/// Start and end (exclusive) of a calendar month in Norwegian time
+pub fn month_bounds(year: i32, month: u32) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    // Clocks change at 02:00 or 03:00, so midnight always exists
+    let start = Oslo.with_ymd_and_hms(year, month, 1, 0, 0, 0).single()?;
+    let end = Oslo.with_ymd_and_hms(next_year, next_month, 1, 0, 0, 0).single()?;
+    Some((start.with_timezone(&Utc), end.with_timezone(&Utc)))
+}
+
+/// The report as CSV for the accountant: one row per VAT rate and a total row
+pub fn render_csv(report: &FinancialReport) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+    for line in &report.by_vat_rate {
+        let row = [
+            format!("{}%", line.vat_rate),
+            line.orders.to_string(),
+            format_amount(line.sales_nok),
+            format_amount(line.sales_vat_nok),
+            line.refunded_orders.to_string(),
+            format_amount(line.refunds_nok),
+            format_amount(line.refunds_vat_nok),
+            format_amount(net_revenue(line)),
+            format_amount(line.sales_vat_nok - line.refunds_vat_nok),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    let total = [
+        "Total".to_string(),
+        report.by_vat_rate.iter().map(|line| line.orders).sum::<i64>().to_string(),
+        format_amount(report.sales_nok()),
+        format_amount(report.sales_vat_nok()),
+        report.by_vat_rate.iter().map(|line| line.refunded_orders).sum::<i64>().to_string(),
+        format_amount(report.refunds_nok()),
+        format_amount(report.refunds_vat_nok()),
+        format_amount(report.net_revenue_nok()),
+        format_amount(report.vat_collected_nok()),
+    ];
+    csv.push_str(&total.join(","));
+    csv.push_str("\r\n");
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_months_run_in_norwegian_time() {
+        let (start, end) = month_bounds(2026, 3).unwrap();
+        assert_eq!(start.to_rfc3339(), "2026-02-28T23:00:00+00:00");
+        // Summer time from the last Sunday of March
+        assert_eq!(end.to_rfc3339(), "2026-03-31T22:00:00+00:00");
+
+        let (_, new_year) = month_bounds(2026, 12).unwrap();
+        assert_eq!(new_year.to_rfc3339(), "2026-12-31T23:00:00+00:00");
+        assert!(month_bounds(2026, 13).is_none());
+    }
+
+    #[test]
+    fn test_renders_csv_with_net_revenue_and_vat_collected() {
+        let report = FinancialReport {
+            year: 2026,
+            month: 3,
+            event_id: None,
+            by_vat_rate: vec![
+                VatBreakdown {
+                    vat_rate: 0,
+                    orders: 2,
+                    sales_nok: 100_000,
+                    sales_vat_nok: 0,
+                    refunded_orders: 0,
+                    refunds_nok: 0,
+                    refunds_vat_nok: 0,
+                },
+                VatBreakdown {
+                    vat_rate: 25,
+                    orders: 3,
+                    sales_nok: 375_000,
+                    sales_vat_nok: 75_000,
+                    refunded_orders: 1,
+                    refunds_nok: 125_000,
+                    refunds_vat_nok: 25_000,
+                },
+            ],
+        };
+
+        assert_eq!(report.net_revenue_nok(), 300_000);
+        assert_eq!(report.vat_collected_nok(), 50_000);
+        let csv = render_csv(&report);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[1], "0%,2,1000.00,0.00,0,0.00,0.00,1000.00,0.00");
+        assert_eq!(lines[2], "25%,3,3750.00,750.00,1,1250.00,250.00,2000.00,500.00");
+        assert_eq!(lines[3], "Total,5,4750.00,750.00,1,1250.00,250.00,3000.00,500.00");
+    }
+}
@@ -0,0 +1,201 @@
+// Exhibitor leads: attendees whose badges an exhibitor's staff scanned at the
+// stand. Contact details are looked up when the leads are read rather than
+// copied at scan time, so a registrant who corrects their email or is
+// anonymized afterwards is exported as they are now.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use aqio_core::{EventRegistration, Exhibitor, Lead, User};
+use chrono::{DateTime, Timelike, Utc};
+use uuid::Uuid;
+
+/// The registration ID on an attendee's badge. The QR code holds either the
+/// bare ID or a link ending in it.
+pub fn parse_badge_code(code: &str) -> Option<Uuid> {
+    code.trim()
+        .rsplit(|c: char| !(c.is_ascii_hexdigit() || c == '-'))
+        .find_map(|token| Uuid::parse_str(token).ok())
+}
+
+/// How to reach the attendee behind a lead
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LeadContact {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub company: Option<String>,
+}
+
+impl LeadContact {
+    /// Registrations with an account are named by the account; guests name themselves
+    pub fn of(registration: &EventRegistration, user: Option<&User>) -> Self {
+        Self {
+            name: user.map(|user| user.name.clone()).or_else(|| registration.registrant_name.clone()),
+            email: user.map(|user| user.email.clone()).or_else(|| registration.registrant_email.clone()),
+            phone: registration.registrant_phone.clone(),
+            company: registration.registrant_company.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapturedLead {
+    pub lead: Lead,
+    pub contact: LeadContact,
+}
+
+const CSV_HEADER: &str = "scanned_at,name,email,phone,company,notes";
+
+/// Quotes a field when it holds a separator, quote or line break
+fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// One row per lead, in the order the leads are given
+pub fn render_csv(leads: &[CapturedLead]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+    for captured in leads {
+        let contact = &captured.contact;
+        let row = [
+            captured.lead.scanned_at.to_rfc3339(),
+            field(contact.name.as_deref().unwrap_or_default()),
+            field(contact.email.as_deref().unwrap_or_default()),
+            field(contact.phone.as_deref().unwrap_or_default()),
+            field(contact.company.as_deref().unwrap_or_default()),
+            field(captured.lead.notes.as_deref().unwrap_or_default()),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExhibitorLeadCount {
+    pub exhibitor_id: Uuid,
+    pub company_name: String,
+    pub leads: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HourlyLeadCount {
+    /// Start of the hour, in UTC
+    pub hour: DateTime<Utc>,
+    pub leads: usize,
+}
+
+/// How lead scanning went across an event's exhibition floor
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeadAnalytics {
+    pub total: usize,
+    /// Attendees scanned by at least one exhibitor
+    pub unique_attendees: usize,
+    /// Every exhibitor, most leads first, then by company name
+    pub by_exhibitor: Vec<ExhibitorLeadCount>,
+    /// Hours with scans, earliest first
+    pub by_hour: Vec<HourlyLeadCount>,
+}
+
+pub fn build_analytics(exhibitors: &[Exhibitor], leads: &[Lead]) -> LeadAnalytics {
+    let mut per_exhibitor: HashMap<Uuid, usize> = HashMap::new();
+    let mut per_hour: BTreeMap<DateTime<Utc>, usize> = BTreeMap::new();
+    let mut attendees = HashSet::new();
+    for lead in leads {
+        *per_exhibitor.entry(lead.exhibitor_id).or_default() += 1;
+        let hour = lead
+            .scanned_at
+            .with_minute(0)
+            .and_then(|at| at.with_second(0))
+            .and_then(|at| at.with_nanosecond(0))
+            .unwrap_or(lead.scanned_at);
+        *per_hour.entry(hour).or_default() += 1;
+        attendees.insert(lead.registration_id);
+    }
+
+    let mut by_exhibitor: Vec<ExhibitorLeadCount> = exhibitors
+        .iter()
+        .map(|exhibitor| ExhibitorLeadCount {
+            exhibitor_id: exhibitor.id,
+            company_name: exhibitor.company_name.clone(),
+            leads: per_exhibitor.get(&exhibitor.id).copied().unwrap_or(0),
+        })
+        .collect();
+    by_exhibitor.sort_by(|a, b| b.leads.cmp(&a.leads).then_with(|| a.company_name.cmp(&b.company_name)));
+
+    LeadAnalytics {
+        total: leads.len(),
+        unique_attendees: attendees.len(),
+        by_exhibitor,
+        by_hour: per_hour
+            .into_iter()
+            .map(|(hour, leads)| HourlyLeadCount { hour, leads })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_leads_are_counted_per_exhibitor_and_hour_and_exported() {
+        let event_id = Uuid::new_v4();
+        let salmon = Exhibitor::new(event_id, "Salmon Feeds AS");
+        let nets = Exhibitor::new(event_id, "Aqua Nets");
+        let quiet = Exhibitor::new(event_id, "Barge Builders");
+        let (kari, ola) = (Uuid::new_v4(), Uuid::new_v4());
+        let scanned = |exhibitor: &Exhibitor, registration_id: Uuid, hour: u32, minute: u32| Lead {
+            scanned_at: Utc.with_ymd_and_hms(2026, 9, 3, hour, minute, 0).unwrap(),
+            ..Lead::new(exhibitor, registration_id, Uuid::new_v4())
+        };
+        let leads = vec![
+            scanned(&salmon, kari, 9, 5),
+            scanned(&nets, kari, 9, 40),
+            scanned(&salmon, ola, 11, 15),
+        ];
+
+        let analytics = build_analytics(&[quiet.clone(), nets.clone(), salmon.clone()], &leads);
+        assert_eq!((analytics.total, analytics.unique_attendees), (3, 2));
+        assert_eq!(
+            analytics
+                .by_exhibitor
+                .iter()
+                .map(|count| (count.company_name.as_str(), count.leads))
+                .collect::<Vec<_>>(),
+            vec![("Salmon Feeds AS", 2), ("Aqua Nets", 1), ("Barge Builders", 0)]
+        );
+        assert_eq!(
+            analytics.by_hour.iter().map(|count| (count.hour.hour(), count.leads)).collect::<Vec<_>>(),
+            vec![(9, 2), (11, 1)]
+        );
+
+        let code = format!("https://aqio.no/checkin/{}", kari);
+        assert_eq!(parse_badge_code(&code), Some(kari));
+        assert_eq!(parse_badge_code("not a badge"), None);
+
+        let csv = render_csv(&[CapturedLead {
+            lead: Lead {
+                notes: Some("Wants a quote, \"urgently\"".to_string()),
+                ..leads[0].clone()
+            },
+            contact: LeadContact {
+                name: Some("Kari Nordmann".to_string()),
+                email: Some("kari@example.no".to_string()),
+                phone: None,
+                company: Some("Fjord Fish".to_string()),
+            },
+        }]);
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "2026-09-03T09:05:00+00:00,Kari Nordmann,kari@example.no,,Fjord Fish,\"Wants a quote, \"\"urgently\"\"\""
+        );
+    }
+}
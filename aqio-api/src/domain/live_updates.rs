@@ -0,0 +1,67 @@
+// Live Q&A and poll updates pushed to everyone following a running event.
+// Like registration notifications, delivery is best effort: clients load the
+// current questions and polls when they (re)connect and apply updates on top.
+
+use aqio_core::{EventPoll, EventQuestion};
+use serde::Serialize;
+use tokio::sync::broadcast;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+// Busy sessions produce a vote per attendee, so keep more headroom than the
+// registration channel before slow subscribers skip ahead
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// The changed item with its current aggregates; clients replace their copy by id
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LiveUpdate {
+    /// A question was asked, upvoted or answered
+    QuestionChanged { question: EventQuestion },
+    /// A poll was opened, voted on or closed
+    PollChanged { poll: EventPoll },
+}
+
+impl LiveUpdate {
+    /// Name used for the SSE `event:` field
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            Self::QuestionChanged { .. } => "question_changed",
+            Self::PollChanged { .. } => "poll_changed",
+        }
+    }
+}
+
+/// An update and the event it belongs to
+#[derive(Debug, Clone)]
+pub struct EventLiveUpdate {
+    pub event_id: Uuid,
+    pub update: LiveUpdate,
+}
+
+#[derive(Clone)]
+pub struct LiveUpdateNotifier {
+    sender: broadcast::Sender<EventLiveUpdate>,
+}
+
+impl LiveUpdateNotifier {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn publish(&self, event_id: Uuid, update: LiveUpdate) {
+        // Sending only fails when nobody is listening, which is fine
+        let _ = self.sender.send(EventLiveUpdate { event_id, update });
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<EventLiveUpdate> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for LiveUpdateNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
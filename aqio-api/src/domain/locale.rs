@@ -0,0 +1,132 @@
+// Language and time zone for text the API renders, such as digest emails and
+// calendar files. A user's own settings win, then the browser's
+// Accept-Language, then the platform defaults.
+
+use aqio_core::{Locale, User};
+use chrono::{DateTime, Datelike, Utc};
+use chrono_tz::Tz;
+
+/// Time zone used when a user hasn't chosen one
+pub const DEFAULT_TIMEZONE: Tz = chrono_tz::Europe::Oslo;
+
+const NB_WEEKDAYS: [&str; 7] = ["man.", "tir.", "ons.", "tor.", "fre.", "lør.", "søn."];
+const NB_MONTHS: [&str; 12] = [
+    "januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Localization {
+    pub locale: Locale,
+    pub timezone: Tz,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self {
+            locale: Locale::default(),
+            timezone: DEFAULT_TIMEZONE,
+        }
+    }
+}
+
+impl Localization {
+    /// The effective settings for a request. A time zone the user stored
+    /// that no longer parses falls back to the default.
+    pub fn resolve(user: Option<&User>, accept_language: Option<&str>) -> Self {
+        let locale = user
+            .and_then(|user| user.locale)
+            .or_else(|| accept_language.and_then(preferred_locale))
+            .unwrap_or_default();
+        let timezone = user
+            .and_then(|user| user.timezone.as_deref())
+            .and_then(|timezone| timezone.parse().ok())
+            .unwrap_or(DEFAULT_TIMEZONE);
+        Self { locale, timezone }
+    }
+
+    /// Settings for text rendered outside a request, such as emails
+    pub fn for_user(user: &User) -> Self {
+        Self::resolve(Some(user), None)
+    }
+
+    /// The English or Norwegian version of a text
+    pub fn text(&self, en: &'static str, nb: &'static str) -> &'static str {
+        match self.locale {
+            Locale::En => en,
+            Locale::Nb => nb,
+        }
+    }
+
+    /// Date and time in the user's time zone, e.g. `Thu 12 Mar 2026, 09:00 CET`
+    /// or `tor. 12. mars 2026 kl. 09:00 CET`
+    pub fn format_datetime(&self, at: DateTime<Utc>) -> String {
+        let local = at.with_timezone(&self.timezone);
+        match self.locale {
+            Locale::En => local.format("%a %-d %b %Y, %H:%M %Z").to_string(),
+            Locale::Nb => format!(
+                "{} {}. {} {} kl. {}",
+                NB_WEEKDAYS[local.weekday().num_days_from_monday() as usize],
+                local.day(),
+                NB_MONTHS[local.month0() as usize],
+                local.year(),
+                local.format("%H:%M %Z")
+            ),
+        }
+    }
+}
+
+/// The supported locale the Accept-Language header ranks highest
+pub fn preferred_locale(accept_language: &str) -> Option<Locale> {
+    let mut ranges: Vec<(&str, f32)> = accept_language
+        .split(',')
+        .filter_map(|range| {
+            let mut parts = range.split(';');
+            let tag = parts.next()?.trim();
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+            (!tag.is_empty() && quality > 0.0).then_some((tag, quality))
+        })
+        .collect();
+    // Stable, so equally ranked languages keep the browser's order
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranges.into_iter().find_map(|(tag, _)| Locale::from_tag(tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestUserBuilder;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_user_settings_win_over_the_browser() {
+        assert_eq!(preferred_locale("sv-SE,nb;q=0.8,en;q=0.9"), Some(Locale::En));
+        assert_eq!(preferred_locale("de, nn-NO;q=0.5"), Some(Locale::Nb));
+        assert_eq!(preferred_locale("nb;q=0, fr"), None);
+
+        let browser = Localization::resolve(None, Some("nb-NO,nb;q=0.9,en;q=0.8"));
+        assert_eq!(browser, Localization { locale: Locale::Nb, timezone: DEFAULT_TIMEZONE });
+
+        let mut user = TestUserBuilder::new().build();
+        user.locale = Some(Locale::En);
+        user.timezone = Some("America/Halifax".to_string());
+        let resolved = Localization::resolve(Some(&user), Some("nb-NO"));
+        assert_eq!(resolved.locale, Locale::En);
+        assert_eq!(resolved.timezone, chrono_tz::America::Halifax);
+
+        user.locale = None;
+        user.timezone = Some("Not/AZone".to_string());
+        assert_eq!(Localization::resolve(Some(&user), Some("nb")), browser);
+    }
+
+    #[test]
+    fn test_format_datetime_in_the_users_zone() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 12, 8, 0, 0).unwrap();
+        let english = Localization::default();
+        assert_eq!(english.format_datetime(at), "Thu 12 Mar 2026, 09:00 CET");
+
+        let norwegian = Localization { locale: Locale::Nb, timezone: chrono_tz::America::Halifax };
+        assert_eq!(norwegian.format_datetime(at), "tor. 12. mars 2026 kl. 05:00 ADT");
+    }
+}
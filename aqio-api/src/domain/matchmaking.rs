@@ -0,0 +1,162 @@
+// B2B matchmaking: suggesting who an attendee should meet, by the interests
+// they share, and finding a slot for a 1:1 meeting. A slot has to fall in
+// both attendees' availability and clear every meeting either of them has
+// requested or accepted; the meeting room is picked by the caller.
+
+use std::collections::{HashMap, HashSet};
+
+use aqio_core::{MatchmakingProfile, Meeting, User};
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// Trims the interests and drops empty ones and repeats, ignoring case; the
+/// first spelling of an interest is kept
+pub fn normalize_interests(interests: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    interests
+        .into_iter()
+        .map(|interest| interest.trim().to_string())
+        .filter(|interest| !interest.is_empty() && seen.insert(interest.to_lowercase()))
+        .collect()
+}
+
+/// The interests of `mine` that `theirs` has too, in my order
+pub fn shared_interests(mine: &[String], theirs: &[String]) -> Vec<String> {
+    let theirs: HashSet<String> = theirs.iter().map(|interest| interest.to_lowercase()).collect();
+    mine.iter()
+        .filter(|interest| theirs.contains(&interest.to_lowercase()))
+        .cloned()
+        .collect()
+}
+
+/// Another attendee suggested for a meeting
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    pub profile: MatchmakingProfile,
+    pub name: String,
+    /// As given when registering for the event
+    pub company: Option<String>,
+    pub shared_interests: Vec<String>,
+}
+
+/// Everyone else taking part, most shared interests first and otherwise in
+/// joining order. Profiles of users that no longer exist are left out.
+pub fn rank_matches(
+    me: &MatchmakingProfile,
+    others: Vec<MatchmakingProfile>,
+    users: &[User],
+    companies: &HashMap<Uuid, String>,
+) -> Vec<Match> {
+    let users: HashMap<Uuid, &User> = users.iter().map(|user| (user.id, user)).collect();
+    let mut matches: Vec<Match> = others
+        .into_iter()
+        .filter(|profile| profile.user_id != me.user_id)
+        .filter_map(|profile| {
+            let user = users.get(&profile.user_id)?;
+            Some(Match {
+                name: user.name.clone(),
+                company: companies.get(&profile.user_id).cloned(),
+                shared_interests: shared_interests(&me.interests, &profile.interests),
+                profile,
+            })
+        })
+        .collect();
+    // Stable, so ties keep joining order
+    matches.sort_by_key(|m| std::cmp::Reverse(m.shared_interests.len()));
+    matches
+}
+
+/// Meeting starts where both attendees are free, earliest first. Starts step
+/// through the requester's windows by the meeting length, so meetings line up
+/// back to back.
+pub fn free_slots(
+    requester: &MatchmakingProfile,
+    invitee: &MatchmakingProfile,
+    meetings: &[Meeting],
+    length: Duration,
+    not_before: DateTime<Utc>,
+) -> Vec<DateTime<Utc>> {
+    let busy: Vec<&Meeting> = meetings
+        .iter()
+        .filter(|meeting| meeting.is_active() && (meeting.involves(requester.user_id) || meeting.involves(invitee.user_id)))
+        .collect();
+
+    let mut slots = Vec::new();
+    for window in &requester.availability {
+        let mut starts_at = window.starts_at;
+        while starts_at + length <= window.ends_at {
+            let ends_at = starts_at + length;
+            if starts_at >= not_before
+                && invitee.is_available(starts_at, ends_at)
+                && !busy.iter().any(|meeting| meeting.overlaps(starts_at, ends_at))
+            {
+                slots.push(starts_at);
+            }
+            starts_at = ends_at;
+        }
+    }
+    slots.sort();
+    slots.dedup();
+    slots
+}
+
+/// A meeting as listed for one of its attendees
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeetingDetails {
+    pub meeting: Meeting,
+    pub requester_name: String,
+    pub invitee_name: String,
+    pub room: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestUserBuilder;
+    use aqio_core::{AvailabilityWindow, MeetingStatus};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_matches_rank_by_shared_interests_and_slots_avoid_busy_times() {
+        let event_id = Uuid::new_v4();
+        let at = |hour: u32, minute: u32| Utc.with_ymd_and_hms(2026, 9, 3, hour, minute, 0).unwrap();
+        let kari = TestUserBuilder::new().with_name("Kari").build();
+        let ola = TestUserBuilder::new().with_name("Ola").build();
+        let per = TestUserBuilder::new().with_name("Per").build();
+        let profile = |user: &User, interests: &[&str], from: (u32, u32), until: (u32, u32)| MatchmakingProfile {
+            interests: normalize_interests(interests.iter().map(|i| i.to_string()).collect()),
+            availability: vec![AvailabilityWindow {
+                starts_at: at(from.0, from.1),
+                ends_at: at(until.0, until.1),
+            }],
+            ..MatchmakingProfile::new(event_id, user.id)
+        };
+        let me = profile(&kari, &["Sensors", " feed ", "sensors", ""], (9, 0), (11, 0));
+        assert_eq!(me.interests, vec!["Sensors".to_string(), "feed".to_string()]);
+        let ola_profile = profile(&ola, &["Nets"], (9, 30), (12, 0));
+        let per_profile = profile(&per, &["FEED", "sensors"], (8, 0), (10, 0));
+
+        let matches = rank_matches(
+            &me,
+            vec![me.clone(), ola_profile.clone(), per_profile.clone()],
+            &[kari.clone(), ola.clone(), per.clone()],
+            &HashMap::from([(per.id, "Fjord Fish".to_string())]),
+        );
+        assert_eq!(matches.iter().map(|m| m.name.as_str()).collect::<Vec<_>>(), vec!["Per", "Ola"]);
+        assert_eq!(matches[0].shared_interests, vec!["Sensors".to_string(), "feed".to_string()]);
+        assert_eq!((matches[0].company.as_deref(), matches[1].company.as_deref()), (Some("Fjord Fish"), None));
+
+        // Kari already meets Per at 9:30; declined meetings don't count
+        let busy = Meeting {
+            status: MeetingStatus::Accepted,
+            ..Meeting::new(event_id, per.id, kari.id, at(9, 30), at(10, 0))
+        };
+        let declined = Meeting {
+            status: MeetingStatus::Declined,
+            ..Meeting::new(event_id, ola.id, kari.id, at(10, 0), at(10, 30))
+        };
+        let slots = free_slots(&me, &ola_profile, &[busy, declined], Duration::minutes(30), at(9, 0));
+        assert_eq!(slots, vec![at(10, 0), at(10, 30)]);
+        assert!(free_slots(&me, &ola_profile, &[], Duration::minutes(30), at(11, 0)).is_empty());
+    }
+}
@@ -1,8 +1,41 @@
 // Domain layer - Business logic and API-specific domain extensions
 
+pub mod annual_report;
+pub mod anonymize;
+pub mod archive;
+pub mod broadcasts;
+pub mod calendar;
+pub mod catering;
+pub mod certificates;
+pub mod company_registry;
+pub mod consents;
+pub mod crm;
+pub mod digest;
 pub mod errors;
 pub mod dto;
+pub mod ehf;
+pub mod event_fields;
+pub mod exports;
+pub mod financial_report;
+pub mod leads;
+pub mod live_updates;
+pub mod locale;
+pub mod matchmaking;
+pub mod moderation;
+pub mod notifications;
+pub mod onboarding;
+pub mod ownership;
+pub mod past_events;
+pub mod photos;
+pub mod posters;
+pub mod postal_codes;
+pub mod rate_limit;
+pub mod seating;
 pub mod services;
+pub mod shifts;
+pub mod structured_data;
+pub mod weather;
+pub mod web_vitals;
 
 // Re-export our API-specific domain types
 pub use errors::{ApiError, ApiResult};
\ No newline at end of file
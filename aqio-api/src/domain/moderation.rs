@@ -0,0 +1,110 @@
+// Emails to users whose content was reported: a warning when a moderator
+// looked at it and let it stand, or notice that their account was
+// deactivated. Each is in the recipient's language and quotes the
+// moderator's note when there is one.
+
+use aqio_core::{EmailMessage, Locale, ReportTarget, User};
+
+use crate::domain::digest::escape_html;
+use crate::domain::locale::Localization;
+
+/// What the moderator did, from the author's point of view
+#[derive(Debug, Clone, Copy)]
+pub enum ModerationEmail<'a> {
+    /// `summary` is the comment text, event title or the user's own name
+    Warned { target: ReportTarget, summary: &'a str },
+    Banned,
+}
+
+pub fn render_moderation_email(
+    email: ModerationEmail<'_>,
+    recipient: &User,
+    note: Option<&str>,
+    localization: &Localization,
+) -> EmailMessage {
+    let l = localization;
+    let (subject, intro) = match (email, l.locale) {
+        (ModerationEmail::Warned { target, summary }, Locale::En) => (
+            "A warning about your content on Aqio".to_string(),
+            match target {
+                ReportTarget::Comment => format!("Your comment \"{}\" was reported by other users and reviewed by a moderator.", summary),
+                ReportTarget::Event => format!("Your event {} was reported by other users and reviewed by a moderator.", summary),
+                ReportTarget::Profile => "Your profile was reported by other users and reviewed by a moderator.".to_string(),
+            },
+        ),
+        (ModerationEmail::Warned { target, summary }, Locale::Nb) => (
+            "En advarsel om innholdet ditt på Aqio".to_string(),
+            match target {
+                ReportTarget::Comment => format!("Kommentaren din «{}» ble rapportert av andre brukere og vurdert av en moderator.", summary),
+                ReportTarget::Event => format!("Arrangementet ditt {} ble rapportert av andre brukere og vurdert av en moderator.", summary),
+                ReportTarget::Profile => "Profilen din ble rapportert av andre brukere og vurdert av en moderator.".to_string(),
+            },
+        ),
+        (ModerationEmail::Banned, Locale::En) => (
+            "Your Aqio account has been deactivated".to_string(),
+            "A moderator deactivated your account after reports from other users.".to_string(),
+        ),
+        (ModerationEmail::Banned, Locale::Nb) => (
+            "Aqio-kontoen din er deaktivert".to_string(),
+            "En moderator har deaktivert kontoen din etter rapporter fra andre brukere.".to_string(),
+        ),
+    };
+    let footer = match email {
+        ModerationEmail::Warned { .. } => l.text(
+            "Repeated reports can lead to your account being deactivated.",
+            "Gjentatte rapporter kan føre til at kontoen din blir deaktivert.",
+        ),
+        ModerationEmail::Banned => l.text(
+            "Contact your organization's administrator if you think this is a mistake.",
+            "Ta kontakt med administratoren i organisasjonen din hvis du mener dette er feil.",
+        ),
+    };
+    let note = note.map(str::trim).filter(|note| !note.is_empty());
+
+    let mut text = format!("{}\n\n", intro);
+    let mut html = format!("<p>{}</p>\n", escape_html(&intro));
+    if let Some(note) = note {
+        text.push_str(&format!("\"{}\"\n\n", note));
+        html.push_str(&format!("<blockquote>{}</blockquote>\n", escape_html(note)));
+    }
+    text.push_str(&format!("{}\n", footer));
+    html.push_str(&format!("<p><small>{}</small></p>\n", escape_html(footer)));
+
+    EmailMessage {
+        to: recipient.email.clone(),
+        subject,
+        text_body: text,
+        html_body: html,
+        from: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestUserBuilder;
+
+    #[test]
+    fn test_warning_quotes_the_content_and_the_note() {
+        let recipient = TestUserBuilder::new().with_email("kari@example.no").build();
+
+        let email = render_moderation_email(
+            ModerationEmail::Warned { target: ReportTarget::Comment, summary: "Buy <cheap> salmon" },
+            &recipient,
+            Some(" Keep it on topic "),
+            &Localization::default(),
+        );
+
+        assert_eq!(email.to, "kari@example.no");
+        assert_eq!(email.subject, "A warning about your content on Aqio");
+        assert!(email.text_body.starts_with("Your comment \"Buy <cheap> salmon\" was reported"));
+        assert!(email.text_body.contains("\n\n\"Keep it on topic\"\n\n"));
+        assert!(email.html_body.contains("Buy &lt;cheap&gt; salmon"));
+        assert!(email.html_body.contains("<blockquote>Keep it on topic</blockquote>"));
+
+        let norwegian = Localization { locale: Locale::Nb, ..Localization::default() };
+        let email = render_moderation_email(ModerationEmail::Banned, &recipient, None, &norwegian);
+        assert_eq!(email.subject, "Aqio-kontoen din er deaktivert");
+        assert!(!email.text_body.contains('"'));
+    }
+}
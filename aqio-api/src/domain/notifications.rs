@@ -0,0 +1,153 @@
+// Registration notifications pushed to attendees while they are connected.
+// Delivery is best effort: nothing is stored, clients re-fetch their
+// registration when they (re)connect.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::broadcast;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+// Slow subscribers that fall further behind than this skip ahead
+const CHANNEL_CAPACITY: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RegistrationNotification {
+    /// Someone ahead on the waitlist left; the position should be re-fetched
+    WaitlistMoved { registration_id: Uuid, event_id: Uuid },
+    /// A spot opened up and must be confirmed before `confirm_by`
+    Promoted {
+        registration_id: Uuid,
+        event_id: Uuid,
+        confirm_by: DateTime<Utc>,
+    },
+    /// The offer was not confirmed in time and went to the next person
+    PromotionExpired { registration_id: Uuid, event_id: Uuid },
+    /// A scheduled draft the user organizes or is invited to went live
+    EventPublished { event_id: Uuid },
+    /// The organizer asked the user to take over the event
+    OwnershipTransferRequested { transfer_id: Uuid, event_id: Uuid },
+    /// The event the user organizes or co-organizes has a new organizer
+    OwnershipTransferred { event_id: Uuid, new_owner_id: Uuid },
+    /// The user the organizer asked to take over the event declined
+    OwnershipTransferDeclined { transfer_id: Uuid, event_id: Uuid },
+    /// A moderator approved or rejected the event the user proposed
+    EventSubmissionReviewed { submission_id: Uuid, event_id: Uuid, approved: bool },
+    /// The organizers sent a message to the event's registrants
+    BroadcastReceived { broadcast_id: Uuid, event_id: Uuid },
+    /// A new message in a conversation about the event; unread counts should be re-fetched
+    MessageReceived { thread_id: Uuid, event_id: Uuid },
+    /// The event was cancelled; paid tickets are being refunded
+    EventCancelled { event_id: Uuid },
+    /// The payment for the user's tickets was refunded
+    RefundSucceeded { refund_id: Uuid, event_id: Uuid },
+    /// The refund could not be completed; the organizers have to follow up
+    RefundFailed { refund_id: Uuid, event_id: Uuid },
+}
+
+impl RegistrationNotification {
+    /// Name used for the SSE `event:` field
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            Self::WaitlistMoved { .. } => "waitlist_moved",
+            Self::Promoted { .. } => "promoted",
+            Self::PromotionExpired { .. } => "promotion_expired",
+            Self::EventPublished { .. } => "event_published",
+            Self::OwnershipTransferRequested { .. } => "ownership_transfer_requested",
+            Self::OwnershipTransferred { .. } => "ownership_transferred",
+            Self::OwnershipTransferDeclined { .. } => "ownership_transfer_declined",
+            Self::EventSubmissionReviewed { .. } => "event_submission_reviewed",
+            Self::BroadcastReceived { .. } => "broadcast_received",
+            Self::MessageReceived { .. } => "message_received",
+            Self::EventCancelled { .. } => "event_cancelled",
+            Self::RefundSucceeded { .. } => "refund_succeeded",
+            Self::RefundFailed { .. } => "refund_failed",
+        }
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        match self {
+            Self::WaitlistMoved { event_id, .. }
+            | Self::Promoted { event_id, .. }
+            | Self::PromotionExpired { event_id, .. }
+            | Self::EventPublished { event_id }
+            | Self::OwnershipTransferRequested { event_id, .. }
+            | Self::OwnershipTransferred { event_id, .. }
+            | Self::OwnershipTransferDeclined { event_id, .. }
+            | Self::EventSubmissionReviewed { event_id, .. }
+            | Self::BroadcastReceived { event_id, .. }
+            | Self::MessageReceived { event_id, .. }
+            | Self::EventCancelled { event_id }
+            | Self::RefundSucceeded { event_id, .. }
+            | Self::RefundFailed { event_id, .. } => *event_id,
+        }
+    }
+
+    /// Urgent notifications need the user to act soon and never wait for a digest
+    pub fn is_urgent(&self) -> bool {
+        matches!(self, Self::Promoted { .. } | Self::OwnershipTransferRequested { .. })
+    }
+
+    /// The notification's content was already emailed, so a digest would repeat it
+    pub fn is_emailed(&self) -> bool {
+        matches!(self, Self::BroadcastReceived { .. })
+    }
+
+    /// The line describing the notification in a digest
+    pub fn digest_message(&self) -> &'static str {
+        match self {
+            Self::WaitlistMoved { .. } => "You moved up the waitlist",
+            Self::Promoted { .. } => "A spot opened up for you",
+            Self::PromotionExpired { .. } => "Your offer of a spot expired and went to the next person",
+            Self::EventPublished { .. } => "The event was published",
+            Self::OwnershipTransferRequested { .. } => "You were asked to take over as organizer",
+            Self::OwnershipTransferred { .. } => "The event has a new organizer",
+            Self::OwnershipTransferDeclined { .. } => "Your request to hand over the event was declined",
+            Self::EventSubmissionReviewed { approved: true, .. } => "The event you proposed was approved",
+            Self::EventSubmissionReviewed { approved: false, .. } => "The event you proposed was rejected",
+            Self::BroadcastReceived { .. } => "The organizers sent a message",
+            Self::MessageReceived { .. } => "You have a new message",
+            Self::EventCancelled { .. } => "The event was cancelled",
+            Self::RefundSucceeded { .. } => "Your tickets were refunded",
+            Self::RefundFailed { .. } => "Your tickets could not be refunded; the organizers will follow up",
+        }
+    }
+}
+
+/// A notification and the user it is meant for
+#[derive(Debug, Clone)]
+pub struct AddressedNotification {
+    pub user_id: Uuid,
+    pub notification: RegistrationNotification,
+}
+
+#[derive(Clone)]
+pub struct RegistrationNotifier {
+    sender: broadcast::Sender<AddressedNotification>,
+}
+
+impl RegistrationNotifier {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn notify(&self, user_id: Uuid, notification: RegistrationNotification) {
+        // Sending only fails when nobody is listening, which is fine
+        let _ = self.sender.send(AddressedNotification {
+            user_id,
+            notification,
+        });
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<AddressedNotification> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for RegistrationNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
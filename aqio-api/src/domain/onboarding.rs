@@ -0,0 +1,101 @@
+// Organization invitation emails: a colleague asks someone to join their
+// company on Aqio. The link opens the join page in the frontend, which has the
+// recipient sign in and accept; the text is in the inviter's language.
+
+use aqio_core::{EmailMessage, Locale, OrganizationInvitation};
+
+use crate::domain::digest::escape_html;
+use crate::domain::locale::Localization;
+
+/// The invitation email; the join link points into the frontend at `public_url`
+pub fn render_organization_invitation(
+    invitation: &OrganizationInvitation,
+    company_name: &str,
+    inviter_name: &str,
+    public_url: &str,
+    localization: &Localization,
+) -> EmailMessage {
+    let l = localization;
+    let link = join_url(public_url, &invitation.token);
+    let subject = match l.locale {
+        Locale::En => format!("{} invited you to join {} on Aqio", inviter_name, company_name),
+        Locale::Nb => format!("{} har invitert deg til {} på Aqio", inviter_name, company_name),
+    };
+    let intro = match l.locale {
+        Locale::En => format!(
+            "{} has invited you to join {} on Aqio, where you can plan and follow the organization's events.",
+            inviter_name, company_name
+        ),
+        Locale::Nb => format!(
+            "{} har invitert deg til å bli med i {} på Aqio, der du kan planlegge og følge organisasjonens arrangementer.",
+            inviter_name, company_name
+        ),
+    };
+    let expiry = format!(
+        "{} {}.",
+        l.text("The invitation is valid until", "Invitasjonen gjelder til"),
+        l.format_datetime(invitation.expires_at)
+    );
+    let footer = l.text(
+        "If you weren't expecting this invitation, you can ignore this email.",
+        "Hvis du ikke ventet denne invitasjonen, kan du se bort fra denne e-posten.",
+    );
+
+    let text = format!("{}\n\n{}\n\n{}\n\n{}\n", intro, link, expiry, footer);
+    let html = format!(
+        "<p>{}</p>\n<p><a href=\"{}\">{}</a></p>\n<p>{}</p>\n<p><small>{}</small></p>\n",
+        escape_html(&intro),
+        escape_html(&link),
+        l.text("Accept the invitation", "Godta invitasjonen"),
+        escape_html(&expiry),
+        escape_html(footer)
+    );
+
+    EmailMessage {
+        to: invitation.email.clone(),
+        subject,
+        text_body: text,
+        html_body: html,
+        from: None,
+    }
+}
+
+/// Frontend page where the invitee accepts
+pub fn join_url(public_url: &str, token: &str) -> String {
+    format!("{}/join/{}", public_url.trim_end_matches('/'), token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aqio_core::UserRole;
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_invitation_links_to_the_join_page() {
+        let mut invitation = OrganizationInvitation::new(Uuid::new_v4(), "kari@example.no", UserRole::Participant, None);
+        invitation.expires_at = Utc.with_ymd_and_hms(2026, 3, 12, 8, 0, 0).unwrap();
+
+        let email = render_organization_invitation(
+            &invitation,
+            "Salmon & Co",
+            "Ola",
+            "https://aqio.no/",
+            &Localization::default(),
+        );
+
+        let link = format!("https://aqio.no/join/{}", invitation.token);
+        assert_eq!(email.to, "kari@example.no");
+        assert_eq!(email.subject, "Ola invited you to join Salmon & Co on Aqio");
+        assert!(email.text_body.contains(&format!("\n\n{}\n\n", link)));
+        assert!(email.text_body.contains("valid until Thu 12 Mar 2026, 09:00 CET."));
+        assert!(email.html_body.contains("join Salmon &amp; Co on Aqio"));
+        assert!(email.html_body.contains(&format!("<a href=\"{}\">Accept the invitation</a>", link)));
+
+        let norwegian = Localization { locale: Locale::Nb, ..Localization::default() };
+        let email = render_organization_invitation(&invitation, "Salmon & Co", "Ola", "https://aqio.no", &norwegian);
+        assert_eq!(email.subject, "Ola har invitert deg til Salmon & Co på Aqio");
+        assert!(email.text_body.contains("gjelder til tor. 12. mars 2026 kl. 09:00 CET."));
+    }
+}
@@ -0,0 +1,156 @@
+// Emails about handing an event to another organizer: the request to the new
+// owner, the confirmation both sides get once it is accepted, and the answer
+// to the previous owner when it is declined. Each is in the recipient's
+// language.
+
+use aqio_core::{EmailMessage, Event, Locale, User};
+
+use crate::domain::digest::escape_html;
+use crate::domain::locale::Localization;
+
+/// What happened to the transfer, from the recipient's point of view
+#[derive(Debug, Clone, Copy)]
+pub enum OwnershipEmail<'a> {
+    /// Sent to the proposed owner, who has to accept
+    Requested { from_name: &'a str, message: Option<&'a str> },
+    /// Sent to the previous owner once the new one accepted
+    HandedOver { to_name: &'a str },
+    /// Sent to the new owner once they accepted
+    TakenOver { from_name: &'a str },
+    /// Sent to the previous owner when the proposed one declined
+    Declined { to_name: &'a str },
+}
+
+pub fn render_ownership_email(
+    email: OwnershipEmail<'_>,
+    event: &Event,
+    recipient: &User,
+    public_url: &str,
+    localization: &Localization,
+) -> EmailMessage {
+    let l = localization;
+    let title = &event.title;
+    let (subject, intro) = match (email, l.locale) {
+        (OwnershipEmail::Requested { from_name, .. }, Locale::En) => (
+            format!("{} wants to hand {} over to you", from_name, title),
+            format!(
+                "{} has asked you to take over as organizer of {}. Open the event to accept or decline.",
+                from_name, title
+            ),
+        ),
+        (OwnershipEmail::Requested { from_name, .. }, Locale::Nb) => (
+            format!("{} vil overlate {} til deg", from_name, title),
+            format!(
+                "{} har bedt deg ta over som arrangør av {}. Åpne arrangementet for å godta eller avslå.",
+                from_name, title
+            ),
+        ),
+        (OwnershipEmail::HandedOver { to_name }, Locale::En) => (
+            format!("{} is now organized by {}", title, to_name),
+            format!(
+                "{} accepted and is now the organizer of {}. You stay on as a co-organizer.",
+                to_name, title
+            ),
+        ),
+        (OwnershipEmail::HandedOver { to_name }, Locale::Nb) => (
+            format!("{} arrangeres nå av {}", title, to_name),
+            format!(
+                "{} har godtatt og er nå arrangør av {}. Du fortsetter som medarrangør.",
+                to_name, title
+            ),
+        ),
+        (OwnershipEmail::TakenOver { from_name }, Locale::En) => (
+            format!("You are now the organizer of {}", title),
+            format!(
+                "You have taken over {} from {}, who stays on as a co-organizer.",
+                title, from_name
+            ),
+        ),
+        (OwnershipEmail::TakenOver { from_name }, Locale::Nb) => (
+            format!("Du er nå arrangør av {}", title),
+            format!(
+                "Du har tatt over {} fra {}, som fortsetter som medarrangør.",
+                title, from_name
+            ),
+        ),
+        (OwnershipEmail::Declined { to_name }, Locale::En) => (
+            format!("{} declined to take over {}", to_name, title),
+            format!("{} declined your request to take over {}. You are still its organizer.", to_name, title),
+        ),
+        (OwnershipEmail::Declined { to_name }, Locale::Nb) => (
+            format!("{} takket nei til å ta over {}", to_name, title),
+            format!("{} takket nei til å ta over {}. Du er fortsatt arrangør.", to_name, title),
+        ),
+    };
+    let message = match email {
+        OwnershipEmail::Requested { message: Some(message), .. } if !message.trim().is_empty() => Some(message.trim()),
+        _ => None,
+    };
+    let link = event_url(public_url, event);
+
+    let mut text = format!("{}\n\n", intro);
+    let mut html = format!("<p>{}</p>\n", escape_html(&intro));
+    if let Some(message) = message {
+        text.push_str(&format!("\"{}\"\n\n", message));
+        html.push_str(&format!("<blockquote>{}</blockquote>\n", escape_html(message)));
+    }
+    text.push_str(&format!("{}\n", link));
+    html.push_str(&format!(
+        "<p><a href=\"{}\">{}</a></p>\n",
+        escape_html(&link),
+        l.text("Open the event", "Åpne arrangementet")
+    ));
+
+    EmailMessage {
+        to: recipient.email.clone(),
+        subject,
+        text_body: text,
+        html_body: html,
+        from: None,
+    }
+}
+
+/// The event's page in the frontend, where organizers manage it
+pub fn event_url(public_url: &str, event: &Event) -> String {
+    format!("{}/events/{}", public_url.trim_end_matches('/'), event.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::{TestEventBuilder, TestUserBuilder};
+
+    #[test]
+    fn test_request_quotes_the_message_and_links_to_the_event() {
+        let event = TestEventBuilder::new().with_title("Salmon & Sea").build();
+        let recipient = TestUserBuilder::new().with_email("kari@example.no").build();
+
+        let email = render_ownership_email(
+            OwnershipEmail::Requested { from_name: "Ola", message: Some(" I'm on leave <3 ") },
+            &event,
+            &recipient,
+            "https://aqio.no/",
+            &Localization::default(),
+        );
+
+        let link = format!("https://aqio.no/events/{}", event.id);
+        assert_eq!(email.to, "kari@example.no");
+        assert_eq!(email.subject, "Ola wants to hand Salmon & Sea over to you");
+        assert!(email.text_body.contains("\"I'm on leave <3\"\n\n"));
+        assert!(email.text_body.ends_with(&format!("{}\n", link)));
+        assert!(email.html_body.contains("<blockquote>I&#39;m on leave &lt;3</blockquote>"));
+        assert!(email.html_body.contains("organizer of Salmon &amp; Sea"));
+
+        let norwegian = Localization { locale: Locale::Nb, ..Localization::default() };
+        let email = render_ownership_email(
+            OwnershipEmail::HandedOver { to_name: "Kari" },
+            &event,
+            &recipient,
+            "https://aqio.no",
+            &norwegian,
+        );
+        assert_eq!(email.subject, "Salmon & Sea arrangeres nå av Kari");
+        assert!(!email.html_body.contains("blockquote"));
+        assert!(email.html_body.contains(&format!("<a href=\"{}\">Åpne arrangementet</a>", link)));
+    }
+}
@@ -0,0 +1,134 @@
+// Year-over-year attendance of past events, for annual reporting. The
+// database totals each category or organizer per calendar year; this puts a
+// group's years side by side and compares each one with the year before.
+// Percentages are rounded to one decimal, as they're shown in reports as-is.
+
+use aqio_core::YearlyAttendance;
+
+/// One year of a group, next to the calendar year before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendYear {
+    pub year: i32,
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub cancelled: i64,
+    /// Share of registrations that checked in, in percent
+    pub attendance_rate: Option<f64>,
+    /// Change in registrations from the year before, in percent; `None` when
+    /// the group had no events that year
+    pub registrations_change: Option<f64>,
+    /// Change in check-ins from the year before, in percent
+    pub checked_in_change: Option<f64>,
+}
+
+/// A category's or organizer's past years, oldest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttendanceTrend {
+    pub group_id: String,
+    pub group_name: Option<String>,
+    pub years: Vec<TrendYear>,
+    /// Totals over every year shown
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+}
+
+/// Share of `registrations` that checked in, in percent; `None` without registrations
+pub fn attendance_rate(registrations: i64, checked_in: i64) -> Option<f64> {
+    (registrations > 0).then(|| round(checked_in as f64 * 100.0 / registrations as f64))
+}
+
+/// Groups the yearly totals, which come ordered by group and then year
+pub fn attendance_trends(rows: &[YearlyAttendance]) -> Vec<AttendanceTrend> {
+    let mut trends: Vec<AttendanceTrend> = Vec::new();
+
+    for row in rows {
+        if trends.last().is_none_or(|trend| trend.group_id != row.group_id) {
+            trends.push(AttendanceTrend {
+                group_id: row.group_id.clone(),
+                group_name: row.group_name.clone(),
+                years: Vec::new(),
+                events: 0,
+                registrations: 0,
+                checked_in: 0,
+            });
+        }
+        let trend = trends.last_mut().expect("a trend for this group");
+
+        let previous = trend.years.last().filter(|previous| previous.year == row.year - 1);
+        let registrations_change = previous.and_then(|p| change(p.registrations, row.registrations));
+        let checked_in_change = previous.and_then(|p| change(p.checked_in, row.checked_in));
+        trend.years.push(TrendYear {
+            year: row.year,
+            events: row.events,
+            registrations: row.registrations,
+            checked_in: row.checked_in,
+            cancelled: row.cancelled,
+            attendance_rate: attendance_rate(row.registrations, row.checked_in),
+            registrations_change,
+            checked_in_change,
+        });
+        trend.events += row.events;
+        trend.registrations += row.registrations;
+        trend.checked_in += row.checked_in;
+        if trend.group_name.is_none() {
+            trend.group_name = row.group_name.clone();
+        }
+    }
+
+    trends
+}
+
+/// Relative change in percent; nothing to compare against when the year before was zero
+pub fn change(before: i64, after: i64) -> Option<f64> {
+    (before > 0).then(|| round((after - before) as f64 * 100.0 / before as f64))
+}
+
+fn round(percent: f64) -> f64 {
+    (percent * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(group_id: &str, year: i32, registrations: i64, checked_in: i64) -> YearlyAttendance {
+        YearlyAttendance {
+            year,
+            group_id: group_id.to_string(),
+            group_name: Some(group_id.to_uppercase()),
+            events: 1,
+            registrations,
+            checked_in,
+            cancelled: 0,
+        }
+    }
+
+    #[test]
+    fn test_each_year_is_compared_with_the_calendar_year_before() {
+        let trends = attendance_trends(&[
+            row("conf", 2023, 40, 30),
+            row("conf", 2024, 50, 45),
+            row("conf", 2026, 60, 0),
+            row("workshop", 2024, 0, 0),
+            row("workshop", 2025, 3, 1),
+        ]);
+
+        assert_eq!(trends.len(), 2);
+        let conf = &trends[0];
+        assert_eq!((conf.group_name.as_deref(), conf.events, conf.registrations, conf.checked_in), (Some("CONF"), 3, 150, 75));
+        let rates: Vec<_> = conf.years.iter().map(|y| y.attendance_rate).collect();
+        assert_eq!(rates, vec![Some(75.0), Some(90.0), Some(0.0)]);
+        assert_eq!(conf.years[0].registrations_change, None);
+        assert_eq!(conf.years[1].registrations_change, Some(25.0));
+        assert_eq!(conf.years[1].checked_in_change, Some(50.0));
+        // 2025 had no events, so 2026 has nothing to compare with
+        assert_eq!(conf.years[2].registrations_change, None);
+
+        let workshop = &trends[1];
+        assert_eq!(workshop.years[0].attendance_rate, None);
+        assert_eq!(workshop.years[1].registrations_change, None);
+        assert_eq!(workshop.years[1].attendance_rate, Some(33.3));
+    }
+}
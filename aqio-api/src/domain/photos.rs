@@ -0,0 +1,130 @@
+// An event's photo gallery as a single ZIP download. Photos are already
+// compressed, so entries are stored as-is rather than deflated; the archive
+// is plain ZIP without the 64-bit extensions, which the caller keeps within
+// reach by capping the total size.
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+
+use aqio_core::EventPhoto;
+
+/// The ZIP file with every given photo, in order. Entries are numbered so
+/// photos uploaded under the same name don't overwrite each other.
+pub fn zip_photos(photos: &[(EventPhoto, Vec<u8>)]) -> Vec<u8> {
+    let mut archive = Vec::new();
+    let mut directory = Vec::new();
+
+    for (index, (photo, bytes)) in photos.iter().enumerate() {
+        let name = format!("{:03}-{}", index + 1, photo.filename);
+        let (time, date) = dos_timestamp(photo.uploaded_at);
+        let crc = crc32(bytes);
+        let offset = archive.len() as u32;
+
+        archive.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        write_entry_fields(&mut archive, time, date, crc, bytes.len() as u32, name.len() as u16);
+        archive.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(bytes);
+
+        directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        directory.extend_from_slice(&20u16.to_le_bytes()); // made by
+        write_entry_fields(&mut directory, time, date, crc, bytes.len() as u32, name.len() as u16);
+        directory.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        directory.extend_from_slice(&0u16.to_le_bytes()); // comment length
+        directory.extend_from_slice(&0u16.to_le_bytes()); // disk number
+        directory.extend_from_slice(&0u16.to_le_bytes()); // internal attributes
+        directory.extend_from_slice(&0u32.to_le_bytes()); // external attributes
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+
+    let directory_offset = archive.len() as u32;
+    let entries = photos.len() as u16;
+    archive.extend_from_slice(&directory);
+    archive.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes()); // this disk
+    archive.extend_from_slice(&0u16.to_le_bytes()); // disk with the directory
+    archive.extend_from_slice(&entries.to_le_bytes());
+    archive.extend_from_slice(&entries.to_le_bytes());
+    archive.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+    archive.extend_from_slice(&directory_offset.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes()); // comment length
+    archive
+}
+
+// The fields local headers and directory entries share, from "version needed"
+// through the file name length
+fn write_entry_fields(out: &mut Vec<u8>, time: u16, date: u16, crc: u32, size: u32, name_len: u16) {
+    out.extend_from_slice(&20u16.to_le_bytes()); // version needed
+    out.extend_from_slice(&0u16.to_le_bytes()); // flags
+    out.extend_from_slice(&0u16.to_le_bytes()); // stored, no compression
+    out.extend_from_slice(&time.to_le_bytes());
+    out.extend_from_slice(&date.to_le_bytes());
+    out.extend_from_slice(&crc.to_le_bytes());
+    out.extend_from_slice(&size.to_le_bytes()); // compressed size
+    out.extend_from_slice(&size.to_le_bytes()); // uncompressed size
+    out.extend_from_slice(&name_len.to_le_bytes());
+}
+
+// MS-DOS time and date; the format starts in 1980 and counts seconds in twos
+fn dos_timestamp(at: DateTime<Utc>) -> (u16, u16) {
+    let year = at.year().clamp(1980, 2107) as u16;
+    let time = ((at.hour() as u16) << 11) | ((at.minute() as u16) << 5) | (at.second() as u16 / 2);
+    let date = ((year - 1980) << 9) | ((at.month() as u16) << 5) | at.day() as u16;
+    (time, date)
+}
+
+// CRC-32 (IEEE), as ZIP requires for every entry
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestUserBuilder;
+    use aqio_core::PhotoStatus;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn u16_at(bytes: &[u8], at: usize) -> u16 {
+        u16::from_le_bytes([bytes[at], bytes[at + 1]])
+    }
+
+    fn u32_at(bytes: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+    }
+
+    #[test]
+    fn test_photos_are_stored_under_numbered_names() {
+        let uploader = TestUserBuilder::new().build();
+        let event_id = Uuid::new_v4();
+        let mut first = EventPhoto::new(event_id, &uploader, "stage.jpg", "image/jpeg", 5, PhotoStatus::Approved);
+        first.uploaded_at = Utc.with_ymd_and_hms(2026, 5, 14, 18, 30, 10).unwrap();
+        let second = EventPhoto::new(event_id, &uploader, "stage.jpg", "image/jpeg", 3, PhotoStatus::Approved);
+
+        let zip = zip_photos(&[(first, b"hello".to_vec()), (second, b"abc".to_vec())]);
+
+        assert_eq!(u32_at(&zip, 0), 0x0403_4b50);
+        assert_eq!(u16_at(&zip, 10), (18 << 11) | (30 << 5) | 5);
+        assert_eq!(u16_at(&zip, 12), (46 << 9) | (5 << 5) | 14);
+        assert_eq!(u32_at(&zip, 14), 0x3610_a686);
+        assert_eq!(&zip[30..43], b"001-stage.jpg");
+        assert_eq!(&zip[43..48], b"hello");
+        assert_eq!(&zip[48 + 30..48 + 43], b"002-stage.jpg");
+
+        let end = zip.len() - 22;
+        assert_eq!(u32_at(&zip, end), 0x0605_4b50);
+        assert_eq!(u16_at(&zip, end + 10), 2);
+        let directory = u32_at(&zip, end + 16) as usize;
+        assert_eq!(directory, 48 + 30 + 13 + 3);
+        assert_eq!(u32_at(&zip, directory), 0x0201_4b50);
+        assert_eq!(u32_at(&zip, end + 12) as usize, end - directory);
+    }
+}
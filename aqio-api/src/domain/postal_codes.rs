@@ -0,0 +1,140 @@
+// Norwegian postal codes and the regions they lie in. Posten's postal code
+// register lists every code with the municipality (kommune) it belongs to; the
+// county (fylke) is given by the first two digits of the municipality number.
+
+use std::collections::HashMap;
+
+use aqio_core::{AddressRegion, PostalCodeDirectory};
+
+/// County of a municipality number, e.g. `4601` (Bergen) is in Vestland.
+/// The counties merged in 2020 and split again in 2024 are both known, so
+/// older copies of the register still resolve.
+pub fn county_name(municipality_number: &str) -> Option<&'static str> {
+    let county = match municipality_number.get(..2)? {
+        "03" => "Oslo",
+        "11" => "Rogaland",
+        "15" => "Møre og Romsdal",
+        "18" => "Nordland",
+        "21" => "Svalbard",
+        "22" => "Jan Mayen",
+        "30" => "Viken",
+        "31" => "Østfold",
+        "32" => "Akershus",
+        "33" => "Buskerud",
+        "34" => "Innlandet",
+        "38" => "Vestfold og Telemark",
+        "39" => "Vestfold",
+        "40" => "Telemark",
+        "42" => "Agder",
+        "46" => "Vestland",
+        "50" => "Trøndelag",
+        "54" => "Troms og Finnmark",
+        "55" => "Troms",
+        "56" => "Finnmark",
+        _ => return None,
+    };
+    Some(county)
+}
+
+/// The postal code in a one-line address, e.g. `5003` in
+/// `Bryggen 1, 5003 Bergen`: the last group of exactly four digits
+pub fn postal_code_in(address: &str) -> Option<&str> {
+    address
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .map(|token| token.strip_prefix("NO-").unwrap_or(token))
+        .filter(|token| token.len() == 4 && token.chars().all(|c| c.is_ascii_digit()))
+        .next_back()
+}
+
+/// Regions by postal code from the register's tab-separated lines:
+/// postal code, postal town, municipality number, municipality name and
+/// category. Lines that don't parse, and codes in no known county, are skipped.
+pub fn parse_register(text: &str) -> HashMap<String, AddressRegion> {
+    text.lines()
+        .filter_map(|line| {
+            let mut columns = line.split('\t').map(str::trim);
+            let postal_code = columns.next()?;
+            let _town = columns.next()?;
+            let municipality_number = columns.next()?;
+            let municipality = columns.next()?;
+            if postal_code.len() != 4 || !postal_code.chars().all(|c| c.is_ascii_digit()) || municipality.is_empty() {
+                return None;
+            }
+            let county = county_name(municipality_number)?;
+            Some((
+                postal_code.to_string(),
+                AddressRegion {
+                    postal_code: postal_code.to_string(),
+                    municipality: place_name(municipality),
+                    county: county.to_string(),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Region of the postal code in `address`, if it has one the directory
+/// knows. A failed lookup is logged and leaves the address without a region.
+pub async fn region_of(directory: &dyn PostalCodeDirectory, address: &str) -> Option<AddressRegion> {
+    let postal_code = postal_code_in(address)?;
+    match directory.region(postal_code).await {
+        Ok(region) => region,
+        Err(e) => {
+            tracing::warn!("Postal code lookup of {} failed: {}", postal_code, e);
+            None
+        }
+    }
+}
+
+// The register spells names in capitals, e.g. `NORD-AURDAL`
+fn place_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut word_start = true;
+    for c in name.chars() {
+        if word_start {
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+        word_start = c == ' ' || c == '-';
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_postal_codes_are_found_in_addresses() {
+        assert_eq!(postal_code_in("Bryggen 1, 5003 Bergen"), Some("5003"));
+        assert_eq!(postal_code_in("Postboks 1234, NO-0150 Oslo"), Some("0150"));
+        assert_eq!(postal_code_in("0150"), Some("0150"));
+        assert_eq!(postal_code_in("Storgata 12345, Tromsø"), None);
+        assert_eq!(postal_code_in("Bergen"), None);
+    }
+
+    #[test]
+    fn test_register_lines_are_parsed() {
+        let regions = parse_register(
+            "5003\tBERGEN\t4601\tBERGEN\tG\n\
+             2940\tHEGGENES\t3451\tNORD-AURDAL\tG\n\
+             6002\tÅLESUND\t1508\tÅLESUND\tP\n\
+             9999\tNOWHERE\t9901\tNOWHERE\tG\n\
+             not a line",
+        );
+        assert_eq!(regions.len(), 3);
+        assert_eq!(
+            regions["5003"],
+            AddressRegion {
+                postal_code: "5003".to_string(),
+                municipality: "Bergen".to_string(),
+                county: "Vestland".to_string(),
+            }
+        );
+        assert_eq!(regions["2940"].municipality, "Nord-Aurdal");
+        assert_eq!(regions["2940"].county, "Innlandet");
+        assert_eq!(regions["6002"].municipality, "Ålesund");
+        assert_eq!(regions["6002"].county, "Møre og Romsdal");
+    }
+}
@@ -0,0 +1,556 @@
+// Event posters: an A4 page with the event's title on a band in its category
+// color, when and where it is, and a QR code pointing at the public page
+// where guests register. Served as a PDF in the standard Helvetica fonts,
+// like certificates, and as a PNG for sharing on social media. The PNG has
+// no font to draw with, so its text is set in capitals in a built-in 5x7
+// pixel font.
+
+use aqio_core::{Event, LocationType};
+use chrono::{DateTime, Datelike, Utc};
+use chrono_tz::Tz;
+use pdf_writer::{
+    Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr,
+    types::{ActionType, AnnotationType},
+};
+use qrcode::{Color, QrCode};
+
+use crate::domain::certificates::{text_width, win_ansi};
+
+// A4 portrait, in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 48.0;
+const TEXT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+const BAND_HEIGHT: f32 = 300.0;
+const QR_SIZE: f32 = 220.0;
+const QR_Y: f32 = 150.0;
+// Title lines on the band before the font gets smaller
+const MAX_TITLE_LINES: usize = 3;
+
+/// Width of the PNG in pixels: A4 at 150 dpi
+pub const PNG_WIDTH: u32 = 1240;
+const PX_PER_PT: f32 = PNG_WIDTH as f32 / PAGE_WIDTH;
+
+const FONT: Name = Name(b"F1");
+const BOLD: Name = Name(b"F2");
+
+/// Band color of events whose category has none
+pub const DEFAULT_ACCENT: [u8; 3] = [0x1C, 0x5C, 0x8C];
+const DARK: [u8; 3] = [0x1F, 0x29, 0x33];
+const GRAY: [u8; 3] = [0x66, 0x66, 0x66];
+const WHITE: [u8; 3] = [0xFF, 0xFF, 0xFF];
+
+/// Everything printed on a poster
+#[derive(Debug, Clone, PartialEq)]
+pub struct PosterDetails {
+    pub title: String,
+    /// "Wednesday 10 June 2026", or a range when the event spans several days
+    pub date_label: String,
+    /// "09:00 – 17:00", in the event's time zone
+    pub time_label: String,
+    pub location: Option<String>,
+    /// Where the QR code leads
+    pub registration_url: String,
+    /// Color of the title band
+    pub accent: [u8; 3],
+}
+
+impl PosterDetails {
+    /// `accent_hex` is the category's `#RRGGBB` color; anything else gets
+    /// [`DEFAULT_ACCENT`]
+    pub fn for_event(event: &Event, registration_url: impl Into<String>, accent_hex: Option<&str>) -> Self {
+        let timezone: Tz = event.timezone.parse().unwrap_or(chrono_tz::Europe::Oslo);
+        let local = |time: DateTime<Utc>| time.with_timezone(&timezone);
+        let (start, end) = (local(event.start_date), local(event.end_date));
+
+        let date_label = if start.date_naive() == end.date_naive() {
+            start.format("%A %-d %B %Y").to_string()
+        } else if start.year() == end.year() {
+            format!("{} \u{2013} {}", start.format("%-d %B"), end.format("%-d %B %Y"))
+        } else {
+            format!("{} \u{2013} {}", start.format("%-d %B %Y"), end.format("%-d %B %Y"))
+        };
+        let place = event.location_name.clone().or_else(|| event.address.clone());
+        let location = match (event.location_type.clone(), place) {
+            (LocationType::Virtual, _) => Some("Online".to_string()),
+            (LocationType::Hybrid, Some(place)) => Some(format!("{} and online", place)),
+            (LocationType::Hybrid, None) => Some("Online".to_string()),
+            (LocationType::Physical, place) => place,
+        };
+
+        Self {
+            title: event.title.clone(),
+            date_label,
+            time_label: format!("{} \u{2013} {}", start.format("%H:%M"), end.format("%H:%M")),
+            location,
+            registration_url: registration_url.into(),
+            accent: accent_hex.and_then(parse_hex_color).unwrap_or(DEFAULT_ACCENT),
+        }
+    }
+
+    /// Download name, e.g. `poster-havbruk-2026.pdf`
+    pub fn filename(&self, extension: &str) -> String {
+        let slug = self
+            .title
+            .to_lowercase()
+            .replace('æ', "ae")
+            .replace('ø', "o")
+            .replace('å', "a")
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        if slug.is_empty() {
+            format!("poster.{}", extension)
+        } else {
+            format!("poster-{}.{}", slug, extension)
+        }
+    }
+}
+
+/// `#RRGGBB` as RGB bytes
+pub fn parse_hex_color(hex: &str) -> Option<[u8; 3]> {
+    let digits = hex.strip_prefix('#')?;
+    if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+// Dark text on light bands, white on the rest
+fn text_on(accent: [u8; 3]) -> [u8; 3] {
+    let [r, g, b] = accent.map(|channel| channel as f32 / 255.0);
+    if 0.299 * r + 0.587 * g + 0.114 * b > 0.6 { DARK } else { WHITE }
+}
+
+// What goes on the page, in points from the bottom left like PDF
+#[derive(Debug, Clone, PartialEq)]
+enum Element {
+    Band,
+    Text { text: String, size: f32, bold: bool, color: [u8; 3], y: f32 },
+    Qr,
+}
+
+// Lays the poster out; text is centered and wrapped with `measure`, the width
+// of a line in points at a font size
+fn layout(details: &PosterDetails, measure: &dyn Fn(&str, f32) -> f32) -> Vec<Element> {
+    let mut elements = vec![Element::Band];
+    let text = |text: &str, size: f32, bold: bool, color: [u8; 3], y: f32| Element::Text {
+        text: text.to_string(),
+        size,
+        bold,
+        color,
+        y,
+    };
+
+    // Title, as large as fits the band in three lines
+    let (size, lines) = [40.0, 32.0, 26.0]
+        .iter()
+        .map(|&size| (size, wrap(&details.title, size, MAX_TITLE_LINES, measure)))
+        .find(|(size, lines)| lines.iter().all(|line| measure(line, *size) <= TEXT_WIDTH))
+        .unwrap_or_else(|| (22.0, wrap(&details.title, 22.0, MAX_TITLE_LINES, measure)));
+    let line_height = size * 1.2;
+    let band_middle = PAGE_HEIGHT - BAND_HEIGHT / 2.0;
+    let mut y = band_middle + (lines.len() as f32 - 1.0) * line_height / 2.0 - size * 0.35;
+    let title_color = text_on(details.accent);
+    for line in lines {
+        elements.push(text(&line, size, true, title_color, y));
+        y -= line_height;
+    }
+
+    let mut y = PAGE_HEIGHT - BAND_HEIGHT - 60.0;
+    elements.push(text(&details.date_label, 22.0, true, DARK, y));
+    y -= 30.0;
+    elements.push(text(&details.time_label, 18.0, false, DARK, y));
+    if let Some(location) = &details.location {
+        for line in wrap(location, 16.0, 2, measure) {
+            y -= 26.0;
+            elements.push(text(&line, 16.0, false, GRAY, y));
+        }
+    }
+
+    elements.push(Element::Qr);
+    elements.push(text("Scan to register", 16.0, true, details.accent, QR_Y - 26.0));
+    let url_size = fitted_size(&details.registration_url, 10.0, measure);
+    elements.push(text(&details.registration_url, url_size, false, GRAY, QR_Y - 44.0));
+    elements
+}
+
+// Word wrap into at most `max_lines`; the last line takes whatever is left
+// and may still be too wide, which the caller checks
+fn wrap(text: &str, size: f32, max_lines: usize, measure: &dyn Fn(&str, f32) -> f32) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() { word.to_string() } else { format!("{} {}", current, word) };
+        if current.is_empty() || measure(&candidate, size) <= TEXT_WIDTH || lines.len() + 1 == max_lines {
+            current = candidate;
+        } else {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+        }
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+fn fitted_size(text: &str, preferred: f32, measure: &dyn Fn(&str, f32) -> f32) -> f32 {
+    let width = measure(text, preferred);
+    if width <= TEXT_WIDTH {
+        preferred
+    } else {
+        (preferred * TEXT_WIDTH / width).max(5.0)
+    }
+}
+
+fn qr_x() -> f32 {
+    (PAGE_WIDTH - QR_SIZE) / 2.0
+}
+
+/// The poster as a one-page PDF; the QR code is also a link
+pub fn render_poster_pdf(details: &PosterDetails) -> Vec<u8> {
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let page_id = Ref::new(3);
+    let font_id = Ref::new(4);
+    let bold_id = Ref::new(5);
+    let content_id = Ref::new(6);
+    let info_id = Ref::new(7);
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids([page_id]).count(1);
+    pdf.document_info(info_id).title(TextStr(&details.title)).creator(TextStr("Aqio"));
+
+    let mut page = pdf.page(page_id);
+    page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT));
+    page.parent(page_tree_id);
+    page.contents(content_id);
+    page.resources().fonts().pair(FONT, font_id).pair(BOLD, bold_id);
+    page.annotations()
+        .push()
+        .subtype(AnnotationType::Link)
+        .rect(Rect::new(qr_x(), QR_Y, qr_x() + QR_SIZE, QR_Y + QR_SIZE))
+        .action()
+        .action_type(ActionType::Uri)
+        .uri(Str(details.registration_url.as_bytes()));
+    page.finish();
+
+    pdf.type1_font(font_id)
+        .base_font(Name(b"Helvetica"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+    pdf.type1_font(bold_id)
+        .base_font(Name(b"Helvetica-Bold"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+
+    let mut content = Content::new();
+    let rgb = |color: [u8; 3]| color.map(|channel| channel as f32 / 255.0);
+    for element in layout(details, &text_width) {
+        match element {
+            Element::Band => {
+                let [r, g, b] = rgb(details.accent);
+                content.set_fill_rgb(r, g, b);
+                content.rect(0.0, PAGE_HEIGHT - BAND_HEIGHT, PAGE_WIDTH, BAND_HEIGHT);
+                content.fill_nonzero();
+            }
+            Element::Text { text, size, bold, color, y } => {
+                let [r, g, b] = rgb(color);
+                content.set_fill_rgb(r, g, b);
+                content.begin_text();
+                content.set_font(if bold { BOLD } else { FONT }, size);
+                content.next_line((PAGE_WIDTH - text_width(&text, size)) / 2.0, y);
+                content.show(Str(&win_ansi(&text)));
+                content.end_text();
+            }
+            Element::Qr => {
+                let Some((modules, dark)) = qr_modules(&details.registration_url) else { continue };
+                let module_size = QR_SIZE / modules as f32;
+                content.set_fill_gray(0.0);
+                for (column, row) in dark {
+                    content.rect(
+                        qr_x() + column as f32 * module_size,
+                        QR_Y + QR_SIZE - (row + 1) as f32 * module_size,
+                        module_size,
+                        module_size,
+                    );
+                }
+                content.fill_nonzero();
+            }
+        }
+    }
+    pdf.stream(content_id, &content.finish());
+    pdf.finish()
+}
+
+// Width of the QR code in modules and the (column, row) of its dark modules
+fn qr_modules(url: &str) -> Option<(usize, Vec<(usize, usize)>)> {
+    let code = QrCode::new(url.as_bytes()).ok()?;
+    let modules = code.width();
+    let dark = code
+        .to_colors()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, color)| *color == Color::Dark)
+        .map(|(index, _)| (index % modules, index / modules))
+        .collect();
+    Some((modules, dark))
+}
+
+/// The poster as an RGB PNG, [`PNG_WIDTH`] pixels wide
+pub fn render_poster_png(details: &PosterDetails) -> Vec<u8> {
+    let mut canvas = Canvas::new(PNG_WIDTH, (PAGE_HEIGHT * PX_PER_PT).round() as u32);
+    let px = |points: f32| (points * PX_PER_PT).round() as i64;
+    let measure = |text: &str, size: f32| pixel_text_width(text, pixel_scale(size)) as f32 / PX_PER_PT;
+
+    for element in layout(details, &measure) {
+        match element {
+            Element::Band => canvas.fill_rect(0, 0, px(PAGE_WIDTH), px(BAND_HEIGHT), details.accent),
+            Element::Text { text, size, bold, color, y } => {
+                let scale = pixel_scale(size);
+                let width = pixel_text_width(&text, scale);
+                let left = (canvas.width as i64 - width) / 2;
+                let top = px(PAGE_HEIGHT - y) - 7 * scale;
+                canvas.draw_text(&text, left, top, scale, bold, color);
+            }
+            Element::Qr => {
+                let Some((modules, dark)) = qr_modules(&details.registration_url) else { continue };
+                let module_size = px(QR_SIZE) / modules as i64;
+                let size = module_size * modules as i64;
+                let left = (canvas.width as i64 - size) / 2;
+                let top = px(PAGE_HEIGHT - QR_Y - QR_SIZE) + (px(QR_SIZE) - size) / 2;
+                for (column, row) in dark {
+                    canvas.fill_rect(
+                        left + column as i64 * module_size,
+                        top + row as i64 * module_size,
+                        module_size,
+                        module_size,
+                        [0, 0, 0],
+                    );
+                }
+            }
+        }
+    }
+    canvas.encode()
+}
+
+// Pixel size of one dot of the 5x7 font, so capitals match the PDF's cap height
+fn pixel_scale(size: f32) -> i64 {
+    ((size * 0.72 * PX_PER_PT / 7.0).round() as i64).max(1)
+}
+
+// Glyphs are 5 dots wide with one dot between them
+fn pixel_text_width(text: &str, scale: i64) -> i64 {
+    let glyphs = text.chars().count() as i64;
+    (glyphs * 6 - 1).max(0) * scale
+}
+
+struct Canvas {
+    width: u32,
+    height: u32,
+    rgb: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            rgb: vec![0xFF; (width * height * 3) as usize],
+        }
+    }
+
+    fn fill_rect(&mut self, x: i64, y: i64, width: i64, height: i64, color: [u8; 3]) {
+        let (x0, y0) = (x.max(0), y.max(0));
+        let (x1, y1) = ((x + width).min(self.width as i64), (y + height).min(self.height as i64));
+        for row in y0..y1 {
+            for column in x0..x1 {
+                let offset = ((row * self.width as i64 + column) * 3) as usize;
+                self.rgb[offset..offset + 3].copy_from_slice(&color);
+            }
+        }
+    }
+
+    // Bold widens each dot by half to the right
+    fn draw_text(&mut self, text: &str, left: i64, top: i64, scale: i64, bold: bool, color: [u8; 3]) {
+        let dot_width = if bold { scale + scale / 2 } else { scale };
+        let mut x = left;
+        for c in text.chars() {
+            for (row, bits) in glyph(c).iter().enumerate() {
+                for column in 0..5 {
+                    if bits & (0b10000 >> column) != 0 {
+                        self.fill_rect(x + column * scale, top + row as i64 * scale, dot_width, scale, color);
+                    }
+                }
+            }
+            x += 6 * scale;
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        // Writing to a Vec only fails on a size mismatch, which `new` rules out
+        if let Ok(mut writer) = encoder.write_header() {
+            let _ = writer.write_image_data(&self.rgb);
+        }
+        bytes
+    }
+}
+
+// 5x7 capitals, digits and common punctuation, one row per byte with the
+// leftmost dot in bit 4. Lowercase is drawn as capitals; anything else as '?'.
+fn glyph(c: char) -> [u8; 7] {
+    let c = match c.to_uppercase().next().unwrap_or(c) {
+        'À' | 'Á' | 'Â' | 'Ã' => 'A',
+        'Ä' => 'Æ',
+        'Ç' => 'C',
+        'È' | 'É' | 'Ê' | 'Ë' => 'E',
+        'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' => 'O',
+        'Ö' => 'Ø',
+        'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+        '\u{2013}' | '\u{2014}' => '-',
+        '\u{2018}' | '\u{2019}' => '\'',
+        '\u{201C}' | '\u{201D}' => '"',
+        c => c,
+    };
+    match c {
+        ' ' => [0; 7],
+        'A' => [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'B' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110],
+        'C' => [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110],
+        'D' => [0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100],
+        'E' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111],
+        'F' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000],
+        'G' => [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111],
+        'H' => [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'I' => [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        'J' => [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100],
+        'K' => [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001],
+        'L' => [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111],
+        'M' => [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001],
+        'N' => [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001],
+        'O' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'P' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000],
+        'Q' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101],
+        'R' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001],
+        'S' => [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110],
+        'T' => [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100],
+        'U' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'V' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100],
+        'W' => [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010],
+        'X' => [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001],
+        'Y' => [0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100],
+        'Z' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111],
+        'Æ' => [0b01111, 0b10100, 0b10100, 0b11111, 0b10100, 0b10100, 0b10111],
+        'Ø' => [0b01110, 0b10011, 0b10101, 0b10101, 0b10101, 0b11001, 0b01110],
+        'Å' => [0b00100, 0b01010, 0b01110, 0b10001, 0b11111, 0b10001, 0b10001],
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        '.' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100],
+        ',' => [0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000],
+        ':' => [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000],
+        ';' => [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b00100, 0b01000],
+        '-' => [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000],
+        '_' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111],
+        '+' => [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000],
+        '=' => [0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000],
+        '/' => [0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000],
+        '&' => [0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101],
+        '\'' => [0b01100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000],
+        '"' => [0b01010, 0b01010, 0b01010, 0b00000, 0b00000, 0b00000, 0b00000],
+        '!' => [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100],
+        '(' => [0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010],
+        ')' => [0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000],
+        '#' => [0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010],
+        '%' => [0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011],
+        '@' => [0b01110, 0b10001, 0b00001, 0b01101, 0b10101, 0b10101, 0b01110],
+        _ => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestEventBuilder;
+    use chrono::TimeZone;
+
+    fn details() -> PosterDetails {
+        let mut event = TestEventBuilder::new().with_title("Havbruk & Sjømat 2026").build();
+        event.start_date = Utc.with_ymd_and_hms(2026, 6, 10, 7, 0, 0).unwrap();
+        event.end_date = Utc.with_ymd_and_hms(2026, 6, 10, 15, 0, 0).unwrap();
+        event.timezone = "Europe/Oslo".to_string();
+        event.location_type = LocationType::Hybrid;
+        event.location_name = Some("Sjøsiden, Bergen".to_string());
+        PosterDetails::for_event(&event, "https://aqio.example/e/havbruk", Some("#F4D35E"))
+    }
+
+    #[test]
+    fn test_details_use_local_time_and_category_color() {
+        let details = details();
+        assert_eq!(details.date_label, "Wednesday 10 June 2026");
+        assert_eq!(details.time_label, "09:00 \u{2013} 17:00");
+        assert_eq!(details.location.as_deref(), Some("Sjøsiden, Bergen and online"));
+        assert_eq!(details.accent, [0xF4, 0xD3, 0x5E]);
+        // A light band gets dark text
+        assert_eq!(text_on(details.accent), DARK);
+        assert_eq!(text_on(DEFAULT_ACCENT), WHITE);
+        assert_eq!(details.filename("png"), "poster-havbruk-sjomat-2026.png");
+        assert_eq!(parse_hex_color("1C5C8C"), None);
+        assert_eq!(parse_hex_color("#1c5c8c"), Some(DEFAULT_ACCENT));
+    }
+
+    #[test]
+    fn test_long_titles_wrap_within_the_page() {
+        let mut details = details();
+        details.title = "The annual Norwegian aquaculture technology, feed and fish health conference".to_string();
+        let elements = layout(&details, &text_width);
+        let titles: Vec<(String, f32)> = elements
+            .iter()
+            .filter_map(|element| match element {
+                Element::Text { text, size, bold: true, y, .. } if *y > PAGE_HEIGHT - BAND_HEIGHT => {
+                    Some((text.clone(), *size))
+                }
+                _ => None,
+            })
+            .collect();
+        assert!(titles.len() > 1 && titles.len() <= MAX_TITLE_LINES, "{:?}", titles);
+        assert!(titles.iter().all(|(line, size)| text_width(line, *size) <= TEXT_WIDTH));
+        assert_eq!(wrap("", 20.0, 2, &text_width), vec![String::new()]);
+    }
+
+    #[test]
+    fn test_renders_pdf_and_png() {
+        let details = details();
+        let pdf = render_poster_pdf(&details);
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(pdf.windows(b"/URI".len()).any(|window| window == b"/URI"));
+
+        let png = render_poster_png(&details);
+        let decoder = png::Decoder::new(std::io::Cursor::new(png));
+        let reader = decoder.read_info().unwrap();
+        assert_eq!(reader.info().width, PNG_WIDTH);
+        assert_eq!(reader.info().height, 1755);
+    }
+
+    #[test]
+    fn test_pixel_font() {
+        assert_eq!(glyph('a'), glyph('A'));
+        assert_eq!(glyph('ø'), glyph('Ø'));
+        assert_eq!(glyph('\u{2013}'), glyph('-'));
+        assert_eq!(glyph('✓'), glyph('?'));
+        assert_eq!(pixel_text_width("AB", 2), 22);
+    }
+}
@@ -0,0 +1,71 @@
+// Per-user request limits for endpoints that are cheap to call but expensive
+// to answer, such as user search. Counts are kept in memory with a fixed
+// window per user, so each API instance limits on its own.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::domain::{ApiError, ApiResult};
+
+/// Windows kept before ended ones are dropped, so the map doesn't grow with
+/// every user who ever called
+const PRUNE_THRESHOLD: usize = 10_000;
+
+#[derive(Clone)]
+pub struct RateLimiter {
+    max_requests: u32,
+    window: Duration,
+    windows: Arc<Mutex<HashMap<Uuid, (Instant, u32)>>>,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        Self { max_requests, window, windows: Arc::new(Mutex::new(HashMap::new())) }
+    }
+
+    /// Counts a request by `user_id`, refusing it with `RateLimit` once the
+    /// user has made `max_requests` in the current window
+    pub fn check(&self, user_id: Uuid) -> ApiResult<()> {
+        self.check_at(user_id, Instant::now())
+    }
+
+    fn check_at(&self, user_id: Uuid, now: Instant) -> ApiResult<()> {
+        let mut windows = self.windows.lock().unwrap_or_else(PoisonError::into_inner);
+        if windows.len() >= PRUNE_THRESHOLD {
+            windows.retain(|_, (started, _)| now.duration_since(*started) < self.window);
+        }
+
+        let (started, count) = windows.entry(user_id).or_insert((now, 0));
+        if now.duration_since(*started) >= self.window {
+            *started = now;
+            *count = 0;
+        }
+        if *count >= self.max_requests {
+            return Err(ApiError::RateLimit);
+        }
+        *count += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refuses_requests_over_the_limit_until_the_window_ends() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let user = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let start = Instant::now();
+
+        assert!(limiter.check_at(user, start).is_ok());
+        assert!(limiter.check_at(user, start + Duration::from_secs(1)).is_ok());
+        assert!(matches!(limiter.check_at(user, start + Duration::from_secs(2)), Err(ApiError::RateLimit)));
+        assert!(limiter.check_at(other, start + Duration::from_secs(2)).is_ok());
+        assert!(limiter.check_at(user, start + Duration::from_secs(60)).is_ok());
+    }
+}
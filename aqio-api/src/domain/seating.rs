@@ -0,0 +1,533 @@
+// Seating plans for gala dinners: which table each confirmed registration
+// (with its guests) sits at, warnings for the organizer to weigh, and a
+// printable chart. Warnings don't block anything; a table may knowingly be
+// squeezed, or colleagues split up on purpose. The chart is an A4 PDF in the
+// standard Helvetica fonts, like certificates: the tables in two columns,
+// then an alphabetical list for guests looking for their table.
+
+use std::collections::{BTreeMap, HashMap};
+
+use aqio_core::{EventRegistration, SeatAssignment, SeatingTable};
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use uuid::Uuid;
+
+use crate::domain::catering::{accessibility_needs, is_confirmed, party_size};
+use crate::domain::certificates::{text_width, win_ansi};
+
+// A4 portrait, in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 48.0;
+const GUTTER: f32 = 24.0;
+const COLUMN_WIDTH: f32 = (PAGE_WIDTH - 2.0 * MARGIN - GUTTER) / 2.0;
+const TITLE_SIZE: f32 = 20.0;
+const HEADING_SIZE: f32 = 12.0;
+const TEXT_SIZE: f32 = 10.0;
+const LINE_HEIGHT: f32 = 13.0;
+
+const FONT: Name = Name(b"F1");
+const BOLD: Name = Name(b"F2");
+
+/// A confirmed registration and the guests it brings, seated together
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatedParty {
+    pub registration_id: Uuid,
+    pub name: String,
+    pub company: Option<String>,
+    pub guest_names: Vec<String>,
+    /// Seats taken: the registrant plus their guests
+    pub seats: usize,
+    /// Labelled like in the catering report
+    pub accessibility_needs: Vec<String>,
+}
+
+impl SeatedParty {
+    fn from_registration(registration: &EventRegistration) -> Self {
+        let name = registration
+            .registrant_name
+            .clone()
+            .or_else(|| registration.registrant_email.clone())
+            .unwrap_or_else(|| "Guest".to_string());
+        let company = registration
+            .registrant_company
+            .as_deref()
+            .map(str::trim)
+            .filter(|company| !company.is_empty())
+            .map(str::to_string);
+        Self {
+            registration_id: registration.id,
+            name,
+            company,
+            guest_names: registration.guest_names.clone(),
+            seats: party_size(registration),
+            accessibility_needs: accessibility_needs(registration),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedTable {
+    pub table: SeatingTable,
+    /// By name
+    pub parties: Vec<SeatedParty>,
+}
+
+impl PlannedTable {
+    pub fn seats_taken(&self) -> usize {
+        self.parties.iter().map(|party| party.seats).sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeatingWarning {
+    /// More people at the table than it has seats
+    OverCapacity { table_id: Uuid, table: String, seats_taken: usize, capacity: usize },
+    /// People from the same company at different tables
+    CompanySplit { company: String, table_ids: Vec<Uuid>, tables: Vec<String> },
+    /// Someone with accessibility needs at a table that isn't accessible
+    NeedsAccessibleTable {
+        registration_id: Uuid,
+        name: String,
+        table_id: Uuid,
+        table: String,
+        needs: Vec<String>,
+    },
+}
+
+impl SeatingWarning {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SeatingWarning::OverCapacity { .. } => "over_capacity",
+            SeatingWarning::CompanySplit { .. } => "company_split",
+            SeatingWarning::NeedsAccessibleTable { .. } => "needs_accessible_table",
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            SeatingWarning::OverCapacity { table, seats_taken, capacity, .. } => {
+                format!("{} has {} people for {} seats", table, seats_taken, capacity)
+            }
+            SeatingWarning::CompanySplit { company, tables, .. } => {
+                format!("{} is spread over {}", company, tables.join(", "))
+            }
+            SeatingWarning::NeedsAccessibleTable { name, table, needs, .. } => {
+                format!("{} needs {}, but {} isn't accessible", name, needs.join(", ").to_lowercase(), table)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatingPlan {
+    /// By position
+    pub tables: Vec<PlannedTable>,
+    /// Confirmed registrations without a table, by company and name so
+    /// colleagues are next to each other
+    pub unassigned: Vec<SeatedParty>,
+    pub warnings: Vec<SeatingWarning>,
+}
+
+impl SeatingPlan {
+    pub fn capacity(&self) -> usize {
+        self.tables.iter().map(|planned| planned.table.capacity.max(0) as usize).sum()
+    }
+
+    pub fn seated_people(&self) -> usize {
+        self.tables.iter().map(PlannedTable::seats_taken).sum()
+    }
+
+    pub fn unassigned_people(&self) -> usize {
+        self.unassigned.iter().map(|party| party.seats).sum()
+    }
+}
+
+/// Seats the confirmed registrations; assignments of registrations that were
+/// cancelled since are left out
+pub fn build_plan(
+    tables: Vec<SeatingTable>,
+    assignments: &[SeatAssignment],
+    registrations: &[EventRegistration],
+) -> SeatingPlan {
+    let table_of: HashMap<Uuid, Uuid> = assignments
+        .iter()
+        .map(|assignment| (assignment.registration_id, assignment.table_id))
+        .collect();
+    let mut planned: Vec<PlannedTable> = tables
+        .into_iter()
+        .map(|table| PlannedTable { table, parties: Vec::new() })
+        .collect();
+    let mut unassigned = Vec::new();
+    for registration in registrations.iter().filter(|registration| is_confirmed(registration)) {
+        let party = SeatedParty::from_registration(registration);
+        let table = table_of
+            .get(&registration.id)
+            .and_then(|table_id| planned.iter_mut().find(|planned| planned.table.id == *table_id));
+        match table {
+            Some(table) => table.parties.push(party),
+            None => unassigned.push(party),
+        }
+    }
+
+    let by_name = |a: &SeatedParty, b: &SeatedParty| a.name.to_lowercase().cmp(&b.name.to_lowercase());
+    for table in &mut planned {
+        table.parties.sort_by(by_name);
+    }
+    unassigned.sort_by(|a, b| {
+        let company = |party: &SeatedParty| party.company.as_ref().map(|company| company.to_lowercase());
+        // Parties without a company last
+        (company(a).is_none(), company(a))
+            .cmp(&(company(b).is_none(), company(b)))
+            .then_with(|| by_name(a, b))
+    });
+
+    let warnings = warnings(&planned);
+    SeatingPlan { tables: planned, unassigned, warnings }
+}
+
+fn warnings(tables: &[PlannedTable]) -> Vec<SeatingWarning> {
+    let mut warnings = Vec::new();
+    for planned in tables {
+        let capacity = planned.table.capacity.max(0) as usize;
+        if planned.seats_taken() > capacity {
+            warnings.push(SeatingWarning::OverCapacity {
+                table_id: planned.table.id,
+                table: planned.table.name.clone(),
+                seats_taken: planned.seats_taken(),
+                capacity,
+            });
+        }
+    }
+
+    // Keyed by the lowercased name, so "Aqio AS" and "aqio as" are one company
+    let mut companies: BTreeMap<String, (String, Vec<&PlannedTable>)> = BTreeMap::new();
+    for planned in tables {
+        for company in planned.parties.iter().filter_map(|party| party.company.as_ref()) {
+            let entry = companies
+                .entry(company.to_lowercase())
+                .or_insert_with(|| (company.clone(), Vec::new()));
+            if !entry.1.iter().any(|seen| seen.table.id == planned.table.id) {
+                entry.1.push(planned);
+            }
+        }
+    }
+    for (company, seated_at) in companies.into_values().filter(|(_, seated_at)| seated_at.len() > 1) {
+        warnings.push(SeatingWarning::CompanySplit {
+            company,
+            table_ids: seated_at.iter().map(|planned| planned.table.id).collect(),
+            tables: seated_at.iter().map(|planned| planned.table.name.clone()).collect(),
+        });
+    }
+
+    for planned in tables.iter().filter(|planned| !planned.table.accessible) {
+        for party in planned.parties.iter().filter(|party| !party.accessibility_needs.is_empty()) {
+            warnings.push(SeatingWarning::NeedsAccessibleTable {
+                registration_id: party.registration_id,
+                name: party.name.clone(),
+                table_id: planned.table.id,
+                table: planned.table.name.clone(),
+                needs: party.accessibility_needs.clone(),
+            });
+        }
+    }
+    warnings
+}
+
+/// Download name, e.g. `seating-gala-2026.pdf`
+pub fn chart_filename(slug: &str) -> String {
+    format!("seating-{}.pdf", slug)
+}
+
+// A line of text on the chart, in points from the bottom left like PDF
+#[derive(Debug, Clone, PartialEq)]
+struct Line {
+    text: String,
+    x: f32,
+    y: f32,
+    size: f32,
+    bold: bool,
+}
+
+// Fills the columns of one page after another
+struct Flow {
+    pages: Vec<Vec<Line>>,
+    column: usize,
+    y: f32,
+    top: f32,
+}
+
+impl Flow {
+    fn new() -> Self {
+        let top = PAGE_HEIGHT - MARGIN;
+        Self {
+            pages: vec![Vec::new()],
+            column: 0,
+            y: top,
+            top,
+        }
+    }
+
+    fn x(&self) -> f32 {
+        MARGIN + self.column as f32 * (COLUMN_WIDTH + GUTTER)
+    }
+
+    // Full-width text at the top of the current page, such as a title
+    fn heading(&mut self, text: &str, size: f32) {
+        let line = Line {
+            text: fit(text, size, true, PAGE_WIDTH - 2.0 * MARGIN),
+            x: MARGIN,
+            y: self.y - size,
+            size,
+            bold: true,
+        };
+        self.pages.last_mut().expect("a page").push(line);
+        self.y -= size * 1.6;
+        self.top = self.y;
+    }
+
+    // Moves to the next column, or page, unless `height` fits below
+    fn keep(&mut self, height: f32) {
+        if self.y - height >= MARGIN || self.y == self.top {
+            return;
+        }
+        if self.column == 0 {
+            self.column = 1;
+        } else {
+            self.pages.push(Vec::new());
+            self.column = 0;
+            self.top = PAGE_HEIGHT - MARGIN;
+        }
+        self.y = self.top;
+    }
+
+    fn line(&mut self, text: &str, size: f32, bold: bool, indent: f32) {
+        self.keep(LINE_HEIGHT);
+        let line = Line {
+            text: fit(text, size, bold, COLUMN_WIDTH - indent),
+            x: self.x() + indent,
+            y: self.y - size,
+            size,
+            bold,
+        };
+        self.pages.last_mut().expect("a page").push(line);
+        self.y -= LINE_HEIGHT;
+    }
+
+    fn space(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    fn new_page(&mut self) {
+        if self.pages.last().is_some_and(|page| !page.is_empty()) {
+            self.pages.push(Vec::new());
+        }
+        self.column = 0;
+        self.top = PAGE_HEIGHT - MARGIN;
+        self.y = self.top;
+    }
+}
+
+// Shortens text wider than `width` with an ellipsis
+fn fit(text: &str, size: f32, bold: bool, width: f32) -> String {
+    // Helvetica-Bold runs about a tenth wider than the metrics measured
+    let measure = |text: &str| text_width(text, size) * if bold { 1.1 } else { 1.0 };
+    if measure(text) <= width {
+        return text.to_string();
+    }
+    let mut fitted: String = text.to_string();
+    while !fitted.is_empty() && measure(&format!("{}...", fitted)) > width {
+        fitted.pop();
+    }
+    format!("{}...", fitted.trim_end())
+}
+
+fn unnamed_guests(party: &SeatedParty) -> usize {
+    (party.seats - 1).saturating_sub(party.guest_names.len())
+}
+
+fn layout(event_title: &str, plan: &SeatingPlan) -> Vec<Vec<Line>> {
+    let mut flow = Flow::new();
+    flow.heading(&format!("Seating chart \u{2013} {}", event_title), TITLE_SIZE);
+    for planned in &plan.tables {
+        let lines: usize = planned.parties.iter().map(|party| 1 + party.guest_names.len() + usize::from(unnamed_guests(party) > 0)).sum();
+        // Keep a table on one column when it fits
+        flow.keep((lines.max(1) + 1) as f32 * LINE_HEIGHT);
+        flow.line(
+            &format!("{} ({}/{})", planned.table.name, planned.seats_taken(), planned.table.capacity),
+            HEADING_SIZE,
+            true,
+            0.0,
+        );
+        if planned.parties.is_empty() {
+            flow.line("Nobody seated yet", TEXT_SIZE, false, 8.0);
+        }
+        for party in &planned.parties {
+            let name = match &party.company {
+                Some(company) => format!("{}, {}", party.name, company),
+                None => party.name.clone(),
+            };
+            flow.line(&name, TEXT_SIZE, false, 8.0);
+            for guest in &party.guest_names {
+                flow.line(&format!("+ {}", guest), TEXT_SIZE, false, 16.0);
+            }
+            let unnamed = unnamed_guests(party);
+            if unnamed > 0 {
+                flow.line(&format!("+ {} guest{}", unnamed, if unnamed == 1 { "" } else { "s" }), TEXT_SIZE, false, 16.0);
+            }
+        }
+        flow.space(LINE_HEIGHT);
+    }
+
+    let mut index: Vec<(String, &str)> = plan
+        .tables
+        .iter()
+        .flat_map(|planned| {
+            planned.parties.iter().flat_map(move |party| {
+                std::iter::once(party.name.clone())
+                    .chain(party.guest_names.iter().cloned())
+                    .map(move |name| (name, planned.table.name.as_str()))
+            })
+        })
+        .collect();
+    if !index.is_empty() {
+        index.sort_by_key(|(name, _)| name.to_lowercase());
+        flow.new_page();
+        flow.heading("Find your table", TITLE_SIZE);
+        for (name, table) in index {
+            flow.line(&format!("{} \u{2013} {}", name, table), TEXT_SIZE, false, 0.0);
+        }
+    }
+    flow.pages
+}
+
+/// The chart as a PDF of one or more A4 pages
+pub fn render_chart_pdf(event_title: &str, plan: &SeatingPlan) -> Vec<u8> {
+    let pages = layout(event_title, plan);
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let font_id = Ref::new(3);
+    let bold_id = Ref::new(4);
+    let info_id = Ref::new(5);
+    let page_ids: Vec<Ref> = (0..pages.len()).map(|i| Ref::new(6 + 2 * i as i32)).collect();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids(page_ids.iter().copied()).count(pages.len() as i32);
+    pdf.document_info(info_id)
+        .title(TextStr(&format!("Seating chart - {}", event_title)))
+        .creator(TextStr("Aqio"));
+    pdf.type1_font(font_id)
+        .base_font(Name(b"Helvetica"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+    pdf.type1_font(bold_id)
+        .base_font(Name(b"Helvetica-Bold"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+
+    for (lines, page_id) in pages.iter().zip(&page_ids) {
+        let content_id = Ref::new(page_id.get() + 1);
+        let mut page = pdf.page(*page_id);
+        page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT));
+        page.parent(page_tree_id);
+        page.contents(content_id);
+        page.resources().fonts().pair(FONT, font_id).pair(BOLD, bold_id);
+        page.finish();
+
+        let mut content = Content::new();
+        content.set_fill_gray(0.1);
+        for line in lines {
+            content.begin_text();
+            content.set_font(if line.bold { BOLD } else { FONT }, line.size);
+            content.next_line(line.x, line.y);
+            content.show(Str(&win_ansi(&line.text)));
+            content.end_text();
+        }
+        pdf.stream(content_id, &content.finish());
+    }
+    pdf.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestRegistrationBuilder;
+    use chrono::Utc;
+
+    fn party(name: &str, company: Option<&str>, accessibility: Option<&str>) -> EventRegistration {
+        let mut registration = TestRegistrationBuilder::new().build();
+        registration.registrant_name = Some(name.to_string());
+        registration.registrant_company = company.map(str::to_string);
+        registration.accessibility_needs = accessibility.map(str::to_string);
+        registration
+    }
+
+    fn seat(registration: &EventRegistration, table: &SeatingTable) -> SeatAssignment {
+        SeatAssignment {
+            registration_id: registration.id,
+            table_id: table.id,
+            event_id: table.event_id,
+            assigned_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_warns_about_full_tables_split_companies_and_accessibility() {
+        let event_id = Uuid::new_v4();
+        let head = SeatingTable { accessible: true, ..SeatingTable::new(event_id, "Head table", 4, 0) };
+        let second = SeatingTable::new(event_id, "Table 2", 2, 1);
+        let kari = party("Kari Nordmann", Some("Mowi ASA"), Some("Wheelchair"));
+        let ola = EventRegistration {
+            guest_count: 2,
+            guest_names: vec!["Per".to_string()],
+            ..party("Ola Nordmann", Some("mowi asa "), None)
+        };
+        let lise = party("Lise", None, Some("Rullestol"));
+        let waiting = party("Anne", Some("Lerøy"), None);
+        let cancelled = EventRegistration {
+            status: aqio_core::RegistrationStatus::Cancelled,
+            ..party("Gone", None, None)
+        };
+        let assignments = [seat(&kari, &head), seat(&ola, &second), seat(&lise, &second), seat(&cancelled, &second)];
+
+        let plan = build_plan(
+            vec![head.clone(), second.clone()],
+            &assignments,
+            &[kari.clone(), ola.clone(), lise.clone(), waiting.clone(), cancelled],
+        );
+        assert_eq!(plan.tables[1].parties.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), vec!["Lise", "Ola Nordmann"]);
+        assert_eq!((plan.capacity(), plan.seated_people(), plan.unassigned_people()), (6, 5, 1));
+        assert_eq!(plan.unassigned[0].registration_id, waiting.id);
+
+        let messages: Vec<String> = plan.warnings.iter().map(SeatingWarning::message).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Table 2 has 4 people for 2 seats",
+                "Mowi ASA is spread over Head table, Table 2",
+                "Lise needs wheelchair / step-free access, but Table 2 isn't accessible",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chart_runs_over_several_pages() {
+        let event_id = Uuid::new_v4();
+        let tables: Vec<SeatingTable> = (1..=12).map(|n| SeatingTable::new(event_id, format!("Table {}", n), 10, n)).collect();
+        let registrations: Vec<EventRegistration> = (0..120).map(|n| party(&format!("Guest {}", n), None, None)).collect();
+        let assignments: Vec<SeatAssignment> = registrations
+            .iter()
+            .enumerate()
+            .map(|(n, registration)| seat(registration, &tables[n % tables.len()]))
+            .collect();
+        let plan = build_plan(tables, &assignments, &registrations);
+
+        let pages = layout("Gallamiddag", &plan);
+        assert!(pages.len() >= 3, "{} pages", pages.len());
+        assert!(pages.iter().flatten().all(|line| line.y >= MARGIN && line.x + text_width(&line.text, line.size) <= PAGE_WIDTH));
+        assert_eq!(pages.iter().flatten().filter(|line| line.text.ends_with("\u{2013} Table 3")).count(), 10);
+
+        let pdf = render_chart_pdf("Gallamiddag", &plan);
+        assert!(pdf.starts_with(b"%PDF-"));
+        let count = format!("/Count {}", pages.len());
+        assert!(pdf.windows(count.len()).any(|window| window == count.as_bytes()));
+    }
+}
@@ -1,16 +1,133 @@
 // Application services that orchestrate domain logic and coordinate between layers
 
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
 use uuid::Uuid;
 
-use crate::domain::dto::{CreateEventRequest, ListEventsQuery};
+use crate::domain::annual_report::{AnnualReport, render_annual_report};
+use crate::domain::anonymize::Anonymizer;
+use crate::domain::archive::{ARCHIVE_FORMAT_VERSION, EventArchive};
+use crate::domain::broadcasts::render_broadcast_email;
+use crate::domain::calendar::{render_event_ics, render_meeting_invite, render_shifts_ics};
+use crate::domain::catering::{self, CateringReport};
+use crate::domain::consents::{self, ConsentReport, Registrant};
+use crate::domain::crm::SyncSource;
+use crate::domain::certificates::{CertificateDetails, SignatureImage, render_certificate};
+use crate::domain::digest::render_digest;
+use crate::domain::ehf::render_ehf;
+use crate::domain::dto::{
+    CreateEventRequest, Hotel, ListEventsQuery, ParkingInfo, TransportOption, TravelInfo, TravelOptionResponse,
+    TravelOptionsQuery, TravelOptionsResponse,
+};
 use crate::domain::errors::{ApiError, ApiResult};
+use crate::domain::company_registry::normalize_org_number;
+use crate::domain::event_fields;
+use crate::domain::exports::ExportTable;
+use crate::domain::financial_report::{self, FinancialReport};
+use crate::domain::leads::{self, CapturedLead, LeadAnalytics, LeadContact};
+use crate::domain::live_updates::{LiveUpdate, LiveUpdateNotifier};
+use crate::domain::locale::{DEFAULT_TIMEZONE, Localization};
+use crate::domain::matchmaking::{self, Match, MeetingDetails};
+use crate::domain::moderation::{ModerationEmail, render_moderation_email};
+use crate::domain::notifications::{AddressedNotification, RegistrationNotification, RegistrationNotifier};
+use crate::domain::onboarding::render_organization_invitation;
+use crate::domain::ownership::{OwnershipEmail, render_ownership_email};
+use crate::domain::past_events::{self, AttendanceTrend};
+use crate::domain::photos::zip_photos;
+use crate::domain::postal_codes;
+use crate::domain::posters::{PosterDetails, render_poster_pdf, render_poster_png};
+use crate::domain::rate_limit::RateLimiter;
+use crate::domain::seating::{self, SeatingPlan};
+use crate::domain::shifts::{self, CoverageReport};
+use crate::domain::structured_data::{self, SitemapEntry};
+use crate::domain::weather;
+use crate::domain::web_vitals::{self, WebVitalSummary};
+use crate::config::InvoicingConfig;
 use aqio_core::{
-    DomainError, Event, EventCategory, EventCategoryRepository, EventInvitation,
-    EventInvitationRepository, EventRegistration, EventRegistrationRepository, EventRepository, 
-    EventService, InvitationStatus, PaginatedResult, PaginationParams, RegistrationStatus, User, UserRepository,
+    AttachmentCategory, AttachmentVisibility, AuditAction, BudgetLine, BudgetSummary, AuditLogEntry, AuditLogFilter, AuditLogRepository, Company, CompanyDirectoryEntry, CompanyFilter,
+    CompanyRepository, DomainError, Event, EventAttachment, EventAttachmentRepository, EventCategory, EventCategoryRepository, EventComment,
+    EventBudgetRepository, EventCommentRepository, EventExpense, EventFilter, EventInvitation, ExpenseReceipt,
+    EventInvitationRepository, EventPoll, EventPollRepository, EventQuestion, EventQuestionRepository,
+    EventRegistration, EventRegistrationRepository, EventRepository, EventSponsor, EventSponsorRepository,
+    EventService, EventStatus, EventWebhooks, EventWithDetails, InvitationFunnel, InvitationService, InvitationStatus, MediaStorage, PaginatedResult, PaginationParams,
+    PollVote, RegistrationCounts, RegistrationService, RegistrationStatus, SponsorTier, User, UserRepository,
+    BlackoutPeriod, RuleEnforcement, ScheduleConflict, SchedulingPolicy, SchedulingPolicyRepository, SchedulingService, UserRole,
+    StorageQuotaRepository, StorageUsage,
+    DeliveryFailure, EmailSuppression, EmailSuppressionRepository,
+    DigestFrequency, DigestItem, Mailer, NotificationDigestRepository, NotificationDigestService, NotificationPreferences,
+    DnsRecordType, DnsResolver, MailPlatform, SendingDomain, SendingDomainLookup, SendingDomainRepository, SendingDomainService,
+    OrganizationInvitation, OrganizationInvitationRepository, OrganizationInvitationStatus,
+    EventOwnershipTransfer, EventOwnershipTransferRepository, OwnershipTransferStatus,
+    EventSummary, EventSummaryRepository, DomainResult, ArchiveGrouping, PastEventFilter,
+    EventSubmission, EventSubmissionRepository, EventSubmissionStatus,
+    ContentReport, ContentReportRepository, ModerationAction, ReportReason, ReportStatus, ReportTarget,
+    ConsentKind, EventConsent, EventConsentRepository, RegistrationConsent,
+    UserMatch,
+    BroadcastAudience, BroadcastDelivery, BroadcastStatus, EmailMessage, EmailSender, EventBroadcast, EventBroadcastRepository,
+    MessageThread, MessageThreadRepository, ThreadMessage,
+    EventPhoto, EventPhotoRepository, PhotoAlbum, PhotoStatus,
+    AnalyticsRepository, OrganizationYear,
+    EventFieldDefinition, EventFieldRepository, EventFieldType, EventFieldValue,
+    CompanyRegistry, IndustryType, RegisteredCompany,
+    AddressRegion, PostalCodeDirectory, WeatherForecast, WeatherForecaster, TravelPlanner, TravelQuery,
+    LocationType,
+    CampaignParameters, ShortLink, ShortLinkClick, ShortLinkClicks, ShortLinkRepository, ShortLinkStats,
+    NoShowHistory, OverbookingPolicy, OverbookingPolicyRepository,
+    Currency, CurrencyRevenue, ExchangeRate, ExchangeRateProvider, OrderStatus, TicketOrder, TicketRepository, TicketType,
+    Invoice, InvoiceFilter, InvoiceParty, InvoiceRepository, InvoiceStatus, VAT_RATES,
+    PaymentProvider, RefundStatus, TicketRefund, TicketRefundRepository,
+    SeatAssignment, SeatingRepository, SeatingTable,
+    AvailabilityWindow, Resource, ResourceBooking, ResourceConflict, ResourceRepository,
+    EventShift, ShiftRepository, ShiftSignup, Booth, Exhibitor, ExhibitorRepository, Lead, LeadRepository,
+    MatchmakingProfile, MatchmakingRepository, Meeting, MeetingStatus,
+    WebVitalMetric, WebVitalRepository, WebVitalSample,
+    AccessGrant, AccessGrantRepository, OrganizationRole, OrganizationRoleRepository, Permission, PermissionChecker,
+    ExportDestination, ExportEntity, ExportFilter, ExportFormat, ExportJob, ExportJobRepository, ExportUploader,
+    CrmClient, CrmConflictPolicy, CrmConnector, CrmConnectorRepository, CrmFieldMapping, CrmProvider, CrmRecordKind,
+    CrmResolution, CrmSyncCounts, CrmSyncRecord, CrmSyncState, CrmSyncSummary,
 };
 
+// Trimmed text, `None` when blank; `what` names the field in the error
+fn optional_text(field: &str, what: &str, text: Option<String>, max_chars: usize) -> ApiResult<Option<String>> {
+    let Some(text) = text.map(|text| text.trim().to_string()).filter(|text| !text.is_empty()) else {
+        return Ok(None);
+    };
+    if text.chars().count() > max_chars {
+        return Err(ApiError::validation(
+            field,
+            format!("{} can be at most {} characters", what, max_chars),
+        ));
+    }
+    Ok(Some(text))
+}
+
+// What the user's platform role allows, for checks made without their
+// organization's custom roles
+fn role_permissions(user: &User, is_admin: bool) -> PermissionChecker {
+    let token_roles: &[UserRole] = if is_admin { &[UserRole::Admin] } else { &[] };
+    PermissionChecker::for_user(user, &[]).with_roles(token_roles)
+}
+
+// Links end up in public pages, so only plain web addresses are accepted
+fn optional_web_url(field: &str, what: &str, url: Option<String>, max_chars: usize) -> ApiResult<Option<String>> {
+    let Some(url) = optional_text(field, what, url, max_chars)? else {
+        return Ok(None);
+    };
+    let lower = url.to_ascii_lowercase();
+    let has_host = ["https://", "http://"]
+        .iter()
+        .any(|scheme| lower.strip_prefix(scheme).is_some_and(|rest| !rest.is_empty()));
+    if !has_host || url.chars().any(char::is_whitespace) {
+        return Err(ApiError::validation(
+            field,
+            format!("{} must be an http:// or https:// address", what),
+        ));
+    }
+    Ok(Some(url))
+}
+
 // ============================================================================
 // Event Application Service
 // ============================================================================
@@ -19,31 +136,113 @@ use aqio_core::{
 pub struct EventApplicationService {
     event_repository: Arc<dyn EventRepository>,
     event_service: EventService,
+    /// Checks new and moved events against their organization's scheduling rules
+    scheduling: Option<SchedulingApplicationService>,
+    /// Events hidden after abuse reports are left off the public page
+    report_repository: Option<Arc<dyn ContentReportRepository>>,
+    /// Origin of the frontend; calendar files link to the public event page there
+    public_url: String,
+    /// Fills in the municipality and county of addresses with a postal code
+    postal_codes: Option<Arc<dyn PostalCodeDirectory>>,
+    /// Forecasts the weather at venues of upcoming events
+    weather: Option<Arc<dyn WeatherForecaster>>,
+    /// Suggests public transport journeys to venues
+    travel_planner: Option<Arc<dyn TravelPlanner>>,
 }
 
 impl EventApplicationService {
-    pub fn new(event_repository: Arc<dyn EventRepository>) -> Self {
+    pub const MAX_TRAVEL_ENTRIES: usize = 10;
+    pub const MAX_TRAVEL_TEXT_CHARS: usize = 200;
+    pub const MAX_TRAVEL_NOTES_CHARS: usize = 500;
+    pub const MAX_URL_CHARS: usize = 500;
+    pub const DEFAULT_TRAVEL_OPTIONS: u32 = 3;
+    pub const MAX_TRAVEL_OPTIONS: u32 = 5;
+    /// Suggested journeys arrive this long before the event starts
+    pub const TRAVEL_ARRIVAL_MARGIN_MINUTES: i64 = 15;
+
+    pub fn new(event_repository: Arc<dyn EventRepository>, public_url: impl Into<String>) -> Self {
         Self {
             event_repository,
             event_service: EventService::new(),
+            scheduling: None,
+            report_repository: None,
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+            postal_codes: None,
+            weather: None,
+            travel_planner: None,
+        }
+    }
+
+    /// Enforces the organizer's company's blackout periods and scheduling policy
+    pub fn with_scheduling(mut self, scheduling: SchedulingApplicationService) -> Self {
+        self.scheduling = Some(scheduling);
+        self
+    }
+
+    pub fn with_moderation(mut self, report_repository: Arc<dyn ContentReportRepository>) -> Self {
+        self.report_repository = Some(report_repository);
+        self
+    }
+
+    pub fn with_postal_codes(mut self, postal_codes: Arc<dyn PostalCodeDirectory>) -> Self {
+        self.postal_codes = Some(postal_codes);
+        self
+    }
+
+    pub fn with_weather(mut self, weather: Arc<dyn WeatherForecaster>) -> Self {
+        self.weather = Some(weather);
+        self
+    }
+
+    pub fn with_travel_planner(mut self, travel_planner: Arc<dyn TravelPlanner>) -> Self {
+        self.travel_planner = Some(travel_planner);
+        self
+    }
+
+    /// Forecast for the day of a physical or hybrid event with coordinates,
+    /// while it is under way or less than a week away. The forecast is extra
+    /// detail on the event page, so a failed one is logged and left out.
+    pub async fn weather_forecast(&self, event: &Event) -> Option<WeatherForecast> {
+        let forecaster = self.weather.as_ref()?;
+        let timezone = event.timezone.parse().unwrap_or(chrono_tz::Europe::Oslo);
+        let date = weather::forecast_date(event, timezone, chrono::Utc::now())?;
+        let (latitude, longitude) = (event.latitude?, event.longitude?);
+        match forecaster.daily_forecast(latitude, longitude, date, timezone).await {
+            Ok(forecast) => forecast,
+            Err(e) => {
+                tracing::warn!("Weather forecast for event {} failed: {}", event.id, e);
+                None
+            }
+        }
+    }
+
+    async fn region_of(&self, address: Option<&str>) -> Option<AddressRegion> {
+        match (&self.postal_codes, address) {
+            (Some(postal_codes), Some(address)) => postal_codes::region_of(postal_codes.as_ref(), address).await,
+            _ => None,
         }
     }
 
+    pub fn public_page_url(&self, slug: &str) -> String {
+        format!("{}/e/{}", self.public_url, slug)
+    }
+
     pub async fn create_event(
         &self,
         request: CreateEventRequest,
         organizer_id: Uuid,
     ) -> ApiResult<Event> {
         // 1. Convert DTO to domain model with validation
-        let event = request.to_domain_event(organizer_id)?;
+        let mut event = request.to_domain_event(organizer_id)?;
+        event.region = self.region_of(event.address.as_deref()).await;
 
         // 2. Apply domain business rules
         self.event_service
             .validate_event(&event)
             .map_err(|e| ApiError::Domain { source: e })?;
 
-        // 3. Check if organizer exists (if we had a user repository)
-        // This would be a good place for additional business logic
+        // 3. Refuse dates the organization has blocked
+        self.enforce_schedule(&event, true).await?;
 
         // 4. Persist via repository
         self.event_repository
@@ -62,6 +261,244 @@ impl EventApplicationService {
             .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
     }
 
+    /// Published, non-private event for the public event page, unless
+    /// moderation has hidden it
+    pub async fn get_public_event(&self, slug: &str) -> ApiResult<Event> {
+        let event = self
+            .event_repository
+            .find_by_slug(slug)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|event| !event.is_private && matches!(event.status, EventStatus::Published))
+            .ok_or_else(|| ApiError::not_found(format!("Event '{}'", slug)))?;
+        if let Some(reports) = &self.report_repository {
+            let hidden = reports
+                .find_hidden(ReportTarget::Event, &[event.id])
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            if !hidden.is_empty() {
+                return Err(ApiError::not_found(format!("Event '{}'", slug)));
+            }
+        }
+        Ok(event)
+    }
+
+    /// Sitemap of the public pages of every published, non-private event that
+    /// isn't hidden after abuse reports
+    pub async fn public_sitemap(&self) -> ApiResult<String> {
+        let filter = EventFilter {
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: Some(false),
+            status: Some(EventStatus::Published),
+            location_type: None,
+            start_date_from: None,
+            start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
+        };
+        let mut events = Vec::new();
+        let mut pagination = PaginationParams::new(0, 1000).map_err(|e| ApiError::Domain { source: e })?;
+        loop {
+            let page = self
+                .event_repository
+                .find_by_filter(&filter, pagination.clone())
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            events.extend(page.items);
+            if !page.has_next || events.len() >= structured_data::MAX_SITEMAP_URLS {
+                break;
+            }
+            pagination.offset += pagination.limit;
+        }
+
+        if let Some(reports) = &self.report_repository {
+            let ids: Vec<Uuid> = events.iter().map(|event| event.id).collect();
+            let hidden: HashSet<Uuid> = reports
+                .find_hidden(ReportTarget::Event, &ids)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .into_iter()
+                .collect();
+            events.retain(|event| !hidden.contains(&event.id));
+        }
+        let entries: Vec<SitemapEntry> = events
+            .into_iter()
+            .map(|event| SitemapEntry {
+                url: self.public_page_url(&event.slug),
+                last_modified: event.updated_at,
+            })
+            .collect();
+        Ok(structured_data::render_sitemap(&entries))
+    }
+
+    /// schema.org `Event` markup for the public page of `event`
+    pub fn structured_data(&self, event: &Event, confirmed_registrations: usize) -> serde_json::Value {
+        let spots_left = self.event_service.calculate_available_spots(event, confirmed_registrations);
+        structured_data::event_json_ld(event, &self.public_page_url(&event.slug), spots_left)
+    }
+
+    /// The public event as an iCalendar file, with its download name
+    pub async fn public_calendar(&self, slug: &str, localization: &Localization) -> ApiResult<(String, String)> {
+        let event = self.get_public_event(slug).await?;
+        let travel = TravelInfo::from_custom_fields(event.custom_fields.as_deref());
+        let ics = render_event_ics(
+            &event,
+            &travel,
+            &self.public_page_url(&event.slug),
+            chrono::Utc::now(),
+            localization,
+        );
+        Ok((format!("{}.ics", event.slug), ics))
+    }
+
+    /// Public transport journeys from the attendee's coordinates to the venue,
+    /// arriving shortly before the event starts, or leaving now once it has
+    pub async fn travel_options(&self, event_id: Uuid, query: TravelOptionsQuery) -> ApiResult<TravelOptionsResponse> {
+        let planner = self
+            .travel_planner
+            .as_ref()
+            .ok_or_else(|| ApiError::external_service("Entur", "Travel planning is not available"))?;
+        if !(-90.0..=90.0).contains(&query.latitude) {
+            return Err(ApiError::validation("latitude", "Latitude must be between -90 and 90"));
+        }
+        if !(-180.0..=180.0).contains(&query.longitude) {
+            return Err(ApiError::validation("longitude", "Longitude must be between -180 and 180"));
+        }
+        let limit = query.limit.unwrap_or(Self::DEFAULT_TRAVEL_OPTIONS);
+        if limit == 0 || limit > Self::MAX_TRAVEL_OPTIONS {
+            return Err(ApiError::validation(
+                "limit",
+                format!("Between 1 and {} journeys can be suggested", Self::MAX_TRAVEL_OPTIONS),
+            ));
+        }
+
+        let event = self.get_event_by_id(event_id).await?;
+        if event.location_type == LocationType::Virtual {
+            return Err(ApiError::bad_request("Virtual events have no venue to travel to"));
+        }
+        let (Some(to_latitude), Some(to_longitude)) = (event.latitude, event.longitude) else {
+            return Err(ApiError::bad_request("The event's venue has no coordinates"));
+        };
+        let now = chrono::Utc::now();
+        if event.end_date < now {
+            return Err(ApiError::bad_request("The event is over"));
+        }
+
+        let arrive_by = Some(event.start_date - chrono::Duration::minutes(Self::TRAVEL_ARRIVAL_MARGIN_MINUTES))
+            .filter(|arrive_by| *arrive_by > now);
+        let travel_query = TravelQuery {
+            from_latitude: query.latitude,
+            from_longitude: query.longitude,
+            to_latitude,
+            to_longitude,
+            time: arrive_by.unwrap_or(now),
+            arrive_by: arrive_by.is_some(),
+            limit,
+        };
+        let options = planner
+            .plan(&travel_query)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(TravelOptionsResponse {
+            event_id,
+            arrive_by,
+            options: options.into_iter().map(TravelOptionResponse::from).collect(),
+        })
+    }
+
+    pub async fn travel_info(&self, event_id: Uuid) -> ApiResult<TravelInfo> {
+        let event = self.get_event_by_id(event_id).await?;
+        Ok(TravelInfo::from_custom_fields(event.custom_fields.as_deref()))
+    }
+
+    /// Replaces the event's accommodation and travel information; an empty one removes it
+    pub async fn update_travel_info(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        travel: TravelInfo,
+    ) -> ApiResult<TravelInfo> {
+        let mut event = self.get_event_by_id(event_id).await?;
+        if !(may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)) {
+            return Err(ApiError::authorization("Only the event's organizers can change its travel information"));
+        }
+        let travel = Self::validate_travel(travel)?;
+
+        event.custom_fields = travel.merge_into_custom_fields(event.custom_fields.as_deref())?;
+        event.updated_at = chrono::Utc::now();
+        self.event_repository
+            .update(&event)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(travel)
+    }
+
+    fn validate_travel(travel: TravelInfo) -> ApiResult<TravelInfo> {
+        if travel.hotels.len() > Self::MAX_TRAVEL_ENTRIES {
+            return Err(ApiError::validation("hotels", format!("At most {} hotels can be listed", Self::MAX_TRAVEL_ENTRIES)));
+        }
+        if travel.transport.len() > Self::MAX_TRAVEL_ENTRIES {
+            return Err(ApiError::validation(
+                "transport",
+                format!("At most {} transport options can be listed", Self::MAX_TRAVEL_ENTRIES),
+            ));
+        }
+        let text = |field: &str, what: &str, value: Option<String>| {
+            optional_text(field, what, value, Self::MAX_TRAVEL_TEXT_CHARS)
+        };
+        let notes = |field: &str, value: Option<String>| optional_text(field, "Notes", value, Self::MAX_TRAVEL_NOTES_CHARS);
+        let url = |field: &str, what: &str, value: Option<String>| optional_web_url(field, what, value, Self::MAX_URL_CHARS);
+
+        let hotels = travel
+            .hotels
+            .into_iter()
+            .map(|hotel| {
+                Ok(Hotel {
+                    name: text("hotels.name", "Hotel name", Some(hotel.name))?
+                        .ok_or_else(|| ApiError::validation("hotels.name", "Every hotel needs a name"))?,
+                    address: text("hotels.address", "Address", hotel.address)?,
+                    website_url: url("hotels.website_url", "Website URL", hotel.website_url)?,
+                    walking_minutes: hotel.walking_minutes,
+                    booking_code: text("hotels.booking_code", "Booking code", hotel.booking_code)?,
+                    notes: notes("hotels.notes", hotel.notes)?,
+                })
+            })
+            .collect::<ApiResult<Vec<_>>>()?;
+        let transport = travel
+            .transport
+            .into_iter()
+            .map(|option| {
+                Ok(TransportOption {
+                    mode: option.mode,
+                    title: text("transport.title", "Title", Some(option.title))?
+                        .ok_or_else(|| ApiError::validation("transport.title", "Every transport option needs a title"))?,
+                    details: notes("transport.details", option.details)?,
+                    url: url("transport.url", "Link", option.url)?,
+                })
+            })
+            .collect::<ApiResult<Vec<_>>>()?;
+        let parking = travel
+            .parking
+            .map(|parking| -> ApiResult<ParkingInfo> {
+                Ok(ParkingInfo {
+                    address: text("parking.address", "Address", parking.address)?,
+                    free_of_charge: parking.free_of_charge,
+                    ev_charging: parking.ev_charging,
+                    notes: notes("parking.notes", parking.notes)?,
+                })
+            })
+            .transpose()?;
+
+        Ok(TravelInfo { hotels, transport, parking })
+    }
+
     pub async fn list_events(&self, query: ListEventsQuery) -> ApiResult<PaginatedResult<Event>> {
         let (filter, pagination) = query.to_filter_and_pagination()?;
 
@@ -90,13 +527,24 @@ impl EventApplicationService {
         // 3. Create updated event while preserving certain fields
         let mut updated_event = request.to_domain_event(organizer_id)?;
         updated_event.id = existing_event.id;
+        updated_event.slug = existing_event.slug;
+        // Editing doesn't unpublish; a published event would otherwise be published again by the scheduler
+        updated_event.status = existing_event.status;
         updated_event.created_at = existing_event.created_at;
         updated_event.updated_at = chrono::Utc::now();
+        // Co-organizers stay unless the request lists them
+        if request.co_organizers.is_none() {
+            updated_event.co_organizers = existing_event.co_organizers.clone();
+        }
+        updated_event.region = self.region_of(updated_event.address.as_deref()).await;
 
         // 4. Apply domain validation
         self.event_service
             .validate_event(&updated_event)
             .map_err(|e| ApiError::Domain { source: e })?;
+        if updated_event.start_date != existing_event.start_date || updated_event.end_date != existing_event.end_date {
+            self.enforce_schedule(&updated_event, false).await?;
+        }
 
         // 5. Update in repository
         self.event_repository
@@ -145,6 +593,13 @@ impl EventApplicationService {
             .map_err(|e| ApiError::Domain { source: e })
     }
 
+    async fn enforce_schedule(&self, event: &Event, is_new: bool) -> ApiResult<()> {
+        if let Some(scheduling) = &self.scheduling {
+            scheduling.enforce(event, is_new).await?;
+        }
+        Ok(())
+    }
+
     pub async fn check_event_capacity(&self, event_id: Uuid) -> ApiResult<Option<i32>> {
         // TODO(aqio-api): Wire this once EventRegistrationRepository exists. For now,
         // returns the configured max capacity without counting registrations.
@@ -156,6 +611,148 @@ impl EventApplicationService {
     }
 }
 
+// ============================================================================
+// Event Details Application Service
+// ============================================================================
+
+/// Read-side service that hydrates events with their category, organizer and
+/// registration counts using one batched query per relation instead of one per event
+#[derive(Clone)]
+pub struct EventDetailsApplicationService {
+    event_repository: Arc<dyn EventRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    category_repository: Arc<dyn EventCategoryRepository>,
+    summary_repository: Arc<dyn EventSummaryRepository>,
+}
+
+impl EventDetailsApplicationService {
+    pub fn new(
+        event_repository: Arc<dyn EventRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        category_repository: Arc<dyn EventCategoryRepository>,
+        summary_repository: Arc<dyn EventSummaryRepository>,
+    ) -> Self {
+        Self {
+            event_repository,
+            user_repository,
+            category_repository,
+            summary_repository,
+        }
+    }
+
+    pub async fn get_event_with_details(&self, event_id: Uuid) -> ApiResult<EventWithDetails> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+
+        let mut hydrated = self.hydrate(vec![event]).await?;
+        Ok(hydrated.remove(0))
+    }
+
+    pub async fn list_events_with_details(
+        &self,
+        query: ListEventsQuery,
+    ) -> ApiResult<PaginatedResult<EventWithDetails>> {
+        let (filter, pagination) = query.to_filter_and_pagination()?;
+
+        let result = self
+            .event_repository
+            .find_by_filter(&filter, pagination)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let items = self.hydrate(result.items).await?;
+
+        Ok(PaginatedResult {
+            items,
+            total_count: result.total_count,
+            offset: result.offset,
+            limit: result.limit,
+            has_next: result.has_next,
+        })
+    }
+
+    /// An event with its registration totals, for the availability endpoint
+    ///
+    /// Private and draft events are hidden as on the public event page;
+    /// cancelled and past ones stay visible so pollers see registration close.
+    pub async fn availability(&self, event_id: Uuid) -> ApiResult<(Event, RegistrationCounts)> {
+        let event_ids = [event_id];
+        let (event, summaries) = tokio::try_join!(
+            self.event_repository.find_by_id(event_id),
+            self.summary_repository.find_by_event_ids(&event_ids),
+        )
+        .map_err(|e| ApiError::Domain { source: e })?;
+        let event = event
+            .filter(|event| !event.is_private && !matches!(event.status, EventStatus::Draft))
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+
+        let counts = summaries
+            .into_iter()
+            .next()
+            .map(|summary| summary.registration_counts)
+            .unwrap_or_else(|| RegistrationCounts::empty(event_id));
+        Ok((event, counts))
+    }
+
+    /// Attach related data to `events`, preserving their order
+    ///
+    /// Registration totals come from the `event_summaries` read model rather
+    /// than being aggregated per request.
+    pub async fn hydrate(&self, events: Vec<Event>) -> ApiResult<Vec<EventWithDetails>> {
+        if events.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let event_ids: Vec<Uuid> = events.iter().map(|e| e.id).collect();
+        let organizer_ids: Vec<Uuid> = events
+            .iter()
+            .map(|e| e.organizer_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let category_ids: Vec<String> = events
+            .iter()
+            .map(|e| e.category_id.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        let (organizers, categories, summaries) = tokio::try_join!(
+            self.user_repository.find_by_ids(&organizer_ids),
+            self.category_repository.find_by_ids(&category_ids),
+            self.summary_repository.find_by_event_ids(&event_ids),
+        )
+        .map_err(|e| ApiError::Domain { source: e })?;
+
+        let organizers: HashMap<Uuid, User> = organizers.into_iter().map(|u| (u.id, u)).collect();
+        let categories: HashMap<String, EventCategory> =
+            categories.into_iter().map(|c| (c.id.clone(), c)).collect();
+        let mut summaries: HashMap<Uuid, EventSummary> =
+            summaries.into_iter().map(|s| (s.event_id, s)).collect();
+
+        Ok(events
+            .into_iter()
+            .map(|event| {
+                let (registration_counts, checked_in) = summaries
+                    .remove(&event.id)
+                    .map(|s| (s.registration_counts, s.checked_in))
+                    .unwrap_or_else(|| (RegistrationCounts::empty(event.id), 0));
+                EventWithDetails {
+                    category: categories.get(&event.category_id).cloned(),
+                    organizer: organizers.get(&event.organizer_id).cloned(),
+                    registration_counts,
+                    checked_in,
+                    event,
+                }
+            })
+            .collect())
+    }
+}
+
 // ============================================================================
 // Health Application Service
 // ============================================================================
@@ -193,11 +790,22 @@ impl HealthApplicationService {
 #[derive(Clone)]
 pub struct UserApplicationService {
     user_repository: Arc<dyn UserRepository>,
+    search_limiter: RateLimiter,
 }
 
 impl UserApplicationService {
+    /// Searches a user may run per minute; pickers search as the user types
+    pub const SEARCHES_PER_MINUTE: u32 = 60;
+    pub const DEFAULT_SEARCH_RESULTS: i64 = 10;
+    pub const MAX_SEARCH_RESULTS: i64 = 25;
+    const MIN_SEARCH_CHARS: usize = 2;
+    const MAX_SEARCH_CHARS: usize = 100;
+
     pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+        Self {
+            user_repository,
+            search_limiter: RateLimiter::new(Self::SEARCHES_PER_MINUTE, std::time::Duration::from_secs(60)),
+        }
     }
 
     pub async fn get_user_by_id(&self, user_id: Uuid) -> ApiResult<User> {
@@ -233,6 +841,39 @@ impl UserApplicationService {
             .map_err(|e| ApiError::Domain { source: e })
     }
 
+    /// Users matching `query` by name, email or company, for picking
+    /// co-organizers and invitees. Results include email addresses, so only
+    /// holders of `user:search` may search.
+    pub async fn search_users(
+        &self,
+        searcher: &User,
+        permissions: &PermissionChecker,
+        query: &str,
+        limit: Option<i64>,
+    ) -> ApiResult<Vec<UserMatch>> {
+        if !permissions.has(Permission::UserSearch) {
+            return Err(ApiError::authorization("Only organizers can search for users"));
+        }
+        self.search_limiter.check(searcher.id)?;
+
+        let query = query.trim();
+        let length = query.chars().count();
+        if length < Self::MIN_SEARCH_CHARS {
+            return Err(ApiError::validation("q", "Type at least 2 characters to search"));
+        }
+        if length > Self::MAX_SEARCH_CHARS {
+            return Err(ApiError::validation("q", "Search text is too long"));
+        }
+
+        let limit = limit
+            .unwrap_or(Self::DEFAULT_SEARCH_RESULTS)
+            .clamp(1, Self::MAX_SEARCH_RESULTS);
+        self.user_repository
+            .search(query, limit)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
     pub async fn create_user(&self, user: &User) -> ApiResult<()> {
         if self
             .user_repository
@@ -325,119 +966,11075 @@ impl EventCategoryApplicationService {
 }
 
 // ============================================================================
-// Invitation Application Service
+// Company Application Service
 // ============================================================================
 
+/// The company directory: companies with their member counts and the upcoming
+/// public events organized by their members. Administrators add companies,
+/// checked against and filled in from the Norwegian business register.
 #[derive(Clone)]
-pub struct InvitationApplicationService {
-    invitation_repository: Arc<dyn EventInvitationRepository>,
+pub struct CompanyApplicationService {
+    company_repository: Arc<dyn CompanyRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    /// Without it, organization numbers are only checked for their check digit
+    company_registry: Option<Arc<dyn CompanyRegistry>>,
+    /// Fills in the municipality and county of the company's location
+    postal_codes: Option<Arc<dyn PostalCodeDirectory>>,
 }
 
-impl InvitationApplicationService {
-    // TODO(aqio-api): Invitation endpoints aren't wired yet. Keep this service and
-    // its methods; route handlers will call into these once implemented.
-    pub fn new(invitation_repository: Arc<dyn EventInvitationRepository>) -> Self {
+/// What the business register has on an organization number
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegistryLookup {
+    Found(RegisteredCompany),
+    NotFound,
+    /// The register couldn't be reached; the number's check digit is valid
+    Unavailable,
+}
+
+/// A new company as entered by an administrator; fields left blank are filled
+/// in from the business register when the organization number is found there
+#[derive(Debug, Clone, Default)]
+pub struct CompanyInput {
+    pub org_number: Option<String>,
+    pub name: Option<String>,
+    pub location: Option<String>,
+    pub industry_type: Option<IndustryType>,
+    pub website: Option<String>,
+    pub phone: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreatedCompany {
+    pub entry: CompanyDirectoryEntry,
+    /// Whether the organization number was confirmed in the business register;
+    /// false when none was given or the register was down
+    pub registry_verified: bool,
+}
+
+impl CompanyApplicationService {
+    // The repository sorts newest first, so upcoming events are fetched in one
+    // page and re-sorted rather than paginated
+    const MAX_UPCOMING_EVENTS: i64 = 1000;
+    pub const MAX_NAME_CHARS: usize = 200;
+    pub const MAX_LOCATION_CHARS: usize = 200;
+    pub const MAX_WEBSITE_CHARS: usize = 500;
+    pub const MAX_PHONE_CHARS: usize = 50;
+
+    pub fn new(
+        company_repository: Arc<dyn CompanyRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
         Self {
-            invitation_repository,
+            company_repository,
+            event_repository,
+            company_registry: None,
+            postal_codes: None,
         }
     }
 
-    pub async fn get_invitation_by_id(&self, invitation_id: Uuid) -> ApiResult<EventInvitation> {
-        self.invitation_repository
-            .find_by_id(invitation_id)
-            .await
-            .map_err(|e| ApiError::Domain { source: e })?
-            .ok_or_else(|| ApiError::not_found(format!("Invitation with ID {}", invitation_id)))
+    pub fn with_registry(mut self, company_registry: Arc<dyn CompanyRegistry>) -> Self {
+        self.company_registry = Some(company_registry);
+        self
     }
 
-    pub async fn get_invitations_by_event(
-        &self,
-        event_id: Uuid,
-    ) -> ApiResult<Vec<EventInvitation>> {
-        self.invitation_repository
-            .find_by_event_id(event_id)
-            .await
-            .map_err(|e| ApiError::Domain { source: e })
+    pub fn with_postal_codes(mut self, postal_codes: Arc<dyn PostalCodeDirectory>) -> Self {
+        self.postal_codes = Some(postal_codes);
+        self
     }
 
-    pub async fn get_invitations_by_user(&self, user_id: Uuid) -> ApiResult<Vec<EventInvitation>> {
-        self.invitation_repository
-            .find_by_user_id(user_id)
-            .await
-            .map_err(|e| ApiError::Domain { source: e })
+    /// Looks `org_number` up in the business register. Only a malformed number
+    /// is an error; the register being down is reported as `Unavailable`.
+    pub async fn lookup_registry(&self, org_number: &str) -> ApiResult<RegistryLookup> {
+        let org_number = normalize_org_number(org_number)?;
+        Ok(self.lookup(&org_number).await)
     }
 
-    pub async fn create_invitation(&self, invitation: &EventInvitation) -> ApiResult<()> {
-        // Check if user/email already invited
-        if let Some(user_id) = invitation.invited_user_id {
-            if self
-                .invitation_repository
-                .user_invited_to_event(user_id, invitation.event_id)
-                .await
-                .map_err(|e| ApiError::Domain { source: e })?
-            {
-                return Err(ApiError::validation(
-                    "user_id",
-                    "User already invited to this event",
-                ));
+    pub async fn create_company(&self, may_manage: bool, input: CompanyInput) -> ApiResult<CreatedCompany> {
+        if !may_manage {
+            return Err(ApiError::authorization("Only administrators can add companies"));
+        }
+
+        let org_number = match input.org_number.as_deref().map(str::trim).filter(|number| !number.is_empty()) {
+            Some(number) => Some(normalize_org_number(number)?),
+            None => None,
+        };
+        let registered = match org_number.as_deref() {
+            Some(number) => {
+                let existing = self
+                    .company_repository
+                    .find_by_org_number(number)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                if let Some(existing) = existing {
+                    return Err(ApiError::conflict(format!(
+                        "{} is already registered with organization number {}",
+                        existing.name, number
+                    )));
+                }
+                match self.lookup(number).await {
+                    RegistryLookup::Found(registered) if !registered.is_active => {
+                        return Err(ApiError::validation(
+                            "org_number",
+                            format!("{} is bankrupt, being wound up or deleted in the business register", registered.name),
+                        ));
+                    }
+                    RegistryLookup::Found(registered) => Some(registered),
+                    RegistryLookup::NotFound => {
+                        return Err(ApiError::validation(
+                            "org_number",
+                            format!("No company is registered under organization number {}", number),
+                        ));
+                    }
+                    RegistryLookup::Unavailable => None,
+                }
+            }
+            None => None,
+        };
+
+        // What the administrator entered wins over the register
+        let name = optional_text("name", "Name", input.name, Self::MAX_NAME_CHARS)?
+            .or_else(|| registered.as_ref().map(|registered| registered.name.clone()))
+            .ok_or_else(|| ApiError::validation("name", "Name is required"))?;
+        let location = optional_text("location", "Location", input.location, Self::MAX_LOCATION_CHARS)?
+            .or_else(|| registered.as_ref().and_then(|registered| registered.city.clone()));
+        let website = optional_web_url("website", "Website", input.website, Self::MAX_WEBSITE_CHARS)?
+            .or_else(|| registered.as_ref().and_then(|registered| registered.website.clone()));
+        let phone = optional_text("phone", "Phone", input.phone, Self::MAX_PHONE_CHARS)?;
+        let industry_type = input.industry_type.unwrap_or_else(|| {
+            IndustryType::Other(
+                registered
+                    .as_ref()
+                    .and_then(|registered| registered.industry_description.clone())
+                    .unwrap_or_default(),
+            )
+        });
+
+        // The location entered rarely has a postal code; the registered business address does
+        let mut region = None;
+        if let Some(postal_codes) = &self.postal_codes {
+            let addresses = [location.as_deref(), registered.as_ref().and_then(|registered| registered.address.as_deref())];
+            for address in addresses.into_iter().flatten() {
+                region = postal_codes::region_of(postal_codes.as_ref(), address).await;
+                if region.is_some() {
+                    break;
+                }
             }
         }
 
-        if let Some(email) = &invitation.invited_email {
-            if self
-                .invitation_repository
-                .email_invited_to_event(email, invitation.event_id)
-                .await
-                .map_err(|e| ApiError::Domain { source: e })?
-            {
-                return Err(ApiError::validation(
-                    "email",
-                    "Email already invited to this event",
-                ));
+        let now = chrono::Utc::now();
+        let company = Company {
+            id: Uuid::new_v4(),
+            name,
+            org_number,
+            location,
+            industry_type,
+            website,
+            phone,
+            region,
+            created_at: now,
+            updated_at: now,
+        };
+        self.company_repository
+            .create(&company)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        Ok(CreatedCompany {
+            entry: CompanyDirectoryEntry { company, member_count: 0 },
+            registry_verified: registered.is_some(),
+        })
+    }
+
+    async fn lookup(&self, org_number: &str) -> RegistryLookup {
+        let Some(registry) = &self.company_registry else {
+            return RegistryLookup::Unavailable;
+        };
+        match registry.lookup(org_number).await {
+            Ok(Some(registered)) => RegistryLookup::Found(registered),
+            Ok(None) => RegistryLookup::NotFound,
+            Err(e) => {
+                tracing::warn!("Business register lookup of {} failed: {}", org_number, e);
+                RegistryLookup::Unavailable
             }
         }
+    }
 
-        self.invitation_repository
-            .create(invitation)
+    pub async fn get_company_by_id(&self, company_id: Uuid) -> ApiResult<CompanyDirectoryEntry> {
+        let company = self
+            .company_repository
+            .find_by_id(company_id)
             .await
-            .map_err(|e| ApiError::Domain { source: e })
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Company with ID {}", company_id)))?;
+
+        let mut entries = self.with_member_counts(vec![company]).await?;
+        Ok(entries.remove(0))
     }
 
-    pub async fn update_invitation_status(
+    pub async fn list_directory(
         &self,
-        invitation_id: Uuid,
-        status: InvitationStatus,
-    ) -> ApiResult<()> {
-        self.invitation_repository
-            .update_status(invitation_id, status)
+        filter: &CompanyFilter,
+        pagination: PaginationParams,
+    ) -> ApiResult<PaginatedResult<CompanyDirectoryEntry>> {
+        let result = self
+            .company_repository
+            .find_by_filter(filter, pagination)
             .await
-            .map_err(|e| ApiError::Domain { source: e })
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let items = self.with_member_counts(result.items).await?;
+
+        Ok(PaginatedResult {
+            items,
+            total_count: result.total_count,
+            offset: result.offset,
+            limit: result.limit,
+            has_next: result.has_next,
+        })
     }
 
-    pub async fn delete_invitation(&self, invitation_id: Uuid) -> ApiResult<()> {
-        self.invitation_repository
-            .delete(invitation_id)
+    /// Published, non-private events organized by the company's members that
+    /// haven't started yet, soonest first
+    pub async fn upcoming_public_events(&self, company_id: Uuid) -> ApiResult<Vec<Event>> {
+        // 404 for unknown companies instead of an empty list
+        self.get_company_by_id(company_id).await?;
+
+        let filter = EventFilter {
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: Some(false),
+            status: Some(EventStatus::Published),
+            location_type: None,
+            start_date_from: Some(chrono::Utc::now()),
+            start_date_to: None,
+            near: None,
+            organizer_company_id: Some(company_id),
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
+        };
+        let pagination = PaginationParams::new(0, Self::MAX_UPCOMING_EVENTS)
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut events = self
+            .event_repository
+            .find_by_filter(&filter, pagination)
             .await
-            .map_err(|e| ApiError::Domain { source: e })
+            .map_err(|e| ApiError::Domain { source: e })?
+            .items;
+        events.sort_by_key(|event| event.start_date);
+        Ok(events)
+    }
+
+    async fn with_member_counts(&self, companies: Vec<Company>) -> ApiResult<Vec<CompanyDirectoryEntry>> {
+        let ids: Vec<Uuid> = companies.iter().map(|c| c.id).collect();
+        let counts: HashMap<Uuid, i64> = self
+            .company_repository
+            .count_members_by_company_ids(&ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|count| (count.company_id, count.members))
+            .collect();
+
+        Ok(companies
+            .into_iter()
+            .map(|company| CompanyDirectoryEntry {
+                member_count: counts.get(&company.id).copied().unwrap_or(0),
+                company,
+            })
+            .collect())
     }
 }
 
 // ============================================================================
-// Event Registration Application Service
+// Event Comment Application Service
 // ============================================================================
 
+/// Discussion threads on events. Authors can delete their own comments; the
+/// event's organizer and administrators can delete any comment on it.
 #[derive(Clone)]
-pub struct EventRegistrationApplicationService {
-    registration_repository: Arc<dyn EventRegistrationRepository>,
+pub struct EventCommentApplicationService {
+    comment_repository: Arc<dyn EventCommentRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    /// Comments hidden after abuse reports are shown like deleted ones
+    report_repository: Option<Arc<dyn ContentReportRepository>>,
 }
 
-impl EventRegistrationApplicationService {
-    pub fn new(registration_repository: Arc<dyn EventRegistrationRepository>) -> Self {
-        Self {
+/// A comment as seen by the user reading the thread
+#[derive(Debug, Clone)]
+pub struct ThreadComment {
+    pub comment: EventComment,
+    pub can_delete: bool,
+    /// Hidden by moderation; the content isn't shown
+    pub is_hidden: bool,
+}
+
+impl EventCommentApplicationService {
+    pub const MAX_CONTENT_CHARS: usize = 2000;
+
+    pub fn new(
+        comment_repository: Arc<dyn EventCommentRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self {
+            comment_repository,
+            event_repository,
+            report_repository: None,
+        }
+    }
+
+    pub fn with_moderation(mut self, report_repository: Arc<dyn ContentReportRepository>) -> Self {
+        self.report_repository = Some(report_repository);
+        self
+    }
+
+    /// The event's thread, oldest first. Deleted and hidden comments are only
+    /// kept as placeholders for replies that are still visible.
+    pub async fn list_comments(
+        &self,
+        event_id: Uuid,
+        viewer_id: Uuid,
+        viewer_may_manage: bool,
+    ) -> ApiResult<Vec<ThreadComment>> {
+        let event = self.get_event(event_id).await?;
+
+        let comments = self
+            .comment_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let hidden: HashSet<Uuid> = match &self.report_repository {
+            Some(reports) => {
+                let ids: Vec<Uuid> = comments.iter().map(|comment| comment.id).collect();
+                reports
+                    .find_hidden(ReportTarget::Comment, &ids)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?
+                    .into_iter()
+                    .collect()
+            }
+            None => HashSet::new(),
+        };
+
+        // Replies are newer than their parents, so walking newest first sees
+        // every reply before the comment it answers
+        let mut needed = HashSet::new();
+        let mut visible: Vec<ThreadComment> = comments
+            .into_iter()
+            .rev()
+            .filter(|comment| {
+                let gone = comment.is_deleted() || hidden.contains(&comment.id);
+                let keep = !gone || needed.contains(&comment.id);
+                if keep {
+                    needed.extend(comment.parent_comment_id);
+                }
+                keep
+            })
+            .map(|comment| ThreadComment {
+                can_delete: !comment.is_deleted()
+                    && Self::may_delete(&event, &comment, viewer_id, viewer_may_manage),
+                is_hidden: hidden.contains(&comment.id),
+                comment,
+            })
+            .collect();
+        visible.reverse();
+        Ok(visible)
+    }
+
+    pub async fn create_comment(
+        &self,
+        event_id: Uuid,
+        author: &User,
+        parent_comment_id: Option<Uuid>,
+        content: &str,
+    ) -> ApiResult<EventComment> {
+        let content = content.trim();
+        if content.is_empty() {
+            return Err(ApiError::validation("content", "Comment cannot be empty"));
+        }
+        if content.chars().count() > Self::MAX_CONTENT_CHARS {
+            return Err(ApiError::validation(
+                "content",
+                format!("Comment can be at most {} characters", Self::MAX_CONTENT_CHARS),
+            ));
+        }
+
+        self.get_event(event_id).await?;
+
+        if let Some(parent_id) = parent_comment_id {
+            let parent = self.get_comment(event_id, parent_id).await?;
+            if parent.is_deleted() {
+                return Err(ApiError::Domain {
+                    source: DomainError::business_rule("Cannot reply to a deleted comment"),
+                });
+            }
+        }
+
+        let mut comment = EventComment::new(event_id, author, content);
+        comment.parent_comment_id = parent_comment_id;
+
+        self.comment_repository
+            .create(&comment)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        Ok(comment)
+    }
+
+    pub async fn delete_comment(
+        &self,
+        event_id: Uuid,
+        comment_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<()> {
+        let event = self.get_event(event_id).await?;
+        let comment = self.get_comment(event_id, comment_id).await?;
+
+        if !Self::may_delete(&event, &comment, user_id, may_manage) {
+            return Err(ApiError::authorization(
+                "Only the author or the event organizer can delete this comment",
+            ));
+        }
+
+        self.comment_repository
+            .soft_delete(comment_id, user_id, chrono::Utc::now())
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    fn may_delete(event: &Event, comment: &EventComment, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || comment.author_id == Some(user_id)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    // Comments are addressed through their event; one from another event is as good as missing
+    async fn get_comment(&self, event_id: Uuid, comment_id: Uuid) -> ApiResult<EventComment> {
+        self.comment_repository
+            .find_by_id(comment_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|comment| comment.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Comment with ID {}", comment_id)))
+    }
+}
+
+// ============================================================================
+// Live Engagement Application Service
+// ============================================================================
+
+/// Audience Q&A and polls for an event. Anyone signed in can follow along at
+/// any time, but questions, upvotes, answers and votes are only accepted while
+/// the event is running. Every change is published to the event's live stream.
+#[derive(Clone)]
+pub struct LiveEngagementApplicationService {
+    question_repository: Arc<dyn EventQuestionRepository>,
+    poll_repository: Arc<dyn EventPollRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    notifier: LiveUpdateNotifier,
+}
+
+/// A question as seen by the user following the session
+#[derive(Debug, Clone)]
+pub struct LiveQuestion {
+    pub question: EventQuestion,
+    pub has_upvoted: bool,
+}
+
+/// A poll as seen by the user following the session
+#[derive(Debug, Clone)]
+pub struct LivePoll {
+    pub poll: EventPoll,
+    /// The option the user voted for, if any
+    pub voted_option_id: Option<Uuid>,
+}
+
+/// Everything a user needs to join the session, loaded before opening the stream
+#[derive(Debug, Clone)]
+pub struct LiveSession {
+    pub is_running: bool,
+    /// Whether the user may answer questions and run polls
+    pub can_moderate: bool,
+    pub questions: Vec<LiveQuestion>,
+    pub polls: Vec<LivePoll>,
+}
+
+impl LiveEngagementApplicationService {
+    pub const MAX_QUESTION_CHARS: usize = 500;
+    pub const MAX_ANSWER_CHARS: usize = 2000;
+    pub const MAX_POLL_OPTIONS: usize = 10;
+
+    pub fn new(
+        question_repository: Arc<dyn EventQuestionRepository>,
+        poll_repository: Arc<dyn EventPollRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self {
+            question_repository,
+            poll_repository,
+            event_repository,
+            notifier: LiveUpdateNotifier::new(),
+        }
+    }
+
+    pub fn notifier(&self) -> &LiveUpdateNotifier {
+        &self.notifier
+    }
+
+    pub async fn session(&self, event_id: Uuid, viewer_id: Uuid, viewer_may_manage: bool) -> ApiResult<LiveSession> {
+        let event = self.get_event(event_id).await?;
+        Ok(LiveSession {
+            is_running: event.is_running(chrono::Utc::now()),
+            can_moderate: Self::may_moderate(&event, viewer_id, viewer_may_manage),
+            questions: self.list_questions(event_id, viewer_id).await?,
+            polls: self.list_polls(event_id, viewer_id).await?,
+        })
+    }
+
+    /// Most upvoted first; ties go to the question asked first
+    pub async fn list_questions(&self, event_id: Uuid, viewer_id: Uuid) -> ApiResult<Vec<LiveQuestion>> {
+        self.get_event(event_id).await?;
+
+        let mut questions = self
+            .question_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let upvoted: HashSet<Uuid> = self
+            .question_repository
+            .find_upvoted_by_user(event_id, viewer_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .collect();
+
+        // The repository returns oldest first and the sort is stable
+        questions.sort_by_key(|question| std::cmp::Reverse(question.upvote_count));
+        Ok(questions
+            .into_iter()
+            .map(|question| LiveQuestion {
+                has_upvoted: upvoted.contains(&question.id),
+                question,
+            })
+            .collect())
+    }
+
+    pub async fn ask_question(&self, event_id: Uuid, author: &User, content: &str) -> ApiResult<LiveQuestion> {
+        let content = Self::checked_text("content", "Question", content, Self::MAX_QUESTION_CHARS)?;
+        self.get_running_event(event_id).await?;
+
+        let question = EventQuestion::new(event_id, author, content);
+        self.question_repository
+            .create(&question)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        self.notifier.publish(event_id, LiveUpdate::QuestionChanged { question: question.clone() });
+        Ok(LiveQuestion { question, has_upvoted: false })
+    }
+
+    /// Adds or withdraws the user's upvote; repeating either is a no-op
+    pub async fn set_upvote(
+        &self,
+        event_id: Uuid,
+        question_id: Uuid,
+        user_id: Uuid,
+        upvoted: bool,
+    ) -> ApiResult<LiveQuestion> {
+        self.get_running_event(event_id).await?;
+        self.get_question(event_id, question_id).await?;
+
+        let changed = if upvoted {
+            self.question_repository.add_upvote(question_id, user_id).await
+        } else {
+            self.question_repository.remove_upvote(question_id, user_id).await
+        }
+        .map_err(|e| ApiError::Domain { source: e })?;
+
+        let question = self.get_question(event_id, question_id).await?;
+        if changed {
+            self.notifier.publish(event_id, LiveUpdate::QuestionChanged { question: question.clone() });
+        }
+        Ok(LiveQuestion { question, has_upvoted: upvoted })
+    }
+
+    /// Answering again replaces the previous answer
+    pub async fn answer_question(
+        &self,
+        event_id: Uuid,
+        question_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        answer: &str,
+    ) -> ApiResult<LiveQuestion> {
+        let answer = Self::checked_text("answer", "Answer", answer, Self::MAX_ANSWER_CHARS)?;
+        let event = self.get_running_event(event_id).await?;
+        Self::ensure_organizer(&event, user_id, may_manage, "answer questions")?;
+        self.get_question(event_id, question_id).await?;
+
+        self.question_repository
+            .answer(question_id, answer, user_id, chrono::Utc::now())
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let question = self.get_question(event_id, question_id).await?;
+        self.notifier.publish(event_id, LiveUpdate::QuestionChanged { question: question.clone() });
+        let has_upvoted = self
+            .question_repository
+            .find_upvoted_by_user(event_id, user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .contains(&question_id);
+        Ok(LiveQuestion { question, has_upvoted })
+    }
+
+    /// Oldest first, with the current results
+    pub async fn list_polls(&self, event_id: Uuid, viewer_id: Uuid) -> ApiResult<Vec<LivePoll>> {
+        self.get_event(event_id).await?;
+
+        let polls = self
+            .poll_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let votes: HashMap<Uuid, Uuid> = self
+            .poll_repository
+            .find_votes_by_user(event_id, viewer_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|vote| (vote.poll_id, vote.option_id))
+            .collect();
+
+        Ok(polls
+            .into_iter()
+            .map(|poll| LivePoll {
+                voted_option_id: votes.get(&poll.id).copied(),
+                poll,
+            })
+            .collect())
+    }
+
+    pub async fn create_poll(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        question: &str,
+        options: &[String],
+    ) -> ApiResult<LivePoll> {
+        let question = Self::checked_text("question", "Poll question", question, Self::MAX_QUESTION_CHARS)?;
+        let options: Vec<String> = options.iter().map(|option| option.trim().to_string()).collect();
+        if options.len() < 2 || options.len() > Self::MAX_POLL_OPTIONS {
+            return Err(ApiError::validation(
+                "options",
+                format!("A poll needs between 2 and {} options", Self::MAX_POLL_OPTIONS),
+            ));
+        }
+        if options.iter().any(|option| option.is_empty()) {
+            return Err(ApiError::validation("options", "Poll options cannot be empty"));
+        }
+        let distinct: HashSet<String> = options.iter().map(|option| option.to_lowercase()).collect();
+        if distinct.len() != options.len() {
+            return Err(ApiError::validation("options", "Poll options must be different"));
+        }
+
+        let event = self.get_running_event(event_id).await?;
+        Self::ensure_organizer(&event, user_id, may_manage, "run polls")?;
+
+        let poll = EventPoll::new(event_id, user_id, question, options);
+        self.poll_repository
+            .create(&poll)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        self.notifier.publish(event_id, LiveUpdate::PollChanged { poll: poll.clone() });
+        Ok(LivePoll { poll, voted_option_id: None })
+    }
+
+    /// Records the user's vote, replacing an earlier one on the same poll
+    pub async fn vote(&self, event_id: Uuid, poll_id: Uuid, option_id: Uuid, user_id: Uuid) -> ApiResult<LivePoll> {
+        self.get_running_event(event_id).await?;
+        let poll = self.get_poll(event_id, poll_id).await?;
+
+        if poll.is_closed() {
+            return Err(ApiError::Domain {
+                source: DomainError::business_rule("The poll is closed"),
+            });
+        }
+        if !poll.options.iter().any(|option| option.id == option_id) {
+            return Err(ApiError::validation("option_id", "Not an option of this poll"));
+        }
+
+        self.poll_repository
+            .cast_vote(&PollVote {
+                poll_id,
+                option_id,
+                user_id,
+                created_at: chrono::Utc::now(),
+            })
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let poll = self.get_poll(event_id, poll_id).await?;
+        self.notifier.publish(event_id, LiveUpdate::PollChanged { poll: poll.clone() });
+        Ok(LivePoll { poll, voted_option_id: Some(option_id) })
+    }
+
+    pub async fn close_poll(&self, event_id: Uuid, poll_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<LivePoll> {
+        let event = self.get_running_event(event_id).await?;
+        Self::ensure_organizer(&event, user_id, may_manage, "run polls")?;
+        self.get_poll(event_id, poll_id).await?;
+
+        self.poll_repository
+            .close(poll_id, chrono::Utc::now())
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let poll = self.get_poll(event_id, poll_id).await?;
+        self.notifier.publish(event_id, LiveUpdate::PollChanged { poll: poll.clone() });
+        let voted_option_id = self
+            .poll_repository
+            .find_votes_by_user(event_id, user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .find(|vote| vote.poll_id == poll_id)
+            .map(|vote| vote.option_id);
+        Ok(LivePoll { poll, voted_option_id })
+    }
+
+    fn checked_text<'a>(field: &str, what: &str, text: &'a str, max_chars: usize) -> ApiResult<&'a str> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(ApiError::validation(field, format!("{} cannot be empty", what)));
+        }
+        if text.chars().count() > max_chars {
+            return Err(ApiError::validation(
+                field,
+                format!("{} can be at most {} characters", what, max_chars),
+            ));
+        }
+        Ok(text)
+    }
+
+    fn may_moderate(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    fn ensure_organizer(event: &Event, user_id: Uuid, may_manage: bool, action: &str) -> ApiResult<()> {
+        if Self::may_moderate(event, user_id, may_manage) {
+            Ok(())
+        } else {
+            Err(ApiError::authorization(format!("Only the event's organizers can {}", action)))
+        }
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    async fn get_running_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !event.is_running(chrono::Utc::now()) {
+            return Err(ApiError::Domain {
+                source: DomainError::business_rule("Live Q&A and polls are only open while the event is running"),
+            });
+        }
+        Ok(event)
+    }
+
+    // Questions and polls are addressed through their event; one from another
+    // event is as good as missing
+    async fn get_question(&self, event_id: Uuid, question_id: Uuid) -> ApiResult<EventQuestion> {
+        self.question_repository
+            .find_by_id(question_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|question| question.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Question with ID {}", question_id)))
+    }
+
+    async fn get_poll(&self, event_id: Uuid, poll_id: Uuid) -> ApiResult<EventPoll> {
+        self.poll_repository
+            .find_by_id(poll_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|poll| poll.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Poll with ID {}", poll_id)))
+    }
+}
+
+// ============================================================================
+// Event Attachment Application Service
+// ============================================================================
+
+/// Slides, handouts and maps attached to events. Organizers upload and remove
+/// them; public attachments are visible to anyone who can see the event, the
+/// rest only to users with a confirmed registration.
+#[derive(Clone)]
+pub struct EventAttachmentApplicationService {
+    attachment_repository: Arc<dyn EventAttachmentRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    storage: Arc<dyn MediaStorage>,
+    storage_quota: Option<StorageQuotaApplicationService>,
+}
+
+/// A file being uploaded, as read from the request
+#[derive(Debug, Clone)]
+pub struct NewAttachment {
+    pub original_filename: String,
+    pub mime_type: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// Guessed from the MIME type when not given
+    pub category: Option<AttachmentCategory>,
+    pub visibility: AttachmentVisibility,
+    pub bytes: Vec<u8>,
+}
+
+/// The attachments a signed-in user may download, with whether they manage them
+#[derive(Debug, Clone)]
+pub struct AttachmentLibrary {
+    /// Whether the user may upload and remove attachments
+    pub can_manage: bool,
+    pub attachments: Vec<EventAttachment>,
+}
+
+impl EventAttachmentApplicationService {
+    pub const MAX_FILE_BYTES: usize = 25 * 1024 * 1024;
+    pub const MAX_TITLE_CHARS: usize = 200;
+    pub const MAX_DESCRIPTION_CHARS: usize = 2000;
+
+    pub fn new(
+        attachment_repository: Arc<dyn EventAttachmentRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        storage: Arc<dyn MediaStorage>,
+    ) -> Self {
+        Self {
+            attachment_repository,
+            event_repository,
+            registration_repository,
+            storage,
+            storage_quota: None,
+        }
+    }
+
+    /// Refuses uploads that would take the organization over its storage quota
+    pub fn with_storage_quota(mut self, storage_quota: StorageQuotaApplicationService) -> Self {
+        self.storage_quota = Some(storage_quota);
+        self
+    }
+
+    /// The attachments `viewer_id` may download, oldest first. Without a viewer
+    /// only public attachments are listed.
+    pub async fn list_attachments(
+        &self,
+        event_id: Uuid,
+        viewer_id: Option<Uuid>,
+        viewer_may_manage: bool,
+    ) -> ApiResult<Vec<EventAttachment>> {
+        let event = self.get_event(event_id).await?;
+        self.visible_attachments(&event, viewer_id, viewer_may_manage).await
+    }
+
+    pub async fn library(&self, event_id: Uuid, viewer_id: Uuid, viewer_may_manage: bool) -> ApiResult<AttachmentLibrary> {
+        let event = self.get_event(event_id).await?;
+        Ok(AttachmentLibrary {
+            can_manage: Self::is_organizer(&event, viewer_id, viewer_may_manage),
+            attachments: self.visible_attachments(&event, Some(viewer_id), viewer_may_manage).await?,
+        })
+    }
+
+    pub async fn upload(
+        &self,
+        event_id: Uuid,
+        uploader: &User,
+        may_manage: bool,
+        upload: NewAttachment,
+    ) -> ApiResult<EventAttachment> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, uploader.id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can upload attachments"));
+        }
+
+        if upload.bytes.is_empty() {
+            return Err(ApiError::validation("file", "File is empty"));
+        }
+        if upload.bytes.len() > Self::MAX_FILE_BYTES {
+            return Err(ApiError::payload_too_large(format!(
+                "File can be at most {} MB",
+                Self::MAX_FILE_BYTES / (1024 * 1024)
+            )));
+        }
+        let original_filename = upload.original_filename.trim();
+        if original_filename.is_empty() {
+            return Err(ApiError::validation("file", "File name is missing"));
+        }
+        let title = optional_text("title", "Title", upload.title, Self::MAX_TITLE_CHARS)?;
+        let description = optional_text(
+            "description",
+            "Description",
+            upload.description,
+            Self::MAX_DESCRIPTION_CHARS,
+        )?;
+
+        let mut attachment = EventAttachment::new(
+            event_id,
+            uploader,
+            original_filename,
+            upload.mime_type,
+            upload.bytes.len() as i64,
+        );
+        attachment.title = title;
+        attachment.description = description;
+        attachment.visibility = upload.visibility;
+        if let Some(category) = upload.category {
+            attachment.category = category;
+        }
+        if let Some(storage_quota) = &self.storage_quota {
+            storage_quota.check_upload(&event, upload.bytes.len(), 0).await?;
+        }
+
+        self.storage
+            .store(&attachment.storage_key, &upload.bytes)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        if let Err(e) = self.attachment_repository.create(&attachment).await {
+            // Don't leave an orphaned file behind; the row is what makes it reachable
+            if let Err(cleanup) = self.storage.delete(&attachment.storage_key).await {
+                tracing::warn!("Could not remove {} after a failed upload: {}", attachment.storage_key, cleanup);
+            }
+            return Err(ApiError::Domain { source: e });
+        }
+
+        Ok(attachment)
+    }
+
+    /// The attachment with its contents. Attendee-only files are refused to
+    /// anyone without a confirmed registration.
+    pub async fn download(
+        &self,
+        event_id: Uuid,
+        attachment_id: Uuid,
+        viewer_id: Option<Uuid>,
+        viewer_may_manage: bool,
+    ) -> ApiResult<(EventAttachment, Vec<u8>)> {
+        let event = self.get_event(event_id).await?;
+        let attachment = self.get_attachment(event_id, attachment_id).await?;
+
+        if !attachment.is_public() && !self.is_attendee(&event, viewer_id, viewer_may_manage).await? {
+            return Err(ApiError::authorization("Only registered attendees can download this file"));
+        }
+
+        let bytes = self
+            .storage
+            .load(&attachment.storage_key)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.attachment_repository
+            .record_download(attachment.id, chrono::Utc::now())
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        Ok((attachment, bytes))
+    }
+
+    pub async fn delete(&self, event_id: Uuid, attachment_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can remove attachments"));
+        }
+        let attachment = self.get_attachment(event_id, attachment_id).await?;
+
+        self.attachment_repository
+            .delete(attachment.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        // The row is gone, so a leftover file is unreachable; log rather than fail
+        if let Err(e) = self.storage.delete(&attachment.storage_key).await {
+            tracing::warn!("Could not remove {} from media storage: {}", attachment.storage_key, e);
+        }
+        Ok(())
+    }
+
+    async fn visible_attachments(
+        &self,
+        event: &Event,
+        viewer_id: Option<Uuid>,
+        viewer_may_manage: bool,
+    ) -> ApiResult<Vec<EventAttachment>> {
+        let attachments = self
+            .attachment_repository
+            .find_by_event_id(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        if attachments.iter().all(EventAttachment::is_public)
+            || self.is_attendee(event, viewer_id, viewer_may_manage).await?
+        {
+            return Ok(attachments);
+        }
+        Ok(attachments.into_iter().filter(EventAttachment::is_public).collect())
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    // Organizers count as attendees of their own events
+    async fn is_attendee(&self, event: &Event, viewer_id: Option<Uuid>, viewer_may_manage: bool) -> ApiResult<bool> {
+        let Some(viewer_id) = viewer_id else {
+            return Ok(false);
+        };
+        if Self::is_organizer(event, viewer_id, viewer_may_manage) {
+            return Ok(true);
+        }
+
+        let registration = self
+            .registration_repository
+            .find_by_event_and_user(event.id, viewer_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(registration.is_some_and(|registration| {
+            matches!(registration.status, RegistrationStatus::Registered | RegistrationStatus::Attended)
+        }))
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    // Attachments are addressed through their event; one from another event is as good as missing
+    async fn get_attachment(&self, event_id: Uuid, attachment_id: Uuid) -> ApiResult<EventAttachment> {
+        self.attachment_repository
+            .find_by_id(attachment_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|attachment| attachment.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Attachment with ID {}", attachment_id)))
+    }
+}
+
+// ============================================================================
+// Event Photo Application Service
+// ============================================================================
+
+/// Photos from events that are over, in one album per event. Organizers add
+/// them, and so do attendees when the album allows it; attendees' photos wait
+/// for an organizer's approval unless the album says otherwise. Files go to
+/// media storage and count against the organization's quota like attachments.
+#[derive(Clone)]
+pub struct EventPhotoApplicationService {
+    photo_repository: Arc<dyn EventPhotoRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    storage: Arc<dyn MediaStorage>,
+    storage_quota: Option<StorageQuotaApplicationService>,
+}
+
+/// A photo being uploaded, as read from the request
+#[derive(Debug, Clone)]
+pub struct NewPhoto {
+    pub original_filename: String,
+    pub mime_type: String,
+    pub caption: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+/// How organizers want the album run
+#[derive(Debug, Clone, Copy)]
+pub struct AlbumSettings {
+    pub attendee_uploads: bool,
+    pub requires_approval: bool,
+    pub is_public: bool,
+}
+
+/// An event's photos as one viewer sees them, with what they may do
+#[derive(Debug, Clone)]
+pub struct PhotoGallery {
+    pub album: PhotoAlbum,
+    /// Whether the viewer may change the album and moderate its photos
+    pub can_manage: bool,
+    /// Whether the viewer may add photos right now
+    pub can_upload: bool,
+    /// Oldest first: approved photos, plus the viewer's own while they wait or
+    /// were turned down. Organizers get every photo.
+    pub photos: Vec<EventPhoto>,
+    viewer_id: Option<Uuid>,
+}
+
+impl PhotoGallery {
+    /// Organizers remove any photo; others only their own
+    pub fn can_remove(&self, photo: &EventPhoto) -> bool {
+        self.can_manage || (self.viewer_id.is_some() && photo.uploader_id == self.viewer_id)
+    }
+}
+
+impl EventPhotoApplicationService {
+    pub const MAX_FILE_BYTES: usize = 15 * 1024 * 1024;
+    pub const MAX_CAPTION_CHARS: usize = 500;
+    /// Largest gallery offered as one download, as the archive is built in memory
+    pub const MAX_ARCHIVE_BYTES: i64 = 1024 * 1024 * 1024;
+    pub const IMAGE_TYPES: [&'static str; 4] = ["image/jpeg", "image/png", "image/webp", "image/gif"];
+
+    pub fn new(
+        photo_repository: Arc<dyn EventPhotoRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        storage: Arc<dyn MediaStorage>,
+    ) -> Self {
+        Self {
+            photo_repository,
+            event_repository,
+            registration_repository,
+            storage,
+            storage_quota: None,
+        }
+    }
+
+    /// Refuses uploads that would take the organization over its storage quota
+    pub fn with_storage_quota(mut self, storage_quota: StorageQuotaApplicationService) -> Self {
+        self.storage_quota = Some(storage_quota);
+        self
+    }
+
+    /// The photos `viewer_id` may see. Without a viewer, as on the public event
+    /// page, only approved photos of public albums are listed.
+    pub async fn gallery(&self, event_id: Uuid, viewer_id: Option<Uuid>, viewer_may_manage: bool) -> ApiResult<PhotoGallery> {
+        let event = self.get_event(event_id).await?;
+        let album = self.album_of(&event).await?;
+        let can_manage = viewer_id.is_some_and(|viewer_id| Self::is_organizer(&event, viewer_id, viewer_may_manage));
+        let can_upload = match viewer_id {
+            Some(viewer_id) => self.may_upload(&event, &album, viewer_id, viewer_may_manage).await?,
+            None => false,
+        };
+
+        let photos = if viewer_id.is_none() && !album.is_public {
+            Vec::new()
+        } else {
+            self.photo_repository
+                .find_by_event_id(event.id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .into_iter()
+                .filter(|photo| {
+                    can_manage || photo.is_approved() || (viewer_id.is_some() && photo.uploader_id == viewer_id)
+                })
+                .collect()
+        };
+
+        Ok(PhotoGallery { album, can_manage, can_upload, photos, viewer_id })
+    }
+
+    pub async fn update_album(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        settings: AlbumSettings,
+    ) -> ApiResult<PhotoAlbum> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can change its photo album"));
+        }
+
+        let mut album = self.album_of(&event).await?;
+        album.attendee_uploads = settings.attendee_uploads;
+        album.requires_approval = settings.requires_approval;
+        album.is_public = settings.is_public;
+        album.updated_at = chrono::Utc::now();
+        self.photo_repository
+            .save_album(&album)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(album)
+    }
+
+    pub async fn upload(&self, event_id: Uuid, uploader: &User, may_manage: bool, upload: NewPhoto) -> ApiResult<EventPhoto> {
+        let event = self.get_event(event_id).await?;
+        let album = self.album_of(&event).await?;
+        let is_organizer = Self::is_organizer(&event, uploader.id, may_manage);
+        if !is_organizer {
+            if !album.attendee_uploads {
+                return Err(ApiError::authorization("Only the event's organizers can add photos"));
+            }
+            if !self.is_attendee(&event, uploader.id).await? {
+                return Err(ApiError::authorization("Only the event's attendees can add photos"));
+            }
+        }
+        if event.end_date > chrono::Utc::now() {
+            return Err(ApiError::validation("file", "Photos can be added once the event is over"));
+        }
+
+        if upload.bytes.is_empty() {
+            return Err(ApiError::validation("file", "File is empty"));
+        }
+        if upload.bytes.len() > Self::MAX_FILE_BYTES {
+            return Err(ApiError::payload_too_large(format!(
+                "Photos can be at most {} MB",
+                Self::MAX_FILE_BYTES / (1024 * 1024)
+            )));
+        }
+        if !Self::IMAGE_TYPES.contains(&upload.mime_type.as_str()) {
+            return Err(ApiError::validation("file", "Photos must be JPEG, PNG, WebP or GIF images"));
+        }
+        let original_filename = upload.original_filename.trim();
+        if original_filename.is_empty() {
+            return Err(ApiError::validation("file", "File name is missing"));
+        }
+        let caption = optional_text("caption", "Caption", upload.caption, Self::MAX_CAPTION_CHARS)?;
+
+        let status = if is_organizer || !album.requires_approval {
+            PhotoStatus::Approved
+        } else {
+            PhotoStatus::Pending
+        };
+        let mut photo = EventPhoto::new(
+            event_id,
+            uploader,
+            original_filename,
+            upload.mime_type,
+            upload.bytes.len() as i64,
+            status,
+        );
+        photo.caption = caption;
+        if let Some(storage_quota) = &self.storage_quota {
+            storage_quota.check_upload(&event, upload.bytes.len(), 0).await?;
+        }
+
+        self.storage
+            .store(&photo.storage_key, &upload.bytes)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        if let Err(e) = self.photo_repository.create(&photo).await {
+            // Don't leave an orphaned file behind; the row is what makes it reachable
+            if let Err(cleanup) = self.storage.delete(&photo.storage_key).await {
+                tracing::warn!("Could not remove {} after a failed upload: {}", photo.storage_key, cleanup);
+            }
+            return Err(ApiError::Domain { source: e });
+        }
+
+        Ok(photo)
+    }
+
+    /// Approves or rejects a photo; either can be undone by choosing the other
+    pub async fn moderate(
+        &self,
+        event_id: Uuid,
+        photo_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        status: PhotoStatus,
+    ) -> ApiResult<EventPhoto> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can approve or reject photos"));
+        }
+        if status == PhotoStatus::Pending {
+            return Err(ApiError::validation("status", "A photo can only be approved or rejected"));
+        }
+        let mut photo = self.get_photo(event_id, photo_id).await?;
+
+        let now = chrono::Utc::now();
+        self.photo_repository
+            .update_status(photo.id, status, now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        photo.status = status;
+        photo.reviewed_at = Some(now);
+        Ok(photo)
+    }
+
+    /// The photo with its contents, if it's in the viewer's gallery
+    pub async fn download(
+        &self,
+        event_id: Uuid,
+        photo_id: Uuid,
+        viewer_id: Option<Uuid>,
+        viewer_may_manage: bool,
+    ) -> ApiResult<(EventPhoto, Vec<u8>)> {
+        let photo = self
+            .gallery(event_id, viewer_id, viewer_may_manage)
+            .await?
+            .photos
+            .into_iter()
+            .find(|photo| photo.id == photo_id)
+            .ok_or_else(|| ApiError::not_found(format!("Photo with ID {}", photo_id)))?;
+
+        let bytes = self
+            .storage
+            .load(&photo.storage_key)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok((photo, bytes))
+    }
+
+    /// Every approved photo in the viewer's gallery as one ZIP file, with its download name
+    pub async fn download_all(&self, event_id: Uuid, viewer_id: Uuid, viewer_may_manage: bool) -> ApiResult<(String, Vec<u8>)> {
+        let event = self.get_event(event_id).await?;
+        let photos: Vec<EventPhoto> = self
+            .gallery(event_id, Some(viewer_id), viewer_may_manage)
+            .await?
+            .photos
+            .into_iter()
+            .filter(EventPhoto::is_approved)
+            .collect();
+        if photos.iter().map(|photo| photo.file_size).sum::<i64>() > Self::MAX_ARCHIVE_BYTES {
+            return Err(ApiError::payload_too_large(
+                "The gallery is too large to download at once; download the photos one by one",
+            ));
+        }
+
+        let mut files = Vec::with_capacity(photos.len());
+        for photo in photos {
+            let bytes = self
+                .storage
+                .load(&photo.storage_key)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            files.push((photo, bytes));
+        }
+        Ok((format!("{}-photos.zip", event.slug), zip_photos(&files)))
+    }
+
+    pub async fn delete(&self, event_id: Uuid, photo_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        let event = self.get_event(event_id).await?;
+        let photo = self.get_photo(event_id, photo_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) && photo.uploader_id != Some(user_id) {
+            return Err(ApiError::authorization("Only the event's organizers and the photo's uploader can remove it"));
+        }
+
+        self.photo_repository
+            .delete(photo.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        // The row is gone, so a leftover file is unreachable; log rather than fail
+        if let Err(e) = self.storage.delete(&photo.storage_key).await {
+            tracing::warn!("Could not remove {} from media storage: {}", photo.storage_key, e);
+        }
+        Ok(())
+    }
+
+    async fn may_upload(&self, event: &Event, album: &PhotoAlbum, user_id: Uuid, may_manage: bool) -> ApiResult<bool> {
+        if event.end_date > chrono::Utc::now() {
+            return Ok(false);
+        }
+        if Self::is_organizer(event, user_id, may_manage) {
+            return Ok(true);
+        }
+        Ok(album.attendee_uploads && self.is_attendee(event, user_id).await?)
+    }
+
+    // Events without saved settings get the defaults
+    async fn album_of(&self, event: &Event) -> ApiResult<PhotoAlbum> {
+        Ok(self
+            .photo_repository
+            .find_album(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .unwrap_or_else(|| PhotoAlbum::new(event.id, event.organizer_id)))
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn is_attendee(&self, event: &Event, user_id: Uuid) -> ApiResult<bool> {
+        let registration = self
+            .registration_repository
+            .find_by_event_and_user(event.id, user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(registration.is_some_and(|registration| {
+            matches!(registration.status, RegistrationStatus::Registered | RegistrationStatus::Attended)
+        }))
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    // Photos are addressed through their event; one from another event is as good as missing
+    async fn get_photo(&self, event_id: Uuid, photo_id: Uuid) -> ApiResult<EventPhoto> {
+        self.photo_repository
+            .find_by_id(photo_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|photo| photo.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Photo with ID {}", photo_id)))
+    }
+}
+
+// ============================================================================
+// Certificate Application Service
+// ============================================================================
+
+/// Certificates of attendance for checked-in registrations. The PDF carries a
+/// link to a public check that confirms the attendance without signing in.
+#[derive(Clone)]
+pub struct CertificateApplicationService {
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    storage: Arc<dyn MediaStorage>,
+    /// Origin of the frontend; verification links point at its certificate page
+    public_url: String,
+}
+
+impl CertificateApplicationService {
+    pub const MAX_SIGNATURE_BYTES: usize = 1024 * 1024;
+
+    pub fn new(
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        storage: Arc<dyn MediaStorage>,
+        public_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            registration_repository,
+            event_repository,
+            user_repository,
+            storage,
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn verification_url(&self, registration_id: Uuid) -> String {
+        format!("{}/certificates/{}", self.public_url, registration_id)
+    }
+
+    /// The certificate as a PDF, with its download name. Available to the
+    /// attendee and the event's organizers once the registration is checked in.
+    pub async fn certificate(
+        &self,
+        registration_id: Uuid,
+        requester_id: Uuid,
+        requester_may_manage: bool,
+    ) -> ApiResult<(String, Vec<u8>)> {
+        let registration = self
+            .registration_repository
+            .find_by_id(registration_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Registration with ID {}", registration_id)))?;
+        let event = self.get_event(registration.event_id).await?;
+
+        let is_own = registration.user_id == Some(requester_id);
+        if !is_own && !Self::is_organizer(&event, requester_id, requester_may_manage) {
+            return Err(ApiError::authorization("You can only download your own certificate"));
+        }
+
+        let Some(details) = self.details(&registration, &event).await? else {
+            return Err(ApiError::validation(
+                "registration",
+                "A certificate is available once you have been checked in",
+            ));
+        };
+
+        let signature = match self.storage.load(&Self::signature_key(event.id)).await {
+            Ok(bytes) => match SignatureImage::from_png(&bytes) {
+                Ok(signature) => Some(signature),
+                Err(e) => {
+                    // Checked on upload, so only a damaged file ends up here; leave the line blank
+                    tracing::warn!("Ignoring unreadable signature for event {}: {}", event.id, e);
+                    None
+                }
+            },
+            Err(DomainError::NotFound { .. }) => None,
+            Err(e) => return Err(ApiError::Domain { source: e }),
+        };
+
+        Ok((details.filename(), render_certificate(&details, signature.as_ref())))
+    }
+
+    /// What the public check shows: the certificate's details, or `None` when
+    /// the registration doesn't exist or was never checked in
+    pub async fn verify(&self, registration_id: Uuid) -> ApiResult<Option<CertificateDetails>> {
+        let Some(registration) = self
+            .registration_repository
+            .find_by_id(registration_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+        else {
+            return Ok(None);
+        };
+        let Some(event) = self
+            .event_repository
+            .find_by_id(registration.event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+        else {
+            return Ok(None);
+        };
+        self.details(&registration, &event).await
+    }
+
+    /// Store the organizer's signature (PNG) printed on the event's certificates
+    pub async fn set_signature(&self, event_id: Uuid, user_id: Uuid, may_manage: bool, bytes: &[u8]) -> ApiResult<()> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can set the certificate signature"));
+        }
+        if bytes.len() > Self::MAX_SIGNATURE_BYTES {
+            return Err(ApiError::payload_too_large(format!(
+                "Signature can be at most {} KB",
+                Self::MAX_SIGNATURE_BYTES / 1024
+            )));
+        }
+        SignatureImage::from_png(bytes).map_err(|e| ApiError::validation("signature", e))?;
+
+        self.storage
+            .store(&Self::signature_key(event_id), bytes)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn remove_signature(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can remove the certificate signature"));
+        }
+        self.storage
+            .delete(&Self::signature_key(event_id))
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    fn signature_key(event_id: Uuid) -> String {
+        format!("events/{}/certificate/signature.png", event_id)
+    }
+
+    async fn details(&self, registration: &EventRegistration, event: &Event) -> ApiResult<Option<CertificateDetails>> {
+        let checked_in_at = match (&registration.status, registration.checked_in_at) {
+            (RegistrationStatus::Attended, Some(checked_in_at)) => checked_in_at,
+            _ => return Ok(None),
+        };
+
+        let organizer_name = self
+            .find_user(Some(event.organizer_id))
+            .await?
+            .map(|organizer| organizer.name)
+            .unwrap_or_else(|| "The organizer".to_string());
+        // Guests are named on the registration; members by their account
+        let attendee_name = match registration.registrant_name.clone().filter(|name| !name.trim().is_empty()) {
+            Some(name) => name,
+            None => self
+                .find_user(registration.user_id)
+                .await?
+                .map(|user| user.name)
+                .or_else(|| registration.registrant_email.clone())
+                .unwrap_or_else(|| "Attendee".to_string()),
+        };
+
+        Ok(Some(CertificateDetails {
+            registration_id: registration.id,
+            attendee_name,
+            event_title: event.title.clone(),
+            start_date: event.start_date,
+            end_date: event.end_date,
+            location_name: event.location_name.clone(),
+            organizer_name,
+            checked_in_at,
+            verification_url: self.verification_url(registration.id),
+        }))
+    }
+
+    async fn find_user(&self, user_id: Option<Uuid>) -> ApiResult<Option<User>> {
+        let Some(user_id) = user_id else {
+            return Ok(None);
+        };
+        self.user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+}
+
+// ============================================================================
+// Event Budget Application Service
+// ============================================================================
+
+/// Budget lines and expenses for events, with receipts kept in media storage.
+/// Only the event's organizers and administrators can see or change them.
+#[derive(Clone)]
+pub struct EventBudgetApplicationService {
+    budget_repository: Arc<dyn EventBudgetRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    storage: Arc<dyn MediaStorage>,
+    storage_quota: Option<StorageQuotaApplicationService>,
+}
+
+/// A budget line as entered by the organizer; amounts are in øre
+#[derive(Debug, Clone)]
+pub struct BudgetLineInput {
+    pub name: String,
+    pub planned_amount: i64,
+    pub notes: Option<String>,
+}
+
+/// An expense as entered by the organizer; amounts are in øre
+#[derive(Debug, Clone)]
+pub struct ExpenseInput {
+    pub budget_line_id: Option<Uuid>,
+    pub description: String,
+    pub vendor: Option<String>,
+    pub amount: i64,
+    pub incurred_on: NaiveDate,
+}
+
+/// A receipt being uploaded, as read from the request
+#[derive(Debug, Clone)]
+pub struct ReceiptUpload {
+    pub original_filename: String,
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Everything on an event's budget, with its totals
+#[derive(Debug, Clone)]
+pub struct EventBudget {
+    pub lines: Vec<BudgetLine>,
+    pub expenses: Vec<EventExpense>,
+    pub summary: BudgetSummary,
+}
+
+impl EventBudgetApplicationService {
+    pub const MAX_RECEIPT_BYTES: usize = 10 * 1024 * 1024;
+    pub const MAX_NAME_CHARS: usize = 100;
+    pub const MAX_DESCRIPTION_CHARS: usize = 200;
+    pub const MAX_NOTES_CHARS: usize = 1000;
+
+    pub fn new(
+        budget_repository: Arc<dyn EventBudgetRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        storage: Arc<dyn MediaStorage>,
+    ) -> Self {
+        Self {
+            budget_repository,
+            event_repository,
+            storage,
+            storage_quota: None,
+        }
+    }
+
+    /// Refuses receipts that would take the organization over its storage quota
+    pub fn with_storage_quota(mut self, storage_quota: StorageQuotaApplicationService) -> Self {
+        self.storage_quota = Some(storage_quota);
+        self
+    }
+
+    pub async fn budget(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<EventBudget> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let (lines, expenses) = self.load(event_id).await?;
+        Ok(EventBudget {
+            summary: BudgetSummary::new(&lines, &expenses),
+            lines,
+            expenses,
+        })
+    }
+
+    /// The event's totals for organizers and administrators; `None` for anyone else
+    pub async fn summary(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Option<BudgetSummary>> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Ok(None);
+        }
+        let (lines, expenses) = self.load(event_id).await?;
+        Ok(Some(BudgetSummary::new(&lines, &expenses)))
+    }
+
+    pub async fn add_line(&self, event_id: Uuid, user_id: Uuid, may_manage: bool, input: BudgetLineInput) -> ApiResult<BudgetLine> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let input = Self::validate_line(input)?;
+
+        let mut line = BudgetLine::new(event_id, input.name, input.planned_amount);
+        line.notes = input.notes;
+        self.budget_repository
+            .create_line(&line)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(line)
+    }
+
+    pub async fn update_line(
+        &self,
+        event_id: Uuid,
+        line_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: BudgetLineInput,
+    ) -> ApiResult<BudgetLine> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut line = self.get_line(event_id, line_id).await?;
+        let input = Self::validate_line(input)?;
+
+        line.name = input.name;
+        line.planned_amount = input.planned_amount;
+        line.notes = input.notes;
+        line.updated_at = chrono::Utc::now();
+        self.budget_repository
+            .update_line(&line)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(line)
+    }
+
+    /// Expenses booked against the line stay on the budget as unassigned
+    pub async fn delete_line(&self, event_id: Uuid, line_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let line = self.get_line(event_id, line_id).await?;
+        self.budget_repository
+            .delete_line(line.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn record_expense(
+        &self,
+        event_id: Uuid,
+        recorded_by: &User,
+        may_manage: bool,
+        input: ExpenseInput,
+    ) -> ApiResult<EventExpense> {
+        self.get_managed_event(event_id, recorded_by.id, may_manage).await?;
+        let input = self.validate_expense(event_id, input).await?;
+
+        let mut expense = EventExpense::new(event_id, recorded_by, input.description, input.amount, input.incurred_on);
+        expense.budget_line_id = input.budget_line_id;
+        expense.vendor = input.vendor;
+        self.budget_repository
+            .create_expense(&expense)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(expense)
+    }
+
+    pub async fn update_expense(
+        &self,
+        event_id: Uuid,
+        expense_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: ExpenseInput,
+    ) -> ApiResult<EventExpense> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut expense = self.get_expense(event_id, expense_id).await?;
+        let input = self.validate_expense(event_id, input).await?;
+
+        expense.budget_line_id = input.budget_line_id;
+        expense.description = input.description;
+        expense.vendor = input.vendor;
+        expense.amount = input.amount;
+        expense.incurred_on = input.incurred_on;
+        expense.updated_at = chrono::Utc::now();
+        self.budget_repository
+            .update_expense(&expense)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(expense)
+    }
+
+    pub async fn delete_expense(&self, event_id: Uuid, expense_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let expense = self.get_expense(event_id, expense_id).await?;
+        self.budget_repository
+            .delete_expense(expense.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        if let Some(receipt) = expense.receipt {
+            self.discard(&receipt.storage_key).await;
+        }
+        Ok(())
+    }
+
+    /// Attaches a scanned receipt (image or PDF), replacing any earlier one
+    pub async fn attach_receipt(
+        &self,
+        event_id: Uuid,
+        expense_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        upload: ReceiptUpload,
+    ) -> ApiResult<EventExpense> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut expense = self.get_expense(event_id, expense_id).await?;
+
+        if upload.bytes.is_empty() {
+            return Err(ApiError::validation("file", "File is empty"));
+        }
+        if upload.bytes.len() > Self::MAX_RECEIPT_BYTES {
+            return Err(ApiError::payload_too_large(format!(
+                "Receipts can be at most {} MB",
+                Self::MAX_RECEIPT_BYTES / (1024 * 1024)
+            )));
+        }
+        let mime_type = upload.mime_type.to_lowercase();
+        if !mime_type.starts_with("image/") && mime_type != "application/pdf" {
+            return Err(ApiError::validation("file", "Receipts must be an image or a PDF"));
+        }
+        let original_filename = upload.original_filename.trim();
+        if original_filename.is_empty() {
+            return Err(ApiError::validation("file", "File name is missing"));
+        }
+
+        let receipt = ExpenseReceipt {
+            original_filename: original_filename.to_string(),
+            storage_key: expense.receipt_key(original_filename),
+            file_size: upload.bytes.len() as i64,
+            mime_type,
+        };
+        if let Some(storage_quota) = &self.storage_quota {
+            let replaced = expense.receipt.as_ref().map_or(0, |previous| previous.file_size);
+            storage_quota.check_upload(&event, upload.bytes.len(), replaced).await?;
+        }
+        self.storage
+            .store(&receipt.storage_key, &upload.bytes)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let previous = expense.receipt.replace(receipt.clone());
+        expense.updated_at = chrono::Utc::now();
+        if let Err(e) = self.budget_repository.update_expense(&expense).await {
+            if previous.as_ref().map(|p| &p.storage_key) != Some(&receipt.storage_key) {
+                self.discard(&receipt.storage_key).await;
+            }
+            return Err(ApiError::Domain { source: e });
+        }
+        if let Some(previous) = previous.filter(|previous| previous.storage_key != receipt.storage_key) {
+            self.discard(&previous.storage_key).await;
+        }
+        Ok(expense)
+    }
+
+    pub async fn receipt(
+        &self,
+        event_id: Uuid,
+        expense_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<(ExpenseReceipt, Vec<u8>)> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let expense = self.get_expense(event_id, expense_id).await?;
+        let receipt = expense
+            .receipt
+            .ok_or_else(|| ApiError::not_found(format!("Receipt for expense {}", expense_id)))?;
+
+        let bytes = self
+            .storage
+            .load(&receipt.storage_key)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok((receipt, bytes))
+    }
+
+    pub async fn remove_receipt(
+        &self,
+        event_id: Uuid,
+        expense_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<EventExpense> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut expense = self.get_expense(event_id, expense_id).await?;
+        let Some(receipt) = expense.receipt.take() else {
+            return Ok(expense);
+        };
+
+        expense.updated_at = chrono::Utc::now();
+        self.budget_repository
+            .update_expense(&expense)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.discard(&receipt.storage_key).await;
+        Ok(expense)
+    }
+
+    async fn load(&self, event_id: Uuid) -> ApiResult<(Vec<BudgetLine>, Vec<EventExpense>)> {
+        let lines = self
+            .budget_repository
+            .find_lines_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let expenses = self
+            .budget_repository
+            .find_expenses_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok((lines, expenses))
+    }
+
+    fn validate_line(input: BudgetLineInput) -> ApiResult<BudgetLineInput> {
+        let name = Self::required_text("name", "Name", input.name, Self::MAX_NAME_CHARS)?;
+        if input.planned_amount < 0 {
+            return Err(ApiError::validation("planned_amount", "Planned amount can't be negative"));
+        }
+        Ok(BudgetLineInput {
+            name,
+            planned_amount: input.planned_amount,
+            notes: optional_text("notes", "Notes", input.notes, Self::MAX_NOTES_CHARS)?,
+        })
+    }
+
+    async fn validate_expense(&self, event_id: Uuid, input: ExpenseInput) -> ApiResult<ExpenseInput> {
+        let description = Self::required_text("description", "Description", input.description, Self::MAX_DESCRIPTION_CHARS)?;
+        if input.amount <= 0 {
+            return Err(ApiError::validation("amount", "Amount must be greater than zero"));
+        }
+        if let Some(line_id) = input.budget_line_id {
+            self.get_line(event_id, line_id).await?;
+        }
+        Ok(ExpenseInput {
+            budget_line_id: input.budget_line_id,
+            description,
+            vendor: optional_text("vendor", "Vendor", input.vendor, Self::MAX_NAME_CHARS)?,
+            amount: input.amount,
+            incurred_on: input.incurred_on,
+        })
+    }
+
+    fn required_text(field: &str, what: &str, text: String, max_chars: usize) -> ApiResult<String> {
+        optional_text(field, what, Some(text), max_chars)?
+            .ok_or_else(|| ApiError::validation(field, format!("{} is required", what)))
+    }
+
+    // The row no longer points at the file, so a leftover is unreachable; log rather than fail
+    async fn discard(&self, storage_key: &str) {
+        if let Err(e) = self.storage.delete(storage_key).await {
+            tracing::warn!("Could not remove {} from media storage: {}", storage_key, e);
+        }
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its budget"));
+        }
+        Ok(event)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    // Lines and expenses are addressed through their event; one from another event is as good as missing
+    async fn get_line(&self, event_id: Uuid, line_id: Uuid) -> ApiResult<BudgetLine> {
+        self.budget_repository
+            .find_line_by_id(line_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|line| line.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Budget line with ID {}", line_id)))
+    }
+
+    async fn get_expense(&self, event_id: Uuid, expense_id: Uuid) -> ApiResult<EventExpense> {
+        self.budget_repository
+            .find_expense_by_id(expense_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|expense| expense.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Expense with ID {}", expense_id)))
+    }
+}
+
+// ============================================================================
+// Event Sponsor Application Service
+// ============================================================================
+
+/// Sponsors shown on an event's public page and printed program.
+/// Anyone can list them; only the event's organizers and administrators can change them.
+#[derive(Clone)]
+pub struct EventSponsorApplicationService {
+    sponsor_repository: Arc<dyn EventSponsorRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+/// A sponsor as entered by the organizer
+#[derive(Debug, Clone)]
+pub struct SponsorInput {
+    pub name: String,
+    pub tier: SponsorTier,
+    pub logo_url: Option<String>,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    pub display_order: Option<i32>,
+}
+
+impl EventSponsorApplicationService {
+    pub const MAX_NAME_CHARS: usize = 100;
+    pub const MAX_URL_CHARS: usize = 500;
+    pub const MAX_DESCRIPTION_CHARS: usize = 500;
+
+    pub fn new(
+        sponsor_repository: Arc<dyn EventSponsorRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self {
+            sponsor_repository,
+            event_repository,
+        }
+    }
+
+    /// The event's sponsors, highest tier first
+    pub async fn list(&self, event_id: Uuid) -> ApiResult<Vec<EventSponsor>> {
+        self.get_event(event_id).await?;
+        self.sponsor_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Without an explicit display order the sponsor goes last within its tier
+    pub async fn add(&self, event_id: Uuid, user_id: Uuid, may_manage: bool, input: SponsorInput) -> ApiResult<EventSponsor> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let input = Self::validate(input)?;
+
+        let display_order = match input.display_order {
+            Some(order) => order,
+            None => self
+                .list(event_id)
+                .await?
+                .iter()
+                .filter(|sponsor| sponsor.tier == input.tier)
+                .map(|sponsor| sponsor.display_order + 1)
+                .max()
+                .unwrap_or(0),
+        };
+        let mut sponsor = EventSponsor::new(event_id, input.name, input.tier);
+        sponsor.logo_url = input.logo_url;
+        sponsor.website_url = input.website_url;
+        sponsor.description = input.description;
+        sponsor.display_order = display_order;
+        self.sponsor_repository
+            .create(&sponsor)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(sponsor)
+    }
+
+    pub async fn update(
+        &self,
+        event_id: Uuid,
+        sponsor_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: SponsorInput,
+    ) -> ApiResult<EventSponsor> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut sponsor = self.get_sponsor(event_id, sponsor_id).await?;
+        let input = Self::validate(input)?;
+
+        sponsor.name = input.name;
+        sponsor.tier = input.tier;
+        sponsor.logo_url = input.logo_url;
+        sponsor.website_url = input.website_url;
+        sponsor.description = input.description;
+        if let Some(order) = input.display_order {
+            sponsor.display_order = order;
+        }
+        sponsor.updated_at = chrono::Utc::now();
+        self.sponsor_repository
+            .update(&sponsor)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(sponsor)
+    }
+
+    pub async fn delete(&self, event_id: Uuid, sponsor_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let sponsor = self.get_sponsor(event_id, sponsor_id).await?;
+        self.sponsor_repository
+            .delete(sponsor.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Gives each listed sponsor its position as display order; tiers still group first
+    pub async fn reorder(
+        &self,
+        event_id: Uuid,
+        sponsor_ids: Vec<Uuid>,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<Vec<EventSponsor>> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut sponsors: HashMap<Uuid, EventSponsor> = self
+            .list(event_id)
+            .await?
+            .into_iter()
+            .map(|sponsor| (sponsor.id, sponsor))
+            .collect();
+
+        let mut seen = HashSet::new();
+        for id in &sponsor_ids {
+            if !seen.insert(*id) {
+                return Err(ApiError::validation("sponsor_ids", "Each sponsor can only be listed once"));
+            }
+            if !sponsors.contains_key(id) {
+                return Err(ApiError::not_found(format!("Sponsor with ID {}", id)));
+            }
+        }
+
+        let now = chrono::Utc::now();
+        for (position, id) in sponsor_ids.iter().enumerate() {
+            let Some(sponsor) = sponsors.get_mut(id) else { continue };
+            let order = position as i32;
+            if sponsor.display_order == order {
+                continue;
+            }
+            sponsor.display_order = order;
+            sponsor.updated_at = now;
+            self.sponsor_repository
+                .update(sponsor)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+        }
+
+        let mut sponsors: Vec<EventSponsor> = sponsors.into_values().collect();
+        EventSponsor::sort_for_display(&mut sponsors);
+        Ok(sponsors)
+    }
+
+    fn validate(input: SponsorInput) -> ApiResult<SponsorInput> {
+        let name = optional_text("name", "Name", Some(input.name), Self::MAX_NAME_CHARS)?
+            .ok_or_else(|| ApiError::validation("name", "Name is required"))?;
+        Ok(SponsorInput {
+            name,
+            tier: input.tier,
+            logo_url: optional_web_url("logo_url", "Logo URL", input.logo_url, Self::MAX_URL_CHARS)?,
+            website_url: optional_web_url("website_url", "Website URL", input.website_url, Self::MAX_URL_CHARS)?,
+            description: optional_text("description", "Description", input.description, Self::MAX_DESCRIPTION_CHARS)?,
+            display_order: input.display_order,
+        })
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its sponsors"));
+        }
+        Ok(event)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    async fn get_sponsor(&self, event_id: Uuid, sponsor_id: Uuid) -> ApiResult<EventSponsor> {
+        self.sponsor_repository
+            .find_by_id(sponsor_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|sponsor| sponsor.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Sponsor with ID {}", sponsor_id)))
+    }
+}
+
+// ============================================================================
+// Short Link Application Service
+// ============================================================================
+
+// Codes start with an uppercase letter and leave out look-alike characters
+const SHORT_CODE_FIRST: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ";
+const SHORT_CODE_REST: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz23456789";
+
+/// Short, trackable links to an event's public page (`/e/{code}`) for
+/// newsletters and social media. Only the event's organizers and
+/// administrators can manage them; anyone can follow them.
+#[derive(Clone)]
+pub struct ShortLinkApplicationService {
+    link_repository: Arc<dyn ShortLinkRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    invitation_repository: Option<Arc<dyn EventInvitationRepository>>,
+    /// Origin of the frontend, where `/e/` pages are served
+    public_url: String,
+}
+
+/// A short link as entered by the organizer
+#[derive(Debug, Clone, Default)]
+pub struct ShortLinkInput {
+    pub label: Option<String>,
+    pub campaign: CampaignParameters,
+}
+
+/// One invitation's personal copy of a short link
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvitationShortLink {
+    pub invitation_id: Uuid,
+    pub url: String,
+}
+
+impl ShortLinkApplicationService {
+    pub const MAX_LABEL_CHARS: usize = 100;
+    pub const MAX_CAMPAIGN_CHARS: usize = 100;
+    /// Referring sites listed in a link's stats
+    pub const MAX_REFERRERS: i64 = 20;
+    // A fresh code is drawn when the previous one was taken
+    const CODE_ATTEMPTS: usize = 5;
+
+    pub fn new(
+        link_repository: Arc<dyn ShortLinkRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        public_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            link_repository,
+            event_repository,
+            invitation_repository: None,
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Attributes clicks on invitations' personal copies of a link and marks
+    /// those invitations as clicked
+    pub fn with_invitations(mut self, invitation_repository: Arc<dyn EventInvitationRepository>) -> Self {
+        self.invitation_repository = Some(invitation_repository);
+        self
+    }
+
+    pub fn short_url(&self, link: &ShortLink) -> String {
+        format!("{}/e/{}", self.public_url, link.code)
+    }
+
+    /// The event's links, newest first, with their clicks so far
+    pub async fn list(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<(ShortLink, ShortLinkClicks)>> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let links = self
+            .link_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let mut totals: HashMap<Uuid, ShortLinkClicks> = self
+            .link_repository
+            .click_totals(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|clicks| (clicks.link_id, clicks))
+            .collect();
+        Ok(links
+            .into_iter()
+            .map(|link| {
+                let clicks = totals.remove(&link.id).unwrap_or_else(|| ShortLinkClicks {
+                    link_id: link.id,
+                    ..ShortLinkClicks::default()
+                });
+                (link, clicks)
+            })
+            .collect())
+    }
+
+    pub async fn create(&self, event_id: Uuid, user_id: Uuid, may_manage: bool, input: ShortLinkInput) -> ApiResult<ShortLink> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let input = Self::validate(input)?;
+
+        let mut link = ShortLink {
+            id: Uuid::new_v4(),
+            event_id,
+            code: String::new(),
+            label: input.label,
+            campaign: input.campaign,
+            created_by: user_id,
+            created_at: chrono::Utc::now(),
+        };
+        for _ in 0..Self::CODE_ATTEMPTS {
+            link.code = Self::generate_code();
+            match self.link_repository.create(&link).await {
+                Ok(()) => return Ok(link),
+                Err(DomainError::ConflictError { .. }) => continue,
+                Err(e) => return Err(ApiError::Domain { source: e }),
+            }
+        }
+        Err(ApiError::internal("Could not find a free short link code"))
+    }
+
+    pub async fn stats(&self, event_id: Uuid, link_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<(ShortLink, ShortLinkStats)> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let link = self.get_link(event_id, link_id).await?;
+        let stats = self
+            .link_repository
+            .stats(link.id, Self::MAX_REFERRERS)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok((link, stats))
+    }
+
+    /// Removes the link along with its clicks; the code stops working
+    pub async fn delete(&self, event_id: Uuid, link_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let link = self.get_link(event_id, link_id).await?;
+        self.link_repository
+            .delete(link.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// A personal copy of the link for each of the event's invitations, for
+    /// mail merges. Invitations created before every invitation got a token
+    /// are issued one.
+    pub async fn invitation_links(
+        &self,
+        event_id: Uuid,
+        link_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<Vec<InvitationShortLink>> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let link = self.get_link(event_id, link_id).await?;
+        let Some(invitations) = &self.invitation_repository else {
+            return Ok(Vec::new());
+        };
+
+        let mut links = Vec::new();
+        for mut invitation in invitations
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+        {
+            let token = match invitation.invitation_token.clone() {
+                Some(token) => token,
+                None => {
+                    let token = InvitationService::new().generate_invitation_token();
+                    invitation.invitation_token = Some(token.clone());
+                    invitation.updated_at = chrono::Utc::now();
+                    invitations
+                        .update(&invitation)
+                        .await
+                        .map_err(|e| ApiError::Domain { source: e })?;
+                    token
+                }
+            };
+            let url = reqwest::Url::parse_with_params(&self.short_url(&link), &[("i", token.as_str())])
+                .map_err(|e| ApiError::internal(format!("Invalid short link: {}", e)))?;
+            links.push(InvitationShortLink {
+                invitation_id: invitation.id,
+                url: url.into(),
+            });
+        }
+        Ok(links)
+    }
+
+    /// Records a click on the link with `code` and returns the event page to
+    /// send the visitor to. `invitation_token` comes from an invitation's
+    /// personal copy of the link; `referrer` is the `Referer` header.
+    pub async fn follow(&self, code: &str, invitation_token: Option<&str>, referrer: Option<&str>) -> ApiResult<String> {
+        let link = self
+            .link_repository
+            .find_by_code(code)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Short link '{}'", code)))?;
+        let event = self.get_event(link.event_id).await?;
+
+        let invitation_id = match invitation_token {
+            Some(token) => self.mark_invitation_clicked(&link, token).await,
+            None => None,
+        };
+        let click = ShortLinkClick {
+            id: Uuid::new_v4(),
+            link_id: link.id,
+            invitation_id,
+            referrer_host: referrer.and_then(Self::referrer_host),
+            clicked_at: chrono::Utc::now(),
+        };
+        // A click that can't be stored shouldn't break the link
+        if let Err(e) = self.link_repository.record_click(&click).await {
+            tracing::warn!("Failed to record click on short link {}: {}", link.code, e);
+        }
+
+        self.target_url(&link, &event, invitation_id.is_some())
+    }
+
+    /// The event page with the link's `utm_*` parameters. Invitations' copies
+    /// default to `utm_source=invitation` and `utm_medium=email` where the
+    /// organizer left those out.
+    pub fn target_url(&self, link: &ShortLink, event: &Event, via_invitation: bool) -> ApiResult<String> {
+        let mut campaign = link.campaign.clone();
+        if via_invitation {
+            campaign.source.get_or_insert_with(|| "invitation".to_string());
+            campaign.medium.get_or_insert_with(|| "email".to_string());
+        }
+        let params: Vec<(&str, &str)> = [
+            ("utm_source", &campaign.source),
+            ("utm_medium", &campaign.medium),
+            ("utm_campaign", &campaign.campaign),
+            ("utm_content", &campaign.content),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.as_deref().map(|value| (name, value)))
+        .collect();
+        let page = format!("{}/e/{}", self.public_url, event.slug);
+        let url = reqwest::Url::parse_with_params(&page, &params)
+            .map_err(|e| ApiError::internal(format!("Invalid event page address: {}", e)))?;
+        // No dangling `?` when the link has no campaign
+        Ok(url.as_str().trim_end_matches('?').to_string())
+    }
+
+    // Unknown tokens and invitations to other events are ignored; the click
+    // is then counted without attribution
+    async fn mark_invitation_clicked(&self, link: &ShortLink, token: &str) -> Option<Uuid> {
+        let invitations = self.invitation_repository.as_ref()?;
+        let mut invitation = match invitations.find_by_token(token).await {
+            Ok(Some(invitation)) if invitation.event_id == link.event_id => invitation,
+            Ok(_) => return None,
+            Err(e) => {
+                tracing::warn!("Failed to look up invitation for short link {}: {}", link.code, e);
+                return None;
+            }
+        };
+        if invitation.clicked_at.is_none() {
+            InvitationService::new().mark_as_clicked(&mut invitation);
+            if let Err(e) = invitations.update(&invitation).await {
+                tracing::warn!("Failed to mark invitation {} as clicked: {}", invitation.id, e);
+            }
+        }
+        Some(invitation.id)
+    }
+
+    fn referrer_host(referrer: &str) -> Option<String> {
+        reqwest::Url::parse(referrer)
+            .ok()
+            .filter(|url| matches!(url.scheme(), "http" | "https"))
+            .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+    }
+
+    fn generate_code() -> String {
+        let bytes = Uuid::new_v4().into_bytes();
+        let mut code = String::with_capacity(ShortLink::CODE_LENGTH);
+        code.push(SHORT_CODE_FIRST[bytes[0] as usize % SHORT_CODE_FIRST.len()] as char);
+        for byte in &bytes[1..ShortLink::CODE_LENGTH] {
+            code.push(SHORT_CODE_REST[*byte as usize % SHORT_CODE_REST.len()] as char);
+        }
+        code
+    }
+
+    fn validate(input: ShortLinkInput) -> ApiResult<ShortLinkInput> {
+        let campaign = input.campaign;
+        Ok(ShortLinkInput {
+            label: optional_text("label", "Label", input.label, Self::MAX_LABEL_CHARS)?,
+            campaign: CampaignParameters {
+                source: optional_text("utm_source", "Source", campaign.source, Self::MAX_CAMPAIGN_CHARS)?,
+                medium: optional_text("utm_medium", "Medium", campaign.medium, Self::MAX_CAMPAIGN_CHARS)?,
+                campaign: optional_text("utm_campaign", "Campaign", campaign.campaign, Self::MAX_CAMPAIGN_CHARS)?,
+                content: optional_text("utm_content", "Content", campaign.content, Self::MAX_CAMPAIGN_CHARS)?,
+            },
+        })
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its short links"));
+        }
+        Ok(event)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    async fn get_link(&self, event_id: Uuid, link_id: Uuid) -> ApiResult<ShortLink> {
+        self.link_repository
+            .find_by_id(link_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|link| link.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Short link with ID {}", link_id)))
+    }
+}
+
+// ============================================================================
+// Event Poster Application Service
+// ============================================================================
+
+/// File formats a poster can be downloaded in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PosterFormat {
+    Pdf,
+    Png,
+}
+
+impl PosterFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            PosterFormat::Pdf => "pdf",
+            PosterFormat::Png => "png",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            PosterFormat::Pdf => "application/pdf",
+            PosterFormat::Png => "image/png",
+        }
+    }
+}
+
+/// Printable posters of an event, in its category's color, with a QR code
+/// leading to the public page where guests register. Only the event's
+/// organizers and administrators can download them.
+#[derive(Clone)]
+pub struct EventPosterApplicationService {
+    event_repository: Arc<dyn EventRepository>,
+    category_repository: Arc<dyn EventCategoryRepository>,
+    link_repository: Option<Arc<dyn ShortLinkRepository>>,
+    /// Origin of the frontend, where `/e/` pages are served
+    public_url: String,
+}
+
+impl EventPosterApplicationService {
+    pub fn new(
+        event_repository: Arc<dyn EventRepository>,
+        category_repository: Arc<dyn EventCategoryRepository>,
+        public_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            event_repository,
+            category_repository,
+            link_repository: None,
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Lets the QR code use one of the event's short links, so scans of the
+    /// poster are counted with that link's clicks
+    pub fn with_short_links(mut self, link_repository: Arc<dyn ShortLinkRepository>) -> Self {
+        self.link_repository = Some(link_repository);
+        self
+    }
+
+    /// The poster's file name and contents. The QR code leads to the event
+    /// page, or through `short_link_id` when given.
+    pub async fn render(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        format: PosterFormat,
+        short_link_id: Option<Uuid>,
+    ) -> ApiResult<(String, Vec<u8>)> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        if !(may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)) {
+            return Err(ApiError::authorization("Only the event's organizers can download its poster"));
+        }
+        if event.is_private {
+            return Err(ApiError::bad_request(
+                "Private events have no public page for a poster to point to",
+            ));
+        }
+
+        let registration_url = match short_link_id {
+            Some(link_id) => self.short_link_url(event.id, link_id).await?,
+            None => format!("{}/e/{}", self.public_url, event.slug),
+        };
+        let category = self
+            .category_repository
+            .find_by_id(&event.category_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let details = PosterDetails::for_event(
+            &event,
+            registration_url,
+            category.as_ref().and_then(|category| category.color_hex.as_deref()),
+        );
+
+        let bytes = match format {
+            PosterFormat::Pdf => render_poster_pdf(&details),
+            PosterFormat::Png => render_poster_png(&details),
+        };
+        Ok((details.filename(format.extension()), bytes))
+    }
+
+    async fn short_link_url(&self, event_id: Uuid, link_id: Uuid) -> ApiResult<String> {
+        let not_found = || ApiError::not_found(format!("Short link with ID {}", link_id));
+        let Some(links) = &self.link_repository else {
+            return Err(not_found());
+        };
+        let link = links
+            .find_by_id(link_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|link| link.event_id == event_id)
+            .ok_or_else(not_found)?;
+        Ok(format!("{}/e/{}", self.public_url, link.code))
+    }
+}
+
+// ============================================================================
+// Overbooking Application Service
+// ============================================================================
+
+/// An event's overbooking policy, with the no-show history of its organizer's
+/// past events to base the percentage on
+#[derive(Debug, Clone)]
+pub struct OverbookingOverview {
+    pub policy: Option<OverbookingPolicy>,
+    pub capacity: Option<i32>,
+    /// Registrations accepted before the waitlist starts
+    pub bookable_capacity: Option<i32>,
+    pub history: NoShowHistory,
+}
+
+/// How an event's turnout compared to its capacity
+#[derive(Debug, Clone)]
+pub struct OverbookingReport {
+    pub policy: Option<OverbookingPolicy>,
+    pub capacity: Option<i32>,
+    pub bookable_capacity: Option<i32>,
+    /// Registrations that weren't cancelled or left on the waitlist
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub no_shows: i64,
+    /// Attendees checked in beyond the capacity
+    pub over_capacity: i64,
+}
+
+/// Organizers let an event take more registrations than it seats, so the
+/// seats of registrants who don't show up aren't left empty. Registrations
+/// apply the policy through `EventRegistrationApplicationService`.
+#[derive(Clone)]
+pub struct EventOverbookingApplicationService {
+    policy_repository: Arc<dyn OverbookingPolicyRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    summary_repository: Arc<dyn EventSummaryRepository>,
+}
+
+impl EventOverbookingApplicationService {
+    /// Most recent past events the no-show history covers
+    pub const HISTORY_EVENTS: i64 = 20;
+
+    pub fn new(
+        policy_repository: Arc<dyn OverbookingPolicyRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        summary_repository: Arc<dyn EventSummaryRepository>,
+    ) -> Self {
+        Self {
+            policy_repository,
+            event_repository,
+            summary_repository,
+        }
+    }
+
+    pub async fn overview(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<OverbookingOverview> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let policy = self.find_policy(event.id).await?;
+        Ok(OverbookingOverview {
+            capacity: event.max_attendees,
+            bookable_capacity: Self::bookable(&event, policy.as_ref()),
+            history: self.no_show_history(&event).await?,
+            policy,
+        })
+    }
+
+    /// Creates or replaces the event's policy. Only events with a capacity
+    /// can be overbooked, and the ceiling can't be below the capacity.
+    pub async fn set_policy(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        percent: i32,
+        hard_ceiling: Option<i32>,
+    ) -> ApiResult<OverbookingPolicy> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let Some(capacity) = event.max_attendees else {
+            return Err(ApiError::bad_request("Only events with a capacity limit can be overbooked"));
+        };
+        if !(0..=OverbookingPolicy::MAX_PERCENT).contains(&percent) {
+            return Err(ApiError::validation(
+                "percent",
+                format!("Overbooking must be between 0 and {} percent", OverbookingPolicy::MAX_PERCENT),
+            ));
+        }
+        if hard_ceiling.is_some_and(|ceiling| ceiling < capacity) {
+            return Err(ApiError::validation(
+                "hard_ceiling",
+                format!("The ceiling can't be below the event's capacity of {}", capacity),
+            ));
+        }
+
+        let policy = OverbookingPolicy {
+            event_id: event.id,
+            percent,
+            hard_ceiling,
+            updated_by: user_id,
+            updated_at: chrono::Utc::now(),
+        };
+        self.policy_repository
+            .save(&policy)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(policy)
+    }
+
+    /// Registrations already accepted beyond the capacity keep their seats
+    pub async fn remove_policy(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.policy_repository
+            .delete(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Realized attendance against the capacity, from check-ins so far
+    pub async fn report(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<OverbookingReport> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let policy = self.find_policy(event.id).await?;
+        let summary = self
+            .summary_repository
+            .find_by_event_ids(&[event.id])
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .next();
+        let (registrations, checked_in) = summary
+            .map(|summary| (summary.registration_counts.confirmed(), summary.checked_in))
+            .unwrap_or_default();
+        Ok(OverbookingReport {
+            capacity: event.max_attendees,
+            bookable_capacity: Self::bookable(&event, policy.as_ref()),
+            registrations,
+            checked_in,
+            no_shows: (registrations - checked_in).max(0),
+            over_capacity: event
+                .max_attendees
+                .map_or(0, |capacity| (checked_in - capacity as i64).max(0)),
+            policy,
+        })
+    }
+
+    fn bookable(event: &Event, policy: Option<&OverbookingPolicy>) -> Option<i32> {
+        let capacity = event.max_attendees?;
+        Some(policy.map_or(capacity, |policy| policy.bookable_capacity(capacity)))
+    }
+
+    // The organizer's most recent past events, this one left out
+    async fn no_show_history(&self, event: &Event) -> ApiResult<NoShowHistory> {
+        let filter = PastEventFilter {
+            organizer_id: Some(event.organizer_id),
+            ..Default::default()
+        };
+        let pagination = PaginationParams::new(0, Self::HISTORY_EVENTS).map_err(|e| ApiError::Domain { source: e })?;
+        let past = self
+            .summary_repository
+            .find_past(&filter, chrono::Utc::now(), pagination)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(past
+            .items
+            .iter()
+            .filter(|summary| summary.event_id != event.id && summary.registration_counts.confirmed() > 0)
+            .fold(NoShowHistory::default(), |history, summary| NoShowHistory {
+                events: history.events + 1,
+                registrations: history.registrations + summary.registration_counts.confirmed(),
+                checked_in: history.checked_in + summary.checked_in,
+            }))
+    }
+
+    async fn find_policy(&self, event_id: Uuid) -> ApiResult<Option<OverbookingPolicy>> {
+        self.policy_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        if !(may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its overbooking"));
+        }
+        Ok(event)
+    }
+}
+
+// ============================================================================
+// Ticketing Application Service
+// ============================================================================
+
+/// A ticket type as entered by the organizer; the price is in minor units of
+/// the currency
+#[derive(Debug, Clone)]
+pub struct TicketTypeInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub price: i64,
+    pub currency: Currency,
+    /// One of `VAT_RATES`; 0 when the tickets aren't subject to VAT
+    pub vat_rate: i32,
+    pub quantity: Option<i32>,
+    pub is_active: bool,
+}
+
+/// A ticket type with how many of it have been sold
+#[derive(Debug, Clone)]
+pub struct TicketTypeAvailability {
+    pub ticket_type: TicketType,
+    pub sold: i64,
+    /// `None` when the type has no quantity limit
+    pub remaining: Option<i64>,
+}
+
+/// An event's confirmed ticket sales, per currency and in NOK at the rates
+/// the orders were placed at
+#[derive(Debug, Clone)]
+pub struct TicketRevenue {
+    pub by_currency: Vec<CurrencyRevenue>,
+    pub orders: i64,
+    pub tickets: i64,
+    /// In øre
+    pub total_nok: i64,
+}
+
+/// Ticket types priced in NOK, EUR or USD, and orders for them. An order
+/// keeps its currency and the Norges Bank rate of the day it was placed, so
+/// revenue adds up in NOK without moving with later rates. Orders are
+/// confirmed as placed; collecting payment is left to the organizer.
+#[derive(Clone)]
+pub struct TicketingApplicationService {
+    ticket_repository: Arc<dyn TicketRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    exchange_rates: Arc<dyn ExchangeRateProvider>,
+    invoice_repository: Option<Arc<dyn InvoiceRepository>>,
+}
+
+impl TicketingApplicationService {
+    /// Most tickets one order can hold
+    pub const MAX_TICKETS_PER_ORDER: i32 = 10;
+    const MAX_NAME_CHARS: usize = 100;
+    const MAX_DESCRIPTION_CHARS: usize = 1000;
+
+    pub fn new(
+        ticket_repository: Arc<dyn TicketRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        exchange_rates: Arc<dyn ExchangeRateProvider>,
+    ) -> Self {
+        Self {
+            ticket_repository,
+            event_repository,
+            exchange_rates,
+            invoice_repository: None,
+        }
+    }
+
+    /// Voids the unpaid invoice of an order when the order is cancelled
+    pub fn with_invoices(mut self, invoice_repository: Arc<dyn InvoiceRepository>) -> Self {
+        self.invoice_repository = Some(invoice_repository);
+        self
+    }
+
+    /// Organizers see every ticket type; everyone else the ones on sale
+    pub async fn list_ticket_types(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<Vec<TicketTypeAvailability>> {
+        let event = self.get_event(event_id).await?;
+        let organizer = Self::is_organizer(&event, user_id, may_manage);
+        let ticket_types = self
+            .ticket_repository
+            .find_ticket_types_by_event(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut availability = Vec::with_capacity(ticket_types.len());
+        for ticket_type in ticket_types.into_iter().filter(|ticket_type| organizer || ticket_type.is_active) {
+            availability.push(self.availability(ticket_type).await?);
+        }
+        Ok(availability)
+    }
+
+    pub async fn create_ticket_type(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: TicketTypeInput,
+    ) -> ApiResult<TicketType> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let input = Self::validate_ticket_type(input)?;
+
+        let now = chrono::Utc::now();
+        let ticket_type = TicketType {
+            id: Uuid::new_v4(),
+            event_id: event.id,
+            name: input.name,
+            description: input.description,
+            price: input.price,
+            currency: input.currency,
+            vat_rate: input.vat_rate,
+            quantity: input.quantity,
+            is_active: input.is_active,
+            created_at: now,
+            updated_at: now,
+        };
+        self.ticket_repository
+            .create_ticket_type(&ticket_type)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(ticket_type)
+    }
+
+    /// Orders already placed keep the price, currency and VAT rate they were
+    /// placed at.
+    /// The quantity can't go below what has been sold.
+    pub async fn update_ticket_type(
+        &self,
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: TicketTypeInput,
+    ) -> ApiResult<TicketType> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut ticket_type = self.get_ticket_type(event_id, ticket_type_id).await?;
+        let input = Self::validate_ticket_type(input)?;
+        if let Some(quantity) = input.quantity {
+            let sold = self
+                .ticket_repository
+                .count_sold(ticket_type.id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            if (quantity as i64) < sold {
+                return Err(ApiError::validation(
+                    "quantity",
+                    format!("{} tickets of this type have already been sold", sold),
+                ));
+            }
+        }
+
+        ticket_type.name = input.name;
+        ticket_type.description = input.description;
+        ticket_type.price = input.price;
+        ticket_type.currency = input.currency;
+        ticket_type.vat_rate = input.vat_rate;
+        ticket_type.quantity = input.quantity;
+        ticket_type.is_active = input.is_active;
+        ticket_type.updated_at = chrono::Utc::now();
+        self.ticket_repository
+            .update_ticket_type(&ticket_type)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(ticket_type)
+    }
+
+    /// Only types nobody has ordered can be deleted; others are deactivated
+    pub async fn delete_ticket_type(
+        &self,
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<()> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let ticket_type = self.get_ticket_type(event_id, ticket_type_id).await?;
+        self.ticket_repository
+            .delete_ticket_type(ticket_type.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Places an order at the ticket type's current price, with today's rate
+    /// to NOK. Tickets can be bought while the event takes registrations.
+    pub async fn purchase(
+        &self,
+        event_id: Uuid,
+        ticket_type_id: Uuid,
+        buyer_id: Uuid,
+        quantity: i32,
+    ) -> ApiResult<TicketOrder> {
+        let event = self.get_event(event_id).await?;
+        EventService::new()
+            .can_register_for_event(&event)
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let ticket_type = self.get_ticket_type(event.id, ticket_type_id).await?;
+        if !ticket_type.is_active {
+            return Err(ApiError::bad_request("This ticket type is not on sale"));
+        }
+        if !(1..=Self::MAX_TICKETS_PER_ORDER).contains(&quantity) {
+            return Err(ApiError::validation(
+                "quantity",
+                format!("An order can hold 1 to {} tickets", Self::MAX_TICKETS_PER_ORDER),
+            ));
+        }
+
+        let rate = self.rate_to_nok(ticket_type.currency).await?;
+        let order = TicketOrder::new(&ticket_type, buyer_id, quantity, &rate);
+        let placed = self
+            .ticket_repository
+            .create_order_within_quantity(&order)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if !placed {
+            return Err(ApiError::conflict("Not enough tickets of this type are left"));
+        }
+        Ok(order)
+    }
+
+    pub async fn my_orders(&self, buyer_id: Uuid) -> ApiResult<Vec<TicketOrder>> {
+        self.ticket_repository
+            .find_orders_by_buyer(buyer_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn event_orders(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<TicketOrder>> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.ticket_repository
+            .find_orders_by_event(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Buyers can cancel their orders until the event starts; organizers any time.
+    /// The tickets go back on sale, and an unpaid invoice for the order is voided.
+    pub async fn cancel_order(&self, order_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<TicketOrder> {
+        let mut order = self
+            .ticket_repository
+            .find_order(order_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Ticket order with ID {}", order_id)))?;
+        let event = self.get_event(order.event_id).await?;
+        let organizer = Self::is_organizer(&event, user_id, may_manage);
+        if !organizer && order.buyer_id != user_id {
+            return Err(ApiError::authorization("Only the buyer or the event's organizers can cancel an order"));
+        }
+        if !organizer && chrono::Utc::now() >= event.start_date {
+            return Err(ApiError::bad_request("Orders can't be cancelled after the event has started"));
+        }
+        if order.status == OrderStatus::Cancelled {
+            return Err(ApiError::conflict("The order is already cancelled"));
+        }
+
+        order.status = OrderStatus::Cancelled;
+        order.cancelled_at = Some(chrono::Utc::now());
+        self.ticket_repository
+            .update_order(&order)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.void_invoice(order.id).await?;
+        Ok(order)
+    }
+
+    async fn void_invoice(&self, order_id: Uuid) -> ApiResult<()> {
+        let Some(invoice_repository) = &self.invoice_repository else {
+            return Ok(());
+        };
+        let invoice = invoice_repository
+            .find_by_order(order_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if let Some(mut invoice) = invoice.filter(|invoice| invoice.status == InvoiceStatus::Issued) {
+            invoice.status = InvoiceStatus::Voided;
+            invoice_repository
+                .update(&invoice)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+        }
+        Ok(())
+    }
+
+    pub async fn revenue(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<TicketRevenue> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let by_currency = self
+            .ticket_repository
+            .revenue_by_currency(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(TicketRevenue {
+            orders: by_currency.iter().map(|revenue| revenue.orders).sum(),
+            tickets: by_currency.iter().map(|revenue| revenue.tickets).sum(),
+            total_nok: by_currency.iter().map(|revenue| revenue.gross_nok).sum(),
+            by_currency,
+        })
+    }
+
+    /// Sales and refunds in a calendar month per VAT rate, for the
+    /// accountant. Administrators can report on every event at once.
+    pub async fn monthly_report(
+        &self,
+        year: i32,
+        month: u32,
+        event_id: Option<Uuid>,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<FinancialReport> {
+        let (from, to) = financial_report::month_bounds(year, month)
+            .ok_or_else(|| ApiError::validation("month", "Month must be between 1 and 12"))?;
+        let event_id = match event_id {
+            Some(event_id) => Some(self.get_managed_event(event_id, user_id, may_manage).await?.id),
+            None if may_manage => None,
+            None => return Err(ApiError::validation("event_id", "Choose the event to report on")),
+        };
+        let by_vat_rate = self
+            .ticket_repository
+            .vat_breakdown(event_id, from, to)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(FinancialReport { year, month, event_id, by_vat_rate })
+    }
+
+    pub async fn monthly_report_csv(
+        &self,
+        year: i32,
+        month: u32,
+        event_id: Option<Uuid>,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<(String, String)> {
+        let report = self.monthly_report(year, month, event_id, user_id, may_manage).await?;
+        let filename = match report.event_id {
+            Some(event_id) => {
+                let event = self.get_event(event_id).await?;
+                format!("financial-report-{}-{}-{:02}.csv", event.slug, year, month)
+            }
+            None => format!("financial-report-{}-{:02}.csv", year, month),
+        };
+        Ok((filename, financial_report::render_csv(&report)))
+    }
+
+    async fn rate_to_nok(&self, currency: Currency) -> ApiResult<ExchangeRate> {
+        if currency == Currency::Nok {
+            return Ok(ExchangeRate::nok(chrono::Utc::now().date_naive()));
+        }
+        self.exchange_rates
+            .rate_to_nok(currency)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn availability(&self, ticket_type: TicketType) -> ApiResult<TicketTypeAvailability> {
+        let sold = self
+            .ticket_repository
+            .count_sold(ticket_type.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(TicketTypeAvailability {
+            remaining: ticket_type.quantity.map(|quantity| (quantity as i64 - sold).max(0)),
+            sold,
+            ticket_type,
+        })
+    }
+
+    fn validate_ticket_type(input: TicketTypeInput) -> ApiResult<TicketTypeInput> {
+        let name = optional_text("name", "Name", Some(input.name), Self::MAX_NAME_CHARS)?
+            .ok_or_else(|| ApiError::validation("name", "Name is required"))?;
+        if input.price < 0 {
+            return Err(ApiError::validation("price", "Price can't be negative"));
+        }
+        if !VAT_RATES.contains(&input.vat_rate) {
+            return Err(ApiError::validation("vat_rate", "VAT rate must be 0, 12, 15 or 25 percent"));
+        }
+        if input.quantity.is_some_and(|quantity| quantity < 1) {
+            return Err(ApiError::validation("quantity", "Quantity must be at least 1"));
+        }
+        Ok(TicketTypeInput {
+            name,
+            description: optional_text("description", "Description", input.description, Self::MAX_DESCRIPTION_CHARS)?,
+            ..input
+        })
+    }
+
+    async fn get_ticket_type(&self, event_id: Uuid, ticket_type_id: Uuid) -> ApiResult<TicketType> {
+        self.ticket_repository
+            .find_ticket_type(ticket_type_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|ticket_type| ticket_type.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Ticket type with ID {}", ticket_type_id)))
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its tickets"));
+        }
+        Ok(event)
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+}
+
+// ============================================================================
+// Invoicing Application Service
+// ============================================================================
+
+/// The buying organization, as entered when an order is invoiced
+#[derive(Debug, Clone)]
+pub struct BillingDetails {
+    pub name: String,
+    pub org_number: String,
+    pub address: Option<String>,
+    pub postal_code: Option<String>,
+    pub city: Option<String>,
+    /// Where the invoice is sent, if not through the PEPPOL network
+    pub email: Option<String>,
+    /// E.g. a cost center or contact person; EHF requires one
+    pub buyer_reference: String,
+}
+
+/// Which invoices to list; non-admins must name an event they organize
+#[derive(Debug, Clone, Default)]
+pub struct InvoiceQuery {
+    pub event_id: Option<Uuid>,
+    pub status: Option<InvoiceStatus>,
+    pub overdue_only: bool,
+}
+
+/// Invoices for ticket orders bought by organizations, issued by the
+/// platform's configured seller and exported as EHF. Each order is invoiced
+/// at most once, in its own currency; invoices are numbered in sequence.
+#[derive(Clone)]
+pub struct InvoicingApplicationService {
+    invoice_repository: Arc<dyn InvoiceRepository>,
+    ticket_repository: Arc<dyn TicketRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    /// `None` while no seller is configured; nothing can be invoiced then
+    config: Option<InvoicingConfig>,
+}
+
+impl InvoicingApplicationService {
+    const MAX_NAME_CHARS: usize = 200;
+    const MAX_REFERENCE_CHARS: usize = 100;
+    const MAX_ADDRESS_CHARS: usize = 200;
+
+    pub fn new(
+        invoice_repository: Arc<dyn InvoiceRepository>,
+        ticket_repository: Arc<dyn TicketRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        config: Option<InvoicingConfig>,
+    ) -> Self {
+        Self {
+            invoice_repository,
+            ticket_repository,
+            event_repository,
+            config,
+        }
+    }
+
+    /// Invoices a confirmed order to the buyer's organization, due after the
+    /// configured number of days. The buyer or an organizer can ask for it.
+    pub async fn issue(
+        &self,
+        order_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        details: BillingDetails,
+    ) -> ApiResult<Invoice> {
+        let config = self
+            .config
+            .as_ref()
+            .ok_or_else(|| ApiError::bad_request("Invoicing is not set up on this server"))?;
+        let order = self.get_order(order_id).await?;
+        let event = self.get_event(order.event_id).await?;
+        if order.buyer_id != user_id && !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the buyer or the event's organizers can invoice an order"));
+        }
+        if order.status == OrderStatus::Cancelled {
+            return Err(ApiError::bad_request("Cancelled orders can't be invoiced"));
+        }
+        if order.vat_rate > 0 && !config.seller.vat_registered {
+            return Err(ApiError::bad_request(
+                "The order includes VAT, but the seller isn't registered for VAT on this server",
+            ));
+        }
+        let existing = self
+            .invoice_repository
+            .find_by_order(order.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if existing.is_some() {
+            return Err(ApiError::conflict("This order has already been invoiced"));
+        }
+        let (buyer, buyer_reference) = Self::validate_billing_details(details)?;
+
+        let ticket_type = self
+            .ticket_repository
+            .find_ticket_type(order.ticket_type_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let description = match ticket_type {
+            Some(ticket_type) => format!("{} – {}", ticket_type.name, event.title),
+            None => format!("Tickets – {}", event.title),
+        };
+        let now = chrono::Utc::now();
+        let issue_date = now.date_naive();
+        let mut invoice = Invoice {
+            id: Uuid::new_v4(),
+            invoice_number: 0,
+            order_id: order.id,
+            event_id: event.id,
+            seller: config.seller.clone(),
+            buyer,
+            buyer_reference,
+            bank_account: config.bank_account.clone(),
+            currency: order.currency,
+            description,
+            quantity: order.quantity,
+            unit_price: order.unit_price,
+            total: order.total,
+            vat_rate: order.vat_rate,
+            vat_amount: order.vat_amount,
+            issue_date,
+            due_date: issue_date + chrono::Duration::days(config.payment_days.into()),
+            status: InvoiceStatus::Issued,
+            paid_at: None,
+            created_at: now,
+        };
+        invoice.invoice_number = self
+            .invoice_repository
+            .create_numbered(&invoice)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(invoice)
+    }
+
+    /// Oldest due date first. Administrators can list every invoice.
+    pub async fn list(&self, user_id: Uuid, may_manage: bool, query: InvoiceQuery) -> ApiResult<Vec<Invoice>> {
+        match query.event_id {
+            Some(event_id) => {
+                self.get_managed_event(event_id, user_id, may_manage).await?;
+            }
+            None if !may_manage => {
+                return Err(ApiError::validation("event_id", "Choose the event to list invoices for"));
+            }
+            None => {}
+        }
+        let filter = InvoiceFilter {
+            event_id: query.event_id,
+            status: query.status,
+            overdue_on: query.overdue_only.then(|| chrono::Utc::now().date_naive()),
+        };
+        self.invoice_repository
+            .find(&filter)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Visible to the buyer of the order and the event's organizers
+    pub async fn get(&self, invoice_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Invoice> {
+        let invoice = self.get_invoice(invoice_id).await?;
+        let event = self.get_event(invoice.event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            let order = self.get_order(invoice.order_id).await?;
+            if order.buyer_id != user_id {
+                return Err(ApiError::authorization("Only the buyer or the event's organizers can see this invoice"));
+            }
+        }
+        Ok(invoice)
+    }
+
+    /// The invoice as an EHF document for the buyer's accounting system
+    pub async fn ehf(&self, invoice_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<(Invoice, String)> {
+        let invoice = self.get(invoice_id, user_id, may_manage).await?;
+        if invoice.status == InvoiceStatus::Voided {
+            return Err(ApiError::bad_request("The invoice has been voided"));
+        }
+        let xml = render_ehf(&invoice);
+        Ok((invoice, xml))
+    }
+
+    /// Records that the payment has come in; organizers only
+    pub async fn mark_paid(&self, invoice_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Invoice> {
+        let mut invoice = self.get_invoice(invoice_id).await?;
+        self.get_managed_event(invoice.event_id, user_id, may_manage).await?;
+        match invoice.status {
+            InvoiceStatus::Issued => {}
+            InvoiceStatus::Paid => return Err(ApiError::conflict("The invoice is already paid")),
+            InvoiceStatus::Voided => return Err(ApiError::bad_request("The invoice has been voided")),
+        }
+
+        invoice.status = InvoiceStatus::Paid;
+        invoice.paid_at = Some(chrono::Utc::now());
+        self.invoice_repository
+            .update(&invoice)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(invoice)
+    }
+
+    fn validate_billing_details(details: BillingDetails) -> ApiResult<(InvoiceParty, String)> {
+        let name = optional_text("name", "Name", Some(details.name), Self::MAX_NAME_CHARS)?
+            .ok_or_else(|| ApiError::validation("name", "The organization's name is required"))?;
+        let buyer_reference = optional_text(
+            "buyer_reference",
+            "Buyer reference",
+            Some(details.buyer_reference),
+            Self::MAX_REFERENCE_CHARS,
+        )?
+        .ok_or_else(|| ApiError::validation("buyer_reference", "A reference, such as a cost center or contact person, is required"))?;
+        let email = optional_text("email", "Email", details.email, Self::MAX_ADDRESS_CHARS)?;
+        if email.as_deref().is_some_and(|email| !email.contains('@')) {
+            return Err(ApiError::validation("email", "Email must be an email address"));
+        }
+        let postal_code = optional_text("postal_code", "Postal code", details.postal_code, 10)?;
+
+        Ok((
+            InvoiceParty {
+                name,
+                org_number: normalize_org_number(&details.org_number)?,
+                address: optional_text("address", "Address", details.address, Self::MAX_ADDRESS_CHARS)?,
+                postal_code,
+                city: optional_text("city", "City", details.city, Self::MAX_NAME_CHARS)?,
+                country: "NO".to_string(),
+                email,
+                vat_registered: false,
+            },
+            buyer_reference,
+        ))
+    }
+
+    async fn get_invoice(&self, invoice_id: Uuid) -> ApiResult<Invoice> {
+        self.invoice_repository
+            .find_by_id(invoice_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Invoice with ID {}", invoice_id)))
+    }
+
+    async fn get_order(&self, order_id: Uuid) -> ApiResult<TicketOrder> {
+        self.ticket_repository
+            .find_order(order_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Ticket order with ID {}", order_id)))
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its invoices"));
+        }
+        Ok(event)
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+}
+
+// ============================================================================
+// Refund Application Service
+// ============================================================================
+
+/// Cancels events and refunds their paid ticket orders through the payment
+/// provider. Refunds that fail are retried with a growing delay by the
+/// refund scheduler; the buyers are told how each one ends.
+#[derive(Clone)]
+pub struct RefundApplicationService {
+    refund_repository: Arc<dyn TicketRefundRepository>,
+    ticket_repository: Arc<dyn TicketRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    /// Cancels the orders, which voids their unpaid invoices
+    ticketing: TicketingApplicationService,
+    notifier: RegistrationNotifier,
+    payments: Option<Arc<dyn PaymentProvider>>,
+}
+
+impl RefundApplicationService {
+    /// Attempts before a refund is given up on
+    pub const MAX_ATTEMPTS: i32 = 5;
+    /// Wait after each failed attempt
+    const RETRY_DELAYS_MINUTES: [i64; 4] = [1, 10, 60, 360];
+    /// Refunds tried per scheduler run
+    const BATCH_SIZE: i64 = 50;
+
+    pub fn new(
+        refund_repository: Arc<dyn TicketRefundRepository>,
+        ticket_repository: Arc<dyn TicketRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        ticketing: TicketingApplicationService,
+        notifier: RegistrationNotifier,
+    ) -> Self {
+        Self {
+            refund_repository,
+            ticket_repository,
+            event_repository,
+            registration_repository,
+            ticketing,
+            notifier,
+            payments: None,
+        }
+    }
+
+    /// Without a payment provider, events with paid orders can't be cancelled
+    pub fn with_payments(mut self, payments: Arc<dyn PaymentProvider>) -> Self {
+        self.payments = Some(payments);
+        self
+    }
+
+    /// Cancels the event and every confirmed order, refunds the paid ones
+    /// right away, and tells registrants and buyers. Refunds that fail now
+    /// stay pending for the scheduler.
+    pub async fn cancel_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<TicketRefund>> {
+        let mut event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        match event.status {
+            EventStatus::Cancelled => return Err(ApiError::conflict("The event is already cancelled")),
+            EventStatus::Completed => return Err(ApiError::bad_request("A completed event can't be cancelled")),
+            EventStatus::Draft | EventStatus::Published => {}
+        }
+        let orders: Vec<TicketOrder> = self
+            .ticket_repository
+            .find_orders_by_event(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .filter(|order| order.status == OrderStatus::Confirmed)
+            .collect();
+        if self.payments.is_none() && orders.iter().any(|order| order.total > 0) {
+            return Err(ApiError::bad_request(
+                "Refunds are not set up on this server, so an event with paid tickets can't be cancelled",
+            ));
+        }
+
+        event.status = EventStatus::Cancelled;
+        event.updated_at = chrono::Utc::now();
+        self.event_repository
+            .update(&event)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut refunds = Vec::new();
+        for order in &orders {
+            let cancelled = self.ticketing.cancel_order(order.id, user_id, may_manage).await?;
+            if cancelled.total == 0 {
+                continue;
+            }
+            let refund = TicketRefund::new(&cancelled, chrono::Utc::now());
+            self.refund_repository
+                .create(&refund)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            refunds.push(refund);
+        }
+
+        let registrations = self
+            .registration_repository
+            .find_by_event_id(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let affected: HashSet<Uuid> = registrations
+            .iter()
+            .filter(|registration| registration.status != RegistrationStatus::Cancelled)
+            .filter_map(|registration| registration.user_id)
+            .chain(orders.iter().map(|order| order.buyer_id))
+            .collect();
+        for user_id in affected {
+            self.notifier.notify(user_id, RegistrationNotification::EventCancelled { event_id: event.id });
+        }
+
+        let mut attempted = Vec::with_capacity(refunds.len());
+        for refund in refunds {
+            attempted.push(self.attempt(refund).await?);
+        }
+        Ok(attempted)
+    }
+
+    /// The event's refunds, oldest first
+    pub async fn list(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<TicketRefund>> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.refund_repository
+            .find_by_event(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Tries the event's failed refunds again, with a fresh set of attempts
+    pub async fn retry_failed(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<TicketRefund>> {
+        let mut refunds = self.list(event_id, user_id, may_manage).await?;
+        if self.payments.is_none() {
+            return Err(ApiError::bad_request("Refunds are not set up on this server"));
+        }
+        for refund in refunds.iter_mut().filter(|refund| refund.status == RefundStatus::Failed) {
+            refund.status = RefundStatus::Pending;
+            refund.attempts = 0;
+            refund.next_attempt_at = Some(chrono::Utc::now());
+            *refund = self.attempt(refund.clone()).await?;
+        }
+        Ok(refunds)
+    }
+
+    /// Tries the pending refunds that are due; run by the refund scheduler
+    pub async fn process_due(&self, now: chrono::DateTime<chrono::Utc>) -> ApiResult<Vec<TicketRefund>> {
+        let due = self
+            .refund_repository
+            .find_due(now, Self::BATCH_SIZE)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let mut attempted = Vec::with_capacity(due.len());
+        for refund in due {
+            attempted.push(self.attempt(refund).await?);
+        }
+        Ok(attempted)
+    }
+
+    // One call to the provider. Refusals fail the refund at once; other
+    // errors are retried until the attempts run out.
+    async fn attempt(&self, mut refund: TicketRefund) -> ApiResult<TicketRefund> {
+        let Some(payments) = &self.payments else {
+            return Ok(refund);
+        };
+        let now = chrono::Utc::now();
+        refund.attempts += 1;
+        refund.updated_at = now;
+        match payments.refund(&refund).await {
+            Ok(reference) => {
+                refund.status = RefundStatus::Succeeded;
+                refund.provider_reference = Some(reference);
+                refund.next_attempt_at = None;
+                refund.last_error = None;
+            }
+            Err(e) => {
+                tracing::warn!("Refund {} of order {} failed (attempt {}): {}", refund.id, refund.order_id, refund.attempts, e);
+                let refused = matches!(e, DomainError::BusinessRuleViolation { .. });
+                refund.last_error = Some(e.to_string());
+                match Self::RETRY_DELAYS_MINUTES.get(refund.attempts as usize - 1) {
+                    Some(minutes) if !refused && refund.attempts < Self::MAX_ATTEMPTS => {
+                        refund.next_attempt_at = Some(now + chrono::Duration::minutes(*minutes));
+                    }
+                    _ => {
+                        refund.status = RefundStatus::Failed;
+                        refund.next_attempt_at = None;
+                    }
+                }
+            }
+        }
+        self.refund_repository
+            .update(&refund)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let notification = match refund.status {
+            RefundStatus::Succeeded => RegistrationNotification::RefundSucceeded { refund_id: refund.id, event_id: refund.event_id },
+            RefundStatus::Failed => RegistrationNotification::RefundFailed { refund_id: refund.id, event_id: refund.event_id },
+            RefundStatus::Pending => return Ok(refund),
+        };
+        self.notifier.notify(refund.buyer_id, notification);
+        Ok(refund)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        if !(may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)) {
+            return Err(ApiError::authorization("Only the event's organizers can cancel it and manage its refunds"));
+        }
+        Ok(event)
+    }
+}
+
+// ============================================================================
+// Catering Application Service
+// ============================================================================
+
+/// Dietary and accessibility needs of an event's confirmed attendees, for the
+/// organizers to pass on to the venue
+#[derive(Clone)]
+pub struct CateringApplicationService {
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+impl CateringApplicationService {
+    pub fn new(
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self {
+            registration_repository,
+            event_repository,
+        }
+    }
+
+    pub async fn report(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<CateringReport> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.build(event_id).await
+    }
+
+    /// The report as CSV, with its download name
+    pub async fn report_csv(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<(String, String)> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let report = self.build(event_id).await?;
+        Ok((format!("catering-{}.csv", event.slug), catering::render_csv(&report)))
+    }
+
+    async fn build(&self, event_id: Uuid) -> ApiResult<CateringReport> {
+        let registrations = self
+            .registration_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(catering::build_report(&registrations))
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can see its catering report"));
+        }
+        Ok(event)
+    }
+}
+
+// ============================================================================
+// Seating Application Service
+// ============================================================================
+
+/// Tables for seated dinners and who sits where. Only the event's organizers
+/// and administrators can plan the seating; the plan's warnings are advice,
+/// so a full table or a split company never blocks an assignment.
+#[derive(Clone)]
+pub struct SeatingApplicationService {
+    seating_repository: Arc<dyn SeatingRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+}
+
+/// A table as entered by the organizer
+#[derive(Debug, Clone)]
+pub struct SeatingTableInput {
+    pub name: String,
+    pub capacity: i32,
+    pub accessible: bool,
+}
+
+impl SeatingApplicationService {
+    pub const MAX_NAME_CHARS: usize = 100;
+    pub const MAX_CAPACITY: i32 = 100;
+
+    pub fn new(
+        seating_repository: Arc<dyn SeatingRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+    ) -> Self {
+        Self {
+            seating_repository,
+            event_repository,
+            registration_repository,
+        }
+    }
+
+    pub async fn plan(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<SeatingPlan> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.build(event_id).await
+    }
+
+    /// New tables go after the existing ones
+    pub async fn create_table(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: SeatingTableInput,
+    ) -> ApiResult<SeatingTable> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let input = Self::validate(input)?;
+
+        let position = self
+            .find_tables(event_id)
+            .await?
+            .iter()
+            .map(|table| table.position + 1)
+            .max()
+            .unwrap_or(0);
+        let mut table = SeatingTable::new(event_id, input.name, input.capacity, position);
+        table.accessible = input.accessible;
+        self.seating_repository
+            .create_table(&table)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(table)
+    }
+
+    pub async fn update_table(
+        &self,
+        event_id: Uuid,
+        table_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: SeatingTableInput,
+    ) -> ApiResult<SeatingTable> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut table = self.get_table(event_id, table_id).await?;
+        let input = Self::validate(input)?;
+
+        table.name = input.name;
+        table.capacity = input.capacity;
+        table.accessible = input.accessible;
+        table.updated_at = chrono::Utc::now();
+        self.seating_repository
+            .update_table(&table)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(table)
+    }
+
+    /// Whoever sat at the table goes back to the unassigned list
+    pub async fn delete_table(&self, event_id: Uuid, table_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let table = self.get_table(event_id, table_id).await?;
+        self.seating_repository
+            .delete_table(table.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Seats a registration and its guests at a table, moving them if they
+    /// sat elsewhere, or unseats them when `table_id` is `None`. Returns the
+    /// updated plan so the planner can show the new warnings.
+    pub async fn assign(
+        &self,
+        event_id: Uuid,
+        registration_id: Uuid,
+        table_id: Option<Uuid>,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<SeatingPlan> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let registration = self
+            .registration_repository
+            .find_by_id(registration_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|registration| registration.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Registration with ID {}", registration_id)))?;
+
+        match table_id {
+            Some(table_id) => {
+                if !catering::is_confirmed(&registration) {
+                    return Err(ApiError::validation(
+                        "registration_id",
+                        "Only confirmed registrations can be seated",
+                    ));
+                }
+                let table = self.get_table(event_id, table_id).await?;
+                let assignment = SeatAssignment {
+                    registration_id: registration.id,
+                    table_id: table.id,
+                    event_id,
+                    assigned_at: chrono::Utc::now(),
+                };
+                self.seating_repository
+                    .assign(&assignment)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+            }
+            None => self
+                .seating_repository
+                .unassign(registration.id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?,
+        }
+        self.build(event_id).await
+    }
+
+    /// The printable chart, with its download name
+    pub async fn chart_pdf(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<(String, Vec<u8>)> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let plan = self.build(event_id).await?;
+        Ok((seating::chart_filename(&event.slug), seating::render_chart_pdf(&event.title, &plan)))
+    }
+
+    async fn build(&self, event_id: Uuid) -> ApiResult<SeatingPlan> {
+        let tables = self.find_tables(event_id).await?;
+        let assignments = self
+            .seating_repository
+            .find_assignments(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let registrations = self
+            .registration_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(seating::build_plan(tables, &assignments, &registrations))
+    }
+
+    fn validate(input: SeatingTableInput) -> ApiResult<SeatingTableInput> {
+        let name = optional_text("name", "Name", Some(input.name), Self::MAX_NAME_CHARS)?
+            .ok_or_else(|| ApiError::validation("name", "Name is required"))?;
+        if !(1..=Self::MAX_CAPACITY).contains(&input.capacity) {
+            return Err(ApiError::validation(
+                "capacity",
+                format!("Capacity must be between 1 and {}", Self::MAX_CAPACITY),
+            ));
+        }
+        Ok(SeatingTableInput { name, ..input })
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can plan its seating"));
+        }
+        Ok(event)
+    }
+
+    async fn find_tables(&self, event_id: Uuid) -> ApiResult<Vec<SeatingTable>> {
+        self.seating_repository
+            .find_tables(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn get_table(&self, event_id: Uuid, table_id: Uuid) -> ApiResult<SeatingTable> {
+        self.seating_repository
+            .find_table(table_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|table| table.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Table with ID {}", table_id)))
+    }
+}
+
+// ============================================================================
+// Resource Application Service
+// ============================================================================
+
+/// Equipment and facilities events can book, such as projectors, boats and
+/// demo tanks. Administrators keep the list of resources and when they are
+/// available; an event's organizers book them for the event's time slot. A
+/// booking that clashes with another booking or falls outside a resource's
+/// availability is refused as a whole.
+#[derive(Clone)]
+pub struct ResourceApplicationService {
+    resource_repository: Arc<dyn ResourceRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+/// A resource as entered by an administrator
+#[derive(Debug, Clone)]
+pub struct ResourceInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub is_active: bool,
+}
+
+impl ResourceApplicationService {
+    pub const MAX_NAME_CHARS: usize = 100;
+    pub const MAX_DESCRIPTION_CHARS: usize = 1000;
+    pub const MAX_LOCATION_CHARS: usize = 200;
+    pub const MAX_WINDOWS: usize = 100;
+    pub const MAX_RESOURCES_PER_BOOKING: usize = 50;
+    /// How far ahead a resource's bookings are listed when no end is given
+    pub const DEFAULT_BOOKINGS_DAYS: i64 = 30;
+
+    pub fn new(resource_repository: Arc<dyn ResourceRepository>, event_repository: Arc<dyn EventRepository>) -> Self {
+        Self {
+            resource_repository,
+            event_repository,
+        }
+    }
+
+    /// Retired resources are only listed for administrators
+    pub async fn list(&self, include_inactive: bool, may_manage: bool) -> ApiResult<Vec<Resource>> {
+        self.resource_repository
+            .list(include_inactive && may_manage)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn get(&self, resource_id: Uuid) -> ApiResult<(Resource, Vec<AvailabilityWindow>)> {
+        let resource = self.get_resource(resource_id).await?;
+        let windows = self.find_windows(resource_id).await?;
+        Ok((resource, windows))
+    }
+
+    pub async fn create(&self, user_id: Uuid, may_manage: bool, input: ResourceInput) -> ApiResult<Resource> {
+        Self::require_admin(may_manage)?;
+        let input = Self::validate(input)?;
+
+        let resource = Resource {
+            description: input.description,
+            location: input.location,
+            is_active: input.is_active,
+            ..Resource::new(input.name, user_id)
+        };
+        self.resource_repository
+            .create(&resource)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(resource)
+    }
+
+    /// Retiring a resource keeps its bookings but stops new ones
+    pub async fn update(&self, resource_id: Uuid, may_manage: bool, input: ResourceInput) -> ApiResult<Resource> {
+        Self::require_admin(may_manage)?;
+        let mut resource = self.get_resource(resource_id).await?;
+        let input = Self::validate(input)?;
+
+        resource.name = input.name;
+        resource.description = input.description;
+        resource.location = input.location;
+        resource.is_active = input.is_active;
+        resource.updated_at = chrono::Utc::now();
+        self.resource_repository
+            .update(&resource)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(resource)
+    }
+
+    /// Replaces the resource's availability; an empty list makes it bookable
+    /// at any time. Existing bookings are kept either way.
+    pub async fn set_availability(
+        &self,
+        resource_id: Uuid,
+        may_manage: bool,
+        mut windows: Vec<AvailabilityWindow>,
+    ) -> ApiResult<Vec<AvailabilityWindow>> {
+        Self::require_admin(may_manage)?;
+        self.get_resource(resource_id).await?;
+        if windows.len() > Self::MAX_WINDOWS {
+            return Err(ApiError::validation(
+                "windows",
+                format!("A resource can have at most {} availability windows", Self::MAX_WINDOWS),
+            ));
+        }
+        if windows.iter().any(|window| window.ends_at <= window.starts_at) {
+            return Err(ApiError::validation("windows", "Availability windows must end after they start"));
+        }
+
+        windows.sort_by_key(|window| (window.starts_at, window.ends_at));
+        self.resource_repository
+            .replace_windows(resource_id, &windows)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(windows)
+    }
+
+    /// The resource's bookings overlapping `from..to`, by default the coming
+    /// [`Self::DEFAULT_BOOKINGS_DAYS`] days
+    pub async fn bookings(
+        &self,
+        resource_id: Uuid,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ApiResult<Vec<ResourceBooking>> {
+        self.get_resource(resource_id).await?;
+        let from = from.unwrap_or_else(chrono::Utc::now);
+        let to = to.unwrap_or(from + chrono::Duration::days(Self::DEFAULT_BOOKINGS_DAYS));
+        Self::validate_slot(from, to)?;
+        self.resource_repository
+            .find_overlapping_bookings(&[resource_id], from, to)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// What would stop the resources from being booked from `starts_at` to
+    /// `ends_at`; empty when they're all free
+    pub async fn check_conflicts(
+        &self,
+        resource_ids: Vec<Uuid>,
+        starts_at: chrono::DateTime<chrono::Utc>,
+        ends_at: chrono::DateTime<chrono::Utc>,
+    ) -> ApiResult<Vec<ResourceConflict>> {
+        Self::validate_slot(starts_at, ends_at)?;
+        let resources = self.get_resources(resource_ids).await?;
+        self.find_conflicts(&resources, starts_at, ends_at).await
+    }
+
+    /// Books the resources for the event, by default for the whole event.
+    /// Either all of them are booked or, if any has a conflict, none are.
+    pub async fn book(
+        &self,
+        event_id: Uuid,
+        resource_ids: Vec<Uuid>,
+        slot: (Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>),
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<Vec<ResourceBooking>> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let starts_at = slot.0.unwrap_or(event.start_date);
+        let ends_at = slot.1.unwrap_or(event.end_date);
+        Self::validate_slot(starts_at, ends_at)?;
+        let resources = self.get_resources(resource_ids).await?;
+
+        let conflicts = self.find_conflicts(&resources, starts_at, ends_at).await?;
+        if !conflicts.is_empty() {
+            return Err(Self::conflict_error(&resources, &conflicts));
+        }
+
+        let bookings: Vec<ResourceBooking> = resources
+            .iter()
+            .map(|resource| ResourceBooking::new(resource.id, event.id, starts_at, ends_at, user_id))
+            .collect();
+        let booked = self
+            .resource_repository
+            .create_bookings_if_free(&bookings)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if !booked {
+            // Someone else booked one of them since the check above
+            let conflicts = self.find_conflicts(&resources, starts_at, ends_at).await?;
+            return Err(Self::conflict_error(&resources, &conflicts));
+        }
+        Ok(bookings)
+    }
+
+    pub async fn event_bookings(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<ResourceBooking>> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.resource_repository
+            .find_bookings_by_event(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn cancel_booking(&self, booking_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        let booking = self
+            .resource_repository
+            .find_booking(booking_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Resource booking with ID {}", booking_id)))?;
+        self.get_managed_event(booking.event_id, user_id, may_manage).await?;
+        self.resource_repository
+            .delete_booking(booking.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn find_conflicts(
+        &self,
+        resources: &[Resource],
+        starts_at: chrono::DateTime<chrono::Utc>,
+        ends_at: chrono::DateTime<chrono::Utc>,
+    ) -> ApiResult<Vec<ResourceConflict>> {
+        let ids: Vec<Uuid> = resources.iter().map(|resource| resource.id).collect();
+        let bookings = self
+            .resource_repository
+            .find_overlapping_bookings(&ids, starts_at, ends_at)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut conflicts = Vec::new();
+        for resource in resources {
+            let windows = self.find_windows(resource.id).await?;
+            conflicts.extend(ResourceConflict::find(resource, &windows, &bookings, starts_at, ends_at));
+        }
+        Ok(conflicts)
+    }
+
+    fn conflict_error(resources: &[Resource], conflicts: &[ResourceConflict]) -> ApiError {
+        let name = |resource_id: Uuid| {
+            resources
+                .iter()
+                .find(|resource| resource.id == resource_id)
+                .map_or_else(|| resource_id.to_string(), |resource| resource.name.clone())
+        };
+        let reasons: Vec<String> = conflicts
+            .iter()
+            .map(|conflict| match conflict {
+                ResourceConflict::AlreadyBooked { resource_id, booking } => format!(
+                    "{} is already booked from {} to {} UTC",
+                    name(*resource_id),
+                    booking.starts_at.format("%Y-%m-%d %H:%M"),
+                    booking.ends_at.format("%Y-%m-%d %H:%M"),
+                ),
+                ResourceConflict::Unavailable { resource_id } => {
+                    format!("{} isn't available at that time", name(*resource_id))
+                }
+                ResourceConflict::Inactive { resource_id } => format!("{} is no longer in use", name(*resource_id)),
+            })
+            .collect();
+        if reasons.is_empty() {
+            return ApiError::conflict("The resources were booked by someone else, please try again");
+        }
+        ApiError::conflict(reasons.join("; "))
+    }
+
+    fn validate(input: ResourceInput) -> ApiResult<ResourceInput> {
+        let name = optional_text("name", "Name", Some(input.name), Self::MAX_NAME_CHARS)?
+            .ok_or_else(|| ApiError::validation("name", "Name is required"))?;
+        Ok(ResourceInput {
+            name,
+            description: optional_text("description", "Description", input.description, Self::MAX_DESCRIPTION_CHARS)?,
+            location: optional_text("location", "Location", input.location, Self::MAX_LOCATION_CHARS)?,
+            is_active: input.is_active,
+        })
+    }
+
+    fn validate_slot(starts_at: chrono::DateTime<chrono::Utc>, ends_at: chrono::DateTime<chrono::Utc>) -> ApiResult<()> {
+        if ends_at <= starts_at {
+            return Err(ApiError::validation("ends_at", "The time slot must end after it starts"));
+        }
+        Ok(())
+    }
+
+    fn require_admin(may_manage: bool) -> ApiResult<()> {
+        if !may_manage {
+            return Err(ApiError::authorization("Only administrators can manage resources"));
+        }
+        Ok(())
+    }
+
+    async fn get_resource(&self, resource_id: Uuid) -> ApiResult<Resource> {
+        self.resource_repository
+            .find_by_id(resource_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Resource with ID {}", resource_id)))
+    }
+
+    /// The resources in the order asked for, each once
+    async fn get_resources(&self, resource_ids: Vec<Uuid>) -> ApiResult<Vec<Resource>> {
+        let mut seen = HashSet::new();
+        let resource_ids: Vec<Uuid> = resource_ids.into_iter().filter(|id| seen.insert(*id)).collect();
+        if resource_ids.is_empty() {
+            return Err(ApiError::validation("resource_ids", "Choose at least one resource"));
+        }
+        if resource_ids.len() > Self::MAX_RESOURCES_PER_BOOKING {
+            return Err(ApiError::validation(
+                "resource_ids",
+                format!("At most {} resources can be booked at once", Self::MAX_RESOURCES_PER_BOOKING),
+            ));
+        }
+
+        let mut resources = Vec::with_capacity(resource_ids.len());
+        for resource_id in resource_ids {
+            resources.push(self.get_resource(resource_id).await?);
+        }
+        Ok(resources)
+    }
+
+    async fn find_windows(&self, resource_id: Uuid) -> ApiResult<Vec<AvailabilityWindow>> {
+        self.resource_repository
+            .find_windows(resource_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can book resources for it"));
+        }
+        Ok(event)
+    }
+}
+
+// ============================================================================
+// Shift Application Service
+// ============================================================================
+
+/// Volunteer and staff shifts at events. The event's organizers define the
+/// shifts; staff, meaning users with the organizer or admin role, sign
+/// themselves up while a shift has room and hasn't started.
+#[derive(Clone)]
+pub struct ShiftApplicationService {
+    shift_repository: Arc<dyn ShiftRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+/// A shift as entered by the organizer
+#[derive(Debug, Clone)]
+pub struct ShiftInput {
+    pub role: String,
+    pub description: Option<String>,
+    pub starts_at: chrono::DateTime<chrono::Utc>,
+    pub ends_at: chrono::DateTime<chrono::Utc>,
+    pub headcount: i32,
+}
+
+impl ShiftApplicationService {
+    pub const MAX_ROLE_CHARS: usize = 100;
+    pub const MAX_DESCRIPTION_CHARS: usize = 1000;
+    pub const MAX_HEADCOUNT: i32 = 500;
+
+    pub fn new(
+        shift_repository: Arc<dyn ShiftRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            shift_repository,
+            event_repository,
+            user_repository,
+        }
+    }
+
+    /// The event's shifts with who is on them, for staff choosing a shift
+    pub async fn shifts(&self, event_id: Uuid, user: &User, may_manage: bool) -> ApiResult<CoverageReport> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_staff(user, may_manage) && !Self::is_organizer(&event, user.id, may_manage) {
+            return Err(ApiError::authorization("Only staff can see the event's shifts"));
+        }
+        self.build(event_id).await
+    }
+
+    /// The shifts and which of them still need people
+    pub async fn coverage(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<CoverageReport> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.build(event_id).await
+    }
+
+    pub async fn create_shift(&self, event_id: Uuid, user_id: Uuid, may_manage: bool, input: ShiftInput) -> ApiResult<EventShift> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let input = Self::validate(input)?;
+
+        let shift = EventShift {
+            description: input.description,
+            ..EventShift::new(event_id, input.role, input.starts_at, input.ends_at, input.headcount)
+        };
+        self.shift_repository
+            .create_shift(&shift)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(shift)
+    }
+
+    /// Lowering the headcount below the signups keeps everyone on the shift;
+    /// it just won't take new signups
+    pub async fn update_shift(
+        &self,
+        event_id: Uuid,
+        shift_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: ShiftInput,
+    ) -> ApiResult<EventShift> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut shift = self.get_shift(event_id, shift_id).await?;
+        let input = Self::validate(input)?;
+
+        shift.role = input.role;
+        shift.description = input.description;
+        shift.starts_at = input.starts_at;
+        shift.ends_at = input.ends_at;
+        shift.headcount = input.headcount;
+        shift.updated_at = chrono::Utc::now();
+        self.shift_repository
+            .update_shift(&shift)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(shift)
+    }
+
+    pub async fn delete_shift(&self, event_id: Uuid, shift_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let shift = self.get_shift(event_id, shift_id).await?;
+        self.shift_repository
+            .delete_shift(shift.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Signs the user up for a shift that hasn't started, has room, and
+    /// doesn't overlap another of their shifts. Returns the updated shifts.
+    pub async fn sign_up(&self, event_id: Uuid, shift_id: Uuid, user: &User, may_manage: bool) -> ApiResult<CoverageReport> {
+        if !Self::is_staff(user, may_manage) {
+            return Err(ApiError::authorization("Only staff can sign up for shifts"));
+        }
+        let shift = self.get_shift(event_id, shift_id).await?;
+        if shift.starts_at <= chrono::Utc::now() {
+            return Err(ApiError::validation("shift_id", "The shift has already started"));
+        }
+        let mine = self
+            .shift_repository
+            .find_shifts_by_user(user.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if mine.iter().any(|other| other.id == shift.id) {
+            return Err(ApiError::conflict("You are already signed up for this shift"));
+        }
+        if let Some(other) = mine.iter().find(|other| other.overlaps(&shift)) {
+            return Err(ApiError::conflict(format!(
+                "The shift overlaps your {} shift from {} to {} UTC",
+                other.role,
+                other.starts_at.format("%Y-%m-%d %H:%M"),
+                other.ends_at.format("%Y-%m-%d %H:%M"),
+            )));
+        }
+
+        let signup = ShiftSignup {
+            shift_id: shift.id,
+            user_id: user.id,
+            signed_up_at: chrono::Utc::now(),
+        };
+        let stored = self
+            .shift_repository
+            .sign_up_within_headcount(&signup)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if !stored {
+            return Err(ApiError::conflict("The shift has all the people it needs"));
+        }
+        self.build(event_id).await
+    }
+
+    pub async fn withdraw(&self, event_id: Uuid, shift_id: Uuid, user_id: Uuid) -> ApiResult<CoverageReport> {
+        let shift = self.get_shift(event_id, shift_id).await?;
+        self.shift_repository
+            .cancel_signup(shift.id, user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.build(event_id).await
+    }
+
+    /// The user's shifts at all events as an iCalendar file, with its download name
+    pub async fn my_shifts_calendar(&self, user: &User, localization: &Localization) -> ApiResult<(String, String)> {
+        let shifts = self
+            .shift_repository
+            .find_shifts_by_user(user.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut events: HashMap<Uuid, Event> = HashMap::new();
+        let mut entries = Vec::with_capacity(shifts.len());
+        for shift in shifts {
+            if !events.contains_key(&shift.event_id) {
+                let event = self.get_event(shift.event_id).await?;
+                events.insert(event.id, event);
+            }
+            let event = events[&shift.event_id].clone();
+            entries.push((shift, event));
+        }
+        Ok(("shifts.ics".to_string(), render_shifts_ics(&entries, chrono::Utc::now(), localization)))
+    }
+
+    async fn build(&self, event_id: Uuid) -> ApiResult<CoverageReport> {
+        let shifts = self
+            .shift_repository
+            .find_shifts(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let signups = self
+            .shift_repository
+            .find_signups(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let user_ids: Vec<Uuid> = signups
+            .iter()
+            .map(|signup| signup.user_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let users = self
+            .user_repository
+            .find_by_ids(&user_ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(shifts::build_coverage(shifts, &signups, &users))
+    }
+
+    fn validate(input: ShiftInput) -> ApiResult<ShiftInput> {
+        let role = optional_text("role", "Role", Some(input.role), Self::MAX_ROLE_CHARS)?
+            .ok_or_else(|| ApiError::validation("role", "Role is required"))?;
+        if input.ends_at <= input.starts_at {
+            return Err(ApiError::validation("ends_at", "The shift must end after it starts"));
+        }
+        if !(1..=Self::MAX_HEADCOUNT).contains(&input.headcount) {
+            return Err(ApiError::validation(
+                "headcount",
+                format!("Headcount must be between 1 and {}", Self::MAX_HEADCOUNT),
+            ));
+        }
+        Ok(ShiftInput {
+            role,
+            description: optional_text("description", "Description", input.description, Self::MAX_DESCRIPTION_CHARS)?,
+            ..input
+        })
+    }
+
+    fn is_staff(user: &User, may_manage: bool) -> bool {
+        role_permissions(user, may_manage).has(Permission::ShiftSignUp)
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its shifts"));
+        }
+        Ok(event)
+    }
+
+    async fn get_shift(&self, event_id: Uuid, shift_id: Uuid) -> ApiResult<EventShift> {
+        self.shift_repository
+            .find_shift(shift_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|shift| shift.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Shift with ID {}", shift_id)))
+    }
+}
+
+// ============================================================================
+// Exhibitor Application Service
+// ============================================================================
+
+/// Booths and exhibitors for trade-fair style expos. The event's organizers
+/// draw the floor plan, register the exhibitors and allocate booths to them;
+/// visitors see the exhibitor list on the public event page.
+#[derive(Clone)]
+pub struct ExhibitorApplicationService {
+    exhibitor_repository: Arc<dyn ExhibitorRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+/// A booth as drawn by the organizer, in metres
+#[derive(Debug, Clone)]
+pub struct BoothInput {
+    pub number: String,
+    pub hall: Option<String>,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub depth: f64,
+}
+
+/// An exhibitor as registered by the organizer
+#[derive(Debug, Clone)]
+pub struct ExhibitorInput {
+    pub company_name: String,
+    /// The registered company whose members scan leads at the stand
+    pub company_id: Option<Uuid>,
+    pub contact_name: Option<String>,
+    pub contact_email: Option<String>,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+}
+
+impl ExhibitorApplicationService {
+    pub const MAX_NUMBER_CHARS: usize = 20;
+    pub const MAX_HALL_CHARS: usize = 100;
+    pub const MAX_NAME_CHARS: usize = 200;
+    pub const MAX_URL_CHARS: usize = 500;
+    pub const MAX_DESCRIPTION_CHARS: usize = 1000;
+    /// Longest side or furthest position on a floor plan, in metres
+    pub const MAX_METRES: f64 = 10_000.0;
+
+    pub fn new(exhibitor_repository: Arc<dyn ExhibitorRepository>, event_repository: Arc<dyn EventRepository>) -> Self {
+        Self {
+            exhibitor_repository,
+            event_repository,
+        }
+    }
+
+    /// The event's floor plan, by hall and booth number
+    pub async fn booths(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<Booth>> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.find_booths(event_id).await
+    }
+
+    pub async fn create_booth(&self, event_id: Uuid, user_id: Uuid, may_manage: bool, input: BoothInput) -> ApiResult<Booth> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let input = Self::validate_booth(input)?;
+
+        let booth = Booth {
+            hall: input.hall,
+            ..Booth::new(event_id, input.number, input.x, input.y, input.width, input.depth)
+        };
+        self.check_floor_space(&booth).await?;
+        self.exhibitor_repository
+            .create_booth(&booth)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(booth)
+    }
+
+    /// Moving or resizing a booth keeps its exhibitor
+    pub async fn update_booth(
+        &self,
+        event_id: Uuid,
+        booth_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: BoothInput,
+    ) -> ApiResult<Booth> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut booth = self.get_booth(event_id, booth_id).await?;
+        let input = Self::validate_booth(input)?;
+
+        booth.number = input.number;
+        booth.hall = input.hall;
+        booth.x = input.x;
+        booth.y = input.y;
+        booth.width = input.width;
+        booth.depth = input.depth;
+        self.check_floor_space(&booth).await?;
+        booth.updated_at = chrono::Utc::now();
+        self.exhibitor_repository
+            .update_booth(&booth)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(booth)
+    }
+
+    /// The booth's exhibitor stays registered, without a booth
+    pub async fn delete_booth(&self, event_id: Uuid, booth_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let booth = self.get_booth(event_id, booth_id).await?;
+        self.exhibitor_repository
+            .delete_booth(booth.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// The event's exhibitors by company name, with their contact details
+    pub async fn exhibitors(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<Exhibitor>> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.find_exhibitors(event_id).await
+    }
+
+    pub async fn create_exhibitor(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: ExhibitorInput,
+    ) -> ApiResult<Exhibitor> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let input = Self::validate_exhibitor(input)?;
+
+        let exhibitor = Exhibitor {
+            contact_name: input.contact_name,
+            contact_email: input.contact_email,
+            website_url: input.website_url,
+            description: input.description,
+            company_id: input.company_id,
+            ..Exhibitor::new(event_id, input.company_name)
+        };
+        self.exhibitor_repository
+            .create_exhibitor(&exhibitor)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(exhibitor)
+    }
+
+    pub async fn update_exhibitor(
+        &self,
+        event_id: Uuid,
+        exhibitor_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        input: ExhibitorInput,
+    ) -> ApiResult<Exhibitor> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut exhibitor = self.get_exhibitor(event_id, exhibitor_id).await?;
+        let input = Self::validate_exhibitor(input)?;
+
+        exhibitor.company_name = input.company_name;
+        exhibitor.contact_name = input.contact_name;
+        exhibitor.contact_email = input.contact_email;
+        exhibitor.website_url = input.website_url;
+        exhibitor.description = input.description;
+        exhibitor.company_id = input.company_id;
+        exhibitor.updated_at = chrono::Utc::now();
+        self.exhibitor_repository
+            .update_exhibitor(&exhibitor)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(exhibitor)
+    }
+
+    pub async fn delete_exhibitor(&self, event_id: Uuid, exhibitor_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let exhibitor = self.get_exhibitor(event_id, exhibitor_id).await?;
+        self.exhibitor_repository
+            .delete_exhibitor(exhibitor.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Gives the exhibitor one of the event's booths, moving it from the
+    /// booth it had; `None` takes its booth away. A booth that already has
+    /// an exhibitor is a conflict.
+    pub async fn allocate(
+        &self,
+        event_id: Uuid,
+        exhibitor_id: Uuid,
+        booth_id: Option<Uuid>,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<Exhibitor> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let mut exhibitor = self.get_exhibitor(event_id, exhibitor_id).await?;
+        if let Some(booth_id) = booth_id {
+            let booth = self.get_booth(event_id, booth_id).await?;
+            if exhibitor.booth_id == Some(booth.id) {
+                return Ok(exhibitor);
+            }
+            let holder = self
+                .find_exhibitors(event_id)
+                .await?
+                .into_iter()
+                .find(|other| other.booth_id == Some(booth.id));
+            if let Some(holder) = holder {
+                return Err(ApiError::conflict(format!(
+                    "Booth {} is already allocated to {}",
+                    booth.number, holder.company_name
+                )));
+            }
+        }
+
+        self.exhibitor_repository
+            .allocate(exhibitor.id, booth_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        exhibitor.booth_id = booth_id;
+        exhibitor.updated_at = chrono::Utc::now();
+        Ok(exhibitor)
+    }
+
+    /// The exhibitors shown on the public event page, by company name, each
+    /// with its booth; contact details are left to the caller to drop
+    pub async fn public_exhibitors(&self, event_id: Uuid) -> ApiResult<Vec<(Exhibitor, Option<Booth>)>> {
+        let booths: HashMap<Uuid, Booth> = self
+            .find_booths(event_id)
+            .await?
+            .into_iter()
+            .map(|booth| (booth.id, booth))
+            .collect();
+        Ok(self
+            .find_exhibitors(event_id)
+            .await?
+            .into_iter()
+            .map(|exhibitor| {
+                let booth = exhibitor.booth_id.and_then(|id| booths.get(&id).cloned());
+                (exhibitor, booth)
+            })
+            .collect())
+    }
+
+    /// Refuses a booth that shares floor space with another booth in its hall
+    async fn check_floor_space(&self, booth: &Booth) -> ApiResult<()> {
+        let overlapping = self
+            .find_booths(booth.event_id)
+            .await?
+            .into_iter()
+            .find(|other| other.id != booth.id && other.overlaps(booth));
+        match overlapping {
+            Some(other) => Err(ApiError::conflict(format!(
+                "Booth {} overlaps booth {} on the floor plan",
+                booth.number, other.number
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_booth(input: BoothInput) -> ApiResult<BoothInput> {
+        let number = optional_text("number", "Booth number", Some(input.number), Self::MAX_NUMBER_CHARS)?
+            .ok_or_else(|| ApiError::validation("number", "Booth number is required"))?;
+        for (field, value) in [("x", input.x), ("y", input.y)] {
+            if !value.is_finite() || !(0.0..=Self::MAX_METRES).contains(&value) {
+                return Err(ApiError::validation(
+                    field,
+                    format!("Positions must be between 0 and {} metres", Self::MAX_METRES),
+                ));
+            }
+        }
+        for (field, value) in [("width", input.width), ("depth", input.depth)] {
+            if !value.is_finite() || value <= 0.0 || value > Self::MAX_METRES {
+                return Err(ApiError::validation(
+                    field,
+                    format!("Booth sizes must be above 0 and at most {} metres", Self::MAX_METRES),
+                ));
+            }
+        }
+        Ok(BoothInput {
+            number,
+            hall: optional_text("hall", "Hall", input.hall, Self::MAX_HALL_CHARS)?,
+            ..input
+        })
+    }
+
+    fn validate_exhibitor(input: ExhibitorInput) -> ApiResult<ExhibitorInput> {
+        let company_name = optional_text("company_name", "Company name", Some(input.company_name), Self::MAX_NAME_CHARS)?
+            .ok_or_else(|| ApiError::validation("company_name", "Company name is required"))?;
+        let contact_email = optional_text("contact_email", "Contact email", input.contact_email, Self::MAX_NAME_CHARS)?;
+        if contact_email.as_deref().is_some_and(|email| !email.contains('@')) {
+            return Err(ApiError::validation("contact_email", "Contact email must be an email address"));
+        }
+        Ok(ExhibitorInput {
+            company_name,
+            company_id: input.company_id,
+            contact_name: optional_text("contact_name", "Contact name", input.contact_name, Self::MAX_NAME_CHARS)?,
+            contact_email,
+            website_url: optional_web_url("website_url", "Website", input.website_url, Self::MAX_URL_CHARS)?,
+            description: optional_text("description", "Description", input.description, Self::MAX_DESCRIPTION_CHARS)?,
+        })
+    }
+
+    async fn find_booths(&self, event_id: Uuid) -> ApiResult<Vec<Booth>> {
+        self.exhibitor_repository
+            .find_booths(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn find_exhibitors(&self, event_id: Uuid) -> ApiResult<Vec<Exhibitor>> {
+        self.exhibitor_repository
+            .find_exhibitors(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn get_booth(&self, event_id: Uuid, booth_id: Uuid) -> ApiResult<Booth> {
+        self.exhibitor_repository
+            .find_booth(booth_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|booth| booth.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Booth with ID {}", booth_id)))
+    }
+
+    async fn get_exhibitor(&self, event_id: Uuid, exhibitor_id: Uuid) -> ApiResult<Exhibitor> {
+        self.exhibitor_repository
+            .find_exhibitor(exhibitor_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|exhibitor| exhibitor.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Exhibitor with ID {}", exhibitor_id)))
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its exhibitors"));
+        }
+        Ok(event)
+    }
+}
+
+// ============================================================================
+// Lead Application Service
+// ============================================================================
+
+/// Lead capture at the exhibition floor: an exhibitor's staff scan attendees'
+/// badges, and the scan only counts when the attendee agreed to share their
+/// contact details with exhibitors. Staff are the members of the exhibitor's
+/// registered company; the event's organizers see every exhibitor's leads.
+#[derive(Clone)]
+pub struct LeadApplicationService {
+    lead_repository: Arc<dyn LeadRepository>,
+    exhibitor_repository: Arc<dyn ExhibitorRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    consent_repository: Arc<dyn EventConsentRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl LeadApplicationService {
+    pub const MAX_NOTES_CHARS: usize = 1000;
+
+    pub fn new(
+        lead_repository: Arc<dyn LeadRepository>,
+        exhibitor_repository: Arc<dyn ExhibitorRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        consent_repository: Arc<dyn EventConsentRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            lead_repository,
+            exhibitor_repository,
+            event_repository,
+            registration_repository,
+            consent_repository,
+            user_repository,
+        }
+    }
+
+    /// Captures the attendee behind the badge as the exhibitor's lead. A badge
+    /// the exhibitor already scanned returns the lead from the first scan;
+    /// the flag tells whether the lead is new.
+    pub async fn scan(
+        &self,
+        event_id: Uuid,
+        exhibitor_id: Uuid,
+        code: &str,
+        notes: Option<String>,
+        user: &User,
+        may_manage: bool,
+    ) -> ApiResult<(Lead, bool)> {
+        let (_, exhibitor) = self.get_staffed_exhibitor(event_id, exhibitor_id, user, may_manage).await?;
+        let notes = optional_text("notes", "Notes", notes, Self::MAX_NOTES_CHARS)?;
+        let registration_id = leads::parse_badge_code(code)
+            .ok_or_else(|| ApiError::validation("code", "Not an attendee badge"))?;
+        let registration = self
+            .registration_repository
+            .find_by_id(registration_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|registration| registration.event_id == event_id && registration.status != RegistrationStatus::Cancelled)
+            .ok_or_else(|| ApiError::validation("code", "The badge isn't for a registration at this event"))?;
+
+        let consented = self
+            .consent_repository
+            .find_answers_by_registration_id(registration.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .iter()
+            .any(|answer| answer.kind == ConsentKind::LeadSharing && answer.accepted);
+        if !consented {
+            return Err(ApiError::validation(
+                "code",
+                "The attendee hasn't agreed to share their contact details with exhibitors",
+            ));
+        }
+
+        let lead = Lead {
+            notes,
+            ..Lead::new(&exhibitor, registration.id, user.id)
+        };
+        let created = self
+            .lead_repository
+            .capture(&lead)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if created {
+            return Ok((lead, true));
+        }
+        let existing = self
+            .find_leads(exhibitor.id)
+            .await?
+            .into_iter()
+            .find(|existing| existing.registration_id == registration.id)
+            .ok_or_else(|| ApiError::conflict("The lead was removed while scanning; scan the badge again"))?;
+        Ok((existing, false))
+    }
+
+    /// The exhibitor's leads in scan order, with the attendees' current contact details
+    pub async fn leads(
+        &self,
+        event_id: Uuid,
+        exhibitor_id: Uuid,
+        user: &User,
+        may_manage: bool,
+    ) -> ApiResult<Vec<CapturedLead>> {
+        let (_, exhibitor) = self.get_staffed_exhibitor(event_id, exhibitor_id, user, may_manage).await?;
+        let leads = self.find_leads(exhibitor.id).await?;
+        self.with_contacts(event_id, leads).await
+    }
+
+    /// The exhibitor's leads as CSV, with a filename naming the event and exhibitor
+    pub async fn export_csv(
+        &self,
+        event_id: Uuid,
+        exhibitor_id: Uuid,
+        user: &User,
+        may_manage: bool,
+    ) -> ApiResult<(String, String)> {
+        let (event, exhibitor) = self.get_staffed_exhibitor(event_id, exhibitor_id, user, may_manage).await?;
+        let leads = self.find_leads(exhibitor.id).await?;
+        let leads = self.with_contacts(event_id, leads).await?;
+        let exhibitor_slug = Event::slug_for(&exhibitor.company_name, exhibitor.id);
+        Ok((format!("leads-{}-{}.csv", event.slug, exhibitor_slug), leads::render_csv(&leads)))
+    }
+
+    pub async fn delete_lead(
+        &self,
+        event_id: Uuid,
+        exhibitor_id: Uuid,
+        lead_id: Uuid,
+        user: &User,
+        may_manage: bool,
+    ) -> ApiResult<()> {
+        let (_, exhibitor) = self.get_staffed_exhibitor(event_id, exhibitor_id, user, may_manage).await?;
+        let lead = self
+            .lead_repository
+            .find_by_id(lead_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|lead| lead.exhibitor_id == exhibitor.id)
+            .ok_or_else(|| ApiError::not_found(format!("Lead with ID {}", lead_id)))?;
+        self.lead_repository
+            .delete(lead.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Leads across every exhibitor at the event, for its organizers
+    pub async fn analytics(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<LeadAnalytics> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can see its lead analytics"));
+        }
+        let exhibitors = self
+            .exhibitor_repository
+            .find_exhibitors(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let leads = self
+            .lead_repository
+            .find_by_event(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(leads::build_analytics(&exhibitors, &leads))
+    }
+
+    /// Looks up who each lead is. Leads whose registration is gone are left out.
+    async fn with_contacts(&self, event_id: Uuid, leads: Vec<Lead>) -> ApiResult<Vec<CapturedLead>> {
+        let registrations: HashMap<Uuid, EventRegistration> = self
+            .registration_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|registration| (registration.id, registration))
+            .collect();
+        let user_ids: Vec<Uuid> = leads
+            .iter()
+            .filter_map(|lead| registrations.get(&lead.registration_id).and_then(|registration| registration.user_id))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let users: HashMap<Uuid, User> = self
+            .user_repository
+            .find_by_ids(&user_ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|user| (user.id, user))
+            .collect();
+
+        Ok(leads
+            .into_iter()
+            .filter_map(|lead| {
+                let registration = registrations.get(&lead.registration_id)?;
+                let user = registration.user_id.and_then(|id| users.get(&id));
+                Some(CapturedLead {
+                    contact: LeadContact::of(registration, user),
+                    lead,
+                })
+            })
+            .collect())
+    }
+
+    async fn find_leads(&self, exhibitor_id: Uuid) -> ApiResult<Vec<Lead>> {
+        self.lead_repository
+            .find_by_exhibitor(exhibitor_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// The exhibitor, when the user works for it or organizes the event
+    async fn get_staffed_exhibitor(
+        &self,
+        event_id: Uuid,
+        exhibitor_id: Uuid,
+        user: &User,
+        may_manage: bool,
+    ) -> ApiResult<(Event, Exhibitor)> {
+        let event = self.get_event(event_id).await?;
+        let exhibitor = self
+            .exhibitor_repository
+            .find_exhibitor(exhibitor_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|exhibitor| exhibitor.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Exhibitor with ID {}", exhibitor_id)))?;
+        let is_staff = user.company_id.is_some() && user.company_id == exhibitor.company_id;
+        if !is_staff && !Self::is_organizer(&event, user.id, may_manage) {
+            return Err(ApiError::authorization(
+                "Only the exhibitor's staff and the event's organizers can handle its leads",
+            ));
+        }
+        Ok((event, exhibitor))
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+}
+
+// ============================================================================
+// Matchmaking Application Service
+// ============================================================================
+
+/// B2B matchmaking between an event's attendees. Attendees opt in with their
+/// interests and when they're free, get suggestions of who to meet, and
+/// request 1:1 meetings. The meeting is scheduled straight away into the
+/// first slot both are free, in one of the meeting rooms the organizers set
+/// aside; the invitee then accepts or declines.
+#[derive(Clone)]
+pub struct MatchmakingApplicationService {
+    matchmaking_repository: Arc<dyn MatchmakingRepository>,
+    resource_repository: Arc<dyn ResourceRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+/// An attendee's matchmaking profile as they fill it in
+#[derive(Debug, Clone)]
+pub struct MatchmakingProfileInput {
+    pub interests: Vec<String>,
+    pub bio: Option<String>,
+    pub availability: Vec<AvailabilityWindow>,
+}
+
+impl MatchmakingApplicationService {
+    pub const MEETING_MINUTES: i64 = 30;
+    pub const MAX_INTERESTS: usize = 15;
+    pub const MAX_INTEREST_CHARS: usize = 50;
+    pub const MAX_BIO_CHARS: usize = 1000;
+    pub const MAX_MESSAGE_CHARS: usize = 500;
+    pub const MAX_WINDOWS: usize = 20;
+
+    pub fn new(
+        matchmaking_repository: Arc<dyn MatchmakingRepository>,
+        resource_repository: Arc<dyn ResourceRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            matchmaking_repository,
+            resource_repository,
+            event_repository,
+            registration_repository,
+            user_repository,
+        }
+    }
+
+    pub async fn profile(&self, event_id: Uuid, user_id: Uuid) -> ApiResult<MatchmakingProfile> {
+        self.get_event(event_id).await?;
+        self.get_profile(event_id, user_id).await
+    }
+
+    /// Joins the event's matchmaking or updates the profile. Only attendees
+    /// can take part, and only be available while the event runs.
+    pub async fn save_profile(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        input: MatchmakingProfileInput,
+    ) -> ApiResult<MatchmakingProfile> {
+        let event = self.get_event(event_id).await?;
+        self.check_attendee(event_id, user_id).await?;
+
+        let interests = matchmaking::normalize_interests(input.interests);
+        if interests.len() > Self::MAX_INTERESTS {
+            return Err(ApiError::validation(
+                "interests",
+                format!("At most {} interests", Self::MAX_INTERESTS),
+            ));
+        }
+        if interests.iter().any(|interest| interest.chars().count() > Self::MAX_INTEREST_CHARS) {
+            return Err(ApiError::validation(
+                "interests",
+                format!("Interests must be at most {} characters", Self::MAX_INTEREST_CHARS),
+            ));
+        }
+        let mut availability = input.availability;
+        if availability.len() > Self::MAX_WINDOWS {
+            return Err(ApiError::validation(
+                "availability",
+                format!("At most {} availability windows", Self::MAX_WINDOWS),
+            ));
+        }
+        if availability.iter().any(|window| window.ends_at <= window.starts_at) {
+            return Err(ApiError::validation("availability", "Availability windows must end after they start"));
+        }
+        if availability
+            .iter()
+            .any(|window| window.starts_at < event.start_date || window.ends_at > event.end_date)
+        {
+            return Err(ApiError::validation("availability", "Availability must be while the event runs"));
+        }
+        availability.sort_by_key(|window| (window.starts_at, window.ends_at));
+
+        let existing = self
+            .matchmaking_repository
+            .find_profile(event_id, user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let profile = MatchmakingProfile {
+            interests,
+            bio: optional_text("bio", "Bio", input.bio, Self::MAX_BIO_CHARS)?,
+            availability,
+            created_at: existing.map_or_else(chrono::Utc::now, |existing| existing.created_at),
+            updated_at: chrono::Utc::now(),
+            ..MatchmakingProfile::new(event_id, user_id)
+        };
+        self.matchmaking_repository
+            .save_profile(&profile)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(profile)
+    }
+
+    /// Leaves the event's matchmaking, cancelling the user's meetings
+    pub async fn leave(&self, event_id: Uuid, user_id: Uuid) -> ApiResult<()> {
+        self.get_profile(event_id, user_id).await?;
+        for meeting in self.find_meetings(event_id).await? {
+            if meeting.is_active() && meeting.involves(user_id) {
+                self.close(meeting, MeetingStatus::Cancelled).await?;
+            }
+        }
+        self.matchmaking_repository
+            .delete_profile(event_id, user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Who else takes part, those sharing the most interests first, with the
+    /// company they registered for
+    pub async fn matches(&self, event_id: Uuid, user_id: Uuid) -> ApiResult<Vec<Match>> {
+        let me = self.get_profile(event_id, user_id).await?;
+        let profiles = self
+            .matchmaking_repository
+            .find_profiles(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let user_ids: Vec<Uuid> = profiles.iter().map(|profile| profile.user_id).collect();
+        let users = self
+            .user_repository
+            .find_by_ids(&user_ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let companies: HashMap<Uuid, String> = self
+            .registration_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .filter(|registration| registration.status != RegistrationStatus::Cancelled)
+            .filter_map(|registration| Some((registration.user_id?, registration.registrant_company?)))
+            .collect();
+        Ok(matchmaking::rank_matches(&me, profiles, &users, &companies))
+    }
+
+    /// The resources the event's meetings are held in, by name
+    pub async fn rooms(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<Resource>> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.find_rooms(event_id).await
+    }
+
+    /// Sets aside the resources for the event's meetings. Meetings already
+    /// scheduled keep their rooms.
+    pub async fn set_rooms(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        resource_ids: Vec<Uuid>,
+    ) -> ApiResult<Vec<Resource>> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let resource_ids: Vec<Uuid> = resource_ids.into_iter().collect::<HashSet<_>>().into_iter().collect();
+        for resource_id in &resource_ids {
+            let resource = self
+                .resource_repository
+                .find_by_id(*resource_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .ok_or_else(|| ApiError::not_found(format!("Resource with ID {}", resource_id)))?;
+            if !resource.is_active {
+                return Err(ApiError::validation(
+                    "resource_ids",
+                    format!("{} is retired and can't be booked", resource.name),
+                ));
+            }
+        }
+        self.matchmaking_repository
+            .replace_rooms(event_id, &resource_ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.find_rooms(event_id).await
+    }
+
+    /// Requests a meeting with another attendee, scheduled into the first
+    /// slot from now on when both are free and, if the event has meeting
+    /// rooms, a room is too
+    pub async fn request_meeting(
+        &self,
+        event_id: Uuid,
+        requester_id: Uuid,
+        invitee_id: Uuid,
+        message: Option<String>,
+    ) -> ApiResult<Meeting> {
+        if requester_id == invitee_id {
+            return Err(ApiError::validation("invitee_id", "You can't meet yourself"));
+        }
+        let requester = self.get_profile(event_id, requester_id).await?;
+        let invitee = self
+            .matchmaking_repository
+            .find_profile(event_id, invitee_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Matchmaking profile of user {}", invitee_id)))?;
+        let message = optional_text("message", "Message", message, Self::MAX_MESSAGE_CHARS)?;
+
+        let meetings = self.find_meetings(event_id).await?;
+        if meetings
+            .iter()
+            .any(|meeting| meeting.is_active() && meeting.involves(requester_id) && meeting.involves(invitee_id))
+        {
+            return Err(ApiError::conflict("You already have a meeting with this attendee"));
+        }
+
+        let length = chrono::Duration::minutes(Self::MEETING_MINUTES);
+        let slots = matchmaking::free_slots(&requester, &invitee, &meetings, length, chrono::Utc::now());
+        let rooms = self.find_rooms(event_id).await?;
+        let mut room_windows = HashMap::new();
+        for room in &rooms {
+            let windows = self
+                .resource_repository
+                .find_windows(room.id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            room_windows.insert(room.id, windows);
+        }
+
+        for starts_at in slots {
+            let ends_at = starts_at + length;
+            let meeting = Meeting {
+                message: message.clone(),
+                ..Meeting::new(event_id, requester_id, invitee_id, starts_at, ends_at)
+            };
+            if rooms.is_empty() {
+                return self.create_meeting(meeting).await;
+            }
+            let booking = self
+                .book_room(&rooms, &room_windows, event_id, requester_id, starts_at, ends_at)
+                .await?;
+            if let Some(booking) = booking {
+                return self
+                    .create_meeting(Meeting {
+                        resource_id: Some(booking.resource_id),
+                        booking_id: Some(booking.id),
+                        ..meeting
+                    })
+                    .await;
+            }
+        }
+        Err(ApiError::conflict(if rooms.is_empty() {
+            "There's no time left when you're both free"
+        } else {
+            "There's no time left when you're both free and a meeting room is available"
+        }))
+    }
+
+    /// The invitee accepts or declines; declining frees the meeting room
+    pub async fn answer(&self, event_id: Uuid, meeting_id: Uuid, user_id: Uuid, accept: bool) -> ApiResult<Meeting> {
+        let mut meeting = self.get_meeting(event_id, meeting_id, user_id).await?;
+        if meeting.invitee_id != user_id {
+            return Err(ApiError::authorization("Only the invitee can answer a meeting request"));
+        }
+        if meeting.status != MeetingStatus::Requested {
+            return Err(ApiError::conflict(format!(
+                "The meeting is already {}",
+                format!("{:?}", meeting.status).to_lowercase()
+            )));
+        }
+        if !accept {
+            return self.close(meeting, MeetingStatus::Declined).await;
+        }
+        meeting.status = MeetingStatus::Accepted;
+        meeting.updated_at = chrono::Utc::now();
+        self.matchmaking_repository
+            .update_meeting(&meeting)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(meeting)
+    }
+
+    /// Either attendee calls the meeting off, freeing its room
+    pub async fn cancel(&self, event_id: Uuid, meeting_id: Uuid, user_id: Uuid) -> ApiResult<Meeting> {
+        let meeting = self.get_meeting(event_id, meeting_id, user_id).await?;
+        if !meeting.is_active() {
+            return Err(ApiError::conflict(format!(
+                "The meeting is already {}",
+                format!("{:?}", meeting.status).to_lowercase()
+            )));
+        }
+        self.close(meeting, MeetingStatus::Cancelled).await
+    }
+
+    /// The user's meetings at the event, earliest first, with who they're
+    /// with and where
+    pub async fn meetings(&self, event_id: Uuid, user_id: Uuid) -> ApiResult<Vec<MeetingDetails>> {
+        self.get_event(event_id).await?;
+        let meetings: Vec<Meeting> = self
+            .find_meetings(event_id)
+            .await?
+            .into_iter()
+            .filter(|meeting| meeting.involves(user_id))
+            .collect();
+        let user_ids: Vec<Uuid> = meetings
+            .iter()
+            .flat_map(|meeting| [meeting.requester_id, meeting.invitee_id])
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let names: HashMap<Uuid, String> = self
+            .user_repository
+            .find_by_ids(&user_ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|user| (user.id, user.name))
+            .collect();
+
+        let mut rooms: HashMap<Uuid, String> = HashMap::new();
+        for resource_id in meetings.iter().filter_map(|meeting| meeting.resource_id) {
+            if rooms.contains_key(&resource_id) {
+                continue;
+            }
+            if let Some(resource) = self
+                .resource_repository
+                .find_by_id(resource_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+            {
+                rooms.insert(resource.id, resource.name);
+            }
+        }
+
+        Ok(meetings
+            .into_iter()
+            .map(|meeting| MeetingDetails {
+                requester_name: names.get(&meeting.requester_id).cloned().unwrap_or_default(),
+                invitee_name: names.get(&meeting.invitee_id).cloned().unwrap_or_default(),
+                room: meeting.resource_id.and_then(|id| rooms.get(&id).cloned()),
+                meeting,
+            })
+            .collect())
+    }
+
+    /// The meeting as an iCalendar invite, with its download name
+    pub async fn invite(
+        &self,
+        event_id: Uuid,
+        meeting_id: Uuid,
+        user_id: Uuid,
+        localization: &Localization,
+    ) -> ApiResult<(String, String)> {
+        let event = self.get_event(event_id).await?;
+        let meeting = self.get_meeting(event_id, meeting_id, user_id).await?;
+        let requester = self.get_user(meeting.requester_id).await?;
+        let invitee = self.get_user(meeting.invitee_id).await?;
+        let room = match meeting.resource_id {
+            Some(resource_id) => self
+                .resource_repository
+                .find_by_id(resource_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .map(|resource| resource.name),
+            None => None,
+        };
+        let ics = render_meeting_invite(
+            &meeting,
+            &event,
+            &requester,
+            &invitee,
+            room.as_deref(),
+            chrono::Utc::now(),
+            localization,
+        );
+        Ok((format!("meeting-{}.ics", meeting.id), ics))
+    }
+
+    /// Books the first of the rooms free from `starts_at` to `ends_at`
+    async fn book_room(
+        &self,
+        rooms: &[Resource],
+        room_windows: &HashMap<Uuid, Vec<AvailabilityWindow>>,
+        event_id: Uuid,
+        booked_by: Uuid,
+        starts_at: chrono::DateTime<chrono::Utc>,
+        ends_at: chrono::DateTime<chrono::Utc>,
+    ) -> ApiResult<Option<ResourceBooking>> {
+        let room_ids: Vec<Uuid> = rooms.iter().map(|room| room.id).collect();
+        let bookings = self
+            .resource_repository
+            .find_overlapping_bookings(&room_ids, starts_at, ends_at)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        for room in rooms {
+            let windows = room_windows.get(&room.id).map(Vec::as_slice).unwrap_or_default();
+            if !ResourceConflict::find(room, windows, &bookings, starts_at, ends_at).is_empty() {
+                continue;
+            }
+            let booking = ResourceBooking::new(room.id, event_id, starts_at, ends_at, booked_by);
+            let booked = self
+                .resource_repository
+                .create_bookings_if_free(std::slice::from_ref(&booking))
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            // Otherwise someone else took the room since the lookup
+            if booked {
+                return Ok(Some(booking));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn create_meeting(&self, meeting: Meeting) -> ApiResult<Meeting> {
+        self.matchmaking_repository
+            .create_meeting(&meeting)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(meeting)
+    }
+
+    /// Declines or cancels the meeting and releases its room booking
+    async fn close(&self, mut meeting: Meeting, status: MeetingStatus) -> ApiResult<Meeting> {
+        if let Some(booking_id) = meeting.booking_id.take() {
+            match self.resource_repository.delete_booking(booking_id).await {
+                Ok(()) | Err(DomainError::NotFound { .. }) => {}
+                Err(e) => return Err(ApiError::Domain { source: e }),
+            }
+        }
+        meeting.status = status;
+        meeting.updated_at = chrono::Utc::now();
+        self.matchmaking_repository
+            .update_meeting(&meeting)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(meeting)
+    }
+
+    async fn find_rooms(&self, event_id: Uuid) -> ApiResult<Vec<Resource>> {
+        let resource_ids = self
+            .matchmaking_repository
+            .find_rooms(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let mut rooms = Vec::with_capacity(resource_ids.len());
+        for resource_id in resource_ids {
+            if let Some(resource) = self
+                .resource_repository
+                .find_by_id(resource_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+            {
+                rooms.push(resource);
+            }
+        }
+        rooms.sort_by_key(|room| room.name.to_lowercase());
+        Ok(rooms)
+    }
+
+    async fn find_meetings(&self, event_id: Uuid) -> ApiResult<Vec<Meeting>> {
+        self.matchmaking_repository
+            .find_meetings(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// One of the user's meetings; other people's meetings are not found
+    async fn get_meeting(&self, event_id: Uuid, meeting_id: Uuid, user_id: Uuid) -> ApiResult<Meeting> {
+        self.matchmaking_repository
+            .find_meeting(meeting_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|meeting| meeting.event_id == event_id && meeting.involves(user_id))
+            .ok_or_else(|| ApiError::not_found(format!("Meeting with ID {}", meeting_id)))
+    }
+
+    async fn get_profile(&self, event_id: Uuid, user_id: Uuid) -> ApiResult<MatchmakingProfile> {
+        self.matchmaking_repository
+            .find_profile(event_id, user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found("Your matchmaking profile; join the event's matchmaking first"))
+    }
+
+    async fn get_user(&self, user_id: Uuid) -> ApiResult<User> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("User with ID {}", user_id)))
+    }
+
+    /// Registered for the event, or already checked in
+    async fn check_attendee(&self, event_id: Uuid, user_id: Uuid) -> ApiResult<()> {
+        let registration = self
+            .registration_repository
+            .find_by_event_and_user(event_id, user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        match registration.map(|registration| registration.status) {
+            Some(RegistrationStatus::Registered | RegistrationStatus::Attended) => Ok(()),
+            _ => Err(ApiError::authorization("Only the event's attendees can take part in its matchmaking")),
+        }
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its meeting rooms"));
+        }
+        Ok(event)
+    }
+}
+
+// ============================================================================
+// Web Vitals Application Service
+// ============================================================================
+
+/// Performance of the web app as measured in visitors' browsers: page load,
+/// input responsiveness, layout shift and API round trips. Reports come in
+/// unauthenticated, so they're bounded here; admins see percentiles per
+/// release. Samples are dropped after `RETENTION_DAYS`.
+#[derive(Clone)]
+pub struct WebVitalsApplicationService {
+    web_vital_repository: Arc<dyn WebVitalRepository>,
+}
+
+/// Measurements one browser took on one page
+#[derive(Debug, Clone)]
+pub struct WebVitalsReportInput {
+    pub release: String,
+    pub route: Option<String>,
+    pub measurements: Vec<(WebVitalMetric, f64)>,
+}
+
+impl WebVitalsApplicationService {
+    pub const RETENTION_DAYS: i64 = 30;
+    const MAX_MEASUREMENTS: usize = 50;
+    const MAX_RELEASE_CHARS: usize = 64;
+    const MAX_ROUTE_CHARS: usize = 500;
+    /// Ten minutes; anything slower is a suspended tab, not a measurement
+    const MAX_MILLISECONDS: f64 = 600_000.0;
+    const MAX_LAYOUT_SHIFT: f64 = 100.0;
+
+    pub fn new(web_vital_repository: Arc<dyn WebVitalRepository>) -> Self {
+        Self { web_vital_repository }
+    }
+
+    /// Stores the report's measurements; returns how many were stored
+    pub async fn record(&self, input: WebVitalsReportInput) -> ApiResult<usize> {
+        let release = input.release.trim().to_string();
+        if release.is_empty() {
+            return Err(ApiError::validation("release", "Release cannot be empty"));
+        }
+        if release.chars().count() > Self::MAX_RELEASE_CHARS {
+            return Err(ApiError::validation(
+                "release",
+                format!("Release can be at most {} characters", Self::MAX_RELEASE_CHARS),
+            ));
+        }
+        // Query strings can carry personal data and would split the routes up
+        let route = input.route.map(|route| route.split(['?', '#']).next().unwrap_or_default().to_string());
+        let route = optional_text("route", "Route", route, Self::MAX_ROUTE_CHARS)?;
+
+        if input.measurements.is_empty() {
+            return Err(ApiError::validation("measurements", "A report needs at least one measurement"));
+        }
+        if input.measurements.len() > Self::MAX_MEASUREMENTS {
+            return Err(ApiError::validation(
+                "measurements",
+                format!("A report can hold at most {} measurements", Self::MAX_MEASUREMENTS),
+            ));
+        }
+
+        let mut samples = Vec::with_capacity(input.measurements.len());
+        for (metric, value) in input.measurements {
+            let max = match metric {
+                WebVitalMetric::Cls => Self::MAX_LAYOUT_SHIFT,
+                _ => Self::MAX_MILLISECONDS,
+            };
+            if !value.is_finite() || !(0.0..=max).contains(&value) {
+                return Err(ApiError::validation(
+                    "measurements",
+                    format!("{:?} must be between 0 and {}", metric, max),
+                ));
+            }
+            samples.push(WebVitalSample {
+                route: route.clone(),
+                ..WebVitalSample::new(metric, value, release.clone())
+            });
+        }
+
+        self.web_vital_repository
+            .record(&samples)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(samples.len())
+    }
+
+    /// Percentiles per release and metric over the last `days` days
+    pub async fn summary(&self, days: i64) -> ApiResult<Vec<WebVitalSummary>> {
+        if !(1..=Self::RETENTION_DAYS).contains(&days) {
+            return Err(ApiError::validation(
+                "days",
+                format!("Days must be between 1 and {}", Self::RETENTION_DAYS),
+            ));
+        }
+        let samples = self
+            .web_vital_repository
+            .find_since(chrono::Utc::now() - chrono::Duration::days(days))
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(web_vitals::summarize(&samples))
+    }
+
+    /// Drops samples older than the retention period; returns how many
+    pub async fn prune(&self, now: chrono::DateTime<chrono::Utc>) -> ApiResult<u64> {
+        self.web_vital_repository
+            .delete_before(now - chrono::Duration::days(Self::RETENTION_DAYS))
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+}
+
+// ============================================================================
+// Event Consent Application Service
+// ============================================================================
+
+/// A registrant's answer to one of the event's consent checkboxes
+#[derive(Debug, Clone)]
+pub struct ConsentAnswer {
+    pub kind: ConsentKind,
+    pub accepted: bool,
+    /// The version the registrant saw, to catch text changed in the meantime
+    pub version: Option<i32>,
+}
+
+/// Consent checkboxes organizers add to an event's registration form (photo
+/// consent, data processing, marketing and lead-sharing opt-ins), the answers
+/// registrants give, and the consent report organizers keep for compliance
+#[derive(Clone)]
+pub struct EventConsentApplicationService {
+    consent_repository: Arc<dyn EventConsentRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl EventConsentApplicationService {
+    pub const MAX_TEXT_CHARS: usize = 2000;
+
+    pub fn new(
+        consent_repository: Arc<dyn EventConsentRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            consent_repository,
+            event_repository,
+            registration_repository,
+            user_repository,
+        }
+    }
+
+    /// The checkboxes shown when registering, in `ConsentKind` order
+    pub async fn list(&self, event_id: Uuid) -> ApiResult<Vec<EventConsent>> {
+        self.get_event(event_id).await?;
+        self.consents(event_id).await
+    }
+
+    /// Adds the checkbox of `kind`, or updates it; changing the text starts a
+    /// new version. Returns the checkbox before and after.
+    pub async fn configure(
+        &self,
+        event_id: Uuid,
+        kind: ConsentKind,
+        user_id: Uuid,
+        may_manage: bool,
+        text: String,
+        required: bool,
+    ) -> ApiResult<(Option<EventConsent>, EventConsent)> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let text = optional_text("text", "Text", Some(text), Self::MAX_TEXT_CHARS)?
+            .ok_or_else(|| ApiError::validation("text", "Text is required"))?;
+
+        match self.find(event_id, kind).await? {
+            Some(previous) => {
+                let mut consent = previous.clone();
+                consent.revise(text, required, chrono::Utc::now());
+                self.consent_repository
+                    .update(&consent)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                Ok((Some(previous), consent))
+            }
+            None => {
+                let consent = EventConsent::new(event_id, kind, text, required);
+                self.consent_repository
+                    .create(&consent)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                Ok((None, consent))
+            }
+        }
+    }
+
+    /// Answers already given stay in the report
+    pub async fn remove(&self, event_id: Uuid, kind: ConsentKind, user_id: Uuid, may_manage: bool) -> ApiResult<EventConsent> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let consent = self
+            .find(event_id, kind)
+            .await?
+            .ok_or_else(|| ApiError::not_found(format!("{:?} consent on event {}", kind, event_id)))?;
+        self.consent_repository
+            .delete(consent.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(consent)
+    }
+
+    /// Checks a registration's answers before it is placed: every required
+    /// checkbox must be accepted, and a checkbox left out counts as declined.
+    /// Returns each checkbox with its answer, for `record`.
+    pub async fn check_answers(&self, event_id: Uuid, answers: &[ConsentAnswer]) -> ApiResult<Vec<(EventConsent, bool)>> {
+        let consents = self.consents(event_id).await?;
+
+        let mut seen = HashSet::new();
+        for answer in answers {
+            if !seen.insert(answer.kind) {
+                return Err(ApiError::validation("consents", "Each consent can only be answered once"));
+            }
+            let Some(consent) = consents.iter().find(|consent| consent.kind == answer.kind) else {
+                return Err(ApiError::validation(
+                    "consents",
+                    format!("This event doesn't ask for {:?} consent", answer.kind),
+                ));
+            };
+            if answer.version.is_some_and(|version| version != consent.version) {
+                return Err(ApiError::conflict(format!(
+                    "The {:?} consent text has changed; review it and register again",
+                    answer.kind
+                )));
+            }
+        }
+
+        consents
+            .into_iter()
+            .map(|consent| {
+                let accepted = answers
+                    .iter()
+                    .any(|answer| answer.kind == consent.kind && answer.accepted);
+                if consent.required && !accepted {
+                    return Err(ApiError::validation(
+                        "consents",
+                        format!("{:?} consent is required to register", consent.kind),
+                    ));
+                }
+                Ok((consent, accepted))
+            })
+            .collect()
+    }
+
+    /// Stores the answers checked by `check_answers` on the placed registration
+    pub async fn record(
+        &self,
+        registration_id: Uuid,
+        answers: &[(EventConsent, bool)],
+    ) -> ApiResult<Vec<RegistrationConsent>> {
+        let now = chrono::Utc::now();
+        let answers: Vec<RegistrationConsent> = answers
+            .iter()
+            .map(|(consent, accepted)| RegistrationConsent::new(registration_id, consent, *accepted, now))
+            .collect();
+        if !answers.is_empty() {
+            self.consent_repository
+                .record_answers(&answers)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+        }
+        Ok(answers)
+    }
+
+    pub async fn report(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<ConsentReport> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.build(event_id).await
+    }
+
+    /// The report as CSV, with its download name
+    pub async fn report_csv(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<(String, String)> {
+        let event = self.get_managed_event(event_id, user_id, may_manage).await?;
+        let report = self.build(event_id).await?;
+        Ok((format!("consents-{}.csv", event.slug), consents::render_csv(&report)))
+    }
+
+    async fn build(&self, event_id: Uuid) -> ApiResult<ConsentReport> {
+        let consents = self.consents(event_id).await?;
+        let registrations = self
+            .registration_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let answers = self
+            .consent_repository
+            .find_answers_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut registrants = HashMap::new();
+        for registration in &registrations {
+            let Some(user_id) = registration.user_id else { continue };
+            let user = self
+                .user_repository
+                .find_by_id(user_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            if let Some(user) = user {
+                registrants.insert(registration.id, Registrant { name: Some(user.name), email: Some(user.email) });
+            }
+        }
+        Ok(consents::build_report(&consents, &registrations, answers, &registrants))
+    }
+
+    async fn consents(&self, event_id: Uuid) -> ApiResult<Vec<EventConsent>> {
+        self.consent_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn find(&self, event_id: Uuid, kind: ConsentKind) -> ApiResult<Option<EventConsent>> {
+        Ok(self.consents(event_id).await?.into_iter().find(|consent| consent.kind == kind))
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can manage its consents"));
+        }
+        Ok(event)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+}
+
+// ============================================================================
+// Event Broadcast Application Service
+// ============================================================================
+
+/// What a broadcast would look like and how many it would reach, before it is sent
+#[derive(Debug, Clone)]
+pub struct BroadcastPreview {
+    /// The email as the sender would get it
+    pub email: EmailMessage,
+    /// Registrants in the audience, one per email address
+    pub recipients: usize,
+    /// Of those, addresses skipped because they bounced or complained before
+    pub suppressed: usize,
+}
+
+/// A broadcast as written by the organizer
+#[derive(Debug, Clone)]
+pub struct BroadcastInput {
+    pub audience: BroadcastAudience,
+    pub subject: String,
+    pub message: String,
+    /// Sent right away when not given
+    pub scheduled_for: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+// A registrant in a broadcast's audience; guests have no account to notify
+struct BroadcastRecipient {
+    user_id: Option<Uuid>,
+    email: Option<String>,
+    localization: Localization,
+}
+
+/// Messages organizers send to an event's registrants, or to those confirmed,
+/// waitlisted or checked in. A broadcast goes out right away or at a scheduled
+/// time; each registrant gets it by email and, with an account, as a live
+/// notification.
+#[derive(Clone)]
+pub struct EventBroadcastApplicationService {
+    broadcast_repository: Arc<dyn EventBroadcastRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    mailer: Arc<dyn Mailer>,
+    notifier: RegistrationNotifier,
+    suppression_repository: Option<Arc<dyn EmailSuppressionRepository>>,
+    public_url: String,
+}
+
+impl EventBroadcastApplicationService {
+    pub const MAX_SUBJECT_CHARS: usize = 200;
+    pub const MAX_MESSAGE_CHARS: usize = 10_000;
+
+    pub fn new(
+        broadcast_repository: Arc<dyn EventBroadcastRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        mailer: Arc<dyn Mailer>,
+        notifier: RegistrationNotifier,
+        public_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            broadcast_repository,
+            event_repository,
+            registration_repository,
+            user_repository,
+            mailer,
+            notifier,
+            suppression_repository: None,
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Broadcasts aren't sent to addresses that bounced or complained
+    pub fn with_suppressions(mut self, suppression_repository: Arc<dyn EmailSuppressionRepository>) -> Self {
+        self.suppression_repository = Some(suppression_repository);
+        self
+    }
+
+    /// Renders the broadcast for the sender and counts who it would reach,
+    /// without sending or storing anything
+    pub async fn preview(&self, event_id: Uuid, sender: &User, may_manage: bool, input: BroadcastInput) -> ApiResult<BroadcastPreview> {
+        let event = self.get_managed_event(event_id, sender.id, may_manage).await?;
+        let broadcast = Self::compose(event_id, sender, input)?;
+
+        let mut recipients = 0;
+        let mut suppressed = 0;
+        for recipient in self.recipients(&event, broadcast.audience).await? {
+            recipients += 1;
+            if let Some(email) = &recipient.email {
+                if self.is_suppressed(email).await? {
+                    suppressed += 1;
+                }
+            }
+        }
+        let email = render_broadcast_email(
+            &broadcast,
+            &event,
+            &sender.email,
+            &self.public_url,
+            &Localization::for_user(sender),
+        );
+        Ok(BroadcastPreview { email, recipients, suppressed })
+    }
+
+    /// Sends the broadcast now, or stores it for the scheduler when
+    /// `scheduled_for` is given; that has to be in the future
+    pub async fn send(&self, event_id: Uuid, sender: &User, may_manage: bool, input: BroadcastInput) -> ApiResult<EventBroadcast> {
+        let event = self.get_managed_event(event_id, sender.id, may_manage).await?;
+        let now = chrono::Utc::now();
+        if input.scheduled_for.is_some_and(|at| at <= now) {
+            return Err(ApiError::validation("scheduled_for", "The send time must be in the future"));
+        }
+        let mut broadcast = Self::compose(event_id, sender, input)?;
+
+        if broadcast.scheduled_for.is_none() {
+            let delivery = self.deliver(&event, &broadcast).await?;
+            broadcast.mark_sent(delivery, now);
+        }
+        self.broadcast_repository
+            .create(&broadcast)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(broadcast)
+    }
+
+    /// The event's broadcasts with their delivery counts, newest first
+    pub async fn list(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Vec<EventBroadcast>> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        self.broadcast_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Calls off a scheduled broadcast. Returns it before and after.
+    pub async fn cancel(
+        &self,
+        event_id: Uuid,
+        broadcast_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+    ) -> ApiResult<(EventBroadcast, EventBroadcast)> {
+        self.get_managed_event(event_id, user_id, may_manage).await?;
+        let previous = self
+            .broadcast_repository
+            .find_by_id(broadcast_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|broadcast| broadcast.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Broadcast with ID {}", broadcast_id)))?;
+        if previous.status != BroadcastStatus::Scheduled {
+            return Err(ApiError::conflict("Only scheduled broadcasts can be cancelled"));
+        }
+
+        let mut broadcast = previous.clone();
+        broadcast.status = BroadcastStatus::Cancelled;
+        broadcast.updated_at = chrono::Utc::now();
+        self.update(&broadcast).await?;
+        Ok((previous, broadcast))
+    }
+
+    /// Sends every scheduled broadcast due at `now` and returns them
+    pub async fn send_due(&self, now: chrono::DateTime<chrono::Utc>) -> ApiResult<Vec<EventBroadcast>> {
+        let due = self
+            .broadcast_repository
+            .find_due(now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut sent = Vec::with_capacity(due.len());
+        for mut broadcast in due {
+            let event = self.get_event(broadcast.event_id).await?;
+            let delivery = self.deliver(&event, &broadcast).await?;
+            broadcast.mark_sent(delivery, now);
+            self.update(&broadcast).await?;
+            sent.push(broadcast);
+        }
+        Ok(sent)
+    }
+
+    fn compose(event_id: Uuid, sender: &User, input: BroadcastInput) -> ApiResult<EventBroadcast> {
+        let subject = optional_text("subject", "Subject", Some(input.subject), Self::MAX_SUBJECT_CHARS)?
+            .ok_or_else(|| ApiError::validation("subject", "Subject is required"))?;
+        let message = optional_text("message", "Message", Some(input.message), Self::MAX_MESSAGE_CHARS)?
+            .ok_or_else(|| ApiError::validation("message", "Message is required"))?;
+        Ok(EventBroadcast::new(event_id, sender.id, input.audience, subject, message, input.scheduled_for))
+    }
+
+    // Emails go out one by one; a refused email is counted and the rest still go
+    async fn deliver(&self, event: &Event, broadcast: &EventBroadcast) -> ApiResult<BroadcastDelivery> {
+        let mut delivery = BroadcastDelivery::default();
+        for recipient in self.recipients(event, broadcast.audience).await? {
+            delivery.recipients += 1;
+            if let Some(user_id) = recipient.user_id {
+                self.notifier.notify(
+                    user_id,
+                    RegistrationNotification::BroadcastReceived { broadcast_id: broadcast.id, event_id: event.id },
+                );
+            }
+            let Some(email) = recipient.email else {
+                delivery.failed += 1;
+                continue;
+            };
+            if self.is_suppressed(&email).await? {
+                delivery.suppressed += 1;
+                continue;
+            }
+            let message = render_broadcast_email(broadcast, event, &email, &self.public_url, &recipient.localization);
+            match self.mailer.send(&message).await {
+                Ok(()) => delivery.delivered += 1,
+                Err(e) => {
+                    tracing::warn!("Broadcast {} to {} failed: {}", broadcast.id, email, e);
+                    delivery.failed += 1;
+                }
+            }
+        }
+        Ok(delivery)
+    }
+
+    // Registrants in the audience, once per email address
+    async fn recipients(&self, event: &Event, audience: BroadcastAudience) -> ApiResult<Vec<BroadcastRecipient>> {
+        let registrations = self
+            .registration_repository
+            .find_by_event_id(event.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut seen = HashSet::new();
+        let mut recipients = Vec::new();
+        for registration in registrations.iter().filter(|registration| audience.includes(registration)) {
+            let user = match registration.user_id {
+                Some(user_id) => self
+                    .user_repository
+                    .find_by_id(user_id)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?,
+                None => None,
+            };
+            let recipient = match user {
+                Some(user) => BroadcastRecipient {
+                    user_id: Some(user.id),
+                    email: Some(user.email.clone()),
+                    localization: Localization::for_user(&user),
+                },
+                None => BroadcastRecipient {
+                    user_id: None,
+                    email: registration
+                        .registrant_email
+                        .clone()
+                        .filter(|email| !email.trim().is_empty()),
+                    localization: Localization::default(),
+                },
+            };
+            if let Some(email) = &recipient.email {
+                if !seen.insert(email.trim().to_lowercase()) {
+                    continue;
+                }
+            }
+            recipients.push(recipient);
+        }
+        Ok(recipients)
+    }
+
+    async fn update(&self, broadcast: &EventBroadcast) -> ApiResult<()> {
+        self.broadcast_repository
+            .update(broadcast)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn is_suppressed(&self, email: &str) -> ApiResult<bool> {
+        let Some(suppressions) = &self.suppression_repository else {
+            return Ok(false);
+        };
+        suppressions
+            .find_by_email(email)
+            .await
+            .map(|suppression| suppression.is_some())
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_managed_event(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<Event> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can message its registrants"));
+        }
+        Ok(event)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+}
+
+// ============================================================================
+// Messaging Application Service
+// ============================================================================
+
+/// A thread with what an inbox shows about it, seen from one side
+#[derive(Debug, Clone)]
+pub struct InboxThread {
+    pub thread: MessageThread,
+    pub event_title: String,
+    pub attendee_name: String,
+    /// The viewer is on the organizers' side of the thread
+    pub as_organizer: bool,
+}
+
+impl InboxThread {
+    /// Messages the viewer's side hasn't read
+    pub fn unread(&self) -> i32 {
+        self.thread.unread_for(self.as_organizer)
+    }
+}
+
+/// Conversations about an event between its organizers and one attendee at a
+/// time. Attendees start them, on their own or by answering a broadcast; any
+/// of the event's organizers can answer, and each side has its own unread
+/// count. New messages are pushed as live notifications to the other side.
+#[derive(Clone)]
+pub struct MessagingApplicationService {
+    thread_repository: Arc<dyn MessageThreadRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    broadcast_repository: Arc<dyn EventBroadcastRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    notifier: RegistrationNotifier,
+}
+
+impl MessagingApplicationService {
+    pub const MAX_MESSAGE_CHARS: usize = 5000;
+
+    pub fn new(
+        thread_repository: Arc<dyn MessageThreadRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        broadcast_repository: Arc<dyn EventBroadcastRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        notifier: RegistrationNotifier,
+    ) -> Self {
+        Self {
+            thread_repository,
+            event_repository,
+            registration_repository,
+            broadcast_repository,
+            user_repository,
+            notifier,
+        }
+    }
+
+    /// Sends a message from a registrant to the event's organizers, in the
+    /// registrant's thread about the event, which is started on the first one.
+    /// `broadcast_id` names the broadcast being answered.
+    pub async fn contact(
+        &self,
+        event_id: Uuid,
+        attendee: &User,
+        body: String,
+        broadcast_id: Option<Uuid>,
+    ) -> ApiResult<ThreadMessage> {
+        let event = self.get_event(event_id).await?;
+        if Self::is_organizer(&event, attendee.id, false) {
+            return Err(ApiError::validation("event_id", "Organizers can't message their own event"));
+        }
+        let body = Self::body(body)?;
+        self.registration_repository
+            .find_by_event_and_user(event_id, attendee.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::authorization("Only the event's registrants can message its organizers"))?;
+        if let Some(broadcast_id) = broadcast_id {
+            self.broadcast_repository
+                .find_by_id(broadcast_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .filter(|broadcast| broadcast.event_id == event_id && broadcast.status == BroadcastStatus::Sent)
+                .ok_or_else(|| ApiError::not_found(format!("Broadcast with ID {}", broadcast_id)))?;
+        }
+
+        let thread = match self
+            .thread_repository
+            .find_by_event_and_attendee(event_id, attendee.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+        {
+            Some(thread) => thread,
+            None => {
+                let thread = MessageThread::new(event_id, attendee.id);
+                self.thread_repository
+                    .create(&thread)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                thread
+            }
+        };
+
+        let message = ThreadMessage::new(thread.id, attendee.id, false, body, broadcast_id);
+        self.post(&thread, &event, &message).await?;
+        Ok(message)
+    }
+
+    /// Answers in a thread; its attendee and the event's organizers can write
+    pub async fn reply(&self, thread_id: Uuid, sender: &User, may_manage: bool, body: String) -> ApiResult<ThreadMessage> {
+        let (thread, event, as_organizer) = self.get_thread(thread_id, sender.id, may_manage).await?;
+        let message = ThreadMessage::new(thread.id, sender.id, as_organizer, Self::body(body)?, None);
+        self.post(&thread, &event, &message).await?;
+        Ok(message)
+    }
+
+    /// The user's threads as an attendee and as an organizer, most recent
+    /// activity first
+    pub async fn inbox(&self, user_id: Uuid) -> ApiResult<Vec<InboxThread>> {
+        let threads = self.threads_of(user_id).await?;
+
+        let attendee_ids: Vec<Uuid> = threads
+            .iter()
+            .map(|(thread, _)| thread.attendee_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let names: HashMap<Uuid, String> = self
+            .user_repository
+            .find_by_ids(&attendee_ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|user| (user.id, user.name))
+            .collect();
+
+        let mut titles: HashMap<Uuid, String> = HashMap::new();
+        let mut inbox = Vec::with_capacity(threads.len());
+        for (thread, as_organizer) in threads {
+            if !titles.contains_key(&thread.event_id) {
+                let title = self.get_event(thread.event_id).await?.title;
+                titles.insert(thread.event_id, title);
+            }
+            inbox.push(InboxThread {
+                event_title: titles[&thread.event_id].clone(),
+                attendee_name: names.get(&thread.attendee_id).cloned().unwrap_or_default(),
+                thread,
+                as_organizer,
+            });
+        }
+        Ok(inbox)
+    }
+
+    /// The thread with its messages, oldest first, after marking it read for
+    /// the viewer's side
+    pub async fn thread(&self, thread_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<(InboxThread, Vec<ThreadMessage>)> {
+        let (mut thread, event, as_organizer) = self.get_thread(thread_id, user_id, may_manage).await?;
+        let messages = self
+            .thread_repository
+            .find_messages(thread_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        if thread.unread_for(as_organizer) > 0 {
+            self.thread_repository
+                .mark_read(thread_id, as_organizer)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            if as_organizer {
+                thread.organizer_unread = 0;
+            } else {
+                thread.attendee_unread = 0;
+            }
+        }
+        let attendee_name = self
+            .user_repository
+            .find_by_id(thread.attendee_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .map(|user| user.name)
+            .unwrap_or_default();
+        let summary = InboxThread {
+            thread,
+            event_title: event.title,
+            attendee_name,
+            as_organizer,
+        };
+        Ok((summary, messages))
+    }
+
+    /// Unread messages across all of the user's threads
+    pub async fn unread_count(&self, user_id: Uuid) -> ApiResult<i64> {
+        Ok(self
+            .threads_of(user_id)
+            .await?
+            .iter()
+            .map(|(thread, as_organizer)| i64::from(thread.unread_for(*as_organizer)))
+            .sum())
+    }
+
+    // Stores the message and lets the other side know
+    async fn post(&self, thread: &MessageThread, event: &Event, message: &ThreadMessage) -> ApiResult<()> {
+        self.thread_repository
+            .add_message(message)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let notification = RegistrationNotification::MessageReceived { thread_id: thread.id, event_id: event.id };
+        if message.from_organizer {
+            self.notifier.notify(thread.attendee_id, notification);
+        } else {
+            for organizer_id in std::iter::once(event.organizer_id).chain(event.co_organizers.iter().copied()) {
+                self.notifier.notify(organizer_id, notification.clone());
+            }
+        }
+        Ok(())
+    }
+
+    // Threads the user is the attendee of, then those of events they organize,
+    // each with whether the user is on the organizers' side; most recent first
+    async fn threads_of(&self, user_id: Uuid) -> ApiResult<Vec<(MessageThread, bool)>> {
+        let (attending, organizing) = tokio::try_join!(
+            self.thread_repository.find_by_attendee(user_id),
+            self.thread_repository.find_for_organizer(user_id),
+        )
+        .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut threads: Vec<(MessageThread, bool)> = attending
+            .into_iter()
+            .map(|thread| (thread, false))
+            .chain(
+                organizing
+                    .into_iter()
+                    .filter(|thread| thread.attendee_id != user_id)
+                    .map(|thread| (thread, true)),
+            )
+            .collect();
+        threads.sort_by_key(|(thread, _)| std::cmp::Reverse(thread.last_message_at));
+        Ok(threads)
+    }
+
+    fn body(body: String) -> ApiResult<String> {
+        optional_text("body", "Message", Some(body), Self::MAX_MESSAGE_CHARS)?
+            .ok_or_else(|| ApiError::validation("body", "Message is required"))
+    }
+
+    // The thread, its event and whether the user writes on the organizers' side
+    async fn get_thread(&self, thread_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<(MessageThread, Event, bool)> {
+        let thread = self
+            .thread_repository
+            .find_by_id(thread_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Message thread with ID {}", thread_id)))?;
+        let event = self.get_event(thread.event_id).await?;
+        if thread.attendee_id == user_id {
+            return Ok((thread, event, false));
+        }
+        if !Self::is_organizer(&event, user_id, may_manage) {
+            return Err(ApiError::authorization("Only the attendee and the event's organizers can read this thread"));
+        }
+        Ok((thread, event, true))
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+}
+
+// ============================================================================
+// Event Archive Application Service
+// ============================================================================
+
+/// Export of an event with its child rows as a JSON archive, and import of such
+/// an archive as a new draft event (see `domain::archive`)
+#[derive(Clone)]
+pub struct EventArchiveApplicationService {
+    event_repository: Arc<dyn EventRepository>,
+    category_repository: Arc<dyn EventCategoryRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    invitation_repository: Arc<dyn EventInvitationRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    sponsor_repository: Arc<dyn EventSponsorRepository>,
+    budget_repository: Arc<dyn EventBudgetRepository>,
+    /// Scrubbed on every import on top of what the caller asks for, so a dev
+    /// server can't end up holding production personal data
+    import_anonymizer: Anonymizer,
+}
+
+impl EventArchiveApplicationService {
+    /// Largest archive accepted for import
+    pub const MAX_ARCHIVE_BYTES: usize = 20 * 1024 * 1024;
+
+    pub fn new(
+        event_repository: Arc<dyn EventRepository>,
+        category_repository: Arc<dyn EventCategoryRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        invitation_repository: Arc<dyn EventInvitationRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        sponsor_repository: Arc<dyn EventSponsorRepository>,
+        budget_repository: Arc<dyn EventBudgetRepository>,
+    ) -> Self {
+        Self {
+            event_repository,
+            category_repository,
+            user_repository,
+            invitation_repository,
+            registration_repository,
+            sponsor_repository,
+            budget_repository,
+            import_anonymizer: Anonymizer::default(),
+        }
+    }
+
+    pub fn with_import_anonymizer(mut self, anonymizer: Anonymizer) -> Self {
+        self.import_anonymizer = anonymizer;
+        self
+    }
+
+    /// The event and its child rows, with the archive's download name;
+    /// `anonymizer` scrubs personal data before it leaves the server
+    pub async fn export(
+        &self,
+        event_id: Uuid,
+        user_id: Uuid,
+        may_manage: bool,
+        anonymizer: &Anonymizer,
+    ) -> ApiResult<(String, EventArchive)> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        if !(may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)) {
+            return Err(ApiError::authorization("Only the event's organizers can export it"));
+        }
+
+        let (invitations, registrations, sponsors, budget_lines, expenses) = tokio::try_join!(
+            self.invitation_repository.find_by_event_id(event_id),
+            self.registration_repository.find_by_event_id(event_id),
+            self.sponsor_repository.find_by_event_id(event_id),
+            self.budget_repository.find_lines_by_event_id(event_id),
+            self.budget_repository.find_expenses_by_event_id(event_id),
+        )
+        .map_err(|e| ApiError::Domain { source: e })?;
+
+        let filename = format!("{}.aqio.json", event.slug);
+        let mut archive = EventArchive {
+            format_version: ARCHIVE_FORMAT_VERSION,
+            exported_at: chrono::Utc::now(),
+            anonymized: vec![],
+            event,
+            invitations,
+            registrations,
+            sponsors,
+            budget_lines,
+            expenses,
+        };
+        anonymizer.apply(&mut archive);
+        Ok((filename, archive))
+    }
+
+    /// Recreate an exported event as a new draft organized by `importer`
+    ///
+    /// Everything gets a new id, and the fields in `anonymizer` and the
+    /// server's import anonymizer are scrubbed. If a row can't be written the
+    /// partly imported event is deleted again, taking its child rows with it.
+    pub async fn import(
+        &self,
+        archive: EventArchive,
+        importer: &User,
+        anonymizer: &Anonymizer,
+    ) -> ApiResult<EventArchive> {
+        if archive.format_version != ARCHIVE_FORMAT_VERSION {
+            return Err(ApiError::validation(
+                "format_version",
+                format!(
+                    "Unsupported archive format {}; this server reads format {}",
+                    archive.format_version, ARCHIVE_FORMAT_VERSION
+                ),
+            ));
+        }
+        let category_exists = self
+            .category_repository
+            .find_by_id(&archive.event.category_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .is_some();
+        if !category_exists {
+            return Err(ApiError::validation(
+                "event.category_id",
+                format!("Category '{}' doesn't exist here", archive.event.category_id),
+            ));
+        }
+
+        let known_users: HashSet<Uuid> = self
+            .user_repository
+            .find_by_ids(&archive.referenced_users())
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|user| user.id)
+            .collect();
+        let mut archive = archive.rekey(importer.id, &known_users, chrono::Utc::now());
+        self.import_anonymizer.union(anonymizer).apply(&mut archive);
+        EventService::new()
+            .validate_event(&archive.event)
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        self.event_repository
+            .create(&archive.event)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if let Err(e) = self.insert_children(&archive).await {
+            if let Err(cleanup) = self.event_repository.delete(archive.event.id).await {
+                tracing::error!("Could not remove partly imported event {}: {}", archive.event.id, cleanup);
+            }
+            return Err(ApiError::Domain { source: e });
+        }
+        Ok(archive)
+    }
+
+    // Invitations before registrations and lines before expenses, which point at them
+    async fn insert_children(&self, archive: &EventArchive) -> DomainResult<()> {
+        for invitation in &archive.invitations {
+            self.invitation_repository.create(invitation).await?;
+        }
+        for registration in &archive.registrations {
+            self.registration_repository.create(registration).await?;
+        }
+        for sponsor in &archive.sponsors {
+            self.sponsor_repository.create(sponsor).await?;
+        }
+        for line in &archive.budget_lines {
+            self.budget_repository.create_line(line).await?;
+        }
+        for expense in &archive.expenses {
+            self.budget_repository.create_expense(expense).await?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Past Events Application Service
+// ============================================================================
+
+/// Browsing of events that are over, with their attendance, and yearly
+/// attendance per category or organizer (see `domain::past_events`). Private
+/// events only count for their organizers and administrators.
+#[derive(Clone)]
+pub struct PastEventsApplicationService {
+    summary_repository: Arc<dyn EventSummaryRepository>,
+}
+
+impl PastEventsApplicationService {
+    pub fn new(summary_repository: Arc<dyn EventSummaryRepository>) -> Self {
+        Self { summary_repository }
+    }
+
+    /// Past events matching `filter`, most recent first
+    pub async fn browse(
+        &self,
+        viewer_id: Uuid,
+        may_manage: bool,
+        filter: PastEventFilter,
+        pagination: PaginationParams,
+    ) -> ApiResult<PaginatedResult<EventSummary>> {
+        let filter = Self::visible(filter, viewer_id, may_manage)?;
+        self.summary_repository
+            .find_past(&filter, chrono::Utc::now(), pagination)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Each group's years side by side, groups ordered by ID
+    pub async fn trends(
+        &self,
+        viewer_id: Uuid,
+        may_manage: bool,
+        grouping: ArchiveGrouping,
+        filter: PastEventFilter,
+    ) -> ApiResult<Vec<AttendanceTrend>> {
+        let filter = Self::visible(filter, viewer_id, may_manage)?;
+        let rows = self
+            .summary_repository
+            .yearly_attendance(&filter, grouping, chrono::Utc::now())
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(past_events::attendance_trends(&rows))
+    }
+
+    fn visible(mut filter: PastEventFilter, viewer_id: Uuid, may_manage: bool) -> ApiResult<PastEventFilter> {
+        if let (Some(from), Some(to)) = (filter.from_year, filter.to_year) {
+            if from > to {
+                return Err(ApiError::validation("from_year", "from_year can't be after to_year"));
+            }
+        }
+        filter.title_contains = filter
+            .title_contains
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty());
+        filter.visible_to = (!may_manage).then_some(viewer_id);
+        Ok(filter)
+    }
+}
+
+// ============================================================================
+// Annual Report Application Service
+// ============================================================================
+
+/// Organizations' annual reports (see `domain::annual_report`), assembled from
+/// the analytics repository for the organization's organizers and
+/// administrators. Private events count too, as they're the organization's own.
+#[derive(Clone)]
+pub struct AnnualReportApplicationService {
+    analytics_repository: Arc<dyn AnalyticsRepository>,
+    company_repository: Arc<dyn CompanyRepository>,
+}
+
+impl AnnualReportApplicationService {
+    pub fn new(
+        analytics_repository: Arc<dyn AnalyticsRepository>,
+        company_repository: Arc<dyn CompanyRepository>,
+    ) -> Self {
+        Self {
+            analytics_repository,
+            company_repository,
+        }
+    }
+
+    /// The report for `year`, compared with the year before; without a year,
+    /// the last full calendar year
+    pub async fn report(&self, company_id: Uuid, year: Option<i32>, user: &User, may_manage: bool) -> ApiResult<AnnualReport> {
+        let company = self
+            .company_repository
+            .find_by_id(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Company with ID {}", company_id)))?;
+        if !SchedulingApplicationService::can_manage(company_id, user, may_manage) {
+            return Err(ApiError::authorization(
+                "Only the organization's organizers can see its annual report",
+            ));
+        }
+
+        let now = chrono::Utc::now();
+        let year = year.unwrap_or(now.year() - 1);
+        if !(1..=now.year()).contains(&year) {
+            return Err(ApiError::validation("year", "Must be this year or one before it"));
+        }
+
+        let current = self.organization_year(company_id, year, now).await?;
+        let previous = self.organization_year(company_id, year - 1, now).await?;
+        Ok(AnnualReport::new(company.name, &current, &previous, now))
+    }
+
+    /// The same report as a PDF, with its download name
+    pub async fn report_pdf(
+        &self,
+        company_id: Uuid,
+        year: Option<i32>,
+        user: &User,
+        may_manage: bool,
+    ) -> ApiResult<(String, Vec<u8>)> {
+        let report = self.report(company_id, year, user, may_manage).await?;
+        Ok((report.filename(), render_annual_report(&report)))
+    }
+
+    async fn organization_year(&self, company_id: Uuid, year: i32, now: chrono::DateTime<chrono::Utc>) -> ApiResult<OrganizationYear> {
+        self.analytics_repository
+            .organization_year(company_id, year, now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+}
+
+// ============================================================================
+// Event Field Application Service
+// ============================================================================
+
+/// A custom event field as entered by the organization
+#[derive(Debug, Clone)]
+pub struct EventFieldInput {
+    pub key: String,
+    pub label: String,
+    pub field_type: EventFieldType,
+    pub options: Vec<String>,
+    pub is_required: bool,
+    /// After the organization's other fields when not given
+    pub position: Option<i32>,
+}
+
+/// One of an event's custom fields, with the event's value when it has one
+#[derive(Debug, Clone)]
+pub struct EventFieldEntry {
+    pub definition: EventFieldDefinition,
+    pub value: Option<String>,
+}
+
+/// The custom fields that apply to an event, in display order
+#[derive(Debug, Clone)]
+pub struct EventFields {
+    pub event_id: Uuid,
+    /// The organizer's organization, whose fields these are
+    pub company_id: Option<Uuid>,
+    pub fields: Vec<EventFieldEntry>,
+    /// Whether the caller may change the values
+    pub can_edit: bool,
+}
+
+/// Structured fields organizations add to their members' events, such as a
+/// species focus or a regulatory topic (see `domain::event_fields`). The
+/// fields of an event are those of its organizer's organization; events whose
+/// organizer has none don't have any.
+#[derive(Clone)]
+pub struct EventFieldApplicationService {
+    field_repository: Arc<dyn EventFieldRepository>,
+    company_repository: Arc<dyn CompanyRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl EventFieldApplicationService {
+    pub const MAX_FIELDS: usize = 30;
+
+    pub fn new(
+        field_repository: Arc<dyn EventFieldRepository>,
+        company_repository: Arc<dyn CompanyRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            field_repository,
+            company_repository,
+            event_repository,
+            user_repository,
+        }
+    }
+
+    /// The company's fields in display order
+    pub async fn definitions(&self, company_id: Uuid) -> ApiResult<Vec<EventFieldDefinition>> {
+        self.get_company(company_id).await?;
+        self.company_definitions(company_id).await
+    }
+
+    pub async fn create_definition(
+        &self,
+        company_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        input: EventFieldInput,
+    ) -> ApiResult<EventFieldDefinition> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let key = event_fields::validate_key(&input.key)?;
+        let label = Self::validate_label(input.label)?;
+        let options = event_fields::validate_options(input.field_type, input.options)?;
+
+        let existing = self.company_definitions(company_id).await?;
+        if existing.len() >= Self::MAX_FIELDS {
+            return Err(ApiError::validation(
+                "key",
+                format!("An organization can have at most {} event fields", Self::MAX_FIELDS),
+            ));
+        }
+        if existing.iter().any(|definition| definition.key == key) {
+            return Err(ApiError::conflict(format!("The organization already has a field with key '{}'", key)));
+        }
+        let position = input
+            .position
+            .unwrap_or_else(|| existing.iter().map(|definition| definition.position + 1).max().unwrap_or(0));
+
+        let definition = EventFieldDefinition::new(
+            company_id,
+            key,
+            label,
+            input.field_type,
+            options,
+            input.is_required,
+            position,
+        );
+        self.field_repository
+            .create_definition(&definition)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(definition)
+    }
+
+    /// Changes the label, options, order and whether the field is required.
+    /// Values already saved keep an option that is taken away, until the
+    /// event's fields are next saved.
+    pub async fn update_definition(
+        &self,
+        company_id: Uuid,
+        field_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        input: EventFieldInput,
+    ) -> ApiResult<EventFieldDefinition> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let mut definition = self.get_definition(company_id, field_id).await?;
+        if input.key.trim() != definition.key {
+            return Err(ApiError::validation("key", "A field's key can't be changed"));
+        }
+        if input.field_type != definition.field_type {
+            return Err(ApiError::validation(
+                "field_type",
+                "A field's type can't be changed; add a new field instead",
+            ));
+        }
+
+        definition.label = Self::validate_label(input.label)?;
+        definition.options = event_fields::validate_options(definition.field_type, input.options)?;
+        definition.is_required = input.is_required;
+        definition.position = input.position.unwrap_or(definition.position);
+        definition.updated_at = chrono::Utc::now();
+        self.field_repository
+            .update_definition(&definition)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(definition)
+    }
+
+    /// Removes the field and every event's value for it
+    pub async fn delete_definition(&self, company_id: Uuid, field_id: Uuid, user: &User, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let definition = self.get_definition(company_id, field_id).await?;
+        self.field_repository
+            .delete_definition(definition.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// The event's fields and values; `viewer` is `None` for callers without a user record
+    pub async fn event_fields(&self, event_id: Uuid, viewer: Option<&User>, may_manage: bool) -> ApiResult<EventFields> {
+        let event = self.get_event(event_id).await?;
+        let company_id = self.organizer_company(&event).await?;
+        let definitions = match company_id {
+            Some(company_id) => self.company_definitions(company_id).await?,
+            None => Vec::new(),
+        };
+        let mut values: HashMap<Uuid, String> = self
+            .field_repository
+            .find_values(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|value| (value.field_id, value.value))
+            .collect();
+
+        Ok(EventFields {
+            event_id,
+            company_id,
+            fields: definitions
+                .into_iter()
+                .map(|definition| EventFieldEntry {
+                    value: values.remove(&definition.id),
+                    definition,
+                })
+                .collect(),
+            can_edit: viewer.is_some_and(|viewer| Self::is_organizer(&event, viewer.id, may_manage)),
+        })
+    }
+
+    /// Replaces the event's values with `values`, keyed by field key; fields
+    /// left out or blank are cleared
+    pub async fn update_event_fields(
+        &self,
+        event_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        values: HashMap<String, String>,
+    ) -> ApiResult<EventFields> {
+        let event = self.get_event(event_id).await?;
+        if !Self::is_organizer(&event, user.id, may_manage) {
+            return Err(ApiError::authorization("Only the event's organizers can set its fields"));
+        }
+        let definitions = match self.organizer_company(&event).await? {
+            Some(company_id) => self.company_definitions(company_id).await?,
+            None => Vec::new(),
+        };
+
+        if let Some(unknown) = values.keys().find(|key| !definitions.iter().any(|d| &d.key == *key)) {
+            return Err(ApiError::validation(
+                unknown.as_str(),
+                format!("The organizer's organization has no event field '{}'", unknown),
+            ));
+        }
+        let mut saved = Vec::new();
+        for definition in &definitions {
+            let value = match values.get(&definition.key) {
+                Some(raw) => event_fields::normalize_value(definition, raw)?,
+                None => None,
+            };
+            match value {
+                Some(value) => saved.push(EventFieldValue { field_id: definition.id, value }),
+                None if definition.is_required => {
+                    return Err(ApiError::validation(
+                        definition.key.as_str(),
+                        format!("{} is required", definition.label),
+                    ))
+                }
+                None => {}
+            }
+        }
+        self.field_repository
+            .replace_values(event_id, &saved)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        self.event_fields(event_id, Some(user), may_manage).await
+    }
+
+    /// Puts the list query's `field_value` in the field's canonical form, so it
+    /// compares equal to what was saved
+    pub async fn normalize_query(&self, mut query: ListEventsQuery) -> ApiResult<ListEventsQuery> {
+        if let (Some(field_id), Some(raw)) = (query.field_id, query.field_value.as_deref()) {
+            let definition = self
+                .field_repository
+                .find_definition_by_id(field_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .ok_or_else(|| ApiError::not_found(format!("Event field with ID {}", field_id)))?;
+            let value = event_fields::normalize_value(&definition, raw)?
+                .ok_or_else(|| ApiError::validation("field_value", "field_value can't be blank"))?;
+            query.field_value = Some(value);
+        }
+        Ok(query)
+    }
+
+    fn validate_label(label: String) -> ApiResult<String> {
+        optional_text("label", "Label", Some(label), event_fields::MAX_LABEL_CHARS)?
+            .ok_or_else(|| ApiError::validation("label", "Label is required"))
+    }
+
+    fn is_organizer(event: &Event, user_id: Uuid, may_manage: bool) -> bool {
+        may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)
+    }
+
+    async fn organizer_company(&self, event: &Event) -> ApiResult<Option<Uuid>> {
+        Ok(self
+            .user_repository
+            .find_by_id(event.organizer_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .and_then(|organizer| organizer.company_id))
+    }
+
+    async fn company_definitions(&self, company_id: Uuid) -> ApiResult<Vec<EventFieldDefinition>> {
+        self.field_repository
+            .find_definitions_by_company(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn get_managed_company(&self, company_id: Uuid, user: &User, may_manage: bool) -> ApiResult<Company> {
+        let company = self.get_company(company_id).await?;
+        if !SchedulingApplicationService::can_manage(company_id, user, may_manage) {
+            return Err(ApiError::authorization(
+                "Only the organization's organizers can manage its event fields",
+            ));
+        }
+        Ok(company)
+    }
+
+    async fn get_company(&self, company_id: Uuid) -> ApiResult<Company> {
+        self.company_repository
+            .find_by_id(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Company with ID {}", company_id)))
+    }
+
+    async fn get_definition(&self, company_id: Uuid, field_id: Uuid) -> ApiResult<EventFieldDefinition> {
+        self.field_repository
+            .find_definition_by_id(field_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|definition| definition.company_id == company_id)
+            .ok_or_else(|| ApiError::not_found(format!("Event field with ID {}", field_id)))
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+}
+
+// ============================================================================
+// Scheduling Application Service
+// ============================================================================
+
+/// Organization-wide blackout periods and scheduling policies, and the check
+/// run against them when events are created or moved. Rules follow the
+/// organizer's company; organizers without one aren't restricted.
+#[derive(Clone)]
+pub struct SchedulingApplicationService {
+    policy_repository: Arc<dyn SchedulingPolicyRepository>,
+    company_repository: Arc<dyn CompanyRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    scheduling_service: SchedulingService,
+}
+
+/// A scheduling policy as entered by the organization
+#[derive(Debug, Clone)]
+pub struct SchedulingPolicyInput {
+    pub max_events_per_day: Option<i32>,
+    pub allow_weekends: bool,
+    pub min_notice_days: Option<i32>,
+    pub enforcement: RuleEnforcement,
+}
+
+/// A blackout period as entered by the organization
+#[derive(Debug, Clone)]
+pub struct BlackoutInput {
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub enforcement: RuleEnforcement,
+}
+
+impl SchedulingApplicationService {
+    pub const MAX_NAME_CHARS: usize = 100;
+    pub const MAX_NOTICE_DAYS: i32 = 365;
+    // Same-day events counted against the daily limit; well above any sensible limit
+    const MAX_SAME_DAY_EVENTS: i64 = 1000;
+
+    pub fn new(
+        policy_repository: Arc<dyn SchedulingPolicyRepository>,
+        company_repository: Arc<dyn CompanyRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            policy_repository,
+            company_repository,
+            event_repository,
+            user_repository,
+            scheduling_service: SchedulingService::new(),
+        }
+    }
+
+    /// The company's policy, or one without limits when it hasn't set any
+    pub async fn policy(&self, company_id: Uuid) -> ApiResult<SchedulingPolicy> {
+        self.get_company(company_id).await?;
+        Ok(self
+            .policy_repository
+            .find_policy(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .unwrap_or_else(|| SchedulingPolicy::new(company_id)))
+    }
+
+    pub async fn update_policy(
+        &self,
+        company_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        input: SchedulingPolicyInput,
+    ) -> ApiResult<SchedulingPolicy> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        if input.max_events_per_day.is_some_and(|max| max < 1) {
+            return Err(ApiError::validation("max_events_per_day", "Events per day must be at least 1"));
+        }
+        if input.min_notice_days.is_some_and(|days| !(0..=Self::MAX_NOTICE_DAYS).contains(&days)) {
+            return Err(ApiError::validation(
+                "min_notice_days",
+                format!("Notice must be between 0 and {} days", Self::MAX_NOTICE_DAYS),
+            ));
+        }
+
+        let policy = SchedulingPolicy {
+            company_id,
+            max_events_per_day: input.max_events_per_day,
+            allow_weekends: input.allow_weekends,
+            min_notice_days: input.min_notice_days,
+            enforcement: input.enforcement,
+            updated_at: chrono::Utc::now(),
+        };
+        self.policy_repository
+            .save_policy(&policy)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(policy)
+    }
+
+    /// The company's blackout periods, earliest first
+    pub async fn blackouts(&self, company_id: Uuid) -> ApiResult<Vec<BlackoutPeriod>> {
+        self.get_company(company_id).await?;
+        self.policy_repository
+            .find_blackouts_by_company(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn add_blackout(
+        &self,
+        company_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        input: BlackoutInput,
+    ) -> ApiResult<BlackoutPeriod> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let input = Self::validate_blackout(input)?;
+
+        let blackout = BlackoutPeriod::new(
+            company_id,
+            input.name,
+            input.start_date,
+            input.end_date,
+            input.enforcement,
+            user.id,
+        );
+        self.policy_repository
+            .create_blackout(&blackout)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(blackout)
+    }
+
+    pub async fn update_blackout(
+        &self,
+        company_id: Uuid,
+        blackout_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        input: BlackoutInput,
+    ) -> ApiResult<BlackoutPeriod> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let mut blackout = self.get_blackout(company_id, blackout_id).await?;
+        let input = Self::validate_blackout(input)?;
+
+        blackout.name = input.name;
+        blackout.start_date = input.start_date;
+        blackout.end_date = input.end_date;
+        blackout.enforcement = input.enforcement;
+        blackout.updated_at = chrono::Utc::now();
+        self.policy_repository
+            .update_blackout(&blackout)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(blackout)
+    }
+
+    pub async fn delete_blackout(&self, company_id: Uuid, blackout_id: Uuid, user: &User, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let blackout = self.get_blackout(company_id, blackout_id).await?;
+        self.policy_repository
+            .delete_blackout(blackout.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Rules an event organized by `organizer_id` from `start` to `end` would break.
+    /// `event_id` is left out of the same-day count so moving an event doesn't count itself.
+    pub async fn check(
+        &self,
+        organizer_id: Uuid,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+        event_id: Option<Uuid>,
+    ) -> ApiResult<Vec<ScheduleConflict>> {
+        let organizer = self
+            .user_repository
+            .find_by_id(organizer_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let Some(company_id) = organizer.and_then(|organizer| organizer.company_id) else {
+            return Ok(Vec::new());
+        };
+
+        let first_day = start.date_naive();
+        let last_day = end.date_naive().max(first_day);
+        let policy = self
+            .policy_repository
+            .find_policy(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let blackouts = self
+            .policy_repository
+            .find_blackouts_overlapping(company_id, first_day, last_day)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let same_day_events = match policy.as_ref().and_then(|policy| policy.max_events_per_day) {
+            Some(_) => self.count_same_day_events(company_id, first_day, event_id).await?,
+            None => 0,
+        };
+
+        Ok(self.scheduling_service.check(
+            start,
+            end,
+            chrono::Utc::now(),
+            policy.as_ref(),
+            &blackouts,
+            same_day_events,
+        ))
+    }
+
+    /// Refuses the event when it breaks a blocking rule; otherwise returns the
+    /// rules it breaks as warnings
+    pub async fn enforce(&self, event: &Event, is_new: bool) -> ApiResult<Vec<ScheduleConflict>> {
+        let event_id = (!is_new).then_some(event.id);
+        let conflicts = self
+            .check(event.organizer_id, event.start_date, event.end_date, event_id)
+            .await?;
+
+        let blocking: Vec<&str> = conflicts
+            .iter()
+            .filter(|conflict| conflict.is_blocking())
+            .map(|conflict| conflict.message.as_str())
+            .collect();
+        if !blocking.is_empty() {
+            return Err(ApiError::Domain {
+                source: DomainError::business_rule(&format!(
+                    "The event can't be scheduled on these dates: {}",
+                    blocking.join("; ")
+                )),
+            });
+        }
+        Ok(conflicts)
+    }
+
+    // Events the company's members start on `day`, not counting cancelled ones
+    async fn count_same_day_events(&self, company_id: Uuid, day: NaiveDate, event_id: Option<Uuid>) -> ApiResult<usize> {
+        let day_start = day.and_time(chrono::NaiveTime::MIN).and_utc();
+        let filter = EventFilter {
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: None,
+            status: None,
+            location_type: None,
+            start_date_from: Some(day_start),
+            start_date_to: Some(day_start + chrono::Duration::days(1) - chrono::Duration::seconds(1)),
+            near: None,
+            organizer_company_id: Some(company_id),
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
+        };
+        let pagination = PaginationParams::new(0, Self::MAX_SAME_DAY_EVENTS)
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        Ok(self
+            .event_repository
+            .find_by_filter(&filter, pagination)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .items
+            .iter()
+            .filter(|event| Some(event.id) != event_id && event.status != EventStatus::Cancelled)
+            .count())
+    }
+
+    fn validate_blackout(input: BlackoutInput) -> ApiResult<BlackoutInput> {
+        let name = optional_text("name", "Name", Some(input.name), Self::MAX_NAME_CHARS)?
+            .ok_or_else(|| ApiError::validation("name", "Name is required"))?;
+        if input.end_date < input.start_date {
+            return Err(ApiError::validation("end_date", "The last day can't be before the first day"));
+        }
+        Ok(BlackoutInput { name, ..input })
+    }
+
+    // Administrators, and organizers belonging to the company
+    pub(crate) fn can_manage(company_id: Uuid, user: &User, may_manage: bool) -> bool {
+        role_permissions(user, may_manage).has_in(Permission::OrganizationManage, Some(company_id))
+    }
+
+    async fn get_managed_company(&self, company_id: Uuid, user: &User, may_manage: bool) -> ApiResult<Company> {
+        let company = self.get_company(company_id).await?;
+        if !Self::can_manage(company_id, user, may_manage) {
+            return Err(ApiError::authorization(
+                "Only the organization's organizers can manage its scheduling rules",
+            ));
+        }
+        Ok(company)
+    }
+
+    async fn get_company(&self, company_id: Uuid) -> ApiResult<Company> {
+        self.company_repository
+            .find_by_id(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Company with ID {}", company_id)))
+    }
+
+    async fn get_blackout(&self, company_id: Uuid, blackout_id: Uuid) -> ApiResult<BlackoutPeriod> {
+        self.policy_repository
+            .find_blackout_by_id(blackout_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|blackout| blackout.company_id == company_id)
+            .ok_or_else(|| ApiError::not_found(format!("Blackout period with ID {}", blackout_id)))
+    }
+}
+
+// ============================================================================
+// Storage Quota Application Service
+// ============================================================================
+
+/// An organization's media storage: what its events hold and what they may hold
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StorageReport {
+    pub company_id: Uuid,
+    pub usage: StorageUsage,
+    /// `None` when the organization's uploads are limited per file only
+    pub quota_bytes: Option<i64>,
+    /// Set by an administrator rather than the platform default
+    pub custom_quota: bool,
+}
+
+impl StorageReport {
+    pub fn remaining_bytes(&self) -> Option<i64> {
+        self.quota_bytes.map(|quota| (quota - self.usage.total_bytes()).max(0))
+    }
+}
+
+/// Per-organization quotas on uploaded media (attachments, receipts and photos), so one
+/// organization can't fill the disk. An event counts against its organizer's
+/// organization; organizers without one are only held to the per-file limits.
+#[derive(Clone)]
+pub struct StorageQuotaApplicationService {
+    quota_repository: Arc<dyn StorageQuotaRepository>,
+    company_repository: Arc<dyn CompanyRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    default_quota_bytes: Option<i64>,
+}
+
+impl StorageQuotaApplicationService {
+    pub const DEFAULT_QUOTA_BYTES: i64 = 5 * 1024 * 1024 * 1024;
+
+    pub fn new(
+        quota_repository: Arc<dyn StorageQuotaRepository>,
+        company_repository: Arc<dyn CompanyRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            quota_repository,
+            company_repository,
+            user_repository,
+            default_quota_bytes: Some(Self::DEFAULT_QUOTA_BYTES),
+        }
+    }
+
+    /// The quota of organizations without one of their own; `None` lifts it
+    pub fn with_default_quota(mut self, quota_bytes: Option<i64>) -> Self {
+        self.default_quota_bytes = quota_bytes;
+        self
+    }
+
+    pub async fn report(&self, company_id: Uuid, user: &User, may_manage: bool) -> ApiResult<StorageReport> {
+        self.get_company(company_id).await?;
+        if !SchedulingApplicationService::can_manage(company_id, user, may_manage) {
+            return Err(ApiError::authorization(
+                "Only the organization's organizers can see its storage use",
+            ));
+        }
+        self.build(company_id).await
+    }
+
+    /// Gives the organization its own quota, or returns it to the default with
+    /// `None`. Returns the report before and after.
+    pub async fn set_quota(
+        &self,
+        company_id: Uuid,
+        quota_bytes: Option<i64>,
+        may_manage: bool,
+    ) -> ApiResult<(StorageReport, StorageReport)> {
+        if !may_manage {
+            return Err(ApiError::authorization("Only administrators can change storage quotas"));
+        }
+        if quota_bytes.is_some_and(|quota| quota < 0) {
+            return Err(ApiError::validation("quota_bytes", "Must be zero or more"));
+        }
+        self.get_company(company_id).await?;
+
+        let before = self.build(company_id).await?;
+        self.quota_repository
+            .save_quota(company_id, quota_bytes)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let after = StorageReport {
+            quota_bytes: quota_bytes.or(self.default_quota_bytes),
+            custom_quota: quota_bytes.is_some(),
+            ..before.clone()
+        };
+        Ok((before, after))
+    }
+
+    /// Refuses an upload of `added` bytes to the event when it would take the
+    /// organization over its quota; `replaced` is the size of a file the upload
+    /// takes the place of.
+    pub async fn check_upload(&self, event: &Event, added: usize, replaced: i64) -> ApiResult<()> {
+        let organizer = self
+            .user_repository
+            .find_by_id(event.organizer_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let Some(company_id) = organizer.and_then(|organizer| organizer.company_id) else {
+            return Ok(());
+        };
+
+        let report = self.build(company_id).await?;
+        let Some(quota) = report.quota_bytes else {
+            return Ok(());
+        };
+        let used = report.usage.total_bytes();
+        if used - replaced + added as i64 > quota {
+            return Err(ApiError::insufficient_storage(format!(
+                "The organization has used {} of its {} storage quota; remove files or ask an administrator for more",
+                megabytes(used),
+                megabytes(quota)
+            )));
+        }
+        Ok(())
+    }
+
+    async fn build(&self, company_id: Uuid) -> ApiResult<StorageReport> {
+        let usage = self
+            .quota_repository
+            .usage_by_company(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let custom = self
+            .quota_repository
+            .find_quota(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(StorageReport {
+            company_id,
+            usage,
+            quota_bytes: custom.or(self.default_quota_bytes),
+            custom_quota: custom.is_some(),
+        })
+    }
+
+    async fn get_company(&self, company_id: Uuid) -> ApiResult<Company> {
+        self.company_repository
+            .find_by_id(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Company with ID {}", company_id)))
+    }
+}
+
+fn megabytes(bytes: i64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+// ============================================================================
+// Publishing Application Service
+// ============================================================================
+
+/// Publishes drafts once their `publish_at` has passed. Run periodically by the
+/// scheduler; organizers and invited users are notified, and any configured
+/// webhooks are called for each published event.
+#[derive(Clone)]
+pub struct PublishingApplicationService {
+    event_repository: Arc<dyn EventRepository>,
+    invitation_repository: Arc<dyn EventInvitationRepository>,
+    notifier: RegistrationNotifier,
+    webhooks: Option<Arc<dyn EventWebhooks>>,
+}
+
+impl PublishingApplicationService {
+    pub fn new(
+        event_repository: Arc<dyn EventRepository>,
+        invitation_repository: Arc<dyn EventInvitationRepository>,
+        notifier: RegistrationNotifier,
+    ) -> Self {
+        Self {
+            event_repository,
+            invitation_repository,
+            notifier,
+            webhooks: None,
+        }
+    }
+
+    pub fn with_webhooks(mut self, webhooks: Arc<dyn EventWebhooks>) -> Self {
+        self.webhooks = Some(webhooks);
+        self
+    }
+
+    /// Publishes every draft due at `now` and returns them
+    pub async fn publish_due(&self, now: chrono::DateTime<chrono::Utc>) -> ApiResult<Vec<Event>> {
+        let due = self
+            .event_repository
+            .find_due_for_publishing(now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut published = Vec::with_capacity(due.len());
+        for mut event in due {
+            event.status = EventStatus::Published;
+            event.updated_at = now;
+            self.event_repository
+                .update(&event)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+
+            self.notify_published(&event).await;
+            published.push(event);
+        }
+        Ok(published)
+    }
+
+    // Best effort: the event is already published, so failures are only logged
+    async fn notify_published(&self, event: &Event) {
+        let mut recipients: Vec<Uuid> = std::iter::once(event.organizer_id)
+            .chain(event.co_organizers.iter().copied())
+            .collect();
+        match self.invitation_repository.find_by_event_id(event.id).await {
+            Ok(invitations) => recipients.extend(invitations.iter().filter_map(|invitation| invitation.invited_user_id)),
+            Err(e) => tracing::warn!("Could not load invitations for published event {}: {}", event.id, e),
+        }
+
+        let mut notified = HashSet::new();
+        for user_id in recipients {
+            if notified.insert(user_id) {
+                self.notifier
+                    .notify(user_id, RegistrationNotification::EventPublished { event_id: event.id });
+            }
+        }
+
+        if let Some(webhooks) = &self.webhooks {
+            if let Err(e) = webhooks.event_published(event).await {
+                tracing::warn!("Publish webhook failed for event {}: {}", event.id, e);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Sending Domain Application Service
+// ============================================================================
+
+/// Organizations' own email sending domains: configuration, the DNS records
+/// they publish and the check of those records. Invitations are sent from the
+/// domain only while its records verify.
+#[derive(Clone)]
+pub struct SendingDomainApplicationService {
+    sending_domain_repository: Arc<dyn SendingDomainRepository>,
+    company_repository: Arc<dyn CompanyRepository>,
+    dns_resolver: Arc<dyn DnsResolver>,
+    platform: MailPlatform,
+    sending_domain_service: SendingDomainService,
+}
+
+/// A sending domain as entered by the organization
+#[derive(Debug, Clone)]
+pub struct SendingDomainInput {
+    pub domain: String,
+    pub from_email: String,
+    pub from_name: Option<String>,
+    pub dkim_selector: Option<String>,
+}
+
+impl SendingDomainApplicationService {
+    pub const MAX_FROM_NAME_CHARS: usize = 100;
+
+    pub fn new(
+        sending_domain_repository: Arc<dyn SendingDomainRepository>,
+        company_repository: Arc<dyn CompanyRepository>,
+        dns_resolver: Arc<dyn DnsResolver>,
+        platform: MailPlatform,
+    ) -> Self {
+        Self {
+            sending_domain_repository,
+            company_repository,
+            dns_resolver,
+            platform,
+            sending_domain_service: SendingDomainService::new(),
+        }
+    }
+
+    pub fn platform(&self) -> &MailPlatform {
+        &self.platform
+    }
+
+    pub async fn sending_domain(&self, company_id: Uuid, user: &User, may_manage: bool) -> ApiResult<SendingDomain> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        self.find(company_id)
+            .await?
+            .ok_or_else(|| ApiError::not_found(format!("Sending domain for company {}", company_id)))
+    }
+
+    /// Sets the company's sending domain. Changing the domain or DKIM selector
+    /// starts verification over; changing only the sender keeps it.
+    pub async fn configure(
+        &self,
+        company_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        input: SendingDomainInput,
+    ) -> ApiResult<SendingDomain> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let domain = input.domain.trim().trim_end_matches('.').to_lowercase();
+        let from_email = input.from_email.trim().to_lowercase();
+        let from_name = optional_text("from_name", "Sender name", input.from_name, Self::MAX_FROM_NAME_CHARS)?;
+        let dkim_selector = input
+            .dkim_selector
+            .map(|selector| selector.trim().to_lowercase())
+            .filter(|selector| !selector.is_empty())
+            .unwrap_or_else(|| SendingDomain::DEFAULT_DKIM_SELECTOR.to_string());
+
+        let sending_domain = match self.find(company_id).await? {
+            Some(mut existing) if existing.domain == domain && existing.dkim_selector == dkim_selector => {
+                existing.from_email = from_email;
+                existing.from_name = from_name;
+                existing.updated_at = chrono::Utc::now();
+                existing
+            }
+            existing => {
+                let mut sending_domain = SendingDomain::new(company_id, domain, from_email, from_name, dkim_selector);
+                if let Some(existing) = existing {
+                    sending_domain.created_at = existing.created_at;
+                }
+                sending_domain
+            }
+        };
+        self.sending_domain_service
+            .validate(&sending_domain)
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        self.save(&sending_domain).await?;
+        Ok(sending_domain)
+    }
+
+    /// Looks up the required records and records which of them were found
+    pub async fn check(&self, company_id: Uuid, user: &User, may_manage: bool) -> ApiResult<SendingDomain> {
+        let mut sending_domain = self.sending_domain(company_id, user, may_manage).await?;
+
+        let lookup = SendingDomainLookup {
+            verification_txt: self.lookup(&sending_domain.verification_record_name(), DnsRecordType::Txt).await?,
+            domain_txt: self.lookup(&sending_domain.domain, DnsRecordType::Txt).await?,
+            dkim_cname: self.lookup(&sending_domain.dkim_record_name(), DnsRecordType::Cname).await?,
+        };
+        self.sending_domain_service
+            .apply_lookup(&mut sending_domain, &self.platform, &lookup, chrono::Utc::now());
+
+        self.save(&sending_domain).await?;
+        Ok(sending_domain)
+    }
+
+    pub async fn remove(&self, company_id: Uuid, user: &User, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        self.sending_domain_repository
+            .delete(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// The verified sending domain invitations from the company go out from;
+    /// `None` means the shared platform sender is used
+    pub async fn sender(&self, company_id: Uuid) -> ApiResult<Option<SendingDomain>> {
+        Ok(self.find(company_id).await?.filter(SendingDomain::is_verified))
+    }
+
+    async fn lookup(&self, name: &str, record_type: DnsRecordType) -> ApiResult<Vec<String>> {
+        self.dns_resolver
+            .lookup(name, record_type)
+            .await
+            .map_err(|e| ApiError::external_service("DNS", e.to_string()))
+    }
+
+    async fn find(&self, company_id: Uuid) -> ApiResult<Option<SendingDomain>> {
+        self.sending_domain_repository
+            .find_by_company(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn save(&self, sending_domain: &SendingDomain) -> ApiResult<()> {
+        self.sending_domain_repository
+            .save(sending_domain)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn get_managed_company(&self, company_id: Uuid, user: &User, may_manage: bool) -> ApiResult<Company> {
+        let company = self
+            .company_repository
+            .find_by_id(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Company with ID {}", company_id)))?;
+        if !SchedulingApplicationService::can_manage(company_id, user, may_manage) {
+            return Err(ApiError::authorization(
+                "Only the organization's organizers can manage its sending domain",
+            ));
+        }
+        Ok(company)
+    }
+}
+
+// ============================================================================
+// Email Delivery Application Service
+// ============================================================================
+
+/// A bounce or spam complaint the mail provider reported for an address
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryReport {
+    pub email: String,
+    pub failure: DeliveryFailure,
+    pub detail: Option<String>,
+}
+
+/// What a batch of delivery reports changed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeliveryReportOutcome {
+    /// Addresses that weren't suppressed before
+    pub suppressed: usize,
+    pub invitations_marked: usize,
+}
+
+#[derive(Clone)]
+pub struct EmailDeliveryApplicationService {
+    suppression_repository: Arc<dyn EmailSuppressionRepository>,
+    invitation_repository: Arc<dyn EventInvitationRepository>,
+    /// Token the provider's webhook URLs carry; without one the webhooks are off
+    webhook_secret: Option<String>,
+}
+
+impl EmailDeliveryApplicationService {
+    pub fn new(
+        suppression_repository: Arc<dyn EmailSuppressionRepository>,
+        invitation_repository: Arc<dyn EventInvitationRepository>,
+        webhook_secret: Option<String>,
+    ) -> Self {
+        Self {
+            suppression_repository,
+            invitation_repository,
+            webhook_secret: webhook_secret.filter(|secret| !secret.is_empty()),
+        }
+    }
+
+    pub fn verify_webhook_token(&self, token: Option<&str>) -> ApiResult<()> {
+        let Some(secret) = &self.webhook_secret else {
+            return Err(ApiError::not_found("Email event webhooks"));
+        };
+        match token {
+            Some(token) if constant_time_eq(token.as_bytes(), secret.as_bytes()) => Ok(()),
+            _ => Err(ApiError::authentication("Invalid webhook token")),
+        }
+    }
+
+    /// Suppresses the reported addresses and marks the invitation emails sent
+    /// to them as undeliverable
+    pub async fn record_reports(&self, provider: &str, reports: &[DeliveryReport]) -> ApiResult<DeliveryReportOutcome> {
+        let service = InvitationService::new();
+        let now = chrono::Utc::now();
+        let mut outcome = DeliveryReportOutcome::default();
+
+        for report in reports {
+            let suppression = EmailSuppression::new(&report.email, report.failure, provider, report.detail.clone());
+            if suppression.email.is_empty() {
+                continue;
+            }
+            if self.suppression(&suppression.email).await?.is_none() {
+                outcome.suppressed += 1;
+            }
+            self.suppression_repository
+                .save(&suppression)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+
+            for mut invitation in self.invitations_to(&suppression.email).await? {
+                if service.mark_as_undeliverable(&mut invitation, report.failure, now) {
+                    self.update_invitation(&invitation).await?;
+                    outcome.invitations_marked += 1;
+                }
+            }
+        }
+
+        tracing::info!(
+            "{} delivery report(s) from {}: {} address(es) suppressed, {} invitation(s) marked undeliverable",
+            reports.len(),
+            provider,
+            outcome.suppressed,
+            outcome.invitations_marked
+        );
+        Ok(outcome)
+    }
+
+    pub async fn suppression(&self, email: &str) -> ApiResult<Option<EmailSuppression>> {
+        self.suppression_repository
+            .find_by_email(email)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn suppressions(&self) -> ApiResult<Vec<EmailSuppression>> {
+        self.suppression_repository
+            .find_all()
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Lets email go to the address again, e.g. once its mailbox is fixed;
+    /// its undeliverable invitations can be resent
+    pub async fn lift_suppression(&self, email: &str) -> ApiResult<()> {
+        let email = email.trim().to_lowercase();
+        self.suppression_repository
+            .delete(&email)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        for mut invitation in self.invitations_to(&email).await? {
+            if invitation.delivery_failure.take().is_some() {
+                invitation.delivery_failed_at = None;
+                invitation.updated_at = chrono::Utc::now();
+                self.update_invitation(&invitation).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn invitations_to(&self, email: &str) -> ApiResult<Vec<EventInvitation>> {
+        self.invitation_repository
+            .find_by_email(email)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn update_invitation(&self, invitation: &EventInvitation) -> ApiResult<()> {
+        self.invitation_repository
+            .update(invitation)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+}
+
+// Compares without an early exit, so the webhook token can't be guessed byte by byte
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+// ============================================================================
+// Notification Digest Application Service
+// ============================================================================
+
+/// Holds non-urgent notifications back for users who chose a daily or weekly
+/// digest, and emails each of them one summary when it is due. Everyone keeps
+/// getting notifications live; the digest only replaces the email.
+#[derive(Clone)]
+pub struct NotificationDigestApplicationService {
+    digest_repository: Arc<dyn NotificationDigestRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    mailer: Arc<dyn Mailer>,
+    suppression_repository: Option<Arc<dyn EmailSuppressionRepository>>,
+    public_url: String,
+}
+
+impl NotificationDigestApplicationService {
+    pub fn new(
+        digest_repository: Arc<dyn NotificationDigestRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        mailer: Arc<dyn Mailer>,
+        public_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            digest_repository,
+            user_repository,
+            event_repository,
+            mailer,
+            suppression_repository: None,
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Digests aren't sent to addresses that bounced or complained
+    pub fn with_suppressions(mut self, suppression_repository: Arc<dyn EmailSuppressionRepository>) -> Self {
+        self.suppression_repository = Some(suppression_repository);
+        self
+    }
+
+    pub async fn preferences(&self, user_id: Uuid) -> ApiResult<NotificationPreferences> {
+        Ok(self
+            .digest_repository
+            .find_preferences(user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .unwrap_or_else(|| NotificationPreferences::new(user_id)))
+    }
+
+    /// Switching to a digest starts its first period now. Switching back to
+    /// `Immediate` drops whatever was waiting, as it was already shown live.
+    pub async fn update_preferences(&self, user_id: Uuid, frequency: DigestFrequency) -> ApiResult<NotificationPreferences> {
+        let mut preferences = self.preferences(user_id).await?;
+        let now = chrono::Utc::now();
+        if preferences.digest_frequency == DigestFrequency::Immediate && frequency != DigestFrequency::Immediate {
+            preferences.last_digest_at = Some(now);
+        }
+        if frequency == DigestFrequency::Immediate {
+            let pending: Vec<Uuid> = self.pending_items(user_id).await?.iter().map(|item| item.id).collect();
+            self.delete_items(&pending).await?;
+        }
+        preferences.digest_frequency = frequency;
+        preferences.updated_at = now;
+
+        self.save_preferences(&preferences).await?;
+        Ok(preferences)
+    }
+
+    /// Queues the notification for the user's next digest; `false` when it
+    /// is urgent, was emailed already or the user doesn't get digests
+    pub async fn queue(&self, notification: &AddressedNotification) -> ApiResult<bool> {
+        if notification.notification.is_urgent() || notification.notification.is_emailed() {
+            return Ok(false);
+        }
+        let preferences = self.preferences(notification.user_id).await?;
+        if preferences.digest_frequency == DigestFrequency::Immediate {
+            return Ok(false);
+        }
+
+        let event_id = notification.notification.event_id();
+        let event_title = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .map(|event| event.title);
+        let item = DigestItem::new(
+            notification.user_id,
+            Some(event_id),
+            event_title,
+            notification.notification.event_name(),
+            notification.notification.digest_message(),
+        );
+        self.digest_repository
+            .add_item(&item)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(true)
+    }
+
+    /// Sends every digest due at `now` and returns how many went out. A
+    /// failing digest is logged and retried on the next run.
+    pub async fn send_due(&self, now: chrono::DateTime<chrono::Utc>) -> ApiResult<usize> {
+        let service = NotificationDigestService::new();
+        let subscribers = self
+            .digest_repository
+            .find_digest_subscribers()
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut sent = 0;
+        for preferences in subscribers.into_iter().filter(|preferences| service.is_due(preferences, now)) {
+            match self.send_digest(preferences, now).await {
+                Ok(true) => sent += 1,
+                Ok(false) => {}
+                Err(e) => tracing::warn!("Notification digest failed: {}", e),
+            }
+        }
+        Ok(sent)
+    }
+
+    // Compiles and mails one user's digest; a period without items sends nothing
+    async fn send_digest(&self, mut preferences: NotificationPreferences, now: chrono::DateTime<chrono::Utc>) -> ApiResult<bool> {
+        let items = self.pending_items(preferences.user_id).await?;
+        let ids: Vec<Uuid> = items.iter().map(|item| item.id).collect();
+        let mut sent = false;
+
+        if let Some(digest) = NotificationDigestService::new().compile(&preferences, items) {
+            let user = self
+                .user_repository
+                .find_by_id(preferences.user_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .ok_or_else(|| ApiError::not_found("User"))?;
+
+            if self.is_suppressed(&user.email).await? {
+                tracing::info!("Skipping digest for {}: the address is suppressed", user.id);
+            } else {
+                let email = render_digest(
+                    &digest,
+                    &user.email,
+                    &user.name,
+                    &self.public_url,
+                    &Localization::for_user(&user),
+                );
+                self.mailer
+                    .send(&email)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                sent = true;
+            }
+            self.delete_items(&ids).await?;
+        }
+
+        preferences.last_digest_at = Some(now);
+        self.save_preferences(&preferences).await?;
+        Ok(sent)
+    }
+
+    async fn pending_items(&self, user_id: Uuid) -> ApiResult<Vec<DigestItem>> {
+        self.digest_repository
+            .find_pending_items(user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn delete_items(&self, ids: &[Uuid]) -> ApiResult<()> {
+        self.digest_repository
+            .delete_items(ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn save_preferences(&self, preferences: &NotificationPreferences) -> ApiResult<()> {
+        self.digest_repository
+            .save_preferences(preferences)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn is_suppressed(&self, email: &str) -> ApiResult<bool> {
+        let Some(suppressions) = &self.suppression_repository else {
+            return Ok(false);
+        };
+        suppressions
+            .find_by_email(email)
+            .await
+            .map(|suppression| suppression.is_some())
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+}
+
+// ============================================================================
+// Organization Invitation Application Service
+// ============================================================================
+
+/// An organization invitation with the name of the company it is for
+#[derive(Debug, Clone)]
+pub struct OrganizationInvitationDetails {
+    pub invitation: OrganizationInvitation,
+    pub company_name: String,
+}
+
+/// Invitations for colleagues to join a company. The invitee gets an email
+/// with a join link; accepting it after signing in creates their account if
+/// this is their first login, adds them to the company and gives them the
+/// invited role.
+#[derive(Clone)]
+pub struct OrganizationInvitationApplicationService {
+    invitation_repository: Arc<dyn OrganizationInvitationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    company_repository: Arc<dyn CompanyRepository>,
+    mailer: Arc<dyn Mailer>,
+    suppression_repository: Option<Arc<dyn EmailSuppressionRepository>>,
+    public_url: String,
+}
+
+impl OrganizationInvitationApplicationService {
+    pub fn new(
+        invitation_repository: Arc<dyn OrganizationInvitationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        company_repository: Arc<dyn CompanyRepository>,
+        mailer: Arc<dyn Mailer>,
+        public_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            invitation_repository,
+            user_repository,
+            company_repository,
+            mailer,
+            suppression_repository: None,
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Invitations aren't sent to addresses that bounced or complained
+    pub fn with_suppressions(mut self, suppression_repository: Arc<dyn EmailSuppressionRepository>) -> Self {
+        self.suppression_repository = Some(suppression_repository);
+        self
+    }
+
+    /// Invites `email` to the company as a participant unless another role is
+    /// given. An earlier pending invitation to the same address is replaced.
+    pub async fn invite(
+        &self,
+        company_id: Uuid,
+        inviter: &User,
+        may_manage: bool,
+        email: &str,
+        role: Option<UserRole>,
+        from: Option<EmailSender>,
+    ) -> ApiResult<OrganizationInvitationDetails> {
+        let company = self.get_company(company_id).await?;
+        if !SchedulingApplicationService::can_manage(company_id, inviter, may_manage) {
+            return Err(ApiError::authorization(
+                "Only the organization's organizers can invite people to it",
+            ));
+        }
+        let email = email.trim().to_lowercase();
+        if !email.contains('@') {
+            return Err(ApiError::validation("email", "Invalid email format"));
+        }
+        let role = role.unwrap_or(UserRole::Participant);
+        if matches!(role, UserRole::Admin) {
+            return Err(ApiError::validation("role", "Invitations can make people participants or organizers"));
+        }
+        let existing = self
+            .user_repository
+            .find_by_email(&email)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if existing.is_some_and(|user| user.company_id == Some(company_id)) {
+            return Err(ApiError::bad_request(format!("{} is already a member of {}", email, company.name)));
+        }
+        if self.is_suppressed(&email).await? {
+            return Err(ApiError::bad_request(format!(
+                "Emails to {} bounced or were reported as spam, so no invitation can be sent",
+                email
+            )));
+        }
+
+        for mut earlier in self.pending_for(&email).await? {
+            if earlier.company_id == company_id {
+                earlier.status = OrganizationInvitationStatus::Revoked;
+                self.update(&earlier).await?;
+            }
+        }
+        let invitation = OrganizationInvitation::new(company_id, &email, role, Some(inviter.id));
+        self.invitation_repository
+            .create(&invitation)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let message = EmailMessage {
+            from,
+            ..render_organization_invitation(
+                &invitation,
+                &company.name,
+                &inviter.name,
+                &self.public_url,
+                &Localization::for_user(inviter),
+            )
+        };
+        self.mailer
+            .send(&message)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        Ok(OrganizationInvitationDetails {
+            invitation,
+            company_name: company.name,
+        })
+    }
+
+    /// The invitation behind a join link, for the page shown before signing in
+    pub async fn preview(&self, token: &str) -> ApiResult<OrganizationInvitationDetails> {
+        let invitation = self.get_by_token(token).await?;
+        let company = self.get_company(invitation.company_id).await?;
+        Ok(OrganizationInvitationDetails {
+            invitation,
+            company_name: company.name,
+        })
+    }
+
+    /// Accepts the invitation for the signed-in user, creating their account on
+    /// first login. Members keep a higher role than the invited one.
+    pub async fn accept(&self, token: &str, keycloak_id: &str, email: &str, name: &str) -> ApiResult<User> {
+        let mut invitation = self.get_by_token(token).await?;
+        let now = chrono::Utc::now();
+        if !invitation.is_open(now) {
+            return Err(ApiError::bad_request(match invitation.status {
+                OrganizationInvitationStatus::Accepted => "This invitation has already been used",
+                OrganizationInvitationStatus::Revoked => "This invitation has been withdrawn",
+                OrganizationInvitationStatus::Pending => "This invitation has expired; ask for a new one",
+            }));
+        }
+        if !invitation.email.eq_ignore_ascii_case(email.trim()) {
+            return Err(ApiError::authorization(
+                "This invitation was sent to another email address",
+            ));
+        }
+
+        let existing = self
+            .user_repository
+            .find_by_keycloak_id(keycloak_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let user = match existing {
+            Some(mut user) => {
+                if user.company_id.is_some_and(|company_id| company_id != invitation.company_id) {
+                    return Err(ApiError::bad_request(
+                        "You already belong to another organization",
+                    ));
+                }
+                user.company_id = Some(invitation.company_id);
+                user.role = Self::higher_role(&user.role, &invitation.role);
+                user.updated_at = now;
+                self.user_repository
+                    .update(&user)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                user
+            }
+            None => {
+                let user = User {
+                    id: Uuid::new_v4(),
+                    keycloak_id: keycloak_id.to_string(),
+                    email: invitation.email.clone(),
+                    name: Some(name.trim()).filter(|name| !name.is_empty()).unwrap_or(&invitation.email).to_string(),
+                    company_id: Some(invitation.company_id),
+                    role: invitation.role.clone(),
+                    is_active: true,
+                    timezone: None,
+                    locale: None,
+                    theme: None,
+                    calendar_view: None,
+                    created_at: now,
+                    updated_at: now,
+                };
+                self.user_repository
+                    .create(&user)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                user
+            }
+        };
+
+        invitation.accept(user.id, now);
+        self.update(&invitation).await?;
+        Ok(user)
+    }
+
+    /// Pending invitations, newest first, optionally for one company
+    pub async fn list_pending(&self, company_id: Option<Uuid>) -> ApiResult<Vec<OrganizationInvitationDetails>> {
+        let invitations = self
+            .invitation_repository
+            .find_pending(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut company_names: HashMap<Uuid, String> = HashMap::new();
+        let mut details = Vec::with_capacity(invitations.len());
+        for invitation in invitations {
+            if !company_names.contains_key(&invitation.company_id) {
+                let name = self
+                    .company_repository
+                    .find_by_id(invitation.company_id)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?
+                    .map(|company| company.name)
+                    .unwrap_or_default();
+                company_names.insert(invitation.company_id, name);
+            }
+            details.push(OrganizationInvitationDetails {
+                company_name: company_names[&invitation.company_id].clone(),
+                invitation,
+            });
+        }
+        Ok(details)
+    }
+
+    /// Withdraws a pending invitation so its link stops working
+    pub async fn revoke(&self, invitation_id: Uuid) -> ApiResult<OrganizationInvitation> {
+        let mut invitation = self
+            .invitation_repository
+            .find_by_id(invitation_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Organization invitation with ID {}", invitation_id)))?;
+        if invitation.status != OrganizationInvitationStatus::Pending {
+            return Err(ApiError::bad_request("Only pending invitations can be withdrawn"));
+        }
+        invitation.status = OrganizationInvitationStatus::Revoked;
+        self.update(&invitation).await?;
+        Ok(invitation)
+    }
+
+    fn higher_role(current: &UserRole, invited: &UserRole) -> UserRole {
+        let rank = |role: &UserRole| match role {
+            UserRole::Participant => 0,
+            UserRole::Organizer => 1,
+            UserRole::Admin => 2,
+        };
+        if rank(invited) > rank(current) { invited.clone() } else { current.clone() }
+    }
+
+    async fn get_by_token(&self, token: &str) -> ApiResult<OrganizationInvitation> {
+        self.invitation_repository
+            .find_by_token(token)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found("Organization invitation"))
+    }
+
+    async fn get_company(&self, company_id: Uuid) -> ApiResult<Company> {
+        self.company_repository
+            .find_by_id(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Company with ID {}", company_id)))
+    }
+
+    async fn pending_for(&self, email: &str) -> ApiResult<Vec<OrganizationInvitation>> {
+        self.invitation_repository
+            .find_pending_by_email(email)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn update(&self, invitation: &OrganizationInvitation) -> ApiResult<()> {
+        self.invitation_repository
+            .update(invitation)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn is_suppressed(&self, email: &str) -> ApiResult<bool> {
+        let Some(suppressions) = &self.suppression_repository else {
+            return Ok(false);
+        };
+        suppressions
+            .find_by_email(email)
+            .await
+            .map(|suppression| suppression.is_some())
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+}
+
+// ============================================================================
+// Permission Application Service
+// ============================================================================
+
+/// A custom role as entered by the organization
+#[derive(Debug, Clone)]
+pub struct OrganizationRoleInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub permissions: Vec<Permission>,
+}
+
+/// Time-bound access to an event, as entered by its organizer
+#[derive(Debug, Clone)]
+pub struct AccessGrantInput {
+    pub user_id: Uuid,
+    pub permissions: Vec<Permission>,
+    /// Defaults to now
+    pub starts_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Works out what users may do, lets organizations define their own roles
+/// on top of the platform ones, and lets organizers delegate access to an
+/// event for a limited time
+#[derive(Clone)]
+pub struct PermissionApplicationService {
+    role_repository: Arc<dyn OrganizationRoleRepository>,
+    grant_repository: Arc<dyn AccessGrantRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    company_repository: Arc<dyn CompanyRepository>,
+    event_repository: Arc<dyn EventRepository>,
+}
+
+impl PermissionApplicationService {
+    pub const MAX_NAME_CHARS: usize = 60;
+    pub const MAX_DESCRIPTION_CHARS: usize = 300;
+    /// Longest an access grant may last; anything longer calls for a role
+    pub const MAX_GRANT_DAYS: i64 = 31;
+
+    pub fn new(
+        role_repository: Arc<dyn OrganizationRoleRepository>,
+        grant_repository: Arc<dyn AccessGrantRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        company_repository: Arc<dyn CompanyRepository>,
+        event_repository: Arc<dyn EventRepository>,
+    ) -> Self {
+        Self {
+            role_repository,
+            grant_repository,
+            user_repository,
+            company_repository,
+            event_repository,
+        }
+    }
+
+    /// Permissions of `token_roles` and, for a user with an account, of their
+    /// stored role, their organization's roles they hold and their access
+    /// grants that hold right now
+    pub async fn checker(&self, user: Option<&User>, token_roles: &[UserRole]) -> ApiResult<PermissionChecker> {
+        let Some(user) = user else {
+            return Ok(PermissionChecker::for_roles(token_roles));
+        };
+        let custom_roles = self
+            .role_repository
+            .find_by_user(user.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let now = chrono::Utc::now();
+        let grants = self
+            .grant_repository
+            .find_active_for_user(user.id, now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(PermissionChecker::for_user(user, &custom_roles)
+            .with_roles(token_roles)
+            .with_grants(&grants, now))
+    }
+
+    /// The organization an event belongs to: its organizer's
+    pub async fn event_company(&self, event_id: Uuid) -> ApiResult<Option<Uuid>> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        let organizer = self
+            .user_repository
+            .find_by_id(event.organizer_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(organizer.and_then(|organizer| organizer.company_id))
+    }
+
+    /// Whether `checker` allows this for the event: anywhere, through a
+    /// role of the organization running it, or through an access grant
+    pub async fn allows_for_event(
+        &self,
+        checker: &PermissionChecker,
+        permission: Permission,
+        event_id: Uuid,
+    ) -> ApiResult<bool> {
+        if checker.has(permission) || checker.has_for_event(permission, event_id) {
+            return Ok(true);
+        }
+        let company_id = self.event_company(event_id).await?;
+        Ok(checker.has_in(permission, company_id))
+    }
+
+    /// Whether `checker` lets the user manage the event without organizing it
+    pub async fn manages_event(&self, checker: &PermissionChecker, event_id: Uuid) -> ApiResult<bool> {
+        self.allows_for_event(checker, Permission::EventManage, event_id).await
+    }
+
+    pub async fn roles(&self, company_id: Uuid, user: &User, may_manage: bool) -> ApiResult<Vec<OrganizationRole>> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        self.role_repository
+            .find_by_company(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn create_role(
+        &self,
+        company_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        input: OrganizationRoleInput,
+    ) -> ApiResult<OrganizationRole> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let input = Self::validate_role(input)?;
+
+        let role = OrganizationRole::new(
+            company_id,
+            &input.name,
+            input.description,
+            input.permissions.into_iter().collect(),
+        );
+        self.role_repository
+            .create(&role)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(role)
+    }
+
+    pub async fn update_role(
+        &self,
+        company_id: Uuid,
+        role_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        input: OrganizationRoleInput,
+    ) -> ApiResult<OrganizationRole> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let mut role = self.get_role(company_id, role_id).await?;
+        let input = Self::validate_role(input)?;
+
+        role.name = input.name;
+        role.description = input.description;
+        role.permissions = input.permissions.into_iter().collect();
+        role.updated_at = chrono::Utc::now();
+        self.role_repository
+            .update(&role)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(role)
+    }
+
+    /// Deletes the role; its members lose what it granted
+    pub async fn delete_role(&self, company_id: Uuid, role_id: Uuid, user: &User, may_manage: bool) -> ApiResult<()> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let role = self.get_role(company_id, role_id).await?;
+        self.role_repository
+            .delete(role.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn members(&self, company_id: Uuid, role_id: Uuid, user: &User, may_manage: bool) -> ApiResult<Vec<User>> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let role = self.get_role(company_id, role_id).await?;
+        let member_ids = self
+            .role_repository
+            .find_member_ids(role.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut members = Vec::with_capacity(member_ids.len());
+        for member_id in member_ids {
+            if let Some(member) = self
+                .user_repository
+                .find_by_id(member_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+            {
+                members.push(member);
+            }
+        }
+        Ok(members)
+    }
+
+    /// Gives the role to a member of the organization
+    pub async fn add_member(
+        &self,
+        company_id: Uuid,
+        role_id: Uuid,
+        member_id: Uuid,
+        user: &User,
+        may_manage: bool,
+    ) -> ApiResult<()> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let role = self.get_role(company_id, role_id).await?;
+        let member = self
+            .user_repository
+            .find_by_id(member_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("User with ID {}", member_id)))?;
+        if member.company_id != Some(company_id) {
+            return Err(ApiError::validation(
+                "user_id",
+                "Roles can only be given to members of the organization",
+            ));
+        }
+        self.role_repository
+            .add_member(role.id, member.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn remove_member(
+        &self,
+        company_id: Uuid,
+        role_id: Uuid,
+        member_id: Uuid,
+        user: &User,
+        may_manage: bool,
+    ) -> ApiResult<()> {
+        self.get_managed_company(company_id, user, may_manage).await?;
+        let role = self.get_role(company_id, role_id).await?;
+        let removed = self
+            .role_repository
+            .remove_member(role.id, member_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if !removed {
+            return Err(ApiError::not_found(format!("User with ID {} in the role", member_id)));
+        }
+        Ok(())
+    }
+
+    /// The event's access grants, including ended ones
+    pub async fn grants(&self, event_id: Uuid, user: &User, may_manage: bool) -> ApiResult<Vec<AccessGrant>> {
+        self.get_organized_event(event_id, user, may_manage).await?;
+        self.grant_repository
+            .find_by_event(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Lets another user do some of what the event's organizers do, from
+    /// `starts_at` until `expires_at`
+    pub async fn grant_access(
+        &self,
+        event_id: Uuid,
+        user: &User,
+        may_manage: bool,
+        input: AccessGrantInput,
+    ) -> ApiResult<AccessGrant> {
+        let event = self.get_organized_event(event_id, user, may_manage).await?;
+        let now = chrono::Utc::now();
+        let starts_at = input.starts_at.unwrap_or(now);
+
+        if input.permissions.is_empty() {
+            return Err(ApiError::validation("permissions", "Choose at least one permission"));
+        }
+        if let Some(permission) = input.permissions.iter().find(|permission| !permission.is_delegable()) {
+            return Err(ApiError::validation(
+                "permissions",
+                format!("{} can't be delegated for an event", permission),
+            ));
+        }
+        if input.expires_at <= starts_at {
+            return Err(ApiError::validation("expires_at", "The grant must end after it starts"));
+        }
+        if input.expires_at <= now {
+            return Err(ApiError::validation("expires_at", "The grant must end in the future"));
+        }
+        if input.expires_at - starts_at > chrono::Duration::days(Self::MAX_GRANT_DAYS) {
+            return Err(ApiError::validation(
+                "expires_at",
+                format!("Access can be granted for at most {} days", Self::MAX_GRANT_DAYS),
+            ));
+        }
+
+        let grantee = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|grantee| grantee.is_active)
+            .ok_or_else(|| ApiError::not_found(format!("User with ID {}", input.user_id)))?;
+        if grantee.id == event.organizer_id || event.co_organizers.contains(&grantee.id) {
+            return Err(ApiError::bad_request("The user already organizes the event"));
+        }
+
+        let grant = AccessGrant::new(
+            event.id,
+            grantee.id,
+            user.id,
+            input.permissions.into_iter().collect(),
+            starts_at,
+            input.expires_at,
+        );
+        self.grant_repository
+            .create(&grant)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(grant)
+    }
+
+    /// Ends a grant before it expires
+    pub async fn revoke_grant(
+        &self,
+        event_id: Uuid,
+        grant_id: Uuid,
+        user: &User,
+        may_manage: bool,
+    ) -> ApiResult<AccessGrant> {
+        self.get_organized_event(event_id, user, may_manage).await?;
+        let mut grant = self
+            .grant_repository
+            .find_by_id(grant_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|grant| grant.event_id == event_id)
+            .ok_or_else(|| ApiError::not_found(format!("Access grant with ID {}", grant_id)))?;
+        let now = chrono::Utc::now();
+        if grant.has_ended(now) {
+            return Err(ApiError::bad_request("The grant has already ended"));
+        }
+
+        self.grant_repository
+            .revoke(grant.id, now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        grant.revoked_at = Some(now);
+        Ok(grant)
+    }
+
+    async fn get_organized_event(&self, event_id: Uuid, user: &User, may_manage: bool) -> ApiResult<Event> {
+        let event = self
+            .event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))?;
+        if !(may_manage || event.organizer_id == user.id || event.co_organizers.contains(&user.id)) {
+            return Err(ApiError::authorization("Only the event's organizers can grant access to it"));
+        }
+        Ok(event)
+    }
+
+    fn validate_role(input: OrganizationRoleInput) -> ApiResult<OrganizationRoleInput> {
+        let name = optional_text("name", "Name", Some(input.name), Self::MAX_NAME_CHARS)?
+            .ok_or_else(|| ApiError::validation("name", "Name is required"))?;
+        let description = optional_text("description", "Description", input.description, Self::MAX_DESCRIPTION_CHARS)?;
+        if let Some(permission) = input.permissions.iter().find(|permission| !permission.is_assignable()) {
+            return Err(ApiError::validation(
+                "permissions",
+                format!("{} can't be given by an organization", permission),
+            ));
+        }
+        Ok(OrganizationRoleInput { name, description, ..input })
+    }
+
+    async fn get_managed_company(&self, company_id: Uuid, user: &User, may_manage: bool) -> ApiResult<Company> {
+        let company = self
+            .company_repository
+            .find_by_id(company_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Company with ID {}", company_id)))?;
+        if !SchedulingApplicationService::can_manage(company_id, user, may_manage) {
+            return Err(ApiError::authorization("Only the organization's organizers can manage its roles"));
+        }
+        Ok(company)
+    }
+
+    async fn get_role(&self, company_id: Uuid, role_id: Uuid) -> ApiResult<OrganizationRole> {
+        self.role_repository
+            .find_by_id(role_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .filter(|role| role.company_id == company_id)
+            .ok_or_else(|| ApiError::not_found(format!("Role with ID {}", role_id)))
+    }
+}
+
+// ============================================================================
+// Event Ownership Application Service
+// ============================================================================
+
+/// An ownership transfer with the title of the event it is for
+#[derive(Debug, Clone)]
+pub struct OwnershipTransferDetails {
+    pub transfer: EventOwnershipTransfer,
+    pub event_title: String,
+}
+
+/// An accepted transfer and the event before and after it changed hands
+#[derive(Debug, Clone)]
+pub struct CompletedTransfer {
+    pub transfer: EventOwnershipTransfer,
+    pub previous: Event,
+    pub event: Event,
+}
+
+/// Hands events to another organizer. The organizer (or an administrator)
+/// asks; nothing changes until the proposed owner accepts, after which the
+/// previous organizer stays on as a co-organizer. Both sides are notified
+/// live and by email; emails are best effort.
+#[derive(Clone)]
+pub struct EventOwnershipApplicationService {
+    transfer_repository: Arc<dyn EventOwnershipTransferRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    mailer: Arc<dyn Mailer>,
+    notifier: RegistrationNotifier,
+    suppression_repository: Option<Arc<dyn EmailSuppressionRepository>>,
+    public_url: String,
+}
+
+impl EventOwnershipApplicationService {
+    pub const MAX_MESSAGE_CHARS: usize = 500;
+
+    pub fn new(
+        transfer_repository: Arc<dyn EventOwnershipTransferRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        mailer: Arc<dyn Mailer>,
+        notifier: RegistrationNotifier,
+        public_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            transfer_repository,
+            event_repository,
+            user_repository,
+            mailer,
+            notifier,
+            suppression_repository: None,
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Emails aren't sent to addresses that bounced or complained
+    pub fn with_suppressions(mut self, suppression_repository: Arc<dyn EmailSuppressionRepository>) -> Self {
+        self.suppression_repository = Some(suppression_repository);
+        self
+    }
+
+    /// Asks `new_owner_id` to take over the event. A pending request for the
+    /// event is replaced.
+    pub async fn request(
+        &self,
+        event_id: Uuid,
+        requester: &User,
+        may_manage: bool,
+        new_owner_id: Uuid,
+        message: Option<String>,
+    ) -> ApiResult<OwnershipTransferDetails> {
+        let event = self.get_event(event_id).await?;
+        if !(may_manage || event.organizer_id == requester.id) {
+            return Err(ApiError::authorization(
+                "Only the event's organizer can hand it over",
+            ));
+        }
+        if new_owner_id == event.organizer_id {
+            return Err(ApiError::bad_request("The user already organizes this event"));
+        }
+        let new_owner = self.get_user(new_owner_id).await?;
+        if !new_owner.is_active {
+            return Err(ApiError::bad_request(format!("{} has been deactivated", new_owner.name)));
+        }
+        if matches!(new_owner.role, UserRole::Participant) {
+            return Err(ApiError::bad_request(format!(
+                "{} is not an organizer, so they can't take over events",
+                new_owner.name
+            )));
+        }
+        let message = message.map(|message| message.trim().to_string()).filter(|message| !message.is_empty());
+        if message.as_ref().is_some_and(|message| message.chars().count() > Self::MAX_MESSAGE_CHARS) {
+            return Err(ApiError::validation(
+                "message",
+                format!("Message must be at most {} characters", Self::MAX_MESSAGE_CHARS),
+            ));
+        }
+
+        if let Some(mut earlier) = self.pending_for_event(event_id).await? {
+            earlier.respond(OwnershipTransferStatus::Cancelled, chrono::Utc::now());
+            self.update(&earlier).await?;
+        }
+        let transfer = EventOwnershipTransfer::new(&event, new_owner.id, requester.id, message);
+        self.transfer_repository
+            .create(&transfer)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        self.notifier.notify(
+            new_owner.id,
+            RegistrationNotification::OwnershipTransferRequested { transfer_id: transfer.id, event_id },
+        );
+        let from_name = self.user_name(event.organizer_id).await;
+        self.send(
+            OwnershipEmail::Requested { from_name: &from_name, message: transfer.message.as_deref() },
+            &event,
+            &new_owner,
+        )
+        .await;
+
+        Ok(OwnershipTransferDetails {
+            transfer,
+            event_title: event.title,
+        })
+    }
+
+    /// The event's pending transfer, for its organizers and the proposed owner
+    pub async fn pending(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<OwnershipTransferDetails> {
+        let event = self.get_event(event_id).await?;
+        let transfer = self
+            .pending_for_event(event_id)
+            .await?
+            .ok_or_else(|| ApiError::not_found("Pending ownership transfer"))?;
+        let involved = event.organizer_id == user_id || event.co_organizers.contains(&user_id) || transfer.to_user_id == user_id;
+        if !(may_manage || involved) {
+            return Err(ApiError::authorization(
+                "Only the event's organizers can see its ownership transfer",
+            ));
+        }
+        Ok(OwnershipTransferDetails {
+            transfer,
+            event_title: event.title,
+        })
+    }
+
+    /// Transfers waiting for the user's answer, newest first
+    pub async fn incoming(&self, user_id: Uuid) -> ApiResult<Vec<OwnershipTransferDetails>> {
+        let transfers = self
+            .transfer_repository
+            .find_pending_for_user(user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut details = Vec::with_capacity(transfers.len());
+        for transfer in transfers {
+            let event = self.get_event(transfer.event_id).await?;
+            details.push(OwnershipTransferDetails {
+                transfer,
+                event_title: event.title,
+            });
+        }
+        Ok(details)
+    }
+
+    /// Withdraws the event's pending transfer
+    pub async fn cancel(&self, event_id: Uuid, user_id: Uuid, may_manage: bool) -> ApiResult<EventOwnershipTransfer> {
+        let event = self.get_event(event_id).await?;
+        let mut transfer = self
+            .pending_for_event(event_id)
+            .await?
+            .ok_or_else(|| ApiError::not_found("Pending ownership transfer"))?;
+        if !(may_manage || event.organizer_id == user_id || transfer.requested_by == user_id) {
+            return Err(ApiError::authorization(
+                "Only the event's organizer can withdraw the transfer",
+            ));
+        }
+        transfer.respond(OwnershipTransferStatus::Cancelled, chrono::Utc::now());
+        self.update(&transfer).await?;
+        Ok(transfer)
+    }
+
+    /// Makes the proposed owner the event's organizer
+    pub async fn accept(&self, transfer_id: Uuid, user_id: Uuid) -> ApiResult<CompletedTransfer> {
+        let mut transfer = self.get_answerable(transfer_id, user_id).await?;
+        let previous = self.get_event(transfer.event_id).await?;
+        let now = chrono::Utc::now();
+        if previous.organizer_id != transfer.from_user_id {
+            // The event changed hands some other way since the request
+            transfer.respond(OwnershipTransferStatus::Cancelled, now);
+            self.update(&transfer).await?;
+            return Err(ApiError::bad_request(
+                "The event has a different organizer than when the transfer was requested",
+            ));
+        }
+
+        let mut event = previous.clone();
+        event.transfer_to(transfer.to_user_id, now);
+        self.event_repository
+            .update(&event)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        transfer.respond(OwnershipTransferStatus::Accepted, now);
+        self.update(&transfer).await?;
+
+        let mut notified = HashSet::new();
+        for user_id in std::iter::once(event.organizer_id).chain(event.co_organizers.iter().copied()) {
+            if notified.insert(user_id) {
+                self.notifier.notify(
+                    user_id,
+                    RegistrationNotification::OwnershipTransferred { event_id: event.id, new_owner_id: event.organizer_id },
+                );
+            }
+        }
+        let previous_owner = self.find_user(transfer.from_user_id).await;
+        let new_owner = self.find_user(transfer.to_user_id).await;
+        if let (Some(previous_owner), Some(new_owner)) = (previous_owner, new_owner) {
+            self.send(OwnershipEmail::HandedOver { to_name: &new_owner.name }, &event, &previous_owner)
+                .await;
+            self.send(OwnershipEmail::TakenOver { from_name: &previous_owner.name }, &event, &new_owner)
+                .await;
+        }
+
+        Ok(CompletedTransfer {
+            transfer,
+            previous,
+            event,
+        })
+    }
+
+    /// Turns the transfer down; the event stays with its organizer
+    pub async fn decline(&self, transfer_id: Uuid, user_id: Uuid) -> ApiResult<OwnershipTransferDetails> {
+        let mut transfer = self.get_answerable(transfer_id, user_id).await?;
+        let event = self.get_event(transfer.event_id).await?;
+        transfer.respond(OwnershipTransferStatus::Declined, chrono::Utc::now());
+        self.update(&transfer).await?;
+
+        self.notifier.notify(
+            transfer.from_user_id,
+            RegistrationNotification::OwnershipTransferDeclined { transfer_id: transfer.id, event_id: event.id },
+        );
+        let previous_owner = self.find_user(transfer.from_user_id).await;
+        let new_owner = self.find_user(transfer.to_user_id).await;
+        if let (Some(previous_owner), Some(new_owner)) = (previous_owner, new_owner) {
+            self.send(OwnershipEmail::Declined { to_name: &new_owner.name }, &event, &previous_owner)
+                .await;
+        }
+
+        Ok(OwnershipTransferDetails {
+            transfer,
+            event_title: event.title,
+        })
+    }
+
+    async fn get_answerable(&self, transfer_id: Uuid, user_id: Uuid) -> ApiResult<EventOwnershipTransfer> {
+        let transfer = self
+            .transfer_repository
+            .find_by_id(transfer_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Ownership transfer with ID {}", transfer_id)))?;
+        if transfer.to_user_id != user_id {
+            return Err(ApiError::authorization(
+                "Only the proposed owner can answer an ownership transfer",
+            ));
+        }
+        if !transfer.is_pending() {
+            return Err(ApiError::bad_request("The ownership transfer has already been answered or withdrawn"));
+        }
+        Ok(transfer)
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    async fn get_user(&self, user_id: Uuid) -> ApiResult<User> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("User with ID {}", user_id)))
+    }
+
+    // For notifications only, so a failed lookup is logged and skipped
+    async fn find_user(&self, user_id: Uuid) -> Option<User> {
+        match self.user_repository.find_by_id(user_id).await {
+            Ok(user) => user,
+            Err(e) => {
+                tracing::warn!("Could not load user {} for an ownership email: {}", user_id, e);
+                None
+            }
+        }
+    }
+
+    async fn user_name(&self, user_id: Uuid) -> String {
+        self.find_user(user_id).await.map(|user| user.name).unwrap_or_default()
+    }
+
+    async fn pending_for_event(&self, event_id: Uuid) -> ApiResult<Option<EventOwnershipTransfer>> {
+        self.transfer_repository
+            .find_pending_for_event(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn update(&self, transfer: &EventOwnershipTransfer) -> ApiResult<()> {
+        self.transfer_repository
+            .update(transfer)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    // Best effort: the transfer has already been stored
+    async fn send(&self, email: OwnershipEmail<'_>, event: &Event, recipient: &User) {
+        if let Some(suppressions) = &self.suppression_repository {
+            match suppressions.find_by_email(&recipient.email).await {
+                Ok(Some(_)) => return,
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Could not check suppression of {}: {}", recipient.email, e),
+            }
+        }
+        let message = render_ownership_email(email, event, recipient, &self.public_url, &Localization::for_user(recipient));
+        if let Err(e) = self.mailer.send(&message).await {
+            tracing::warn!("Ownership email for event {} to {} failed: {}", event.id, recipient.email, e);
+        }
+    }
+}
+
+// ============================================================================
+// Event Submission Application Service
+// ============================================================================
+
+/// A submission with the proposed event and the name of who proposed it
+#[derive(Debug, Clone)]
+pub struct EventSubmissionDetails {
+    pub submission: EventSubmission,
+    pub event: Event,
+    pub submitter_name: String,
+}
+
+/// Community-proposed events. A submitted event is a draft organized by the
+/// submitter that moderators (organizers and administrators) approve or
+/// reject; it is published once approved, or on its `publish_at` if that is
+/// still ahead. A rejected event can be edited and submitted again.
+#[derive(Clone)]
+pub struct EventSubmissionApplicationService {
+    submission_repository: Arc<dyn EventSubmissionRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    notifier: RegistrationNotifier,
+    event_service: EventService,
+    /// Participants can only propose events, not create them directly
+    required_for_participants: bool,
+}
+
+impl EventSubmissionApplicationService {
+    pub const MAX_COMMENT_CHARS: usize = 1000;
+
+    pub fn new(
+        submission_repository: Arc<dyn EventSubmissionRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        notifier: RegistrationNotifier,
+    ) -> Self {
+        Self {
+            submission_repository,
+            event_repository,
+            user_repository,
+            notifier,
+            event_service: EventService::new(),
+            required_for_participants: false,
+        }
+    }
+
+    pub fn required_for_participants(mut self, required: bool) -> Self {
+        self.required_for_participants = required;
+        self
+    }
+
+    /// Whether the user has to go through the moderation queue to create events
+    pub fn requires_review(&self, permissions: &PermissionChecker) -> bool {
+        self.required_for_participants && !permissions.has_any(Permission::EventCreate)
+    }
+
+    /// Creates the event as a draft and puts it in the moderation queue
+    pub async fn submit(&self, request: CreateEventRequest, submitter: &User) -> ApiResult<EventSubmissionDetails> {
+        let event = request.to_domain_event(submitter.id)?;
+        self.event_service
+            .validate_event(&event)
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.event_repository
+            .create(&event)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let submission = EventSubmission::new(event.id, submitter.id);
+        if let Err(e) = self.submission_repository.create(&submission).await {
+            // An event outside the queue could be published without review
+            if let Err(cleanup) = self.event_repository.delete(event.id).await {
+                tracing::error!("Could not remove event {} after its submission failed: {}", event.id, cleanup);
+            }
+            return Err(ApiError::Domain { source: e });
+        }
+
+        Ok(EventSubmissionDetails {
+            submission,
+            event,
+            submitter_name: submitter.name.clone(),
+        })
+    }
+
+    /// Submissions waiting for a moderator, oldest first
+    pub async fn queue(&self, moderator: &User, may_manage: bool) -> ApiResult<Vec<EventSubmissionDetails>> {
+        Self::ensure_moderator(moderator, may_manage)?;
+        let submissions = self
+            .submission_repository
+            .find_pending()
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.with_details(submissions).await
+    }
+
+    /// The user's own submissions, newest first
+    pub async fn mine(&self, user_id: Uuid) -> ApiResult<Vec<EventSubmissionDetails>> {
+        let submissions = self
+            .submission_repository
+            .find_by_submitter(user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.with_details(submissions).await
+    }
+
+    /// Accepts the event; it is published now unless it is scheduled for later
+    pub async fn approve(
+        &self,
+        submission_id: Uuid,
+        moderator: &User,
+        may_manage: bool,
+        comment: Option<String>,
+    ) -> ApiResult<EventSubmissionDetails> {
+        let comment = optional_text("comment", "Comment", comment, Self::MAX_COMMENT_CHARS)?;
+        let (mut submission, mut event) = self.get_reviewable(submission_id, moderator, may_manage).await?;
+        let now = chrono::Utc::now();
+
+        if event.publish_at.is_none_or(|publish_at| publish_at <= now) {
+            event.status = EventStatus::Published;
+            event.updated_at = now;
+            self.event_repository
+                .update(&event)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+        }
+        submission.review(EventSubmissionStatus::Approved, moderator.id, comment, now);
+        self.update(&submission).await?;
+
+        self.notify_reviewed(&submission);
+        self.details(submission, event).await
+    }
+
+    /// Turns the event down; the comment tells the submitter why
+    pub async fn reject(
+        &self,
+        submission_id: Uuid,
+        moderator: &User,
+        may_manage: bool,
+        comment: Option<String>,
+    ) -> ApiResult<EventSubmissionDetails> {
+        let comment = optional_text("comment", "Comment", comment, Self::MAX_COMMENT_CHARS)?
+            .ok_or_else(|| ApiError::validation("comment", "Say why the event was rejected"))?;
+        let (mut submission, event) = self.get_reviewable(submission_id, moderator, may_manage).await?;
+        submission.review(EventSubmissionStatus::Rejected, moderator.id, Some(comment), chrono::Utc::now());
+        self.update(&submission).await?;
+
+        self.notify_reviewed(&submission);
+        self.details(submission, event).await
+    }
+
+    /// Puts a rejected event back in the queue, typically after editing it
+    pub async fn resubmit(&self, submission_id: Uuid, user: &User) -> ApiResult<EventSubmissionDetails> {
+        let rejected = self.get_submission(submission_id).await?;
+        if rejected.submitted_by != user.id {
+            return Err(ApiError::authorization("Only the submitter can resubmit an event"));
+        }
+        if rejected.status != EventSubmissionStatus::Rejected {
+            return Err(ApiError::bad_request("Only rejected submissions can be resubmitted"));
+        }
+        let event = self.get_event(rejected.event_id).await?;
+        if event.organizer_id != user.id {
+            return Err(ApiError::bad_request("The event has a different organizer than when it was submitted"));
+        }
+        let already_queued = self
+            .submission_repository
+            .find_by_submitter(user.id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .iter()
+            .any(|submission| submission.event_id == event.id && submission.is_pending());
+        if already_queued {
+            return Err(ApiError::bad_request("The event is already waiting for a moderator"));
+        }
+
+        let submission = EventSubmission::new(event.id, user.id);
+        self.submission_repository
+            .create(&submission)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(EventSubmissionDetails {
+            submission,
+            event,
+            submitter_name: user.name.clone(),
+        })
+    }
+
+    fn ensure_moderator(user: &User, may_manage: bool) -> ApiResult<()> {
+        if role_permissions(user, may_manage).has(Permission::EventModerate) {
+            Ok(())
+        } else {
+            Err(ApiError::authorization("Only organizers and administrators moderate submitted events"))
+        }
+    }
+
+    async fn get_reviewable(
+        &self,
+        submission_id: Uuid,
+        moderator: &User,
+        may_manage: bool,
+    ) -> ApiResult<(EventSubmission, Event)> {
+        Self::ensure_moderator(moderator, may_manage)?;
+        let submission = self.get_submission(submission_id).await?;
+        if submission.submitted_by == moderator.id && !may_manage {
+            return Err(ApiError::authorization("Events can't be approved or rejected by whoever submitted them"));
+        }
+        if !submission.is_pending() {
+            return Err(ApiError::bad_request("The submission has already been reviewed"));
+        }
+        let event = self.get_event(submission.event_id).await?;
+        Ok((submission, event))
+    }
+
+    async fn get_submission(&self, submission_id: Uuid) -> ApiResult<EventSubmission> {
+        self.submission_repository
+            .find_by_id(submission_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event submission with ID {}", submission_id)))
+    }
+
+    async fn get_event(&self, event_id: Uuid) -> ApiResult<Event> {
+        self.event_repository
+            .find_by_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Event with ID {}", event_id)))
+    }
+
+    async fn update(&self, submission: &EventSubmission) -> ApiResult<()> {
+        self.submission_repository
+            .update(submission)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    fn notify_reviewed(&self, submission: &EventSubmission) {
+        self.notifier.notify(
+            submission.submitted_by,
+            RegistrationNotification::EventSubmissionReviewed {
+                submission_id: submission.id,
+                event_id: submission.event_id,
+                approved: submission.status == EventSubmissionStatus::Approved,
+            },
+        );
+    }
+
+    async fn details(&self, submission: EventSubmission, event: Event) -> ApiResult<EventSubmissionDetails> {
+        let submitter_name = self
+            .user_repository
+            .find_by_id(submission.submitted_by)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .map(|user| user.name)
+            .unwrap_or_default();
+        Ok(EventSubmissionDetails {
+            submission,
+            event,
+            submitter_name,
+        })
+    }
+
+    async fn with_details(&self, submissions: Vec<EventSubmission>) -> ApiResult<Vec<EventSubmissionDetails>> {
+        let user_ids: Vec<Uuid> = submissions
+            .iter()
+            .map(|submission| submission.submitted_by)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let names: HashMap<Uuid, String> = self
+            .user_repository
+            .find_by_ids(&user_ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|user| (user.id, user.name))
+            .collect();
+
+        let mut details = Vec::with_capacity(submissions.len());
+        for submission in submissions {
+            // The event may have been deleted by its organizer while under review
+            let Some(event) = self
+                .event_repository
+                .find_by_id(submission.event_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+            else {
+                continue;
+            };
+            details.push(EventSubmissionDetails {
+                submitter_name: names.get(&submission.submitted_by).cloned().unwrap_or_default(),
+                submission,
+                event,
+            });
+        }
+        Ok(details)
+    }
+}
+
+// ============================================================================
+// Export Application Service
+// ============================================================================
+
+/// A scheduled export as entered by an administrator
+#[derive(Debug, Clone)]
+pub struct ExportJobInput {
+    pub name: String,
+    pub entity: ExportEntity,
+    pub filter: ExportFilter,
+    pub format: ExportFormat,
+    pub destination: ExportDestination,
+    pub schedule: String,
+    /// Defaults to the platform's time zone
+    pub timezone: Option<String>,
+    pub is_active: bool,
+}
+
+/// Export jobs that push registrations or events to external systems, such
+/// as an association's CRM, on a cron schedule. Runs that fail are recorded
+/// on the job and tried again at its next scheduled time.
+#[derive(Clone)]
+pub struct ExportApplicationService {
+    export_repository: Arc<dyn ExportJobRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    uploader: Arc<dyn ExportUploader>,
+}
+
+impl ExportApplicationService {
+    pub fn new(
+        export_repository: Arc<dyn ExportJobRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        uploader: Arc<dyn ExportUploader>,
+    ) -> Self {
+        Self {
+            export_repository,
+            event_repository,
+            registration_repository,
+            user_repository,
+            uploader,
+        }
+    }
+
+    pub async fn list(&self) -> ApiResult<Vec<ExportJob>> {
+        self.export_repository
+            .find_all()
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn get(&self, id: Uuid) -> ApiResult<ExportJob> {
+        self.export_repository
+            .find_by_id(id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Export job with ID {}", id)))
+    }
+
+    pub async fn create(&self, input: ExportJobInput, created_by: Uuid) -> ApiResult<ExportJob> {
+        let now = chrono::Utc::now();
+        let mut job = ExportJob::new(
+            input.name.trim(),
+            input.entity,
+            input.filter,
+            input.format,
+            input.destination,
+            input.schedule.trim(),
+            input.timezone.unwrap_or_else(|| DEFAULT_TIMEZONE.name().to_string()),
+            created_by,
+            now,
+        )
+        .map_err(|e| ApiError::Domain { source: e })?;
+        if !input.is_active {
+            job.is_active = false;
+            job.next_run_at = None;
+        }
+        self.check_filter(&job.filter).await?;
+
+        self.export_repository
+            .create(&job)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(job)
+    }
+
+    /// Replaces the job's settings; its run history is kept
+    pub async fn update(&self, id: Uuid, input: ExportJobInput) -> ApiResult<ExportJob> {
+        let mut job = self.get(id).await?;
+        let now = chrono::Utc::now();
+        job.name = input.name.trim().to_string();
+        job.entity = input.entity;
+        job.filter = input.filter;
+        job.format = input.format;
+        job.destination = input.destination;
+        job.schedule = input.schedule.trim().to_string();
+        if let Some(timezone) = input.timezone {
+            job.timezone = timezone;
+        }
+        job.is_active = input.is_active;
+        job.validate().map_err(|e| ApiError::Domain { source: e })?;
+        self.check_filter(&job.filter).await?;
+        job.next_run_at = job.next_run_after(now).map_err(|e| ApiError::Domain { source: e })?;
+        job.updated_at = now;
+
+        self.export_repository
+            .update(&job)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(job)
+    }
+
+    pub async fn delete(&self, id: Uuid) -> ApiResult<()> {
+        self.get(id).await?;
+        self.export_repository
+            .delete(id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Runs the job right away, paused or not. The outcome is recorded like
+    /// a scheduled run's; a failure is returned as well.
+    pub async fn run_now(&self, id: Uuid) -> ApiResult<ExportJob> {
+        let mut job = self.get(id).await?;
+        match self.run(&mut job, chrono::Utc::now()).await? {
+            Ok(_) => Ok(job),
+            Err(message) => Err(ApiError::Domain {
+                source: DomainError::external_service("Export destination", &message),
+            }),
+        }
+    }
+
+    /// Runs every job whose time has come and returns them with the outcome
+    /// recorded. One job failing doesn't hold up the others.
+    pub async fn run_due(&self, now: chrono::DateTime<chrono::Utc>) -> ApiResult<Vec<ExportJob>> {
+        let due = self
+            .export_repository
+            .find_due(now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut ran = Vec::new();
+        for mut job in due {
+            // A failed run is recorded on the job, like a successful one
+            let _ = self.run(&mut job, now).await?;
+            ran.push(job);
+        }
+        Ok(ran)
+    }
+
+    // Exports and delivers the file, then saves the outcome on the job. The
+    // inner result is the run's; the outer one is saving it.
+    async fn run(&self, job: &mut ExportJob, started_at: chrono::DateTime<chrono::Utc>) -> ApiResult<Result<i32, String>> {
+        let outcome = match self.render(job).await {
+            Ok(table) => {
+                let bytes = table.render(job.format);
+                match self.uploader.upload(&job.destination, &job.file_name(started_at), &bytes).await {
+                    Ok(()) => Ok(table.rows.len() as i32),
+                    Err(e) => Err(e.to_string()),
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        };
+
+        job.record_run(started_at, outcome.clone())
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.export_repository
+            .update(job)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(outcome)
+    }
+
+    async fn check_filter(&self, filter: &ExportFilter) -> ApiResult<()> {
+        if let Some(event_id) = filter.event_id {
+            let exists = self
+                .event_repository
+                .exists(event_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            if !exists {
+                return Err(ApiError::not_found(format!("Event with ID {}", event_id)));
+            }
+        }
+        Ok(())
+    }
+
+    async fn render(&self, job: &ExportJob) -> ApiResult<ExportTable> {
+        let since = job.last_success_at.filter(|_| job.filter.changed_since_last_run);
+        let events = self.events(&job.filter).await?;
+
+        match job.entity {
+            ExportEntity::Events => {
+                let events: Vec<Event> = events
+                    .into_iter()
+                    .filter(|event| since.is_none_or(|since| event.updated_at >= since))
+                    .collect();
+                Ok(ExportTable::events(&events))
+            }
+            ExportEntity::Registrations => {
+                let mut registrations = Vec::new();
+                for event in &events {
+                    let found = self
+                        .registration_repository
+                        .find_by_event_id(event.id)
+                        .await
+                        .map_err(|e| ApiError::Domain { source: e })?;
+                    registrations.extend(
+                        found
+                            .into_iter()
+                            .filter(|registration| since.is_none_or(|since| registration.updated_at >= since)),
+                    );
+                }
+
+                let user_ids: Vec<Uuid> = registrations
+                    .iter()
+                    .filter_map(|registration| registration.user_id)
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect();
+                let users: HashMap<Uuid, User> = self
+                    .user_repository
+                    .find_by_ids(&user_ids)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?
+                    .into_iter()
+                    .map(|user| (user.id, user))
+                    .collect();
+                Ok(ExportTable::registrations(&events, &registrations, &users))
+            }
+        }
+    }
+
+    // The events the filter picks, earliest first
+    async fn events(&self, filter: &ExportFilter) -> ApiResult<Vec<Event>> {
+        if let Some(event_id) = filter.event_id {
+            let event = self
+                .event_repository
+                .find_by_id(event_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            return Ok(event.into_iter().collect());
+        }
+        organized_events(self.event_repository.as_ref(), filter.company_id).await
+    }
+}
+
+// Every event the company organizes, or every event at all, earliest first
+async fn organized_events(event_repository: &dyn EventRepository, company_id: Option<Uuid>) -> ApiResult<Vec<Event>> {
+    let event_filter = EventFilter {
+        title_contains: None,
+        category_id: None,
+        organizer_id: None,
+        is_private: None,
+        status: None,
+        location_type: None,
+        start_date_from: None,
+        start_date_to: None,
+        near: None,
+        organizer_company_id: company_id,
+        co_organizer_id: None,
+        field: None,
+        county: None,
+        municipality: None,
+    };
+    let mut events = Vec::new();
+    let mut pagination = PaginationParams::new(0, 1000).map_err(|e| ApiError::Domain { source: e })?;
+    loop {
+        let page = event_repository
+            .find_by_filter(&event_filter, pagination.clone())
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        events.extend(page.items);
+        if !page.has_next {
+            break;
+        }
+        pagination.offset += pagination.limit;
+    }
+    events.sort_by_key(|event| event.start_date);
+    Ok(events)
+}
+
+// ============================================================================
+// CRM Sync Application Service
+// ============================================================================
+
+/// Failed records retried per run; the rest wait for the next one
+const CRM_RETRY_LIMIT: i64 = 500;
+/// Records listed on a connector's status page, per state
+const CRM_PROBLEM_LIMIT: i64 = 50;
+
+/// A CRM connector as entered by an administrator
+#[derive(Debug, Clone)]
+pub struct CrmConnectorInput {
+    pub name: String,
+    pub provider: CrmProvider,
+    pub base_url: Option<String>,
+    pub company_id: Option<Uuid>,
+    pub registration_object: Option<String>,
+    /// Contacts' first and last names when unset
+    pub field_mappings: Option<Vec<CrmFieldMapping>>,
+    /// Defaults to `CrmWins`
+    pub conflict_policy: Option<CrmConflictPolicy>,
+    /// Defaults to an hour
+    pub sync_interval_minutes: Option<i32>,
+    pub is_active: bool,
+}
+
+/// How a connector's syncs are going, for its status page
+#[derive(Debug, Clone)]
+pub struct CrmConnectorStatus {
+    pub connector: CrmConnector,
+    pub counts: CrmSyncCounts,
+    /// Records in conflict or failed, most recently touched first
+    pub problems: Vec<CrmSyncRecord>,
+}
+
+/// Connectors that keep a CRM's contacts, and optionally a record per
+/// registration, in step with registrations for Aqio events. Each run syncs
+/// the registrations changed since the last one got through, and retries the
+/// records that failed.
+#[derive(Clone)]
+pub struct CrmSyncApplicationService {
+    crm_repository: Arc<dyn CrmConnectorRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    client: Arc<dyn CrmClient>,
+}
+
+impl CrmSyncApplicationService {
+    pub fn new(
+        crm_repository: Arc<dyn CrmConnectorRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        registration_repository: Arc<dyn EventRegistrationRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        client: Arc<dyn CrmClient>,
+    ) -> Self {
+        Self {
+            crm_repository,
+            event_repository,
+            registration_repository,
+            user_repository,
+            client,
+        }
+    }
+
+    pub async fn list(&self) -> ApiResult<Vec<CrmConnector>> {
+        self.crm_repository
+            .find_all()
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn get(&self, id: Uuid) -> ApiResult<CrmConnector> {
+        self.crm_repository
+            .find_by_id(id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("CRM connector with ID {}", id)))
+    }
+
+    pub async fn create(&self, input: CrmConnectorInput, created_by: Uuid) -> ApiResult<CrmConnector> {
+        let now = chrono::Utc::now();
+        let mut connector = CrmConnector::new(input.name.trim(), input.provider, created_by, now);
+        Self::apply(&mut connector, input, now);
+        connector.validate().map_err(|e| ApiError::Domain { source: e })?;
+
+        self.crm_repository
+            .create(&connector)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(connector)
+    }
+
+    /// Replaces the connector's settings. A change to what is synced, or to
+    /// how conflicts are settled, makes the next run go through every
+    /// registration again.
+    pub async fn update(&self, id: Uuid, input: CrmConnectorInput) -> ApiResult<CrmConnector> {
+        let mut connector = self.get(id).await?;
+        if input.provider != connector.provider {
+            return Err(ApiError::validation(
+                "provider",
+                "A connector stays with its CRM; create a new connector for another one",
+            ));
+        }
+        let before = connector.clone();
+        let now = chrono::Utc::now();
+        connector.name = input.name.trim().to_string();
+        Self::apply(&mut connector, input, now);
+        connector.validate().map_err(|e| ApiError::Domain { source: e })?;
+
+        if connector.field_mappings != before.field_mappings
+            || connector.registration_object != before.registration_object
+            || connector.company_id != before.company_id
+            || connector.conflict_policy != before.conflict_policy
+        {
+            connector.sync_cursor = None;
+        }
+        if connector.is_active != before.is_active || connector.sync_interval_minutes != before.sync_interval_minutes {
+            connector.next_sync_at = match connector.last_sync_at {
+                Some(last_sync_at) => connector.next_sync_after(last_sync_at),
+                None => connector.is_active.then_some(now),
+            };
+        }
+        connector.updated_at = now;
+
+        self.crm_repository
+            .update(&connector)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(connector)
+    }
+
+    pub async fn delete(&self, id: Uuid) -> ApiResult<()> {
+        self.get(id).await?;
+        self.crm_repository
+            .delete(id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn status(&self, id: Uuid) -> ApiResult<CrmConnectorStatus> {
+        let connector = self.get(id).await?;
+        let counts = self
+            .crm_repository
+            .count_records(id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        let mut problems = Vec::new();
+        for state in [CrmSyncState::Conflict, CrmSyncState::Failed] {
+            let records = self
+                .crm_repository
+                .find_records(id, state, CRM_PROBLEM_LIMIT)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            problems.extend(records);
+        }
+        problems.sort_by_key(|record| std::cmp::Reverse(record.updated_at));
+
+        Ok(CrmConnectorStatus {
+            connector,
+            counts,
+            problems,
+        })
+    }
+
+    /// Syncs the connector right away, paused or not. The outcome is recorded
+    /// like a scheduled run's; a run that couldn't get going is returned as
+    /// an error as well.
+    pub async fn sync_now(&self, id: Uuid) -> ApiResult<CrmConnector> {
+        let mut connector = self.get(id).await?;
+        match self.run(&mut connector, chrono::Utc::now()).await? {
+            Ok(_) => Ok(connector),
+            Err(message) => Err(ApiError::Domain {
+                source: DomainError::external_service("CRM", &message),
+            }),
+        }
+    }
+
+    /// Syncs every connector whose time has come and returns them with the
+    /// outcome recorded. One connector failing doesn't hold up the others.
+    pub async fn sync_due(&self, now: chrono::DateTime<chrono::Utc>) -> ApiResult<Vec<CrmConnector>> {
+        let due = self
+            .crm_repository
+            .find_due(now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut ran = Vec::new();
+        for mut connector in due {
+            // A failed run is recorded on the connector, like a successful one
+            let _ = self.run(&mut connector, now).await?;
+            ran.push(connector);
+        }
+        Ok(ran)
+    }
+
+    fn apply(connector: &mut CrmConnector, input: CrmConnectorInput, now: chrono::DateTime<chrono::Utc>) {
+        let trimmed = |value: Option<String>| value.map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+        connector.base_url = trimmed(input.base_url).map(|url| url.trim_end_matches('/').to_string());
+        connector.company_id = input.company_id;
+        connector.registration_object = trimmed(input.registration_object);
+        if let Some(mappings) = input.field_mappings {
+            connector.field_mappings = mappings
+                .into_iter()
+                .map(|mapping| CrmFieldMapping {
+                    property: mapping.property.trim().to_string(),
+                    ..mapping
+                })
+                .collect();
+        }
+        if let Some(policy) = input.conflict_policy {
+            connector.conflict_policy = policy;
+        }
+        if let Some(minutes) = input.sync_interval_minutes {
+            connector.sync_interval_minutes = minutes;
+        }
+        connector.is_active = input.is_active;
+        if !connector.is_active {
+            connector.next_sync_at = None;
+        } else if connector.next_sync_at.is_none() {
+            connector.next_sync_at = Some(now);
+        }
+    }
+
+    // Syncs and saves the outcome on the connector. The inner result is the
+    // run's; the outer one is saving it.
+    async fn run(
+        &self,
+        connector: &mut CrmConnector,
+        started_at: chrono::DateTime<chrono::Utc>,
+    ) -> ApiResult<Result<CrmSyncSummary, String>> {
+        let outcome = self.push(connector, started_at).await.map_err(|e| e.to_string());
+
+        connector.record_sync(started_at, outcome.clone());
+        self.crm_repository
+            .update(connector)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(outcome)
+    }
+
+    async fn push(&self, connector: &CrmConnector, now: chrono::DateTime<chrono::Utc>) -> ApiResult<CrmSyncSummary> {
+        let events = organized_events(self.event_repository.as_ref(), connector.company_id).await?;
+        let failed: HashSet<(CrmRecordKind, String)> = self
+            .crm_repository
+            .find_records(connector.id, CrmSyncState::Failed, CRM_RETRY_LIMIT)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|record| (record.kind, record.local_key))
+            .collect();
+
+        let mut registrations = Vec::new();
+        for event in &events {
+            let found = self
+                .registration_repository
+                .find_by_event_id(event.id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            registrations.extend(found);
+        }
+        registrations.sort_by_key(|registration| registration.updated_at);
+        let user_ids: Vec<Uuid> = registrations
+            .iter()
+            .filter_map(|registration| registration.user_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let users: HashMap<Uuid, User> = self
+            .user_repository
+            .find_by_ids(&user_ids)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .into_iter()
+            .map(|user| (user.id, user))
+            .collect();
+        let events: HashMap<Uuid, &Event> = events.iter().map(|event| (event.id, event)).collect();
+
+        let sources: Vec<SyncSource> = registrations
+            .iter()
+            .map(|registration| {
+                let user = registration.user_id.and_then(|id| users.get(&id));
+                SyncSource::new(registration, events.get(&registration.event_id).copied(), user)
+            })
+            .filter(|source| {
+                connector
+                    .sync_cursor
+                    .is_none_or(|cursor| source.registration.updated_at >= cursor)
+                    || failed.contains(&(CrmRecordKind::Registration, source.registration.id.to_string()))
+                    || source
+                        .contact_key()
+                        .is_some_and(|key| failed.contains(&(CrmRecordKind::Contact, key)))
+            })
+            .collect();
+
+        let mut summary = CrmSyncSummary::default();
+        // The latest registration speaks for the person
+        let contacts: BTreeMap<String, &SyncSource> = sources
+            .iter()
+            .filter_map(|source| Some((source.contact_key()?, source)))
+            .collect();
+        for (key, source) in contacts {
+            let desired = source.properties(connector, CrmRecordKind::Contact);
+            self.sync_record(connector, CrmRecordKind::Contact, &key, desired, now, &mut summary)
+                .await?;
+        }
+        if connector.registration_object.is_some() {
+            for source in &sources {
+                let key = source.registration.id.to_string();
+                let desired = source.properties(connector, CrmRecordKind::Registration);
+                self.sync_record(connector, CrmRecordKind::Registration, &key, desired, now, &mut summary)
+                    .await?;
+            }
+        }
+        Ok(summary)
+    }
+
+    // Writes the `desired` values to one record under the connector's
+    // conflict policy. The CRM failing is recorded on the record and counted;
+    // only failing to save the record is an error.
+    async fn sync_record(
+        &self,
+        connector: &CrmConnector,
+        kind: CrmRecordKind,
+        key: &str,
+        desired: BTreeMap<String, String>,
+        now: chrono::DateTime<chrono::Utc>,
+        summary: &mut CrmSyncSummary,
+    ) -> ApiResult<()> {
+        let mut record = self
+            .crm_repository
+            .find_record(connector.id, kind, key)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .unwrap_or_else(|| CrmSyncRecord::new(connector.id, kind, key, now));
+        let unchanged = desired
+            .iter()
+            .all(|(property, value)| record.synced_values.get(property) == Some(value));
+        if record.state == CrmSyncState::Synced && record.remote_id.is_some() && unchanged {
+            summary.unchanged += 1;
+            return Ok(());
+        }
+
+        let object = match kind {
+            CrmRecordKind::Contact => connector.provider.contact_object(),
+            CrmRecordKind::Registration => connector.registration_object.as_deref().unwrap_or_default(),
+        };
+        let properties: Vec<String> = desired.keys().cloned().collect();
+        let outcome: DomainResult<(String, bool, CrmResolution, BTreeMap<String, String>)> = async {
+            let mut remote = match &record.remote_id {
+                Some(id) => self.client.get(connector, object, id, &properties).await?,
+                None => None,
+            };
+            if remote.is_none() && kind == CrmRecordKind::Contact {
+                remote = self.client.find_contact(connector, key, &properties).await?;
+            }
+            match remote {
+                Some(remote) => {
+                    let resolution = connector
+                        .conflict_policy
+                        .resolve(&desired, &record.synced_values, &remote.properties);
+                    if !resolution.changes.is_empty() {
+                        self.client.update(connector, object, &remote.id, &resolution.changes).await?;
+                    }
+                    Ok((remote.id, false, resolution, remote.properties))
+                }
+                None => {
+                    let id = self.client.create(connector, object, &desired).await?;
+                    let resolution = CrmResolution {
+                        changes: desired.clone(),
+                        conflicts: Vec::new(),
+                    };
+                    Ok((id, true, resolution, BTreeMap::new()))
+                }
+            }
+        }
+        .await;
+
+        match outcome {
+            Ok((remote_id, created, resolution, mut values)) => {
+                values.extend(resolution.changes.clone());
+                // Keep what Aqio wrote before, so the CRM's edit stays a
+                // conflict on later runs instead of becoming the baseline
+                for property in &resolution.conflicts {
+                    match record.synced_values.get(property) {
+                        Some(synced) => values.insert(property.clone(), synced.clone()),
+                        None => values.remove(property),
+                    };
+                }
+                if created {
+                    summary.created += 1;
+                } else if !resolution.conflicts.is_empty() {
+                    summary.conflicts += 1;
+                } else if !resolution.changes.is_empty() {
+                    summary.updated += 1;
+                } else {
+                    summary.unchanged += 1;
+                }
+                record.remote_id = Some(remote_id);
+                record.state = match resolution.conflicts.is_empty() {
+                    true => CrmSyncState::Synced,
+                    false => CrmSyncState::Conflict,
+                };
+                record.synced_values = values;
+                record.conflicts = resolution.conflicts;
+                record.error = None;
+            }
+            Err(e) => {
+                summary.failed += 1;
+                record.state = CrmSyncState::Failed;
+                record.error = Some(e.to_string());
+            }
+        }
+        record.updated_at = now;
+
+        self.crm_repository
+            .save_record(&record)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+}
+
+// ============================================================================
+// Moderation Application Service
+// ============================================================================
+
+/// A comment, event or profile with its open reports, for the moderation dashboard
+#[derive(Debug, Clone)]
+pub struct ReportedContent {
+    pub target: ReportTarget,
+    pub target_id: Uuid,
+    /// Comment text, event title or user name; empty when the content is gone
+    pub summary: String,
+    /// Who wrote the comment, organizes the event or owns the profile
+    pub author_id: Option<Uuid>,
+    pub event_id: Option<Uuid>,
+    pub hidden: bool,
+    /// Oldest first
+    pub reports: Vec<ContentReport>,
+}
+
+/// What a moderation action changed
+#[derive(Debug, Clone)]
+pub struct ModerationOutcome {
+    pub target: ReportTarget,
+    pub target_id: Uuid,
+    pub action: ModerationAction,
+    pub resolved_reports: u64,
+    pub hidden: bool,
+    /// The author's account before and after a ban
+    pub banned: Option<(User, User)>,
+}
+
+// The reported row, reduced to what moderation needs
+struct ReportableContent {
+    summary: String,
+    author_id: Option<Uuid>,
+    event_id: Option<Uuid>,
+}
+
+/// Spam and abuse reports on comments, events and profiles. Comments and
+/// events are hidden automatically once enough users have reported them,
+/// until an administrator hides them for good, warns or bans the author, or
+/// dismisses the reports. Authors are told by email; emails are best effort.
+#[derive(Clone)]
+pub struct ModerationApplicationService {
+    report_repository: Arc<dyn ContentReportRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    comment_repository: Arc<dyn EventCommentRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    mailer: Arc<dyn Mailer>,
+    /// Distinct reporters after which content is hidden pending review; 0 never hides
+    hide_threshold: i64,
+}
+
+impl ModerationApplicationService {
+    pub const DEFAULT_HIDE_THRESHOLD: i64 = 3;
+    pub const MAX_DETAILS_CHARS: usize = 1000;
+    pub const MAX_NOTE_CHARS: usize = 1000;
+    const MAX_SUMMARY_CHARS: usize = 200;
+
+    pub fn new(
+        report_repository: Arc<dyn ContentReportRepository>,
+        event_repository: Arc<dyn EventRepository>,
+        comment_repository: Arc<dyn EventCommentRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        mailer: Arc<dyn Mailer>,
+    ) -> Self {
+        Self {
+            report_repository,
+            event_repository,
+            comment_repository,
+            user_repository,
+            mailer,
+            hide_threshold: Self::DEFAULT_HIDE_THRESHOLD,
+        }
+    }
+
+    pub fn with_hide_threshold(mut self, hide_threshold: i64) -> Self {
+        self.hide_threshold = hide_threshold;
+        self
+    }
+
+    /// Files the reporter's report and hides the content if it has now been
+    /// reported by enough users
+    pub async fn report(
+        &self,
+        target: ReportTarget,
+        target_id: Uuid,
+        reporter: &User,
+        reason: ReportReason,
+        details: Option<String>,
+    ) -> ApiResult<ContentReport> {
+        let details = optional_text("details", "Details", details, Self::MAX_DETAILS_CHARS)?;
+        let content = self
+            .content(target, target_id)
+            .await?
+            .ok_or_else(|| Self::not_found(target, target_id))?;
+        if content.author_id == Some(reporter.id) {
+            return Err(ApiError::bad_request("You can't report your own content"));
+        }
+
+        let report = ContentReport::new(target, target_id, reporter.id, reason, details);
+        self.report_repository
+            .create(&report)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        // Profiles stay visible; a moderator decides whether to ban
+        if target != ReportTarget::Profile && self.hide_threshold > 0 {
+            let reporters = self
+                .report_repository
+                .count_open_reporters(target, target_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            if reporters >= self.hide_threshold {
+                self.report_repository
+                    .hide(target, target_id, None, chrono::Utc::now())
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                tracing::info!("Hid {:?} {} after reports from {} users", target, target_id, reporters);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Reports a comment, checking it belongs to the event in the URL
+    pub async fn report_comment(
+        &self,
+        event_id: Uuid,
+        comment_id: Uuid,
+        reporter: &User,
+        reason: ReportReason,
+        details: Option<String>,
+    ) -> ApiResult<ContentReport> {
+        let comment = self
+            .comment_repository
+            .find_by_id(comment_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        if comment.is_none_or(|comment| comment.event_id != event_id) {
+            return Err(Self::not_found(ReportTarget::Comment, comment_id));
+        }
+        self.report(ReportTarget::Comment, comment_id, reporter, reason, details).await
+    }
+
+    /// Everything with open reports, most reported first
+    pub async fn reported_content(&self) -> ApiResult<Vec<ReportedContent>> {
+        let reports = self
+            .report_repository
+            .find_open()
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let mut grouped: Vec<((ReportTarget, Uuid), Vec<ContentReport>)> = Vec::new();
+        for report in reports {
+            let key = (report.target, report.target_id);
+            match grouped.iter_mut().find(|(existing, _)| *existing == key) {
+                Some((_, reports)) => reports.push(report),
+                None => grouped.push((key, vec![report])),
+            }
+        }
+        // Stable, so equally reported content stays oldest first
+        grouped.sort_by_key(|(_, reports)| std::cmp::Reverse(reports.len()));
+
+        let mut hidden = HashSet::new();
+        for target in [ReportTarget::Comment, ReportTarget::Event, ReportTarget::Profile] {
+            let ids: Vec<Uuid> = grouped
+                .iter()
+                .filter(|((t, _), _)| *t == target)
+                .map(|((_, id), _)| *id)
+                .collect();
+            let found = self
+                .report_repository
+                .find_hidden(target, &ids)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            hidden.extend(found.into_iter().map(|id| (target, id)));
+        }
+
+        let mut reported = Vec::with_capacity(grouped.len());
+        for ((target, target_id), reports) in grouped {
+            let content = self.content(target, target_id).await?;
+            reported.push(ReportedContent {
+                target,
+                target_id,
+                summary: content.as_ref().map(|c| c.summary.clone()).unwrap_or_default(),
+                author_id: content.as_ref().and_then(|c| c.author_id),
+                event_id: content.as_ref().and_then(|c| c.event_id),
+                hidden: hidden.contains(&(target, target_id)),
+                reports,
+            });
+        }
+        Ok(reported)
+    }
+
+    /// Applies the administrator's decision and closes the content's open reports
+    pub async fn act(
+        &self,
+        target: ReportTarget,
+        target_id: Uuid,
+        action: ModerationAction,
+        moderator: &User,
+        note: Option<String>,
+    ) -> ApiResult<ModerationOutcome> {
+        let note = optional_text("note", "Note", note, Self::MAX_NOTE_CHARS)?;
+        let content = self
+            .content(target, target_id)
+            .await?
+            .ok_or_else(|| Self::not_found(target, target_id))?;
+        let now = chrono::Utc::now();
+
+        let mut hidden = !self
+            .report_repository
+            .find_hidden(target, &[target_id])
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .is_empty();
+        let mut banned = None;
+        match action {
+            ModerationAction::Hide => {
+                if target == ReportTarget::Profile {
+                    return Err(ApiError::bad_request("Profiles can't be hidden; warn or ban the user instead"));
+                }
+                self.hide(target, target_id, moderator.id, now).await?;
+                hidden = true;
+            }
+            ModerationAction::Warn => {
+                let author = self.get_author(&content).await?;
+                self.send(
+                    ModerationEmail::Warned { target, summary: &content.summary },
+                    &author,
+                    note.as_deref(),
+                )
+                .await;
+            }
+            ModerationAction::Ban => {
+                let previous = self.get_author(&content).await?;
+                if previous.id == moderator.id || matches!(previous.role, UserRole::Admin) {
+                    return Err(ApiError::bad_request("Administrators can't be banned"));
+                }
+                let mut user = previous.clone();
+                user.is_active = false;
+                user.updated_at = now;
+                self.user_repository
+                    .update(&user)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                if target != ReportTarget::Profile {
+                    self.hide(target, target_id, moderator.id, now).await?;
+                    hidden = true;
+                }
+                self.send(ModerationEmail::Banned, &user, note.as_deref()).await;
+                banned = Some((previous, user));
+            }
+            ModerationAction::Dismiss => {
+                self.report_repository
+                    .unhide(target, target_id)
+                    .await
+                    .map_err(|e| ApiError::Domain { source: e })?;
+                hidden = false;
+            }
+        }
+
+        let status = match action {
+            ModerationAction::Dismiss => ReportStatus::Dismissed,
+            _ => ReportStatus::Actioned,
+        };
+        let resolved_reports = self
+            .report_repository
+            .resolve_open(target, target_id, status, action, moderator.id, now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        Ok(ModerationOutcome {
+            target,
+            target_id,
+            action,
+            resolved_reports,
+            hidden,
+            banned,
+        })
+    }
+
+    async fn content(&self, target: ReportTarget, target_id: Uuid) -> ApiResult<Option<ReportableContent>> {
+        let content = match target {
+            ReportTarget::Comment => self
+                .comment_repository
+                .find_by_id(target_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .filter(|comment| !comment.is_deleted())
+                .map(|comment| ReportableContent {
+                    summary: comment.content.chars().take(Self::MAX_SUMMARY_CHARS).collect(),
+                    author_id: comment.author_id,
+                    event_id: Some(comment.event_id),
+                }),
+            ReportTarget::Event => self
+                .event_repository
+                .find_by_id(target_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .map(|event| ReportableContent {
+                    summary: event.title,
+                    author_id: Some(event.organizer_id),
+                    event_id: Some(event.id),
+                }),
+            ReportTarget::Profile => self
+                .user_repository
+                .find_by_id(target_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+                .map(|user| ReportableContent {
+                    summary: user.name,
+                    author_id: Some(user.id),
+                    event_id: None,
+                }),
+        };
+        Ok(content)
+    }
+
+    async fn get_author(&self, content: &ReportableContent) -> ApiResult<User> {
+        let author_id = content
+            .author_id
+            .ok_or_else(|| ApiError::bad_request("The content has no author to act on"))?;
+        self.user_repository
+            .find_by_id(author_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("User with ID {}", author_id)))
+    }
+
+    async fn hide(&self, target: ReportTarget, target_id: Uuid, moderator_id: Uuid, now: chrono::DateTime<chrono::Utc>) -> ApiResult<()> {
+        self.report_repository
+            .hide(target, target_id, Some(moderator_id), now)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    fn not_found(target: ReportTarget, target_id: Uuid) -> ApiError {
+        let what = match target {
+            ReportTarget::Comment => "Comment",
+            ReportTarget::Event => "Event",
+            ReportTarget::Profile => "User",
+        };
+        ApiError::not_found(format!("{} with ID {}", what, target_id))
+    }
+
+    // Best effort: the decision has already been stored
+    async fn send(&self, email: ModerationEmail<'_>, recipient: &User, note: Option<&str>) {
+        let message = render_moderation_email(email, recipient, note, &Localization::for_user(recipient));
+        if let Err(e) = self.mailer.send(&message).await {
+            tracing::warn!("Moderation email to {} failed: {}", recipient.email, e);
+        }
+    }
+}
+
+// ============================================================================
+// Invitation Application Service
+// ============================================================================
+
+/// Builds and checks the tracking URLs embedded in invitation emails
+///
+/// The open pixel is addressed by the invitation token alone. Redirect links
+/// also carry an HMAC of token and target, so they can't be bent into an open
+/// redirect to some other site.
+#[derive(Clone)]
+pub struct InvitationTracking {
+    /// Origin the API is reachable at from the recipient's mail client
+    public_url: String,
+    secret: Arc<[u8]>,
+}
+
+impl InvitationTracking {
+    pub fn new(public_url: impl Into<String>, secret: impl AsRef<[u8]>) -> Self {
+        Self {
+            public_url: public_url.into().trim_end_matches('/').to_string(),
+            secret: Arc::from(secret.as_ref()),
+        }
+    }
+
+    pub fn pixel_url(&self, token: &str) -> String {
+        format!("{}/api/v1/public/invitations/{}/open.gif", self.public_url, token)
+    }
+
+    /// A link through the click endpoint that ends up at `target`
+    pub fn link_url(&self, token: &str, target: &str) -> ApiResult<String> {
+        let target_url = reqwest::Url::parse(target)
+            .ok()
+            .filter(|url| matches!(url.scheme(), "http" | "https"))
+            .ok_or_else(|| ApiError::validation("url", "Only http and https links can be tracked"))?;
+        let signature = self.signature(token, target_url.as_str())?;
+        let link = reqwest::Url::parse_with_params(
+            &format!("{}/api/v1/public/invitations/{}/click", self.public_url, token),
+            &[("url", target_url.as_str()), ("sig", signature.as_str())],
+        )
+        .map_err(|e| ApiError::internal(format!("Invalid tracking link: {}", e)))?;
+        Ok(link.into())
+    }
+
+    pub fn verify_link(&self, token: &str, target: &str, signature: &str) -> bool {
+        jsonwebtoken::crypto::verify(
+            signature,
+            Self::signed_message(token, target).as_bytes(),
+            &jsonwebtoken::DecodingKey::from_secret(&self.secret),
+            jsonwebtoken::Algorithm::HS256,
+        )
+        .unwrap_or(false)
+    }
+
+    fn signature(&self, token: &str, target: &str) -> ApiResult<String> {
+        jsonwebtoken::crypto::sign(
+            Self::signed_message(token, target).as_bytes(),
+            &jsonwebtoken::EncodingKey::from_secret(&self.secret),
+            jsonwebtoken::Algorithm::HS256,
+        )
+        .map_err(|e| ApiError::internal(format!("Failed to sign tracking link: {}", e)))
+    }
+
+    fn signed_message(token: &str, target: &str) -> String {
+        format!("{}\n{}", token, target)
+    }
+}
+
+#[derive(Clone)]
+pub struct InvitationApplicationService {
+    invitation_repository: Arc<dyn EventInvitationRepository>,
+    tracking: InvitationTracking,
+    suppression_repository: Option<Arc<dyn EmailSuppressionRepository>>,
+}
+
+impl InvitationApplicationService {
+    pub fn new(invitation_repository: Arc<dyn EventInvitationRepository>, tracking: InvitationTracking) -> Self {
+        Self {
+            invitation_repository,
+            tracking,
+            suppression_repository: None,
+        }
+    }
+
+    /// Refuses new invitations to addresses that bounced or complained
+    pub fn with_suppressions(mut self, suppression_repository: Arc<dyn EmailSuppressionRepository>) -> Self {
+        self.suppression_repository = Some(suppression_repository);
+        self
+    }
+
+    pub fn tracking(&self) -> &InvitationTracking {
+        &self.tracking
+    }
+
+    /// The invitation's token for tracking URLs, issuing one for invitations
+    /// created before every invitation got a token
+    pub async fn tracking_token(&self, invitation_id: Uuid) -> ApiResult<(EventInvitation, String)> {
+        let mut invitation = self.get_invitation_by_id(invitation_id).await?;
+        if let Some(token) = invitation.invitation_token.clone() {
+            return Ok((invitation, token));
+        }
+
+        let token = InvitationService::new().generate_invitation_token();
+        invitation.invitation_token = Some(token.clone());
+        invitation.updated_at = chrono::Utc::now();
+        self.invitation_repository
+            .update(&invitation)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok((invitation, token))
+    }
+
+    /// Record that the invitation email was opened; unknown tokens are ignored
+    /// since the pixel is served either way
+    pub async fn record_open(&self, token: &str) -> ApiResult<()> {
+        self.record(token, |service, invitation| service.mark_as_opened(invitation)).await
+    }
+
+    /// Check a tracked link and record the click, returning where to send the
+    /// recipient
+    pub async fn record_click(&self, token: &str, target: &str, signature: &str) -> ApiResult<String> {
+        if !self.tracking.verify_link(token, target, signature) {
+            return Err(ApiError::bad_request("Invalid or tampered link"));
+        }
+        self.record(token, |service, invitation| service.mark_as_clicked(invitation)).await?;
+        Ok(target.to_string())
+    }
+
+    async fn record(&self, token: &str, mark: impl Fn(&InvitationService, &mut EventInvitation)) -> ApiResult<()> {
+        let Some(mut invitation) = self
+            .invitation_repository
+            .find_by_token(token)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+        else {
+            return Ok(());
+        };
+
+        let before = (invitation.status.clone(), invitation.opened_at, invitation.clicked_at);
+        mark(&InvitationService::new(), &mut invitation);
+        if before == (invitation.status.clone(), invitation.opened_at, invitation.clicked_at) {
+            return Ok(());
+        }
+        self.invitation_repository
+            .update(&invitation)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn funnel(&self, event_id: Uuid) -> ApiResult<InvitationFunnel> {
+        let invitations = self.get_invitations_by_event(event_id).await?;
+        Ok(InvitationService::new().funnel(&invitations))
+    }
+
+    pub async fn get_invitation_by_id(&self, invitation_id: Uuid) -> ApiResult<EventInvitation> {
+        self.invitation_repository
+            .find_by_id(invitation_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })?
+            .ok_or_else(|| ApiError::not_found(format!("Invitation with ID {}", invitation_id)))
+    }
+
+    pub async fn get_invitations_by_event(
+        &self,
+        event_id: Uuid,
+    ) -> ApiResult<Vec<EventInvitation>> {
+        self.invitation_repository
+            .find_by_event_id(event_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn get_invitations_by_user(&self, user_id: Uuid) -> ApiResult<Vec<EventInvitation>> {
+        self.invitation_repository
+            .find_by_user_id(user_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn create_invitation(&self, invitation: &EventInvitation) -> ApiResult<()> {
+        // Check if user/email already invited
+        if let Some(user_id) = invitation.invited_user_id {
+            if self
+                .invitation_repository
+                .user_invited_to_event(user_id, invitation.event_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+            {
+                return Err(ApiError::validation(
+                    "user_id",
+                    "User already invited to this event",
+                ));
+            }
+        }
+
+        if let Some(email) = &invitation.invited_email {
+            if self
+                .invitation_repository
+                .email_invited_to_event(email, invitation.event_id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?
+            {
+                return Err(ApiError::validation(
+                    "email",
+                    "Email already invited to this event",
+                ));
+            }
+
+            if let Some(suppression) = self.find_suppression(email).await? {
+                let message = match suppression.reason {
+                    DeliveryFailure::Bounce => "Email to this address bounced; it no longer receives invitations",
+                    DeliveryFailure::Complaint => "This address reported our email as spam; it no longer receives invitations",
+                };
+                return Err(ApiError::validation("email", message));
+            }
+        }
+
+        self.invitation_repository
+            .create(invitation)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn update_invitation_status(
+        &self,
+        invitation_id: Uuid,
+        status: InvitationStatus,
+    ) -> ApiResult<()> {
+        // (Re)sending goes nowhere once the address bounced or complained
+        if status == InvitationStatus::Sent {
+            let invitation = self.get_invitation_by_id(invitation_id).await?;
+            InvitationService::new()
+                .check_deliverable(&invitation)
+                .map_err(|e| ApiError::Domain { source: e })?;
+        }
+
+        self.invitation_repository
+            .update_status(invitation_id, status)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    pub async fn delete_invitation(&self, invitation_id: Uuid) -> ApiResult<()> {
+        self.invitation_repository
+            .delete(invitation_id)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    async fn find_suppression(&self, email: &str) -> ApiResult<Option<EmailSuppression>> {
+        let Some(suppressions) = &self.suppression_repository else {
+            return Ok(None);
+        };
+        suppressions
+            .find_by_email(email)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+}
+
+// ============================================================================
+// Event Registration Application Service
+// ============================================================================
+
+#[derive(Clone)]
+pub struct EventRegistrationApplicationService {
+    registration_repository: Arc<dyn EventRegistrationRepository>,
+    overbooking_repository: Option<Arc<dyn OverbookingPolicyRepository>>,
+    notifier: RegistrationNotifier,
+}
+
+/// How long a promoted attendee has to confirm the offered spot
+const PROMOTION_CONFIRM_HOURS: i64 = 24;
+
+/// A waitlisted (or freshly promoted) registration as seen by its owner
+#[derive(Debug, Clone)]
+pub struct WaitlistStatus {
+    pub registration: EventRegistration,
+    /// 1-based position, `None` once the registration has left the waitlist
+    pub position: Option<usize>,
+    pub waitlist_size: usize,
+}
+
+/// Outcome of merging duplicate registrations
+#[derive(Debug, Clone)]
+pub struct RegistrationMerge {
+    /// The kept registration as it was before the merge
+    pub before: EventRegistration,
+    pub merged: EventRegistration,
+    /// The duplicates that were folded in and deleted
+    pub removed: Vec<EventRegistration>,
+}
+
+impl EventRegistrationApplicationService {
+    pub fn new(registration_repository: Arc<dyn EventRegistrationRepository>) -> Self {
+        Self {
             registration_repository,
+            overbooking_repository: None,
+            notifier: RegistrationNotifier::new(),
         }
     }
 
+    /// Seats registrations up to the capacity the event's overbooking policy allows
+    pub fn with_overbooking(mut self, overbooking_repository: Arc<dyn OverbookingPolicyRepository>) -> Self {
+        self.overbooking_repository = Some(overbooking_repository);
+        self
+    }
+
+    /// Registrations the event takes before the waitlist starts: its
+    /// capacity, raised by its overbooking policy. `None` without a limit.
+    pub async fn bookable_capacity(&self, event: &Event) -> ApiResult<Option<i32>> {
+        let Some(capacity) = event.max_attendees else {
+            return Ok(None);
+        };
+        let policy = match &self.overbooking_repository {
+            Some(policies) => policies
+                .find_by_event_id(event.id)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?,
+            None => None,
+        };
+        Ok(Some(policy.map_or(capacity, |policy| policy.bookable_capacity(capacity))))
+    }
+
+    pub fn notifier(&self) -> &RegistrationNotifier {
+        &self.notifier
+    }
+
     pub async fn get_registration_by_id(&self, registration_id: Uuid) -> ApiResult<EventRegistration> {
         self.registration_repository
             .find_by_id(registration_id)
@@ -463,19 +12060,8 @@ impl EventRegistrationApplicationService {
             .map_err(|e| ApiError::Domain { source: e })
     }
 
-    pub async fn get_registration_by_event_and_user(
-        &self,
-        event_id: Uuid,
-        user_id: Uuid,
-    ) -> ApiResult<Option<EventRegistration>> {
-        self.registration_repository
-            .find_by_event_and_user(event_id, user_id)
-            .await
-            .map_err(|e| ApiError::Domain { source: e })
-    }
-
-    pub async fn create_registration(&self, registration: &EventRegistration) -> ApiResult<()> {
-        // Check for duplicate registration
+    // Refuses a second registration of the same user for an event
+    async fn ensure_not_registered(&self, registration: &EventRegistration) -> ApiResult<()> {
         if let Some(user_id) = registration.user_id {
             if let Some(_existing) = self
                 .registration_repository
@@ -489,11 +12075,51 @@ impl EventRegistrationApplicationService {
                 ));
             }
         }
+        Ok(())
+    }
+
+    /// Register for `event`; refused outside its registration window
+    ///
+    /// Once the event is full the registration goes on the waitlist if the
+    /// event has one. Returns the registration with the status it got.
+    pub async fn register(&self, event: &Event, registration: &EventRegistration) -> ApiResult<EventRegistration> {
+        EventService::new()
+            .can_register_for_event(event)
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.place(event, registration.clone()).await
+    }
+
+    // Give `registration` a seat, or a waitlist spot once the event is full.
+    // The seat is claimed by the repository's conditional insert rather than
+    // counted here first, so parallel registrations can't overbook.
+    async fn place(&self, event: &Event, mut registration: EventRegistration) -> ApiResult<EventRegistration> {
+        self.ensure_not_registered(&registration).await?;
+        let registration_service = RegistrationService::new();
+        registration_service.register_for_event(&mut registration, false);
+
+        if let Some(capacity) = self.bookable_capacity(event).await? {
+            let seated = self
+                .registration_repository
+                .create_within_capacity(&registration, capacity)
+                .await
+                .map_err(|e| ApiError::Domain { source: e })?;
+            if seated {
+                return Ok(registration);
+            }
+            if !event.allow_waitlist {
+                return Err(ApiError::conflict("This event is fully booked"));
+            }
+
+            let waitlisted = self.get_event_waitlist_count(event.id).await?;
+            registration_service.register_for_event(&mut registration, true);
+            registration.waitlist_position = Some(registration_service.calculate_waitlist_position(waitlisted));
+        }
 
         self.registration_repository
-            .create(registration)
+            .create(&registration)
             .await
-            .map_err(|e| ApiError::Domain { source: e })
+            .map_err(|e| ApiError::Domain { source: e })?;
+        Ok(registration)
     }
 
     pub async fn update_registration(&self, registration: &EventRegistration) -> ApiResult<()> {
@@ -509,6 +12135,7 @@ impl EventRegistrationApplicationService {
         status: RegistrationStatus,
     ) -> ApiResult<()> {
         let mut registration = self.get_registration_by_id(registration_id).await?;
+        let previous_status = registration.status.clone();
         
         // Handle status-specific logic
         match status {
@@ -524,7 +12151,20 @@ impl EventRegistrationApplicationService {
         registration.status = status;
         registration.updated_at = chrono::Utc::now();
 
-        self.update_registration(&registration).await
+        self.update_registration(&registration).await?;
+
+        // A cancelled spot goes to the next person on the waitlist
+        let freed_spot = registration.status == RegistrationStatus::Cancelled
+            && matches!(previous_status, RegistrationStatus::Registered | RegistrationStatus::Attended);
+        if freed_spot {
+            self.promote_next(registration.event_id).await?;
+        } else if previous_status == RegistrationStatus::Waitlisted
+            && registration.status != RegistrationStatus::Waitlisted
+        {
+            self.notify_waitlist_moved(registration.event_id).await?;
+        }
+
+        Ok(())
     }
 
     pub async fn cancel_registration(&self, registration_id: Uuid) -> ApiResult<()> {
@@ -544,6 +12184,56 @@ impl EventRegistrationApplicationService {
             .map_err(|e| ApiError::Domain { source: e })
     }
 
+    /// Consolidate registrations of the same person for an event
+    ///
+    /// The earliest registration is kept and takes over guests, answers and
+    /// requirements from the others, which are deleted.
+    pub async fn merge_registrations(
+        &self,
+        event_id: Uuid,
+        registration_ids: &[Uuid],
+    ) -> ApiResult<RegistrationMerge> {
+        let mut ids = registration_ids.to_vec();
+        ids.sort();
+        ids.dedup();
+        if ids.len() < 2 {
+            return Err(ApiError::validation(
+                "registration_ids",
+                "At least two different registrations are needed for a merge",
+            ));
+        }
+
+        let mut registrations = Vec::with_capacity(ids.len());
+        for id in ids {
+            let registration = self.get_registration_by_id(id).await?;
+            if registration.event_id != event_id {
+                return Err(ApiError::validation(
+                    "registration_ids",
+                    format!("Registration {} belongs to another event", id),
+                ));
+            }
+            registrations.push(registration);
+        }
+        registrations.sort_by_key(|registration| (registration.registered_at, registration.id));
+
+        let removed = registrations.split_off(1);
+        let before = registrations.remove(0);
+        let mut merged = before.clone();
+        RegistrationService::new()
+            .merge_duplicates(&mut merged, &removed, chrono::Utc::now())
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        self.update_registration(&merged).await?;
+        for duplicate in &removed {
+            self.delete_registration(duplicate.id).await?;
+        }
+        if removed.iter().any(|r| r.status == RegistrationStatus::Waitlisted) {
+            self.notify_waitlist_moved(event_id).await?;
+        }
+
+        Ok(RegistrationMerge { before, merged, removed })
+    }
+
     pub async fn get_event_attendance_count(&self, event_id: Uuid) -> ApiResult<usize> {
         let registrations = self.get_registrations_by_event(event_id).await?;
         let count = registrations
@@ -561,9 +12251,307 @@ impl EventRegistrationApplicationService {
             .count();
         Ok(count)
     }
+
+    /// Register someone without an account from the public event page
+    ///
+    /// Enforces the registration window and capacity; once the event is full
+    /// the registration goes on the waitlist if the event has one.
+    pub async fn register_guest(
+        &self,
+        event: &Event,
+        registration: EventRegistration,
+    ) -> ApiResult<EventRegistration> {
+        EventService::new()
+            .can_register_for_event(event)
+            .map_err(|e| ApiError::Domain { source: e })?;
+
+        let email = registration.registrant_email.clone().unwrap_or_default();
+        if !email.contains('@') {
+            return Err(ApiError::validation("registrant_email", "Invalid email format"));
+        }
+
+        if registration.guest_count > 0 {
+            if !event.allow_guests {
+                return Err(ApiError::validation("guest_count", "This event does not allow guests"));
+            }
+            if event
+                .max_guests_per_person
+                .is_some_and(|max| registration.guest_count > max)
+            {
+                return Err(ApiError::validation("guest_count", "Too many guests for this event"));
+            }
+        }
+
+        let registrations = self.get_registrations_by_event(event.id).await?;
+        let already_registered = registrations.iter().any(|r| {
+            r.status != RegistrationStatus::Cancelled
+                && r.registrant_email
+                    .as_deref()
+                    .is_some_and(|existing| existing.eq_ignore_ascii_case(&email))
+        });
+        if already_registered {
+            return Err(ApiError::validation(
+                "registrant_email",
+                "This email is already registered for the event",
+            ));
+        }
+
+        self.place(event, registration).await
+    }
+
+    /// Offer the spot to whoever has waited longest; returns the promoted registration
+    pub async fn promote_next(&self, event_id: Uuid) -> ApiResult<Option<EventRegistration>> {
+        let waitlist = self.waitlist(event_id).await?;
+        let Some(mut next) = waitlist.into_iter().next() else {
+            return Ok(None);
+        };
+
+        let confirm_by = chrono::Utc::now() + chrono::Duration::hours(PROMOTION_CONFIRM_HOURS);
+        RegistrationService::new()
+            .promote_from_waitlist(&mut next, confirm_by)
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.update_registration(&next).await?;
+
+        if let Some(user_id) = next.user_id {
+            self.notifier.notify(
+                user_id,
+                RegistrationNotification::Promoted {
+                    registration_id: next.id,
+                    event_id,
+                    confirm_by,
+                },
+            );
+        }
+        self.notify_waitlist_moved(event_id).await?;
+
+        Ok(Some(next))
+    }
+
+    /// Accept a spot offered by `promote_next`
+    ///
+    /// An expired offer is released to the next person on the waitlist
+    /// before the error is returned.
+    pub async fn confirm_promotion(&self, registration_id: Uuid) -> ApiResult<EventRegistration> {
+        let mut registration = self.get_registration_by_id(registration_id).await?;
+        if self.release_if_expired(&registration).await? {
+            return Err(ApiError::conflict(
+                "The spot offer has expired and was passed on to the next person on the waitlist",
+            ));
+        }
+
+        RegistrationService::new()
+            .confirm_promotion(&mut registration, chrono::Utc::now())
+            .map_err(|e| ApiError::Domain { source: e })?;
+        self.update_registration(&registration).await?;
+
+        Ok(registration)
+    }
+
+    pub async fn waitlist_status(&self, registration_id: Uuid) -> ApiResult<WaitlistStatus> {
+        let registration = self.get_registration_by_id(registration_id).await?;
+        // Offers are expired lazily, whenever somebody looks at them
+        self.release_if_expired(&registration).await?;
+
+        let registration = self.get_registration_by_id(registration_id).await?;
+        let waitlist = self.waitlist(registration.event_id).await?;
+        let position = waitlist
+            .iter()
+            .position(|r| r.id == registration.id)
+            .map(|index| index + 1);
+
+        Ok(WaitlistStatus {
+            registration,
+            position,
+            waitlist_size: waitlist.len(),
+        })
+    }
+
+    // Waitlisted registrations in the order they will be promoted
+    async fn waitlist(&self, event_id: Uuid) -> ApiResult<Vec<EventRegistration>> {
+        let mut waitlist: Vec<EventRegistration> = self
+            .get_registrations_by_event(event_id)
+            .await?
+            .into_iter()
+            .filter(|r| r.status == RegistrationStatus::Waitlisted)
+            .collect();
+        waitlist.sort_by_key(|r| {
+            (
+                r.waitlist_added_at.unwrap_or(r.registered_at),
+                r.waitlist_position.unwrap_or(i32::MAX),
+            )
+        });
+        Ok(waitlist)
+    }
+
+    // Cancels an unconfirmed offer past its deadline and promotes the next person
+    async fn release_if_expired(&self, registration: &EventRegistration) -> ApiResult<bool> {
+        if !RegistrationService::new().promotion_expired(registration, chrono::Utc::now()) {
+            return Ok(false);
+        }
+
+        let mut released = registration.clone();
+        released.status = RegistrationStatus::Cancelled;
+        released.cancelled_at = Some(chrono::Utc::now());
+        released.promotion_expires_at = None;
+        released.updated_at = chrono::Utc::now();
+        self.update_registration(&released).await?;
+
+        if let Some(user_id) = released.user_id {
+            self.notifier.notify(
+                user_id,
+                RegistrationNotification::PromotionExpired {
+                    registration_id: released.id,
+                    event_id: released.event_id,
+                },
+            );
+        }
+        self.promote_next(released.event_id).await?;
+
+        Ok(true)
+    }
+
+    async fn notify_waitlist_moved(&self, event_id: Uuid) -> ApiResult<()> {
+        for registration in self.waitlist(event_id).await? {
+            if let Some(user_id) = registration.user_id {
+                self.notifier.notify(
+                    user_id,
+                    RegistrationNotification::WaitlistMoved {
+                        registration_id: registration.id,
+                        event_id,
+                    },
+                );
+            }
+        }
+        Ok(())
+    }
 }
 
 // Tests are in a separate file for better organization
 #[cfg(test)]
 #[path = "services_test.rs"]
 mod services_test;
+
+// ============================================================================
+// Audit Log Application Service
+// ============================================================================
+
+/// Who performed an audited change
+#[derive(Debug, Clone)]
+pub struct AuditActor {
+    pub user_id: Option<Uuid>,
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Clone)]
+pub struct AuditLogApplicationService {
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl AuditLogApplicationService {
+    pub fn new(audit_log_repository: Arc<dyn AuditLogRepository>) -> Self {
+        Self {
+            audit_log_repository,
+        }
+    }
+
+    pub async fn list_entries(
+        &self,
+        filter: &AuditLogFilter,
+        pagination: PaginationParams,
+    ) -> ApiResult<PaginatedResult<AuditLogEntry>> {
+        self.audit_log_repository
+            .find_by_filter(filter, pagination)
+            .await
+            .map_err(|e| ApiError::Domain { source: e })
+    }
+
+    /// Record a change made by `actor` after it has been persisted
+    ///
+    /// `before`/`after` are stored as JSON snapshots and diffed to fill in
+    /// `changed_fields`. Failures are logged rather than returned so a broken
+    /// audit trail never rolls back an administrative action the caller has
+    /// already committed.
+    pub async fn record_change<T: Serialize>(
+        &self,
+        actor: &AuditActor,
+        table_name: &str,
+        record_id: impl Into<String>,
+        action: AuditAction,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) {
+        self.record(actor, None, table_name, record_id, action, before, after).await
+    }
+
+    /// Like `record_change`, for a change that belongs to an event so it
+    /// shows up in that event's history
+    #[allow(clippy::too_many_arguments)]
+    pub async fn record_event_change<T: Serialize>(
+        &self,
+        actor: &AuditActor,
+        event_id: Uuid,
+        table_name: &str,
+        record_id: impl Into<String>,
+        action: AuditAction,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) {
+        self.record(actor, Some(event_id), table_name, record_id, action, before, after).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn record<T: Serialize>(
+        &self,
+        actor: &AuditActor,
+        event_id: Option<Uuid>,
+        table_name: &str,
+        record_id: impl Into<String>,
+        action: AuditAction,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) {
+        let mut entry = AuditLogEntry::new(table_name, record_id, action);
+        entry.event_id = event_id;
+        entry.user_id = actor.user_id;
+        entry.user_name = Some(actor.name.clone());
+        entry.user_email = Some(actor.email.clone());
+
+        let before = before.and_then(|value| serde_json::to_value(value).ok());
+        let after = after.and_then(|value| serde_json::to_value(value).ok());
+        entry.changed_fields = changed_fields(before.as_ref(), after.as_ref());
+        entry.old_values = before.map(|value| value.to_string());
+        entry.new_values = after.map(|value| value.to_string());
+
+        if let Err(e) = self.audit_log_repository.create(&entry).await {
+            tracing::warn!(
+                "Failed to record audit log entry for {}/{}: {}",
+                entry.table_name,
+                entry.record_id,
+                e
+            );
+        }
+    }
+}
+
+// Top-level fields whose values differ between two JSON snapshots. Bookkeeping
+// timestamps are skipped since they change on every write.
+fn changed_fields(before: Option<&serde_json::Value>, after: Option<&serde_json::Value>) -> Vec<String> {
+    const IGNORED: [&str; 2] = ["created_at", "updated_at"];
+
+    let empty = serde_json::Map::new();
+    let before = before.and_then(|v| v.as_object()).unwrap_or(&empty);
+    let after = after.and_then(|v| v.as_object()).unwrap_or(&empty);
+
+    let mut fields: Vec<String> = before
+        .keys()
+        .chain(after.keys())
+        .filter(|key| !IGNORED.contains(&key.as_str()))
+        .filter(|key| before.get(*key) != after.get(*key))
+        .cloned()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    fields.sort();
+    fields
+}
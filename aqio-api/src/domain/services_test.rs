@@ -9,6 +9,9 @@ mod tests {
         helpers::*,
     };
     use crate::domain::{dto::*, errors::*, services::*};
+    use crate::domain::locale::Localization;
+    use crate::domain::notifications::RegistrationNotification;
+    use crate::domain::anonymize::{Anonymizer, PiiField};
     use aqio_core::*;
     use chrono::Utc;
     use uuid::Uuid;
@@ -31,6 +34,18 @@ mod tests {
         assert_eq!(event.title, "Test Event");
     }
 
+    #[tokio::test]
+    async fn test_create_event_keeps_picked_co_organizers() {
+        let (service, _mock_repo) = create_mock_event_service();
+        let organizer_id = Uuid::new_v4();
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut request = create_event_request();
+        request.co_organizers = Some(vec![first, organizer_id, second, first]);
+
+        let event = service.create_event(request, organizer_id).await.unwrap();
+        assert_eq!(event.co_organizers, vec![first, second]);
+    }
+
     #[tokio::test]
     async fn test_create_event_validation_fails() {
         let (service, _mock_repo) = create_mock_event_service();
@@ -69,6 +84,361 @@ mod tests {
         assert_eq!(result.unwrap().id, event.id);
     }
 
+    #[tokio::test]
+    async fn test_get_public_event_hides_drafts_and_private_events() {
+        let (service, mock_repo) = create_mock_event_service();
+        let published = TestEventBuilder::new().with_slug("open-day").published().build();
+        let draft = TestEventBuilder::new().with_slug("draft-day").build();
+        let private = TestEventBuilder::new().with_slug("board-meeting").published().private().build();
+
+        mock_repo.add_event(published.clone()).await;
+        mock_repo.add_event(draft).await;
+        mock_repo.add_event(private).await;
+
+        assert_eq!(service.get_public_event("open-day").await.unwrap().id, published.id);
+        assert!(matches!(
+            service.get_public_event("draft-day").await,
+            Err(ApiError::NotFound { .. })
+        ));
+        assert!(matches!(
+            service.get_public_event("board-meeting").await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sitemap_lists_only_public_events() {
+        let (service, mock_repo) = create_mock_event_service();
+        let reports = MockContentReportRepository::new();
+        let service = service.with_moderation(std::sync::Arc::new(reports.clone()));
+        let published = TestEventBuilder::new().with_slug("open-day").published().build();
+        let reported = TestEventBuilder::new().with_slug("spam-day").published().build();
+        mock_repo.add_event(published.clone()).await;
+        mock_repo.add_event(reported.clone()).await;
+        mock_repo.add_event(TestEventBuilder::new().with_slug("draft-day").build()).await;
+        mock_repo.add_event(TestEventBuilder::new().with_slug("board-meeting").published().private().build()).await;
+        reports.hide(ReportTarget::Event, reported.id, None, Utc::now()).await.unwrap();
+
+        let sitemap = service.public_sitemap().await.unwrap();
+        assert_eq!(sitemap.matches("<url>").count(), 1);
+        assert!(sitemap.contains("<loc>https://aqio.example/e/open-day</loc>"));
+
+        let markup = service.structured_data(&published, 100);
+        assert_eq!(markup["url"], "https://aqio.example/e/open-day");
+        assert_eq!(markup["offers"]["availability"], "https://schema.org/SoldOut");
+    }
+
+    fn hotel(name: &str) -> Hotel {
+        Hotel {
+            name: name.to_string(),
+            address: None,
+            website_url: None,
+            walking_minutes: None,
+            booking_code: None,
+            notes: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_travel_info_round_trips_alongside_other_custom_fields() {
+        let (service, mock_repo) = create_mock_event_service();
+        let organizer = Uuid::new_v4();
+        let mut event = TestEventBuilder::new()
+            .with_slug("havbruk")
+            .with_organizer(organizer)
+            .published()
+            .build();
+        event.custom_fields = Some(r#"{"agenda":[{"time":"09:00","title":"Welcome"}]}"#.to_string());
+        mock_repo.add_event(event.clone()).await;
+
+        assert!(service.travel_info(event.id).await.unwrap().is_empty());
+
+        let travel = TravelInfo {
+            hotels: vec![Hotel {
+                website_url: Some(" https://hotelnorge.example ".to_string()),
+                walking_minutes: Some(5),
+                notes: Some("   ".to_string()),
+                ..hotel("  Hotel Norge ")
+            }],
+            transport: vec![TransportOption {
+                mode: TransportMode::Bus,
+                title: "Flybussen".to_string(),
+                details: None,
+                url: None,
+            }],
+            parking: Some(ParkingInfo {
+                ev_charging: true,
+                ..ParkingInfo::default()
+            }),
+        };
+        let saved = service.update_travel_info(event.id, organizer, false, travel).await.unwrap();
+        assert_eq!(saved.hotels[0].name, "Hotel Norge");
+        assert_eq!(saved.hotels[0].website_url.as_deref(), Some("https://hotelnorge.example"));
+        assert_eq!(saved.hotels[0].notes, None);
+        assert_eq!(service.travel_info(event.id).await.unwrap(), saved);
+
+        // The agenda stored next to it is kept
+        let stored = service.get_event_by_id(event.id).await.unwrap();
+        assert!(stored.custom_fields.as_deref().unwrap().contains("Welcome"));
+        assert_eq!(TravelInfo::from_custom_fields(stored.custom_fields.as_deref()), saved);
+
+        let (filename, ics) = service.public_calendar("havbruk", &Localization::default()).await.unwrap();
+        assert_eq!(filename, "havbruk.ics");
+        let ics = ics.replace("\r\n ", "");
+        assert!(ics.contains("Where to stay\\n- Hotel Norge (5 min walk)"));
+        assert!(ics.contains("URL:https://aqio.example/e/havbruk"));
+
+        // Clearing it removes the key but leaves the agenda alone
+        service
+            .update_travel_info(event.id, organizer, false, TravelInfo::default())
+            .await
+            .unwrap();
+        let stored = service.get_event_by_id(event.id).await.unwrap();
+        assert!(!stored.custom_fields.as_deref().unwrap().contains("travel"));
+        assert!(stored.custom_fields.as_deref().unwrap().contains("Welcome"));
+        assert!(service.travel_info(event.id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_travel_info_is_organizer_managed_and_validated() {
+        let (service, mock_repo) = create_mock_event_service();
+        let organizer = Uuid::new_v4();
+        let event = TestEventBuilder::new().with_organizer(organizer).build();
+        mock_repo.add_event(event.clone()).await;
+        let with_hotel = |hotel: Hotel| TravelInfo {
+            hotels: vec![hotel],
+            ..TravelInfo::default()
+        };
+
+        assert!(matches!(
+            service
+                .update_travel_info(event.id, Uuid::new_v4(), false, with_hotel(hotel("Hotel Norge")))
+                .await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(service
+            .update_travel_info(event.id, Uuid::new_v4(), true, with_hotel(hotel("Hotel Norge")))
+            .await
+            .is_ok());
+
+        let rejected = [
+            with_hotel(hotel(" ")),
+            with_hotel(Hotel {
+                website_url: Some("javascript:alert(1)".to_string()),
+                ..hotel("Hotel Norge")
+            }),
+            with_hotel(Hotel {
+                notes: Some("x".repeat(EventApplicationService::MAX_TRAVEL_NOTES_CHARS + 1)),
+                ..hotel("Hotel Norge")
+            }),
+            TravelInfo {
+                hotels: vec![hotel("Hotel Norge"); EventApplicationService::MAX_TRAVEL_ENTRIES + 1],
+                ..TravelInfo::default()
+            },
+        ];
+        for travel in rejected {
+            assert!(matches!(
+                service.update_travel_info(event.id, organizer, false, travel).await,
+                Err(ApiError::Validation { .. })
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_public_calendar_only_for_public_events() {
+        let (service, mock_repo) = create_mock_event_service();
+        mock_repo.add_event(TestEventBuilder::new().with_slug("draft-day").build()).await;
+
+        assert!(matches!(
+            service.public_calendar("draft-day", &Localization::default()).await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+
+    fn near_query(latitude: Option<f64>, longitude: Option<f64>, radius_km: Option<f64>) -> ListEventsQuery {
+        ListEventsQuery {
+            page: None,
+            limit: None,
+            view: None,
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: None,
+            status: None,
+            location_type: None,
+            start_date_from: None,
+            start_date_to: None,
+            latitude,
+            longitude,
+            radius_km,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field_id: None,
+            field_value: None,
+            county: None,
+            municipality: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_events_near_location() {
+        let (service, mock_repo) = create_mock_event_service();
+        let bergen = TestEventBuilder::new().with_coordinates(60.3913, 5.3221).build();
+        let trondheim = TestEventBuilder::new().with_coordinates(63.4305, 10.3951).build();
+        let online = TestEventBuilder::new().build();
+
+        mock_repo.add_event(bergen.clone()).await;
+        mock_repo.add_event(trondheim).await;
+        mock_repo.add_event(online).await;
+
+        let result = service
+            .list_events(near_query(Some(60.39), Some(5.33), Some(25.0)))
+            .await
+            .unwrap();
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].id, bergen.id);
+
+        // The centre, radius and both coordinates only make sense together
+        assert!(matches!(
+            service.list_events(near_query(Some(60.39), Some(5.33), None)).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(service
+            .list_events(near_query(Some(95.0), Some(5.33), Some(25.0)))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_events_get_their_region_from_the_postal_code() {
+        let (service, _mock_repo) = create_mock_event_service();
+        let postal_codes = MockPostalCodeDirectory::new();
+        postal_codes.add("5003", "Bergen", "Vestland").await;
+        postal_codes.add("8006", "Bodø", "Nordland").await;
+        let service = service.with_postal_codes(std::sync::Arc::new(postal_codes));
+        let organizer_id = Uuid::new_v4();
+
+        let at = |address: &str| CreateEventRequest {
+            location_type: LocationType::Physical,
+            address: Some(address.to_string()),
+            virtual_link: None,
+            ..create_event_request()
+        };
+        let bergen = service.create_event(at("Bryggen 1, 5003 Bergen"), organizer_id).await.unwrap();
+        assert_eq!(bergen.region.as_ref().map(|r| r.county.as_str()), Some("Vestland"));
+        let unknown = service.create_event(at("Somewhere 1, 9999 Nowhere"), organizer_id).await.unwrap();
+        assert_eq!(unknown.region, None);
+
+        // Moving the event moves its region
+        let moved = service
+            .update_event(bergen.id, at("Sjøgata 1, 8006 Bodø"), organizer_id)
+            .await
+            .unwrap();
+        assert_eq!(moved.region.as_ref().map(|r| r.municipality.as_str()), Some("Bodø"));
+
+        service.create_event(at("Torgallmenningen 2, 5003 Bergen"), organizer_id).await.unwrap();
+        let in_vestland = service
+            .list_events(ListEventsQuery { county: Some("vestland".to_string()), ..near_query(None, None, None) })
+            .await
+            .unwrap();
+        assert_eq!(in_vestland.items.len(), 1);
+        assert_eq!(in_vestland.items[0].address.as_deref(), Some("Torgallmenningen 2, 5003 Bergen"));
+    }
+
+    #[tokio::test]
+    async fn test_weather_forecast_for_upcoming_physical_events() {
+        let (service, _mock_repo) = create_mock_event_service();
+        let weather = MockWeatherForecaster::new();
+        let service = service.with_weather(std::sync::Arc::new(weather.clone()));
+        let outdoors = |starts_in: chrono::Duration| {
+            let mut event = TestEventBuilder::new()
+                .with_coordinates(60.3913, 5.3221)
+                .starting_at(Utc::now() + starts_in)
+                .build();
+            event.location_type = LocationType::Physical;
+            event
+        };
+
+        let tomorrow = outdoors(chrono::Duration::days(1));
+        let forecast = service.weather_forecast(&tomorrow).await.unwrap();
+        assert_eq!(forecast.date, tomorrow.start_date.date_naive());
+        assert_eq!(weather.requests.lock().await.as_slice(), &[(60.3913, 5.3221, forecast.date)]);
+
+        // Too far ahead, already over, online or without a venue: no forecast is asked for
+        let online = TestEventBuilder::new().with_coordinates(60.3913, 5.3221).build();
+        let unplaced = {
+            let mut event = outdoors(chrono::Duration::days(1));
+            event.latitude = None;
+            event
+        };
+        for event in [outdoors(chrono::Duration::days(8)), outdoors(-chrono::Duration::days(1)), online, unplaced] {
+            assert_eq!(service.weather_forecast(&event).await, None);
+        }
+        assert_eq!(weather.requests.lock().await.len(), 1);
+
+        // The event is still shown when MET Norway is down
+        weather.set_offline(true).await;
+        assert_eq!(service.weather_forecast(&tomorrow).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_travel_options_arrive_before_the_event_starts() {
+        let (service, mock_repo) = create_mock_event_service();
+        let planner = MockTravelPlanner::new();
+        let service = service.with_travel_planner(std::sync::Arc::new(planner.clone()));
+        let coastal = |starts_in: chrono::Duration| {
+            let mut event = TestEventBuilder::new()
+                .with_coordinates(60.0215, 5.4712)
+                .starting_at(Utc::now() + starts_in)
+                .build();
+            event.location_type = LocationType::Physical;
+            event
+        };
+        let from_bergen = |limit: Option<u32>| TravelOptionsQuery { latitude: 60.3913, longitude: 5.3221, limit };
+
+        let upcoming = coastal(chrono::Duration::days(2));
+        mock_repo.add_event(upcoming.clone()).await;
+        let travel = service.travel_options(upcoming.id, from_bergen(None)).await.unwrap();
+        let arrive_by = upcoming.start_date - chrono::Duration::minutes(15);
+        assert_eq!(travel.arrive_by, Some(arrive_by));
+        assert_eq!(travel.options.len(), 1);
+        assert!(travel.options[0].uses_ferry);
+        assert_eq!(travel.options[0].transfers, 1);
+        let query = planner.queries.lock().await[0].clone();
+        assert_eq!((query.to_latitude, query.to_longitude), (60.0215, 5.4712));
+        assert_eq!((query.time, query.arrive_by, query.limit), (arrive_by, true, 3));
+
+        // Once the event is under way, journeys leave now
+        let ongoing = coastal(-chrono::Duration::minutes(30));
+        mock_repo.add_event(ongoing.clone()).await;
+        let travel = service.travel_options(ongoing.id, from_bergen(Some(5))).await.unwrap();
+        assert_eq!(travel.arrive_by, None);
+        assert!(!planner.queries.lock().await[1].arrive_by);
+
+        // Nowhere to travel to, or no way to ask
+        let over = coastal(-chrono::Duration::days(1));
+        let online = TestEventBuilder::new().with_coordinates(60.0215, 5.4712).build();
+        for event in [over, online] {
+            mock_repo.add_event(event.clone()).await;
+            assert!(matches!(
+                service.travel_options(event.id, from_bergen(None)).await,
+                Err(ApiError::BadRequest { .. })
+            ));
+        }
+        for query in [from_bergen(Some(0)), from_bergen(Some(6)), TravelOptionsQuery { latitude: 91.0, longitude: 5.0, limit: None }] {
+            assert!(matches!(
+                service.travel_options(upcoming.id, query).await,
+                Err(ApiError::Validation { .. })
+            ));
+        }
+        assert_eq!(planner.queries.lock().await.len(), 2);
+        let (unplanned, _) = create_mock_event_service();
+        assert!(matches!(
+            unplanned.travel_options(upcoming.id, from_bergen(None)).await,
+            Err(ApiError::ExternalService { .. })
+        ));
+    }
+
     #[tokio::test]
     async fn test_get_event_by_id_not_found() {
         let (service, _mock_repo) = create_mock_event_service();
@@ -201,6 +571,39 @@ mod tests {
         assert_eq!(result.unwrap().id, user.id);
     }
 
+    #[tokio::test]
+    async fn test_search_users_is_for_organizers_and_rate_limited() {
+        let (service, mock_repo) = create_mock_user_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let participant = TestUserBuilder::new().build();
+        mock_repo.add_user(TestUserBuilder::new().with_name("Kari Nordmann").with_email("kari@example.no").build()).await;
+        mock_repo.add_user(TestUserBuilder::new().with_name("Ola Karlsen").with_email("ola@example.no").build()).await;
+        mock_repo.add_user(TestUserBuilder::new().with_name("Karin Berg").with_email("karin@example.no").inactive().build()).await;
+
+        let found = service.search_users(&organizer, &role_permissions(&organizer, false), " kar ", None).await.unwrap();
+        let names: Vec<&str> = found.iter().map(|m| m.user.name.as_str()).collect();
+        assert_eq!(names, vec!["Kari Nordmann", "Ola Karlsen"]);
+        assert_eq!(service.search_users(&organizer, &role_permissions(&organizer, false), "kar", Some(1)).await.unwrap().len(), 1);
+
+        assert!(matches!(
+            service.search_users(&participant, &role_permissions(&participant, false), "kar", None).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(service.search_users(&participant, &role_permissions(&participant, true), "kar", None).await.is_ok());
+        assert!(matches!(
+            service.search_users(&organizer, &role_permissions(&organizer, false), "k", None).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        for _ in 3..UserApplicationService::SEARCHES_PER_MINUTE {
+            service.search_users(&organizer, &role_permissions(&organizer, false), "kar", None).await.unwrap();
+        }
+        assert!(matches!(
+            service.search_users(&organizer, &role_permissions(&organizer, false), "kar", None).await,
+            Err(ApiError::RateLimit)
+        ));
+    }
+
     // ============================================================================
     // Event Category Application Service Tests
     // ============================================================================
@@ -289,19 +692,21 @@ mod tests {
     // ============================================================================
 
     #[tokio::test]
-    async fn test_create_registration_success() {
+    async fn test_register_success() {
         let (service, _mock_repo) = create_mock_registration_service();
-        let registration = TestRegistrationBuilder::new().build();
+        let event = TestEventBuilder::new().published().build();
+        let registration = TestRegistrationBuilder::new().with_event(event.id).build();
 
-        let result = service.create_registration(&registration).await;
+        let result = service.register(&event, &registration).await;
         assert!(result.is_ok());
     }
 
     #[tokio::test]
-    async fn test_create_registration_duplicate_user() {
+    async fn test_register_duplicate_user() {
         let (service, mock_repo) = create_mock_registration_service();
         let user_id = Uuid::new_v4();
-        let event_id = Uuid::new_v4();
+        let event = TestEventBuilder::new().published().build();
+        let event_id = event.id;
 
         // Add first registration
         let first_registration = TestRegistrationBuilder::new()
@@ -316,10 +721,103 @@ mod tests {
             .with_event(event_id)
             .build();
 
-        let result = service.create_registration(&duplicate_registration).await;
+        let result = service.register(&event, &duplicate_registration).await;
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_register_enforces_registration_window() {
+        let (service, mock_repo) = create_mock_registration_service();
+        let mut event = TestEventBuilder::new().published().build();
+        let registration = TestRegistrationBuilder::new().with_event(event.id).build();
+
+        event.registration_opens = Some(Utc::now() + chrono::Duration::hours(1));
+        let result = service.register(&event, &registration).await;
+        assert!(matches!(result, Err(ApiError::Domain { source: DomainError::BusinessRuleViolation { .. } })));
+
+        event.registration_opens = None;
+        event.registration_closes = Some(Utc::now() - chrono::Duration::minutes(1));
+        assert!(service.register(&event, &registration).await.is_err());
+
+        event.registration_closes = None;
+        service.register(&event, &registration).await.unwrap();
+        assert!(mock_repo.registrations.lock().await.contains_key(&registration.id));
+    }
+
+    #[tokio::test]
+    async fn test_register_claims_last_seat_then_waitlists() {
+        let (service, mock_repo) = create_mock_registration_service();
+        let event = TestEventBuilder::new().published().with_capacity(1, true).build();
+
+        let first = TestRegistrationBuilder::new().with_event(event.id).build();
+        let seated = service.register(&event, &first).await.unwrap();
+        assert_eq!(seated.status, RegistrationStatus::Registered);
+
+        let second = TestRegistrationBuilder::new().with_event(event.id).build();
+        let waitlisted = service.register(&event, &second).await.unwrap();
+        assert_eq!(waitlisted.status, RegistrationStatus::Waitlisted);
+        assert_eq!(waitlisted.waitlist_position, Some(1));
+        assert_eq!(
+            mock_repo.registrations.lock().await[&second.id].status,
+            RegistrationStatus::Waitlisted
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merge_registrations_keeps_earliest() {
+        let (service, mock_repo) = create_mock_registration_service();
+        let event_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+
+        let mut invited = TestRegistrationBuilder::new()
+            .with_event(event_id)
+            .with_user(user_id)
+            .build();
+        invited.invitation_id = Some(Uuid::new_v4());
+        invited.registered_at = Utc::now() - chrono::Duration::days(2);
+        let direct = TestRegistrationBuilder::new()
+            .with_event(event_id)
+            .with_user(user_id)
+            .with_guests(1, vec!["Ola Nordmann".to_string()])
+            .build();
+        mock_repo.add_registration(invited.clone()).await;
+        mock_repo.add_registration(direct.clone()).await;
+
+        let merge = service
+            .merge_registrations(event_id, &[direct.id, invited.id])
+            .await
+            .unwrap();
+        assert_eq!(merge.merged.id, invited.id);
+        assert_eq!(merge.merged.invitation_id, invited.invitation_id);
+        assert_eq!(merge.merged.guest_count, 1);
+        assert_eq!(merge.before.guest_count, 0);
+        assert_eq!(merge.removed.len(), 1);
+
+        let registrations = mock_repo.registrations.lock().await;
+        assert!(!registrations.contains_key(&direct.id));
+        assert_eq!(registrations[&invited.id].guest_names, vec!["Ola Nordmann".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_merge_registrations_rejects_other_events() {
+        let (service, mock_repo) = create_mock_registration_service();
+        let registration = TestRegistrationBuilder::new().build();
+        let other = TestRegistrationBuilder::new().build();
+        mock_repo.add_registration(registration.clone()).await;
+        mock_repo.add_registration(other.clone()).await;
+
+        let result = service
+            .merge_registrations(registration.event_id, &[registration.id, other.id])
+            .await;
+        assert!(matches!(result, Err(ApiError::Validation { .. })));
+
+        let result = service
+            .merge_registrations(registration.event_id, &[registration.id, registration.id])
+            .await;
+        assert!(matches!(result, Err(ApiError::Validation { .. })));
+        assert_eq!(mock_repo.registrations.lock().await.len(), 2);
+    }
+
     #[tokio::test]
     async fn test_get_registration_by_id_success() {
         let (service, mock_repo) = create_mock_registration_service();
@@ -531,47 +1029,172 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_delete_registration() {
+    async fn test_cancel_registration_promotes_longest_waiting() {
         let (service, mock_repo) = create_mock_registration_service();
-        let registration = TestRegistrationBuilder::new().build();
+        let mut notifications = service.notifier().subscribe();
+        let event_id = Uuid::new_v4();
+
+        let attending = TestRegistrationBuilder::new().with_event(event_id).build();
+        let mut first = TestRegistrationBuilder::new()
+            .with_event(event_id)
+            .with_user(Uuid::new_v4())
+            .waitlisted()
+            .build();
+        first.waitlist_added_at = Some(Utc::now() - chrono::Duration::hours(2));
+        let second = TestRegistrationBuilder::new()
+            .with_event(event_id)
+            .with_user(Uuid::new_v4())
+            .waitlisted()
+            .build();
+
+        mock_repo.add_registration(attending.clone()).await;
+        mock_repo.add_registration(second.clone()).await;
+        mock_repo.add_registration(first.clone()).await;
+
+        service.cancel_registration(attending.id).await.unwrap();
+
+        let promoted = service.get_registration_by_id(first.id).await.unwrap();
+        assert_eq!(promoted.status, RegistrationStatus::Registered);
+        assert!(matches!(promoted.registration_source, RegistrationSource::WaitlistPromotion));
+        assert!(promoted.promotion_expires_at.is_some());
+
+        let status = service.waitlist_status(second.id).await.unwrap();
+        assert_eq!(status.position, Some(1));
+        assert_eq!(status.waitlist_size, 1);
+
+        let message = notifications.recv().await.unwrap();
+        assert_eq!(message.user_id, first.user_id.unwrap());
+        assert!(matches!(
+            message.notification,
+            crate::domain::notifications::RegistrationNotification::Promoted { registration_id, .. }
+                if registration_id == first.id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_promotion_clears_deadline() {
+        let (service, mock_repo) = create_mock_registration_service();
+        let mut registration = TestRegistrationBuilder::new().build();
+        registration.promotion_expires_at = Some(Utc::now() + chrono::Duration::hours(1));
 
         mock_repo.add_registration(registration.clone()).await;
 
-        let result = service.delete_registration(registration.id).await;
-        assert!(result.is_ok());
+        let confirmed = service.confirm_promotion(registration.id).await.unwrap();
+        assert_eq!(confirmed.status, RegistrationStatus::Registered);
+        assert_eq!(confirmed.promotion_expires_at, None);
 
-        // Verify the registration was deleted
-        let result = service.get_registration_by_id(registration.id).await;
-        assert!(result.is_err());
+        // Nothing left to confirm
+        assert!(service.confirm_promotion(registration.id).await.is_err());
     }
 
     #[tokio::test]
-    async fn test_get_registration_by_event_and_user() {
+    async fn test_expired_promotion_passes_spot_on() {
         let (service, mock_repo) = create_mock_registration_service();
         let event_id = Uuid::new_v4();
-        let user_id = Uuid::new_v4();
 
-        let registration = TestRegistrationBuilder::new()
+        let mut expired = TestRegistrationBuilder::new().with_event(event_id).build();
+        expired.promotion_expires_at = Some(Utc::now() - chrono::Duration::minutes(1));
+        let waiting = TestRegistrationBuilder::new()
             .with_event(event_id)
-            .with_user(user_id)
+            .waitlisted()
             .build();
 
-        mock_repo.add_registration(registration.clone()).await;
+        mock_repo.add_registration(expired.clone()).await;
+        mock_repo.add_registration(waiting.clone()).await;
 
-        let result = service.get_registration_by_event_and_user(event_id, user_id).await;
-        assert!(result.is_ok());
-        assert!(result.unwrap().is_some());
+        let result = service.confirm_promotion(expired.id).await;
+        assert!(matches!(result, Err(ApiError::Conflict { .. })));
+
+        let released = service.get_registration_by_id(expired.id).await.unwrap();
+        assert_eq!(released.status, RegistrationStatus::Cancelled);
+
+        let status = service.waitlist_status(waiting.id).await.unwrap();
+        assert_eq!(status.registration.status, RegistrationStatus::Registered);
+        assert_eq!(status.position, None);
+        assert!(status.registration.promotion_expires_at.is_some());
+    }
+
+    fn guest_registration(event_id: Uuid, email: &str) -> EventRegistration {
+        CreateRegistrationRequest {
+            registrant_email: Some(email.to_string()),
+            registrant_name: Some("Kari Nordmann".to_string()),
+            registrant_phone: None,
+            registrant_company: None,
+            guest_count: None,
+            guest_names: None,
+            dietary_restrictions: None,
+            accessibility_needs: None,
+            special_requests: None,
+            custom_responses: None,
+            consents: None,
+        }
+        .to_domain_registration(event_id, None, None)
+        .unwrap()
     }
 
     #[tokio::test]
-    async fn test_get_registration_by_event_and_user_not_found() {
+    async fn test_register_guest_waitlists_once_full() {
         let (service, _mock_repo) = create_mock_registration_service();
-        let event_id = Uuid::new_v4();
-        let user_id = Uuid::new_v4();
+        let event = TestEventBuilder::new().published().with_capacity(1, true).build();
+
+        let first = service
+            .register_guest(&event, guest_registration(event.id, "first@example.com"))
+            .await
+            .unwrap();
+        assert_eq!(first.status, RegistrationStatus::Registered);
+
+        let second = service
+            .register_guest(&event, guest_registration(event.id, "second@example.com"))
+            .await
+            .unwrap();
+        assert_eq!(second.status, RegistrationStatus::Waitlisted);
+        assert_eq!(second.waitlist_position, Some(1));
+
+        let duplicate = service
+            .register_guest(&event, guest_registration(event.id, "FIRST@example.com"))
+            .await;
+        assert!(matches!(duplicate, Err(ApiError::Validation { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_register_guest_rejected_when_full_without_waitlist() {
+        let (service, mock_repo) = create_mock_registration_service();
+        let event = TestEventBuilder::new().published().with_capacity(1, false).build();
+        mock_repo
+            .add_registration(TestRegistrationBuilder::new().with_event(event.id).build())
+            .await;
+
+        let result = service
+            .register_guest(&event, guest_registration(event.id, "late@example.com"))
+            .await;
+        assert!(matches!(result, Err(ApiError::Conflict { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_register_guest_requires_open_registration() {
+        let (service, _mock_repo) = create_mock_registration_service();
+        let mut event = TestEventBuilder::new().published().build();
+        event.registration_closes = Some(Utc::now() - chrono::Duration::minutes(5));
+
+        let result = service
+            .register_guest(&event, guest_registration(event.id, "guest@example.com"))
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delete_registration() {
+        let (service, mock_repo) = create_mock_registration_service();
+        let registration = TestRegistrationBuilder::new().build();
+
+        mock_repo.add_registration(registration.clone()).await;
 
-        let result = service.get_registration_by_event_and_user(event_id, user_id).await;
+        let result = service.delete_registration(registration.id).await;
         assert!(result.is_ok());
-        assert!(result.unwrap().is_none());
+
+        // Verify the registration was deleted
+        let result = service.get_registration_by_id(registration.id).await;
+        assert!(result.is_err());
     }
 
     #[tokio::test]
@@ -623,24 +1246,4674 @@ mod tests {
     }
 
     // ============================================================================
-    // Error Scenario Tests
+    // Event Details Application Service Tests
     // ============================================================================
 
     #[tokio::test]
-    async fn test_repository_failure_propagation() {
-        let (service, mock_repo) = create_mock_event_service();
-        
-        // Make repository fail
-        mock_repo.set_should_fail(true).await;
-        
-        let non_existent_id = Uuid::new_v4();
-        let result = service.get_event_by_id(non_existent_id).await;
-        
-        assert!(result.is_err());
-        // The error should be a domain error (infrastructure failure)
-        match result.unwrap_err() {
-            ApiError::Domain { .. } => {}, // Expected
-            _ => panic!("Expected domain error"),
+    async fn test_list_events_with_details_hydrates_relations() {
+        let (service, mocks) = create_mock_event_details_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let category = TestCategoryBuilder::new().with_id("workshop").with_name("Workshop").build();
+        let event = TestEventBuilder::new()
+            .with_organizer(organizer.id)
+            .with_category("workshop")
+            .published()
+            .build();
+
+        mocks.users.add_user(organizer.clone()).await;
+        mocks.categories.add_category(category).await;
+        mocks.events.add_event(event.clone()).await;
+        for status in [RegistrationStatus::Registered, RegistrationStatus::Attended, RegistrationStatus::Waitlisted] {
+            let mut registration = TestRegistrationBuilder::new().with_event(event.id).with_status(status.clone()).build();
+            if matches!(status, RegistrationStatus::Attended) {
+                registration.checked_in_at = Some(Utc::now());
+            }
+            mocks.registrations.add_registration(registration).await;
+        }
+
+        let query = ListEventsQuery {
+            page: None,
+            limit: None,
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: None,
+            status: None,
+            location_type: None,
+            start_date_from: None,
+            start_date_to: None,
+            latitude: None,
+            longitude: None,
+            radius_km: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field_id: None,
+            field_value: None,
+            county: None,
+            municipality: None,
+            view: None,
+        };
+        let result = service.list_events_with_details(query).await.unwrap();
+
+        assert_eq!(result.items.len(), 1);
+        let details = &result.items[0];
+        assert_eq!(details.event.id, event.id);
+        assert_eq!(details.category.as_ref().unwrap().name, "Workshop");
+        assert_eq!(details.organizer.as_ref().unwrap().id, organizer.id);
+        assert_eq!(details.registration_counts.confirmed(), 2);
+        assert_eq!(details.registration_counts.waitlisted, 1);
+        assert_eq!(details.checked_in, 1);
+    }
+
+    #[tokio::test]
+    async fn test_hydrate_preserves_order_and_handles_missing_relations() {
+        let (service, _mocks) = create_mock_event_details_service();
+        let first = TestEventBuilder::new().with_title("First").build();
+        let second = TestEventBuilder::new().with_title("Second").build();
+
+        let hydrated = service.hydrate(vec![first.clone(), second.clone()]).await.unwrap();
+
+        assert_eq!(hydrated.len(), 2);
+        assert_eq!(hydrated[0].event.id, first.id);
+        assert_eq!(hydrated[1].event.id, second.id);
+        // Unknown organizer/category and no registrations don't fail the batch
+        assert!(hydrated[0].organizer.is_none());
+        assert!(hydrated[0].category.is_none());
+        assert_eq!(hydrated[0].registration_counts, RegistrationCounts::empty(first.id));
+        assert_eq!(hydrated[0].checked_in, 0);
+    }
+
+    #[tokio::test]
+    async fn test_availability_reports_seats_and_waitlist() {
+        let (service, mocks) = create_mock_event_details_service();
+        let event = TestEventBuilder::new().published().with_capacity(2, true).build();
+        let private = TestEventBuilder::new().published().private().build();
+        mocks.events.add_event(event.clone()).await;
+        mocks.events.add_event(private.clone()).await;
+        for status in [RegistrationStatus::Registered, RegistrationStatus::Attended, RegistrationStatus::Waitlisted] {
+            mocks
+                .registrations
+                .add_registration(TestRegistrationBuilder::new().with_event(event.id).with_status(status).build())
+                .await;
         }
+
+        let (found, counts) = service.availability(event.id).await.unwrap();
+        let availability = EventAvailabilityResponse::new(&found, &counts, Utc::now());
+        assert_eq!(availability.remaining_spots, Some(0));
+        assert_eq!(availability.waitlist_length, 1);
+        assert_eq!(availability.registration_state, RegistrationState::WaitlistOnly);
+        assert!(availability.seconds_until_registration_closes.is_some());
+
+        // Private events are hidden like on the public event page
+        let result = service.availability(private.id).await;
+        assert!(matches!(result, Err(ApiError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_get_event_with_details_not_found() {
+        let (service, _mocks) = create_mock_event_details_service();
+
+        let result = service.get_event_with_details(Uuid::new_v4()).await;
+        assert!(matches!(result, Err(ApiError::NotFound { .. })));
+    }
+
+    // ============================================================================
+    // Event View Tests
+    // ============================================================================
+
+    #[test]
+    fn test_event_view_parsing() {
+        let summary: EventView = serde_json::from_str("\"summary\"").unwrap();
+        let full: EventView = serde_json::from_str("\"full\"").unwrap();
+
+        assert_eq!(summary, EventView::Summary);
+        assert_eq!(full, EventView::Full);
+        assert_eq!(EventView::default(), EventView::Full);
+        assert!(serde_json::from_str::<EventView>("\"compact\"").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_summary_view_omits_heavy_fields() {
+        let (service, mock_repo) = create_mock_event_service();
+        let event = TestEventBuilder::new().with_title("Calendar Event").build();
+        mock_repo.add_event(event.clone()).await;
+
+        let result = service
+            .get_events_by_organizer(event.organizer_id, PaginationParams::default())
+            .await
+            .unwrap();
+        let summary = PaginatedEventSummaryResponse::from_paginated_result(result);
+        let json = serde_json::to_value(&summary).unwrap();
+
+        let item = &json["items"][0];
+        assert_eq!(item["title"], "Calendar Event");
+        assert!(item.get("start_date").is_some());
+        assert!(item.get("description").is_none());
+        assert!(item.get("custom_fields").is_none());
+        assert_eq!(json["pagination"]["total_count"], 1);
+    }
+
+    // ============================================================================
+    // Audit Log Service Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_record_change_captures_actor_and_changed_fields() {
+        let (service, mock_repo) = create_mock_audit_log_service();
+        let before = TestCategoryBuilder::new().with_id("conf").with_name("Conference").build();
+        let mut after = before.clone();
+        after.name = "Conferences".to_string();
+        after.color_hex = Some("#10B981".to_string());
+
+        let actor = create_admin_actor();
+        service
+            .record_change(&actor, "event_categories", "conf", AuditAction::Update, Some(&before), Some(&after))
+            .await;
+
+        let entries = mock_repo.entries.lock().await;
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.user_id, actor.user_id);
+        assert_eq!(entry.user_name.as_deref(), Some("Admin User"));
+        assert_eq!(entry.changed_fields, vec!["color_hex", "name"]);
+
+        let old_values: serde_json::Value = serde_json::from_str(entry.old_values.as_deref().unwrap()).unwrap();
+        assert_eq!(old_values["name"], "Conference");
+    }
+
+    #[tokio::test]
+    async fn test_record_change_failure_is_not_propagated() {
+        let (service, mock_repo) = create_mock_audit_log_service();
+        mock_repo.set_should_fail(true).await;
+
+        let user = TestUserBuilder::new().build();
+        service
+            .record_change(&create_admin_actor(), "users", user.id.to_string(), AuditAction::Delete, Some(&user), None)
+            .await;
+
+        assert!(mock_repo.entries.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_audit_entries_newest_first() {
+        let (service, _) = create_mock_audit_log_service();
+        let actor = create_admin_actor();
+        let user = TestUserBuilder::new().build();
+
+        service
+            .record_change(&actor, "users", user.id.to_string(), AuditAction::Insert, None, Some(&user))
+            .await;
+        service
+            .record_change(&actor, "users", user.id.to_string(), AuditAction::Delete, Some(&user), None)
+            .await;
+
+        let query = AuditLogQuery {
+            page: None,
+            limit: None,
+            table_name: Some("users".to_string()),
+            record_id: None,
+            user_id: None,
+            action: None,
+        };
+        let (filter, pagination) = query.to_filter_and_pagination().unwrap();
+        let result = service.list_entries(&filter, pagination).await.unwrap();
+
+        assert_eq!(result.total_count, 2);
+        assert_eq!(result.items[0].action, AuditAction::Delete);
+
+        let response = PaginatedAuditLogResponse::from_paginated_result(result);
+        assert_eq!(response.items[1].changed_fields.len(), 11);
+        assert!(response.items[1].old_values.is_none());
+        assert_eq!(response.items[1].new_values.as_ref().unwrap()["email"], user.email.as_str());
+    }
+
+    #[test]
+    fn test_category_color_must_be_hex() {
+        let mut request = create_category_request();
+        request.color_hex = Some("blue".to_string());
+        assert!(request.to_domain_category().is_err());
+
+        let update = UpdateEventCategoryRequest {
+            name: None,
+            description: None,
+            color_hex: Some(Some("#12345G".to_string())),
+            icon_name: None,
+            is_active: None,
+        };
+        let category = TestCategoryBuilder::new().build();
+        assert!(update.apply_to_category(category.clone()).is_err());
+
+        let clear = UpdateEventCategoryRequest {
+            name: None,
+            description: None,
+            color_hex: Some(None),
+            icon_name: None,
+            is_active: None,
+        };
+        assert_eq!(clear.apply_to_category(category).unwrap().color_hex, None);
+    }
+
+    #[test]
+    fn test_client_error_report_validation() {
+        let report = ClientErrorReport {
+            reference: "ERR-1A2B3C4D".to_string(),
+            message: "Failed to load event".to_string(),
+            route: Some("/events".to_string()),
+            user_agent: None,
+        };
+        assert!(report.validate().is_ok());
+
+        let blank = ClientErrorReport {
+            reference: " ".to_string(),
+            ..report
+        };
+        assert!(blank.validate().is_err());
+
+        let oversized = ClientErrorReport {
+            reference: "ERR-1A2B3C4D".to_string(),
+            message: "x".repeat(4001),
+            route: None,
+            user_agent: None,
+        };
+        assert!(oversized.validate().is_err());
+    }
+
+    // ============================================================================
+    // Company Application Service Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_company_directory_filters_by_industry_with_member_counts() {
+        let (service, companies, _) = create_mock_company_service();
+        let salmon = create_test_company("Vestland Salmon", IndustryType::Salmon);
+        let trout = create_test_company("Fjord Trout", IndustryType::Trout);
+        let empty = create_test_company("Arctic Salmon", IndustryType::Salmon);
+        companies.add_company(salmon.clone(), 12).await;
+        companies.add_company(trout, 3).await;
+        companies.add_company(empty.clone(), 0).await;
+
+        let query = CompanyDirectoryQuery {
+            page: None,
+            limit: None,
+            industry_type: Some("Salmon".to_string()),
+        };
+        let (filter, pagination) = query.to_filter_and_pagination().unwrap();
+        let result = service.list_directory(&filter, pagination).await.unwrap();
+
+        let response = PaginatedCompanyResponse::from_paginated_result(result);
+        assert_eq!(response.pagination.total_count, 2);
+        assert_eq!(response.items[0].id, empty.id);
+        assert_eq!(response.items[0].member_count, 0);
+        assert_eq!(response.items[1].id, salmon.id);
+        assert_eq!(response.items[1].member_count, 12);
+        assert_eq!(response.items[1].industry_type, "salmon");
+
+        let unknown = CompanyDirectoryQuery {
+            page: None,
+            limit: None,
+            industry_type: Some("cod".to_string()),
+        };
+        assert!(matches!(
+            unknown.to_filter_and_pagination(),
+            Err(ApiError::Validation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_companies_are_checked_against_and_filled_in_from_the_business_register() {
+        let (service, companies, _) = create_mock_company_service();
+        let registry = MockCompanyRegistry::new();
+        let postal_codes = MockPostalCodeDirectory::new();
+        postal_codes.add("8450", "Hadsel", "Nordland").await;
+        let service = service
+            .with_registry(std::sync::Arc::new(registry.clone()))
+            .with_postal_codes(std::sync::Arc::new(postal_codes));
+        registry
+            .add(RegisteredCompany {
+                org_number: "923609016".to_string(),
+                name: "NORDLAKS OPPDRETT AS".to_string(),
+                address: Some("Hadselveien 2, 8450 Stokmarknes".to_string()),
+                city: Some("Stokmarknes".to_string()),
+                website: Some("https://www.nordlaks.no".to_string()),
+                industry_code: Some("03.211".to_string()),
+                industry_description: Some("Produksjon av matfisk og skalldyr i hav- og kystbasert fiskeoppdrett".to_string()),
+                is_active: true,
+            })
+            .await;
+        registry
+            .add(RegisteredCompany {
+                org_number: "974760673".to_string(),
+                name: "KONKURSBO AS".to_string(),
+                address: None,
+                city: None,
+                website: None,
+                industry_code: None,
+                industry_description: None,
+                is_active: false,
+            })
+            .await;
+
+        // Lookups normalize the number and report what the register has
+        match service.lookup_registry("923 609 016").await.unwrap() {
+            RegistryLookup::Found(found) => assert_eq!(found.name, "NORDLAKS OPPDRETT AS"),
+            other => panic!("expected a registered company, got {:?}", other),
+        }
+        assert!(matches!(service.lookup_registry("923609017").await, Err(ApiError::Validation { .. })));
+
+        let input = |org_number: &str| CompanyInput {
+            org_number: Some(org_number.to_string()),
+            ..CompanyInput::default()
+        };
+        assert!(matches!(
+            service.create_company(false, input("923609016")).await,
+            Err(ApiError::Authorization { .. })
+        ));
+
+        // Blank fields come from the register; entered ones are kept
+        let created = service
+            .create_company(
+                true,
+                CompanyInput {
+                    industry_type: Some(IndustryType::Salmon),
+                    location: Some("Hadsel".to_string()),
+                    ..input("923 609 016")
+                },
+            )
+            .await
+            .unwrap();
+        let company = &created.entry.company;
+        assert!(created.registry_verified);
+        assert_eq!(company.name, "NORDLAKS OPPDRETT AS");
+        assert_eq!(company.org_number.as_deref(), Some("923609016"));
+        assert_eq!(company.location.as_deref(), Some("Hadsel"));
+        assert_eq!(company.website.as_deref(), Some("https://www.nordlaks.no"));
+        // The entered location has no postal code; the registered address does
+        assert_eq!(company.region.as_ref().map(|r| r.municipality.as_str()), Some("Hadsel"));
+        assert!(companies.find_by_org_number("923609016").await.unwrap().is_some());
+        assert!(matches!(
+            service.create_company(true, input("923609016")).await,
+            Err(ApiError::Conflict { .. })
+        ));
+
+        // Closed companies and numbers the register doesn't have are refused
+        assert!(matches!(
+            service.create_company(true, input("974760673")).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.create_company(true, input("986649077")).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        // While the register is down, a valid number is taken with the entered details
+        registry.set_offline(true).await;
+        assert_eq!(service.lookup_registry("986649077").await.unwrap(), RegistryLookup::Unavailable);
+        assert!(matches!(
+            service.create_company(true, input("986649077")).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let offline = service
+            .create_company(
+                true,
+                CompanyInput {
+                    name: Some("Lerøy Midt AS".to_string()),
+                    ..input("986649077")
+                },
+            )
+            .await
+            .unwrap();
+        assert!(!offline.registry_verified);
+        assert_eq!(offline.entry.company.name, "Lerøy Midt AS");
+        assert_eq!(offline.entry.company.industry_type.as_str(), "other");
+    }
+
+    #[tokio::test]
+    async fn test_company_upcoming_events_are_public_and_soonest_first() {
+        let (service, companies, events) = create_mock_company_service();
+        let company = create_test_company("Vestland Salmon", IndustryType::Salmon);
+        companies.add_company(company.clone(), 1).await;
+
+        let now = Utc::now();
+        let published = |title: &str, days: i64| {
+            TestEventBuilder::new()
+                .with_title(title)
+                .starting_at(now + chrono::Duration::days(days))
+                .published()
+                .build()
+        };
+        let later = published("Later", 30);
+        let sooner = published("Sooner", 2);
+        let past = published("Past", -2);
+        let private = TestEventBuilder::new().with_title("Private").published().private().build();
+        let draft = TestEventBuilder::new().with_title("Draft").build();
+        for event in [&later, &sooner, &past, &private, &draft] {
+            events.add_event(event.clone()).await;
+        }
+
+        let upcoming = service.upcoming_public_events(company.id).await.unwrap();
+        let titles: Vec<&str> = upcoming.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, vec!["Sooner", "Later"]);
+
+        assert!(matches!(
+            service.upcoming_public_events(Uuid::new_v4()).await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+
+    // ============================================================================
+    // Event Comment Application Service Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_comment_replies_and_validation() {
+        let (service, _, events) = create_mock_comment_service();
+        let event = TestEventBuilder::new().published().build();
+        let other_event = TestEventBuilder::new().published().build();
+        events.add_event(event.clone()).await;
+        events.add_event(other_event.clone()).await;
+        let author = TestUserBuilder::new().with_name("Kari Nordmann").build();
+
+        let question = service
+            .create_comment(event.id, &author, None, "  Is there parking?  ")
+            .await
+            .unwrap();
+        assert_eq!(question.content, "Is there parking?");
+        assert_eq!(question.author_name.as_deref(), Some("Kari Nordmann"));
+
+        let answer = service
+            .create_comment(event.id, &author, Some(question.id), "Behind the hall")
+            .await
+            .unwrap();
+        assert_eq!(answer.parent_comment_id, Some(question.id));
+
+        assert!(matches!(
+            service.create_comment(event.id, &author, None, "   ").await,
+            Err(ApiError::Validation { .. })
+        ));
+        let too_long = "a".repeat(EventCommentApplicationService::MAX_CONTENT_CHARS + 1);
+        assert!(matches!(
+            service.create_comment(event.id, &author, None, &too_long).await,
+            Err(ApiError::Validation { .. })
+        ));
+        // Replies must stay within the parent's event
+        assert!(matches!(
+            service.create_comment(other_event.id, &author, Some(question.id), "Hi").await,
+            Err(ApiError::NotFound { .. })
+        ));
+        assert!(matches!(
+            service.create_comment(Uuid::new_v4(), &author, None, "Hi").await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_comment_moderation_and_deleted_placeholders() {
+        let (service, _, events) = create_mock_comment_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        events.add_event(event.clone()).await;
+        let author = TestUserBuilder::new().build();
+        let bystander = TestUserBuilder::new().build();
+
+        let question = service.create_comment(event.id, &author, None, "Question").await.unwrap();
+        let answer = service
+            .create_comment(event.id, &organizer, Some(question.id), "Answer")
+            .await
+            .unwrap();
+        let off_topic = service.create_comment(event.id, &author, None, "Off topic").await.unwrap();
+
+        assert!(matches!(
+            service.delete_comment(event.id, question.id, bystander.id, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        // Admins can moderate any event; organizers their own; authors their own comments
+        service.delete_comment(event.id, off_topic.id, bystander.id, true).await.unwrap();
+        service.delete_comment(event.id, question.id, organizer.id, false).await.unwrap();
+
+        // The deleted question stays as a placeholder for its answer; the off-topic one disappears
+        let thread = service.list_comments(event.id, author.id, false).await.unwrap();
+        let ids: Vec<Uuid> = thread.iter().map(|c| c.comment.id).collect();
+        assert_eq!(ids, vec![question.id, answer.id]);
+        let placeholder = CommentResponse::from(thread[0].clone());
+        assert!(placeholder.is_deleted);
+        assert!(!placeholder.can_delete);
+        assert_eq!(placeholder.content, None);
+        // The author can't remove the organizer's answer, but the organizer can
+        assert!(!thread[1].can_delete);
+        let as_organizer = service.list_comments(event.id, organizer.id, false).await.unwrap();
+        assert!(as_organizer[1].can_delete);
+
+        assert!(matches!(
+            service.create_comment(event.id, &author, Some(question.id), "Follow-up").await,
+            Err(ApiError::Domain { .. })
+        ));
+
+        service.delete_comment(event.id, answer.id, organizer.id, false).await.unwrap();
+        assert!(service.list_comments(event.id, organizer.id, false).await.unwrap().is_empty());
+    }
+
+    // ============================================================================
+    // Live Engagement Application Service Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_live_questions_upvotes_and_answers() {
+        let (service, events) = create_mock_live_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).running().build();
+        let upcoming = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        events.add_event(event.clone()).await;
+        events.add_event(upcoming.clone()).await;
+        let attendee = TestUserBuilder::new().build();
+        let other = TestUserBuilder::new().build();
+
+        let first = service.ask_question(event.id, &attendee, "  Slides?  ").await.unwrap();
+        assert_eq!(first.question.content, "Slides?");
+        let second = service.ask_question(event.id, &attendee, "Lunch?").await.unwrap();
+
+        service.set_upvote(event.id, second.question.id, attendee.id, true).await.unwrap();
+        let upvoted = service.set_upvote(event.id, second.question.id, attendee.id, true).await.unwrap();
+        assert_eq!(upvoted.question.upvote_count, 1);
+        assert!(upvoted.has_upvoted);
+
+        // Most upvoted first, and each viewer sees their own upvotes
+        let listed = service.list_questions(event.id, other.id).await.unwrap();
+        assert_eq!(listed.iter().map(|q| q.question.id).collect::<Vec<_>>(), vec![second.question.id, first.question.id]);
+        assert!(!listed[0].has_upvoted);
+
+        let withdrawn = service.set_upvote(event.id, second.question.id, attendee.id, false).await.unwrap();
+        assert_eq!(withdrawn.question.upvote_count, 0);
+
+        assert!(matches!(
+            service.answer_question(event.id, first.question.id, attendee.id, false, "Yes").await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let answered = service
+            .answer_question(event.id, first.question.id, organizer.id, false, "Shared after the session")
+            .await
+            .unwrap();
+        assert!(answered.question.is_answered());
+
+        assert!(matches!(
+            service.ask_question(event.id, &attendee, "   ").await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.set_upvote(upcoming.id, first.question.id, attendee.id, true).await,
+            Err(ApiError::Domain { .. })
+        ));
+        // Questions can still be read before and after the event
+        assert!(matches!(
+            service.ask_question(upcoming.id, &attendee, "Too early").await,
+            Err(ApiError::Domain { .. })
+        ));
+        assert!(service.list_questions(upcoming.id, attendee.id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_live_poll_votes_are_aggregated_and_published() {
+        let (service, events) = create_mock_live_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).running().build();
+        events.add_event(event.clone()).await;
+        let attendee = TestUserBuilder::new().build();
+        let options = |labels: &[&str]| labels.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+
+        assert!(matches!(
+            service.create_poll(event.id, attendee.id, false, "Next?", &options(&["A", "B"])).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.create_poll(event.id, organizer.id, false, "Next?", &options(&["Only"])).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.create_poll(event.id, organizer.id, false, "Next?", &options(&["Tea", " tea "])).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        let mut updates = service.notifier().subscribe();
+        let poll = service
+            .create_poll(event.id, organizer.id, false, "Next session?", &options(&["Feed", "Fish health"]))
+            .await
+            .unwrap()
+            .poll;
+        let (feed, health) = (poll.options[0].id, poll.options[1].id);
+
+        service.vote(event.id, poll.id, feed, attendee.id).await.unwrap();
+        service.vote(event.id, poll.id, health, organizer.id).await.unwrap();
+        let changed = service.vote(event.id, poll.id, health, attendee.id).await.unwrap();
+        assert_eq!(changed.voted_option_id, Some(health));
+        assert_eq!(changed.poll.options.iter().map(|o| o.vote_count).collect::<Vec<_>>(), vec![0, 2]);
+
+        // Every change reaches subscribers with the current tally
+        let mut last = None;
+        while let Ok(message) = updates.try_recv() {
+            assert_eq!(message.event_id, event.id);
+            last = Some(message.update);
+        }
+        match last {
+            Some(crate::domain::live_updates::LiveUpdate::PollChanged { poll }) => assert_eq!(poll.total_votes(), 2),
+            other => panic!("expected a poll update, got {:?}", other),
+        }
+
+        assert!(matches!(
+            service.vote(event.id, poll.id, Uuid::new_v4(), attendee.id).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let closed = service.close_poll(event.id, poll.id, organizer.id, false).await.unwrap();
+        assert!(closed.poll.is_closed());
+        assert_eq!(closed.voted_option_id, Some(health));
+        assert!(matches!(
+            service.vote(event.id, poll.id, feed, attendee.id).await,
+            Err(ApiError::Domain { .. })
+        ));
+
+        let session = LiveSessionResponse::from(service.session(event.id, attendee.id, false).await.unwrap());
+        assert!(session.is_running);
+        assert!(!session.can_moderate);
+        assert_eq!(session.polls.len(), 1);
+        assert_eq!(session.polls[0].voted_option_id, Some(health));
+        assert_eq!(session.polls[0].total_votes, 2);
+        assert!(service.session(event.id, organizer.id, false).await.unwrap().can_moderate);
+    }
+
+    // ============================================================================
+    // Event Attachment Tests
+    // ============================================================================
+
+    fn new_attachment(filename: &str, visibility: AttachmentVisibility) -> NewAttachment {
+        NewAttachment {
+            original_filename: filename.to_string(),
+            mime_type: "application/pdf".to_string(),
+            title: Some("  ".to_string()),
+            description: None,
+            category: None,
+            visibility,
+            bytes: b"%PDF-1.7".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_attachment_upload_is_organizer_only_and_validated() {
+        let (service, mocks) = create_mock_attachment_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        let attendee = TestUserBuilder::new().build();
+
+        assert!(matches!(
+            service.upload(event.id, &attendee, false, new_attachment("map.pdf", AttachmentVisibility::Public)).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let mut empty = new_attachment("map.pdf", AttachmentVisibility::Public);
+        empty.bytes.clear();
+        assert!(matches!(
+            service.upload(event.id, &organizer, false, empty).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let mut oversized = new_attachment("map.pdf", AttachmentVisibility::Public);
+        oversized.bytes = vec![0; EventAttachmentApplicationService::MAX_FILE_BYTES + 1];
+        assert!(matches!(
+            service.upload(event.id, &organizer, false, oversized).await,
+            Err(ApiError::PayloadTooLarge { .. })
+        ));
+
+        let attachment = service
+            .upload(event.id, &organizer, false, new_attachment("Site map (hall B).pdf", AttachmentVisibility::Public))
+            .await
+            .unwrap();
+        assert_eq!(attachment.category, AttachmentCategory::Document);
+        assert_eq!(attachment.title, None);
+        assert_eq!(attachment.file_size, 8);
+        assert_eq!(
+            mocks.storage.files.lock().await.get(&attachment.storage_key).map(Vec::len),
+            Some(8)
+        );
+
+        // A failed insert must not leave the file behind
+        mocks.attachments.set_should_fail(true).await;
+        assert!(service
+            .upload(event.id, &organizer, false, new_attachment("slides.pdf", AttachmentVisibility::Public))
+            .await
+            .is_err());
+        assert_eq!(mocks.storage.files.lock().await.len(), 1);
+        mocks.attachments.set_should_fail(false).await;
+
+        service.delete(event.id, attachment.id, organizer.id, false).await.unwrap();
+        assert!(mocks.storage.files.lock().await.is_empty());
+        assert!(matches!(
+            service.delete(event.id, attachment.id, organizer.id, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_attendee_only_attachments_require_confirmed_registration() {
+        let (service, mocks) = create_mock_attachment_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+
+        let map = service
+            .upload(event.id, &organizer, false, new_attachment("map.pdf", AttachmentVisibility::Public))
+            .await
+            .unwrap();
+        let slides = service
+            .upload(event.id, &organizer, false, new_attachment("slides.pdf", AttachmentVisibility::AttendeesOnly))
+            .await
+            .unwrap();
+
+        let attendee = TestUserBuilder::new().build();
+        let waitlisted = TestUserBuilder::new().build();
+        mocks
+            .registrations
+            .add_registration(TestRegistrationBuilder::new().with_event(event.id).with_user(attendee.id).build())
+            .await;
+        mocks
+            .registrations
+            .add_registration(TestRegistrationBuilder::new().with_event(event.id).with_user(waitlisted.id).waitlisted().build())
+            .await;
+
+        let ids = |attachments: Vec<EventAttachment>| attachments.into_iter().map(|a| a.id).collect::<Vec<_>>();
+        assert_eq!(ids(service.list_attachments(event.id, None, false).await.unwrap()), vec![map.id]);
+        assert_eq!(ids(service.list_attachments(event.id, Some(waitlisted.id), false).await.unwrap()), vec![map.id]);
+        assert_eq!(
+            ids(service.list_attachments(event.id, Some(attendee.id), false).await.unwrap()),
+            vec![map.id, slides.id]
+        );
+        let library = service.library(event.id, organizer.id, false).await.unwrap();
+        assert!(library.can_manage);
+        assert_eq!(library.attachments.len(), 2);
+        assert!(!service.library(event.id, attendee.id, false).await.unwrap().can_manage);
+        assert_eq!(ids(service.list_attachments(event.id, Some(waitlisted.id), true).await.unwrap()).len(), 2);
+
+        assert!(matches!(
+            service.download(event.id, slides.id, Some(waitlisted.id), false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.download(event.id, slides.id, None, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let (downloaded, bytes) = service.download(event.id, slides.id, Some(attendee.id), false).await.unwrap();
+        assert_eq!(downloaded.id, slides.id);
+        assert_eq!(bytes, b"%PDF-1.7");
+        service.download(event.id, map.id, None, false).await.unwrap();
+
+        // Attachments are only reachable through their own event
+        assert!(matches!(
+            service.download(Uuid::new_v4(), map.id, None, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+        let stored = mocks.attachments.find_by_id(slides.id).await.unwrap().unwrap();
+        assert_eq!(stored.download_count, 1);
+
+        // The public page only ever lists public files
+        let public = PublicEventResponse::new(
+            event.clone(),
+            0,
+            mocks.attachments.find_by_event_id(event.id).await.unwrap(),
+            Vec::new(),
+            Vec::new(),
+        );
+        assert_eq!(public.attachments.len(), 1);
+        assert_eq!(public.attachments[0].filename, "map.pdf");
+    }
+
+    // ============================================================================
+    // Certificate Service Tests
+    // ============================================================================
+
+    fn signature_png() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, 2, 1);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header().unwrap().write_image_data(&[0, 0, 0, 255, 0, 0, 0, 0]).unwrap();
+        bytes
+    }
+
+    #[tokio::test]
+    async fn test_certificate_requires_check_in_and_is_limited_to_attendee_and_organizers() {
+        let (service, mocks) = create_mock_certificate_service();
+        let organizer = TestUserBuilder::new().organizer().with_name("Ola Hansen").build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        mocks.users.add_user(organizer.clone()).await;
+
+        let attendee = TestUserBuilder::new().build();
+        let registration = TestRegistrationBuilder::new().with_event(event.id).with_user(attendee.id).build();
+        mocks.registrations.add_registration(registration.clone()).await;
+
+        assert!(matches!(
+            service.certificate(registration.id, attendee.id, false).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(service.verify(registration.id).await.unwrap().is_none());
+
+        let mut checked_in = registration.clone();
+        checked_in.status = RegistrationStatus::Attended;
+        checked_in.checked_in_at = Some(Utc::now());
+        mocks.registrations.update(&checked_in).await.unwrap();
+
+        let (filename, pdf) = service.certificate(registration.id, attendee.id, false).await.unwrap();
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(filename.starts_with("certificate-") && filename.ends_with(".pdf"));
+        service.certificate(registration.id, organizer.id, false).await.unwrap();
+        service.certificate(registration.id, Uuid::new_v4(), true).await.unwrap();
+        assert!(matches!(
+            service.certificate(registration.id, Uuid::new_v4(), false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.certificate(Uuid::new_v4(), attendee.id, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        let details = service.verify(registration.id).await.unwrap().unwrap();
+        assert_eq!(details.attendee_name, "Test User");
+        assert_eq!(details.organizer_name, "Ola Hansen");
+        assert_eq!(details.event_title, event.title);
+        assert_eq!(
+            details.verification_url,
+            format!("https://aqio.example/certificates/{}", registration.id)
+        );
+        assert!(service.verify(Uuid::new_v4()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_certificate_signature_is_organizer_only_and_must_be_png() {
+        let (service, mocks) = create_mock_certificate_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        let registration = TestRegistrationBuilder::new().with_event(event.id).attended().build();
+        mocks.registrations.add_registration(registration.clone()).await;
+        let attendee_id = registration.user_id.unwrap();
+
+        assert!(matches!(
+            service.set_signature(event.id, attendee_id, false, &signature_png()).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.set_signature(event.id, organizer.id, false, b"not an image").await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(mocks.storage.files.lock().await.is_empty());
+
+        let (_, unsigned) = service.certificate(registration.id, attendee_id, false).await.unwrap();
+        service.set_signature(event.id, organizer.id, false, &signature_png()).await.unwrap();
+        let (_, signed) = service.certificate(registration.id, attendee_id, false).await.unwrap();
+        assert!(signed.len() > unsigned.len());
+
+        service.remove_signature(event.id, organizer.id, false).await.unwrap();
+        assert!(mocks.storage.files.lock().await.is_empty());
+    }
+
+    // ============================================================================
+    // Event Budget Service Tests
+    // ============================================================================
+
+    fn expense_input(description: &str, amount: i64, budget_line_id: Option<Uuid>) -> ExpenseInput {
+        ExpenseInput {
+            budget_line_id,
+            description: description.to_string(),
+            vendor: None,
+            amount,
+            incurred_on: chrono::NaiveDate::from_ymd_opt(2026, 5, 4).unwrap(),
+        }
+    }
+
+    fn receipt_upload(filename: &str, mime_type: &str) -> ReceiptUpload {
+        ReceiptUpload {
+            original_filename: filename.to_string(),
+            mime_type: mime_type.to_string(),
+            bytes: b"%PDF-1.7".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_budget_is_organizer_only_and_totals_expenses_against_lines() {
+        let (service, mocks) = create_mock_budget_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        let attendee = TestUserBuilder::new().build();
+
+        let catering = BudgetLineInput {
+            name: "  Catering ".to_string(),
+            planned_amount: 2_000_000,
+            notes: None,
+        };
+        assert!(matches!(
+            service.add_line(event.id, attendee.id, false, catering.clone()).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.add_line(event.id, organizer.id, false, BudgetLineInput { planned_amount: -1, ..catering.clone() }).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let line = service.add_line(event.id, organizer.id, false, catering).await.unwrap();
+        assert_eq!(line.name, "Catering");
+
+        assert!(matches!(
+            service.record_expense(event.id, &organizer, false, expense_input("Lunch", 0, Some(line.id))).await,
+            Err(ApiError::Validation { .. })
+        ));
+        // Lines from other events cannot be booked against
+        assert!(matches!(
+            service.record_expense(event.id, &organizer, false, expense_input("Lunch", 100, Some(Uuid::new_v4()))).await,
+            Err(ApiError::NotFound { .. })
+        ));
+        let lunch = service
+            .record_expense(event.id, &organizer, false, expense_input("Lunch", 1_500_000, Some(line.id)))
+            .await
+            .unwrap();
+        service
+            .record_expense(event.id, &organizer, false, expense_input("Name badges", 80_000, None))
+            .await
+            .unwrap();
+        service
+            .update_expense(event.id, lunch.id, organizer.id, false, expense_input("Lunch", 2_500_000, Some(line.id)))
+            .await
+            .unwrap();
+
+        let budget = service.budget(event.id, organizer.id, false).await.unwrap();
+        assert_eq!(budget.expenses.len(), 2);
+        assert_eq!(budget.summary.planned, 2_000_000);
+        assert_eq!(budget.summary.spent, 2_580_000);
+        assert_eq!(budget.summary.unassigned, 80_000);
+        assert!(budget.summary.is_over_budget());
+        assert_eq!(budget.summary.lines[0].remaining(), -500_000);
+
+        assert!(service.summary(event.id, attendee.id, false).await.unwrap().is_none());
+        assert!(service.summary(event.id, attendee.id, true).await.unwrap().is_some());
+        assert!(matches!(
+            service.budget(event.id, attendee.id, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+
+        // Removing a line keeps its spending on the budget as unassigned
+        service.delete_line(event.id, line.id, organizer.id, false).await.unwrap();
+        let summary = service.summary(event.id, organizer.id, false).await.unwrap().unwrap();
+        assert_eq!(summary.planned, 0);
+        assert_eq!(summary.spent, 2_580_000);
+        assert_eq!(summary.unassigned, 2_580_000);
+    }
+
+    #[tokio::test]
+    async fn test_expense_receipts_are_replaced_and_cleaned_up() {
+        let (service, mocks) = create_mock_budget_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        let expense = service
+            .record_expense(event.id, &organizer, false, expense_input("Venue hire", 4_000_000, None))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            service.attach_receipt(event.id, expense.id, organizer.id, false, receipt_upload("receipt.zip", "application/zip")).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.receipt(event.id, expense.id, organizer.id, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        let first = service
+            .attach_receipt(event.id, expense.id, organizer.id, false, receipt_upload("invoice.pdf", "application/pdf"))
+            .await
+            .unwrap();
+        let second = service
+            .attach_receipt(event.id, expense.id, organizer.id, false, receipt_upload("invoice-2.jpg", "image/jpeg"))
+            .await
+            .unwrap();
+        let files = mocks.storage.files.lock().await.keys().cloned().collect::<Vec<_>>();
+        assert_eq!(files, vec![second.receipt.clone().unwrap().storage_key]);
+        assert_ne!(first.receipt.unwrap().storage_key, files[0]);
+
+        let (receipt, bytes) = service.receipt(event.id, expense.id, organizer.id, false).await.unwrap();
+        assert_eq!(receipt.original_filename, "invoice-2.jpg");
+        assert_eq!(bytes, b"%PDF-1.7");
+
+        let cleared = service.remove_receipt(event.id, expense.id, organizer.id, false).await.unwrap();
+        assert!(cleared.receipt.is_none());
+        assert!(mocks.storage.files.lock().await.is_empty());
+
+        service
+            .attach_receipt(event.id, expense.id, organizer.id, false, receipt_upload("invoice.pdf", "application/pdf"))
+            .await
+            .unwrap();
+        service.delete_expense(event.id, expense.id, organizer.id, false).await.unwrap();
+        assert!(mocks.storage.files.lock().await.is_empty());
+        assert!(matches!(
+            service.delete_expense(event.id, expense.id, organizer.id, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+
+    // ============================================================================
+    // Storage Quota Service Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_uploads_are_refused_once_the_organization_quota_is_used() {
+        let (quota, mocks) = create_mock_storage_quota_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 2).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+        let participant = TestUserBuilder::new().with_company(company.id).build();
+        let freelancer = TestUserBuilder::new().organizer().build();
+        mocks.users.add_user(organizer.clone()).await;
+        mocks.users.add_user(freelancer.clone()).await;
+
+        assert!(matches!(
+            quota.report(company.id, &participant, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let report = quota.report(company.id, &organizer, false).await.unwrap();
+        assert_eq!(report.quota_bytes, Some(StorageQuotaApplicationService::DEFAULT_QUOTA_BYTES));
+        assert!(!report.custom_quota);
+
+        assert!(matches!(
+            quota.set_quota(company.id, Some(1_000), false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            quota.set_quota(company.id, Some(-1), true).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let (before, after) = quota.set_quota(company.id, Some(1_000), true).await.unwrap();
+        assert!(!before.custom_quota);
+        assert_eq!((after.quota_bytes, after.custom_quota), (Some(1_000), true));
+
+        let (attachments, attachment_mocks) = create_mock_attachment_service();
+        let attachments = attachments.with_storage_quota(quota.clone());
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        let freelance_event = TestEventBuilder::new().with_organizer(freelancer.id).build();
+        attachment_mocks.events.add_event(event.clone()).await;
+        attachment_mocks.events.add_event(freelance_event.clone()).await;
+
+        mocks.quotas.set_usage(company.id, StorageUsage { attachment_bytes: 995, receipt_bytes: 0, photo_bytes: 0, files: 3 }).await;
+        assert_eq!(quota.report(company.id, &organizer, false).await.unwrap().remaining_bytes(), Some(5));
+        assert!(matches!(
+            attachments.upload(event.id, &organizer, false, new_attachment("map.pdf", AttachmentVisibility::Public)).await,
+            Err(ApiError::InsufficientStorage { .. })
+        ));
+        assert!(attachment_mocks.storage.files.lock().await.is_empty());
+        // Organizers outside an organization are held to the per-file limit only
+        attachments
+            .upload(freelance_event.id, &freelancer, false, new_attachment("map.pdf", AttachmentVisibility::Public))
+            .await
+            .unwrap();
+
+        // Replacing a receipt only counts the difference
+        let (budgets, budget_mocks) = create_mock_budget_service();
+        let budgets = budgets.with_storage_quota(quota.clone());
+        budget_mocks.events.add_event(event.clone()).await;
+        let expense = budgets
+            .record_expense(event.id, &organizer, false, expense_input("Venue hire", 4_000_000, None))
+            .await
+            .unwrap();
+        mocks.quotas.set_usage(company.id, StorageUsage { attachment_bytes: 990, receipt_bytes: 0, photo_bytes: 0, files: 2 }).await;
+        budgets
+            .attach_receipt(event.id, expense.id, organizer.id, false, receipt_upload("invoice.pdf", "application/pdf"))
+            .await
+            .unwrap();
+        mocks.quotas.set_usage(company.id, StorageUsage { attachment_bytes: 990, receipt_bytes: 8, photo_bytes: 0, files: 3 }).await;
+        budgets
+            .attach_receipt(event.id, expense.id, organizer.id, false, receipt_upload("invoice-2.pdf", "application/pdf"))
+            .await
+            .unwrap();
+
+        // Back to the default quota
+        let (_, after) = quota.set_quota(company.id, None, true).await.unwrap();
+        assert!(!after.custom_quota);
+        assert!(mocks.quotas.quotas.lock().await.is_empty());
+    }
+
+    // ============================================================================
+    // Event Sponsor Service Tests
+    // ============================================================================
+
+    fn sponsor_input(name: &str, tier: SponsorTier) -> SponsorInput {
+        SponsorInput {
+            name: name.to_string(),
+            tier,
+            logo_url: None,
+            website_url: None,
+            description: None,
+            display_order: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sponsors_are_organizer_managed_and_validated() {
+        let (service, mocks) = create_mock_sponsor_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        let attendee = TestUserBuilder::new().build();
+
+        assert!(matches!(
+            service.add(event.id, attendee.id, false, sponsor_input("Mowi", SponsorTier::Gold)).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.add(event.id, organizer.id, false, sponsor_input("   ", SponsorTier::Gold)).await,
+            Err(ApiError::Validation { .. })
+        ));
+        for bad_url in ["javascript:alert(1)", "https://", "https://mowi.example/a logo.png"] {
+            let input = SponsorInput {
+                logo_url: Some(bad_url.to_string()),
+                ..sponsor_input("Mowi", SponsorTier::Gold)
+            };
+            assert!(matches!(
+                service.add(event.id, organizer.id, false, input).await,
+                Err(ApiError::Validation { .. })
+            ));
+        }
+
+        let mowi = service
+            .add(
+                event.id,
+                organizer.id,
+                false,
+                SponsorInput {
+                    logo_url: Some(" https://mowi.example/logo.png ".to_string()),
+                    website_url: Some("".to_string()),
+                    ..sponsor_input(" Mowi ", SponsorTier::Gold)
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(mowi.name, "Mowi");
+        assert_eq!(mowi.logo_url.as_deref(), Some("https://mowi.example/logo.png"));
+        assert!(mowi.website_url.is_none());
+        assert_eq!(mowi.display_order, 0);
+
+        // New sponsors go last within their tier
+        let salmar = service.add(event.id, organizer.id, false, sponsor_input("Salmar", SponsorTier::Gold)).await.unwrap();
+        assert_eq!(salmar.display_order, 1);
+        let partner = service.add(event.id, organizer.id, false, sponsor_input("Fjord Media", SponsorTier::Partner)).await.unwrap();
+        assert_eq!(partner.display_order, 0);
+
+        let updated = service
+            .update(event.id, partner.id, organizer.id, false, sponsor_input("Fjord Media", SponsorTier::Platinum))
+            .await
+            .unwrap();
+        assert_eq!(updated.tier, SponsorTier::Platinum);
+        assert!(matches!(
+            service.update(Uuid::new_v4(), partner.id, organizer.id, true, sponsor_input("Fjord Media", SponsorTier::Gold)).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        let listed: Vec<Uuid> = service.list(event.id).await.unwrap().iter().map(|s| s.id).collect();
+        assert_eq!(listed, vec![partner.id, mowi.id, salmar.id]);
+
+        assert!(matches!(
+            service.delete(event.id, salmar.id, attendee.id, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        service.delete(event.id, salmar.id, attendee.id, true).await.unwrap();
+        assert_eq!(service.list(event.id).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_reordering_sponsors_sets_display_order_within_tiers() {
+        let (service, mocks) = create_mock_sponsor_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+
+        let mowi = service.add(event.id, organizer.id, false, sponsor_input("Mowi", SponsorTier::Gold)).await.unwrap();
+        let salmar = service.add(event.id, organizer.id, false, sponsor_input("Salmar", SponsorTier::Gold)).await.unwrap();
+        let lerøy = service.add(event.id, organizer.id, false, sponsor_input("Lerøy", SponsorTier::Platinum)).await.unwrap();
+
+        assert!(matches!(
+            service.reorder(event.id, vec![salmar.id, salmar.id], organizer.id, false).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.reorder(event.id, vec![Uuid::new_v4()], organizer.id, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        // Order within a tier follows the list; the tier still decides first
+        let reordered = service
+            .reorder(event.id, vec![salmar.id, mowi.id, lerøy.id], organizer.id, false)
+            .await
+            .unwrap();
+        let ids: Vec<Uuid> = reordered.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![lerøy.id, salmar.id, mowi.id]);
+        let stored: Vec<Uuid> = service.list(event.id).await.unwrap().iter().map(|s| s.id).collect();
+        assert_eq!(stored, ids);
+
+        let public = PublicEventResponse::new(event.clone(), 0, Vec::new(), service.list(event.id).await.unwrap(), Vec::new());
+        assert_eq!(public.sponsors.len(), 3);
+        assert_eq!(public.sponsors[0].name, "Lerøy");
+    }
+
+    // ============================================================================
+    // Short Link Service Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_short_links_are_organizer_managed() {
+        let (service, mocks) = create_mock_short_link_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).with_slug("havbruk-2026").published().build();
+        mocks.events.add_event(event.clone()).await;
+        let attendee = TestUserBuilder::new().build();
+
+        assert!(matches!(
+            service.create(event.id, attendee.id, false, ShortLinkInput::default()).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let too_long = ShortLinkInput {
+            label: Some("x".repeat(ShortLinkApplicationService::MAX_LABEL_CHARS + 1)),
+            ..ShortLinkInput::default()
+        };
+        assert!(matches!(
+            service.create(event.id, organizer.id, false, too_long).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        let input = ShortLinkInput {
+            label: Some(" April newsletter ".to_string()),
+            campaign: CampaignParameters {
+                source: Some("newsletter".to_string()),
+                medium: Some(" ".to_string()),
+                campaign: Some("spring 2026".to_string()),
+                content: None,
+            },
+        };
+        let link = service.create(event.id, organizer.id, false, input).await.unwrap();
+        assert!(ShortLink::is_code(&link.code), "{}", link.code);
+        assert_eq!(link.label.as_deref(), Some("April newsletter"));
+        assert!(link.campaign.medium.is_none());
+        assert_eq!(service.short_url(&link), format!("https://aqio.example/e/{}", link.code));
+
+        // Another event's link isn't reachable through this event
+        assert!(matches!(
+            service.delete(Uuid::new_v4(), link.id, organizer.id, true).await,
+            Err(ApiError::NotFound { .. })
+        ));
+        let listed = service.list(event.id, organizer.id, false).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!((listed[0].1.link_id, listed[0].1.clicks), (link.id, 0));
+
+        service.delete(event.id, link.id, organizer.id, false).await.unwrap();
+        assert!(service.list(event.id, organizer.id, false).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_following_a_short_link_tracks_the_click() {
+        let (service, mocks) = create_mock_short_link_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).with_slug("havbruk-2026").published().build();
+        mocks.events.add_event(event.clone()).await;
+        let guest = TestUserBuilder::new().build();
+        let invitation = create_test_invitation(event.id, guest.id, organizer.id);
+        mocks.invitations.add_invitation(invitation.clone()).await;
+        let other_event_invitation = create_test_invitation(Uuid::new_v4(), guest.id, organizer.id);
+        mocks.invitations.add_invitation(other_event_invitation.clone()).await;
+
+        let plain = service.create(event.id, organizer.id, false, ShortLinkInput::default()).await.unwrap();
+        let campaign = ShortLinkInput {
+            label: None,
+            campaign: CampaignParameters {
+                source: Some("linkedin".to_string()),
+                medium: None,
+                campaign: Some("spring 2026".to_string()),
+                content: None,
+            },
+        };
+        let shared = service.create(event.id, organizer.id, false, campaign).await.unwrap();
+
+        assert!(matches!(service.follow("Zzzzzzz", None, None).await, Err(ApiError::NotFound { .. })));
+        assert_eq!(
+            service.follow(&plain.code, None, None).await.unwrap(),
+            "https://aqio.example/e/havbruk-2026"
+        );
+        assert_eq!(
+            service
+                .follow(&shared.code, None, Some("https://www.LinkedIn.com/feed/"))
+                .await
+                .unwrap(),
+            "https://aqio.example/e/havbruk-2026?utm_source=linkedin&utm_campaign=spring+2026"
+        );
+
+        // Invitations' personal copies default the medium and mark the invitation clicked
+        let personal = service.invitation_links(event.id, shared.id, organizer.id, false).await.unwrap();
+        let token = invitation.invitation_token.clone().unwrap();
+        assert_eq!(personal.len(), 1);
+        assert_eq!(personal[0].url, format!("https://aqio.example/e/{}?i={}", shared.code, token));
+        assert_eq!(
+            service.follow(&shared.code, Some(&token), None).await.unwrap(),
+            "https://aqio.example/e/havbruk-2026?utm_source=linkedin&utm_medium=email&utm_campaign=spring+2026"
+        );
+        let clicked = mocks.invitations.invitations.lock().await[&invitation.id].clone();
+        assert!(clicked.clicked_at.is_some());
+        assert_eq!(clicked.status, InvitationStatus::Opened);
+        // Tokens of other events' invitations still count, without attribution
+        let other_token = other_event_invitation.invitation_token.clone().unwrap();
+        service.follow(&shared.code, Some(&other_token), None).await.unwrap();
+
+        let (_, stats) = service.stats(event.id, shared.id, organizer.id, false).await.unwrap();
+        assert_eq!((stats.clicks, stats.invitation_clicks, stats.invitations_clicked), (3, 1, 1));
+        assert_eq!(stats.referrers[0], ReferrerClicks { host: "".to_string(), clicks: 2 });
+        assert_eq!(stats.referrers[1].host, "www.linkedin.com");
+        let listed = service.list(event.id, organizer.id, false).await.unwrap();
+        let clicks: Vec<i64> = listed.iter().map(|(_, clicks)| clicks.clicks).collect();
+        assert_eq!(clicks.iter().sum::<i64>(), 4);
+    }
+
+    // ============================================================================
+    // Event Poster Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_posters_point_to_the_public_page() {
+        let (service, mocks) = create_mock_poster_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new()
+            .with_organizer(organizer.id)
+            .with_slug("havbruk-2026")
+            .with_title("Havbruk 2026")
+            .published()
+            .build();
+        mocks.events.add_event(event.clone()).await;
+        mocks.categories.add_category(TestCategoryBuilder::new().with_id(event.category_id.clone()).build()).await;
+        let attendee = TestUserBuilder::new().build();
+
+        assert!(matches!(
+            service.render(event.id, attendee.id, false, PosterFormat::Pdf, None).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let (filename, pdf) = service.render(event.id, organizer.id, false, PosterFormat::Pdf, None).await.unwrap();
+        assert_eq!(filename, "poster-havbruk-2026.pdf");
+        assert!(pdf.starts_with(b"%PDF-"));
+        let contains = |bytes: &[u8], needle: &str| bytes.windows(needle.len()).any(|window| window == needle.as_bytes());
+        assert!(contains(&pdf, "https://aqio.example/e/havbruk-2026"));
+        let (filename, png) = service.render(event.id, organizer.id, true, PosterFormat::Png, None).await.unwrap();
+        assert_eq!(filename, "poster-havbruk-2026.png");
+        assert!(png.starts_with(b"\x89PNG"));
+
+        // Scans through a short link are counted with its clicks
+        let link = ShortLink {
+            id: Uuid::new_v4(),
+            event_id: event.id,
+            code: "Havbruk".to_string(),
+            label: None,
+            campaign: CampaignParameters::default(),
+            created_by: organizer.id,
+            created_at: Utc::now(),
+        };
+        mocks.links.create(&link).await.unwrap();
+        let (_, pdf) = service
+            .render(event.id, organizer.id, false, PosterFormat::Pdf, Some(link.id))
+            .await
+            .unwrap();
+        assert!(contains(&pdf, "https://aqio.example/e/Havbruk"));
+        assert!(matches!(
+            service.render(event.id, organizer.id, false, PosterFormat::Pdf, Some(Uuid::new_v4())).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        let private = TestEventBuilder::new().with_organizer(organizer.id).private().build();
+        mocks.events.add_event(private.clone()).await;
+        assert!(matches!(
+            service.render(private.id, organizer.id, false, PosterFormat::Png, None).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+    }
+
+    // ============================================================================
+    // Overbooking Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_overbooking_policy_raises_the_capacity_up_to_the_ceiling() {
+        let (service, registrations, mocks) = create_mock_overbooking_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        // Past events where one in four registrants didn't show up
+        for weeks_ago in 1..=3 {
+            let past = TestEventBuilder::new()
+                .with_organizer(organizer.id)
+                .starting_at(Utc::now() - chrono::Duration::weeks(weeks_ago))
+                .published()
+                .build();
+            mocks.events.add_event(past.clone()).await;
+            for attended in [true, true, true, false] {
+                let registration = TestRegistrationBuilder::new().with_event(past.id);
+                let registration = if attended { registration.attended() } else { registration };
+                mocks.registrations.add_registration(registration.build()).await;
+            }
+        }
+        let event = TestEventBuilder::new().with_organizer(organizer.id).with_capacity(4, true).published().build();
+        mocks.events.add_event(event.clone()).await;
+        let mut unlimited = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        unlimited.max_attendees = None;
+        mocks.events.add_event(unlimited.clone()).await;
+
+        let overview = service.overview(event.id, organizer.id, false).await.unwrap();
+        assert!(overview.policy.is_none());
+        assert_eq!((overview.capacity, overview.bookable_capacity), (Some(4), Some(4)));
+        assert_eq!((overview.history.events, overview.history.registrations, overview.history.checked_in), (3, 12, 9));
+        // Booking a third more fills the seats on average; three quarters of that is suggested
+        assert_eq!(overview.history.suggested_percent(), Some(25));
+
+        let attendee = TestUserBuilder::new().build();
+        assert!(matches!(
+            service.set_policy(event.id, attendee.id, false, 25, None).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.set_policy(event.id, organizer.id, false, OverbookingPolicy::MAX_PERCENT + 1, None).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.set_policy(event.id, organizer.id, false, 25, Some(3)).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.set_policy(unlimited.id, organizer.id, false, 25, None).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+
+        // 50% would allow six; the ceiling stops at five
+        service.set_policy(event.id, organizer.id, false, 50, Some(5)).await.unwrap();
+        assert_eq!(registrations.bookable_capacity(&event).await.unwrap(), Some(5));
+        let mut statuses = Vec::new();
+        for _ in 0..6 {
+            let registration = TestRegistrationBuilder::new().with_event(event.id).build();
+            statuses.push(registrations.register(&event, &registration).await.unwrap().status);
+        }
+        assert_eq!(statuses.iter().filter(|s| **s == RegistrationStatus::Registered).count(), 5);
+        assert_eq!(statuses[5], RegistrationStatus::Waitlisted);
+
+        let report = service.report(event.id, organizer.id, false).await.unwrap();
+        assert_eq!((report.registrations, report.checked_in, report.no_shows), (5, 0, 5));
+        assert_eq!((report.capacity, report.bookable_capacity, report.over_capacity), (Some(4), Some(5), 0));
+
+        service.remove_policy(event.id, organizer.id, false).await.unwrap();
+        assert_eq!(registrations.bookable_capacity(&event).await.unwrap(), Some(4));
+    }
+
+    // ============================================================================
+    // Ticketing Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_ticket_orders_keep_the_exchange_rate_of_their_day() {
+        let (service, mocks) = create_mock_ticketing_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let buyer = TestUserBuilder::new().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+        let ticket_type = |name: &str, price, currency, quantity| TicketTypeInput {
+            name: name.to_string(),
+            description: None,
+            price,
+            currency,
+            vat_rate: 0,
+            quantity,
+            is_active: true,
+        };
+
+        assert!(matches!(
+            service.create_ticket_type(event.id, buyer.id, false, ticket_type("Standard", 150_000, Currency::Nok, None)).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let nok = service
+            .create_ticket_type(event.id, organizer.id, false, ticket_type("Standard", 150_000, Currency::Nok, None))
+            .await
+            .unwrap();
+        let eur = service
+            .create_ticket_type(event.id, organizer.id, false, ticket_type("International", 12_000, Currency::Eur, Some(3)))
+            .await
+            .unwrap();
+
+        service.purchase(event.id, nok.id, buyer.id, 2).await.unwrap();
+        let first = service.purchase(event.id, eur.id, buyer.id, 1).await.unwrap();
+        assert_eq!((first.currency, first.exchange_rate, first.total_nok), (Currency::Eur, 11.5, 138_000));
+
+        // A later rate applies to later orders only
+        mocks.rates.set_rate(Currency::Eur, 12.0).await;
+        service.purchase(event.id, eur.id, buyer.id, 2).await.unwrap();
+        assert!(matches!(
+            service.purchase(event.id, eur.id, buyer.id, 1).await,
+            Err(ApiError::Conflict { .. })
+        ));
+        assert!(matches!(
+            service.purchase(event.id, nok.id, buyer.id, TicketingApplicationService::MAX_TICKETS_PER_ORDER + 1).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        let revenue = service.revenue(event.id, organizer.id, false).await.unwrap();
+        let by_code: Vec<_> = revenue.by_currency.iter().map(|r| (r.currency, r.gross, r.gross_nok)).collect();
+        assert_eq!(by_code, vec![(Currency::Eur, 36_000, 138_000 + 288_000), (Currency::Nok, 300_000, 300_000)]);
+        assert_eq!((revenue.orders, revenue.tickets, revenue.total_nok), (3, 5, 726_000));
+
+        // Cancelling puts the tickets back on sale and out of the revenue
+        service.cancel_order(first.id, buyer.id, false).await.unwrap();
+        assert!(matches!(
+            service.cancel_order(first.id, buyer.id, false).await,
+            Err(ApiError::Conflict { .. })
+        ));
+        let types = service.list_ticket_types(event.id, buyer.id, false).await.unwrap();
+        let international = types.iter().find(|t| t.ticket_type.id == eur.id).unwrap();
+        assert_eq!((international.sold, international.remaining), (2, Some(1)));
+        assert_eq!(service.revenue(event.id, organizer.id, false).await.unwrap().total_nok, 588_000);
+    }
+
+    // ============================================================================
+    // Invoicing Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_invoices_are_issued_once_per_order_and_voided_on_cancel() {
+        let (service, mocks) = create_mock_invoicing_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let buyer = TestUserBuilder::new().build();
+        let stranger = TestUserBuilder::new().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+        let standard = mocks
+            .ticketing
+            .create_ticket_type(
+                event.id,
+                organizer.id,
+                false,
+                TicketTypeInput {
+                    name: "Standard".to_string(),
+                    description: None,
+                    price: 150_000,
+                    currency: Currency::Nok,
+                    vat_rate: 0,
+                    quantity: None,
+                    is_active: true,
+                },
+            )
+            .await
+            .unwrap();
+        let first = mocks.ticketing.purchase(event.id, standard.id, buyer.id, 2).await.unwrap();
+        let second = mocks.ticketing.purchase(event.id, standard.id, buyer.id, 1).await.unwrap();
+        let billing = |org_number: &str, reference: &str| BillingDetails {
+            name: "Havbruk AS".to_string(),
+            org_number: org_number.to_string(),
+            address: None,
+            postal_code: None,
+            city: Some("Bergen".to_string()),
+            email: None,
+            buyer_reference: reference.to_string(),
+        };
+
+        assert!(matches!(
+            service.issue(first.id, stranger.id, false, billing("974760673", "4410")).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.issue(first.id, buyer.id, false, billing("974760673", " ")).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.issue(first.id, buyer.id, false, billing("974760674", "4410")).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let invoice = service.issue(first.id, buyer.id, false, billing("974 760 673", "4410")).await.unwrap();
+        assert_eq!(invoice.invoice_number, 1);
+        assert_eq!((invoice.total, invoice.currency), (300_000, Currency::Nok));
+        assert_eq!(invoice.buyer.org_number, "974760673");
+        assert_eq!(invoice.due_date, invoice.issue_date + chrono::Duration::days(14));
+        assert!(invoice.description.starts_with("Standard – "));
+        assert!(matches!(
+            service.issue(first.id, organizer.id, false, billing("974760673", "4410")).await,
+            Err(ApiError::Conflict { .. })
+        ));
+        let (_, xml) = service.ehf(invoice.id, buyer.id, false).await.unwrap();
+        assert!(xml.contains("<cbc:PayableAmount currencyID=\"NOK\">3000.00</cbc:PayableAmount>"));
+        assert!(xml.contains("<cbc:ID>O</cbc:ID>"));
+        assert_eq!(service.issue(second.id, organizer.id, false, billing("974760673", "4410")).await.unwrap().invoice_number, 2);
+
+        // Listing is for organizers, per event
+        assert!(matches!(
+            service.list(buyer.id, false, InvoiceQuery::default()).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let query = InvoiceQuery { event_id: Some(event.id), ..Default::default() };
+        assert_eq!(service.list(organizer.id, false, query.clone()).await.unwrap().len(), 2);
+        let overdue = InvoiceQuery { overdue_only: true, ..query.clone() };
+        assert!(service.list(organizer.id, false, overdue).await.unwrap().is_empty());
+
+        // Cancelling the order voids its unpaid invoice
+        mocks.ticketing.cancel_order(first.id, buyer.id, false).await.unwrap();
+        let voided = service.get(invoice.id, buyer.id, false).await.unwrap();
+        assert_eq!(voided.status, InvoiceStatus::Voided);
+        assert!(matches!(service.ehf(invoice.id, buyer.id, false).await, Err(ApiError::BadRequest { .. })));
+        assert!(matches!(
+            service.mark_paid(invoice.id, organizer.id, false).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unpaid_invoices_past_due_are_overdue() {
+        let (service, mocks) = create_mock_invoicing_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+        let today = Utc::now().date_naive();
+        let invoice = |order_id, due_in_days| Invoice {
+            id: Uuid::new_v4(),
+            invoice_number: 0,
+            order_id,
+            event_id: event.id,
+            seller: InvoiceParty::default(),
+            buyer: InvoiceParty::default(),
+            buyer_reference: "4410".to_string(),
+            bank_account: None,
+            currency: Currency::Nok,
+            description: "Standard".to_string(),
+            quantity: 1,
+            unit_price: 150_000,
+            total: 150_000,
+            vat_rate: 0,
+            vat_amount: 0,
+            issue_date: today - chrono::Duration::days(30),
+            due_date: today + chrono::Duration::days(due_in_days),
+            status: InvoiceStatus::Issued,
+            paid_at: None,
+            created_at: Utc::now(),
+        };
+        let late = invoice(Uuid::new_v4(), -5);
+        mocks.invoices.create_numbered(&late).await.unwrap();
+        mocks.invoices.create_numbered(&invoice(Uuid::new_v4(), 3)).await.unwrap();
+
+        let overdue_only = InvoiceQuery { event_id: Some(event.id), overdue_only: true, ..Default::default() };
+        let overdue = service.list(organizer.id, false, overdue_only.clone()).await.unwrap();
+        assert_eq!(overdue.iter().map(|i| i.id).collect::<Vec<_>>(), vec![late.id]);
+        assert_eq!(overdue[0].days_overdue(today), 5);
+
+        let paid = service.mark_paid(late.id, organizer.id, false).await.unwrap();
+        assert_eq!(paid.status, InvoiceStatus::Paid);
+        assert!(!paid.is_overdue(today));
+        assert!(matches!(service.mark_paid(late.id, organizer.id, false).await, Err(ApiError::Conflict { .. })));
+        assert!(service.list(organizer.id, false, overdue_only).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_orders_with_vat_need_a_vat_registered_seller() {
+        let (service, mocks) = create_mock_invoicing_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+        let input = TicketTypeInput {
+            name: "Standard".to_string(),
+            description: None,
+            price: 150_000,
+            currency: Currency::Nok,
+            vat_rate: 25,
+            quantity: None,
+            is_active: true,
+        };
+        let standard = mocks.ticketing.create_ticket_type(event.id, organizer.id, false, input).await.unwrap();
+        let order = mocks.ticketing.purchase(event.id, standard.id, organizer.id, 1).await.unwrap();
+        assert_eq!((order.vat_rate, order.vat_amount), (25, 30_000));
+
+        let billing = BillingDetails {
+            name: "Havbruk AS".to_string(),
+            org_number: "974760673".to_string(),
+            address: None,
+            postal_code: None,
+            city: None,
+            email: None,
+            buyer_reference: "4410".to_string(),
+        };
+        assert!(matches!(
+            service.issue(order.id, organizer.id, false, billing).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+    }
+
+    // ============================================================================
+    // Financial Report Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_monthly_report_nets_refunds_per_vat_rate() {
+        let (service, mocks) = create_mock_ticketing_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let buyer = TestUserBuilder::new().build();
+        let event = TestEventBuilder::new()
+            .with_slug("havbruk")
+            .with_organizer(organizer.id)
+            .published()
+            .build();
+        mocks.events.add_event(event.clone()).await;
+        let ticket_type = |name: &str, price, vat_rate| TicketTypeInput {
+            name: name.to_string(),
+            description: None,
+            price,
+            currency: Currency::Nok,
+            vat_rate,
+            quantity: None,
+            is_active: true,
+        };
+
+        assert!(matches!(
+            service.create_ticket_type(event.id, organizer.id, false, ticket_type("Standard", 150_000, 20)).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let standard = service
+            .create_ticket_type(event.id, organizer.id, false, ticket_type("Standard", 150_000, 25))
+            .await
+            .unwrap();
+        let student = service
+            .create_ticket_type(event.id, organizer.id, false, ticket_type("Student", 50_000, 0))
+            .await
+            .unwrap();
+        service.purchase(event.id, standard.id, buyer.id, 2).await.unwrap();
+        let refunded = service.purchase(event.id, standard.id, buyer.id, 1).await.unwrap();
+        service.purchase(event.id, student.id, buyer.id, 1).await.unwrap();
+        service.cancel_order(refunded.id, buyer.id, false).await.unwrap();
+
+        let now = Utc::now().with_timezone(&chrono_tz::Europe::Oslo);
+        let (year, month) = (chrono::Datelike::year(&now), chrono::Datelike::month(&now));
+        assert!(matches!(
+            service.monthly_report(year, month, Some(event.id), buyer.id, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.monthly_report(year, month, None, organizer.id, false).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.monthly_report(year, 13, Some(event.id), organizer.id, false).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        let report = service.monthly_report(year, month, Some(event.id), organizer.id, false).await.unwrap();
+        let rates: Vec<_> = report.by_vat_rate.iter().map(|line| (line.vat_rate, line.orders, line.refunded_orders)).collect();
+        assert_eq!(rates, vec![(0, 1, 0), (25, 2, 1)]);
+        assert_eq!((report.sales_nok(), report.sales_vat_nok()), (500_000, 90_000));
+        assert_eq!((report.refunds_nok(), report.refunds_vat_nok()), (150_000, 30_000));
+        assert_eq!((report.net_revenue_nok(), report.vat_collected_nok()), (290_000, 60_000));
+        let last_year = service.monthly_report(year - 1, month, Some(event.id), organizer.id, false).await.unwrap();
+        assert!(last_year.by_vat_rate.is_empty());
+
+        // Administrators can report on every event
+        let all = service.monthly_report(year, month, None, Uuid::new_v4(), true).await.unwrap();
+        assert_eq!(all.event_id, None);
+        assert_eq!(all.vat_collected_nok(), 60_000);
+
+        let (filename, csv) = service.monthly_report_csv(year, month, Some(event.id), organizer.id, false).await.unwrap();
+        assert_eq!(filename, format!("financial-report-havbruk-{}-{:02}.csv", year, month));
+        assert!(csv.ends_with("Total,3,5000.00,900.00,1,1500.00,300.00,2900.00,600.00\r\n"));
+    }
+
+    // ============================================================================
+    // Refund Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_cancelling_an_event_refunds_paid_orders_with_retries() {
+        let (service, mocks) = create_mock_refund_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let (buyer, other_buyer, free_buyer) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+        let ticket_type = |name: &str, price| TicketTypeInput {
+            name: name.to_string(),
+            description: None,
+            price,
+            currency: Currency::Nok,
+            vat_rate: 0,
+            quantity: None,
+            is_active: true,
+        };
+        let standard = mocks
+            .ticketing
+            .create_ticket_type(event.id, organizer.id, false, ticket_type("Standard", 150_000))
+            .await
+            .unwrap();
+        let free = mocks
+            .ticketing
+            .create_ticket_type(event.id, organizer.id, false, ticket_type("Speaker", 0))
+            .await
+            .unwrap();
+        mocks.ticketing.purchase(event.id, standard.id, buyer, 2).await.unwrap();
+        mocks.ticketing.purchase(event.id, standard.id, other_buyer, 1).await.unwrap();
+        mocks.ticketing.purchase(event.id, free.id, free_buyer, 1).await.unwrap();
+        let registrant = TestRegistrationBuilder::new().with_event(event.id).build();
+        let gone = TestRegistrationBuilder::new().with_event(event.id).cancelled().build();
+        mocks.registrations.add_registration(registrant.clone()).await;
+        mocks.registrations.add_registration(gone).await;
+
+        assert!(matches!(
+            service.cancel_event(event.id, buyer, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+
+        // The first refund finds Vipps down and is left for the scheduler
+        mocks.payments.set_unavailable_for(1).await;
+        let mut notifications = mocks.notifier.subscribe();
+        let refunds = service.cancel_event(event.id, organizer.id, false).await.unwrap();
+        assert_eq!(refunds.len(), 2);
+        assert_eq!(refunds.iter().map(|r| r.amount).sum::<i64>(), 450_000);
+        let pending = refunds.iter().find(|r| r.status == RefundStatus::Pending).unwrap().clone();
+        assert_eq!(pending.attempts, 1);
+        assert!(refunds.iter().any(|r| r.status == RefundStatus::Succeeded && r.provider_reference.is_some()));
+        assert_eq!(mocks.events.events.lock().await[&event.id].status, EventStatus::Cancelled);
+        assert!(mocks.tickets.orders.lock().await.iter().all(|order| order.status == OrderStatus::Cancelled));
+        assert!(matches!(
+            service.cancel_event(event.id, organizer.id, false).await,
+            Err(ApiError::Conflict { .. })
+        ));
+
+        let received: Vec<_> = std::iter::from_fn(|| notifications.try_recv().ok()).collect();
+        let cancelled: std::collections::HashSet<Uuid> = received
+            .iter()
+            .filter(|n| matches!(n.notification, RegistrationNotification::EventCancelled { .. }))
+            .map(|n| n.user_id)
+            .collect();
+        assert_eq!(cancelled, std::collections::HashSet::from([registrant.user_id.unwrap(), buyer, other_buyer, free_buyer]));
+        assert_eq!(
+            received.iter().filter(|n| matches!(n.notification, RegistrationNotification::RefundSucceeded { .. })).count(),
+            1
+        );
+
+        // Not due until the delay has passed; then retried until the attempts run out
+        assert!(service.process_due(Utc::now()).await.unwrap().is_empty());
+        mocks.payments.set_unavailable_for(10).await;
+        for attempt in 2..=RefundApplicationService::MAX_ATTEMPTS {
+            let tried = service.process_due(Utc::now() + chrono::Duration::days(1)).await.unwrap();
+            assert_eq!((tried.len(), tried[0].attempts), (1, attempt));
+        }
+        let refunds = service.list(event.id, organizer.id, false).await.unwrap();
+        let failed = refunds.iter().find(|r| r.id == pending.id).unwrap();
+        assert_eq!((failed.status, failed.next_attempt_at), (RefundStatus::Failed, None));
+        assert!(failed.last_error.as_deref().unwrap().contains("503"));
+        assert!(service.process_due(Utc::now() + chrono::Duration::days(1)).await.unwrap().is_empty());
+        let failure = notifications.try_recv().unwrap();
+        assert_eq!(failure.user_id, failed.buyer_id);
+        assert!(matches!(failure.notification, RegistrationNotification::RefundFailed { refund_id, .. } if refund_id == failed.id));
+
+        // Organizers can try failed refunds again; the refund ID keeps Vipps from paying twice
+        mocks.payments.set_unavailable_for(0).await;
+        let retried = service.retry_failed(event.id, organizer.id, false).await.unwrap();
+        assert!(retried.iter().all(|r| r.status == RefundStatus::Succeeded));
+        let calls = mocks.payments.refunded.lock().await;
+        assert_eq!(calls.iter().filter(|r| r.id == pending.id).count(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_paid_events_need_a_payment_provider_and_refusals_fail_at_once() {
+        let (service, mocks) = create_mock_refund_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+        let paid = mocks
+            .ticketing
+            .create_ticket_type(
+                event.id,
+                organizer.id,
+                false,
+                TicketTypeInput {
+                    name: "Standard".to_string(),
+                    description: None,
+                    price: 20_000,
+                    currency: Currency::Usd,
+                    vat_rate: 0,
+                    quantity: None,
+                    is_active: true,
+                },
+            )
+            .await
+            .unwrap();
+        mocks.ticketing.purchase(event.id, paid.id, Uuid::new_v4(), 1).await.unwrap();
+
+        let without_payments = RefundApplicationService::new(
+            std::sync::Arc::new(mocks.refunds.clone()),
+            std::sync::Arc::new(mocks.tickets.clone()),
+            std::sync::Arc::new(mocks.events.clone()),
+            std::sync::Arc::new(mocks.registrations.clone()),
+            mocks.ticketing.clone(),
+            mocks.notifier.clone(),
+        );
+        assert!(matches!(
+            without_payments.cancel_event(event.id, organizer.id, false).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+        assert_eq!(mocks.events.events.lock().await[&event.id].status, EventStatus::Published);
+
+        mocks.payments.set_refuses(true).await;
+        let refunds = service.cancel_event(event.id, organizer.id, false).await.unwrap();
+        assert_eq!((refunds[0].status, refunds[0].attempts), (RefundStatus::Failed, 1));
+        assert!(refunds[0].last_error.as_deref().unwrap().contains("refused"));
+    }
+
+    // ============================================================================
+    // Catering Report Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_catering_report_for_organizers_only() {
+        let (service, mocks) = create_mock_catering_service();
+        let organizer = Uuid::new_v4();
+        let event = TestEventBuilder::new()
+            .with_slug("havbruk")
+            .with_organizer(organizer)
+            .build();
+        mocks.events.add_event(event.clone()).await;
+        for (dietary, guests) in [("Vegetarian", 1), ("vegetarisk", 0), ("Gluten free", 0)] {
+            let mut registration = TestRegistrationBuilder::new()
+                .with_event(event.id)
+                .with_guests(guests, vec![])
+                .build();
+            registration.dietary_restrictions = Some(dietary.to_string());
+            mocks.registrations.add_registration(registration).await;
+        }
+        let mut waitlisted = TestRegistrationBuilder::new().with_event(event.id).waitlisted().build();
+        waitlisted.dietary_restrictions = Some("Vegan".to_string());
+        mocks.registrations.add_registration(waitlisted).await;
+
+        assert!(matches!(
+            service.report(event.id, Uuid::new_v4(), false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.report(Uuid::new_v4(), organizer, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        let report = service.report(event.id, organizer, false).await.unwrap();
+        assert_eq!(report.confirmed_registrations, 3);
+        assert_eq!(report.total_people(), 4);
+        let response = CateringReportResponse::from(report);
+        assert_eq!(response.dietary[0].label, "Vegetarian");
+        assert_eq!(response.dietary[0].people, 3);
+        assert_eq!(response.dietary.len(), 2);
+
+        let (filename, csv) = service.report_csv(event.id, Uuid::new_v4(), true).await.unwrap();
+        assert_eq!(filename, "catering-havbruk.csv");
+        assert!(csv.starts_with("section,need,registrations,people,answers\r\nTotal,Confirmed attendance,3,4,\r\n"));
+        assert!(csv.contains("Dietary,Vegetarian,2,3,Vegetarian; vegetarisk\r\n"));
+    }
+
+    // ============================================================================
+    // Seating Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_seating_tables_and_assignments_for_organizers_only() {
+        let (service, mocks) = create_mock_seating_service();
+        let organizer = Uuid::new_v4();
+        let event = TestEventBuilder::new()
+            .with_slug("gallamiddag")
+            .with_organizer(organizer)
+            .build();
+        mocks.events.add_event(event.clone()).await;
+        let table = |name: &str, capacity| SeatingTableInput {
+            name: name.to_string(),
+            capacity,
+            accessible: false,
+        };
+
+        assert!(matches!(
+            service.create_table(event.id, Uuid::new_v4(), false, table("Table 1", 8)).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.create_table(event.id, organizer, false, table("  ", 8)).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.create_table(event.id, organizer, false, table("Table 1", 0)).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let first = service.create_table(event.id, organizer, false, table(" Table 1 ", 2)).await.unwrap();
+        let second = service.create_table(event.id, Uuid::new_v4(), true, table("Table 2", 8)).await.unwrap();
+        assert_eq!((first.name.as_str(), first.position, second.position), ("Table 1", 0, 1));
+
+        let ola = TestRegistrationBuilder::new()
+            .with_event(event.id)
+            .with_guests(2, vec!["Per".to_string()])
+            .build();
+        let cancelled = TestRegistrationBuilder::new().with_event(event.id).cancelled().build();
+        let elsewhere = TestRegistrationBuilder::new().build();
+        for registration in [ola.clone(), cancelled.clone(), elsewhere.clone()] {
+            mocks.registrations.add_registration(registration).await;
+        }
+
+        assert!(matches!(
+            service.assign(event.id, cancelled.id, Some(first.id), organizer, false).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.assign(event.id, elsewhere.id, Some(first.id), organizer, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+        assert!(matches!(
+            service.assign(event.id, ola.id, Some(Uuid::new_v4()), organizer, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        // A full table is a warning, not an error
+        let plan = service.assign(event.id, ola.id, Some(first.id), organizer, false).await.unwrap();
+        assert_eq!(plan.tables[0].seats_taken(), 3);
+        assert_eq!(plan.warnings.len(), 1);
+        assert_eq!(plan.warnings[0].message(), "Table 1 has 3 people for 2 seats");
+
+        let plan = service.assign(event.id, ola.id, Some(second.id), organizer, false).await.unwrap();
+        assert!(plan.warnings.is_empty());
+        assert_eq!(plan.tables[1].parties[0].guest_names, vec!["Per".to_string()]);
+        let response = SeatingPlanResponse::from(plan);
+        assert_eq!((response.capacity, response.seated_people, response.unassigned_people), (10, 3, 0));
+
+        let (filename, pdf) = service.chart_pdf(event.id, organizer, false).await.unwrap();
+        assert_eq!(filename, "seating-gallamiddag.pdf");
+        assert!(pdf.starts_with(b"%PDF-"));
+
+        // Removing the table sends its guests back to the unassigned list
+        service.delete_table(event.id, second.id, organizer, false).await.unwrap();
+        let plan = service.plan(event.id, organizer, false).await.unwrap();
+        assert_eq!(plan.tables.len(), 1);
+        assert_eq!(plan.unassigned.iter().map(|party| party.registration_id).collect::<Vec<_>>(), vec![ola.id]);
+
+        let plan = service.assign(event.id, ola.id, Some(first.id), organizer, false).await.unwrap();
+        assert!(plan.unassigned.is_empty());
+        let plan = service.assign(event.id, ola.id, None, organizer, false).await.unwrap();
+        assert_eq!(plan.unassigned.len(), 1);
+    }
+
+    // ============================================================================
+    // Resource Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_resources_are_managed_by_admins_and_booked_without_overlap() {
+        let (service, mocks) = create_mock_resource_service();
+        let admin = Uuid::new_v4();
+        let organizer = Uuid::new_v4();
+        let first = TestEventBuilder::new().with_organizer(organizer).build();
+        let second = TestEventBuilder::new().with_organizer(organizer).build();
+        mocks.events.add_event(first.clone()).await;
+        mocks.events.add_event(second.clone()).await;
+        let input = |name: &str| ResourceInput {
+            name: name.to_string(),
+            description: None,
+            location: Some("Brattøra".to_string()),
+            is_active: true,
+        };
+
+        assert!(matches!(
+            service.create(organizer, false, input("RIB boat")).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let boat = service.create(admin, true, input(" RIB boat ")).await.unwrap();
+        let projector = service.create(admin, true, input("Projector")).await.unwrap();
+        assert_eq!(boat.name, "RIB boat");
+
+        // Booked for the whole event by default
+        let bookings = service
+            .book(first.id, vec![boat.id, boat.id], (None, None), organizer, false)
+            .await
+            .unwrap();
+        assert_eq!(bookings.len(), 1);
+        assert_eq!((bookings[0].starts_at, bookings[0].ends_at), (first.start_date, first.end_date));
+
+        // The boat is taken, so neither resource is booked for the overlapping slot
+        let (starts_at, ends_at) = (first.start_date, first.end_date + chrono::Duration::hours(1));
+        let conflicts = service.check_conflicts(vec![projector.id, boat.id], starts_at, ends_at).await.unwrap();
+        assert!(matches!(
+            conflicts.as_slice(),
+            [ResourceConflict::AlreadyBooked { resource_id, .. }] if *resource_id == boat.id
+        ));
+        match service
+            .book(second.id, vec![projector.id, boat.id], (Some(starts_at), Some(ends_at)), organizer, false)
+            .await
+        {
+            Err(ApiError::Conflict { message }) => assert!(message.starts_with("RIB boat is already booked")),
+            other => panic!("Expected a conflict, got {:?}", other.map(|bookings| bookings.len())),
+        }
+        assert!(service.event_bookings(second.id, organizer, false).await.unwrap().is_empty());
+        assert!(matches!(
+            service.event_bookings(second.id, Uuid::new_v4(), false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+
+        // Outside its availability, the projector can't be booked either
+        let window = AvailabilityWindow {
+            starts_at: ends_at,
+            ends_at: ends_at + chrono::Duration::days(1),
+        };
+        service.set_availability(projector.id, true, vec![window]).await.unwrap();
+        assert!(matches!(
+            service.check_conflicts(vec![projector.id], starts_at, ends_at).await.unwrap().as_slice(),
+            [ResourceConflict::Unavailable { .. }]
+        ));
+        assert!(matches!(
+            service.set_availability(projector.id, true, vec![AvailabilityWindow { starts_at: ends_at, ends_at: starts_at }]).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        // Once the first event lets the boat go, the second can have it
+        assert!(matches!(
+            service.cancel_booking(bookings[0].id, Uuid::new_v4(), false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        service.cancel_booking(bookings[0].id, organizer, false).await.unwrap();
+        service
+            .book(second.id, vec![boat.id], (Some(starts_at), Some(ends_at)), organizer, false)
+            .await
+            .unwrap();
+
+        // Retired resources are hidden from organizers and can't be booked
+        service.update(boat.id, true, ResourceInput { is_active: false, ..input("RIB boat") }).await.unwrap();
+        assert_eq!(service.list(true, false).await.unwrap().len(), 1);
+        assert_eq!(service.list(true, true).await.unwrap().len(), 2);
+        assert!(matches!(
+            service.book(first.id, vec![boat.id], (None, None), organizer, false).await,
+            Err(ApiError::Conflict { .. })
+        ));
+    }
+
+    // ============================================================================
+    // Shift Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_staff_sign_up_for_shifts_until_they_are_full() {
+        let (service, mocks) = create_mock_shift_service();
+        let organizer = TestUserBuilder::new().organizer().with_name("Kari").build();
+        let staff = TestUserBuilder::new().organizer().with_name("Ola").build();
+        let participant = TestUserBuilder::new().build();
+        for user in [organizer.clone(), staff.clone(), participant.clone()] {
+            mocks.users.add_user(user).await;
+        }
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        let start = Utc::now() + chrono::Duration::days(3);
+        let shift = |role: &str, from: i64, to: i64, headcount| ShiftInput {
+            role: role.to_string(),
+            description: None,
+            starts_at: start + chrono::Duration::hours(from),
+            ends_at: start + chrono::Duration::hours(to),
+            headcount,
+        };
+
+        assert!(matches!(
+            service.create_shift(event.id, staff.id, false, shift("Bar", 0, 2, 1)).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.create_shift(event.id, organizer.id, false, shift("Bar", 2, 0, 1)).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let desk = service.create_shift(event.id, organizer.id, false, shift("Desk", 0, 3, 1)).await.unwrap();
+        let cloakroom = service.create_shift(event.id, organizer.id, false, shift("Cloakroom", 2, 5, 2)).await.unwrap();
+        let bar = service.create_shift(event.id, organizer.id, false, shift("Bar", 5, 8, 2)).await.unwrap();
+
+        assert!(matches!(
+            service.sign_up(event.id, desk.id, &participant, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        service.sign_up(event.id, desk.id, &staff, false).await.unwrap();
+        // Full, and overlapping the desk
+        assert!(matches!(
+            service.sign_up(event.id, desk.id, &organizer, false).await,
+            Err(ApiError::Conflict { .. })
+        ));
+        assert!(matches!(
+            service.sign_up(event.id, cloakroom.id, &staff, false).await,
+            Err(ApiError::Conflict { .. })
+        ));
+        let report = service.sign_up(event.id, bar.id, &staff, false).await.unwrap();
+        assert_eq!(report.shifts[2].staff[0].name, "Ola");
+
+        let coverage = service.coverage(event.id, organizer.id, false).await.unwrap();
+        assert_eq!((coverage.needed(), coverage.filled(), coverage.missing()), (5, 2, 3));
+        assert_eq!(coverage.gaps().map(|s| s.shift.id).collect::<Vec<_>>(), vec![cloakroom.id, bar.id]);
+        assert!(matches!(
+            service.coverage(event.id, staff.id, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+
+        let (filename, ics) = service.my_shifts_calendar(&staff, &Localization::default()).await.unwrap();
+        assert_eq!(filename, "shifts.ics");
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+
+        let report = service.withdraw(event.id, desk.id, staff.id).await.unwrap();
+        assert!(report.shifts[0].staff.is_empty());
+        assert!(matches!(
+            service.withdraw(event.id, desk.id, staff.id).await,
+            Err(ApiError::Domain { source: DomainError::NotFound { .. } })
+        ));
+    }
+
+    // ============================================================================
+    // Exhibitor Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_booths_are_allocated_to_one_exhibitor_each_and_listed_publicly() {
+        let (service, mocks) = create_mock_exhibitor_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let visitor = TestUserBuilder::new().build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        let booth = |number: &str, hall: &str, x: f64| BoothInput {
+            number: number.to_string(),
+            hall: Some(hall.to_string()),
+            x,
+            y: 0.0,
+            width: 3.0,
+            depth: 2.0,
+        };
+        let exhibitor = |name: &str| ExhibitorInput {
+            company_name: name.to_string(),
+            company_id: None,
+            contact_name: None,
+            contact_email: Some("expo@example.no".to_string()),
+            website_url: None,
+            description: None,
+        };
+
+        assert!(matches!(
+            service.create_booth(event.id, visitor.id, false, booth("A1", "A", 0.0)).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.create_booth(event.id, organizer.id, false, booth("A1", "A", -1.0)).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let a1 = service.create_booth(event.id, organizer.id, false, booth("A1", "A", 0.0)).await.unwrap();
+        // Touching is fine, sharing floor space isn't; other halls have their own floor
+        let a2 = service.create_booth(event.id, organizer.id, false, booth("A2", "A", 3.0)).await.unwrap();
+        assert!(matches!(
+            service.create_booth(event.id, organizer.id, false, booth("A3", "A", 4.5)).await,
+            Err(ApiError::Conflict { .. })
+        ));
+        service.create_booth(event.id, organizer.id, false, booth("B1", "B", 4.5)).await.unwrap();
+
+        assert!(matches!(
+            service
+                .create_exhibitor(event.id, organizer.id, false, ExhibitorInput {
+                    website_url: Some("salmon.example".to_string()),
+                    ..exhibitor("Salmon Feeds AS")
+                })
+                .await,
+            Err(ApiError::Validation { .. })
+        ));
+        let salmon = service.create_exhibitor(event.id, organizer.id, false, exhibitor("Salmon Feeds AS")).await.unwrap();
+        let nets = service.create_exhibitor(event.id, organizer.id, false, exhibitor("Aqua Nets")).await.unwrap();
+
+        service.allocate(event.id, salmon.id, Some(a1.id), organizer.id, false).await.unwrap();
+        assert!(matches!(
+            service.allocate(event.id, nets.id, Some(a1.id), organizer.id, false).await,
+            Err(ApiError::Conflict { .. })
+        ));
+        let nets = service.allocate(event.id, nets.id, Some(a2.id), organizer.id, false).await.unwrap();
+        assert_eq!(nets.booth_id, Some(a2.id));
+        assert!(matches!(
+            service.allocate(event.id, nets.id, Some(Uuid::new_v4()), organizer.id, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        service.delete_booth(event.id, a1.id, organizer.id, false).await.unwrap();
+        let public: Vec<PublicExhibitorResponse> = service
+            .public_exhibitors(event.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(PublicExhibitorResponse::from)
+            .collect();
+        assert_eq!(
+            public.iter().map(|e| (e.company_name.as_str(), e.booth_number.as_deref())).collect::<Vec<_>>(),
+            vec![("Aqua Nets", Some("A2")), ("Salmon Feeds AS", None)]
+        );
+        assert_eq!(public[0].hall.as_deref(), Some("A"));
+    }
+
+    // ============================================================================
+    // Lead Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_exhibitor_staff_capture_only_consenting_attendees_as_leads() {
+        let (service, mocks) = create_mock_lead_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let company_id = Uuid::new_v4();
+        let staff = TestUserBuilder::new().with_company(company_id).build();
+        let competitor = TestUserBuilder::new().with_company(Uuid::new_v4()).build();
+        let kari = TestUserBuilder::new().with_name("Kari Nordmann").build();
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        mocks.users.add_user(kari.clone()).await;
+        let exhibitor = Exhibitor {
+            company_id: Some(company_id),
+            ..Exhibitor::new(event.id, "Salmon Feeds AS")
+        };
+        mocks.exhibitors.create_exhibitor(&exhibitor).await.unwrap();
+
+        let consenting = TestRegistrationBuilder::new().with_event(event.id).with_user(kari.id).build();
+        let declining = TestRegistrationBuilder::new().with_event(event.id).build();
+        let cancelled = TestRegistrationBuilder::new().with_event(event.id).cancelled().build();
+        for registration in [&consenting, &declining, &cancelled] {
+            mocks.registrations.add_registration(registration.clone()).await;
+        }
+        let lead_sharing = EventConsent::new(event.id, ConsentKind::LeadSharing, "Exhibitors may contact me", false);
+        mocks
+            .consents
+            .record_answers(&[
+                RegistrationConsent::new(consenting.id, &lead_sharing, true, chrono::Utc::now()),
+                RegistrationConsent::new(declining.id, &lead_sharing, false, chrono::Utc::now()),
+                RegistrationConsent::new(cancelled.id, &lead_sharing, true, chrono::Utc::now()),
+            ])
+            .await
+            .unwrap();
+
+        let badge = format!("https://aqio.example/checkin/{}", consenting.id);
+        assert!(matches!(
+            service.scan(event.id, exhibitor.id, &badge, None, &competitor, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        for code in [declining.id.to_string(), cancelled.id.to_string(), "not a badge".to_string()] {
+            assert!(matches!(
+                service.scan(event.id, exhibitor.id, &code, None, &staff, false).await,
+                Err(ApiError::Validation { .. })
+            ));
+        }
+
+        let (lead, created) = service
+            .scan(event.id, exhibitor.id, &badge, Some("Wants a demo".to_string()), &staff, false)
+            .await
+            .unwrap();
+        assert!(created);
+        // Rescanning returns the first scan's lead
+        let (again, created) = service.scan(event.id, exhibitor.id, &badge, None, &staff, false).await.unwrap();
+        assert!(!created);
+        assert_eq!((again.id, again.notes.as_deref()), (lead.id, Some("Wants a demo")));
+
+        let leads = service.leads(event.id, exhibitor.id, &staff, false).await.unwrap();
+        assert_eq!(leads.len(), 1);
+        assert_eq!(leads[0].contact.name.as_deref(), Some("Kari Nordmann"));
+        let (filename, csv) = service.export_csv(event.id, exhibitor.id, &organizer, false).await.unwrap();
+        assert!(filename.starts_with(&format!("leads-{}-salmon-feeds-as-", event.slug)));
+        assert!(csv.lines().nth(1).unwrap().contains(",Kari Nordmann,"));
+
+        assert!(matches!(
+            service.analytics(event.id, staff.id, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let analytics = service.analytics(event.id, organizer.id, false).await.unwrap();
+        assert_eq!((analytics.total, analytics.by_exhibitor[0].leads), (1, 1));
+
+        service.delete_lead(event.id, exhibitor.id, lead.id, &staff, false).await.unwrap();
+        assert!(service.leads(event.id, exhibitor.id, &staff, false).await.unwrap().is_empty());
+    }
+
+    // ============================================================================
+    // Matchmaking Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_meetings_are_scheduled_into_free_slots_and_rooms() {
+        let (service, mocks) = create_mock_matchmaking_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let kari = TestUserBuilder::new().with_name("Kari").build();
+        let ola = TestUserBuilder::new().with_name("Ola").build();
+        let per = TestUserBuilder::new().with_name("Per").build();
+        let starts = Utc::now() + chrono::Duration::days(1);
+        let at = |minutes: i64| starts + chrono::Duration::minutes(minutes);
+        let event = Event {
+            end_date: at(240),
+            ..TestEventBuilder::new().with_organizer(organizer.id).starting_at(starts).build()
+        };
+        mocks.events.add_event(event.clone()).await;
+        for user in [&kari, &ola, &per] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        for user in [&kari, &ola] {
+            let registration = TestRegistrationBuilder::new().with_event(event.id).with_user(user.id).build();
+            mocks.registrations.add_registration(registration).await;
+        }
+        let profile = |interests: &[&str], from: i64, until: i64| MatchmakingProfileInput {
+            interests: interests.iter().map(|interest| interest.to_string()).collect(),
+            bio: None,
+            availability: vec![AvailabilityWindow { starts_at: at(from), ends_at: at(until) }],
+        };
+
+        assert!(matches!(
+            service.save_profile(event.id, per.id, profile(&["Nets"], 0, 60)).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.save_profile(event.id, kari.id, profile(&["Nets"], 0, 300)).await,
+            Err(ApiError::Validation { .. })
+        ));
+        service.save_profile(event.id, kari.id, profile(&["Sensors", "Feed"], 0, 120)).await.unwrap();
+        service.save_profile(event.id, ola.id, profile(&["feed"], 30, 120)).await.unwrap();
+        let matches = service.matches(event.id, kari.id).await.unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!((matches[0].name.as_str(), matches[0].shared_interests.clone()), ("Ola", vec!["Feed".to_string()]));
+
+        // The only meeting room is taken for the first slot they're both free
+        let room = Resource::new("Meeting room A", organizer.id);
+        mocks.resources.create(&room).await.unwrap();
+        let other_event = TestEventBuilder::new().build();
+        let taken = ResourceBooking::new(room.id, other_event.id, at(30), at(60), organizer.id);
+        mocks.resources.create_bookings_if_free(std::slice::from_ref(&taken)).await.unwrap();
+        assert!(matches!(
+            service.set_rooms(event.id, kari.id, false, vec![room.id]).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        service.set_rooms(event.id, organizer.id, false, vec![room.id]).await.unwrap();
+
+        assert!(matches!(
+            service.request_meeting(event.id, kari.id, kari.id, None).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let meeting = service
+            .request_meeting(event.id, kari.id, ola.id, Some("Talk feed?".to_string()))
+            .await
+            .unwrap();
+        assert_eq!((meeting.starts_at, meeting.ends_at), (at(60), at(90)));
+        assert_eq!((meeting.resource_id, meeting.status), (Some(room.id), MeetingStatus::Requested));
+        assert!(matches!(
+            service.request_meeting(event.id, ola.id, kari.id, None).await,
+            Err(ApiError::Conflict { .. })
+        ));
+
+        assert!(matches!(
+            service.answer(event.id, meeting.id, kari.id, true).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let accepted = service.answer(event.id, meeting.id, ola.id, true).await.unwrap();
+        assert_eq!(accepted.status, MeetingStatus::Accepted);
+        let listed = service.meetings(event.id, ola.id).await.unwrap();
+        assert_eq!(listed[0].requester_name, "Kari");
+        assert_eq!(listed[0].room.as_deref(), Some("Meeting room A"));
+        let (_, ics) = service.invite(event.id, meeting.id, ola.id, &Localization::default()).await.unwrap();
+        assert!(ics.contains("METHOD:REQUEST"));
+        assert!(matches!(
+            service.invite(event.id, meeting.id, per.id, &Localization::default()).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        // Cancelling frees the room again
+        let cancelled = service.cancel(event.id, meeting.id, kari.id).await.unwrap();
+        assert_eq!((cancelled.status, cancelled.booking_id), (MeetingStatus::Cancelled, None));
+        assert_eq!(mocks.resources.bookings.lock().await.clone(), vec![taken]);
+    }
+
+    // ============================================================================
+    // Web Vitals Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_web_vitals_are_validated_summarized_and_pruned() {
+        let (service, mock_repo) = create_mock_web_vitals_service();
+        let report = |release: &str, measurements: Vec<(WebVitalMetric, f64)>| WebVitalsReportInput {
+            release: release.to_string(),
+            route: Some("/events/42?token=secret".to_string()),
+            measurements,
+        };
+
+        let stored = service
+            .record(report(" 0.4.0 ", vec![(WebVitalMetric::Lcp, 2100.0), (WebVitalMetric::Cls, 0.05)]))
+            .await
+            .unwrap();
+        assert_eq!(stored, 2);
+        {
+            let samples = mock_repo.samples.lock().await;
+            assert_eq!(samples[0].release, "0.4.0");
+            assert_eq!(samples[0].route.as_deref(), Some("/events/42"));
+        }
+
+        // Nothing is stored from a report with a bad value
+        for bad in [
+            report("", vec![(WebVitalMetric::Lcp, 1.0)]),
+            report("0.4.0", vec![]),
+            report("0.4.0", vec![(WebVitalMetric::Lcp, 1.0), (WebVitalMetric::Inp, -5.0)]),
+            report("0.4.0", vec![(WebVitalMetric::Cls, f64::NAN)]),
+            report("0.4.0", vec![(WebVitalMetric::ApiLatency, 1.0); 51]),
+        ] {
+            assert!(matches!(service.record(bad).await, Err(ApiError::Validation { .. })));
+        }
+        assert_eq!(mock_repo.samples.lock().await.len(), 2);
+
+        let summary = service.summary(7).await.unwrap();
+        assert_eq!(summary.len(), 2);
+        assert_eq!((summary[0].metric, summary[0].p75), (WebVitalMetric::Lcp, 2100.0));
+        assert!(service.summary(0).await.is_err());
+        assert!(service.summary(31).await.is_err());
+
+        mock_repo.samples.lock().await.push(WebVitalSample {
+            recorded_at: Utc::now() - chrono::Duration::days(45),
+            ..WebVitalSample::new(WebVitalMetric::Lcp, 4000.0, "0.3.0")
+        });
+        assert_eq!(service.prune(Utc::now()).await.unwrap(), 1);
+        assert_eq!(mock_repo.samples.lock().await.len(), 2);
+    }
+
+    // ============================================================================
+    // Error Scenario Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_repository_failure_propagation() {
+        let (service, mock_repo) = create_mock_event_service();
+        
+        // Make repository fail
+        mock_repo.set_should_fail(true).await;
+        
+        let non_existent_id = Uuid::new_v4();
+        let result = service.get_event_by_id(non_existent_id).await;
+        
+        assert!(result.is_err());
+        // The error should be a domain error (infrastructure failure)
+        match result.unwrap_err() {
+            ApiError::Domain { .. } => {}, // Expected
+            _ => panic!("Expected domain error"),
+        }
+    }
+
+    // ============================================================================
+    // Scheduling Service Tests
+    // ============================================================================
+
+    fn blackout_input(name: &str, start_date: chrono::NaiveDate, end_date: chrono::NaiveDate) -> BlackoutInput {
+        BlackoutInput {
+            name: name.to_string(),
+            start_date,
+            end_date,
+            enforcement: RuleEnforcement::Block,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scheduling_rules_are_managed_by_company_organizers() {
+        let (service, mocks) = create_mock_scheduling_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 2).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+        let participant = TestUserBuilder::new().with_company(company.id).build();
+        let outsider = TestUserBuilder::new().organizer().build();
+        let day = |d: u32| chrono::NaiveDate::from_ymd_opt(2026, 12, d).unwrap();
+
+        // Companies without a policy have no limits
+        let policy = service.policy(company.id).await.unwrap();
+        assert!(policy.allow_weekends);
+        assert!(policy.max_events_per_day.is_none());
+        assert!(matches!(service.policy(Uuid::new_v4()).await, Err(ApiError::NotFound { .. })));
+
+        let input = SchedulingPolicyInput {
+            max_events_per_day: Some(1),
+            allow_weekends: false,
+            min_notice_days: Some(14),
+            enforcement: RuleEnforcement::Warn,
+        };
+        for user in [&participant, &outsider] {
+            assert!(matches!(
+                service.update_policy(company.id, user, false, input.clone()).await,
+                Err(ApiError::Authorization { .. })
+            ));
+        }
+        assert!(matches!(
+            service
+                .update_policy(company.id, &organizer, false, SchedulingPolicyInput { max_events_per_day: Some(0), ..input.clone() })
+                .await,
+            Err(ApiError::Validation { .. })
+        ));
+        service.update_policy(company.id, &organizer, false, input.clone()).await.unwrap();
+        service.update_policy(company.id, &outsider, true, input).await.unwrap();
+        assert_eq!(service.policy(company.id).await.unwrap().min_notice_days, Some(14));
+
+        assert!(matches!(
+            service.add_blackout(company.id, &organizer, false, blackout_input("Christmas", day(26), day(24))).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.add_blackout(company.id, &outsider, false, blackout_input("Christmas", day(24), day(26))).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let christmas = service
+            .add_blackout(company.id, &organizer, false, blackout_input(" Christmas ", day(24), day(26)))
+            .await
+            .unwrap();
+        assert_eq!(christmas.name, "Christmas");
+        assert_eq!(christmas.created_by, organizer.id);
+        let new_year = service
+            .add_blackout(company.id, &organizer, false, blackout_input("New Year's Eve", day(31), day(31)))
+            .await
+            .unwrap();
+
+        let updated = service
+            .update_blackout(company.id, christmas.id, &organizer, false, blackout_input("Christmas", day(23), day(26)))
+            .await
+            .unwrap();
+        assert_eq!(updated.start_date, day(23));
+        assert!(matches!(
+            service
+                .update_blackout(Uuid::new_v4(), christmas.id, &organizer, true, blackout_input("Christmas", day(23), day(26)))
+                .await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        service.delete_blackout(company.id, new_year.id, &organizer, false).await.unwrap();
+        let listed: Vec<Uuid> = service.blackouts(company.id).await.unwrap().iter().map(|b| b.id).collect();
+        assert_eq!(listed, vec![christmas.id]);
+    }
+
+    #[tokio::test]
+    async fn test_event_dates_are_checked_against_scheduling_rules() {
+        let (scheduling, mocks) = create_mock_scheduling_service();
+        let event_service = EventApplicationService::new(std::sync::Arc::new(mocks.events.clone()), "https://aqio.example")
+            .with_scheduling(scheduling.clone());
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 1).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+        let freelancer = TestUserBuilder::new().organizer().build();
+        mocks.users.add_user(organizer.clone()).await;
+        mocks.users.add_user(freelancer.clone()).await;
+
+        // A warning doesn't stop the event from being created
+        let mut input = SchedulingPolicyInput {
+            max_events_per_day: None,
+            allow_weekends: true,
+            min_notice_days: Some(7),
+            enforcement: RuleEnforcement::Warn,
+        };
+        scheduling.update_policy(company.id, &organizer, false, input.clone()).await.unwrap();
+        let request = create_event_request();
+        let conflicts = scheduling
+            .check(organizer.id, request.start_date, request.end_date, None)
+            .await
+            .unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].rule, ScheduleRule::MinimumNotice);
+        let event = event_service.create_event(create_event_request(), organizer.id).await.unwrap();
+
+        // The event itself doesn't count against the daily limit when it's moved
+        input.min_notice_days = None;
+        input.max_events_per_day = Some(1);
+        input.enforcement = RuleEnforcement::Block;
+        scheduling.update_policy(company.id, &organizer, false, input).await.unwrap();
+        let conflicts = scheduling
+            .check(organizer.id, event.start_date, event.end_date, Some(event.id))
+            .await
+            .unwrap();
+        assert!(conflicts.is_empty());
+        let result = event_service.create_event(create_event_request(), organizer.id).await;
+        assert!(matches!(result, Err(ApiError::Domain { source: DomainError::BusinessRuleViolation { .. } })));
+
+        // Blocked dates refuse the event; organizers without a company aren't restricted
+        let today = Utc::now().date_naive();
+        let blackout = blackout_input("Nor-Fishing", today, today + chrono::Duration::days(1));
+        scheduling.add_blackout(company.id, &organizer, false, blackout).await.unwrap();
+        let conflicts = scheduling
+            .check(organizer.id, event.start_date, event.end_date, Some(event.id))
+            .await
+            .unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].is_blocking());
+        event_service.create_event(create_event_request(), freelancer.id).await.unwrap();
+    }
+
+    // ============================================================================
+    // Sending Domain Application Service Tests
+    // ============================================================================
+
+    fn sending_domain_input(domain: &str, from_email: &str) -> SendingDomainInput {
+        SendingDomainInput {
+            domain: domain.to_string(),
+            from_email: from_email.to_string(),
+            from_name: Some("Mowi Events".to_string()),
+            dkim_selector: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sending_domain_is_verified_once_records_are_published() {
+        let (service, mocks) = create_mock_sending_domain_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 1).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+        let outsider = TestUserBuilder::new().organizer().build();
+
+        assert!(matches!(
+            service
+                .configure(company.id, &outsider, false, sending_domain_input("mowi.no", "events@mowi.no"))
+                .await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service
+                .configure(company.id, &organizer, false, sending_domain_input("mowi.no", "events@gmail.com"))
+                .await,
+            Err(ApiError::Domain { .. })
+        ));
+
+        let configured = service
+            .configure(company.id, &organizer, false, sending_domain_input(" Mowi.no. ", "Events@Mowi.no"))
+            .await
+            .unwrap();
+        assert_eq!(configured.domain, "mowi.no");
+        assert_eq!(configured.from_email, "events@mowi.no");
+        assert_eq!(configured.status, DomainVerificationStatus::Pending);
+        assert!(service.sender(company.id).await.unwrap().is_none());
+
+        // SPF and ownership alone aren't enough
+        mocks
+            .dns
+            .add(&configured.verification_record_name(), DnsRecordType::Txt, &configured.verification_record_value())
+            .await;
+        mocks.dns.add("mowi.no", DnsRecordType::Txt, "v=spf1 include:_spf.google.com include:spf.aqio.no ~all").await;
+        let checked = service.check(company.id, &organizer, false).await.unwrap();
+        assert_eq!(checked.status, DomainVerificationStatus::Failed);
+        assert!(checked.ownership_verified && checked.spf_verified && !checked.dkim_verified);
+
+        mocks
+            .dns
+            .add("aqio._domainkey.mowi.no", DnsRecordType::Cname, "aqio._domainkey.dkim.aqio.no.")
+            .await;
+        let checked = service.check(company.id, &organizer, false).await.unwrap();
+        assert_eq!(checked.status, DomainVerificationStatus::Verified);
+        assert!(checked.verified_at.is_some());
+        assert_eq!(service.sender(company.id).await.unwrap().unwrap().from_email, "events@mowi.no");
+    }
+
+    #[tokio::test]
+    async fn test_changing_sending_domain_restarts_verification() {
+        let (service, mocks) = create_mock_sending_domain_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 1).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+
+        let configured = service
+            .configure(company.id, &organizer, false, sending_domain_input("mowi.no", "events@mowi.no"))
+            .await
+            .unwrap();
+        let mut verified = configured.clone();
+        verified.status = DomainVerificationStatus::Verified;
+        verified.verified_at = Some(Utc::now());
+        mocks.sending_domains.save(&verified).await.unwrap();
+
+        // A new sender address on the same domain stays verified
+        let renamed = service
+            .configure(company.id, &organizer, false, sending_domain_input("mowi.no", "kurs@mowi.no"))
+            .await
+            .unwrap();
+        assert!(renamed.is_verified());
+        assert_eq!(renamed.verification_token, configured.verification_token);
+
+        // A new DKIM selector needs a new CNAME, so it starts over
+        let input = SendingDomainInput {
+            dkim_selector: Some("mowi2026".to_string()),
+            ..sending_domain_input("mowi.no", "kurs@mowi.no")
+        };
+        let reselected = service.configure(company.id, &organizer, false, input).await.unwrap();
+        assert_eq!(reselected.status, DomainVerificationStatus::Pending);
+        assert_eq!(reselected.created_at, configured.created_at);
+        assert!(service.sender(company.id).await.unwrap().is_none());
+
+        service.remove(company.id, &organizer, false).await.unwrap();
+        assert!(matches!(
+            service.sending_domain(company.id, &organizer, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+
+    // ============================================================================
+    // Email Delivery Application Service Tests
+    // ============================================================================
+
+    fn emailed_invitation(event_id: Uuid, email: &str) -> EventInvitation {
+        let mut invitation = create_test_invitation(event_id, Uuid::new_v4(), Uuid::new_v4());
+        invitation.invited_user_id = None;
+        invitation.invited_email = Some(email.to_string());
+        invitation.status = InvitationStatus::Sent;
+        invitation
+    }
+
+    #[tokio::test]
+    async fn test_bounce_marks_invitations_and_suppresses_address() {
+        let (delivery, invitations, mocks) = create_mock_email_delivery_service();
+        let event_id = Uuid::new_v4();
+        let bounced = emailed_invitation(event_id, "kari@example.no");
+        let other = emailed_invitation(event_id, "ola@example.no");
+        mocks.invitations.add_invitation(bounced.clone()).await;
+        mocks.invitations.add_invitation(other.clone()).await;
+
+        assert!(matches!(delivery.verify_webhook_token(None), Err(ApiError::Authentication { .. })));
+        assert!(matches!(delivery.verify_webhook_token(Some("guess")), Err(ApiError::Authentication { .. })));
+        delivery.verify_webhook_token(Some("webhook-secret")).unwrap();
+
+        let report = DeliveryReport {
+            email: "Kari@Example.no".to_string(),
+            failure: DeliveryFailure::Bounce,
+            detail: Some("550 5.1.1 user unknown".to_string()),
+        };
+        let outcome = delivery.record_reports("ses", std::slice::from_ref(&report)).await.unwrap();
+        assert_eq!(outcome, DeliveryReportOutcome { suppressed: 1, invitations_marked: 1 });
+        // A repeated notification changes nothing
+        let outcome = delivery.record_reports("ses", &[report]).await.unwrap();
+        assert_eq!(outcome, DeliveryReportOutcome::default());
+
+        let stored = invitations.get_invitation_by_id(bounced.id).await.unwrap();
+        assert_eq!(stored.delivery_failure, Some(DeliveryFailure::Bounce));
+        assert!(invitations.get_invitation_by_id(other.id).await.unwrap().delivery_failure.is_none());
+        assert_eq!(delivery.suppressions().await.unwrap()[0].email, "kari@example.no");
+
+        // No resends and no new invitations to the address
+        assert!(matches!(
+            invitations.update_invitation_status(bounced.id, InvitationStatus::Sent).await,
+            Err(ApiError::Domain { source: DomainError::BusinessRuleViolation { .. } })
+        ));
+        invitations.update_invitation_status(other.id, InvitationStatus::Sent).await.unwrap();
+        let again = emailed_invitation(Uuid::new_v4(), "kari@example.no");
+        assert!(matches!(
+            invitations.create_invitation(&again).await,
+            Err(ApiError::Validation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_lifting_suppression_allows_resending() {
+        let (delivery, invitations, mocks) = create_mock_email_delivery_service();
+        let invitation = emailed_invitation(Uuid::new_v4(), "per@example.no");
+        mocks.invitations.add_invitation(invitation.clone()).await;
+
+        let complaint = DeliveryReport {
+            email: "per@example.no".to_string(),
+            failure: DeliveryFailure::Complaint,
+            detail: None,
+        };
+        delivery.record_reports("sendgrid", &[complaint]).await.unwrap();
+        assert_eq!(
+            delivery.suppression("per@example.no").await.unwrap().map(|s| s.reason),
+            Some(DeliveryFailure::Complaint)
+        );
+
+        delivery.lift_suppression("Per@Example.no").await.unwrap();
+        assert!(delivery.suppression("per@example.no").await.unwrap().is_none());
+        assert!(invitations.get_invitation_by_id(invitation.id).await.unwrap().delivery_failure.is_none());
+        invitations.update_invitation_status(invitation.id, InvitationStatus::Sent).await.unwrap();
+        assert!(matches!(
+            delivery.lift_suppression("per@example.no").await,
+            Err(ApiError::Domain { source: DomainError::NotFound { .. } })
+        ));
+    }
+
+    // ============================================================================
+    // Publishing Application Service Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_publish_due_publishes_and_notifies() {
+        let (service, mocks) = create_mock_publishing_service();
+        let mut notifications = mocks.notifier.subscribe();
+        let now = Utc::now();
+        let organizer_id = Uuid::new_v4();
+        let invitee_id = Uuid::new_v4();
+
+        let due = TestEventBuilder::new()
+            .with_organizer(organizer_id)
+            .publishing_at(now - chrono::Duration::minutes(1))
+            .build();
+        let later = TestEventBuilder::new().publishing_at(now + chrono::Duration::hours(1)).build();
+        mocks.events.add_event(due.clone()).await;
+        mocks.events.add_event(later.clone()).await;
+        mocks.invitations.add_invitation(create_test_invitation(due.id, invitee_id, organizer_id)).await;
+
+        let published = service.publish_due(now).await.unwrap();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].id, due.id);
+        let stored = mocks.events.events.lock().await;
+        assert_eq!(stored[&due.id].status, EventStatus::Published);
+        assert_eq!(stored[&later.id].status, EventStatus::Draft);
+        drop(stored);
+        assert_eq!(*mocks.webhooks.published.lock().await, vec![due.id]);
+
+        let mut recipients = vec![notifications.recv().await.unwrap(), notifications.recv().await.unwrap()];
+        recipients.sort_by_key(|message| message.user_id != organizer_id);
+        assert_eq!(recipients[0].user_id, organizer_id);
+        assert_eq!(recipients[1].user_id, invitee_id);
+        assert!(matches!(
+            recipients[1].notification,
+            crate::domain::notifications::RegistrationNotification::EventPublished { event_id } if event_id == due.id
+        ));
+
+        // Already published events aren't published again
+        assert!(service.publish_due(now).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_event_keeps_status() {
+        let (service, mock_repo) = create_mock_event_service();
+        let organizer_id = Uuid::new_v4();
+        let event = TestEventBuilder::new().with_organizer(organizer_id).published().build();
+        mock_repo.add_event(event.clone()).await;
+
+        let updated = service.update_event(event.id, create_event_request(), organizer_id).await.unwrap();
+        assert_eq!(updated.status, EventStatus::Published);
+    }
+
+    // ============================================================================
+    // Invitation Tracking Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_tracked_link_records_click() {
+        let (service, mock_repo) = create_mock_invitation_service();
+        let invitation = create_test_invitation(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let token = invitation.invitation_token.clone().unwrap();
+        mock_repo.add_invitation(invitation.clone()).await;
+
+        let link = service.tracking().link_url(&token, "https://aqio.test/e/sjomat-2026").unwrap();
+        let link = reqwest::Url::parse(&link).unwrap();
+        assert_eq!(link.path(), format!("/api/v1/public/invitations/{}/click", token));
+        let query: std::collections::HashMap<_, _> = link.query_pairs().into_owned().collect();
+
+        let tampered = service
+            .record_click(&token, "https://evil.example/login", &query["sig"])
+            .await;
+        assert!(matches!(tampered, Err(ApiError::BadRequest { .. })));
+        assert!(mock_repo.invitations.lock().await[&invitation.id].opened_at.is_none());
+
+        let target = service.record_click(&token, &query["url"], &query["sig"]).await.unwrap();
+        assert_eq!(target, "https://aqio.test/e/sjomat-2026");
+        let stored = mock_repo.invitations.lock().await[&invitation.id].clone();
+        assert_eq!(stored.status, InvitationStatus::Opened);
+        assert!(stored.opened_at.is_some());
+        assert!(stored.clicked_at.is_some());
+
+        assert!(service.tracking().link_url(&token, "javascript:alert(1)").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_record_open_ignores_unknown_tokens() {
+        let (service, mock_repo) = create_mock_invitation_service();
+        let invitation = create_test_invitation(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        mock_repo.add_invitation(invitation.clone()).await;
+
+        service.record_open("not-a-token").await.unwrap();
+        service.record_open(invitation.invitation_token.as_deref().unwrap()).await.unwrap();
+
+        let funnel = service.funnel(invitation.event_id).await.unwrap();
+        assert_eq!(funnel.invited, 1);
+        assert_eq!(funnel.opened, 1);
+        assert_eq!(funnel.clicked, 0);
+    }
+
+    // ============================================================================
+    // Notification Digest Tests
+    // ============================================================================
+
+    fn addressed(user_id: Uuid, notification: crate::domain::notifications::RegistrationNotification) -> crate::domain::notifications::AddressedNotification {
+        crate::domain::notifications::AddressedNotification { user_id, notification }
+    }
+
+    #[tokio::test]
+    async fn test_digest_queues_non_urgent_notifications_and_sends_when_due() {
+        use crate::domain::notifications::RegistrationNotification;
+
+        let (service, mocks) = create_mock_digest_service();
+        let mut user = TestUserBuilder::new().with_email("kari@example.no").with_name("Kari").build();
+        user.locale = Some(aqio_core::Locale::Nb);
+        mocks.users.add_user(user.clone()).await;
+        let event = TestEventBuilder::new().with_title("Salmon Summit").build();
+        mocks.events.add_event(event.clone()).await;
+        let moved = RegistrationNotification::WaitlistMoved { registration_id: Uuid::new_v4(), event_id: event.id };
+
+        // Nothing is held back until the user picks a digest
+        assert!(!service.queue(&addressed(user.id, moved.clone())).await.unwrap());
+
+        let preferences = service.update_preferences(user.id, DigestFrequency::Daily).await.unwrap();
+        assert!(preferences.last_digest_at.is_some());
+        assert!(service.queue(&addressed(user.id, moved)).await.unwrap());
+        let promoted = RegistrationNotification::Promoted {
+            registration_id: Uuid::new_v4(),
+            event_id: event.id,
+            confirm_by: Utc::now() + chrono::Duration::hours(24),
+        };
+        assert!(!service.queue(&addressed(user.id, promoted)).await.unwrap());
+        assert!(service
+            .queue(&addressed(user.id, RegistrationNotification::EventPublished { event_id: event.id }))
+            .await
+            .unwrap());
+
+        // The first digest goes out a day after opting in
+        assert_eq!(service.send_due(Utc::now()).await.unwrap(), 0);
+        let tomorrow = Utc::now() + chrono::Duration::days(1);
+        assert_eq!(service.send_due(tomorrow).await.unwrap(), 1);
+
+        let sent = mocks.mailer.sent.lock().await.clone();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "kari@example.no");
+        // Rendered in the user's own language
+        assert_eq!(sent[0].subject, "Ditt daglige Aqio-sammendrag: 2 oppdateringer");
+        assert!(sent[0].text_body.contains("Salmon Summit\n- Du har rykket opp på ventelisten ("));
+        assert!(sent[0].text_body.contains("- Arrangementet ble publisert ("));
+        assert!(mocks.digests.items.lock().await.is_empty());
+        assert_eq!(service.preferences(user.id).await.unwrap().last_digest_at, Some(tomorrow));
+
+        // Nothing new, so the next day passes without an email
+        assert_eq!(service.send_due(tomorrow + chrono::Duration::days(1)).await.unwrap(), 0);
+        assert_eq!(mocks.mailer.sent.lock().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_digest_skips_suppressed_addresses_and_immediate_drops_pending() {
+        use crate::domain::notifications::RegistrationNotification;
+
+        let (service, mocks) = create_mock_digest_service();
+        let user = TestUserBuilder::new().with_email("bounced@example.no").build();
+        mocks.users.add_user(user.clone()).await;
+        let event_id = Uuid::new_v4();
+        let published = RegistrationNotification::EventPublished { event_id };
+
+        service.update_preferences(user.id, DigestFrequency::Weekly).await.unwrap();
+        service.queue(&addressed(user.id, published.clone())).await.unwrap();
+        // Unknown events still get a line, just without a title
+        assert_eq!(mocks.digests.items.lock().await[0].event_title, None);
+
+        mocks
+            .suppressions
+            .save(&EmailSuppression::new("bounced@example.no", DeliveryFailure::Bounce, "ses", None))
+            .await
+            .unwrap();
+        assert_eq!(service.send_due(Utc::now() + chrono::Duration::weeks(1)).await.unwrap(), 0);
+        assert!(mocks.mailer.sent.lock().await.is_empty());
+        assert!(mocks.digests.items.lock().await.is_empty());
+
+        service.queue(&addressed(user.id, published)).await.unwrap();
+        let preferences = service.update_preferences(user.id, DigestFrequency::Immediate).await.unwrap();
+        assert_eq!(preferences.digest_frequency, DigestFrequency::Immediate);
+        assert!(mocks.digests.items.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_organization_invitations_are_sent_by_organizers_and_replace_earlier_ones() {
+        let (service, mocks) = create_mock_organization_invitation_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 1).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).with_name("Ola").build();
+        let member = TestUserBuilder::new().with_company(company.id).with_email("member@mowi.no").build();
+        mocks.users.add_user(member.clone()).await;
+
+        assert!(matches!(
+            service.invite(company.id, &member, false, "kari@mowi.no", None, None).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.invite(company.id, &organizer, false, "kari@mowi.no", Some(UserRole::Admin), None).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.invite(company.id, &organizer, false, "member@mowi.no", None, None).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+
+        let first = service.invite(company.id, &organizer, false, " Kari@Mowi.no ", None, None).await.unwrap();
+        assert_eq!(first.invitation.email, "kari@mowi.no");
+        assert!(matches!(first.invitation.role, UserRole::Participant));
+        assert_eq!(first.invitation.invited_by, Some(organizer.id));
+        let sender = EmailSender { email: "events@mowi.no".to_string(), name: Some("Mowi".to_string()) };
+        let second = service
+            .invite(company.id, &organizer, false, "kari@mowi.no", Some(UserRole::Organizer), Some(sender.clone()))
+            .await
+            .unwrap();
+
+        let sent = mocks.mailer.sent.lock().await.clone();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].from, None);
+        assert_eq!(sent[1].from, Some(sender));
+        assert_eq!(sent[1].subject, "Ola invited you to join Mowi on Aqio");
+        assert!(sent[1].text_body.contains(&format!("https://aqio.test/join/{}", second.invitation.token)));
+
+        // The newer invitation replaces the first one
+        let pending = service.list_pending(Some(company.id)).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].invitation.id, second.invitation.id);
+        assert_eq!(pending[0].company_name, "Mowi");
+        assert_eq!(
+            service.preview(&first.invitation.token).await.unwrap().invitation.status,
+            OrganizationInvitationStatus::Revoked
+        );
+
+        service.revoke(second.invitation.id).await.unwrap();
+        assert!(service.list_pending(None).await.unwrap().is_empty());
+        assert!(matches!(service.revoke(second.invitation.id).await, Err(ApiError::BadRequest { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_accepting_organization_invitation_joins_the_company_on_first_login() {
+        let (service, mocks) = create_mock_organization_invitation_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 1).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+        let invited = service
+            .invite(company.id, &organizer, false, "kari@mowi.no", Some(UserRole::Organizer), None)
+            .await
+            .unwrap()
+            .invitation;
+
+        assert!(matches!(
+            service.accept(&invited.token, "kc-ola", "ola@mowi.no", "Ola").await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.accept("unknown", "kc-kari", "kari@mowi.no", "Kari").await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        // First login: the account is created in the company with the invited role
+        let user = service.accept(&invited.token, "kc-kari", "KARI@mowi.no", "Kari Nordmann").await.unwrap();
+        assert_eq!(user.keycloak_id, "kc-kari");
+        assert_eq!(user.name, "Kari Nordmann");
+        assert_eq!(user.company_id, Some(company.id));
+        assert!(matches!(user.role, UserRole::Organizer));
+        assert!(mocks.users.users.lock().await.contains_key(&user.id));
+        let accepted = mocks.invitations.invitations.lock().await[&invited.id].clone();
+        assert_eq!(accepted.status, OrganizationInvitationStatus::Accepted);
+        assert_eq!(accepted.accepted_by, Some(user.id));
+
+        // A link only works once
+        assert!(matches!(
+            service.accept(&invited.token, "kc-kari", "kari@mowi.no", "Kari").await,
+            Err(ApiError::BadRequest { .. })
+        ));
+
+        // Existing users keep a higher role, and can't be pulled out of another company
+        let admin = TestUserBuilder::new().admin().with_email("admin@mowi.no").build();
+        mocks.users.add_user(admin.clone()).await;
+        let invitation = service.invite(company.id, &organizer, false, "admin@mowi.no", None, None).await.unwrap().invitation;
+        let joined = service.accept(&invitation.token, &admin.keycloak_id, &admin.email, &admin.name).await.unwrap();
+        assert_eq!(joined.id, admin.id);
+        assert!(matches!(joined.role, UserRole::Admin));
+
+        let elsewhere = TestUserBuilder::new().with_company(Uuid::new_v4()).with_email("per@mowi.no").build();
+        mocks.users.add_user(elsewhere.clone()).await;
+        let invitation = service.invite(company.id, &organizer, false, "per@mowi.no", None, None).await.unwrap().invitation;
+        assert!(matches!(
+            service.accept(&invitation.token, &elsewhere.keycloak_id, &elsewhere.email, &elsewhere.name).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_ownership_transfer_waits_for_the_new_owner_to_accept() {
+        let (service, mocks) = create_mock_ownership_service();
+        let owner = TestUserBuilder::new().organizer().with_name("Ola").with_email("ola@mowi.no").build();
+        let new_owner = TestUserBuilder::new().organizer().with_name("Kari").with_email("kari@mowi.no").build();
+        let participant = TestUserBuilder::new().build();
+        for user in [&owner, &new_owner, &participant] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let mut event = TestEventBuilder::new().with_organizer(owner.id).with_title("Sjømatdagen").build();
+        event.co_organizers = vec![new_owner.id];
+        mocks.events.add_event(event.clone()).await;
+        let mut notifications = mocks.notifier.subscribe();
+
+        assert!(matches!(
+            service.request(event.id, &new_owner, false, new_owner.id, None).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.request(event.id, &owner, false, participant.id, None).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+        assert!(matches!(
+            service.request(event.id, &owner, false, owner.id, None).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+
+        let requested = service
+            .request(event.id, &owner, false, new_owner.id, Some(" Over to you ".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(requested.transfer.message.as_deref(), Some("Over to you"));
+        assert_eq!(requested.event_title, "Sjømatdagen");
+        // Nothing changes before the new owner accepts
+        assert_eq!(mocks.events.events.lock().await[&event.id].organizer_id, owner.id);
+        let notification = notifications.try_recv().unwrap();
+        assert_eq!(notification.user_id, new_owner.id);
+        assert!(notification.notification.is_urgent());
+        assert_eq!(service.incoming(new_owner.id).await.unwrap().len(), 1);
+
+        assert!(matches!(
+            service.accept(requested.transfer.id, owner.id).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let completed = service.accept(requested.transfer.id, new_owner.id).await.unwrap();
+        assert_eq!(completed.previous.organizer_id, owner.id);
+        assert_eq!(completed.transfer.status, OwnershipTransferStatus::Accepted);
+        let stored = mocks.events.events.lock().await[&event.id].clone();
+        assert_eq!(stored.organizer_id, new_owner.id);
+        assert_eq!(stored.co_organizers, vec![owner.id]);
+
+        // Both sides hear about it
+        let notified: Vec<Uuid> = std::iter::from_fn(|| notifications.try_recv().ok()).map(|n| n.user_id).collect();
+        assert_eq!(notified, vec![new_owner.id, owner.id]);
+        let sent = mocks.mailer.sent.lock().await.clone();
+        let subjects: Vec<(&str, &str)> = sent.iter().map(|m| (m.to.as_str(), m.subject.as_str())).collect();
+        assert_eq!(
+            subjects,
+            vec![
+                ("kari@mowi.no", "Ola wants to hand Sjømatdagen over to you"),
+                ("ola@mowi.no", "Sjømatdagen is now organized by Kari"),
+                ("kari@mowi.no", "You are now the organizer of Sjømatdagen"),
+            ]
+        );
+
+        assert!(matches!(
+            service.accept(requested.transfer.id, new_owner.id).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+        assert!(service.incoming(new_owner.id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ownership_transfer_can_be_replaced_cancelled_and_declined() {
+        let (service, mocks) = create_mock_ownership_service();
+        let owner = TestUserBuilder::new().organizer().with_name("Ola").build();
+        let kari = TestUserBuilder::new().organizer().with_name("Kari").build();
+        let admin = TestUserBuilder::new().admin().with_name("Per").build();
+        for user in [&owner, &kari, &admin] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let event = TestEventBuilder::new().with_organizer(owner.id).build();
+        mocks.events.add_event(event.clone()).await;
+
+        // A newer request replaces the pending one
+        let first = service.request(event.id, &owner, false, kari.id, None).await.unwrap();
+        let second = service.request(event.id, &admin, true, admin.id, None).await.unwrap();
+        assert_eq!(second.transfer.requested_by, admin.id);
+        assert_eq!(second.transfer.from_user_id, owner.id);
+        assert_eq!(
+            mocks.transfers.transfers.lock().await[&first.transfer.id].status,
+            OwnershipTransferStatus::Cancelled
+        );
+        assert!(matches!(
+            service.decline(first.transfer.id, kari.id).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+        assert_eq!(service.pending(event.id, owner.id, false).await.unwrap().transfer.id, second.transfer.id);
+        assert!(matches!(service.pending(event.id, kari.id, false).await, Err(ApiError::Authorization { .. })));
+
+        let cancelled = service.cancel(event.id, owner.id, false).await.unwrap();
+        assert_eq!(cancelled.status, OwnershipTransferStatus::Cancelled);
+        assert!(matches!(service.pending(event.id, owner.id, false).await, Err(ApiError::NotFound { .. })));
+
+        let third = service.request(event.id, &owner, false, kari.id, None).await.unwrap();
+        let mut notifications = mocks.notifier.subscribe();
+        let declined = service.decline(third.transfer.id, kari.id).await.unwrap();
+        assert_eq!(declined.transfer.status, OwnershipTransferStatus::Declined);
+        assert!(declined.transfer.responded_at.is_some());
+        assert_eq!(mocks.events.events.lock().await[&event.id].organizer_id, owner.id);
+        assert_eq!(notifications.try_recv().unwrap().user_id, owner.id);
+        let sent = mocks.mailer.sent.lock().await.clone();
+        assert!(sent.last().unwrap().subject.starts_with("Kari declined to take over"));
+    }
+
+    #[tokio::test]
+    async fn test_organization_roles_grant_assignable_permissions_to_members() {
+        let (service, mocks) = create_mock_permission_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 2).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+        let volunteer = TestUserBuilder::new().with_company(company.id).build();
+        let outsider = TestUserBuilder::new().build();
+        for user in [&organizer, &volunteer, &outsider] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let input = |permissions: Vec<Permission>| OrganizationRoleInput {
+            name: " Door staff ".to_string(),
+            description: None,
+            permissions,
+        };
+
+        assert!(matches!(
+            service.create_role(company.id, &volunteer, false, input(vec![Permission::RegistrationCheckIn])).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.create_role(company.id, &organizer, false, input(vec![Permission::UserManage])).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let role = service
+            .create_role(company.id, &organizer, false, input(vec![Permission::RegistrationCheckIn]))
+            .await
+            .unwrap();
+        assert_eq!(role.name, "Door staff");
+
+        assert!(matches!(
+            service.add_member(company.id, role.id, outsider.id, &organizer, false).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let before = service.checker(Some(&volunteer), &[]).await.unwrap();
+        assert!(!before.has_any(Permission::RegistrationCheckIn));
+
+        service.add_member(company.id, role.id, volunteer.id, &organizer, false).await.unwrap();
+        service.add_member(company.id, role.id, volunteer.id, &organizer, false).await.unwrap();
+        let members = service.members(company.id, role.id, &organizer, false).await.unwrap();
+        assert_eq!(members.len(), 1);
+
+        // Granted in the volunteer's own organization only
+        let after = service.checker(Some(&volunteer), &[]).await.unwrap();
+        assert!(!after.has(Permission::RegistrationCheckIn));
+        assert!(after.has_in(Permission::RegistrationCheckIn, Some(company.id)));
+        assert!(!after.has_in(Permission::RegistrationCheckIn, Some(Uuid::new_v4())));
+
+        service.remove_member(company.id, role.id, volunteer.id, &organizer, false).await.unwrap();
+        assert!(matches!(
+            service.remove_member(company.id, role.id, volunteer.id, &organizer, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_event_permissions_follow_the_organizers_organization() {
+        let (service, mocks) = create_mock_permission_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 2).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+        let volunteer = TestUserBuilder::new().with_company(company.id).build();
+        let elsewhere = TestUserBuilder::new().organizer().build();
+        for user in [&organizer, &volunteer, &elsewhere] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let own_event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        let other_event = TestEventBuilder::new().with_organizer(elsewhere.id).build();
+        mocks.events.add_event(own_event.clone()).await;
+        mocks.events.add_event(other_event.clone()).await;
+
+        let role = service
+            .create_role(
+                company.id,
+                &organizer,
+                false,
+                OrganizationRoleInput {
+                    name: "Approvers".to_string(),
+                    description: None,
+                    permissions: vec![Permission::RegistrationApprove],
+                },
+            )
+            .await
+            .unwrap();
+        service.add_member(company.id, role.id, volunteer.id, &organizer, false).await.unwrap();
+
+        let volunteer = service.checker(Some(&volunteer), &[]).await.unwrap();
+        let allows = |event_id| service.allows_for_event(&volunteer, Permission::RegistrationApprove, event_id);
+        assert!(allows(own_event.id).await.unwrap());
+        assert!(!allows(other_event.id).await.unwrap());
+
+        // Platform roles reach every event
+        let admin = service.checker(None, &[UserRole::Admin]).await.unwrap();
+        assert!(service
+            .allows_for_event(&admin, Permission::RegistrationApprove, other_event.id)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_organizers_grant_event_access_for_a_limited_time() {
+        let (service, mocks) = create_mock_permission_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let helper = TestUserBuilder::new().build();
+        let stranger = TestUserBuilder::new().build();
+        for user in [&organizer, &helper, &stranger] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let event = TestEventBuilder::new().with_organizer(organizer.id).build();
+        mocks.events.add_event(event.clone()).await;
+        let in_hours = |hours| Utc::now() + chrono::Duration::hours(hours);
+        let input = |permissions: Vec<Permission>, expires_at| AccessGrantInput {
+            user_id: helper.id,
+            permissions,
+            starts_at: None,
+            expires_at,
+        };
+
+        assert!(matches!(
+            service.grant_access(event.id, &stranger, false, input(vec![Permission::RegistrationCheckIn], in_hours(8))).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.grant_access(event.id, &organizer, false, input(vec![Permission::EventCreate], in_hours(8))).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.grant_access(event.id, &organizer, false, input(vec![Permission::RegistrationCheckIn], in_hours(-1))).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.grant_access(event.id, &organizer, false, input(vec![Permission::RegistrationCheckIn], in_hours(40 * 24))).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        let grant = service
+            .grant_access(event.id, &organizer, false, input(vec![Permission::RegistrationCheckIn], in_hours(8)))
+            .await
+            .unwrap();
+        assert_eq!(grant.granted_by, organizer.id);
+
+        let checker = service.checker(Some(&helper), &[]).await.unwrap();
+        assert!(!checker.has(Permission::RegistrationCheckIn));
+        assert!(service.allows_for_event(&checker, Permission::RegistrationCheckIn, event.id).await.unwrap());
+        assert!(!service.allows_for_event(&checker, Permission::RegistrationApprove, event.id).await.unwrap());
+        assert_eq!(service.grants(event.id, &organizer, false).await.unwrap().len(), 1);
+
+        // Revoking takes effect right away, and only once
+        service.revoke_grant(event.id, grant.id, &organizer, false).await.unwrap();
+        let checker = service.checker(Some(&helper), &[]).await.unwrap();
+        assert!(!service.allows_for_event(&checker, Permission::RegistrationCheckIn, event.id).await.unwrap());
+        assert!(matches!(
+            service.revoke_grant(event.id, grant.id, &organizer, false).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_submitted_event_is_published_once_a_moderator_approves() {
+        let (service, mocks) = create_mock_submission_service();
+        let submitter = TestUserBuilder::new().with_name("Kari").build();
+        let moderator = TestUserBuilder::new().organizer().build();
+        let other_participant = TestUserBuilder::new().build();
+        for user in [&submitter, &moderator, &other_participant] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        assert!(service.requires_review(&role_permissions(&submitter, false)));
+        assert!(!service.requires_review(&role_permissions(&submitter, true)));
+        assert!(!service.requires_review(&role_permissions(&moderator, false)));
+
+        let submitted = service.submit(create_event_request(), &submitter).await.unwrap();
+        assert!(matches!(submitted.event.status, EventStatus::Draft));
+        assert_eq!(submitted.event.organizer_id, submitter.id);
+        assert!(submitted.submission.is_pending());
+
+        assert!(matches!(service.queue(&other_participant, false).await, Err(ApiError::Authorization { .. })));
+        let queue = service.queue(&moderator, false).await.unwrap();
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue[0].submitter_name, "Kari");
+        assert!(matches!(
+            service.approve(submitted.submission.id, &other_participant, false, None).await,
+            Err(ApiError::Authorization { .. })
+        ));
+
+        let mut notifications = mocks.notifier.subscribe();
+        let approved = service
+            .approve(submitted.submission.id, &moderator, false, Some(" Looks good ".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(approved.submission.status, EventSubmissionStatus::Approved);
+        assert_eq!(approved.submission.review_comment.as_deref(), Some("Looks good"));
+        assert!(matches!(
+            mocks.events.events.lock().await[&submitted.event.id].status,
+            EventStatus::Published
+        ));
+        let notification = notifications.try_recv().unwrap();
+        assert_eq!(notification.user_id, submitter.id);
+        assert_eq!(
+            notification.notification,
+            RegistrationNotification::EventSubmissionReviewed {
+                submission_id: submitted.submission.id,
+                event_id: submitted.event.id,
+                approved: true,
+            }
+        );
+
+        assert!(service.queue(&moderator, false).await.unwrap().is_empty());
+        assert!(matches!(
+            service.reject(submitted.submission.id, &moderator, false, Some("Too late".to_string())).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_submission_needs_a_comment_and_can_be_resubmitted() {
+        let (service, mocks) = create_mock_submission_service();
+        let submitter = TestUserBuilder::new().organizer().build();
+        let moderator = TestUserBuilder::new().organizer().build();
+        for user in [&submitter, &moderator] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let submitted = service.submit(create_event_request(), &submitter).await.unwrap();
+
+        // Moderators don't review their own events
+        assert!(matches!(
+            service.approve(submitted.submission.id, &submitter, false, None).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.reject(submitted.submission.id, &moderator, false, Some("  ".to_string())).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.resubmit(submitted.submission.id, &submitter).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+
+        let rejected = service
+            .reject(submitted.submission.id, &moderator, false, Some("Add a venue".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(rejected.submission.reviewed_by, Some(moderator.id));
+        assert!(matches!(
+            mocks.events.events.lock().await[&submitted.event.id].status,
+            EventStatus::Draft
+        ));
+
+        assert!(matches!(
+            service.resubmit(submitted.submission.id, &moderator).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let resubmitted = service.resubmit(submitted.submission.id, &submitter).await.unwrap();
+        assert_eq!(resubmitted.submission.event_id, submitted.event.id);
+        assert!(matches!(
+            service.resubmit(submitted.submission.id, &submitter).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+
+        let mine = service.mine(submitter.id).await.unwrap();
+        assert_eq!(mine.len(), 2);
+        assert_eq!(service.queue(&moderator, false).await.unwrap()[0].submission.id, resubmitted.submission.id);
+    }
+
+    #[tokio::test]
+    async fn test_reported_comment_is_hidden_at_the_threshold_until_dismissed() {
+        let (service, mocks) = create_mock_moderation_service();
+        let comments = EventCommentApplicationService::new(
+            std::sync::Arc::new(mocks.comments.clone()),
+            std::sync::Arc::new(mocks.events.clone()),
+        )
+        .with_moderation(std::sync::Arc::new(mocks.reports.clone()));
+        let organizer = TestUserBuilder::new().organizer().build();
+        let spammer = TestUserBuilder::new().build();
+        let first = TestUserBuilder::new().build();
+        let second = TestUserBuilder::new().build();
+        let admin = TestUserBuilder::new().admin().build();
+        for user in [&organizer, &spammer, &first, &second, &admin] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+        let spam = comments.create_comment(event.id, &spammer, None, "Cheap salmon").await.unwrap();
+
+        assert!(matches!(
+            service.report_comment(Uuid::new_v4(), spam.id, &first, ReportReason::Spam, None).await,
+            Err(ApiError::NotFound { .. })
+        ));
+        assert!(matches!(
+            service.report_comment(event.id, spam.id, &spammer, ReportReason::Spam, None).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+        service.report_comment(event.id, spam.id, &first, ReportReason::Spam, None).await.unwrap();
+        assert!(matches!(
+            service.report_comment(event.id, spam.id, &first, ReportReason::Other, None).await,
+            Err(ApiError::Domain { .. })
+        ));
+        assert!(!comments.list_comments(event.id, first.id, false).await.unwrap().is_empty());
+
+        // The second reporter reaches the threshold of two
+        service
+            .report_comment(event.id, spam.id, &second, ReportReason::Spam, Some("Ads".to_string()))
+            .await
+            .unwrap();
+        assert!(comments.list_comments(event.id, first.id, false).await.unwrap().is_empty());
+
+        let dashboard = service.reported_content().await.unwrap();
+        assert_eq!(dashboard.len(), 1);
+        assert_eq!((dashboard[0].target, dashboard[0].target_id), (ReportTarget::Comment, spam.id));
+        assert_eq!(dashboard[0].author_id, Some(spammer.id));
+        assert!(dashboard[0].hidden);
+        assert_eq!(dashboard[0].reports.len(), 2);
+
+        assert!(matches!(
+            service.act(ReportTarget::Profile, spammer.id, ModerationAction::Hide, &admin, None).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+        let outcome = service
+            .act(ReportTarget::Comment, spam.id, ModerationAction::Dismiss, &admin, None)
+            .await
+            .unwrap();
+        assert_eq!(outcome.resolved_reports, 2);
+        assert!(!outcome.hidden);
+        assert!(service.reported_content().await.unwrap().is_empty());
+        let thread = comments.list_comments(event.id, first.id, false).await.unwrap();
+        assert_eq!(thread.len(), 1);
+        assert!(!thread[0].is_hidden);
+        assert!(mocks.mailer.sent.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ban_deactivates_the_author_and_hides_the_event() {
+        let (service, mocks) = create_mock_moderation_service();
+        let organizer = TestUserBuilder::new().organizer().with_email("spam@example.no").build();
+        let reporter = TestUserBuilder::new().build();
+        let admin = TestUserBuilder::new().admin().build();
+        for user in [&organizer, &reporter, &admin] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+
+        service.report(ReportTarget::Event, event.id, &reporter, ReportReason::Spam, None).await.unwrap();
+        service.report(ReportTarget::Profile, organizer.id, &reporter, ReportReason::Spam, None).await.unwrap();
+        assert!(matches!(
+            service.report(ReportTarget::Profile, Uuid::new_v4(), &reporter, ReportReason::Spam, None).await,
+            Err(ApiError::NotFound { .. })
+        ));
+        // One report stays below the threshold
+        assert!(mocks.reports.hidden.lock().await.is_empty());
+
+        assert!(matches!(
+            service.act(ReportTarget::Profile, admin.id, ModerationAction::Ban, &admin, None).await,
+            Err(ApiError::BadRequest { .. })
+        ));
+        let outcome = service
+            .act(ReportTarget::Event, event.id, ModerationAction::Ban, &admin, Some("Advertising".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(outcome.resolved_reports, 1);
+        assert!(outcome.hidden);
+        let (before, after) = outcome.banned.unwrap();
+        assert!(before.is_active && !after.is_active);
+        assert!(!mocks.users.users.lock().await[&organizer.id].is_active);
+        assert_eq!(mocks.reports.hidden.lock().await[&(ReportTarget::Event, event.id)], Some(admin.id));
+
+        let sent = mocks.mailer.sent.lock().await;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "spam@example.no");
+        assert!(sent[0].text_body.contains("Advertising"));
+        drop(sent);
+
+        // The profile's report is still open for its own decision
+        let dashboard = service.reported_content().await.unwrap();
+        assert_eq!(dashboard.len(), 1);
+        assert_eq!(dashboard[0].target, ReportTarget::Profile);
+    }
+
+    #[tokio::test]
+    async fn test_consent_answers_are_checked_recorded_and_reported() {
+        let (service, mocks) = create_mock_consent_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let attendee = TestUserBuilder::new().with_email("kari@example.no").build();
+        let outsider = TestUserBuilder::new().build();
+        for user in [&organizer, &attendee, &outsider] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+
+        assert!(matches!(
+            service
+                .configure(event.id, ConsentKind::Photo, outsider.id, false, "Photos".to_string(), true)
+                .await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let (_, photo) = service
+            .configure(event.id, ConsentKind::Photo, organizer.id, false, "Photos may be taken".to_string(), true)
+            .await
+            .unwrap();
+        service
+            .configure(event.id, ConsentKind::Marketing, organizer.id, false, "Send me news".to_string(), false)
+            .await
+            .unwrap();
+
+        let accept = |kind, version| ConsentAnswer { kind, accepted: true, version };
+        assert!(matches!(
+            service.check_answers(event.id, &[accept(ConsentKind::Marketing, None)]).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.check_answers(event.id, &[accept(ConsentKind::DataProcessing, None)]).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let answers = service
+            .check_answers(event.id, &[accept(ConsentKind::Photo, Some(photo.version))])
+            .await
+            .unwrap();
+        // Marketing was left out, so it counts as declined
+        assert_eq!(answers.iter().map(|(c, accepted)| (c.kind, *accepted)).collect::<Vec<_>>(), vec![
+            (ConsentKind::Photo, true),
+            (ConsentKind::Marketing, false)
+        ]);
+        let registration = TestRegistrationBuilder::new().with_event(event.id).with_user(attendee.id).build();
+        mocks.registrations.add_registration(registration.clone()).await;
+        service.record(registration.id, &answers).await.unwrap();
+
+        // Changing the text is a new version; a form loaded before is refused
+        let (previous, revised) = service
+            .configure(event.id, ConsentKind::Photo, organizer.id, false, "Photos and video".to_string(), true)
+            .await
+            .unwrap();
+        assert_eq!((previous.unwrap().version, revised.version), (1, 2));
+        assert!(matches!(
+            service.check_answers(event.id, &[accept(ConsentKind::Photo, Some(1))]).await,
+            Err(ApiError::Conflict { .. })
+        ));
+
+        let report = service.report(event.id, organizer.id, false).await.unwrap();
+        let photo_totals = &report.totals[0];
+        assert_eq!((photo_totals.accepted, photo_totals.accepted_earlier_version), (0, 1));
+        assert_eq!(report.totals[1].declined, 1);
+        assert_eq!(report.rows[0].registrant.email.as_deref(), Some("kari@example.no"));
+        assert_eq!(report.rows[0].answers[0].text, "Photos may be taken");
+
+        // Removing the checkbox keeps the answers on record
+        service.remove(event.id, ConsentKind::Photo, organizer.id, false).await.unwrap();
+        let (filename, csv) = service.report_csv(event.id, organizer.id, false).await.unwrap();
+        assert_eq!(filename, format!("consents-{}.csv", event.slug));
+        assert!(csv.contains(",Photo,1,yes,"));
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_reaches_its_audience_once_per_address() {
+        let (service, mocks) = create_mock_broadcast_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let outsider = TestUserBuilder::new().build();
+        let kari = TestUserBuilder::new().with_email("kari@example.no").build();
+        let ola = TestUserBuilder::new().with_email("ola@example.no").build();
+        for user in [&organizer, &outsider, &kari, &ola] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let event = TestEventBuilder::new().with_organizer(organizer.id).with_title("Sjømatdagen").published().build();
+        mocks.events.add_event(event.clone()).await;
+
+        let guest = |email: &str, status| {
+            let mut registration = TestRegistrationBuilder::new()
+                .with_event(event.id)
+                .with_email(email)
+                .with_status(status)
+                .build();
+            registration.user_id = None;
+            registration
+        };
+        for registration in [
+            TestRegistrationBuilder::new().with_event(event.id).with_user(kari.id).build(),
+            TestRegistrationBuilder::new().with_event(event.id).with_user(ola.id).attended().build(),
+            TestRegistrationBuilder::new().with_event(event.id).cancelled().build(),
+            // Kari also registered without signing in
+            guest(" KARI@example.no", RegistrationStatus::Registered),
+            guest("bounced@example.no", RegistrationStatus::Registered),
+            guest("waiting@example.no", RegistrationStatus::Waitlisted),
+        ] {
+            mocks.registrations.add_registration(registration).await;
+        }
+        mocks
+            .suppressions
+            .save(&EmailSuppression::new("bounced@example.no", DeliveryFailure::Bounce, "ses", None))
+            .await
+            .unwrap();
+
+        let input = |audience, scheduled_for| BroadcastInput {
+            audience,
+            subject: "Parking".to_string(),
+            message: "Use gate B".to_string(),
+            scheduled_for,
+        };
+        assert!(matches!(
+            service.send(event.id, &outsider, false, input(BroadcastAudience::All, None)).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let blank = BroadcastInput { subject: " ".to_string(), ..input(BroadcastAudience::All, None) };
+        assert!(matches!(
+            service.send(event.id, &organizer, false, blank).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        let preview = service.preview(event.id, &organizer, false, input(BroadcastAudience::All, None)).await.unwrap();
+        assert_eq!((preview.recipients, preview.suppressed), (4, 1));
+        assert_eq!(preview.email.to, organizer.email);
+        assert_eq!(preview.email.subject, "[Sjømatdagen] Parking");
+        let checked_in = service
+            .preview(event.id, &organizer, false, input(BroadcastAudience::CheckedIn, None))
+            .await
+            .unwrap();
+        assert_eq!(checked_in.recipients, 1);
+        assert!(mocks.mailer.sent.lock().await.is_empty());
+
+        let mut notifications = mocks.notifier.subscribe();
+        let sent = service
+            .send(event.id, &organizer, false, input(BroadcastAudience::Confirmed, None))
+            .await
+            .unwrap();
+        assert_eq!(sent.status, BroadcastStatus::Sent);
+        assert_eq!(sent.delivery, BroadcastDelivery { recipients: 3, delivered: 2, suppressed: 1, failed: 0 });
+        let recipients: Vec<String> = mocks.mailer.sent.lock().await.iter().map(|email| email.to.clone()).collect();
+        assert_eq!(recipients, vec!["kari@example.no", "ola@example.no"]);
+        let notified: Vec<Uuid> = std::iter::from_fn(|| notifications.try_recv().ok())
+            .inspect(|notification| assert!(notification.notification.is_emailed()))
+            .map(|notification| notification.user_id)
+            .collect();
+        assert_eq!(notified, vec![kari.id, ola.id]);
+
+        // Scheduled broadcasts wait for the scheduler
+        let now = Utc::now();
+        assert!(matches!(
+            service
+                .send(event.id, &organizer, false, input(BroadcastAudience::All, Some(now - chrono::Duration::minutes(1))))
+                .await,
+            Err(ApiError::Validation { .. })
+        ));
+        let scheduled = service
+            .send(event.id, &organizer, false, input(BroadcastAudience::Waitlisted, Some(now + chrono::Duration::hours(1))))
+            .await
+            .unwrap();
+        assert_eq!(scheduled.status, BroadcastStatus::Scheduled);
+        let called_off = service
+            .send(event.id, &organizer, false, input(BroadcastAudience::All, Some(now + chrono::Duration::hours(1))))
+            .await
+            .unwrap();
+        let (_, cancelled) = service.cancel(event.id, called_off.id, organizer.id, false).await.unwrap();
+        assert_eq!(cancelled.status, BroadcastStatus::Cancelled);
+        assert!(matches!(
+            service.cancel(event.id, sent.id, organizer.id, false).await,
+            Err(ApiError::Conflict { .. })
+        ));
+        assert!(service.send_due(now).await.unwrap().is_empty());
+
+        let due = service.send_due(now + chrono::Duration::hours(2)).await.unwrap();
+        assert_eq!(due.iter().map(|b| b.id).collect::<Vec<_>>(), vec![scheduled.id]);
+        assert_eq!(due[0].delivery.delivered, 1);
+        assert_eq!(mocks.mailer.sent.lock().await.last().unwrap().to, "waiting@example.no");
+        assert_eq!(service.list(event.id, organizer.id, false).await.unwrap().len(), 3);
+        assert!(matches!(
+            service.list(event.id, outsider.id, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_message_threads_keep_unread_counts_per_side() {
+        let (service, mocks) = create_mock_messaging_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let co_organizer = TestUserBuilder::new().organizer().build();
+        let kari = TestUserBuilder::new().with_name("Kari Nordmann").build();
+        let outsider = TestUserBuilder::new().build();
+        for user in [&organizer, &co_organizer, &kari, &outsider] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        let mut event = TestEventBuilder::new().with_organizer(organizer.id).with_title("Sjømatdagen").published().build();
+        event.co_organizers = vec![co_organizer.id];
+        mocks.events.add_event(event.clone()).await;
+        mocks
+            .registrations
+            .add_registration(TestRegistrationBuilder::new().with_event(event.id).with_user(kari.id).build())
+            .await;
+        let mut broadcast = EventBroadcast::new(event.id, organizer.id, BroadcastAudience::All, "Parking", "Use gate B", None);
+        broadcast.mark_sent(BroadcastDelivery::default(), Utc::now());
+        mocks.broadcasts.create(&broadcast).await.unwrap();
+
+        assert!(matches!(
+            service.contact(event.id, &outsider, "Hello?".to_string(), None).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.contact(event.id, &organizer, "Hello?".to_string(), None).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.contact(event.id, &kari, "  ".to_string(), None).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.contact(event.id, &kari, "Hello?".to_string(), Some(Uuid::new_v4())).await,
+            Err(ApiError::NotFound { .. })
+        ));
+
+        let mut notifications = mocks.notifier.subscribe();
+        let question = service
+            .contact(event.id, &kari, " Which gate is B? ".to_string(), Some(broadcast.id))
+            .await
+            .unwrap();
+        assert_eq!((question.body.as_str(), question.broadcast_id), ("Which gate is B?", Some(broadcast.id)));
+        let notified: Vec<Uuid> = std::iter::from_fn(|| notifications.try_recv().ok())
+            .map(|notification| notification.user_id)
+            .collect();
+        assert_eq!(notified, vec![organizer.id, co_organizer.id]);
+        // Writing again continues the same thread
+        let follow_up = service.contact(event.id, &kari, "Also, is there a cloakroom?".to_string(), None).await.unwrap();
+        assert_eq!(follow_up.thread_id, question.thread_id);
+
+        assert_eq!(service.unread_count(organizer.id).await.unwrap(), 2);
+        assert_eq!(service.unread_count(co_organizer.id).await.unwrap(), 2);
+        assert_eq!(service.unread_count(kari.id).await.unwrap(), 0);
+        let inbox = service.inbox(co_organizer.id).await.unwrap();
+        assert_eq!(inbox.len(), 1);
+        assert_eq!((inbox[0].event_title.as_str(), inbox[0].attendee_name.as_str()), ("Sjømatdagen", "Kari Nordmann"));
+        assert!(inbox[0].as_organizer);
+        assert_eq!(inbox[0].unread(), 2);
+
+        assert!(matches!(
+            service.thread(question.thread_id, outsider.id, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let (thread, messages) = service.thread(question.thread_id, co_organizer.id, false).await.unwrap();
+        assert_eq!(thread.unread(), 0);
+        assert_eq!(messages.len(), 2);
+        // Read for one organizer is read for all of them
+        assert_eq!(service.unread_count(organizer.id).await.unwrap(), 0);
+
+        let answer = service
+            .reply(question.thread_id, &co_organizer, false, "Next to the main entrance".to_string())
+            .await
+            .unwrap();
+        assert!(answer.from_organizer);
+        assert_eq!(service.unread_count(kari.id).await.unwrap(), 1);
+        let inbox = service.inbox(kari.id).await.unwrap();
+        assert!(!inbox[0].as_organizer);
+        assert_eq!(inbox[0].unread(), 1);
+        service.thread(question.thread_id, kari.id, false).await.unwrap();
+        assert_eq!(service.unread_count(kari.id).await.unwrap(), 0);
+    }
+
+    fn new_photo(filename: &str) -> NewPhoto {
+        NewPhoto {
+            original_filename: filename.to_string(),
+            mime_type: "image/jpeg".to_string(),
+            caption: Some("  ".to_string()),
+            bytes: b"\xFF\xD8\xFF\xE0JFIF".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_photo_gallery_moderates_attendee_uploads_after_the_event() {
+        let (service, mocks) = create_mock_photo_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let upcoming = TestEventBuilder::new().with_organizer(organizer.id).build();
+        let event = TestEventBuilder::new()
+            .with_organizer(organizer.id)
+            .starting_at(Utc::now() - chrono::Duration::days(1))
+            .build();
+        mocks.events.add_event(upcoming.clone()).await;
+        mocks.events.add_event(event.clone()).await;
+        let attendee = TestUserBuilder::new().build();
+        let stranger = TestUserBuilder::new().build();
+        mocks
+            .registrations
+            .add_registration(TestRegistrationBuilder::new().with_event(event.id).with_user(attendee.id).build())
+            .await;
+
+        assert!(matches!(
+            service.upload(upcoming.id, &organizer, false, new_photo("stage.jpg")).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let mut pdf = new_photo("stage.pdf");
+        pdf.mime_type = "application/pdf".to_string();
+        assert!(matches!(
+            service.upload(event.id, &organizer, false, pdf).await,
+            Err(ApiError::Validation { .. })
+        ));
+        assert!(matches!(
+            service.upload(event.id, &attendee, false, new_photo("crowd.jpg")).await,
+            Err(ApiError::Authorization { .. })
+        ));
+
+        let stage = service.upload(event.id, &organizer, false, new_photo("stage.jpg")).await.unwrap();
+        assert_eq!(stage.status, PhotoStatus::Approved);
+        assert_eq!(stage.caption, None);
+        let settings = AlbumSettings {
+            attendee_uploads: true,
+            requires_approval: true,
+            is_public: false,
+        };
+        assert!(matches!(
+            service.update_album(event.id, attendee.id, false, settings).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        service.update_album(event.id, organizer.id, false, settings).await.unwrap();
+        assert!(matches!(
+            service.upload(event.id, &stranger, false, new_photo("crowd.jpg")).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let crowd = service.upload(event.id, &attendee, false, new_photo("crowd.jpg")).await.unwrap();
+        assert_eq!(crowd.status, PhotoStatus::Pending);
+
+        // A pending photo is shown only to its uploader and the organizers
+        let ids = |gallery: PhotoGallery| gallery.photos.into_iter().map(|p| p.id).collect::<Vec<_>>();
+        assert_eq!(ids(service.gallery(event.id, Some(organizer.id), false).await.unwrap()), vec![stage.id, crowd.id]);
+        assert_eq!(ids(service.gallery(event.id, Some(attendee.id), false).await.unwrap()), vec![stage.id, crowd.id]);
+        assert_eq!(ids(service.gallery(event.id, Some(stranger.id), false).await.unwrap()), vec![stage.id]);
+        assert!(matches!(
+            service.download(event.id, crowd.id, Some(stranger.id), false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+        let gallery = service.gallery(event.id, Some(attendee.id), false).await.unwrap();
+        assert!(gallery.can_upload && !gallery.can_manage);
+        assert!(gallery.can_remove(&crowd) && !gallery.can_remove(&stage));
+
+        assert!(matches!(
+            service.moderate(event.id, crowd.id, attendee.id, false, PhotoStatus::Approved).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        assert!(matches!(
+            service.moderate(event.id, crowd.id, organizer.id, false, PhotoStatus::Pending).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let approved = service
+            .moderate(event.id, crowd.id, organizer.id, false, PhotoStatus::Approved)
+            .await
+            .unwrap();
+        assert!(approved.reviewed_at.is_some());
+        assert_eq!(ids(service.gallery(event.id, Some(stranger.id), false).await.unwrap()).len(), 2);
+
+        // The public page shows nothing until the album is made public
+        assert!(service.gallery(event.id, None, false).await.unwrap().photos.is_empty());
+        service
+            .update_album(event.id, organizer.id, false, AlbumSettings { is_public: true, ..settings })
+            .await
+            .unwrap();
+        assert_eq!(ids(service.gallery(event.id, None, false).await.unwrap()).len(), 2);
+
+        let (filename, zip) = service.download_all(event.id, stranger.id, false).await.unwrap();
+        assert_eq!(filename, format!("{}-photos.zip", event.slug));
+        assert_eq!(&zip[..4], b"PK\x03\x04");
+
+        assert!(matches!(
+            service.delete(event.id, stage.id, attendee.id, false).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        service.delete(event.id, crowd.id, attendee.id, false).await.unwrap();
+        assert_eq!(mocks.storage.files.lock().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_past_events_are_browsed_and_compared_year_over_year() {
+        use chrono::TimeZone;
+
+        let (service, mocks) = create_mock_past_events_service();
+        let organizer = TestUserBuilder::new().organizer().build();
+        let stranger = TestUserBuilder::new().build();
+        let past = |year: i32, title: &str| {
+            TestEventBuilder::new()
+                .with_organizer(organizer.id)
+                .with_title(title)
+                .with_category("conf")
+                .starting_at(Utc.with_ymd_and_hms(year, 5, 12, 9, 0, 0).unwrap())
+                .published()
+        };
+        let summit_2024 = past(2024, "Salmon Summit").build();
+        let summit_2025 = past(2025, "Salmon Summit").build();
+        let board_meeting = past(2025, "Board meeting").private().build();
+        let draft = TestEventBuilder::new()
+            .with_organizer(organizer.id)
+            .starting_at(Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap())
+            .build();
+        let upcoming = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        for event in [&summit_2024, &summit_2025, &board_meeting, &draft, &upcoming] {
+            mocks.events.add_event(event.clone()).await;
+        }
+        for registration in [
+            TestRegistrationBuilder::new().with_event(summit_2024.id).build(),
+            TestRegistrationBuilder::new().with_event(summit_2025.id).build(),
+            TestRegistrationBuilder::new().with_event(summit_2025.id).build(),
+            TestRegistrationBuilder::new().with_event(summit_2025.id).attended().build(),
+            TestRegistrationBuilder::new().with_event(summit_2025.id).cancelled().build(),
+        ] {
+            mocks.registrations.add_registration(registration).await;
+        }
+
+        let ids = |result: PaginatedResult<EventSummary>| result.items.into_iter().map(|s| s.event_id).collect::<Vec<_>>();
+        let all = PaginationParams::default();
+        // The private event counts only for its organizer and administrators
+        assert_eq!(
+            ids(service.browse(stranger.id, false, PastEventFilter::default(), all.clone()).await.unwrap()),
+            vec![summit_2025.id, summit_2024.id]
+        );
+        assert_eq!(service.browse(organizer.id, false, PastEventFilter::default(), all.clone()).await.unwrap().total_count, 3);
+        assert_eq!(service.browse(stranger.id, true, PastEventFilter::default(), all.clone()).await.unwrap().total_count, 3);
+
+        let query = PastEventsQuery {
+            page: None,
+            limit: None,
+            title_contains: Some("  summit ".to_string()),
+            category_id: None,
+            organizer_id: Some(organizer.id),
+            year: Some(2025),
+        };
+        let (filter, pagination) = query.to_filter_and_pagination().unwrap();
+        let page = service.browse(organizer.id, false, filter, pagination).await.unwrap();
+        assert_eq!(ids(page.clone()), vec![summit_2025.id]);
+        let event = PastEventResponse::from(page.items[0].clone());
+        assert_eq!((event.registrations, event.checked_in, event.no_shows, event.cancelled), (3, 1, 2, 1));
+        assert_eq!(event.attendance_rate, Some(33.3));
+
+        let trends = service
+            .trends(stranger.id, false, ArchiveGrouping::Category, PastEventFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(trends.len(), 1);
+        let years: Vec<_> = trends[0].years.iter().map(|y| (y.year, y.registrations, y.registrations_change)).collect();
+        assert_eq!(years, vec![(2024, 1, None), (2025, 3, Some(200.0))]);
+
+        let by_organizer = service
+            .trends(organizer.id, false, ArchiveGrouping::Organizer, PastEventFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(by_organizer[0].group_id, organizer.id.to_string());
+        assert_eq!(by_organizer[0].events, 3);
+
+        let backwards = PastEventFilter {
+            from_year: Some(2025),
+            to_year: Some(2024),
+            ..Default::default()
+        };
+        assert!(matches!(
+            service.trends(stranger.id, false, ArchiveGrouping::Category, backwards).await,
+            Err(ApiError::Validation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_annual_report_is_for_the_organizations_organizers() {
+        use chrono::Datelike;
+
+        let (reports, mocks) = create_mock_annual_report_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 2).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+        let participant = TestUserBuilder::new().with_company(company.id).build();
+        let outsider = TestUserBuilder::new().organizer().build();
+
+        let last_year = Utc::now().year() - 1;
+        mocks
+            .analytics
+            .set_year(OrganizationYear {
+                events: 4,
+                registrations: 120,
+                checked_in: 90,
+                categories: vec![CategoryAttendance {
+                    category_id: "conf".to_string(),
+                    category_name: Some("Conference".to_string()),
+                    events: 4,
+                    registrations: 120,
+                    checked_in: 90,
+                }],
+                satisfaction: SurveySatisfaction {
+                    responses: 10,
+                    average_rating: Some(4.25),
+                    recommend_share: Some(0.8),
+                    average_return_likelihood: Some(8.0),
+                },
+                ..OrganizationYear::empty(company.id, last_year)
+            })
+            .await;
+        mocks
+            .analytics
+            .set_year(OrganizationYear {
+                events: 2,
+                registrations: 100,
+                checked_in: 60,
+                ..OrganizationYear::empty(company.id, last_year - 1)
+            })
+            .await;
+
+        for user in [&participant, &outsider] {
+            assert!(matches!(
+                reports.report(company.id, None, user, false).await,
+                Err(ApiError::Authorization { .. })
+            ));
+        }
+        assert!(matches!(
+            reports.report(Uuid::new_v4(), None, &organizer, false).await,
+            Err(ApiError::NotFound { .. })
+        ));
+        assert!(matches!(
+            reports.report(company.id, Some(last_year + 2), &organizer, false).await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        // The last full year by default
+        let report = reports.report(company.id, None, &organizer, false).await.unwrap();
+        assert_eq!(report.company_name, "Mowi");
+        assert_eq!((report.current.year, report.previous.year), (last_year, last_year - 1));
+        assert_eq!(report.current.attendance_rate, Some(75.0));
+        assert_eq!(report.events_growth, Some(100.0));
+        assert_eq!(report.attendance_growth, Some(50.0));
+        assert_eq!(report.top_categories[0].category_name.as_deref(), Some("Conference"));
+        assert_eq!(report.satisfaction.average_rating, Some(4.3));
+        assert_eq!(report.satisfaction.recommend_rate, Some(80.0));
+
+        // Administrators can report on any organization
+        let (filename, pdf) = reports
+            .report_pdf(company.id, Some(last_year - 1), &outsider, true)
+            .await
+            .unwrap();
+        assert_eq!(filename, format!("annual-report-mowi-{}.pdf", last_year - 1));
+        assert!(pdf.starts_with(b"%PDF-"));
+    }
+
+    #[tokio::test]
+    async fn test_event_fields_are_defined_by_the_organization_and_filled_in_by_organizers() {
+        use std::collections::HashMap;
+
+        let (fields, mocks) = create_mock_event_field_service();
+        let company = create_test_company("Mowi", IndustryType::Salmon);
+        mocks.companies.add_company(company.clone(), 2).await;
+        let organizer = TestUserBuilder::new().organizer().with_company(company.id).build();
+        let participant = TestUserBuilder::new().with_company(company.id).build();
+        mocks.users.add_user(organizer.clone()).await;
+        let event = TestEventBuilder::new().with_organizer(organizer.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+
+        let input = |key: &str, field_type: EventFieldType, options: &[&str], is_required: bool| EventFieldInput {
+            key: key.to_string(),
+            label: key.replace('_', " "),
+            field_type,
+            options: options.iter().map(|option| option.to_string()).collect(),
+            is_required,
+            position: None,
+        };
+        let species_input = input("species_focus", EventFieldType::Select, &["Salmon", "Cod"], true);
+        assert!(matches!(
+            fields.create_definition(company.id, &participant, false, species_input.clone()).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let species = fields.create_definition(company.id, &organizer, false, species_input.clone()).await.unwrap();
+        let quota = fields
+            .create_definition(company.id, &organizer, false, input("lice_quota", EventFieldType::Number, &[], false))
+            .await
+            .unwrap();
+        assert_eq!((species.position, quota.position), (0, 1));
+        assert!(matches!(
+            fields.create_definition(company.id, &organizer, false, species_input.clone()).await,
+            Err(ApiError::Conflict { .. })
+        ));
+        assert!(matches!(
+            fields
+                .update_definition(company.id, quota.id, &organizer, false, input("lice_quota", EventFieldType::Text, &[], false))
+                .await,
+            Err(ApiError::Validation { .. })
+        ));
+
+        let values = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+        };
+        assert!(matches!(
+            fields.update_event_fields(event.id, &participant, false, values(&[("species_focus", "Salmon")])).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        // Unknown keys, values that don't suit the type and blank required fields are refused
+        for refused in [
+            values(&[("species_focus", "Salmon"), ("topic", "Lice")]),
+            values(&[("species_focus", "Trout")]),
+            values(&[("species_focus", "Salmon"), ("lice_quota", "many")]),
+            values(&[("lice_quota", "0.5")]),
+        ] {
+            assert!(matches!(
+                fields.update_event_fields(event.id, &organizer, false, refused).await,
+                Err(ApiError::Validation { .. })
+            ));
+        }
+
+        let saved = fields
+            .update_event_fields(event.id, &organizer, false, values(&[("species_focus", "salmon"), ("lice_quota", "0.50")]))
+            .await
+            .unwrap();
+        assert!(saved.can_edit);
+        assert_eq!(saved.company_id, Some(company.id));
+        let shown: Vec<(&str, Option<&str>)> = saved
+            .fields
+            .iter()
+            .map(|entry| (entry.definition.key.as_str(), entry.value.as_deref()))
+            .collect();
+        assert_eq!(shown, vec![("species_focus", Some("Salmon")), ("lice_quota", Some("0.5"))]);
+        let viewed = fields.event_fields(event.id, Some(&participant), false).await.unwrap();
+        assert!(!viewed.can_edit);
+        assert_eq!(viewed.fields[0].value.as_deref(), Some("Salmon"));
+
+        // List filters are put in the stored form
+        let query = ListEventsQuery {
+            page: None,
+            limit: None,
+            view: None,
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: None,
+            status: None,
+            location_type: None,
+            start_date_from: None,
+            start_date_to: None,
+            latitude: None,
+            longitude: None,
+            radius_km: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field_id: Some(quota.id),
+            field_value: Some(" .50 ".to_string()),
+            county: None,
+            municipality: None,
+        };
+        let query = fields.normalize_query(query).await.unwrap();
+        assert_eq!(query.field_value.as_deref(), Some("0.5"));
+        let (filter, _) = query.to_filter_and_pagination().unwrap();
+        assert_eq!(filter.field, Some(EventFieldMatch { field_id: quota.id, value: "0.5".to_string() }));
+
+        fields.delete_definition(company.id, quota.id, &organizer, false).await.unwrap();
+        assert_eq!(fields.definitions(company.id).await.unwrap().len(), 1);
+        assert!(mocks.fields.find_values(event.id).await.unwrap().iter().all(|value| value.field_id == species.id));
+    }
+
+    #[tokio::test]
+    async fn test_event_archive_round_trip_creates_a_draft_copy() {
+        let (service, mocks) = create_mock_archive_service();
+        let owner = TestUserBuilder::new().organizer().build();
+        let attendee = TestUserBuilder::new().build();
+        let outsider = TestUserBuilder::new().organizer().build();
+        for user in [&owner, &attendee, &outsider] {
+            mocks.users.add_user(user.clone()).await;
+        }
+        mocks.categories.add_category(TestCategoryBuilder::new().with_id("general").build()).await;
+        let event = TestEventBuilder::new().with_organizer(owner.id).published().build();
+        mocks.events.add_event(event.clone()).await;
+        mocks
+            .registrations
+            .add_registration(TestRegistrationBuilder::new().with_event(event.id).with_user(attendee.id).build())
+            .await;
+        mocks
+            .registrations
+            .add_registration(TestRegistrationBuilder::new().with_event(event.id).build())
+            .await;
+        mocks.budget.lines.lock().await.push(BudgetLine::new(event.id, "Venue", 50_000));
+
+        assert!(matches!(
+            service.export(event.id, outsider.id, false, &Anonymizer::default()).await,
+            Err(ApiError::Authorization { .. })
+        ));
+        let (filename, archive) = service.export(event.id, owner.id, false, &Anonymizer::default()).await.unwrap();
+        assert_eq!(filename, format!("{}.aqio.json", event.slug));
+        assert_eq!(archive.registrations.len(), 2);
+
+        // Through JSON, as it travels between environments
+        let archive: crate::domain::archive::EventArchive =
+            serde_json::from_str(&serde_json::to_string(&archive).unwrap()).unwrap();
+        let imported = service.import(archive.clone(), &outsider, &Anonymizer::default()).await.unwrap();
+        assert_ne!(imported.event.id, event.id);
+        assert_eq!(imported.event.organizer_id, outsider.id);
+        assert!(matches!(imported.event.status, EventStatus::Draft));
+        assert!(mocks.events.events.lock().await.contains_key(&imported.event.id));
+
+        let copied = mocks.registrations.find_by_event_id(imported.event.id).await.unwrap();
+        assert_eq!(copied.len(), 2);
+        // Only the attendee that exists here keeps their account link
+        assert_eq!(copied.iter().filter(|r| r.user_id == Some(attendee.id)).count(), 1);
+        assert_eq!(copied.iter().filter(|r| r.user_id.is_none()).count(), 1);
+        assert_eq!(mocks.budget.lines.lock().await.iter().filter(|l| l.event_id == imported.event.id).count(), 1);
+
+        let mut unknown_category = archive.clone();
+        unknown_category.event.category_id = "staging-only".to_string();
+        assert!(matches!(
+            service.import(unknown_category, &outsider, &Anonymizer::default()).await,
+            Err(ApiError::Validation { .. })
+        ));
+        let mut newer = archive;
+        newer.format_version += 1;
+        assert!(matches!(
+            service.import(newer, &outsider, &Anonymizer::default()).await,
+            Err(ApiError::Validation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_event_archive_anonymizes_on_export_and_import() {
+        let (service, mocks) = create_mock_archive_service();
+        let owner = TestUserBuilder::new().organizer().build();
+        mocks.users.add_user(owner.clone()).await;
+        mocks.categories.add_category(TestCategoryBuilder::new().with_id("general").build()).await;
+        let event = TestEventBuilder::new().with_organizer(owner.id).build();
+        mocks.events.add_event(event.clone()).await;
+        let mut registration = TestRegistrationBuilder::new()
+            .with_event(event.id)
+            .with_email("kari@mowi.no")
+            .build();
+        registration.registrant_phone = Some("912 34 567".to_string());
+        mocks.registrations.add_registration(registration).await;
+
+        let (_, archive) = service
+            .export(event.id, owner.id, false, &Anonymizer::new([PiiField::Email]))
+            .await
+            .unwrap();
+        assert!(archive.registrations[0].registrant_email.as_deref().unwrap().ends_with("@example.invalid"));
+        assert_eq!(archive.registrations[0].registrant_phone.as_deref(), Some("912 34 567"));
+        assert_eq!(archive.anonymized, vec![PiiField::Email]);
+
+        // The server's own import settings apply whatever the caller asks for
+        let service = service.with_import_anonymizer(Anonymizer::new([PiiField::Phone]));
+        let imported = service
+            .import(archive, &owner, &Anonymizer::new([PiiField::Notes]))
+            .await
+            .unwrap();
+        assert_eq!(imported.registrations[0].registrant_phone.as_deref(), Some("*** ** *67"));
+        assert_eq!(imported.anonymized, vec![PiiField::Email, PiiField::Phone, PiiField::Notes]);
+        assert!(matches!(
+            EventArchiveQuery { anonymize: Some("email,ssn".to_string()) }.anonymizer(),
+            Err(ApiError::Validation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_update_user_sets_and_clears_timezone_and_locale() {
+        let user = TestUserBuilder::new().build();
+        let update = |json: &str| serde_json::from_str::<UpdateUserRequest>(json).unwrap();
+
+        let user = update(r#"{"timezone": "America/Halifax", "locale": "nb-NO"}"#)
+            .apply_to_user(user)
+            .unwrap();
+        assert_eq!(user.timezone.as_deref(), Some("America/Halifax"));
+        assert_eq!(user.locale, Some(Locale::Nb));
+
+        // Missing fields are left alone, null clears them
+        let user = update(r#"{"locale": null}"#).apply_to_user(user).unwrap();
+        assert_eq!(user.timezone.as_deref(), Some("America/Halifax"));
+        assert_eq!(user.locale, None);
+
+        assert!(matches!(
+            update(r#"{"timezone": "Mars/Olympus"}"#).apply_to_user(user),
+            Err(ApiError::Validation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_update_user_sets_and_clears_interface_preferences() {
+        let user = TestUserBuilder::new().build();
+        let update = |json: &str| serde_json::from_str::<UpdateUserRequest>(json);
+
+        let user = update(r#"{"theme": "Dark", "calendar_view": "week"}"#)
+            .unwrap()
+            .apply_to_user(user)
+            .unwrap();
+        assert_eq!((user.theme, user.calendar_view), (Some(Theme::Dark), Some(CalendarView::Week)));
+
+        let user = update(r#"{"theme": null}"#).unwrap().apply_to_user(user).unwrap();
+        assert_eq!((user.theme, user.calendar_view), (None, Some(CalendarView::Week)));
+
+        assert!(update(r#"{"calendar_view": "year"}"#).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_exports_deliver_due_jobs_and_record_failures() {
+        let (service, mocks) = create_mock_export_service();
+        let admin = TestUserBuilder::new().build();
+        let event = TestEventBuilder::new().with_title("Sjømatdagen").build();
+        mocks.events.add_event(event.clone()).await;
+        let old = TestRegistrationBuilder::new().with_event(event.id).with_email("ola@example.org").build();
+        mocks.registrations.add_registration(old.clone()).await;
+
+        let input = ExportJobInput {
+            name: "Nightly CRM".to_string(),
+            entity: ExportEntity::Registrations,
+            filter: ExportFilter { company_id: None, event_id: Some(event.id), changed_since_last_run: true },
+            format: ExportFormat::Csv,
+            destination: ExportDestination::S3 {
+                bucket: "crm-drop".to_string(),
+                region: "eu-north-1".to_string(),
+                prefix: "aqio/".to_string(),
+                endpoint: None,
+            },
+            schedule: "0 2 * * *".to_string(),
+            timezone: None,
+            is_active: true,
+        };
+        let job = service.create(input.clone(), admin.id).await.unwrap();
+        assert_eq!(job.timezone, "Europe/Oslo");
+        let next_run_at = job.next_run_at.unwrap();
+        assert!(service.run_due(next_run_at - chrono::Duration::minutes(1)).await.unwrap().is_empty());
+
+        // The first run exports everything
+        let ran = service.run_due(next_run_at).await.unwrap();
+        assert_eq!(ran[0].last_status, Some(ExportRunStatus::Succeeded));
+        assert_eq!(ran[0].last_row_count, Some(1));
+        assert!(ran[0].next_run_at.unwrap() > next_run_at);
+        let first = mocks.uploader.files.lock().await.clone();
+        let (name, bytes) = first.iter().next().unwrap();
+        assert!(name.starts_with("nightly-crm-") && name.ends_with(".csv"));
+        assert!(String::from_utf8(bytes.clone()).unwrap().contains("ola@example.org"));
+
+        // Later runs only pick up what changed since the last good one
+        let mut new = TestRegistrationBuilder::new().with_event(event.id).with_email("kari@example.org").build();
+        new.updated_at = next_run_at + chrono::Duration::minutes(1);
+        mocks.registrations.add_registration(new).await;
+        mocks.uploader.set_unavailable(true).await;
+        let failed = service.run_now(job.id).await;
+        assert!(matches!(failed, Err(ApiError::Domain { source: DomainError::ExternalServiceError { .. } })));
+        let recorded = service.get(job.id).await.unwrap();
+        assert_eq!(recorded.last_status, Some(ExportRunStatus::Failed));
+        assert!(recorded.last_error.unwrap().contains("connection refused"));
+
+        mocks.uploader.set_unavailable(false).await;
+        let delivered = service.run_now(job.id).await.unwrap();
+        assert_eq!(delivered.last_row_count, Some(1));
+        let files = mocks.uploader.files.lock().await.clone();
+        let (_, bytes) = files.iter().find(|(name, _)| !first.contains_key(*name)).unwrap();
+        let csv = String::from_utf8(bytes.clone()).unwrap();
+        assert!(csv.contains("kari@example.org") && !csv.contains("ola@example.org"));
+
+        // Paused jobs don't come due, and filters must point at a real event
+        let paused = service.update(job.id, ExportJobInput { is_active: false, ..input.clone() }).await.unwrap();
+        assert!(paused.next_run_at.is_none());
+        assert!(service.run_due(next_run_at + chrono::Duration::days(30)).await.unwrap().is_empty());
+        let missing = ExportFilter { event_id: Some(Uuid::new_v4()), ..input.filter.clone() };
+        assert!(matches!(
+            service.create(ExportJobInput { filter: missing, ..input }, admin.id).await,
+            Err(ApiError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_crm_sync_is_incremental_keeps_crm_edits_and_retries_failures() {
+        let (service, mocks) = create_mock_crm_service();
+        let admin = TestUserBuilder::new().build();
+        let event = TestEventBuilder::new().with_title("Sjømatdagen").build();
+        mocks.events.add_event(event.clone()).await;
+        let ola = TestRegistrationBuilder::new().with_event(event.id).with_email("ola@example.org").build();
+        let mut kari = TestRegistrationBuilder::new().with_event(event.id).with_email("Kari@Example.org").build();
+        kari.registrant_name = Some("Kari Nordmann".to_string());
+        kari.registrant_phone = Some("+47 911 11 111".to_string());
+        mocks.registrations.add_registration(ola.clone()).await;
+        mocks.registrations.add_registration(kari.clone()).await;
+        // Kari is in the CRM already, with a phone number someone typed in there
+        mocks.client.records.lock().await.insert(
+            "contacts/451".to_string(),
+            [("email", "kari@example.org"), ("firstname", "Kari"), ("phone", "+47 922 22 222")]
+                .into_iter()
+                .map(|(property, value)| (property.to_string(), value.to_string()))
+                .collect(),
+        );
+
+        let mut mappings = CrmConnector::new("", CrmProvider::HubSpot, admin.id, Utc::now()).field_mappings;
+        mappings.extend([
+            CrmFieldMapping { record: CrmRecordKind::Contact, source: CrmSourceField::Phone, property: "phone".to_string() },
+            CrmFieldMapping {
+                record: CrmRecordKind::Registration,
+                source: CrmSourceField::RegistrationStatus,
+                property: "status".to_string(),
+            },
+        ]);
+        let input = CrmConnectorInput {
+            name: "Medlemsregister".to_string(),
+            provider: CrmProvider::HubSpot,
+            base_url: None,
+            company_id: None,
+            registration_object: Some("p_registrations".to_string()),
+            field_mappings: Some(mappings),
+            conflict_policy: None,
+            sync_interval_minutes: Some(15),
+            is_active: true,
+        };
+        let connector = service.create(input.clone(), admin.id).await.unwrap();
+        assert_eq!(connector.conflict_policy, CrmConflictPolicy::CrmWins);
+        assert_eq!(service.sync_due(Utc::now()).await.unwrap().len(), 1);
+
+        // The first run creates Ola, fills in Kari's blanks and leaves her phone alone
+        let synced = service.get(connector.id).await.unwrap();
+        assert_eq!(synced.last_status, Some(CrmSyncRunStatus::Succeeded));
+        assert_eq!(
+            synced.last_summary,
+            Some(CrmSyncSummary { created: 3, updated: 0, unchanged: 0, conflicts: 1, failed: 0 })
+        );
+        assert!(synced.next_sync_at.unwrap() > synced.last_sync_at.unwrap());
+        let crm_kari = mocks.client.records.lock().await["contacts/451"].clone();
+        assert_eq!(crm_kari["lastname"], "Nordmann");
+        assert_eq!(crm_kari["phone"], "+47 922 22 222");
+        let status = service.status(connector.id).await.unwrap();
+        assert_eq!(status.counts, CrmSyncCounts { synced: 3, conflicts: 1, failed: 0 });
+        assert_eq!(status.problems[0].local_key, "kari@example.org");
+        assert_eq!(status.problems[0].conflicts, vec!["phone"]);
+
+        // Nothing changed, so nothing is read or written
+        let writes = *mocks.client.writes.lock().await;
+        let quiet = service.sync_now(connector.id).await.unwrap();
+        assert_eq!(quiet.last_summary, Some(CrmSyncSummary::default()));
+        assert_eq!(*mocks.client.writes.lock().await, writes);
+
+        // A changed registration that can't be written is retried on the next run
+        let mut cancelled = ola.clone();
+        cancelled.status = RegistrationStatus::Cancelled;
+        cancelled.updated_at = Utc::now();
+        mocks.registrations.update(&cancelled).await.unwrap();
+        mocks.client.set_unavailable(true).await;
+        let partial = service.sync_now(connector.id).await.unwrap();
+        assert_eq!(partial.last_status, Some(CrmSyncRunStatus::PartiallyFailed));
+        assert_eq!(partial.last_summary.unwrap().failed, 1);
+        let status = service.status(connector.id).await.unwrap();
+        assert_eq!(status.counts.failed, 1);
+        assert!(status.problems.iter().any(|record| record.error.as_deref().is_some_and(|e| e.contains("503"))));
+
+        mocks.client.set_unavailable(false).await;
+        let retried = service.sync_now(connector.id).await.unwrap();
+        assert_eq!(retried.last_status, Some(CrmSyncRunStatus::Succeeded));
+        assert_eq!(retried.last_summary.unwrap().updated, 1);
+        let record = mocks
+            .connectors
+            .find_record(connector.id, CrmRecordKind::Registration, &ola.id.to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        let crm_records = mocks.client.records.lock().await.clone();
+        assert_eq!(crm_records[&format!("p_registrations/{}", record.remote_id.unwrap())]["status"], "Cancelled");
+
+        // Switching to AqioWins goes through everything again and overwrites the CRM's edit
+        let updated = service
+            .update(connector.id, CrmConnectorInput { conflict_policy: Some(CrmConflictPolicy::AqioWins), ..input.clone() })
+            .await
+            .unwrap();
+        assert!(updated.sync_cursor.is_none());
+        service.sync_now(connector.id).await.unwrap();
+        let crm_kari = mocks.client.records.lock().await["contacts/451"].clone();
+        assert_eq!(crm_kari["phone"], "+47 911 11 111");
+        assert_eq!(service.status(connector.id).await.unwrap().counts.conflicts, 0);
+
+        // A connector stays with its CRM
+        let moved = CrmConnectorInput { provider: CrmProvider::Dynamics, ..input };
+        assert!(matches!(service.update(connector.id, moved).await, Err(ApiError::Validation { .. })));
     }
-}
\ No newline at end of file
+}
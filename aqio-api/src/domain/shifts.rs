@@ -0,0 +1,118 @@
+// Volunteer shift coverage: who is on each of an event's shifts and how many
+// people each one still needs. Shifts are listed in time order, so the gaps
+// read as a timeline of where the event is short-staffed.
+
+use std::collections::HashMap;
+
+use aqio_core::{EventShift, ShiftSignup, User};
+use uuid::Uuid;
+
+/// A staff user on a shift
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShiftStaff {
+    pub user_id: Uuid,
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaffedShift {
+    pub shift: EventShift,
+    /// In signup order
+    pub staff: Vec<ShiftStaff>,
+}
+
+impl StaffedShift {
+    /// People still needed to reach the headcount
+    pub fn missing(&self) -> usize {
+        usize::try_from(self.shift.headcount).unwrap_or(0).saturating_sub(self.staff.len())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport {
+    pub shifts: Vec<StaffedShift>,
+}
+
+impl CoverageReport {
+    pub fn needed(&self) -> usize {
+        self.shifts.iter().map(|shift| usize::try_from(shift.shift.headcount).unwrap_or(0)).sum()
+    }
+
+    /// Signups counting towards a headcount; extra people on a full shift don't fill another one
+    pub fn filled(&self) -> usize {
+        self.needed() - self.missing()
+    }
+
+    pub fn missing(&self) -> usize {
+        self.shifts.iter().map(StaffedShift::missing).sum()
+    }
+
+    /// Shifts short of people, earliest first
+    pub fn gaps(&self) -> impl Iterator<Item = &StaffedShift> {
+        self.shifts.iter().filter(|shift| shift.missing() > 0)
+    }
+}
+
+/// Puts the signups on their shifts. Signups of users that no longer exist
+/// are left out.
+pub fn build_coverage(shifts: Vec<EventShift>, signups: &[ShiftSignup], users: &[User]) -> CoverageReport {
+    let users: HashMap<Uuid, &User> = users.iter().map(|user| (user.id, user)).collect();
+    let mut staff: HashMap<Uuid, Vec<ShiftStaff>> = HashMap::new();
+    for signup in signups {
+        if let Some(user) = users.get(&signup.user_id) {
+            staff.entry(signup.shift_id).or_default().push(ShiftStaff {
+                user_id: user.id,
+                name: user.name.clone(),
+                email: user.email.clone(),
+            });
+        }
+    }
+
+    let shifts = shifts
+        .into_iter()
+        .map(|shift| StaffedShift {
+            staff: staff.remove(&shift.id).unwrap_or_default(),
+            shift,
+        })
+        .collect();
+    CoverageReport { shifts }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestUserBuilder;
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_coverage_counts_gaps_per_shift() {
+        let start = Utc::now();
+        let event_id = Uuid::new_v4();
+        let desk = EventShift::new(event_id, "Registration desk", start, start + Duration::hours(2), 2);
+        let bar = EventShift::new(event_id, "Bar", start + Duration::hours(4), start + Duration::hours(6), 1);
+        let kari = TestUserBuilder::new().with_name("Kari").build();
+        let ola = TestUserBuilder::new().with_name("Ola").build();
+        let signup = |shift: &EventShift, user: &User| ShiftSignup {
+            shift_id: shift.id,
+            user_id: user.id,
+            signed_up_at: Utc::now(),
+        };
+        let deleted = ShiftSignup {
+            user_id: Uuid::new_v4(),
+            ..signup(&bar, &kari)
+        };
+
+        let report = build_coverage(
+            vec![desk.clone(), bar.clone()],
+            &[signup(&desk, &kari), signup(&bar, &ola), signup(&bar, &kari), deleted],
+            &[kari.clone(), ola.clone()],
+        );
+
+        assert_eq!(report.shifts[0].staff.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(), vec!["Kari"]);
+        assert!(report.shifts[1].staff.iter().any(|staff| staff.user_id == ola.id));
+        // The bar is overstaffed, which doesn't help the desk
+        assert_eq!((report.needed(), report.filled(), report.missing()), (3, 2, 1));
+        assert_eq!(report.gaps().map(|s| s.shift.id).collect::<Vec<_>>(), vec![desk.id]);
+    }
+}
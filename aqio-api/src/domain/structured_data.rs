@@ -0,0 +1,187 @@
+// Search engine output for public events: a sitemap listing their pages and
+// schema.org `Event` markup (JSON-LD) for each page, which is what search
+// engines read to show events as rich results.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use chrono_tz::Tz;
+use serde_json::{Map, Value, json};
+
+use aqio_core::{Event, EventStatus, LocationType};
+
+use crate::domain::digest::escape_html;
+
+/// Most URLs one sitemap file may list
+pub const MAX_SITEMAP_URLS: usize = 50_000;
+
+/// A page listed in the sitemap
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapEntry {
+    pub url: String,
+    pub last_modified: DateTime<Utc>,
+}
+
+/// A sitemaps.org `urlset`; entries past [`MAX_SITEMAP_URLS`] are left out
+pub fn render_sitemap(entries: &[SitemapEntry]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for entry in entries.iter().take(MAX_SITEMAP_URLS) {
+        xml.push_str(&format!(
+            "  <url>\n    <loc>{}</loc>\n    <lastmod>{}</lastmod>\n  </url>\n",
+            escape_html(&entry.url),
+            entry.last_modified.to_rfc3339_opts(SecondsFormat::Secs, true)
+        ));
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// schema.org `Event` for a public event. The join link of virtual events is
+/// never included; their location is the public page. `spots_left` is `None`
+/// for events without a capacity limit.
+pub fn event_json_ld(event: &Event, page_url: &str, spots_left: Option<i32>) -> Value {
+    let timezone: Tz = event.timezone.parse().unwrap_or(chrono_tz::Europe::Oslo);
+    let local = |time: DateTime<Utc>| time.with_timezone(&timezone).to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let mut markup = Map::new();
+    markup.insert("@context".into(), json!("https://schema.org"));
+    markup.insert("@type".into(), json!("Event"));
+    markup.insert("name".into(), json!(event.title));
+    markup.insert("description".into(), json!(event.description));
+    markup.insert("url".into(), json!(page_url));
+    markup.insert("startDate".into(), json!(local(event.start_date)));
+    markup.insert("endDate".into(), json!(local(event.end_date)));
+    let status = match event.status {
+        EventStatus::Cancelled => "EventCancelled",
+        _ => "EventScheduled",
+    };
+    markup.insert("eventStatus".into(), json!(format!("https://schema.org/{status}")));
+    let mode = match event.location_type {
+        LocationType::Physical => "OfflineEventAttendanceMode",
+        LocationType::Virtual => "OnlineEventAttendanceMode",
+        LocationType::Hybrid => "MixedEventAttendanceMode",
+    };
+    markup.insert("eventAttendanceMode".into(), json!(format!("https://schema.org/{mode}")));
+
+    let virtual_location = json!({"@type": "VirtualLocation", "url": page_url});
+    let location = match event.location_type {
+        LocationType::Virtual => virtual_location,
+        LocationType::Physical => place(event),
+        LocationType::Hybrid => json!([place(event), virtual_location]),
+    };
+    markup.insert("location".into(), location);
+
+    if let Some(image_url) = &event.image_url {
+        markup.insert("image".into(), json!([image_url]));
+    }
+    if event.registration_required {
+        let availability = match spots_left {
+            Some(0) if event.allow_waitlist => "LimitedAvailability",
+            Some(0) => "SoldOut",
+            _ => "InStock",
+        };
+        let mut offer = json!({
+            "@type": "Offer",
+            "url": page_url,
+            "price": 0,
+            "priceCurrency": "NOK",
+            "availability": format!("https://schema.org/{availability}"),
+        });
+        if let Some(opens) = event.registration_opens {
+            offer["validFrom"] = json!(local(opens));
+        }
+        markup.insert("offers".into(), offer);
+    }
+    Value::Object(markup)
+}
+
+fn place(event: &Event) -> Value {
+    let mut address = json!({"@type": "PostalAddress", "addressCountry": "NO"});
+    if let Some(street) = &event.address {
+        address["streetAddress"] = json!(street);
+    }
+    if let Some(region) = &event.region {
+        address["postalCode"] = json!(region.postal_code);
+        address["addressLocality"] = json!(region.municipality);
+        address["addressRegion"] = json!(region.county);
+    }
+    let mut place = json!({
+        "@type": "Place",
+        "name": event.location_name.as_deref().or(event.address.as_deref()).unwrap_or(&event.title),
+        "address": address,
+    });
+    if let (Some(latitude), Some(longitude)) = (event.latitude, event.longitude) {
+        place["geo"] = json!({"@type": "GeoCoordinates", "latitude": latitude, "longitude": longitude});
+    }
+    place
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::helpers::TestEventBuilder;
+    use aqio_core::AddressRegion;
+    use chrono::TimeZone;
+
+    fn event(location_type: LocationType) -> Event {
+        let mut event = TestEventBuilder::new().with_title("Havbruk & Teknologi").build();
+        event.start_date = Utc.with_ymd_and_hms(2026, 6, 10, 7, 0, 0).unwrap();
+        event.end_date = Utc.with_ymd_and_hms(2026, 6, 10, 15, 0, 0).unwrap();
+        event.timezone = "Europe/Oslo".to_string();
+        event.registration_required = false;
+        event.location_type = location_type;
+        event.location_name = Some("Sjøsiden".to_string());
+        event.address = Some("Strandkaien 2, 5013 Bergen".to_string());
+        event.latitude = Some(60.3946);
+        event.longitude = Some(5.3245);
+        event.region = Some(AddressRegion {
+            postal_code: "5013".to_string(),
+            municipality: "Bergen".to_string(),
+            county: "Vestland".to_string(),
+        });
+        event.virtual_link = Some("https://meet.example/secret".to_string());
+        event
+    }
+
+    #[test]
+    fn test_sitemap_lists_escaped_urls() {
+        let xml = render_sitemap(&[SitemapEntry {
+            url: "https://aqio.example/e/a&b".to_string(),
+            last_modified: Utc.with_ymd_and_hms(2026, 5, 1, 12, 30, 0).unwrap(),
+        }]);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains("<loc>https://aqio.example/e/a&amp;b</loc>"));
+        assert!(xml.contains("<lastmod>2026-05-01T12:30:00Z</lastmod>"));
+        assert!(xml.trim_end().ends_with("</urlset>"));
+    }
+
+    #[test]
+    fn test_physical_event_markup() {
+        let mut physical = event(LocationType::Physical);
+        physical.registration_required = true;
+        let markup = event_json_ld(&physical, "https://aqio.example/e/havbruk", Some(0));
+
+        assert_eq!(markup["@type"], "Event");
+        // Local time of the event, not UTC
+        assert_eq!(markup["startDate"], "2026-06-10T09:00:00+02:00");
+        assert_eq!(markup["eventAttendanceMode"], "https://schema.org/OfflineEventAttendanceMode");
+        assert_eq!(markup["location"]["name"], "Sjøsiden");
+        assert_eq!(markup["location"]["address"]["addressRegion"], "Vestland");
+        assert_eq!(markup["location"]["geo"]["latitude"], 60.3946);
+        assert_eq!(markup["offers"]["availability"], "https://schema.org/SoldOut");
+    }
+
+    #[test]
+    fn test_online_events_point_to_the_public_page() {
+        let markup = event_json_ld(&event(LocationType::Virtual), "https://aqio.example/e/havbruk", None);
+
+        assert_eq!(markup["location"]["@type"], "VirtualLocation");
+        assert_eq!(markup["location"]["url"], "https://aqio.example/e/havbruk");
+        assert!(!markup.to_string().contains("meet.example"));
+        assert!(markup.get("offers").is_none());
+
+        let hybrid = event_json_ld(&event(LocationType::Hybrid), "https://aqio.example/e/havbruk", None);
+        assert_eq!(hybrid["location"].as_array().map(Vec::len), Some(2));
+    }
+}
@@ -0,0 +1,123 @@
+// Daily weather from a point forecast. MET Norway forecasts hour by hour for
+// the first few days and in six-hour steps after that; a day's totals are
+// worked out from whichever steps cover it.
+
+use chrono::{DateTime, Duration, NaiveDate, Timelike, Utc};
+use chrono_tz::Tz;
+
+use aqio_core::{Event, LocationType, WeatherForecast};
+
+/// Forecasts reach this many days ahead
+pub const FORECAST_DAYS: i64 = 7;
+
+/// One step of a point forecast
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForecastStep {
+    pub time: DateTime<Utc>,
+    pub air_temperature_c: f64,
+    pub wind_speed_ms: f64,
+    /// Symbol for the hours after `time`
+    pub symbol_code: Option<String>,
+    /// Precipitation over the next `period_hours`
+    pub precipitation_mm: Option<f64>,
+    /// 1 or 6; 0 when the step has no period after it
+    pub period_hours: i64,
+}
+
+/// The local day of an event a forecast is wanted for: the day it starts,
+/// or today for one already under way. `None` for virtual events, events
+/// without coordinates, and events that are over or more than a week away.
+pub fn forecast_date(event: &Event, timezone: Tz, now: DateTime<Utc>) -> Option<NaiveDate> {
+    if event.location_type == LocationType::Virtual || event.latitude.is_none() || event.longitude.is_none() {
+        return None;
+    }
+    if event.end_date < now || event.start_date > now + Duration::days(FORECAST_DAYS) {
+        return None;
+    }
+    Some(event.start_date.max(now).with_timezone(&timezone).date_naive())
+}
+
+/// Totals for `date` in `timezone`; `None` when no step falls on that day
+pub fn summarize_day(steps: &[ForecastStep], date: NaiveDate, timezone: Tz) -> Option<WeatherForecast> {
+    let day: Vec<&ForecastStep> = steps
+        .iter()
+        .filter(|step| step.time.with_timezone(&timezone).date_naive() == date)
+        .collect();
+    if day.is_empty() {
+        return None;
+    }
+
+    let min_temperature_c = day.iter().map(|step| step.air_temperature_c).fold(f64::INFINITY, f64::min);
+    let max_temperature_c = day.iter().map(|step| step.air_temperature_c).fold(f64::NEG_INFINITY, f64::max);
+    let max_wind_speed_ms = day.iter().map(|step| step.wind_speed_ms).fold(0.0, f64::max);
+
+    // Periods are counted once even where hourly and six-hour steps overlap
+    let mut precipitation_mm = 0.0;
+    let mut covered_until: Option<DateTime<Utc>> = None;
+    for step in &day {
+        if step.period_hours == 0 || covered_until.is_some_and(|until| step.time < until) {
+            continue;
+        }
+        if let Some(amount) = step.precipitation_mm {
+            precipitation_mm += amount;
+            covered_until = Some(step.time + Duration::hours(step.period_hours));
+        }
+    }
+
+    let symbol_code = day
+        .iter()
+        .filter(|step| step.symbol_code.is_some())
+        .min_by_key(|step| (step.time.with_timezone(&timezone).hour() as i32 - 12).abs())
+        .and_then(|step| step.symbol_code.clone());
+
+    Some(WeatherForecast {
+        date,
+        symbol_code,
+        min_temperature_c,
+        max_temperature_c,
+        precipitation_mm: (precipitation_mm * 10.0).round() / 10.0,
+        max_wind_speed_ms,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn step(hour: u32, temperature: f64, precipitation: f64, period_hours: i64, symbol: &str) -> ForecastStep {
+        ForecastStep {
+            time: Utc.with_ymd_and_hms(2026, 6, 10, hour, 0, 0).unwrap(),
+            air_temperature_c: temperature,
+            wind_speed_ms: temperature / 4.0,
+            symbol_code: Some(symbol.to_string()),
+            precipitation_mm: Some(precipitation),
+            period_hours,
+        }
+    }
+
+    #[test]
+    fn test_a_day_is_summarized_from_its_steps() {
+        let steps = vec![
+            step(4, 9.0, 0.5, 1, "rain"),
+            step(5, 10.0, 0.2, 1, "cloudy"),
+            step(6, 12.0, 1.0, 6, "cloudy"),
+            step(10, 16.0, 0.0, 1, "partlycloudy_day"),
+            step(12, 18.0, 2.0, 6, "clearsky_day"),
+            step(22, 11.0, 0.4, 6, "clearsky_night"),
+        ];
+        let date = NaiveDate::from_ymd_opt(2026, 6, 10).unwrap();
+        let forecast = summarize_day(&steps, date, chrono_tz::Europe::Oslo).unwrap();
+
+        assert_eq!(forecast.min_temperature_c, 9.0);
+        assert_eq!(forecast.max_temperature_c, 18.0);
+        assert_eq!(forecast.max_wind_speed_ms, 4.5);
+        // 04:00, 05:00, 06:00-12:00 and 12:00-18:00; 10:00 overlaps and 22:00 UTC is the next day in Oslo
+        assert_eq!(forecast.precipitation_mm, 3.7);
+        // 10:00 UTC is noon in Oslo in summer
+        assert_eq!(forecast.symbol_code.as_deref(), Some("partlycloudy_day"));
+
+        let next_week = NaiveDate::from_ymd_opt(2026, 6, 17).unwrap();
+        assert_eq!(summarize_day(&steps, next_week, chrono_tz::Europe::Oslo), None);
+    }
+}
@@ -0,0 +1,107 @@
+// Web vitals: percentiles of the measurements browsers report, per release
+// of the web app and metric, so a regression shows up next to the release
+// that introduced it.
+
+use std::collections::BTreeMap;
+
+use aqio_core::{WebVitalMetric, WebVitalSample};
+use chrono::{DateTime, Utc};
+
+/// Nearest-rank percentile of `sorted`, which must be in ascending order;
+/// `p` is between 0 and 100
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// One metric of one release
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebVitalSummary {
+    pub release: String,
+    pub metric: WebVitalMetric,
+    pub samples: usize,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+    /// When the release reported its first sample of any metric
+    pub first_seen: DateTime<Utc>,
+}
+
+/// Summaries for every release and metric that has samples, releases in the
+/// order they were first seen
+pub fn summarize(samples: &[WebVitalSample]) -> Vec<WebVitalSummary> {
+    let mut first_seen: BTreeMap<&str, DateTime<Utc>> = BTreeMap::new();
+    let mut values: BTreeMap<(&str, WebVitalMetric), Vec<f64>> = BTreeMap::new();
+    for sample in samples {
+        first_seen
+            .entry(sample.release.as_str())
+            .and_modify(|seen| *seen = (*seen).min(sample.recorded_at))
+            .or_insert(sample.recorded_at);
+        values.entry((sample.release.as_str(), sample.metric)).or_default().push(sample.value);
+    }
+
+    let mut summaries: Vec<WebVitalSummary> = values
+        .into_iter()
+        .map(|((release, metric), mut values)| {
+            values.sort_by(f64::total_cmp);
+            WebVitalSummary {
+                release: release.to_string(),
+                metric,
+                samples: values.len(),
+                p50: percentile(&values, 50.0),
+                p75: percentile(&values, 75.0),
+                p95: percentile(&values, 95.0),
+                first_seen: first_seen[release],
+            }
+        })
+        .collect();
+    // Stable, so a release's metrics stay in metric order
+    summaries.sort_by_key(|summary| summary.first_seen);
+    summaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_summaries_hold_percentiles_per_release_in_release_order() {
+        assert_eq!(percentile(&[], 50.0), 0.0);
+        let values: Vec<f64> = (1..=20).map(f64::from).collect();
+        assert_eq!(percentile(&values, 50.0), 10.0);
+        assert_eq!(percentile(&values, 75.0), 15.0);
+        assert_eq!(percentile(&values, 95.0), 19.0);
+        assert_eq!(percentile(&values, 0.0), 1.0);
+
+        let now = Utc::now();
+        let sample = |metric, value, release: &str, days_ago| WebVitalSample {
+            recorded_at: now - Duration::days(days_ago),
+            ..WebVitalSample::new(metric, value, release)
+        };
+        let samples = vec![
+            sample(WebVitalMetric::Lcp, 1800.0, "0.4.0", 1),
+            sample(WebVitalMetric::Cls, 0.02, "0.3.0", 9),
+            sample(WebVitalMetric::Lcp, 3000.0, "0.3.0", 10),
+            sample(WebVitalMetric::Lcp, 2000.0, "0.3.0", 8),
+            sample(WebVitalMetric::Lcp, 2200.0, "0.4.0", 2),
+        ];
+
+        let summaries = summarize(&samples);
+        let keys: Vec<(&str, WebVitalMetric)> = summaries.iter().map(|s| (s.release.as_str(), s.metric)).collect();
+        assert_eq!(
+            keys,
+            vec![
+                ("0.3.0", WebVitalMetric::Lcp),
+                ("0.3.0", WebVitalMetric::Cls),
+                ("0.4.0", WebVitalMetric::Lcp),
+            ]
+        );
+        assert_eq!((summaries[0].samples, summaries[0].p50, summaries[0].p95), (2, 2000.0, 3000.0));
+        assert_eq!(summaries[0].first_seen, now - Duration::days(10));
+        assert_eq!((summaries[2].p50, summaries[2].p75), (1800.0, 2200.0));
+    }
+}
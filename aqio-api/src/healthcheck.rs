@@ -0,0 +1,34 @@
+// `aqio-api healthcheck` - probes a running server for container HEALTHCHECKs
+// Uses the same HOST/PORT configuration as the server so no extra tooling (curl, wget)
+// is needed in the runtime image.
+
+use std::time::Duration;
+
+use crate::config::ServerConfig;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Returns whether `GET /health` answered with a success status
+pub async fn run(config: &ServerConfig) -> bool {
+    let url = format!("http://{}/health", config.local_addr());
+
+    let client = match reqwest::Client::builder().timeout(PROBE_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("healthcheck: failed to build HTTP client: {}", e);
+            return false;
+        }
+    };
+
+    match client.get(&url).send().await {
+        Ok(response) if response.status().is_success() => true,
+        Ok(response) => {
+            eprintln!("healthcheck: {} returned {}", url, response.status());
+            false
+        }
+        Err(e) => {
+            eprintln!("healthcheck: {} unreachable: {}", url, e);
+            false
+        }
+    }
+}
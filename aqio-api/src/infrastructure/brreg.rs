@@ -0,0 +1,366 @@
+// Company lookups in the Norwegian business register
+// Reads the Central Coordinating Register for Legal Entities through
+// Brønnøysundregistrene's open API, behind a cache so creating a company and
+// autocompleting its details while it's typed in ask the register only once.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use aqio_core::{CompanyRegistry, DomainError, DomainResult, RegisteredCompany};
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+use crate::infrastructure::resilience::{CallError, Resilience, RetryPolicy, destination_host};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Register API used when `BRREG_API_URL` isn't set
+pub const DEFAULT_BRREG_URL: &str = "https://data.brreg.no/enhetsregisteret/api";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BrregEntity {
+    organisasjonsnummer: String,
+    navn: String,
+    hjemmeside: Option<String>,
+    forretningsadresse: Option<BrregAddress>,
+    naeringskode1: Option<BrregIndustryCode>,
+    #[serde(default)]
+    konkurs: bool,
+    #[serde(default)]
+    under_avvikling: bool,
+    #[serde(default)]
+    under_tvangsavvikling_eller_tvangsopplosning: bool,
+    slettedato: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BrregAddress {
+    #[serde(default)]
+    adresse: Vec<String>,
+    postnummer: Option<String>,
+    poststed: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BrregIndustryCode {
+    kode: String,
+    beskrivelse: Option<String>,
+}
+
+impl From<BrregEntity> for RegisteredCompany {
+    fn from(entity: BrregEntity) -> Self {
+        let is_active = !entity.konkurs
+            && !entity.under_avvikling
+            && !entity.under_tvangsavvikling_eller_tvangsopplosning
+            && entity.slettedato.is_none();
+        // Towns are registered in capitals, e.g. `BODØ`
+        let city = entity
+            .forretningsadresse
+            .as_ref()
+            .and_then(|address| address.poststed.as_deref())
+            .map(title_case);
+        let address = entity.forretningsadresse.map(|address| {
+            let post = [address.postnummer, city.clone()].into_iter().flatten().collect::<Vec<_>>().join(" ");
+            address
+                .adresse
+                .into_iter()
+                .chain(Some(post))
+                .filter(|line| !line.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+
+        Self {
+            org_number: entity.organisasjonsnummer,
+            name: entity.navn,
+            address: address.filter(|address| !address.is_empty()),
+            city,
+            website: entity.hjemmeside.filter(|site| !site.trim().is_empty()).map(with_scheme),
+            industry_code: entity.naeringskode1.as_ref().map(|code| code.kode.clone()),
+            industry_description: entity.naeringskode1.and_then(|code| code.beskrivelse),
+            is_active,
+        }
+    }
+}
+
+fn title_case(text: &str) -> String {
+    text.split(' ')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// The register leaves off the scheme, e.g. `www.mowi.com`
+fn with_scheme(site: String) -> String {
+    if site.starts_with("http://") || site.starts_with("https://") {
+        site
+    } else {
+        format!("https://{}", site)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BrregCompanyRegistry {
+    client: reqwest::Client,
+    url: String,
+    resilience: Resilience,
+}
+
+impl BrregCompanyRegistry {
+    pub fn new(url: impl Into<String>) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(Self {
+            client,
+            url: url.into().trim_end_matches('/').to_string(),
+            resilience: Resilience::default().with_retry(lookup_retry()),
+        })
+    }
+
+    /// Shares breaker state with the other outbound adapters
+    pub fn with_resilience(mut self, resilience: Resilience) -> Self {
+        self.resilience = resilience.with_retry(lookup_retry());
+        self
+    }
+}
+
+// Lookups run while an administrator fills in the company form
+fn lookup_retry() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 2,
+        budget: Duration::from_secs(8),
+        ..RetryPolicy::default()
+    }
+}
+
+#[async_trait]
+impl CompanyRegistry for BrregCompanyRegistry {
+    async fn lookup(&self, org_number: &str) -> DomainResult<Option<RegisteredCompany>> {
+        let url = format!("{}/enheter/{}", self.url, org_number);
+
+        let entity: Option<BrregEntity> = self
+            .resilience
+            .call(&format!("brreg:{}", destination_host(&self.url)), || async {
+                let response = self
+                    .client
+                    .get(&url)
+                    .header(reqwest::header::ACCEPT, "application/json")
+                    .send()
+                    .await
+                    .map_err(CallError::from_reqwest)?;
+                // 410 Gone is a deleted company, which can't be added either
+                match response.status() {
+                    reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::GONE => return Ok(None),
+                    status if !status.is_success() => return Err(CallError::from_status(status)),
+                    _ => {}
+                }
+                response.json().await.map(Some).map_err(CallError::from_reqwest)
+            })
+            .await
+            .map_err(|e| DomainError::external_service("Brønnøysundregistrene", &e.to_string()))?;
+
+        debug!("Business register lookup of {}: {}", org_number, if entity.is_some() { "found" } else { "not found" });
+        Ok(entity.map(RegisteredCompany::from))
+    }
+}
+
+/// How long lookups are kept before the register is asked again
+#[derive(Debug, Clone, Copy)]
+pub struct RegistryCachePolicy {
+    pub found_for: Duration,
+    /// Numbers not in the register are kept shorter, as new companies appear
+    pub missing_for: Duration,
+    /// Lookups held at most; the oldest go first
+    pub capacity: usize,
+}
+
+impl Default for RegistryCachePolicy {
+    fn default() -> Self {
+        Self {
+            found_for: Duration::from_secs(24 * 60 * 60),
+            missing_for: Duration::from_secs(60 * 60),
+            capacity: 1000,
+        }
+    }
+}
+
+struct CachedLookup {
+    company: Option<RegisteredCompany>,
+    fetched_at: Instant,
+}
+
+/// Caches another registry's answers. Expired answers are kept, and served
+/// when the register can't be reached, so a company looked up before still
+/// fills in while it's down.
+#[derive(Clone)]
+pub struct CachedCompanyRegistry<R> {
+    inner: R,
+    policy: RegistryCachePolicy,
+    lookups: Arc<Mutex<HashMap<String, CachedLookup>>>,
+}
+
+impl<R: CompanyRegistry> CachedCompanyRegistry<R> {
+    pub fn new(inner: R) -> Self {
+        Self::with_policy(inner, RegistryCachePolicy::default())
+    }
+
+    pub fn with_policy(inner: R, policy: RegistryCachePolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            lookups: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn is_fresh(&self, lookup: &CachedLookup, now: Instant) -> bool {
+        let ttl = if lookup.company.is_some() { self.policy.found_for } else { self.policy.missing_for };
+        now.duration_since(lookup.fetched_at) < ttl
+    }
+
+    fn remember(&self, org_number: &str, company: Option<RegisteredCompany>) {
+        let mut lookups = self.lookups.lock().expect("registry cache lock poisoned");
+        if lookups.len() >= self.policy.capacity && !lookups.contains_key(org_number) {
+            let oldest = lookups
+                .iter()
+                .min_by_key(|(_, lookup)| lookup.fetched_at)
+                .map(|(org_number, _)| org_number.clone());
+            if let Some(oldest) = oldest {
+                lookups.remove(&oldest);
+            }
+        }
+        lookups.insert(
+            org_number.to_string(),
+            CachedLookup {
+                company,
+                fetched_at: Instant::now(),
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl<R: CompanyRegistry> CompanyRegistry for CachedCompanyRegistry<R> {
+    async fn lookup(&self, org_number: &str) -> DomainResult<Option<RegisteredCompany>> {
+        let cached = {
+            let lookups = self.lookups.lock().expect("registry cache lock poisoned");
+            lookups
+                .get(org_number)
+                .map(|lookup| (self.is_fresh(lookup, Instant::now()), lookup.company.clone()))
+        };
+        if let Some((true, company)) = cached {
+            return Ok(company);
+        }
+
+        match self.inner.lookup(org_number).await {
+            Ok(company) => {
+                self.remember(org_number, company.clone());
+                Ok(company)
+            }
+            Err(e) => match cached {
+                Some((_, company)) => {
+                    warn!("Business register unavailable, using the earlier lookup of {}: {}", org_number, e);
+                    Ok(company)
+                }
+                None => Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[derive(Clone, Default)]
+    struct StubRegistry {
+        calls: Arc<AtomicUsize>,
+        offline: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl CompanyRegistry for StubRegistry {
+        async fn lookup(&self, org_number: &str) -> DomainResult<Option<RegisteredCompany>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.offline.load(Ordering::SeqCst) {
+                return Err(DomainError::external_service("Brønnøysundregistrene", "unreachable"));
+            }
+            Ok((org_number == "923609016").then(|| RegisteredCompany {
+                org_number: org_number.to_string(),
+                name: "EQUINOR ASA".to_string(),
+                address: None,
+                city: Some("Stavanger".to_string()),
+                website: None,
+                industry_code: None,
+                industry_description: None,
+                is_active: true,
+            }))
+        }
+    }
+
+    #[test]
+    fn test_register_entities_are_mapped() {
+        let entity: BrregEntity = serde_json::from_str(
+            r#"{
+                "organisasjonsnummer": "923609016",
+                "navn": "EQUINOR ASA",
+                "hjemmeside": "www.equinor.com",
+                "forretningsadresse": {"adresse": ["Forusbeen 50"], "postnummer": "4035", "poststed": "STAVANGER"},
+                "naeringskode1": {"kode": "06.100", "beskrivelse": "Utvinning av råolje"},
+                "konkurs": false,
+                "underAvvikling": false,
+                "underTvangsavviklingEllerTvangsopplosning": false
+            }"#,
+        )
+        .unwrap();
+        let company = RegisteredCompany::from(entity);
+        assert_eq!(company.address.as_deref(), Some("Forusbeen 50, 4035 Stavanger"));
+        assert_eq!(company.city.as_deref(), Some("Stavanger"));
+        assert_eq!(company.website.as_deref(), Some("https://www.equinor.com"));
+        assert_eq!(company.industry_code.as_deref(), Some("06.100"));
+        assert!(company.is_active);
+
+        let bankrupt: BrregEntity =
+            serde_json::from_str(r#"{"organisasjonsnummer": "974760673", "navn": "KONKURS AS", "konkurs": true}"#).unwrap();
+        let company = RegisteredCompany::from(bankrupt);
+        assert!(!company.is_active);
+        assert_eq!((company.address, company.city), (None, None));
+        assert_eq!(title_case("MO I RANA"), "Mo I Rana");
+    }
+
+    #[tokio::test]
+    async fn test_lookups_are_cached_and_served_stale_when_the_register_is_down() {
+        let stub = StubRegistry::default();
+        let cached = CachedCompanyRegistry::new(stub.clone());
+
+        assert!(cached.lookup("923609016").await.unwrap().is_some());
+        assert!(cached.lookup("923609016").await.unwrap().is_some());
+        assert!(cached.lookup("974760673").await.unwrap().is_none());
+        assert!(cached.lookup("974760673").await.unwrap().is_none());
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+
+        // Everything expires at once, so each lookup goes to the register
+        let expiring = CachedCompanyRegistry::with_policy(
+            stub.clone(),
+            RegistryCachePolicy {
+                found_for: Duration::ZERO,
+                missing_for: Duration::ZERO,
+                capacity: 1,
+            },
+        );
+        assert!(expiring.lookup("923609016").await.unwrap().is_some());
+        stub.offline.store(true, Ordering::SeqCst);
+        assert!(expiring.lookup("923609016").await.unwrap().is_some());
+        assert!(expiring.lookup("974760673").await.is_err());
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 5);
+    }
+}
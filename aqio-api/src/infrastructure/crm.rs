@@ -0,0 +1,420 @@
+// CRM connectors' calls to HubSpot and Microsoft Dynamics 365
+// HubSpot is called through its CRM v3 objects API with a private app token.
+// Dynamics goes through the Dataverse Web API of the connector's organization,
+// with an app-only token from Entra ID that is cached until shortly before it
+// expires. Values are read and written as text; Dynamics columns mapped to
+// should be text columns.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use aqio_core::{CrmClient, CrmConnector, CrmProvider, CrmRecord, DomainError, DomainResult};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::time::Instant;
+use tracing::debug;
+
+use crate::config::{CrmConfig, DynamicsCredentials};
+use crate::infrastructure::resilience::{CallError, OutboundError, Resilience, destination_host};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// HubSpot's API, for connectors without a base URL
+pub const DEFAULT_HUBSPOT_URL: &str = "https://api.hubapi.com";
+
+/// Tokens are renewed this long before Entra ID says they expire
+const TOKEN_MARGIN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpCrmClient {
+    client: reqwest::Client,
+    config: CrmConfig,
+    resilience: Resilience,
+    /// Dynamics tokens by organization URL
+    tokens: Arc<Mutex<HashMap<String, (String, Instant)>>>,
+}
+
+impl HttpCrmClient {
+    pub fn new(config: CrmConfig) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(Self {
+            client,
+            config,
+            resilience: Resilience::default(),
+            tokens: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    /// Shares retry and breaker state with the other outbound adapters
+    pub fn with_resilience(mut self, resilience: Resilience) -> Self {
+        self.resilience = resilience;
+        self
+    }
+
+    /// The bearer token for `connector`, and the URL its API calls start with
+    async fn session(&self, connector: &CrmConnector) -> DomainResult<(String, String)> {
+        // Connectors are validated when saved; this keeps the server's
+        // credentials from ever going anywhere else
+        if let Some(url) = connector.base_url.as_deref().filter(|url| !connector.provider.allows_url(url)) {
+            return Err(DomainError::business_rule(&format!("{:?} connectors cannot call {}", connector.provider, url)));
+        }
+        match connector.provider {
+            CrmProvider::HubSpot => {
+                let Some(token) = &self.config.hubspot_access_token else {
+                    return Err(DomainError::business_rule(
+                        "HubSpot connectors are not set up on this server (HUBSPOT_ACCESS_TOKEN)",
+                    ));
+                };
+                let base = connector.base_url.as_deref().unwrap_or(DEFAULT_HUBSPOT_URL);
+                Ok((token.clone(), format!("{}/crm/v3/objects", base.trim_end_matches('/'))))
+            }
+            CrmProvider::Dynamics => {
+                let Some(credentials) = &self.config.dynamics else {
+                    return Err(DomainError::business_rule(
+                        "Dynamics connectors are not set up on this server (DYNAMICS_CLIENT_ID)",
+                    ));
+                };
+                let organization = connector.base_url.as_deref().unwrap_or_default().trim_end_matches('/');
+                let token = self
+                    .dynamics_token(credentials, organization)
+                    .await
+                    .map_err(|e| DomainError::external_service("Microsoft Entra ID", &e.to_string()))?;
+                Ok((token, format!("{}/api/data/v9.2", organization)))
+            }
+        }
+    }
+
+    async fn dynamics_token(&self, credentials: &DynamicsCredentials, organization: &str) -> Result<String, OutboundError> {
+        if let Some((token, expires)) = self.tokens.lock().expect("CRM token cache lock poisoned").get(organization) {
+            if *expires > Instant::now() {
+                return Ok(token.clone());
+            }
+        }
+
+        let url = format!("https://login.microsoftonline.com/{}/oauth2/v2.0/token", credentials.tenant_id);
+        let scope = format!("{}/.default", organization);
+        let form = [
+            ("grant_type", "client_credentials"),
+            ("client_id", credentials.client_id.as_str()),
+            ("client_secret", credentials.client_secret.as_str()),
+            ("scope", scope.as_str()),
+        ];
+        let response = self
+            .send(&url, || self.client.post(&url).form(&form))
+            .await?
+            .ok_or_else(|| OutboundError::Rejected(format!("no token endpoint for tenant {}", credentials.tenant_id)))?;
+        let token: TokenResponse = response.json().await.map_err(|e| OutboundError::Rejected(e.to_string()))?;
+
+        let expires = Instant::now() + Duration::from_secs(token.expires_in).saturating_sub(TOKEN_MARGIN);
+        self.tokens
+            .lock()
+            .expect("CRM token cache lock poisoned")
+            .insert(organization.to_string(), (token.access_token.clone(), expires));
+        Ok(token.access_token)
+    }
+
+    /// Sends the request `build` makes, retrying it as a whole; `None` when
+    /// the CRM answers 404
+    async fn send(
+        &self,
+        url: &str,
+        build: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<Option<reqwest::Response>, OutboundError> {
+        self.resilience
+            .call(&destination_host(url), || async {
+                let response = build().send().await.map_err(CallError::from_reqwest)?;
+                match response.status() {
+                    reqwest::StatusCode::NOT_FOUND => Ok(None),
+                    status if status.is_success() => Ok(Some(response)),
+                    status => match CallError::from_status(status) {
+                        // Both CRMs say which property or value they refused
+                        CallError::Permanent(message) => {
+                            let body = response.text().await.unwrap_or_default();
+                            Err(CallError::Permanent(format!("{}: {}", message, truncate(&body))))
+                        }
+                        transient => Err(transient),
+                    },
+                }
+            })
+            .await
+    }
+
+    fn request(
+        &self,
+        connector: &CrmConnector,
+        method: reqwest::Method,
+        url: &str,
+        token: &str,
+    ) -> reqwest::RequestBuilder {
+        let request = self.client.request(method, url).bearer_auth(token);
+        match connector.provider {
+            CrmProvider::HubSpot => request,
+            CrmProvider::Dynamics => request
+                .header("Accept", "application/json")
+                .header("OData-MaxVersion", "4.0")
+                .header("OData-Version", "4.0"),
+        }
+    }
+}
+
+fn provider_name(provider: CrmProvider) -> &'static str {
+    match provider {
+        CrmProvider::HubSpot => "HubSpot",
+        CrmProvider::Dynamics => "Dynamics 365",
+    }
+}
+
+fn truncate(body: &str) -> String {
+    body.trim().chars().take(300).collect()
+}
+
+/// The requested `properties` of a JSON object, as text. Empty values are
+/// left out, so they read the same as properties the CRM doesn't have.
+fn properties_of(object: &Value, properties: &[String]) -> BTreeMap<String, String> {
+    properties
+        .iter()
+        .filter_map(|property| {
+            let value = match object.get(property)? {
+                Value::Null => return None,
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            Some((property.clone(), value)).filter(|(_, value)| !value.is_empty())
+        })
+        .collect()
+}
+
+fn hubspot_record(object: &Value, properties: &[String]) -> Option<CrmRecord> {
+    Some(CrmRecord {
+        id: object.get("id")?.as_str()?.to_string(),
+        properties: properties_of(object.get("properties")?, properties),
+    })
+}
+
+/// The ID in the `OData-EntityId` header Dynamics answers creates with, e.g.
+/// `https://contoso.crm4.dynamics.com/api/data/v9.2/contacts(00000000-...)`
+fn entity_id(header: &str) -> Option<String> {
+    let (_, id) = header.rsplit_once('(')?;
+    id.strip_suffix(')').map(str::to_string)
+}
+
+/// A string literal for an OData `$filter`
+fn odata_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[async_trait]
+impl CrmClient for HttpCrmClient {
+    async fn find_contact(
+        &self,
+        connector: &CrmConnector,
+        email: &str,
+        properties: &[String],
+    ) -> DomainResult<Option<CrmRecord>> {
+        let (token, base) = self.session(connector).await?;
+        let object = connector.provider.contact_object();
+        let result = match connector.provider {
+            CrmProvider::HubSpot => {
+                let url = format!("{}/{}/search", base, object);
+                let search = json!({
+                    "filterGroups": [{
+                        "filters": [{ "propertyName": "email", "operator": "EQ", "value": email }],
+                    }],
+                    "properties": properties,
+                    "limit": 1,
+                });
+                let response = self
+                    .send(&url, || self.request(connector, reqwest::Method::POST, &url, &token).json(&search))
+                    .await;
+                match response {
+                    Ok(Some(response)) => response.json::<Value>().await.map_err(|e| OutboundError::Rejected(e.to_string())),
+                    Ok(None) => Ok(Value::Null),
+                    Err(e) => Err(e),
+                }
+                .map(|found| found["results"].get(0).and_then(|contact| hubspot_record(contact, properties)))
+            }
+            CrmProvider::Dynamics => {
+                let url = format!("{}/{}", base, object);
+                let filter = format!("{} eq {}", connector.provider.email_property(), odata_string(email));
+                let select = std::iter::once("contactid")
+                    .chain(properties.iter().map(String::as_str))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let query = [("$filter", filter.as_str()), ("$select", select.as_str()), ("$top", "1")];
+                let response = self
+                    .send(&url, || self.request(connector, reqwest::Method::GET, &url, &token).query(&query))
+                    .await;
+                match response {
+                    Ok(Some(response)) => response.json::<Value>().await.map_err(|e| OutboundError::Rejected(e.to_string())),
+                    Ok(None) => Ok(Value::Null),
+                    Err(e) => Err(e),
+                }
+                .map(|found| {
+                    let contact = found["value"].get(0)?;
+                    Some(CrmRecord {
+                        id: contact.get("contactid")?.as_str()?.to_string(),
+                        properties: properties_of(contact, properties),
+                    })
+                })
+            }
+        };
+        result.map_err(|e| DomainError::external_service(provider_name(connector.provider), &e.to_string()))
+    }
+
+    async fn get(
+        &self,
+        connector: &CrmConnector,
+        object: &str,
+        id: &str,
+        properties: &[String],
+    ) -> DomainResult<Option<CrmRecord>> {
+        let (token, base) = self.session(connector).await?;
+        let (url, query) = match connector.provider {
+            CrmProvider::HubSpot => (format!("{}/{}/{}", base, object, id), [("properties", properties.join(","))]),
+            CrmProvider::Dynamics => (format!("{}/{}({})", base, object, id), [("$select", properties.join(","))]),
+        };
+        let response = self
+            .send(&url, || self.request(connector, reqwest::Method::GET, &url, &token).query(&query))
+            .await;
+        let result = match response {
+            Ok(Some(response)) => response.json::<Value>().await.map_err(|e| OutboundError::Rejected(e.to_string())),
+            Ok(None) => return Ok(None),
+            Err(e) => Err(e),
+        };
+        let found = result.map_err(|e| DomainError::external_service(provider_name(connector.provider), &e.to_string()))?;
+
+        Ok(match connector.provider {
+            CrmProvider::HubSpot => hubspot_record(&found, properties),
+            CrmProvider::Dynamics => Some(CrmRecord {
+                id: id.to_string(),
+                properties: properties_of(&found, properties),
+            }),
+        })
+    }
+
+    async fn create(
+        &self,
+        connector: &CrmConnector,
+        object: &str,
+        properties: &BTreeMap<String, String>,
+    ) -> DomainResult<String> {
+        let (token, base) = self.session(connector).await?;
+        let url = format!("{}/{}", base, object);
+        let body = match connector.provider {
+            CrmProvider::HubSpot => json!({ "properties": properties }),
+            CrmProvider::Dynamics => json!(properties),
+        };
+        let response = self
+            .send(&url, || self.request(connector, reqwest::Method::POST, &url, &token).json(&body))
+            .await
+            .map_err(|e| DomainError::external_service(provider_name(connector.provider), &e.to_string()))?;
+
+        let id = match (connector.provider, response) {
+            (CrmProvider::HubSpot, Some(response)) => response
+                .json::<Value>()
+                .await
+                .ok()
+                .and_then(|created| created.get("id")?.as_str().map(str::to_string)),
+            (CrmProvider::Dynamics, Some(response)) => response
+                .headers()
+                .get("OData-EntityId")
+                .and_then(|header| header.to_str().ok())
+                .and_then(entity_id),
+            (_, None) => None,
+        };
+        let id = id.ok_or_else(|| {
+            DomainError::external_service(
+                provider_name(connector.provider),
+                &format!("created a record of '{}' without saying its ID", object),
+            )
+        })?;
+        debug!("Created {} {} in {}", object, id, connector.name);
+        Ok(id)
+    }
+
+    async fn update(
+        &self,
+        connector: &CrmConnector,
+        object: &str,
+        id: &str,
+        properties: &BTreeMap<String, String>,
+    ) -> DomainResult<()> {
+        let (token, base) = self.session(connector).await?;
+        let (url, body) = match connector.provider {
+            CrmProvider::HubSpot => (format!("{}/{}/{}", base, object, id), json!({ "properties": properties })),
+            CrmProvider::Dynamics => (format!("{}/{}({})", base, object, id), json!(properties)),
+        };
+        let response = self
+            .send(&url, || {
+                let request = self.request(connector, reqwest::Method::PATCH, &url, &token).json(&body);
+                match connector.provider {
+                    CrmProvider::HubSpot => request,
+                    // Without it a PATCH to a deleted record creates it again
+                    CrmProvider::Dynamics => request.header("If-Match", "*"),
+                }
+            })
+            .await
+            .map_err(|e| DomainError::external_service(provider_name(connector.provider), &e.to_string()))?;
+
+        match response {
+            Some(_) => Ok(()),
+            None => Err(DomainError::external_service(
+                provider_name(connector.provider),
+                &format!("{} {} no longer exists", object, id),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_are_read_as_text_without_empty_values() {
+        let contact = json!({
+            "id": "451",
+            "properties": { "firstname": "Kari", "lastname": "", "phone": null, "hs_object_id": "451" },
+        });
+        let properties = vec!["firstname".to_string(), "lastname".to_string(), "phone".to_string()];
+        let record = hubspot_record(&contact, &properties).unwrap();
+        assert_eq!(record.id, "451");
+        assert_eq!(record.properties, BTreeMap::from([("firstname".to_string(), "Kari".to_string())]));
+
+        let registration = json!({ "@odata.etag": "W/\"1\"", "new_guests": 2, "new_checkedin": true });
+        let properties = vec!["new_guests".to_string(), "new_checkedin".to_string()];
+        assert_eq!(properties_of(&registration, &properties)["new_guests"], "2");
+        assert_eq!(properties_of(&registration, &properties)["new_checkedin"], "true");
+
+        assert_eq!(
+            entity_id("https://contoso.crm4.dynamics.com/api/data/v9.2/contacts(7d5b1e1a-0c2f-ef11-8409-000d3a4b2c11)"),
+            Some("7d5b1e1a-0c2f-ef11-8409-000d3a4b2c11".to_string())
+        );
+        assert_eq!(odata_string("o'neil@example.org"), "'o''neil@example.org'");
+    }
+
+    #[tokio::test]
+    async fn test_hubspot_token_is_only_sent_to_hubspot() {
+        let client = HttpCrmClient::new(CrmConfig {
+            hubspot_access_token: Some("pat-eu1-secret".to_string()),
+            dynamics: None,
+        })
+        .unwrap();
+        let mut connector = CrmConnector::new("HubSpot", CrmProvider::HubSpot, uuid::Uuid::new_v4(), chrono::Utc::now());
+
+        let (token, base) = client.session(&connector).await.unwrap();
+        assert_eq!(token, "pat-eu1-secret");
+        assert_eq!(base, "https://api.hubapi.com/crm/v3/objects");
+
+        connector.base_url = Some("https://crm.example.org".to_string());
+        assert!(client.session(&connector).await.is_err());
+    }
+}
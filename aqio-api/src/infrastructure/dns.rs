@@ -0,0 +1,149 @@
+// DNS lookups over HTTPS
+// Queries a DNS-over-HTTPS resolver's JSON API, so checking organizations'
+// records needs nothing beyond the HTTP client the API already has.
+
+use std::time::Duration;
+
+use aqio_core::{DnsRecordType, DnsResolver, DomainError, DomainResult};
+use async_trait::async_trait;
+use serde::Deserialize;
+use tracing::debug;
+
+use crate::infrastructure::resilience::{CallError, Resilience, RetryPolicy, destination_host};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Resolver used when `DNS_OVER_HTTPS_URL` isn't set
+pub const DEFAULT_DOH_URL: &str = "https://cloudflare-dns.com/dns-query";
+
+// Response codes from RFC 1035; NXDOMAIN just means there are no records
+const NOERROR: u16 = 0;
+const NXDOMAIN: u16 = 3;
+
+#[derive(Debug, Deserialize)]
+struct DohResponse {
+    #[serde(rename = "Status")]
+    status: u16,
+    #[serde(rename = "Answer", default)]
+    answer: Vec<DohAnswer>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DohAnswer {
+    #[serde(rename = "type")]
+    record_type: u16,
+    data: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct DohDnsResolver {
+    client: reqwest::Client,
+    url: String,
+    resilience: Resilience,
+}
+
+impl DohDnsResolver {
+    pub fn new(url: impl Into<String>) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(Self {
+            client,
+            url: url.into(),
+            resilience: Resilience::default().with_retry(lookup_retry()),
+        })
+    }
+
+    /// Shares breaker state with the other outbound adapters
+    pub fn with_resilience(mut self, resilience: Resilience) -> Self {
+        self.resilience = resilience.with_retry(lookup_retry());
+        self
+    }
+}
+
+// Lookups run while an organizer waits for a domain check
+fn lookup_retry() -> RetryPolicy {
+    RetryPolicy {
+        budget: Duration::from_secs(10),
+        ..RetryPolicy::default()
+    }
+}
+
+#[async_trait]
+impl DnsResolver for DohDnsResolver {
+    async fn lookup(&self, name: &str, record_type: DnsRecordType) -> DomainResult<Vec<String>> {
+        let (type_name, type_code) = match record_type {
+            DnsRecordType::Txt => ("TXT", 16),
+            DnsRecordType::Cname => ("CNAME", 5),
+        };
+        let unavailable = |e: String| DomainError::external_service("DNS", &e);
+
+        let response: DohResponse = self
+            .resilience
+            .call(&format!("dns:{}", destination_host(&self.url)), || async {
+                self.client
+                    .get(&self.url)
+                    .query(&[("name", name), ("type", type_name)])
+                    .header(reqwest::header::ACCEPT, "application/dns-json")
+                    .send()
+                    .await
+                    .and_then(reqwest::Response::error_for_status)
+                    .map_err(CallError::from_reqwest)?
+                    .json()
+                    .await
+                    .map_err(CallError::from_reqwest)
+            })
+            .await
+            .map_err(|e| unavailable(e.to_string()))?;
+
+        match response.status {
+            NXDOMAIN => return Ok(Vec::new()),
+            NOERROR => {}
+            status => return Err(unavailable(format!("lookup of {} failed with code {}", name, status))),
+        }
+
+        // Answers for a TXT query may include the CNAMEs followed on the way
+        let records: Vec<String> = response
+            .answer
+            .into_iter()
+            .filter(|answer| answer.record_type == type_code)
+            .map(|answer| match record_type {
+                DnsRecordType::Txt => join_txt_chunks(&answer.data),
+                DnsRecordType::Cname => answer.data.trim_end_matches('.').to_string(),
+            })
+            .collect();
+        debug!("{} {} record(s) at {}", records.len(), type_name, name);
+        Ok(records)
+    }
+}
+
+// TXT data arrives as one or more quoted strings that together form the value,
+// e.g. `"v=spf1 include:a" " ~all"`
+fn join_txt_chunks(data: &str) -> String {
+    if !data.trim_start().starts_with('"') {
+        return data.to_string();
+    }
+
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut chars = data.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => quoted = !quoted,
+            '\\' if quoted => value.extend(chars.next()),
+            c if quoted => value.push(c),
+            _ => {}
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_txt_chunks() {
+        assert_eq!(join_txt_chunks("\"v=spf1 include:spf.aqio.no\" \" ~all\""), "v=spf1 include:spf.aqio.no ~all");
+        assert_eq!(join_txt_chunks("\"say \\\"hi\\\"\""), "say \"hi\"");
+        assert_eq!(join_txt_chunks("aqio-verification=abc"), "aqio-verification=abc");
+    }
+}
@@ -0,0 +1,241 @@
+// Bounce and complaint notifications from the mail provider
+// Amazon SES publishes them through SNS, SendGrid through its Event Webhook.
+// Only permanent failures become delivery reports; the providers retry
+// temporary ones themselves.
+
+use std::time::Duration;
+
+use aqio_core::DeliveryFailure;
+use serde::Deserialize;
+use tracing::info;
+
+use crate::domain::services::DeliveryReport;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What an SNS delivery to the SES endpoint asks of us
+#[derive(Debug, Clone, PartialEq)]
+pub enum SesMessage {
+    /// A new subscription; SNS sends nothing until the URL has been visited
+    SubscriptionConfirmation { subscribe_url: String },
+    Reports(Vec<DeliveryReport>),
+}
+
+#[derive(Debug, Deserialize)]
+struct SnsEnvelope {
+    #[serde(rename = "Type")]
+    kind: String,
+    #[serde(rename = "Message", default)]
+    message: Option<String>,
+    #[serde(rename = "SubscribeURL", default)]
+    subscribe_url: Option<String>,
+}
+
+// Notifications use `notificationType`, configuration set event publishing `eventType`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SesNotification {
+    #[serde(alias = "eventType")]
+    notification_type: String,
+    #[serde(default)]
+    bounce: Option<SesBounce>,
+    #[serde(default)]
+    complaint: Option<SesComplaint>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SesBounce {
+    bounce_type: String,
+    #[serde(default)]
+    bounced_recipients: Vec<SesRecipient>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SesComplaint {
+    #[serde(default)]
+    complained_recipients: Vec<SesRecipient>,
+    #[serde(default)]
+    complaint_feedback_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SesRecipient {
+    email_address: String,
+    #[serde(default)]
+    diagnostic_code: Option<String>,
+}
+
+/// Reads an SNS HTTP delivery, or an SES notification sent as the raw message
+pub fn parse_ses(body: &str) -> Result<SesMessage, String> {
+    let value: serde_json::Value = serde_json::from_str(body).map_err(|e| format!("Invalid JSON: {}", e))?;
+    if value.get("Type").is_none() {
+        return parse_ses_notification(value).map(SesMessage::Reports);
+    }
+
+    let envelope: SnsEnvelope = serde_json::from_value(value).map_err(|e| format!("Invalid SNS message: {}", e))?;
+    match envelope.kind.as_str() {
+        "SubscriptionConfirmation" => envelope
+            .subscribe_url
+            .map(|subscribe_url| SesMessage::SubscriptionConfirmation { subscribe_url })
+            .ok_or_else(|| "Subscription confirmation without SubscribeURL".to_string()),
+        "Notification" => {
+            let message = envelope.message.ok_or_else(|| "Notification without Message".to_string())?;
+            let notification = serde_json::from_str(&message).map_err(|e| format!("Invalid SES notification: {}", e))?;
+            parse_ses_notification(notification).map(SesMessage::Reports)
+        }
+        _ => Ok(SesMessage::Reports(Vec::new())),
+    }
+}
+
+fn parse_ses_notification(value: serde_json::Value) -> Result<Vec<DeliveryReport>, String> {
+    let notification: SesNotification =
+        serde_json::from_value(value).map_err(|e| format!("Invalid SES notification: {}", e))?;
+
+    let reports = match (notification.notification_type.as_str(), notification.bounce, notification.complaint) {
+        ("Bounce", Some(bounce), _) if bounce.bounce_type == "Permanent" => bounce
+            .bounced_recipients
+            .into_iter()
+            .map(|recipient| DeliveryReport {
+                email: recipient.email_address,
+                failure: DeliveryFailure::Bounce,
+                detail: recipient.diagnostic_code,
+            })
+            .collect(),
+        ("Complaint", _, Some(complaint)) => complaint
+            .complained_recipients
+            .into_iter()
+            .map(|recipient| DeliveryReport {
+                email: recipient.email_address,
+                failure: DeliveryFailure::Complaint,
+                detail: complaint.complaint_feedback_type.clone(),
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    Ok(reports)
+}
+
+/// Visits an SNS subscription URL. Only HTTPS URLs on amazonaws.com are
+/// followed, so the endpoint can't be used to make us call anywhere else.
+pub async fn confirm_sns_subscription(subscribe_url: &str) -> Result<(), String> {
+    let url = reqwest::Url::parse(subscribe_url).map_err(|e| format!("Invalid SubscribeURL: {}", e))?;
+    let trusted = url.scheme() == "https" && url.host_str().is_some_and(|host| host.ends_with(".amazonaws.com"));
+    if !trusted {
+        return Err(format!("Refusing to confirm subscription at {}", url));
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    client
+        .get(url)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(|e| format!("Subscription confirmation failed: {}", e))?;
+    info!("Confirmed SNS subscription for SES notifications");
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct SendGridEvent {
+    email: String,
+    event: String,
+    /// `bounce` or `blocked` on bounce events; blocks are temporary
+    #[serde(rename = "type", default)]
+    bounce_type: Option<String>,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+/// Reads a batch from SendGrid's Event Webhook, skipping events other than
+/// bounces and spam reports
+pub fn parse_sendgrid(body: &str) -> Result<Vec<DeliveryReport>, String> {
+    let events: Vec<SendGridEvent> = serde_json::from_str(body).map_err(|e| format!("Invalid SendGrid events: {}", e))?;
+
+    Ok(events
+        .into_iter()
+        .filter_map(|event| {
+            let failure = match event.event.as_str() {
+                "bounce" if event.bounce_type.as_deref() != Some("blocked") => DeliveryFailure::Bounce,
+                "spamreport" => DeliveryFailure::Complaint,
+                _ => return None,
+            };
+            Some(DeliveryReport {
+                email: event.email,
+                failure,
+                detail: event.reason,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ses_reads_permanent_bounces_and_complaints() {
+        let bounce = serde_json::json!({
+            "notificationType": "Bounce",
+            "bounce": {
+                "bounceType": "Permanent",
+                "bouncedRecipients": [{ "emailAddress": "kari@example.no", "diagnosticCode": "smtp; 550 5.1.1 user unknown" }]
+            }
+        });
+        let envelope = serde_json::json!({ "Type": "Notification", "Message": bounce.to_string() });
+        assert_eq!(
+            parse_ses(&envelope.to_string()).unwrap(),
+            SesMessage::Reports(vec![DeliveryReport {
+                email: "kari@example.no".to_string(),
+                failure: DeliveryFailure::Bounce,
+                detail: Some("smtp; 550 5.1.1 user unknown".to_string()),
+            }])
+        );
+
+        let transient = serde_json::json!({
+            "notificationType": "Bounce",
+            "bounce": { "bounceType": "Transient", "bouncedRecipients": [{ "emailAddress": "ola@example.no" }] }
+        });
+        assert_eq!(parse_ses(&transient.to_string()).unwrap(), SesMessage::Reports(Vec::new()));
+
+        let complaint = serde_json::json!({
+            "eventType": "Complaint",
+            "complaint": { "complainedRecipients": [{ "emailAddress": "ola@example.no" }], "complaintFeedbackType": "abuse" }
+        });
+        let SesMessage::Reports(reports) = parse_ses(&complaint.to_string()).unwrap() else {
+            panic!("Expected reports");
+        };
+        assert_eq!(reports[0].failure, DeliveryFailure::Complaint);
+        assert_eq!(reports[0].detail.as_deref(), Some("abuse"));
+
+        let confirmation = serde_json::json!({
+            "Type": "SubscriptionConfirmation",
+            "SubscribeURL": "https://sns.eu-north-1.amazonaws.com/?Action=ConfirmSubscription"
+        });
+        assert!(matches!(
+            parse_ses(&confirmation.to_string()).unwrap(),
+            SesMessage::SubscriptionConfirmation { .. }
+        ));
+        assert!(parse_ses("not json").is_err());
+    }
+
+    #[test]
+    fn test_parse_sendgrid_skips_blocks_and_other_events() {
+        let events = serde_json::json!([
+            { "email": "kari@example.no", "event": "bounce", "type": "bounce", "reason": "550 5.1.1 user unknown" },
+            { "email": "ola@example.no", "event": "bounce", "type": "blocked" },
+            { "email": "ola@example.no", "event": "delivered" },
+            { "email": "per@example.no", "event": "spamreport" }
+        ]);
+        let reports = parse_sendgrid(&events.to_string()).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].email, "kari@example.no");
+        assert_eq!(reports[0].failure, DeliveryFailure::Bounce);
+        assert_eq!(reports[1].failure, DeliveryFailure::Complaint);
+    }
+}
@@ -0,0 +1,304 @@
+// Public transport journeys from Entur
+// Asks Entur's Journey Planner (GraphQL) for trips between two coordinates.
+// It covers every operator in Norway, including the ferries and express
+// boats coastal venues are reached by. Entur asks clients to name themselves
+// in the `ET-Client-Name` header.
+
+use std::time::Duration;
+
+use aqio_core::{DomainError, DomainResult, TravelLeg, TravelMode, TravelOption, TravelPlanner, TravelQuery};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+use crate::infrastructure::resilience::{CallError, Resilience, RetryPolicy, destination_host};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Journey Planner used when `ENTUR_API_URL` isn't set
+pub const DEFAULT_ENTUR_API_URL: &str = "https://api.entur.io/journey-planner/v3/graphql";
+
+/// Client name sent when `ENTUR_CLIENT_NAME` isn't set
+pub const DEFAULT_ENTUR_CLIENT_NAME: &str = "aqio-api";
+
+const TRIP_QUERY: &str = r#"
+query ($from: Location!, $to: Location!, $dateTime: DateTime!, $arriveBy: Boolean!, $numTripPatterns: Int!) {
+  trip(from: $from, to: $to, dateTime: $dateTime, arriveBy: $arriveBy, numTripPatterns: $numTripPatterns) {
+    tripPatterns {
+      aimedStartTime
+      aimedEndTime
+      duration
+      walkDistance
+      legs {
+        mode
+        aimedStartTime
+        aimedEndTime
+        distance
+        fromPlace { name }
+        toPlace { name }
+        line { publicCode name }
+        operator { name }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse {
+    data: Option<TripData>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TripData {
+    trip: Trip,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Trip {
+    trip_patterns: Vec<EnturTripPattern>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnturTripPattern {
+    aimed_start_time: DateTime<Utc>,
+    aimed_end_time: DateTime<Utc>,
+    /// Seconds
+    duration: i64,
+    #[serde(default)]
+    walk_distance: f64,
+    legs: Vec<EnturLeg>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnturLeg {
+    mode: String,
+    aimed_start_time: DateTime<Utc>,
+    aimed_end_time: DateTime<Utc>,
+    #[serde(default)]
+    distance: f64,
+    from_place: EnturPlace,
+    to_place: EnturPlace,
+    line: Option<EnturLine>,
+    operator: Option<EnturName>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnturPlace {
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnturLine {
+    public_code: Option<String>,
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnturName {
+    name: String,
+}
+
+fn travel_mode(mode: &str) -> TravelMode {
+    match mode {
+        "foot" => TravelMode::Foot,
+        "bus" | "trolleybus" => TravelMode::Bus,
+        "coach" => TravelMode::Coach,
+        "tram" => TravelMode::Tram,
+        "metro" => TravelMode::Metro,
+        "rail" => TravelMode::Rail,
+        "water" => TravelMode::Water,
+        "air" => TravelMode::Air,
+        _ => TravelMode::Other,
+    }
+}
+
+impl From<EnturTripPattern> for TravelOption {
+    fn from(pattern: EnturTripPattern) -> Self {
+        Self {
+            departure: pattern.aimed_start_time,
+            arrival: pattern.aimed_end_time,
+            duration_minutes: (pattern.duration + 59) / 60,
+            walk_distance_m: pattern.walk_distance.round(),
+            legs: pattern.legs.into_iter().map(TravelLeg::from).collect(),
+        }
+    }
+}
+
+impl From<EnturLeg> for TravelLeg {
+    fn from(leg: EnturLeg) -> Self {
+        // Ferries often have no public code, only a route name
+        let line = leg
+            .line
+            .and_then(|line| line.public_code.filter(|code| !code.is_empty()).or(line.name));
+        Self {
+            mode: travel_mode(&leg.mode),
+            from_place: leg.from_place.name.unwrap_or_default(),
+            to_place: leg.to_place.name.unwrap_or_default(),
+            departure: leg.aimed_start_time,
+            arrival: leg.aimed_end_time,
+            line,
+            operator: leg.operator.map(|operator| operator.name),
+            distance_m: leg.distance.round(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EnturTravelPlanner {
+    client: reqwest::Client,
+    url: String,
+    client_name: String,
+    resilience: Resilience,
+}
+
+impl EnturTravelPlanner {
+    /// `client_name` identifies the application to Entur, e.g. `aqio-api`
+    pub fn new(url: impl Into<String>, client_name: impl Into<String>) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(Self {
+            client,
+            url: url.into(),
+            client_name: client_name.into(),
+            resilience: Resilience::default().with_retry(planner_retry()),
+        })
+    }
+
+    /// Shares breaker state with the other outbound adapters
+    pub fn with_resilience(mut self, resilience: Resilience) -> Self {
+        self.resilience = resilience.with_retry(planner_retry());
+        self
+    }
+}
+
+// Journeys are planned while an attendee waits for them
+fn planner_retry() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 2,
+        budget: Duration::from_secs(12),
+        ..RetryPolicy::default()
+    }
+}
+
+#[async_trait]
+impl TravelPlanner for EnturTravelPlanner {
+    async fn plan(&self, query: &TravelQuery) -> DomainResult<Vec<TravelOption>> {
+        let body = json!({
+            "query": TRIP_QUERY,
+            "variables": {
+                "from": {"coordinates": {"latitude": query.from_latitude, "longitude": query.from_longitude}},
+                "to": {"coordinates": {"latitude": query.to_latitude, "longitude": query.to_longitude}},
+                "dateTime": query.time.to_rfc3339(),
+                "arriveBy": query.arrive_by,
+                "numTripPatterns": query.limit,
+            },
+        });
+
+        let response: GraphQlResponse = self
+            .resilience
+            .call(&format!("entur:{}", destination_host(&self.url)), || async {
+                let response = self
+                    .client
+                    .post(&self.url)
+                    .header("ET-Client-Name", &self.client_name)
+                    .json(&body)
+                    .send()
+                    .await
+                    .map_err(CallError::from_reqwest)?;
+                if !response.status().is_success() {
+                    return Err(CallError::from_status(response.status()));
+                }
+                response.json().await.map_err(CallError::from_reqwest)
+            })
+            .await
+            .map_err(|e| DomainError::external_service("Entur", &e.to_string()))?;
+
+        let Some(data) = response.data else {
+            let message = response
+                .errors
+                .into_iter()
+                .map(|error| error.message)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(DomainError::external_service("Entur", &message));
+        };
+        let mut options: Vec<TravelOption> = data.trip.trip_patterns.into_iter().map(TravelOption::from).collect();
+        options.sort_by_key(|option| option.departure);
+        options.truncate(query.limit as usize);
+        debug!(
+            "Entur planned {} journeys to {:.4},{:.4}",
+            options.len(),
+            query.to_latitude,
+            query.to_longitude
+        );
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trip_patterns_are_mapped() {
+        let response: GraphQlResponse = serde_json::from_str(
+            r#"{"data": {"trip": {"tripPatterns": [{
+                "aimedStartTime": "2026-06-10T07:05:00+02:00",
+                "aimedEndTime": "2026-06-10T08:31:00+02:00",
+                "duration": 5130,
+                "walkDistance": 412.37,
+                "legs": [
+                    {"mode": "foot", "aimedStartTime": "2026-06-10T07:05:00+02:00", "aimedEndTime": "2026-06-10T07:12:00+02:00",
+                     "distance": 402.1, "fromPlace": {"name": null}, "toPlace": {"name": "Bergen busstasjon"},
+                     "line": null, "operator": null},
+                    {"mode": "bus", "aimedStartTime": "2026-06-10T07:15:00+02:00", "aimedEndTime": "2026-06-10T07:50:00+02:00",
+                     "distance": 21000.0, "fromPlace": {"name": "Bergen busstasjon"}, "toPlace": {"name": "Halhjem ferjekai"},
+                     "line": {"publicCode": "600", "name": "Bergen - Stord"}, "operator": {"name": "Skyss"}},
+                    {"mode": "water", "aimedStartTime": "2026-06-10T07:55:00+02:00", "aimedEndTime": "2026-06-10T08:31:00+02:00",
+                     "distance": 15000.0, "fromPlace": {"name": "Halhjem ferjekai"}, "toPlace": {"name": "Sandvikvåg ferjekai"},
+                     "line": {"publicCode": "", "name": "Halhjem - Sandvikvåg"}, "operator": {"name": "Fjord1"}}
+                ]
+            }]}}}"#,
+        )
+        .unwrap();
+        let option = TravelOption::from(response.data.unwrap().trip.trip_patterns.into_iter().next().unwrap());
+
+        assert_eq!(option.departure.to_rfc3339(), "2026-06-10T05:05:00+00:00");
+        assert_eq!((option.duration_minutes, option.walk_distance_m), (86, 412.0));
+        assert_eq!(option.legs[0].from_place, "");
+        assert_eq!(option.legs[1].line.as_deref(), Some("600"));
+        assert_eq!(option.legs[2].mode, TravelMode::Water);
+        assert_eq!(option.legs[2].line.as_deref(), Some("Halhjem - Sandvikvåg"));
+        assert_eq!(option.legs[2].operator.as_deref(), Some("Fjord1"));
+        assert!(option.uses_ferry());
+        assert_eq!(option.transfers(), 1);
+    }
+
+    #[test]
+    fn test_graphql_errors_have_no_data() {
+        let response: GraphQlResponse =
+            serde_json::from_str(r#"{"errors": [{"message": "Location not found"}]}"#).unwrap();
+        assert!(response.data.is_none());
+        assert_eq!(response.errors[0].message, "Location not found");
+    }
+
+    #[test]
+    fn test_unknown_modes_are_other() {
+        assert_eq!(travel_mode("water"), TravelMode::Water);
+        assert_eq!(travel_mode("trolleybus"), TravelMode::Bus);
+        assert_eq!(travel_mode("cableway"), TravelMode::Other);
+    }
+}
@@ -0,0 +1,97 @@
+// Outgoing email
+// Sends through SendGrid's v3 mail API when an API key is configured; without
+// one, emails are only written to the log so development setups need no
+// mail account.
+
+use std::time::Duration;
+
+use aqio_core::{DomainError, DomainResult, EmailMessage, EmailSender, Mailer};
+use async_trait::async_trait;
+use serde_json::json;
+use tracing::{debug, info};
+
+use crate::infrastructure::resilience::{CallError, Resilience};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+const SENDGRID_SEND_URL: &str = "https://api.sendgrid.com/v3/mail/send";
+
+#[derive(Debug, Clone)]
+pub struct SendGridMailer {
+    client: reqwest::Client,
+    api_key: String,
+    from: String,
+    resilience: Resilience,
+}
+
+impl SendGridMailer {
+    pub fn new(api_key: impl Into<String>, from: impl Into<String>) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(Self {
+            client,
+            api_key: api_key.into(),
+            from: from.into(),
+            resilience: Resilience::default(),
+        })
+    }
+
+    /// Shares retry and breaker state with the other outbound adapters
+    pub fn with_resilience(mut self, resilience: Resilience) -> Self {
+        self.resilience = resilience;
+        self
+    }
+}
+
+#[async_trait]
+impl Mailer for SendGridMailer {
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()> {
+        let from = match &message.from {
+            Some(EmailSender { email, name: Some(name) }) => json!({ "email": email, "name": name }),
+            Some(EmailSender { email, name: None }) => json!({ "email": email }),
+            None => json!({ "email": self.from }),
+        };
+        let payload = json!({
+            "personalizations": [{ "to": [{ "email": message.to }] }],
+            "from": from,
+            "subject": message.subject,
+            // SendGrid wants text/plain before text/html
+            "content": [
+                { "type": "text/plain", "value": message.text_body },
+                { "type": "text/html", "value": message.html_body },
+            ],
+        });
+
+        self.resilience
+            .call("sendgrid", || async {
+                let response = self
+                    .client
+                    .post(SENDGRID_SEND_URL)
+                    .bearer_auth(&self.api_key)
+                    .json(&payload)
+                    .send()
+                    .await
+                    .map_err(CallError::from_reqwest)?;
+                if !response.status().is_success() {
+                    return Err(CallError::from_status(response.status()));
+                }
+                Ok(())
+            })
+            .await
+            .map_err(|e| DomainError::external_service("SendGrid", &e.to_string()))?;
+
+        debug!("Sent '{}' to {}", message.subject, message.to);
+        Ok(())
+    }
+}
+
+/// Logs emails instead of sending them
+#[derive(Debug, Clone, Default)]
+pub struct LogMailer;
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()> {
+        info!("Email to {} (not sent, no mail provider configured): {}\n{}", message.to, message.subject, message.text_body);
+        Ok(())
+    }
+}
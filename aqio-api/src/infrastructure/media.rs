@@ -0,0 +1,111 @@
+// Media storage on the local filesystem
+// Every key maps to a file below one root directory (MEDIA_DIR)
+
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use aqio_core::{DomainError, DomainResult, MediaStorage};
+use async_trait::async_trait;
+use tracing::debug;
+
+#[derive(Debug, Clone)]
+pub struct LocalMediaStorage {
+    root: PathBuf,
+}
+
+impl LocalMediaStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    // Keys are built by the application, but never let one escape the root
+    fn path_for(&self, key: &str) -> DomainResult<PathBuf> {
+        let relative = Path::new(key);
+        let is_plain = !key.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !is_plain {
+            return Err(DomainError::validation("key", "Invalid media storage key"));
+        }
+        Ok(self.root.join(relative))
+    }
+
+    fn unavailable(error: io::Error) -> DomainError {
+        DomainError::system_unavailable(&format!("Media storage: {}", error))
+    }
+}
+
+#[async_trait]
+impl MediaStorage for LocalMediaStorage {
+    async fn store(&self, key: &str, bytes: &[u8]) -> DomainResult<()> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(Self::unavailable)?;
+        }
+
+        // Write next to the target and rename so readers never see a partial file
+        let partial = path.with_file_name(format!(
+            "{}.partial",
+            path.file_name().and_then(|name| name.to_str()).unwrap_or_default()
+        ));
+        tokio::fs::write(&partial, bytes).await.map_err(Self::unavailable)?;
+        tokio::fs::rename(&partial, &path).await.map_err(Self::unavailable)?;
+
+        debug!("Stored {} bytes at {}", bytes.len(), path.display());
+        Ok(())
+    }
+
+    async fn load(&self, key: &str) -> DomainResult<Vec<u8>> {
+        let path = self.path_for(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(bytes),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Err(DomainError::not_found_by_field("Media", "key", key))
+            }
+            Err(e) => Err(Self::unavailable(e)),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> DomainResult<()> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(Self::unavailable(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_store_load_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = LocalMediaStorage::new(dir.path());
+
+        storage.store("events/1/attachments/map.pdf", b"%PDF").await.unwrap();
+        assert_eq!(storage.load("events/1/attachments/map.pdf").await.unwrap(), b"%PDF");
+        assert!(dir.path().join("events/1/attachments/map.pdf").exists());
+
+        storage.delete("events/1/attachments/map.pdf").await.unwrap();
+        storage.delete("events/1/attachments/map.pdf").await.unwrap();
+        assert!(matches!(
+            storage.load("events/1/attachments/map.pdf").await,
+            Err(DomainError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_keys_cannot_escape_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = LocalMediaStorage::new(dir.path().join("media"));
+
+        for key in ["../outside.txt", "/etc/passwd", "events/../../outside.txt", ""] {
+            assert!(storage.store(key, b"x").await.is_err(), "accepted key {:?}", key);
+        }
+        assert!(!dir.path().join("outside.txt").exists());
+    }
+}
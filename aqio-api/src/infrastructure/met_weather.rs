@@ -0,0 +1,360 @@
+// Weather forecasts from MET Norway
+// Reads the Locationforecast API, behind a cache so an event page viewed by
+// many attendees asks for its venue's forecast once per day and refresh.
+// MET's terms require a User-Agent that identifies the application.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use aqio_core::{DomainError, DomainResult, WeatherForecast, WeatherForecaster};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use serde::Deserialize;
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+use crate::domain::weather::{ForecastStep, summarize_day};
+use crate::infrastructure::resilience::{CallError, Resilience, RetryPolicy, destination_host};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Forecast API used when `MET_API_URL` isn't set
+pub const DEFAULT_MET_API_URL: &str = "https://api.met.no/weatherapi/locationforecast/2.0";
+
+#[derive(Debug, Deserialize)]
+struct MetForecast {
+    properties: MetProperties,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetProperties {
+    timeseries: Vec<MetTimestep>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetTimestep {
+    time: DateTime<Utc>,
+    data: MetData,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetData {
+    instant: MetInstant,
+    next_1_hours: Option<MetPeriod>,
+    next_6_hours: Option<MetPeriod>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetInstant {
+    details: MetInstantDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetInstantDetails {
+    air_temperature: f64,
+    #[serde(default)]
+    wind_speed: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetPeriod {
+    summary: Option<MetSummary>,
+    details: Option<MetPeriodDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetSummary {
+    symbol_code: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetPeriodDetails {
+    precipitation_amount: Option<f64>,
+}
+
+impl From<MetTimestep> for ForecastStep {
+    fn from(step: MetTimestep) -> Self {
+        // Hourly periods are given for the first days, six-hour ones after
+        let (period, period_hours) = match (step.data.next_1_hours, step.data.next_6_hours) {
+            (Some(hour), _) => (Some(hour), 1),
+            (None, Some(six_hours)) => (Some(six_hours), 6),
+            (None, None) => (None, 0),
+        };
+        Self {
+            time: step.time,
+            air_temperature_c: step.data.instant.details.air_temperature,
+            wind_speed_ms: step.data.instant.details.wind_speed,
+            symbol_code: period.as_ref().and_then(|p| p.summary.as_ref()).map(|s| s.symbol_code.clone()),
+            precipitation_mm: period.and_then(|p| p.details).and_then(|d| d.precipitation_amount),
+            period_hours,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MetWeatherForecaster {
+    client: reqwest::Client,
+    url: String,
+    resilience: Resilience,
+}
+
+impl MetWeatherForecaster {
+    /// `user_agent` identifies the application to MET, e.g. `aqio.no support@aqio.no`
+    pub fn new(url: impl Into<String>, user_agent: &str) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(user_agent)
+            .build()?;
+        Ok(Self {
+            client,
+            url: url.into().trim_end_matches('/').to_string(),
+            resilience: Resilience::default().with_retry(forecast_retry()),
+        })
+    }
+
+    /// Shares breaker state with the other outbound adapters
+    pub fn with_resilience(mut self, resilience: Resilience) -> Self {
+        self.resilience = resilience.with_retry(forecast_retry());
+        self
+    }
+}
+
+// Forecasts are fetched while an event page loads
+fn forecast_retry() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 2,
+        budget: Duration::from_secs(8),
+        ..RetryPolicy::default()
+    }
+}
+
+#[async_trait]
+impl WeatherForecaster for MetWeatherForecaster {
+    async fn daily_forecast(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        date: NaiveDate,
+        timezone: Tz,
+    ) -> DomainResult<Option<WeatherForecast>> {
+        // MET refuses coordinates with more than four decimals
+        let url = format!("{}/compact?lat={:.4}&lon={:.4}", self.url, latitude, longitude);
+
+        let forecast: MetForecast = self
+            .resilience
+            .call(&format!("met:{}", destination_host(&self.url)), || async {
+                let response = self
+                    .client
+                    .get(&url)
+                    .header(reqwest::header::ACCEPT, "application/json")
+                    .send()
+                    .await
+                    .map_err(CallError::from_reqwest)?;
+                if !response.status().is_success() {
+                    return Err(CallError::from_status(response.status()));
+                }
+                response.json().await.map_err(CallError::from_reqwest)
+            })
+            .await
+            .map_err(|e| DomainError::external_service("MET Norway", &e.to_string()))?;
+
+        let steps: Vec<ForecastStep> = forecast.properties.timeseries.into_iter().map(ForecastStep::from).collect();
+        debug!("Weather forecast for {:.4},{:.4}: {} steps", latitude, longitude, steps.len());
+        Ok(summarize_day(&steps, date, timezone))
+    }
+}
+
+/// How long forecasts are kept before MET is asked again
+#[derive(Debug, Clone, Copy)]
+pub struct ForecastCachePolicy {
+    /// MET updates its forecasts about once an hour
+    pub fresh_for: Duration,
+    /// Forecasts held at most; the oldest go first
+    pub capacity: usize,
+}
+
+impl Default for ForecastCachePolicy {
+    fn default() -> Self {
+        Self {
+            fresh_for: Duration::from_secs(60 * 60),
+            capacity: 1000,
+        }
+    }
+}
+
+struct CachedForecast {
+    forecast: Option<WeatherForecast>,
+    fetched_at: Instant,
+}
+
+/// Caches another forecaster's answers per location and day. Locations are
+/// rounded to about a kilometre, so events at the same venue share an entry.
+/// Expired forecasts are served while MET can't be reached.
+#[derive(Clone)]
+pub struct CachedWeatherForecaster<F> {
+    inner: F,
+    policy: ForecastCachePolicy,
+    forecasts: Arc<Mutex<HashMap<String, CachedForecast>>>,
+}
+
+impl<F: WeatherForecaster> CachedWeatherForecaster<F> {
+    pub fn new(inner: F) -> Self {
+        Self::with_policy(inner, ForecastCachePolicy::default())
+    }
+
+    pub fn with_policy(inner: F, policy: ForecastCachePolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            forecasts: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn remember(&self, key: String, forecast: Option<WeatherForecast>) {
+        let mut forecasts = self.forecasts.lock().expect("forecast cache lock poisoned");
+        if forecasts.len() >= self.policy.capacity && !forecasts.contains_key(&key) {
+            let oldest = forecasts
+                .iter()
+                .min_by_key(|(_, cached)| cached.fetched_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                forecasts.remove(&oldest);
+            }
+        }
+        forecasts.insert(
+            key,
+            CachedForecast {
+                forecast,
+                fetched_at: Instant::now(),
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl<F: WeatherForecaster> WeatherForecaster for CachedWeatherForecaster<F> {
+    async fn daily_forecast(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        date: NaiveDate,
+        timezone: Tz,
+    ) -> DomainResult<Option<WeatherForecast>> {
+        let key = format!("{:.2},{:.2},{},{}", latitude, longitude, date, timezone);
+        let cached = {
+            let forecasts = self.forecasts.lock().expect("forecast cache lock poisoned");
+            forecasts
+                .get(&key)
+                .map(|cached| (cached.fetched_at.elapsed() < self.policy.fresh_for, cached.forecast.clone()))
+        };
+        if let Some((true, forecast)) = cached {
+            return Ok(forecast);
+        }
+
+        match self.inner.daily_forecast(latitude, longitude, date, timezone).await {
+            Ok(forecast) => {
+                self.remember(key, forecast.clone());
+                Ok(forecast)
+            }
+            Err(e) => match cached {
+                Some((_, forecast)) => {
+                    warn!("MET Norway unavailable, using the earlier forecast for {}: {}", key, e);
+                    Ok(forecast)
+                }
+                None => Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[derive(Clone, Default)]
+    struct StubForecaster {
+        calls: Arc<AtomicUsize>,
+        offline: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl WeatherForecaster for StubForecaster {
+        async fn daily_forecast(
+            &self,
+            _latitude: f64,
+            _longitude: f64,
+            date: NaiveDate,
+            _timezone: Tz,
+        ) -> DomainResult<Option<WeatherForecast>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.offline.load(Ordering::SeqCst) {
+                return Err(DomainError::external_service("MET Norway", "unreachable"));
+            }
+            Ok(Some(WeatherForecast {
+                date,
+                symbol_code: Some("rain".to_string()),
+                min_temperature_c: 8.0,
+                max_temperature_c: 12.0,
+                precipitation_mm: 4.2,
+                max_wind_speed_ms: 6.0,
+            }))
+        }
+    }
+
+    #[test]
+    fn test_met_timesteps_are_mapped() {
+        let forecast: MetForecast = serde_json::from_str(
+            r#"{"properties": {"timeseries": [
+                {"time": "2026-06-10T10:00:00Z", "data": {
+                    "instant": {"details": {"air_temperature": 14.2, "wind_speed": 3.1}},
+                    "next_1_hours": {"summary": {"symbol_code": "lightrain"}, "details": {"precipitation_amount": 0.3}},
+                    "next_6_hours": {"summary": {"symbol_code": "rain"}, "details": {"precipitation_amount": 2.0}}
+                }},
+                {"time": "2026-06-14T12:00:00Z", "data": {
+                    "instant": {"details": {"air_temperature": 16.0, "wind_speed": 5.0}},
+                    "next_6_hours": {"summary": {"symbol_code": "cloudy"}, "details": {"precipitation_amount": 0.0}}
+                }},
+                {"time": "2026-06-20T00:00:00Z", "data": {
+                    "instant": {"details": {"air_temperature": 9.0}}
+                }}
+            ]}}"#,
+        )
+        .unwrap();
+        let steps: Vec<ForecastStep> = forecast.properties.timeseries.into_iter().map(ForecastStep::from).collect();
+
+        assert_eq!((steps[0].period_hours, steps[0].precipitation_mm), (1, Some(0.3)));
+        assert_eq!(steps[0].symbol_code.as_deref(), Some("lightrain"));
+        assert_eq!((steps[1].period_hours, steps[1].symbol_code.as_deref()), (6, Some("cloudy")));
+        assert_eq!((steps[2].period_hours, steps[2].precipitation_mm, steps[2].wind_speed_ms), (0, None, 0.0));
+    }
+
+    #[tokio::test]
+    async fn test_forecasts_are_cached_per_location_and_day() {
+        let stub = StubForecaster::default();
+        let cached = CachedWeatherForecaster::new(stub.clone());
+        let oslo = chrono_tz::Europe::Oslo;
+        let monday = NaiveDate::from_ymd_opt(2026, 6, 8).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2026, 6, 9).unwrap();
+
+        cached.daily_forecast(60.3913, 5.3221, monday, oslo).await.unwrap();
+        // A few metres away is the same venue
+        cached.daily_forecast(60.3911, 5.3224, monday, oslo).await.unwrap();
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 1);
+        cached.daily_forecast(60.3913, 5.3221, tuesday, oslo).await.unwrap();
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+
+        // Stale forecasts are served while MET is down; unknown ones fail
+        let expiring = CachedWeatherForecaster::with_policy(
+            stub.clone(),
+            ForecastCachePolicy { fresh_for: Duration::ZERO, capacity: 10 },
+        );
+        expiring.daily_forecast(60.3913, 5.3221, monday, oslo).await.unwrap();
+        stub.offline.store(true, Ordering::SeqCst);
+        let stale = expiring.daily_forecast(60.3913, 5.3221, monday, oslo).await.unwrap().unwrap();
+        assert_eq!(stale.date, monday);
+        assert!(expiring.daily_forecast(59.9139, 10.7522, monday, oslo).await.is_err());
+    }
+}
@@ -1,5 +1,20 @@
 // Infrastructure layer - External concerns and adapters
 
+pub mod brreg;
+pub mod crm;
+pub mod dns;
+pub mod email_events;
+pub mod entur;
+pub mod exports;
+pub mod mailer;
+pub mod met_weather;
+pub mod media;
+pub mod norges_bank;
+pub mod postal_codes;
+pub mod resilience;
+pub mod scheduler;
+pub mod vipps;
 pub mod web;
+pub mod webhooks;
 
 // Infrastructure layer items are imported directly from submodules
\ No newline at end of file
@@ -0,0 +1,299 @@
+// Exchange rates from Norges Bank
+// Reads the central bank's daily reference rates (the EXR dataflow, in
+// SDMX-JSON), behind a cache: rates are published once per banking day
+// around 16:00, so there is no need to ask for every ticket sold.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use aqio_core::{Currency, DomainError, DomainResult, ExchangeRate, ExchangeRateProvider};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use crate::infrastructure::resilience::{CallError, Resilience, RetryPolicy, destination_host};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Data API used when `NORGES_BANK_API_URL` isn't set
+pub const DEFAULT_NORGES_BANK_URL: &str = "https://data.norges-bank.no/api/data/EXR";
+
+#[derive(Debug, Deserialize)]
+struct SdmxResponse {
+    data: SdmxData,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SdmxData {
+    data_sets: Vec<SdmxDataSet>,
+    structure: SdmxStructure,
+}
+
+#[derive(Debug, Deserialize)]
+struct SdmxDataSet {
+    #[serde(default)]
+    series: HashMap<String, SdmxSeries>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SdmxSeries {
+    /// Keyed by the index of the observation's time period; the first item
+    /// of each observation is the value
+    observations: HashMap<String, Vec<serde_json::Value>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SdmxStructure {
+    dimensions: SdmxDimensions,
+}
+
+#[derive(Debug, Deserialize)]
+struct SdmxDimensions {
+    observation: Vec<SdmxDimension>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SdmxDimension {
+    id: String,
+    values: Vec<SdmxDimensionValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SdmxDimensionValue {
+    id: String,
+}
+
+impl SdmxResponse {
+    /// The latest observation of the series. EUR and USD are quoted per one
+    /// unit (some currencies, like SEK, are per 100, but aren't sold in).
+    fn latest_rate(&self, currency: Currency) -> Option<ExchangeRate> {
+        let periods = &self
+            .data
+            .structure
+            .dimensions
+            .observation
+            .iter()
+            .find(|dimension| dimension.id == "TIME_PERIOD")?
+            .values;
+        let series = self.data.data_sets.first()?.series.values().next()?;
+        series
+            .observations
+            .iter()
+            .filter_map(|(index, values)| {
+                let date = NaiveDate::parse_from_str(&periods.get(index.parse::<usize>().ok()?)?.id, "%Y-%m-%d").ok()?;
+                let value = values.first()?;
+                let rate = value.as_str().and_then(|v| v.parse().ok()).or_else(|| value.as_f64())?;
+                Some(ExchangeRate { currency, nok_per_unit: rate, date })
+            })
+            .filter(|rate| rate.nok_per_unit > 0.0)
+            .max_by_key(|rate| rate.date)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NorgesBankExchangeRates {
+    client: reqwest::Client,
+    url: String,
+    resilience: Resilience,
+}
+
+impl NorgesBankExchangeRates {
+    pub fn new(url: impl Into<String>) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(Self {
+            client,
+            url: url.into().trim_end_matches('/').to_string(),
+            resilience: Resilience::default().with_retry(rates_retry()),
+        })
+    }
+
+    /// Shares breaker state with the other outbound adapters
+    pub fn with_resilience(mut self, resilience: Resilience) -> Self {
+        self.resilience = resilience.with_retry(rates_retry());
+        self
+    }
+}
+
+// A buyer is waiting at checkout
+fn rates_retry() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 2,
+        budget: Duration::from_secs(8),
+        ..RetryPolicy::default()
+    }
+}
+
+#[async_trait]
+impl ExchangeRateProvider for NorgesBankExchangeRates {
+    async fn rate_to_nok(&self, currency: Currency) -> DomainResult<ExchangeRate> {
+        if currency == Currency::Nok {
+            return Ok(ExchangeRate::nok(chrono::Utc::now().date_naive()));
+        }
+        // The last observation is the last banking day's, also on weekends and holidays
+        let url = format!(
+            "{}/B.{}.NOK.SP?format=sdmx-json&lastNObservations=1&locale=en",
+            self.url,
+            currency.code()
+        );
+        let response: SdmxResponse = self
+            .resilience
+            .call(&format!("norges-bank:{}", destination_host(&self.url)), || async {
+                let response = self.client.get(&url).send().await.map_err(CallError::from_reqwest)?;
+                if !response.status().is_success() {
+                    return Err(CallError::from_status(response.status()));
+                }
+                response.json().await.map_err(CallError::from_reqwest)
+            })
+            .await
+            .map_err(|e| DomainError::external_service("Norges Bank", &e.to_string()))?;
+
+        let rate = response.latest_rate(currency).ok_or_else(|| {
+            DomainError::external_service("Norges Bank", &format!("no exchange rate for {}", currency.code()))
+        })?;
+        debug!("Norges Bank rate for {} on {}: {}", currency.code(), rate.date, rate.nok_per_unit);
+        Ok(rate)
+    }
+}
+
+/// How long rates are kept before Norges Bank is asked again
+#[derive(Debug, Clone, Copy)]
+pub struct RateCachePolicy {
+    pub fresh_for: Duration,
+    /// Older rates are still used while Norges Bank can't be reached, up to this age
+    pub usable_for: Duration,
+}
+
+impl Default for RateCachePolicy {
+    fn default() -> Self {
+        Self {
+            fresh_for: Duration::from_secs(60 * 60),
+            usable_for: Duration::from_secs(4 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// Caches another provider's rates, and falls back to the last rate fetched
+/// while the provider is down, so ticket sales in other currencies don't
+/// stop with it
+#[derive(Clone)]
+pub struct CachedExchangeRates<P> {
+    inner: P,
+    policy: RateCachePolicy,
+    rates: Arc<Mutex<HashMap<Currency, (ExchangeRate, Instant)>>>,
+}
+
+impl<P: ExchangeRateProvider> CachedExchangeRates<P> {
+    pub fn new(inner: P) -> Self {
+        Self::with_policy(inner, RateCachePolicy::default())
+    }
+
+    pub fn with_policy(inner: P, policy: RateCachePolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            rates: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl<P: ExchangeRateProvider> ExchangeRateProvider for CachedExchangeRates<P> {
+    async fn rate_to_nok(&self, currency: Currency) -> DomainResult<ExchangeRate> {
+        let cached = {
+            let rates = self.rates.lock().expect("exchange rate cache lock poisoned");
+            rates.get(&currency).map(|(rate, fetched_at)| (*rate, fetched_at.elapsed()))
+        };
+        if let Some((rate, _)) = cached.filter(|(_, age)| *age < self.policy.fresh_for) {
+            return Ok(rate);
+        }
+
+        match self.inner.rate_to_nok(currency).await {
+            Ok(rate) => {
+                let mut rates = self.rates.lock().expect("exchange rate cache lock poisoned");
+                rates.insert(currency, (rate, Instant::now()));
+                Ok(rate)
+            }
+            Err(e) => match cached {
+                Some((rate, age)) if age < self.policy.usable_for => {
+                    warn!("Norges Bank unavailable, using the {} rate of {}: {}", currency.code(), rate.date, e);
+                    Ok(rate)
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[derive(Clone, Default)]
+    struct StubRates {
+        calls: Arc<AtomicUsize>,
+        offline: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl ExchangeRateProvider for StubRates {
+        async fn rate_to_nok(&self, currency: Currency) -> DomainResult<ExchangeRate> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.offline.load(Ordering::SeqCst) {
+                return Err(DomainError::external_service("Norges Bank", "unreachable"));
+            }
+            Ok(ExchangeRate {
+                currency,
+                nok_per_unit: 11.5,
+                date: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_latest_observation_is_read() {
+        let response: SdmxResponse = serde_json::from_str(
+            r#"{"data": {
+                "dataSets": [{"series": {"0:0:0:0": {"attributes": [0, 0], "observations": {
+                    "0": ["11.4870"], "1": ["11.5275"]
+                }}}}],
+                "structure": {"dimensions": {
+                    "series": [{"id": "FREQ", "values": [{"id": "B"}]}],
+                    "observation": [{"id": "TIME_PERIOD", "values": [{"id": "2026-02-27"}, {"id": "2026-03-02"}]}]
+                }}
+            }}"#,
+        )
+        .unwrap();
+
+        let rate = response.latest_rate(Currency::Eur).unwrap();
+        assert_eq!(rate.date, NaiveDate::from_ymd_opt(2026, 3, 2).unwrap());
+        assert_eq!(rate.nok_per_unit, 11.5275);
+        assert_eq!(rate.to_nok(12_000), 138_330);
+    }
+
+    #[tokio::test]
+    async fn test_rates_are_cached_and_kept_while_offline() {
+        let stub = StubRates::default();
+        let cached = CachedExchangeRates::with_policy(
+            stub.clone(),
+            RateCachePolicy {
+                fresh_for: Duration::ZERO,
+                usable_for: Duration::from_secs(60),
+            },
+        );
+        cached.rate_to_nok(Currency::Eur).await.unwrap();
+        assert_eq!(stub.calls.load(Ordering::SeqCst), 1);
+
+        stub.offline.store(true, Ordering::SeqCst);
+        assert_eq!(cached.rate_to_nok(Currency::Eur).await.unwrap().nok_per_unit, 11.5);
+        assert!(cached.rate_to_nok(Currency::Usd).await.is_err());
+
+        let fresh = CachedExchangeRates::new(StubRates::default());
+        fresh.rate_to_nok(Currency::Usd).await.unwrap();
+        fresh.rate_to_nok(Currency::Usd).await.unwrap();
+        assert_eq!(fresh.inner.calls.load(Ordering::SeqCst), 1);
+    }
+}
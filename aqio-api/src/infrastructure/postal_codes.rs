@@ -0,0 +1,88 @@
+// Posten's postal code register, held in memory
+// The register is a few thousand lines; it is read once at startup from a
+// file or from Bring's website, and lookups never leave the process.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use aqio_core::{AddressRegion, DomainResult, PostalCodeDirectory};
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::domain::postal_codes::parse_register;
+
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Register used when `POSTAL_CODE_REGISTER` isn't set
+pub const DEFAULT_POSTAL_CODE_REGISTER_URL: &str = "https://www.bring.no/postnummerregister-ansi.txt";
+
+#[derive(Debug, Clone, Default)]
+pub struct PostalCodeRegister {
+    regions: HashMap<String, AddressRegion>,
+}
+
+impl PostalCodeRegister {
+    pub fn from_register(text: &str) -> Self {
+        Self { regions: parse_register(text) }
+    }
+
+    /// Reads the register from an `http(s)://` URL or a file path
+    pub async fn load(source: &str) -> anyhow::Result<Self> {
+        let bytes = if source.starts_with("http://") || source.starts_with("https://") {
+            let client = reqwest::Client::builder().timeout(DOWNLOAD_TIMEOUT).build()?;
+            client.get(source).send().await?.error_for_status()?.bytes().await?.to_vec()
+        } else {
+            tokio::fs::read(source).await?
+        };
+        let register = Self::from_register(&decode(bytes));
+        if register.is_empty() {
+            anyhow::bail!("{} has no postal codes in the register's format", source);
+        }
+        info!("Loaded {} postal codes from {}", register.len(), source);
+        Ok(register)
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+// Posten publishes the register in Latin-1 as well as UTF-8
+fn decode(bytes: Vec<u8>) -> String {
+    match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => e.into_bytes().into_iter().map(char::from).collect(),
+    }
+}
+
+#[async_trait]
+impl PostalCodeDirectory for PostalCodeRegister {
+    async fn region(&self, postal_code: &str) -> DomainResult<Option<AddressRegion>> {
+        Ok(self.regions.get(postal_code).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_latin1_registers_are_decoded() {
+        // `TROMSØ` with Ø as the single Latin-1 byte 0xD8
+        let mut bytes = b"9008\tTROMS".to_vec();
+        bytes.push(0xD8);
+        bytes.extend_from_slice(b"\t5501\tTROMS");
+        bytes.push(0xD8);
+        bytes.extend_from_slice(b"\tG\n");
+
+        let register = PostalCodeRegister::from_register(&decode(bytes));
+        let region = register.region("9008").await.unwrap().unwrap();
+        assert_eq!(region.municipality, "Tromsø");
+        assert_eq!(region.county, "Troms");
+        assert_eq!(register.region("0000").await.unwrap(), None);
+    }
+}
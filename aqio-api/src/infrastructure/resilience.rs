@@ -0,0 +1,459 @@
+// Resilience for calls to external services (mail provider, webhooks, DNS)
+// Each call is retried with jittered exponential backoff inside an overall
+// time budget, and every destination has a circuit breaker: after repeated
+// failures calls fail fast for a while instead of piling up on a service
+// that is down, then a single trial call decides whether it is back.
+
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use aqio_database::{Fault, FaultInjector};
+use chrono::{DateTime, Utc};
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+/// How often and for how long a call is retried
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for each further one
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Time allowed for all attempts and the waits between them together
+    pub budget: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+            budget: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait before the attempt after `attempt`: the exponential delay with
+    /// up to half of it taken off at random, so callers that failed together
+    /// don't retry together
+    fn delay(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        let jitter = RandomState::new().build_hasher().finish() % 1000;
+        exponential - exponential / 2 * jitter as u32 / 1000
+    }
+}
+
+/// When a destination's breaker opens and for how long
+#[derive(Debug, Clone, Copy)]
+pub struct BreakerPolicy {
+    /// Failed attempts in a row that open the breaker
+    pub failure_threshold: u32,
+    /// How long calls fail fast before a trial call is let through
+    pub open_for: Duration,
+}
+
+impl Default for BreakerPolicy {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_for: Duration::from_secs(30),
+        }
+    }
+}
+
+/// How a single attempt failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallError {
+    /// Worth retrying: the service was unreachable, overloaded or erroring
+    Transient(String),
+    /// The service answered and refused the request; retrying won't help
+    Permanent(String),
+}
+
+impl CallError {
+    /// 408, 429 and 5xx are transient, anything else permanent
+    pub fn from_status(status: reqwest::StatusCode) -> Self {
+        let message = format!("answered {}", status);
+        if status.is_server_error()
+            || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || status == reqwest::StatusCode::REQUEST_TIMEOUT
+        {
+            CallError::Transient(message)
+        } else {
+            CallError::Permanent(message)
+        }
+    }
+
+    pub fn from_reqwest(error: reqwest::Error) -> Self {
+        match error.status() {
+            Some(status) => Self::from_status(status),
+            // An answer that can't be read won't read better the second time
+            None if error.is_decode() => CallError::Permanent(error.to_string()),
+            None => CallError::Transient(error.to_string()),
+        }
+    }
+}
+
+/// Why a call gave up
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum OutboundError {
+    #[error("not called, failing repeatedly; next try after {until}")]
+    CircuitOpen { until: DateTime<Utc> },
+    #[error("{0}")]
+    Rejected(String),
+    #[error("failed after {attempts} attempt(s): {last}")]
+    Exhausted { attempts: u32, last: String },
+    #[error("no answer within {0:?}")]
+    TimedOut(Duration),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    /// Calls go through
+    Closed,
+    /// Calls fail fast
+    Open,
+    /// The open period is over; the next call is a trial
+    HalfOpen,
+}
+
+impl BreakerState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BreakerState::Closed => "closed",
+            BreakerState::Open => "open",
+            BreakerState::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// A destination's breaker, for health checks
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreakerStatus {
+    pub destination: String,
+    pub state: BreakerState,
+    pub consecutive_failures: u32,
+    pub open_until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default)]
+struct Breaker {
+    consecutive_failures: u32,
+    open_until: Option<DateTime<Utc>>,
+    trial_in_flight: bool,
+}
+
+impl Breaker {
+    fn state(&self, now: DateTime<Utc>) -> BreakerState {
+        match self.open_until {
+            Some(until) if now < until => BreakerState::Open,
+            Some(_) => BreakerState::HalfOpen,
+            None => BreakerState::Closed,
+        }
+    }
+}
+
+/// Retry and circuit-breaker policy shared by the outbound adapters
+///
+/// Clones share their breakers, so one instance handed to every adapter
+/// gives the health check a view of all destinations.
+#[derive(Debug, Clone, Default)]
+pub struct Resilience {
+    retry: RetryPolicy,
+    breaker: BreakerPolicy,
+    breakers: Arc<Mutex<HashMap<String, Breaker>>>,
+    /// Disturbs attempts on dev and staging servers
+    faults: Option<FaultInjector>,
+}
+
+impl Resilience {
+    /// Every attempt first goes through `faults`, so an injected dropped
+    /// connection is retried and counted by the breaker like a real one
+    pub fn with_faults(self, faults: FaultInjector) -> Self {
+        Self {
+            faults: Some(faults),
+            ..self
+        }
+    }
+
+    /// The same breakers with a different retry policy, for adapters that
+    /// need a shorter budget
+    pub fn with_retry(&self, retry: RetryPolicy) -> Self {
+        Self {
+            retry,
+            ..self.clone()
+        }
+    }
+
+    /// Runs `attempt` until it succeeds, fails permanently, runs out of
+    /// attempts or time, or `destination`'s breaker opens
+    pub async fn call<T, F, Fut>(&self, destination: &str, mut attempt: F) -> Result<T, OutboundError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, CallError>>,
+    {
+        let deadline = Instant::now() + self.retry.budget;
+        let mut attempts = 0;
+        loop {
+            self.admit(destination)?;
+            attempts += 1;
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let disturbed = async {
+                if let Some(fault) = self.disturb(destination).await {
+                    return Err(CallError::Transient(format!("injected fault: {}", fault)));
+                }
+                attempt().await
+            };
+            let outcome = match tokio::time::timeout(remaining, disturbed).await {
+                Ok(outcome) => outcome,
+                Err(_) => {
+                    self.record_failure(destination);
+                    return Err(OutboundError::TimedOut(self.retry.budget));
+                }
+            };
+
+            match outcome {
+                Ok(value) => {
+                    self.record_success(destination);
+                    return Ok(value);
+                }
+                // The service is up, it just said no
+                Err(CallError::Permanent(message)) => {
+                    self.record_success(destination);
+                    return Err(OutboundError::Rejected(message));
+                }
+                Err(CallError::Transient(message)) => {
+                    self.record_failure(destination);
+                    let delay = self.retry.delay(attempts);
+                    if attempts >= self.retry.max_attempts || Instant::now() + delay >= deadline {
+                        return Err(OutboundError::Exhausted { attempts, last: message });
+                    }
+                    warn!("{} attempt {} failed, retrying in {:?}: {}", destination, attempts, delay, message);
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    /// Every destination called so far
+    pub fn breakers(&self) -> Vec<BreakerStatus> {
+        let now = Utc::now();
+        let breakers = self.breakers.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut statuses: Vec<BreakerStatus> = breakers
+            .iter()
+            .map(|(destination, breaker)| BreakerStatus {
+                destination: destination.clone(),
+                state: breaker.state(now),
+                consecutive_failures: breaker.consecutive_failures,
+                open_until: breaker.open_until,
+            })
+            .collect();
+        statuses.sort_by(|a, b| a.destination.cmp(&b.destination));
+        statuses
+    }
+
+    async fn disturb(&self, destination: &str) -> Option<Fault> {
+        match &self.faults {
+            Some(faults) => faults.disturb(destination, false).await,
+            None => None,
+        }
+    }
+
+    fn admit(&self, destination: &str) -> Result<(), OutboundError> {
+        let now = Utc::now();
+        let mut breakers = self.breakers.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let breaker = breakers.entry(destination.to_string()).or_default();
+        match (breaker.state(now), breaker.open_until) {
+            (BreakerState::Closed, _) => Ok(()),
+            (BreakerState::HalfOpen, _) if !breaker.trial_in_flight => {
+                breaker.trial_in_flight = true;
+                Ok(())
+            }
+            (_, Some(until)) => Err(OutboundError::CircuitOpen { until }),
+            (_, None) => Ok(()),
+        }
+    }
+
+    fn record_success(&self, destination: &str) {
+        let mut breakers = self.breakers.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(breaker) = breakers.get_mut(destination) {
+            if breaker.open_until.is_some() {
+                info!("{} is answering again, closing its circuit breaker", destination);
+            }
+            *breaker = Breaker::default();
+        }
+    }
+
+    fn record_failure(&self, destination: &str) {
+        let now = Utc::now();
+        let mut breakers = self.breakers.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let breaker = breakers.entry(destination.to_string()).or_default();
+        breaker.consecutive_failures += 1;
+        // A failed trial reopens straight away
+        if breaker.trial_in_flight || breaker.consecutive_failures >= self.breaker.failure_threshold {
+            let until = now + chrono::Duration::milliseconds(self.breaker.open_for.as_millis() as i64);
+            if breaker.open_until.is_none_or(|open_until| open_until <= now) {
+                warn!(
+                    "{} failed {} time(s) in a row, opening its circuit breaker until {}",
+                    destination, breaker.consecutive_failures, until
+                );
+            }
+            breaker.open_until = Some(until);
+            breaker.trial_in_flight = false;
+        }
+    }
+}
+
+/// The host of `url`, to key a breaker by without logging paths or query
+/// strings that may carry secrets
+pub fn destination_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aqio_database::FaultPlan;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn quick() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+            budget: Duration::from_secs(5),
+        }
+    }
+
+    #[test]
+    fn test_delay_grows_with_jitter_and_is_capped() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            ..RetryPolicy::default()
+        };
+        for _ in 0..20 {
+            let first = policy.delay(1);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+            let fifth = policy.delay(5);
+            assert!(fifth >= Duration::from_millis(150) && fifth <= Duration::from_millis(300));
+        }
+        assert_eq!(CallError::from_status(reqwest::StatusCode::BAD_GATEWAY), CallError::Transient("answered 502 Bad Gateway".to_string()));
+        assert!(matches!(CallError::from_status(reqwest::StatusCode::UNAUTHORIZED), CallError::Permanent(_)));
+    }
+
+    #[tokio::test]
+    async fn test_transient_failures_are_retried_and_permanent_ones_are_not() {
+        let resilience = Resilience::default().with_retry(quick());
+        let counter = AtomicU32::new(0);
+        let calls = &counter;
+
+        let value = resilience
+            .call("mail", || async move {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(CallError::Transient("connection reset".to_string())),
+                    _ => Ok(42),
+                }
+            })
+            .await;
+        assert_eq!(value, Ok(42));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(resilience.breakers()[0].consecutive_failures, 0);
+
+        calls.store(0, Ordering::SeqCst);
+        let rejected: Result<(), _> = resilience
+            .call("mail", || async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(CallError::Permanent("answered 400 Bad Request".to_string()))
+            })
+            .await;
+        assert_eq!(rejected, Err(OutboundError::Rejected("answered 400 Bad Request".to_string())));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_fails_fast_and_closes_after_a_good_trial() {
+        let resilience = Resilience {
+            retry: quick(),
+            breaker: BreakerPolicy { failure_threshold: 3, open_for: Duration::from_millis(50) },
+            ..Default::default()
+        };
+        let counter = AtomicU32::new(0);
+        let calls = &counter;
+        let failing = || async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(CallError::Transient("answered 503 Service Unavailable".to_string()))
+        };
+
+        let exhausted = resilience.call("hooks.example.no", failing).await;
+        assert!(matches!(exhausted, Err(OutboundError::Exhausted { attempts: 3, .. })));
+        assert_eq!(resilience.breakers()[0].state, BreakerState::Open);
+
+        // Fails fast without calling the destination
+        let open = resilience.call("hooks.example.no", failing).await;
+        assert!(matches!(open, Err(OutboundError::CircuitOpen { .. })));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        // Other destinations are unaffected
+        assert_eq!(resilience.call("dns", || async { Ok(()) }).await, Ok(()));
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(resilience.breakers()[1].state, BreakerState::HalfOpen);
+        assert_eq!(resilience.call("hooks.example.no", || async { Ok(()) }).await, Ok(()));
+        assert_eq!(resilience.breakers()[1].state, BreakerState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_budget_bounds_the_whole_call() {
+        let resilience = Resilience::default().with_retry(RetryPolicy { budget: Duration::from_millis(20), ..quick() });
+        let slow = resilience
+            .call("slow", || async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            })
+            .await;
+        assert_eq!(slow, Err(OutboundError::TimedOut(Duration::from_millis(20))));
+        assert_eq!(destination_host("https://hooks.example.no/aqio?token=secret"), "hooks.example.no");
+    }
+
+    #[tokio::test]
+    async fn test_injected_faults_are_retried_without_calling_the_destination() {
+        let resilience = Resilience::default().with_retry(quick()).with_faults(FaultInjector::new(
+            FaultPlan {
+                failure_rate: 1.0,
+                targets: vec!["webhook".to_string()],
+                ..Default::default()
+            },
+        ));
+        let counter = AtomicU32::new(0);
+        let calls = &counter;
+
+        let disturbed: Result<(), _> = resilience
+            .call("webhook:hooks.example.no", || async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+        assert_eq!(
+            disturbed,
+            Err(OutboundError::Exhausted { attempts: 3, last: "injected fault: connection dropped".to_string() })
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert_eq!(resilience.breakers()[0].consecutive_failures, 3);
+        // Destinations outside the plan's targets are called as usual
+        assert_eq!(resilience.call("sendgrid", || async { Ok(()) }).await, Ok(()));
+    }
+}
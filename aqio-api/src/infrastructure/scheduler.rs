@@ -0,0 +1,178 @@
+// Background jobs run on a fixed interval next to the HTTP server
+
+use std::time::Duration;
+
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+
+use crate::domain::notifications::RegistrationNotifier;
+use crate::domain::services::{
+    CrmSyncApplicationService, EventBroadcastApplicationService, ExportApplicationService, NotificationDigestApplicationService, PublishingApplicationService,
+    RefundApplicationService, WebVitalsApplicationService,
+};
+
+/// Publishes due drafts every `interval` until the process exits
+pub fn spawn_publish_scheduler(publishing: PublishingApplicationService, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        // After a stall, catch up once instead of firing every missed tick
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match publishing.publish_due(chrono::Utc::now()).await {
+                Ok(published) => {
+                    for event in published {
+                        info!("Published scheduled event {} ({})", event.id, event.title);
+                    }
+                }
+                Err(e) => warn!("Scheduled publishing failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Sends scheduled broadcasts every `interval` until the process exits
+pub fn spawn_broadcast_scheduler(broadcasts: EventBroadcastApplicationService, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match broadcasts.send_due(chrono::Utc::now()).await {
+                Ok(sent) => {
+                    for broadcast in sent {
+                        info!(
+                            "Sent scheduled broadcast {} of event {} to {} recipient(s)",
+                            broadcast.id, broadcast.event_id, broadcast.delivery.recipients
+                        );
+                    }
+                }
+                Err(e) => warn!("Sending scheduled broadcasts failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Queues notifications for the digests of users who chose one
+pub fn spawn_digest_collector(digests: NotificationDigestApplicationService, notifier: &RegistrationNotifier) {
+    let mut receiver = notifier.subscribe();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(notification) => {
+                    if let Err(e) = digests.queue(&notification).await {
+                        warn!("Could not queue notification for user {}: {}", notification.user_id, e);
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => warn!("Digest collector fell behind; {} notifications skipped", skipped),
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+/// Sends due notification digests every `interval` until the process exits
+pub fn spawn_digest_scheduler(digests: NotificationDigestApplicationService, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match digests.send_due(chrono::Utc::now()).await {
+                Ok(0) => {}
+                Ok(sent) => info!("Sent {} notification digest(s)", sent),
+                Err(e) => warn!("Sending notification digests failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Retries pending ticket refunds every `interval` until the process exits
+pub fn spawn_refund_scheduler(refunds: RefundApplicationService, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match refunds.process_due(chrono::Utc::now()).await {
+                Ok(attempted) => {
+                    for refund in attempted {
+                        info!(
+                            "Refund {} of order {} is {:?} after {} attempt(s)",
+                            refund.id, refund.order_id, refund.status, refund.attempts
+                        );
+                    }
+                }
+                Err(e) => warn!("Retrying ticket refunds failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Runs scheduled exports that are due every `interval` until the process exits
+pub fn spawn_export_scheduler(exports: ExportApplicationService, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match exports.run_due(chrono::Utc::now()).await {
+                Ok(ran) => {
+                    for job in ran {
+                        info!(
+                            "Export {} ({}) {:?} with {} row(s), next run at {:?}",
+                            job.id,
+                            job.name,
+                            job.last_status,
+                            job.last_row_count.unwrap_or(0),
+                            job.next_run_at
+                        );
+                    }
+                }
+                Err(e) => warn!("Running scheduled exports failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Syncs CRM connectors that are due every `interval` until the process exits
+pub fn spawn_crm_sync_scheduler(crm: CrmSyncApplicationService, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match crm.sync_due(chrono::Utc::now()).await {
+                Ok(ran) => {
+                    for connector in ran {
+                        info!(
+                            "CRM connector {} ({}) {:?} with {:?}, next sync at {:?}",
+                            connector.id,
+                            connector.name,
+                            connector.last_status,
+                            connector.last_summary,
+                            connector.next_sync_at
+                        );
+                    }
+                }
+                Err(e) => warn!("Syncing CRM connectors failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Drops web vitals past their retention every `interval` until the process exits
+pub fn spawn_web_vitals_pruner(web_vitals: WebVitalsApplicationService, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match web_vitals.prune(chrono::Utc::now()).await {
+                Ok(0) => {}
+                Ok(pruned) => info!("Pruned {} web vital sample(s)", pruned),
+                Err(e) => warn!("Pruning web vitals failed: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,178 @@
+// Refunds through Vipps MobilePay
+// Ticket orders are paid with the ePayment API, using the order id as the
+// payment reference, so a refund is a modification of that payment. The
+// refund id is sent as the idempotency key: a retried refund is never paid
+// out twice.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use aqio_core::{Currency, DomainError, DomainResult, PaymentProvider, TicketRefund};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+use crate::config::VippsConfig;
+use crate::infrastructure::resilience::{CallError, OutboundError, Resilience};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Tokens are renewed this long before Vipps says they expire
+const TOKEN_MARGIN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Deserialize)]
+struct AccessTokenResponse {
+    access_token: String,
+    /// Seconds; Vipps sends it as a string
+    #[serde(deserialize_with = "seconds")]
+    expires_in: u64,
+}
+
+fn seconds<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Seconds {
+        Number(u64),
+        Text(String),
+    }
+    match Seconds::deserialize(deserializer)? {
+        Seconds::Number(seconds) => Ok(seconds),
+        Seconds::Text(text) => text.trim().parse().map_err(serde::de::Error::custom),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModificationResponse {
+    psp_reference: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct VippsPayments {
+    client: reqwest::Client,
+    config: VippsConfig,
+    resilience: Resilience,
+    token: Arc<Mutex<Option<(String, Instant)>>>,
+}
+
+impl VippsPayments {
+    pub fn new(config: VippsConfig) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        Ok(Self {
+            client,
+            config,
+            resilience: Resilience::default(),
+            token: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Shares retry and breaker state with the other outbound adapters
+    pub fn with_resilience(mut self, resilience: Resilience) -> Self {
+        self.resilience = resilience;
+        self
+    }
+
+    async fn access_token(&self) -> Result<String, OutboundError> {
+        {
+            let token = self.token.lock().expect("Vipps token lock poisoned");
+            if let Some((token, _)) = token.as_ref().filter(|(_, expires_at)| Instant::now() < *expires_at) {
+                return Ok(token.clone());
+            }
+        }
+
+        let url = format!("{}/accesstoken/get", self.config.api_url);
+        let response: AccessTokenResponse = self
+            .resilience
+            .call("vipps", || async {
+                let response = self
+                    .client
+                    .post(&url)
+                    .header("client_id", &self.config.client_id)
+                    .header("client_secret", &self.config.client_secret)
+                    .header("Ocp-Apim-Subscription-Key", &self.config.subscription_key)
+                    .header("Merchant-Serial-Number", &self.config.merchant_serial_number)
+                    .send()
+                    .await
+                    .map_err(CallError::from_reqwest)?;
+                if !response.status().is_success() {
+                    return Err(CallError::from_status(response.status()));
+                }
+                response.json().await.map_err(CallError::from_reqwest)
+            })
+            .await?;
+
+        let lifetime = Duration::from_secs(response.expires_in).saturating_sub(TOKEN_MARGIN);
+        *self.token.lock().expect("Vipps token lock poisoned") =
+            Some((response.access_token.clone(), Instant::now() + lifetime));
+        Ok(response.access_token)
+    }
+
+    async fn modify(&self, refund: &TicketRefund) -> Result<ModificationResponse, OutboundError> {
+        let token = self.access_token().await?;
+        let url = format!("{}/epayment/v1/payments/{}/refund", self.config.api_url, refund.order_id);
+        let payload = json!({
+            "modificationAmount": {
+                "currency": refund.currency.code(),
+                "value": refund.amount,
+            }
+        });
+
+        self.resilience
+            .call("vipps", || async {
+                let response = self
+                    .client
+                    .post(&url)
+                    .bearer_auth(&token)
+                    .header("Ocp-Apim-Subscription-Key", &self.config.subscription_key)
+                    .header("Merchant-Serial-Number", &self.config.merchant_serial_number)
+                    .header("Idempotency-Key", refund.id.to_string())
+                    .header("Vipps-System-Name", "aqio")
+                    .json(&payload)
+                    .send()
+                    .await
+                    .map_err(CallError::from_reqwest)?;
+                if !response.status().is_success() {
+                    return Err(CallError::from_status(response.status()));
+                }
+                response.json().await.map_err(CallError::from_reqwest)
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl PaymentProvider for VippsPayments {
+    async fn refund(&self, refund: &TicketRefund) -> DomainResult<String> {
+        // Vipps MobilePay takes payments in NOK and EUR (and DKK) only
+        if refund.currency == Currency::Usd {
+            return Err(DomainError::business_rule("Vipps MobilePay can't refund payments in USD"));
+        }
+
+        match self.modify(refund).await {
+            Ok(response) => {
+                debug!("Vipps refunded order {}: {}", refund.order_id, response.psp_reference);
+                Ok(response.psp_reference)
+            }
+            Err(OutboundError::Rejected(message)) => {
+                Err(DomainError::business_rule(&format!("Vipps refused the refund: {}", message)))
+            }
+            Err(e) => Err(DomainError::external_service("Vipps", &e.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_lifetime_may_be_a_string() {
+        let text: AccessTokenResponse =
+            serde_json::from_str(r#"{"token_type": "Bearer", "expires_in": "86398", "access_token": "abc"}"#).unwrap();
+        assert_eq!((text.access_token.as_str(), text.expires_in), ("abc", 86398));
+
+        let number: AccessTokenResponse = serde_json::from_str(r#"{"expires_in": 3600, "access_token": "abc"}"#).unwrap();
+        assert_eq!(number.expires_in, 3600);
+    }
+}
@@ -0,0 +1,19 @@
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::access_grants,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn access_grant_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/access-grants",
+            get(access_grants::list_access_grants).post(access_grants::create_access_grant),
+        )
+        .route("/{id}/access-grants/{grant_id}", delete(access_grants::revoke_access_grant))
+}
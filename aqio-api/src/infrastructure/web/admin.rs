@@ -0,0 +1,40 @@
+use axum::{
+    routing::{delete, get, post, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::{admin, companies, crm, exports, reports, storage},
+    state::AppState,
+};
+
+pub fn admin_routes() -> Router<AppState> {
+    Router::new()
+        // All admin routes are protected and require the admin role
+        .route("/audit-log", get(admin::list_audit_log))
+        .route("/email-suppressions", get(admin::list_email_suppressions))
+        .route("/email-suppressions/{email}", delete(admin::delete_email_suppression))
+        .route("/impersonations", post(admin::start_impersonation))
+        .route("/query-metrics", get(admin::get_query_metrics))
+        .route("/web-vitals", get(admin::get_web_vitals))
+        .route("/organization-invitations", get(admin::list_organization_invitations))
+        .route("/organization-invitations/{id}", delete(admin::revoke_organization_invitation))
+        .route("/reports", get(reports::list_reported_content))
+        .route("/reports/{target}/{target_id}/actions", post(reports::moderate_content))
+        .route("/companies", post(companies::create_company))
+        .route("/companies/{id}/storage-quota", put(storage::set_storage_quota))
+        .route("/company-registry/{org_number}", get(companies::lookup_company_registry))
+        .route("/exports", get(exports::list_export_jobs).post(exports::create_export_job))
+        .route(
+            "/exports/{id}",
+            get(exports::get_export_job).put(exports::update_export_job).delete(exports::delete_export_job),
+        )
+        .route("/exports/{id}/run", post(exports::run_export_job))
+        .route("/crm/connectors", get(crm::list_crm_connectors).post(crm::create_crm_connector))
+        .route(
+            "/crm/connectors/{id}",
+            get(crm::get_crm_connector).put(crm::update_crm_connector).delete(crm::delete_crm_connector),
+        )
+        .route("/crm/connectors/{id}/status", get(crm::get_crm_connector_status))
+        .route("/crm/connectors/{id}/sync", post(crm::sync_crm_connector))
+}
@@ -0,0 +1,13 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{
+    handlers::annual_reports,
+    state::AppState,
+};
+
+// Nested under `/organizations`; the IDs are company IDs
+pub fn annual_report_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/reports/annual", get(annual_reports::get_annual_report))
+        .route("/{id}/reports/annual.pdf", get(annual_reports::download_annual_report))
+}
@@ -0,0 +1,27 @@
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{delete, get},
+    Router,
+};
+
+use crate::domain::services::EventAttachmentApplicationService;
+use crate::infrastructure::web::{
+    handlers::attachments,
+    state::AppState,
+};
+
+// Room for the multipart framing and text fields around the largest accepted file
+const UPLOAD_BODY_LIMIT: usize = EventAttachmentApplicationService::MAX_FILE_BYTES + 64 * 1024;
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn attachment_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/attachments",
+            get(attachments::list_attachments)
+                .post(attachments::upload_attachment)
+                .layer(DefaultBodyLimit::max(UPLOAD_BODY_LIMIT)),
+        )
+        .route("/{id}/attachments/{attachment_id}", delete(attachments::delete_attachment))
+        .route("/{id}/attachments/{attachment_id}/download", get(attachments::download_attachment))
+}
@@ -0,0 +1,15 @@
+use axum::{routing::{delete, get, post}, Router};
+
+use crate::infrastructure::web::{
+    handlers::broadcasts,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn broadcast_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/broadcast", post(broadcasts::send_broadcast))
+        .route("/{id}/broadcast/preview", post(broadcasts::preview_broadcast))
+        .route("/{id}/broadcasts", get(broadcasts::list_broadcasts))
+        .route("/{id}/broadcasts/{broadcast_id}", delete(broadcasts::cancel_broadcast))
+}
@@ -0,0 +1,37 @@
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::domain::services::EventBudgetApplicationService;
+use crate::infrastructure::web::{
+    handlers::budgets,
+    state::AppState,
+};
+
+// Room for the multipart framing around the largest accepted receipt
+const RECEIPT_BODY_LIMIT: usize = EventBudgetApplicationService::MAX_RECEIPT_BYTES + 16 * 1024;
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn budget_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/budget", get(budgets::get_budget))
+        .route("/{id}/budget/lines", post(budgets::create_budget_line))
+        .route(
+            "/{id}/budget/lines/{line_id}",
+            put(budgets::update_budget_line).delete(budgets::delete_budget_line),
+        )
+        .route("/{id}/budget/expenses", post(budgets::create_expense))
+        .route(
+            "/{id}/budget/expenses/{expense_id}",
+            put(budgets::update_expense).delete(budgets::delete_expense),
+        )
+        .route(
+            "/{id}/budget/expenses/{expense_id}/receipt",
+            get(budgets::download_receipt)
+                .put(budgets::upload_receipt)
+                .delete(budgets::delete_receipt)
+                .layer(DefaultBodyLimit::max(RECEIPT_BODY_LIMIT)),
+        )
+}
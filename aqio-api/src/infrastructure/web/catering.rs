@@ -0,0 +1,13 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{
+    handlers::catering,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn catering_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/catering-report", get(catering::get_catering_report))
+        .route("/{id}/catering-report.csv", get(catering::download_catering_report))
+}
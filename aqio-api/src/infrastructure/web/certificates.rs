@@ -0,0 +1,26 @@
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{get, put},
+    Router,
+};
+
+use crate::domain::services::CertificateApplicationService;
+use crate::infrastructure::web::{
+    handlers::certificates,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn certificate_routes() -> Router<AppState> {
+    Router::new().route(
+        "/{id}/certificate/signature",
+        put(certificates::set_certificate_signature)
+            .delete(certificates::remove_certificate_signature)
+            .layer(DefaultBodyLimit::max(CertificateApplicationService::MAX_SIGNATURE_BYTES)),
+    )
+}
+
+// Nested under `/api/v1/public/certificates`; reachable without credentials
+pub fn public_certificate_routes() -> Router<AppState> {
+    Router::new().route("/{id}", get(certificates::verify_certificate))
+}
@@ -0,0 +1,10 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{
+    handlers::client_config,
+    state::AppState,
+};
+
+pub fn client_config_routes() -> Router<AppState> {
+    Router::new().route("/", get(client_config::get_client_config))
+}
@@ -0,0 +1,10 @@
+use axum::{routing::post, Router};
+
+use crate::infrastructure::web::{
+    handlers::client_errors,
+    state::AppState,
+};
+
+pub fn client_error_routes() -> Router<AppState> {
+    Router::new().route("/", post(client_errors::report_client_error))
+}
@@ -0,0 +1,16 @@
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::comments,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn comment_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/comments", get(comments::list_comments).post(comments::create_comment))
+        .route("/{id}/comments/{comment_id}", delete(comments::delete_comment))
+}
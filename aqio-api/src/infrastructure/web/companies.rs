@@ -0,0 +1,13 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{
+    handlers::companies,
+    state::AppState,
+};
+
+pub fn company_routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(companies::list_companies))
+        .route("/{id}", get(companies::get_company))
+        .route("/{id}/events", get(companies::list_company_events))
+}
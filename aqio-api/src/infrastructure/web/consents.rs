@@ -0,0 +1,15 @@
+use axum::{routing::{get, put}, Router};
+
+use crate::infrastructure::web::{
+    handlers::consents,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn consent_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/consents", get(consents::list_consents))
+        .route("/{id}/consents/{kind}", put(consents::configure_consent).delete(consents::remove_consent))
+        .route("/{id}/consent-report", get(consents::get_consent_report))
+        .route("/{id}/consent-report.csv", get(consents::download_consent_report))
+}
@@ -0,0 +1,22 @@
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::post,
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::email_events,
+    state::AppState,
+};
+
+// SendGrid batches up to a thousand events in one post
+const WEBHOOK_BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+// Nested under `/api/v1/public/email-events`; called by the mail provider, which
+// authenticates with the `token` query parameter instead of a user login
+pub fn email_event_routes() -> Router<AppState> {
+    Router::new()
+        .route("/ses", post(email_events::receive_ses_events))
+        .route("/sendgrid", post(email_events::receive_sendgrid_events))
+        .layer(DefaultBodyLimit::max(WEBHOOK_BODY_LIMIT))
+}
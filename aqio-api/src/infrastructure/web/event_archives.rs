@@ -0,0 +1,22 @@
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+    Router,
+};
+
+use crate::domain::services::EventArchiveApplicationService;
+use crate::infrastructure::web::{
+    handlers::event_archives,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn event_archive_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/export", get(event_archives::export_event))
+        .route(
+            "/import",
+            post(event_archives::import_event)
+                .layer(DefaultBodyLimit::max(EventArchiveApplicationService::MAX_ARCHIVE_BYTES)),
+        )
+}
@@ -0,0 +1,30 @@
+use axum::{
+    routing::{get, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::event_fields,
+    state::AppState,
+};
+
+// Merged into the company routes, so paths are relative to `/companies`
+pub fn event_field_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/event-fields",
+            get(event_fields::list_event_fields).post(event_fields::create_event_field),
+        )
+        .route(
+            "/{id}/event-fields/{field_id}",
+            put(event_fields::update_event_field).delete(event_fields::delete_event_field),
+        )
+}
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn event_field_value_routes() -> Router<AppState> {
+    Router::new().route(
+        "/{id}/fields",
+        get(event_fields::get_event_field_values).put(event_fields::update_event_field_values),
+    )
+}
@@ -0,0 +1,22 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::event_submissions,
+    state::AppState,
+};
+
+// Nested under `/api/v1/event-submissions`
+pub fn event_submission_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            post(event_submissions::submit_event).get(event_submissions::list_event_submission_queue),
+        )
+        .route("/mine", get(event_submissions::list_my_event_submissions))
+        .route("/{id}/approve", post(event_submissions::approve_event_submission))
+        .route("/{id}/reject", post(event_submissions::reject_event_submission))
+        .route("/{id}/resubmit", post(event_submissions::resubmit_event_submission))
+}
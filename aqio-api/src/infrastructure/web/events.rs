@@ -13,9 +13,16 @@ pub fn events_routes() -> Router<AppState> {
         // Public routes
         .route("/", get(events::list_events))
         .route("/{id}", get(events::get_event))
+        .route("/details", get(events::list_events_with_details))
+        .route("/{id}/details", get(events::get_event_with_details))
         // Protected routes (auth middleware will be applied at the router level)
         .route("/", post(events::create_event))
         .route("/{id}", put(events::update_event))
         .route("/{id}", delete(events::delete_event))
         .route("/my", get(events::get_my_events))
-}
\ No newline at end of file
+}
+
+// Reachable without credentials so public event pages can poll it
+pub fn public_event_availability_routes() -> Router<AppState> {
+    Router::new().route("/{id}/availability", get(events::get_event_availability))
+}
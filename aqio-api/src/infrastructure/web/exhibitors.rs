@@ -0,0 +1,22 @@
+use axum::{
+    routing::{get, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::exhibitors,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn exhibitor_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/booths", get(exhibitors::list_booths).post(exhibitors::create_booth))
+        .route("/{id}/booths/{booth_id}", put(exhibitors::update_booth).delete(exhibitors::delete_booth))
+        .route("/{id}/exhibitors", get(exhibitors::list_exhibitors).post(exhibitors::create_exhibitor))
+        .route(
+            "/{id}/exhibitors/{exhibitor_id}",
+            put(exhibitors::update_exhibitor).delete(exhibitors::delete_exhibitor),
+        )
+        .route("/{id}/exhibitors/{exhibitor_id}/booth", put(exhibitors::allocate_booth))
+}
@@ -1,6 +1,19 @@
 // Custom extractors for validation and common patterns
 
-use crate::domain::ApiResult;
+use std::convert::Infallible;
+
+use axum::{
+    extract::FromRequestParts,
+    http::{header::ACCEPT_LANGUAGE, request::Parts},
+};
+
+use aqio_core::PermissionChecker;
+
+use crate::{
+    auth::Claims,
+    domain::{ApiError, ApiResult, locale::Localization},
+    infrastructure::web::state::AppState,
+};
 
 // Trait for types that can be validated  
 pub trait Validate {
@@ -12,4 +25,48 @@ impl Validate for crate::domain::dto::CreateEventRequest {
     fn validate(&self) -> ApiResult<()> {
         self.validate()
     }
-}
\ No newline at end of file
+}
+/// The language and time zone to render a response in: the signed-in user's
+/// settings, then the Accept-Language header, then the defaults. Works on
+/// public routes too, where there are no claims.
+pub struct EffectiveLocale(pub Localization);
+
+impl FromRequestParts<AppState> for EffectiveLocale {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let user = match parts.extensions.get::<Claims>() {
+            // A failed lookup only costs the user's own settings
+            Some(claims) => state.user_service.get_user_by_keycloak_id(&claims.sub).await.ok().flatten(),
+            None => None,
+        };
+        let accept_language = parts
+            .headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok());
+
+        Ok(Self(Localization::resolve(user.as_ref(), accept_language)))
+    }
+}
+
+/// What the signed-in user may do: the roles in their token, their stored
+/// role, and the custom roles their organization gave them
+pub struct Permissions(pub PermissionChecker);
+
+impl FromRequestParts<AppState> for Permissions {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let claims = parts
+            .extensions
+            .get::<Claims>()
+            .ok_or_else(|| ApiError::authentication("Sign in required"))?;
+        let user = state.user_service.get_user_by_keycloak_id(&claims.sub).await?;
+        let checker = state
+            .permission_service
+            .checker(user.as_ref(), &claims.user_roles())
+            .await?;
+
+        Ok(Self(checker))
+    }
+}
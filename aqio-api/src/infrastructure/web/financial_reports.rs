@@ -0,0 +1,13 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{
+    handlers::financial_reports,
+    state::AppState,
+};
+
+// Nested under `/api/v1/financial-reports`
+pub fn financial_report_routes() -> Router<AppState> {
+    Router::new()
+        .route("/monthly", get(financial_reports::get_monthly_financial_report))
+        .route("/monthly.csv", get(financial_reports::download_monthly_financial_report))
+}
@@ -0,0 +1,137 @@
+// Access grant handlers - time-bound access to an event that its organizers
+// delegate, such as check-in rights for the day of the event
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::{Permission, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{AccessGrantRequest, AccessGrantResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/access-grants",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The event's access grants, including ended ones, latest starting first", body = Vec<AccessGrantResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not one of the event's organizers"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "access-grants"
+)]
+pub async fn list_access_grants(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let grants = app_state
+        .permission_service
+        .grants(event_id, &user, may_manage)
+        .await?;
+
+    Ok(success_response(
+        grants.into_iter().map(AccessGrantResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/access-grants",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = AccessGrantRequest,
+    responses(
+        (status = 201, description = "Access granted; it holds from `starts_at` until `expires_at`", body = AccessGrantResponse),
+        (status = 400, description = "No permissions, one that can't be delegated, an end before the start or in the past, a span over 31 days, or a user who already organizes the event"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not one of the event's organizers"),
+        (status = 404, description = "Event or active user not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "access-grants"
+)]
+pub async fn create_access_grant(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<AccessGrantRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let grant = app_state
+        .permission_service
+        .grant_access(event_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(AccessGrantResponse::from(grant)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/access-grants/{grant_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("grant_id" = Uuid, Path, description = "Access grant ID")
+    ),
+    responses(
+        (status = 200, description = "Grant revoked; it stops applying right away", body = AccessGrantResponse),
+        (status = 400, description = "The grant was already revoked or has expired"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not one of the event's organizers"),
+        (status = 404, description = "Event or grant not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "access-grants"
+)]
+pub async fn revoke_access_grant(
+    State(app_state): State<AppState>,
+    Path((event_id, grant_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let grant = app_state
+        .permission_service
+        .revoke_grant(event_id, grant_id, &user, may_manage)
+        .await?;
+
+    Ok(success_response(AccessGrantResponse::from(grant)))
+}
@@ -0,0 +1,319 @@
+// Admin handlers - HTTP endpoints backing the admin console
+
+use aqio_core::{AuditAction, Permission};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+};
+use uuid::Uuid;
+
+use crate::auth::{Claims, impersonation::ImpersonationTokens};
+use crate::domain::{
+    ApiError, ApiResult,
+    dto::{
+        AuditLogQuery, ImpersonationResponse, OrganizationInvitationQuery, OrganizationInvitationResponse,
+        PaginatedAuditLogResponse, QueryMetricsResponse, StartImpersonationRequest, UserResponse,
+        WebVitalSummaryResponse, WebVitalsQuery,
+    },
+};
+use crate::infrastructure::web::{
+    extractors::Permissions,
+    response::{created_response, empty_success, success_response},
+    state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/audit-log",
+    params(AuditLogQuery),
+    responses(
+        (status = 200, description = "Audit log entries, newest first", body = PaginatedAuditLogResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_audit_log(
+    State(app_state): State<AppState>,
+    Query(query): Query<AuditLogQuery>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    if !permissions.has(Permission::AuditView) {
+        return Err(ApiError::authorization(
+            "Only administrators can view the audit log",
+        ));
+    }
+
+    let (filter, pagination) = query.to_filter_and_pagination()?;
+    let result = app_state
+        .audit_log_service
+        .list_entries(&filter, pagination)
+        .await?;
+    Ok(success_response(
+        PaginatedAuditLogResponse::from_paginated_result(result),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/email-suppressions",
+    responses(
+        (status = 200, description = "Addresses that bounced or complained, newest first", body = Vec<EmailSuppression>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_email_suppressions(
+    State(app_state): State<AppState>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    if !permissions.has(Permission::EmailManage) {
+        return Err(ApiError::authorization(
+            "Only administrators can view suppressed email addresses",
+        ));
+    }
+
+    let suppressions = app_state.email_delivery_service.suppressions().await?;
+    Ok(success_response(suppressions))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/email-suppressions/{email}",
+    params(
+        ("email" = String, Path, description = "Suppressed address")
+    ),
+    responses(
+        (status = 204, description = "Suppression lifted; the address's undeliverable invitations can be resent"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "Address is not suppressed")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn delete_email_suppression(
+    State(app_state): State<AppState>,
+    Path(email): Path<String>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    if !permissions.has(Permission::EmailManage) {
+        return Err(ApiError::authorization(
+            "Only administrators can lift email suppressions",
+        ));
+    }
+
+    app_state.email_delivery_service.lift_suppression(&email).await?;
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/query-metrics",
+    responses(
+        (status = 200, description = "Call counts, timings and slow calls per repository method since startup", body = QueryMetricsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_query_metrics(
+    State(app_state): State<AppState>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    if !permissions.has(Permission::MetricsView) {
+        return Err(ApiError::authorization(
+            "Only administrators can view query metrics",
+        ));
+    }
+
+    Ok(success_response(QueryMetricsResponse::from(&app_state.query_metrics)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/web-vitals",
+    params(WebVitalsQuery),
+    responses(
+        (status = 200, description = "p50, p75 and p95 of each web vital per release of the web app, releases oldest first", body = Vec<WebVitalSummaryResponse>),
+        (status = 400, description = "Days outside 1 to 30"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_web_vitals(
+    State(app_state): State<AppState>,
+    Query(query): Query<WebVitalsQuery>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    if !permissions.has(Permission::MetricsView) {
+        return Err(ApiError::authorization(
+            "Only administrators can view web vitals",
+        ));
+    }
+
+    let summaries = app_state.web_vitals_service.summary(query.days.unwrap_or(7)).await?;
+    let response: Vec<WebVitalSummaryResponse> = summaries.into_iter().map(WebVitalSummaryResponse::from).collect();
+    Ok(success_response(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/impersonations",
+    request_body = StartImpersonationRequest,
+    responses(
+        (status = 201, description = "Short-lived token acting as the user; responses to it carry an X-Impersonated-By header", body = ImpersonationResponse),
+        (status = 400, description = "Missing reason, invalid duration, own or deactivated account"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator, or the user is one"),
+        (status = 404, description = "User not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn start_impersonation(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<StartImpersonationRequest>,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    if !permissions.has(Permission::UserImpersonate) {
+        return Err(ApiError::authorization(
+            "Only administrators can impersonate users",
+        ));
+    }
+
+    let user = app_state.user_service.get_user_by_id(request.user_id).await?;
+    let (access_token, expires_at) = app_state.impersonation_tokens.issue(
+        &claims,
+        &user,
+        &request.reason,
+        request.minutes.unwrap_or(ImpersonationTokens::DEFAULT_MINUTES),
+        chrono::Utc::now(),
+    )?;
+
+    let session = serde_json::json!({
+        "user_id": user.id,
+        "user_email": user.email,
+        "reason": request.reason.trim(),
+        "expires_at": expires_at,
+    });
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "impersonations",
+            user.id.to_string(),
+            AuditAction::Insert,
+            None,
+            Some(&session),
+        )
+        .await;
+
+    Ok(created_response(ImpersonationResponse {
+        access_token,
+        token_type: "Bearer".to_string(),
+        expires_at,
+        user: UserResponse::from(user),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/organization-invitations",
+    params(OrganizationInvitationQuery),
+    responses(
+        (status = 200, description = "Pending organization invitations, newest first; expired ones have `is_open` false", body = Vec<OrganizationInvitationResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_organization_invitations(
+    State(app_state): State<AppState>,
+    Query(query): Query<OrganizationInvitationQuery>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    if !permissions.has(Permission::OrganizationManage) {
+        return Err(ApiError::authorization(
+            "Only administrators can view organization invitations",
+        ));
+    }
+
+    let invitations = app_state
+        .organization_invitation_service
+        .list_pending(query.company_id)
+        .await?;
+    Ok(success_response(
+        invitations
+            .into_iter()
+            .map(OrganizationInvitationResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/organization-invitations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Invitation ID")
+    ),
+    responses(
+        (status = 204, description = "Invitation withdrawn; its join link no longer works"),
+        (status = 400, description = "Invitation is no longer pending"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "Invitation not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn revoke_organization_invitation(
+    State(app_state): State<AppState>,
+    Path(invitation_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    if !permissions.has(Permission::OrganizationManage) {
+        return Err(ApiError::authorization(
+            "Only administrators can withdraw organization invitations",
+        ));
+    }
+
+    let invitation = app_state.organization_invitation_service.revoke(invitation_id).await?;
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "organization_invitations",
+            invitation.id.to_string(),
+            AuditAction::Update,
+            None,
+            Some(&invitation),
+        )
+        .await;
+    Ok(empty_success())
+}
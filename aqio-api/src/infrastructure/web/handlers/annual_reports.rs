@@ -0,0 +1,104 @@
+// Annual report handlers - an organization's year of events, attendance and
+// survey satisfaction for its organizers, as JSON or as a PDF
+
+use aqio_core::{Permission, User};
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{AnnualReportQuery, AnnualReportResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        handlers::attachments::file_response,
+        response::success_response,
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/organizations/{id}/reports/annual",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        AnnualReportQuery
+    ),
+    responses(
+        (status = 200, description = "Events held, attendance and its growth, top categories and survey satisfaction for the year", body = AnnualReportResponse),
+        (status = 400, description = "Year that hasn't started"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "annual-reports"
+)]
+pub async fn get_annual_report(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Query(query): Query<AnnualReportQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .annual_report_service
+        .report(company_id, query.year, &user, may_manage)
+        .await?;
+
+    Ok(success_response(AnnualReportResponse::from(report)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/organizations/{id}/reports/annual.pdf",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        AnnualReportQuery
+    ),
+    responses(
+        (status = 200, description = "The report as a one-page PDF", content_type = "application/pdf"),
+        (status = 400, description = "Year that hasn't started"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "annual-reports"
+)]
+pub async fn download_annual_report(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Query(query): Query<AnnualReportQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let (filename, pdf) = app_state
+        .annual_report_service
+        .report_pdf(company_id, query.year, &user, may_manage)
+        .await?;
+
+    Ok(file_response("application/pdf", &filename, &filename, pdf))
+}
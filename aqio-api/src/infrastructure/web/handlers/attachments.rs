@@ -0,0 +1,259 @@
+// Event attachment handlers - slides, handouts and maps uploaded by organizers
+
+use axum::{
+    Extension,
+    extract::{Multipart, Path, State},
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use aqio_core::{AttachmentVisibility, EventAttachment, User};
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{AttachmentResponse, EventAttachmentsResponse},
+        services::NewAttachment,
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+// Enum fields arrive as plain text; reuse the models' case-insensitive parsing
+fn parse_field<T: DeserializeOwned>(field: &str, text: String) -> ApiResult<T> {
+    serde_json::from_value(serde_json::Value::String(text))
+        .map_err(|e| ApiError::validation(field, e.to_string()))
+}
+
+async fn read_upload(mut multipart: Multipart) -> ApiResult<NewAttachment> {
+    let mut file = None;
+    let mut upload = NewAttachment {
+        original_filename: String::new(),
+        mime_type: String::new(),
+        title: None,
+        description: None,
+        category: None,
+        visibility: AttachmentVisibility::AttendeesOnly,
+        bytes: Vec::new(),
+    };
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::bad_request(e.body_text()))?
+    {
+        let name = field.name().unwrap_or_default().to_string();
+        if name == "file" {
+            let filename = field.file_name().unwrap_or_default().to_string();
+            let mime_type = field
+                .content_type()
+                .unwrap_or("application/octet-stream")
+                .to_string();
+            let bytes = field.bytes().await.map_err(|e| ApiError::bad_request(e.body_text()))?;
+            file = Some((filename, mime_type, bytes));
+            continue;
+        }
+
+        let text = field.text().await.map_err(|e| ApiError::bad_request(e.body_text()))?;
+        match name.as_str() {
+            "title" => upload.title = Some(text),
+            "description" => upload.description = Some(text),
+            "category" if !text.trim().is_empty() => upload.category = Some(parse_field("category", text)?),
+            "visibility" if !text.trim().is_empty() => upload.visibility = parse_field("visibility", text)?,
+            _ => {}
+        }
+    }
+
+    let (filename, mime_type, bytes) = file.ok_or_else(|| ApiError::validation("file", "No file was uploaded"))?;
+    upload.original_filename = filename;
+    upload.mime_type = mime_type;
+    upload.bytes = bytes.to_vec();
+    Ok(upload)
+}
+
+/// Serve the file for download under the name it was uploaded with
+pub(crate) fn download_response(attachment: EventAttachment, bytes: Vec<u8>) -> Response {
+    file_response(&attachment.mime_type, &attachment.filename, &attachment.original_filename, bytes)
+}
+
+/// `filename` is the sanitized ASCII name, `original_filename` the one it was uploaded with
+pub(crate) fn file_response(mime_type: &str, filename: &str, original_filename: &str, bytes: Vec<u8>) -> Response {
+    let content_type = HeaderValue::from_str(mime_type)
+        .unwrap_or_else(|_| HeaderValue::from_static("application/octet-stream"));
+    // `filename` is the ASCII fallback; `filename*` carries the original name (RFC 6266)
+    let disposition = format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        filename,
+        percent_encode(original_filename)
+    );
+    let disposition = HeaderValue::from_str(&disposition)
+        .unwrap_or_else(|_| HeaderValue::from_static("attachment"));
+
+    (
+        [(header::CONTENT_TYPE, content_type), (header::CONTENT_DISPOSITION, disposition)],
+        bytes,
+    )
+        .into_response()
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/attachments",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Attachments the caller may download, oldest first", body = EventAttachmentsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "attachments"
+)]
+pub async fn list_attachments(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let viewer = current_user(&app_state, &claims).await?;
+    let library = app_state
+        .attachment_service
+        .library(event_id, viewer.id, may_manage)
+        .await?;
+
+    Ok(success_response(EventAttachmentsResponse::from(library)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/attachments",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body(content = UploadAttachmentForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Attachment uploaded", body = AttachmentResponse),
+        (status = 400, description = "Missing or empty file"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found"),
+        (status = 413, description = "File larger than the upload limit"),
+        (status = 507, description = "The organization's storage quota is used up")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "attachments"
+)]
+pub async fn upload_attachment(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    multipart: Multipart,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let uploader = current_user(&app_state, &claims).await?;
+    let upload = read_upload(multipart).await?;
+    let attachment = app_state
+        .attachment_service
+        .upload(event_id, &uploader, may_manage, upload)
+        .await?;
+
+    Ok(created_response(AttachmentResponse::from(attachment)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/attachments/{attachment_id}/download",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("attachment_id" = Uuid, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 200, description = "File contents", content_type = "application/octet-stream"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Attendees-only file and the caller has no confirmed registration"),
+        (status = 404, description = "Event or attachment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "attachments"
+)]
+pub async fn download_attachment(
+    State(app_state): State<AppState>,
+    Path((event_id, attachment_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let viewer = current_user(&app_state, &claims).await?;
+    let (attachment, bytes) = app_state
+        .attachment_service
+        .download(event_id, attachment_id, Some(viewer.id), may_manage)
+        .await?;
+
+    Ok(download_response(attachment, bytes))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/attachments/{attachment_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("attachment_id" = Uuid, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 204, description = "Attachment removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or attachment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "attachments"
+)]
+pub async fn delete_attachment(
+    State(app_state): State<AppState>,
+    Path((event_id, attachment_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .attachment_service
+        .delete(event_id, attachment_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
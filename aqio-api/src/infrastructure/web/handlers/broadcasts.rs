@@ -0,0 +1,195 @@
+// Broadcast handlers - messages organizers send to an event's registrants,
+// right away or at a scheduled time
+
+use aqio_core::{AuditAction, User};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{BroadcastPreviewResponse, BroadcastRequest},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/broadcast",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = BroadcastRequest,
+    responses(
+        (status = 201, description = "Broadcast sent, with its delivery counts, or scheduled", body = EventBroadcast),
+        (status = 400, description = "Missing or too long subject or message, or a send time in the past"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "broadcasts"
+)]
+pub async fn send_broadcast(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<BroadcastRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let broadcast = app_state
+        .broadcast_service
+        .send(event_id, &user, may_manage, request.into())
+        .await?;
+
+    app_state
+        .audit_log_service
+        .record_event_change(
+            &claims.audit_actor(),
+            event_id,
+            "event_broadcasts",
+            broadcast.id.to_string(),
+            AuditAction::Insert,
+            None,
+            Some(&broadcast),
+        )
+        .await;
+    Ok(created_response(broadcast))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/broadcast/preview",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = BroadcastRequest,
+    responses(
+        (status = 200, description = "The email as the sender would get it and how many it would reach; nothing is sent", body = BroadcastPreviewResponse),
+        (status = 400, description = "Missing or too long subject or message"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "broadcasts"
+)]
+pub async fn preview_broadcast(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<BroadcastRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let preview = app_state
+        .broadcast_service
+        .preview(event_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(BroadcastPreviewResponse::from(preview)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/broadcasts",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The event's broadcasts with their delivery counts, newest first", body = Vec<EventBroadcast>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "broadcasts"
+)]
+pub async fn list_broadcasts(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let broadcasts = app_state
+        .broadcast_service
+        .list(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(broadcasts))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/broadcasts/{broadcast_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("broadcast_id" = Uuid, Path, description = "Broadcast ID")
+    ),
+    responses(
+        (status = 204, description = "Scheduled broadcast called off"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or broadcast not found"),
+        (status = 409, description = "The broadcast was already sent or cancelled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "broadcasts"
+)]
+pub async fn cancel_broadcast(
+    State(app_state): State<AppState>,
+    Path((event_id, broadcast_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let (previous, broadcast) = app_state
+        .broadcast_service
+        .cancel(event_id, broadcast_id, user.id, may_manage)
+        .await?;
+
+    app_state
+        .audit_log_service
+        .record_event_change(
+            &claims.audit_actor(),
+            event_id,
+            "event_broadcasts",
+            broadcast.id.to_string(),
+            AuditAction::Update,
+            Some(&previous),
+            Some(&broadcast),
+        )
+        .await;
+    Ok(empty_success())
+}
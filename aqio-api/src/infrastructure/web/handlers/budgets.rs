@@ -0,0 +1,412 @@
+// Event budget handlers - budget lines, expenses and their receipts, for organizers
+
+use axum::{
+    Extension, Json,
+    extract::{Multipart, Path, State},
+    response::{IntoResponse, Response},
+};
+use aqio_core::{EventAttachment, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{BudgetLineRequest, BudgetLineResponse, EventBudgetResponse, ExpenseRequest, ExpenseResponse},
+        services::ReceiptUpload,
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        handlers::attachments::file_response,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+async fn read_receipt(mut multipart: Multipart) -> ApiResult<ReceiptUpload> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::bad_request(e.body_text()))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let original_filename = field.file_name().unwrap_or_default().to_string();
+        let mime_type = field
+            .content_type()
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let bytes = field.bytes().await.map_err(|e| ApiError::bad_request(e.body_text()))?;
+        return Ok(ReceiptUpload {
+            original_filename,
+            mime_type,
+            bytes: bytes.to_vec(),
+        });
+    }
+    Err(ApiError::validation("file", "No file was uploaded"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/budget",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Budget lines, expenses and totals", body = EventBudgetResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn get_budget(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let budget = app_state
+        .budget_service
+        .budget(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(EventBudgetResponse::from(budget)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/budget/lines",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = BudgetLineRequest,
+    responses(
+        (status = 201, description = "Budget line added", body = BudgetLineResponse),
+        (status = 400, description = "Missing name or negative amount"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn create_budget_line(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<BudgetLineRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let line = app_state
+        .budget_service
+        .add_line(event_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(BudgetLineResponse::from(line)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/budget/lines/{line_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("line_id" = Uuid, Path, description = "Budget line ID")
+    ),
+    request_body = BudgetLineRequest,
+    responses(
+        (status = 200, description = "Budget line updated", body = BudgetLineResponse),
+        (status = 400, description = "Missing name or negative amount"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or budget line not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn update_budget_line(
+    State(app_state): State<AppState>,
+    Path((event_id, line_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<BudgetLineRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let line = app_state
+        .budget_service
+        .update_line(event_id, line_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(BudgetLineResponse::from(line)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/budget/lines/{line_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("line_id" = Uuid, Path, description = "Budget line ID")
+    ),
+    responses(
+        (status = 204, description = "Budget line removed; its expenses become unassigned"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or budget line not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn delete_budget_line(
+    State(app_state): State<AppState>,
+    Path((event_id, line_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .budget_service
+        .delete_line(event_id, line_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/budget/expenses",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = ExpenseRequest,
+    responses(
+        (status = 201, description = "Expense recorded", body = ExpenseResponse),
+        (status = 400, description = "Missing description or non-positive amount"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or budget line not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn create_expense(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ExpenseRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let expense = app_state
+        .budget_service
+        .record_expense(event_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(ExpenseResponse::from(expense)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/budget/expenses/{expense_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("expense_id" = Uuid, Path, description = "Expense ID")
+    ),
+    request_body = ExpenseRequest,
+    responses(
+        (status = 200, description = "Expense updated; the receipt is kept", body = ExpenseResponse),
+        (status = 400, description = "Missing description or non-positive amount"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event, expense or budget line not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn update_expense(
+    State(app_state): State<AppState>,
+    Path((event_id, expense_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ExpenseRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let expense = app_state
+        .budget_service
+        .update_expense(event_id, expense_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(ExpenseResponse::from(expense)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/budget/expenses/{expense_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("expense_id" = Uuid, Path, description = "Expense ID")
+    ),
+    responses(
+        (status = 204, description = "Expense and its receipt removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or expense not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn delete_expense(
+    State(app_state): State<AppState>,
+    Path((event_id, expense_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .budget_service
+        .delete_expense(event_id, expense_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/budget/expenses/{expense_id}/receipt",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("expense_id" = Uuid, Path, description = "Expense ID")
+    ),
+    request_body(content = UploadReceiptForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Receipt attached, replacing any earlier one", body = ExpenseResponse),
+        (status = 400, description = "Missing or empty file, or not an image or PDF"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or expense not found"),
+        (status = 413, description = "File larger than the upload limit"),
+        (status = 507, description = "The organization's storage quota is used up")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn upload_receipt(
+    State(app_state): State<AppState>,
+    Path((event_id, expense_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    multipart: Multipart,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let upload = read_receipt(multipart).await?;
+    let expense = app_state
+        .budget_service
+        .attach_receipt(event_id, expense_id, user.id, may_manage, upload)
+        .await?;
+
+    Ok(success_response(ExpenseResponse::from(expense)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/budget/expenses/{expense_id}/receipt",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("expense_id" = Uuid, Path, description = "Expense ID")
+    ),
+    responses(
+        (status = 200, description = "Receipt contents", content_type = "application/octet-stream"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event, expense or receipt not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn download_receipt(
+    State(app_state): State<AppState>,
+    Path((event_id, expense_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let (receipt, bytes) = app_state
+        .budget_service
+        .receipt(event_id, expense_id, user.id, may_manage)
+        .await?;
+
+    let filename = EventAttachment::sanitize_filename(&receipt.original_filename);
+    Ok(file_response(&receipt.mime_type, &filename, &receipt.original_filename, bytes))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/budget/expenses/{expense_id}/receipt",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("expense_id" = Uuid, Path, description = "Expense ID")
+    ),
+    responses(
+        (status = 200, description = "Receipt removed", body = ExpenseResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or expense not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "budgets"
+)]
+pub async fn delete_receipt(
+    State(app_state): State<AppState>,
+    Path((event_id, expense_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let expense = app_state
+        .budget_service
+        .remove_receipt(event_id, expense_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(ExpenseResponse::from(expense)))
+}
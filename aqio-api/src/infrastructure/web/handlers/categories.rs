@@ -1,5 +1,6 @@
 // Event category handlers - HTTP endpoints for event category management
 
+use aqio_core::{AuditAction, Permission};
 use axum::{
     Extension, Json,
     extract::{Path, State},
@@ -11,6 +12,7 @@ use crate::domain::{
     dto::{CreateEventCategoryRequest, EventCategoryResponse, UpdateEventCategoryRequest},
 };
 use crate::infrastructure::web::{
+    extractors::Permissions,
     response::{created_response, empty_success, success_response},
     state::AppState,
 };
@@ -31,10 +33,10 @@ pub async fn list_active_categories(
 
 pub async fn list_all_categories(
     State(app_state): State<AppState>,
-    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Only admins can see all categories (including inactive ones)
-    if !claims.is_admin() {
+    if !permissions.has(Permission::CategoryManage) {
         return Err(ApiError::authorization(
             "Only administrators can view all categories",
         ));
@@ -65,10 +67,11 @@ pub async fn get_category(
 pub async fn create_category(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
     Json(request): Json<CreateEventCategoryRequest>,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Only admins can create categories
-    if !claims.is_admin() {
+    if !permissions.has(Permission::CategoryManage) {
         return Err(ApiError::authorization(
             "Only administrators can create categories",
         ));
@@ -79,6 +82,17 @@ pub async fn create_category(
         .event_category_service
         .create_category(&category)
         .await?;
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "event_categories",
+            category.id.clone(),
+            AuditAction::Insert,
+            None,
+            Some(&category),
+        )
+        .await;
     Ok(created_response(EventCategoryResponse::from(category)))
 }
 
@@ -86,10 +100,11 @@ pub async fn update_category(
     State(app_state): State<AppState>,
     Path(category_id): Path<String>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
     Json(request): Json<UpdateEventCategoryRequest>,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Only admins can update categories
-    if !claims.is_admin() {
+    if !permissions.has(Permission::CategoryManage) {
         return Err(ApiError::authorization(
             "Only administrators can update categories",
         ));
@@ -100,12 +115,23 @@ pub async fn update_category(
         .event_category_service
         .get_category_by_id(&category_id)
         .await?;
-    let updated_category = request.apply_to_category(existing_category)?;
+    let updated_category = request.apply_to_category(existing_category.clone())?;
 
     app_state
         .event_category_service
         .update_category(&updated_category)
         .await?;
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "event_categories",
+            category_id,
+            AuditAction::Update,
+            Some(&existing_category),
+            Some(&updated_category),
+        )
+        .await;
     Ok(success_response(EventCategoryResponse::from(
         updated_category,
     )))
@@ -115,17 +141,33 @@ pub async fn delete_category(
     State(app_state): State<AppState>,
     Path(category_id): Path<String>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Only admins can delete categories
-    if !claims.is_admin() {
+    if !permissions.has(Permission::CategoryManage) {
         return Err(ApiError::authorization(
             "Only administrators can delete categories",
         ));
     }
 
+    let existing_category = app_state
+        .event_category_service
+        .get_category_by_id(&category_id)
+        .await?;
     app_state
         .event_category_service
         .delete_category(&category_id)
         .await?;
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "event_categories",
+            category_id,
+            AuditAction::Delete,
+            Some(&existing_category),
+            None,
+        )
+        .await;
     Ok(empty_success())
 }
@@ -0,0 +1,94 @@
+// Catering report handlers - dietary and accessibility needs of confirmed attendees, for organizers
+
+use axum::{
+    Extension,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{ApiError, ApiResult, dto::CateringReportResponse},
+    infrastructure::web::{
+        extractors::Permissions,
+        handlers::attachments::file_response,
+        response::success_response,
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/catering-report",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Needs grouped and counted, most people first", body = CateringReportResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "catering"
+)]
+pub async fn get_catering_report(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .catering_service
+        .report(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(CateringReportResponse::from(report)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/catering-report.csv",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The report as CSV for the venue", content_type = "text/csv"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "catering"
+)]
+pub async fn download_catering_report(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let (filename, csv) = app_state
+        .catering_service
+        .report_csv(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(file_response("text/csv; charset=utf-8", &filename, &filename, csv.into_bytes()))
+}
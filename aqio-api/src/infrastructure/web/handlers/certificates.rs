@@ -0,0 +1,168 @@
+// Certificate of attendance handlers - the PDF for checked-in attendees, the
+// organizer's signature printed on it and the public check behind its QR code
+
+use axum::{
+    Extension,
+    body::Bytes,
+    extract::{Path, State},
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{ApiError, ApiResult, dto::CertificateVerificationResponse},
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/registrations/{id}/certificate",
+    params(
+        ("id" = Uuid, Path, description = "Registration ID")
+    ),
+    responses(
+        (status = 200, description = "Certificate of attendance", content_type = "application/pdf"),
+        (status = 400, description = "The registration hasn't been checked in"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the attendee nor an organizer of the event"),
+        (status = 404, description = "Registration not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "certificates"
+)]
+pub async fn download_certificate(
+    State(app_state): State<AppState>,
+    Path(registration_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let requester = current_user(&app_state, &claims).await?;
+    let registration = app_state.registration_service.get_registration_by_id(registration_id).await?;
+    let may_manage = app_state
+        .permission_service
+        .manages_event(&permissions, registration.event_id)
+        .await?;
+    let (filename, pdf) = app_state
+        .certificate_service
+        .certificate(registration_id, requester.id, may_manage)
+        .await?;
+
+    // The generated name is plain ASCII, so no `filename*` is needed
+    let disposition = HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename))
+        .unwrap_or_else(|_| HeaderValue::from_static("attachment"));
+    Ok((
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static("application/pdf")),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        pdf,
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/certificate/signature",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body(content = Vec<u8>, content_type = "image/png", description = "Signature image, PNG up to 1 MB"),
+    responses(
+        (status = 204, description = "Signature saved"),
+        (status = 400, description = "Not a PNG image"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found"),
+        (status = 413, description = "Larger than 1 MB")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "certificates"
+)]
+pub async fn set_certificate_signature(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    body: Bytes,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .certificate_service
+        .set_signature(event_id, user.id, may_manage, &body)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/certificate/signature",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 204, description = "Signature removed; certificates show an empty signature line"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "certificates"
+)]
+pub async fn remove_certificate_signature(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .certificate_service
+        .remove_signature(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/certificates/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Registration ID printed on the certificate")
+    ),
+    responses(
+        (status = 200, description = "Whether the certificate is valid, with its details when it is", body = CertificateVerificationResponse)
+    ),
+    tag = "public"
+)]
+pub async fn verify_certificate(
+    State(app_state): State<AppState>,
+    Path(registration_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let details = app_state.certificate_service.verify(registration_id).await?;
+
+    Ok(success_response(CertificateVerificationResponse::new(registration_id, details)))
+}
@@ -0,0 +1,21 @@
+// HTTP handler for the settings the frontend reads at startup
+// Reachable without credentials, since the frontend needs them to sign in
+
+use axum::{extract::State, response::IntoResponse};
+
+use crate::{
+    domain::dto::ClientConfigResponse,
+    infrastructure::web::{response::success_response, state::AppState},
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/config",
+    responses(
+        (status = 200, description = "Auth mode, branding, languages, upload limit, feature flags and remote log level", body = ClientConfigResponse)
+    ),
+    tag = "config"
+)]
+pub async fn get_client_config(State(app_state): State<AppState>) -> impl IntoResponse {
+    success_response(ClientConfigResponse::from(app_state.client_config.as_ref()))
+}
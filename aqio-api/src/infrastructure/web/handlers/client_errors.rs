@@ -0,0 +1,32 @@
+// HTTP handler for errors reported by the web client
+// Reachable without credentials, since errors also happen on public pages
+
+use axum::{Json, http::StatusCode, response::IntoResponse};
+
+use crate::domain::{ApiResult, dto::ClientErrorReport};
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/client-errors",
+    request_body = ClientErrorReport,
+    responses(
+        (status = 202, description = "Error recorded"),
+        (status = 400, description = "Invalid report")
+    ),
+    tag = "client-errors"
+)]
+pub async fn report_client_error(
+    Json(report): Json<ClientErrorReport>,
+) -> ApiResult<impl IntoResponse> {
+    report.validate()?;
+
+    tracing::error!(
+        reference = %report.reference,
+        route = report.route.as_deref().unwrap_or("unknown"),
+        user_agent = report.user_agent.as_deref().unwrap_or("unknown"),
+        "client error: {}",
+        report.message
+    );
+
+    Ok(StatusCode::ACCEPTED)
+}
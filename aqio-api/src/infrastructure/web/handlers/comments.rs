@@ -0,0 +1,132 @@
+// Event discussion handlers - threaded comments attendees use for questions about an event
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{CommentResponse, CreateCommentRequest},
+        services::ThreadComment,
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/comments",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The event's comments, oldest first; deleted comments only appear when they have replies", body = Vec<CommentResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn list_comments(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let viewer = current_user(&app_state, &claims).await?;
+    let comments = app_state
+        .comment_service
+        .list_comments(event_id, viewer.id, may_manage)
+        .await?;
+    let response: Vec<CommentResponse> = comments.into_iter().map(CommentResponse::from).collect();
+    Ok(success_response(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/comments",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = CreateCommentRequest,
+    responses(
+        (status = 201, description = "Comment posted", body = CommentResponse),
+        (status = 400, description = "Empty or overly long comment"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event or parent comment not found"),
+        (status = 422, description = "The parent comment was deleted")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn create_comment(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<CreateCommentRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let author = current_user(&app_state, &claims).await?;
+    let comment = app_state
+        .comment_service
+        .create_comment(event_id, &author, request.parent_comment_id, &request.content)
+        .await?;
+
+    // Authors can always delete their own comments
+    Ok(created_response(CommentResponse::from(ThreadComment { comment, can_delete: true, is_hidden: false })))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/comments/{comment_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("comment_id" = Uuid, Path, description = "Comment ID")
+    ),
+    responses(
+        (status = 204, description = "Comment deleted; replies to it stay visible"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the author, the event organizer nor an administrator"),
+        (status = 404, description = "Event or comment not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "comments"
+)]
+pub async fn delete_comment(
+    State(app_state): State<AppState>,
+    Path((event_id, comment_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .comment_service
+        .delete_comment(event_id, comment_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
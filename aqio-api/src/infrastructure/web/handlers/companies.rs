@@ -0,0 +1,179 @@
+// Company directory handlers - browse aquaculture companies and their upcoming
+// events; administrators add companies, checked against the business register
+
+use aqio_core::{AuditAction, Permission};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiResult,
+        company_registry::normalize_org_number,
+        dto::{
+            CompanyDirectoryQuery, CompanyRegistryResponse, CompanyResponse, CreateCompanyRequest,
+            CreatedCompanyResponse, EventSummaryResponse, PaginatedCompanyResponse,
+        },
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, success_response},
+        state::AppState,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies",
+    params(CompanyDirectoryQuery),
+    responses(
+        (status = 200, description = "Companies sorted by name, with member counts", body = PaginatedCompanyResponse),
+        (status = 400, description = "Unknown industry type"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "companies"
+)]
+pub async fn list_companies(
+    State(app_state): State<AppState>,
+    Query(query): Query<CompanyDirectoryQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let (filter, pagination) = query.to_filter_and_pagination()?;
+    let result = app_state
+        .company_service
+        .list_directory(&filter, pagination)
+        .await?;
+    Ok(success_response(
+        PaginatedCompanyResponse::from_paginated_result(result),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Company with its member count", body = CompanyResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "companies"
+)]
+pub async fn get_company(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let entry = app_state.company_service.get_company_by_id(company_id).await?;
+    Ok(success_response(CompanyResponse::from(entry)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies/{id}/events",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Upcoming published, non-private events organized by the company's members, soonest first", body = Vec<EventSummaryResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "companies"
+)]
+pub async fn list_company_events(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let events = app_state
+        .company_service
+        .upcoming_public_events(company_id)
+        .await?;
+    let responses: Vec<EventSummaryResponse> = events
+        .into_iter()
+        .map(EventSummaryResponse::from)
+        .collect();
+    Ok(success_response(responses))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/company-registry/{org_number}",
+    params(
+        ("org_number" = String, Path, description = "Norwegian organization number, nine digits")
+    ),
+    responses(
+        (status = 200, description = "What the business register has on the number; `unavailable` when it couldn't be reached", body = CompanyRegistryResponse),
+        (status = 400, description = "Not nine digits, or the check digit doesn't match"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "companies"
+)]
+pub async fn lookup_company_registry(
+    State(app_state): State<AppState>,
+    Path(org_number): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let org_number = normalize_org_number(&org_number)?;
+    let lookup = app_state.company_service.lookup_registry(&org_number).await?;
+    Ok(success_response(CompanyRegistryResponse::new(org_number, lookup)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/companies",
+    request_body = CreateCompanyRequest,
+    responses(
+        (status = 201, description = "Company added, with blank fields filled in from the business register", body = CreatedCompanyResponse),
+        (status = 400, description = "Invalid organization number, one the register doesn't have or lists as closed, or a missing name"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required"),
+        (status = 409, description = "A company with this organization number already exists")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "companies"
+)]
+pub async fn create_company(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<CreateCompanyRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::OrganizationManage);
+    let created = app_state
+        .company_service
+        .create_company(may_manage, request.into_input()?)
+        .await?;
+
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "companies",
+            created.entry.company.id.to_string(),
+            AuditAction::Insert,
+            None,
+            Some(&created.entry.company),
+        )
+        .await;
+
+    Ok(created_response(CreatedCompanyResponse::from(created)))
+}
@@ -0,0 +1,218 @@
+// Consent handlers - the checkboxes organizers add to an event's registration
+// form, and the consent report they keep for compliance
+
+use aqio_core::{AuditAction, ConsentKind, User};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{ConfigureConsentRequest, ConsentReportResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        handlers::attachments::file_response,
+        response::{empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/consents",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Consent checkboxes shown when registering", body = Vec<EventConsent>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "consents"
+)]
+pub async fn list_consents(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let consents = app_state.consent_service.list(event_id).await?;
+    Ok(success_response(consents))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/consents/{kind}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("kind" = ConsentKind, Path, description = "Photo, DataProcessing, Marketing or LeadSharing")
+    ),
+    request_body = ConfigureConsentRequest,
+    responses(
+        (status = 200, description = "Checkbox added or updated; a changed text is a new version", body = EventConsent),
+        (status = 400, description = "Missing or too long text"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "consents"
+)]
+pub async fn configure_consent(
+    State(app_state): State<AppState>,
+    Path((event_id, kind)): Path<(Uuid, ConsentKind)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ConfigureConsentRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let (previous, consent) = app_state
+        .consent_service
+        .configure(event_id, kind, user.id, may_manage, request.text, request.required)
+        .await?;
+
+    let action = if previous.is_some() { AuditAction::Update } else { AuditAction::Insert };
+    app_state
+        .audit_log_service
+        .record_event_change(
+            &claims.audit_actor(),
+            event_id,
+            "event_consents",
+            consent.id.to_string(),
+            action,
+            previous.as_ref(),
+            Some(&consent),
+        )
+        .await;
+    Ok(success_response(consent))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/consents/{kind}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("kind" = ConsentKind, Path, description = "Photo, DataProcessing, Marketing or LeadSharing")
+    ),
+    responses(
+        (status = 204, description = "Checkbox removed; answers already given stay in the report"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or checkbox not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "consents"
+)]
+pub async fn remove_consent(
+    State(app_state): State<AppState>,
+    Path((event_id, kind)): Path<(Uuid, ConsentKind)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let consent = app_state
+        .consent_service
+        .remove(event_id, kind, user.id, may_manage)
+        .await?;
+
+    app_state
+        .audit_log_service
+        .record_event_change(
+            &claims.audit_actor(),
+            event_id,
+            "event_consents",
+            consent.id.to_string(),
+            AuditAction::Delete,
+            Some(&consent),
+            None,
+        )
+        .await;
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/consent-report",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Totals per checkbox and every registration's answers", body = ConsentReportResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "consents"
+)]
+pub async fn get_consent_report(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .consent_service
+        .report(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(ConsentReportResponse::from(report)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/consent-report.csv",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "One row per answer with the text agreed to", content_type = "text/csv"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "consents"
+)]
+pub async fn download_consent_report(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let (filename, csv) = app_state
+        .consent_service
+        .report_csv(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(file_response("text/csv; charset=utf-8", &filename, &filename, csv.into_bytes()))
+}
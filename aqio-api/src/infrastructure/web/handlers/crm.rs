@@ -0,0 +1,264 @@
+// CRM connector handlers - admin endpoints for connectors that sync
+// registrations to HubSpot or Dynamics 365, and their sync status
+
+use aqio_core::{AuditAction, Permission};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::auth::Claims;
+use crate::domain::{
+    ApiError, ApiResult,
+    dto::{CrmConnectorRequest, CrmConnectorStatusResponse},
+};
+use crate::infrastructure::web::{
+    extractors::Permissions,
+    response::{created_response, empty_success, success_response},
+    state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/crm/connectors",
+    responses(
+        (status = 200, description = "CRM connectors by name, with the outcome of their last sync", body = Vec<aqio_core::CrmConnector>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "crm"
+)]
+pub async fn list_crm_connectors(
+    State(app_state): State<AppState>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::CrmManage) {
+        return Err(ApiError::authorization("Only administrators can manage CRM connectors"));
+    }
+
+    let connectors = app_state.crm_service.list().await?;
+    Ok(success_response(connectors))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/crm/connectors",
+    request_body = CrmConnectorRequest,
+    responses(
+        (status = 201, description = "Connector created; an active one syncs everything on its first run", body = aqio_core::CrmConnector),
+        (status = 400, description = "Invalid URL, interval or field mappings"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "crm"
+)]
+pub async fn create_crm_connector(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<CrmConnectorRequest>,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::CrmManage) {
+        return Err(ApiError::authorization("Only administrators can manage CRM connectors"));
+    }
+
+    let user = app_state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))?;
+    let connector = app_state.crm_service.create(request.into(), user.id).await?;
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "crm_connectors",
+            connector.id.to_string(),
+            AuditAction::Insert,
+            None,
+            Some(&connector),
+        )
+        .await;
+    Ok(created_response(connector))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/crm/connectors/{id}",
+    params(
+        ("id" = Uuid, Path, description = "CRM connector ID")
+    ),
+    responses(
+        (status = 200, description = "The connector", body = aqio_core::CrmConnector),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "CRM connector not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "crm"
+)]
+pub async fn get_crm_connector(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::CrmManage) {
+        return Err(ApiError::authorization("Only administrators can manage CRM connectors"));
+    }
+
+    let connector = app_state.crm_service.get(id).await?;
+    Ok(success_response(connector))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/crm/connectors/{id}",
+    params(
+        ("id" = Uuid, Path, description = "CRM connector ID")
+    ),
+    request_body = CrmConnectorRequest,
+    responses(
+        (status = 200, description = "Connector updated; new mappings are applied to every registration on the next sync", body = aqio_core::CrmConnector),
+        (status = 400, description = "Invalid URL, interval or field mappings, or a different provider"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "CRM connector not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "crm"
+)]
+pub async fn update_crm_connector(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<CrmConnectorRequest>,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::CrmManage) {
+        return Err(ApiError::authorization("Only administrators can manage CRM connectors"));
+    }
+
+    let existing = app_state.crm_service.get(id).await?;
+    let connector = app_state.crm_service.update(id, request.into()).await?;
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "crm_connectors",
+            id.to_string(),
+            AuditAction::Update,
+            Some(&existing),
+            Some(&connector),
+        )
+        .await;
+    Ok(success_response(connector))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/crm/connectors/{id}",
+    params(
+        ("id" = Uuid, Path, description = "CRM connector ID")
+    ),
+    responses(
+        (status = 200, description = "Connector and its sync records deleted; nothing is removed from the CRM"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "CRM connector not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "crm"
+)]
+pub async fn delete_crm_connector(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::CrmManage) {
+        return Err(ApiError::authorization("Only administrators can manage CRM connectors"));
+    }
+
+    let existing = app_state.crm_service.get(id).await?;
+    app_state.crm_service.delete(id).await?;
+    app_state
+        .audit_log_service
+        .record_change(&claims.audit_actor(), "crm_connectors", id.to_string(), AuditAction::Delete, Some(&existing), None)
+        .await;
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/crm/connectors/{id}/status",
+    params(
+        ("id" = Uuid, Path, description = "CRM connector ID")
+    ),
+    responses(
+        (status = 200, description = "The last sync, records by state, and records in conflict or failed", body = CrmConnectorStatusResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "CRM connector not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "crm"
+)]
+pub async fn get_crm_connector_status(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::CrmManage) {
+        return Err(ApiError::authorization("Only administrators can manage CRM connectors"));
+    }
+
+    let status = app_state.crm_service.status(id).await?;
+    Ok(success_response(CrmConnectorStatusResponse::from(status)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/crm/connectors/{id}/sync",
+    params(
+        ("id" = Uuid, Path, description = "CRM connector ID")
+    ),
+    responses(
+        (status = 200, description = "Sync ran; the connector shows what it did, records that failed are retried on the next one", body = aqio_core::CrmConnector),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "CRM connector not found"),
+        (status = 503, description = "The sync couldn't run; the error is recorded on the connector")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "crm"
+)]
+pub async fn sync_crm_connector(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::CrmManage) {
+        return Err(ApiError::authorization("Only administrators can manage CRM connectors"));
+    }
+
+    let connector = app_state.crm_service.sync_now(id).await?;
+    Ok(success_response(connector))
+}
@@ -0,0 +1,78 @@
+// Email event handlers - bounce and complaint webhooks called by the mail
+// provider, authenticated by the token in their URL
+
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+};
+
+use crate::domain::{
+    ApiError, ApiResult,
+    dto::{DeliveryReportResponse, EmailWebhookQuery},
+};
+use crate::infrastructure::{
+    email_events::{self, SesMessage},
+    web::{response::success_response, state::AppState},
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/public/email-events/ses",
+    params(EmailWebhookQuery),
+    request_body(content = String, description = "SNS message carrying an SES bounce or complaint notification", content_type = "text/plain"),
+    responses(
+        (status = 200, description = "Notification recorded, or subscription confirmed", body = DeliveryReportResponse),
+        (status = 400, description = "Not an SNS message or SES notification"),
+        (status = 401, description = "Missing or wrong token"),
+        (status = 404, description = "EMAIL_WEBHOOK_SECRET is not configured")
+    ),
+    tag = "email-delivery"
+)]
+pub async fn receive_ses_events(
+    State(app_state): State<AppState>,
+    Query(query): Query<EmailWebhookQuery>,
+    body: String,
+) -> ApiResult<impl IntoResponse> {
+    let service = &app_state.email_delivery_service;
+    service.verify_webhook_token(query.token.as_deref())?;
+
+    let reports = match email_events::parse_ses(&body).map_err(ApiError::bad_request)? {
+        SesMessage::SubscriptionConfirmation { subscribe_url } => {
+            email_events::confirm_sns_subscription(&subscribe_url)
+                .await
+                .map_err(|e| ApiError::external_service("SNS", e))?;
+            Vec::new()
+        }
+        SesMessage::Reports(reports) => reports,
+    };
+    let outcome = service.record_reports("ses", &reports).await?;
+
+    Ok(success_response(DeliveryReportResponse::from(outcome)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/public/email-events/sendgrid",
+    params(EmailWebhookQuery),
+    request_body(content = String, description = "Batch of SendGrid Event Webhook events", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Bounces and spam reports recorded; other events are ignored", body = DeliveryReportResponse),
+        (status = 400, description = "Not a SendGrid event batch"),
+        (status = 401, description = "Missing or wrong token"),
+        (status = 404, description = "EMAIL_WEBHOOK_SECRET is not configured")
+    ),
+    tag = "email-delivery"
+)]
+pub async fn receive_sendgrid_events(
+    State(app_state): State<AppState>,
+    Query(query): Query<EmailWebhookQuery>,
+    body: String,
+) -> ApiResult<impl IntoResponse> {
+    let service = &app_state.email_delivery_service;
+    service.verify_webhook_token(query.token.as_deref())?;
+
+    let reports = email_events::parse_sendgrid(&body).map_err(ApiError::bad_request)?;
+    let outcome = service.record_reports("sendgrid", &reports).await?;
+
+    Ok(success_response(DeliveryReportResponse::from(outcome)))
+}
@@ -0,0 +1,113 @@
+// Event archive handlers - export an event with its child rows as JSON and
+// import such an archive as a new draft, e.g. to move it from staging to production
+
+use aqio_core::{AuditAction, User};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        archive::EventArchive,
+        dto::{EventArchiveQuery, EventImportResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        handlers::attachments::file_response,
+        response::created_response,
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/export",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        EventArchiveQuery
+    ),
+    responses(
+        (status = 200, description = "The event with its invitations, registrations, sponsors and budget; attachments and receipts are not included", body = EventArchive, content_type = "application/json"),
+        (status = 400, description = "Unknown field in `anonymize`"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "events"
+)]
+pub async fn export_event(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Query(query): Query<EventArchiveQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let anonymizer = query.anonymizer()?;
+    let user = current_user(&app_state, &claims).await?;
+    let (filename, archive) = app_state
+        .archive_service
+        .export(event_id, user.id, may_manage, &anonymizer)
+        .await?;
+    let json = serde_json::to_vec_pretty(&archive)
+        .map_err(|e| ApiError::internal(format!("Could not serialize the event archive: {}", e)))?;
+
+    Ok(file_response("application/json", &filename, &filename, json))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/import",
+    params(
+        EventArchiveQuery
+    ),
+    request_body = EventArchive,
+    responses(
+        (status = 201, description = "Imported as a new draft organized by the caller; every row gets a new id. Fields the server always anonymizes on import are scrubbed as well", body = EventImportResponse),
+        (status = 400, description = "Unsupported archive format, unknown category, invalid event or unknown field in `anonymize`"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "events"
+)]
+pub async fn import_event(
+    State(app_state): State<AppState>,
+    Query(query): Query<EventArchiveQuery>,
+    Extension(claims): Extension<Claims>,
+    Json(archive): Json<EventArchive>,
+) -> ApiResult<impl IntoResponse> {
+    let anonymizer = query.anonymizer()?;
+    let user = current_user(&app_state, &claims).await?;
+    let imported = app_state.archive_service.import(archive, &user, &anonymizer).await?;
+
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "events",
+            imported.event.id.to_string(),
+            AuditAction::Insert,
+            None,
+            Some(&imported.event),
+        )
+        .await;
+    Ok(created_response(EventImportResponse::from(imported)))
+}
@@ -0,0 +1,238 @@
+// Custom event field handlers - the fields an organization defines, readable by
+// everyone signed in and managed by its organizers, and each event's values
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::{Permission, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{EventFieldRequest, EventFieldResponse, EventFieldValuesRequest, EventFieldsResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies/{id}/event-fields",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "The company's custom event fields in display order", body = Vec<EventFieldResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-fields"
+)]
+pub async fn list_event_fields(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let definitions = app_state.event_field_service.definitions(company_id).await?;
+
+    Ok(success_response(
+        definitions.into_iter().map(EventFieldResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/companies/{id}/event-fields",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = EventFieldRequest,
+    responses(
+        (status = 201, description = "Field added", body = EventFieldResponse),
+        (status = 400, description = "Invalid key, missing label, or options that don't suit the type"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found"),
+        (status = 409, description = "The company already has a field with this key")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-fields"
+)]
+pub async fn create_event_field(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<EventFieldRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let definition = app_state
+        .event_field_service
+        .create_definition(company_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(EventFieldResponse::from(definition)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/companies/{id}/event-fields/{field_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("field_id" = Uuid, Path, description = "Event field ID")
+    ),
+    request_body = EventFieldRequest,
+    responses(
+        (status = 200, description = "Field updated", body = EventFieldResponse),
+        (status = 400, description = "Missing label, invalid options, or a changed key or type"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company or field not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-fields"
+)]
+pub async fn update_event_field(
+    State(app_state): State<AppState>,
+    Path((company_id, field_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<EventFieldRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let definition = app_state
+        .event_field_service
+        .update_definition(company_id, field_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(EventFieldResponse::from(definition)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/companies/{id}/event-fields/{field_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("field_id" = Uuid, Path, description = "Event field ID")
+    ),
+    responses(
+        (status = 204, description = "Field and every event's value for it removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company or field not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-fields"
+)]
+pub async fn delete_event_field(
+    State(app_state): State<AppState>,
+    Path((company_id, field_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .event_field_service
+        .delete_definition(company_id, field_id, &user, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/fields",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The custom fields of the organizer's organization, with the event's values", body = EventFieldsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-fields"
+)]
+pub async fn get_event_field_values(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    // Callers without a user record yet can read the values but not edit them
+    let viewer = app_state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?;
+    let fields = app_state
+        .event_field_service
+        .event_fields(event_id, viewer.as_ref(), may_manage)
+        .await?;
+
+    Ok(success_response(EventFieldsResponse::from(fields)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/fields",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = EventFieldValuesRequest,
+    responses(
+        (status = 200, description = "Values saved", body = EventFieldsResponse),
+        (status = 400, description = "Unknown field, a value that doesn't suit its type, or a required field left blank"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not one of the event's organizers"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-fields"
+)]
+pub async fn update_event_field_values(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<EventFieldValuesRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let fields = app_state
+        .event_field_service
+        .update_event_fields(event_id, &user, may_manage, request.values)
+        .await?;
+
+    Ok(success_response(EventFieldsResponse::from(fields)))
+}
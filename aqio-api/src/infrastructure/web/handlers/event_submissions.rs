@@ -0,0 +1,247 @@
+// Event submission handlers - users propose events that moderators approve
+// or reject before they are published
+
+use aqio_core::{AuditAction, Permission, User};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{CreateEventRequest, EventSubmissionResponse, ReviewEventSubmissionRequest},
+        services::EventSubmissionDetails,
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+async fn audit_submission(state: &AppState, claims: &Claims, details: &EventSubmissionDetails, action: AuditAction) {
+    state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "event_submissions",
+            details.submission.id.to_string(),
+            action,
+            None,
+            Some(&details.submission),
+        )
+        .await;
+}
+
+fn submission_list(submissions: Vec<EventSubmissionDetails>) -> Vec<EventSubmissionResponse> {
+    submissions.into_iter().map(EventSubmissionResponse::from).collect()
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/event-submissions",
+    request_body = CreateEventRequest,
+    responses(
+        (status = 201, description = "Event created as a draft and queued for moderation", body = EventSubmissionResponse),
+        (status = 400, description = "Invalid event"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-submissions"
+)]
+pub async fn submit_event(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<CreateEventRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let details = app_state.submission_service.submit(request, &user).await?;
+
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "events",
+            details.event.id.to_string(),
+            AuditAction::Insert,
+            None,
+            Some(&details.event),
+        )
+        .await;
+    audit_submission(&app_state, &claims, &details, AuditAction::Insert).await;
+    Ok(created_response(EventSubmissionResponse::from(details)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/event-submissions",
+    responses(
+        (status = 200, description = "Submissions waiting for a moderator, oldest first", body = Vec<EventSubmissionResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer or administrator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-submissions"
+)]
+pub async fn list_event_submission_queue(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventModerate);
+    let user = current_user(&app_state, &claims).await?;
+    let submissions = app_state.submission_service.queue(&user, may_manage).await?;
+    Ok(success_response(submission_list(submissions)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/event-submissions/mine",
+    responses(
+        (status = 200, description = "The caller's submissions and their reviews, newest first", body = Vec<EventSubmissionResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-submissions"
+)]
+pub async fn list_my_event_submissions(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let submissions = app_state.submission_service.mine(user.id).await?;
+    Ok(success_response(submission_list(submissions)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/event-submissions/{id}/approve",
+    params(
+        ("id" = Uuid, Path, description = "Submission ID")
+    ),
+    request_body = ReviewEventSubmissionRequest,
+    responses(
+        (status = 200, description = "Approved; the event is published, or scheduled if its publish time is still ahead", body = EventSubmissionResponse),
+        (status = 400, description = "Already reviewed, or comment too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a moderator, or reviewing one's own submission"),
+        (status = 404, description = "Submission not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-submissions"
+)]
+pub async fn approve_event_submission(
+    State(app_state): State<AppState>,
+    Path(submission_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ReviewEventSubmissionRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventModerate);
+    let user = current_user(&app_state, &claims).await?;
+    let details = app_state
+        .submission_service
+        .approve(submission_id, &user, may_manage, request.comment)
+        .await?;
+
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "events",
+            details.event.id.to_string(),
+            AuditAction::Update,
+            None,
+            Some(&details.event),
+        )
+        .await;
+    audit_submission(&app_state, &claims, &details, AuditAction::Update).await;
+    Ok(success_response(EventSubmissionResponse::from(details)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/event-submissions/{id}/reject",
+    params(
+        ("id" = Uuid, Path, description = "Submission ID")
+    ),
+    request_body = ReviewEventSubmissionRequest,
+    responses(
+        (status = 200, description = "Rejected; the event stays a draft the submitter can edit and resubmit", body = EventSubmissionResponse),
+        (status = 400, description = "Already reviewed, or comment missing or too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a moderator, or reviewing one's own submission"),
+        (status = 404, description = "Submission not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-submissions"
+)]
+pub async fn reject_event_submission(
+    State(app_state): State<AppState>,
+    Path(submission_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ReviewEventSubmissionRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventModerate);
+    let user = current_user(&app_state, &claims).await?;
+    let details = app_state
+        .submission_service
+        .reject(submission_id, &user, may_manage, request.comment)
+        .await?;
+
+    audit_submission(&app_state, &claims, &details, AuditAction::Update).await;
+    Ok(success_response(EventSubmissionResponse::from(details)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/event-submissions/{id}/resubmit",
+    params(
+        ("id" = Uuid, Path, description = "ID of the rejected submission")
+    ),
+    responses(
+        (status = 201, description = "The event is back in the moderation queue", body = EventSubmissionResponse),
+        (status = 400, description = "The submission wasn't rejected, or is already back in the queue"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Someone else's submission"),
+        (status = 404, description = "Submission not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "event-submissions"
+)]
+pub async fn resubmit_event_submission(
+    State(app_state): State<AppState>,
+    Path(submission_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let details = app_state.submission_service.resubmit(submission_id, &user).await?;
+
+    audit_submission(&app_state, &claims, &details, AuditAction::Insert).await;
+    Ok(created_response(EventSubmissionResponse::from(details)))
+}
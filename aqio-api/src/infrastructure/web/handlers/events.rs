@@ -3,6 +3,8 @@
 use axum::{
     Extension, Json,
     extract::{Path, Query, State},
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
 };
 use uuid::Uuid;
 use utoipa;
@@ -10,12 +12,35 @@ use utoipa;
 use crate::auth::Claims;
 use crate::domain::{
     ApiError, ApiResult,
-    dto::{CreateEventRequest, EventResponse, ListEventsQuery, PaginatedEventResponse},
+    dto::{
+        CreateEventRequest, EventAvailabilityResponse, EventResponse, EventView, EventViewQuery, EventWithDetailsResponse,
+        ListEventsQuery, PaginatedEventResponse, PaginatedEventSummaryResponse,
+        PaginatedEventWithDetailsResponse,
+    },
 };
 use crate::infrastructure::web::{
+    extractors::Permissions,
     response::{created_response, empty_success, success_response},
     state::AppState,
 };
+use aqio_core::{Event, PaginatedResult};
+
+// Availability is polled by public pages; a few seconds of staleness lets
+// shared caches absorb the polling
+const AVAILABILITY_CACHE_CONTROL: &str = "public, max-age=5";
+
+// Shape a page of events according to the requested view
+fn paginated_events_response(result: PaginatedResult<Event>, view: EventView) -> Response {
+    match view {
+        EventView::Full => {
+            success_response(PaginatedEventResponse::from_paginated_result(result)).into_response()
+        }
+        EventView::Summary => {
+            success_response(PaginatedEventSummaryResponse::from_paginated_result(result))
+                .into_response()
+        }
+    }
+}
 
 #[utoipa::path(
     post,
@@ -24,7 +49,8 @@ use crate::infrastructure::web::{
     responses(
         (status = 201, description = "Event created successfully", body = EventResponse),
         (status = 400, description = "Invalid request"),
-        (status = 401, description = "Unauthorized")
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Participants must propose events through /api/v1/event-submissions")
     ),
     security(
         ("bearer_auth" = [])
@@ -34,6 +60,7 @@ use crate::infrastructure::web::{
 pub async fn create_event(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
     Json(request): Json<CreateEventRequest>,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Look up user by Keycloak ID to get their database UUID
@@ -42,6 +69,11 @@ pub async fn create_event(
         .get_user_by_keycloak_id(&claims.sub)
         .await?
         .ok_or_else(|| ApiError::authentication("User not found"))?;
+    if app_state.submission_service.requires_review(&permissions) {
+        return Err(ApiError::authorization(
+            "Events from participants are reviewed first; submit it to /api/v1/event-submissions",
+        ));
+    }
 
     let event = app_state
         .event_service
@@ -58,7 +90,7 @@ pub async fn create_event(
         ("id" = Uuid, Path, description = "Event ID")
     ),
     responses(
-        (status = 200, description = "Event found", body = EventResponse),
+        (status = 200, description = "Event found, with the attachments the caller may download and the weather forecast for upcoming physical events", body = EventResponse),
         (status = 404, description = "Event not found")
     ),
     tag = "events"
@@ -66,10 +98,30 @@ pub async fn create_event(
 pub async fn get_event(
     State(app_state): State<AppState>,
     Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl axum::response::IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
     let event = app_state.event_service.get_event_by_id(event_id).await?;
 
-    Ok(success_response(EventResponse::from(event)))
+    // Callers without a user record yet only see public attachments
+    let viewer = app_state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?;
+    let attachments = app_state
+        .attachment_service
+        .list_attachments(event_id, viewer.map(|user| user.id), may_manage)
+        .await?;
+
+    let confirmed = app_state.registration_service.get_event_attendance_count(event_id).await?;
+    let forecast = app_state.event_service.weather_forecast(&event).await;
+
+    Ok(success_response(
+        EventResponse::with_confirmed_registrations(event, confirmed as i64)
+            .with_attachments(attachments)
+            .with_forecast(forecast),
+    ))
 }
 
 #[utoipa::path(
@@ -79,18 +131,68 @@ pub async fn get_event(
         ListEventsQuery
     ),
     responses(
-        (status = 200, description = "List of events", body = PaginatedEventResponse)
+        (status = 200, description = "List of events; items are `EventSummaryResponse` when `view=summary`", body = PaginatedEventResponse)
     ),
     tag = "events"
 )]
 pub async fn list_events(
     State(app_state): State<AppState>,
     Query(query): Query<ListEventsQuery>,
-) -> ApiResult<impl axum::response::IntoResponse> {
+) -> ApiResult<Response> {
+    let view = query.view.unwrap_or_default();
+    let query = app_state.event_field_service.normalize_query(query).await?;
     let result = app_state.event_service.list_events(query).await?;
 
+    Ok(paginated_events_response(result, view))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/details",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Event with category, organizer and registration counts", body = EventWithDetailsResponse),
+        (status = 404, description = "Event not found")
+    ),
+    tag = "events"
+)]
+pub async fn get_event_with_details(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    let details = app_state
+        .event_details_service
+        .get_event_with_details(event_id)
+        .await?;
+
+    Ok(success_response(EventWithDetailsResponse::from(details)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/details",
+    params(
+        ListEventsQuery
+    ),
+    responses(
+        (status = 200, description = "List of events with category, organizer and registration counts", body = PaginatedEventWithDetailsResponse)
+    ),
+    tag = "events"
+)]
+pub async fn list_events_with_details(
+    State(app_state): State<AppState>,
+    Query(query): Query<ListEventsQuery>,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    let query = app_state.event_field_service.normalize_query(query).await?;
+    let result = app_state
+        .event_details_service
+        .list_events_with_details(query)
+        .await?;
+
     Ok(success_response(
-        PaginatedEventResponse::from_paginated_result(result),
+        PaginatedEventWithDetailsResponse::from_paginated_result(result),
     ))
 }
 
@@ -173,10 +275,11 @@ pub async fn delete_event(
     get,
     path = "/api/v1/events/my",
     params(
-        crate::domain::dto::PaginationQuery
+        crate::domain::dto::PaginationQuery,
+        EventViewQuery
     ),
     responses(
-        (status = 200, description = "User's events", body = PaginatedEventResponse),
+        (status = 200, description = "User's events; items are `EventSummaryResponse` when `view=summary`", body = PaginatedEventResponse),
         (status = 401, description = "Unauthorized")
     ),
     security(
@@ -188,7 +291,8 @@ pub async fn get_my_events(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Query(pagination): Query<crate::domain::dto::PaginationQuery>,
-) -> ApiResult<impl axum::response::IntoResponse> {
+    Query(view_query): Query<EventViewQuery>,
+) -> ApiResult<Response> {
     // Look up user by Keycloak ID to get their database UUID
     let user = app_state
         .user_service
@@ -203,7 +307,31 @@ pub async fn get_my_events(
         .get_events_by_organizer(user.id, pagination_params)
         .await?;
 
-    Ok(success_response(
-        PaginatedEventResponse::from_paginated_result(result),
+    Ok(paginated_events_response(result, view_query.view.unwrap_or_default()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/availability",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Remaining seats, waitlist length and registration window; cacheable for a few seconds", body = EventAvailabilityResponse),
+        (status = 404, description = "Event not found, private or a draft")
+    ),
+    tag = "events"
+)]
+pub async fn get_event_availability(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let (mut event, counts) = app_state.event_details_service.availability(event_id).await?;
+    // Seats added by the event's overbooking policy are offered like any other
+    event.max_attendees = app_state.registration_service.bookable_capacity(&event).await?;
+
+    Ok((
+        [(header::CACHE_CONTROL, HeaderValue::from_static(AVAILABILITY_CACHE_CONTROL))],
+        success_response(EventAvailabilityResponse::new(&event, &counts, chrono::Utc::now())),
     ))
 }
@@ -0,0 +1,350 @@
+// Exhibitor handlers - booths on an expo's floor plan, the exhibitors taking
+// part, and which booth each exhibitor was allocated
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{AllocateBoothRequest, BoothRequest, ExhibitorRequest},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/booths",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The floor plan's booths by hall and number", body = [Booth]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exhibitors"
+)]
+pub async fn list_booths(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let booths = app_state
+        .exhibitor_service
+        .booths(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(booths))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/booths",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = BoothRequest,
+    responses(
+        (status = 201, description = "Booth added to the floor plan", body = Booth),
+        (status = 400, description = "Missing number, or a position or size out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found"),
+        (status = 409, description = "The number is taken or the booth overlaps another in its hall")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exhibitors"
+)]
+pub async fn create_booth(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<BoothRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let booth = app_state
+        .exhibitor_service
+        .create_booth(event_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(booth))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/booths/{booth_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("booth_id" = Uuid, Path, description = "Booth ID")
+    ),
+    request_body = BoothRequest,
+    responses(
+        (status = 200, description = "Booth moved or renumbered; its exhibitor stays", body = Booth),
+        (status = 400, description = "Missing number, or a position or size out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or booth not found"),
+        (status = 409, description = "The number is taken or the booth overlaps another in its hall")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exhibitors"
+)]
+pub async fn update_booth(
+    State(app_state): State<AppState>,
+    Path((event_id, booth_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<BoothRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let booth = app_state
+        .exhibitor_service
+        .update_booth(event_id, booth_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(booth))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/booths/{booth_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("booth_id" = Uuid, Path, description = "Booth ID")
+    ),
+    responses(
+        (status = 200, description = "Booth removed; its exhibitor is left without a booth"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or booth not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exhibitors"
+)]
+pub async fn delete_booth(
+    State(app_state): State<AppState>,
+    Path((event_id, booth_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .exhibitor_service
+        .delete_booth(event_id, booth_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/exhibitors",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The event's exhibitors by company name, with contact details", body = [Exhibitor]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exhibitors"
+)]
+pub async fn list_exhibitors(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let exhibitors = app_state
+        .exhibitor_service
+        .exhibitors(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(exhibitors))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/exhibitors",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = ExhibitorRequest,
+    responses(
+        (status = 201, description = "Exhibitor registered, without a booth", body = Exhibitor),
+        (status = 400, description = "Missing company name, invalid email or website, or text too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exhibitors"
+)]
+pub async fn create_exhibitor(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ExhibitorRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let exhibitor = app_state
+        .exhibitor_service
+        .create_exhibitor(event_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(exhibitor))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/exhibitors/{exhibitor_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("exhibitor_id" = Uuid, Path, description = "Exhibitor ID")
+    ),
+    request_body = ExhibitorRequest,
+    responses(
+        (status = 200, description = "Exhibitor updated; its booth is unchanged", body = Exhibitor),
+        (status = 400, description = "Missing company name, invalid email or website, or text too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or exhibitor not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exhibitors"
+)]
+pub async fn update_exhibitor(
+    State(app_state): State<AppState>,
+    Path((event_id, exhibitor_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ExhibitorRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let exhibitor = app_state
+        .exhibitor_service
+        .update_exhibitor(event_id, exhibitor_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(exhibitor))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/exhibitors/{exhibitor_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("exhibitor_id" = Uuid, Path, description = "Exhibitor ID")
+    ),
+    responses(
+        (status = 200, description = "Exhibitor removed; its booth is free again"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or exhibitor not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exhibitors"
+)]
+pub async fn delete_exhibitor(
+    State(app_state): State<AppState>,
+    Path((event_id, exhibitor_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .exhibitor_service
+        .delete_exhibitor(event_id, exhibitor_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/exhibitors/{exhibitor_id}/booth",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("exhibitor_id" = Uuid, Path, description = "Exhibitor ID")
+    ),
+    request_body = AllocateBoothRequest,
+    responses(
+        (status = 200, description = "Exhibitor moved to the booth, or left without one", body = Exhibitor),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event, exhibitor or booth not found"),
+        (status = 409, description = "The booth is allocated to another exhibitor")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exhibitors"
+)]
+pub async fn allocate_booth(
+    State(app_state): State<AppState>,
+    Path((event_id, exhibitor_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<AllocateBoothRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let exhibitor = app_state
+        .exhibitor_service
+        .allocate(event_id, exhibitor_id, request.booth_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(exhibitor))
+}
@@ -0,0 +1,225 @@
+// Scheduled export handlers - admin endpoints for jobs that push registrations
+// or events to external systems on a schedule
+
+use aqio_core::{AuditAction, Permission};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::auth::Claims;
+use crate::domain::{ApiError, ApiResult, dto::ExportJobRequest};
+use crate::infrastructure::web::{
+    extractors::Permissions,
+    response::{created_response, empty_success, success_response},
+    state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/exports",
+    responses(
+        (status = 200, description = "Scheduled exports by name, with the outcome of their last run", body = Vec<aqio_core::ExportJob>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exports"
+)]
+pub async fn list_export_jobs(
+    State(app_state): State<AppState>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::ExportManage) {
+        return Err(ApiError::authorization("Only administrators can manage scheduled exports"));
+    }
+
+    let jobs = app_state.export_service.list().await?;
+    Ok(success_response(jobs))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/exports",
+    request_body = ExportJobRequest,
+    responses(
+        (status = 201, description = "Export scheduled", body = aqio_core::ExportJob),
+        (status = 400, description = "Invalid schedule, time zone, filter or destination"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "The filter's event was not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exports"
+)]
+pub async fn create_export_job(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ExportJobRequest>,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::ExportManage) {
+        return Err(ApiError::authorization("Only administrators can manage scheduled exports"));
+    }
+
+    let user = app_state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))?;
+    let job = app_state.export_service.create(request.into(), user.id).await?;
+    app_state
+        .audit_log_service
+        .record_change(&claims.audit_actor(), "export_jobs", job.id.to_string(), AuditAction::Insert, None, Some(&job))
+        .await;
+    Ok(created_response(job))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/exports/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Export job ID")
+    ),
+    responses(
+        (status = 200, description = "The export job", body = aqio_core::ExportJob),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "Export job not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exports"
+)]
+pub async fn get_export_job(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::ExportManage) {
+        return Err(ApiError::authorization("Only administrators can manage scheduled exports"));
+    }
+
+    let job = app_state.export_service.get(id).await?;
+    Ok(success_response(job))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/exports/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Export job ID")
+    ),
+    request_body = ExportJobRequest,
+    responses(
+        (status = 200, description = "Export updated and rescheduled; its run history is kept", body = aqio_core::ExportJob),
+        (status = 400, description = "Invalid schedule, time zone, filter or destination"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "Export job or the filter's event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exports"
+)]
+pub async fn update_export_job(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ExportJobRequest>,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::ExportManage) {
+        return Err(ApiError::authorization("Only administrators can manage scheduled exports"));
+    }
+
+    let existing = app_state.export_service.get(id).await?;
+    let job = app_state.export_service.update(id, request.into()).await?;
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "export_jobs",
+            id.to_string(),
+            AuditAction::Update,
+            Some(&existing),
+            Some(&job),
+        )
+        .await;
+    Ok(success_response(job))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/exports/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Export job ID")
+    ),
+    responses(
+        (status = 200, description = "Export deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "Export job not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exports"
+)]
+pub async fn delete_export_job(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::ExportManage) {
+        return Err(ApiError::authorization("Only administrators can manage scheduled exports"));
+    }
+
+    let existing = app_state.export_service.get(id).await?;
+    app_state.export_service.delete(id).await?;
+    app_state
+        .audit_log_service
+        .record_change(&claims.audit_actor(), "export_jobs", id.to_string(), AuditAction::Delete, Some(&existing), None)
+        .await;
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/exports/{id}/run",
+    params(
+        ("id" = Uuid, Path, description = "Export job ID")
+    ),
+    responses(
+        (status = 200, description = "Export delivered; the job shows the row count", body = aqio_core::ExportJob),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Caller is not an administrator"),
+        (status = 404, description = "Export job not found"),
+        (status = 503, description = "The destination couldn't be reached or refused the file; the error is recorded on the job")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "exports"
+)]
+pub async fn run_export_job(
+    State(app_state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::ExportManage) {
+        return Err(ApiError::authorization("Only administrators can manage scheduled exports"));
+    }
+
+    let job = app_state.export_service.run_now(id).await?;
+    Ok(success_response(job))
+}
@@ -0,0 +1,101 @@
+// Financial report handlers - monthly ticket sales, refunds and VAT per rate,
+// as JSON or as CSV for accounting
+
+use axum::{
+    Extension,
+    extract::{Query, State},
+    response::{IntoResponse, Response},
+};
+use aqio_core::{Permission, User};
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{FinancialReportQuery, FinancialReportResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        handlers::attachments::file_response,
+        response::success_response,
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial-reports/monthly",
+    params(FinancialReportQuery),
+    responses(
+        (status = 200, description = "Sales, refunds and VAT collected in the month, per VAT rate", body = FinancialReportResponse),
+        (status = 400, description = "No such month, or no event chosen"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "financial-reports"
+)]
+pub async fn get_monthly_financial_report(
+    State(app_state): State<AppState>,
+    Query(query): Query<FinancialReportQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = match query.event_id {
+        Some(event_id) => app_state.permission_service.manages_event(&permissions, event_id).await?,
+        None => permissions.has(Permission::EventManage),
+    };
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .ticketing_service
+        .monthly_report(query.year, query.month, query.event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(FinancialReportResponse::from(report)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial-reports/monthly.csv",
+    params(FinancialReportQuery),
+    responses(
+        (status = 200, description = "The report as CSV for accounting, with a total row", content_type = "text/csv"),
+        (status = 400, description = "No such month, or no event chosen"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "financial-reports"
+)]
+pub async fn download_monthly_financial_report(
+    State(app_state): State<AppState>,
+    Query(query): Query<FinancialReportQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = match query.event_id {
+        Some(event_id) => app_state.permission_service.manages_event(&permissions, event_id).await?,
+        None => permissions.has(Permission::EventManage),
+    };
+    let user = current_user(&app_state, &claims).await?;
+    let (filename, csv) = app_state
+        .ticketing_service
+        .monthly_report_csv(query.year, query.month, query.event_id, user.id, may_manage)
+        .await?;
+
+    Ok(file_response("text/csv; charset=utf-8", &filename, &filename, csv.into_bytes()))
+}
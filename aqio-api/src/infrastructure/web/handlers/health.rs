@@ -5,6 +5,7 @@ use utoipa;
 
 use crate::domain::{
     ApiResult,
+    dto::IntegrationHealth,
 };
 use crate::infrastructure::web::{state::AppState, response::success_response};
 
@@ -12,7 +13,7 @@ use crate::infrastructure::web::{state::AppState, response::success_response};
     get,
     path = "/health/detailed",
     responses(
-        (status = 200, description = "Detailed health check", body = HealthResponse),
+        (status = 200, description = "Detailed health check; `degraded` while an external service's circuit breaker is open", body = HealthResponse),
         (status = 500, description = "Service unhealthy")
     ),
     tag = "health"
@@ -20,8 +21,19 @@ use crate::infrastructure::web::{state::AppState, response::success_response};
 pub async fn health_check(
     State(app_state): State<AppState>,
 ) -> ApiResult<impl axum::response::IntoResponse> {
+    let integrations = app_state
+        .resilience
+        .breakers()
+        .into_iter()
+        .map(|breaker| IntegrationHealth {
+            destination: breaker.destination,
+            state: breaker.state.as_str().to_string(),
+            consecutive_failures: breaker.consecutive_failures,
+            open_until: breaker.open_until,
+        })
+        .collect();
     let health = app_state.health_service.check_health().await?;
-    Ok(success_response(health))
+    Ok(success_response(health.with_integrations(integrations)))
 }
 
 #[utoipa::path(
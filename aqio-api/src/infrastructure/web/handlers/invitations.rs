@@ -1,17 +1,24 @@
 // Invitation handlers - HTTP endpoints for event invitations
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::{HeaderValue, header},
+    response::{IntoResponse, Redirect, Response},
     Extension, Json,
 };
+use aqio_core::{Event, Permission, PermissionChecker};
 use uuid::Uuid;
 
 use crate::auth::Claims;
 use crate::domain::{
-    dto::{CreateInvitationRequest, InvitationResponse, UpdateInvitationStatusRequest},
+    dto::{
+        CreateInvitationRequest, InvitationFunnelResponse, InvitationResponse, InvitationTrackingResponse,
+        TrackedLinkQuery, TrackedLinkRequest, TrackedLinkResponse, UpdateInvitationStatusRequest,
+    },
     ApiError, ApiResult,
 };
 use crate::infrastructure::web::{
+    extractors::Permissions,
     response::{created_response, empty_success, success_response},
     state::AppState,
 };
@@ -20,12 +27,30 @@ use crate::infrastructure::web::{
 pub async fn create_invitation(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
     Path(event_id): Path<Uuid>,
     Json(request): Json<CreateInvitationRequest>,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     let inviter_id = Uuid::parse_str(&claims.sub)
         .map_err(|_| ApiError::authentication("Invalid user ID format"))?;
 
+    // The event's own organizers invite freely; anyone else needs invitation:send
+    let event = app_state.event_service.get_event_by_id(event_id).await?;
+    let user_id = app_state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .map(|user| user.id);
+    let organizes = user_id.is_some_and(|id| event.organizer_id == id || event.co_organizers.contains(&id));
+    if !organizes
+        && !app_state
+            .permission_service
+            .allows_for_event(&permissions, Permission::InvitationSend, event_id)
+            .await?
+    {
+        return Err(ApiError::authorization("Only the event's organizers can send invitations"));
+    }
+
     let invitation = request.to_domain_invitation(event_id, inviter_id)?;
     app_state
         .invitation_service
@@ -106,3 +131,113 @@ pub async fn delete_invitation(
 
     Ok(empty_success())
 }
+
+// The event, if the signed-in user may see how its invitations perform
+async fn organized_event(
+    app_state: &AppState,
+    claims: &Claims,
+    permissions: &PermissionChecker,
+    event_id: Uuid,
+) -> ApiResult<Event> {
+    let event = app_state.event_service.get_event_by_id(event_id).await?;
+    let user_id = app_state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .map(|user| user.id)
+        .ok_or_else(|| ApiError::authentication("User not found"))?;
+
+    let may_send = app_state
+        .permission_service
+        .allows_for_event(permissions, Permission::InvitationSend, event_id)
+        .await?;
+    if !(may_send || event.organizer_id == user_id || event.co_organizers.contains(&user_id)) {
+        return Err(ApiError::authorization("Only the event's organizers can track its invitations"));
+    }
+    Ok(event)
+}
+
+// Tracking pixel and event link to embed in the invitation email
+pub async fn get_invitation_tracking(
+    State(app_state): State<AppState>,
+    Path(invitation_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let invitation = app_state.invitation_service.get_invitation_by_id(invitation_id).await?;
+    let event = organized_event(&app_state, &claims, &permissions, invitation.event_id).await?;
+    let (_, token) = app_state.invitation_service.tracking_token(invitation_id).await?;
+
+    let tracking = app_state.invitation_service.tracking();
+    Ok(success_response(InvitationTrackingResponse {
+        pixel_url: tracking.pixel_url(&token),
+        event_url: tracking.link_url(&token, &app_state.event_service.public_page_url(&event.slug))?,
+    }))
+}
+
+// Wrap any other link in the invitation email so following it is recorded
+pub async fn create_tracked_link(
+    State(app_state): State<AppState>,
+    Path(invitation_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<TrackedLinkRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let invitation = app_state.invitation_service.get_invitation_by_id(invitation_id).await?;
+    organized_event(&app_state, &claims, &permissions, invitation.event_id).await?;
+    let (_, token) = app_state.invitation_service.tracking_token(invitation_id).await?;
+
+    let url = app_state.invitation_service.tracking().link_url(&token, &request.url)?;
+    Ok(success_response(TrackedLinkResponse { url }))
+}
+
+// How far the event's invitations got: sent, opened, clicked and answered
+pub async fn get_invitation_funnel(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    organized_event(&app_state, &claims, &permissions, event_id).await?;
+    let funnel = app_state.invitation_service.funnel(event_id).await?;
+
+    Ok(success_response(InvitationFunnelResponse::from(funnel)))
+}
+
+// Transparent 1x1 GIF
+const TRACKING_PIXEL: &[u8] = b"GIF89a\x01\x00\x01\x00\x80\x00\x00\x00\x00\x00\xff\xff\xff!\xf9\x04\x01\x00\x00\x00\x00,\x00\x00\x00\x00\x01\x00\x01\x00\x00\x02\x02D\x01\x00;";
+
+// Public: the pixel in the invitation email. Always served, so it gives away
+// nothing about which tokens exist.
+pub async fn track_open(
+    State(app_state): State<AppState>,
+    Path(token): Path<String>,
+) -> Response {
+    if let Err(e) = app_state.invitation_service.record_open(&token).await {
+        tracing::warn!("Failed to record invitation open: {}", e);
+    }
+
+    (
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static("image/gif")),
+            // Every open has to reach us, not a cache
+            (header::CACHE_CONTROL, HeaderValue::from_static("no-store, max-age=0")),
+        ],
+        TRACKING_PIXEL,
+    )
+        .into_response()
+}
+
+// Public: a tracked link from the invitation email
+pub async fn track_click(
+    State(app_state): State<AppState>,
+    Path(token): Path<String>,
+    Query(query): Query<TrackedLinkQuery>,
+) -> ApiResult<Redirect> {
+    let target = app_state
+        .invitation_service
+        .record_click(&token, &query.url, &query.sig)
+        .await?;
+
+    Ok(Redirect::to(&target))
+}
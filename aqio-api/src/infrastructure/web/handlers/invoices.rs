@@ -0,0 +1,214 @@
+// Invoicing handlers - invoices for ticket orders bought by organizations,
+// their EHF export, and overdue tracking
+
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use aqio_core::{Permission, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{InvoiceListQuery, InvoiceRequest, InvoiceResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/ticket-orders/{order_id}/invoice",
+    params(
+        ("order_id" = Uuid, Path, description = "Ticket order ID")
+    ),
+    request_body = InvoiceRequest,
+    responses(
+        (status = 201, description = "Invoice issued under the next invoice number", body = InvoiceResponse),
+        (status = 400, description = "Invalid billing details, a cancelled order, or invoicing isn't set up"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the buyer nor an organizer"),
+        (status = 404, description = "Order not found"),
+        (status = 409, description = "The order has already been invoiced")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "invoices"
+)]
+pub async fn create_invoice(
+    State(app_state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<InvoiceRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let invoice = app_state
+        .invoicing_service
+        .issue(order_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(InvoiceResponse::from(invoice)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/invoices",
+    params(InvoiceListQuery),
+    responses(
+        (status = 200, description = "Invoices, oldest due date first", body = Vec<InvoiceResponse>),
+        (status = 400, description = "No event chosen"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "invoices"
+)]
+pub async fn list_invoices(
+    State(app_state): State<AppState>,
+    Query(query): Query<InvoiceListQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let invoices = app_state
+        .invoicing_service
+        .list(user.id, may_manage, query.into())
+        .await?;
+
+    Ok(success_response(
+        invoices.into_iter().map(InvoiceResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/invoices/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Invoice ID")
+    ),
+    responses(
+        (status = 200, description = "The invoice", body = InvoiceResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the buyer nor an organizer"),
+        (status = 404, description = "Invoice not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "invoices"
+)]
+pub async fn get_invoice(
+    State(app_state): State<AppState>,
+    Path(invoice_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let invoice = app_state
+        .invoicing_service
+        .get(invoice_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(InvoiceResponse::from(invoice)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/invoices/{id}/ehf.xml",
+    params(
+        ("id" = Uuid, Path, description = "Invoice ID")
+    ),
+    responses(
+        (status = 200, description = "The invoice as EHF (PEPPOL BIS Billing 3.0)", content_type = "application/xml"),
+        (status = 400, description = "The invoice has been voided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the buyer nor an organizer"),
+        (status = 404, description = "Invoice not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "invoices"
+)]
+pub async fn download_invoice_ehf(
+    State(app_state): State<AppState>,
+    Path(invoice_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let (invoice, xml) = app_state
+        .invoicing_service
+        .ehf(invoice_id, user.id, may_manage)
+        .await?;
+
+    let disposition = HeaderValue::from_str(&format!("attachment; filename=\"invoice-{}.xml\"", invoice.invoice_number))
+        .unwrap_or_else(|_| HeaderValue::from_static("attachment"));
+    Ok((
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static("application/xml; charset=utf-8")),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        xml,
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/invoices/{id}/paid",
+    params(
+        ("id" = Uuid, Path, description = "Invoice ID")
+    ),
+    responses(
+        (status = 200, description = "Payment recorded", body = InvoiceResponse),
+        (status = 400, description = "The invoice has been voided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Invoice not found"),
+        (status = 409, description = "Already paid")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "invoices"
+)]
+pub async fn mark_invoice_paid(
+    State(app_state): State<AppState>,
+    Path(invoice_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let invoice = app_state
+        .invoicing_service
+        .mark_paid(invoice_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(InvoiceResponse::from(invoice)))
+}
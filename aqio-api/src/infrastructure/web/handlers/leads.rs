@@ -0,0 +1,210 @@
+// Lead handlers - exhibitors' staff scanning attendee badges at the stand,
+// their lead lists and CSV exports, and the organizers' lead analytics
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{LeadAnalyticsResponse, LeadResponse, ScanLeadRequest},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        handlers::attachments::file_response,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/exhibitors/{exhibitor_id}/leads",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("exhibitor_id" = Uuid, Path, description = "Exhibitor ID")
+    ),
+    request_body = ScanLeadRequest,
+    responses(
+        (status = 201, description = "Attendee captured as a lead", body = Lead),
+        (status = 200, description = "The badge was scanned before; the first scan's lead", body = Lead),
+        (status = 400, description = "Not a badge for this event, the attendee didn't consent to lead sharing, or notes too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the exhibitor's staff nor an organizer of the event"),
+        (status = 404, description = "Event or exhibitor not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "leads"
+)]
+pub async fn scan_lead(
+    State(app_state): State<AppState>,
+    Path((event_id, exhibitor_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ScanLeadRequest>,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let (lead, created) = app_state
+        .lead_service
+        .scan(event_id, exhibitor_id, &request.code, request.notes, &user, may_manage)
+        .await?;
+
+    Ok(if created {
+        created_response(lead).into_response()
+    } else {
+        success_response(lead).into_response()
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/exhibitors/{exhibitor_id}/leads",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("exhibitor_id" = Uuid, Path, description = "Exhibitor ID")
+    ),
+    responses(
+        (status = 200, description = "The exhibitor's leads in scan order, with contact details", body = [LeadResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the exhibitor's staff nor an organizer of the event"),
+        (status = 404, description = "Event or exhibitor not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "leads"
+)]
+pub async fn list_leads(
+    State(app_state): State<AppState>,
+    Path((event_id, exhibitor_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let leads = app_state
+        .lead_service
+        .leads(event_id, exhibitor_id, &user, may_manage)
+        .await?;
+
+    Ok(success_response(leads.into_iter().map(LeadResponse::from).collect::<Vec<_>>()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/exhibitors/{exhibitor_id}/leads.csv",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("exhibitor_id" = Uuid, Path, description = "Exhibitor ID")
+    ),
+    responses(
+        (status = 200, description = "One row per lead with the attendee's contact details", content_type = "text/csv"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the exhibitor's staff nor an organizer of the event"),
+        (status = 404, description = "Event or exhibitor not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "leads"
+)]
+pub async fn download_leads(
+    State(app_state): State<AppState>,
+    Path((event_id, exhibitor_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let (filename, csv) = app_state
+        .lead_service
+        .export_csv(event_id, exhibitor_id, &user, may_manage)
+        .await?;
+
+    Ok(file_response("text/csv; charset=utf-8", &filename, &filename, csv.into_bytes()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/exhibitors/{exhibitor_id}/leads/{lead_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("exhibitor_id" = Uuid, Path, description = "Exhibitor ID"),
+        ("lead_id" = Uuid, Path, description = "Lead ID")
+    ),
+    responses(
+        (status = 200, description = "Lead removed; the badge can be scanned again"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the exhibitor's staff nor an organizer of the event"),
+        (status = 404, description = "Event, exhibitor or lead not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "leads"
+)]
+pub async fn delete_lead(
+    State(app_state): State<AppState>,
+    Path((event_id, exhibitor_id, lead_id)): Path<(Uuid, Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .lead_service
+        .delete_lead(event_id, exhibitor_id, lead_id, &user, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/leads/analytics",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Leads per exhibitor and per hour across the event", body = LeadAnalyticsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "leads"
+)]
+pub async fn lead_analytics(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let analytics = app_state
+        .lead_service
+        .analytics(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(LeadAnalyticsResponse::from(analytics)))
+}
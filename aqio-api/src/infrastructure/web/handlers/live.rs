@@ -0,0 +1,329 @@
+// Live engagement handlers - audience Q&A and polls while an event is running
+
+use std::convert::Infallible;
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::{
+        IntoResponse,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use aqio_core::User;
+use tokio_stream::{Stream, StreamExt, wrappers::BroadcastStream};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{
+            AnswerQuestionRequest, AskQuestionRequest, CreatePollRequest, LiveSessionResponse, PollResponse,
+            PollVoteRequest, QuestionResponse,
+        },
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/live",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The event's questions and polls with current results", body = LiveSessionResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "live"
+)]
+pub async fn get_live_session(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let viewer = current_user(&app_state, &claims).await?;
+    let session = app_state
+        .live_service
+        .session(event_id, viewer.id, may_manage)
+        .await?;
+    Ok(success_response(LiveSessionResponse::from(session)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/questions",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = AskQuestionRequest,
+    responses(
+        (status = 201, description = "Question asked", body = QuestionResponse),
+        (status = 400, description = "Empty or overly long question"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found"),
+        (status = 422, description = "The event is not running")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "live"
+)]
+pub async fn ask_question(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<AskQuestionRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let author = current_user(&app_state, &claims).await?;
+    let question = app_state
+        .live_service
+        .ask_question(event_id, &author, &request.content)
+        .await?;
+    Ok(created_response(QuestionResponse::from(question)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/questions/{question_id}/upvote",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("question_id" = Uuid, Path, description = "Question ID")
+    ),
+    responses(
+        (status = 200, description = "Question upvoted; upvoting twice counts once", body = QuestionResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event or question not found"),
+        (status = 422, description = "The event is not running")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "live"
+)]
+pub async fn upvote_question(
+    State(app_state): State<AppState>,
+    Path((event_id, question_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let question = app_state
+        .live_service
+        .set_upvote(event_id, question_id, user.id, true)
+        .await?;
+    Ok(success_response(QuestionResponse::from(question)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/questions/{question_id}/upvote",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("question_id" = Uuid, Path, description = "Question ID")
+    ),
+    responses(
+        (status = 200, description = "Upvote withdrawn", body = QuestionResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event or question not found"),
+        (status = 422, description = "The event is not running")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "live"
+)]
+pub async fn remove_question_upvote(
+    State(app_state): State<AppState>,
+    Path((event_id, question_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let question = app_state
+        .live_service
+        .set_upvote(event_id, question_id, user.id, false)
+        .await?;
+    Ok(success_response(QuestionResponse::from(question)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/questions/{question_id}/answer",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("question_id" = Uuid, Path, description = "Question ID")
+    ),
+    request_body = AnswerQuestionRequest,
+    responses(
+        (status = 200, description = "Answer saved, replacing any earlier answer", body = QuestionResponse),
+        (status = 400, description = "Empty or overly long answer"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or question not found"),
+        (status = 422, description = "The event is not running")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "live"
+)]
+pub async fn answer_question(
+    State(app_state): State<AppState>,
+    Path((event_id, question_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<AnswerQuestionRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let question = app_state
+        .live_service
+        .answer_question(event_id, question_id, user.id, may_manage, &request.answer)
+        .await?;
+    Ok(success_response(QuestionResponse::from(question)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/polls",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = CreatePollRequest,
+    responses(
+        (status = 201, description = "Poll opened", body = PollResponse),
+        (status = 400, description = "Invalid question or options"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found"),
+        (status = 422, description = "The event is not running")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "live"
+)]
+pub async fn create_poll(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<CreatePollRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let poll = app_state
+        .live_service
+        .create_poll(event_id, user.id, may_manage, &request.question, &request.options)
+        .await?;
+    Ok(created_response(PollResponse::from(poll)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/polls/{poll_id}/vote",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("poll_id" = Uuid, Path, description = "Poll ID")
+    ),
+    request_body = PollVoteRequest,
+    responses(
+        (status = 200, description = "Vote recorded, replacing any earlier vote", body = PollResponse),
+        (status = 400, description = "The option does not belong to the poll"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event or poll not found"),
+        (status = 422, description = "The event is not running or the poll is closed")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "live"
+)]
+pub async fn vote_in_poll(
+    State(app_state): State<AppState>,
+    Path((event_id, poll_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<PollVoteRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let poll = app_state
+        .live_service
+        .vote(event_id, poll_id, request.option_id, user.id)
+        .await?;
+    Ok(success_response(PollResponse::from(poll)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/polls/{poll_id}/close",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("poll_id" = Uuid, Path, description = "Poll ID")
+    ),
+    responses(
+        (status = 200, description = "Poll closed; its results stay visible", body = PollResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or poll not found"),
+        (status = 422, description = "The event is not running")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "live"
+)]
+pub async fn close_poll(
+    State(app_state): State<AppState>,
+    Path((event_id, poll_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let poll = app_state
+        .live_service
+        .close_poll(event_id, poll_id, user.id, may_manage)
+        .await?;
+    Ok(success_response(PollResponse::from(poll)))
+}
+
+/// Server-sent events with question and poll changes for one event
+pub async fn live_update_stream(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<Sse<impl Stream<Item = Result<Event, Infallible>>>> {
+    current_user(&app_state, &claims).await?;
+    // Subscribe before returning so nothing published after the client's initial fetch is missed
+    let receiver = app_state.live_service.notifier().subscribe();
+
+    // Lagged receivers drop the missed messages; each update carries the full item, so the next one catches up
+    let stream = BroadcastStream::new(receiver).filter_map(move |message| {
+        let message = message.ok().filter(|message| message.event_id == event_id)?;
+        let event = Event::default()
+            .event(message.update.event_name())
+            .json_data(&message.update)
+            .ok()?;
+        Some(Ok(event))
+    });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
@@ -0,0 +1,409 @@
+// Matchmaking handlers - attendees' matchmaking profiles and suggested
+// matches, the organizers' meeting rooms, and 1:1 meetings with their
+// accept/decline flow and calendar invites
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{MatchResponse, MatchmakingProfileRequest, MeetingResponse, MeetingRoomsRequest, RequestMeetingRequest},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        extractors::EffectiveLocale,
+        handlers::attachments::file_response,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/matchmaking/profile",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Your matchmaking profile for the event", body = MatchmakingProfile),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found, or you haven't joined its matchmaking")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn get_matchmaking_profile(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let profile = app_state.matchmaking_service.profile(event_id, user.id).await?;
+
+    Ok(success_response(profile))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/matchmaking/profile",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = MatchmakingProfileRequest,
+    responses(
+        (status = 200, description = "Profile saved; you're now suggested to the event's other attendees", body = MatchmakingProfile),
+        (status = 400, description = "Too many or too long interests, bio too long, or availability outside the event"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not registered for the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn save_matchmaking_profile(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<MatchmakingProfileRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let profile = app_state
+        .matchmaking_service
+        .save_profile(event_id, user.id, request.into())
+        .await?;
+
+    Ok(success_response(profile))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/matchmaking/profile",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Left the event's matchmaking; your meetings are cancelled"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found, or you haven't joined its matchmaking")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn leave_matchmaking(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    app_state.matchmaking_service.leave(event_id, user.id).await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/matchmaking/matches",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The event's other attendees taking part, most shared interests first", body = [MatchResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found, or you haven't joined its matchmaking")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn list_matches(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let matches = app_state.matchmaking_service.matches(event_id, user.id).await?;
+
+    Ok(success_response(matches.into_iter().map(MatchResponse::from).collect::<Vec<_>>()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/matchmaking/rooms",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The resources the event's meetings are held in", body = [Resource]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn list_meeting_rooms(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let rooms = app_state
+        .matchmaking_service
+        .rooms(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(rooms))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/matchmaking/rooms",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = MeetingRoomsRequest,
+    responses(
+        (status = 200, description = "Meeting rooms replaced; meetings already scheduled keep theirs", body = [Resource]),
+        (status = 400, description = "A resource is retired"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or resource not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn set_meeting_rooms(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<MeetingRoomsRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let rooms = app_state
+        .matchmaking_service
+        .set_rooms(event_id, user.id, may_manage, request.resource_ids)
+        .await?;
+
+    Ok(success_response(rooms))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/meetings",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = RequestMeetingRequest,
+    responses(
+        (status = 201, description = "Meeting requested in the first slot you're both free, with a room booked if the event has meeting rooms", body = Meeting),
+        (status = 400, description = "Requested a meeting with yourself, or message too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found, or you or the invitee haven't joined its matchmaking"),
+        (status = 409, description = "Already meeting the invitee, or no free slot or room left")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn request_meeting(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<RequestMeetingRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let meeting = app_state
+        .matchmaking_service
+        .request_meeting(event_id, user.id, request.invitee_id, request.message)
+        .await?;
+
+    Ok(created_response(meeting))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/meetings",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Your meetings at the event, earliest first", body = [MeetingResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn list_meetings(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let meetings = app_state.matchmaking_service.meetings(event_id, user.id).await?;
+
+    Ok(success_response(meetings.into_iter().map(MeetingResponse::from).collect::<Vec<_>>()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/meetings/{meeting_id}/accept",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("meeting_id" = Uuid, Path, description = "Meeting ID")
+    ),
+    responses(
+        (status = 200, description = "Meeting accepted", body = Meeting),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the meeting's invitee"),
+        (status = 404, description = "Meeting not found"),
+        (status = 409, description = "The meeting was already answered or cancelled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn accept_meeting(
+    State(app_state): State<AppState>,
+    Path((event_id, meeting_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let meeting = app_state
+        .matchmaking_service
+        .answer(event_id, meeting_id, user.id, true)
+        .await?;
+
+    Ok(success_response(meeting))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/meetings/{meeting_id}/decline",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("meeting_id" = Uuid, Path, description = "Meeting ID")
+    ),
+    responses(
+        (status = 200, description = "Meeting declined; its room is free again", body = Meeting),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the meeting's invitee"),
+        (status = 404, description = "Meeting not found"),
+        (status = 409, description = "The meeting was already answered or cancelled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn decline_meeting(
+    State(app_state): State<AppState>,
+    Path((event_id, meeting_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let meeting = app_state
+        .matchmaking_service
+        .answer(event_id, meeting_id, user.id, false)
+        .await?;
+
+    Ok(success_response(meeting))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/meetings/{meeting_id}/cancel",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("meeting_id" = Uuid, Path, description = "Meeting ID")
+    ),
+    responses(
+        (status = 200, description = "Meeting cancelled; its room is free again", body = Meeting),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Meeting not found"),
+        (status = 409, description = "The meeting was already declined or cancelled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn cancel_meeting(
+    State(app_state): State<AppState>,
+    Path((event_id, meeting_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let meeting = app_state
+        .matchmaking_service
+        .cancel(event_id, meeting_id, user.id)
+        .await?;
+
+    Ok(success_response(meeting))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/meetings/{meeting_id}/invite.ics",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("meeting_id" = Uuid, Path, description = "Meeting ID")
+    ),
+    responses(
+        (status = 200, description = "The meeting as an iCalendar invite; cancelled and declined meetings cancel it", content_type = "text/calendar"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event or meeting not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "matchmaking"
+)]
+pub async fn download_meeting_invite(
+    State(app_state): State<AppState>,
+    Path((event_id, meeting_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    EffectiveLocale(localization): EffectiveLocale,
+) -> ApiResult<Response> {
+    let user = current_user(&app_state, &claims).await?;
+    let (filename, ics) = app_state
+        .matchmaking_service
+        .invite(event_id, meeting_id, user.id, &localization)
+        .await?;
+
+    Ok(file_response("text/calendar; charset=utf-8", &filename, &filename, ics.into_bytes()))
+}
@@ -0,0 +1,186 @@
+// Message handlers - conversations about an event between its organizers and
+// one attendee, with unread counts for the inbox
+
+use aqio_core::{Permission, User};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{
+            ContactOrganizersRequest, MessageThreadDetailsResponse, MessageThreadResponse, ReplyMessageRequest,
+            UnreadMessagesResponse,
+        },
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/messages",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = ContactOrganizersRequest,
+    responses(
+        (status = 201, description = "Message sent to the organizers, in the caller's thread about the event", body = ThreadMessage),
+        (status = 400, description = "Missing or too long message, or the caller organizes the event"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not registered for the event"),
+        (status = 404, description = "Event or broadcast not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "messages"
+)]
+pub async fn contact_organizers(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<ContactOrganizersRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let message = app_state
+        .messaging_service
+        .contact(event_id, &user, request.body, request.broadcast_id)
+        .await?;
+
+    Ok(created_response(message))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/messages",
+    responses(
+        (status = 200, description = "The caller's threads as an attendee and as an organizer, most recent activity first", body = Vec<MessageThreadResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "messages"
+)]
+pub async fn list_message_threads(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let threads = app_state.messaging_service.inbox(user.id).await?;
+
+    Ok(success_response(
+        threads.into_iter().map(MessageThreadResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/messages/unread",
+    responses(
+        (status = 200, description = "Unread messages across the caller's threads", body = UnreadMessagesResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "messages"
+)]
+pub async fn count_unread_messages(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let unread = app_state.messaging_service.unread_count(user.id).await?;
+
+    Ok(success_response(UnreadMessagesResponse { unread }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/messages/{thread_id}",
+    params(
+        ("thread_id" = Uuid, Path, description = "Message thread ID")
+    ),
+    responses(
+        (status = 200, description = "The thread with its messages, oldest first; marked read for the caller's side", body = MessageThreadDetailsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the thread's attendee nor an organizer of its event"),
+        (status = 404, description = "Thread not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "messages"
+)]
+pub async fn get_message_thread(
+    State(app_state): State<AppState>,
+    Path(thread_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let (thread, messages) = app_state
+        .messaging_service
+        .thread(thread_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(MessageThreadDetailsResponse {
+        thread: MessageThreadResponse::from(thread),
+        messages,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/messages/{thread_id}",
+    params(
+        ("thread_id" = Uuid, Path, description = "Message thread ID")
+    ),
+    request_body = ReplyMessageRequest,
+    responses(
+        (status = 201, description = "Reply added to the thread", body = ThreadMessage),
+        (status = 400, description = "Missing or too long message"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the thread's attendee nor an organizer of its event"),
+        (status = 404, description = "Thread not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "messages"
+)]
+pub async fn reply_to_message_thread(
+    State(app_state): State<AppState>,
+    Path(thread_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ReplyMessageRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let message = app_state
+        .messaging_service
+        .reply(thread_id, &user, may_manage, request.body)
+        .await?;
+
+    Ok(created_response(message))
+}
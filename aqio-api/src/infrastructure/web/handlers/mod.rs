@@ -3,13 +3,109 @@
 pub mod events;
 pub mod health;
 pub mod users;
+pub mod notification_preferences;
 pub mod categories;
+pub mod companies;
+pub mod comments;
+pub mod live;
+pub mod attachments;
+pub mod photos;
+pub mod budgets;
+pub mod sponsors;
+pub mod short_links;
+pub mod posters;
+pub mod overbooking;
+pub mod tickets;
+pub mod invoices;
+pub mod financial_reports;
+pub mod refunds;
+pub mod travel;
+pub mod catering;
+pub mod seating;
+pub mod resources;
+pub mod shifts;
+pub mod exhibitors;
+pub mod leads;
+pub mod matchmaking;
+pub mod past_events;
+pub mod annual_reports;
+pub mod consents;
+pub mod broadcasts;
+pub mod messages;
+pub mod scheduling;
+pub mod event_fields;
+pub mod sending_domains;
+pub mod organization_invitations;
+pub mod organization_roles;
+pub mod access_grants;
+pub mod exports;
+pub mod crm;
+pub mod ownership_transfers;
+pub mod event_submissions;
+pub mod reports;
+pub mod event_archives;
+pub mod storage;
+pub mod email_events;
+pub mod certificates;
 pub mod invitations;
 pub mod registrations;
+pub mod public_events;
+pub mod admin;
+pub mod client_errors;
+pub mod telemetry;
+pub mod client_config;
 
 pub use events::*;
 pub use health::*;
 pub use users::*;
+pub use notification_preferences::*;
 pub use categories::*;
+pub use companies::*;
+pub use comments::*;
+pub use live::*;
+pub use attachments::*;
+pub use photos::*;
+pub use budgets::*;
+pub use sponsors::*;
+pub use short_links::*;
+pub use posters::*;
+pub use overbooking::*;
+pub use tickets::*;
+pub use invoices::*;
+pub use financial_reports::*;
+pub use refunds::*;
+pub use travel::*;
+pub use catering::*;
+pub use seating::*;
+pub use resources::*;
+pub use shifts::*;
+pub use exhibitors::*;
+pub use leads::*;
+pub use matchmaking::*;
+pub use past_events::*;
+pub use annual_reports::*;
+pub use consents::*;
+pub use broadcasts::*;
+pub use messages::*;
+pub use scheduling::*;
+pub use event_fields::*;
+pub use sending_domains::*;
+pub use organization_invitations::*;
+pub use organization_roles::*;
+pub use access_grants::*;
+pub use exports::*;
+pub use crm::*;
+pub use ownership_transfers::*;
+pub use event_submissions::*;
+pub use reports::*;
+pub use event_archives::*;
+pub use storage::*;
+pub use email_events::*;
+pub use certificates::*;
 pub use invitations::*;
-pub use registrations::*;
\ No newline at end of file
+pub use registrations::*;
+pub use public_events::*;
+pub use admin::*;
+pub use client_errors::*;
+pub use telemetry::*;
+pub use client_config::*;
\ No newline at end of file
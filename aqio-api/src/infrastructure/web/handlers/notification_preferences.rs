@@ -0,0 +1,78 @@
+// Notification preference handlers - the signed-in user's own settings
+
+use axum::{
+    Extension, Json,
+    extract::State,
+    response::IntoResponse,
+};
+use aqio_core::User;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{NotificationPreferencesRequest, NotificationPreferencesResponse},
+    },
+    infrastructure::web::{
+        response::success_response,
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/notification-preferences",
+    responses(
+        (status = 200, description = "The caller's notification settings; immediate delivery when never changed", body = NotificationPreferencesResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+)]
+pub async fn get_notification_preferences(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let preferences = app_state.notification_digest_service.preferences(user.id).await?;
+
+    Ok(success_response(NotificationPreferencesResponse::from(preferences)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/users/me/notification-preferences",
+    request_body = NotificationPreferencesRequest,
+    responses(
+        (status = 200, description = "Settings saved", body = NotificationPreferencesResponse),
+        (status = 400, description = "Unknown digest frequency"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+)]
+pub async fn update_notification_preferences(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<NotificationPreferencesRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let preferences = app_state
+        .notification_digest_service
+        .update_preferences(user.id, request.digest_frequency)
+        .await?;
+
+    Ok(success_response(NotificationPreferencesResponse::from(preferences)))
+}
@@ -0,0 +1,147 @@
+// Organization invitation handlers - organizers invite colleagues by email;
+// the invitee opens the join link and accepts after signing in
+
+use aqio_core::{AuditAction, Permission, User};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{CreateOrganizationInvitationRequest, OrganizationInvitationResponse, UserResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/companies/{id}/invitations",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = CreateOrganizationInvitationRequest,
+    responses(
+        (status = 201, description = "Invitation emailed; an earlier pending invitation to the address is withdrawn", body = OrganizationInvitationResponse),
+        (status = 400, description = "Invalid address or role, already a member, or the address is suppressed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organization-invitations"
+)]
+pub async fn create_organization_invitation(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<CreateOrganizationInvitationRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let from = app_state
+        .sending_domain_service
+        .sender(company_id)
+        .await?
+        .map(|sending_domain| sending_domain.sender());
+    let details = app_state
+        .organization_invitation_service
+        .invite(company_id, &user, may_manage, &request.email, request.role, from)
+        .await?;
+
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "organization_invitations",
+            details.invitation.id.to_string(),
+            AuditAction::Insert,
+            None,
+            Some(&details.invitation),
+        )
+        .await;
+    Ok(created_response(OrganizationInvitationResponse::from(details)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/organization-invitations/{token}",
+    params(
+        ("token" = String, Path, description = "Token from the join link")
+    ),
+    responses(
+        (status = 200, description = "The company and address the link invites; `is_open` tells whether it can still be accepted", body = OrganizationInvitationResponse),
+        (status = 404, description = "Unknown link")
+    ),
+    tag = "organization-invitations"
+)]
+pub async fn get_organization_invitation_by_token(
+    State(app_state): State<AppState>,
+    Path(token): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let details = app_state.organization_invitation_service.preview(&token).await?;
+    Ok(success_response(OrganizationInvitationResponse::from(details)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/organization-invitations/{token}/accept",
+    params(
+        ("token" = String, Path, description = "Token from the join link")
+    ),
+    responses(
+        (status = 200, description = "Joined the company; the account is created on first login", body = UserResponse),
+        (status = 400, description = "Invitation used, withdrawn or expired, or the caller belongs to another organization"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "The invitation was sent to another address"),
+        (status = 404, description = "Unknown link")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organization-invitations"
+)]
+pub async fn accept_organization_invitation(
+    State(app_state): State<AppState>,
+    Path(token): Path<String>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let before = app_state.user_service.get_user_by_keycloak_id(&claims.sub).await?;
+    let user = app_state
+        .organization_invitation_service
+        .accept(&token, &claims.sub, &claims.email, &claims.name)
+        .await?;
+
+    let action = if before.is_some() { AuditAction::Update } else { AuditAction::Insert };
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "users",
+            user.id.to_string(),
+            action,
+            before.as_ref(),
+            Some(&user),
+        )
+        .await;
+    Ok(success_response(UserResponse::from(user)))
+}
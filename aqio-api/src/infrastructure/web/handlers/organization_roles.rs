@@ -0,0 +1,282 @@
+// Organization role handlers - custom roles an organization's organizers define
+// for their colleagues, and who holds them
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::{Permission, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{OrganizationRoleRequest, OrganizationRoleResponse, UserResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies/{id}/roles",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "The company's custom roles by name", body = Vec<OrganizationRoleResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organization-roles"
+)]
+pub async fn list_organization_roles(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let roles = app_state
+        .permission_service
+        .roles(company_id, &user, may_manage)
+        .await?;
+
+    Ok(success_response(
+        roles.into_iter().map(OrganizationRoleResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/companies/{id}/roles",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = OrganizationRoleRequest,
+    responses(
+        (status = 201, description = "Role created", body = OrganizationRoleResponse),
+        (status = 400, description = "Missing name or a permission organizations can't hand out"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found"),
+        (status = 409, description = "The company already has a role with that name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organization-roles"
+)]
+pub async fn create_organization_role(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<OrganizationRoleRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let role = app_state
+        .permission_service
+        .create_role(company_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(OrganizationRoleResponse::from(role)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/companies/{id}/roles/{role_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("role_id" = Uuid, Path, description = "Role ID")
+    ),
+    request_body = OrganizationRoleRequest,
+    responses(
+        (status = 200, description = "Role updated; its members get the new permissions right away", body = OrganizationRoleResponse),
+        (status = 400, description = "Missing name or a permission organizations can't hand out"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company or role not found"),
+        (status = 409, description = "The company already has a role with that name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organization-roles"
+)]
+pub async fn update_organization_role(
+    State(app_state): State<AppState>,
+    Path((company_id, role_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<OrganizationRoleRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let role = app_state
+        .permission_service
+        .update_role(company_id, role_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(OrganizationRoleResponse::from(role)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/companies/{id}/roles/{role_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("role_id" = Uuid, Path, description = "Role ID")
+    ),
+    responses(
+        (status = 204, description = "Role deleted and taken from its members"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company or role not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organization-roles"
+)]
+pub async fn delete_organization_role(
+    State(app_state): State<AppState>,
+    Path((company_id, role_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .permission_service
+        .delete_role(company_id, role_id, &user, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies/{id}/roles/{role_id}/members",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("role_id" = Uuid, Path, description = "Role ID")
+    ),
+    responses(
+        (status = 200, description = "Users holding the role, in the order they got it", body = Vec<UserResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company or role not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organization-roles"
+)]
+pub async fn list_organization_role_members(
+    State(app_state): State<AppState>,
+    Path((company_id, role_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let members = app_state
+        .permission_service
+        .members(company_id, role_id, &user, may_manage)
+        .await?;
+
+    Ok(success_response(
+        members.into_iter().map(UserResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/companies/{id}/roles/{role_id}/members/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("role_id" = Uuid, Path, description = "Role ID"),
+        ("user_id" = Uuid, Path, description = "Member to give the role")
+    ),
+    responses(
+        (status = 204, description = "The user holds the role"),
+        (status = 400, description = "The user isn't a member of the company"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company, role or user not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organization-roles"
+)]
+pub async fn add_organization_role_member(
+    State(app_state): State<AppState>,
+    Path((company_id, role_id, member_id)): Path<(Uuid, Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .permission_service
+        .add_member(company_id, role_id, member_id, &user, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/companies/{id}/roles/{role_id}/members/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("role_id" = Uuid, Path, description = "Role ID"),
+        ("user_id" = Uuid, Path, description = "Member to take the role from")
+    ),
+    responses(
+        (status = 204, description = "The user no longer holds the role"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company or role not found, or the user doesn't hold it")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "organization-roles"
+)]
+pub async fn remove_organization_role_member(
+    State(app_state): State<AppState>,
+    Path((company_id, role_id, member_id)): Path<(Uuid, Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .permission_service
+        .remove_member(company_id, role_id, member_id, &user, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
@@ -0,0 +1,167 @@
+// Overbooking handlers - the policy organizers set from their no-show history
+// and how the event's turnout compared to its capacity
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{OverbookingReportResponse, OverbookingResponse, SetOverbookingPolicyRequest},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/overbooking",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The overbooking policy, with the no-show rate of the organizer's past events", body = OverbookingResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "overbooking"
+)]
+pub async fn get_overbooking(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let overview = app_state
+        .overbooking_service
+        .overview(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(OverbookingResponse::new(event_id, overview)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/overbooking",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = SetOverbookingPolicyRequest,
+    responses(
+        (status = 200, description = "Policy saved", body = OverbookingResponse),
+        (status = 400, description = "The event has no capacity limit, the percentage is out of range or the ceiling is below the capacity"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "overbooking"
+)]
+pub async fn set_overbooking_policy(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<SetOverbookingPolicyRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let service = &app_state.overbooking_service;
+    service
+        .set_policy(event_id, user.id, may_manage, request.percent, request.hard_ceiling)
+        .await?;
+    let overview = service.overview(event_id, user.id, may_manage).await?;
+
+    Ok(success_response(OverbookingResponse::new(event_id, overview)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/overbooking",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 204, description = "The event no longer takes registrations beyond its capacity"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "overbooking"
+)]
+pub async fn remove_overbooking_policy(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .overbooking_service
+        .remove_policy(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/overbooking/report",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Check-ins and no-shows against the capacity", body = OverbookingReportResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "overbooking"
+)]
+pub async fn get_overbooking_report(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .overbooking_service
+        .report(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(OverbookingReportResponse::new(event_id, report)))
+}
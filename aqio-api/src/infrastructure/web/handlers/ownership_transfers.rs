@@ -0,0 +1,272 @@
+// Event ownership transfer handlers - the organizer asks another organizer to
+// take over an event; it changes hands once they accept
+
+use aqio_core::{AuditAction, Permission, User};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{OwnershipTransferResponse, TransferOwnershipRequest},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/transfer-ownership",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = TransferOwnershipRequest,
+    responses(
+        (status = 201, description = "Transfer requested; the proposed owner is notified and an earlier pending request is withdrawn", body = OwnershipTransferResponse),
+        (status = 400, description = "Already the organizer, not an active organizer, or message too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the event's organizer"),
+        (status = 404, description = "Event or user not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "ownership-transfers"
+)]
+pub async fn transfer_event_ownership(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<TransferOwnershipRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let details = app_state
+        .ownership_service
+        .request(event_id, &user, may_manage, request.new_owner_id, request.message)
+        .await?;
+
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "event_ownership_transfers",
+            details.transfer.id.to_string(),
+            AuditAction::Insert,
+            None,
+            Some(&details.transfer),
+        )
+        .await;
+    Ok(created_response(OwnershipTransferResponse::from(details)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/transfer-ownership",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The event's pending ownership transfer", body = OwnershipTransferResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither an organizer of the event nor the proposed owner"),
+        (status = 404, description = "Event not found or no pending transfer")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "ownership-transfers"
+)]
+pub async fn get_pending_ownership_transfer(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let details = app_state
+        .ownership_service
+        .pending(event_id, user.id, may_manage)
+        .await?;
+    Ok(success_response(OwnershipTransferResponse::from(details)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/transfer-ownership",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 204, description = "Pending transfer withdrawn"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not the event's organizer"),
+        (status = 404, description = "Event not found or no pending transfer")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "ownership-transfers"
+)]
+pub async fn cancel_ownership_transfer(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let transfer = app_state
+        .ownership_service
+        .cancel(event_id, user.id, may_manage)
+        .await?;
+
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "event_ownership_transfers",
+            transfer.id.to_string(),
+            AuditAction::Update,
+            None,
+            Some(&transfer),
+        )
+        .await;
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/ownership-transfers",
+    responses(
+        (status = 200, description = "Transfers waiting for the caller to accept or decline, newest first", body = Vec<OwnershipTransferResponse>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "ownership-transfers"
+)]
+pub async fn list_incoming_ownership_transfers(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let transfers = app_state.ownership_service.incoming(user.id).await?;
+    Ok(success_response(
+        transfers
+            .into_iter()
+            .map(OwnershipTransferResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/ownership-transfers/{id}/accept",
+    params(
+        ("id" = Uuid, Path, description = "Transfer ID")
+    ),
+    responses(
+        (status = 200, description = "The caller now organizes the event; the previous organizer stays on as a co-organizer", body = OwnershipTransferResponse),
+        (status = 400, description = "Already answered or withdrawn, or the event changed hands meanwhile"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "The transfer is for someone else"),
+        (status = 404, description = "Transfer not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "ownership-transfers"
+)]
+pub async fn accept_ownership_transfer(
+    State(app_state): State<AppState>,
+    Path(transfer_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let completed = app_state.ownership_service.accept(transfer_id, user.id).await?;
+
+    let actor = claims.audit_actor();
+    app_state
+        .audit_log_service
+        .record_change(
+            &actor,
+            "events",
+            completed.event.id.to_string(),
+            AuditAction::Update,
+            Some(&completed.previous),
+            Some(&completed.event),
+        )
+        .await;
+    app_state
+        .audit_log_service
+        .record_change(
+            &actor,
+            "event_ownership_transfers",
+            completed.transfer.id.to_string(),
+            AuditAction::Update,
+            None,
+            Some(&completed.transfer),
+        )
+        .await;
+    Ok(success_response(OwnershipTransferResponse::from(completed)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/ownership-transfers/{id}/decline",
+    params(
+        ("id" = Uuid, Path, description = "Transfer ID")
+    ),
+    responses(
+        (status = 200, description = "Transfer declined; the organizer is notified", body = OwnershipTransferResponse),
+        (status = 400, description = "Already answered or withdrawn"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "The transfer is for someone else"),
+        (status = 404, description = "Transfer not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "ownership-transfers"
+)]
+pub async fn decline_ownership_transfer(
+    State(app_state): State<AppState>,
+    Path(transfer_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let details = app_state.ownership_service.decline(transfer_id, user.id).await?;
+
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "event_ownership_transfers",
+            details.transfer.id.to_string(),
+            AuditAction::Update,
+            None,
+            Some(&details.transfer),
+        )
+        .await;
+    Ok(success_response(OwnershipTransferResponse::from(details)))
+}
@@ -0,0 +1,89 @@
+// Past event handlers - the archive of events that are over, with their
+// attendance and year-over-year totals per category or organizer
+
+use aqio_core::{Permission, User};
+use axum::{
+    Extension,
+    extract::{Query, State},
+    response::IntoResponse,
+};
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{AttendanceTrendResponse, AttendanceTrendsQuery, PaginatedPastEventResponse, PastEventsQuery},
+    },
+    infrastructure::web::{extractors::Permissions, response::success_response, state::AppState},
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/past",
+    params(PastEventsQuery),
+    responses(
+        (status = 200, description = "Events that are over, most recent first, with their attendance", body = PaginatedPastEventResponse),
+        (status = 400, description = "Invalid pagination"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "past-events"
+)]
+pub async fn list_past_events(
+    State(app_state): State<AppState>,
+    Query(query): Query<PastEventsQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let viewer = current_user(&app_state, &claims).await?;
+    let (filter, pagination) = query.to_filter_and_pagination()?;
+    let result = app_state
+        .past_events_service
+        .browse(viewer.id, may_manage, filter, pagination)
+        .await?;
+
+    Ok(success_response(PaginatedPastEventResponse::from_paginated_result(result)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/past/trends",
+    params(AttendanceTrendsQuery),
+    responses(
+        (status = 200, description = "Attendance per category, organizer or county and year, each year compared with the one before", body = Vec<AttendanceTrendResponse>),
+        (status = 400, description = "from_year after to_year, or unknown grouping"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "past-events"
+)]
+pub async fn get_attendance_trends(
+    State(app_state): State<AppState>,
+    Query(query): Query<AttendanceTrendsQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let viewer = current_user(&app_state, &claims).await?;
+    let trends = app_state
+        .past_events_service
+        .trends(viewer.id, may_manage, query.group_by.unwrap_or_default(), query.to_filter())
+        .await?;
+
+    Ok(success_response(
+        trends.into_iter().map(AttendanceTrendResponse::from).collect::<Vec<_>>(),
+    ))
+}
@@ -0,0 +1,315 @@
+// Event photo handlers - the gallery organizers and attendees fill once an
+// event is over, with moderation and a download of the whole album
+
+use aqio_core::{EventPhoto, User};
+use axum::{
+    Extension, Json,
+    extract::{Multipart, Path, State},
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{ModeratePhotoRequest, PhotoAlbumRequest, PhotoGalleryResponse, PhotoResponse},
+        services::NewPhoto,
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        handlers::attachments::file_response,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+async fn read_upload(mut multipart: Multipart) -> ApiResult<NewPhoto> {
+    let mut file = None;
+    let mut caption = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::bad_request(e.body_text()))?
+    {
+        match field.name().unwrap_or_default() {
+            "file" => {
+                let filename = field.file_name().unwrap_or_default().to_string();
+                let mime_type = field
+                    .content_type()
+                    .unwrap_or("application/octet-stream")
+                    .to_string();
+                let bytes = field.bytes().await.map_err(|e| ApiError::bad_request(e.body_text()))?;
+                file = Some((filename, mime_type, bytes));
+            }
+            "caption" => caption = Some(field.text().await.map_err(|e| ApiError::bad_request(e.body_text()))?),
+            _ => {}
+        }
+    }
+
+    let (original_filename, mime_type, bytes) =
+        file.ok_or_else(|| ApiError::validation("file", "No file was uploaded"))?;
+    Ok(NewPhoto {
+        original_filename,
+        mime_type,
+        caption,
+        bytes: bytes.to_vec(),
+    })
+}
+
+/// Serve the photo under the name it was uploaded with
+pub(crate) fn photo_response(photo: EventPhoto, bytes: Vec<u8>) -> Response {
+    file_response(&photo.mime_type, &photo.filename, &photo.original_filename, bytes)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/photos",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The album's settings and the photos the caller may see, oldest first", body = PhotoGalleryResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "photos"
+)]
+pub async fn get_photo_gallery(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let viewer = current_user(&app_state, &claims).await?;
+    let gallery = app_state
+        .photo_service
+        .gallery(event_id, Some(viewer.id), may_manage)
+        .await?;
+
+    Ok(success_response(PhotoGalleryResponse::from(gallery)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/photos",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body(content = UploadPhotoForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Photo added; an attendee's may wait for approval", body = PhotoResponse),
+        (status = 400, description = "Missing or empty file, not an image, or the event isn't over yet"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither an organizer nor, when the album allows it, an attendee"),
+        (status = 404, description = "Event not found"),
+        (status = 413, description = "Photo larger than the upload limit"),
+        (status = 507, description = "The organization's storage quota is used up")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "photos"
+)]
+pub async fn upload_photo(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    multipart: Multipart,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let uploader = current_user(&app_state, &claims).await?;
+    let upload = read_upload(multipart).await?;
+    let photo = app_state
+        .photo_service
+        .upload(event_id, &uploader, may_manage, upload)
+        .await?;
+
+    Ok(created_response(PhotoResponse::new(photo, true)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/photos/album",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = PhotoAlbumRequest,
+    responses(
+        (status = 200, description = "Album settings saved", body = PhotoAlbum),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "photos"
+)]
+pub async fn update_photo_album(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<PhotoAlbumRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let album = app_state
+        .photo_service
+        .update_album(event_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(album))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/photos/download",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Every approved photo the caller may see, as a ZIP file", content_type = "application/zip"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found"),
+        (status = 413, description = "The album is too large to download at once")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "photos"
+)]
+pub async fn download_photo_album(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let viewer = current_user(&app_state, &claims).await?;
+    let (filename, bytes) = app_state
+        .photo_service
+        .download_all(event_id, viewer.id, may_manage)
+        .await?;
+
+    Ok(file_response("application/zip", &filename, &filename, bytes))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/photos/{photo_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("photo_id" = Uuid, Path, description = "Photo ID")
+    ),
+    responses(
+        (status = 200, description = "The photo", content_type = "image/*"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event or photo not found, or not in the caller's gallery")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "photos"
+)]
+pub async fn download_photo(
+    State(app_state): State<AppState>,
+    Path((event_id, photo_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let viewer = current_user(&app_state, &claims).await?;
+    let (photo, bytes) = app_state
+        .photo_service
+        .download(event_id, photo_id, Some(viewer.id), may_manage)
+        .await?;
+
+    Ok(photo_response(photo, bytes))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/photos/{photo_id}/status",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("photo_id" = Uuid, Path, description = "Photo ID")
+    ),
+    request_body = ModeratePhotoRequest,
+    responses(
+        (status = 200, description = "Photo approved or rejected", body = PhotoResponse),
+        (status = 400, description = "Status other than Approved or Rejected"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or photo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "photos"
+)]
+pub async fn moderate_photo(
+    State(app_state): State<AppState>,
+    Path((event_id, photo_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ModeratePhotoRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let photo = app_state
+        .photo_service
+        .moderate(event_id, photo_id, user.id, may_manage, request.status)
+        .await?;
+
+    Ok(success_response(PhotoResponse::new(photo, true)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/photos/{photo_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("photo_id" = Uuid, Path, description = "Photo ID")
+    ),
+    responses(
+        (status = 204, description = "Photo removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither an organizer of the event nor the photo's uploader"),
+        (status = 404, description = "Event or photo not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "photos"
+)]
+pub async fn delete_photo(
+    State(app_state): State<AppState>,
+    Path((event_id, photo_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .photo_service
+        .delete(event_id, photo_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
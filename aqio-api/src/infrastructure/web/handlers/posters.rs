@@ -0,0 +1,110 @@
+// Event poster handlers - the printable PDF and the PNG for social media
+
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use aqio_core::{PermissionChecker, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{ApiError, ApiResult, dto::PosterQuery, services::PosterFormat},
+    infrastructure::web::{extractors::Permissions, state::AppState},
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+async fn poster(
+    app_state: &AppState,
+    claims: &Claims,
+    permissions: &PermissionChecker,
+    event_id: Uuid,
+    query: PosterQuery,
+    format: PosterFormat,
+) -> ApiResult<Response> {
+    let user = current_user(app_state, claims).await?;
+    let may_manage = app_state.permission_service.manages_event(permissions, event_id).await?;
+    let (filename, bytes) = app_state
+        .poster_service
+        .render(event_id, user.id, may_manage, format, query.short_link)
+        .await?;
+
+    // The generated name is plain ASCII, so no `filename*` is needed
+    let disposition = HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename))
+        .unwrap_or_else(|_| HeaderValue::from_static("attachment"));
+    Ok((
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static(format.content_type())),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/poster.pdf",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        PosterQuery
+    ),
+    responses(
+        (status = 200, description = "A4 poster with a QR code to the event's public page", content_type = "application/pdf"),
+        (status = 400, description = "The event is private"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or short link not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posters"
+)]
+pub async fn download_poster_pdf(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Query(query): Query<PosterQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    poster(&app_state, &claims, &permissions, event_id, query, PosterFormat::Pdf).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/poster.png",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        PosterQuery
+    ),
+    responses(
+        (status = 200, description = "The poster as a 1240 pixel wide image", content_type = "image/png"),
+        (status = 400, description = "The event is private"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or short link not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "posters"
+)]
+pub async fn download_poster_png(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Query(query): Query<PosterQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    poster(&app_state, &claims, &permissions, event_id, query, PosterFormat::Png).await
+}
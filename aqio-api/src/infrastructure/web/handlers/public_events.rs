@@ -0,0 +1,208 @@
+// HTTP handlers for the public event page
+// Reachable without credentials; only published, non-private events are exposed
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        ApiResult,
+        dto::{CreateRegistrationRequest, GuestRegistrationResponse, PublicEventResponse},
+    },
+    infrastructure::web::{
+        extractors::EffectiveLocale,
+        handlers::attachments::{download_response, file_response},
+        handlers::photos::photo_response,
+        response::{created_response, success_response},
+        state::AppState,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/{slug}",
+    params(
+        ("slug" = String, Path, description = "Event slug")
+    ),
+    responses(
+        (status = 200, description = "Published event, with schema.org markup for search engines", body = PublicEventResponse),
+        (status = 404, description = "Event not found, private or unpublished")
+    ),
+    tag = "public"
+)]
+pub async fn get_public_event(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let mut event = state.event_service.get_public_event(&slug).await?;
+    // Seats added by the event's overbooking policy are offered like any other
+    event.max_attendees = state.registration_service.bookable_capacity(&event).await?;
+    let confirmed = state
+        .registration_service
+        .get_event_attendance_count(event.id)
+        .await?;
+    let attachments = state
+        .attachment_service
+        .list_attachments(event.id, None, false)
+        .await?;
+    let sponsors = state.sponsor_service.list(event.id).await?;
+    let photos = state.photo_service.gallery(event.id, None, false).await?.photos;
+    let exhibitors = state.exhibitor_service.public_exhibitors(event.id).await?;
+    let structured_data = state.event_service.structured_data(&event, confirmed);
+
+    Ok(success_response(
+        PublicEventResponse::new(event, confirmed, attachments, sponsors, photos)
+            .with_exhibitors(exhibitors)
+            .with_structured_data(structured_data),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/sitemap.xml",
+    responses(
+        (status = 200, description = "Sitemap of the public event pages", content_type = "application/xml")
+    ),
+    tag = "public"
+)]
+pub async fn get_sitemap(State(state): State<AppState>) -> ApiResult<Response> {
+    let sitemap = state.event_service.public_sitemap().await?;
+
+    Ok((
+        [(header::CONTENT_TYPE, HeaderValue::from_static("application/xml; charset=utf-8"))],
+        sitemap,
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/{slug}/attachments/{attachment_id}",
+    params(
+        ("slug" = String, Path, description = "Event slug"),
+        ("attachment_id" = Uuid, Path, description = "Attachment ID")
+    ),
+    responses(
+        (status = 200, description = "File contents", content_type = "application/octet-stream"),
+        (status = 403, description = "The attachment is for registered attendees only"),
+        (status = 404, description = "Event or attachment not found")
+    ),
+    tag = "public"
+)]
+pub async fn download_public_attachment(
+    State(state): State<AppState>,
+    Path((slug, attachment_id)): Path<(String, Uuid)>,
+) -> ApiResult<Response> {
+    let event = state.event_service.get_public_event(&slug).await?;
+    let (attachment, bytes) = state
+        .attachment_service
+        .download(event.id, attachment_id, None, false)
+        .await?;
+
+    Ok(download_response(attachment, bytes))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/{slug}/photos/{photo_id}",
+    params(
+        ("slug" = String, Path, description = "Event slug"),
+        ("photo_id" = Uuid, Path, description = "Photo ID")
+    ),
+    responses(
+        (status = 200, description = "The photo", content_type = "image/*"),
+        (status = 404, description = "Event or photo not found, or the album isn't public")
+    ),
+    tag = "public"
+)]
+pub async fn download_public_photo(
+    State(state): State<AppState>,
+    Path((slug, photo_id)): Path<(String, Uuid)>,
+) -> ApiResult<Response> {
+    let event = state.event_service.get_public_event(&slug).await?;
+    let (photo, bytes) = state.photo_service.download(event.id, photo_id, None, false).await?;
+
+    Ok(photo_response(photo, bytes))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/{slug}/calendar.ics",
+    params(
+        ("slug" = String, Path, description = "Event slug"),
+        ("Accept-Language" = Option<String>, Header, description = "Language for the description when the caller has no saved preference")
+    ),
+    responses(
+        (status = 200, description = "The event as an iCalendar file, travel information included", content_type = "text/calendar"),
+        (status = 404, description = "Event not found, private or unpublished")
+    ),
+    tag = "public"
+)]
+pub async fn download_public_calendar(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+    EffectiveLocale(localization): EffectiveLocale,
+) -> ApiResult<Response> {
+    let (filename, ics) = state.event_service.public_calendar(&slug, &localization).await?;
+
+    Ok(file_response("text/calendar; charset=utf-8", &filename, &filename, ics.into_bytes()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/{slug}/consents",
+    params(
+        ("slug" = String, Path, description = "Event slug")
+    ),
+    responses(
+        (status = 200, description = "Consent checkboxes to show on the registration form", body = Vec<EventConsent>),
+        (status = 404, description = "Event not found, private or unpublished")
+    ),
+    tag = "public"
+)]
+pub async fn list_public_consents(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let event = state.event_service.get_public_event(&slug).await?;
+    let consents = state.consent_service.list(event.id).await?;
+
+    Ok(success_response(consents))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/public/events/{slug}/registrations",
+    params(
+        ("slug" = String, Path, description = "Event slug")
+    ),
+    request_body = CreateRegistrationRequest,
+    responses(
+        (status = 201, description = "Registered, or added to the waitlist", body = GuestRegistrationResponse),
+        (status = 400, description = "Invalid registration, registration closed or a required consent not accepted"),
+        (status = 404, description = "Event not found, private or unpublished"),
+        (status = 409, description = "Event is fully booked, or a consent text changed since the form was loaded")
+    ),
+    tag = "public"
+)]
+pub async fn register_guest(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+    Json(request): Json<CreateRegistrationRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let event = state.event_service.get_public_event(&slug).await?;
+    let registration = request.to_domain_registration(event.id, None, None)?;
+    let consents = state.consent_service.check_answers(event.id, &request.consent_answers()).await?;
+    let registration = state
+        .registration_service
+        .register_guest(&event, registration)
+        .await?;
+    state.consent_service.record(registration.id, &consents).await?;
+
+    Ok(created_response(GuestRegistrationResponse::from(registration)))
+}
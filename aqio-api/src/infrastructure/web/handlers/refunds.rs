@@ -0,0 +1,130 @@
+// Refund handlers - cancelling a paid event, which refunds its ticket orders
+// through the payment provider, and following up on the refunds
+
+use axum::{
+    Extension,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::EventRefundsResponse,
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::success_response,
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/cancel",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Event and its orders cancelled, paid orders refunded; refunds that failed are retried", body = EventRefundsResponse),
+        (status = 400, description = "The event is completed, or it has paid orders and refunds aren't set up"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found"),
+        (status = 409, description = "Already cancelled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "refunds"
+)]
+pub async fn cancel_event(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let refunds = app_state
+        .refund_service
+        .cancel_event(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(EventRefundsResponse::new(event_id, refunds)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/refunds",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Refunds of the event's paid orders, oldest first", body = EventRefundsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "refunds"
+)]
+pub async fn list_event_refunds(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let refunds = app_state.refund_service.list(event_id, user.id, may_manage).await?;
+
+    Ok(success_response(EventRefundsResponse::new(event_id, refunds)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/refunds/retry",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Failed refunds tried again, with a fresh set of attempts", body = EventRefundsResponse),
+        (status = 400, description = "Refunds aren't set up"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "refunds"
+)]
+pub async fn retry_event_refunds(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let refunds = app_state
+        .refund_service
+        .retry_failed(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(EventRefundsResponse::new(event_id, refunds)))
+}
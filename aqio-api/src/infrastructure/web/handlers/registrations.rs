@@ -1,23 +1,31 @@
 // HTTP handlers for event registration endpoints
 // Thin layer that delegates to EventRegistrationApplicationService
 
+use std::convert::Infallible;
+
 use axum::{
     Extension, Json,
     extract::{Path, State},
-    response::IntoResponse,
+    response::{
+        IntoResponse,
+        sse::{Event, KeepAlive, Sse},
+    },
 };
+use aqio_core::{AuditAction, Permission};
+use tokio_stream::{Stream, StreamExt, wrappers::BroadcastStream};
 use uuid::Uuid;
 
 use crate::{
     auth::Claims,
     domain::{
         dto::{
-            CreateRegistrationRequest, EventRegistrationStatsResponse, RegistrationResponse,
-            UpdateRegistrationRequest, UpdateRegistrationStatusRequest,
+            BudgetSummaryResponse, CreateRegistrationRequest, EventRegistrationStatsResponse,
+            MergeRegistrationsRequest, RegistrationResponse, UpdateRegistrationRequest, UpdateRegistrationStatusRequest, WaitlistStatusResponse,
         },
         errors::{ApiError, ApiResult},
     },
     infrastructure::web::{
+        extractors::Permissions,
         response::{created_response, empty_success, success_response},
         state::AppState,
     },
@@ -43,11 +51,17 @@ pub async fn create_registration(
         None
     };
     
+    let event = state.event_service.get_event_by_id(event_id).await?;
+
     // Convert DTO to domain model
     let registration = request.to_domain_registration(event_id, user_id, None)?;
+    // Required consent checkboxes must be ticked before anything is stored
+    let consents = state.consent_service.check_answers(event_id, &request.consent_answers()).await?;
 
-    // Delegate to application service
-    state.registration_service.create_registration(&registration).await?;
+    // Delegate to application service, which enforces the registration window
+    // and capacity; a full event may put the registration on the waitlist
+    let registration = state.registration_service.register(&event, &registration).await?;
+    state.consent_service.record(registration.id, &consents).await?;
 
     let response = RegistrationResponse::from(registration);
     Ok(created_response(response))
@@ -57,6 +71,7 @@ pub async fn get_registration(
     State(state): State<AppState>,
     Path(registration_id): Path<Uuid>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl IntoResponse> {
     let registration = state.registration_service.get_registration_by_id(registration_id).await?;
     
@@ -64,7 +79,12 @@ pub async fn get_registration(
     let requesting_user_id = Uuid::parse_str(&claims.sub)
         .map_err(|_| ApiError::authentication("Invalid user ID format"))?;
 
-    if registration.user_id != Some(requesting_user_id) && !claims.is_admin() {
+    if registration.user_id != Some(requesting_user_id)
+        && !state
+            .permission_service
+            .allows_for_event(&permissions, Permission::RegistrationManage, registration.event_id)
+            .await?
+    {
         return Err(ApiError::authorization("Access denied"));
     }
 
@@ -124,6 +144,7 @@ pub async fn delete_registration(
     State(state): State<AppState>,
     Path(registration_id): Path<Uuid>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl IntoResponse> {
     let requesting_user_id = Uuid::parse_str(&claims.sub)
         .map_err(|_| ApiError::authentication("Invalid user ID format"))?;
@@ -131,9 +152,13 @@ pub async fn delete_registration(
     // Get existing registration to check ownership
     let registration = state.registration_service.get_registration_by_id(registration_id).await?;
 
-    // Check authorization - user can delete their own, or admin/organizer can delete any
-    let is_admin_or_organizer = claims.is_admin() || claims.is_organizer();
-    if !is_admin_or_organizer && registration.user_id != Some(requesting_user_id) {
+    // Check authorization - user can delete their own, or those managing registrations can delete any
+    if registration.user_id != Some(requesting_user_id)
+        && !state
+            .permission_service
+            .allows_for_event(&permissions, Permission::RegistrationManage, registration.event_id)
+            .await?
+    {
         return Err(ApiError::authorization("You can only delete your own registration"));
     }
 
@@ -142,6 +167,77 @@ pub async fn delete_registration(
     Ok(empty_success())
 }
 
+// ============================================================================
+// Waitlist Promotion Handlers
+// ============================================================================
+
+// Database ID of the signed-in user, as stored on their registrations
+async fn current_user_id(state: &AppState, claims: &Claims) -> ApiResult<Uuid> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .map(|user| user.id)
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+pub async fn get_waitlist_status(
+    State(state): State<AppState>,
+    Path(registration_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let registration = state.registration_service.get_registration_by_id(registration_id).await?;
+
+    if registration.user_id != Some(current_user_id(&state, &claims).await?)
+        && !state
+            .permission_service
+            .allows_for_event(&permissions, Permission::RegistrationManage, registration.event_id)
+            .await?
+    {
+        return Err(ApiError::authorization("Access denied"));
+    }
+
+    let status = state.registration_service.waitlist_status(registration_id).await?;
+    Ok(success_response(WaitlistStatusResponse::from(status)))
+}
+
+pub async fn confirm_promotion(
+    State(state): State<AppState>,
+    Path(registration_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let registration = state.registration_service.get_registration_by_id(registration_id).await?;
+
+    if registration.user_id != Some(current_user_id(&state, &claims).await?) {
+        return Err(ApiError::authorization("You can only confirm your own registration"));
+    }
+
+    let confirmed = state.registration_service.confirm_promotion(registration_id).await?;
+    Ok(success_response(RegistrationResponse::from(confirmed)))
+}
+
+/// Server-sent events for the signed-in user's registrations
+pub async fn registration_notification_stream(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<Sse<impl Stream<Item = Result<Event, Infallible>>>> {
+    let user_id = current_user_id(&state, &claims).await?;
+    let receiver = state.registration_service.notifier().subscribe();
+
+    // Lagged receivers drop the missed messages; clients re-fetch on any event anyway
+    let stream = BroadcastStream::new(receiver).filter_map(move |message| {
+        let message = message.ok().filter(|message| message.user_id == user_id)?;
+        let event = Event::default()
+            .event(message.notification.event_name())
+            .json_data(&message.notification)
+            .ok()?;
+        Some(Ok(event))
+    });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
 // ============================================================================
 // Event Registration Management Handlers (Admin/Organizer)
 // ============================================================================
@@ -181,11 +277,15 @@ pub async fn get_user_registrations(
 pub async fn update_registration_status(
     State(state): State<AppState>,
     Path(registration_id): Path<Uuid>,
-    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
     Json(request): Json<UpdateRegistrationStatusRequest>,
 ) -> ApiResult<impl IntoResponse> {
-    // Only admin/organizer can update status
-    if !claims.is_admin() && !claims.is_organizer() {
+    let registration = state.registration_service.get_registration_by_id(registration_id).await?;
+    if !state
+        .permission_service
+        .allows_for_event(&permissions, Permission::RegistrationApprove, registration.event_id)
+        .await?
+    {
         return Err(ApiError::authorization("Only admins and organizers can update registration status"));
     }
 
@@ -196,10 +296,14 @@ pub async fn update_registration_status(
 pub async fn check_in_registration(
     State(state): State<AppState>,
     Path(registration_id): Path<Uuid>,
-    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl IntoResponse> {
-    // Only admin/organizer can check in attendees
-    if !claims.is_admin() && !claims.is_organizer() {
+    let registration = state.registration_service.get_registration_by_id(registration_id).await?;
+    if !state
+        .permission_service
+        .allows_for_event(&permissions, Permission::RegistrationCheckIn, registration.event_id)
+        .await?
+    {
         return Err(ApiError::authorization("Only admins and organizers can check in attendees"));
     }
 
@@ -207,11 +311,65 @@ pub async fn check_in_registration(
     Ok(empty_success())
 }
 
+/// Fold duplicate registrations of one person into the earliest of them,
+/// e.g. after they registered both through an invitation and directly
+pub async fn merge_registrations(
+    State(state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<MergeRegistrationsRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = state.permission_service.manages_event(&permissions, event_id).await?;
+    let event = state.event_service.get_event_by_id(event_id).await?;
+    let user_id = current_user_id(&state, &claims).await?;
+    if !(may_manage || event.organizer_id == user_id || event.co_organizers.contains(&user_id)) {
+        return Err(ApiError::authorization("Only the event's organizers can merge registrations"));
+    }
+
+    let merge = state
+        .registration_service
+        .merge_registrations(event_id, &request.registration_ids)
+        .await?;
+
+    let actor = claims.audit_actor();
+    state
+        .audit_log_service
+        .record_event_change(
+            &actor,
+            event_id,
+            "event_registrations",
+            merge.merged.id.to_string(),
+            AuditAction::Update,
+            Some(&merge.before),
+            Some(&merge.merged),
+        )
+        .await;
+    for duplicate in &merge.removed {
+        state
+            .audit_log_service
+            .record_event_change(
+                &actor,
+                event_id,
+                "event_registrations",
+                duplicate.id.to_string(),
+                AuditAction::Delete,
+                Some(duplicate),
+                None,
+            )
+            .await;
+    }
+
+    Ok(success_response(RegistrationResponse::from(merge.merged)))
+}
+
 pub async fn get_event_registration_stats(
     State(state): State<AppState>,
     Path(event_id): Path<Uuid>,
-    Extension(_claims): Extension<Claims>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl IntoResponse> {
+    let may_manage = state.permission_service.manages_event(&permissions, event_id).await?;
     // TODO: Add authorization check - only event organizers/admins should see stats
     
     let registrations = state.registration_service.get_registrations_by_event(event_id).await?;
@@ -243,6 +401,12 @@ pub async fn get_event_registration_stats(
         total_attended,
         total_waitlisted,
         total_cancelled,
+        // Spending is organizer-only, even while the counts above are not
+        budget: state
+            .budget_service
+            .summary(event_id, current_user_id(&state, &claims).await?, may_manage)
+            .await?
+            .map(BudgetSummaryResponse::from),
     };
     
     Ok(success_response(stats))
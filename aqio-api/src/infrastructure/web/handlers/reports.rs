@@ -0,0 +1,229 @@
+// Abuse report handlers - users report comments, events and profiles, and
+// administrators work through the reports on the moderation dashboard
+
+use aqio_core::{AuditAction, ContentReport, Permission, ReportTarget, User};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{
+            ContentReportResponse, ModerateContentRequest, ModerationOutcomeResponse, ReportContentRequest,
+            ReportedContentResponse,
+        },
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+async fn report_created(state: &AppState, claims: &Claims, report: ContentReport) -> impl IntoResponse {
+    state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "content_reports",
+            report.id.to_string(),
+            AuditAction::Insert,
+            None,
+            Some(&report),
+        )
+        .await;
+    created_response(ContentReportResponse::from(report))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/report",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = ReportContentRequest,
+    responses(
+        (status = 201, description = "Reported; the event is hidden once enough users have reported it", body = ContentReportResponse),
+        (status = 400, description = "Reporting one's own event, or details too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found"),
+        (status = 409, description = "Already reported by the caller and waiting for a moderator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "reports"
+)]
+pub async fn report_event(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<ReportContentRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .moderation_service
+        .report(ReportTarget::Event, event_id, &user, request.reason, request.details)
+        .await?;
+    Ok(report_created(&app_state, &claims, report).await)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/comments/{comment_id}/report",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("comment_id" = Uuid, Path, description = "Comment ID")
+    ),
+    request_body = ReportContentRequest,
+    responses(
+        (status = 201, description = "Reported; the comment is hidden once enough users have reported it", body = ContentReportResponse),
+        (status = 400, description = "Reporting one's own comment, or details too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Comment not found on this event, or deleted"),
+        (status = 409, description = "Already reported by the caller and waiting for a moderator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "reports"
+)]
+pub async fn report_comment(
+    State(app_state): State<AppState>,
+    Path((event_id, comment_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<ReportContentRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .moderation_service
+        .report_comment(event_id, comment_id, &user, request.reason, request.details)
+        .await?;
+    Ok(report_created(&app_state, &claims, report).await)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/{id}/report",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = ReportContentRequest,
+    responses(
+        (status = 201, description = "Reported; profiles stay visible until a moderator acts", body = ContentReportResponse),
+        (status = 400, description = "Reporting oneself, or details too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "Already reported by the caller and waiting for a moderator")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "reports"
+)]
+pub async fn report_profile(
+    State(app_state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<ReportContentRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .moderation_service
+        .report(ReportTarget::Profile, user_id, &user, request.reason, request.details)
+        .await?;
+    Ok(report_created(&app_state, &claims, report).await)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/reports",
+    responses(
+        (status = 200, description = "Content with open reports, most reported first", body = Vec<ReportedContentResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "reports"
+)]
+pub async fn list_reported_content(
+    State(app_state): State<AppState>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::ContentModerate) {
+        return Err(ApiError::authorization("Only administrators can review reported content"));
+    }
+
+    let reported = app_state.moderation_service.reported_content().await?;
+    Ok(success_response(
+        reported.into_iter().map(ReportedContentResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/reports/{target}/{target_id}/actions",
+    params(
+        ("target" = ReportTarget, Path, description = "Comment, Event or Profile"),
+        ("target_id" = Uuid, Path, description = "ID of the reported comment, event or user")
+    ),
+    request_body = ModerateContentRequest,
+    responses(
+        (status = 200, description = "Action taken and the open reports closed", body = ModerationOutcomeResponse),
+        (status = 400, description = "Hiding a profile, banning an administrator, or note too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "Reported content not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "reports"
+)]
+pub async fn moderate_content(
+    State(app_state): State<AppState>,
+    Path((target, target_id)): Path<(ReportTarget, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ModerateContentRequest>,
+) -> ApiResult<impl IntoResponse> {
+    if !permissions.has(Permission::ContentModerate) {
+        return Err(ApiError::authorization("Only administrators can act on reported content"));
+    }
+
+    let moderator = current_user(&app_state, &claims).await?;
+    let outcome = app_state
+        .moderation_service
+        .act(target, target_id, request.action, &moderator, request.note)
+        .await?;
+
+    if let Some((previous, banned)) = &outcome.banned {
+        app_state
+            .audit_log_service
+            .record_change(
+                &claims.audit_actor(),
+                "users",
+                banned.id.to_string(),
+                AuditAction::Update,
+                Some(previous),
+                Some(banned),
+            )
+            .await;
+    }
+    Ok(success_response(ModerationOutcomeResponse::from(&outcome)))
+}
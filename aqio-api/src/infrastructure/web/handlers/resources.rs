@@ -0,0 +1,352 @@
+// Resource handlers - bookable equipment and facilities, their availability,
+// and bookings of them for events
+
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    response::IntoResponse,
+};
+use aqio_core::{Permission, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{
+            BookResourcesRequest, EventResourceBookingsQuery, ListResourcesQuery, ResourceAvailabilityRequest,
+            ResourceBookingsQuery, ResourceConflictsRequest, ResourceConflictsResponse, ResourceRequest,
+            ResourceResponse,
+        },
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/resources",
+    params(ListResourcesQuery),
+    responses(
+        (status = 200, description = "Resources by name", body = [Resource]),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn list_resources(
+    State(app_state): State<AppState>,
+    Query(query): Query<ListResourcesQuery>,
+    Extension(_claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let resources = app_state
+        .resource_service
+        .list(query.include_inactive, may_manage)
+        .await?;
+
+    Ok(success_response(resources))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/resources",
+    request_body = ResourceRequest,
+    responses(
+        (status = 201, description = "Resource added, bookable at any time until it gets availability windows", body = Resource),
+        (status = 400, description = "Missing name or text too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an administrator"),
+        (status = 409, description = "There is already a resource with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn create_resource(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ResourceRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let resource = app_state
+        .resource_service
+        .create(user.id, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(resource))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/resources/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Resource ID")
+    ),
+    responses(
+        (status = 200, description = "The resource and its availability windows", body = ResourceResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Resource not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn get_resource(
+    State(app_state): State<AppState>,
+    Path(resource_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let resource = app_state.resource_service.get(resource_id).await?;
+
+    Ok(success_response(ResourceResponse::from(resource)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/resources/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Resource ID")
+    ),
+    request_body = ResourceRequest,
+    responses(
+        (status = 200, description = "Resource updated; retired resources keep their bookings", body = Resource),
+        (status = 400, description = "Missing name or text too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an administrator"),
+        (status = 404, description = "Resource not found"),
+        (status = 409, description = "There is already a resource with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn update_resource(
+    State(app_state): State<AppState>,
+    Path(resource_id): Path<Uuid>,
+    Extension(_claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ResourceRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let resource = app_state
+        .resource_service
+        .update(resource_id, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(resource))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/resources/{id}/availability",
+    params(
+        ("id" = Uuid, Path, description = "Resource ID")
+    ),
+    request_body = ResourceAvailabilityRequest,
+    responses(
+        (status = 200, description = "The new availability windows, earliest first", body = [AvailabilityWindow]),
+        (status = 400, description = "A window ending before it starts, or too many windows"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an administrator"),
+        (status = 404, description = "Resource not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn set_resource_availability(
+    State(app_state): State<AppState>,
+    Path(resource_id): Path<Uuid>,
+    Extension(_claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ResourceAvailabilityRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let windows = app_state
+        .resource_service
+        .set_availability(resource_id, may_manage, request.windows)
+        .await?;
+
+    Ok(success_response(windows))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/resources/{id}/bookings",
+    params(
+        ("id" = Uuid, Path, description = "Resource ID"),
+        ResourceBookingsQuery
+    ),
+    responses(
+        (status = 200, description = "The resource's bookings in the period, earliest first", body = [ResourceBooking]),
+        (status = 400, description = "Period ending before it starts"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Resource not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn list_resource_bookings(
+    State(app_state): State<AppState>,
+    Path(resource_id): Path<Uuid>,
+    Query(query): Query<ResourceBookingsQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let bookings = app_state
+        .resource_service
+        .bookings(resource_id, query.from, query.to)
+        .await?;
+
+    Ok(success_response(bookings))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/resources/conflicts",
+    request_body = ResourceConflictsRequest,
+    responses(
+        (status = 200, description = "What stands in the way of booking the resources for the slot", body = ResourceConflictsResponse),
+        (status = 400, description = "No resources, or a slot ending before it starts"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Resource not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn check_resource_conflicts(
+    State(app_state): State<AppState>,
+    Json(request): Json<ResourceConflictsRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let conflicts = app_state
+        .resource_service
+        .check_conflicts(request.resource_ids, request.starts_at, request.ends_at)
+        .await?;
+
+    Ok(success_response(ResourceConflictsResponse::from(conflicts)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/resources/bookings",
+    request_body = BookResourcesRequest,
+    responses(
+        (status = 201, description = "All the resources booked for the event", body = [ResourceBooking]),
+        (status = 400, description = "No resources, or a slot ending before it starts"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or resource not found"),
+        (status = 409, description = "A resource is booked, unavailable or retired; nothing was booked")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn book_resources(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<BookResourcesRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, request.event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let bookings = app_state
+        .resource_service
+        .book(
+            request.event_id,
+            request.resource_ids,
+            (request.starts_at, request.ends_at),
+            user.id,
+            may_manage,
+        )
+        .await?;
+
+    Ok(created_response(bookings))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/resources/bookings",
+    params(EventResourceBookingsQuery),
+    responses(
+        (status = 200, description = "The event's resource bookings, earliest first", body = [ResourceBooking]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn list_event_resource_bookings(
+    State(app_state): State<AppState>,
+    Query(query): Query<EventResourceBookingsQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, query.event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let bookings = app_state
+        .resource_service
+        .event_bookings(query.event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(bookings))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/resources/bookings/{booking_id}",
+    params(
+        ("booking_id" = Uuid, Path, description = "Booking ID")
+    ),
+    responses(
+        (status = 200, description = "Booking cancelled"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the booking's event"),
+        (status = 404, description = "Booking not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "resources"
+)]
+pub async fn cancel_resource_booking(
+    State(app_state): State<AppState>,
+    Path(booking_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .resource_service
+        .cancel_booking(booking_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
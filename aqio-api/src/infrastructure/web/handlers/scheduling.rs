@@ -0,0 +1,260 @@
+// Scheduling rule handlers - reading for everyone signed in, management for the
+// organization's organizers, and the date check behind event form warnings
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::{Permission, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{
+            BlackoutRequest, BlackoutResponse, ScheduleCheckRequest, ScheduleCheckResponse,
+            SchedulingPolicyRequest, SchedulingPolicyResponse,
+        },
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies/{id}/scheduling-policy",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "The company's scheduling policy; without limits when it hasn't set one", body = SchedulingPolicyResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "scheduling"
+)]
+pub async fn get_scheduling_policy(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let policy = app_state.scheduling_service.policy(company_id).await?;
+
+    Ok(success_response(SchedulingPolicyResponse::from(policy)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/companies/{id}/scheduling-policy",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = SchedulingPolicyRequest,
+    responses(
+        (status = 200, description = "Policy saved", body = SchedulingPolicyResponse),
+        (status = 400, description = "Limit out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "scheduling"
+)]
+pub async fn update_scheduling_policy(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<SchedulingPolicyRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let policy = app_state
+        .scheduling_service
+        .update_policy(company_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(SchedulingPolicyResponse::from(policy)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies/{id}/blackouts",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Blackout periods, earliest first", body = Vec<BlackoutResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "scheduling"
+)]
+pub async fn list_blackouts(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let blackouts = app_state.scheduling_service.blackouts(company_id).await?;
+
+    Ok(success_response(
+        blackouts.into_iter().map(BlackoutResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/companies/{id}/blackouts",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = BlackoutRequest,
+    responses(
+        (status = 201, description = "Blackout period added", body = BlackoutResponse),
+        (status = 400, description = "Missing name or last day before first day"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "scheduling"
+)]
+pub async fn create_blackout(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<BlackoutRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let blackout = app_state
+        .scheduling_service
+        .add_blackout(company_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(BlackoutResponse::from(blackout)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/companies/{id}/blackouts/{blackout_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("blackout_id" = Uuid, Path, description = "Blackout period ID")
+    ),
+    request_body = BlackoutRequest,
+    responses(
+        (status = 200, description = "Blackout period updated", body = BlackoutResponse),
+        (status = 400, description = "Missing name or last day before first day"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company or blackout period not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "scheduling"
+)]
+pub async fn update_blackout(
+    State(app_state): State<AppState>,
+    Path((company_id, blackout_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<BlackoutRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let blackout = app_state
+        .scheduling_service
+        .update_blackout(company_id, blackout_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(BlackoutResponse::from(blackout)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/companies/{id}/blackouts/{blackout_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("blackout_id" = Uuid, Path, description = "Blackout period ID")
+    ),
+    responses(
+        (status = 204, description = "Blackout period removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company or blackout period not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "scheduling"
+)]
+pub async fn delete_blackout(
+    State(app_state): State<AppState>,
+    Path((company_id, blackout_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .scheduling_service
+        .delete_blackout(company_id, blackout_id, &user, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/schedule-check",
+    request_body = ScheduleCheckRequest,
+    responses(
+        (status = 200, description = "Scheduling rules the dates would break for an event organized by the caller", body = ScheduleCheckResponse),
+        (status = 400, description = "End before start"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "scheduling"
+)]
+pub async fn check_schedule(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<ScheduleCheckRequest>,
+) -> ApiResult<impl IntoResponse> {
+    if request.end_date < request.start_date {
+        return Err(ApiError::validation("end_date", "The event can't end before it starts"));
+    }
+    let user = current_user(&app_state, &claims).await?;
+    let conflicts = app_state
+        .scheduling_service
+        .check(user.id, request.start_date, request.end_date, request.event_id)
+        .await?;
+
+    Ok(success_response(ScheduleCheckResponse::from(conflicts)))
+}
@@ -0,0 +1,243 @@
+// Seating handlers - tables, seat assignments and the printable chart, for organizers
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{SeatAssignmentRequest, SeatingPlanResponse, SeatingTableRequest},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        handlers::attachments::file_response,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/seating",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Tables, who sits where, who has no seat yet, and warnings", body = SeatingPlanResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "seating"
+)]
+pub async fn get_seating_plan(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let plan = app_state
+        .seating_service
+        .plan(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(SeatingPlanResponse::from(plan)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/seating/tables",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = SeatingTableRequest,
+    responses(
+        (status = 201, description = "Table added after the existing ones", body = SeatingTable),
+        (status = 400, description = "Missing name or capacity out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found"),
+        (status = 409, description = "The event already has a table with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "seating"
+)]
+pub async fn create_seating_table(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<SeatingTableRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let table = app_state
+        .seating_service
+        .create_table(event_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(table))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/seating/tables/{table_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("table_id" = Uuid, Path, description = "Table ID")
+    ),
+    request_body = SeatingTableRequest,
+    responses(
+        (status = 200, description = "Table updated", body = SeatingTable),
+        (status = 400, description = "Missing name or capacity out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or table not found"),
+        (status = 409, description = "The event already has a table with this name")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "seating"
+)]
+pub async fn update_seating_table(
+    State(app_state): State<AppState>,
+    Path((event_id, table_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<SeatingTableRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let table = app_state
+        .seating_service
+        .update_table(event_id, table_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(table))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/seating/tables/{table_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("table_id" = Uuid, Path, description = "Table ID")
+    ),
+    responses(
+        (status = 204, description = "Table removed; whoever sat there is unassigned"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or table not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "seating"
+)]
+pub async fn delete_seating_table(
+    State(app_state): State<AppState>,
+    Path((event_id, table_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .seating_service
+        .delete_table(event_id, table_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/seating/assignments/{registration_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("registration_id" = Uuid, Path, description = "Registration ID")
+    ),
+    request_body = SeatAssignmentRequest,
+    responses(
+        (status = 200, description = "The updated plan, with its warnings", body = SeatingPlanResponse),
+        (status = 400, description = "The registration isn't confirmed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event, registration or table not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "seating"
+)]
+pub async fn assign_seat(
+    State(app_state): State<AppState>,
+    Path((event_id, registration_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<SeatAssignmentRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let plan = app_state
+        .seating_service
+        .assign(event_id, registration_id, request.table_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(SeatingPlanResponse::from(plan)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/seating/chart.pdf",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Printable seating chart with an alphabetical index", content_type = "application/pdf"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "seating"
+)]
+pub async fn download_seating_chart(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<Response> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let (filename, pdf) = app_state
+        .seating_service
+        .chart_pdf(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(file_response("application/pdf", &filename, &filename, pdf))
+}
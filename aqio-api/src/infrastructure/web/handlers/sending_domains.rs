@@ -0,0 +1,163 @@
+// Sending domain handlers - configuration and DNS checks for the organization's
+// organizers; invitations come from the domain once its records verify
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::{Permission, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{SendingDomainRequest, SendingDomainResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies/{id}/sending-domain",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "The company's sending domain and the DNS records it needs", body = SendingDomainResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found, or no sending domain configured")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "sending-domains"
+)]
+pub async fn get_sending_domain(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let service = &app_state.sending_domain_service;
+    let sending_domain = service.sending_domain(company_id, &user, may_manage).await?;
+
+    Ok(success_response(SendingDomainResponse::new(sending_domain, service.platform())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/companies/{id}/sending-domain",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = SendingDomainRequest,
+    responses(
+        (status = 200, description = "Sending domain saved; a new domain or DKIM selector has to be verified again", body = SendingDomainResponse),
+        (status = 400, description = "Invalid domain or selector, or sender address not on the domain"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "sending-domains"
+)]
+pub async fn update_sending_domain(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<SendingDomainRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let service = &app_state.sending_domain_service;
+    let sending_domain = service
+        .configure(company_id, &user, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(SendingDomainResponse::new(sending_domain, service.platform())))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/companies/{id}/sending-domain/check",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Records looked up; `status` is verified only when all of them were found", body = SendingDomainResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found, or no sending domain configured"),
+        (status = 503, description = "DNS lookup failed")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "sending-domains"
+)]
+pub async fn check_sending_domain(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let service = &app_state.sending_domain_service;
+    let sending_domain = service.check(company_id, &user, may_manage).await?;
+
+    Ok(success_response(SendingDomainResponse::new(sending_domain, service.platform())))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/companies/{id}/sending-domain",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 204, description = "Sending domain removed; invitations use the shared sender again"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found, or no sending domain configured")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "sending-domains"
+)]
+pub async fn delete_sending_domain(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .sending_domain_service
+        .remove(company_id, &user, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
@@ -0,0 +1,299 @@
+// Shift handlers - staff shifts at events, self-signup, coverage gaps for
+// organizers, and the signed-in user's shifts as a calendar file
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::{IntoResponse, Response},
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{ShiftCoverageResponse, ShiftRequest},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        extractors::EffectiveLocale,
+        handlers::attachments::file_response,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/shifts",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The event's shifts in time order, with who is on them", body = ShiftCoverageResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither staff nor an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shifts"
+)]
+pub async fn list_shifts(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .shift_service
+        .shifts(event_id, &user, may_manage)
+        .await?;
+
+    Ok(success_response(ShiftCoverageResponse::from(report)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/shifts/coverage",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "People needed and signed up, and the shifts still short of people", body = ShiftCoverageResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shifts"
+)]
+pub async fn get_shift_coverage(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .shift_service
+        .coverage(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(ShiftCoverageResponse::from(report)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/shifts",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = ShiftRequest,
+    responses(
+        (status = 201, description = "Shift added", body = EventShift),
+        (status = 400, description = "Missing role, a shift ending before it starts, or headcount out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shifts"
+)]
+pub async fn create_shift(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ShiftRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let shift = app_state
+        .shift_service
+        .create_shift(event_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(shift))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/shifts/{shift_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("shift_id" = Uuid, Path, description = "Shift ID")
+    ),
+    request_body = ShiftRequest,
+    responses(
+        (status = 200, description = "Shift updated; everyone signed up stays on it", body = EventShift),
+        (status = 400, description = "Missing role, a shift ending before it starts, or headcount out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or shift not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shifts"
+)]
+pub async fn update_shift(
+    State(app_state): State<AppState>,
+    Path((event_id, shift_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ShiftRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let shift = app_state
+        .shift_service
+        .update_shift(event_id, shift_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(shift))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/shifts/{shift_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("shift_id" = Uuid, Path, description = "Shift ID")
+    ),
+    responses(
+        (status = 200, description = "Shift removed with its signups"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or shift not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shifts"
+)]
+pub async fn delete_shift(
+    State(app_state): State<AppState>,
+    Path((event_id, shift_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .shift_service
+        .delete_shift(event_id, shift_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/shifts/{shift_id}/signup",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("shift_id" = Uuid, Path, description = "Shift ID")
+    ),
+    responses(
+        (status = 200, description = "Signed up; the event's shifts as they are now", body = ShiftCoverageResponse),
+        (status = 400, description = "The shift has already started"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not staff"),
+        (status = 404, description = "Shift not found"),
+        (status = 409, description = "Already signed up, the shift is full, or it overlaps another of your shifts")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shifts"
+)]
+pub async fn sign_up_for_shift(
+    State(app_state): State<AppState>,
+    Path((event_id, shift_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .shift_service
+        .sign_up(event_id, shift_id, &user, may_manage)
+        .await?;
+
+    Ok(success_response(ShiftCoverageResponse::from(report)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/shifts/{shift_id}/signup",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("shift_id" = Uuid, Path, description = "Shift ID")
+    ),
+    responses(
+        (status = 200, description = "Off the shift; the event's shifts as they are now", body = ShiftCoverageResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Shift not found, or not signed up for it")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shifts"
+)]
+pub async fn withdraw_from_shift(
+    State(app_state): State<AppState>,
+    Path((event_id, shift_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .shift_service
+        .withdraw(event_id, shift_id, user.id)
+        .await?;
+
+    Ok(success_response(ShiftCoverageResponse::from(report)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/shifts/mine.ics",
+    responses(
+        (status = 200, description = "The shifts you signed up for, at all events, as an iCalendar file", content_type = "text/calendar"),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "shifts"
+)]
+pub async fn download_my_shifts_calendar(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    EffectiveLocale(localization): EffectiveLocale,
+) -> ApiResult<Response> {
+    let user = current_user(&app_state, &claims).await?;
+    let (filename, ics) = app_state
+        .shift_service
+        .my_shifts_calendar(&user, &localization)
+        .await?;
+
+    Ok(file_response("text/calendar; charset=utf-8", &filename, &filename, ics.into_bytes()))
+}
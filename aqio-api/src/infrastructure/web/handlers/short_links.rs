@@ -0,0 +1,249 @@
+// Short link handlers - management for organizers, and the public redirect
+// the frontend sends `/e/{code}` visitors through
+
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::{HeaderMap, header},
+    response::{IntoResponse, Redirect},
+};
+use aqio_core::{ShortLinkClicks, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{
+            CreateShortLinkRequest, FollowShortLinkQuery, InvitationShortLinkResponse, ShortLinkResponse,
+            ShortLinkStatsResponse,
+        },
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/short-links",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Short links, newest first, with their clicks", body = Vec<ShortLinkResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "short-links"
+)]
+pub async fn list_short_links(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let service = &app_state.short_link_service;
+    let links = service.list(event_id, user.id, may_manage).await?;
+
+    Ok(success_response(
+        links
+            .into_iter()
+            .map(|(link, clicks)| {
+                let short_url = service.short_url(&link);
+                ShortLinkResponse::new(link, clicks, short_url)
+            })
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/short-links",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = CreateShortLinkRequest,
+    responses(
+        (status = 201, description = "Short link created", body = ShortLinkResponse),
+        (status = 400, description = "Label or campaign parameter too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "short-links"
+)]
+pub async fn create_short_link(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<CreateShortLinkRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let service = &app_state.short_link_service;
+    let link = service
+        .create(event_id, user.id, may_manage, request.into())
+        .await?;
+    let short_url = service.short_url(&link);
+    let clicks = ShortLinkClicks {
+        link_id: link.id,
+        ..ShortLinkClicks::default()
+    };
+
+    Ok(created_response(ShortLinkResponse::new(link, clicks, short_url)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/short-links/{link_id}/stats",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("link_id" = Uuid, Path, description = "Short link ID")
+    ),
+    responses(
+        (status = 200, description = "Clicks per day, referrer and invitation", body = ShortLinkStatsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or short link not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "short-links"
+)]
+pub async fn get_short_link_stats(
+    State(app_state): State<AppState>,
+    Path((event_id, link_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let service = &app_state.short_link_service;
+    let (link, stats) = service.stats(event_id, link_id, user.id, may_manage).await?;
+    let short_url = service.short_url(&link);
+
+    Ok(success_response(ShortLinkStatsResponse::new(link, stats, short_url)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/short-links/{link_id}/invitations",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("link_id" = Uuid, Path, description = "Short link ID")
+    ),
+    responses(
+        (status = 200, description = "Each invitation's personal copy of the link", body = Vec<InvitationShortLinkResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or short link not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "short-links"
+)]
+pub async fn list_invitation_short_links(
+    State(app_state): State<AppState>,
+    Path((event_id, link_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let links = app_state
+        .short_link_service
+        .invitation_links(event_id, link_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(
+        links.into_iter().map(InvitationShortLinkResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/short-links/{link_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("link_id" = Uuid, Path, description = "Short link ID")
+    ),
+    responses(
+        (status = 204, description = "Short link and its clicks removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or short link not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "short-links"
+)]
+pub async fn delete_short_link(
+    State(app_state): State<AppState>,
+    Path((event_id, link_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .short_link_service
+        .delete(event_id, link_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/links/{code}",
+    params(
+        ("code" = String, Path, description = "Short link code"),
+        FollowShortLinkQuery
+    ),
+    responses(
+        (status = 303, description = "Redirect to the event page with the link's campaign parameters"),
+        (status = 404, description = "Unknown code")
+    ),
+    tag = "short-links"
+)]
+pub async fn follow_short_link(
+    State(app_state): State<AppState>,
+    Path(code): Path<String>,
+    Query(query): Query<FollowShortLinkQuery>,
+    headers: HeaderMap,
+) -> ApiResult<Redirect> {
+    let referrer = query
+        .r
+        .as_deref()
+        .or_else(|| headers.get(header::REFERER).and_then(|value| value.to_str().ok()));
+    let target = app_state
+        .short_link_service
+        .follow(&code, query.i.as_deref(), referrer)
+        .await?;
+
+    Ok(Redirect::to(&target))
+}
@@ -0,0 +1,202 @@
+// Event sponsor handlers - listing for everyone signed in, management for organizers
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{ReorderSponsorsRequest, SponsorRequest, SponsorResponse},
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/sponsors",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Sponsors, highest tier first", body = Vec<SponsorResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "sponsors"
+)]
+pub async fn list_sponsors(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let sponsors = app_state.sponsor_service.list(event_id).await?;
+
+    Ok(success_response(
+        sponsors.into_iter().map(SponsorResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/sponsors",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = SponsorRequest,
+    responses(
+        (status = 201, description = "Sponsor added", body = SponsorResponse),
+        (status = 400, description = "Missing name or invalid URL"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "sponsors"
+)]
+pub async fn create_sponsor(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<SponsorRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let sponsor = app_state
+        .sponsor_service
+        .add(event_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(SponsorResponse::from(sponsor)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/sponsors/{sponsor_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("sponsor_id" = Uuid, Path, description = "Sponsor ID")
+    ),
+    request_body = SponsorRequest,
+    responses(
+        (status = 200, description = "Sponsor updated", body = SponsorResponse),
+        (status = 400, description = "Missing name or invalid URL"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or sponsor not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "sponsors"
+)]
+pub async fn update_sponsor(
+    State(app_state): State<AppState>,
+    Path((event_id, sponsor_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<SponsorRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let sponsor = app_state
+        .sponsor_service
+        .update(event_id, sponsor_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(success_response(SponsorResponse::from(sponsor)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/sponsors/{sponsor_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("sponsor_id" = Uuid, Path, description = "Sponsor ID")
+    ),
+    responses(
+        (status = 204, description = "Sponsor removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or sponsor not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "sponsors"
+)]
+pub async fn delete_sponsor(
+    State(app_state): State<AppState>,
+    Path((event_id, sponsor_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .sponsor_service
+        .delete(event_id, sponsor_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/sponsors/order",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = ReorderSponsorsRequest,
+    responses(
+        (status = 200, description = "Sponsors in their new order", body = Vec<SponsorResponse>),
+        (status = 400, description = "A sponsor is listed twice"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or sponsor not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "sponsors"
+)]
+pub async fn reorder_sponsors(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<ReorderSponsorsRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let sponsors = app_state
+        .sponsor_service
+        .reorder(event_id, request.sponsor_ids, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(
+        sponsors.into_iter().map(SponsorResponse::from).collect::<Vec<_>>(),
+    ))
+}
@@ -0,0 +1,106 @@
+// Storage quota handlers - an organization's media storage use for its
+// organizers, and quota changes for administrators
+
+use aqio_core::{AuditAction, Permission, User};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{StorageQuotaRequest, StorageUsageResponse},
+    },
+    infrastructure::web::{extractors::Permissions, response::success_response, state::AppState},
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/companies/{id}/storage",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Attachments, receipts and photos held for the organization's events, against its quota", body = StorageUsageResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer in the company"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "storage"
+)]
+pub async fn get_storage_usage(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has_in(Permission::OrganizationManage, Some(company_id));
+    let user = current_user(&app_state, &claims).await?;
+    let report = app_state
+        .storage_quota_service
+        .report(company_id, &user, may_manage)
+        .await?;
+
+    Ok(success_response(StorageUsageResponse::from(report)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/companies/{id}/storage-quota",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = StorageQuotaRequest,
+    responses(
+        (status = 200, description = "Quota changed; uploads already stored are kept", body = StorageUsageResponse),
+        (status = 400, description = "Negative quota"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Admin access required"),
+        (status = 404, description = "Company not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "storage"
+)]
+pub async fn set_storage_quota(
+    State(app_state): State<AppState>,
+    Path(company_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<StorageQuotaRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::OrganizationManage);
+    let (before, after) = app_state
+        .storage_quota_service
+        .set_quota(company_id, request.quota_bytes, may_manage)
+        .await?;
+
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "company_storage_quotas",
+            company_id.to_string(),
+            AuditAction::Update,
+            Some(&before),
+            Some(&after),
+        )
+        .await;
+    Ok(success_response(StorageUsageResponse::from(after)))
+}
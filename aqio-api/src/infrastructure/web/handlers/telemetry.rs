@@ -0,0 +1,56 @@
+// HTTP handlers for web vitals measured by the web client and the log lines it ships
+// Reachable without credentials, since public pages are measured and logged too
+
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+
+use crate::domain::{ApiResult, dto::{ClientLogBatch, ClientLogLevel, WebVitalsReport}};
+use crate::infrastructure::web::state::AppState;
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/telemetry/web-vitals",
+    request_body = WebVitalsReport,
+    responses(
+        (status = 202, description = "Measurements recorded"),
+        (status = 400, description = "Invalid report, or a value out of range")
+    ),
+    tag = "telemetry"
+)]
+pub async fn report_web_vitals(
+    State(app_state): State<AppState>,
+    Json(report): Json<WebVitalsReport>,
+) -> ApiResult<impl IntoResponse> {
+    app_state.web_vitals_service.record(report.into()).await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/telemetry/logs",
+    request_body = ClientLogBatch,
+    responses(
+        (status = 202, description = "Log lines written to the server log"),
+        (status = 400, description = "Invalid batch")
+    ),
+    tag = "telemetry"
+)]
+pub async fn report_client_logs(
+    Json(batch): Json<ClientLogBatch>,
+) -> ApiResult<impl IntoResponse> {
+    batch.validate()?;
+
+    // Written under their own target, so they can be filtered with RUST_LOG
+    for entry in &batch.entries {
+        let request_id = entry.request_id.as_deref().unwrap_or("none");
+        let route = entry.route.as_deref().unwrap_or("unknown");
+        match entry.level {
+            ClientLogLevel::Error => tracing::error!(target: "aqio_web", release = %batch.release, client_target = %entry.target, request_id, route, "{}", entry.message),
+            ClientLogLevel::Warn => tracing::warn!(target: "aqio_web", release = %batch.release, client_target = %entry.target, request_id, route, "{}", entry.message),
+            ClientLogLevel::Info => tracing::info!(target: "aqio_web", release = %batch.release, client_target = %entry.target, request_id, route, "{}", entry.message),
+            ClientLogLevel::Debug => tracing::debug!(target: "aqio_web", release = %batch.release, client_target = %entry.target, request_id, route, "{}", entry.message),
+        }
+    }
+
+    Ok(StatusCode::ACCEPTED)
+}
@@ -0,0 +1,343 @@
+// Ticketing handlers - ticket types priced in NOK, EUR or USD, orders with the
+// exchange rate of their day, and revenue per currency
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use aqio_core::{Permission, User};
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{
+        ApiError, ApiResult,
+        dto::{PurchaseTicketsRequest, TicketRevenueResponse, TicketTypeRequest, TicketTypeResponse},
+        services::TicketTypeAvailability,
+    },
+    infrastructure::web::{
+        extractors::Permissions,
+        response::{created_response, empty_success, success_response},
+        state::AppState,
+    },
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/ticket-types",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Ticket types on sale, cheapest first; organizers also see inactive ones", body = Vec<TicketTypeResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tickets"
+)]
+pub async fn list_ticket_types(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let ticket_types = app_state
+        .ticketing_service
+        .list_ticket_types(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(
+        ticket_types.into_iter().map(TicketTypeResponse::from).collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/ticket-types",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = TicketTypeRequest,
+    responses(
+        (status = 201, description = "Ticket type created", body = TicketTypeResponse),
+        (status = 400, description = "Invalid name, price or quantity"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tickets"
+)]
+pub async fn create_ticket_type(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<TicketTypeRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let ticket_type = app_state
+        .ticketing_service
+        .create_ticket_type(event_id, user.id, may_manage, request.into())
+        .await?;
+
+    Ok(created_response(TicketTypeResponse::from(TicketTypeAvailability {
+        remaining: ticket_type.quantity.map(i64::from),
+        sold: 0,
+        ticket_type,
+    })))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/ticket-types/{ticket_type_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("ticket_type_id" = Uuid, Path, description = "Ticket type ID")
+    ),
+    request_body = TicketTypeRequest,
+    responses(
+        (status = 200, description = "Ticket type updated; existing orders keep their price", body = TicketTypeResponse),
+        (status = 400, description = "Invalid name or price, or a quantity below what has been sold"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or ticket type not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tickets"
+)]
+pub async fn update_ticket_type(
+    State(app_state): State<AppState>,
+    Path((event_id, ticket_type_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<TicketTypeRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let service = &app_state.ticketing_service;
+    service
+        .update_ticket_type(event_id, ticket_type_id, user.id, may_manage, request.into())
+        .await?;
+    let updated = service
+        .list_ticket_types(event_id, user.id, may_manage)
+        .await?
+        .into_iter()
+        .find(|availability| availability.ticket_type.id == ticket_type_id)
+        .ok_or_else(|| ApiError::not_found(format!("Ticket type with ID {}", ticket_type_id)))?;
+
+    Ok(success_response(TicketTypeResponse::from(updated)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/ticket-types/{ticket_type_id}",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        ("ticket_type_id" = Uuid, Path, description = "Ticket type ID")
+    ),
+    responses(
+        (status = 204, description = "Ticket type deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event or ticket type not found"),
+        (status = 409, description = "The type has orders; deactivate it instead")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tickets"
+)]
+pub async fn delete_ticket_type(
+    State(app_state): State<AppState>,
+    Path((event_id, ticket_type_id)): Path<(Uuid, Uuid)>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    app_state
+        .ticketing_service
+        .delete_ticket_type(event_id, ticket_type_id, user.id, may_manage)
+        .await?;
+
+    Ok(empty_success())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/ticket-orders",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = PurchaseTicketsRequest,
+    responses(
+        (status = 201, description = "Order placed at today's exchange rate to NOK", body = TicketOrder),
+        (status = 400, description = "The type isn't on sale or the quantity is out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event or ticket type not found"),
+        (status = 409, description = "Not enough tickets left"),
+        (status = 422, description = "The event takes no registrations now"),
+        (status = 503, description = "No exchange rate could be fetched")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tickets"
+)]
+pub async fn purchase_tickets(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Json(request): Json<PurchaseTicketsRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let order = app_state
+        .ticketing_service
+        .purchase(event_id, request.ticket_type_id, user.id, request.quantity)
+        .await?;
+
+    Ok(created_response(order))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/ticket-orders",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "The event's orders, newest first", body = Vec<TicketOrder>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tickets"
+)]
+pub async fn list_event_ticket_orders(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let orders = app_state
+        .ticketing_service
+        .event_orders(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(orders))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/ticket-revenue",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Confirmed sales per currency and in NOK", body = TicketRevenueResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tickets"
+)]
+pub async fn get_ticket_revenue(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let revenue = app_state
+        .ticketing_service
+        .revenue(event_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(TicketRevenueResponse::new(event_id, revenue)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/ticket-orders",
+    responses(
+        (status = 200, description = "The current user's orders, newest first", body = Vec<TicketOrder>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tickets"
+)]
+pub async fn list_my_ticket_orders(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> ApiResult<impl IntoResponse> {
+    let user = current_user(&app_state, &claims).await?;
+    let orders = app_state.ticketing_service.my_orders(user.id).await?;
+
+    Ok(success_response(orders))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/ticket-orders/{order_id}/cancel",
+    params(
+        ("order_id" = Uuid, Path, description = "Ticket order ID")
+    ),
+    responses(
+        (status = 200, description = "Order cancelled; its tickets are back on sale", body = TicketOrder),
+        (status = 400, description = "The event has started"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Neither the buyer nor an organizer"),
+        (status = 404, description = "Order not found"),
+        (status = 409, description = "Already cancelled")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tickets"
+)]
+pub async fn cancel_ticket_order(
+    State(app_state): State<AppState>,
+    Path(order_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = permissions.has(Permission::EventManage);
+    let user = current_user(&app_state, &claims).await?;
+    let order = app_state
+        .ticketing_service
+        .cancel_order(order_id, user.id, may_manage)
+        .await?;
+
+    Ok(success_response(order))
+}
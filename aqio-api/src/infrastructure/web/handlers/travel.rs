@@ -0,0 +1,114 @@
+// Accommodation and travel information handlers - readable by everyone signed in, editable by organizers;
+// journeys to the venue are planned with Entur
+
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    response::IntoResponse,
+};
+use aqio_core::User;
+use uuid::Uuid;
+
+use crate::{
+    auth::Claims,
+    domain::{ApiError, ApiResult, dto::{TravelInfo, TravelOptionsQuery}},
+    infrastructure::web::{extractors::Permissions, response::success_response, state::AppState},
+};
+
+async fn current_user(state: &AppState, claims: &Claims) -> ApiResult<User> {
+    state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/travel",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    responses(
+        (status = 200, description = "Hotels, transport and parking; empty when none are set", body = TravelInfo),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "travel"
+)]
+pub async fn get_travel_info(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    let travel = app_state.event_service.travel_info(event_id).await?;
+
+    Ok(success_response(travel))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/travel",
+    params(
+        ("id" = Uuid, Path, description = "Event ID")
+    ),
+    request_body = TravelInfo,
+    responses(
+        (status = 200, description = "Travel information replaced", body = TravelInfo),
+        (status = 400, description = "Missing name, text too long or invalid URL"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not an organizer of the event"),
+        (status = 404, description = "Event not found")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "travel"
+)]
+pub async fn update_travel_info(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+    Json(request): Json<TravelInfo>,
+) -> ApiResult<impl IntoResponse> {
+    let may_manage = app_state.permission_service.manages_event(&permissions, event_id).await?;
+    let user = current_user(&app_state, &claims).await?;
+    let travel = app_state
+        .event_service
+        .update_travel_info(event_id, user.id, may_manage, request)
+        .await?;
+
+    Ok(success_response(travel))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/travel-options",
+    params(
+        ("id" = Uuid, Path, description = "Event ID"),
+        TravelOptionsQuery
+    ),
+    responses(
+        (status = 200, description = "Public transport and ferry journeys from the given coordinates to the venue, soonest first", body = TravelOptionsResponse),
+        (status = 400, description = "Invalid coordinates or limit, a virtual event, a venue without coordinates or an event that is over"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Event not found"),
+        (status = 503, description = "Entur is unavailable")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "travel"
+)]
+pub async fn get_travel_options(
+    State(app_state): State<AppState>,
+    Path(event_id): Path<Uuid>,
+    Query(query): Query<TravelOptionsQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let options = app_state.event_service.travel_options(event_id, query).await?;
+
+    Ok(success_response(options))
+}
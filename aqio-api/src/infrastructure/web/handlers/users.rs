@@ -1,5 +1,6 @@
 // User handlers - HTTP endpoints for user management
 
+use aqio_core::{AuditAction, Permission, UserRole};
 use axum::{
     Extension, Json,
     extract::{Path, Query, State},
@@ -10,10 +11,12 @@ use crate::auth::Claims;
 use crate::domain::{
     ApiError, ApiResult,
     dto::{
-        CreateUserRequest, PaginatedUserResponse, PaginationQuery, UpdateUserRequest, UserResponse,
+        CreateUserRequest, PaginatedUserResponse, PaginationQuery, PermissionsResponse, UpdateUserRequest,
+        UserResponse, UserSearchQuery, UserSearchResult,
     },
 };
 use crate::infrastructure::web::{
+    extractors::Permissions,
     response::{created_response, empty_success, success_response},
     state::AppState,
 };
@@ -21,10 +24,11 @@ use crate::infrastructure::web::{
 pub async fn create_user(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
     Json(request): Json<CreateUserRequest>,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Only admins can create users
-    if !claims.is_admin() {
+    if !permissions.has(Permission::UserManage) {
         return Err(ApiError::authorization(
             "Only administrators can create users",
         ));
@@ -32,6 +36,10 @@ pub async fn create_user(
 
     let user = request.to_domain_user()?;
     app_state.user_service.create_user(&user).await?;
+    app_state
+        .audit_log_service
+        .record_change(&claims.audit_actor(), "users", user.id.to_string(), AuditAction::Insert, None, Some(&user))
+        .await;
     Ok(created_response(UserResponse::from(user)))
 }
 
@@ -39,12 +47,13 @@ pub async fn get_user(
     State(app_state): State<AppState>,
     Path(user_id): Path<Uuid>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Users can only access their own data unless they're admin
     let requesting_user_id = Uuid::parse_str(&claims.sub)
         .map_err(|_| ApiError::authentication("Invalid user ID format"))?;
 
-    if user_id != requesting_user_id && !claims.is_admin() {
+    if user_id != requesting_user_id && !permissions.has(Permission::UserManage) {
         return Err(ApiError::authorization("Access denied"));
     }
 
@@ -55,10 +64,10 @@ pub async fn get_user(
 pub async fn list_users(
     State(app_state): State<AppState>,
     Query(pagination): Query<PaginationQuery>,
-    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Only admins can list all users
-    if !claims.is_admin() {
+    if !permissions.has(Permission::UserManage) {
         return Err(ApiError::authorization(
             "Only administrators can list users",
         ));
@@ -71,25 +80,92 @@ pub async fn list_users(
     ))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/search",
+    params(UserSearchQuery),
+    responses(
+        (status = 200, description = "Active users matching by name, email or company, prefix matches first", body = Vec<UserSearchResult>),
+        (status = 400, description = "Search text shorter than 2 characters or too long"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Only organizers and administrators can search"),
+        (status = 429, description = "Too many searches; try again in a minute")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+)]
+pub async fn search_users(
+    State(app_state): State<AppState>,
+    Query(query): Query<UserSearchQuery>,
+    Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    let searcher = app_state
+        .user_service
+        .get_user_by_keycloak_id(&claims.sub)
+        .await?
+        .ok_or_else(|| ApiError::authentication("User not found"))?;
+
+    let found = app_state
+        .user_service
+        .search_users(&searcher, &permissions, &query.q, query.limit)
+        .await?;
+    Ok(success_response(
+        found.into_iter().map(UserSearchResult::from).collect::<Vec<_>>(),
+    ))
+}
+
 pub async fn update_user(
     State(app_state): State<AppState>,
     Path(user_id): Path<Uuid>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
     Json(request): Json<UpdateUserRequest>,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Users can only update their own data unless they're admin
     let requesting_user_id = Uuid::parse_str(&claims.sub)
         .map_err(|_| ApiError::authentication("Invalid user ID format"))?;
 
-    if user_id != requesting_user_id && !claims.is_admin() {
+    if user_id != requesting_user_id && !permissions.has(Permission::UserManage) {
         return Err(ApiError::authorization("Access denied"));
     }
 
+    // Role and account status are administrative fields
+    let changes_access = request.role.is_some() || request.is_active.is_some();
+    if changes_access && !permissions.has(Permission::UserManage) {
+        return Err(ApiError::authorization(
+            "Only administrators can change roles or account status",
+        ));
+    }
+
     // Get existing user and apply updates
     let existing_user = app_state.user_service.get_user_by_id(user_id).await?;
-    let updated_user = request.apply_to_user(existing_user)?;
+    let updated_user = request.apply_to_user(existing_user.clone())?;
+
+    // Keep admins from locking themselves out
+    if user_id == requesting_user_id
+        && (!updated_user.is_active || !matches!(updated_user.role, UserRole::Admin))
+        && matches!(existing_user.role, UserRole::Admin)
+    {
+        return Err(ApiError::bad_request(
+            "Administrators cannot demote or deactivate their own account",
+        ));
+    }
 
     app_state.user_service.update_user(&updated_user).await?;
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "users",
+            user_id.to_string(),
+            AuditAction::Update,
+            Some(&existing_user),
+            Some(&updated_user),
+        )
+        .await;
     Ok(success_response(UserResponse::from(updated_user)))
 }
 
@@ -97,15 +173,32 @@ pub async fn delete_user(
     State(app_state): State<AppState>,
     Path(user_id): Path<Uuid>,
     Extension(claims): Extension<Claims>,
+    Permissions(permissions): Permissions,
 ) -> ApiResult<impl axum::response::IntoResponse> {
     // Only admins can delete users
-    if !claims.is_admin() {
+    if !permissions.has(Permission::UserManage) {
         return Err(ApiError::authorization(
             "Only administrators can delete users",
         ));
     }
 
+    if claims.sub == user_id.to_string() {
+        return Err(ApiError::bad_request("Administrators cannot delete their own account"));
+    }
+
+    let existing_user = app_state.user_service.get_user_by_id(user_id).await?;
     app_state.user_service.delete_user(user_id).await?;
+    app_state
+        .audit_log_service
+        .record_change(
+            &claims.audit_actor(),
+            "users",
+            user_id.to_string(),
+            AuditAction::Delete,
+            Some(&existing_user),
+            None,
+        )
+        .await;
     Ok(empty_success())
 }
 
@@ -119,3 +212,21 @@ pub async fn get_current_user(
     let user = app_state.user_service.get_user_by_id(user_id).await?;
     Ok(success_response(UserResponse::from(user)))
 }
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/permissions",
+    responses(
+        (status = 200, description = "What the signed-in user may do, for showing only the controls that will work", body = PermissionsResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "users"
+)]
+pub async fn get_current_user_permissions(
+    Permissions(permissions): Permissions,
+) -> ApiResult<impl axum::response::IntoResponse> {
+    Ok(success_response(PermissionsResponse::from(&permissions)))
+}
@@ -15,9 +15,21 @@ pub fn invitation_routes() -> Router<AppState> {
         // Event-scoped invitations
         .route("/event/{event_id}", get(invitations::list_event_invitations))
         .route("/event/{event_id}", post(invitations::create_invitation))
+        .route("/event/{event_id}/funnel", get(invitations::get_invitation_funnel))
         // User-scoped
         .route("/me", get(invitations::list_my_invitations))
         // Status updates and deletion
         .route("/{id}/status", put(invitations::update_invitation_status))
         .route("/{id}", delete(invitations::delete_invitation))
+        // Tracking URLs for the invitation email
+        .route("/{id}/tracking", get(invitations::get_invitation_tracking))
+        .route("/{id}/tracking/links", post(invitations::create_tracked_link))
+}
+
+// Nested under `/api/v1/public/invitations`; opened from the recipient's mail
+// client, so reachable without credentials
+pub fn public_invitation_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{token}/open.gif", get(invitations::track_open))
+        .route("/{token}/click", get(invitations::track_click))
 }
\ No newline at end of file
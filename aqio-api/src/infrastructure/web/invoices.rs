@@ -0,0 +1,20 @@
+use axum::{routing::{get, post}, Router};
+
+use crate::infrastructure::web::{
+    handlers::invoices,
+    state::AppState,
+};
+
+// Nested under `/api/v1/invoices`
+pub fn invoice_routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(invoices::list_invoices))
+        .route("/{id}", get(invoices::get_invoice))
+        .route("/{id}/ehf.xml", get(invoices::download_invoice_ehf))
+        .route("/{id}/paid", post(invoices::mark_invoice_paid))
+}
+
+// Merged into the ticket order routes, so paths are relative to `/ticket-orders`
+pub fn order_invoice_routes() -> Router<AppState> {
+    Router::new().route("/{order_id}/invoice", post(invoices::create_invoice))
+}
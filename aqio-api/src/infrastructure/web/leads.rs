@@ -0,0 +1,18 @@
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::leads,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn lead_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/exhibitors/{exhibitor_id}/leads", get(leads::list_leads).post(leads::scan_lead))
+        .route("/{id}/exhibitors/{exhibitor_id}/leads.csv", get(leads::download_leads))
+        .route("/{id}/exhibitors/{exhibitor_id}/leads/{lead_id}", delete(leads::delete_lead))
+        .route("/{id}/leads/analytics", get(leads::lead_analytics))
+}
@@ -0,0 +1,25 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::live,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn live_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/live", get(live::get_live_session))
+        .route("/{id}/questions", post(live::ask_question))
+        .route(
+            "/{id}/questions/{question_id}/upvote",
+            put(live::upvote_question).delete(live::remove_question_upvote),
+        )
+        .route("/{id}/questions/{question_id}/answer", put(live::answer_question))
+        .route("/{id}/polls", post(live::create_poll))
+        .route("/{id}/polls/{poll_id}/vote", put(live::vote_in_poll))
+        .route("/{id}/polls/{poll_id}/close", post(live::close_poll))
+        .route("/{id}/live/stream", get(live::live_update_stream))
+}
@@ -0,0 +1,30 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::matchmaking,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn matchmaking_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/matchmaking/profile",
+            get(matchmaking::get_matchmaking_profile)
+                .put(matchmaking::save_matchmaking_profile)
+                .delete(matchmaking::leave_matchmaking),
+        )
+        .route("/{id}/matchmaking/matches", get(matchmaking::list_matches))
+        .route(
+            "/{id}/matchmaking/rooms",
+            get(matchmaking::list_meeting_rooms).put(matchmaking::set_meeting_rooms),
+        )
+        .route("/{id}/meetings", get(matchmaking::list_meetings).post(matchmaking::request_meeting))
+        .route("/{id}/meetings/{meeting_id}/accept", post(matchmaking::accept_meeting))
+        .route("/{id}/meetings/{meeting_id}/decline", post(matchmaking::decline_meeting))
+        .route("/{id}/meetings/{meeting_id}/cancel", post(matchmaking::cancel_meeting))
+        .route("/{id}/meetings/{meeting_id}/invite.ics", get(matchmaking::download_meeting_invite))
+}
@@ -0,0 +1,20 @@
+use axum::{routing::{get, post}, Router};
+
+use crate::infrastructure::web::{
+    handlers::messages,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`; attendees
+// start a thread with the organizers here
+pub fn event_message_routes() -> Router<AppState> {
+    Router::new().route("/{id}/messages", post(messages::contact_organizers))
+}
+
+// Nested under `/api/v1/messages`
+pub fn message_routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(messages::list_message_threads))
+        .route("/unread", get(messages::count_unread_messages))
+        .route("/{thread_id}", get(messages::get_message_thread).post(messages::reply_to_message_thread))
+}
@@ -0,0 +1,230 @@
+// HTTP caching middleware - ETag, conditional GET and Cache-Control
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use axum::{
+    body::{Body, HttpBody, to_bytes},
+    http::{HeaderMap, HeaderValue, Method, Request, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+// Anonymous responses may be stored by shared caches, authenticated ones only by
+// the browser. Both must revalidate, which is cheap thanks to the ETag.
+const PUBLIC_CACHE_CONTROL: &str = "public, no-cache";
+const PRIVATE_CACHE_CONTROL: &str = "private, no-cache";
+
+// Bodies are buffered to compute the ETag, so anything larger (exports, downloads)
+// is sent as-is without one.
+const MAX_BUFFERED_BODY: u64 = 1024 * 1024;
+
+// Adds a weak ETag and Cache-Control header to successful GET/HEAD responses and
+// answers `If-None-Match` with 304 Not Modified when the representation is unchanged.
+// The ETag is weak because the compression layer may re-encode the body.
+// Streams (SSE, or any body without a known length) and large bodies are passed
+// through untouched so they are never buffered.
+pub async fn http_caching(request: Request<Body>, next: Next) -> Response {
+    let cacheable_method = matches!(*request.method(), Method::GET | Method::HEAD);
+    let authenticated = request.headers().contains_key(header::AUTHORIZATION);
+    let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+
+    let response = next.run(request).await;
+
+    if !cacheable_method || response.status() != StatusCode::OK || !is_bufferable(&response) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_BUFFERED_BODY as usize).await {
+        Ok(bytes) => bytes,
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+
+    let etag = weak_etag(&bytes);
+
+    if !parts.headers.contains_key(header::CACHE_CONTROL) {
+        let cache_control = if authenticated {
+            PRIVATE_CACHE_CONTROL
+        } else {
+            PUBLIC_CACHE_CONTROL
+        };
+        parts
+            .headers
+            .insert(header::CACHE_CONTROL, HeaderValue::from_static(cache_control));
+    }
+    parts.headers.insert(header::VARY, HeaderValue::from_static("Authorization, Accept-Encoding"));
+
+    if let Some(if_none_match) = if_none_match {
+        if etag_matches(&if_none_match, &etag) {
+            let mut not_modified = Response::new(Body::empty());
+            *not_modified.status_mut() = StatusCode::NOT_MODIFIED;
+            copy_validator_headers(&parts.headers, not_modified.headers_mut());
+            not_modified.headers_mut().insert(header::ETAG, etag);
+            return not_modified;
+        }
+    }
+
+    parts.headers.insert(header::ETAG, etag);
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+fn is_bufferable(response: &Response) -> bool {
+    let is_event_stream = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"));
+    if is_event_stream {
+        return false;
+    }
+
+    let declared_length = response
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    match declared_length.or_else(|| response.body().size_hint().exact()) {
+        Some(length) => length <= MAX_BUFFERED_BODY,
+        None => false,
+    }
+}
+
+fn weak_etag(body: &[u8]) -> HeaderValue {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    let value = format!("W/\"{:016x}-{:x}\"", hasher.finish(), body.len());
+    HeaderValue::from_str(&value).expect("ETag is valid ASCII")
+}
+
+// Weak comparison (RFC 9110 §13.1.2): the W/ prefix is ignored on both sides
+fn etag_matches(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let Ok(if_none_match) = if_none_match.to_str() else {
+        return false;
+    };
+    let etag = strip_weak(etag.to_str().unwrap_or_default());
+
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || strip_weak(candidate) == etag)
+}
+
+fn strip_weak(tag: &str) -> &str {
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
+
+// A 304 must carry the headers a 200 would have sent that affect caching
+fn copy_validator_headers(from: &HeaderMap, to: &mut HeaderMap) {
+    for name in [header::CACHE_CONTROL, header::VARY, header::EXPIRES, header::CONTENT_LOCATION] {
+        if let Some(value) = from.get(&name) {
+            to.insert(name, value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, middleware, routing::get};
+    use tokio_stream::StreamExt;
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route("/events", get(|| async { axum::Json(serde_json::json!({"items": [1, 2, 3]})) }))
+            .route("/missing", get(|| async { StatusCode::NOT_FOUND }))
+            .route(
+                "/stream",
+                get(|| async {
+                    let event = axum::response::sse::Event::default().data("hello");
+                    let stream = tokio_stream::once(Ok::<_, std::convert::Infallible>(event))
+                        .chain(tokio_stream::pending());
+                    axum::response::Sse::new(stream)
+                }),
+            )
+            .route("/export", get(|| async { vec![b'x'; MAX_BUFFERED_BODY as usize + 1] }))
+            .layer(middleware::from_fn(http_caching))
+    }
+
+    async fn get_with(uri: &str, headers: &[(header::HeaderName, &str)]) -> Response {
+        let mut request = Request::builder().uri(uri);
+        for (name, value) in headers {
+            request = request.header(name, *value);
+        }
+        app().oneshot(request.body(Body::empty()).unwrap()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_adds_etag_and_cache_control() {
+        let response = get_with("/events", &[]).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers()[header::ETAG].to_str().unwrap().starts_with("W/\""));
+        assert_eq!(response.headers()[header::CACHE_CONTROL], PUBLIC_CACHE_CONTROL);
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_responses_are_private() {
+        let response = get_with("/events", &[(header::AUTHORIZATION, "Bearer token")]).await;
+
+        assert_eq!(response.headers()[header::CACHE_CONTROL], PRIVATE_CACHE_CONTROL);
+    }
+
+    #[tokio::test]
+    async fn test_conditional_get_returns_not_modified() {
+        let first = get_with("/events", &[]).await;
+        let etag = first.headers()[header::ETAG].to_str().unwrap().to_string();
+
+        let second = get_with("/events", &[(header::IF_NONE_MATCH, &etag)]).await;
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(second.headers()[header::ETAG], etag.as_str());
+        let body = to_bytes(second.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+
+        let stale = get_with("/events", &[(header::IF_NONE_MATCH, "W/\"stale\"")]).await;
+        assert_eq!(stale.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_error_responses_are_untouched() {
+        let response = get_with("/missing", &[]).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(!response.headers().contains_key(header::ETAG));
+    }
+
+    #[tokio::test]
+    async fn test_event_streams_are_not_buffered() {
+        let response = get_with("/stream", &[]).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!response.headers().contains_key(header::ETAG));
+        let mut body = response.into_body().into_data_stream();
+        let frame = tokio::time::timeout(std::time::Duration::from_secs(1), body.next())
+            .await
+            .expect("first SSE frame arrives without waiting for the stream to end")
+            .unwrap()
+            .unwrap();
+        assert_eq!(&frame[..], b"data: hello\n\n");
+    }
+
+    #[tokio::test]
+    async fn test_large_bodies_are_sent_without_etag() {
+        let response = get_with("/export", &[]).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!response.headers().contains_key(header::ETAG));
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body.len(), MAX_BUFFERED_BODY as usize + 1);
+    }
+
+    #[test]
+    fn test_etag_matching() {
+        let etag = HeaderValue::from_static("W/\"abc\"");
+
+        assert!(etag_matches(&HeaderValue::from_static("\"abc\""), &etag));
+        assert!(etag_matches(&HeaderValue::from_static("W/\"x\", W/\"abc\""), &etag));
+        assert!(etag_matches(&HeaderValue::from_static("*"), &etag));
+        assert!(!etag_matches(&HeaderValue::from_static("W/\"abd\""), &etag));
+    }
+}
@@ -1,7 +1,7 @@
 // Global error handling middleware
 
 use axum::{
-    http::Request,
+    http::{header::CONTENT_TYPE, Request, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
@@ -11,7 +11,21 @@ use crate::domain::ApiError;
 // This middleware catches any unhandled errors and converts them to proper API responses
 pub async fn handle_errors(request: Request<axum::body::Body>, next: Next) -> Response {
     let response = next.run(request).await;
+
+    // Extractors refuse bodies over the route's `DefaultBodyLimit` with a plain
+    // text 413; answer in the same shape as every other error
+    if response.status() == StatusCode::PAYLOAD_TOO_LARGE && !is_json(&response) {
+        return ApiError::payload_too_large("Request body is too large for this endpoint").into_response();
+    }
+    response
+}
+
+fn is_json(response: &Response) -> bool {
     response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
 }
 
 // Extension trait to convert Result<Response, ApiError> to Response
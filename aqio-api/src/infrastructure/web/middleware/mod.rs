@@ -1,7 +1,11 @@
 // Middleware for cross-cutting concerns
 
+pub mod caching;
 pub mod error_handling;
+pub mod request_id;
 pub mod response;
 
+pub use caching::http_caching;
 pub use error_handling::{handle_errors, ApiResultExt};
+pub use request_id::trace_requests;
 pub use response::response_middleware;
\ No newline at end of file
@@ -0,0 +1,90 @@
+// Request correlation - every request runs in a tracing span carrying a request
+// ID, which is echoed in the `X-Request-Id` response header. The web client sends
+// its own ID with each call and logs it, so a failing call in the browser console
+// can be found in the server logs.
+
+use std::time::Instant;
+
+use axum::{
+    body::Body,
+    http::{HeaderName, HeaderValue, Request},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+const MAX_REQUEST_ID_LENGTH: usize = 64;
+
+// Taken from the client when it's short and plain enough to log as is,
+// otherwise generated here
+pub async fn trace_requests(request: Request<Body>, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_usable_request_id(id))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().simple().to_string());
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %request.method(),
+        path = %request.uri().path(),
+    );
+    let started = Instant::now();
+    let mut response = next.run(request).instrument(span.clone()).await;
+
+    span.in_scope(|| {
+        tracing::debug!(
+            status = response.status().as_u16(),
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "request finished"
+        );
+    });
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+fn is_usable_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LENGTH
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, middleware, routing::get};
+    use tower::ServiceExt;
+
+    async fn get_with(request_id: Option<&str>) -> Response {
+        let app = Router::new()
+            .route("/events", get(|| async { "ok" }))
+            .layer(middleware::from_fn(trace_requests));
+        let mut request = Request::builder().uri("/events");
+        if let Some(request_id) = request_id {
+            request = request.header(REQUEST_ID_HEADER, request_id);
+        }
+        app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_echoes_client_request_id_or_generates_one() {
+        let response = get_with(Some("3f2a9c01e4b64d0e")).await;
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "3f2a9c01e4b64d0e");
+
+        let too_long = "x".repeat(65);
+        for unusable in [None, Some("has spaces"), Some(too_long.as_str())] {
+            let response = get_with(unusable).await;
+            let generated = response.headers()[REQUEST_ID_HEADER].to_str().unwrap();
+            assert_eq!(generated.len(), 32);
+            assert!(is_usable_request_id(generated));
+        }
+    }
+}
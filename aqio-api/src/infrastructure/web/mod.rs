@@ -7,15 +7,62 @@ pub mod openapi;
 pub mod response;
 pub mod routing;
 pub mod state;
+pub mod static_files;
 
 // Route modules
 pub mod events;
 pub mod users;
 pub mod categories;
+pub mod companies;
+pub mod comments;
+pub mod live;
+pub mod attachments;
+pub mod photos;
+pub mod budgets;
+pub mod sponsors;
+pub mod short_links;
+pub mod posters;
+pub mod overbooking;
+pub mod tickets;
+pub mod invoices;
+pub mod financial_reports;
+pub mod refunds;
+pub mod travel;
+pub mod catering;
+pub mod seating;
+pub mod resources;
+pub mod shifts;
+pub mod exhibitors;
+pub mod leads;
+pub mod matchmaking;
+pub mod past_events;
+pub mod annual_reports;
+pub mod consents;
+pub mod broadcasts;
+pub mod messages;
+pub mod scheduling;
+pub mod event_fields;
+pub mod sending_domains;
+pub mod organization_invitations;
+pub mod organization_roles;
+pub mod access_grants;
+pub mod ownership_transfers;
+pub mod event_submissions;
+pub mod reports;
+pub mod event_archives;
+pub mod storage;
+pub mod email_events;
+pub mod certificates;
 pub mod invitations;
 pub mod registrations;
+pub mod public_events;
 pub mod health;
+pub mod admin;
+pub mod client_errors;
+pub mod telemetry;
+pub mod client_config;
 
 // Re-export commonly used items
-pub use routing::{create_routes, add_auth_middleware};
-pub use state::AppState;
\ No newline at end of file
+pub use routing::{create_routes, add_auth_middleware, public_routes};
+pub use state::{AppDependencies, AppState};
+pub use static_files::spa_router;
\ No newline at end of file
@@ -18,10 +18,252 @@ use aqio_core::*;
         crate::infrastructure::web::handlers::simple_health,
         crate::infrastructure::web::handlers::list_events,
         crate::infrastructure::web::handlers::get_event,
+        crate::infrastructure::web::handlers::list_events_with_details,
+        crate::infrastructure::web::handlers::get_event_with_details,
+        crate::infrastructure::web::handlers::get_event_availability,
         crate::infrastructure::web::handlers::create_event,
         crate::infrastructure::web::handlers::update_event,
         crate::infrastructure::web::handlers::delete_event,
         crate::infrastructure::web::handlers::get_my_events,
+        crate::infrastructure::web::handlers::list_comments,
+        crate::infrastructure::web::handlers::create_comment,
+        crate::infrastructure::web::handlers::delete_comment,
+        crate::infrastructure::web::handlers::get_live_session,
+        crate::infrastructure::web::handlers::ask_question,
+        crate::infrastructure::web::handlers::upvote_question,
+        crate::infrastructure::web::handlers::remove_question_upvote,
+        crate::infrastructure::web::handlers::answer_question,
+        crate::infrastructure::web::handlers::create_poll,
+        crate::infrastructure::web::handlers::vote_in_poll,
+        crate::infrastructure::web::handlers::close_poll,
+        crate::infrastructure::web::handlers::list_attachments,
+        crate::infrastructure::web::handlers::upload_attachment,
+        crate::infrastructure::web::handlers::download_attachment,
+        crate::infrastructure::web::handlers::delete_attachment,
+        crate::infrastructure::web::handlers::get_photo_gallery,
+        crate::infrastructure::web::handlers::upload_photo,
+        crate::infrastructure::web::handlers::update_photo_album,
+        crate::infrastructure::web::handlers::download_photo_album,
+        crate::infrastructure::web::handlers::download_photo,
+        crate::infrastructure::web::handlers::moderate_photo,
+        crate::infrastructure::web::handlers::delete_photo,
+        crate::infrastructure::web::handlers::get_budget,
+        crate::infrastructure::web::handlers::create_budget_line,
+        crate::infrastructure::web::handlers::update_budget_line,
+        crate::infrastructure::web::handlers::delete_budget_line,
+        crate::infrastructure::web::handlers::create_expense,
+        crate::infrastructure::web::handlers::update_expense,
+        crate::infrastructure::web::handlers::delete_expense,
+        crate::infrastructure::web::handlers::upload_receipt,
+        crate::infrastructure::web::handlers::download_receipt,
+        crate::infrastructure::web::handlers::delete_receipt,
+        crate::infrastructure::web::handlers::list_sponsors,
+        crate::infrastructure::web::handlers::create_sponsor,
+        crate::infrastructure::web::handlers::update_sponsor,
+        crate::infrastructure::web::handlers::delete_sponsor,
+        crate::infrastructure::web::handlers::reorder_sponsors,
+        crate::infrastructure::web::handlers::list_short_links,
+        crate::infrastructure::web::handlers::create_short_link,
+        crate::infrastructure::web::handlers::get_short_link_stats,
+        crate::infrastructure::web::handlers::list_invitation_short_links,
+        crate::infrastructure::web::handlers::delete_short_link,
+        crate::infrastructure::web::handlers::follow_short_link,
+        crate::infrastructure::web::handlers::download_poster_pdf,
+        crate::infrastructure::web::handlers::download_poster_png,
+        crate::infrastructure::web::handlers::get_overbooking,
+        crate::infrastructure::web::handlers::set_overbooking_policy,
+        crate::infrastructure::web::handlers::remove_overbooking_policy,
+        crate::infrastructure::web::handlers::get_overbooking_report,
+        crate::infrastructure::web::handlers::list_ticket_types,
+        crate::infrastructure::web::handlers::create_ticket_type,
+        crate::infrastructure::web::handlers::update_ticket_type,
+        crate::infrastructure::web::handlers::delete_ticket_type,
+        crate::infrastructure::web::handlers::purchase_tickets,
+        crate::infrastructure::web::handlers::list_event_ticket_orders,
+        crate::infrastructure::web::handlers::get_ticket_revenue,
+        crate::infrastructure::web::handlers::list_my_ticket_orders,
+        crate::infrastructure::web::handlers::cancel_ticket_order,
+        crate::infrastructure::web::handlers::create_invoice,
+        crate::infrastructure::web::handlers::list_invoices,
+        crate::infrastructure::web::handlers::get_invoice,
+        crate::infrastructure::web::handlers::download_invoice_ehf,
+        crate::infrastructure::web::handlers::mark_invoice_paid,
+        crate::infrastructure::web::handlers::get_monthly_financial_report,
+        crate::infrastructure::web::handlers::download_monthly_financial_report,
+        crate::infrastructure::web::handlers::cancel_event,
+        crate::infrastructure::web::handlers::list_event_refunds,
+        crate::infrastructure::web::handlers::retry_event_refunds,
+        crate::infrastructure::web::handlers::get_travel_info,
+        crate::infrastructure::web::handlers::update_travel_info,
+        crate::infrastructure::web::handlers::get_travel_options,
+        crate::infrastructure::web::handlers::get_catering_report,
+        crate::infrastructure::web::handlers::download_catering_report,
+        crate::infrastructure::web::handlers::get_seating_plan,
+        crate::infrastructure::web::handlers::create_seating_table,
+        crate::infrastructure::web::handlers::update_seating_table,
+        crate::infrastructure::web::handlers::delete_seating_table,
+        crate::infrastructure::web::handlers::assign_seat,
+        crate::infrastructure::web::handlers::download_seating_chart,
+        crate::infrastructure::web::handlers::list_resources,
+        crate::infrastructure::web::handlers::create_resource,
+        crate::infrastructure::web::handlers::get_resource,
+        crate::infrastructure::web::handlers::update_resource,
+        crate::infrastructure::web::handlers::set_resource_availability,
+        crate::infrastructure::web::handlers::list_resource_bookings,
+        crate::infrastructure::web::handlers::check_resource_conflicts,
+        crate::infrastructure::web::handlers::book_resources,
+        crate::infrastructure::web::handlers::list_event_resource_bookings,
+        crate::infrastructure::web::handlers::cancel_resource_booking,
+        crate::infrastructure::web::handlers::list_shifts,
+        crate::infrastructure::web::handlers::get_shift_coverage,
+        crate::infrastructure::web::handlers::create_shift,
+        crate::infrastructure::web::handlers::update_shift,
+        crate::infrastructure::web::handlers::delete_shift,
+        crate::infrastructure::web::handlers::sign_up_for_shift,
+        crate::infrastructure::web::handlers::withdraw_from_shift,
+        crate::infrastructure::web::handlers::download_my_shifts_calendar,
+        crate::infrastructure::web::handlers::list_booths,
+        crate::infrastructure::web::handlers::create_booth,
+        crate::infrastructure::web::handlers::update_booth,
+        crate::infrastructure::web::handlers::delete_booth,
+        crate::infrastructure::web::handlers::list_exhibitors,
+        crate::infrastructure::web::handlers::create_exhibitor,
+        crate::infrastructure::web::handlers::update_exhibitor,
+        crate::infrastructure::web::handlers::delete_exhibitor,
+        crate::infrastructure::web::handlers::allocate_booth,
+        crate::infrastructure::web::handlers::scan_lead,
+        crate::infrastructure::web::handlers::list_leads,
+        crate::infrastructure::web::handlers::download_leads,
+        crate::infrastructure::web::handlers::delete_lead,
+        crate::infrastructure::web::handlers::lead_analytics,
+        crate::infrastructure::web::handlers::get_matchmaking_profile,
+        crate::infrastructure::web::handlers::save_matchmaking_profile,
+        crate::infrastructure::web::handlers::leave_matchmaking,
+        crate::infrastructure::web::handlers::list_matches,
+        crate::infrastructure::web::handlers::list_meeting_rooms,
+        crate::infrastructure::web::handlers::set_meeting_rooms,
+        crate::infrastructure::web::handlers::request_meeting,
+        crate::infrastructure::web::handlers::list_meetings,
+        crate::infrastructure::web::handlers::accept_meeting,
+        crate::infrastructure::web::handlers::decline_meeting,
+        crate::infrastructure::web::handlers::cancel_meeting,
+        crate::infrastructure::web::handlers::download_meeting_invite,
+        crate::infrastructure::web::handlers::list_past_events,
+        crate::infrastructure::web::handlers::get_attendance_trends,
+        crate::infrastructure::web::handlers::get_annual_report,
+        crate::infrastructure::web::handlers::download_annual_report,
+        crate::infrastructure::web::handlers::list_consents,
+        crate::infrastructure::web::handlers::configure_consent,
+        crate::infrastructure::web::handlers::remove_consent,
+        crate::infrastructure::web::handlers::get_consent_report,
+        crate::infrastructure::web::handlers::download_consent_report,
+        crate::infrastructure::web::handlers::send_broadcast,
+        crate::infrastructure::web::handlers::preview_broadcast,
+        crate::infrastructure::web::handlers::list_broadcasts,
+        crate::infrastructure::web::handlers::cancel_broadcast,
+        crate::infrastructure::web::handlers::contact_organizers,
+        crate::infrastructure::web::handlers::list_message_threads,
+        crate::infrastructure::web::handlers::count_unread_messages,
+        crate::infrastructure::web::handlers::get_message_thread,
+        crate::infrastructure::web::handlers::reply_to_message_thread,
+        crate::infrastructure::web::handlers::download_certificate,
+        crate::infrastructure::web::handlers::set_certificate_signature,
+        crate::infrastructure::web::handlers::remove_certificate_signature,
+        crate::infrastructure::web::handlers::get_notification_preferences,
+        crate::infrastructure::web::handlers::update_notification_preferences,
+        crate::infrastructure::web::handlers::list_companies,
+        crate::infrastructure::web::handlers::get_company,
+        crate::infrastructure::web::handlers::list_company_events,
+        crate::infrastructure::web::handlers::create_company,
+        crate::infrastructure::web::handlers::lookup_company_registry,
+        crate::infrastructure::web::handlers::get_scheduling_policy,
+        crate::infrastructure::web::handlers::update_scheduling_policy,
+        crate::infrastructure::web::handlers::list_blackouts,
+        crate::infrastructure::web::handlers::create_blackout,
+        crate::infrastructure::web::handlers::update_blackout,
+        crate::infrastructure::web::handlers::delete_blackout,
+        crate::infrastructure::web::handlers::check_schedule,
+        crate::infrastructure::web::handlers::list_event_fields,
+        crate::infrastructure::web::handlers::create_event_field,
+        crate::infrastructure::web::handlers::update_event_field,
+        crate::infrastructure::web::handlers::delete_event_field,
+        crate::infrastructure::web::handlers::get_event_field_values,
+        crate::infrastructure::web::handlers::update_event_field_values,
+        crate::infrastructure::web::handlers::get_sending_domain,
+        crate::infrastructure::web::handlers::update_sending_domain,
+        crate::infrastructure::web::handlers::check_sending_domain,
+        crate::infrastructure::web::handlers::delete_sending_domain,
+        crate::infrastructure::web::handlers::create_organization_invitation,
+        crate::infrastructure::web::handlers::accept_organization_invitation,
+        crate::infrastructure::web::handlers::list_organization_roles,
+        crate::infrastructure::web::handlers::create_organization_role,
+        crate::infrastructure::web::handlers::update_organization_role,
+        crate::infrastructure::web::handlers::delete_organization_role,
+        crate::infrastructure::web::handlers::list_organization_role_members,
+        crate::infrastructure::web::handlers::add_organization_role_member,
+        crate::infrastructure::web::handlers::remove_organization_role_member,
+        crate::infrastructure::web::handlers::list_access_grants,
+        crate::infrastructure::web::handlers::create_access_grant,
+        crate::infrastructure::web::handlers::revoke_access_grant,
+        crate::infrastructure::web::handlers::list_export_jobs,
+        crate::infrastructure::web::handlers::create_export_job,
+        crate::infrastructure::web::handlers::get_export_job,
+        crate::infrastructure::web::handlers::update_export_job,
+        crate::infrastructure::web::handlers::delete_export_job,
+        crate::infrastructure::web::handlers::run_export_job,
+        crate::infrastructure::web::handlers::list_crm_connectors,
+        crate::infrastructure::web::handlers::create_crm_connector,
+        crate::infrastructure::web::handlers::get_crm_connector,
+        crate::infrastructure::web::handlers::update_crm_connector,
+        crate::infrastructure::web::handlers::delete_crm_connector,
+        crate::infrastructure::web::handlers::get_crm_connector_status,
+        crate::infrastructure::web::handlers::sync_crm_connector,
+        crate::infrastructure::web::handlers::transfer_event_ownership,
+        crate::infrastructure::web::handlers::get_pending_ownership_transfer,
+        crate::infrastructure::web::handlers::cancel_ownership_transfer,
+        crate::infrastructure::web::handlers::list_incoming_ownership_transfers,
+        crate::infrastructure::web::handlers::accept_ownership_transfer,
+        crate::infrastructure::web::handlers::decline_ownership_transfer,
+        crate::infrastructure::web::handlers::submit_event,
+        crate::infrastructure::web::handlers::list_event_submission_queue,
+        crate::infrastructure::web::handlers::list_my_event_submissions,
+        crate::infrastructure::web::handlers::approve_event_submission,
+        crate::infrastructure::web::handlers::reject_event_submission,
+        crate::infrastructure::web::handlers::resubmit_event_submission,
+        crate::infrastructure::web::handlers::report_event,
+        crate::infrastructure::web::handlers::report_comment,
+        crate::infrastructure::web::handlers::report_profile,
+        crate::infrastructure::web::handlers::list_reported_content,
+        crate::infrastructure::web::handlers::moderate_content,
+        crate::infrastructure::web::handlers::get_storage_usage,
+        crate::infrastructure::web::handlers::set_storage_quota,
+        crate::infrastructure::web::handlers::export_event,
+        crate::infrastructure::web::handlers::import_event,
+        crate::infrastructure::web::handlers::list_audit_log,
+        crate::infrastructure::web::handlers::list_email_suppressions,
+        crate::infrastructure::web::handlers::delete_email_suppression,
+        crate::infrastructure::web::handlers::start_impersonation,
+        crate::infrastructure::web::handlers::get_query_metrics,
+        crate::infrastructure::web::handlers::get_web_vitals,
+        crate::infrastructure::web::handlers::list_organization_invitations,
+        crate::infrastructure::web::handlers::revoke_organization_invitation,
+        crate::infrastructure::web::handlers::receive_ses_events,
+        crate::infrastructure::web::handlers::receive_sendgrid_events,
+        crate::infrastructure::web::handlers::get_public_event,
+        crate::infrastructure::web::handlers::get_sitemap,
+        crate::infrastructure::web::handlers::download_public_calendar,
+        crate::infrastructure::web::handlers::list_public_consents,
+        crate::infrastructure::web::handlers::register_guest,
+        crate::infrastructure::web::handlers::download_public_attachment,
+        crate::infrastructure::web::handlers::download_public_photo,
+        crate::infrastructure::web::handlers::verify_certificate,
+        crate::infrastructure::web::handlers::get_organization_invitation_by_token,
+        crate::infrastructure::web::handlers::report_client_error,
+        crate::infrastructure::web::handlers::report_web_vitals,
+        crate::infrastructure::web::handlers::report_client_logs,
+        crate::infrastructure::web::handlers::get_client_config,
+        crate::infrastructure::web::handlers::search_users,
+        crate::infrastructure::web::handlers::get_current_user_permissions,
     ),
     components(
         schemas(
@@ -30,10 +272,13 @@ use aqio_core::*;
             User,
             IndustryType,
             Company,
+            CompanyFilter,
+            CompanyMemberCount,
             UserProfile,
             EventCategory,
             LocationType,
             EventStatus,
+            RegistrationState,
             Event,
             InvitationMethod,
             InvitationStatus,
@@ -42,7 +287,24 @@ use aqio_core::*;
             RegistrationSource,
             EventRegistration,
             ExternalContact,
+            EventComment,
+            EventQuestion,
+            EventPoll,
+            PollOption,
+            AttachmentCategory,
+            AttachmentVisibility,
+            EventAttachment,
+            PhotoStatus,
+            PhotoAlbum,
+            EventPhoto,
+            RegistrationCounts,
+            AuditAction,
+            AuditLogEntry,
+            AuditLogFilter,
             EventFilter,
+            GeoRadius,
+            AddressRegion,
+            WeatherForecast,
             PaginationParams,
             PaginatedResult<Event>,
             PaginatedResult<User>,
@@ -54,6 +316,18 @@ use aqio_core::*;
             ListEventsQuery,
             PaginationQuery,
             PaginatedEventResponse,
+            EventView,
+            EventSummaryResponse,
+            PaginatedEventSummaryResponse,
+            OrganizerSummary,
+            RegistrationCountsResponse,
+            EventWithDetailsResponse,
+            EventAvailabilityResponse,
+            crate::domain::archive::EventArchive,
+            crate::domain::anonymize::PiiField,
+            EventArchiveQuery,
+            EventImportResponse,
+            PaginatedEventWithDetailsResponse,
             PaginationInfo,
             ApiResponse<EventResponse>,
             ApiResponse<UserResponse>,
@@ -64,20 +338,297 @@ use aqio_core::*;
             UpdateUserRequest,
             UserResponse,
             PaginatedUserResponse,
+            UserSearchQuery,
+            UserSearchResult,
             CreateEventCategoryRequest,
             UpdateEventCategoryRequest,
             EventCategoryResponse,
+            CompanyDirectoryQuery,
+            CompanyResponse,
+            PaginatedCompanyResponse,
+            CreateCompanyRequest,
+            CreatedCompanyResponse,
+            CompanyRegistryResponse,
+            RegisteredCompanyResponse,
+            CreateCommentRequest,
+            CommentResponse,
+            AskQuestionRequest,
+            AnswerQuestionRequest,
+            QuestionResponse,
+            CreatePollRequest,
+            PollVoteRequest,
+            PollResponse,
+            PollOptionResponse,
+            LiveSessionResponse,
+            UploadAttachmentForm,
+            AttachmentResponse,
+            EventAttachmentsResponse,
+            UploadPhotoForm,
+            PhotoResponse,
+            PhotoGalleryResponse,
+            PhotoAlbumRequest,
+            ModeratePhotoRequest,
+            BudgetLineRequest,
+            ExpenseRequest,
+            UploadReceiptForm,
+            BudgetLineResponse,
+            ReceiptResponse,
+            ExpenseResponse,
+            BudgetLineTotalsResponse,
+            BudgetSummaryResponse,
+            EventBudgetResponse,
+            SponsorTier,
+            SponsorRequest,
+            ReorderSponsorsRequest,
+            SponsorResponse,
+            CreateShortLinkRequest,
+            ShortLinkResponse,
+            ShortLinkStatsResponse,
+            InvitationShortLinkResponse,
+            FollowShortLinkQuery,
+            PosterQuery,
+            SetOverbookingPolicyRequest,
+            OverbookingResponse,
+            NoShowHistoryResponse,
+            OverbookingReportResponse,
+            OverbookingPolicy,
+            TicketTypeRequest,
+            TicketTypeResponse,
+            PurchaseTicketsRequest,
+            TicketRevenueResponse,
+            TicketOrder,
+            OrderStatus,
+            Currency,
+            CurrencyRevenue,
+            InvoiceRequest,
+            InvoiceListQuery,
+            InvoiceResponse,
+            FinancialReportQuery,
+            FinancialReportResponse,
+            VatBreakdown,
+            TicketRefund,
+            RefundStatus,
+            EventRefundsResponse,
+            Invoice,
+            InvoiceParty,
+            InvoiceStatus,
+            CampaignParameters,
+            DailyClicks,
+            ReferrerClicks,
+            TravelInfo,
+            Hotel,
+            TransportOption,
+            TransportMode,
+            ParkingInfo,
+            TravelOptionsQuery,
+            TravelOptionsResponse,
+            TravelOptionResponse,
+            TravelOption,
+            TravelLeg,
+            TravelMode,
+            CateringReportResponse,
+            CateringGroupResponse,
+            SeatingTable,
+            SeatingTableRequest,
+            SeatAssignmentRequest,
+            SeatingPlanResponse,
+            PlannedTableResponse,
+            SeatedPartyResponse,
+            SeatingWarningResponse,
+            Resource,
+            AvailabilityWindow,
+            ResourceBooking,
+            ResourceConflict,
+            ListResourcesQuery,
+            ResourceRequest,
+            ResourceAvailabilityRequest,
+            ResourceResponse,
+            ResourceBookingsQuery,
+            ResourceConflictsRequest,
+            ResourceConflictsResponse,
+            BookResourcesRequest,
+            EventResourceBookingsQuery,
+            EventShift,
+            ShiftRequest,
+            ShiftStaffResponse,
+            StaffedShiftResponse,
+            ShiftCoverageResponse,
+            Booth,
+            Exhibitor,
+            BoothRequest,
+            ExhibitorRequest,
+            AllocateBoothRequest,
+            PublicExhibitorResponse,
+            Lead,
+            ScanLeadRequest,
+            LeadResponse,
+            ExhibitorLeadCountResponse,
+            HourlyLeadCountResponse,
+            LeadAnalyticsResponse,
+            MatchmakingProfile,
+            MeetingStatus,
+            Meeting,
+            MatchmakingProfileRequest,
+            MatchResponse,
+            MeetingRoomsRequest,
+            RequestMeetingRequest,
+            MeetingResponse,
+            ArchiveGrouping,
+            PastEventsQuery,
+            AttendanceTrendsQuery,
+            PastEventResponse,
+            PaginatedPastEventResponse,
+            TrendYearResponse,
+            AttendanceTrendResponse,
+            AnnualReportQuery,
+            AnnualReportResponse,
+            AnnualTotalsResponse,
+            AnnualGrowthResponse,
+            ReportCategoryResponse,
+            SatisfactionResponse,
+            ConsentKind,
+            EventConsent,
+            RegistrationConsent,
+            ConsentAnswerRequest,
+            ConfigureConsentRequest,
+            ConsentTotalsResponse,
+            ConsentRowResponse,
+            ConsentReportResponse,
+            BroadcastAudience,
+            BroadcastStatus,
+            BroadcastDelivery,
+            EventBroadcast,
+            BroadcastRequest,
+            BroadcastPreviewResponse,
+            ThreadMessage,
+            ContactOrganizersRequest,
+            ReplyMessageRequest,
+            MessageThreadResponse,
+            MessageThreadDetailsResponse,
+            UnreadMessagesResponse,
+            CertificateVerificationResponse,
+            RuleEnforcement,
+            ScheduleRule,
+            ScheduleConflict,
+            SchedulingPolicyRequest,
+            SchedulingPolicyResponse,
+            BlackoutRequest,
+            BlackoutResponse,
+            ScheduleCheckRequest,
+            ScheduleCheckResponse,
+            EventFieldType,
+            EventFieldRequest,
+            EventFieldResponse,
+            EventFieldValuesRequest,
+            EventFieldValueResponse,
+            EventFieldsResponse,
+            DomainVerificationStatus,
+            DnsRecordType,
+            SendingDomainCheck,
+            RequiredDnsRecord,
+            SendingDomainRequest,
+            SendingDomainResponse,
+            AuditLogQuery,
+            AuditLogEntryResponse,
+            PaginatedAuditLogResponse,
+            StartImpersonationRequest,
+            ImpersonationResponse,
+            QueryMetricsResponse,
+            QueryMethodStatsResponse,
+            OrganizationInvitationStatus,
+            CreateOrganizationInvitationRequest,
+            OrganizationInvitationQuery,
+            OrganizationInvitationResponse,
+            Permission,
+            PermissionsResponse,
+            OrganizationPermissionsResponse,
+            EventPermissionsResponse,
+            OrganizationRoleRequest,
+            OrganizationRoleResponse,
+            AccessGrantRequest,
+            AccessGrantResponse,
+            ExportEntity,
+            ExportFormat,
+            ExportFilter,
+            ExportDestination,
+            ExportRunStatus,
+            ExportJob,
+            ExportJobRequest,
+            CrmProvider,
+            CrmRecordKind,
+            CrmSourceField,
+            CrmFieldMapping,
+            CrmConflictPolicy,
+            CrmSyncRunStatus,
+            CrmSyncSummary,
+            CrmConnector,
+            CrmConnectorRequest,
+            CrmSyncState,
+            CrmSyncRecord,
+            CrmSyncCounts,
+            CrmConnectorStatusResponse,
+            OwnershipTransferStatus,
+            TransferOwnershipRequest,
+            OwnershipTransferResponse,
+            EventSubmissionStatus,
+            ReviewEventSubmissionRequest,
+            EventSubmissionResponse,
+            ReportTarget,
+            ReportReason,
+            ReportStatus,
+            ModerationAction,
+            ReportContentRequest,
+            ContentReportResponse,
+            ReportedContentResponse,
+            ModerateContentRequest,
+            ModerationOutcomeResponse,
+            StorageUsage,
+            StorageUsageResponse,
+            StorageQuotaRequest,
             HealthResponse,
             HealthServices,
             ServiceHealth,
+            IntegrationHealth,
             CreateInvitationRequest,
             UpdateInvitationStatusRequest,
             InvitationResponse,
+            InvitationTrackingResponse,
+            TrackedLinkRequest,
+            TrackedLinkResponse,
+            InvitationFunnelResponse,
+            DeliveryFailure,
+            EmailSuppression,
+            DeliveryReportResponse,
+            DigestFrequency,
+            Locale,
+            Theme,
+            CalendarView,
+            NotificationPreferencesRequest,
+            NotificationPreferencesResponse,
             CreateRegistrationRequest,
             UpdateRegistrationRequest,
             UpdateRegistrationStatusRequest,
+            MergeRegistrationsRequest,
             RegistrationResponse,
             EventRegistrationStatsResponse,
+            WaitlistStatusResponse,
+            AgendaItem,
+            Speaker,
+            PublicEventResponse,
+            GuestRegistrationResponse,
+            ClientErrorReport,
+            WebVitalMetric,
+            WebVitalMeasurement,
+            WebVitalsReport,
+            WebVitalSummaryResponse,
+            ClientLogLevel,
+            ClientLogEntry,
+            ClientLogBatch,
+            AuthModeResponse,
+            BrandingResponse,
+            ClientConfigResponse,
+            crate::domain::notifications::RegistrationNotification,
+            crate::domain::live_updates::LiveUpdate,
         )
     ),
     tags(
@@ -85,8 +636,54 @@ use aqio_core::*;
         (name = "events", description = "Event management"),
         (name = "users", description = "User management"),
         (name = "categories", description = "Event category management"),
+        (name = "comments", description = "Event discussion threads"),
+        (name = "live", description = "Audience Q&A and polls while an event is running"),
+        (name = "attachments", description = "Slides, documents and maps attached to events"),
+        (name = "photos", description = "Photo galleries filled once events are over, with moderation of attendees' photos and a download of the whole album"),
+        (name = "budgets", description = "Event budget lines, expenses and receipts, visible to organizers only"),
+        (name = "sponsors", description = "Event sponsors shown on the public page and printed program"),
+        (name = "short-links", description = "Short, trackable links to event pages for newsletters and social media, with clicks per day, referrer and invitation"),
+        (name = "posters", description = "Printable and shareable event posters with a QR code to the registration page"),
+        (name = "overbooking", description = "Registrations beyond an event's capacity, based on the no-show rate of past events, and realized attendance"),
+        (name = "tickets", description = "Ticket types priced in NOK, EUR or USD, orders with the exchange rate of their day, and revenue per currency"),
+        (name = "invoices", description = "Invoices for ticket orders bought by organizations, EHF export and overdue tracking"),
+        (name = "financial-reports", description = "Monthly ticket sales, refunds and VAT per rate, exportable as CSV for accounting"),
+        (name = "refunds", description = "Cancelling paid events: ticket orders refunded through Vipps MobilePay, retried until they succeed or fail"),
+        (name = "travel", description = "Hotels, transport and parking for attendees travelling to an event"),
+        (name = "catering", description = "Dietary and accessibility needs of confirmed attendees, for the venue"),
+        (name = "seating", description = "Tables and seat assignments for seated dinners, with warnings and a printable chart"),
+        (name = "shifts", description = "Staff shifts at events with self-signup, coverage gaps and a calendar of your own shifts"),
+        (name = "exhibitors", description = "Expo booths on a floor plan, exhibitors and booth allocation"),
+        (name = "leads", description = "Exhibitors scanning consenting attendees' badges, lead exports and lead analytics"),
+        (name = "matchmaking", description = "Attendees' matchmaking profiles and suggested matches, and 1:1 meetings scheduled into free slots and meeting rooms"),
+        (name = "resources", description = "Bookable equipment and facilities, their availability windows, and conflict-checked bookings for events"),
+        (name = "past-events", description = "Archive of events that are over, with attendance and year-over-year totals per category or organizer"),
+        (name = "annual-reports", description = "Organizations' annual reports of events held, attendance and survey satisfaction, as JSON or PDF"),
+        (name = "consents", description = "Consent checkboxes on the registration form and the consent report for compliance"),
+        (name = "broadcasts", description = "Messages organizers send to all, confirmed, waitlisted or checked-in registrants, now or at a scheduled time"),
+        (name = "messages", description = "Conversations about an event between its organizers and one attendee, with unread counts"),
+        (name = "certificates", description = "Certificates of attendance for checked-in attendees"),
+        (name = "companies", description = "Company directory, and adding companies checked against the Norwegian business register"),
+        (name = "event-fields", description = "Typed custom fields organizations add to their members' events, and each event's values"),
+        (name = "scheduling", description = "Organization blackout dates and scheduling policies checked when events are created or moved"),
+        (name = "sending-domains", description = "Organization email sending domains and the SPF/DKIM records that verify them"),
+        (name = "organization-invitations", description = "Emailed invitations for colleagues to join an organization"),
+        (name = "organization-roles", description = "Custom roles organizations define for their members, such as door staff who may only check people in"),
+        (name = "access-grants", description = "Time-bound access to an event its organizers delegate, such as check-in rights for the day of the event"),
+        (name = "ownership-transfers", description = "Handing events to another organizer, who accepts or declines"),
+        (name = "event-submissions", description = "Events proposed by the community, approved or rejected by moderators before they are published"),
+        (name = "reports", description = "Spam and abuse reports on comments, events and profiles, and the moderation dashboard"),
+        (name = "storage", description = "Media storage used by an organization's events against its quota"),
         (name = "invitations", description = "Invitation management"),
+        (name = "email-delivery", description = "Bounce and complaint webhooks from the mail provider"),
         (name = "registrations", description = "Registration management"),
+        (name = "admin", description = "Administration and audit log"),
+        (name = "exports", description = "Registrations and events pushed to external systems such as a CRM, as CSV or JSON over SFTP or S3, on a cron schedule"),
+        (name = "crm", description = "Connectors that sync registrants to HubSpot or Dynamics 365 contacts and registration records, and their sync status"),
+        (name = "public", description = "Public event pages and certificate checks, no authentication required"),
+        (name = "client-errors", description = "Errors reported by the web client, no authentication required"),
+        (name = "telemetry", description = "Web vitals and log lines from the web client, no authentication required"),
+        (name = "config", description = "Settings the web client reads at startup, no authentication required"),
     )
 )]
 pub struct ApiDoc;
\ No newline at end of file
@@ -0,0 +1,26 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::organization_invitations,
+    state::AppState,
+};
+
+// Merged into the company routes, so paths are relative to `/companies`
+pub fn company_invitation_routes() -> Router<AppState> {
+    Router::new().route("/{id}/invitations", post(organization_invitations::create_organization_invitation))
+}
+
+// Nested under `/api/v1/organization-invitations`; accepting needs a signed-in
+// user, who is created on first login
+pub fn organization_invitation_routes() -> Router<AppState> {
+    Router::new().route("/{token}/accept", post(organization_invitations::accept_organization_invitation))
+}
+
+// Nested under `/api/v1/public/organization-invitations`; the join page shows
+// the invitation before the recipient signs in
+pub fn public_organization_invitation_routes() -> Router<AppState> {
+    Router::new().route("/{token}", get(organization_invitations::get_organization_invitation_by_token))
+}
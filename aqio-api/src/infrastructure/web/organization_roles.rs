@@ -0,0 +1,28 @@
+use axum::{
+    routing::{get, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::organization_roles,
+    state::AppState,
+};
+
+// Merged into the company routes, so paths are relative to `/companies`
+pub fn organization_role_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/roles",
+            get(organization_roles::list_organization_roles).post(organization_roles::create_organization_role),
+        )
+        .route(
+            "/{id}/roles/{role_id}",
+            put(organization_roles::update_organization_role).delete(organization_roles::delete_organization_role),
+        )
+        .route("/{id}/roles/{role_id}/members", get(organization_roles::list_organization_role_members))
+        .route(
+            "/{id}/roles/{role_id}/members/{user_id}",
+            put(organization_roles::add_organization_role_member)
+                .delete(organization_roles::remove_organization_role_member),
+        )
+}
@@ -0,0 +1,18 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{
+    handlers::overbooking,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn overbooking_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/overbooking",
+            get(overbooking::get_overbooking)
+                .put(overbooking::set_overbooking_policy)
+                .delete(overbooking::remove_overbooking_policy),
+        )
+        .route("/{id}/overbooking/report", get(overbooking::get_overbooking_report))
+}
@@ -0,0 +1,27 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::ownership_transfers,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn event_ownership_routes() -> Router<AppState> {
+    Router::new().route(
+        "/{id}/transfer-ownership",
+        post(ownership_transfers::transfer_event_ownership)
+            .get(ownership_transfers::get_pending_ownership_transfer)
+            .delete(ownership_transfers::cancel_ownership_transfer),
+    )
+}
+
+// Nested under `/api/v1/ownership-transfers`; the proposed owner answers here
+pub fn ownership_transfer_routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(ownership_transfers::list_incoming_ownership_transfers))
+        .route("/{id}/accept", post(ownership_transfers::accept_ownership_transfer))
+        .route("/{id}/decline", post(ownership_transfers::decline_ownership_transfer))
+}
@@ -0,0 +1,13 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{
+    handlers::past_events,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn past_event_routes() -> Router<AppState> {
+    Router::new()
+        .route("/past", get(past_events::list_past_events))
+        .route("/past/trends", get(past_events::get_attendance_trends))
+}
@@ -0,0 +1,29 @@
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{get, put},
+    Router,
+};
+
+use crate::domain::services::EventPhotoApplicationService;
+use crate::infrastructure::web::{
+    handlers::photos,
+    state::AppState,
+};
+
+// Room for the multipart framing and caption around the largest accepted photo
+const UPLOAD_BODY_LIMIT: usize = EventPhotoApplicationService::MAX_FILE_BYTES + 64 * 1024;
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn photo_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/photos",
+            get(photos::get_photo_gallery)
+                .post(photos::upload_photo)
+                .layer(DefaultBodyLimit::max(UPLOAD_BODY_LIMIT)),
+        )
+        .route("/{id}/photos/album", put(photos::update_photo_album))
+        .route("/{id}/photos/download", get(photos::download_photo_album))
+        .route("/{id}/photos/{photo_id}", get(photos::download_photo).delete(photos::delete_photo))
+        .route("/{id}/photos/{photo_id}/status", put(photos::moderate_photo))
+}
@@ -0,0 +1,13 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{
+    handlers::posters,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn poster_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/poster.pdf", get(posters::download_poster_pdf))
+        .route("/{id}/poster.png", get(posters::download_poster_png))
+}
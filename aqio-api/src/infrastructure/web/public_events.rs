@@ -0,0 +1,24 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::public_events,
+    state::AppState,
+};
+
+pub fn public_event_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{slug}", get(public_events::get_public_event))
+        .route("/{slug}/calendar.ics", get(public_events::download_public_calendar))
+        .route("/{slug}/consents", get(public_events::list_public_consents))
+        .route("/{slug}/registrations", post(public_events::register_guest))
+        .route("/{slug}/attachments/{attachment_id}", get(public_events::download_public_attachment))
+        .route("/{slug}/photos/{photo_id}", get(public_events::download_public_photo))
+}
+
+// Served at the site root, where search engines look for it
+pub fn sitemap_routes() -> Router<AppState> {
+    Router::new().route("/sitemap.xml", get(public_events::get_sitemap))
+}
@@ -0,0 +1,14 @@
+use axum::{routing::{get, post}, Router};
+
+use crate::infrastructure::web::{
+    handlers::refunds,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn refund_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/cancel", post(refunds::cancel_event))
+        .route("/{id}/refunds", get(refunds::list_event_refunds))
+        .route("/{id}/refunds/retry", post(refunds::retry_event_refunds))
+}
@@ -4,7 +4,7 @@ use axum::{
 };
 
 use crate::infrastructure::web::{
-    handlers::registrations,
+    handlers::{certificates, registrations},
     state::AppState,
 };
 
@@ -18,9 +18,16 @@ pub fn registration_routes() -> Router<AppState> {
         .route("/{id}", delete(registrations::delete_registration))
         // User's own registrations
         .route("/me", get(registrations::get_user_registrations))
+        .route("/me/stream", get(registrations::registration_notification_stream))
+        // Waitlist promotion
+        .route("/{id}/waitlist", get(registrations::get_waitlist_status))
+        .route("/{id}/confirm", post(registrations::confirm_promotion))
+        // Certificate of attendance, once checked in
+        .route("/{id}/certificate", get(certificates::download_certificate))
         // Event management endpoints (admin/organizer)
         .route("/event/{event_id}/list", get(registrations::get_event_registrations))
         .route("/event/{event_id}/stats", get(registrations::get_event_registration_stats))
+        .route("/event/{event_id}/merge", post(registrations::merge_registrations))
         .route("/{id}/status", put(registrations::update_registration_status))
         .route("/{id}/checkin", post(registrations::check_in_registration))
 }
\ No newline at end of file
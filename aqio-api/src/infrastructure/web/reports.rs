@@ -0,0 +1,18 @@
+use axum::{routing::post, Router};
+
+use crate::infrastructure::web::{
+    handlers::reports,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn event_report_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/report", post(reports::report_event))
+        .route("/{id}/comments/{comment_id}/report", post(reports::report_comment))
+}
+
+// Merged into the user routes, so paths are relative to `/users`
+pub fn user_report_routes() -> Router<AppState> {
+    Router::new().route("/{id}/report", post(reports::report_profile))
+}
@@ -0,0 +1,24 @@
+use axum::{
+    routing::{delete, get, post, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::resources,
+    state::AppState,
+};
+
+// Nested under `/resources`
+pub fn resource_routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(resources::list_resources).post(resources::create_resource))
+        .route("/conflicts", post(resources::check_resource_conflicts))
+        .route(
+            "/bookings",
+            get(resources::list_event_resource_bookings).post(resources::book_resources),
+        )
+        .route("/bookings/{booking_id}", delete(resources::cancel_resource_booking))
+        .route("/{id}", get(resources::get_resource).put(resources::update_resource))
+        .route("/{id}/availability", put(resources::set_resource_availability))
+        .route("/{id}/bookings", get(resources::list_resource_bookings))
+}
@@ -1,35 +1,131 @@
 // Modular routing configuration
 
-use super::{events::events_routes, users::user_routes, categories::category_routes, 
-           invitations::invitation_routes, registrations::registration_routes, health::health_routes};
+use super::{
+    events::{events_routes, public_event_availability_routes},
+    comments::comment_routes,
+    live::live_routes,
+    attachments::attachment_routes,
+    photos::photo_routes,
+    budgets::budget_routes,
+    sponsors::sponsor_routes,
+    short_links::{short_link_routes, public_short_link_routes},
+    posters::poster_routes,
+    overbooking::overbooking_routes,
+    tickets::{ticket_routes, ticket_order_routes},
+    invoices::{invoice_routes, order_invoice_routes},
+    financial_reports::financial_report_routes,
+    refunds::refund_routes,
+    travel::travel_routes,
+    catering::catering_routes,
+    seating::seating_routes,
+    resources::resource_routes,
+    shifts::{event_shift_routes, shift_routes},
+    exhibitors::exhibitor_routes,
+    leads::lead_routes,
+    matchmaking::matchmaking_routes,
+    past_events::past_event_routes,
+    annual_reports::annual_report_routes,
+    consents::consent_routes,
+    broadcasts::broadcast_routes,
+    messages::{event_message_routes, message_routes},
+    scheduling::{scheduling_routes, schedule_check_routes},
+    event_fields::{event_field_routes, event_field_value_routes},
+    sending_domains::sending_domain_routes,
+    organization_invitations::{
+        company_invitation_routes,
+        organization_invitation_routes,
+        public_organization_invitation_routes,
+    },
+    organization_roles::organization_role_routes,
+    access_grants::access_grant_routes,
+    ownership_transfers::{event_ownership_routes, ownership_transfer_routes},
+    event_submissions::event_submission_routes,
+    reports::{event_report_routes, user_report_routes},
+    event_archives::event_archive_routes,
+    storage::storage_routes,
+    certificates::{certificate_routes, public_certificate_routes},
+    users::user_routes,
+    categories::category_routes,
+    companies::company_routes,
+    invitations::{invitation_routes, public_invitation_routes},
+    registrations::registration_routes,
+    health::health_routes,
+    admin::admin_routes,
+    public_events::{public_event_routes, sitemap_routes},
+    client_errors::client_error_routes,
+    telemetry::telemetry_routes,
+    client_config::client_config_routes,
+    email_events::email_event_routes,
+};
 
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
     response::IntoResponse,
     routing::get,
     Router,
 };
 use tower::ServiceBuilder;
-use tower_http::cors::CorsLayer;
+use tower_http::{compression::CompressionLayer, cors::CorsLayer};
 use utoipa::OpenApi;
 
 use crate::{
     auth::{auth_middleware, KeycloakConfig},
     auth::mock::{mock_auth_middleware, MockAuthConfig},
     infrastructure::web::{
-        middleware::handle_errors,
+        middleware::{handle_errors, http_caching},
         state::AppState,
         openapi::ApiDoc,
     },
 };
 
+/// Largest request body accepted by JSON endpoints. Uploads, event imports and
+/// mail provider webhooks raise it on their own routes.
+pub const JSON_BODY_LIMIT: usize = 256 * 1024;
 
 pub fn create_routes() -> Router<AppState> {
     Router::new()
         .route("/api-docs/openapi.json", get(openapi_spec))
-        .nest("/api/v1", api_v1_routes())
-        .merge(health_routes())
-        .layer(ServiceBuilder::new().layer(CorsLayer::permissive()))
+        .nest(
+            "/api/v1",
+            api_v1_routes()
+                .layer(DefaultBodyLimit::max(JSON_BODY_LIMIT))
+                .layer(middleware::from_fn(http_caching)),
+        )
+        .layer(
+            ServiceBuilder::new()
+                .layer(CorsLayer::permissive())
+                // gzip/brotli negotiated via Accept-Encoding; small bodies are skipped
+                .layer(CompressionLayer::new()),
+        )
+        .layer(middleware::from_fn(handle_errors))
+}
+
+// Routes that must stay reachable without credentials (container health probes,
+// load balancers, public event pages, their sitemap and availability polling, certificate
+// checks, invitation tracking, short links, organization join links, mail provider webhooks,
+// client error reports, web vitals and client log lines, the frontend's startup configuration). Merge these after the auth middleware has been applied.
+pub fn public_routes() -> Router<AppState> {
+    health_routes()
+        .nest(
+            "/api/v1/public/events",
+            public_event_routes().layer(middleware::from_fn(http_caching)),
+        )
+        .merge(sitemap_routes().layer(middleware::from_fn(http_caching)))
+        .nest(
+            "/api/v1/events",
+            public_event_availability_routes().layer(middleware::from_fn(http_caching)),
+        )
+        .nest("/api/v1/public/certificates", public_certificate_routes())
+        .nest("/api/v1/public/invitations", public_invitation_routes())
+        .nest("/api/v1/public/links", public_short_link_routes())
+        .nest("/api/v1/public/organization-invitations", public_organization_invitation_routes())
+        .nest("/api/v1/public/email-events", email_event_routes())
+        .nest("/api/v1/client-errors", client_error_routes())
+        .nest("/api/v1/telemetry", telemetry_routes())
+        .nest("/api/v1/config", client_config_routes().layer(middleware::from_fn(http_caching)))
+        .layer(DefaultBodyLimit::max(JSON_BODY_LIMIT))
+        .layer(CorsLayer::permissive())
         .layer(middleware::from_fn(handle_errors))
 }
 
@@ -39,11 +135,64 @@ async fn openapi_spec() -> impl IntoResponse {
 
 fn api_v1_routes() -> Router<AppState> {
     Router::new()
-        .nest("/events", events_routes())
-        .nest("/users", user_routes())
+        .nest(
+            "/events",
+            events_routes()
+                .merge(comment_routes())
+                .merge(live_routes())
+                .merge(attachment_routes())
+                .merge(photo_routes())
+                .merge(budget_routes())
+                .merge(sponsor_routes())
+                .merge(short_link_routes())
+                .merge(poster_routes())
+                .merge(overbooking_routes())
+                .merge(ticket_routes())
+                .merge(refund_routes())
+                .merge(travel_routes())
+                .merge(catering_routes())
+                .merge(seating_routes())
+                .merge(event_shift_routes())
+                .merge(exhibitor_routes())
+                .merge(lead_routes())
+                .merge(matchmaking_routes())
+                .merge(past_event_routes())
+                .merge(consent_routes())
+                .merge(broadcast_routes())
+                .merge(event_message_routes())
+                .merge(certificate_routes())
+                .merge(schedule_check_routes())
+                .merge(event_ownership_routes())
+                .merge(event_archive_routes())
+                .merge(event_report_routes())
+                .merge(event_field_value_routes())
+                .merge(access_grant_routes())
+        )
+        .nest("/users", user_routes().merge(user_report_routes()))
         .nest("/categories", category_routes())
+        .nest(
+            "/companies",
+            company_routes()
+                .merge(scheduling_routes())
+                .merge(sending_domain_routes())
+                .merge(company_invitation_routes())
+                .merge(organization_role_routes())
+                .merge(storage_routes())
+                .merge(event_field_routes())
+        )
+        .nest("/organizations", annual_report_routes())
         .nest("/invitations", invitation_routes())
+        .nest("/organization-invitations", organization_invitation_routes())
+        .nest("/ownership-transfers", ownership_transfer_routes())
+        .nest("/event-submissions", event_submission_routes())
+        .nest("/messages", message_routes())
+        .nest("/ticket-orders", ticket_order_routes().merge(order_invoice_routes()))
+        .nest("/invoices", invoice_routes())
+        .nest("/financial-reports", financial_report_routes())
+        .nest("/resources", resource_routes())
+        .nest("/shifts", shift_routes())
         .nest("/registrations", registration_routes())
+        .nest("/admin", admin_routes())
 }
 
 pub fn add_auth_middleware<S>(
@@ -64,4 +213,90 @@ where
     }
     
     router
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::{Body, Bytes},
+        http::{Request, StatusCode, header},
+    };
+    use std::time::Duration;
+    use tokio_stream::StreamExt;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        domain::{live_updates::LiveUpdate, notifications::RegistrationNotification},
+        testing::{app::create_test_app_state, helpers::TestUserBuilder},
+    };
+    use aqio_core::{EventQuestion, User};
+
+    // Subject of the mock auth middleware's default user
+    const DEV_USER_SUB: &str = "550e8400-e29b-41d4-a716-446655440001";
+
+    async fn signed_in_app() -> (Router, AppState, User, tempfile::TempDir) {
+        let (state, media_dir) = create_test_app_state().await;
+        let user = TestUserBuilder::new().with_keycloak_id(DEV_USER_SUB).build();
+        state.user_service.create_user(&user).await.unwrap();
+        let app = add_auth_middleware(create_routes(), true, None, Some(MockAuthConfig::new(true)))
+            .with_state(state.clone());
+        (app, state, user, media_dir)
+    }
+
+    // A middleware that buffers the body would never let the response through, so
+    // the headers must arrive in time as well
+    async fn open_stream(app: Router, uri: &str) -> axum::body::BodyDataStream {
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = tokio::time::timeout(Duration::from_secs(5), app.oneshot(request))
+            .await
+            .expect("SSE response is sent before the stream ends")
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/event-stream");
+        response.into_body().into_data_stream()
+    }
+
+    async fn next_frame(stream: &mut axum::body::BodyDataStream) -> String {
+        let frame: Bytes = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("SSE frame arrives while the stream is still open")
+            .expect("stream is open")
+            .unwrap();
+        String::from_utf8(frame.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_live_stream_delivers_updates_through_the_router() {
+        let (app, state, user, _media_dir) = signed_in_app().await;
+        let event_id = Uuid::new_v4();
+
+        let mut stream = open_stream(app, &format!("/api/v1/events/{}/live/stream", event_id)).await;
+        let question = EventQuestion::new(event_id, &user, "Is there parking?");
+        state.live_service.notifier().publish(event_id, LiveUpdate::QuestionChanged { question });
+
+        let frame = next_frame(&mut stream).await;
+        assert!(frame.starts_with("event: question_changed\n"), "{}", frame);
+        assert!(frame.contains("Is there parking?"));
+    }
+
+    #[tokio::test]
+    async fn test_registration_stream_delivers_promotions_through_the_router() {
+        let (app, state, user, _media_dir) = signed_in_app().await;
+        let registration_id = Uuid::new_v4();
+
+        let mut stream = open_stream(app, "/api/v1/registrations/me/stream").await;
+        state.registration_service.notifier().notify(
+            user.id,
+            RegistrationNotification::Promoted {
+                registration_id,
+                event_id: Uuid::new_v4(),
+                confirm_by: chrono::Utc::now() + chrono::Duration::hours(24),
+            },
+        );
+
+        let frame = next_frame(&mut stream).await;
+        assert!(frame.starts_with("event: promoted\n"), "{}", frame);
+        assert!(frame.contains(&registration_id.to_string()));
+    }
+}
@@ -0,0 +1,28 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::scheduling,
+    state::AppState,
+};
+
+// Merged into the company routes, so paths are relative to `/companies`
+pub fn scheduling_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/scheduling-policy",
+            get(scheduling::get_scheduling_policy).put(scheduling::update_scheduling_policy),
+        )
+        .route("/{id}/blackouts", get(scheduling::list_blackouts).post(scheduling::create_blackout))
+        .route(
+            "/{id}/blackouts/{blackout_id}",
+            put(scheduling::update_blackout).delete(scheduling::delete_blackout),
+        )
+}
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn schedule_check_routes() -> Router<AppState> {
+    Router::new().route("/schedule-check", post(scheduling::check_schedule))
+}
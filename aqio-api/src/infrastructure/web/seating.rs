@@ -0,0 +1,22 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::seating,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn seating_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/seating", get(seating::get_seating_plan))
+        .route("/{id}/seating/chart.pdf", get(seating::download_seating_chart))
+        .route("/{id}/seating/tables", post(seating::create_seating_table))
+        .route(
+            "/{id}/seating/tables/{table_id}",
+            put(seating::update_seating_table).delete(seating::delete_seating_table),
+        )
+        .route("/{id}/seating/assignments/{registration_id}", put(seating::assign_seat))
+}
@@ -0,0 +1,21 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::sending_domains,
+    state::AppState,
+};
+
+// Merged into the company routes, so paths are relative to `/companies`
+pub fn sending_domain_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/sending-domain",
+            get(sending_domains::get_sending_domain)
+                .put(sending_domains::update_sending_domain)
+                .delete(sending_domains::delete_sending_domain),
+        )
+        .route("/{id}/sending-domain/check", post(sending_domains::check_sending_domain))
+}
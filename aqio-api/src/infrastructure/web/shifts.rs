@@ -0,0 +1,26 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::shifts,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn event_shift_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/shifts", get(shifts::list_shifts).post(shifts::create_shift))
+        .route("/{id}/shifts/coverage", get(shifts::get_shift_coverage))
+        .route("/{id}/shifts/{shift_id}", put(shifts::update_shift).delete(shifts::delete_shift))
+        .route(
+            "/{id}/shifts/{shift_id}/signup",
+            post(shifts::sign_up_for_shift).delete(shifts::withdraw_from_shift),
+        )
+}
+
+// Nested under `/api/v1/shifts`
+pub fn shift_routes() -> Router<AppState> {
+    Router::new().route("/mine.ics", get(shifts::download_my_shifts_calendar))
+}
@@ -0,0 +1,30 @@
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::short_links,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn short_link_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/short-links",
+            get(short_links::list_short_links).post(short_links::create_short_link),
+        )
+        .route("/{id}/short-links/{link_id}", delete(short_links::delete_short_link))
+        .route("/{id}/short-links/{link_id}/stats", get(short_links::get_short_link_stats))
+        .route(
+            "/{id}/short-links/{link_id}/invitations",
+            get(short_links::list_invitation_short_links),
+        )
+}
+
+// Nested under `/api/v1/public/links`; followed by anyone the link was
+// shared with, so reachable without credentials
+pub fn public_short_link_routes() -> Router<AppState> {
+    Router::new().route("/{code}", get(short_links::follow_short_link))
+}
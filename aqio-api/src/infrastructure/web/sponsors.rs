@@ -0,0 +1,20 @@
+use axum::{
+    routing::{get, put},
+    Router,
+};
+
+use crate::infrastructure::web::{
+    handlers::sponsors,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn sponsor_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/sponsors", get(sponsors::list_sponsors).post(sponsors::create_sponsor))
+        .route("/{id}/sponsors/order", put(sponsors::reorder_sponsors))
+        .route(
+            "/{id}/sponsors/{sponsor_id}",
+            put(sponsors::update_sponsor).delete(sponsors::delete_sponsor),
+        )
+}
@@ -3,41 +3,492 @@
 
 use std::sync::Arc;
 
+use crate::auth::impersonation::ImpersonationTokens;
+use crate::config::{ClientConfig, InvoicingConfig};
+use crate::domain::anonymize::Anonymizer;
+use crate::infrastructure::resilience::Resilience;
 use crate::domain::services::{
-    EventApplicationService, EventCategoryApplicationService, EventRegistrationApplicationService,
-    HealthApplicationService, InvitationApplicationService, UserApplicationService,
+    AuditLogApplicationService, CertificateApplicationService, EmailDeliveryApplicationService, CompanyApplicationService, EventApplicationService, EventAttachmentApplicationService, EventPhotoApplicationService, CateringApplicationService, SeatingApplicationService, ResourceApplicationService, ShiftApplicationService, ExhibitorApplicationService, LeadApplicationService, MatchmakingApplicationService, WebVitalsApplicationService, PastEventsApplicationService, AnnualReportApplicationService, EventConsentApplicationService, EventBroadcastApplicationService, MessagingApplicationService, EventBudgetApplicationService, EventCategoryApplicationService, EventSponsorApplicationService, ShortLinkApplicationService, EventPosterApplicationService, EventOverbookingApplicationService, TicketingApplicationService, InvoicingApplicationService, RefundApplicationService, EventCommentApplicationService,
+    EventArchiveApplicationService, EventDetailsApplicationService, EventOwnershipApplicationService, EventSubmissionApplicationService, EventRegistrationApplicationService, ModerationApplicationService,
+    HealthApplicationService, InvitationApplicationService, InvitationTracking, LiveEngagementApplicationService, NotificationDigestApplicationService, OrganizationInvitationApplicationService, PermissionApplicationService, ExportApplicationService, CrmSyncApplicationService, SchedulingApplicationService, EventFieldApplicationService, SendingDomainApplicationService, StorageQuotaApplicationService, UserApplicationService,
 };
+use aqio_database::QueryMetrics;
 use aqio_core::{
-    EventCategoryRepository, EventInvitationRepository, EventRegistrationRepository,
-    EventRepository, UserRepository,
+    AnalyticsRepository, AuditLogRepository, CompanyRegistry, PostalCodeDirectory, WeatherForecaster, TravelPlanner, CompanyRepository, DnsResolver, EmailSuppressionRepository, EventAttachmentRepository, EventPhotoRepository, EventBudgetRepository, EventCategoryRepository, EventCommentRepository, EventConsentRepository, EventFieldRepository, EventBroadcastRepository, MessageThreadRepository, EventInvitationRepository, EventOwnershipTransferRepository, EventSubmissionRepository, ContentReportRepository,
+    EventPollRepository, EventQuestionRepository, EventRegistrationRepository, EventRepository, EventSponsorRepository, ShortLinkRepository, OverbookingPolicyRepository, TicketRepository, ExchangeRateProvider, InvoiceRepository, TicketRefundRepository, PaymentProvider, SeatingRepository, ResourceRepository, ShiftRepository, ExhibitorRepository, LeadRepository, MatchmakingRepository, WebVitalRepository, EventSummaryRepository, MailPlatform, Mailer, MediaStorage, NotificationDigestRepository, OrganizationInvitationRepository, OrganizationRoleRepository, AccessGrantRepository, ExportJobRepository, ExportUploader, CrmConnectorRepository, CrmClient, SchedulingPolicyRepository, SendingDomainRepository, StorageQuotaRepository, UserRepository,
 };
 
 // Concrete AppState that works with Axum
 #[derive(Clone)]
 pub struct AppState {
     pub event_service: EventApplicationService,
+    pub event_details_service: EventDetailsApplicationService,
     pub user_service: UserApplicationService,
     pub event_category_service: EventCategoryApplicationService,
     pub invitation_service: InvitationApplicationService,
     pub registration_service: EventRegistrationApplicationService,
     pub health_service: HealthApplicationService,
+    pub audit_log_service: AuditLogApplicationService,
+    pub company_service: CompanyApplicationService,
+    pub comment_service: EventCommentApplicationService,
+    pub live_service: LiveEngagementApplicationService,
+    pub attachment_service: EventAttachmentApplicationService,
+    pub photo_service: EventPhotoApplicationService,
+    pub budget_service: EventBudgetApplicationService,
+    pub sponsor_service: EventSponsorApplicationService,
+    pub short_link_service: ShortLinkApplicationService,
+    pub poster_service: EventPosterApplicationService,
+    pub overbooking_service: EventOverbookingApplicationService,
+    pub ticketing_service: TicketingApplicationService,
+    pub invoicing_service: InvoicingApplicationService,
+    pub refund_service: RefundApplicationService,
+    pub catering_service: CateringApplicationService,
+    pub seating_service: SeatingApplicationService,
+    pub resource_service: ResourceApplicationService,
+    pub shift_service: ShiftApplicationService,
+    pub exhibitor_service: ExhibitorApplicationService,
+    pub lead_service: LeadApplicationService,
+    pub matchmaking_service: MatchmakingApplicationService,
+    pub web_vitals_service: WebVitalsApplicationService,
+    pub past_events_service: PastEventsApplicationService,
+    pub annual_report_service: AnnualReportApplicationService,
+    pub consent_service: EventConsentApplicationService,
+    pub broadcast_service: EventBroadcastApplicationService,
+    pub messaging_service: MessagingApplicationService,
+    pub storage_quota_service: StorageQuotaApplicationService,
+    pub scheduling_service: SchedulingApplicationService,
+    pub event_field_service: EventFieldApplicationService,
+    pub sending_domain_service: SendingDomainApplicationService,
+    pub email_delivery_service: EmailDeliveryApplicationService,
+    pub notification_digest_service: NotificationDigestApplicationService,
+    pub organization_invitation_service: OrganizationInvitationApplicationService,
+    pub permission_service: PermissionApplicationService,
+    pub export_service: ExportApplicationService,
+    pub crm_service: CrmSyncApplicationService,
+    pub ownership_service: EventOwnershipApplicationService,
+    pub submission_service: EventSubmissionApplicationService,
+    pub moderation_service: ModerationApplicationService,
+    pub archive_service: EventArchiveApplicationService,
+    pub certificate_service: CertificateApplicationService,
+    pub impersonation_tokens: ImpersonationTokens,
+    /// Timings of the instrumented repositories, for the admin query metrics
+    pub query_metrics: QueryMetrics,
+    /// Circuit breakers of the outbound adapters, for the health check
+    pub resilience: Resilience,
+    /// Served to the frontend at startup
+    pub client_config: Arc<ClientConfig>,
+}
+
+/// Everything `AppState` wires its services from: the repositories, the
+/// outbound adapters and the settings read at startup
+pub struct AppDependencies {
+    pub event_repository: Arc<dyn EventRepository>,
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_category_repository: Arc<dyn EventCategoryRepository>,
+    pub invitation_repository: Arc<dyn EventInvitationRepository>,
+    pub registration_repository: Arc<dyn EventRegistrationRepository>,
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+    pub company_repository: Arc<dyn CompanyRepository>,
+    pub comment_repository: Arc<dyn EventCommentRepository>,
+    pub question_repository: Arc<dyn EventQuestionRepository>,
+    pub poll_repository: Arc<dyn EventPollRepository>,
+    pub attachment_repository: Arc<dyn EventAttachmentRepository>,
+    pub photo_repository: Arc<dyn EventPhotoRepository>,
+    pub budget_repository: Arc<dyn EventBudgetRepository>,
+    pub sponsor_repository: Arc<dyn EventSponsorRepository>,
+    pub short_link_repository: Arc<dyn ShortLinkRepository>,
+    pub overbooking_policy_repository: Arc<dyn OverbookingPolicyRepository>,
+    pub ticket_repository: Arc<dyn TicketRepository>,
+    pub exchange_rates: Arc<dyn ExchangeRateProvider>,
+    pub invoice_repository: Arc<dyn InvoiceRepository>,
+    pub invoicing_config: Option<InvoicingConfig>,
+    pub refund_repository: Arc<dyn TicketRefundRepository>,
+    pub payments: Option<Arc<dyn PaymentProvider>>,
+    pub seating_repository: Arc<dyn SeatingRepository>,
+    pub resource_repository: Arc<dyn ResourceRepository>,
+    pub shift_repository: Arc<dyn ShiftRepository>,
+    pub exhibitor_repository: Arc<dyn ExhibitorRepository>,
+    pub lead_repository: Arc<dyn LeadRepository>,
+    pub matchmaking_repository: Arc<dyn MatchmakingRepository>,
+    pub web_vital_repository: Arc<dyn WebVitalRepository>,
+    pub scheduling_policy_repository: Arc<dyn SchedulingPolicyRepository>,
+    pub event_field_repository: Arc<dyn EventFieldRepository>,
+    pub sending_domain_repository: Arc<dyn SendingDomainRepository>,
+    pub dns_resolver: Arc<dyn DnsResolver>,
+    pub company_registry: Arc<dyn CompanyRegistry>,
+    pub postal_codes: Arc<dyn PostalCodeDirectory>,
+    pub weather: Option<Arc<dyn WeatherForecaster>>,
+    pub travel_planner: Arc<dyn TravelPlanner>,
+    pub mail_platform: MailPlatform,
+    pub email_suppression_repository: Arc<dyn EmailSuppressionRepository>,
+    pub notification_digest_repository: Arc<dyn NotificationDigestRepository>,
+    pub organization_invitation_repository: Arc<dyn OrganizationInvitationRepository>,
+    pub organization_role_repository: Arc<dyn OrganizationRoleRepository>,
+    pub access_grant_repository: Arc<dyn AccessGrantRepository>,
+    pub export_job_repository: Arc<dyn ExportJobRepository>,
+    pub export_uploader: Arc<dyn ExportUploader>,
+    pub crm_connector_repository: Arc<dyn CrmConnectorRepository>,
+    pub crm_client: Arc<dyn CrmClient>,
+    pub ownership_transfer_repository: Arc<dyn EventOwnershipTransferRepository>,
+    pub event_summary_repository: Arc<dyn EventSummaryRepository>,
+    pub analytics_repository: Arc<dyn AnalyticsRepository>,
+    pub submission_repository: Arc<dyn EventSubmissionRepository>,
+    pub submissions_required: bool,
+    pub content_report_repository: Arc<dyn ContentReportRepository>,
+    pub report_hide_threshold: i64,
+    pub consent_repository: Arc<dyn EventConsentRepository>,
+    pub broadcast_repository: Arc<dyn EventBroadcastRepository>,
+    pub message_thread_repository: Arc<dyn MessageThreadRepository>,
+    pub storage_quota_repository: Arc<dyn StorageQuotaRepository>,
+    pub storage_quota_bytes: Option<i64>,
+    pub mailer: Arc<dyn Mailer>,
+    pub media_storage: Arc<dyn MediaStorage>,
+    pub public_url: String,
+    pub tracking_secret: String,
+    pub email_webhook_secret: Option<String>,
+    pub import_anonymizer: Anonymizer,
+    pub impersonation_tokens: ImpersonationTokens,
+    pub query_metrics: QueryMetrics,
+    pub resilience: Resilience,
+    pub client_config: ClientConfig,
 }
 
 impl AppState {
-    pub fn new(
-        event_repository: Arc<dyn EventRepository>,
-        user_repository: Arc<dyn UserRepository>,
-        event_category_repository: Arc<dyn EventCategoryRepository>,
-        invitation_repository: Arc<dyn EventInvitationRepository>,
-        registration_repository: Arc<dyn EventRegistrationRepository>,
-    ) -> Self {
+    pub fn new(dependencies: AppDependencies) -> Self {
+        let AppDependencies {
+            event_repository,
+            user_repository,
+            event_category_repository,
+            invitation_repository,
+            registration_repository,
+            audit_log_repository,
+            company_repository,
+            comment_repository,
+            question_repository,
+            poll_repository,
+            attachment_repository,
+            photo_repository,
+            budget_repository,
+            sponsor_repository,
+            short_link_repository,
+            overbooking_policy_repository,
+            ticket_repository,
+            exchange_rates,
+            invoice_repository,
+            invoicing_config,
+            refund_repository,
+            payments,
+            seating_repository,
+            resource_repository,
+            shift_repository,
+            exhibitor_repository,
+            lead_repository,
+            matchmaking_repository,
+            web_vital_repository,
+            scheduling_policy_repository,
+            event_field_repository,
+            sending_domain_repository,
+            dns_resolver,
+            company_registry,
+            postal_codes,
+            weather,
+            travel_planner,
+            mail_platform,
+            email_suppression_repository,
+            notification_digest_repository,
+            organization_invitation_repository,
+            organization_role_repository,
+            access_grant_repository,
+            export_job_repository,
+            export_uploader,
+            crm_connector_repository,
+            crm_client,
+            ownership_transfer_repository,
+            event_summary_repository,
+            analytics_repository,
+            submission_repository,
+            submissions_required,
+            content_report_repository,
+            report_hide_threshold,
+            consent_repository,
+            broadcast_repository,
+            message_thread_repository,
+            storage_quota_repository,
+            storage_quota_bytes,
+            mailer,
+            media_storage,
+            public_url,
+            tracking_secret,
+            email_webhook_secret,
+            import_anonymizer,
+            impersonation_tokens,
+            query_metrics,
+            resilience,
+            client_config,
+        } = dependencies;
+        let scheduling_service = SchedulingApplicationService::new(
+            scheduling_policy_repository,
+            company_repository.clone(),
+            event_repository.clone(),
+            user_repository.clone(),
+        );
+        let storage_quota_service = StorageQuotaApplicationService::new(
+            storage_quota_repository,
+            company_repository.clone(),
+            user_repository.clone(),
+        )
+        .with_default_quota(storage_quota_bytes);
+        // Ownership and submission notifications go out on the registration stream
+        let registration_service = EventRegistrationApplicationService::new(registration_repository.clone())
+            .with_overbooking(overbooking_policy_repository.clone());
+        let mut event_service = EventApplicationService::new(event_repository.clone(), public_url.clone())
+            .with_scheduling(scheduling_service.clone())
+            .with_moderation(content_report_repository.clone())
+            .with_postal_codes(postal_codes.clone())
+            .with_travel_planner(travel_planner);
+        if let Some(weather) = weather {
+            event_service = event_service.with_weather(weather);
+        }
+        let ticketing_service =
+            TicketingApplicationService::new(ticket_repository.clone(), event_repository.clone(), exchange_rates)
+                .with_invoices(invoice_repository.clone());
+        let mut refund_service = RefundApplicationService::new(
+            refund_repository,
+            ticket_repository.clone(),
+            event_repository.clone(),
+            registration_repository.clone(),
+            ticketing_service.clone(),
+            registration_service.notifier().clone(),
+        );
+        if let Some(payments) = payments {
+            refund_service = refund_service.with_payments(payments);
+        }
         Self {
-            event_service: EventApplicationService::new(event_repository.clone()),
+            event_service,
+            scheduling_service,
+            event_field_service: EventFieldApplicationService::new(
+                event_field_repository,
+                company_repository.clone(),
+                event_repository.clone(),
+                user_repository.clone(),
+            ),
+            sending_domain_service: SendingDomainApplicationService::new(
+                sending_domain_repository,
+                company_repository.clone(),
+                dns_resolver,
+                mail_platform,
+            ),
+            event_details_service: EventDetailsApplicationService::new(
+                event_repository.clone(),
+                user_repository.clone(),
+                event_category_repository.clone(),
+                event_summary_repository.clone(),
+            ),
+            certificate_service: CertificateApplicationService::new(
+                registration_repository.clone(),
+                event_repository.clone(),
+                user_repository.clone(),
+                media_storage.clone(),
+                public_url.clone(),
+            ),
+            archive_service: EventArchiveApplicationService::new(
+                event_repository.clone(),
+                event_category_repository.clone(),
+                user_repository.clone(),
+                invitation_repository.clone(),
+                registration_repository.clone(),
+                sponsor_repository.clone(),
+                budget_repository.clone(),
+            )
+            .with_import_anonymizer(import_anonymizer),
+            budget_service: EventBudgetApplicationService::new(
+                budget_repository,
+                event_repository.clone(),
+                media_storage.clone(),
+            )
+            .with_storage_quota(storage_quota_service.clone()),
+            sponsor_service: EventSponsorApplicationService::new(sponsor_repository, event_repository.clone()),
+            short_link_service: ShortLinkApplicationService::new(
+                short_link_repository.clone(),
+                event_repository.clone(),
+                public_url.clone(),
+            )
+            .with_invitations(invitation_repository.clone()),
+            poster_service: EventPosterApplicationService::new(
+                event_repository.clone(),
+                event_category_repository.clone(),
+                public_url.clone(),
+            )
+            .with_short_links(short_link_repository),
+            overbooking_service: EventOverbookingApplicationService::new(
+                overbooking_policy_repository,
+                event_repository.clone(),
+                event_summary_repository.clone(),
+            ),
+            ticketing_service,
+            invoicing_service: InvoicingApplicationService::new(
+                invoice_repository,
+                ticket_repository,
+                event_repository.clone(),
+                invoicing_config,
+            ),
+            refund_service,
+            catering_service: CateringApplicationService::new(registration_repository.clone(), event_repository.clone()),
+            seating_service: SeatingApplicationService::new(
+                seating_repository,
+                event_repository.clone(),
+                registration_repository.clone(),
+            ),
+            resource_service: ResourceApplicationService::new(resource_repository.clone(), event_repository.clone()),
+            shift_service: ShiftApplicationService::new(shift_repository, event_repository.clone(), user_repository.clone()),
+            exhibitor_service: ExhibitorApplicationService::new(exhibitor_repository.clone(), event_repository.clone()),
+            lead_service: LeadApplicationService::new(
+                lead_repository,
+                exhibitor_repository,
+                event_repository.clone(),
+                registration_repository.clone(),
+                consent_repository.clone(),
+                user_repository.clone(),
+            ),
+            matchmaking_service: MatchmakingApplicationService::new(
+                matchmaking_repository,
+                resource_repository,
+                event_repository.clone(),
+                registration_repository.clone(),
+                user_repository.clone(),
+            ),
+            web_vitals_service: WebVitalsApplicationService::new(web_vital_repository),
+            past_events_service: PastEventsApplicationService::new(event_summary_repository),
+            annual_report_service: AnnualReportApplicationService::new(analytics_repository, company_repository.clone()),
+            consent_service: EventConsentApplicationService::new(
+                consent_repository,
+                event_repository.clone(),
+                registration_repository.clone(),
+                user_repository.clone(),
+            ),
+            broadcast_service: EventBroadcastApplicationService::new(
+                broadcast_repository.clone(),
+                event_repository.clone(),
+                registration_repository.clone(),
+                user_repository.clone(),
+                mailer.clone(),
+                registration_service.notifier().clone(),
+                public_url.clone(),
+            )
+            .with_suppressions(email_suppression_repository.clone()),
+            messaging_service: MessagingApplicationService::new(
+                message_thread_repository,
+                event_repository.clone(),
+                registration_repository.clone(),
+                broadcast_repository,
+                user_repository.clone(),
+                registration_service.notifier().clone(),
+            ),
+            notification_digest_service: NotificationDigestApplicationService::new(
+                notification_digest_repository,
+                user_repository.clone(),
+                event_repository.clone(),
+                mailer.clone(),
+                public_url.clone(),
+            )
+            .with_suppressions(email_suppression_repository.clone()),
+            organization_invitation_service: OrganizationInvitationApplicationService::new(
+                organization_invitation_repository,
+                user_repository.clone(),
+                company_repository.clone(),
+                mailer.clone(),
+                public_url.clone(),
+            )
+            .with_suppressions(email_suppression_repository.clone()),
+            permission_service: PermissionApplicationService::new(
+                organization_role_repository,
+                access_grant_repository,
+                user_repository.clone(),
+                company_repository.clone(),
+                event_repository.clone(),
+            ),
+            export_service: ExportApplicationService::new(
+                export_job_repository,
+                event_repository.clone(),
+                registration_repository.clone(),
+                user_repository.clone(),
+                export_uploader,
+            ),
+            crm_service: CrmSyncApplicationService::new(
+                crm_connector_repository,
+                event_repository.clone(),
+                registration_repository.clone(),
+                user_repository.clone(),
+                crm_client,
+            ),
+            submission_service: EventSubmissionApplicationService::new(
+                submission_repository,
+                event_repository.clone(),
+                user_repository.clone(),
+                registration_service.notifier().clone(),
+            )
+            .required_for_participants(submissions_required),
+            ownership_service: EventOwnershipApplicationService::new(
+                ownership_transfer_repository,
+                event_repository.clone(),
+                user_repository.clone(),
+                mailer.clone(),
+                registration_service.notifier().clone(),
+                public_url.clone(),
+            )
+            .with_suppressions(email_suppression_repository.clone()),
+            moderation_service: ModerationApplicationService::new(
+                content_report_repository.clone(),
+                event_repository.clone(),
+                comment_repository.clone(),
+                user_repository.clone(),
+                mailer,
+            )
+            .with_hide_threshold(report_hide_threshold),
             user_service: UserApplicationService::new(user_repository),
             event_category_service: EventCategoryApplicationService::new(event_category_repository),
-            invitation_service: InvitationApplicationService::new(invitation_repository),
-            registration_service: EventRegistrationApplicationService::new(registration_repository),
+            email_delivery_service: EmailDeliveryApplicationService::new(
+                email_suppression_repository.clone(),
+                invitation_repository.clone(),
+                email_webhook_secret,
+            ),
+            invitation_service: InvitationApplicationService::new(
+                invitation_repository,
+                InvitationTracking::new(public_url, tracking_secret),
+            )
+            .with_suppressions(email_suppression_repository),
+            registration_service,
+            company_service: CompanyApplicationService::new(company_repository, event_repository.clone())
+                .with_registry(company_registry)
+                .with_postal_codes(postal_codes),
+            comment_service: EventCommentApplicationService::new(comment_repository, event_repository.clone())
+                .with_moderation(content_report_repository),
+            live_service: LiveEngagementApplicationService::new(
+                question_repository,
+                poll_repository,
+                event_repository.clone(),
+            ),
+            attachment_service: EventAttachmentApplicationService::new(
+                attachment_repository,
+                event_repository.clone(),
+                registration_repository.clone(),
+                media_storage.clone(),
+            )
+            .with_storage_quota(storage_quota_service.clone()),
+            photo_service: EventPhotoApplicationService::new(
+                photo_repository,
+                event_repository.clone(),
+                registration_repository.clone(),
+                media_storage,
+            )
+            .with_storage_quota(storage_quota_service.clone()),
+            storage_quota_service,
             health_service: HealthApplicationService::new(event_repository),
+            audit_log_service: AuditLogApplicationService::new(audit_log_repository),
+            impersonation_tokens,
+            query_metrics,
+            resilience,
+            client_config: Arc::new(client_config),
         }
     }
 }
@@ -49,6 +500,12 @@ impl axum::extract::FromRef<AppState> for EventApplicationService {
     }
 }
 
+impl axum::extract::FromRef<AppState> for EventDetailsApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.event_details_service.clone()
+    }
+}
+
 impl axum::extract::FromRef<AppState> for UserApplicationService {
     fn from_ref(app_state: &AppState) -> Self {
         app_state.user_service.clone()
@@ -78,3 +535,255 @@ impl axum::extract::FromRef<AppState> for HealthApplicationService {
         app_state.health_service.clone()
     }
 }
+
+impl axum::extract::FromRef<AppState> for AuditLogApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.audit_log_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for CompanyApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.company_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventCommentApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.comment_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for LiveEngagementApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.live_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventAttachmentApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.attachment_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventPhotoApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.photo_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventBudgetApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.budget_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventSponsorApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.sponsor_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for ShortLinkApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.short_link_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventPosterApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.poster_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventOverbookingApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.overbooking_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for TicketingApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.ticketing_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for InvoicingApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.invoicing_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for RefundApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.refund_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for CateringApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.catering_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for SeatingApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.seating_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for ResourceApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.resource_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for ShiftApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.shift_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for ExhibitorApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.exhibitor_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for LeadApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.lead_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for MatchmakingApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.matchmaking_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for WebVitalsApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.web_vitals_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for PastEventsApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.past_events_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for AnnualReportApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.annual_report_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for CertificateApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.certificate_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for SchedulingApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.scheduling_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventFieldApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.event_field_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for SendingDomainApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.sending_domain_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EmailDeliveryApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.email_delivery_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for NotificationDigestApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.notification_digest_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventOwnershipApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.ownership_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventSubmissionApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.submission_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for ModerationApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.moderation_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventConsentApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.consent_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventBroadcastApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.broadcast_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for MessagingApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.messaging_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for StorageQuotaApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.storage_quota_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for EventArchiveApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.archive_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for OrganizationInvitationApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.organization_invitation_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for PermissionApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.permission_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for ExportApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.export_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for CrmSyncApplicationService {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.crm_service.clone()
+    }
+}
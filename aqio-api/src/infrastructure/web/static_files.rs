@@ -0,0 +1,158 @@
+// Static asset serving for single-binary deployments
+// Serves the built Dioxus bundle and falls back to index.html for client-side routes
+
+use std::path::Path;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{HeaderValue, Request, StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+};
+use tower_http::{
+    compression::CompressionLayer,
+    services::{ServeDir, ServeFile},
+};
+
+// Dioxus fingerprints everything under /assets, so those files never change in place
+const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+// index.html and unhashed files (e.g. the wasm bundle) must be revalidated on each load
+const REVALIDATE_CACHE_CONTROL: &str = "no-cache";
+
+// Paths owned by the API; these must 404 instead of returning the SPA shell
+const API_PREFIXES: [&str; 3] = ["/api/", "/api-docs/", "/auth/"];
+
+/// Build a router serving `dir` with an `index.html` fallback for unknown paths
+///
+/// Unknown paths under `/assets/` are a 404 rather than the SPA shell, so a stale
+/// hashed URL is never cached as HTML. Meant to be installed as the application's
+/// fallback so API routes keep priority.
+pub fn spa_router<S>(dir: impl AsRef<Path>) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let dir = dir.as_ref();
+    let assets = ServeDir::new(dir.join("assets"))
+        .precompressed_br()
+        .precompressed_gzip();
+    let serve_dir = ServeDir::new(dir)
+        .precompressed_br()
+        .precompressed_gzip()
+        .fallback(ServeFile::new(dir.join("index.html")));
+
+    Router::new()
+        .nest_service("/assets", assets)
+        .fallback_service(serve_dir)
+        .layer(middleware::from_fn(static_cache_control))
+        .layer(middleware::from_fn(reject_api_paths))
+        .layer(CompressionLayer::new())
+}
+
+async fn reject_api_paths(request: Request<Body>, next: Next) -> Response {
+    let path = request.uri().path();
+    if API_PREFIXES.iter().any(|prefix| path.starts_with(prefix)) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    next.run(request).await
+}
+
+// Only real files are served with a success status under /assets, so the
+// immutable header never lands on the index.html fallback
+async fn static_cache_control(request: Request<Body>, next: Next) -> Response {
+    let immutable = request.uri().path().starts_with("/assets/");
+    let mut response = next.run(request).await;
+
+    if response.status().is_success() {
+        let value = if immutable {
+            IMMUTABLE_CACHE_CONTROL
+        } else {
+            REVALIDATE_CACHE_CONTROL
+        };
+        response
+            .headers_mut()
+            .insert(header::CACHE_CONTROL, HeaderValue::from_static(value));
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use tempfile::TempDir;
+    use tower::ServiceExt;
+
+    fn bundle() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("index.html"), "<html>aqio</html>").unwrap();
+        std::fs::create_dir(dir.path().join("assets")).unwrap();
+        std::fs::write(dir.path().join("assets/main-dxh3f2a.css"), "body {}").unwrap();
+        std::fs::create_dir(dir.path().join("wasm")).unwrap();
+        std::fs::write(dir.path().join("wasm/aqio-frontend_bg.wasm"), [0u8, 97, 115, 109]).unwrap();
+        dir
+    }
+
+    async fn get(dir: &TempDir, uri: &str) -> Response {
+        spa_router::<()>(dir.path())
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn body_text(response: Response) -> String {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_serves_index_for_client_routes() {
+        let dir = bundle();
+
+        for uri in ["/", "/events/123", "/calendar"] {
+            let response = get(&dir, uri).await;
+            assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+            assert_eq!(response.headers()[header::CACHE_CONTROL], REVALIDATE_CACHE_CONTROL);
+            assert_eq!(body_text(response).await, "<html>aqio</html>");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hashed_assets_are_immutable() {
+        let dir = bundle();
+        let response = get(&dir, "/assets/main-dxh3f2a.css").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/css");
+        assert_eq!(response.headers()[header::CACHE_CONTROL], IMMUTABLE_CACHE_CONTROL);
+    }
+
+    #[tokio::test]
+    async fn test_missing_assets_are_not_found() {
+        let dir = bundle();
+        let response = get(&dir, "/assets/main-0ld4a5h.css").await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(!response.headers().contains_key(header::CACHE_CONTROL));
+        assert_ne!(body_text(response).await, "<html>aqio</html>");
+    }
+
+    #[tokio::test]
+    async fn test_wasm_mime_type() {
+        let dir = bundle();
+        let response = get(&dir, "/wasm/aqio-frontend_bg.wasm").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/wasm");
+        assert_eq!(response.headers()[header::CACHE_CONTROL], REVALIDATE_CACHE_CONTROL);
+    }
+
+    #[tokio::test]
+    async fn test_api_paths_are_not_rewritten() {
+        let dir = bundle();
+        let response = get(&dir, "/api/v1/unknown").await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
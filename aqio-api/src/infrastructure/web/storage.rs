@@ -0,0 +1,7 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{handlers::storage, state::AppState};
+
+pub fn storage_routes() -> Router<AppState> {
+    Router::new().route("/{id}/storage", get(storage::get_storage_usage))
+}
@@ -0,0 +1,12 @@
+use axum::{routing::post, Router};
+
+use crate::infrastructure::web::{
+    handlers::telemetry,
+    state::AppState,
+};
+
+pub fn telemetry_routes() -> Router<AppState> {
+    Router::new()
+        .route("/web-vitals", post(telemetry::report_web_vitals))
+        .route("/logs", post(telemetry::report_client_logs))
+}
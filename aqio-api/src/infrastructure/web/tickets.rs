@@ -0,0 +1,28 @@
+use axum::{routing::{get, post, put}, Router};
+
+use crate::infrastructure::web::{
+    handlers::tickets,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn ticket_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/ticket-types", get(tickets::list_ticket_types).post(tickets::create_ticket_type))
+        .route(
+            "/{id}/ticket-types/{ticket_type_id}",
+            put(tickets::update_ticket_type).delete(tickets::delete_ticket_type),
+        )
+        .route(
+            "/{id}/ticket-orders",
+            get(tickets::list_event_ticket_orders).post(tickets::purchase_tickets),
+        )
+        .route("/{id}/ticket-revenue", get(tickets::get_ticket_revenue))
+}
+
+// Nested under `/api/v1/ticket-orders`; the current user's own orders
+pub fn ticket_order_routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(tickets::list_my_ticket_orders))
+        .route("/{order_id}/cancel", post(tickets::cancel_ticket_order))
+}
@@ -0,0 +1,13 @@
+use axum::{routing::get, Router};
+
+use crate::infrastructure::web::{
+    handlers::travel,
+    state::AppState,
+};
+
+// Merged into the event routes, so paths are relative to `/events`
+pub fn travel_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/travel", get(travel::get_travel_info).put(travel::update_travel_info))
+        .route("/{id}/travel-options", get(travel::get_travel_options))
+}
@@ -4,7 +4,7 @@ use axum::{
 };
 
 use crate::infrastructure::web::{
-    handlers::users,
+    handlers::{notification_preferences, users},
     state::AppState,
 };
 
@@ -14,6 +14,13 @@ pub fn user_routes() -> Router<AppState> {
         .route("/", post(users::create_user))
         .route("/", get(users::list_users))
         .route("/me", get(users::get_current_user))
+        .route("/me/permissions", get(users::get_current_user_permissions))
+        .route("/search", get(users::search_users))
+        .route(
+            "/me/notification-preferences",
+            get(notification_preferences::get_notification_preferences)
+                .put(notification_preferences::update_notification_preferences),
+        )
         .route("/{id}", get(users::get_user))
         .route("/{id}", put(users::update_user))
         .route("/{id}", delete(users::delete_user))
@@ -0,0 +1,113 @@
+// Event webhooks over HTTP
+// Each configured URL receives a JSON POST; a failing endpoint doesn't stop
+// the remaining ones from being called. Endpoints are retried and have a
+// circuit breaker per host.
+
+use std::time::Duration;
+
+use aqio_core::{DomainError, DomainResult, Event, EventWebhooks};
+use async_trait::async_trait;
+use serde::Serialize;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::infrastructure::resilience::{CallError, Resilience, RetryPolicy, destination_host};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Webhooks are called one after the other from the publish scheduler, so a
+// slow endpoint gets less time than the default budget
+fn webhook_retry() -> RetryPolicy {
+    RetryPolicy {
+        budget: Duration::from_secs(20),
+        ..RetryPolicy::default()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct EventPublishedPayload<'a> {
+    event: &'static str,
+    event_id: Uuid,
+    title: &'a str,
+    slug: &'a str,
+    start_date: chrono::DateTime<chrono::Utc>,
+    url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpEventWebhooks {
+    client: reqwest::Client,
+    urls: Vec<String>,
+    public_url: String,
+    resilience: Resilience,
+}
+
+impl HttpEventWebhooks {
+    /// `urls` is the comma-separated `PUBLISH_WEBHOOK_URLS` setting
+    pub fn new(urls: &str, public_url: String) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build()?;
+        let urls = urls
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(str::to_string)
+            .collect();
+        Ok(Self {
+            client,
+            urls,
+            public_url,
+            resilience: Resilience::default().with_retry(webhook_retry()),
+        })
+    }
+
+    /// Shares breaker state with the other outbound adapters
+    pub fn with_resilience(mut self, resilience: Resilience) -> Self {
+        self.resilience = resilience.with_retry(webhook_retry());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.urls.is_empty()
+    }
+
+    async fn post(&self, url: &str, payload: &EventPublishedPayload<'_>) -> Result<(), String> {
+        self.resilience
+            .call(&format!("webhook:{}", destination_host(url)), || async {
+                let response = self.client.post(url).json(payload).send().await.map_err(CallError::from_reqwest)?;
+                if !response.status().is_success() {
+                    return Err(CallError::from_status(response.status()));
+                }
+                Ok(())
+            })
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[async_trait]
+impl EventWebhooks for HttpEventWebhooks {
+    async fn event_published(&self, event: &Event) -> DomainResult<()> {
+        let payload = EventPublishedPayload {
+            event: "event.published",
+            event_id: event.id,
+            title: &event.title,
+            slug: &event.slug,
+            start_date: event.start_date,
+            url: format!("{}/e/{}", self.public_url, event.slug),
+        };
+
+        let mut failures = Vec::new();
+        for url in &self.urls {
+            if let Err(e) = self.post(url, &payload).await {
+                failures.push(format!("{}: {}", url, e));
+            }
+        }
+
+        debug!("Sent event.published for {} to {} webhooks", event.id, self.urls.len() - failures.len());
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(DomainError::system_unavailable(&format!("Webhooks failed ({})", failures.join("; "))))
+        }
+    }
+}
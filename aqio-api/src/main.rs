@@ -1,59 +1,441 @@
 mod auth;
+mod config;
 mod domain;
+mod healthcheck;
 mod infrastructure;
 
 #[cfg(test)]
 mod testing;
 
 use aqio_database::{
-    Database,
+    Database, FaultInjected, FaultInjector, Instrumented, QueryMetrics,
     infrastructure::persistence::sqlite::{
-        SqliteEventCategoryRepository, SqliteEventRepository, SqliteEventRegistrationRepository,
-        SqliteInvitationRepository, SqliteUserRepository,
+        SqliteAuditLogRepository, SqliteCompanyRepository, SqliteEventAttachmentRepository, SqliteEventPhotoRepository, SqliteEventBudgetRepository, SqliteEventSponsorRepository, SqliteShortLinkRepository, SqliteOverbookingPolicyRepository, SqliteTicketRepository, SqliteInvoiceRepository, SqliteTicketRefundRepository, SqliteSeatingRepository, SqliteResourceRepository, SqliteShiftRepository, SqliteExhibitorRepository, SqliteLeadRepository, SqliteMatchmakingRepository, SqliteWebVitalRepository, SqliteSchedulingPolicyRepository, SqliteEventFieldRepository, SqliteSendingDomainRepository, SqliteEmailSuppressionRepository, SqliteNotificationDigestRepository, SqliteOrganizationInvitationRepository, SqliteOrganizationRoleRepository, SqliteAccessGrantRepository, SqliteExportJobRepository, SqliteCrmConnectorRepository, SqliteEventOwnershipTransferRepository, SqliteEventSummaryRepository, SqliteAnalyticsRepository, SqliteEventSubmissionRepository, SqliteContentReportRepository, SqliteEventConsentRepository, SqliteStorageQuotaRepository, SqliteEventBroadcastRepository, SqliteMessageThreadRepository, SqliteEventCategoryRepository, SqliteEventCommentRepository,
+        SqliteEventPollRepository, SqliteEventQuestionRepository,
+        SqliteEventRepository, SqliteEventRegistrationRepository, SqliteInvitationRepository, SqliteUserRepository,
     },
 };
 use auth::KeycloakConfig;
+use auth::impersonation::ImpersonationTokens;
 use auth::mock::{MockAuthConfig, mock_login, mock_logout};
 use axum::{
     Router,
     routing::{get, post},
 };
-use infrastructure::web::{AppState, add_auth_middleware, create_routes};
+use aqio_core::{MailPlatform, Mailer, PaymentProvider, WeatherForecaster};
+use config::{AuthMode, ClientConfig, CrmConfig, ExportConfig, InvoicingConfig, ServerConfig, VippsConfig};
+use domain::anonymize::Anonymizer;
+use domain::services::{ModerationApplicationService, PublishingApplicationService, StorageQuotaApplicationService};
+use infrastructure::brreg::{BrregCompanyRegistry, CachedCompanyRegistry, DEFAULT_BRREG_URL};
+use infrastructure::postal_codes::{PostalCodeRegister, DEFAULT_POSTAL_CODE_REGISTER_URL};
+use infrastructure::entur::{EnturTravelPlanner, DEFAULT_ENTUR_API_URL, DEFAULT_ENTUR_CLIENT_NAME};
+use infrastructure::met_weather::{CachedWeatherForecaster, MetWeatherForecaster, DEFAULT_MET_API_URL};
+use infrastructure::norges_bank::{CachedExchangeRates, NorgesBankExchangeRates, DEFAULT_NORGES_BANK_URL};
+use infrastructure::dns::{DEFAULT_DOH_URL, DohDnsResolver};
+use infrastructure::crm::HttpCrmClient;
+use infrastructure::exports::ExportDelivery;
+use infrastructure::mailer::{LogMailer, SendGridMailer};
+use infrastructure::media::LocalMediaStorage;
+use infrastructure::resilience::Resilience;
+use infrastructure::scheduler::{
+    spawn_broadcast_scheduler, spawn_digest_collector, spawn_digest_scheduler, spawn_crm_sync_scheduler, spawn_export_scheduler, spawn_publish_scheduler, spawn_refund_scheduler,
+    spawn_web_vitals_pruner,
+};
+use infrastructure::vipps::VippsPayments;
+use infrastructure::webhooks::HttpEventWebhooks;
+use infrastructure::web::{AppDependencies, AppState, add_auth_middleware, create_routes, public_routes, spa_router};
+use infrastructure::web::middleware::trace_requests;
 use std::env;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    let server_config = ServerConfig::from_env()?;
+
+    // `aqio-api healthcheck` probes a running instance and exits (Docker HEALTHCHECK)
+    if env::args().nth(1).as_deref() == Some("healthcheck") {
+        let healthy = healthcheck::run(&server_config).await;
+        std::process::exit(if healthy { 0 } else { 1 });
+    }
+
     tracing_subscriber::fmt::init();
 
     let database_url = env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite:aqio.db".to_string());
+    let media_dir = env::var("MEDIA_DIR").unwrap_or_else(|_| "media".to_string());
+    // Where the frontend is reachable; used for links on certificates and in invitation emails
+    let public_url = env::var("PUBLIC_URL").unwrap_or_else(|_| "http://127.0.0.1:8080".to_string());
+    // Signs the tracked links in invitation emails; without it links only work
+    // until the next restart
+    let tracking_secret = env::var("TRACKING_SECRET").unwrap_or_else(|_| {
+        tracing::warn!("TRACKING_SECRET is not set; tracked invitation links will break on restart");
+        format!("{}{}", uuid::Uuid::new_v4(), uuid::Uuid::new_v4())
+    });
+    // Signs the tokens administrators get to act as another user for support;
+    // without it those tokens stop working on restart
+    let impersonation_secret = env::var("IMPERSONATION_SECRET").unwrap_or_else(|_| {
+        tracing::warn!("IMPERSONATION_SECRET is not set; impersonation tokens will break on restart");
+        format!("{}{}", uuid::Uuid::new_v4(), uuid::Uuid::new_v4())
+    });
+    let impersonation_tokens = ImpersonationTokens::new(impersonation_secret);
+    // Mail platform records organizations point their sending domains at
+    let mail_platform = MailPlatform {
+        spf_include: env::var("MAIL_SPF_INCLUDE").unwrap_or_else(|_| "spf.aqio.no".to_string()),
+        dkim_host: env::var("MAIL_DKIM_HOST").unwrap_or_else(|_| "dkim.aqio.no".to_string()),
+    };
+    // DNS-over-HTTPS endpoint (JSON API) used to verify sending domains
+    let dns_over_https_url = env::var("DNS_OVER_HTTPS_URL").unwrap_or_else(|_| DEFAULT_DOH_URL.to_string());
+    // Business register API that new companies' organization numbers are
+    // checked against and filled in from
+    let brreg_api_url = env::var("BRREG_API_URL").unwrap_or_else(|_| DEFAULT_BRREG_URL.to_string());
+    // Posten's postal code register (a file path or URL) that event and
+    // company addresses get their municipality and county from
+    let postal_code_register =
+        env::var("POSTAL_CODE_REGISTER").unwrap_or_else(|_| DEFAULT_POSTAL_CODE_REGISTER_URL.to_string());
+    // Weather forecasts on event pages come from MET Norway, whose terms ask
+    // for a User-Agent naming the application and a contact, e.g.
+    // `aqio.no support@aqio.no`; without one forecasts are off
+    let met_user_agent = env::var("MET_USER_AGENT").ok().filter(|agent| !agent.trim().is_empty());
+    let met_api_url = env::var("MET_API_URL").unwrap_or_else(|_| DEFAULT_MET_API_URL.to_string());
+    // Entur's journey planner suggests public transport and ferries to venues;
+    // Entur asks for a client name of the form `company-application`
+    let entur_api_url = env::var("ENTUR_API_URL").unwrap_or_else(|_| DEFAULT_ENTUR_API_URL.to_string());
+    let entur_client_name = env::var("ENTUR_CLIENT_NAME").unwrap_or_else(|_| DEFAULT_ENTUR_CLIENT_NAME.to_string());
+    // Tickets priced in EUR or USD are converted to NOK at Norges Bank's
+    // reference rate of the day they are bought
+    let norges_bank_api_url = env::var("NORGES_BANK_API_URL").unwrap_or_else(|_| DEFAULT_NORGES_BANK_URL.to_string());
+    // The seller on invoices for ticket orders; organizations can't ask for
+    // invoices until it is set
+    let invoicing_config = InvoicingConfig::from_env()?;
+    if invoicing_config.is_none() {
+        tracing::info!("INVOICE_SELLER_NAME and INVOICE_SELLER_ORG_NUMBER are not set; ticket orders can't be invoiced");
+    }
+    // Paid ticket orders are refunded through Vipps MobilePay when an event
+    // is cancelled; without it, events with paid orders can't be cancelled
+    let vipps_config = VippsConfig::from_env()?;
+    if vipps_config.is_none() {
+        tracing::info!("VIPPS_CLIENT_ID is not set; events with paid ticket orders can't be cancelled");
+    }
+    // Credentials for scheduled exports stay with the server; export jobs
+    // only name the bucket or host they deliver to
+    let export_config = ExportConfig::from_env()?;
+    // Likewise the CRM credentials; connectors only name the provider and,
+    // for Dynamics, the organization URL
+    let crm_config = CrmConfig::from_env()?;
+    // Token the mail provider's bounce and complaint webhooks must carry; the
+    // webhooks are off without it
+    let email_webhook_secret = env::var("EMAIL_WEBHOOK_SECRET").ok();
     let use_mock_auth = env::var("MOCK_AUTH").unwrap_or_else(|_| "true".to_string()) == "true";
+    let keycloak_realm_url = env::var("KEYCLOAK_REALM_URL")
+        .unwrap_or_else(|_| "http://localhost:8080/realms/aqio".to_string());
+    // Comma-separated URLs called with a JSON payload when a scheduled event is published
+    let publish_webhook_urls = env::var("PUBLISH_WEBHOOK_URLS").unwrap_or_default();
+    let publish_check_seconds: u64 = match env::var("PUBLISH_CHECK_SECONDS") {
+        Ok(seconds) => seconds
+            .parse()
+            .ok()
+            .filter(|seconds| *seconds > 0)
+            .ok_or_else(|| anyhow::anyhow!("PUBLISH_CHECK_SECONDS must be a positive number, got '{}'", seconds))?,
+        Err(_) => 60,
+    };
+    // Outgoing email goes through SendGrid; without an API key it is only logged
+    let sendgrid_api_key = env::var("SENDGRID_API_KEY").ok().filter(|key| !key.is_empty());
+    let mail_from = env::var("MAIL_FROM").unwrap_or_else(|_| "noreply@aqio.no".to_string());
+    let digest_check_seconds: u64 = match env::var("DIGEST_CHECK_SECONDS") {
+        Ok(seconds) => seconds
+            .parse()
+            .ok()
+            .filter(|seconds| *seconds > 0)
+            .ok_or_else(|| anyhow::anyhow!("DIGEST_CHECK_SECONDS must be a positive number, got '{}'", seconds))?,
+        Err(_) => 900,
+    };
+
+    // Participants propose events through the moderation queue instead of
+    // creating them directly
+    let submissions_required = env::var("REVIEW_PARTICIPANT_EVENTS").unwrap_or_else(|_| "false".to_string()) == "true";
+
+    // Distinct users whose reports hide a comment or event until a moderator
+    // looks at it; 0 leaves everything visible
+    let report_hide_threshold = match env::var("REPORT_HIDE_THRESHOLD") {
+        Ok(threshold) => threshold
+            .parse()
+            .ok()
+            .filter(|threshold: &i64| *threshold >= 0)
+            .ok_or_else(|| anyhow::anyhow!("REPORT_HIDE_THRESHOLD must be zero or a positive number, got '{}'", threshold))?,
+        Err(_) => ModerationApplicationService::DEFAULT_HIDE_THRESHOLD,
+    };
+
+    // Attachments, receipts and photos an organization may store, in megabytes, unless
+    // an administrator gave it its own quota; 0 means unlimited
+    let storage_quota_bytes = match env::var("ORGANIZATION_STORAGE_QUOTA_MB") {
+        Ok(megabytes) => megabytes
+            .parse()
+            .ok()
+            .filter(|megabytes: &i64| *megabytes >= 0)
+            .ok_or_else(|| anyhow::anyhow!("ORGANIZATION_STORAGE_QUOTA_MB must be zero or a positive number, got '{}'", megabytes))?
+            .checked_mul(1024 * 1024)
+            .filter(|bytes| *bytes > 0),
+        Err(_) => Some(StorageQuotaApplicationService::DEFAULT_QUOTA_BYTES),
+    };
+
+    // Auth mode, branding and feature flags served to the frontend at startup
+    let client_auth = if use_mock_auth {
+        AuthMode::Mock
+    } else {
+        AuthMode::Keycloak {
+            realm_url: keycloak_realm_url.clone(),
+            // The public client the frontend signs in with, not the API's own
+            client_id: env::var("KEYCLOAK_FRONTEND_CLIENT_ID").unwrap_or_else(|_| "aqio-frontend".to_string()),
+        }
+    };
+    let client_config = ClientConfig::from_env(
+        client_auth,
+        &[
+            ("event_submissions", submissions_required),
+            ("email_delivery", sendgrid_api_key.is_some()),
+        ],
+    )?;
+
+    // Personal data scrubbed from every imported event archive, e.g. `all` on
+    // dev and staging servers that are fed production exports
+    let import_anonymizer = match env::var("IMPORT_ANONYMIZE") {
+        Ok(fields) => Anonymizer::parse(&fields).map_err(|e| anyhow::anyhow!("IMPORT_ANONYMIZE: {}", e))?,
+        Err(_) => Anonymizer::default(),
+    };
+
+    // Repository calls slower than this are logged with their (redacted)
+    // parameters and counted in the admin query metrics
+    let slow_query_ms: u64 = match env::var("SLOW_QUERY_MS") {
+        Ok(ms) => ms
+            .parse()
+            .map_err(|_| anyhow::anyhow!("SLOW_QUERY_MS must be a number of milliseconds, got '{}'", ms))?,
+        Err(_) => 250,
+    };
+    let query_metrics = QueryMetrics::new(Duration::from_millis(slow_query_ms));
+
+    // Latency, dropped connections and constraint violations injected into the
+    // busiest repositories and every outbound call, so error handling and
+    // retries can be exercised on dev and staging servers; off unless set
+    let fault_plan = config::fault_plan_from_env()?;
+    if let Some(plan) = &fault_plan {
+        tracing::warn!(
+            "Injecting faults: up to {:?} latency, {} of calls failing, {} of writes conflicting, targets: {}",
+            plan.max_latency,
+            plan.failure_rate,
+            plan.conflict_rate,
+            if plan.targets.is_empty() { "all".to_string() } else { plan.targets.join(", ") }
+        );
+    }
+    let faults = FaultInjector::new(fault_plan.unwrap_or_default());
+
+    // Retries and circuit breakers for email, webhooks and DNS lookups; one
+    // instance so the health check sees every destination
+    let resilience = Resilience::default().with_faults(faults.clone());
 
     let db = Database::new(&database_url).await?;
 
     // Create repository implementations
-    let event_repository = Arc::new(SqliteEventRepository::new(db.pool().clone()));
-    let user_repository = Arc::new(SqliteUserRepository::new(db.pool().clone()));
+    let event_repository = Arc::new(Instrumented::new(FaultInjected::new(SqliteEventRepository::new(db.pool().clone()), "events", faults.clone()), "events", query_metrics.clone()));
+    let user_repository = Arc::new(Instrumented::new(FaultInjected::new(SqliteUserRepository::new(db.pool().clone()), "users", faults.clone()), "users", query_metrics.clone()));
     let event_category_repository = Arc::new(SqliteEventCategoryRepository::new(db.pool().clone()));
-    let invitation_repository = Arc::new(SqliteInvitationRepository::new(db.pool().clone()));
-    let registration_repository = Arc::new(SqliteEventRegistrationRepository::new(db.pool().clone()));
+    let invitation_repository = Arc::new(Instrumented::new(FaultInjected::new(SqliteInvitationRepository::new(db.pool().clone()), "invitations", faults.clone()), "invitations", query_metrics.clone()));
+    let registration_repository = Arc::new(Instrumented::new(FaultInjected::new(SqliteEventRegistrationRepository::new(db.pool().clone()), "registrations", faults.clone()), "registrations", query_metrics.clone()));
+    let audit_log_repository = Arc::new(SqliteAuditLogRepository::new(db.pool().clone()));
+    let company_repository = Arc::new(SqliteCompanyRepository::new(db.pool().clone()));
+    let comment_repository = Arc::new(SqliteEventCommentRepository::new(db.pool().clone()));
+    let question_repository = Arc::new(SqliteEventQuestionRepository::new(db.pool().clone()));
+    let poll_repository = Arc::new(SqliteEventPollRepository::new(db.pool().clone()));
+    let attachment_repository = Arc::new(SqliteEventAttachmentRepository::new(db.pool().clone()));
+    let photo_repository = Arc::new(SqliteEventPhotoRepository::new(db.pool().clone()));
+    let budget_repository = Arc::new(SqliteEventBudgetRepository::new(db.pool().clone()));
+    let sponsor_repository = Arc::new(SqliteEventSponsorRepository::new(db.pool().clone()));
+    let short_link_repository = Arc::new(SqliteShortLinkRepository::new(db.pool().clone()));
+    let overbooking_policy_repository = Arc::new(SqliteOverbookingPolicyRepository::new(db.pool().clone()));
+    let ticket_repository = Arc::new(SqliteTicketRepository::new(db.pool().clone()));
+    let invoice_repository = Arc::new(SqliteInvoiceRepository::new(db.pool().clone()));
+    let refund_repository = Arc::new(SqliteTicketRefundRepository::new(db.pool().clone()));
+    let seating_repository = Arc::new(SqliteSeatingRepository::new(db.pool().clone()));
+    let resource_repository = Arc::new(SqliteResourceRepository::new(db.pool().clone()));
+    let shift_repository = Arc::new(SqliteShiftRepository::new(db.pool().clone()));
+    let exhibitor_repository = Arc::new(SqliteExhibitorRepository::new(db.pool().clone()));
+    let lead_repository = Arc::new(SqliteLeadRepository::new(db.pool().clone()));
+    let matchmaking_repository = Arc::new(SqliteMatchmakingRepository::new(db.pool().clone()));
+    let web_vital_repository = Arc::new(SqliteWebVitalRepository::new(db.pool().clone()));
+    let scheduling_policy_repository = Arc::new(SqliteSchedulingPolicyRepository::new(db.pool().clone()));
+    let event_field_repository = Arc::new(SqliteEventFieldRepository::new(db.pool().clone()));
+    let sending_domain_repository = Arc::new(SqliteSendingDomainRepository::new(db.pool().clone()));
+    let dns_resolver = Arc::new(DohDnsResolver::new(dns_over_https_url)?.with_resilience(resilience.clone()));
+    let company_registry = Arc::new(CachedCompanyRegistry::new(
+        BrregCompanyRegistry::new(brreg_api_url)?.with_resilience(resilience.clone()),
+    ));
+    let postal_codes = Arc::new(PostalCodeRegister::load(&postal_code_register).await.unwrap_or_else(|e| {
+        tracing::warn!("Postal code register unavailable ({}); addresses won't get a municipality or county", e);
+        PostalCodeRegister::default()
+    }));
+    let weather: Option<Arc<dyn WeatherForecaster>> = match met_user_agent {
+        Some(user_agent) => Some(Arc::new(CachedWeatherForecaster::new(
+            MetWeatherForecaster::new(met_api_url, &user_agent)?.with_resilience(resilience.clone()),
+        ))),
+        None => {
+            tracing::info!("MET_USER_AGENT is not set; event pages show no weather forecast");
+            None
+        }
+    };
+    let travel_planner =
+        Arc::new(EnturTravelPlanner::new(entur_api_url, entur_client_name)?.with_resilience(resilience.clone()));
+    let exchange_rates = Arc::new(CachedExchangeRates::new(
+        NorgesBankExchangeRates::new(norges_bank_api_url)?.with_resilience(resilience.clone()),
+    ));
+    let payments: Option<Arc<dyn PaymentProvider>> = match vipps_config {
+        Some(config) => Some(Arc::new(VippsPayments::new(config)?.with_resilience(resilience.clone()))),
+        None => None,
+    };
+    let email_suppression_repository = Arc::new(SqliteEmailSuppressionRepository::new(db.pool().clone()));
+    let notification_digest_repository = Arc::new(SqliteNotificationDigestRepository::new(db.pool().clone()));
+    let organization_invitation_repository = Arc::new(SqliteOrganizationInvitationRepository::new(db.pool().clone()));
+    let organization_role_repository = Arc::new(SqliteOrganizationRoleRepository::new(db.pool().clone()));
+    let access_grant_repository = Arc::new(SqliteAccessGrantRepository::new(db.pool().clone()));
+    let export_job_repository = Arc::new(SqliteExportJobRepository::new(db.pool().clone()));
+    let export_delivery = Arc::new(ExportDelivery::new(export_config)?.with_resilience(resilience.clone()));
+    let crm_connector_repository = Arc::new(SqliteCrmConnectorRepository::new(db.pool().clone()));
+    let crm_client = Arc::new(HttpCrmClient::new(crm_config)?.with_resilience(resilience.clone()));
+    let ownership_transfer_repository = Arc::new(SqliteEventOwnershipTransferRepository::new(db.pool().clone()));
+    let event_summary_repository = Arc::new(Instrumented::new(FaultInjected::new(SqliteEventSummaryRepository::new(db.pool().clone()), "event_summaries", faults.clone()), "event_summaries", query_metrics.clone()));
+    let analytics_repository = Arc::new(SqliteAnalyticsRepository::new(db.pool().clone()));
+    let submission_repository = Arc::new(SqliteEventSubmissionRepository::new(db.pool().clone()));
+    let content_report_repository = Arc::new(SqliteContentReportRepository::new(db.pool().clone()));
+    let consent_repository = Arc::new(SqliteEventConsentRepository::new(db.pool().clone()));
+    let storage_quota_repository = Arc::new(SqliteStorageQuotaRepository::new(db.pool().clone()));
+    let broadcast_repository = Arc::new(SqliteEventBroadcastRepository::new(db.pool().clone()));
+    let message_thread_repository = Arc::new(SqliteMessageThreadRepository::new(db.pool().clone()));
+    let mailer: Arc<dyn Mailer> = match sendgrid_api_key {
+        Some(api_key) => Arc::new(SendGridMailer::new(api_key, mail_from)?.with_resilience(resilience.clone())),
+        None => {
+            tracing::warn!("SENDGRID_API_KEY is not set; emails are logged instead of sent");
+            Arc::new(LogMailer)
+        }
+    };
+    let media_storage = Arc::new(LocalMediaStorage::new(media_dir));
+    let publish_webhooks =
+        HttpEventWebhooks::new(&publish_webhook_urls, public_url.clone())?.with_resilience(resilience.clone());
 
     // Create concrete application state with dependency injection
-    let app_state = AppState::new(
-        event_repository,
+    let app_state = AppState::new(AppDependencies {
+        event_repository: event_repository.clone(),
         user_repository,
         event_category_repository,
-        invitation_repository,
+        invitation_repository: invitation_repository.clone(),
         registration_repository,
+        audit_log_repository,
+        company_repository,
+        comment_repository,
+        question_repository,
+        poll_repository,
+        attachment_repository,
+        photo_repository,
+        budget_repository,
+        sponsor_repository,
+        short_link_repository,
+        overbooking_policy_repository,
+        ticket_repository,
+        exchange_rates,
+        invoice_repository,
+        invoicing_config,
+        refund_repository,
+        payments,
+        seating_repository,
+        resource_repository,
+        shift_repository,
+        exhibitor_repository,
+        lead_repository,
+        matchmaking_repository,
+        web_vital_repository,
+        scheduling_policy_repository,
+        event_field_repository,
+        sending_domain_repository,
+        dns_resolver,
+        company_registry,
+        postal_codes,
+        weather,
+        travel_planner,
+        mail_platform,
+        email_suppression_repository,
+        notification_digest_repository,
+        organization_invitation_repository,
+        organization_role_repository,
+        access_grant_repository,
+        export_job_repository,
+        export_uploader: export_delivery,
+        crm_connector_repository,
+        crm_client,
+        ownership_transfer_repository,
+        event_summary_repository,
+        analytics_repository,
+        submission_repository,
+        submissions_required,
+        content_report_repository,
+        report_hide_threshold,
+        consent_repository,
+        broadcast_repository,
+        message_thread_repository,
+        storage_quota_repository,
+        storage_quota_bytes,
+        mailer,
+        media_storage,
+        public_url,
+        tracking_secret,
+        email_webhook_secret,
+        import_anonymizer,
+        impersonation_tokens: impersonation_tokens.clone(),
+        query_metrics,
+        resilience,
+        client_config,
+    });
+
+    // Publish scheduled drafts in the background
+    let mut publishing_service = PublishingApplicationService::new(
+        event_repository,
+        invitation_repository,
+        app_state.registration_service.notifier().clone(),
+    );
+    if !publish_webhooks.is_empty() {
+        publishing_service = publishing_service.with_webhooks(Arc::new(publish_webhooks));
+    }
+    spawn_publish_scheduler(publishing_service, Duration::from_secs(publish_check_seconds));
+
+    // Send scheduled broadcasts as often as drafts are checked for publishing
+    spawn_broadcast_scheduler(app_state.broadcast_service.clone(), Duration::from_secs(publish_check_seconds));
+
+    // Retry ticket refunds that failed on the first attempt
+    spawn_refund_scheduler(app_state.refund_service.clone(), Duration::from_secs(publish_check_seconds));
+
+    // Run scheduled exports; cron schedules have minute precision, so checking
+    // as often as drafts is close enough
+    spawn_export_scheduler(app_state.export_service.clone(), Duration::from_secs(publish_check_seconds));
+
+    // Sync CRM connectors; their intervals are whole minutes
+    spawn_crm_sync_scheduler(app_state.crm_service.clone(), Duration::from_secs(publish_check_seconds));
+
+    // Collect non-urgent notifications for digests and send the digests when due
+    spawn_digest_collector(
+        app_state.notification_digest_service.clone(),
+        app_state.registration_service.notifier(),
+    );
+    spawn_digest_scheduler(
+        app_state.notification_digest_service.clone(),
+        Duration::from_secs(digest_check_seconds),
     );
 
+    // Web vitals are only kept for a few weeks, so pruning once an hour is plenty
+    spawn_web_vitals_pruner(app_state.web_vitals_service.clone(), Duration::from_secs(60 * 60));
+
     // Create base routes (expecting AppState)
     let mut app = create_routes();
 
     // Add authentication middleware and auth routes
     if use_mock_auth {
         println!("🔓 Using mock authentication for development");
-        let mock_config = MockAuthConfig::new(true);
+        let mock_config = MockAuthConfig::new(true).with_impersonation(impersonation_tokens);
 
         // Add mock auth routes
         let auth_routes = Router::new()
@@ -64,16 +446,26 @@ async fn main() -> anyhow::Result<()> {
         app = add_auth_middleware(app, true, None, Some(mock_config));
     } else {
         println!("🔒 Using Keycloak authentication");
-        let keycloak_realm_url = env::var("KEYCLOAK_REALM_URL")
-            .unwrap_or_else(|_| "http://localhost:8080/realms/aqio".to_string());
         let keycloak_client_id =
             env::var("KEYCLOAK_CLIENT_ID").unwrap_or_else(|_| "aqio-api".to_string());
-        let keycloak_config = KeycloakConfig::new(keycloak_realm_url, keycloak_client_id);
+        let keycloak_config = KeycloakConfig::new(keycloak_realm_url, keycloak_client_id)
+            .with_impersonation(impersonation_tokens);
 
         app = add_auth_middleware(app, false, Some(keycloak_config), None);
     };
 
-    println!("🚀 Server running on http://127.0.0.1:3000");
+    // Health checks and public event pages stay public regardless of the auth mode
+    app = app.merge(public_routes());
+
+    // Optionally serve the built frontend bundle (single-binary deployments).
+    // Installed after the auth layer so static assets stay public.
+    if let Ok(static_dir) = env::var("STATIC_DIR") {
+        println!("📦 Serving frontend from {}", static_dir);
+        app = app.fallback_service(spa_router(static_dir));
+    }
+
+    let bind_addr = server_config.bind_addr();
+    println!("🚀 Server running on http://{}", bind_addr);
 
     if use_mock_auth {
         println!("🔑 Mock auth endpoints:");
@@ -82,10 +474,13 @@ async fn main() -> anyhow::Result<()> {
         println!("📝 Available mock users: dev-user, admin-user, john-doe, jane-smith");
     }
 
+    // Outermost, so every log line of a request, authentication included, carries its request ID
+    app = app.layer(axum::middleware::from_fn(trace_requests));
+
     // Add the state to the router before serving
     let app_with_state = app.with_state(app_state);
 
-    let listener = tokio::net::TcpListener::bind("127.0.0.1:3000").await?;
+    let listener = tokio::net::TcpListener::bind(bind_addr).await?;
     axum::serve(listener, app_with_state).await?;
     Ok(())
 }
\ No newline at end of file
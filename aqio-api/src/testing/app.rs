@@ -0,0 +1,124 @@
+#![cfg(test)]
+// A complete AppState on an in-memory database, for tests that go through the
+// real router and middleware stack instead of calling handlers directly.
+// Outbound adapters point at an unroutable address and are never expected to be called.
+use std::sync::Arc;
+
+use aqio_core::MailPlatform;
+use aqio_database::{
+    Database, QueryMetrics,
+    infrastructure::persistence::sqlite::*,
+};
+
+use crate::auth::impersonation::ImpersonationTokens;
+use crate::config::{AuthMode, Branding, ClientConfig, CrmConfig, ExportConfig};
+use crate::domain::anonymize::Anonymizer;
+use crate::infrastructure::{
+    brreg::{BrregCompanyRegistry, CachedCompanyRegistry},
+    crm::HttpCrmClient,
+    dns::DohDnsResolver,
+    entur::EnturTravelPlanner,
+    exports::ExportDelivery,
+    mailer::LogMailer,
+    media::LocalMediaStorage,
+    norges_bank::{CachedExchangeRates, NorgesBankExchangeRates},
+    postal_codes::PostalCodeRegister,
+    resilience::Resilience,
+    web::{AppDependencies, AppState},
+};
+
+const UNREACHABLE_URL: &str = "http://127.0.0.1:9";
+
+/// The state and the directory its media storage writes to, which lives as long as the guard
+pub async fn create_test_app_state() -> (AppState, tempfile::TempDir) {
+    let db = Database::new("sqlite::memory:").await.expect("in-memory database");
+    let pool = db.pool().clone();
+    let media_dir = tempfile::tempdir().expect("media directory");
+    let resilience = Resilience::default();
+
+    let state = AppState::new(AppDependencies {
+        event_repository: Arc::new(SqliteEventRepository::new(pool.clone())),
+        user_repository: Arc::new(SqliteUserRepository::new(pool.clone())),
+        event_category_repository: Arc::new(SqliteEventCategoryRepository::new(pool.clone())),
+        invitation_repository: Arc::new(SqliteInvitationRepository::new(pool.clone())),
+        registration_repository: Arc::new(SqliteEventRegistrationRepository::new(pool.clone())),
+        audit_log_repository: Arc::new(SqliteAuditLogRepository::new(pool.clone())),
+        company_repository: Arc::new(SqliteCompanyRepository::new(pool.clone())),
+        comment_repository: Arc::new(SqliteEventCommentRepository::new(pool.clone())),
+        question_repository: Arc::new(SqliteEventQuestionRepository::new(pool.clone())),
+        poll_repository: Arc::new(SqliteEventPollRepository::new(pool.clone())),
+        attachment_repository: Arc::new(SqliteEventAttachmentRepository::new(pool.clone())),
+        photo_repository: Arc::new(SqliteEventPhotoRepository::new(pool.clone())),
+        budget_repository: Arc::new(SqliteEventBudgetRepository::new(pool.clone())),
+        sponsor_repository: Arc::new(SqliteEventSponsorRepository::new(pool.clone())),
+        short_link_repository: Arc::new(SqliteShortLinkRepository::new(pool.clone())),
+        overbooking_policy_repository: Arc::new(SqliteOverbookingPolicyRepository::new(pool.clone())),
+        ticket_repository: Arc::new(SqliteTicketRepository::new(pool.clone())),
+        exchange_rates: Arc::new(CachedExchangeRates::new(NorgesBankExchangeRates::new(UNREACHABLE_URL).unwrap())),
+        invoice_repository: Arc::new(SqliteInvoiceRepository::new(pool.clone())),
+        invoicing_config: None,
+        refund_repository: Arc::new(SqliteTicketRefundRepository::new(pool.clone())),
+        payments: None,
+        seating_repository: Arc::new(SqliteSeatingRepository::new(pool.clone())),
+        resource_repository: Arc::new(SqliteResourceRepository::new(pool.clone())),
+        shift_repository: Arc::new(SqliteShiftRepository::new(pool.clone())),
+        exhibitor_repository: Arc::new(SqliteExhibitorRepository::new(pool.clone())),
+        lead_repository: Arc::new(SqliteLeadRepository::new(pool.clone())),
+        matchmaking_repository: Arc::new(SqliteMatchmakingRepository::new(pool.clone())),
+        web_vital_repository: Arc::new(SqliteWebVitalRepository::new(pool.clone())),
+        scheduling_policy_repository: Arc::new(SqliteSchedulingPolicyRepository::new(pool.clone())),
+        event_field_repository: Arc::new(SqliteEventFieldRepository::new(pool.clone())),
+        sending_domain_repository: Arc::new(SqliteSendingDomainRepository::new(pool.clone())),
+        dns_resolver: Arc::new(DohDnsResolver::new(UNREACHABLE_URL).unwrap()),
+        company_registry: Arc::new(CachedCompanyRegistry::new(BrregCompanyRegistry::new(UNREACHABLE_URL).unwrap())),
+        postal_codes: Arc::new(PostalCodeRegister::default()),
+        weather: None,
+        travel_planner: Arc::new(EnturTravelPlanner::new(UNREACHABLE_URL, "aqio-test").unwrap()),
+        mail_platform: MailPlatform {
+            spf_include: "spf.aqio.test".to_string(),
+            dkim_host: "dkim.aqio.test".to_string(),
+        },
+        email_suppression_repository: Arc::new(SqliteEmailSuppressionRepository::new(pool.clone())),
+        notification_digest_repository: Arc::new(SqliteNotificationDigestRepository::new(pool.clone())),
+        organization_invitation_repository: Arc::new(SqliteOrganizationInvitationRepository::new(pool.clone())),
+        organization_role_repository: Arc::new(SqliteOrganizationRoleRepository::new(pool.clone())),
+        access_grant_repository: Arc::new(SqliteAccessGrantRepository::new(pool.clone())),
+        export_job_repository: Arc::new(SqliteExportJobRepository::new(pool.clone())),
+        export_uploader: Arc::new(ExportDelivery::new(ExportConfig::default()).unwrap()),
+        crm_connector_repository: Arc::new(SqliteCrmConnectorRepository::new(pool.clone())),
+        crm_client: Arc::new(HttpCrmClient::new(CrmConfig::default()).unwrap()),
+        ownership_transfer_repository: Arc::new(SqliteEventOwnershipTransferRepository::new(pool.clone())),
+        event_summary_repository: Arc::new(SqliteEventSummaryRepository::new(pool.clone())),
+        analytics_repository: Arc::new(SqliteAnalyticsRepository::new(pool.clone())),
+        submission_repository: Arc::new(SqliteEventSubmissionRepository::new(pool.clone())),
+        submissions_required: false,
+        content_report_repository: Arc::new(SqliteContentReportRepository::new(pool.clone())),
+        report_hide_threshold: 0,
+        consent_repository: Arc::new(SqliteEventConsentRepository::new(pool.clone())),
+        broadcast_repository: Arc::new(SqliteEventBroadcastRepository::new(pool.clone())),
+        message_thread_repository: Arc::new(SqliteMessageThreadRepository::new(pool.clone())),
+        storage_quota_repository: Arc::new(SqliteStorageQuotaRepository::new(pool.clone())),
+        storage_quota_bytes: None,
+        mailer: Arc::new(LogMailer),
+        media_storage: Arc::new(LocalMediaStorage::new(media_dir.path())),
+        public_url: "http://aqio.test".to_string(),
+        tracking_secret: "test-tracking-secret".to_string(),
+        email_webhook_secret: None,
+        import_anonymizer: Anonymizer::default(),
+        impersonation_tokens: ImpersonationTokens::new("test-impersonation-secret"),
+        query_metrics: QueryMetrics::default(),
+        resilience,
+        client_config: ClientConfig {
+            auth: AuthMode::Mock,
+            branding: Branding {
+                name: "Aqio".to_string(),
+                logo_url: None,
+                primary_color: None,
+            },
+            features: Default::default(),
+            remote_log_level: None,
+        },
+    });
+
+    (state, media_dir)
+}
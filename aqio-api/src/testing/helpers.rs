@@ -26,6 +26,7 @@ impl TestEventBuilder {
             event: Event {
                 id: Uuid::new_v4(),
                 title: "Test Event".to_string(),
+                slug: "test-event".to_string(),
                 description: "A test event".to_string(),
                 category_id: "general".to_string(),
                 organizer_id: Uuid::new_v4(),
@@ -38,6 +39,9 @@ impl TestEventBuilder {
                 address: None,
                 virtual_link: Some("https://example.com".to_string()),
                 virtual_access_code: None,
+                latitude: None,
+                longitude: None,
+                region: None,
                 is_private: false,
                 requires_approval: false,
                 max_attendees: Some(100),
@@ -53,6 +57,7 @@ impl TestEventBuilder {
                 image_url: None,
                 custom_fields: None,
                 status: EventStatus::Draft,
+                publish_at: None,
                 created_at: now,
                 updated_at: now,
             },
@@ -69,21 +74,58 @@ impl TestEventBuilder {
         self
     }
 
+    pub fn with_slug(mut self, slug: impl Into<String>) -> Self {
+        self.event.slug = slug.into();
+        self
+    }
+
+    pub fn with_capacity(mut self, max_attendees: i32, allow_waitlist: bool) -> Self {
+        self.event.max_attendees = Some(max_attendees);
+        self.event.allow_waitlist = allow_waitlist;
+        self
+    }
+
     pub fn with_organizer(mut self, organizer_id: Uuid) -> Self {
         self.event.organizer_id = organizer_id;
         self
     }
 
+    pub fn with_coordinates(mut self, latitude: f64, longitude: f64) -> Self {
+        self.event.latitude = Some(latitude);
+        self.event.longitude = Some(longitude);
+        self
+    }
+
     pub fn with_category(mut self, category_id: impl Into<String>) -> Self {
         self.event.category_id = category_id.into();
         self
     }
 
+    pub fn starting_at(mut self, start_date: chrono::DateTime<Utc>) -> Self {
+        self.event.start_date = start_date;
+        self.event.end_date = start_date + Duration::hours(1);
+        self
+    }
+
+    pub fn publishing_at(mut self, publish_at: chrono::DateTime<Utc>) -> Self {
+        self.event.publish_at = Some(publish_at);
+        self
+    }
+
     pub fn published(mut self) -> Self {
         self.event.status = EventStatus::Published;
         self
     }
 
+    /// Published, started half an hour ago and ending in half an hour
+    pub fn running(mut self) -> Self {
+        let now = Utc::now();
+        self.event.status = EventStatus::Published;
+        self.event.start_date = now - Duration::minutes(30);
+        self.event.end_date = now + Duration::minutes(30);
+        self
+    }
+
     pub fn private(mut self) -> Self {
         self.event.is_private = true;
         self
@@ -110,6 +152,10 @@ impl TestUserBuilder {
                 company_id: None,
                 role: UserRole::Participant,
                 is_active: true,
+                timezone: None,
+                locale: None,
+                theme: None,
+                calendar_view: None,
                 created_at: now,
                 updated_at: now,
             },
@@ -121,6 +167,11 @@ impl TestUserBuilder {
         self
     }
 
+    pub fn with_keycloak_id(mut self, keycloak_id: impl Into<String>) -> Self {
+        self.user.keycloak_id = keycloak_id.into();
+        self
+    }
+
     pub fn with_email(mut self, email: impl Into<String>) -> Self {
         self.user.email = email.into();
         self
@@ -141,6 +192,11 @@ impl TestUserBuilder {
         self
     }
 
+    pub fn with_company(mut self, company_id: Uuid) -> Self {
+        self.user.company_id = Some(company_id);
+        self
+    }
+
     pub fn inactive(mut self) -> Self {
         self.user.is_active = false;
         self
@@ -221,6 +277,7 @@ impl TestRegistrationBuilder {
                 checked_in_at: None,
                 waitlist_position: None,
                 waitlist_added_at: None,
+                promotion_expires_at: None,
                 created_at: now,
                 updated_at: now,
             },
@@ -306,6 +363,8 @@ pub fn create_event_request() -> CreateEventRequest {
         address: None,
         virtual_link: Some("https://example.com".to_string()),
         virtual_access_code: None,
+        latitude: None,
+        longitude: None,
         is_private: Some(false),
         requires_approval: Some(false),
         max_attendees: Some(100),
@@ -320,6 +379,8 @@ pub fn create_event_request() -> CreateEventRequest {
         collect_accessibility_info: Some(false),
         image_url: None,
         custom_fields: None,
+        publish_at: None,
+        co_organizers: None,
     }
 }
 
@@ -358,6 +419,7 @@ pub fn create_admin_claims() -> Claims {
         roles: Some(vec!["admin".to_string()]),
         exp: (Utc::now().timestamp() + 3600) as usize,
         iat: Utc::now().timestamp() as usize,
+        impersonator: None,
     }
     // TODO(aqio-api/tests): Frequently used in handler tests to simulate admin.
 }
@@ -370,6 +432,7 @@ pub fn create_organizer_claims() -> Claims {
         roles: Some(vec!["organizer".to_string()]),
         exp: (Utc::now().timestamp() + 3600) as usize,
         iat: Utc::now().timestamp() as usize,
+        impersonator: None,
     }
     // TODO(aqio-api/tests): Used to simulate organizer role in tests.
 }
@@ -382,17 +445,61 @@ pub fn create_participant_claims() -> Claims {
         roles: Some(vec!["participant".to_string()]),
         exp: (Utc::now().timestamp() + 3600) as usize,
         iat: Utc::now().timestamp() as usize,
+        impersonator: None,
     }
     // TODO(aqio-api/tests): Used to simulate participant role in tests.
 }
 
+pub fn create_test_company(name: &str, industry_type: IndustryType) -> Company {
+    let now = Utc::now();
+    Company {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        org_number: None,
+        location: Some("Bergen".to_string()),
+        industry_type,
+        website: None,
+        phone: None,
+        region: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// A pending invitation of a registered user
+pub fn create_test_invitation(event_id: Uuid, user_id: Uuid, inviter_id: Uuid) -> EventInvitation {
+    let now = Utc::now();
+    EventInvitation {
+        id: Uuid::new_v4(),
+        event_id,
+        invited_user_id: Some(user_id),
+        invited_contact_id: None,
+        invited_email: None,
+        invited_name: None,
+        inviter_id,
+        invitation_method: InvitationMethod::Manual,
+        personal_message: None,
+        status: InvitationStatus::Pending,
+        sent_at: None,
+        opened_at: None,
+        clicked_at: None,
+        responded_at: None,
+        delivery_failure: None,
+        delivery_failed_at: None,
+        invitation_token: Some(Uuid::new_v4().to_string()),
+        expires_at: None,
+        created_at: now,
+        updated_at: now,
+    }
+}
+
 // ============================================================================
 // Service Builders with Mocks
 // ============================================================================
 
 pub fn create_mock_event_service() -> (EventApplicationService, MockEventRepository) {
     let mock_repo = MockEventRepository::new();
-    let service = EventApplicationService::new(Arc::new(mock_repo.clone()));
+    let service = EventApplicationService::new(Arc::new(mock_repo.clone()), "https://aqio.example");
     (service, mock_repo)
 }
 
@@ -412,10 +519,12 @@ pub fn create_mock_category_service()
 pub fn create_mock_invitation_service() -> (InvitationApplicationService, MockInvitationRepository)
 {
     let mock_repo = MockInvitationRepository::new();
-    let service = InvitationApplicationService::new(Arc::new(mock_repo.clone()));
+    let service = InvitationApplicationService::new(
+        Arc::new(mock_repo.clone()),
+        InvitationTracking::new("https://aqio.test", "test-secret"),
+    );
     (service, mock_repo)
 }
-// TODO(aqio-api/tests): Will be used once invitation handlers are wired. Keep.
 
 pub fn create_mock_registration_service() -> (EventRegistrationApplicationService, MockEventRegistrationRepository) {
     let mock_repo = MockEventRegistrationRepository::new();
@@ -423,6 +532,984 @@ pub fn create_mock_registration_service() -> (EventRegistrationApplicationServic
     (service, mock_repo)
 }
 
+pub fn create_mock_audit_log_service() -> (AuditLogApplicationService, MockAuditLogRepository) {
+    let mock_repo = MockAuditLogRepository::new();
+    let service = AuditLogApplicationService::new(Arc::new(mock_repo.clone()));
+    (service, mock_repo)
+}
+
+pub fn create_mock_company_service() -> (CompanyApplicationService, MockCompanyRepository, MockEventRepository) {
+    let company_repo = MockCompanyRepository::new();
+    let event_repo = MockEventRepository::new();
+    let service = CompanyApplicationService::new(
+        Arc::new(company_repo.clone()),
+        Arc::new(event_repo.clone()),
+    );
+    (service, company_repo, event_repo)
+}
+
+pub fn create_mock_comment_service() -> (EventCommentApplicationService, MockEventCommentRepository, MockEventRepository) {
+    let comment_repo = MockEventCommentRepository::new();
+    let event_repo = MockEventRepository::new();
+    let service = EventCommentApplicationService::new(
+        Arc::new(comment_repo.clone()),
+        Arc::new(event_repo.clone()),
+    );
+    (service, comment_repo, event_repo)
+}
+
+pub fn create_mock_live_service() -> (LiveEngagementApplicationService, MockEventRepository) {
+    let event_repo = MockEventRepository::new();
+    let service = LiveEngagementApplicationService::new(
+        Arc::new(MockEventQuestionRepository::new()),
+        Arc::new(MockEventPollRepository::new()),
+        Arc::new(event_repo.clone()),
+    );
+    (service, event_repo)
+}
+
+pub struct AttachmentMocks {
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub attachments: MockEventAttachmentRepository,
+    pub storage: MockMediaStorage,
+}
+
+pub fn create_mock_attachment_service() -> (EventAttachmentApplicationService, AttachmentMocks) {
+    let mocks = AttachmentMocks {
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+        attachments: MockEventAttachmentRepository::new(),
+        storage: MockMediaStorage::new(),
+    };
+    let service = EventAttachmentApplicationService::new(
+        Arc::new(mocks.attachments.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.storage.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct PhotoMocks {
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub photos: MockEventPhotoRepository,
+    pub storage: MockMediaStorage,
+}
+
+pub fn create_mock_photo_service() -> (EventPhotoApplicationService, PhotoMocks) {
+    let mocks = PhotoMocks {
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+        photos: MockEventPhotoRepository::new(),
+        storage: MockMediaStorage::new(),
+    };
+    let service = EventPhotoApplicationService::new(
+        Arc::new(mocks.photos.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.storage.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct BudgetMocks {
+    pub events: MockEventRepository,
+    pub budgets: MockEventBudgetRepository,
+    pub storage: MockMediaStorage,
+}
+
+pub fn create_mock_budget_service() -> (EventBudgetApplicationService, BudgetMocks) {
+    let mocks = BudgetMocks {
+        events: MockEventRepository::new(),
+        budgets: MockEventBudgetRepository::new(),
+        storage: MockMediaStorage::new(),
+    };
+    let service = EventBudgetApplicationService::new(
+        Arc::new(mocks.budgets.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.storage.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct SponsorMocks {
+    pub events: MockEventRepository,
+    pub sponsors: MockEventSponsorRepository,
+}
+
+pub fn create_mock_sponsor_service() -> (EventSponsorApplicationService, SponsorMocks) {
+    let mocks = SponsorMocks {
+        events: MockEventRepository::new(),
+        sponsors: MockEventSponsorRepository::new(),
+    };
+    let service = EventSponsorApplicationService::new(
+        Arc::new(mocks.sponsors.clone()),
+        Arc::new(mocks.events.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct ShortLinkMocks {
+    pub events: MockEventRepository,
+    pub links: MockShortLinkRepository,
+    pub invitations: MockInvitationRepository,
+}
+
+pub fn create_mock_short_link_service() -> (ShortLinkApplicationService, ShortLinkMocks) {
+    let mocks = ShortLinkMocks {
+        events: MockEventRepository::new(),
+        links: MockShortLinkRepository::new(),
+        invitations: MockInvitationRepository::new(),
+    };
+    let service = ShortLinkApplicationService::new(
+        Arc::new(mocks.links.clone()),
+        Arc::new(mocks.events.clone()),
+        "https://aqio.example/",
+    )
+    .with_invitations(Arc::new(mocks.invitations.clone()));
+    (service, mocks)
+}
+
+pub struct PosterMocks {
+    pub events: MockEventRepository,
+    pub categories: MockEventCategoryRepository,
+    pub links: MockShortLinkRepository,
+}
+
+pub fn create_mock_poster_service() -> (EventPosterApplicationService, PosterMocks) {
+    let mocks = PosterMocks {
+        events: MockEventRepository::new(),
+        categories: MockEventCategoryRepository::new(),
+        links: MockShortLinkRepository::new(),
+    };
+    let service = EventPosterApplicationService::new(
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.categories.clone()),
+        "https://aqio.example/",
+    )
+    .with_short_links(Arc::new(mocks.links.clone()));
+    (service, mocks)
+}
+
+pub struct SchedulingMocks {
+    pub policies: MockSchedulingPolicyRepository,
+    pub companies: MockCompanyRepository,
+    pub events: MockEventRepository,
+    pub users: MockUserRepository,
+}
+
+pub fn create_mock_scheduling_service() -> (SchedulingApplicationService, SchedulingMocks) {
+    let mocks = SchedulingMocks {
+        policies: MockSchedulingPolicyRepository::new(),
+        companies: MockCompanyRepository::new(),
+        events: MockEventRepository::new(),
+        users: MockUserRepository::new(),
+    };
+    let service = SchedulingApplicationService::new(
+        Arc::new(mocks.policies.clone()),
+        Arc::new(mocks.companies.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.users.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct EventFieldMocks {
+    pub fields: MockEventFieldRepository,
+    pub companies: MockCompanyRepository,
+    pub events: MockEventRepository,
+    pub users: MockUserRepository,
+}
+
+pub fn create_mock_event_field_service() -> (EventFieldApplicationService, EventFieldMocks) {
+    let mocks = EventFieldMocks {
+        fields: MockEventFieldRepository::new(),
+        companies: MockCompanyRepository::new(),
+        events: MockEventRepository::new(),
+        users: MockUserRepository::new(),
+    };
+    let service = EventFieldApplicationService::new(
+        Arc::new(mocks.fields.clone()),
+        Arc::new(mocks.companies.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.users.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct StorageQuotaMocks {
+    pub quotas: MockStorageQuotaRepository,
+    pub companies: MockCompanyRepository,
+    pub users: MockUserRepository,
+}
+
+pub fn create_mock_storage_quota_service() -> (StorageQuotaApplicationService, StorageQuotaMocks) {
+    let mocks = StorageQuotaMocks {
+        quotas: MockStorageQuotaRepository::new(),
+        companies: MockCompanyRepository::new(),
+        users: MockUserRepository::new(),
+    };
+    let service = StorageQuotaApplicationService::new(
+        Arc::new(mocks.quotas.clone()),
+        Arc::new(mocks.companies.clone()),
+        Arc::new(mocks.users.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct EmailDeliveryMocks {
+    pub invitations: MockInvitationRepository,
+    pub suppressions: MockEmailSuppressionRepository,
+}
+
+/// The delivery service with an invitation service sharing its repositories
+pub fn create_mock_email_delivery_service() -> (EmailDeliveryApplicationService, InvitationApplicationService, EmailDeliveryMocks) {
+    let mocks = EmailDeliveryMocks {
+        invitations: MockInvitationRepository::new(),
+        suppressions: MockEmailSuppressionRepository::new(),
+    };
+    let delivery = EmailDeliveryApplicationService::new(
+        Arc::new(mocks.suppressions.clone()),
+        Arc::new(mocks.invitations.clone()),
+        Some("webhook-secret".to_string()),
+    );
+    let invitations = InvitationApplicationService::new(
+        Arc::new(mocks.invitations.clone()),
+        InvitationTracking::new("https://aqio.test", "test-secret"),
+    )
+    .with_suppressions(Arc::new(mocks.suppressions.clone()));
+    (delivery, invitations, mocks)
+}
+
+pub struct DigestMocks {
+    pub digests: MockNotificationDigestRepository,
+    pub users: MockUserRepository,
+    pub events: MockEventRepository,
+    pub suppressions: MockEmailSuppressionRepository,
+    pub mailer: MockMailer,
+}
+
+pub fn create_mock_digest_service() -> (NotificationDigestApplicationService, DigestMocks) {
+    let mocks = DigestMocks {
+        digests: MockNotificationDigestRepository::new(),
+        users: MockUserRepository::new(),
+        events: MockEventRepository::new(),
+        suppressions: MockEmailSuppressionRepository::new(),
+        mailer: MockMailer::new(),
+    };
+    let service = NotificationDigestApplicationService::new(
+        Arc::new(mocks.digests.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.mailer.clone()),
+        "https://aqio.test",
+    )
+    .with_suppressions(Arc::new(mocks.suppressions.clone()));
+    (service, mocks)
+}
+
+pub struct OrganizationInvitationMocks {
+    pub invitations: MockOrganizationInvitationRepository,
+    pub users: MockUserRepository,
+    pub companies: MockCompanyRepository,
+    pub suppressions: MockEmailSuppressionRepository,
+    pub mailer: MockMailer,
+}
+
+pub fn create_mock_organization_invitation_service() -> (OrganizationInvitationApplicationService, OrganizationInvitationMocks) {
+    let mocks = OrganizationInvitationMocks {
+        invitations: MockOrganizationInvitationRepository::new(),
+        users: MockUserRepository::new(),
+        companies: MockCompanyRepository::new(),
+        suppressions: MockEmailSuppressionRepository::new(),
+        mailer: MockMailer::new(),
+    };
+    let service = OrganizationInvitationApplicationService::new(
+        Arc::new(mocks.invitations.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.companies.clone()),
+        Arc::new(mocks.mailer.clone()),
+        "https://aqio.test",
+    )
+    .with_suppressions(Arc::new(mocks.suppressions.clone()));
+    (service, mocks)
+}
+
+pub struct OwnershipMocks {
+    pub transfers: MockEventOwnershipTransferRepository,
+    pub events: MockEventRepository,
+    pub users: MockUserRepository,
+    pub mailer: MockMailer,
+    pub notifier: crate::domain::notifications::RegistrationNotifier,
+}
+
+pub fn create_mock_ownership_service() -> (EventOwnershipApplicationService, OwnershipMocks) {
+    let mocks = OwnershipMocks {
+        transfers: MockEventOwnershipTransferRepository::new(),
+        events: MockEventRepository::new(),
+        users: MockUserRepository::new(),
+        mailer: MockMailer::new(),
+        notifier: crate::domain::notifications::RegistrationNotifier::new(),
+    };
+    let service = EventOwnershipApplicationService::new(
+        Arc::new(mocks.transfers.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.mailer.clone()),
+        mocks.notifier.clone(),
+        "https://aqio.test",
+    );
+    (service, mocks)
+}
+
+pub struct PermissionMocks {
+    pub roles: MockOrganizationRoleRepository,
+    pub grants: MockAccessGrantRepository,
+    pub users: MockUserRepository,
+    pub companies: MockCompanyRepository,
+    pub events: MockEventRepository,
+}
+
+pub fn create_mock_permission_service() -> (PermissionApplicationService, PermissionMocks) {
+    let mocks = PermissionMocks {
+        roles: MockOrganizationRoleRepository::new(),
+        grants: MockAccessGrantRepository::new(),
+        users: MockUserRepository::new(),
+        companies: MockCompanyRepository::new(),
+        events: MockEventRepository::new(),
+    };
+    let service = PermissionApplicationService::new(
+        Arc::new(mocks.roles.clone()),
+        Arc::new(mocks.grants.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.companies.clone()),
+        Arc::new(mocks.events.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct ExportMocks {
+    pub jobs: MockExportJobRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub users: MockUserRepository,
+    pub uploader: MockExportUploader,
+}
+
+pub fn create_mock_export_service() -> (ExportApplicationService, ExportMocks) {
+    let mocks = ExportMocks {
+        jobs: MockExportJobRepository::new(),
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+        users: MockUserRepository::new(),
+        uploader: MockExportUploader::new(),
+    };
+    let service = ExportApplicationService::new(
+        Arc::new(mocks.jobs.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.uploader.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct CrmMocks {
+    pub connectors: MockCrmConnectorRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub users: MockUserRepository,
+    pub client: MockCrmClient,
+}
+
+pub fn create_mock_crm_service() -> (CrmSyncApplicationService, CrmMocks) {
+    let mocks = CrmMocks {
+        connectors: MockCrmConnectorRepository::new(),
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+        users: MockUserRepository::new(),
+        client: MockCrmClient::new(),
+    };
+    let service = CrmSyncApplicationService::new(
+        Arc::new(mocks.connectors.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.client.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct SubmissionMocks {
+    pub submissions: MockEventSubmissionRepository,
+    pub events: MockEventRepository,
+    pub users: MockUserRepository,
+    pub notifier: crate::domain::notifications::RegistrationNotifier,
+}
+
+pub fn create_mock_submission_service() -> (EventSubmissionApplicationService, SubmissionMocks) {
+    let mocks = SubmissionMocks {
+        submissions: MockEventSubmissionRepository::new(),
+        events: MockEventRepository::new(),
+        users: MockUserRepository::new(),
+        notifier: crate::domain::notifications::RegistrationNotifier::new(),
+    };
+    let service = EventSubmissionApplicationService::new(
+        Arc::new(mocks.submissions.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.users.clone()),
+        mocks.notifier.clone(),
+    )
+    .required_for_participants(true);
+    (service, mocks)
+}
+
+pub struct ModerationMocks {
+    pub reports: MockContentReportRepository,
+    pub events: MockEventRepository,
+    pub comments: MockEventCommentRepository,
+    pub users: MockUserRepository,
+    pub mailer: MockMailer,
+}
+
+pub fn create_mock_moderation_service() -> (ModerationApplicationService, ModerationMocks) {
+    let mocks = ModerationMocks {
+        reports: MockContentReportRepository::new(),
+        events: MockEventRepository::new(),
+        comments: MockEventCommentRepository::new(),
+        users: MockUserRepository::new(),
+        mailer: MockMailer::new(),
+    };
+    let service = ModerationApplicationService::new(
+        Arc::new(mocks.reports.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.comments.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.mailer.clone()),
+    )
+    .with_hide_threshold(2);
+    (service, mocks)
+}
+
+pub struct ConsentMocks {
+    pub consents: MockEventConsentRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub users: MockUserRepository,
+}
+
+pub fn create_mock_consent_service() -> (EventConsentApplicationService, ConsentMocks) {
+    let registrations = MockEventRegistrationRepository::new();
+    let mocks = ConsentMocks {
+        consents: MockEventConsentRepository::new(registrations.clone()),
+        events: MockEventRepository::new(),
+        registrations,
+        users: MockUserRepository::new(),
+    };
+    let service = EventConsentApplicationService::new(
+        Arc::new(mocks.consents.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.users.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct BroadcastMocks {
+    pub broadcasts: MockEventBroadcastRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub users: MockUserRepository,
+    pub suppressions: MockEmailSuppressionRepository,
+    pub mailer: MockMailer,
+    pub notifier: crate::domain::notifications::RegistrationNotifier,
+}
+
+pub fn create_mock_broadcast_service() -> (EventBroadcastApplicationService, BroadcastMocks) {
+    let mocks = BroadcastMocks {
+        broadcasts: MockEventBroadcastRepository::new(),
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+        users: MockUserRepository::new(),
+        suppressions: MockEmailSuppressionRepository::new(),
+        mailer: MockMailer::new(),
+        notifier: crate::domain::notifications::RegistrationNotifier::new(),
+    };
+    let service = EventBroadcastApplicationService::new(
+        Arc::new(mocks.broadcasts.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.mailer.clone()),
+        mocks.notifier.clone(),
+        "https://aqio.test",
+    )
+    .with_suppressions(Arc::new(mocks.suppressions.clone()));
+    (service, mocks)
+}
+
+pub struct MessagingMocks {
+    pub threads: MockMessageThreadRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub broadcasts: MockEventBroadcastRepository,
+    pub users: MockUserRepository,
+    pub notifier: crate::domain::notifications::RegistrationNotifier,
+}
+
+pub fn create_mock_messaging_service() -> (MessagingApplicationService, MessagingMocks) {
+    let events = MockEventRepository::new();
+    let mocks = MessagingMocks {
+        threads: MockMessageThreadRepository::new(events.clone()),
+        events,
+        registrations: MockEventRegistrationRepository::new(),
+        broadcasts: MockEventBroadcastRepository::new(),
+        users: MockUserRepository::new(),
+        notifier: crate::domain::notifications::RegistrationNotifier::new(),
+    };
+    let service = MessagingApplicationService::new(
+        Arc::new(mocks.threads.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.broadcasts.clone()),
+        Arc::new(mocks.users.clone()),
+        mocks.notifier.clone(),
+    );
+    (service, mocks)
+}
+
+pub struct ArchiveMocks {
+    pub events: MockEventRepository,
+    pub categories: MockEventCategoryRepository,
+    pub users: MockUserRepository,
+    pub invitations: MockInvitationRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub sponsors: MockEventSponsorRepository,
+    pub budget: MockEventBudgetRepository,
+}
+
+pub fn create_mock_archive_service() -> (EventArchiveApplicationService, ArchiveMocks) {
+    let mocks = ArchiveMocks {
+        events: MockEventRepository::new(),
+        categories: MockEventCategoryRepository::new(),
+        users: MockUserRepository::new(),
+        invitations: MockInvitationRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+        sponsors: MockEventSponsorRepository::new(),
+        budget: MockEventBudgetRepository::new(),
+    };
+    let service = EventArchiveApplicationService::new(
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.categories.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.invitations.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.sponsors.clone()),
+        Arc::new(mocks.budget.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct SendingDomainMocks {
+    pub sending_domains: MockSendingDomainRepository,
+    pub companies: MockCompanyRepository,
+    pub dns: MockDnsResolver,
+}
+
+pub fn create_mock_sending_domain_service() -> (SendingDomainApplicationService, SendingDomainMocks) {
+    let mocks = SendingDomainMocks {
+        sending_domains: MockSendingDomainRepository::new(),
+        companies: MockCompanyRepository::new(),
+        dns: MockDnsResolver::new(),
+    };
+    let service = SendingDomainApplicationService::new(
+        Arc::new(mocks.sending_domains.clone()),
+        Arc::new(mocks.companies.clone()),
+        Arc::new(mocks.dns.clone()),
+        MailPlatform {
+            spf_include: "spf.aqio.no".to_string(),
+            dkim_host: "dkim.aqio.no".to_string(),
+        },
+    );
+    (service, mocks)
+}
+
+pub struct PublishingMocks {
+    pub events: MockEventRepository,
+    pub invitations: MockInvitationRepository,
+    pub webhooks: MockEventWebhooks,
+    pub notifier: crate::domain::notifications::RegistrationNotifier,
+}
+
+pub fn create_mock_publishing_service() -> (PublishingApplicationService, PublishingMocks) {
+    let mocks = PublishingMocks {
+        events: MockEventRepository::new(),
+        invitations: MockInvitationRepository::new(),
+        webhooks: MockEventWebhooks::new(),
+        notifier: crate::domain::notifications::RegistrationNotifier::new(),
+    };
+    let service = PublishingApplicationService::new(
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.invitations.clone()),
+        mocks.notifier.clone(),
+    )
+    .with_webhooks(Arc::new(mocks.webhooks.clone()));
+    (service, mocks)
+}
+
+pub struct CateringMocks {
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+}
+
+pub fn create_mock_catering_service() -> (CateringApplicationService, CateringMocks) {
+    let mocks = CateringMocks {
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+    };
+    let service = CateringApplicationService::new(
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.events.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct SeatingMocks {
+    pub seating: MockSeatingRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+}
+
+pub fn create_mock_seating_service() -> (SeatingApplicationService, SeatingMocks) {
+    let mocks = SeatingMocks {
+        seating: MockSeatingRepository::new(),
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+    };
+    let service = SeatingApplicationService::new(
+        Arc::new(mocks.seating.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct ResourceMocks {
+    pub resources: MockResourceRepository,
+    pub events: MockEventRepository,
+}
+
+pub fn create_mock_resource_service() -> (ResourceApplicationService, ResourceMocks) {
+    let mocks = ResourceMocks {
+        resources: MockResourceRepository::new(),
+        events: MockEventRepository::new(),
+    };
+    let service = ResourceApplicationService::new(Arc::new(mocks.resources.clone()), Arc::new(mocks.events.clone()));
+    (service, mocks)
+}
+
+pub struct ShiftMocks {
+    pub shifts: MockShiftRepository,
+    pub events: MockEventRepository,
+    pub users: MockUserRepository,
+}
+
+pub fn create_mock_shift_service() -> (ShiftApplicationService, ShiftMocks) {
+    let mocks = ShiftMocks {
+        shifts: MockShiftRepository::new(),
+        events: MockEventRepository::new(),
+        users: MockUserRepository::new(),
+    };
+    let service = ShiftApplicationService::new(
+        Arc::new(mocks.shifts.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.users.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct ExhibitorMocks {
+    pub exhibitors: MockExhibitorRepository,
+    pub events: MockEventRepository,
+}
+
+pub fn create_mock_exhibitor_service() -> (ExhibitorApplicationService, ExhibitorMocks) {
+    let mocks = ExhibitorMocks {
+        exhibitors: MockExhibitorRepository::new(),
+        events: MockEventRepository::new(),
+    };
+    let service = ExhibitorApplicationService::new(Arc::new(mocks.exhibitors.clone()), Arc::new(mocks.events.clone()));
+    (service, mocks)
+}
+
+pub struct LeadMocks {
+    pub leads: MockLeadRepository,
+    pub exhibitors: MockExhibitorRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub consents: MockEventConsentRepository,
+    pub users: MockUserRepository,
+}
+
+pub fn create_mock_lead_service() -> (LeadApplicationService, LeadMocks) {
+    let registrations = MockEventRegistrationRepository::new();
+    let mocks = LeadMocks {
+        leads: MockLeadRepository::new(),
+        exhibitors: MockExhibitorRepository::new(),
+        events: MockEventRepository::new(),
+        consents: MockEventConsentRepository::new(registrations.clone()),
+        registrations,
+        users: MockUserRepository::new(),
+    };
+    let service = LeadApplicationService::new(
+        Arc::new(mocks.leads.clone()),
+        Arc::new(mocks.exhibitors.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.consents.clone()),
+        Arc::new(mocks.users.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct MatchmakingMocks {
+    pub matchmaking: MockMatchmakingRepository,
+    pub resources: MockResourceRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub users: MockUserRepository,
+}
+
+pub fn create_mock_matchmaking_service() -> (MatchmakingApplicationService, MatchmakingMocks) {
+    let mocks = MatchmakingMocks {
+        matchmaking: MockMatchmakingRepository::new(),
+        resources: MockResourceRepository::new(),
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+        users: MockUserRepository::new(),
+    };
+    let service = MatchmakingApplicationService::new(
+        Arc::new(mocks.matchmaking.clone()),
+        Arc::new(mocks.resources.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.users.clone()),
+    );
+    (service, mocks)
+}
+
+pub fn create_mock_web_vitals_service() -> (WebVitalsApplicationService, MockWebVitalRepository) {
+    let mock_repo = MockWebVitalRepository::new();
+    let service = WebVitalsApplicationService::new(Arc::new(mock_repo.clone()));
+    (service, mock_repo)
+}
+
+pub struct CertificateMocks {
+    pub events: MockEventRepository,
+    pub users: MockUserRepository,
+    pub registrations: MockEventRegistrationRepository,
+    pub storage: MockMediaStorage,
+}
+
+pub fn create_mock_certificate_service() -> (CertificateApplicationService, CertificateMocks) {
+    let mocks = CertificateMocks {
+        events: MockEventRepository::new(),
+        users: MockUserRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+        storage: MockMediaStorage::new(),
+    };
+    let service = CertificateApplicationService::new(
+        Arc::new(mocks.registrations.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.storage.clone()),
+        "https://aqio.example/",
+    );
+    (service, mocks)
+}
+
+pub fn create_admin_actor() -> AuditActor {
+    create_admin_claims().audit_actor()
+}
+
+pub struct EventDetailsMocks {
+    pub events: MockEventRepository,
+    pub users: MockUserRepository,
+    pub categories: MockEventCategoryRepository,
+    pub registrations: MockEventRegistrationRepository,
+}
+
+pub fn create_mock_event_details_service() -> (EventDetailsApplicationService, EventDetailsMocks) {
+    let mocks = EventDetailsMocks {
+        events: MockEventRepository::new(),
+        users: MockUserRepository::new(),
+        categories: MockEventCategoryRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+    };
+    let service = EventDetailsApplicationService::new(
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.users.clone()),
+        Arc::new(mocks.categories.clone()),
+        Arc::new(MockEventSummaryRepository::new(
+            mocks.events.clone(),
+            mocks.registrations.clone(),
+        )),
+    );
+    (service, mocks)
+}
+
+pub struct OverbookingMocks {
+    pub policies: MockOverbookingPolicyRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+}
+
+/// The overbooking service and a registration service applying its policies
+pub fn create_mock_overbooking_service() -> (EventOverbookingApplicationService, EventRegistrationApplicationService, OverbookingMocks) {
+    let mocks = OverbookingMocks {
+        policies: MockOverbookingPolicyRepository::new(),
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+    };
+    let service = EventOverbookingApplicationService::new(
+        Arc::new(mocks.policies.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(MockEventSummaryRepository::new(
+            mocks.events.clone(),
+            mocks.registrations.clone(),
+        )),
+    );
+    let registrations = EventRegistrationApplicationService::new(Arc::new(mocks.registrations.clone()))
+        .with_overbooking(Arc::new(mocks.policies.clone()));
+    (service, registrations, mocks)
+}
+
+pub struct TicketingMocks {
+    pub tickets: MockTicketRepository,
+    pub events: MockEventRepository,
+    pub rates: MockExchangeRates,
+}
+
+pub fn create_mock_ticketing_service() -> (TicketingApplicationService, TicketingMocks) {
+    let mocks = TicketingMocks {
+        tickets: MockTicketRepository::new(),
+        events: MockEventRepository::new(),
+        rates: MockExchangeRates::new(),
+    };
+    let service = TicketingApplicationService::new(
+        Arc::new(mocks.tickets.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.rates.clone()),
+    );
+    (service, mocks)
+}
+
+pub struct InvoicingMocks {
+    pub invoices: MockInvoiceRepository,
+    pub tickets: MockTicketRepository,
+    pub events: MockEventRepository,
+    /// Shares the repositories, to place and cancel the orders invoiced
+    pub ticketing: TicketingApplicationService,
+}
+
+pub fn create_mock_invoicing_service() -> (InvoicingApplicationService, InvoicingMocks) {
+    let (ticketing, ticketing_mocks) = create_mock_ticketing_service();
+    let invoices = MockInvoiceRepository::new();
+    let mocks = InvoicingMocks {
+        ticketing: ticketing.with_invoices(Arc::new(invoices.clone())),
+        invoices,
+        tickets: ticketing_mocks.tickets,
+        events: ticketing_mocks.events,
+    };
+    let config = crate::config::InvoicingConfig {
+        seller: InvoiceParty {
+            name: "Aqio AS".to_string(),
+            org_number: "923609016".to_string(),
+            country: "NO".to_string(),
+            ..Default::default()
+        },
+        bank_account: Some("15032080119".to_string()),
+        payment_days: 14,
+    };
+    let service = InvoicingApplicationService::new(
+        Arc::new(mocks.invoices.clone()),
+        Arc::new(mocks.tickets.clone()),
+        Arc::new(mocks.events.clone()),
+        Some(config),
+    );
+    (service, mocks)
+}
+
+pub struct RefundMocks {
+    pub refunds: MockTicketRefundRepository,
+    pub payments: MockPaymentProvider,
+    pub tickets: MockTicketRepository,
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+    /// Shares the repositories, to place the orders refunded
+    pub ticketing: TicketingApplicationService,
+    pub notifier: crate::domain::notifications::RegistrationNotifier,
+}
+
+pub fn create_mock_refund_service() -> (RefundApplicationService, RefundMocks) {
+    let (ticketing, ticketing_mocks) = create_mock_ticketing_service();
+    let mocks = RefundMocks {
+        refunds: MockTicketRefundRepository::new(),
+        payments: MockPaymentProvider::new(),
+        tickets: ticketing_mocks.tickets,
+        events: ticketing_mocks.events,
+        registrations: MockEventRegistrationRepository::new(),
+        ticketing,
+        notifier: crate::domain::notifications::RegistrationNotifier::new(),
+    };
+    let service = RefundApplicationService::new(
+        Arc::new(mocks.refunds.clone()),
+        Arc::new(mocks.tickets.clone()),
+        Arc::new(mocks.events.clone()),
+        Arc::new(mocks.registrations.clone()),
+        mocks.ticketing.clone(),
+        mocks.notifier.clone(),
+    )
+    .with_payments(Arc::new(mocks.payments.clone()));
+    (service, mocks)
+}
+
+pub struct PastEventMocks {
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+}
+
+pub fn create_mock_past_events_service() -> (PastEventsApplicationService, PastEventMocks) {
+    let mocks = PastEventMocks {
+        events: MockEventRepository::new(),
+        registrations: MockEventRegistrationRepository::new(),
+    };
+    let service = PastEventsApplicationService::new(Arc::new(MockEventSummaryRepository::new(
+        mocks.events.clone(),
+        mocks.registrations.clone(),
+    )));
+    (service, mocks)
+}
+
+pub struct AnnualReportMocks {
+    pub analytics: MockAnalyticsRepository,
+    pub companies: MockCompanyRepository,
+}
+
+pub fn create_mock_annual_report_service() -> (AnnualReportApplicationService, AnnualReportMocks) {
+    let mocks = AnnualReportMocks {
+        analytics: MockAnalyticsRepository::new(),
+        companies: MockCompanyRepository::new(),
+    };
+    let service = AnnualReportApplicationService::new(
+        Arc::new(mocks.analytics.clone()),
+        Arc::new(mocks.companies.clone()),
+    );
+    (service, mocks)
+}
+
 // ============================================================================
 // Test Scenario Helpers
 // ============================================================================
@@ -53,6 +53,12 @@ impl EventRepository for MockEventRepository {
         Ok(events.get(&id).cloned())
     }
 
+    async fn find_by_slug(&self, slug: &str) -> DomainResult<Option<Event>> {
+        self.check_failure().await?;
+        let events = self.events.lock().await;
+        Ok(events.values().find(|e| e.slug == slug).cloned())
+    }
+
     async fn find_by_filter(
         &self,
         filter: &EventFilter,
@@ -71,6 +77,42 @@ impl EventRepository for MockEventRepository {
             filtered_events.retain(|e| e.organizer_id == organizer_id);
         }
 
+        if let Some(co_organizer_id) = filter.co_organizer_id {
+            filtered_events.retain(|e| e.co_organizers.contains(&co_organizer_id));
+        }
+
+        if let Some(is_private) = filter.is_private {
+            filtered_events.retain(|e| e.is_private == is_private);
+        }
+
+        if let Some(status) = &filter.status {
+            filtered_events.retain(|e| e.status == *status);
+        }
+
+        if let Some(start_from) = filter.start_date_from {
+            filtered_events.retain(|e| e.start_date >= start_from);
+        }
+
+        if let Some(start_to) = filter.start_date_to {
+            filtered_events.retain(|e| e.start_date <= start_to);
+        }
+
+        if let Some(near) = filter.near {
+            filtered_events.retain(|e| match (e.latitude, e.longitude) {
+                (Some(latitude), Some(longitude)) => near.contains(latitude, longitude),
+                _ => false,
+            });
+        }
+
+        if let Some(county) = &filter.county {
+            filtered_events.retain(|e| e.region.as_ref().is_some_and(|r| r.county.eq_ignore_ascii_case(county)));
+        }
+
+        if let Some(municipality) = &filter.municipality {
+            filtered_events
+                .retain(|e| e.region.as_ref().is_some_and(|r| r.municipality.eq_ignore_ascii_case(municipality)));
+        }
+
         // Sort by created_at desc
         filtered_events.sort_by(|a, b| b.created_at.cmp(&a.created_at));
 
@@ -101,6 +143,12 @@ impl EventRepository for MockEventRepository {
             location_type: None,
             start_date_from: None,
             start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
         };
         self.find_by_filter(&filter, pagination).await
     }
@@ -153,6 +201,12 @@ impl EventRepository for MockEventRepository {
             location_type: None,
             start_date_from: None,
             start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
         };
         self.find_by_filter(&filter, pagination).await
     }
@@ -161,6 +215,14 @@ impl EventRepository for MockEventRepository {
         self.check_failure().await?;
         Ok(self.events.lock().await.contains_key(&id))
     }
+
+    async fn find_due_for_publishing(&self, now: chrono::DateTime<chrono::Utc>) -> DomainResult<Vec<Event>> {
+        self.check_failure().await?;
+        let events = self.events.lock().await;
+        let mut due: Vec<Event> = events.values().filter(|e| e.is_due_for_publishing(now)).cloned().collect();
+        due.sort_by_key(|e| e.publish_at);
+        Ok(due)
+    }
 }
 
 // ============================================================================
@@ -230,6 +292,12 @@ impl UserRepository for MockUserRepository {
             .cloned())
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<User>> {
+        self.check_failure().await?;
+        let users = self.users.lock().await;
+        Ok(ids.iter().filter_map(|id| users.get(id).cloned()).collect())
+    }
+
     async fn create(&self, user: &User) -> DomainResult<()> {
         self.check_failure().await?;
         let mut users = self.users.lock().await;
@@ -290,6 +358,25 @@ impl UserRepository for MockUserRepository {
         Ok(PaginatedResult::new(page_users, total_count, pagination))
     }
 
+    async fn search(&self, query: &str, limit: i64) -> DomainResult<Vec<UserMatch>> {
+        self.check_failure().await?;
+        let query = query.trim().to_lowercase();
+        let mut found: Vec<User> = self
+            .users
+            .lock()
+            .await
+            .values()
+            .filter(|u| u.is_active)
+            .filter(|u| u.name.to_lowercase().contains(&query) || u.email.to_lowercase().contains(&query))
+            .cloned()
+            .collect();
+
+        // Prefix matches first, as the database does
+        found.sort_by_key(|u| (!u.name.to_lowercase().starts_with(&query), u.name.clone()));
+        found.truncate(limit.max(0) as usize);
+        Ok(found.into_iter().map(|user| UserMatch { user, company_name: None }).collect())
+    }
+
     async fn exists(&self, id: Uuid) -> DomainResult<bool> {
         self.check_failure().await?;
         Ok(self.users.lock().await.contains_key(&id))
@@ -346,6 +433,12 @@ impl EventCategoryRepository for MockEventCategoryRepository {
         Ok(categories.get(id).cloned())
     }
 
+    async fn find_by_ids(&self, ids: &[String]) -> DomainResult<Vec<EventCategory>> {
+        self.check_failure().await?;
+        let categories = self.categories.lock().await;
+        Ok(ids.iter().filter_map(|id| categories.get(id).cloned()).collect())
+    }
+
     async fn list_active(&self) -> DomainResult<Vec<EventCategory>> {
         self.check_failure().await?;
         let categories = self.categories.lock().await;
@@ -692,12 +785,55 @@ impl EventRegistrationRepository for MockEventRegistrationRepository {
             .cloned())
     }
 
+    async fn count_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<RegistrationCounts>> {
+        self.check_failure().await?;
+        let registrations = self.registrations.lock().await;
+        let mut counts: HashMap<Uuid, RegistrationCounts> = HashMap::new();
+
+        for registration in registrations.values().filter(|r| event_ids.contains(&r.event_id)) {
+            let entry = counts
+                .entry(registration.event_id)
+                .or_insert_with(|| RegistrationCounts::empty(registration.event_id));
+            match registration.status {
+                RegistrationStatus::Registered => entry.registered += 1,
+                RegistrationStatus::Waitlisted => entry.waitlisted += 1,
+                RegistrationStatus::Attended => entry.attended += 1,
+                RegistrationStatus::Cancelled => entry.cancelled += 1,
+                RegistrationStatus::NoShow => {}
+            }
+        }
+
+        Ok(counts.into_values().collect())
+    }
+
     async fn create(&self, registration: &EventRegistration) -> DomainResult<()> {
         self.check_failure().await?;
         self.add_registration(registration.clone()).await;
         Ok(())
     }
 
+    async fn create_within_capacity(&self, registration: &EventRegistration, capacity: i32) -> DomainResult<bool> {
+        self.check_failure().await?;
+        let mut registrations = self.registrations.lock().await;
+        let seats = registrations
+            .values()
+            .filter(|r| {
+                r.event_id == registration.event_id
+                    && matches!(r.status, RegistrationStatus::Registered | RegistrationStatus::Attended)
+            })
+            .count();
+        if seats >= capacity.max(0) as usize {
+            return Ok(false);
+        }
+
+        self.by_event.lock().await.entry(registration.event_id).or_default().push(registration.id);
+        if let Some(user_id) = registration.user_id {
+            self.by_user.lock().await.entry(user_id).or_default().push(registration.id);
+        }
+        registrations.insert(registration.id, registration.clone());
+        Ok(true)
+    }
+
     async fn update(&self, registration: &EventRegistration) -> DomainResult<()> {
         self.check_failure().await?;
         let mut registrations = self.registrations.lock().await;
@@ -719,3 +855,4096 @@ impl EventRegistrationRepository for MockEventRegistrationRepository {
         }
     }
 }
+
+// ============================================================================
+// Mock Audit Log Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockAuditLogRepository {
+    pub entries: Arc<Mutex<Vec<AuditLogEntry>>>,
+    pub should_fail: Arc<Mutex<bool>>,
+}
+
+impl MockAuditLogRepository {
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(Vec::new())),
+            should_fail: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_should_fail(&self, should_fail: bool) {
+        *self.should_fail.lock().await = should_fail;
+    }
+
+    async fn check_failure(&self) -> DomainResult<()> {
+        if *self.should_fail.lock().await {
+            return Err(DomainError::business_rule("Mock failure"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for MockAuditLogRepository {
+    async fn create(&self, entry: &AuditLogEntry) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.entries.lock().await.push(entry.clone());
+        Ok(())
+    }
+
+    async fn find_by_filter(
+        &self,
+        filter: &AuditLogFilter,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<AuditLogEntry>> {
+        self.check_failure().await?;
+        let entries = self.entries.lock().await;
+        let matching: Vec<AuditLogEntry> = entries
+            .iter()
+            .rev()
+            .filter(|e| filter.table_name.as_ref().is_none_or(|t| &e.table_name == t))
+            .filter(|e| filter.record_id.as_ref().is_none_or(|r| &e.record_id == r))
+            .filter(|e| filter.user_id.is_none_or(|u| e.user_id == Some(u)))
+            .filter(|e| filter.action.as_ref().is_none_or(|a| &e.action == a))
+            .cloned()
+            .collect();
+
+        let total = matching.len() as i64;
+        let items = matching
+            .into_iter()
+            .skip(pagination.offset as usize)
+            .take(pagination.limit as usize)
+            .collect();
+        Ok(PaginatedResult::new(items, total, pagination))
+    }
+}
+
+// ============================================================================
+// Mock Company Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockCompanyRepository {
+    pub companies: Arc<Mutex<HashMap<Uuid, Company>>>,
+    /// Active members per company, set directly since the mock has no users
+    pub member_counts: Arc<Mutex<HashMap<Uuid, i64>>>,
+    pub should_fail: Arc<Mutex<bool>>,
+}
+
+impl MockCompanyRepository {
+    pub fn new() -> Self {
+        Self {
+            companies: Arc::new(Mutex::new(HashMap::new())),
+            member_counts: Arc::new(Mutex::new(HashMap::new())),
+            should_fail: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn add_company(&self, company: Company, members: i64) {
+        self.member_counts.lock().await.insert(company.id, members);
+        self.companies.lock().await.insert(company.id, company);
+    }
+
+    pub async fn set_should_fail(&self, should_fail: bool) {
+        *self.should_fail.lock().await = should_fail;
+    }
+
+    async fn check_failure(&self) -> DomainResult<()> {
+        if *self.should_fail.lock().await {
+            return Err(DomainError::business_rule("Mock failure"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CompanyRepository for MockCompanyRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Company>> {
+        self.check_failure().await?;
+        Ok(self.companies.lock().await.get(&id).cloned())
+    }
+
+    async fn find_by_org_number(&self, org_number: &str) -> DomainResult<Option<Company>> {
+        self.check_failure().await?;
+        let companies = self.companies.lock().await;
+        Ok(companies
+            .values()
+            .find(|c| c.org_number.as_deref() == Some(org_number))
+            .cloned())
+    }
+
+    async fn create(&self, company: &Company) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.companies.lock().await.insert(company.id, company.clone());
+        Ok(())
+    }
+
+    async fn update(&self, company: &Company) -> DomainResult<()> {
+        self.check_failure().await?;
+        match self.companies.lock().await.get_mut(&company.id) {
+            Some(existing) => {
+                *existing = company.clone();
+                Ok(())
+            }
+            None => Err(DomainError::not_found("Company", company.id)),
+        }
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.check_failure().await?;
+        match self.companies.lock().await.remove(&id) {
+            Some(_) => Ok(()),
+            None => Err(DomainError::not_found("Company", id)),
+        }
+    }
+
+    async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<Company>> {
+        self.find_by_filter(&CompanyFilter::default(), pagination).await
+    }
+
+    async fn find_by_filter(
+        &self,
+        filter: &CompanyFilter,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<Company>> {
+        self.check_failure().await?;
+        let companies = self.companies.lock().await;
+        let mut matching: Vec<Company> = companies
+            .values()
+            .filter(|c| {
+                filter
+                    .industry_type
+                    .as_ref()
+                    .is_none_or(|industry| industry.as_str() == c.industry_type.as_str())
+            })
+            .cloned()
+            .collect();
+        matching.sort_by_key(|c| c.name.to_lowercase());
+
+        let total = matching.len() as i64;
+        let items = matching
+            .into_iter()
+            .skip(pagination.offset as usize)
+            .take(pagination.limit as usize)
+            .collect();
+        Ok(PaginatedResult::new(items, total, pagination))
+    }
+
+    async fn count_members_by_company_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<CompanyMemberCount>> {
+        self.check_failure().await?;
+        let member_counts = self.member_counts.lock().await;
+        Ok(ids
+            .iter()
+            .filter_map(|id| {
+                member_counts
+                    .get(id)
+                    .filter(|members| **members > 0)
+                    .map(|members| CompanyMemberCount { company_id: *id, members: *members })
+            })
+            .collect())
+    }
+}
+
+// ============================================================================
+// Mock Event Comment Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventCommentRepository {
+    /// Kept in insertion order, which stands in for `created_at` ordering
+    pub comments: Arc<Mutex<Vec<EventComment>>>,
+    pub should_fail: Arc<Mutex<bool>>,
+}
+
+impl MockEventCommentRepository {
+    pub fn new() -> Self {
+        Self {
+            comments: Arc::new(Mutex::new(Vec::new())),
+            should_fail: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_should_fail(&self, should_fail: bool) {
+        *self.should_fail.lock().await = should_fail;
+    }
+
+    async fn check_failure(&self) -> DomainResult<()> {
+        if *self.should_fail.lock().await {
+            return Err(DomainError::business_rule("Mock failure"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventCommentRepository for MockEventCommentRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventComment>> {
+        self.check_failure().await?;
+        Ok(self.comments.lock().await.iter().find(|c| c.id == id).cloned())
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventComment>> {
+        self.check_failure().await?;
+        Ok(self
+            .comments
+            .lock()
+            .await
+            .iter()
+            .filter(|c| c.event_id == event_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, comment: &EventComment) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.comments.lock().await.push(comment.clone());
+        Ok(())
+    }
+
+    async fn soft_delete(
+        &self,
+        id: Uuid,
+        deleted_by: Uuid,
+        deleted_at: chrono::DateTime<chrono::Utc>,
+    ) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut comments = self.comments.lock().await;
+        let comment = comments
+            .iter_mut()
+            .find(|c| c.id == id)
+            .ok_or_else(|| DomainError::not_found("EventComment", id))?;
+        if comment.deleted_at.is_none() {
+            comment.deleted_at = Some(deleted_at);
+            comment.deleted_by = Some(deleted_by);
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Event Question Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventQuestionRepository {
+    /// Kept in insertion order, which stands in for `created_at` ordering
+    pub questions: Arc<Mutex<Vec<EventQuestion>>>,
+    /// (question_id, user_id) pairs
+    pub upvotes: Arc<Mutex<Vec<(Uuid, Uuid)>>>,
+}
+
+impl MockEventQuestionRepository {
+    pub fn new() -> Self {
+        Self {
+            questions: Arc::new(Mutex::new(Vec::new())),
+            upvotes: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    async fn with_count(&self, mut question: EventQuestion) -> EventQuestion {
+        question.upvote_count = self
+            .upvotes
+            .lock()
+            .await
+            .iter()
+            .filter(|(question_id, _)| *question_id == question.id)
+            .count() as i64;
+        question
+    }
+}
+
+#[async_trait]
+impl EventQuestionRepository for MockEventQuestionRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventQuestion>> {
+        let question = self.questions.lock().await.iter().find(|q| q.id == id).cloned();
+        match question {
+            Some(question) => Ok(Some(self.with_count(question).await)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventQuestion>> {
+        let questions: Vec<EventQuestion> = self
+            .questions
+            .lock()
+            .await
+            .iter()
+            .filter(|q| q.event_id == event_id)
+            .cloned()
+            .collect();
+        let mut counted = Vec::with_capacity(questions.len());
+        for question in questions {
+            counted.push(self.with_count(question).await);
+        }
+        Ok(counted)
+    }
+
+    async fn create(&self, question: &EventQuestion) -> DomainResult<()> {
+        self.questions.lock().await.push(question.clone());
+        Ok(())
+    }
+
+    async fn answer(
+        &self,
+        id: Uuid,
+        answer: &str,
+        answered_by: Uuid,
+        answered_at: chrono::DateTime<chrono::Utc>,
+    ) -> DomainResult<()> {
+        let mut questions = self.questions.lock().await;
+        let question = questions
+            .iter_mut()
+            .find(|q| q.id == id)
+            .ok_or_else(|| DomainError::not_found("EventQuestion", id))?;
+        question.answer = Some(answer.to_string());
+        question.answered_by = Some(answered_by);
+        question.answered_at = Some(answered_at);
+        Ok(())
+    }
+
+    async fn add_upvote(&self, question_id: Uuid, user_id: Uuid) -> DomainResult<bool> {
+        let mut upvotes = self.upvotes.lock().await;
+        if upvotes.contains(&(question_id, user_id)) {
+            return Ok(false);
+        }
+        upvotes.push((question_id, user_id));
+        Ok(true)
+    }
+
+    async fn remove_upvote(&self, question_id: Uuid, user_id: Uuid) -> DomainResult<bool> {
+        let mut upvotes = self.upvotes.lock().await;
+        let before = upvotes.len();
+        upvotes.retain(|upvote| *upvote != (question_id, user_id));
+        Ok(upvotes.len() != before)
+    }
+
+    async fn find_upvoted_by_user(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Vec<Uuid>> {
+        let questions = self.questions.lock().await;
+        Ok(self
+            .upvotes
+            .lock()
+            .await
+            .iter()
+            .filter(|(question_id, voter)| {
+                *voter == user_id && questions.iter().any(|q| q.id == *question_id && q.event_id == event_id)
+            })
+            .map(|(question_id, _)| *question_id)
+            .collect())
+    }
+}
+
+// ============================================================================
+// Mock Event Poll Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventPollRepository {
+    /// Stored without counts; they're filled in from `votes` on read
+    pub polls: Arc<Mutex<Vec<EventPoll>>>,
+    pub votes: Arc<Mutex<Vec<PollVote>>>,
+}
+
+impl MockEventPollRepository {
+    pub fn new() -> Self {
+        Self {
+            polls: Arc::new(Mutex::new(Vec::new())),
+            votes: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    async fn with_counts(&self, mut poll: EventPoll) -> EventPoll {
+        let votes = self.votes.lock().await;
+        for option in poll.options.iter_mut() {
+            option.vote_count = votes.iter().filter(|vote| vote.option_id == option.id).count() as i64;
+        }
+        poll
+    }
+}
+
+#[async_trait]
+impl EventPollRepository for MockEventPollRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventPoll>> {
+        let poll = self.polls.lock().await.iter().find(|p| p.id == id).cloned();
+        match poll {
+            Some(poll) => Ok(Some(self.with_counts(poll).await)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventPoll>> {
+        let polls: Vec<EventPoll> = self
+            .polls
+            .lock()
+            .await
+            .iter()
+            .filter(|p| p.event_id == event_id)
+            .cloned()
+            .collect();
+        let mut counted = Vec::with_capacity(polls.len());
+        for poll in polls {
+            counted.push(self.with_counts(poll).await);
+        }
+        Ok(counted)
+    }
+
+    async fn create(&self, poll: &EventPoll) -> DomainResult<()> {
+        self.polls.lock().await.push(poll.clone());
+        Ok(())
+    }
+
+    async fn close(&self, id: Uuid, closed_at: chrono::DateTime<chrono::Utc>) -> DomainResult<()> {
+        let mut polls = self.polls.lock().await;
+        let poll = polls
+            .iter_mut()
+            .find(|p| p.id == id)
+            .ok_or_else(|| DomainError::not_found("EventPoll", id))?;
+        poll.closed_at.get_or_insert(closed_at);
+        Ok(())
+    }
+
+    async fn cast_vote(&self, vote: &PollVote) -> DomainResult<()> {
+        let mut votes = self.votes.lock().await;
+        votes.retain(|v| !(v.poll_id == vote.poll_id && v.user_id == vote.user_id));
+        votes.push(vote.clone());
+        Ok(())
+    }
+
+    async fn find_votes_by_user(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Vec<PollVote>> {
+        let polls = self.polls.lock().await;
+        Ok(self
+            .votes
+            .lock()
+            .await
+            .iter()
+            .filter(|vote| {
+                vote.user_id == user_id && polls.iter().any(|p| p.id == vote.poll_id && p.event_id == event_id)
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+// ============================================================================
+// Mock Event Attachment Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventAttachmentRepository {
+    /// Kept in insertion order, which stands in for `uploaded_at` ordering
+    pub attachments: Arc<Mutex<Vec<EventAttachment>>>,
+    pub should_fail: Arc<Mutex<bool>>,
+}
+
+impl MockEventAttachmentRepository {
+    pub fn new() -> Self {
+        Self {
+            attachments: Arc::new(Mutex::new(Vec::new())),
+            should_fail: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_should_fail(&self, should_fail: bool) {
+        *self.should_fail.lock().await = should_fail;
+    }
+
+    async fn check_failure(&self) -> DomainResult<()> {
+        if *self.should_fail.lock().await {
+            return Err(DomainError::business_rule("Mock failure"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventAttachmentRepository for MockEventAttachmentRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventAttachment>> {
+        self.check_failure().await?;
+        Ok(self.attachments.lock().await.iter().find(|a| a.id == id).cloned())
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventAttachment>> {
+        self.check_failure().await?;
+        Ok(self
+            .attachments
+            .lock()
+            .await
+            .iter()
+            .filter(|a| a.event_id == event_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, attachment: &EventAttachment) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.attachments.lock().await.push(attachment.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut attachments = self.attachments.lock().await;
+        let before = attachments.len();
+        attachments.retain(|a| a.id != id);
+        if attachments.len() == before {
+            return Err(DomainError::not_found("EventAttachment", id));
+        }
+        Ok(())
+    }
+
+    async fn record_download(&self, id: Uuid, _downloaded_at: chrono::DateTime<chrono::Utc>) -> DomainResult<()> {
+        self.check_failure().await?;
+        if let Some(attachment) = self.attachments.lock().await.iter_mut().find(|a| a.id == id) {
+            attachment.download_count += 1;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Event Photo Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventPhotoRepository {
+    /// Kept in insertion order, which stands in for `uploaded_at` ordering
+    pub photos: Arc<Mutex<Vec<EventPhoto>>>,
+    pub albums: Arc<Mutex<HashMap<Uuid, PhotoAlbum>>>,
+    pub should_fail: Arc<Mutex<bool>>,
+}
+
+impl MockEventPhotoRepository {
+    pub fn new() -> Self {
+        Self {
+            photos: Arc::new(Mutex::new(Vec::new())),
+            albums: Arc::new(Mutex::new(HashMap::new())),
+            should_fail: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_should_fail(&self, should_fail: bool) {
+        *self.should_fail.lock().await = should_fail;
+    }
+
+    async fn check_failure(&self) -> DomainResult<()> {
+        if *self.should_fail.lock().await {
+            return Err(DomainError::business_rule("Mock failure"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventPhotoRepository for MockEventPhotoRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventPhoto>> {
+        self.check_failure().await?;
+        Ok(self.photos.lock().await.iter().find(|p| p.id == id).cloned())
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventPhoto>> {
+        self.check_failure().await?;
+        Ok(self
+            .photos
+            .lock()
+            .await
+            .iter()
+            .filter(|p| p.event_id == event_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, photo: &EventPhoto) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.photos.lock().await.push(photo.clone());
+        Ok(())
+    }
+
+    async fn update_status(&self, id: Uuid, status: PhotoStatus, reviewed_at: chrono::DateTime<chrono::Utc>) -> DomainResult<()> {
+        self.check_failure().await?;
+        if let Some(photo) = self.photos.lock().await.iter_mut().find(|p| p.id == id) {
+            photo.status = status;
+            photo.reviewed_at = Some(reviewed_at);
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut photos = self.photos.lock().await;
+        let before = photos.len();
+        photos.retain(|p| p.id != id);
+        if photos.len() == before {
+            return Err(DomainError::not_found("EventPhoto", id));
+        }
+        Ok(())
+    }
+
+    async fn find_album(&self, event_id: Uuid) -> DomainResult<Option<PhotoAlbum>> {
+        self.check_failure().await?;
+        Ok(self.albums.lock().await.get(&event_id).cloned())
+    }
+
+    async fn save_album(&self, album: &PhotoAlbum) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut albums = self.albums.lock().await;
+        match albums.get_mut(&album.event_id) {
+            // Like the table's unique event index, a second save keeps the first album's identity
+            Some(existing) => {
+                existing.attendee_uploads = album.attendee_uploads;
+                existing.requires_approval = album.requires_approval;
+                existing.is_public = album.is_public;
+                existing.updated_at = album.updated_at;
+            }
+            None => {
+                albums.insert(album.event_id, album.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Event Budget Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventBudgetRepository {
+    /// Kept in insertion order, which stands in for `created_at` ordering
+    pub lines: Arc<Mutex<Vec<BudgetLine>>>,
+    pub expenses: Arc<Mutex<Vec<EventExpense>>>,
+    pub should_fail: Arc<Mutex<bool>>,
+}
+
+impl MockEventBudgetRepository {
+    pub fn new() -> Self {
+        Self {
+            lines: Arc::new(Mutex::new(Vec::new())),
+            expenses: Arc::new(Mutex::new(Vec::new())),
+            should_fail: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_should_fail(&self, should_fail: bool) {
+        *self.should_fail.lock().await = should_fail;
+    }
+
+    async fn check_failure(&self) -> DomainResult<()> {
+        if *self.should_fail.lock().await {
+            return Err(DomainError::business_rule("Mock failure"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventBudgetRepository for MockEventBudgetRepository {
+    async fn find_line_by_id(&self, id: Uuid) -> DomainResult<Option<BudgetLine>> {
+        self.check_failure().await?;
+        Ok(self.lines.lock().await.iter().find(|l| l.id == id).cloned())
+    }
+
+    async fn find_lines_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<BudgetLine>> {
+        self.check_failure().await?;
+        Ok(self
+            .lines
+            .lock()
+            .await
+            .iter()
+            .filter(|l| l.event_id == event_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create_line(&self, line: &BudgetLine) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.lines.lock().await.push(line.clone());
+        Ok(())
+    }
+
+    async fn update_line(&self, line: &BudgetLine) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut lines = self.lines.lock().await;
+        let existing = lines
+            .iter_mut()
+            .find(|l| l.id == line.id)
+            .ok_or_else(|| DomainError::not_found("BudgetLine", line.id))?;
+        *existing = line.clone();
+        Ok(())
+    }
+
+    async fn delete_line(&self, id: Uuid) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut lines = self.lines.lock().await;
+        let before = lines.len();
+        lines.retain(|l| l.id != id);
+        if lines.len() == before {
+            return Err(DomainError::not_found("BudgetLine", id));
+        }
+        for expense in self.expenses.lock().await.iter_mut() {
+            if expense.budget_line_id == Some(id) {
+                expense.budget_line_id = None;
+            }
+        }
+        Ok(())
+    }
+
+    async fn find_expense_by_id(&self, id: Uuid) -> DomainResult<Option<EventExpense>> {
+        self.check_failure().await?;
+        Ok(self.expenses.lock().await.iter().find(|e| e.id == id).cloned())
+    }
+
+    async fn find_expenses_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventExpense>> {
+        self.check_failure().await?;
+        let mut expenses: Vec<EventExpense> = self
+            .expenses
+            .lock()
+            .await
+            .iter()
+            .filter(|e| e.event_id == event_id)
+            .cloned()
+            .collect();
+        expenses.sort_by_key(|e| e.incurred_on);
+        Ok(expenses)
+    }
+
+    async fn create_expense(&self, expense: &EventExpense) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.expenses.lock().await.push(expense.clone());
+        Ok(())
+    }
+
+    async fn update_expense(&self, expense: &EventExpense) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut expenses = self.expenses.lock().await;
+        let existing = expenses
+            .iter_mut()
+            .find(|e| e.id == expense.id)
+            .ok_or_else(|| DomainError::not_found("EventExpense", expense.id))?;
+        *existing = expense.clone();
+        Ok(())
+    }
+
+    async fn delete_expense(&self, id: Uuid) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut expenses = self.expenses.lock().await;
+        let before = expenses.len();
+        expenses.retain(|e| e.id != id);
+        if expenses.len() == before {
+            return Err(DomainError::not_found("EventExpense", id));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Event Sponsor Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventSponsorRepository {
+    pub sponsors: Arc<Mutex<Vec<EventSponsor>>>,
+    pub should_fail: Arc<Mutex<bool>>,
+}
+
+impl MockEventSponsorRepository {
+    pub fn new() -> Self {
+        Self {
+            sponsors: Arc::new(Mutex::new(Vec::new())),
+            should_fail: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_should_fail(&self, should_fail: bool) {
+        *self.should_fail.lock().await = should_fail;
+    }
+
+    async fn check_failure(&self) -> DomainResult<()> {
+        if *self.should_fail.lock().await {
+            return Err(DomainError::business_rule("Mock failure"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventSponsorRepository for MockEventSponsorRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventSponsor>> {
+        self.check_failure().await?;
+        Ok(self.sponsors.lock().await.iter().find(|s| s.id == id).cloned())
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventSponsor>> {
+        self.check_failure().await?;
+        let mut sponsors: Vec<EventSponsor> = self
+            .sponsors
+            .lock()
+            .await
+            .iter()
+            .filter(|s| s.event_id == event_id)
+            .cloned()
+            .collect();
+        EventSponsor::sort_for_display(&mut sponsors);
+        Ok(sponsors)
+    }
+
+    async fn create(&self, sponsor: &EventSponsor) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.sponsors.lock().await.push(sponsor.clone());
+        Ok(())
+    }
+
+    async fn update(&self, sponsor: &EventSponsor) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut sponsors = self.sponsors.lock().await;
+        let existing = sponsors
+            .iter_mut()
+            .find(|s| s.id == sponsor.id)
+            .ok_or_else(|| DomainError::not_found("EventSponsor", sponsor.id))?;
+        *existing = sponsor.clone();
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut sponsors = self.sponsors.lock().await;
+        let before = sponsors.len();
+        sponsors.retain(|s| s.id != id);
+        if sponsors.len() == before {
+            return Err(DomainError::not_found("EventSponsor", id));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Short Link Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockShortLinkRepository {
+    pub links: Arc<Mutex<Vec<ShortLink>>>,
+    pub clicks: Arc<Mutex<Vec<ShortLinkClick>>>,
+}
+
+impl MockShortLinkRepository {
+    pub fn new() -> Self {
+        Self {
+            links: Arc::new(Mutex::new(Vec::new())),
+            clicks: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn totals(link_id: Uuid, clicks: &[&ShortLinkClick]) -> ShortLinkClicks {
+        ShortLinkClicks {
+            link_id,
+            clicks: clicks.len() as i64,
+            last_clicked_at: clicks.iter().map(|c| c.clicked_at).max(),
+        }
+    }
+}
+
+#[async_trait]
+impl ShortLinkRepository for MockShortLinkRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<ShortLink>> {
+        Ok(self.links.lock().await.iter().find(|l| l.id == id).cloned())
+    }
+
+    async fn find_by_code(&self, code: &str) -> DomainResult<Option<ShortLink>> {
+        Ok(self.links.lock().await.iter().find(|l| l.code == code).cloned())
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<ShortLink>> {
+        let mut links: Vec<ShortLink> = self
+            .links
+            .lock()
+            .await
+            .iter()
+            .filter(|l| l.event_id == event_id)
+            .cloned()
+            .collect();
+        links.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(links)
+    }
+
+    async fn create(&self, link: &ShortLink) -> DomainResult<()> {
+        let mut links = self.links.lock().await;
+        if links.iter().any(|l| l.code == link.code) {
+            return Err(DomainError::conflict("Short link code is taken"));
+        }
+        links.push(link.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        let mut links = self.links.lock().await;
+        let before = links.len();
+        links.retain(|l| l.id != id);
+        if links.len() == before {
+            return Err(DomainError::not_found("ShortLink", id));
+        }
+        self.clicks.lock().await.retain(|c| c.link_id != id);
+        Ok(())
+    }
+
+    async fn record_click(&self, click: &ShortLinkClick) -> DomainResult<()> {
+        self.clicks.lock().await.push(click.clone());
+        Ok(())
+    }
+
+    async fn click_totals(&self, event_id: Uuid) -> DomainResult<Vec<ShortLinkClicks>> {
+        let link_ids: Vec<Uuid> = self.find_by_event_id(event_id).await?.iter().map(|l| l.id).collect();
+        let clicks = self.clicks.lock().await;
+        Ok(link_ids
+            .into_iter()
+            .filter_map(|link_id| {
+                let link_clicks: Vec<&ShortLinkClick> = clicks.iter().filter(|c| c.link_id == link_id).collect();
+                (!link_clicks.is_empty()).then(|| Self::totals(link_id, &link_clicks))
+            })
+            .collect())
+    }
+
+    async fn stats(&self, link_id: Uuid, max_referrers: i64) -> DomainResult<ShortLinkStats> {
+        let clicks = self.clicks.lock().await;
+        let link_clicks: Vec<&ShortLinkClick> = clicks.iter().filter(|c| c.link_id == link_id).collect();
+        let totals = Self::totals(link_id, &link_clicks);
+
+        let mut daily: Vec<DailyClicks> = Vec::new();
+        let mut referrers: Vec<ReferrerClicks> = Vec::new();
+        for click in &link_clicks {
+            let date = click.clicked_at.date_naive();
+            match daily.iter_mut().find(|d| d.date == date) {
+                Some(day) => day.clicks += 1,
+                None => daily.push(DailyClicks { date, clicks: 1 }),
+            }
+            let host = click.referrer_host.clone().unwrap_or_default();
+            match referrers.iter_mut().find(|r| r.host == host) {
+                Some(referrer) => referrer.clicks += 1,
+                None => referrers.push(ReferrerClicks { host, clicks: 1 }),
+            }
+        }
+        daily.sort_by_key(|d| d.date);
+        referrers.sort_by(|a, b| b.clicks.cmp(&a.clicks).then_with(|| a.host.cmp(&b.host)));
+        referrers.truncate(max_referrers as usize);
+
+        let mut invitations: Vec<Uuid> = link_clicks.iter().filter_map(|c| c.invitation_id).collect();
+        let invitation_clicks = invitations.len() as i64;
+        invitations.sort();
+        invitations.dedup();
+        Ok(ShortLinkStats {
+            link_id,
+            clicks: totals.clicks,
+            last_clicked_at: totals.last_clicked_at,
+            invitation_clicks,
+            invitations_clicked: invitations.len() as i64,
+            daily,
+            referrers,
+        })
+    }
+}
+
+// ============================================================================
+// Mock Overbooking Policy Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockOverbookingPolicyRepository {
+    pub policies: Arc<Mutex<HashMap<Uuid, OverbookingPolicy>>>,
+}
+
+impl MockOverbookingPolicyRepository {
+    pub fn new() -> Self {
+        Self {
+            policies: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl OverbookingPolicyRepository for MockOverbookingPolicyRepository {
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Option<OverbookingPolicy>> {
+        Ok(self.policies.lock().await.get(&event_id).cloned())
+    }
+
+    async fn save(&self, policy: &OverbookingPolicy) -> DomainResult<()> {
+        self.policies.lock().await.insert(policy.event_id, policy.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, event_id: Uuid) -> DomainResult<()> {
+        self.policies.lock().await.remove(&event_id);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Ticket Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockTicketRepository {
+    pub ticket_types: Arc<Mutex<HashMap<Uuid, TicketType>>>,
+    pub orders: Arc<Mutex<Vec<TicketOrder>>>,
+}
+
+impl MockTicketRepository {
+    pub fn new() -> Self {
+        Self {
+            ticket_types: Arc::new(Mutex::new(HashMap::new())),
+            orders: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn sold(orders: &[TicketOrder], ticket_type_id: Uuid) -> i64 {
+        orders
+            .iter()
+            .filter(|order| order.ticket_type_id == ticket_type_id && order.status == OrderStatus::Confirmed)
+            .map(|order| order.quantity as i64)
+            .sum()
+    }
+
+    fn newest_first(mut orders: Vec<TicketOrder>) -> Vec<TicketOrder> {
+        orders.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        orders
+    }
+}
+
+#[async_trait]
+impl TicketRepository for MockTicketRepository {
+    async fn find_ticket_type(&self, id: Uuid) -> DomainResult<Option<TicketType>> {
+        Ok(self.ticket_types.lock().await.get(&id).cloned())
+    }
+
+    async fn find_ticket_types_by_event(&self, event_id: Uuid) -> DomainResult<Vec<TicketType>> {
+        let mut ticket_types: Vec<TicketType> = self
+            .ticket_types
+            .lock()
+            .await
+            .values()
+            .filter(|ticket_type| ticket_type.event_id == event_id)
+            .cloned()
+            .collect();
+        ticket_types.sort_by(|a, b| a.price.cmp(&b.price).then_with(|| a.name.cmp(&b.name)));
+        Ok(ticket_types)
+    }
+
+    async fn create_ticket_type(&self, ticket_type: &TicketType) -> DomainResult<()> {
+        self.ticket_types.lock().await.insert(ticket_type.id, ticket_type.clone());
+        Ok(())
+    }
+
+    async fn update_ticket_type(&self, ticket_type: &TicketType) -> DomainResult<()> {
+        let mut ticket_types = self.ticket_types.lock().await;
+        if !ticket_types.contains_key(&ticket_type.id) {
+            return Err(DomainError::not_found("TicketType", ticket_type.id));
+        }
+        ticket_types.insert(ticket_type.id, ticket_type.clone());
+        Ok(())
+    }
+
+    async fn delete_ticket_type(&self, id: Uuid) -> DomainResult<()> {
+        if self.orders.lock().await.iter().any(|order| order.ticket_type_id == id) {
+            return Err(DomainError::conflict("Ticket type has orders; deactivate it instead"));
+        }
+        self.ticket_types
+            .lock()
+            .await
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| DomainError::not_found("TicketType", id))
+    }
+
+    async fn count_sold(&self, ticket_type_id: Uuid) -> DomainResult<i64> {
+        Ok(Self::sold(&self.orders.lock().await, ticket_type_id))
+    }
+
+    async fn find_order(&self, id: Uuid) -> DomainResult<Option<TicketOrder>> {
+        Ok(self.orders.lock().await.iter().find(|order| order.id == id).cloned())
+    }
+
+    async fn find_orders_by_event(&self, event_id: Uuid) -> DomainResult<Vec<TicketOrder>> {
+        let orders = self.orders.lock().await;
+        Ok(Self::newest_first(orders.iter().filter(|order| order.event_id == event_id).cloned().collect()))
+    }
+
+    async fn find_orders_by_buyer(&self, buyer_id: Uuid) -> DomainResult<Vec<TicketOrder>> {
+        let orders = self.orders.lock().await;
+        Ok(Self::newest_first(orders.iter().filter(|order| order.buyer_id == buyer_id).cloned().collect()))
+    }
+
+    async fn create_order_within_quantity(&self, order: &TicketOrder) -> DomainResult<bool> {
+        let ticket_types = self.ticket_types.lock().await;
+        let mut orders = self.orders.lock().await;
+        let Some(ticket_type) = ticket_types.get(&order.ticket_type_id) else {
+            return Ok(false);
+        };
+        if ticket_type
+            .quantity
+            .is_some_and(|quantity| Self::sold(&orders, ticket_type.id) + order.quantity as i64 > quantity as i64)
+        {
+            return Ok(false);
+        }
+        orders.push(order.clone());
+        Ok(true)
+    }
+
+    async fn update_order(&self, order: &TicketOrder) -> DomainResult<()> {
+        let mut orders = self.orders.lock().await;
+        let stored = orders
+            .iter_mut()
+            .find(|stored| stored.id == order.id)
+            .ok_or_else(|| DomainError::not_found("TicketOrder", order.id))?;
+        *stored = order.clone();
+        Ok(())
+    }
+
+    async fn revenue_by_currency(&self, event_id: Uuid) -> DomainResult<Vec<CurrencyRevenue>> {
+        let orders = self.orders.lock().await;
+        let mut revenue: Vec<CurrencyRevenue> = Vec::new();
+        for order in orders
+            .iter()
+            .filter(|order| order.event_id == event_id && order.status == OrderStatus::Confirmed)
+        {
+            let index = match revenue.iter().position(|entry| entry.currency == order.currency) {
+                Some(index) => index,
+                None => {
+                    revenue.push(CurrencyRevenue {
+                        currency: order.currency,
+                        orders: 0,
+                        tickets: 0,
+                        gross: 0,
+                        gross_nok: 0,
+                    });
+                    revenue.len() - 1
+                }
+            };
+            let entry = &mut revenue[index];
+            entry.orders += 1;
+            entry.tickets += order.quantity as i64;
+            entry.gross += order.total;
+            entry.gross_nok += order.total_nok;
+        }
+        revenue.sort_by_key(|entry| entry.currency.code());
+        Ok(revenue)
+    }
+
+    async fn vat_breakdown(
+        &self,
+        event_id: Option<Uuid>,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+    ) -> DomainResult<Vec<VatBreakdown>> {
+        let orders = self.orders.lock().await;
+        let in_period = |at: chrono::DateTime<chrono::Utc>| at >= from && at < to;
+        let mut breakdown: Vec<VatBreakdown> = Vec::new();
+        for order in orders.iter().filter(|order| event_id.is_none_or(|id| order.event_id == id)) {
+            let sold = in_period(order.created_at);
+            let refunded = order.cancelled_at.is_some_and(in_period);
+            if !sold && !refunded {
+                continue;
+            }
+            let index = match breakdown.iter().position(|entry| entry.vat_rate == order.vat_rate) {
+                Some(index) => index,
+                None => {
+                    breakdown.push(VatBreakdown {
+                        vat_rate: order.vat_rate,
+                        orders: 0,
+                        sales_nok: 0,
+                        sales_vat_nok: 0,
+                        refunded_orders: 0,
+                        refunds_nok: 0,
+                        refunds_vat_nok: 0,
+                    });
+                    breakdown.len() - 1
+                }
+            };
+            let entry = &mut breakdown[index];
+            if sold {
+                entry.orders += 1;
+                entry.sales_nok += order.total_nok;
+                entry.sales_vat_nok += order.vat_amount_nok;
+            }
+            if refunded {
+                entry.refunded_orders += 1;
+                entry.refunds_nok += order.total_nok;
+                entry.refunds_vat_nok += order.vat_amount_nok;
+            }
+        }
+        breakdown.sort_by_key(|entry| entry.vat_rate);
+        Ok(breakdown)
+    }
+}
+
+// ============================================================================
+// Mock Scheduling Policy Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockSchedulingPolicyRepository {
+    pub policies: Arc<Mutex<HashMap<Uuid, SchedulingPolicy>>>,
+    pub blackouts: Arc<Mutex<Vec<BlackoutPeriod>>>,
+    pub should_fail: Arc<Mutex<bool>>,
+}
+
+impl MockSchedulingPolicyRepository {
+    pub fn new() -> Self {
+        Self {
+            policies: Arc::new(Mutex::new(HashMap::new())),
+            blackouts: Arc::new(Mutex::new(Vec::new())),
+            should_fail: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_should_fail(&self, should_fail: bool) {
+        *self.should_fail.lock().await = should_fail;
+    }
+
+    async fn check_failure(&self) -> DomainResult<()> {
+        if *self.should_fail.lock().await {
+            return Err(DomainError::business_rule("Mock failure"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SchedulingPolicyRepository for MockSchedulingPolicyRepository {
+    async fn find_policy(&self, company_id: Uuid) -> DomainResult<Option<SchedulingPolicy>> {
+        self.check_failure().await?;
+        Ok(self.policies.lock().await.get(&company_id).cloned())
+    }
+
+    async fn save_policy(&self, policy: &SchedulingPolicy) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.policies.lock().await.insert(policy.company_id, policy.clone());
+        Ok(())
+    }
+
+    async fn find_blackout_by_id(&self, id: Uuid) -> DomainResult<Option<BlackoutPeriod>> {
+        self.check_failure().await?;
+        Ok(self.blackouts.lock().await.iter().find(|b| b.id == id).cloned())
+    }
+
+    async fn find_blackouts_by_company(&self, company_id: Uuid) -> DomainResult<Vec<BlackoutPeriod>> {
+        self.check_failure().await?;
+        let mut blackouts: Vec<BlackoutPeriod> = self
+            .blackouts
+            .lock()
+            .await
+            .iter()
+            .filter(|b| b.company_id == company_id)
+            .cloned()
+            .collect();
+        blackouts.sort_by_key(|b| (b.start_date, b.end_date));
+        Ok(blackouts)
+    }
+
+    async fn find_blackouts_overlapping(
+        &self,
+        company_id: Uuid,
+        first_day: chrono::NaiveDate,
+        last_day: chrono::NaiveDate,
+    ) -> DomainResult<Vec<BlackoutPeriod>> {
+        let mut blackouts = self.find_blackouts_by_company(company_id).await?;
+        blackouts.retain(|b| b.overlaps(first_day, last_day));
+        Ok(blackouts)
+    }
+
+    async fn create_blackout(&self, blackout: &BlackoutPeriod) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.blackouts.lock().await.push(blackout.clone());
+        Ok(())
+    }
+
+    async fn update_blackout(&self, blackout: &BlackoutPeriod) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut blackouts = self.blackouts.lock().await;
+        let existing = blackouts
+            .iter_mut()
+            .find(|b| b.id == blackout.id)
+            .ok_or_else(|| DomainError::not_found("BlackoutPeriod", blackout.id))?;
+        *existing = blackout.clone();
+        Ok(())
+    }
+
+    async fn delete_blackout(&self, id: Uuid) -> DomainResult<()> {
+        self.check_failure().await?;
+        let mut blackouts = self.blackouts.lock().await;
+        let before = blackouts.len();
+        blackouts.retain(|b| b.id != id);
+        if blackouts.len() == before {
+            return Err(DomainError::not_found("BlackoutPeriod", id));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Sending Domain Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockSendingDomainRepository {
+    pub sending_domains: Arc<Mutex<HashMap<Uuid, SendingDomain>>>,
+    pub should_fail: Arc<Mutex<bool>>,
+}
+
+impl MockSendingDomainRepository {
+    pub fn new() -> Self {
+        Self {
+            sending_domains: Arc::new(Mutex::new(HashMap::new())),
+            should_fail: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_should_fail(&self, should_fail: bool) {
+        *self.should_fail.lock().await = should_fail;
+    }
+
+    async fn check_failure(&self) -> DomainResult<()> {
+        if *self.should_fail.lock().await {
+            return Err(DomainError::business_rule("Mock failure"));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SendingDomainRepository for MockSendingDomainRepository {
+    async fn find_by_company(&self, company_id: Uuid) -> DomainResult<Option<SendingDomain>> {
+        self.check_failure().await?;
+        Ok(self.sending_domains.lock().await.get(&company_id).cloned())
+    }
+
+    async fn save(&self, sending_domain: &SendingDomain) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.sending_domains
+            .lock()
+            .await
+            .insert(sending_domain.company_id, sending_domain.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, company_id: Uuid) -> DomainResult<()> {
+        self.check_failure().await?;
+        self.sending_domains
+            .lock()
+            .await
+            .remove(&company_id)
+            .map(|_| ())
+            .ok_or_else(|| DomainError::not_found_by_field("SendingDomain", "company_id", &company_id.to_string()))
+    }
+}
+
+// ============================================================================
+// Mock Storage Quota Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockStorageQuotaRepository {
+    pub usage: Arc<Mutex<HashMap<Uuid, StorageUsage>>>,
+    pub quotas: Arc<Mutex<HashMap<Uuid, i64>>>,
+}
+
+impl MockStorageQuotaRepository {
+    pub fn new() -> Self {
+        Self {
+            usage: Arc::new(Mutex::new(HashMap::new())),
+            quotas: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub async fn set_usage(&self, company_id: Uuid, usage: StorageUsage) {
+        self.usage.lock().await.insert(company_id, usage);
+    }
+}
+
+#[async_trait]
+impl StorageQuotaRepository for MockStorageQuotaRepository {
+    async fn usage_by_company(&self, company_id: Uuid) -> DomainResult<StorageUsage> {
+        Ok(self.usage.lock().await.get(&company_id).copied().unwrap_or_default())
+    }
+
+    async fn find_quota(&self, company_id: Uuid) -> DomainResult<Option<i64>> {
+        Ok(self.quotas.lock().await.get(&company_id).copied())
+    }
+
+    async fn save_quota(&self, company_id: Uuid, quota_bytes: Option<i64>) -> DomainResult<()> {
+        let mut quotas = self.quotas.lock().await;
+        match quota_bytes {
+            Some(quota_bytes) => quotas.insert(company_id, quota_bytes),
+            None => quotas.remove(&company_id),
+        };
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Analytics Repository
+// ============================================================================
+
+/// Returns the years set with `set_year`, and empty years otherwise
+#[derive(Clone)]
+pub struct MockAnalyticsRepository {
+    pub years: Arc<Mutex<HashMap<(Uuid, i32), OrganizationYear>>>,
+}
+
+impl MockAnalyticsRepository {
+    pub fn new() -> Self {
+        Self {
+            years: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub async fn set_year(&self, year: OrganizationYear) {
+        self.years.lock().await.insert((year.company_id, year.year), year);
+    }
+}
+
+#[async_trait]
+impl AnalyticsRepository for MockAnalyticsRepository {
+    async fn organization_year(&self, company_id: Uuid, year: i32, _now: chrono::DateTime<chrono::Utc>) -> DomainResult<OrganizationYear> {
+        Ok(self
+            .years
+            .lock()
+            .await
+            .get(&(company_id, year))
+            .cloned()
+            .unwrap_or_else(|| OrganizationYear::empty(company_id, year)))
+    }
+}
+
+// ============================================================================
+// Mock Event Field Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventFieldRepository {
+    pub definitions: Arc<Mutex<Vec<EventFieldDefinition>>>,
+    pub values: Arc<Mutex<HashMap<Uuid, Vec<EventFieldValue>>>>,
+}
+
+impl MockEventFieldRepository {
+    pub fn new() -> Self {
+        Self {
+            definitions: Arc::new(Mutex::new(Vec::new())),
+            values: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl EventFieldRepository for MockEventFieldRepository {
+    async fn find_definition_by_id(&self, id: Uuid) -> DomainResult<Option<EventFieldDefinition>> {
+        Ok(self.definitions.lock().await.iter().find(|d| d.id == id).cloned())
+    }
+
+    async fn find_definitions_by_company(&self, company_id: Uuid) -> DomainResult<Vec<EventFieldDefinition>> {
+        let mut definitions: Vec<EventFieldDefinition> = self
+            .definitions
+            .lock()
+            .await
+            .iter()
+            .filter(|d| d.company_id == company_id)
+            .cloned()
+            .collect();
+        definitions.sort_by_key(|d| d.position);
+        Ok(definitions)
+    }
+
+    async fn create_definition(&self, definition: &EventFieldDefinition) -> DomainResult<()> {
+        self.definitions.lock().await.push(definition.clone());
+        Ok(())
+    }
+
+    async fn update_definition(&self, definition: &EventFieldDefinition) -> DomainResult<()> {
+        let mut definitions = self.definitions.lock().await;
+        let existing = definitions
+            .iter_mut()
+            .find(|d| d.id == definition.id)
+            .ok_or_else(|| DomainError::not_found("EventFieldDefinition", definition.id))?;
+        *existing = definition.clone();
+        Ok(())
+    }
+
+    async fn delete_definition(&self, id: Uuid) -> DomainResult<()> {
+        self.definitions.lock().await.retain(|d| d.id != id);
+        for values in self.values.lock().await.values_mut() {
+            values.retain(|v| v.field_id != id);
+        }
+        Ok(())
+    }
+
+    async fn find_values(&self, event_id: Uuid) -> DomainResult<Vec<EventFieldValue>> {
+        Ok(self.values.lock().await.get(&event_id).cloned().unwrap_or_default())
+    }
+
+    async fn replace_values(&self, event_id: Uuid, values: &[EventFieldValue]) -> DomainResult<()> {
+        self.values.lock().await.insert(event_id, values.to_vec());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Email Suppression Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEmailSuppressionRepository {
+    pub suppressions: Arc<Mutex<HashMap<String, EmailSuppression>>>,
+}
+
+impl MockEmailSuppressionRepository {
+    pub fn new() -> Self {
+        Self {
+            suppressions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl EmailSuppressionRepository for MockEmailSuppressionRepository {
+    async fn find_by_email(&self, email: &str) -> DomainResult<Option<EmailSuppression>> {
+        Ok(self.suppressions.lock().await.get(&email.to_lowercase()).cloned())
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<EmailSuppression>> {
+        let mut suppressions: Vec<EmailSuppression> = self.suppressions.lock().await.values().cloned().collect();
+        suppressions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(suppressions)
+    }
+
+    async fn save(&self, suppression: &EmailSuppression) -> DomainResult<()> {
+        self.suppressions
+            .lock()
+            .await
+            .entry(suppression.email.to_lowercase())
+            .or_insert_with(|| suppression.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, email: &str) -> DomainResult<()> {
+        self.suppressions
+            .lock()
+            .await
+            .remove(&email.to_lowercase())
+            .map(|_| ())
+            .ok_or_else(|| DomainError::not_found_by_field("EmailSuppression", "email", email))
+    }
+}
+
+// ============================================================================
+// Mock Notification Digest Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockNotificationDigestRepository {
+    pub preferences: Arc<Mutex<HashMap<Uuid, NotificationPreferences>>>,
+    pub items: Arc<Mutex<Vec<DigestItem>>>,
+}
+
+impl MockNotificationDigestRepository {
+    pub fn new() -> Self {
+        Self {
+            preferences: Arc::new(Mutex::new(HashMap::new())),
+            items: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationDigestRepository for MockNotificationDigestRepository {
+    async fn find_preferences(&self, user_id: Uuid) -> DomainResult<Option<NotificationPreferences>> {
+        Ok(self.preferences.lock().await.get(&user_id).cloned())
+    }
+
+    async fn find_digest_subscribers(&self) -> DomainResult<Vec<NotificationPreferences>> {
+        Ok(self
+            .preferences
+            .lock()
+            .await
+            .values()
+            .filter(|preferences| preferences.digest_frequency != DigestFrequency::Immediate)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_preferences(&self, preferences: &NotificationPreferences) -> DomainResult<()> {
+        self.preferences.lock().await.insert(preferences.user_id, preferences.clone());
+        Ok(())
+    }
+
+    async fn add_item(&self, item: &DigestItem) -> DomainResult<()> {
+        self.items.lock().await.push(item.clone());
+        Ok(())
+    }
+
+    async fn find_pending_items(&self, user_id: Uuid) -> DomainResult<Vec<DigestItem>> {
+        let mut items: Vec<DigestItem> = self
+            .items
+            .lock()
+            .await
+            .iter()
+            .filter(|item| item.user_id == user_id)
+            .cloned()
+            .collect();
+        items.sort_by_key(|item| item.created_at);
+        Ok(items)
+    }
+
+    async fn delete_items(&self, ids: &[Uuid]) -> DomainResult<()> {
+        self.items.lock().await.retain(|item| !ids.contains(&item.id));
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Organization Invitation Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockOrganizationInvitationRepository {
+    pub invitations: Arc<Mutex<HashMap<Uuid, OrganizationInvitation>>>,
+}
+
+impl MockOrganizationInvitationRepository {
+    pub fn new() -> Self {
+        Self {
+            invitations: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    async fn matching(&self, keep: impl Fn(&OrganizationInvitation) -> bool) -> Vec<OrganizationInvitation> {
+        let mut invitations: Vec<OrganizationInvitation> =
+            self.invitations.lock().await.values().filter(|i| keep(i)).cloned().collect();
+        invitations.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        invitations
+    }
+}
+
+#[async_trait]
+impl OrganizationInvitationRepository for MockOrganizationInvitationRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<OrganizationInvitation>> {
+        Ok(self.invitations.lock().await.get(&id).cloned())
+    }
+
+    async fn find_by_token(&self, token: &str) -> DomainResult<Option<OrganizationInvitation>> {
+        Ok(self.invitations.lock().await.values().find(|i| i.token == token).cloned())
+    }
+
+    async fn find_pending_by_email(&self, email: &str) -> DomainResult<Vec<OrganizationInvitation>> {
+        let email = email.trim().to_lowercase();
+        Ok(self
+            .matching(|i| i.email == email && i.status == OrganizationInvitationStatus::Pending)
+            .await)
+    }
+
+    async fn find_pending(&self, company_id: Option<Uuid>) -> DomainResult<Vec<OrganizationInvitation>> {
+        Ok(self
+            .matching(|i| {
+                i.status == OrganizationInvitationStatus::Pending && company_id.is_none_or(|id| i.company_id == id)
+            })
+            .await)
+    }
+
+    async fn create(&self, invitation: &OrganizationInvitation) -> DomainResult<()> {
+        self.invitations.lock().await.insert(invitation.id, invitation.clone());
+        Ok(())
+    }
+
+    async fn update(&self, invitation: &OrganizationInvitation) -> DomainResult<()> {
+        self.invitations
+            .lock()
+            .await
+            .get_mut(&invitation.id)
+            .map(|existing| *existing = invitation.clone())
+            .ok_or_else(|| DomainError::not_found("OrganizationInvitation", invitation.id))
+    }
+}
+
+// ============================================================================
+// Mock Organization Role Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockOrganizationRoleRepository {
+    pub roles: Arc<Mutex<HashMap<Uuid, OrganizationRole>>>,
+    /// (role_id, user_id) pairs, in the order they were assigned
+    pub members: Arc<Mutex<Vec<(Uuid, Uuid)>>>,
+}
+
+impl MockOrganizationRoleRepository {
+    pub fn new() -> Self {
+        Self {
+            roles: Arc::new(Mutex::new(HashMap::new())),
+            members: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl OrganizationRoleRepository for MockOrganizationRoleRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<OrganizationRole>> {
+        Ok(self.roles.lock().await.get(&id).cloned())
+    }
+
+    async fn find_by_company(&self, company_id: Uuid) -> DomainResult<Vec<OrganizationRole>> {
+        let mut roles: Vec<OrganizationRole> = self
+            .roles
+            .lock()
+            .await
+            .values()
+            .filter(|r| r.company_id == company_id)
+            .cloned()
+            .collect();
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(roles)
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> DomainResult<Vec<OrganizationRole>> {
+        let roles = self.roles.lock().await;
+        Ok(self
+            .members
+            .lock()
+            .await
+            .iter()
+            .filter(|(_, member)| *member == user_id)
+            .filter_map(|(role_id, _)| roles.get(role_id).cloned())
+            .collect())
+    }
+
+    async fn create(&self, role: &OrganizationRole) -> DomainResult<()> {
+        let mut roles = self.roles.lock().await;
+        if roles.values().any(|r| r.company_id == role.company_id && r.name == role.name) {
+            return Err(DomainError::conflict("The organization already has a role with that name"));
+        }
+        roles.insert(role.id, role.clone());
+        Ok(())
+    }
+
+    async fn update(&self, role: &OrganizationRole) -> DomainResult<()> {
+        self.roles
+            .lock()
+            .await
+            .get_mut(&role.id)
+            .map(|existing| *existing = role.clone())
+            .ok_or_else(|| DomainError::not_found("OrganizationRole", role.id))
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.members.lock().await.retain(|(role_id, _)| *role_id != id);
+        self.roles
+            .lock()
+            .await
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| DomainError::not_found("OrganizationRole", id))
+    }
+
+    async fn find_member_ids(&self, role_id: Uuid) -> DomainResult<Vec<Uuid>> {
+        Ok(self
+            .members
+            .lock()
+            .await
+            .iter()
+            .filter(|(role, _)| *role == role_id)
+            .map(|(_, user_id)| *user_id)
+            .collect())
+    }
+
+    async fn add_member(&self, role_id: Uuid, user_id: Uuid) -> DomainResult<()> {
+        let mut members = self.members.lock().await;
+        if !members.contains(&(role_id, user_id)) {
+            members.push((role_id, user_id));
+        }
+        Ok(())
+    }
+
+    async fn remove_member(&self, role_id: Uuid, user_id: Uuid) -> DomainResult<bool> {
+        let mut members = self.members.lock().await;
+        let before = members.len();
+        members.retain(|pair| *pair != (role_id, user_id));
+        Ok(members.len() < before)
+    }
+}
+
+// ============================================================================
+// Mock Access Grant Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockAccessGrantRepository {
+    pub grants: Arc<Mutex<HashMap<Uuid, AccessGrant>>>,
+}
+
+impl MockAccessGrantRepository {
+    pub fn new() -> Self {
+        Self {
+            grants: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl AccessGrantRepository for MockAccessGrantRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<AccessGrant>> {
+        Ok(self.grants.lock().await.get(&id).cloned())
+    }
+
+    async fn find_by_event(&self, event_id: Uuid) -> DomainResult<Vec<AccessGrant>> {
+        let mut grants: Vec<AccessGrant> = self
+            .grants
+            .lock()
+            .await
+            .values()
+            .filter(|g| g.event_id == event_id)
+            .cloned()
+            .collect();
+        grants.sort_by_key(|g| std::cmp::Reverse(g.starts_at));
+        Ok(grants)
+    }
+
+    async fn find_active_for_user(&self, user_id: Uuid, now: chrono::DateTime<chrono::Utc>) -> DomainResult<Vec<AccessGrant>> {
+        Ok(self
+            .grants
+            .lock()
+            .await
+            .values()
+            .filter(|g| g.user_id == user_id && g.is_active_at(now))
+            .cloned()
+            .collect())
+    }
+
+    async fn create(&self, grant: &AccessGrant) -> DomainResult<()> {
+        self.grants.lock().await.insert(grant.id, grant.clone());
+        Ok(())
+    }
+
+    async fn revoke(&self, id: Uuid, revoked_at: chrono::DateTime<chrono::Utc>) -> DomainResult<()> {
+        self.grants
+            .lock()
+            .await
+            .get_mut(&id)
+            .map(|grant| grant.revoked_at = Some(revoked_at))
+            .ok_or_else(|| DomainError::not_found("AccessGrant", id))
+    }
+}
+
+// ============================================================================
+// Mock Event Ownership Transfer Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventOwnershipTransferRepository {
+    pub transfers: Arc<Mutex<HashMap<Uuid, EventOwnershipTransfer>>>,
+}
+
+impl MockEventOwnershipTransferRepository {
+    pub fn new() -> Self {
+        Self {
+            transfers: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl EventOwnershipTransferRepository for MockEventOwnershipTransferRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventOwnershipTransfer>> {
+        Ok(self.transfers.lock().await.get(&id).cloned())
+    }
+
+    async fn find_pending_for_event(&self, event_id: Uuid) -> DomainResult<Option<EventOwnershipTransfer>> {
+        Ok(self
+            .transfers
+            .lock()
+            .await
+            .values()
+            .find(|t| t.event_id == event_id && t.is_pending())
+            .cloned())
+    }
+
+    async fn find_pending_for_user(&self, user_id: Uuid) -> DomainResult<Vec<EventOwnershipTransfer>> {
+        let mut transfers: Vec<EventOwnershipTransfer> = self
+            .transfers
+            .lock()
+            .await
+            .values()
+            .filter(|t| t.to_user_id == user_id && t.is_pending())
+            .cloned()
+            .collect();
+        transfers.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(transfers)
+    }
+
+    async fn create(&self, transfer: &EventOwnershipTransfer) -> DomainResult<()> {
+        let mut transfers = self.transfers.lock().await;
+        if transfer.is_pending() && transfers.values().any(|t| t.event_id == transfer.event_id && t.is_pending()) {
+            return Err(DomainError::conflict("The event already has a pending ownership transfer"));
+        }
+        transfers.insert(transfer.id, transfer.clone());
+        Ok(())
+    }
+
+    async fn update(&self, transfer: &EventOwnershipTransfer) -> DomainResult<()> {
+        self.transfers
+            .lock()
+            .await
+            .get_mut(&transfer.id)
+            .map(|existing| *existing = transfer.clone())
+            .ok_or_else(|| DomainError::not_found("EventOwnershipTransfer", transfer.id))
+    }
+}
+
+// ============================================================================
+// Mock Event Submission Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventSubmissionRepository {
+    pub submissions: Arc<Mutex<HashMap<Uuid, EventSubmission>>>,
+}
+
+impl MockEventSubmissionRepository {
+    pub fn new() -> Self {
+        Self {
+            submissions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl EventSubmissionRepository for MockEventSubmissionRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventSubmission>> {
+        Ok(self.submissions.lock().await.get(&id).cloned())
+    }
+
+    async fn find_pending(&self) -> DomainResult<Vec<EventSubmission>> {
+        let mut submissions: Vec<EventSubmission> = self
+            .submissions
+            .lock()
+            .await
+            .values()
+            .filter(|s| s.is_pending())
+            .cloned()
+            .collect();
+        submissions.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(submissions)
+    }
+
+    async fn find_by_submitter(&self, user_id: Uuid) -> DomainResult<Vec<EventSubmission>> {
+        let mut submissions: Vec<EventSubmission> = self
+            .submissions
+            .lock()
+            .await
+            .values()
+            .filter(|s| s.submitted_by == user_id)
+            .cloned()
+            .collect();
+        submissions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(submissions)
+    }
+
+    async fn create(&self, submission: &EventSubmission) -> DomainResult<()> {
+        let mut submissions = self.submissions.lock().await;
+        if submission.is_pending() && submissions.values().any(|s| s.event_id == submission.event_id && s.is_pending()) {
+            return Err(DomainError::conflict("The event is already waiting for a moderator"));
+        }
+        submissions.insert(submission.id, submission.clone());
+        Ok(())
+    }
+
+    async fn update(&self, submission: &EventSubmission) -> DomainResult<()> {
+        self.submissions
+            .lock()
+            .await
+            .get_mut(&submission.id)
+            .map(|existing| *existing = submission.clone())
+            .ok_or_else(|| DomainError::not_found("EventSubmission", submission.id))
+    }
+}
+
+// ============================================================================
+// Mock Content Report Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockContentReportRepository {
+    pub reports: Arc<Mutex<HashMap<Uuid, ContentReport>>>,
+    /// Hidden content and who hid it; `None` for automatic hiding
+    pub hidden: Arc<Mutex<HashMap<(ReportTarget, Uuid), Option<Uuid>>>>,
+}
+
+impl MockContentReportRepository {
+    pub fn new() -> Self {
+        Self {
+            reports: Arc::new(Mutex::new(HashMap::new())),
+            hidden: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl ContentReportRepository for MockContentReportRepository {
+    async fn create(&self, report: &ContentReport) -> DomainResult<()> {
+        let mut reports = self.reports.lock().await;
+        if reports.values().any(|r| {
+            r.is_open() && r.target == report.target && r.target_id == report.target_id && r.reporter_id == report.reporter_id
+        }) {
+            return Err(DomainError::conflict("You have already reported this"));
+        }
+        reports.insert(report.id, report.clone());
+        Ok(())
+    }
+
+    async fn find_open(&self) -> DomainResult<Vec<ContentReport>> {
+        let mut reports: Vec<ContentReport> = self
+            .reports
+            .lock()
+            .await
+            .values()
+            .filter(|r| r.is_open())
+            .cloned()
+            .collect();
+        reports.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(reports)
+    }
+
+    async fn count_open_reporters(&self, target: ReportTarget, target_id: Uuid) -> DomainResult<i64> {
+        let reports = self.reports.lock().await;
+        let reporters: std::collections::HashSet<Uuid> = reports
+            .values()
+            .filter(|r| r.is_open() && r.target == target && r.target_id == target_id)
+            .map(|r| r.reporter_id)
+            .collect();
+        Ok(reporters.len() as i64)
+    }
+
+    async fn resolve_open(
+        &self,
+        target: ReportTarget,
+        target_id: Uuid,
+        status: ReportStatus,
+        action: ModerationAction,
+        resolved_by: Uuid,
+        resolved_at: chrono::DateTime<chrono::Utc>,
+    ) -> DomainResult<u64> {
+        let mut resolved = 0;
+        for report in self.reports.lock().await.values_mut() {
+            if report.is_open() && report.target == target && report.target_id == target_id {
+                report.status = status;
+                report.action = Some(action);
+                report.resolved_by = Some(resolved_by);
+                report.resolved_at = Some(resolved_at);
+                resolved += 1;
+            }
+        }
+        Ok(resolved)
+    }
+
+    async fn hide(
+        &self,
+        target: ReportTarget,
+        target_id: Uuid,
+        hidden_by: Option<Uuid>,
+        _hidden_at: chrono::DateTime<chrono::Utc>,
+    ) -> DomainResult<()> {
+        let mut hidden = self.hidden.lock().await;
+        let entry = hidden.entry((target, target_id)).or_insert(None);
+        *entry = hidden_by.or(*entry);
+        Ok(())
+    }
+
+    async fn unhide(&self, target: ReportTarget, target_id: Uuid) -> DomainResult<()> {
+        self.hidden.lock().await.remove(&(target, target_id));
+        Ok(())
+    }
+
+    async fn find_hidden(&self, target: ReportTarget, target_ids: &[Uuid]) -> DomainResult<Vec<Uuid>> {
+        let hidden = self.hidden.lock().await;
+        Ok(target_ids.iter().copied().filter(|id| hidden.contains_key(&(target, *id))).collect())
+    }
+}
+
+// ============================================================================
+// Mock Event Consent Repository
+// ============================================================================
+
+/// Answers are matched to events through the registration mock, like the
+/// join on `event_registrations` in the database
+#[derive(Clone)]
+pub struct MockEventConsentRepository {
+    pub consents: Arc<Mutex<HashMap<Uuid, EventConsent>>>,
+    pub answers: Arc<Mutex<HashMap<(Uuid, Uuid), RegistrationConsent>>>,
+    pub registrations: MockEventRegistrationRepository,
+}
+
+impl MockEventConsentRepository {
+    pub fn new(registrations: MockEventRegistrationRepository) -> Self {
+        Self {
+            consents: Arc::new(Mutex::new(HashMap::new())),
+            answers: Arc::new(Mutex::new(HashMap::new())),
+            registrations,
+        }
+    }
+}
+
+#[async_trait]
+impl EventConsentRepository for MockEventConsentRepository {
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventConsent>> {
+        let mut consents: Vec<EventConsent> = self
+            .consents
+            .lock()
+            .await
+            .values()
+            .filter(|c| c.event_id == event_id)
+            .cloned()
+            .collect();
+        consents.sort_by_key(|c| c.kind);
+        Ok(consents)
+    }
+
+    async fn create(&self, consent: &EventConsent) -> DomainResult<()> {
+        let mut consents = self.consents.lock().await;
+        if consents.values().any(|c| c.event_id == consent.event_id && c.kind == consent.kind) {
+            return Err(DomainError::conflict("The event already has this consent"));
+        }
+        consents.insert(consent.id, consent.clone());
+        Ok(())
+    }
+
+    async fn update(&self, consent: &EventConsent) -> DomainResult<()> {
+        let mut consents = self.consents.lock().await;
+        if !consents.contains_key(&consent.id) {
+            return Err(DomainError::not_found("EventConsent", consent.id));
+        }
+        consents.insert(consent.id, consent.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.consents
+            .lock()
+            .await
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| DomainError::not_found("EventConsent", id))
+    }
+
+    async fn record_answers(&self, answers: &[RegistrationConsent]) -> DomainResult<()> {
+        let mut stored = self.answers.lock().await;
+        for answer in answers {
+            stored.insert((answer.registration_id, answer.consent_id), answer.clone());
+        }
+        Ok(())
+    }
+
+    async fn find_answers_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<RegistrationConsent>> {
+        let registrations = self.registrations.registrations.lock().await;
+        let mut answers: Vec<RegistrationConsent> = self
+            .answers
+            .lock()
+            .await
+            .values()
+            .filter(|a| registrations.get(&a.registration_id).is_some_and(|r| r.event_id == event_id))
+            .cloned()
+            .collect();
+        answers.sort_by_key(|a| (a.registration_id, a.kind));
+        Ok(answers)
+    }
+
+    async fn find_answers_by_registration_id(&self, registration_id: Uuid) -> DomainResult<Vec<RegistrationConsent>> {
+        let mut answers: Vec<RegistrationConsent> = self
+            .answers
+            .lock()
+            .await
+            .values()
+            .filter(|a| a.registration_id == registration_id)
+            .cloned()
+            .collect();
+        answers.sort_by_key(|a| a.answered_at);
+        Ok(answers)
+    }
+}
+
+// ============================================================================
+// Mock Event Broadcast Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventBroadcastRepository {
+    pub broadcasts: Arc<Mutex<HashMap<Uuid, EventBroadcast>>>,
+}
+
+impl MockEventBroadcastRepository {
+    pub fn new() -> Self {
+        Self {
+            broadcasts: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl EventBroadcastRepository for MockEventBroadcastRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventBroadcast>> {
+        Ok(self.broadcasts.lock().await.get(&id).cloned())
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventBroadcast>> {
+        let mut broadcasts: Vec<EventBroadcast> = self
+            .broadcasts
+            .lock()
+            .await
+            .values()
+            .filter(|b| b.event_id == event_id)
+            .cloned()
+            .collect();
+        broadcasts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(broadcasts)
+    }
+
+    async fn find_due(&self, now: chrono::DateTime<chrono::Utc>) -> DomainResult<Vec<EventBroadcast>> {
+        let mut broadcasts: Vec<EventBroadcast> = self
+            .broadcasts
+            .lock()
+            .await
+            .values()
+            .filter(|b| b.status == BroadcastStatus::Scheduled && b.scheduled_for.is_some_and(|at| at <= now))
+            .cloned()
+            .collect();
+        broadcasts.sort_by_key(|b| b.scheduled_for);
+        Ok(broadcasts)
+    }
+
+    async fn create(&self, broadcast: &EventBroadcast) -> DomainResult<()> {
+        self.broadcasts.lock().await.insert(broadcast.id, broadcast.clone());
+        Ok(())
+    }
+
+    async fn update(&self, broadcast: &EventBroadcast) -> DomainResult<()> {
+        self.broadcasts
+            .lock()
+            .await
+            .get_mut(&broadcast.id)
+            .map(|existing| *existing = broadcast.clone())
+            .ok_or_else(|| DomainError::not_found("EventBroadcast", broadcast.id))
+    }
+}
+
+// ============================================================================
+// Mock Message Thread Repository
+// ============================================================================
+
+/// Looks up who organizes a thread's event in the event mock
+#[derive(Clone)]
+pub struct MockMessageThreadRepository {
+    pub threads: Arc<Mutex<HashMap<Uuid, MessageThread>>>,
+    pub messages: Arc<Mutex<Vec<ThreadMessage>>>,
+    pub events: MockEventRepository,
+}
+
+impl MockMessageThreadRepository {
+    pub fn new(events: MockEventRepository) -> Self {
+        Self {
+            threads: Arc::new(Mutex::new(HashMap::new())),
+            messages: Arc::new(Mutex::new(Vec::new())),
+            events,
+        }
+    }
+
+    fn newest_first(mut threads: Vec<MessageThread>) -> Vec<MessageThread> {
+        threads.sort_by(|a, b| b.last_message_at.cmp(&a.last_message_at));
+        threads
+    }
+}
+
+#[async_trait]
+impl MessageThreadRepository for MockMessageThreadRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<MessageThread>> {
+        Ok(self.threads.lock().await.get(&id).cloned())
+    }
+
+    async fn find_by_event_and_attendee(&self, event_id: Uuid, attendee_id: Uuid) -> DomainResult<Option<MessageThread>> {
+        Ok(self
+            .threads
+            .lock()
+            .await
+            .values()
+            .find(|t| t.event_id == event_id && t.attendee_id == attendee_id)
+            .cloned())
+    }
+
+    async fn find_by_attendee(&self, attendee_id: Uuid) -> DomainResult<Vec<MessageThread>> {
+        let threads = self.threads.lock().await.values().filter(|t| t.attendee_id == attendee_id).cloned().collect();
+        Ok(Self::newest_first(threads))
+    }
+
+    async fn find_for_organizer(&self, user_id: Uuid) -> DomainResult<Vec<MessageThread>> {
+        let events = self.events.events.lock().await;
+        let threads = self
+            .threads
+            .lock()
+            .await
+            .values()
+            .filter(|t| {
+                events
+                    .get(&t.event_id)
+                    .is_some_and(|e| e.organizer_id == user_id || e.co_organizers.contains(&user_id))
+            })
+            .cloned()
+            .collect();
+        Ok(Self::newest_first(threads))
+    }
+
+    async fn create(&self, thread: &MessageThread) -> DomainResult<()> {
+        let mut threads = self.threads.lock().await;
+        if threads.values().any(|t| t.event_id == thread.event_id && t.attendee_id == thread.attendee_id) {
+            return Err(DomainError::conflict("The attendee already has a thread about this event"));
+        }
+        threads.insert(thread.id, thread.clone());
+        Ok(())
+    }
+
+    async fn find_messages(&self, thread_id: Uuid) -> DomainResult<Vec<ThreadMessage>> {
+        Ok(self.messages.lock().await.iter().filter(|m| m.thread_id == thread_id).cloned().collect())
+    }
+
+    async fn add_message(&self, message: &ThreadMessage) -> DomainResult<()> {
+        let mut threads = self.threads.lock().await;
+        let thread = threads
+            .get_mut(&message.thread_id)
+            .ok_or_else(|| DomainError::not_found("MessageThread", message.thread_id))?;
+        if message.from_organizer {
+            thread.attendee_unread += 1;
+        } else {
+            thread.organizer_unread += 1;
+        }
+        thread.last_message_at = message.created_at;
+        self.messages.lock().await.push(message.clone());
+        Ok(())
+    }
+
+    async fn mark_read(&self, thread_id: Uuid, by_organizer: bool) -> DomainResult<()> {
+        let mut threads = self.threads.lock().await;
+        let thread = threads
+            .get_mut(&thread_id)
+            .ok_or_else(|| DomainError::not_found("MessageThread", thread_id))?;
+        if by_organizer {
+            thread.organizer_unread = 0;
+        } else {
+            thread.attendee_unread = 0;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Event Summary Repository
+// ============================================================================
+
+/// Derives summaries from the event and registration mocks on every read,
+/// standing in for the database triggers that maintain `event_summaries`
+#[derive(Clone)]
+pub struct MockEventSummaryRepository {
+    pub events: MockEventRepository,
+    pub registrations: MockEventRegistrationRepository,
+}
+
+impl MockEventSummaryRepository {
+    pub fn new(events: MockEventRepository, registrations: MockEventRegistrationRepository) -> Self {
+        Self { events, registrations }
+    }
+
+    // Summaries of the events `find_past` would return, most recent first
+    async fn past(&self, filter: &PastEventFilter, now: chrono::DateTime<chrono::Utc>) -> DomainResult<Vec<EventSummary>> {
+        use chrono::Datelike;
+
+        let ids: Vec<Uuid> = self
+            .events
+            .events
+            .lock()
+            .await
+            .values()
+            .filter(|e| e.end_date < now)
+            .filter(|e| !matches!(e.status, EventStatus::Draft | EventStatus::Cancelled))
+            .filter(|e| match filter.visible_to {
+                Some(viewer) => !e.is_private || e.organizer_id == viewer || e.co_organizers.contains(&viewer),
+                None => true,
+            })
+            .filter(|e| {
+                filter
+                    .title_contains
+                    .as_ref()
+                    .is_none_or(|text| e.title.to_lowercase().contains(&text.to_lowercase()))
+            })
+            .filter(|e| filter.category_id.as_ref().is_none_or(|id| &e.category_id == id))
+            .filter(|e| filter.organizer_id.is_none_or(|id| e.organizer_id == id))
+            .filter(|e| filter.from_year.is_none_or(|year| e.start_date.year() >= year))
+            .filter(|e| filter.to_year.is_none_or(|year| e.start_date.year() <= year))
+            .map(|e| e.id)
+            .collect();
+
+        let mut summaries = self.find_by_event_ids(&ids).await?;
+        summaries.sort_by(|a, b| b.start_date.cmp(&a.start_date));
+        Ok(summaries)
+    }
+}
+
+#[async_trait]
+impl EventSummaryRepository for MockEventSummaryRepository {
+    async fn find_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<EventSummary>> {
+        let counts = self.registrations.count_by_event_ids(event_ids).await?;
+        let events = self.events.events.lock().await;
+        let registrations = self.registrations.registrations.lock().await;
+
+        Ok(event_ids
+            .iter()
+            .filter_map(|id| events.get(id))
+            .map(|event| EventSummary {
+                event_id: event.id,
+                title: event.title.clone(),
+                category_id: event.category_id.clone(),
+                category_name: None,
+                organizer_id: event.organizer_id,
+                organizer_name: None,
+                start_date: event.start_date,
+                end_date: event.end_date,
+                status: event.status.clone(),
+                registration_counts: counts
+                    .iter()
+                    .find(|c| c.event_id == event.id)
+                    .cloned()
+                    .unwrap_or_else(|| RegistrationCounts::empty(event.id)),
+                checked_in: registrations
+                    .values()
+                    .filter(|r| r.event_id == event.id && r.checked_in_at.is_some())
+                    .count() as i64,
+                updated_at: event.updated_at,
+            })
+            .collect())
+    }
+
+    async fn find_past(
+        &self,
+        filter: &PastEventFilter,
+        now: chrono::DateTime<chrono::Utc>,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<EventSummary>> {
+        let past = self.past(filter, now).await?;
+        let total = past.len() as i64;
+        let items = past
+            .into_iter()
+            .skip(pagination.offset as usize)
+            .take(pagination.limit as usize)
+            .collect();
+        Ok(PaginatedResult::new(items, total, pagination))
+    }
+
+    async fn yearly_attendance(
+        &self,
+        filter: &PastEventFilter,
+        grouping: ArchiveGrouping,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> DomainResult<Vec<YearlyAttendance>> {
+        use chrono::Datelike;
+
+        let mut rows: Vec<YearlyAttendance> = Vec::new();
+        for summary in self.past(filter, now).await? {
+            let (group_id, group_name) = match grouping {
+                ArchiveGrouping::Category => (summary.category_id.clone(), summary.category_name.clone()),
+                ArchiveGrouping::Organizer => (summary.organizer_id.to_string(), summary.organizer_name.clone()),
+                ArchiveGrouping::County => {
+                    let county = self
+                        .events
+                        .events
+                        .lock()
+                        .await
+                        .get(&summary.event_id)
+                        .and_then(|e| e.region.as_ref().map(|r| r.county.clone()));
+                    (county.clone().unwrap_or_default(), county)
+                }
+            };
+            let year = summary.start_date.year();
+            let index = match rows.iter().position(|r| r.group_id == group_id && r.year == year) {
+                Some(index) => index,
+                None => {
+                    rows.push(YearlyAttendance {
+                        year,
+                        group_id,
+                        group_name,
+                        events: 0,
+                        registrations: 0,
+                        checked_in: 0,
+                        cancelled: 0,
+                    });
+                    rows.len() - 1
+                }
+            };
+            let row = &mut rows[index];
+            let counts = &summary.registration_counts;
+            row.events += 1;
+            row.registrations += counts.registered + counts.attended;
+            row.checked_in += summary.checked_in;
+            row.cancelled += counts.cancelled;
+        }
+        rows.sort_by(|a, b| a.group_id.cmp(&b.group_id).then(a.year.cmp(&b.year)));
+        Ok(rows)
+    }
+}
+
+// ============================================================================
+// Mock Mailer
+// ============================================================================
+
+/// Keeps sent emails for assertions
+#[derive(Clone)]
+pub struct MockMailer {
+    pub sent: Arc<Mutex<Vec<EmailMessage>>>,
+}
+
+impl MockMailer {
+    pub fn new() -> Self {
+        Self {
+            sent: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for MockMailer {
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()> {
+        self.sent.lock().await.push(message.clone());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock DNS Resolver
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockDnsResolver {
+    pub records: Arc<Mutex<HashMap<(String, DnsRecordType), Vec<String>>>>,
+}
+
+impl MockDnsResolver {
+    pub fn new() -> Self {
+        Self {
+            records: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub async fn add(&self, name: &str, record_type: DnsRecordType, value: &str) {
+        self.records
+            .lock()
+            .await
+            .entry((name.to_string(), record_type))
+            .or_default()
+            .push(value.to_string());
+    }
+}
+
+#[async_trait]
+impl DnsResolver for MockDnsResolver {
+    async fn lookup(&self, name: &str, record_type: DnsRecordType) -> DomainResult<Vec<String>> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .get(&(name.to_string(), record_type))
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+// ============================================================================
+// Mock Company Registry
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockCompanyRegistry {
+    pub companies: Arc<Mutex<HashMap<String, RegisteredCompany>>>,
+    /// Lookups fail as if the register couldn't be reached
+    pub offline: Arc<Mutex<bool>>,
+}
+
+impl MockCompanyRegistry {
+    pub fn new() -> Self {
+        Self {
+            companies: Arc::new(Mutex::new(HashMap::new())),
+            offline: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn add(&self, company: RegisteredCompany) {
+        self.companies.lock().await.insert(company.org_number.clone(), company);
+    }
+
+    pub async fn set_offline(&self, offline: bool) {
+        *self.offline.lock().await = offline;
+    }
+}
+
+#[async_trait]
+impl CompanyRegistry for MockCompanyRegistry {
+    async fn lookup(&self, org_number: &str) -> DomainResult<Option<RegisteredCompany>> {
+        if *self.offline.lock().await {
+            return Err(DomainError::external_service("Brønnøysundregistrene", "unreachable"));
+        }
+        Ok(self.companies.lock().await.get(org_number).cloned())
+    }
+}
+
+// ============================================================================
+// Mock Postal Code Directory
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockPostalCodeDirectory {
+    pub regions: Arc<Mutex<HashMap<String, AddressRegion>>>,
+}
+
+impl MockPostalCodeDirectory {
+    pub fn new() -> Self {
+        Self {
+            regions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub async fn add(&self, postal_code: &str, municipality: &str, county: &str) {
+        self.regions.lock().await.insert(
+            postal_code.to_string(),
+            AddressRegion {
+                postal_code: postal_code.to_string(),
+                municipality: municipality.to_string(),
+                county: county.to_string(),
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl PostalCodeDirectory for MockPostalCodeDirectory {
+    async fn region(&self, postal_code: &str) -> DomainResult<Option<AddressRegion>> {
+        Ok(self.regions.lock().await.get(postal_code).cloned())
+    }
+}
+
+// ============================================================================
+// Mock Weather Forecaster
+// ============================================================================
+
+/// Forecasts the same weather everywhere, and records the days asked for
+#[derive(Clone)]
+pub struct MockWeatherForecaster {
+    pub requests: Arc<Mutex<Vec<(f64, f64, chrono::NaiveDate)>>>,
+    /// Forecasts fail as if MET Norway couldn't be reached
+    pub offline: Arc<Mutex<bool>>,
+}
+
+impl MockWeatherForecaster {
+    pub fn new() -> Self {
+        Self {
+            requests: Arc::new(Mutex::new(Vec::new())),
+            offline: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_offline(&self, offline: bool) {
+        *self.offline.lock().await = offline;
+    }
+}
+
+#[async_trait]
+impl WeatherForecaster for MockWeatherForecaster {
+    async fn daily_forecast(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        date: chrono::NaiveDate,
+        _timezone: chrono_tz::Tz,
+    ) -> DomainResult<Option<WeatherForecast>> {
+        if *self.offline.lock().await {
+            return Err(DomainError::external_service("MET Norway", "unreachable"));
+        }
+        self.requests.lock().await.push((latitude, longitude, date));
+        Ok(Some(WeatherForecast {
+            date,
+            symbol_code: Some("partlycloudy_day".to_string()),
+            min_temperature_c: 9.0,
+            max_temperature_c: 15.0,
+            precipitation_mm: 1.2,
+            max_wind_speed_ms: 5.0,
+        }))
+    }
+}
+
+// ============================================================================
+// Mock Travel Planner
+// ============================================================================
+
+/// Suggests a bus and ferry journey, timed by the query, and records the queries
+#[derive(Clone)]
+pub struct MockTravelPlanner {
+    pub queries: Arc<Mutex<Vec<TravelQuery>>>,
+}
+
+impl MockTravelPlanner {
+    pub fn new() -> Self {
+        Self {
+            queries: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TravelPlanner for MockTravelPlanner {
+    async fn plan(&self, query: &TravelQuery) -> DomainResult<Vec<TravelOption>> {
+        self.queries.lock().await.push(query.clone());
+        let (departure, arrival) = if query.arrive_by {
+            (query.time - chrono::Duration::minutes(90), query.time)
+        } else {
+            (query.time, query.time + chrono::Duration::minutes(90))
+        };
+        let ferry_departure = departure + chrono::Duration::minutes(50);
+        Ok(vec![TravelOption {
+            departure,
+            arrival,
+            duration_minutes: 90,
+            walk_distance_m: 300.0,
+            legs: vec![
+                TravelLeg {
+                    mode: TravelMode::Bus,
+                    from_place: "Bergen busstasjon".to_string(),
+                    to_place: "Halhjem ferjekai".to_string(),
+                    departure,
+                    arrival: ferry_departure - chrono::Duration::minutes(5),
+                    line: Some("600".to_string()),
+                    operator: Some("Skyss".to_string()),
+                    distance_m: 21000.0,
+                },
+                TravelLeg {
+                    mode: TravelMode::Water,
+                    from_place: "Halhjem ferjekai".to_string(),
+                    to_place: "Sandvikvåg ferjekai".to_string(),
+                    departure: ferry_departure,
+                    arrival,
+                    line: Some("Halhjem - Sandvikvåg".to_string()),
+                    operator: Some("Fjord1".to_string()),
+                    distance_m: 15000.0,
+                },
+            ],
+        }])
+    }
+}
+
+// ============================================================================
+// Mock Invoice Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockInvoiceRepository {
+    pub invoices: Arc<Mutex<Vec<Invoice>>>,
+}
+
+impl MockInvoiceRepository {
+    pub fn new() -> Self {
+        Self {
+            invoices: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl InvoiceRepository for MockInvoiceRepository {
+    async fn create_numbered(&self, invoice: &Invoice) -> DomainResult<i64> {
+        let mut invoices = self.invoices.lock().await;
+        if invoices.iter().any(|existing| existing.order_id == invoice.order_id) {
+            return Err(DomainError::conflict("This order has already been invoiced."));
+        }
+        let invoice_number = invoices.iter().map(|existing| existing.invoice_number).max().unwrap_or(0) + 1;
+        invoices.push(Invoice { invoice_number, ..invoice.clone() });
+        Ok(invoice_number)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Invoice>> {
+        Ok(self.invoices.lock().await.iter().find(|invoice| invoice.id == id).cloned())
+    }
+
+    async fn find_by_order(&self, order_id: Uuid) -> DomainResult<Option<Invoice>> {
+        Ok(self.invoices.lock().await.iter().find(|invoice| invoice.order_id == order_id).cloned())
+    }
+
+    async fn find(&self, filter: &InvoiceFilter) -> DomainResult<Vec<Invoice>> {
+        let mut invoices: Vec<Invoice> = self
+            .invoices
+            .lock()
+            .await
+            .iter()
+            .filter(|invoice| filter.event_id.is_none_or(|event_id| invoice.event_id == event_id))
+            .filter(|invoice| filter.status.is_none_or(|status| invoice.status == status))
+            .filter(|invoice| filter.overdue_on.is_none_or(|day| invoice.is_overdue(day)))
+            .cloned()
+            .collect();
+        invoices.sort_by(|a, b| a.due_date.cmp(&b.due_date).then_with(|| a.invoice_number.cmp(&b.invoice_number)));
+        Ok(invoices)
+    }
+
+    async fn update(&self, invoice: &Invoice) -> DomainResult<()> {
+        let mut invoices = self.invoices.lock().await;
+        let existing = invoices
+            .iter_mut()
+            .find(|existing| existing.id == invoice.id)
+            .ok_or_else(|| DomainError::not_found("Invoice", invoice.id))?;
+        *existing = invoice.clone();
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Ticket Refund Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockTicketRefundRepository {
+    pub refunds: Arc<Mutex<Vec<TicketRefund>>>,
+}
+
+impl MockTicketRefundRepository {
+    pub fn new() -> Self {
+        Self {
+            refunds: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl TicketRefundRepository for MockTicketRefundRepository {
+    async fn create(&self, refund: &TicketRefund) -> DomainResult<()> {
+        let mut refunds = self.refunds.lock().await;
+        if refunds.iter().any(|existing| existing.order_id == refund.order_id) {
+            return Err(DomainError::conflict("This order is already being refunded."));
+        }
+        refunds.push(refund.clone());
+        Ok(())
+    }
+
+    async fn find_by_event(&self, event_id: Uuid) -> DomainResult<Vec<TicketRefund>> {
+        let refunds = self.refunds.lock().await;
+        Ok(refunds.iter().filter(|refund| refund.event_id == event_id).cloned().collect())
+    }
+
+    async fn find_due(&self, now: chrono::DateTime<chrono::Utc>, limit: i64) -> DomainResult<Vec<TicketRefund>> {
+        let mut due: Vec<TicketRefund> = self
+            .refunds
+            .lock()
+            .await
+            .iter()
+            .filter(|refund| refund.status == RefundStatus::Pending)
+            .filter(|refund| refund.next_attempt_at.is_some_and(|at| at <= now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|refund| refund.next_attempt_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+
+    async fn update(&self, refund: &TicketRefund) -> DomainResult<()> {
+        let mut refunds = self.refunds.lock().await;
+        let existing = refunds
+            .iter_mut()
+            .find(|existing| existing.id == refund.id)
+            .ok_or_else(|| DomainError::not_found("TicketRefund", refund.id))?;
+        *existing = refund.clone();
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Seating Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockSeatingRepository {
+    pub tables: Arc<Mutex<Vec<SeatingTable>>>,
+    pub assignments: Arc<Mutex<Vec<SeatAssignment>>>,
+}
+
+impl MockSeatingRepository {
+    pub fn new() -> Self {
+        Self {
+            tables: Arc::new(Mutex::new(Vec::new())),
+            assignments: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl SeatingRepository for MockSeatingRepository {
+    async fn find_table(&self, id: Uuid) -> DomainResult<Option<SeatingTable>> {
+        Ok(self.tables.lock().await.iter().find(|table| table.id == id).cloned())
+    }
+
+    async fn find_tables(&self, event_id: Uuid) -> DomainResult<Vec<SeatingTable>> {
+        let mut tables: Vec<SeatingTable> = self
+            .tables
+            .lock()
+            .await
+            .iter()
+            .filter(|table| table.event_id == event_id)
+            .cloned()
+            .collect();
+        tables.sort_by_key(|table| table.position);
+        Ok(tables)
+    }
+
+    async fn create_table(&self, table: &SeatingTable) -> DomainResult<()> {
+        let mut tables = self.tables.lock().await;
+        if tables
+            .iter()
+            .any(|existing| existing.event_id == table.event_id && existing.name.eq_ignore_ascii_case(&table.name))
+        {
+            return Err(DomainError::conflict("The event already has a table with this name."));
+        }
+        tables.push(table.clone());
+        Ok(())
+    }
+
+    async fn update_table(&self, table: &SeatingTable) -> DomainResult<()> {
+        let mut tables = self.tables.lock().await;
+        let existing = tables
+            .iter_mut()
+            .find(|existing| existing.id == table.id)
+            .ok_or_else(|| DomainError::not_found("SeatingTable", table.id))?;
+        *existing = table.clone();
+        Ok(())
+    }
+
+    async fn delete_table(&self, id: Uuid) -> DomainResult<()> {
+        let mut tables = self.tables.lock().await;
+        let before = tables.len();
+        tables.retain(|table| table.id != id);
+        if tables.len() == before {
+            return Err(DomainError::not_found("SeatingTable", id));
+        }
+        self.assignments.lock().await.retain(|assignment| assignment.table_id != id);
+        Ok(())
+    }
+
+    async fn find_assignments(&self, event_id: Uuid) -> DomainResult<Vec<SeatAssignment>> {
+        let assignments = self.assignments.lock().await;
+        Ok(assignments.iter().filter(|assignment| assignment.event_id == event_id).cloned().collect())
+    }
+
+    async fn assign(&self, assignment: &SeatAssignment) -> DomainResult<()> {
+        let mut assignments = self.assignments.lock().await;
+        assignments.retain(|existing| existing.registration_id != assignment.registration_id);
+        assignments.push(assignment.clone());
+        Ok(())
+    }
+
+    async fn unassign(&self, registration_id: Uuid) -> DomainResult<()> {
+        self.assignments.lock().await.retain(|assignment| assignment.registration_id != registration_id);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Resource Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockResourceRepository {
+    pub resources: Arc<Mutex<Vec<Resource>>>,
+    pub windows: Arc<Mutex<HashMap<Uuid, Vec<AvailabilityWindow>>>>,
+    pub bookings: Arc<Mutex<Vec<ResourceBooking>>>,
+}
+
+impl MockResourceRepository {
+    pub fn new() -> Self {
+        Self {
+            resources: Arc::new(Mutex::new(Vec::new())),
+            windows: Arc::new(Mutex::new(HashMap::new())),
+            bookings: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl ResourceRepository for MockResourceRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Resource>> {
+        Ok(self.resources.lock().await.iter().find(|resource| resource.id == id).cloned())
+    }
+
+    async fn list(&self, include_inactive: bool) -> DomainResult<Vec<Resource>> {
+        let mut resources: Vec<Resource> = self
+            .resources
+            .lock()
+            .await
+            .iter()
+            .filter(|resource| include_inactive || resource.is_active)
+            .cloned()
+            .collect();
+        resources.sort_by_key(|resource| resource.name.to_lowercase());
+        Ok(resources)
+    }
+
+    async fn create(&self, resource: &Resource) -> DomainResult<()> {
+        let mut resources = self.resources.lock().await;
+        if resources.iter().any(|existing| existing.name.eq_ignore_ascii_case(&resource.name)) {
+            return Err(DomainError::conflict("There is already a resource with this name."));
+        }
+        resources.push(resource.clone());
+        Ok(())
+    }
+
+    async fn update(&self, resource: &Resource) -> DomainResult<()> {
+        let mut resources = self.resources.lock().await;
+        let existing = resources
+            .iter_mut()
+            .find(|existing| existing.id == resource.id)
+            .ok_or_else(|| DomainError::not_found("Resource", resource.id))?;
+        *existing = resource.clone();
+        Ok(())
+    }
+
+    async fn find_windows(&self, resource_id: Uuid) -> DomainResult<Vec<AvailabilityWindow>> {
+        Ok(self.windows.lock().await.get(&resource_id).cloned().unwrap_or_default())
+    }
+
+    async fn replace_windows(&self, resource_id: Uuid, windows: &[AvailabilityWindow]) -> DomainResult<()> {
+        self.windows.lock().await.insert(resource_id, windows.to_vec());
+        Ok(())
+    }
+
+    async fn find_booking(&self, id: Uuid) -> DomainResult<Option<ResourceBooking>> {
+        Ok(self.bookings.lock().await.iter().find(|booking| booking.id == id).cloned())
+    }
+
+    async fn find_overlapping_bookings(
+        &self,
+        resource_ids: &[Uuid],
+        starts_at: chrono::DateTime<chrono::Utc>,
+        ends_at: chrono::DateTime<chrono::Utc>,
+    ) -> DomainResult<Vec<ResourceBooking>> {
+        let mut bookings: Vec<ResourceBooking> = self
+            .bookings
+            .lock()
+            .await
+            .iter()
+            .filter(|booking| resource_ids.contains(&booking.resource_id) && booking.overlaps(starts_at, ends_at))
+            .cloned()
+            .collect();
+        bookings.sort_by_key(|booking| booking.starts_at);
+        Ok(bookings)
+    }
+
+    async fn find_bookings_by_event(&self, event_id: Uuid) -> DomainResult<Vec<ResourceBooking>> {
+        let mut bookings: Vec<ResourceBooking> = self
+            .bookings
+            .lock()
+            .await
+            .iter()
+            .filter(|booking| booking.event_id == event_id)
+            .cloned()
+            .collect();
+        bookings.sort_by_key(|booking| booking.starts_at);
+        Ok(bookings)
+    }
+
+    async fn create_bookings_if_free(&self, bookings: &[ResourceBooking]) -> DomainResult<bool> {
+        let mut stored = self.bookings.lock().await;
+        let mut taken = stored.clone();
+        for booking in bookings {
+            if taken
+                .iter()
+                .any(|existing| existing.resource_id == booking.resource_id && existing.overlaps(booking.starts_at, booking.ends_at))
+            {
+                return Ok(false);
+            }
+            taken.push(booking.clone());
+        }
+        *stored = taken;
+        Ok(true)
+    }
+
+    async fn delete_booking(&self, id: Uuid) -> DomainResult<()> {
+        let mut bookings = self.bookings.lock().await;
+        let before = bookings.len();
+        bookings.retain(|booking| booking.id != id);
+        if bookings.len() == before {
+            return Err(DomainError::not_found("ResourceBooking", id));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Shift Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockShiftRepository {
+    pub shifts: Arc<Mutex<Vec<EventShift>>>,
+    pub signups: Arc<Mutex<Vec<ShiftSignup>>>,
+}
+
+impl MockShiftRepository {
+    pub fn new() -> Self {
+        Self {
+            shifts: Arc::new(Mutex::new(Vec::new())),
+            signups: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub async fn add_shift(&self, shift: EventShift) {
+        self.shifts.lock().await.push(shift);
+    }
+}
+
+#[async_trait]
+impl ShiftRepository for MockShiftRepository {
+    async fn find_shift(&self, id: Uuid) -> DomainResult<Option<EventShift>> {
+        Ok(self.shifts.lock().await.iter().find(|shift| shift.id == id).cloned())
+    }
+
+    async fn find_shifts(&self, event_id: Uuid) -> DomainResult<Vec<EventShift>> {
+        let mut shifts: Vec<EventShift> = self
+            .shifts
+            .lock()
+            .await
+            .iter()
+            .filter(|shift| shift.event_id == event_id)
+            .cloned()
+            .collect();
+        shifts.sort_by_key(|shift| shift.starts_at);
+        Ok(shifts)
+    }
+
+    async fn create_shift(&self, shift: &EventShift) -> DomainResult<()> {
+        self.shifts.lock().await.push(shift.clone());
+        Ok(())
+    }
+
+    async fn update_shift(&self, shift: &EventShift) -> DomainResult<()> {
+        let mut shifts = self.shifts.lock().await;
+        let existing = shifts
+            .iter_mut()
+            .find(|existing| existing.id == shift.id)
+            .ok_or_else(|| DomainError::not_found("EventShift", shift.id))?;
+        *existing = shift.clone();
+        Ok(())
+    }
+
+    async fn delete_shift(&self, id: Uuid) -> DomainResult<()> {
+        let mut shifts = self.shifts.lock().await;
+        let before = shifts.len();
+        shifts.retain(|shift| shift.id != id);
+        if shifts.len() == before {
+            return Err(DomainError::not_found("EventShift", id));
+        }
+        self.signups.lock().await.retain(|signup| signup.shift_id != id);
+        Ok(())
+    }
+
+    async fn find_signups(&self, event_id: Uuid) -> DomainResult<Vec<ShiftSignup>> {
+        let shift_ids: Vec<Uuid> = self.find_shifts(event_id).await?.iter().map(|shift| shift.id).collect();
+        let signups = self.signups.lock().await;
+        Ok(signups.iter().filter(|signup| shift_ids.contains(&signup.shift_id)).cloned().collect())
+    }
+
+    async fn find_shifts_by_user(&self, user_id: Uuid) -> DomainResult<Vec<EventShift>> {
+        let shift_ids: Vec<Uuid> = self
+            .signups
+            .lock()
+            .await
+            .iter()
+            .filter(|signup| signup.user_id == user_id)
+            .map(|signup| signup.shift_id)
+            .collect();
+        let mut shifts: Vec<EventShift> = self
+            .shifts
+            .lock()
+            .await
+            .iter()
+            .filter(|shift| shift_ids.contains(&shift.id))
+            .cloned()
+            .collect();
+        shifts.sort_by_key(|shift| shift.starts_at);
+        Ok(shifts)
+    }
+
+    async fn sign_up_within_headcount(&self, signup: &ShiftSignup) -> DomainResult<bool> {
+        let shifts = self.shifts.lock().await;
+        let mut signups = self.signups.lock().await;
+        let Some(shift) = shifts.iter().find(|shift| shift.id == signup.shift_id) else {
+            return Ok(false);
+        };
+        if signups
+            .iter()
+            .any(|existing| existing.shift_id == signup.shift_id && existing.user_id == signup.user_id)
+        {
+            return Err(DomainError::conflict("You are already signed up for this shift."));
+        }
+        let taken = signups.iter().filter(|existing| existing.shift_id == shift.id).count();
+        if taken >= usize::try_from(shift.headcount).unwrap_or(0) {
+            return Ok(false);
+        }
+        signups.push(signup.clone());
+        Ok(true)
+    }
+
+    async fn cancel_signup(&self, shift_id: Uuid, user_id: Uuid) -> DomainResult<()> {
+        let mut signups = self.signups.lock().await;
+        let before = signups.len();
+        signups.retain(|signup| !(signup.shift_id == shift_id && signup.user_id == user_id));
+        if signups.len() == before {
+            return Err(DomainError::not_found("ShiftSignup", shift_id));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Exhibitor Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockExhibitorRepository {
+    pub booths: Arc<Mutex<Vec<Booth>>>,
+    pub exhibitors: Arc<Mutex<Vec<Exhibitor>>>,
+}
+
+impl MockExhibitorRepository {
+    pub fn new() -> Self {
+        Self {
+            booths: Arc::new(Mutex::new(Vec::new())),
+            exhibitors: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl ExhibitorRepository for MockExhibitorRepository {
+    async fn find_booth(&self, id: Uuid) -> DomainResult<Option<Booth>> {
+        Ok(self.booths.lock().await.iter().find(|booth| booth.id == id).cloned())
+    }
+
+    async fn find_booths(&self, event_id: Uuid) -> DomainResult<Vec<Booth>> {
+        let mut booths: Vec<Booth> = self
+            .booths
+            .lock()
+            .await
+            .iter()
+            .filter(|booth| booth.event_id == event_id)
+            .cloned()
+            .collect();
+        booths.sort_by(|a, b| (&a.hall, &a.number).cmp(&(&b.hall, &b.number)));
+        Ok(booths)
+    }
+
+    async fn create_booth(&self, booth: &Booth) -> DomainResult<()> {
+        let mut booths = self.booths.lock().await;
+        if booths
+            .iter()
+            .any(|existing| existing.event_id == booth.event_id && existing.number == booth.number)
+        {
+            return Err(DomainError::conflict("The event already has a booth with this number."));
+        }
+        booths.push(booth.clone());
+        Ok(())
+    }
+
+    async fn update_booth(&self, booth: &Booth) -> DomainResult<()> {
+        let mut booths = self.booths.lock().await;
+        let existing = booths
+            .iter_mut()
+            .find(|existing| existing.id == booth.id)
+            .ok_or_else(|| DomainError::not_found("Booth", booth.id))?;
+        *existing = booth.clone();
+        Ok(())
+    }
+
+    async fn delete_booth(&self, id: Uuid) -> DomainResult<()> {
+        let mut booths = self.booths.lock().await;
+        let before = booths.len();
+        booths.retain(|booth| booth.id != id);
+        if booths.len() == before {
+            return Err(DomainError::not_found("Booth", id));
+        }
+        for exhibitor in self.exhibitors.lock().await.iter_mut() {
+            if exhibitor.booth_id == Some(id) {
+                exhibitor.booth_id = None;
+            }
+        }
+        Ok(())
+    }
+
+    async fn find_exhibitor(&self, id: Uuid) -> DomainResult<Option<Exhibitor>> {
+        Ok(self.exhibitors.lock().await.iter().find(|exhibitor| exhibitor.id == id).cloned())
+    }
+
+    async fn find_exhibitors(&self, event_id: Uuid) -> DomainResult<Vec<Exhibitor>> {
+        let mut exhibitors: Vec<Exhibitor> = self
+            .exhibitors
+            .lock()
+            .await
+            .iter()
+            .filter(|exhibitor| exhibitor.event_id == event_id)
+            .cloned()
+            .collect();
+        exhibitors.sort_by_key(|exhibitor| exhibitor.company_name.to_lowercase());
+        Ok(exhibitors)
+    }
+
+    async fn create_exhibitor(&self, exhibitor: &Exhibitor) -> DomainResult<()> {
+        self.exhibitors.lock().await.push(exhibitor.clone());
+        Ok(())
+    }
+
+    async fn update_exhibitor(&self, exhibitor: &Exhibitor) -> DomainResult<()> {
+        let mut exhibitors = self.exhibitors.lock().await;
+        let existing = exhibitors
+            .iter_mut()
+            .find(|existing| existing.id == exhibitor.id)
+            .ok_or_else(|| DomainError::not_found("Exhibitor", exhibitor.id))?;
+        *existing = Exhibitor {
+            booth_id: existing.booth_id,
+            ..exhibitor.clone()
+        };
+        Ok(())
+    }
+
+    async fn delete_exhibitor(&self, id: Uuid) -> DomainResult<()> {
+        let mut exhibitors = self.exhibitors.lock().await;
+        let before = exhibitors.len();
+        exhibitors.retain(|exhibitor| exhibitor.id != id);
+        if exhibitors.len() == before {
+            return Err(DomainError::not_found("Exhibitor", id));
+        }
+        Ok(())
+    }
+
+    async fn allocate(&self, exhibitor_id: Uuid, booth_id: Option<Uuid>) -> DomainResult<()> {
+        let mut exhibitors = self.exhibitors.lock().await;
+        if booth_id.is_some()
+            && exhibitors
+                .iter()
+                .any(|other| other.id != exhibitor_id && other.booth_id == booth_id)
+        {
+            return Err(DomainError::conflict("This booth is already allocated to another exhibitor."));
+        }
+        let exhibitor = exhibitors
+            .iter_mut()
+            .find(|exhibitor| exhibitor.id == exhibitor_id)
+            .ok_or_else(|| DomainError::not_found("Exhibitor", exhibitor_id))?;
+        exhibitor.booth_id = booth_id;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Lead Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockLeadRepository {
+    pub leads: Arc<Mutex<Vec<Lead>>>,
+}
+
+impl MockLeadRepository {
+    pub fn new() -> Self {
+        Self {
+            leads: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl LeadRepository for MockLeadRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Lead>> {
+        Ok(self.leads.lock().await.iter().find(|lead| lead.id == id).cloned())
+    }
+
+    async fn capture(&self, lead: &Lead) -> DomainResult<bool> {
+        let mut leads = self.leads.lock().await;
+        if leads
+            .iter()
+            .any(|existing| existing.exhibitor_id == lead.exhibitor_id && existing.registration_id == lead.registration_id)
+        {
+            return Ok(false);
+        }
+        leads.push(lead.clone());
+        Ok(true)
+    }
+
+    async fn find_by_exhibitor(&self, exhibitor_id: Uuid) -> DomainResult<Vec<Lead>> {
+        Ok(self
+            .leads
+            .lock()
+            .await
+            .iter()
+            .filter(|lead| lead.exhibitor_id == exhibitor_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_event(&self, event_id: Uuid) -> DomainResult<Vec<Lead>> {
+        Ok(self
+            .leads
+            .lock()
+            .await
+            .iter()
+            .filter(|lead| lead.event_id == event_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        let mut leads = self.leads.lock().await;
+        let before = leads.len();
+        leads.retain(|lead| lead.id != id);
+        if leads.len() == before {
+            return Err(DomainError::not_found("Lead", id));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Matchmaking Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockMatchmakingRepository {
+    pub profiles: Arc<Mutex<Vec<MatchmakingProfile>>>,
+    pub rooms: Arc<Mutex<HashMap<Uuid, Vec<Uuid>>>>,
+    pub meetings: Arc<Mutex<Vec<Meeting>>>,
+}
+
+impl MockMatchmakingRepository {
+    pub fn new() -> Self {
+        Self {
+            profiles: Arc::new(Mutex::new(Vec::new())),
+            rooms: Arc::new(Mutex::new(HashMap::new())),
+            meetings: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl MatchmakingRepository for MockMatchmakingRepository {
+    async fn find_profile(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Option<MatchmakingProfile>> {
+        Ok(self
+            .profiles
+            .lock()
+            .await
+            .iter()
+            .find(|profile| profile.event_id == event_id && profile.user_id == user_id)
+            .cloned())
+    }
+
+    async fn find_profiles(&self, event_id: Uuid) -> DomainResult<Vec<MatchmakingProfile>> {
+        Ok(self
+            .profiles
+            .lock()
+            .await
+            .iter()
+            .filter(|profile| profile.event_id == event_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_profile(&self, profile: &MatchmakingProfile) -> DomainResult<()> {
+        let mut profiles = self.profiles.lock().await;
+        match profiles
+            .iter_mut()
+            .find(|existing| existing.event_id == profile.event_id && existing.user_id == profile.user_id)
+        {
+            Some(existing) => *existing = profile.clone(),
+            None => profiles.push(profile.clone()),
+        }
+        Ok(())
+    }
+
+    async fn delete_profile(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<()> {
+        let mut profiles = self.profiles.lock().await;
+        let before = profiles.len();
+        profiles.retain(|profile| !(profile.event_id == event_id && profile.user_id == user_id));
+        if profiles.len() == before {
+            return Err(DomainError::not_found("MatchmakingProfile", user_id));
+        }
+        Ok(())
+    }
+
+    async fn find_rooms(&self, event_id: Uuid) -> DomainResult<Vec<Uuid>> {
+        Ok(self.rooms.lock().await.get(&event_id).cloned().unwrap_or_default())
+    }
+
+    async fn replace_rooms(&self, event_id: Uuid, resource_ids: &[Uuid]) -> DomainResult<()> {
+        self.rooms.lock().await.insert(event_id, resource_ids.to_vec());
+        Ok(())
+    }
+
+    async fn find_meeting(&self, id: Uuid) -> DomainResult<Option<Meeting>> {
+        Ok(self.meetings.lock().await.iter().find(|meeting| meeting.id == id).cloned())
+    }
+
+    async fn find_meetings(&self, event_id: Uuid) -> DomainResult<Vec<Meeting>> {
+        let mut meetings: Vec<Meeting> = self
+            .meetings
+            .lock()
+            .await
+            .iter()
+            .filter(|meeting| meeting.event_id == event_id)
+            .cloned()
+            .collect();
+        meetings.sort_by_key(|meeting| meeting.starts_at);
+        Ok(meetings)
+    }
+
+    async fn create_meeting(&self, meeting: &Meeting) -> DomainResult<()> {
+        self.meetings.lock().await.push(meeting.clone());
+        Ok(())
+    }
+
+    async fn update_meeting(&self, meeting: &Meeting) -> DomainResult<()> {
+        let mut meetings = self.meetings.lock().await;
+        let existing = meetings
+            .iter_mut()
+            .find(|existing| existing.id == meeting.id)
+            .ok_or_else(|| DomainError::not_found("Meeting", meeting.id))?;
+        *existing = meeting.clone();
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Web Vital Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockWebVitalRepository {
+    pub samples: Arc<Mutex<Vec<WebVitalSample>>>,
+}
+
+impl MockWebVitalRepository {
+    pub fn new() -> Self {
+        Self {
+            samples: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl WebVitalRepository for MockWebVitalRepository {
+    async fn record(&self, samples: &[WebVitalSample]) -> DomainResult<()> {
+        self.samples.lock().await.extend_from_slice(samples);
+        Ok(())
+    }
+
+    async fn find_since(&self, since: chrono::DateTime<chrono::Utc>) -> DomainResult<Vec<WebVitalSample>> {
+        let mut samples: Vec<WebVitalSample> = self
+            .samples
+            .lock()
+            .await
+            .iter()
+            .filter(|sample| sample.recorded_at >= since)
+            .cloned()
+            .collect();
+        samples.sort_by_key(|sample| sample.recorded_at);
+        Ok(samples)
+    }
+
+    async fn delete_before(&self, before: chrono::DateTime<chrono::Utc>) -> DomainResult<u64> {
+        let mut samples = self.samples.lock().await;
+        let count = samples.len();
+        samples.retain(|sample| sample.recorded_at >= before);
+        Ok((count - samples.len()) as u64)
+    }
+}
+
+// ============================================================================
+// Mock Payment Provider
+// ============================================================================
+
+/// Refunds everything unless told to fail; remembers each refund it was asked for
+#[derive(Clone)]
+pub struct MockPaymentProvider {
+    pub refunded: Arc<Mutex<Vec<TicketRefund>>>,
+    /// Calls that fail as if the provider were down, before the next one succeeds
+    pub unavailable_for: Arc<Mutex<usize>>,
+    /// Every call is refused
+    pub refuses: Arc<Mutex<bool>>,
+}
+
+impl MockPaymentProvider {
+    pub fn new() -> Self {
+        Self {
+            refunded: Arc::new(Mutex::new(Vec::new())),
+            unavailable_for: Arc::new(Mutex::new(0)),
+            refuses: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_unavailable_for(&self, calls: usize) {
+        *self.unavailable_for.lock().await = calls;
+    }
+
+    pub async fn set_refuses(&self, refuses: bool) {
+        *self.refuses.lock().await = refuses;
+    }
+}
+
+#[async_trait]
+impl PaymentProvider for MockPaymentProvider {
+    async fn refund(&self, refund: &TicketRefund) -> DomainResult<String> {
+        self.refunded.lock().await.push(refund.clone());
+        if *self.refuses.lock().await {
+            return Err(DomainError::business_rule("Vipps refused the refund: answered 400 Bad Request"));
+        }
+        let mut unavailable_for = self.unavailable_for.lock().await;
+        if *unavailable_for > 0 {
+            *unavailable_for -= 1;
+            return Err(DomainError::external_service("Vipps", "answered 503 Service Unavailable"));
+        }
+        Ok(format!("psp-{}", refund.id))
+    }
+}
+
+// ============================================================================
+// Mock Exchange Rates
+// ============================================================================
+
+/// Fixed rates to NOK, changeable between purchases
+#[derive(Clone)]
+pub struct MockExchangeRates {
+    pub rates: Arc<Mutex<HashMap<Currency, f64>>>,
+}
+
+impl MockExchangeRates {
+    pub fn new() -> Self {
+        Self {
+            rates: Arc::new(Mutex::new(HashMap::from([(Currency::Eur, 11.5), (Currency::Usd, 10.5)]))),
+        }
+    }
+
+    pub async fn set_rate(&self, currency: Currency, nok_per_unit: f64) {
+        self.rates.lock().await.insert(currency, nok_per_unit);
+    }
+}
+
+#[async_trait]
+impl ExchangeRateProvider for MockExchangeRates {
+    async fn rate_to_nok(&self, currency: Currency) -> DomainResult<ExchangeRate> {
+        let nok_per_unit = self
+            .rates
+            .lock()
+            .await
+            .get(&currency)
+            .copied()
+            .ok_or_else(|| DomainError::external_service("Norges Bank", "no rate"))?;
+        Ok(ExchangeRate {
+            currency,
+            nok_per_unit,
+            date: chrono::Utc::now().date_naive(),
+        })
+    }
+}
+
+// ============================================================================
+// Mock Media Storage
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockMediaStorage {
+    pub files: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+}
+
+impl MockMediaStorage {
+    pub fn new() -> Self {
+        Self {
+            files: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl MediaStorage for MockMediaStorage {
+    async fn store(&self, key: &str, bytes: &[u8]) -> DomainResult<()> {
+        self.files.lock().await.insert(key.to_string(), bytes.to_vec());
+        Ok(())
+    }
+
+    async fn load(&self, key: &str) -> DomainResult<Vec<u8>> {
+        self.files
+            .lock()
+            .await
+            .get(key)
+            .cloned()
+            .ok_or_else(|| DomainError::not_found_by_field("Media", "key", key))
+    }
+
+    async fn delete(&self, key: &str) -> DomainResult<()> {
+        self.files.lock().await.remove(key);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Event Webhooks
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockEventWebhooks {
+    pub published: Arc<Mutex<Vec<Uuid>>>,
+}
+
+impl MockEventWebhooks {
+    pub fn new() -> Self {
+        Self {
+            published: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl EventWebhooks for MockEventWebhooks {
+    async fn event_published(&self, event: &Event) -> DomainResult<()> {
+        self.published.lock().await.push(event.id);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock Export Job Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockExportJobRepository {
+    pub jobs: Arc<Mutex<HashMap<Uuid, ExportJob>>>,
+}
+
+impl MockExportJobRepository {
+    pub fn new() -> Self {
+        Self {
+            jobs: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl ExportJobRepository for MockExportJobRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<ExportJob>> {
+        Ok(self.jobs.lock().await.get(&id).cloned())
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<ExportJob>> {
+        let mut jobs: Vec<ExportJob> = self.jobs.lock().await.values().cloned().collect();
+        jobs.sort_by_key(|job| job.name.to_lowercase());
+        Ok(jobs)
+    }
+
+    async fn find_due(&self, now: chrono::DateTime<chrono::Utc>) -> DomainResult<Vec<ExportJob>> {
+        let mut jobs: Vec<ExportJob> = self
+            .jobs
+            .lock()
+            .await
+            .values()
+            .filter(|job| job.is_active && job.next_run_at.is_some_and(|at| at <= now))
+            .cloned()
+            .collect();
+        jobs.sort_by_key(|job| job.next_run_at);
+        Ok(jobs)
+    }
+
+    async fn create(&self, job: &ExportJob) -> DomainResult<()> {
+        self.jobs.lock().await.insert(job.id, job.clone());
+        Ok(())
+    }
+
+    async fn update(&self, job: &ExportJob) -> DomainResult<()> {
+        let mut jobs = self.jobs.lock().await;
+        if !jobs.contains_key(&job.id) {
+            return Err(DomainError::not_found("ExportJob", job.id));
+        }
+        jobs.insert(job.id, job.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.jobs
+            .lock()
+            .await
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| DomainError::not_found("ExportJob", id))
+    }
+}
+
+// ============================================================================
+// Mock Export Uploader
+// ============================================================================
+
+/// Keeps every delivered file by name unless told the destination is down
+#[derive(Clone)]
+pub struct MockExportUploader {
+    pub files: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    pub unavailable: Arc<Mutex<bool>>,
+}
+
+impl MockExportUploader {
+    pub fn new() -> Self {
+        Self {
+            files: Arc::new(Mutex::new(HashMap::new())),
+            unavailable: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_unavailable(&self, unavailable: bool) {
+        *self.unavailable.lock().await = unavailable;
+    }
+}
+
+#[async_trait]
+impl ExportUploader for MockExportUploader {
+    async fn upload(&self, destination: &ExportDestination, file_name: &str, bytes: &[u8]) -> DomainResult<()> {
+        if *self.unavailable.lock().await {
+            return Err(DomainError::external_service("Export destination", "connection refused"));
+        }
+        self.files.lock().await.insert(file_name.to_string(), bytes.to_vec());
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Mock CRM Connector Repository
+// ============================================================================
+
+#[derive(Clone)]
+pub struct MockCrmConnectorRepository {
+    pub connectors: Arc<Mutex<HashMap<Uuid, CrmConnector>>>,
+    pub records: Arc<Mutex<Vec<CrmSyncRecord>>>,
+}
+
+impl MockCrmConnectorRepository {
+    pub fn new() -> Self {
+        Self {
+            connectors: Arc::new(Mutex::new(HashMap::new())),
+            records: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl CrmConnectorRepository for MockCrmConnectorRepository {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<CrmConnector>> {
+        Ok(self.connectors.lock().await.get(&id).cloned())
+    }
+
+    async fn find_all(&self) -> DomainResult<Vec<CrmConnector>> {
+        let mut connectors: Vec<CrmConnector> = self.connectors.lock().await.values().cloned().collect();
+        connectors.sort_by_key(|connector| connector.name.to_lowercase());
+        Ok(connectors)
+    }
+
+    async fn find_due(&self, now: chrono::DateTime<chrono::Utc>) -> DomainResult<Vec<CrmConnector>> {
+        let mut connectors: Vec<CrmConnector> = self
+            .connectors
+            .lock()
+            .await
+            .values()
+            .filter(|connector| connector.is_active && connector.next_sync_at.is_some_and(|at| at <= now))
+            .cloned()
+            .collect();
+        connectors.sort_by_key(|connector| connector.next_sync_at);
+        Ok(connectors)
+    }
+
+    async fn create(&self, connector: &CrmConnector) -> DomainResult<()> {
+        self.connectors.lock().await.insert(connector.id, connector.clone());
+        Ok(())
+    }
+
+    async fn update(&self, connector: &CrmConnector) -> DomainResult<()> {
+        let mut connectors = self.connectors.lock().await;
+        if !connectors.contains_key(&connector.id) {
+            return Err(DomainError::not_found("CrmConnector", connector.id));
+        }
+        connectors.insert(connector.id, connector.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.records.lock().await.retain(|record| record.connector_id != id);
+        self.connectors
+            .lock()
+            .await
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| DomainError::not_found("CrmConnector", id))
+    }
+
+    async fn find_record(
+        &self,
+        connector_id: Uuid,
+        kind: CrmRecordKind,
+        local_key: &str,
+    ) -> DomainResult<Option<CrmSyncRecord>> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .iter()
+            .find(|record| record.connector_id == connector_id && record.kind == kind && record.local_key == local_key)
+            .cloned())
+    }
+
+    async fn find_records(&self, connector_id: Uuid, state: CrmSyncState, limit: i64) -> DomainResult<Vec<CrmSyncRecord>> {
+        let mut records: Vec<CrmSyncRecord> = self
+            .records
+            .lock()
+            .await
+            .iter()
+            .filter(|record| record.connector_id == connector_id && record.state == state)
+            .cloned()
+            .collect();
+        records.sort_by_key(|record| std::cmp::Reverse(record.updated_at));
+        records.truncate(limit as usize);
+        Ok(records)
+    }
+
+    async fn save_record(&self, record: &CrmSyncRecord) -> DomainResult<()> {
+        let mut records = self.records.lock().await;
+        records.retain(|existing| {
+            (existing.connector_id, existing.kind, &existing.local_key) != (record.connector_id, record.kind, &record.local_key)
+        });
+        records.push(record.clone());
+        Ok(())
+    }
+
+    async fn count_records(&self, connector_id: Uuid) -> DomainResult<CrmSyncCounts> {
+        let mut counts = CrmSyncCounts::default();
+        for record in self.records.lock().await.iter().filter(|record| record.connector_id == connector_id) {
+            match record.state {
+                CrmSyncState::Synced => counts.synced += 1,
+                CrmSyncState::Conflict => counts.conflicts += 1,
+                CrmSyncState::Failed => counts.failed += 1,
+            }
+        }
+        Ok(counts)
+    }
+}
+
+// ============================================================================
+// Mock CRM Client
+// ============================================================================
+
+/// An in-memory CRM holding records by `object/id`; every call fails while
+/// it's unavailable
+#[derive(Clone)]
+pub struct MockCrmClient {
+    pub records: Arc<Mutex<HashMap<String, std::collections::BTreeMap<String, String>>>>,
+    pub writes: Arc<Mutex<usize>>,
+    pub unavailable: Arc<Mutex<bool>>,
+}
+
+impl MockCrmClient {
+    pub fn new() -> Self {
+        Self {
+            records: Arc::new(Mutex::new(HashMap::new())),
+            writes: Arc::new(Mutex::new(0)),
+            unavailable: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub async fn set_unavailable(&self, unavailable: bool) {
+        *self.unavailable.lock().await = unavailable;
+    }
+
+    async fn check(&self) -> DomainResult<()> {
+        match *self.unavailable.lock().await {
+            true => Err(DomainError::external_service("HubSpot", "answered 503 Service Unavailable")),
+            false => Ok(()),
+        }
+    }
+
+    fn record(id: &str, values: &std::collections::BTreeMap<String, String>, properties: &[String]) -> CrmRecord {
+        CrmRecord {
+            id: id.to_string(),
+            properties: values
+                .iter()
+                .filter(|(property, _)| properties.contains(property))
+                .map(|(property, value)| (property.clone(), value.clone()))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl CrmClient for MockCrmClient {
+    async fn find_contact(
+        &self,
+        connector: &CrmConnector,
+        email: &str,
+        properties: &[String],
+    ) -> DomainResult<Option<CrmRecord>> {
+        self.check().await?;
+        let email_property = connector.provider.email_property();
+        Ok(self
+            .records
+            .lock()
+            .await
+            .iter()
+            .find(|(key, values)| {
+                key.starts_with(&format!("{}/", connector.provider.contact_object()))
+                    && values.get(email_property).is_some_and(|value| value.eq_ignore_ascii_case(email))
+            })
+            .map(|(key, values)| Self::record(key.rsplit('/').next().unwrap_or_default(), values, properties)))
+    }
+
+    async fn get(
+        &self,
+        connector: &CrmConnector,
+        object: &str,
+        id: &str,
+        properties: &[String],
+    ) -> DomainResult<Option<CrmRecord>> {
+        self.check().await?;
+        Ok(self
+            .records
+            .lock()
+            .await
+            .get(&format!("{}/{}", object, id))
+            .map(|values| Self::record(id, values, properties)))
+    }
+
+    async fn create(
+        &self,
+        connector: &CrmConnector,
+        object: &str,
+        properties: &std::collections::BTreeMap<String, String>,
+    ) -> DomainResult<String> {
+        self.check().await?;
+        let id = Uuid::new_v4().to_string();
+        self.records.lock().await.insert(format!("{}/{}", object, id), properties.clone());
+        *self.writes.lock().await += 1;
+        Ok(id)
+    }
+
+    async fn update(
+        &self,
+        connector: &CrmConnector,
+        object: &str,
+        id: &str,
+        properties: &std::collections::BTreeMap<String, String>,
+    ) -> DomainResult<()> {
+        self.check().await?;
+        let mut records = self.records.lock().await;
+        let values = records
+            .get_mut(&format!("{}/{}", object, id))
+            .ok_or_else(|| DomainError::external_service("HubSpot", "answered 404 Not Found"))?;
+        values.extend(properties.clone());
+        *self.writes.lock().await += 1;
+        Ok(())
+    }
+}
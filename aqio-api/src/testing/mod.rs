@@ -3,4 +3,5 @@
 // This module provides mock repositories and test helpers for isolated testing
 
 pub mod mocks;
-pub mod helpers;
\ No newline at end of file
+pub mod helpers;
+pub mod app;
\ No newline at end of file
@@ -0,0 +1,173 @@
+// Contract between the API and the web client: JSON fixtures of the API's
+// responses, kept in `fixtures/`, that both sides test against.
+//
+// The API's tests serialize sample DTOs and compare them with the fixtures
+// (`assert_provides`); the web client's tests deserialize the fixtures into
+// its own models (`assert_consumes`). A field renamed or an enum variant
+// respelled on one side then fails the other side's build.
+//
+// Fixtures are kept per contract line, the part of the workspace version a
+// breaking change has to bump: `0.1` for 0.1.x, `1` for 1.x. Within a line the
+// API may only add fields, array items and enum variants. Changing or removing
+// anything else needs a version bump, after which the API's tests record the
+// new line's fixtures on their first run.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Part of `version` that has to change for an incompatible contract change
+pub fn contract_line(version: &str) -> String {
+    let mut parts = version.split('.');
+    match (parts.next(), parts.next()) {
+        (Some("0"), Some(minor)) => format!("0.{}", minor),
+        (Some(major), _) => major.to_string(),
+        _ => version.to_string(),
+    }
+}
+
+fn fixture_path(version: &str, name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(contract_line(version))
+        .join(format!("{}.json", name))
+}
+
+/// The fixture `name` of the contract line of `version`
+pub fn fixture(version: &str, name: &str) -> Value {
+    let path = fixture_path(version, name);
+    let json = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "no contract fixture at {}; run `cargo test -p aqio-api` to record it",
+            path.display()
+        )
+    });
+    serde_json::from_str(&json).unwrap_or_else(|e| panic!("{} isn't valid JSON: {}", path.display(), e))
+}
+
+/// API side: `sample` serializes compatibly with the fixture `name`, which is
+/// recorded when the contract line has none yet.
+///
+/// Additions pass only when recorded with `UPDATE_CONTRACTS=1`, so the web
+/// client's tests see them too.
+pub fn assert_provides<T: Serialize>(version: &str, name: &str, sample: &T) {
+    let actual = serde_json::to_value(sample).expect("sample serializes to JSON");
+    let path = fixture_path(version, name);
+    let Ok(json) = fs::read_to_string(&path) else {
+        record(&path, &actual);
+        return;
+    };
+    let expected: Value =
+        serde_json::from_str(&json).unwrap_or_else(|e| panic!("{} isn't valid JSON: {}", path.display(), e));
+
+    let breaking = breaking_changes(&expected, &actual);
+    assert!(
+        breaking.is_empty(),
+        "{} changed incompatibly within contract line {}:\n  {}\nKeep the old shape, or bump the workspace version so the web client moves with it",
+        name,
+        contract_line(version),
+        breaking.join("\n  ")
+    );
+    if expected != actual {
+        assert!(
+            std::env::var_os("UPDATE_CONTRACTS").is_some(),
+            "{} gained fields; run with UPDATE_CONTRACTS=1 to record them in {}",
+            name,
+            path.display()
+        );
+        record(&path, &actual);
+    }
+}
+
+/// Client side: the fixture `name` deserializes into `T`
+pub fn assert_consumes<T: DeserializeOwned>(version: &str, name: &str) -> T {
+    serde_json::from_value(fixture(version, name))
+        .unwrap_or_else(|e| panic!("the web client can't read the API's {}: {}", name, e))
+}
+
+fn record(path: &Path, value: &Value) {
+    fs::create_dir_all(path.parent().expect("fixture directory")).expect("create fixture directory");
+    let json = serde_json::to_string_pretty(value).expect("fixture serializes");
+    fs::write(path, format!("{}\n", json)).expect("write fixture");
+}
+
+/// Everything in `expected` that `actual` dropped or changed, by JSON path.
+/// New object fields and trailing array items are compatible.
+pub fn breaking_changes(expected: &Value, actual: &Value) -> Vec<String> {
+    let mut changes = Vec::new();
+    compare(expected, actual, "$", &mut changes);
+    changes
+}
+
+fn compare(expected: &Value, actual: &Value, path: &str, changes: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected) in expected {
+                let path = format!("{}.{}", path, key);
+                match actual.get(key) {
+                    Some(actual) => compare(expected, actual, &path, changes),
+                    None => changes.push(format!("{} was removed", path)),
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            for (index, expected) in expected.iter().enumerate() {
+                let path = format!("{}[{}]", path, index);
+                match actual.get(index) {
+                    Some(actual) => compare(expected, actual, &path, changes),
+                    None => changes.push(format!("{} was removed", path)),
+                }
+            }
+        }
+        (expected, actual) if expected != actual => {
+            changes.push(format!("{} changed from {} to {}", path, expected, actual))
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_contract_line_is_the_part_a_breaking_change_bumps() {
+        assert_eq!(contract_line("0.1.0"), "0.1");
+        assert_eq!(contract_line("0.12.3"), "0.12");
+        assert_eq!(contract_line("1.4.2"), "1");
+        assert_eq!(contract_line("2.0.0-beta.1"), "2");
+    }
+
+    #[test]
+    fn test_additions_are_compatible() {
+        let expected = json!({"id": "a", "status": "Registered", "tags": ["x"]});
+        let actual = json!({"id": "a", "status": "Registered", "tags": ["x", "y"], "guest_count": 2});
+        assert!(breaking_changes(&expected, &actual).is_empty());
+    }
+
+    #[test]
+    fn test_renames_removals_and_respelled_variants_break() {
+        let expected = json!({"registrant_name": "Kari", "status": "NoShow", "nested": {"ids": [1, 2]}});
+        let actual = json!({"name": "Kari", "status": "no_show", "nested": {"ids": [1]}});
+        let mut changes = breaking_changes(&expected, &actual);
+        changes.sort();
+        assert_eq!(
+            changes,
+            vec![
+                "$.nested.ids[1] was removed".to_string(),
+                "$.registrant_name was removed".to_string(),
+                r#"$.status changed from "NoShow" to "no_show""#.to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_null_becoming_a_value_or_a_type_change_breaks() {
+        let changes = breaking_changes(&json!({"sent_at": null, "count": 1}), &json!({"sent_at": "2025-06-01", "count": "1"}));
+        assert_eq!(changes.len(), 2);
+    }
+}
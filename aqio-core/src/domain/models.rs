@@ -1,5 +1,6 @@
 use crate::domain::errors::{DomainError, DomainResult};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::BTreeMap;
 use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 use validator::Validate;
@@ -20,16 +21,228 @@ impl<'de> Deserialize<'de> for UserRole {
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        match s.to_lowercase().as_str() {
-            "admin" => Ok(UserRole::Admin),
-            "organizer" => Ok(UserRole::Organizer),
-            "participant" => Ok(UserRole::Participant),
-            _ => Err(serde::de::Error::custom(format!(
+        UserRole::parse(&s).ok_or_else(|| {
+            serde::de::Error::custom(format!(
                 "Invalid user role '{}'. Valid options are: Admin, Organizer, Participant (case insensitive)",
                 s
-            ))),
+            ))
+        })
+    }
+}
+
+impl UserRole {
+    /// What the role allows. Organizers hold `organization:manage` only for
+    /// their own organization; see `PermissionChecker`.
+    pub fn permissions(&self) -> PermissionSet {
+        match self {
+            UserRole::Admin => Permission::ALL.into_iter().collect(),
+            UserRole::Organizer => [
+                Permission::EventCreate,
+                Permission::EventModerate,
+                Permission::RegistrationApprove,
+                Permission::RegistrationCheckIn,
+                Permission::RegistrationManage,
+                Permission::InvitationSend,
+                Permission::UserSearch,
+                Permission::ShiftSignUp,
+                Permission::OrganizationManage,
+            ]
+            .into_iter()
+            .collect(),
+            UserRole::Participant => PermissionSet::default(),
+        }
+    }
+
+    /// Role named in a sign-in token, case insensitive
+    pub fn parse(value: &str) -> Option<UserRole> {
+        match value.to_lowercase().as_str() {
+            "admin" => Some(UserRole::Admin),
+            "organizer" => Some(UserRole::Organizer),
+            "participant" => Some(UserRole::Participant),
+            _ => None,
+        }
+    }
+}
+
+/// Something a user may do, written `resource:action` on the wire
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema)]
+pub enum Permission {
+    /// Create events without going through the moderation queue
+    #[serde(rename = "event:create")]
+    EventCreate,
+    /// Approve or reject events submitted for review
+    #[serde(rename = "event:moderate")]
+    EventModerate,
+    /// Manage events one doesn't organize: their budget, tickets, seating,
+    /// staffing, exhibitors, media and the rest of their setup
+    #[serde(rename = "event:manage")]
+    EventManage,
+    /// Change the status of registrations
+    #[serde(rename = "registration:approve")]
+    RegistrationApprove,
+    #[serde(rename = "registration:check_in")]
+    RegistrationCheckIn,
+    /// See and cancel other people's registrations
+    #[serde(rename = "registration:manage")]
+    RegistrationManage,
+    /// Invite people to events one doesn't organize
+    #[serde(rename = "invitation:send")]
+    InvitationSend,
+    /// Look up users by name, email or company
+    #[serde(rename = "user:search")]
+    UserSearch,
+    /// Take shifts at events
+    #[serde(rename = "shift:sign_up")]
+    ShiftSignUp,
+    /// Create, list and delete user accounts
+    #[serde(rename = "user:manage")]
+    UserManage,
+    #[serde(rename = "user:impersonate")]
+    UserImpersonate,
+    /// Settings, invitations and roles of an organization
+    #[serde(rename = "organization:manage")]
+    OrganizationManage,
+    #[serde(rename = "category:manage")]
+    CategoryManage,
+    /// Review reported comments, photos and questions
+    #[serde(rename = "content:moderate")]
+    ContentModerate,
+    #[serde(rename = "audit:view")]
+    AuditView,
+    /// Email suppressions
+    #[serde(rename = "email:manage")]
+    EmailManage,
+    /// Query metrics and web vitals
+    #[serde(rename = "metrics:view")]
+    MetricsView,
+    /// Scheduled exports to external systems
+    #[serde(rename = "export:manage")]
+    ExportManage,
+    /// CRM connectors and their sync status
+    #[serde(rename = "crm:manage")]
+    CrmManage,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 19] = [
+        Permission::EventCreate,
+        Permission::EventModerate,
+        Permission::EventManage,
+        Permission::RegistrationApprove,
+        Permission::RegistrationCheckIn,
+        Permission::RegistrationManage,
+        Permission::InvitationSend,
+        Permission::UserSearch,
+        Permission::ShiftSignUp,
+        Permission::UserManage,
+        Permission::UserImpersonate,
+        Permission::OrganizationManage,
+        Permission::CategoryManage,
+        Permission::ContentModerate,
+        Permission::AuditView,
+        Permission::EmailManage,
+        Permission::MetricsView,
+        Permission::ExportManage,
+        Permission::CrmManage,
+    ];
+
+    /// Permissions an organization may put in its own roles. They hold for
+    /// the organization's events only.
+    pub const ASSIGNABLE: [Permission; 6] = [
+        Permission::EventCreate,
+        Permission::EventManage,
+        Permission::RegistrationApprove,
+        Permission::RegistrationCheckIn,
+        Permission::RegistrationManage,
+        Permission::InvitationSend,
+    ];
+
+    /// Permissions an organizer may hand someone for one of their events,
+    /// for a limited time
+    pub const DELEGABLE: [Permission; 5] = [
+        Permission::EventManage,
+        Permission::RegistrationApprove,
+        Permission::RegistrationCheckIn,
+        Permission::RegistrationManage,
+        Permission::InvitationSend,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Permission::EventCreate => "event:create",
+            Permission::EventModerate => "event:moderate",
+            Permission::EventManage => "event:manage",
+            Permission::RegistrationApprove => "registration:approve",
+            Permission::RegistrationCheckIn => "registration:check_in",
+            Permission::RegistrationManage => "registration:manage",
+            Permission::InvitationSend => "invitation:send",
+            Permission::UserSearch => "user:search",
+            Permission::ShiftSignUp => "shift:sign_up",
+            Permission::UserManage => "user:manage",
+            Permission::UserImpersonate => "user:impersonate",
+            Permission::OrganizationManage => "organization:manage",
+            Permission::CategoryManage => "category:manage",
+            Permission::ContentModerate => "content:moderate",
+            Permission::AuditView => "audit:view",
+            Permission::EmailManage => "email:manage",
+            Permission::MetricsView => "metrics:view",
+            Permission::ExportManage => "export:manage",
+            Permission::CrmManage => "crm:manage",
         }
     }
+
+    pub fn from_code(code: &str) -> Option<Permission> {
+        Permission::ALL.into_iter().find(|permission| permission.code() == code)
+    }
+
+    pub fn is_assignable(&self) -> bool {
+        Permission::ASSIGNABLE.contains(self)
+    }
+
+    pub fn is_delegable(&self) -> bool {
+        Permission::DELEGABLE.contains(self)
+    }
+}
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Permissions without duplicates, in catalog order
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+pub struct PermissionSet(#[schema(value_type = Vec<Permission>)] std::collections::BTreeSet<Permission>);
+
+impl PermissionSet {
+    pub fn contains(&self, permission: Permission) -> bool {
+        self.0.contains(&permission)
+    }
+
+    pub fn insert(&mut self, permission: Permission) -> bool {
+        self.0.insert(permission)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Permission> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromIterator<Permission> for PermissionSet {
+    fn from_iter<I: IntoIterator<Item = Permission>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Permission> for PermissionSet {
+    fn extend<I: IntoIterator<Item = Permission>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -41,10 +254,116 @@ pub struct User {
     pub company_id: Option<Uuid>,
     pub role: UserRole,
     pub is_active: bool,
+    /// IANA time zone such as `Europe/Oslo`; emails and calendar files use
+    /// the platform default when unset
+    pub timezone: Option<String>,
+    /// Language for emails and calendar files; taken from the browser when unset
+    pub locale: Option<Locale>,
+    /// Colour scheme of the web app; follows the device when unset
+    pub theme: Option<Theme>,
+    /// View the web app's event calendar opens on; month when unset
+    pub calendar_view: Option<CalendarView>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Languages Aqio writes emails and calendar files in
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    /// Norwegian Bokmål
+    Nb,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Nb];
+
+    /// BCP 47 language tag
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Nb => "nb",
+        }
+    }
+
+    /// The locale for a language tag such as `nb-NO`. Norwegian in general
+    /// (`no`) and Nynorsk (`nn`) get Bokmål.
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+        match language.as_str() {
+            "en" => Some(Locale::En),
+            "nb" | "no" | "nn" => Some(Locale::Nb),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Locale::from_tag(&s).ok_or_else(|| {
+            serde::de::Error::custom(format!("Invalid locale '{}'. Valid options are: en, nb", s))
+        })
+    }
+}
+
+/// Colour scheme of the web app
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+impl<'de> Deserialize<'de> for Theme {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "light" => Ok(Theme::Light),
+            "dark" => Ok(Theme::Dark),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid theme '{}'. Valid options are: light, dark (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Layout of the web app's event calendar
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CalendarView {
+    Month,
+    Week,
+    Day,
+}
+
+impl<'de> Deserialize<'de> for CalendarView {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "month" => Ok(CalendarView::Month),
+            "week" => Ok(CalendarView::Week),
+            "day" => Ok(CalendarView::Day),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid calendar view '{}'. Valid options are: month, week, day (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub enum IndustryType {
     Salmon,
@@ -66,6 +385,25 @@ impl<'de> Deserialize<'de> for IndustryType {
     }
 }
 
+impl IndustryType {
+    /// Lowercase name as accepted by deserialization; `Other` loses its description
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IndustryType::Salmon => "salmon",
+            IndustryType::Trout => "trout",
+            IndustryType::Other(_) => "other",
+        }
+    }
+
+    /// Free-text description of an `Other` industry
+    pub fn other_description(&self) -> Option<&str> {
+        match self {
+            IndustryType::Other(description) => Some(description),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Company {
     pub id: Uuid,
@@ -75,10 +413,20 @@ pub struct Company {
     pub industry_type: IndustryType,
     pub website: Option<String>,
     pub phone: Option<String>,
+    /// Where the company is, from the postal code in its location
+    pub region: Option<AddressRegion>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// A user found by name, email or company, for pickers such as choosing
+/// co-organizers and invitees
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UserMatch {
+    pub user: User,
+    pub company_name: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UserProfile {
     pub user_id: Uuid,
@@ -98,7 +446,7 @@ pub struct UserProfile {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 pub struct EventCategory {
     pub id: String,
     pub name: String,
@@ -109,7 +457,28 @@ pub struct EventCategory {
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, ToSchema)]
+impl EventCategory {
+    /// Category for events without a seeded equivalent of their legacy type
+    pub const LEGACY_FALLBACK_ID: &'static str = "meeting";
+
+    /// Seeded category replacing a legacy `event_type` value
+    ///
+    /// Events used to carry a fixed `EventType` (conference, workshop, networking,
+    /// training or a free-form other) before categories moved to the
+    /// `event_categories` table. Matching is case-insensitive; `None` for other
+    /// and unknown types, which callers map to [`Self::LEGACY_FALLBACK_ID`].
+    pub fn id_for_legacy_type(event_type: &str) -> Option<&'static str> {
+        match event_type.trim().to_lowercase().as_str() {
+            "conference" => Some("conf"),
+            "workshop" => Some("workshop"),
+            "networking" => Some("networking"),
+            "training" => Some("training"),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
 pub enum LocationType {
     Physical,
     Virtual,
@@ -134,7 +503,7 @@ impl<'de> Deserialize<'de> for LocationType {
     }
 }
 
-#[derive(Debug, Clone, Serialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
 pub enum EventStatus {
     Draft,
     Published,
@@ -161,10 +530,24 @@ impl<'de> Deserialize<'de> for EventStatus {
     }
 }
 
+/// Whether an event takes registrations at a given moment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum RegistrationState {
+    NotYetOpen,
+    Open,
+    Closed,
+    /// No seats left and no waitlist
+    Full,
+    /// No seats left; new registrations go on the waitlist
+    WaitlistOnly,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Event {
     pub id: Uuid,
     pub title: String,
+    /// URL-friendly identifier for the public event page, fixed at creation
+    pub slug: String,
     pub description: String,
     pub category_id: String,
     
@@ -179,6 +562,11 @@ pub struct Event {
     pub address: Option<String>,
     pub virtual_link: Option<String>,
     pub virtual_access_code: Option<String>,
+    /// WGS84 coordinates of the venue, used for map views and proximity search
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Municipality and county of the address, from its postal code
+    pub region: Option<AddressRegion>,
     
     // Organizer and permissions
     pub organizer_id: Uuid,
@@ -208,12 +596,85 @@ pub struct Event {
     
     // Status
     pub status: EventStatus,
+    /// When a draft is published automatically; `None` leaves publishing to the organizer
+    pub publish_at: Option<DateTime<Utc>>,
     
     // Metadata
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl Event {
+    /// Slug built from the title plus the start of the id, unique without a lookup
+    pub fn slug_for(title: &str, id: Uuid) -> String {
+        let title = title
+            .to_lowercase()
+            .replace('æ', "ae")
+            .replace('ø', "o")
+            .replace('å', "a");
+        let words: Vec<&str> = title
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect();
+        let base: String = words.join("-").chars().take(60).collect();
+        let suffix = &id.simple().to_string()[..8];
+
+        match base.trim_end_matches('-') {
+            "" => suffix.to_string(),
+            base => format!("{}-{}", base, suffix),
+        }
+    }
+
+    /// Published and between its start (inclusive) and end (exclusive)
+    pub fn is_running(&self, now: DateTime<Utc>) -> bool {
+        matches!(self.status, EventStatus::Published) && self.start_date <= now && now < self.end_date
+    }
+
+    /// When registration closes: `registration_closes`, and at the latest when the event starts
+    pub fn registration_closes_at(&self) -> DateTime<Utc> {
+        self.registration_closes
+            .map_or(self.start_date, |closes| closes.min(self.start_date))
+    }
+
+    /// Where registration stands at `now`, following the rules of `can_be_registered_for`
+    ///
+    /// `confirmed` is the number of seats taken; without it a full event shows as `Open`.
+    pub fn registration_state(&self, now: DateTime<Utc>, confirmed: Option<i64>) -> RegistrationState {
+        if matches!(self.status, EventStatus::Cancelled | EventStatus::Completed) || now > self.registration_closes_at() {
+            return RegistrationState::Closed;
+        }
+        if matches!(self.status, EventStatus::Draft) || self.registration_opens.is_some_and(|opens| now < opens) {
+            return RegistrationState::NotYetOpen;
+        }
+        let full = self
+            .max_attendees
+            .zip(confirmed)
+            .is_some_and(|(max, confirmed)| confirmed >= i64::from(max));
+        match (full, self.allow_waitlist) {
+            (false, _) => RegistrationState::Open,
+            (true, true) => RegistrationState::WaitlistOnly,
+            (true, false) => RegistrationState::Full,
+        }
+    }
+
+    /// Makes `new_owner` the organizer. The previous organizer stays on as a
+    /// co-organizer so they keep access to the event.
+    pub fn transfer_to(&mut self, new_owner: Uuid, now: DateTime<Utc>) {
+        let previous = self.organizer_id;
+        self.co_organizers.retain(|id| *id != new_owner && *id != previous);
+        if previous != new_owner {
+            self.co_organizers.push(previous);
+        }
+        self.organizer_id = new_owner;
+        self.updated_at = now;
+    }
+
+    /// A draft whose scheduled publish time has come
+    pub fn is_due_for_publishing(&self, now: DateTime<Utc>) -> bool {
+        matches!(self.status, EventStatus::Draft) && self.publish_at.is_some_and(|publish_at| publish_at <= now)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub enum InvitationMethod {
     Email,
@@ -296,7 +757,13 @@ pub struct EventInvitation {
     pub status: InvitationStatus,
     pub sent_at: Option<DateTime<Utc>>,
     pub opened_at: Option<DateTime<Utc>>,
+    /// First time a tracked link in the invitation email was followed
+    pub clicked_at: Option<DateTime<Utc>>,
     pub responded_at: Option<DateTime<Utc>>,
+    /// Set when the mail provider reports the invitation email bounced or
+    /// was marked as spam; nothing more is sent for the invitation
+    pub delivery_failure: Option<DeliveryFailure>,
+    pub delivery_failed_at: Option<DateTime<Utc>>,
     
     // Invitation token for secure RSVP links
     pub invitation_token: Option<String>,
@@ -400,6 +867,8 @@ pub struct EventRegistration {
     // Waitlist management
     pub waitlist_position: Option<i32>,
     pub waitlist_added_at: Option<DateTime<Utc>>,
+    // Set when promoted from the waitlist; the spot is released if not confirmed by then
+    pub promotion_expires_at: Option<DateTime<Utc>>,
     
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -420,131 +889,4796 @@ pub struct ExternalContact {
     pub updated_at: DateTime<Utc>,
 }
 
-// Domain filtering and pagination
+// Event discussion
 
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
-pub struct EventFilter {
-    #[validate(length(min = 1, max = 100))]
-    pub title_contains: Option<String>,
-    
-    pub category_id: Option<String>,
-    pub organizer_id: Option<Uuid>,
-    pub is_private: Option<bool>,
-    pub status: Option<EventStatus>,
-    pub location_type: Option<LocationType>,
-    pub start_date_from: Option<DateTime<Utc>>,
-    pub start_date_to: Option<DateTime<Utc>>,
+/// A post in an event's discussion; replies point at the comment they answer
+///
+/// `author_name` is denormalized so the thread stays readable after the author
+/// is deleted. Deleted comments keep their row, with `deleted_at`/`deleted_by`
+/// set, so replies to them keep their place in the thread.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventComment {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub parent_comment_id: Option<Uuid>,
+    pub author_id: Option<Uuid>,
+    pub author_name: Option<String>,
+    pub content: String,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EventComment {
+    pub fn new(event_id: Uuid, author: &User, content: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            parent_comment_id: None,
+            author_id: Some(author.id),
+            author_name: Some(author.name.clone()),
+            content: content.into(),
+            deleted_at: None,
+            deleted_by: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
 }
 
+// Live engagement while an event is running
+
+/// A question from the audience; attendees upvote the ones they want answered
+///
+/// `upvote_count` is aggregated by the repository when the question is loaded.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
-pub struct PaginationParams {
-    pub offset: i64,
-    pub limit: i64,
+pub struct EventQuestion {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub author_id: Option<Uuid>,
+    pub author_name: Option<String>,
+    pub content: String,
+    pub upvote_count: i64,
+    pub answer: Option<String>,
+    pub answered_by: Option<Uuid>,
+    pub answered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
-impl PaginationParams {
-    pub fn new(offset: i64, limit: i64) -> DomainResult<Self> {
-        if offset < 0 {
-            return Err(DomainError::validation(
-                "offset",
-                "Offset must be non-negative"
-            ));
-        }
-        
-        if limit <= 0 || limit > 1000 {
-            return Err(DomainError::validation(
-                "limit",
-                "Limit must be between 1 and 1000"
-            ));
+impl EventQuestion {
+    pub fn new(event_id: Uuid, author: &User, content: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            author_id: Some(author.id),
+            author_name: Some(author.name.clone()),
+            content: content.into(),
+            upvote_count: 0,
+            answer: None,
+            answered_by: None,
+            answered_at: None,
+            created_at: now,
+            updated_at: now,
         }
-        
-        Ok(Self { offset, limit })
     }
-}
 
-impl Default for PaginationParams {
-    fn default() -> Self {
-        Self { offset: 0, limit: 50 }
+    pub fn is_answered(&self) -> bool {
+        self.answered_at.is_some()
     }
 }
 
+/// A single-choice poll run by the organizer; closed polls keep their results
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
-pub struct PaginatedResult<T> {
-    pub items: Vec<T>,
-    pub total_count: i64,
-    pub offset: i64,
-    pub limit: i64,
-    pub has_next: bool,
+pub struct EventPoll {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub question: String,
+    /// In display order
+    pub options: Vec<PollOption>,
+    pub created_by: Option<Uuid>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
-impl<T> PaginatedResult<T> {
-    pub fn new(items: Vec<T>, total_count: i64, pagination: PaginationParams) -> Self {
-        let has_next = pagination.offset + pagination.limit < total_count;
-        
+impl EventPoll {
+    pub fn new(event_id: Uuid, created_by: Uuid, question: impl Into<String>, options: Vec<String>) -> Self {
+        let now = Utc::now();
+        let id = Uuid::new_v4();
         Self {
-            items,
-            total_count,
-            offset: pagination.offset,
-            limit: pagination.limit,
-            has_next,
+            id,
+            event_id,
+            question: question.into(),
+            options: options
+                .into_iter()
+                .enumerate()
+                .map(|(position, label)| PollOption {
+                    id: Uuid::new_v4(),
+                    poll_id: id,
+                    label,
+                    position: position as i32,
+                    vote_count: 0,
+                })
+                .collect(),
+            created_by: Some(created_by),
+            closed_at: None,
+            created_at: now,
+            updated_at: now,
         }
     }
-}
 
-// Domain validation traits
+    pub fn is_closed(&self) -> bool {
+        self.closed_at.is_some()
+    }
 
-pub trait DomainValidation {
-    fn validate_for_creation(&self) -> DomainResult<()>;
+    pub fn total_votes(&self) -> i64 {
+        self.options.iter().map(|option| option.vote_count).sum()
+    }
 }
 
-pub trait EventDomainValidation {
-    fn can_be_registered_for(&self, current_time: DateTime<Utc>) -> DomainResult<()>;
+/// `vote_count` is aggregated by the repository when the poll is loaded
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PollOption {
+    pub id: Uuid,
+    pub poll_id: Uuid,
+    pub label: String,
+    pub position: i32,
+    pub vote_count: i64,
 }
 
-pub trait InvitationDomainValidation {
-    fn can_respond(&self, current_time: DateTime<Utc>) -> DomainResult<()>;
+/// One attendee's answer to a poll; voting again replaces it
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PollVote {
+    pub poll_id: Uuid,
+    pub option_id: Uuid,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
 }
 
-// Domain validation implementations
+// Event materials (slides, handouts, maps)
 
-impl DomainValidation for Event {
-    fn validate_for_creation(&self) -> DomainResult<()> {
-        if self.title.trim().is_empty() {
-            return Err(DomainError::validation("title", "Title cannot be empty"));
-        }
-        
-        if self.title.len() > 200 {
-            return Err(DomainError::validation("title", "Title cannot exceed 200 characters"));
-        }
-        
-        if self.description.trim().is_empty() {
-            return Err(DomainError::validation("description", "Description cannot be empty"));
-        }
-        
-        if self.start_date >= self.end_date {
-            return Err(DomainError::validation(
-                "dates",
-                "End date must be after start date"
-            ));
-        }
-        
-        Ok(())
-    }
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+pub enum AttachmentCategory {
+    Presentation,
+    Document,
+    Photo,
+    Video,
+    Audio,
+    Other,
 }
 
-impl EventDomainValidation for Event {
-    fn can_be_registered_for(&self, current_time: DateTime<Utc>) -> DomainResult<()> {
-        if current_time > self.start_date {
-            return Err(DomainError::business_rule(
-                "Cannot register for an event that has already started"
-            ));
+impl AttachmentCategory {
+    /// Best guess from the uploaded file's MIME type, used when the organizer doesn't pick one
+    pub fn from_mime_type(mime_type: &str) -> Self {
+        let mime_type = mime_type.to_lowercase();
+        match mime_type.split('/').next().unwrap_or_default() {
+            "image" => AttachmentCategory::Photo,
+            "video" => AttachmentCategory::Video,
+            "audio" => AttachmentCategory::Audio,
+            _ if mime_type.contains("presentation") || mime_type.contains("powerpoint") => {
+                AttachmentCategory::Presentation
+            }
+            "text" => AttachmentCategory::Document,
+            _ if mime_type == "application/pdf"
+                || mime_type.contains("document")
+                || mime_type.contains("msword")
+                || mime_type.contains("spreadsheet") =>
+            {
+                AttachmentCategory::Document
+            }
+            _ => AttachmentCategory::Other,
         }
-        
-        Ok(())
     }
 }
 
-impl DomainValidation for User {
+impl<'de> Deserialize<'de> for AttachmentCategory {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "presentation" => Ok(AttachmentCategory::Presentation),
+            "document" => Ok(AttachmentCategory::Document),
+            "photo" => Ok(AttachmentCategory::Photo),
+            "video" => Ok(AttachmentCategory::Video),
+            "audio" => Ok(AttachmentCategory::Audio),
+            "other" => Ok(AttachmentCategory::Other),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid attachment category '{}'. Valid options are: Presentation, Document, Photo, Video, Audio, Other (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Who may see and download an attachment
+///
+/// Organizers and administrators can always access every attachment on their events.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+pub enum AttachmentVisibility {
+    /// Anyone who can see the event, including visitors to its public page
+    Public,
+    /// Only users with a confirmed registration (registered or attended)
+    AttendeesOnly,
+}
+
+impl<'de> Deserialize<'de> for AttachmentVisibility {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "public" => Ok(AttachmentVisibility::Public),
+            "attendeesonly" | "attendees_only" => Ok(AttachmentVisibility::AttendeesOnly),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid attachment visibility '{}'. Valid options are: Public, AttendeesOnly (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A file attached to an event; the bytes live in media storage under `storage_key`
+///
+/// `uploader_name` is denormalized so the listing stays readable after the
+/// uploader is deleted.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventAttachment {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub uploader_id: Option<Uuid>,
+    pub uploader_name: Option<String>,
+    /// Name the file was uploaded with, offered again on download
+    pub original_filename: String,
+    /// Sanitized file name used in `storage_key`
+    pub filename: String,
+    pub storage_key: String,
+    pub file_size: i64,
+    pub mime_type: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub category: AttachmentCategory,
+    pub visibility: AttachmentVisibility,
+    pub download_count: i64,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+impl EventAttachment {
+    pub fn new(
+        event_id: Uuid,
+        uploader: &User,
+        original_filename: impl Into<String>,
+        mime_type: impl Into<String>,
+        file_size: i64,
+    ) -> Self {
+        let id = Uuid::new_v4();
+        let original_filename = original_filename.into();
+        let mime_type = mime_type.into();
+        let filename = Self::sanitize_filename(&original_filename);
+        Self {
+            id,
+            event_id,
+            uploader_id: Some(uploader.id),
+            uploader_name: Some(uploader.name.clone()),
+            storage_key: format!("events/{}/attachments/{}-{}", event_id, id, filename),
+            filename,
+            file_size,
+            category: AttachmentCategory::from_mime_type(&mime_type),
+            mime_type,
+            original_filename,
+            title: None,
+            description: None,
+            visibility: AttachmentVisibility::AttendeesOnly,
+            download_count: 0,
+            uploaded_at: Utc::now(),
+        }
+    }
+
+    /// Keeps ASCII letters, digits, `.`, `-` and `_` so the name is safe in a storage path
+    pub fn sanitize_filename(name: &str) -> String {
+        // Browsers on Windows may send the full client path
+        let name = name.rsplit(['/', '\\']).next().unwrap_or_default();
+        let sanitized: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' })
+            .collect();
+        let sanitized = sanitized.trim_start_matches('.');
+        if sanitized.is_empty() {
+            "file".to_string()
+        } else {
+            sanitized.to_string()
+        }
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.visibility == AttachmentVisibility::Public
+    }
+}
+
+// Event photos
+
+/// Where a photo stands in the gallery's moderation
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, ToSchema)]
+pub enum PhotoStatus {
+    /// Uploaded by an attendee and waiting for an organizer
+    Pending,
+    /// Shown in the gallery
+    Approved,
+    /// Turned down by an organizer; only its uploader still sees it
+    Rejected,
+}
+
+impl<'de> Deserialize<'de> for PhotoStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(PhotoStatus::Pending),
+            "approved" => Ok(PhotoStatus::Approved),
+            "rejected" => Ok(PhotoStatus::Rejected),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid photo status '{}'. Valid options are: Pending, Approved, Rejected (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// How an event's photo album is run. Events whose organizers haven't changed
+/// anything get `PhotoAlbum::new`: only organizers add photos, and the gallery
+/// stays off the public page.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PhotoAlbum {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    /// Whether attendees may add photos too
+    pub attendee_uploads: bool,
+    /// Whether attendees' photos wait for an organizer before they're shown
+    pub requires_approval: bool,
+    /// Whether approved photos are shown on the event's public page
+    pub is_public: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PhotoAlbum {
+    pub fn new(event_id: Uuid, created_by: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            attendee_uploads: false,
+            requires_approval: true,
+            is_public: false,
+            created_by,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// A photo from an event, added once it's over; the bytes live in media storage
+/// under `storage_key`
+///
+/// `uploader_name` is denormalized so the gallery stays readable after the
+/// uploader is deleted.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventPhoto {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub uploader_id: Option<Uuid>,
+    pub uploader_name: Option<String>,
+    /// Name the file was uploaded with, offered again on download
+    pub original_filename: String,
+    /// Sanitized file name used in `storage_key`
+    pub filename: String,
+    pub storage_key: String,
+    pub file_size: i64,
+    pub mime_type: String,
+    pub caption: Option<String>,
+    pub status: PhotoStatus,
+    pub uploaded_at: DateTime<Utc>,
+    /// When an organizer last approved or rejected it
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl EventPhoto {
+    pub fn new(
+        event_id: Uuid,
+        uploader: &User,
+        original_filename: impl Into<String>,
+        mime_type: impl Into<String>,
+        file_size: i64,
+        status: PhotoStatus,
+    ) -> Self {
+        let id = Uuid::new_v4();
+        let original_filename = original_filename.into();
+        let filename = EventAttachment::sanitize_filename(&original_filename);
+        Self {
+            id,
+            event_id,
+            uploader_id: Some(uploader.id),
+            uploader_name: Some(uploader.name.clone()),
+            storage_key: format!("events/{}/photos/{}-{}", event_id, id, filename),
+            filename,
+            original_filename,
+            file_size,
+            mime_type: mime_type.into(),
+            caption: None,
+            status,
+            uploaded_at: Utc::now(),
+            reviewed_at: None,
+        }
+    }
+
+    pub fn is_approved(&self) -> bool {
+        self.status == PhotoStatus::Approved
+    }
+}
+
+// Event budgets
+
+/// A planned amount for one kind of cost, e.g. venue or catering
+///
+/// Amounts are in minor currency units (øre) so totals add up exactly.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BudgetLine {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub name: String,
+    pub planned_amount: i64,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BudgetLine {
+    pub fn new(event_id: Uuid, name: impl Into<String>, planned_amount: i64) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            name: name.into(),
+            planned_amount,
+            notes: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Scanned receipt for an expense; the bytes live in media storage under `storage_key`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExpenseReceipt {
+    /// Name the file was uploaded with, offered again on download
+    pub original_filename: String,
+    pub storage_key: String,
+    pub file_size: i64,
+    pub mime_type: String,
+}
+
+/// Money spent on an event, optionally booked against one of its budget lines
+///
+/// `recorded_by_name` is denormalized so the ledger stays readable after the
+/// user is deleted.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventExpense {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    /// `None` for expenses that don't fit any line; deleting a line unassigns its expenses
+    pub budget_line_id: Option<Uuid>,
+    pub description: String,
+    pub vendor: Option<String>,
+    pub amount: i64,
+    pub incurred_on: NaiveDate,
+    pub receipt: Option<ExpenseReceipt>,
+    pub recorded_by: Option<Uuid>,
+    pub recorded_by_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EventExpense {
+    pub fn new(event_id: Uuid, recorded_by: &User, description: impl Into<String>, amount: i64, incurred_on: NaiveDate) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            budget_line_id: None,
+            description: description.into(),
+            vendor: None,
+            amount,
+            incurred_on,
+            receipt: None,
+            recorded_by: Some(recorded_by.id),
+            recorded_by_name: Some(recorded_by.name.clone()),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Storage key for a receipt uploaded as `filename`
+    pub fn receipt_key(&self, filename: &str) -> String {
+        format!(
+            "events/{}/receipts/{}-{}",
+            self.event_id,
+            self.id,
+            EventAttachment::sanitize_filename(filename)
+        )
+    }
+}
+
+/// Planned against spent for one budget line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BudgetLineTotals {
+    pub budget_line_id: Uuid,
+    pub name: String,
+    pub planned: i64,
+    pub spent: i64,
+}
+
+impl BudgetLineTotals {
+    /// Negative when the line is over budget
+    pub fn remaining(&self) -> i64 {
+        self.planned - self.spent
+    }
+}
+
+/// An event's totals: what was planned, what has been spent and where
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BudgetSummary {
+    pub planned: i64,
+    pub spent: i64,
+    /// Spent on expenses not booked against any line
+    pub unassigned: i64,
+    pub expense_count: usize,
+    /// In the order the lines were given
+    pub lines: Vec<BudgetLineTotals>,
+}
+
+impl BudgetSummary {
+    pub fn new(lines: &[BudgetLine], expenses: &[EventExpense]) -> Self {
+        let spent_on = |line_id: Option<Uuid>| {
+            expenses
+                .iter()
+                .filter(|expense| expense.budget_line_id == line_id)
+                .map(|expense| expense.amount)
+                .sum::<i64>()
+        };
+        let lines: Vec<BudgetLineTotals> = lines
+            .iter()
+            .map(|line| BudgetLineTotals {
+                budget_line_id: line.id,
+                name: line.name.clone(),
+                planned: line.planned_amount,
+                spent: spent_on(Some(line.id)),
+            })
+            .collect();
+
+        // Expenses pointing at a line that isn't in `lines` count as unassigned
+        let spent: i64 = expenses.iter().map(|expense| expense.amount).sum();
+        let assigned: i64 = lines.iter().map(|line| line.spent).sum();
+        Self {
+            planned: lines.iter().map(|line| line.planned).sum(),
+            spent,
+            unassigned: spent - assigned,
+            expense_count: expenses.len(),
+            lines,
+        }
+    }
+
+    /// Negative when the event is over budget
+    pub fn remaining(&self) -> i64 {
+        self.planned - self.spent
+    }
+
+    pub fn is_over_budget(&self) -> bool {
+        self.spent > self.planned
+    }
+}
+
+// Event sponsors
+
+/// Sponsorship level, highest first; sponsors are listed tier by tier
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema)]
+pub enum SponsorTier {
+    Platinum,
+    Gold,
+    Silver,
+    Bronze,
+    /// In-kind and media partners
+    Partner,
+}
+
+impl SponsorTier {
+    pub const ALL: [SponsorTier; 5] = [
+        SponsorTier::Platinum,
+        SponsorTier::Gold,
+        SponsorTier::Silver,
+        SponsorTier::Bronze,
+        SponsorTier::Partner,
+    ];
+}
+
+impl<'de> Deserialize<'de> for SponsorTier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "platinum" => Ok(SponsorTier::Platinum),
+            "gold" => Ok(SponsorTier::Gold),
+            "silver" => Ok(SponsorTier::Silver),
+            "bronze" => Ok(SponsorTier::Bronze),
+            "partner" => Ok(SponsorTier::Partner),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid sponsor tier '{}'. Valid options are: Platinum, Gold, Silver, Bronze, Partner (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A company sponsoring an event, shown on its public page and printed program
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventSponsor {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub name: String,
+    pub tier: SponsorTier,
+    pub logo_url: Option<String>,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    /// Position within the tier, lowest first
+    pub display_order: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EventSponsor {
+    pub fn new(event_id: Uuid, name: impl Into<String>, tier: SponsorTier) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            name: name.into(),
+            tier,
+            logo_url: None,
+            website_url: None,
+            description: None,
+            display_order: 0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Orders sponsors for display: by tier, then `display_order`, then name
+    pub fn sort_for_display(sponsors: &mut [EventSponsor]) {
+        sponsors.sort_by(|a, b| {
+            a.tier
+                .cmp(&b.tier)
+                .then(a.display_order.cmp(&b.display_order))
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+    }
+}
+
+// Scheduling rules
+
+/// What happens when an event's dates break a scheduling rule
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum RuleEnforcement {
+    /// The organizer is told about the collision but may go ahead
+    Warn,
+    /// Events can't be created on, or moved to, the dates
+    Block,
+}
+
+impl RuleEnforcement {
+    pub const ALL: [RuleEnforcement; 2] = [RuleEnforcement::Warn, RuleEnforcement::Block];
+}
+
+impl<'de> Deserialize<'de> for RuleEnforcement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "warn" => Ok(RuleEnforcement::Warn),
+            "block" => Ok(RuleEnforcement::Block),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid rule enforcement '{}'. Valid options are: Warn, Block (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Days an organization keeps free of events, such as a national holiday or an
+/// industry fair its members attend
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlackoutPeriod {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub name: String,
+    pub start_date: NaiveDate,
+    /// Last day of the period, inclusive
+    pub end_date: NaiveDate,
+    pub enforcement: RuleEnforcement,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BlackoutPeriod {
+    pub fn new(
+        company_id: Uuid,
+        name: impl Into<String>,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        enforcement: RuleEnforcement,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            company_id,
+            name: name.into(),
+            start_date,
+            end_date,
+            enforcement,
+            created_by,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether any day from `first_day` through `last_day` falls in the period
+    pub fn overlaps(&self, first_day: NaiveDate, last_day: NaiveDate) -> bool {
+        self.start_date <= last_day && first_day <= self.end_date
+    }
+}
+
+/// An organization's standing rules for when its members' events may take place
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SchedulingPolicy {
+    pub company_id: Uuid,
+    /// Most events the organization's members may start on the same day
+    pub max_events_per_day: Option<i32>,
+    pub allow_weekends: bool,
+    /// Days of notice required between creating an event and its start
+    pub min_notice_days: Option<i32>,
+    /// Applies to the rules above; blackout periods carry their own
+    pub enforcement: RuleEnforcement,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SchedulingPolicy {
+    /// A policy with no limits, which is what organizations without one get
+    pub fn new(company_id: Uuid) -> Self {
+        Self {
+            company_id,
+            max_events_per_day: None,
+            allow_weekends: true,
+            min_notice_days: None,
+            enforcement: RuleEnforcement::Warn,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum ScheduleRule {
+    Blackout,
+    DailyCapacity,
+    Weekend,
+    MinimumNotice,
+}
+
+/// A scheduling rule an event's dates break
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub struct ScheduleConflict {
+    pub rule: ScheduleRule,
+    pub enforcement: RuleEnforcement,
+    pub message: String,
+}
+
+impl ScheduleConflict {
+    pub fn is_blocking(&self) -> bool {
+        self.enforcement == RuleEnforcement::Block
+    }
+}
+
+// Custom event fields
+
+/// Kind of value a custom event field holds
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum EventFieldType {
+    Text,
+    Number,
+    /// A calendar day
+    Date,
+    Boolean,
+    /// One of the field's options
+    Select,
+}
+
+impl EventFieldType {
+    pub const ALL: [EventFieldType; 5] = [
+        EventFieldType::Text,
+        EventFieldType::Number,
+        EventFieldType::Date,
+        EventFieldType::Boolean,
+        EventFieldType::Select,
+    ];
+}
+
+impl<'de> Deserialize<'de> for EventFieldType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "text" => Ok(EventFieldType::Text),
+            "number" => Ok(EventFieldType::Number),
+            "date" => Ok(EventFieldType::Date),
+            "boolean" => Ok(EventFieldType::Boolean),
+            "select" => Ok(EventFieldType::Select),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid event field type '{}'. Valid options are: Text, Number, Date, Boolean, Select (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A structured field an organization adds to its members' events, such as
+/// "Fish species focus" or "Regulatory topic"
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventFieldDefinition {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    /// Identifies the field in API requests, e.g. `species_focus`; fixed once created
+    pub key: String,
+    pub label: String,
+    /// Fixed once created, so stored values keep their meaning
+    pub field_type: EventFieldType,
+    /// Choices of a `Select` field, in display order; empty for other types
+    pub options: Vec<String>,
+    /// Whether organizers must fill it in when they set an event's fields
+    pub is_required: bool,
+    /// Order among the organization's fields, lowest first
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EventFieldDefinition {
+    pub fn new(
+        company_id: Uuid,
+        key: impl Into<String>,
+        label: impl Into<String>,
+        field_type: EventFieldType,
+        options: Vec<String>,
+        is_required: bool,
+        position: i32,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            company_id,
+            key: key.into(),
+            label: label.into(),
+            field_type,
+            options,
+            is_required,
+            position,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// An event's value for one custom field, in the field type's canonical form:
+/// numbers without trailing zeros, dates as `YYYY-MM-DD`, booleans as `true`
+/// or `false` and select values spelled as the option
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EventFieldValue {
+    pub field_id: Uuid,
+    pub value: String,
+}
+
+/// Events whose value for a custom field is `value`, ignoring case. The value
+/// is compared in canonical form, as stored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EventFieldMatch {
+    pub field_id: Uuid,
+    pub value: String,
+}
+
+// Business register lookups
+
+/// A company as the Norwegian business register (Brønnøysundregistrene) has
+/// it, used to check and fill in companies by organization number
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct RegisteredCompany {
+    /// Nine digits, e.g. `923609016`
+    pub org_number: String,
+    pub name: String,
+    /// Business address on one line, e.g. `Sjøgata 1, 8006 Bodø`
+    pub address: Option<String>,
+    /// Town of the business address, e.g. `Bodø`
+    pub city: Option<String>,
+    pub website: Option<String>,
+    /// Primary NACE industry code, e.g. `03.211`
+    pub industry_code: Option<String>,
+    pub industry_description: Option<String>,
+    /// False once the company is bankrupt, being wound up or deleted
+    pub is_active: bool,
+}
+
+// Postal regions
+
+/// Where in Norway an address lies, looked up from its postal code
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AddressRegion {
+    /// Four digits, e.g. `5003`
+    pub postal_code: String,
+    /// Kommune, e.g. `Bergen`
+    pub municipality: String,
+    /// Fylke, e.g. `Vestland`
+    pub county: String,
+}
+
+// Weather forecasts
+
+/// Forecast weather at an event's venue over one local day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct WeatherForecast {
+    pub date: NaiveDate,
+    /// MET Norway weather symbol around midday, e.g. `partlycloudy_day`
+    pub symbol_code: Option<String>,
+    pub min_temperature_c: f64,
+    pub max_temperature_c: f64,
+    /// Total over the day
+    pub precipitation_mm: f64,
+    /// Highest mean wind speed, in metres per second
+    pub max_wind_speed_ms: f64,
+}
+
+// Public transport travel planning
+
+/// How one leg of a journey is travelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TravelMode {
+    Foot,
+    Bus,
+    Coach,
+    Tram,
+    Metro,
+    Rail,
+    /// Ferries and express boats
+    Water,
+    Air,
+    Other,
+}
+
+/// One leg of a journey, on foot or on a single line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TravelLeg {
+    pub mode: TravelMode,
+    pub from_place: String,
+    pub to_place: String,
+    pub departure: DateTime<Utc>,
+    pub arrival: DateTime<Utc>,
+    /// Public code or name of the line, e.g. `1` or `Bergen - Stavanger`
+    pub line: Option<String>,
+    pub operator: Option<String>,
+    pub distance_m: f64,
+}
+
+/// A suggested journey to an event's venue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TravelOption {
+    pub departure: DateTime<Utc>,
+    pub arrival: DateTime<Utc>,
+    pub duration_minutes: i64,
+    pub walk_distance_m: f64,
+    pub legs: Vec<TravelLeg>,
+}
+
+impl TravelOption {
+    /// Coastal venues are often reached by boat
+    pub fn uses_ferry(&self) -> bool {
+        self.legs.iter().any(|leg| leg.mode == TravelMode::Water)
+    }
+
+    /// Changes between vehicles; walking between them doesn't count
+    pub fn transfers(&self) -> usize {
+        self.legs.iter().filter(|leg| leg.mode != TravelMode::Foot).count().saturating_sub(1)
+    }
+}
+
+/// Journeys wanted between two coordinates
+#[derive(Debug, Clone, PartialEq)]
+pub struct TravelQuery {
+    pub from_latitude: f64,
+    pub from_longitude: f64,
+    pub to_latitude: f64,
+    pub to_longitude: f64,
+    /// Arrival time when `arrive_by`, otherwise departure time
+    pub time: DateTime<Utc>,
+    pub arrive_by: bool,
+    pub limit: u32,
+}
+
+// Short links
+
+/// `utm_*` parameters added to the event page address when a short link is followed
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CampaignParameters {
+    /// `utm_source`, e.g. `newsletter`
+    pub source: Option<String>,
+    /// `utm_medium`, e.g. `email`
+    pub medium: Option<String>,
+    /// `utm_campaign`, e.g. `spring-2026`
+    pub campaign: Option<String>,
+    /// `utm_content`, to tell links in the same campaign apart
+    pub content: Option<String>,
+}
+
+impl CampaignParameters {
+    pub fn is_empty(&self) -> bool {
+        self.source.is_none() && self.medium.is_none() && self.campaign.is_none() && self.content.is_none()
+    }
+}
+
+/// A short, trackable address for an event's public page, e.g. `/e/K7dx2Qm`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ShortLink {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    /// Seven letters and digits starting with an uppercase letter; event slugs
+    /// are lowercase, so codes and slugs can share `/e/`
+    pub code: String,
+    /// What the organizer uses the link for, e.g. `LinkedIn post`
+    pub label: Option<String>,
+    pub campaign: CampaignParameters,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShortLink {
+    pub const CODE_LENGTH: usize = 7;
+
+    /// Whether `text` has the shape of a short link code rather than an event slug
+    pub fn is_code(text: &str) -> bool {
+        text.len() == Self::CODE_LENGTH
+            && text.starts_with(|c: char| c.is_ascii_uppercase())
+            && text.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+}
+
+/// One time a short link was followed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ShortLinkClick {
+    pub id: Uuid,
+    pub link_id: Uuid,
+    /// Set when the link was followed from an invitation's personal copy
+    pub invitation_id: Option<Uuid>,
+    /// Host of the page the visitor came from, e.g. `www.linkedin.com`
+    pub referrer_host: Option<String>,
+    pub clicked_at: DateTime<Utc>,
+}
+
+/// Clicks on one of an event's short links so far
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ShortLinkClicks {
+    pub link_id: Uuid,
+    pub clicks: i64,
+    pub last_clicked_at: Option<DateTime<Utc>>,
+}
+
+/// Clicks on a short link on one day (UTC)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DailyClicks {
+    pub date: NaiveDate,
+    pub clicks: i64,
+}
+
+/// Clicks on a short link from one referring site
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReferrerClicks {
+    /// Empty for visitors who came without a referrer, e.g. from an email client
+    pub host: String,
+    pub clicks: i64,
+}
+
+/// How a short link has been used
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ShortLinkStats {
+    pub link_id: Uuid,
+    pub clicks: i64,
+    pub last_clicked_at: Option<DateTime<Utc>>,
+    /// Clicks from invitations' personal copies of the link
+    pub invitation_clicks: i64,
+    /// Invitations whose personal copy was followed at least once
+    pub invitations_clicked: i64,
+    /// Days with clicks, oldest first
+    pub daily: Vec<DailyClicks>,
+    /// Most clicks first
+    pub referrers: Vec<ReferrerClicks>,
+}
+
+// Overbooking
+
+/// Registrations accepted beyond an event's capacity, expecting some
+/// registrants not to show up
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct OverbookingPolicy {
+    pub event_id: Uuid,
+    /// Extra registrations accepted, in percent of `max_attendees`
+    pub percent: i32,
+    /// Registrations never go beyond this, whatever the percentage
+    pub hard_ceiling: Option<i32>,
+    pub updated_by: Uuid,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OverbookingPolicy {
+    pub const MAX_PERCENT: i32 = 50;
+
+    /// Registrations accepted for an event seating `capacity`; never less
+    /// than the capacity itself
+    pub fn bookable_capacity(&self, capacity: i32) -> i32 {
+        let extra = (capacity as i64 * self.percent.clamp(0, Self::MAX_PERCENT) as i64 / 100) as i32;
+        match self.hard_ceiling {
+            Some(ceiling) => (capacity + extra).min(ceiling).max(capacity),
+            None => capacity + extra,
+        }
+    }
+}
+
+/// How registrations for an organizer's past events turned out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct NoShowHistory {
+    /// Past events with at least one registration
+    pub events: i64,
+    /// Registrations that weren't cancelled or left on the waitlist
+    pub registrations: i64,
+    pub checked_in: i64,
+}
+
+impl NoShowHistory {
+    /// Fewer past events than this give no suggestion
+    pub const MIN_EVENTS: i64 = 3;
+    // Share of the expected no-shows the suggestion fills, leaving room for a
+    // turnout above the average
+    const SUGGESTION_SHARE: f64 = 0.75;
+
+    pub fn no_shows(&self) -> i64 {
+        (self.registrations - self.checked_in).max(0)
+    }
+
+    /// Share of registrants who didn't show up, in percent
+    pub fn no_show_rate(&self) -> Option<f64> {
+        (self.registrations > 0).then(|| self.no_shows() as f64 * 100.0 / self.registrations as f64)
+    }
+
+    /// Overbooking percentage at which the expected turnout stays below the
+    /// capacity; `None` without enough history
+    pub fn suggested_percent(&self) -> Option<i32> {
+        if self.events < Self::MIN_EVENTS {
+            return None;
+        }
+        let rate = self.no_show_rate()? / 100.0;
+        // Booking capacity / (1 - rate) fills the seats on average
+        let full = if rate >= 1.0 { f64::INFINITY } else { rate / (1.0 - rate) * 100.0 };
+        Some(((full * Self::SUGGESTION_SHARE).round() as i32).clamp(0, OverbookingPolicy::MAX_PERCENT))
+    }
+}
+
+// Ticketing
+
+/// Currencies tickets can be priced in; amounts are kept in minor units
+/// (øre, cents)
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema)]
+pub enum Currency {
+    #[serde(rename = "NOK")]
+    Nok,
+    #[serde(rename = "EUR")]
+    Eur,
+    #[serde(rename = "USD")]
+    Usd,
+}
+
+impl Currency {
+    pub const ALL: [Currency; 3] = [Currency::Nok, Currency::Eur, Currency::Usd];
+
+    /// ISO 4217 code
+    pub fn code(self) -> &'static str {
+        match self {
+            Currency::Nok => "NOK",
+            Currency::Eur => "EUR",
+            Currency::Usd => "USD",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|currency| currency.code().eq_ignore_ascii_case(code.trim()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Currency::from_code(&s).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "Invalid currency '{}'. Valid options are: NOK, EUR, USD (case insensitive)",
+                s
+            ))
+        })
+    }
+}
+
+/// Value of one unit of a currency in Norwegian kroner on a given day
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ExchangeRate {
+    pub currency: Currency,
+    pub nok_per_unit: f64,
+    /// Day the rate was published for
+    pub date: NaiveDate,
+}
+
+impl ExchangeRate {
+    /// NOK itself, at 1
+    pub fn nok(date: NaiveDate) -> Self {
+        Self { currency: Currency::Nok, nok_per_unit: 1.0, date }
+    }
+
+    /// `amount` minor units of the currency in øre, rounded to the nearest øre
+    pub fn to_nok(&self, amount: i64) -> i64 {
+        (amount as f64 * self.nok_per_unit).round() as i64
+    }
+}
+
+/// Norwegian VAT rates in percent: exempt, the low rate (e.g. admission to
+/// sports and amusement events), the food rate and the standard rate
+pub const VAT_RATES: [i32; 4] = [0, 12, 15, 25];
+
+/// The VAT contained in `gross`, which includes it at `vat_rate` percent,
+/// rounded to the nearest minor unit
+pub fn vat_included(gross: i64, vat_rate: i32) -> i64 {
+    let net = (gross as f64 * 100.0 / (100.0 + vat_rate as f64)).round() as i64;
+    gross - net
+}
+
+/// Something sold for an event, e.g. "Early bird" or "Exhibitor pass"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TicketType {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    /// In minor units of `currency`, VAT included
+    pub price: i64,
+    pub currency: Currency,
+    /// Percent, one of [`VAT_RATES`]
+    pub vat_rate: i32,
+    /// Tickets for sale; `None` for no limit
+    pub quantity: Option<i32>,
+    /// Inactive types stay on existing orders but can't be bought
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum OrderStatus {
+    Confirmed,
+    Cancelled,
+}
+
+impl<'de> Deserialize<'de> for OrderStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "confirmed" => Ok(OrderStatus::Confirmed),
+            "cancelled" => Ok(OrderStatus::Cancelled),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid order status '{}'. Valid options are: Confirmed, Cancelled (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Tickets of one type bought together. Price and currency are copied from
+/// the ticket type, and the exchange rate of the day is kept, so later price
+/// or rate changes don't alter what was paid.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TicketOrder {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+    pub buyer_id: Uuid,
+    pub quantity: i32,
+    /// In minor units of `currency`
+    pub unit_price: i64,
+    pub currency: Currency,
+    /// `quantity` × `unit_price`
+    pub total: i64,
+    /// Rate at purchase time, 1 for NOK
+    pub exchange_rate: f64,
+    pub exchange_rate_date: NaiveDate,
+    /// `total` in øre at `exchange_rate`
+    pub total_nok: i64,
+    /// Percent, from the ticket type
+    pub vat_rate: i32,
+    /// VAT included in `total`
+    pub vat_amount: i64,
+    /// VAT included in `total_nok`
+    pub vat_amount_nok: i64,
+    pub status: OrderStatus,
+    pub created_at: DateTime<Utc>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+}
+
+impl TicketOrder {
+    pub fn new(ticket_type: &TicketType, buyer_id: Uuid, quantity: i32, rate: &ExchangeRate) -> Self {
+        let total = ticket_type.price * quantity as i64;
+        let total_nok = rate.to_nok(total);
+        Self {
+            id: Uuid::new_v4(),
+            event_id: ticket_type.event_id,
+            ticket_type_id: ticket_type.id,
+            buyer_id,
+            quantity,
+            unit_price: ticket_type.price,
+            currency: ticket_type.currency,
+            total,
+            exchange_rate: rate.nok_per_unit,
+            exchange_rate_date: rate.date,
+            total_nok,
+            vat_rate: ticket_type.vat_rate,
+            vat_amount: vat_included(total, ticket_type.vat_rate),
+            vat_amount_nok: vat_included(total_nok, ticket_type.vat_rate),
+            status: OrderStatus::Confirmed,
+            created_at: Utc::now(),
+            cancelled_at: None,
+        }
+    }
+}
+
+/// Confirmed orders of an event in one currency
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CurrencyRevenue {
+    pub currency: Currency,
+    pub orders: i64,
+    pub tickets: i64,
+    /// In minor units of `currency`
+    pub gross: i64,
+    /// In øre, at each order's exchange rate
+    pub gross_nok: i64,
+}
+
+/// Ticket sales and refunds at one VAT rate over a period, in øre at each
+/// order's exchange rate. Orders count as sales when placed and as refunds
+/// when cancelled, so an order placed and cancelled in the same period
+/// cancels out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct VatBreakdown {
+    pub vat_rate: i32,
+    pub orders: i64,
+    /// VAT included
+    pub sales_nok: i64,
+    pub sales_vat_nok: i64,
+    pub refunded_orders: i64,
+    pub refunds_nok: i64,
+    pub refunds_vat_nok: i64,
+}
+
+// Invoicing
+
+/// Seller or buyer on an invoice, as it was when the invoice was issued
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct InvoiceParty {
+    pub name: String,
+    /// Nine digits
+    pub org_number: String,
+    pub address: Option<String>,
+    pub postal_code: Option<String>,
+    pub city: Option<String>,
+    /// ISO 3166 alpha-2
+    pub country: String,
+    pub email: Option<String>,
+    /// Registered for VAT (in Merverdiavgiftsregisteret)
+    #[serde(default)]
+    pub vat_registered: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum InvoiceStatus {
+    Issued,
+    Paid,
+    /// The order was cancelled before the invoice was paid
+    Voided,
+}
+
+impl<'de> Deserialize<'de> for InvoiceStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "issued" => Ok(InvoiceStatus::Issued),
+            "paid" => Ok(InvoiceStatus::Paid),
+            "voided" => Ok(InvoiceStatus::Voided),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid invoice status '{}'. Valid options are: Issued, Paid, Voided (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// An invoice for one ticket order, in the order's currency
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Invoice {
+    pub id: Uuid,
+    /// Sequential, assigned when the invoice is stored
+    pub invoice_number: i64,
+    pub order_id: Uuid,
+    pub event_id: Uuid,
+    pub seller: InvoiceParty,
+    pub buyer: InvoiceParty,
+    /// The buyer's reference, e.g. a cost center or contact; required by EHF
+    pub buyer_reference: String,
+    /// Account the seller is paid to, 11 digits
+    pub bank_account: Option<String>,
+    pub currency: Currency,
+    /// E.g. "Early bird – Havbrukskonferansen 2026"
+    pub description: String,
+    pub quantity: i32,
+    /// In minor units of `currency`, VAT included
+    pub unit_price: i64,
+    pub total: i64,
+    /// Percent
+    pub vat_rate: i32,
+    /// VAT included in `total`
+    pub vat_amount: i64,
+    pub issue_date: NaiveDate,
+    pub due_date: NaiveDate,
+    pub status: InvoiceStatus,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Invoice {
+    /// Unpaid after the due date
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status == InvoiceStatus::Issued && today > self.due_date
+    }
+
+    pub fn days_overdue(&self, today: NaiveDate) -> i64 {
+        if self.is_overdue(today) {
+            (today - self.due_date).num_days()
+        } else {
+            0
+        }
+    }
+}
+
+/// Which invoices to list
+#[derive(Debug, Clone, Default)]
+pub struct InvoiceFilter {
+    pub event_id: Option<Uuid>,
+    pub status: Option<InvoiceStatus>,
+    /// Only unpaid invoices due before this day
+    pub overdue_on: Option<NaiveDate>,
+}
+
+// Refunds
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum RefundStatus {
+    /// Not refunded yet; tried again at `next_attempt_at`
+    Pending,
+    Succeeded,
+    /// Gave up after the last attempt, or the provider refused the refund
+    Failed,
+}
+
+impl<'de> Deserialize<'de> for RefundStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(RefundStatus::Pending),
+            "succeeded" => Ok(RefundStatus::Succeeded),
+            "failed" => Ok(RefundStatus::Failed),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid refund status '{}'. Valid options are: Pending, Succeeded, Failed (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Refund of a ticket order's payment through the payment provider
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TicketRefund {
+    pub id: Uuid,
+    pub order_id: Uuid,
+    pub event_id: Uuid,
+    pub buyer_id: Uuid,
+    /// The order's total, in minor units of `currency`
+    pub amount: i64,
+    pub currency: Currency,
+    pub status: RefundStatus,
+    pub attempts: i32,
+    /// When a pending refund is tried next
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    /// Why the last attempt failed
+    pub last_error: Option<String>,
+    /// The provider's reference, once refunded
+    pub provider_reference: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TicketRefund {
+    /// A pending refund of the whole order, due right away
+    pub fn new(order: &TicketOrder, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            order_id: order.id,
+            event_id: order.event_id,
+            buyer_id: order.buyer_id,
+            amount: order.total,
+            currency: order.currency,
+            status: RefundStatus::Pending,
+            attempts: 0,
+            next_attempt_at: Some(now),
+            last_error: None,
+            provider_reference: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+// Seating
+
+/// A table at a seated event, such as a gala dinner
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SeatingTable {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    /// "Table 1", "Head table"
+    pub name: String,
+    /// Seats, counting each registrant and their guests
+    pub capacity: i32,
+    /// Reachable without steps, with room for a wheelchair
+    pub accessible: bool,
+    /// Order on the floor plan and chart, lowest first
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SeatingTable {
+    pub fn new(event_id: Uuid, name: impl Into<String>, capacity: i32, position: i32) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            name: name.into(),
+            capacity,
+            accessible: false,
+            position,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// A registration seated at a table, with any guests it brings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SeatAssignment {
+    pub registration_id: Uuid,
+    pub table_id: Uuid,
+    pub event_id: Uuid,
+    pub assigned_at: DateTime<Utc>,
+}
+
+// Resource booking
+
+/// Equipment or a facility events can book, such as a projector, a boat or a demo tank
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Resource {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    /// Where it's kept or moored
+    pub location: Option<String>,
+    /// Retired resources keep their bookings but can't be booked again
+    pub is_active: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Resource {
+    pub fn new(name: impl Into<String>, created_by: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name: name.into(),
+            description: None,
+            location: None,
+            is_active: true,
+            created_by,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// A period a resource can be booked in. Resources without any windows can
+/// be booked at any time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AvailabilityWindow {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl AvailabilityWindow {
+    /// Whether `starts_at..ends_at` lies within the window
+    pub fn covers(&self, starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> bool {
+        self.starts_at <= starts_at && ends_at <= self.ends_at
+    }
+}
+
+/// A resource reserved for an event's time slot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ResourceBooking {
+    pub id: Uuid,
+    pub resource_id: Uuid,
+    pub event_id: Uuid,
+    pub starts_at: DateTime<Utc>,
+    /// Exclusive, so back-to-back bookings don't overlap
+    pub ends_at: DateTime<Utc>,
+    pub booked_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ResourceBooking {
+    pub fn new(
+        resource_id: Uuid,
+        event_id: Uuid,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        booked_by: Uuid,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            resource_id,
+            event_id,
+            starts_at,
+            ends_at,
+            booked_by,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn overlaps(&self, starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> bool {
+        self.starts_at < ends_at && starts_at < self.ends_at
+    }
+}
+
+/// Why a resource can't be booked for a time slot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ResourceConflict {
+    /// Already booked at an overlapping time
+    AlreadyBooked { resource_id: Uuid, booking: ResourceBooking },
+    /// The slot falls outside the resource's availability windows
+    Unavailable { resource_id: Uuid },
+    /// The resource is retired
+    Inactive { resource_id: Uuid },
+}
+
+impl ResourceConflict {
+    /// The conflicts of booking `resource` from `starts_at` to `ends_at`, given
+    /// its availability windows and the bookings it already has
+    pub fn find(
+        resource: &Resource,
+        windows: &[AvailabilityWindow],
+        bookings: &[ResourceBooking],
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Vec<ResourceConflict> {
+        if !resource.is_active {
+            return vec![ResourceConflict::Inactive { resource_id: resource.id }];
+        }
+        let mut conflicts = Vec::new();
+        if !windows.is_empty() && !windows.iter().any(|window| window.covers(starts_at, ends_at)) {
+            conflicts.push(ResourceConflict::Unavailable { resource_id: resource.id });
+        }
+        conflicts.extend(
+            bookings
+                .iter()
+                .filter(|booking| booking.resource_id == resource.id && booking.overlaps(starts_at, ends_at))
+                .map(|booking| ResourceConflict::AlreadyBooked {
+                    resource_id: resource.id,
+                    booking: booking.clone(),
+                }),
+        );
+        conflicts
+    }
+
+    pub fn resource_id(&self) -> Uuid {
+        match self {
+            ResourceConflict::AlreadyBooked { resource_id, .. }
+            | ResourceConflict::Unavailable { resource_id }
+            | ResourceConflict::Inactive { resource_id } => *resource_id,
+        }
+    }
+}
+
+// Volunteer shifts
+
+/// A shift to staff at an event, such as the registration desk or coat check
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EventShift {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    /// What the staff on the shift do
+    pub role: String,
+    pub description: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// How many people the shift needs
+    pub headcount: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EventShift {
+    pub fn new(
+        event_id: Uuid,
+        role: impl Into<String>,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        headcount: i32,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            role: role.into(),
+            description: None,
+            starts_at,
+            ends_at,
+            headcount,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn overlaps(&self, other: &EventShift) -> bool {
+        self.starts_at < other.ends_at && other.starts_at < self.ends_at
+    }
+}
+
+/// A staff user signed up for a shift
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ShiftSignup {
+    pub shift_id: Uuid,
+    pub user_id: Uuid,
+    pub signed_up_at: DateTime<Utc>,
+}
+
+// Exhibitors
+
+/// A stand on an expo's floor plan. Positions and sizes are in metres from
+/// the hall's top-left corner, so the frontend can draw the plan to scale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Booth {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    /// The number on the stand and in the catalogue, such as "B12"
+    pub number: String,
+    pub hall: Option<String>,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub depth: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Booth {
+    pub fn new(event_id: Uuid, number: impl Into<String>, x: f64, y: f64, width: f64, depth: f64) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            number: number.into(),
+            hall: None,
+            x,
+            y,
+            width,
+            depth,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Floor space in square metres
+    pub fn area(&self) -> f64 {
+        self.width * self.depth
+    }
+
+    /// Whether the two stands share floor space; stands that only touch don't
+    pub fn overlaps(&self, other: &Booth) -> bool {
+        self.hall == other.hall
+            && self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.depth
+            && other.y < self.y + self.depth
+    }
+}
+
+/// A company exhibiting at an event, with the booth it was given if any
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Exhibitor {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub company_name: String,
+    /// Members of this registered company scan leads for the exhibitor
+    pub company_id: Option<Uuid>,
+    /// Who the organizers deal with; never shown publicly
+    pub contact_name: Option<String>,
+    pub contact_email: Option<String>,
+    pub website_url: Option<String>,
+    /// What the exhibitor shows, for the public exhibitor list
+    pub description: Option<String>,
+    pub booth_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Exhibitor {
+    pub fn new(event_id: Uuid, company_name: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            company_name: company_name.into(),
+            company_id: None,
+            contact_name: None,
+            contact_email: None,
+            website_url: None,
+            description: None,
+            booth_id: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// An attendee whose badge an exhibitor scanned, having agreed to share
+/// their contact details with exhibitors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Lead {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub exhibitor_id: Uuid,
+    pub registration_id: Uuid,
+    pub scanned_by: Uuid,
+    /// What the exhibitor's staff noted down at the stand
+    pub notes: Option<String>,
+    pub scanned_at: DateTime<Utc>,
+}
+
+impl Lead {
+    pub fn new(exhibitor: &Exhibitor, registration_id: Uuid, scanned_by: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_id: exhibitor.event_id,
+            exhibitor_id: exhibitor.id,
+            registration_id,
+            scanned_by,
+            notes: None,
+            scanned_at: Utc::now(),
+        }
+    }
+}
+
+// Matchmaking
+
+/// An attendee taking part in an event's B2B matchmaking: what they want to
+/// talk about and when they're free for meetings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MatchmakingProfile {
+    pub event_id: Uuid,
+    pub user_id: Uuid,
+    /// Topics, in the attendee's order; matched case-insensitively
+    pub interests: Vec<String>,
+    /// What the attendee offers or is looking for
+    pub bio: Option<String>,
+    /// When the attendee can meet, earliest first
+    pub availability: Vec<AvailabilityWindow>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MatchmakingProfile {
+    pub fn new(event_id: Uuid, user_id: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            event_id,
+            user_id,
+            interests: Vec::new(),
+            bio: None,
+            availability: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_available(&self, starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> bool {
+        self.availability.iter().any(|window| window.covers(starts_at, ends_at))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum MeetingStatus {
+    /// Scheduled by the requester, waiting for the invitee to answer
+    Requested,
+    Accepted,
+    Declined,
+    /// Called off by either attendee
+    Cancelled,
+}
+
+impl<'de> Deserialize<'de> for MeetingStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "requested" => Ok(MeetingStatus::Requested),
+            "accepted" => Ok(MeetingStatus::Accepted),
+            "declined" => Ok(MeetingStatus::Declined),
+            "cancelled" => Ok(MeetingStatus::Cancelled),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid meeting status '{}'. Valid options are: Requested, Accepted, Declined, Cancelled (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A 1:1 meeting between two attendees, placed in a slot both are free and,
+/// when the event has meeting rooms, in a room booked for it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Meeting {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub requester_id: Uuid,
+    pub invitee_id: Uuid,
+    /// Why the requester wants to meet
+    pub message: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    /// Exclusive, so back-to-back meetings don't overlap
+    pub ends_at: DateTime<Utc>,
+    /// The meeting room, a bookable resource
+    pub resource_id: Option<Uuid>,
+    /// The room's booking, released when the meeting is declined or cancelled
+    pub booking_id: Option<Uuid>,
+    pub status: MeetingStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Meeting {
+    pub fn new(
+        event_id: Uuid,
+        requester_id: Uuid,
+        invitee_id: Uuid,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            requester_id,
+            invitee_id,
+            message: None,
+            starts_at,
+            ends_at,
+            resource_id: None,
+            booking_id: None,
+            status: MeetingStatus::Requested,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Requested or accepted; declined and cancelled meetings free the slot
+    pub fn is_active(&self) -> bool {
+        matches!(self.status, MeetingStatus::Requested | MeetingStatus::Accepted)
+    }
+
+    pub fn involves(&self, user_id: Uuid) -> bool {
+        self.requester_id == user_id || self.invitee_id == user_id
+    }
+
+    pub fn overlaps(&self, starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> bool {
+        self.starts_at < ends_at && starts_at < self.ends_at
+    }
+}
+
+// Web vitals
+
+/// What a web vitals sample measures
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, ToSchema)]
+pub enum WebVitalMetric {
+    /// Largest contentful paint, in milliseconds
+    Lcp,
+    /// First input delay, in milliseconds; reported by browsers without INP
+    Fid,
+    /// Interaction to next paint, in milliseconds
+    Inp,
+    /// Cumulative layout shift, unitless
+    Cls,
+    /// Round trip of a request to the API, in milliseconds
+    ApiLatency,
+}
+
+impl<'de> Deserialize<'de> for WebVitalMetric {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "lcp" => Ok(WebVitalMetric::Lcp),
+            "fid" => Ok(WebVitalMetric::Fid),
+            "inp" => Ok(WebVitalMetric::Inp),
+            "cls" => Ok(WebVitalMetric::Cls),
+            "apilatency" | "api_latency" => Ok(WebVitalMetric::ApiLatency),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid web vital metric '{}'. Valid options are: Lcp, Fid, Inp, Cls, ApiLatency (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+impl WebVitalMetric {
+    /// Upper bounds of "good" and "needs improvement" at the 75th percentile,
+    /// as published for the Core Web Vitals; `None` for API latency
+    pub fn thresholds(self) -> Option<(f64, f64)> {
+        match self {
+            WebVitalMetric::Lcp => Some((2500.0, 4000.0)),
+            WebVitalMetric::Fid => Some((100.0, 300.0)),
+            WebVitalMetric::Inp => Some((200.0, 500.0)),
+            WebVitalMetric::Cls => Some((0.1, 0.25)),
+            WebVitalMetric::ApiLatency => None,
+        }
+    }
+}
+
+/// One measurement reported by a visitor's browser, kept so percentiles can
+/// be compared between releases of the web app
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct WebVitalSample {
+    pub id: Uuid,
+    pub metric: WebVitalMetric,
+    pub value: f64,
+    /// Version of the web app that took the measurement
+    pub release: String,
+    /// Client-side route, without query string
+    pub route: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl WebVitalSample {
+    pub fn new(metric: WebVitalMetric, value: f64, release: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            metric,
+            value,
+            release: release.into(),
+            route: None,
+            recorded_at: Utc::now(),
+        }
+    }
+}
+
+// Media storage quotas
+
+/// Uploaded media held for the events an organization's members organize
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct StorageUsage {
+    pub attachment_bytes: i64,
+    pub receipt_bytes: i64,
+    pub photo_bytes: i64,
+    /// Attachments, receipts and photos
+    pub files: i64,
+}
+
+impl StorageUsage {
+    pub fn total_bytes(&self) -> i64 {
+        self.attachment_bytes + self.receipt_bytes + self.photo_bytes
+    }
+}
+
+// Email sending domains
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum DomainVerificationStatus {
+    /// Records haven't been checked since the domain was configured
+    Pending,
+    Verified,
+    /// The last check didn't find every required record
+    Failed,
+}
+
+impl<'de> Deserialize<'de> for DomainVerificationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(DomainVerificationStatus::Pending),
+            "verified" => Ok(DomainVerificationStatus::Verified),
+            "failed" => Ok(DomainVerificationStatus::Failed),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid verification status '{}'. Valid options are: Pending, Verified, Failed (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum DnsRecordType {
+    Txt,
+    Cname,
+}
+
+/// What a required DNS record proves
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum SendingDomainCheck {
+    /// The organization controls the domain
+    Ownership,
+    /// The platform's mail servers may send for the domain
+    Spf,
+    /// Mail is signed with the platform's key on the domain's behalf
+    Dkim,
+}
+
+/// A record the organization publishes for its sending domain, and whether
+/// the last check found it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct RequiredDnsRecord {
+    pub check: SendingDomainCheck,
+    pub record_type: DnsRecordType,
+    pub name: String,
+    pub value: String,
+    pub verified: bool,
+}
+
+/// Hosts of the platform's own mail setup that organizations' records point at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MailPlatform {
+    /// Included from the organization's SPF record, e.g. `spf.aqio.no`
+    pub spf_include: String,
+    /// Parent of the platform's DKIM keys, e.g. `dkim.aqio.no`
+    pub dkim_host: String,
+}
+
+/// The domain an organization's invitations are sent from once verified
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SendingDomain {
+    pub company_id: Uuid,
+    pub domain: String,
+    /// Sender address; must be on `domain`
+    pub from_email: String,
+    pub from_name: Option<String>,
+    pub dkim_selector: String,
+    /// Published in a TXT record to prove ownership
+    pub verification_token: String,
+    pub status: DomainVerificationStatus,
+    pub ownership_verified: bool,
+    pub spf_verified: bool,
+    pub dkim_verified: bool,
+    pub last_checked_at: Option<DateTime<Utc>>,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SendingDomain {
+    pub const DEFAULT_DKIM_SELECTOR: &'static str = "aqio";
+
+    pub fn new(
+        company_id: Uuid,
+        domain: impl Into<String>,
+        from_email: impl Into<String>,
+        from_name: Option<String>,
+        dkim_selector: impl Into<String>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            company_id,
+            domain: domain.into(),
+            from_email: from_email.into(),
+            from_name,
+            dkim_selector: dkim_selector.into(),
+            verification_token: Uuid::new_v4().simple().to_string(),
+            status: DomainVerificationStatus::Pending,
+            ownership_verified: false,
+            spf_verified: false,
+            dkim_verified: false,
+            last_checked_at: None,
+            verified_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.status == DomainVerificationStatus::Verified
+    }
+
+    /// Who emails from the organization are sent as
+    pub fn sender(&self) -> EmailSender {
+        EmailSender {
+            email: self.from_email.clone(),
+            name: self.from_name.clone(),
+        }
+    }
+
+    pub fn verification_record_name(&self) -> String {
+        format!("_aqio.{}", self.domain)
+    }
+
+    pub fn verification_record_value(&self) -> String {
+        format!("aqio-verification={}", self.verification_token)
+    }
+
+    pub fn dkim_record_name(&self) -> String {
+        format!("{}._domainkey.{}", self.dkim_selector, self.domain)
+    }
+
+    pub fn dkim_record_target(&self, platform: &MailPlatform) -> String {
+        format!("{}._domainkey.{}", self.dkim_selector, platform.dkim_host)
+    }
+
+    /// The records to publish, flagged with what the last check found
+    pub fn required_records(&self, platform: &MailPlatform) -> Vec<RequiredDnsRecord> {
+        vec![
+            RequiredDnsRecord {
+                check: SendingDomainCheck::Ownership,
+                record_type: DnsRecordType::Txt,
+                name: self.verification_record_name(),
+                value: self.verification_record_value(),
+                verified: self.ownership_verified,
+            },
+            RequiredDnsRecord {
+                check: SendingDomainCheck::Spf,
+                record_type: DnsRecordType::Txt,
+                name: self.domain.clone(),
+                value: format!("v=spf1 include:{} ~all", platform.spf_include),
+                verified: self.spf_verified,
+            },
+            RequiredDnsRecord {
+                check: SendingDomainCheck::Dkim,
+                record_type: DnsRecordType::Cname,
+                name: self.dkim_record_name(),
+                value: self.dkim_record_target(platform),
+                verified: self.dkim_verified,
+            },
+        ]
+    }
+}
+
+/// Records found in DNS for a sending domain, as returned by the resolver
+#[derive(Debug, Clone, Default)]
+pub struct SendingDomainLookup {
+    pub verification_txt: Vec<String>,
+    pub domain_txt: Vec<String>,
+    pub dkim_cname: Vec<String>,
+}
+
+// Email delivery failures
+
+/// Why the mail provider gave up on an address
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum DeliveryFailure {
+    /// Permanent bounce; the mailbox doesn't exist or refuses mail
+    Bounce,
+    /// The recipient reported the email as spam
+    Complaint,
+}
+
+/// An address no email is sent to any more
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct EmailSuppression {
+    /// Lowercased
+    pub email: String,
+    pub reason: DeliveryFailure,
+    /// Provider that reported the failure, e.g. `ses` or `sendgrid`
+    pub provider: String,
+    /// Diagnostic from the provider, such as the remote server's response
+    pub detail: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl EmailSuppression {
+    pub fn new(email: &str, reason: DeliveryFailure, provider: impl Into<String>, detail: Option<String>) -> Self {
+        Self {
+            email: email.trim().to_lowercase(),
+            reason,
+            provider: provider.into(),
+            detail,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+// Notification digests
+
+/// How often a user's non-urgent notifications are emailed as a digest
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum DigestFrequency {
+    /// No digest; notifications only arrive live while the user is signed in
+    #[default]
+    Immediate,
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    pub const ALL: [DigestFrequency; 3] = [DigestFrequency::Immediate, DigestFrequency::Daily, DigestFrequency::Weekly];
+
+    /// Time between two digests; `None` when the user gets no digest
+    pub fn period(&self) -> Option<chrono::Duration> {
+        match self {
+            DigestFrequency::Immediate => None,
+            DigestFrequency::Daily => Some(chrono::Duration::days(1)),
+            DigestFrequency::Weekly => Some(chrono::Duration::weeks(1)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DigestFrequency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "immediate" => Ok(DigestFrequency::Immediate),
+            "daily" => Ok(DigestFrequency::Daily),
+            "weekly" => Ok(DigestFrequency::Weekly),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid digest frequency '{}'. Valid options are: Immediate, Daily, Weekly (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A user's notification settings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NotificationPreferences {
+    pub user_id: Uuid,
+    pub digest_frequency: DigestFrequency,
+    /// When the last digest went out; the next one is due a period later
+    pub last_digest_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl NotificationPreferences {
+    /// Settings for a user who hasn't chosen any
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+            digest_frequency: DigestFrequency::default(),
+            last_digest_at: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+/// A notification held back for the user's next digest
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct DigestItem {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub event_id: Option<Uuid>,
+    /// The event's title when the notification happened
+    pub event_title: Option<String>,
+    /// Notification type, e.g. `waitlist_moved`
+    pub kind: String,
+    /// One line describing what happened
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl DigestItem {
+    pub fn new(
+        user_id: Uuid,
+        event_id: Option<Uuid>,
+        event_title: Option<String>,
+        kind: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            event_id,
+            event_title,
+            kind: kind.into(),
+            message: message.into(),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// A user's pending items, grouped per event, ready to be rendered
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationDigest {
+    pub user_id: Uuid,
+    pub frequency: DigestFrequency,
+    /// Groups in the order their first item arrived
+    pub groups: Vec<DigestGroup>,
+}
+
+impl NotificationDigest {
+    pub fn item_count(&self) -> usize {
+        self.groups.iter().map(|group| group.items.len()).sum()
+    }
+}
+
+/// Digest items about the same event; `event_id` is `None` for items that
+/// aren't about an event
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigestGroup {
+    pub event_id: Option<Uuid>,
+    pub event_title: Option<String>,
+    /// Oldest first
+    pub items: Vec<DigestItem>,
+}
+
+/// An email ready to hand to the mail provider
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+    /// The platform's sender when unset
+    pub from: Option<EmailSender>,
+}
+
+/// The address and display name an email is sent as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailSender {
+    pub email: String,
+    pub name: Option<String>,
+}
+
+// Organization invitations
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum OrganizationInvitationStatus {
+    Pending,
+    Accepted,
+    /// Withdrawn by an administrator or replaced by a newer invitation
+    Revoked,
+}
+
+impl<'de> Deserialize<'de> for OrganizationInvitationStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(OrganizationInvitationStatus::Pending),
+            "accepted" => Ok(OrganizationInvitationStatus::Accepted),
+            "revoked" => Ok(OrganizationInvitationStatus::Revoked),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid organization invitation status '{}'. Valid options are: Pending, Accepted, Revoked (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// An emailed link asking someone to join a company on Aqio
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationInvitation {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    /// Lowercased
+    pub email: String,
+    /// Role the invitee gets when joining
+    pub role: UserRole,
+    /// Secret part of the join link
+    pub token: String,
+    pub invited_by: Option<Uuid>,
+    pub status: OrganizationInvitationStatus,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_by: Option<Uuid>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OrganizationInvitation {
+    pub const VALID_DAYS: i64 = 14;
+
+    pub fn new(company_id: Uuid, email: &str, role: UserRole, invited_by: Option<Uuid>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            company_id,
+            email: email.trim().to_lowercase(),
+            role,
+            token: Uuid::new_v4().simple().to_string(),
+            invited_by,
+            status: OrganizationInvitationStatus::Pending,
+            expires_at: now + chrono::Duration::days(Self::VALID_DAYS),
+            accepted_by: None,
+            accepted_at: None,
+            created_at: now,
+        }
+    }
+
+    /// Pending and not expired, so the link still works
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        self.status == OrganizationInvitationStatus::Pending && self.expires_at > now
+    }
+
+    pub fn accept(&mut self, user_id: Uuid, now: DateTime<Utc>) {
+        self.status = OrganizationInvitationStatus::Accepted;
+        self.accepted_by = Some(user_id);
+        self.accepted_at = Some(now);
+    }
+}
+
+// Custom organization roles
+
+/// A role an organization defines for its own members, such as door staff
+/// who may only check people in
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationRole {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub permissions: PermissionSet,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OrganizationRole {
+    pub fn new(company_id: Uuid, name: &str, description: Option<String>, permissions: PermissionSet) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            company_id,
+            name: name.trim().to_string(),
+            description,
+            permissions,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Access to one event an organizer delegates for a limited time, such as
+/// check-in rights for the day of the event
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AccessGrant {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub user_id: Uuid,
+    pub granted_by: Uuid,
+    pub permissions: PermissionSet,
+    pub starts_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AccessGrant {
+    pub fn new(
+        event_id: Uuid,
+        user_id: Uuid,
+        granted_by: Uuid,
+        permissions: PermissionSet,
+        starts_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            user_id,
+            granted_by,
+            permissions,
+            starts_at,
+            expires_at,
+            revoked_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Whether the grant holds at `now`: started, not expired and not revoked
+    pub fn is_active_at(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.starts_at <= now && now < self.expires_at
+    }
+
+    /// Revoked, or past its expiry; such grants can't come back
+    pub fn has_ended(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_some() || now >= self.expires_at
+    }
+}
+
+// Event ownership transfers
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum OwnershipTransferStatus {
+    Pending,
+    Accepted,
+    Declined,
+    /// Withdrawn by the owner or replaced by a newer request
+    Cancelled,
+}
+
+impl<'de> Deserialize<'de> for OwnershipTransferStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(OwnershipTransferStatus::Pending),
+            "accepted" => Ok(OwnershipTransferStatus::Accepted),
+            "declined" => Ok(OwnershipTransferStatus::Declined),
+            "cancelled" => Ok(OwnershipTransferStatus::Cancelled),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid ownership transfer status '{}'. Valid options are: Pending, Accepted, Declined, Cancelled (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A request to hand an event to another organizer, who has to accept it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EventOwnershipTransfer {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    /// Organizer when the transfer was requested
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+    /// The owner, or an administrator acting for them
+    pub requested_by: Uuid,
+    pub message: Option<String>,
+    pub status: OwnershipTransferStatus,
+    pub created_at: DateTime<Utc>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+impl EventOwnershipTransfer {
+    pub fn new(event: &Event, to_user_id: Uuid, requested_by: Uuid, message: Option<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_id: event.id,
+            from_user_id: event.organizer_id,
+            to_user_id,
+            requested_by,
+            message,
+            status: OwnershipTransferStatus::Pending,
+            created_at: Utc::now(),
+            responded_at: None,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == OwnershipTransferStatus::Pending
+    }
+
+    pub fn respond(&mut self, status: OwnershipTransferStatus, now: DateTime<Utc>) {
+        self.status = status;
+        self.responded_at = Some(now);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum EventSubmissionStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl<'de> Deserialize<'de> for EventSubmissionStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(EventSubmissionStatus::Pending),
+            "approved" => Ok(EventSubmissionStatus::Approved),
+            "rejected" => Ok(EventSubmissionStatus::Rejected),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid event submission status '{}'. Valid options are: Pending, Approved, Rejected (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A community-proposed event waiting for, or answered by, a moderator
+///
+/// The event itself is an ordinary draft organized by the submitter; it isn't
+/// published, on schedule or otherwise, until a submission is approved.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EventSubmission {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub submitted_by: Uuid,
+    pub status: EventSubmissionStatus,
+    /// The moderator's reasoning; required when rejecting
+    pub review_comment: Option<String>,
+    pub reviewed_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl EventSubmission {
+    pub fn new(event_id: Uuid, submitted_by: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            submitted_by,
+            status: EventSubmissionStatus::Pending,
+            review_comment: None,
+            reviewed_by: None,
+            created_at: Utc::now(),
+            reviewed_at: None,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == EventSubmissionStatus::Pending
+    }
+
+    pub fn review(
+        &mut self,
+        status: EventSubmissionStatus,
+        moderator_id: Uuid,
+        comment: Option<String>,
+        now: DateTime<Utc>,
+    ) {
+        self.status = status;
+        self.reviewed_by = Some(moderator_id);
+        self.review_comment = comment;
+        self.reviewed_at = Some(now);
+    }
+}
+
+// Abuse reports
+
+/// What kind of public content a report is about
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum ReportTarget {
+    Comment,
+    Event,
+    Profile,
+}
+
+impl<'de> Deserialize<'de> for ReportTarget {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "comment" => Ok(ReportTarget::Comment),
+            "event" => Ok(ReportTarget::Event),
+            "profile" => Ok(ReportTarget::Profile),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid report target '{}'. Valid options are: Comment, Event, Profile (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    Inappropriate,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for ReportReason {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "spam" => Ok(ReportReason::Spam),
+            "harassment" => Ok(ReportReason::Harassment),
+            "inappropriate" => Ok(ReportReason::Inappropriate),
+            "other" => Ok(ReportReason::Other),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid report reason '{}'. Valid options are: Spam, Harassment, Inappropriate, Other (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum ReportStatus {
+    Open,
+    /// A moderator hid the content, warned or banned its author
+    Actioned,
+    Dismissed,
+}
+
+impl<'de> Deserialize<'de> for ReportStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "open" => Ok(ReportStatus::Open),
+            "actioned" => Ok(ReportStatus::Actioned),
+            "dismissed" => Ok(ReportStatus::Dismissed),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid report status '{}'. Valid options are: Open, Actioned, Dismissed (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// What a moderator did about reported content
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum ModerationAction {
+    /// Keep the content out of sight
+    Hide,
+    /// Tell the author their content was reported and checked
+    Warn,
+    /// Deactivate the author's account and hide the content
+    Ban,
+    /// Nothing wrong; hidden content is shown again
+    Dismiss,
+}
+
+impl<'de> Deserialize<'de> for ModerationAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "hide" => Ok(ModerationAction::Hide),
+            "warn" => Ok(ModerationAction::Warn),
+            "ban" => Ok(ModerationAction::Ban),
+            "dismiss" => Ok(ModerationAction::Dismiss),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid moderation action '{}'. Valid options are: Hide, Warn, Ban, Dismiss (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A user's report of a comment, event or profile as spam or abuse
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ContentReport {
+    pub id: Uuid,
+    pub target: ReportTarget,
+    pub target_id: Uuid,
+    pub reporter_id: Uuid,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub status: ReportStatus,
+    pub action: Option<ModerationAction>,
+    pub resolved_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl ContentReport {
+    pub fn new(target: ReportTarget, target_id: Uuid, reporter_id: Uuid, reason: ReportReason, details: Option<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            target,
+            target_id,
+            reporter_id,
+            reason,
+            details,
+            status: ReportStatus::Open,
+            action: None,
+            resolved_by: None,
+            created_at: Utc::now(),
+            resolved_at: None,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.status == ReportStatus::Open
+    }
+}
+
+// Registration consents
+
+/// What a consent checkbox at registration asks for
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema)]
+pub enum ConsentKind {
+    /// Being photographed or filmed at the event
+    Photo,
+    /// Processing of the registration's personal data
+    DataProcessing,
+    /// Marketing emails after the event
+    Marketing,
+    /// Exhibitors who scan the attendee's badge keep their contact details
+    LeadSharing,
+}
+
+impl ConsentKind {
+    pub const ALL: [ConsentKind; 4] = [
+        ConsentKind::Photo,
+        ConsentKind::DataProcessing,
+        ConsentKind::Marketing,
+        ConsentKind::LeadSharing,
+    ];
+}
+
+impl<'de> Deserialize<'de> for ConsentKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "photo" => Ok(ConsentKind::Photo),
+            "dataprocessing" | "data_processing" => Ok(ConsentKind::DataProcessing),
+            "marketing" => Ok(ConsentKind::Marketing),
+            "leadsharing" | "lead_sharing" => Ok(ConsentKind::LeadSharing),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid consent kind '{}'. Valid options are: Photo, DataProcessing, Marketing, LeadSharing (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A consent checkbox shown when registering for an event. Changing the text
+/// starts a new version, so earlier answers keep pointing at what was shown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EventConsent {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub kind: ConsentKind,
+    pub text: String,
+    /// Registration is refused unless the box is ticked
+    pub required: bool,
+    pub version: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EventConsent {
+    pub fn new(event_id: Uuid, kind: ConsentKind, text: impl Into<String>, required: bool) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            kind,
+            text: text.into(),
+            required,
+            version: 1,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Updates the checkbox; a new text is a new version
+    pub fn revise(&mut self, text: impl Into<String>, required: bool, now: DateTime<Utc>) {
+        let text = text.into();
+        if text != self.text {
+            self.text = text;
+            self.version += 1;
+        }
+        self.required = required;
+        self.updated_at = now;
+    }
+}
+
+/// A registrant's answer to a consent checkbox, with the text they saw
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RegistrationConsent {
+    pub registration_id: Uuid,
+    pub consent_id: Uuid,
+    pub kind: ConsentKind,
+    pub version: i32,
+    pub text: String,
+    pub accepted: bool,
+    pub answered_at: DateTime<Utc>,
+}
+
+impl RegistrationConsent {
+    pub fn new(registration_id: Uuid, consent: &EventConsent, accepted: bool, answered_at: DateTime<Utc>) -> Self {
+        Self {
+            registration_id,
+            consent_id: consent.id,
+            kind: consent.kind,
+            version: consent.version,
+            text: consent.text.clone(),
+            accepted,
+            answered_at,
+        }
+    }
+}
+
+// Broadcasts
+
+/// Which registrants an organizer's broadcast goes to
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum BroadcastAudience {
+    /// Everyone whose registration isn't cancelled
+    All,
+    Confirmed,
+    Waitlisted,
+    CheckedIn,
+}
+
+impl BroadcastAudience {
+    pub fn includes(&self, registration: &EventRegistration) -> bool {
+        match self {
+            BroadcastAudience::All => registration.status != RegistrationStatus::Cancelled,
+            BroadcastAudience::Confirmed => matches!(
+                registration.status,
+                RegistrationStatus::Registered | RegistrationStatus::Attended
+            ),
+            BroadcastAudience::Waitlisted => registration.status == RegistrationStatus::Waitlisted,
+            BroadcastAudience::CheckedIn => {
+                registration.status != RegistrationStatus::Cancelled
+                    && (registration.checked_in_at.is_some() || registration.status == RegistrationStatus::Attended)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BroadcastAudience {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "all" => Ok(BroadcastAudience::All),
+            "confirmed" => Ok(BroadcastAudience::Confirmed),
+            "waitlisted" => Ok(BroadcastAudience::Waitlisted),
+            "checkedin" | "checked_in" => Ok(BroadcastAudience::CheckedIn),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid broadcast audience '{}'. Valid options are: All, Confirmed, Waitlisted, CheckedIn (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum BroadcastStatus {
+    /// Waiting for its send time
+    Scheduled,
+    Sent,
+    /// Called off by an organizer before it went out
+    Cancelled,
+}
+
+impl<'de> Deserialize<'de> for BroadcastStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "scheduled" => Ok(BroadcastStatus::Scheduled),
+            "sent" => Ok(BroadcastStatus::Sent),
+            "cancelled" => Ok(BroadcastStatus::Cancelled),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid broadcast status '{}'. Valid options are: Scheduled, Sent, Cancelled (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// What happened to a broadcast's emails
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BroadcastDelivery {
+    /// Registrants in the audience, one per email address
+    pub recipients: i32,
+    /// Handed to the mail provider
+    pub delivered: i32,
+    /// Skipped because the address bounced or complained before
+    pub suppressed: i32,
+    /// Refused by the mail provider, or registrants without an email address
+    pub failed: i32,
+}
+
+/// A message from an event's organizers to its registrants
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EventBroadcast {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub sender_id: Uuid,
+    pub audience: BroadcastAudience,
+    pub subject: String,
+    pub message: String,
+    pub status: BroadcastStatus,
+    /// Set when the broadcast waits for a later send time
+    pub scheduled_for: Option<DateTime<Utc>>,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub delivery: BroadcastDelivery,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EventBroadcast {
+    /// A broadcast waiting to be sent, either right away or at `scheduled_for`
+    pub fn new(
+        event_id: Uuid,
+        sender_id: Uuid,
+        audience: BroadcastAudience,
+        subject: impl Into<String>,
+        message: impl Into<String>,
+        scheduled_for: Option<DateTime<Utc>>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            sender_id,
+            audience,
+            subject: subject.into(),
+            message: message.into(),
+            status: BroadcastStatus::Scheduled,
+            scheduled_for,
+            sent_at: None,
+            delivery: BroadcastDelivery::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn mark_sent(&mut self, delivery: BroadcastDelivery, now: DateTime<Utc>) {
+        self.status = BroadcastStatus::Sent;
+        self.delivery = delivery;
+        self.sent_at = Some(now);
+        self.updated_at = now;
+    }
+}
+
+// Messages
+
+/// A conversation about an event between its organizers and one attendee.
+/// There is at most one per event and attendee; the organizers share it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MessageThread {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub attendee_id: Uuid,
+    /// Messages from the organizers the attendee hasn't read
+    pub attendee_unread: i32,
+    /// Messages from the attendee none of the organizers has read
+    pub organizer_unread: i32,
+    pub last_message_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl MessageThread {
+    pub fn new(event_id: Uuid, attendee_id: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            event_id,
+            attendee_id,
+            attendee_unread: 0,
+            organizer_unread: 0,
+            last_message_at: now,
+            created_at: now,
+        }
+    }
+
+    /// Unread messages for the organizers' side or the attendee's
+    pub fn unread_for(&self, as_organizer: bool) -> i32 {
+        if as_organizer {
+            self.organizer_unread
+        } else {
+            self.attendee_unread
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ThreadMessage {
+    pub id: Uuid,
+    pub thread_id: Uuid,
+    pub sender_id: Uuid,
+    /// Written by one of the event's organizers rather than the attendee
+    pub from_organizer: bool,
+    pub body: String,
+    /// The broadcast the attendee was answering, if any
+    pub broadcast_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ThreadMessage {
+    pub fn new(
+        thread_id: Uuid,
+        sender_id: Uuid,
+        from_organizer: bool,
+        body: impl Into<String>,
+        broadcast_id: Option<Uuid>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            thread_id,
+            sender_id,
+            from_organizer,
+            body: body.into(),
+            broadcast_id,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+// Scheduled exports
+
+/// What a scheduled export contains
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum ExportEntity {
+    /// One row per registration, with the event it belongs to
+    Registrations,
+    Events,
+}
+
+impl<'de> Deserialize<'de> for ExportEntity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "registrations" => Ok(ExportEntity::Registrations),
+            "events" => Ok(ExportEntity::Events),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid export entity '{}'. Valid options are: Registrations, Events (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum ExportFormat {
+    Csv,
+    /// An array of objects keyed by the CSV header
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Json => "application/json",
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ExportFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid export format '{}'. Valid options are: Csv, Json (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Which rows go into a scheduled export
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ExportFilter {
+    /// Events organized by this company
+    #[serde(default)]
+    pub company_id: Option<Uuid>,
+    /// One event only; can't be combined with a company
+    #[serde(default)]
+    pub event_id: Option<Uuid>,
+    /// Only rows changed since the job last succeeded; everything on the first run
+    #[serde(default)]
+    pub changed_since_last_run: bool,
+}
+
+/// Where a scheduled export is delivered. Credentials are not stored with
+/// the job; the server's own S3 keys and SFTP key are used.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum ExportDestination {
+    Sftp {
+        host: String,
+        #[serde(default = "ExportDestination::default_sftp_port")]
+        port: u16,
+        username: String,
+        /// Remote directory files are put in; the login directory when empty
+        #[serde(default)]
+        directory: String,
+    },
+    S3 {
+        bucket: String,
+        region: String,
+        /// Prepended to file names, e.g. `aqio/registrations/`
+        #[serde(default)]
+        prefix: String,
+        /// S3-compatible endpoint such as `https://s3.example.com`; AWS when unset
+        #[serde(default)]
+        endpoint: Option<String>,
+    },
+}
+
+impl ExportDestination {
+    fn default_sftp_port() -> u16 {
+        22
+    }
+
+    pub fn validate(&self) -> DomainResult<()> {
+        let is_plain = |value: &str| {
+            !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        };
+        match self {
+            ExportDestination::Sftp { host, port, username, directory } => {
+                if !is_plain(host) {
+                    return Err(DomainError::invalid_format("destination.host", "a host name", host));
+                }
+                if *port == 0 {
+                    return Err(DomainError::validation("destination.port", "Port must be between 1 and 65535"));
+                }
+                if !is_plain(username) {
+                    return Err(DomainError::invalid_format("destination.username", "a user name", username));
+                }
+                if directory.chars().any(|c| c.is_control() || c == '"') {
+                    return Err(DomainError::validation("destination.directory", "Directory contains invalid characters"));
+                }
+            }
+            ExportDestination::S3 { bucket, region, prefix, endpoint } => {
+                if !is_plain(bucket) {
+                    return Err(DomainError::invalid_format("destination.bucket", "an S3 bucket name", bucket));
+                }
+                if !is_plain(region) {
+                    return Err(DomainError::invalid_format("destination.region", "an AWS region such as eu-north-1", region));
+                }
+                if prefix.starts_with('/') || prefix.chars().any(char::is_control) {
+                    return Err(DomainError::validation("destination.prefix", "Prefix must be relative and printable"));
+                }
+                if let Some(endpoint) = endpoint {
+                    if !endpoint.starts_with("https://") && !endpoint.starts_with("http://") {
+                        return Err(DomainError::invalid_format("destination.endpoint", "an http(s) URL", endpoint));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum ExportRunStatus {
+    Succeeded,
+    Failed,
+}
+
+impl<'de> Deserialize<'de> for ExportRunStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "succeeded" => Ok(ExportRunStatus::Succeeded),
+            "failed" => Ok(ExportRunStatus::Failed),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid export run status '{}'. Valid options are: Succeeded, Failed (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A five-field cron expression: minute, hour, day of month, month and day
+/// of week (0 or 7 is Sunday). Fields take `*`, numbers, ranges (`1-5`),
+/// steps (`*/15`) and lists (`1,15`); `@hourly`, `@daily`, `@weekly` and
+/// `@monthly` are shorthands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// Cron runs on either day field when both are restricted
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl CronSchedule {
+    /// How far ahead to look for the next run; covers a February 29th
+    const HORIZON_DAYS: i64 = 5 * 366;
+
+    pub fn parse(expression: &str) -> DomainResult<CronSchedule> {
+        let expanded = match expression.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+        let invalid = || DomainError::invalid_format("schedule", "a cron expression such as '0 2 * * *'", expression);
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields[..] else {
+            return Err(invalid());
+        };
+
+        let mut days_of_week_mask = Self::field(days_of_week, 0, 7).ok_or_else(invalid)?;
+        if days_of_week_mask & (1 << 7) != 0 {
+            days_of_week_mask = (days_of_week_mask | 1) & !(1 << 7);
+        }
+        let schedule = CronSchedule {
+            minutes: Self::field(minutes, 0, 59).ok_or_else(invalid)?,
+            hours: Self::field(hours, 0, 23).ok_or_else(invalid)?,
+            days_of_month: Self::field(days_of_month, 1, 31).ok_or_else(invalid)?,
+            months: Self::field(months, 1, 12).ok_or_else(invalid)?,
+            days_of_week: days_of_week_mask,
+            any_day_of_month: days_of_month == "*",
+            any_day_of_week: days_of_week == "*",
+        };
+
+        // 2000-01-01, so the horizon takes in a leap day
+        let start = DateTime::from_timestamp(946_684_800, 0).unwrap_or_default();
+        if schedule.next_after(start, chrono_tz::UTC).is_none() {
+            return Err(DomainError::validation_with_value("schedule", "Schedule never runs", expression));
+        }
+        Ok(schedule)
+    }
+
+    // Bit n is set when the field matches n
+    fn field(spec: &str, min: u32, max: u32) -> Option<u64> {
+        let mut mask = 0u64;
+        for item in spec.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, step.parse::<u32>().ok().filter(|step| *step > 0)?),
+                None => (item, 1),
+            };
+            let (first, last) = match range {
+                "*" => (min, max),
+                _ => match range.split_once('-') {
+                    Some((first, last)) => (first.parse().ok()?, last.parse().ok()?),
+                    // `5/15` runs from 5 to the end of the range
+                    None if item.contains('/') => (range.parse().ok()?, max),
+                    None => (range.parse().ok()?, range.parse().ok()?),
+                },
+            };
+            if first < min || last > max || first > last {
+                return None;
+            }
+            for value in (first..=last).step_by(step as usize) {
+                mask |= 1 << value;
+            }
+        }
+        Some(mask)
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        use chrono::Datelike;
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day_of_month = self.days_of_month & (1 << date.day()) != 0;
+        let day_of_week = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (false, false) => day_of_month || day_of_week,
+            _ => day_of_month && day_of_week,
+        }
+    }
+
+    /// The first run strictly after `after`, reading the schedule as wall
+    /// clock time in `timezone`. A run in the hour skipped when clocks go
+    /// forward happens an hour later; one in the hour repeated when clocks go
+    /// back happens once, the first time.
+    pub fn next_after(&self, after: DateTime<Utc>, timezone: chrono_tz::Tz) -> Option<DateTime<Utc>> {
+        use chrono::{LocalResult, TimeZone};
+        let first_day = after.with_timezone(&timezone).date_naive();
+
+        for offset in 0..=Self::HORIZON_DAYS {
+            let date = first_day + chrono::Duration::days(offset);
+            if !self.matches_day(date) {
+                continue;
+            }
+            for hour in (0..24).filter(|hour| self.hours & (1 << hour) != 0) {
+                for minute in (0..60).filter(|minute| self.minutes & (1 << minute) != 0) {
+                    let Some(local) = date.and_hms_opt(hour, minute, 0) else {
+                        continue;
+                    };
+                    let run = match timezone.from_local_datetime(&local) {
+                        LocalResult::Single(run) => run,
+                        LocalResult::Ambiguous(first, _) => first,
+                        LocalResult::None => match timezone.from_local_datetime(&(local + chrono::Duration::hours(1))) {
+                            LocalResult::Single(run) | LocalResult::Ambiguous(run, _) => run,
+                            LocalResult::None => continue,
+                        },
+                    };
+                    let run = run.with_timezone(&Utc);
+                    if run > after {
+                        return Some(run);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// A file of registrations or events an admin set up to be pushed to an
+/// external system on a schedule, e.g. an association's CRM every night
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ExportJob {
+    pub id: Uuid,
+    pub name: String,
+    pub entity: ExportEntity,
+    pub filter: ExportFilter,
+    pub format: ExportFormat,
+    pub destination: ExportDestination,
+    /// Cron expression; see [`CronSchedule`]
+    pub schedule: String,
+    /// IANA time zone the schedule is read in
+    pub timezone: String,
+    /// Paused jobs keep their settings but don't run
+    pub is_active: bool,
+    /// Unset while the job is paused
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_status: Option<ExportRunStatus>,
+    /// Why the last run failed
+    pub last_error: Option<String>,
+    /// Rows in the last delivered file
+    pub last_row_count: Option<i32>,
+    /// Start of the last successful run; incremental exports pick up from here
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExportJob {
+    /// An active job, first run at the schedule's next time after `now`
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: impl Into<String>,
+        entity: ExportEntity,
+        filter: ExportFilter,
+        format: ExportFormat,
+        destination: ExportDestination,
+        schedule: impl Into<String>,
+        timezone: impl Into<String>,
+        created_by: Uuid,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Self> {
+        let mut job = Self {
+            id: Uuid::new_v4(),
+            name: name.into(),
+            entity,
+            filter,
+            format,
+            destination,
+            schedule: schedule.into(),
+            timezone: timezone.into(),
+            is_active: true,
+            next_run_at: None,
+            last_run_at: None,
+            last_status: None,
+            last_error: None,
+            last_row_count: None,
+            last_success_at: None,
+            created_by,
+            created_at: now,
+            updated_at: now,
+        };
+        job.validate()?;
+        job.next_run_at = job.next_run_after(now)?;
+        Ok(job)
+    }
+
+    pub fn validate(&self) -> DomainResult<()> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::validation("name", "Name cannot be empty"));
+        }
+        if self.name.len() > 100 {
+            return Err(DomainError::validation("name", "Name cannot exceed 100 characters"));
+        }
+        if self.filter.company_id.is_some() && self.filter.event_id.is_some() {
+            return Err(DomainError::validation("filter", "Filter by company or by event, not both"));
+        }
+        self.destination.validate()?;
+        self.cron()?;
+        self.tz()?;
+        Ok(())
+    }
+
+    pub fn cron(&self) -> DomainResult<CronSchedule> {
+        CronSchedule::parse(&self.schedule)
+    }
+
+    pub fn tz(&self) -> DomainResult<chrono_tz::Tz> {
+        self.timezone
+            .parse()
+            .map_err(|_| DomainError::invalid_format("timezone", "an IANA time zone such as Europe/Oslo", &self.timezone))
+    }
+
+    /// When the job runs next after `now`, or `None` while it's paused
+    pub fn next_run_after(&self, now: DateTime<Utc>) -> DomainResult<Option<DateTime<Utc>>> {
+        if !self.is_active {
+            return Ok(None);
+        }
+        Ok(self.cron()?.next_after(now, self.tz()?))
+    }
+
+    /// Records a run that started at `started_at` and schedules the next one
+    pub fn record_run(&mut self, started_at: DateTime<Utc>, outcome: Result<i32, String>) -> DomainResult<()> {
+        match outcome {
+            Ok(rows) => {
+                self.last_status = Some(ExportRunStatus::Succeeded);
+                self.last_error = None;
+                self.last_row_count = Some(rows);
+                self.last_success_at = Some(started_at);
+            }
+            Err(error) => {
+                self.last_status = Some(ExportRunStatus::Failed);
+                self.last_error = Some(error);
+            }
+        }
+        self.last_run_at = Some(started_at);
+        self.next_run_at = self.next_run_after(started_at)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// File name for a run, e.g. `nightly-crm-20260101T020000Z.csv`
+    pub fn file_name(&self, started_at: DateTime<Utc>) -> String {
+        let slug = Event::slug_for(&self.name, self.id);
+        format!("{}-{}.{}", slug, started_at.format("%Y%m%dT%H%M%SZ"), self.format.extension())
+    }
+}
+
+// CRM sync
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum CrmProvider {
+    HubSpot,
+    /// Microsoft Dynamics 365 through its Dataverse Web API
+    Dynamics,
+}
+
+impl CrmProvider {
+    /// Contacts are matched on this property
+    pub fn email_property(&self) -> &'static str {
+        match self {
+            CrmProvider::HubSpot => "email",
+            CrmProvider::Dynamics => "emailaddress1",
+        }
+    }
+
+    /// Object type (HubSpot) or entity set (Dynamics) contacts live in
+    pub fn contact_object(&self) -> &'static str {
+        "contacts"
+    }
+
+    /// Whether the server's credentials may be sent to `url`: HubSpot's own
+    /// API, or a Dynamics organization under dynamics.com
+    pub fn allows_url(&self, url: &str) -> bool {
+        let Some(rest) = url.strip_prefix("https://") else {
+            return false;
+        };
+        let host = rest.split(['/', '?', '#']).next().unwrap_or_default().to_ascii_lowercase();
+        match self {
+            CrmProvider::HubSpot => host == "api.hubapi.com",
+            CrmProvider::Dynamics => host.strip_suffix(".dynamics.com").is_some_and(|organization| {
+                !organization.is_empty()
+                    && organization.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.'))
+            }),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CrmProvider {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "hubspot" => Ok(CrmProvider::HubSpot),
+            "dynamics" => Ok(CrmProvider::Dynamics),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid CRM provider '{}'. Valid options are: HubSpot, Dynamics (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// The kind of CRM record a registration is synced to
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum CrmRecordKind {
+    /// One per email address, shared by everyone's registrations
+    Contact,
+    /// One per registration, in the connector's registration object
+    Registration,
+}
+
+impl<'de> Deserialize<'de> for CrmRecordKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "contact" => Ok(CrmRecordKind::Contact),
+            "registration" => Ok(CrmRecordKind::Registration),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid CRM record kind '{}'. Valid options are: Contact, Registration (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A value Aqio knows about a registration, to be written to a CRM property
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum CrmSourceField {
+    Email,
+    FullName,
+    /// Everything but the last word of the name, or all of a one-word name
+    FirstName,
+    LastName,
+    Phone,
+    Company,
+    EventId,
+    EventTitle,
+    EventStart,
+    RegistrationId,
+    RegistrationStatus,
+    RegisteredAt,
+    CheckedInAt,
+    GuestCount,
+}
+
+impl CrmSourceField {
+    pub const ALL: [CrmSourceField; 14] = [
+        CrmSourceField::Email,
+        CrmSourceField::FullName,
+        CrmSourceField::FirstName,
+        CrmSourceField::LastName,
+        CrmSourceField::Phone,
+        CrmSourceField::Company,
+        CrmSourceField::EventId,
+        CrmSourceField::EventTitle,
+        CrmSourceField::EventStart,
+        CrmSourceField::RegistrationId,
+        CrmSourceField::RegistrationStatus,
+        CrmSourceField::RegisteredAt,
+        CrmSourceField::CheckedInAt,
+        CrmSourceField::GuestCount,
+    ];
+
+    /// Fields about the person rather than one registration; the only ones
+    /// that can go on contacts
+    pub fn is_contact_field(&self) -> bool {
+        matches!(
+            self,
+            CrmSourceField::Email
+                | CrmSourceField::FullName
+                | CrmSourceField::FirstName
+                | CrmSourceField::LastName
+                | CrmSourceField::Phone
+                | CrmSourceField::Company
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for CrmSourceField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        CrmSourceField::ALL
+            .into_iter()
+            .find(|field| format!("{:?}", field).eq_ignore_ascii_case(&s))
+            .ok_or_else(|| {
+                serde::de::Error::custom(format!(
+                    "Invalid CRM source field '{}'. Valid options are: Email, FullName, FirstName, LastName, Phone, \
+                     Company, EventId, EventTitle, EventStart, RegistrationId, RegistrationStatus, RegisteredAt, \
+                     CheckedInAt, GuestCount (case insensitive)",
+                    s
+                ))
+            })
+    }
+}
+
+/// Writes one Aqio value to a property of a CRM record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CrmFieldMapping {
+    pub record: CrmRecordKind,
+    pub source: CrmSourceField,
+    /// Internal name of the CRM property, e.g. `jobtitle` or `new_eventname`
+    pub property: String,
+}
+
+/// What a sync does with a property someone changed in the CRM
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum CrmConflictPolicy {
+    /// Aqio's value is written regardless
+    AqioWins,
+    /// Properties changed in the CRM since Aqio last wrote them, or filled in
+    /// there before Aqio first did, are left alone and reported as conflicts
+    CrmWins,
+    /// Only properties that are empty in the CRM are written
+    FillBlanks,
+}
+
+/// Properties a sync writes to one CRM record, and those it left alone
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CrmResolution {
+    pub changes: BTreeMap<String, String>,
+    pub conflicts: Vec<String>,
+}
+
+impl CrmConflictPolicy {
+    /// Decides which of the `desired` values to write to a record holding
+    /// `remote`, given the values Aqio wrote to it last time
+    pub fn resolve(
+        &self,
+        desired: &BTreeMap<String, String>,
+        last_synced: &BTreeMap<String, String>,
+        remote: &BTreeMap<String, String>,
+    ) -> CrmResolution {
+        let mut resolution = CrmResolution::default();
+        for (property, value) in desired {
+            let current = remote.get(property).map(String::as_str).unwrap_or_default();
+            if current == value {
+                continue;
+            }
+            let changed_in_crm = match last_synced.get(property) {
+                Some(synced) => synced != current,
+                None => !current.is_empty(),
+            };
+            let write = match self {
+                CrmConflictPolicy::AqioWins => true,
+                CrmConflictPolicy::CrmWins => !changed_in_crm,
+                CrmConflictPolicy::FillBlanks => current.is_empty(),
+            };
+            if write {
+                resolution.changes.insert(property.clone(), value.clone());
+            } else if *self == CrmConflictPolicy::CrmWins {
+                resolution.conflicts.push(property.clone());
+            }
+        }
+        resolution
+    }
+}
+
+impl<'de> Deserialize<'de> for CrmConflictPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "aqiowins" => Ok(CrmConflictPolicy::AqioWins),
+            "crmwins" => Ok(CrmConflictPolicy::CrmWins),
+            "fillblanks" => Ok(CrmConflictPolicy::FillBlanks),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid conflict policy '{}'. Valid options are: AqioWins, CrmWins, FillBlanks (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum CrmSyncRunStatus {
+    Succeeded,
+    /// Some records failed; they are tried again on the next run
+    PartiallyFailed,
+    Failed,
+}
+
+impl<'de> Deserialize<'de> for CrmSyncRunStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "succeeded" => Ok(CrmSyncRunStatus::Succeeded),
+            "partiallyfailed" => Ok(CrmSyncRunStatus::PartiallyFailed),
+            "failed" => Ok(CrmSyncRunStatus::Failed),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid sync status '{}'. Valid options are: Succeeded, PartiallyFailed, Failed (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Records touched by one sync run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CrmSyncSummary {
+    pub created: i32,
+    pub updated: i32,
+    pub unchanged: i32,
+    /// Records with properties left alone under the `CrmWins` policy
+    pub conflicts: i32,
+    pub failed: i32,
+}
+
+/// A CRM that registrations of Aqio events are synced to: contacts by email
+/// address, and optionally one record per registration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CrmConnector {
+    pub id: Uuid,
+    pub name: String,
+    pub provider: CrmProvider,
+    /// API root; the Dynamics organization URL such as
+    /// `https://contoso.crm4.dynamics.com`, or HubSpot's public API when unset
+    pub base_url: Option<String>,
+    /// Only registrations for events this company organizes
+    pub company_id: Option<Uuid>,
+    /// Object type (HubSpot) or entity set (Dynamics) registrations are
+    /// written to; contacts only when unset
+    pub registration_object: Option<String>,
+    pub field_mappings: Vec<CrmFieldMapping>,
+    pub conflict_policy: CrmConflictPolicy,
+    pub sync_interval_minutes: i32,
+    /// Paused connectors keep their settings and sync state but don't run
+    pub is_active: bool,
+    /// Unset while the connector is paused
+    pub next_sync_at: Option<DateTime<Utc>>,
+    pub last_sync_at: Option<DateTime<Utc>>,
+    pub last_status: Option<CrmSyncRunStatus>,
+    pub last_error: Option<String>,
+    pub last_summary: Option<CrmSyncSummary>,
+    /// Start of the last run that got through; registrations changed since
+    /// are picked up by the next one
+    pub sync_cursor: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CrmConnector {
+    pub const MIN_SYNC_INTERVAL_MINUTES: i32 = 5;
+    pub const MAX_SYNC_INTERVAL_MINUTES: i32 = 24 * 60;
+
+    /// An active connector that syncs contacts' names every hour, first
+    /// right away
+    pub fn new(name: impl Into<String>, provider: CrmProvider, created_by: Uuid, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name: name.into(),
+            provider,
+            base_url: None,
+            company_id: None,
+            registration_object: None,
+            // Both CRMs' contacts have these out of the box
+            field_mappings: vec![
+                CrmFieldMapping {
+                    record: CrmRecordKind::Contact,
+                    source: CrmSourceField::FirstName,
+                    property: "firstname".to_string(),
+                },
+                CrmFieldMapping {
+                    record: CrmRecordKind::Contact,
+                    source: CrmSourceField::LastName,
+                    property: "lastname".to_string(),
+                },
+            ],
+            conflict_policy: CrmConflictPolicy::CrmWins,
+            sync_interval_minutes: 60,
+            is_active: true,
+            next_sync_at: Some(now),
+            last_sync_at: None,
+            last_status: None,
+            last_error: None,
+            last_summary: None,
+            sync_cursor: None,
+            created_by,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> DomainResult<()> {
+        let is_identifier =
+            |value: &str| !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'));
+
+        if self.name.trim().is_empty() {
+            return Err(DomainError::validation("name", "Name cannot be empty"));
+        }
+        if self.name.len() > 100 {
+            return Err(DomainError::validation("name", "Name cannot exceed 100 characters"));
+        }
+        match (&self.base_url, self.provider) {
+            (Some(url), CrmProvider::HubSpot) if !self.provider.allows_url(url) => {
+                return Err(DomainError::invalid_format("base_url", "https://api.hubapi.com", url));
+            }
+            (Some(url), CrmProvider::Dynamics) if !self.provider.allows_url(url) => {
+                return Err(DomainError::invalid_format("base_url", "an https://<organization>.dynamics.com URL", url));
+            }
+            (None, CrmProvider::Dynamics) => {
+                return Err(DomainError::validation("base_url", "Dynamics connectors need the organization URL"));
+            }
+            _ => {}
+        }
+        if let Some(object) = &self.registration_object {
+            if !is_identifier(object) {
+                return Err(DomainError::invalid_format("registration_object", "an object or entity set name", object));
+            }
+        }
+        if !(Self::MIN_SYNC_INTERVAL_MINUTES..=Self::MAX_SYNC_INTERVAL_MINUTES).contains(&self.sync_interval_minutes) {
+            return Err(DomainError::validation_with_value(
+                "sync_interval_minutes",
+                "Sync interval must be between 5 minutes and a day",
+                &self.sync_interval_minutes.to_string(),
+            ));
+        }
+
+        if self.field_mappings.is_empty() {
+            return Err(DomainError::validation("field_mappings", "Map at least one field"));
+        }
+        let mut seen = std::collections::HashSet::new();
+        for mapping in &self.field_mappings {
+            if !is_identifier(&mapping.property) {
+                return Err(DomainError::invalid_format("field_mappings.property", "a CRM property name", &mapping.property));
+            }
+            if !seen.insert((mapping.record, mapping.property.to_lowercase())) {
+                return Err(DomainError::validation_with_value(
+                    "field_mappings",
+                    "A property can only be mapped once",
+                    &mapping.property,
+                ));
+            }
+            match mapping.record {
+                CrmRecordKind::Contact if !mapping.source.is_contact_field() => {
+                    return Err(DomainError::validation_with_value(
+                        "field_mappings",
+                        "Contacts can only hold the person's email, name, phone and company",
+                        &format!("{:?}", mapping.source),
+                    ));
+                }
+                CrmRecordKind::Contact if mapping.property.eq_ignore_ascii_case(self.provider.email_property()) => {
+                    return Err(DomainError::validation(
+                        "field_mappings",
+                        "Contacts are matched by email address, which is always written",
+                    ));
+                }
+                CrmRecordKind::Registration if self.registration_object.is_none() => {
+                    return Err(DomainError::validation(
+                        "field_mappings",
+                        "Set a registration object to map registration fields",
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Mappings onto records of one kind
+    pub fn mappings(&self, record: CrmRecordKind) -> impl Iterator<Item = &CrmFieldMapping> {
+        self.field_mappings.iter().filter(move |mapping| mapping.record == record)
+    }
+
+    /// When the connector syncs next after a run at `now`, or `None` while
+    /// it's paused
+    pub fn next_sync_after(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.is_active
+            .then(|| now + chrono::Duration::minutes(self.sync_interval_minutes.into()))
+    }
+
+    /// Records a run that started at `started_at` and schedules the next.
+    /// Runs that got through move the cursor even when some records failed,
+    /// since failed records are retried on their own.
+    pub fn record_sync(&mut self, started_at: DateTime<Utc>, outcome: Result<CrmSyncSummary, String>) {
+        match outcome {
+            Ok(summary) => {
+                let succeeded = summary.created + summary.updated + summary.unchanged + summary.conflicts;
+                self.last_status = Some(match summary.failed {
+                    0 => CrmSyncRunStatus::Succeeded,
+                    _ if succeeded == 0 => CrmSyncRunStatus::Failed,
+                    _ => CrmSyncRunStatus::PartiallyFailed,
+                });
+                self.last_error = None;
+                self.last_summary = Some(summary);
+                self.sync_cursor = Some(started_at);
+            }
+            Err(error) => {
+                self.last_status = Some(CrmSyncRunStatus::Failed);
+                self.last_error = Some(error);
+                self.last_summary = None;
+            }
+        }
+        self.last_sync_at = Some(started_at);
+        self.next_sync_at = self.next_sync_after(started_at);
+        self.updated_at = Utc::now();
+    }
+}
+
+/// Where a record stands in the CRM after the last attempt to sync it
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+pub enum CrmSyncState {
+    Synced,
+    /// Synced, but properties changed in the CRM were left alone
+    Conflict,
+    /// Tried again on the next run
+    Failed,
+}
+
+impl<'de> Deserialize<'de> for CrmSyncState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "synced" => Ok(CrmSyncState::Synced),
+            "conflict" => Ok(CrmSyncState::Conflict),
+            "failed" => Ok(CrmSyncState::Failed),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid sync state '{}'. Valid options are: Synced, Conflict, Failed (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// What a connector last wrote to one CRM record. Contacts are keyed by
+/// lowercase email address, registrations by their ID.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CrmSyncRecord {
+    pub connector_id: Uuid,
+    pub kind: CrmRecordKind,
+    pub local_key: String,
+    /// The CRM's ID, once the record exists there
+    pub remote_id: Option<String>,
+    pub state: CrmSyncState,
+    /// Property values as the CRM held them after Aqio's last write; a
+    /// different value there later means someone changed it in the CRM
+    pub synced_values: BTreeMap<String, String>,
+    /// Properties left alone on the last run
+    pub conflicts: Vec<String>,
+    pub error: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CrmSyncRecord {
+    pub fn new(connector_id: Uuid, kind: CrmRecordKind, local_key: impl Into<String>, now: DateTime<Utc>) -> Self {
+        Self {
+            connector_id,
+            kind,
+            local_key: local_key.into(),
+            remote_id: None,
+            state: CrmSyncState::Failed,
+            synced_values: BTreeMap::new(),
+            conflicts: Vec::new(),
+            error: None,
+            updated_at: now,
+        }
+    }
+}
+
+/// Records of a connector by state, for its status page
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CrmSyncCounts {
+    pub synced: i64,
+    pub conflicts: i64,
+    pub failed: i64,
+}
+
+/// A record as the CRM holds it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrmRecord {
+    pub id: String,
+    /// The requested properties; empty ones may be missing
+    pub properties: BTreeMap<String, String>,
+}
+
+// Audit trail
+
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub enum AuditAction {
+    Insert,
+    Update,
+    Delete,
+}
+
+impl<'de> Deserialize<'de> for AuditAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "insert" => Ok(AuditAction::Insert),
+            "update" => Ok(AuditAction::Update),
+            "delete" => Ok(AuditAction::Delete),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid audit action '{}'. Valid options are: Insert, Update, Delete (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// A recorded change to a row, written by administrative actions
+///
+/// `user_name`/`user_email` are denormalized so the entry stays readable after
+/// the acting user is deleted. `old_values`/`new_values` are JSON strings.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogEntry {
+    pub id: Uuid,
+    pub table_name: String,
+    pub record_id: String,
+    pub action: AuditAction,
+    pub user_id: Option<Uuid>,
+    pub user_name: Option<String>,
+    pub user_email: Option<String>,
+    pub old_values: Option<String>,
+    pub new_values: Option<String>,
+    pub changed_fields: Vec<String>,
+    pub event_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditLogEntry {
+    pub fn new(table_name: impl Into<String>, record_id: impl Into<String>, action: AuditAction) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            table_name: table_name.into(),
+            record_id: record_id.into(),
+            action,
+            user_id: None,
+            user_name: None,
+            user_email: None,
+            old_values: None,
+            new_values: None,
+            changed_fields: Vec::new(),
+            event_id: None,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogFilter {
+    pub table_name: Option<String>,
+    pub record_id: Option<String>,
+    pub user_id: Option<Uuid>,
+    pub action: Option<AuditAction>,
+}
+
+/// Narrows the company directory; `Other` matches every other industry
+/// regardless of its description
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct CompanyFilter {
+    pub industry_type: Option<IndustryType>,
+}
+
+// Aggregates for read-heavy views
+
+/// Number of active users belonging to a company
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CompanyMemberCount {
+    pub company_id: Uuid,
+    pub members: i64,
+}
+
+/// A company with its member count, as listed in the company directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyDirectoryEntry {
+    pub company: Company,
+    pub member_count: i64,
+}
+
+/// Registration totals for a single event, grouped by status
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RegistrationCounts {
+    pub event_id: Uuid,
+    pub registered: i64,
+    pub waitlisted: i64,
+    pub attended: i64,
+    pub cancelled: i64,
+}
+
+impl RegistrationCounts {
+    pub fn empty(event_id: Uuid) -> Self {
+        Self { event_id, ..Default::default() }
+    }
+
+    /// Registrations holding a seat (registered or already checked in)
+    pub fn confirmed(&self) -> i64 {
+        self.registered + self.attended
+    }
+}
+
+/// Denormalized row of the event summaries read model, kept up to date by the
+/// database as events and registrations change. Names are `None` if the
+/// category or organizer has since been removed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EventSummary {
+    pub event_id: Uuid,
+    pub title: String,
+    pub category_id: String,
+    pub category_name: Option<String>,
+    pub organizer_id: Uuid,
+    pub organizer_name: Option<String>,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub status: EventStatus,
+    pub registration_counts: RegistrationCounts,
+    /// Registrations that were checked in at the door
+    pub checked_in: i64,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Which past events the archive covers; every field narrows it down
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PastEventFilter {
+    pub title_contains: Option<String>,
+    pub category_id: Option<String>,
+    pub organizer_id: Option<Uuid>,
+    /// First and last calendar year the events started in, inclusive
+    pub from_year: Option<i32>,
+    pub to_year: Option<i32>,
+    /// Private events are left out unless this user organizes or co-organizes
+    /// them; `None` includes every event, as administrators see them
+    pub visible_to: Option<Uuid>,
+}
+
+/// What the archive's year-over-year totals are grouped by
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, ToSchema)]
+pub enum ArchiveGrouping {
+    #[default]
+    Category,
+    Organizer,
+    /// The county (fylke) the events were held in
+    County,
+}
+
+impl<'de> Deserialize<'de> for ArchiveGrouping {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.to_lowercase().as_str() {
+            "category" => Ok(ArchiveGrouping::Category),
+            "organizer" => Ok(ArchiveGrouping::Organizer),
+            "county" => Ok(ArchiveGrouping::County),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid archive grouping '{}'. Valid options are: Category, Organizer, County (case insensitive)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Totals of one category's, organizer's or county's past events that started in one year
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct YearlyAttendance {
+    pub year: i32,
+    /// The category ID, the organizer's user ID or the county; empty for
+    /// events with no known county
+    pub group_id: String,
+    /// `None` if the category or organizer has since been removed, or the
+    /// county isn't known
+    pub group_name: Option<String>,
+    pub events: i64,
+    /// Registrations holding a seat: registered or checked in
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub cancelled: i64,
+}
+
+/// One category's part in an organization's year
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CategoryAttendance {
+    pub category_id: String,
+    /// `None` if the category has since been removed
+    pub category_name: Option<String>,
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+}
+
+/// What attendees answered in the surveys of an organization's events.
+/// Averages are `None` when no response gave that answer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SurveySatisfaction {
+    pub responses: i64,
+    /// Overall rating, 1 to 5
+    pub average_rating: Option<f64>,
+    /// Share of respondents who would recommend the event, 0 to 1
+    pub recommend_share: Option<f64>,
+    /// Likelihood to attend again, 1 to 10
+    pub average_return_likelihood: Option<f64>,
+}
+
+/// Totals of the past events an organization's members organized that started
+/// in one calendar year
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationYear {
+    pub company_id: Uuid,
+    pub year: i32,
+    pub events: i64,
+    /// Registrations holding a seat: registered or checked in
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub cancelled: i64,
+    /// Most check-ins first
+    pub categories: Vec<CategoryAttendance>,
+    pub satisfaction: SurveySatisfaction,
+}
+
+impl OrganizationYear {
+    /// A year without any events
+    pub fn empty(company_id: Uuid, year: i32) -> Self {
+        Self {
+            company_id,
+            year,
+            events: 0,
+            registrations: 0,
+            checked_in: 0,
+            cancelled: 0,
+            categories: Vec::new(),
+            satisfaction: SurveySatisfaction::default(),
+        }
+    }
+}
+
+/// How far an event's invitations got, each stage counting the invitations
+/// that reached it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct InvitationFunnel {
+    pub invited: i64,
+    pub sent: i64,
+    pub opened: i64,
+    pub clicked: i64,
+    pub accepted: i64,
+    pub declined: i64,
+}
+
+/// An event hydrated with the related data list views need
+///
+/// Built from batched lookups rather than per-event queries. `category` and
+/// `organizer` are `None` if the referenced row has since been removed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventWithDetails {
+    pub event: Event,
+    pub category: Option<EventCategory>,
+    pub organizer: Option<User>,
+    pub registration_counts: RegistrationCounts,
+    /// Registrations checked in at the door
+    pub checked_in: i64,
+}
+
+// Domain filtering and pagination
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct EventFilter {
+    #[validate(length(min = 1, max = 100))]
+    pub title_contains: Option<String>,
+    
+    pub category_id: Option<String>,
+    pub organizer_id: Option<Uuid>,
+    pub is_private: Option<bool>,
+    pub status: Option<EventStatus>,
+    pub location_type: Option<LocationType>,
+    pub start_date_from: Option<DateTime<Utc>>,
+    pub start_date_to: Option<DateTime<Utc>>,
+    /// Only events with coordinates inside this circle
+    pub near: Option<GeoRadius>,
+    /// Only events organized by members of this company
+    pub organizer_company_id: Option<Uuid>,
+    /// Only events this user is a co-organizer of
+    pub co_organizer_id: Option<Uuid>,
+    /// Only events with this value for one of their custom fields
+    pub field: Option<EventFieldMatch>,
+    /// Only events in this county (fylke), e.g. `Vestland`, ignoring case
+    pub county: Option<String>,
+    /// Only events in this municipality (kommune), ignoring case
+    pub municipality: Option<String>,
+}
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// A circle on the map, used to find events near a point
+///
+/// Distances use an equirectangular approximation, which is accurate to well
+/// under a percent for the regional radii event search deals with and can be
+/// evaluated in plain SQL.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GeoRadius {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_km: f64,
+}
+
+impl GeoRadius {
+    pub const MAX_RADIUS_KM: f64 = 1000.0;
+
+    pub fn new(latitude: f64, longitude: f64, radius_km: f64) -> DomainResult<Self> {
+        validate_coordinates(latitude, longitude)?;
+        if !(radius_km > 0.0 && radius_km <= Self::MAX_RADIUS_KM) {
+            return Err(DomainError::validation(
+                "radius_km",
+                &format!("Radius must be between 0 and {} km", Self::MAX_RADIUS_KM),
+            ));
+        }
+        Ok(Self { latitude, longitude, radius_km })
+    }
+
+    /// Kilometres per degree of longitude at the centre, relative to a degree of latitude
+    pub fn longitude_scale(&self) -> f64 {
+        self.latitude.to_radians().cos()
+    }
+
+    /// Radius in degrees of latitude
+    pub fn radius_degrees(&self) -> f64 {
+        (self.radius_km / EARTH_RADIUS_KM).to_degrees()
+    }
+
+    /// `(min_latitude, max_latitude, min_longitude, max_longitude)` enclosing the circle
+    pub fn bounding_box(&self) -> (f64, f64, f64, f64) {
+        let lat_delta = self.radius_degrees();
+        let lng_delta = (lat_delta / self.longitude_scale().max(0.01)).min(180.0);
+        (
+            self.latitude - lat_delta,
+            self.latitude + lat_delta,
+            self.longitude - lng_delta,
+            self.longitude + lng_delta,
+        )
+    }
+
+    pub fn distance_km(&self, latitude: f64, longitude: f64) -> f64 {
+        let dlat = latitude - self.latitude;
+        let dlng = (longitude - self.longitude) * self.longitude_scale();
+        (dlat * dlat + dlng * dlng).sqrt().to_radians() * EARTH_RADIUS_KM
+    }
+
+    pub fn contains(&self, latitude: f64, longitude: f64) -> bool {
+        self.distance_km(latitude, longitude) <= self.radius_km
+    }
+}
+
+fn validate_coordinates(latitude: f64, longitude: f64) -> DomainResult<()> {
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(DomainError::validation("latitude", "Latitude must be between -90 and 90"));
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(DomainError::validation("longitude", "Longitude must be between -180 and 180"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PaginationParams {
+    pub offset: i64,
+    pub limit: i64,
+}
+
+impl PaginationParams {
+    pub fn new(offset: i64, limit: i64) -> DomainResult<Self> {
+        if offset < 0 {
+            return Err(DomainError::validation(
+                "offset",
+                "Offset must be non-negative"
+            ));
+        }
+        
+        if limit <= 0 || limit > 1000 {
+            return Err(DomainError::validation(
+                "limit",
+                "Limit must be between 1 and 1000"
+            ));
+        }
+        
+        Ok(Self { offset, limit })
+    }
+}
+
+impl Default for PaginationParams {
+    fn default() -> Self {
+        Self { offset: 0, limit: 50 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PaginatedResult<T> {
+    pub items: Vec<T>,
+    pub total_count: i64,
+    pub offset: i64,
+    pub limit: i64,
+    pub has_next: bool,
+}
+
+impl<T> PaginatedResult<T> {
+    pub fn new(items: Vec<T>, total_count: i64, pagination: PaginationParams) -> Self {
+        let has_next = pagination.offset + pagination.limit < total_count;
+        
+        Self {
+            items,
+            total_count,
+            offset: pagination.offset,
+            limit: pagination.limit,
+            has_next,
+        }
+    }
+}
+
+// Domain validation traits
+
+pub trait DomainValidation {
+    fn validate_for_creation(&self) -> DomainResult<()>;
+}
+
+pub trait EventDomainValidation {
+    fn can_be_registered_for(&self, current_time: DateTime<Utc>) -> DomainResult<()>;
+}
+
+pub trait InvitationDomainValidation {
+    fn can_respond(&self, current_time: DateTime<Utc>) -> DomainResult<()>;
+}
+
+// Domain validation implementations
+
+impl DomainValidation for Event {
+    fn validate_for_creation(&self) -> DomainResult<()> {
+        if self.title.trim().is_empty() {
+            return Err(DomainError::validation("title", "Title cannot be empty"));
+        }
+        
+        if self.title.len() > 200 {
+            return Err(DomainError::validation("title", "Title cannot exceed 200 characters"));
+        }
+        
+        if self.description.trim().is_empty() {
+            return Err(DomainError::validation("description", "Description cannot be empty"));
+        }
+        
+        if self.start_date >= self.end_date {
+            return Err(DomainError::validation(
+                "dates",
+                "End date must be after start date"
+            ));
+        }
+
+        match (self.latitude, self.longitude) {
+            (Some(latitude), Some(longitude)) => validate_coordinates(latitude, longitude)?,
+            (None, None) => {}
+            _ => {
+                return Err(DomainError::validation(
+                    "coordinates",
+                    "Latitude and longitude must be given together"
+                ));
+            }
+        }
+        
+        Ok(())
+    }
+}
+
+impl EventDomainValidation for Event {
+    fn can_be_registered_for(&self, current_time: DateTime<Utc>) -> DomainResult<()> {
+        if !matches!(self.status, EventStatus::Published) {
+            return Err(DomainError::business_rule(
+                "Registration is only possible for published events"
+            ));
+        }
+
+        if current_time > self.start_date {
+            return Err(DomainError::business_rule(
+                "Cannot register for an event that has already started"
+            ));
+        }
+
+        if self.registration_opens.is_some_and(|opens| current_time < opens) {
+            return Err(DomainError::business_rule("Registration has not opened yet"));
+        }
+
+        if self.registration_closes.is_some_and(|closes| current_time > closes) {
+            return Err(DomainError::business_rule("Registration has closed"));
+        }
+        
+        Ok(())
+    }
+}
+
+impl DomainValidation for User {
     fn validate_for_creation(&self) -> DomainResult<()> {
         if self.name.trim().is_empty() {
             return Err(DomainError::validation("name", "Name cannot be empty"));
@@ -566,6 +5700,12 @@ impl DomainValidation for User {
         if self.keycloak_id.trim().is_empty() {
             return Err(DomainError::validation("keycloak_id", "Keycloak ID cannot be empty"));
         }
+
+        if let Some(timezone) = &self.timezone {
+            if timezone.parse::<chrono_tz::Tz>().is_err() {
+                return Err(DomainError::invalid_format("timezone", "an IANA time zone such as Europe/Oslo", timezone));
+            }
+        }
         
         Ok(())
     }
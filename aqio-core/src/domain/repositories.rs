@@ -1,9 +1,24 @@
 
 use crate::domain::{
     DomainResult, Event, EventCategory, EventFilter, EventInvitation, EventRegistration,
-    ExternalContact, PaginatedResult, PaginationParams, User, UserProfile, Company, InvitationStatus
+    ExternalContact, PaginatedResult, PaginationParams, User, UserMatch, UserProfile, Company, CompanyFilter, CompanyMemberCount, InvitationStatus,
+    RegistrationCounts, AuditLogEntry, AuditLogFilter, EventComment, EventQuestion, EventPoll, PollVote, EventAttachment,
+    BudgetLine, EventExpense, EventSponsor, BlackoutPeriod, SchedulingPolicy, StorageUsage, SendingDomain, DnsRecordType, EmailSuppression,
+    NotificationPreferences, DigestItem, EmailMessage, OrganizationInvitation, EventOwnershipTransfer, EventSummary,
+    EventSubmission, ContentReport, ModerationAction, ReportStatus, ReportTarget, EventConsent, RegistrationConsent,
+    EventBroadcast, MessageThread, ThreadMessage, EventPhoto, PhotoAlbum, PhotoStatus, PastEventFilter,
+    ArchiveGrouping, YearlyAttendance, OrganizationYear, EventFieldDefinition, EventFieldValue, RegisteredCompany,
+    AddressRegion, WeatherForecast, TravelOption, TravelQuery, ShortLink, ShortLinkClick, ShortLinkClicks,
+    ShortLinkStats, OverbookingPolicy, TicketType, TicketOrder, CurrencyRevenue, Currency, ExchangeRate,
+    Invoice, InvoiceFilter, VatBreakdown, TicketRefund, SeatingTable, SeatAssignment,
+    Resource, AvailabilityWindow, ResourceBooking, EventShift, ShiftSignup, Booth, Exhibitor, Lead,
+    MatchmakingProfile, Meeting, WebVitalSample, OrganizationRole, AccessGrant, ExportJob, ExportDestination,
+    CrmConnector, CrmRecord, CrmRecordKind, CrmSyncCounts, CrmSyncRecord, CrmSyncState,
 };
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
 // Core repository traits (no database dependencies)
@@ -14,10 +29,15 @@ pub trait UserRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<User>>;
     async fn find_by_email(&self, email: &str) -> DomainResult<Option<User>>;
     async fn find_by_keycloak_id(&self, keycloak_id: &str) -> DomainResult<Option<User>>;
+    /// Batch lookup; IDs that don't exist are skipped
+    async fn find_by_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<User>>;
     async fn create(&self, user: &User) -> DomainResult<()>;
     async fn update(&self, user: &User) -> DomainResult<()>;
     async fn delete(&self, id: Uuid) -> DomainResult<()>;
     async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<User>>;
+    /// Active users whose name, email or company name contains `query`, those
+    /// starting with it first; at most `limit`
+    async fn search(&self, query: &str, limit: i64) -> DomainResult<Vec<UserMatch>>;
     async fn exists(&self, id: Uuid) -> DomainResult<bool>;
     async fn email_exists(&self, email: &str) -> DomainResult<bool>;
 }
@@ -38,11 +58,20 @@ pub trait CompanyRepository: Send + Sync {
     async fn update(&self, company: &Company) -> DomainResult<()>;
     async fn delete(&self, id: Uuid) -> DomainResult<()>;
     async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<Company>>;
+    async fn find_by_filter(
+        &self,
+        filter: &CompanyFilter,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<Company>>;
+    /// Active members per company; companies without members are omitted
+    async fn count_members_by_company_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<CompanyMemberCount>>;
 }
 
 #[async_trait]
 pub trait EventCategoryRepository: Send + Sync {
     async fn find_by_id(&self, id: &str) -> DomainResult<Option<EventCategory>>;
+    /// Batch lookup; IDs that don't exist are skipped
+    async fn find_by_ids(&self, ids: &[String]) -> DomainResult<Vec<EventCategory>>;
     async fn list_active(&self) -> DomainResult<Vec<EventCategory>>;
     async fn list_all(&self) -> DomainResult<Vec<EventCategory>>;
     async fn create(&self, category: &EventCategory) -> DomainResult<()>;
@@ -53,6 +82,7 @@ pub trait EventCategoryRepository: Send + Sync {
 #[async_trait]
 pub trait EventRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Event>>;
+    async fn find_by_slug(&self, slug: &str) -> DomainResult<Option<Event>>;
     async fn find_by_filter(
         &self, 
         filter: &EventFilter, 
@@ -65,6 +95,8 @@ pub trait EventRepository: Send + Sync {
     async fn delete(&self, id: Uuid) -> DomainResult<()>;
     async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<Event>>;
     async fn exists(&self, id: Uuid) -> DomainResult<bool>;
+    /// Drafts whose `publish_at` is at or before `now`, earliest first
+    async fn find_due_for_publishing(&self, now: DateTime<Utc>) -> DomainResult<Vec<Event>>;
 }
 
 #[async_trait]
@@ -89,7 +121,16 @@ pub trait EventRegistrationRepository: Send + Sync {
     async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventRegistration>>;
     async fn find_by_user_id(&self, user_id: Uuid) -> DomainResult<Vec<EventRegistration>>;
     async fn find_by_event_and_user(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Option<EventRegistration>>;
+    /// Registration counts for several events in one query; events without
+    /// registrations are omitted
+    async fn count_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<RegistrationCounts>>;
     async fn create(&self, registration: &EventRegistration) -> DomainResult<()>;
+    /// Insert `registration` only while fewer than `capacity` registrations
+    /// hold a seat (registered or attended); `false` means the event is full
+    ///
+    /// The check and the insert must be atomic, so parallel registrations
+    /// cannot overbook.
+    async fn create_within_capacity(&self, registration: &EventRegistration, capacity: i32) -> DomainResult<bool>;
     async fn update(&self, registration: &EventRegistration) -> DomainResult<()>;
     async fn delete(&self, id: Uuid) -> DomainResult<()>;
 }
@@ -103,4 +144,762 @@ pub trait ExternalContactRepository: Send + Sync {
     async fn update(&self, contact: &ExternalContact) -> DomainResult<()>;
     async fn delete(&self, id: Uuid) -> DomainResult<()>;
     async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<ExternalContact>>;
-}
\ No newline at end of file
+}
+
+#[async_trait]
+pub trait EventCommentRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventComment>>;
+    /// Every comment on the event, deleted ones included, oldest first
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventComment>>;
+    async fn create(&self, comment: &EventComment) -> DomainResult<()>;
+    /// Marks the comment deleted; its row stays so replies keep their parent
+    async fn soft_delete(&self, id: Uuid, deleted_by: Uuid, deleted_at: DateTime<Utc>) -> DomainResult<()>;
+}
+
+#[async_trait]
+pub trait EventQuestionRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventQuestion>>;
+    /// Every question on the event, oldest first
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventQuestion>>;
+    async fn create(&self, question: &EventQuestion) -> DomainResult<()>;
+    /// Sets or replaces the answer
+    async fn answer(&self, id: Uuid, answer: &str, answered_by: Uuid, answered_at: DateTime<Utc>) -> DomainResult<()>;
+    /// Returns false when the user had already upvoted the question
+    async fn add_upvote(&self, question_id: Uuid, user_id: Uuid) -> DomainResult<bool>;
+    /// Returns false when there was no upvote to remove
+    async fn remove_upvote(&self, question_id: Uuid, user_id: Uuid) -> DomainResult<bool>;
+    /// IDs of the event's questions the user has upvoted
+    async fn find_upvoted_by_user(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Vec<Uuid>>;
+}
+
+#[async_trait]
+pub trait EventPollRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventPoll>>;
+    /// Every poll on the event with its current results, oldest first
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventPoll>>;
+    /// Stores the poll together with its options
+    async fn create(&self, poll: &EventPoll) -> DomainResult<()>;
+    async fn close(&self, id: Uuid, closed_at: DateTime<Utc>) -> DomainResult<()>;
+    /// Records the user's vote, replacing any earlier vote on the same poll
+    async fn cast_vote(&self, vote: &PollVote) -> DomainResult<()>;
+    /// The user's votes across the event's polls
+    async fn find_votes_by_user(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Vec<PollVote>>;
+}
+
+#[async_trait]
+pub trait EventAttachmentRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventAttachment>>;
+    /// Every attachment on the event regardless of visibility, oldest first
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventAttachment>>;
+    async fn create(&self, attachment: &EventAttachment) -> DomainResult<()>;
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+    async fn record_download(&self, id: Uuid, downloaded_at: DateTime<Utc>) -> DomainResult<()>;
+}
+
+/// Photos in events' albums, and how each album is run
+#[async_trait]
+pub trait EventPhotoRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventPhoto>>;
+    /// Every photo on the event whatever its status, oldest first
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventPhoto>>;
+    async fn create(&self, photo: &EventPhoto) -> DomainResult<()>;
+    async fn update_status(&self, id: Uuid, status: PhotoStatus, reviewed_at: DateTime<Utc>) -> DomainResult<()>;
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+    /// The event's album settings; `None` until an organizer changes the defaults
+    async fn find_album(&self, event_id: Uuid) -> DomainResult<Option<PhotoAlbum>>;
+    /// Creates or replaces the event's album settings
+    async fn save_album(&self, album: &PhotoAlbum) -> DomainResult<()>;
+}
+
+#[async_trait]
+pub trait EventBudgetRepository: Send + Sync {
+    async fn find_line_by_id(&self, id: Uuid) -> DomainResult<Option<BudgetLine>>;
+    /// The event's budget lines, oldest first
+    async fn find_lines_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<BudgetLine>>;
+    async fn create_line(&self, line: &BudgetLine) -> DomainResult<()>;
+    async fn update_line(&self, line: &BudgetLine) -> DomainResult<()>;
+    /// Expenses booked against the line are kept and become unassigned
+    async fn delete_line(&self, id: Uuid) -> DomainResult<()>;
+
+    async fn find_expense_by_id(&self, id: Uuid) -> DomainResult<Option<EventExpense>>;
+    /// The event's expenses, oldest first by the date they were incurred
+    async fn find_expenses_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventExpense>>;
+    async fn create_expense(&self, expense: &EventExpense) -> DomainResult<()>;
+    /// Replaces every field, including the receipt
+    async fn update_expense(&self, expense: &EventExpense) -> DomainResult<()>;
+    async fn delete_expense(&self, id: Uuid) -> DomainResult<()>;
+}
+
+#[async_trait]
+pub trait EventSponsorRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventSponsor>>;
+    /// The event's sponsors in display order (see `EventSponsor::sort_for_display`)
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventSponsor>>;
+    async fn create(&self, sponsor: &EventSponsor) -> DomainResult<()>;
+    async fn update(&self, sponsor: &EventSponsor) -> DomainResult<()>;
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+}
+
+/// Organization-wide scheduling rules: blackout periods and the scheduling policy
+#[async_trait]
+pub trait SchedulingPolicyRepository: Send + Sync {
+    async fn find_policy(&self, company_id: Uuid) -> DomainResult<Option<SchedulingPolicy>>;
+    /// Creates or replaces the company's policy
+    async fn save_policy(&self, policy: &SchedulingPolicy) -> DomainResult<()>;
+
+    async fn find_blackout_by_id(&self, id: Uuid) -> DomainResult<Option<BlackoutPeriod>>;
+    /// The company's blackout periods, earliest first
+    async fn find_blackouts_by_company(&self, company_id: Uuid) -> DomainResult<Vec<BlackoutPeriod>>;
+    /// Blackout periods touching any day from `first_day` through `last_day`
+    async fn find_blackouts_overlapping(
+        &self,
+        company_id: Uuid,
+        first_day: NaiveDate,
+        last_day: NaiveDate,
+    ) -> DomainResult<Vec<BlackoutPeriod>>;
+    async fn create_blackout(&self, blackout: &BlackoutPeriod) -> DomainResult<()>;
+    async fn update_blackout(&self, blackout: &BlackoutPeriod) -> DomainResult<()>;
+    async fn delete_blackout(&self, id: Uuid) -> DomainResult<()>;
+}
+
+/// Custom fields organizations define for their members' events, and the
+/// events' values for them
+#[async_trait]
+pub trait EventFieldRepository: Send + Sync {
+    async fn find_definition_by_id(&self, id: Uuid) -> DomainResult<Option<EventFieldDefinition>>;
+    /// The company's fields in display order
+    async fn find_definitions_by_company(&self, company_id: Uuid) -> DomainResult<Vec<EventFieldDefinition>>;
+    async fn create_definition(&self, definition: &EventFieldDefinition) -> DomainResult<()>;
+    async fn update_definition(&self, definition: &EventFieldDefinition) -> DomainResult<()>;
+    /// Deletes the field along with every event's value for it
+    async fn delete_definition(&self, id: Uuid) -> DomainResult<()>;
+
+    async fn find_values(&self, event_id: Uuid) -> DomainResult<Vec<EventFieldValue>>;
+    /// Replaces all of the event's values with `values`
+    async fn replace_values(&self, event_id: Uuid, values: &[EventFieldValue]) -> DomainResult<()>;
+}
+
+/// Media storage used by organizations' events, and the quotas set for them
+#[async_trait]
+pub trait StorageQuotaRepository: Send + Sync {
+    /// Attachments, receipts and photos on events organized by the company's members
+    async fn usage_by_company(&self, company_id: Uuid) -> DomainResult<StorageUsage>;
+    /// The company's quota in bytes; `None` when it has the platform default
+    async fn find_quota(&self, company_id: Uuid) -> DomainResult<Option<i64>>;
+    /// Sets the company's quota, or returns it to the platform default with `None`
+    async fn save_quota(&self, company_id: Uuid, quota_bytes: Option<i64>) -> DomainResult<()>;
+}
+
+/// Organizations' email sending domains, at most one per company
+#[async_trait]
+pub trait SendingDomainRepository: Send + Sync {
+    async fn find_by_company(&self, company_id: Uuid) -> DomainResult<Option<SendingDomain>>;
+    /// Creates or replaces the company's sending domain
+    async fn save(&self, sending_domain: &SendingDomain) -> DomainResult<()>;
+    async fn delete(&self, company_id: Uuid) -> DomainResult<()>;
+}
+
+/// Addresses that bounced or complained and receive no more email
+#[async_trait]
+pub trait EmailSuppressionRepository: Send + Sync {
+    /// Case-insensitive on `email`
+    async fn find_by_email(&self, email: &str) -> DomainResult<Option<EmailSuppression>>;
+    /// Newest first
+    async fn find_all(&self) -> DomainResult<Vec<EmailSuppression>>;
+    /// Adds the address; one that is already suppressed keeps its first reason
+    async fn save(&self, suppression: &EmailSuppression) -> DomainResult<()>;
+    async fn delete(&self, email: &str) -> DomainResult<()>;
+}
+
+/// Users' notification settings and the items waiting for their digests
+#[async_trait]
+pub trait NotificationDigestRepository: Send + Sync {
+    async fn find_preferences(&self, user_id: Uuid) -> DomainResult<Option<NotificationPreferences>>;
+    /// Users who get a digest, i.e. whose frequency isn't `Immediate`
+    async fn find_digest_subscribers(&self) -> DomainResult<Vec<NotificationPreferences>>;
+    /// Creates or replaces the user's settings
+    async fn save_preferences(&self, preferences: &NotificationPreferences) -> DomainResult<()>;
+    async fn add_item(&self, item: &DigestItem) -> DomainResult<()>;
+    /// Oldest first
+    async fn find_pending_items(&self, user_id: Uuid) -> DomainResult<Vec<DigestItem>>;
+    /// Removes items once they went out in a digest
+    async fn delete_items(&self, ids: &[Uuid]) -> DomainResult<()>;
+}
+
+/// Invitations to join a company, redeemed through their emailed token
+#[async_trait]
+pub trait OrganizationInvitationRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<OrganizationInvitation>>;
+    async fn find_by_token(&self, token: &str) -> DomainResult<Option<OrganizationInvitation>>;
+    /// Pending invitations to the address, expired ones included; newest first
+    async fn find_pending_by_email(&self, email: &str) -> DomainResult<Vec<OrganizationInvitation>>;
+    /// Pending invitations, expired ones included, newest first; every
+    /// company's when `company_id` is `None`
+    async fn find_pending(&self, company_id: Option<Uuid>) -> DomainResult<Vec<OrganizationInvitation>>;
+    async fn create(&self, invitation: &OrganizationInvitation) -> DomainResult<()>;
+    async fn update(&self, invitation: &OrganizationInvitation) -> DomainResult<()>;
+}
+
+/// Roles organizations define for their members, and who holds them
+#[async_trait]
+pub trait OrganizationRoleRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<OrganizationRole>>;
+    /// The company's roles by name
+    async fn find_by_company(&self, company_id: Uuid) -> DomainResult<Vec<OrganizationRole>>;
+    /// Roles the user holds, in any company
+    async fn find_by_user(&self, user_id: Uuid) -> DomainResult<Vec<OrganizationRole>>;
+    async fn create(&self, role: &OrganizationRole) -> DomainResult<()>;
+    /// Replaces the name, description and permissions
+    async fn update(&self, role: &OrganizationRole) -> DomainResult<()>;
+    /// Deletes the role along with its assignments
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+    /// Users holding the role, in the order they got it
+    async fn find_member_ids(&self, role_id: Uuid) -> DomainResult<Vec<Uuid>>;
+    /// Assigning a role twice is a no-op
+    async fn add_member(&self, role_id: Uuid, user_id: Uuid) -> DomainResult<()>;
+    async fn remove_member(&self, role_id: Uuid, user_id: Uuid) -> DomainResult<bool>;
+}
+
+/// Time-bound access to events, delegated by their organizers
+#[async_trait]
+pub trait AccessGrantRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<AccessGrant>>;
+    /// Every grant for the event, including ended ones, latest starting first
+    async fn find_by_event(&self, event_id: Uuid) -> DomainResult<Vec<AccessGrant>>;
+    /// The user's grants that hold at `now`
+    async fn find_active_for_user(&self, user_id: Uuid, now: DateTime<Utc>) -> DomainResult<Vec<AccessGrant>>;
+    async fn create(&self, grant: &AccessGrant) -> DomainResult<()>;
+    async fn revoke(&self, id: Uuid, revoked_at: DateTime<Utc>) -> DomainResult<()>;
+}
+
+/// Requests to hand events to another organizer
+#[async_trait]
+pub trait EventOwnershipTransferRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventOwnershipTransfer>>;
+    /// The event's pending transfer; there is at most one
+    async fn find_pending_for_event(&self, event_id: Uuid) -> DomainResult<Option<EventOwnershipTransfer>>;
+    /// Transfers waiting for the user to accept or decline, newest first
+    async fn find_pending_for_user(&self, user_id: Uuid) -> DomainResult<Vec<EventOwnershipTransfer>>;
+    async fn create(&self, transfer: &EventOwnershipTransfer) -> DomainResult<()>;
+    async fn update(&self, transfer: &EventOwnershipTransfer) -> DomainResult<()>;
+}
+
+/// Community-proposed events and their moderation
+#[async_trait]
+pub trait EventSubmissionRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventSubmission>>;
+    /// The moderation queue, oldest first
+    async fn find_pending(&self) -> DomainResult<Vec<EventSubmission>>;
+    /// The user's submissions, newest first
+    async fn find_by_submitter(&self, user_id: Uuid) -> DomainResult<Vec<EventSubmission>>;
+    async fn create(&self, submission: &EventSubmission) -> DomainResult<()>;
+    async fn update(&self, submission: &EventSubmission) -> DomainResult<()>;
+}
+
+/// Spam and abuse reports, and the content hidden while they are looked at
+#[async_trait]
+pub trait ContentReportRepository: Send + Sync {
+    /// Fails with a conflict when the reporter already has an open report on the target
+    async fn create(&self, report: &ContentReport) -> DomainResult<()>;
+    /// Every open report, oldest first
+    async fn find_open(&self) -> DomainResult<Vec<ContentReport>>;
+    /// Distinct users with an open report on the target
+    async fn count_open_reporters(&self, target: ReportTarget, target_id: Uuid) -> DomainResult<i64>;
+    /// Closes the target's open reports; returns how many there were
+    async fn resolve_open(
+        &self,
+        target: ReportTarget,
+        target_id: Uuid,
+        status: ReportStatus,
+        action: ModerationAction,
+        resolved_by: Uuid,
+        resolved_at: DateTime<Utc>,
+    ) -> DomainResult<u64>;
+    /// `hidden_by` is `None` when the content was hidden automatically
+    async fn hide(&self, target: ReportTarget, target_id: Uuid, hidden_by: Option<Uuid>, hidden_at: DateTime<Utc>) -> DomainResult<()>;
+    async fn unhide(&self, target: ReportTarget, target_id: Uuid) -> DomainResult<()>;
+    /// The ones among `target_ids` that are hidden
+    async fn find_hidden(&self, target: ReportTarget, target_ids: &[Uuid]) -> DomainResult<Vec<Uuid>>;
+}
+
+/// Consent checkboxes of events and the registrants' answers to them
+#[async_trait]
+pub trait EventConsentRepository: Send + Sync {
+    /// The event's checkboxes, in `ConsentKind` order
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventConsent>>;
+    /// Fails with a conflict when the event already has a checkbox of that kind
+    async fn create(&self, consent: &EventConsent) -> DomainResult<()>;
+    async fn update(&self, consent: &EventConsent) -> DomainResult<()>;
+    /// Answers already given stay on record
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+    /// Stores a registration's answers, replacing earlier answers to the same checkboxes
+    async fn record_answers(&self, answers: &[RegistrationConsent]) -> DomainResult<()>;
+    /// Every answer given for the event's registrations
+    async fn find_answers_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<RegistrationConsent>>;
+    /// The registration's current answers
+    async fn find_answers_by_registration_id(&self, registration_id: Uuid) -> DomainResult<Vec<RegistrationConsent>>;
+}
+
+/// Organizers' messages to the registrants of their events
+#[async_trait]
+pub trait EventBroadcastRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventBroadcast>>;
+    /// Newest first
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventBroadcast>>;
+    /// Scheduled broadcasts whose send time is at or before `now`, oldest first
+    async fn find_due(&self, now: DateTime<Utc>) -> DomainResult<Vec<EventBroadcast>>;
+    async fn create(&self, broadcast: &EventBroadcast) -> DomainResult<()>;
+    async fn update(&self, broadcast: &EventBroadcast) -> DomainResult<()>;
+}
+
+/// Conversations between event organizers and attendees
+#[async_trait]
+pub trait MessageThreadRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<MessageThread>>;
+    async fn find_by_event_and_attendee(&self, event_id: Uuid, attendee_id: Uuid) -> DomainResult<Option<MessageThread>>;
+    /// The attendee's threads, most recent activity first
+    async fn find_by_attendee(&self, attendee_id: Uuid) -> DomainResult<Vec<MessageThread>>;
+    /// Threads about events the user organizes or co-organizes, most recent activity first
+    async fn find_for_organizer(&self, user_id: Uuid) -> DomainResult<Vec<MessageThread>>;
+    /// Fails with a conflict when the attendee already has a thread about the event
+    async fn create(&self, thread: &MessageThread) -> DomainResult<()>;
+    /// Oldest first
+    async fn find_messages(&self, thread_id: Uuid) -> DomainResult<Vec<ThreadMessage>>;
+    /// Stores the message, counts it as unread for the other side and moves
+    /// the thread's `last_message_at` to it
+    async fn add_message(&self, message: &ThreadMessage) -> DomainResult<()>;
+    /// Clears the unread count of the organizers' side or the attendee's
+    async fn mark_read(&self, thread_id: Uuid, by_organizer: bool) -> DomainResult<()>;
+}
+
+/// Read model of events with their names and registration totals resolved,
+/// for list and dashboard views. Read-only: the store maintains it.
+#[async_trait]
+pub trait EventSummaryRepository: Send + Sync {
+    /// Summaries of the given events; events without one are skipped
+    async fn find_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<EventSummary>>;
+    /// Events that ended before `now`, leaving out drafts and cancelled
+    /// events, most recent first
+    async fn find_past(
+        &self,
+        filter: &PastEventFilter,
+        now: DateTime<Utc>,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<EventSummary>>;
+    /// Totals of the same events per start year and group, ordered by group then year
+    async fn yearly_attendance(
+        &self,
+        filter: &PastEventFilter,
+        grouping: ArchiveGrouping,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Vec<YearlyAttendance>>;
+}
+
+/// Figures for reporting on organizations, read from the event summaries and
+/// the survey responses
+#[async_trait]
+pub trait AnalyticsRepository: Send + Sync {
+    /// Events organized by the company's members that started in `year` and
+    /// ended before `now`, leaving out drafts and cancelled events
+    async fn organization_year(&self, company_id: Uuid, year: i32, now: DateTime<Utc>) -> DomainResult<OrganizationYear>;
+}
+
+/// Byte storage for uploaded media. Keys are relative, `/`-separated paths
+/// chosen by the caller, e.g. `events/<id>/attachments/<file>`.
+#[async_trait]
+pub trait MediaStorage: Send + Sync {
+    /// Stores `bytes` under `key`, replacing anything already there
+    async fn store(&self, key: &str, bytes: &[u8]) -> DomainResult<()>;
+    async fn load(&self, key: &str) -> DomainResult<Vec<u8>>;
+    /// Deleting a missing key is not an error
+    async fn delete(&self, key: &str) -> DomainResult<()>;
+}
+
+/// Outgoing calls telling systems outside Aqio that something happened to an event
+#[async_trait]
+pub trait EventWebhooks: Send + Sync {
+    async fn event_published(&self, event: &Event) -> DomainResult<()>;
+}
+
+/// Outgoing email through the mail provider
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: &EmailMessage) -> DomainResult<()>;
+}
+
+/// DNS lookups for checking records organizations publish
+#[async_trait]
+pub trait DnsResolver: Send + Sync {
+    /// Record data at `name`; empty when there are no such records. TXT
+    /// strings split over several chunks come back joined.
+    async fn lookup(&self, name: &str, record_type: DnsRecordType) -> DomainResult<Vec<String>>;
+}
+
+/// The Norwegian business register
+#[async_trait]
+pub trait CompanyRegistry: Send + Sync {
+    /// The company registered under `org_number` (nine digits); `None` when
+    /// there is none
+    async fn lookup(&self, org_number: &str) -> DomainResult<Option<RegisteredCompany>>;
+}
+
+/// Norwegian postal codes and the municipality and county each lies in
+#[async_trait]
+pub trait PostalCodeDirectory: Send + Sync {
+    /// The region of `postal_code` (four digits); `None` for codes not in use
+    async fn region(&self, postal_code: &str) -> DomainResult<Option<AddressRegion>>;
+}
+
+/// Weather forecasts for a place
+#[async_trait]
+pub trait WeatherForecaster: Send + Sync {
+    /// The forecast for `date` in `timezone` at the coordinates; `None` when
+    /// the date is beyond the forecast
+    async fn daily_forecast(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        date: NaiveDate,
+        timezone: Tz,
+    ) -> DomainResult<Option<WeatherForecast>>;
+}
+
+/// Public transport journeys between two places
+#[async_trait]
+pub trait TravelPlanner: Send + Sync {
+    /// Up to `query.limit` journeys, soonest first; empty when there are none
+    async fn plan(&self, query: &TravelQuery) -> DomainResult<Vec<TravelOption>>;
+}
+
+/// Short links to public event pages and their clicks
+#[async_trait]
+pub trait ShortLinkRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<ShortLink>>;
+    async fn find_by_code(&self, code: &str) -> DomainResult<Option<ShortLink>>;
+    /// Newest first
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<ShortLink>>;
+    /// Fails with a conflict when the code is taken
+    async fn create(&self, link: &ShortLink) -> DomainResult<()>;
+    /// Removes the link and its clicks
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+    async fn record_click(&self, click: &ShortLinkClick) -> DomainResult<()>;
+    /// Totals for the event's links that have been clicked
+    async fn click_totals(&self, event_id: Uuid) -> DomainResult<Vec<ShortLinkClicks>>;
+    /// At most `max_referrers` referring sites are listed
+    async fn stats(&self, link_id: Uuid, max_referrers: i64) -> DomainResult<ShortLinkStats>;
+}
+
+/// Events' overbooking policies
+#[async_trait]
+pub trait OverbookingPolicyRepository: Send + Sync {
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Option<OverbookingPolicy>>;
+    /// Creates or replaces the event's policy
+    async fn save(&self, policy: &OverbookingPolicy) -> DomainResult<()>;
+    /// Removing a policy that doesn't exist is not an error
+    async fn delete(&self, event_id: Uuid) -> DomainResult<()>;
+}
+
+/// Ticket types events sell and the orders for them
+#[async_trait]
+pub trait TicketRepository: Send + Sync {
+    async fn find_ticket_type(&self, id: Uuid) -> DomainResult<Option<TicketType>>;
+    /// Cheapest first
+    async fn find_ticket_types_by_event(&self, event_id: Uuid) -> DomainResult<Vec<TicketType>>;
+    async fn create_ticket_type(&self, ticket_type: &TicketType) -> DomainResult<()>;
+    async fn update_ticket_type(&self, ticket_type: &TicketType) -> DomainResult<()>;
+    /// Fails with a conflict once the type has orders
+    async fn delete_ticket_type(&self, id: Uuid) -> DomainResult<()>;
+    /// Tickets of the type on confirmed orders
+    async fn count_sold(&self, ticket_type_id: Uuid) -> DomainResult<i64>;
+
+    async fn find_order(&self, id: Uuid) -> DomainResult<Option<TicketOrder>>;
+    /// Newest first
+    async fn find_orders_by_event(&self, event_id: Uuid) -> DomainResult<Vec<TicketOrder>>;
+    /// Newest first
+    async fn find_orders_by_buyer(&self, buyer_id: Uuid) -> DomainResult<Vec<TicketOrder>>;
+    /// Stores the order unless it would sell more than the type's quantity;
+    /// the check and insert are one statement, so parallel purchases can't
+    /// oversell. Returns whether the order was stored.
+    async fn create_order_within_quantity(&self, order: &TicketOrder) -> DomainResult<bool>;
+    async fn update_order(&self, order: &TicketOrder) -> DomainResult<()>;
+    /// Confirmed orders per currency, ordered by currency code
+    async fn revenue_by_currency(&self, event_id: Uuid) -> DomainResult<Vec<CurrencyRevenue>>;
+    /// Orders placed and orders cancelled in `[from, to)`, per VAT rate, for
+    /// one event or all of them; lowest rate first
+    async fn vat_breakdown(
+        &self,
+        event_id: Option<Uuid>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<VatBreakdown>>;
+}
+
+/// Invoices for ticket orders
+#[async_trait]
+pub trait InvoiceRepository: Send + Sync {
+    /// Stores the invoice under the next invoice number, which is returned.
+    /// Fails with a conflict if the order already has an invoice.
+    async fn create_numbered(&self, invoice: &Invoice) -> DomainResult<i64>;
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Invoice>>;
+    async fn find_by_order(&self, order_id: Uuid) -> DomainResult<Option<Invoice>>;
+    /// Oldest due date first
+    async fn find(&self, filter: &InvoiceFilter) -> DomainResult<Vec<Invoice>>;
+    async fn update(&self, invoice: &Invoice) -> DomainResult<()>;
+}
+
+/// Refunds of ticket orders
+#[async_trait]
+pub trait TicketRefundRepository: Send + Sync {
+    /// Fails with a conflict if the order already has a refund
+    async fn create(&self, refund: &TicketRefund) -> DomainResult<()>;
+    /// Oldest first
+    async fn find_by_event(&self, event_id: Uuid) -> DomainResult<Vec<TicketRefund>>;
+    /// Pending refunds due to be tried at `now`, oldest first
+    async fn find_due(&self, now: DateTime<Utc>, limit: i64) -> DomainResult<Vec<TicketRefund>>;
+    async fn update(&self, refund: &TicketRefund) -> DomainResult<()>;
+}
+
+/// The payment provider ticket orders are paid through
+#[async_trait]
+pub trait PaymentProvider: Send + Sync {
+    /// Refunds `refund.amount` of the order's payment and returns the
+    /// provider's reference. The refund's ID is the idempotency key, so a
+    /// retry after a lost answer doesn't refund twice. A refusal that
+    /// retrying won't change is a business rule violation.
+    async fn refund(&self, refund: &TicketRefund) -> DomainResult<String>;
+}
+
+/// Tables of seated events and who sits where
+#[async_trait]
+pub trait SeatingRepository: Send + Sync {
+    async fn find_table(&self, id: Uuid) -> DomainResult<Option<SeatingTable>>;
+    /// The event's tables by position
+    async fn find_tables(&self, event_id: Uuid) -> DomainResult<Vec<SeatingTable>>;
+    async fn create_table(&self, table: &SeatingTable) -> DomainResult<()>;
+    async fn update_table(&self, table: &SeatingTable) -> DomainResult<()>;
+    /// Deletes the table; whoever sat there is unseated
+    async fn delete_table(&self, id: Uuid) -> DomainResult<()>;
+
+    async fn find_assignments(&self, event_id: Uuid) -> DomainResult<Vec<SeatAssignment>>;
+    /// Seats the registration, moving it if it already has a table
+    async fn assign(&self, assignment: &SeatAssignment) -> DomainResult<()>;
+    /// Succeeds also when the registration had no table
+    async fn unassign(&self, registration_id: Uuid) -> DomainResult<()>;
+}
+
+/// Bookable equipment and facilities, their availability and bookings
+#[async_trait]
+pub trait ResourceRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Resource>>;
+    /// By name; retired resources only when `include_inactive`
+    async fn list(&self, include_inactive: bool) -> DomainResult<Vec<Resource>>;
+    async fn create(&self, resource: &Resource) -> DomainResult<()>;
+    async fn update(&self, resource: &Resource) -> DomainResult<()>;
+
+    /// The resource's windows, earliest first
+    async fn find_windows(&self, resource_id: Uuid) -> DomainResult<Vec<AvailabilityWindow>>;
+    async fn replace_windows(&self, resource_id: Uuid, windows: &[AvailabilityWindow]) -> DomainResult<()>;
+
+    async fn find_booking(&self, id: Uuid) -> DomainResult<Option<ResourceBooking>>;
+    /// Bookings of the resources overlapping `starts_at..ends_at`, earliest first
+    async fn find_overlapping_bookings(
+        &self,
+        resource_ids: &[Uuid],
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> DomainResult<Vec<ResourceBooking>>;
+    async fn find_bookings_by_event(&self, event_id: Uuid) -> DomainResult<Vec<ResourceBooking>>;
+    /// Stores all the bookings, or none of them if any overlaps an existing
+    /// booking of its resource. The overlap check and the insert are atomic,
+    /// so parallel bookings can't both get the same resource. Returns whether
+    /// the bookings were stored.
+    async fn create_bookings_if_free(&self, bookings: &[ResourceBooking]) -> DomainResult<bool>;
+    async fn delete_booking(&self, id: Uuid) -> DomainResult<()>;
+}
+
+/// Staff shifts at events and who signed up for them
+#[async_trait]
+pub trait ShiftRepository: Send + Sync {
+    async fn find_shift(&self, id: Uuid) -> DomainResult<Option<EventShift>>;
+    /// The event's shifts, earliest first
+    async fn find_shifts(&self, event_id: Uuid) -> DomainResult<Vec<EventShift>>;
+    async fn create_shift(&self, shift: &EventShift) -> DomainResult<()>;
+    async fn update_shift(&self, shift: &EventShift) -> DomainResult<()>;
+    /// Removes the shift with its signups
+    async fn delete_shift(&self, id: Uuid) -> DomainResult<()>;
+
+    /// Signups for the event's shifts, in the order they were made
+    async fn find_signups(&self, event_id: Uuid) -> DomainResult<Vec<ShiftSignup>>;
+    /// The shifts the user signed up for, at any event, earliest first
+    async fn find_shifts_by_user(&self, user_id: Uuid) -> DomainResult<Vec<EventShift>>;
+    /// Stores the signup unless the shift already has its headcount; the
+    /// check and insert are one statement, so a shift is never overfilled.
+    /// Returns whether the signup was stored.
+    async fn sign_up_within_headcount(&self, signup: &ShiftSignup) -> DomainResult<bool>;
+    async fn cancel_signup(&self, shift_id: Uuid, user_id: Uuid) -> DomainResult<()>;
+}
+
+/// Expo floor plans and the exhibitors placed on them
+#[async_trait]
+pub trait ExhibitorRepository: Send + Sync {
+    async fn find_booth(&self, id: Uuid) -> DomainResult<Option<Booth>>;
+    /// The event's booths by hall, then number
+    async fn find_booths(&self, event_id: Uuid) -> DomainResult<Vec<Booth>>;
+    async fn create_booth(&self, booth: &Booth) -> DomainResult<()>;
+    async fn update_booth(&self, booth: &Booth) -> DomainResult<()>;
+    /// Removes the booth; its exhibitor stays, without a booth
+    async fn delete_booth(&self, id: Uuid) -> DomainResult<()>;
+
+    async fn find_exhibitor(&self, id: Uuid) -> DomainResult<Option<Exhibitor>>;
+    /// The event's exhibitors by company name
+    async fn find_exhibitors(&self, event_id: Uuid) -> DomainResult<Vec<Exhibitor>>;
+    async fn create_exhibitor(&self, exhibitor: &Exhibitor) -> DomainResult<()>;
+    /// Updates the exhibitor's details; the booth is only changed by [`Self::allocate`]
+    async fn update_exhibitor(&self, exhibitor: &Exhibitor) -> DomainResult<()>;
+    async fn delete_exhibitor(&self, id: Uuid) -> DomainResult<()>;
+    /// Gives the exhibitor the booth, or takes its booth away with `None`.
+    /// A booth holds one exhibitor; allocating a taken booth is a conflict.
+    async fn allocate(&self, exhibitor_id: Uuid, booth_id: Option<Uuid>) -> DomainResult<()>;
+}
+
+/// Attendees exhibitors captured by scanning their badges
+#[async_trait]
+pub trait LeadRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Lead>>;
+    /// Stores the lead unless the exhibitor already has the registration as
+    /// a lead; returns whether it was stored
+    async fn capture(&self, lead: &Lead) -> DomainResult<bool>;
+    /// The exhibitor's leads, in the order they were scanned
+    async fn find_by_exhibitor(&self, exhibitor_id: Uuid) -> DomainResult<Vec<Lead>>;
+    /// Every exhibitor's leads at the event, in the order they were scanned
+    async fn find_by_event(&self, event_id: Uuid) -> DomainResult<Vec<Lead>>;
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+}
+
+/// Attendees' matchmaking profiles, the rooms set aside for their meetings,
+/// and the meetings themselves
+#[async_trait]
+pub trait MatchmakingRepository: Send + Sync {
+    async fn find_profile(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Option<MatchmakingProfile>>;
+    /// Everyone taking part in the event's matchmaking, in joining order
+    async fn find_profiles(&self, event_id: Uuid) -> DomainResult<Vec<MatchmakingProfile>>;
+    /// Creates the profile or replaces it, interests and availability included
+    async fn save_profile(&self, profile: &MatchmakingProfile) -> DomainResult<()>;
+    async fn delete_profile(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<()>;
+
+    /// The resources meetings at the event are held in
+    async fn find_rooms(&self, event_id: Uuid) -> DomainResult<Vec<Uuid>>;
+    async fn replace_rooms(&self, event_id: Uuid, resource_ids: &[Uuid]) -> DomainResult<()>;
+
+    async fn find_meeting(&self, id: Uuid) -> DomainResult<Option<Meeting>>;
+    /// The event's meetings of every status, earliest first
+    async fn find_meetings(&self, event_id: Uuid) -> DomainResult<Vec<Meeting>>;
+    async fn create_meeting(&self, meeting: &Meeting) -> DomainResult<()>;
+    /// Updates the status and the room booking
+    async fn update_meeting(&self, meeting: &Meeting) -> DomainResult<()>;
+}
+
+/// Web vitals reported by browsers running the web app
+#[async_trait]
+pub trait WebVitalRepository: Send + Sync {
+    async fn record(&self, samples: &[WebVitalSample]) -> DomainResult<()>;
+    /// Samples recorded at or after `since`, oldest first
+    async fn find_since(&self, since: DateTime<Utc>) -> DomainResult<Vec<WebVitalSample>>;
+    /// Removes samples recorded before `before`; returns how many were removed
+    async fn delete_before(&self, before: DateTime<Utc>) -> DomainResult<u64>;
+}
+
+/// Exports admins scheduled, and how their last run went
+#[async_trait]
+pub trait ExportJobRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<ExportJob>>;
+    /// Every job by name
+    async fn find_all(&self) -> DomainResult<Vec<ExportJob>>;
+    /// Active jobs whose next run is at or before `now`, earliest first
+    async fn find_due(&self, now: DateTime<Utc>) -> DomainResult<Vec<ExportJob>>;
+    async fn create(&self, job: &ExportJob) -> DomainResult<()>;
+    async fn update(&self, job: &ExportJob) -> DomainResult<()>;
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+}
+
+/// Delivers export files to external systems
+#[async_trait]
+pub trait ExportUploader: Send + Sync {
+    /// Puts `bytes` at the destination as `file_name`, replacing a file of
+    /// that name
+    async fn upload(&self, destination: &ExportDestination, file_name: &str, bytes: &[u8]) -> DomainResult<()>;
+}
+
+/// CRM connectors and what each has synced, record by record
+#[async_trait]
+pub trait CrmConnectorRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<CrmConnector>>;
+    /// Every connector by name
+    async fn find_all(&self) -> DomainResult<Vec<CrmConnector>>;
+    /// Active connectors whose next sync is at or before `now`, earliest first
+    async fn find_due(&self, now: DateTime<Utc>) -> DomainResult<Vec<CrmConnector>>;
+    async fn create(&self, connector: &CrmConnector) -> DomainResult<()>;
+    async fn update(&self, connector: &CrmConnector) -> DomainResult<()>;
+    /// Deletes the connector with its sync records
+    async fn delete(&self, id: Uuid) -> DomainResult<()>;
+
+    async fn find_record(
+        &self,
+        connector_id: Uuid,
+        kind: CrmRecordKind,
+        local_key: &str,
+    ) -> DomainResult<Option<CrmSyncRecord>>;
+    /// The connector's records in `state`, most recently touched first
+    async fn find_records(&self, connector_id: Uuid, state: CrmSyncState, limit: i64) -> DomainResult<Vec<CrmSyncRecord>>;
+    /// Inserts the record or replaces the one with the same key
+    async fn save_record(&self, record: &CrmSyncRecord) -> DomainResult<()>;
+    async fn count_records(&self, connector_id: Uuid) -> DomainResult<CrmSyncCounts>;
+}
+
+/// Reads and writes records in a connector's CRM
+#[async_trait]
+pub trait CrmClient: Send + Sync {
+    /// The contact with `email`, if the CRM has one
+    async fn find_contact(
+        &self,
+        connector: &CrmConnector,
+        email: &str,
+        properties: &[String],
+    ) -> DomainResult<Option<CrmRecord>>;
+    /// A record of `object` by its CRM ID; `None` when it was deleted there
+    async fn get(
+        &self,
+        connector: &CrmConnector,
+        object: &str,
+        id: &str,
+        properties: &[String],
+    ) -> DomainResult<Option<CrmRecord>>;
+    /// Creates a record of `object` and returns its CRM ID
+    async fn create(
+        &self,
+        connector: &CrmConnector,
+        object: &str,
+        properties: &BTreeMap<String, String>,
+    ) -> DomainResult<String>;
+    async fn update(
+        &self,
+        connector: &CrmConnector,
+        object: &str,
+        id: &str,
+        properties: &BTreeMap<String, String>,
+    ) -> DomainResult<()>;
+}
+
+/// Exchange rates to Norwegian kroner
+#[async_trait]
+pub trait ExchangeRateProvider: Send + Sync {
+    /// The most recent published rate of `currency`
+    async fn rate_to_nok(&self, currency: Currency) -> DomainResult<ExchangeRate>;
+}
+
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    async fn create(&self, entry: &AuditLogEntry) -> DomainResult<()>;
+    /// Newest entries first
+    async fn find_by_filter(&self, filter: &AuditLogFilter, pagination: PaginationParams) -> DomainResult<PaginatedResult<AuditLogEntry>>;
+}
@@ -1,9 +1,14 @@
 use crate::domain::{
     DomainResult, Event, EventInvitation, EventRegistration, User,
     EventDomainValidation, InvitationDomainValidation, DomainValidation,
-    InvitationStatus, RegistrationStatus
+    DeliveryFailure, InvitationFunnel, InvitationStatus, RegistrationSource, RegistrationStatus,
+    BlackoutPeriod, ScheduleConflict, ScheduleRule, SchedulingPolicy,
+    DomainVerificationStatus, MailPlatform, SendingDomain, SendingDomainLookup,
+    DigestGroup, DigestItem, NotificationDigest, NotificationPreferences,
+    AccessGrant, OrganizationRole, Permission, PermissionSet, UserRole,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use std::collections::BTreeMap;
 use uuid::Uuid;
 
 // Core domain services (business logic without infrastructure dependencies)
@@ -86,12 +91,77 @@ impl InvitationService {
         invitation.updated_at = Utc::now();
     }
 
+    /// Records the first open of the invitation email
+    ///
+    /// An open also proves the email went out, so a pending invitation moves
+    /// on as well; answered or cancelled invitations keep their status.
     pub fn mark_as_opened(&self, invitation: &mut EventInvitation) {
-        if invitation.status == InvitationStatus::Sent || invitation.status == InvitationStatus::Delivered {
+        let now = Utc::now();
+        if invitation.opened_at.is_none() {
+            invitation.opened_at = Some(now);
+            invitation.updated_at = now;
+        }
+        if matches!(
+            invitation.status,
+            InvitationStatus::Pending | InvitationStatus::Sent | InvitationStatus::Delivered
+        ) {
+            invitation.sent_at.get_or_insert(now);
             invitation.status = InvitationStatus::Opened;
-            invitation.opened_at = Some(Utc::now());
-            invitation.updated_at = Utc::now();
+            invitation.updated_at = now;
+        }
+    }
+
+    /// Records the first click on a tracked link, which implies an open
+    pub fn mark_as_clicked(&self, invitation: &mut EventInvitation) {
+        self.mark_as_opened(invitation);
+        if invitation.clicked_at.is_none() {
+            let now = Utc::now();
+            invitation.clicked_at = Some(now);
+            invitation.updated_at = now;
+        }
+    }
+
+    /// Records a bounce or spam complaint for the invitation email. The first
+    /// report wins; returns whether anything changed.
+    pub fn mark_as_undeliverable(
+        &self,
+        invitation: &mut EventInvitation,
+        failure: DeliveryFailure,
+        now: DateTime<Utc>,
+    ) -> bool {
+        if invitation.delivery_failure.is_some() {
+            return false;
+        }
+        invitation.delivery_failure = Some(failure);
+        invitation.delivery_failed_at = Some(now);
+        invitation.updated_at = now;
+        true
+    }
+
+    /// Refuses to send an invitation whose address bounced or complained
+    pub fn check_deliverable(&self, invitation: &EventInvitation) -> DomainResult<()> {
+        match invitation.delivery_failure {
+            Some(DeliveryFailure::Bounce) => Err(crate::domain::DomainError::business_rule(
+                "The invitation email bounced; the address no longer receives email",
+            )),
+            Some(DeliveryFailure::Complaint) => Err(crate::domain::DomainError::business_rule(
+                "The recipient reported the invitation email as spam",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub fn funnel(&self, invitations: &[EventInvitation]) -> InvitationFunnel {
+        let mut funnel = InvitationFunnel::default();
+        for invitation in invitations.iter().filter(|i| i.status != InvitationStatus::Cancelled) {
+            funnel.invited += 1;
+            funnel.sent += i64::from(invitation.sent_at.is_some() || invitation.status != InvitationStatus::Pending);
+            funnel.opened += i64::from(invitation.opened_at.is_some());
+            funnel.clicked += i64::from(invitation.clicked_at.is_some());
+            funnel.accepted += i64::from(invitation.status == InvitationStatus::Accepted);
+            funnel.declined += i64::from(invitation.status == InvitationStatus::Declined);
         }
+        funnel
     }
 
     pub fn accept_invitation(&self, invitation: &mut EventInvitation) -> DomainResult<()> {
@@ -157,7 +227,12 @@ impl RegistrationService {
         Ok(())
     }
 
-    pub fn promote_from_waitlist(&self, registration: &mut EventRegistration) -> DomainResult<()> {
+    // The promoted attendee holds the spot until `confirm_by`; see `confirm_promotion`
+    pub fn promote_from_waitlist(
+        &self,
+        registration: &mut EventRegistration,
+        confirm_by: DateTime<Utc>,
+    ) -> DomainResult<()> {
         if registration.status != RegistrationStatus::Waitlisted {
             return Err(crate::domain::DomainError::business_rule(
                 "Only waitlisted registrations can be promoted"
@@ -165,13 +240,42 @@ impl RegistrationService {
         }
 
         registration.status = RegistrationStatus::Registered;
+        registration.registration_source = RegistrationSource::WaitlistPromotion;
         registration.waitlist_position = None;
         registration.waitlist_added_at = None;
+        registration.promotion_expires_at = Some(confirm_by);
         registration.updated_at = Utc::now();
 
         Ok(())
     }
 
+    pub fn confirm_promotion(
+        &self,
+        registration: &mut EventRegistration,
+        now: DateTime<Utc>,
+    ) -> DomainResult<()> {
+        match registration.promotion_expires_at {
+            None => Err(crate::domain::DomainError::business_rule(
+                "There is no spot offer to confirm"
+            )),
+            Some(deadline) if deadline <= now => Err(crate::domain::DomainError::business_rule(
+                "The spot offer has expired"
+            )),
+            Some(_) => {
+                registration.promotion_expires_at = None;
+                registration.updated_at = now;
+                Ok(())
+            }
+        }
+    }
+
+    pub fn promotion_expired(&self, registration: &EventRegistration, now: DateTime<Utc>) -> bool {
+        registration.status == RegistrationStatus::Registered
+            && registration
+                .promotion_expires_at
+                .is_some_and(|deadline| deadline <= now)
+    }
+
     pub fn check_in(&self, registration: &mut EventRegistration) -> DomainResult<()> {
         if registration.status != RegistrationStatus::Registered {
             return Err(crate::domain::DomainError::business_rule(
@@ -198,6 +302,95 @@ impl RegistrationService {
 
         Ok(())
     }
+
+    /// Folds `duplicates` of the same person's registration into `kept`
+    ///
+    /// Details missing from `kept` are taken from the duplicates and differing
+    /// requirements are combined. Guests are pooled, and the furthest status
+    /// wins: checked in, then registered, waitlisted, no-show and cancelled.
+    pub fn merge_duplicates(
+        &self,
+        kept: &mut EventRegistration,
+        duplicates: &[EventRegistration],
+        now: DateTime<Utc>,
+    ) -> DomainResult<()> {
+        for duplicate in duplicates {
+            if duplicate.event_id != kept.event_id {
+                return Err(crate::domain::DomainError::business_rule(
+                    "Only registrations for the same event can be merged"
+                ));
+            }
+            if kept.user_id.is_some() && duplicate.user_id.is_some() && kept.user_id != duplicate.user_id {
+                return Err(crate::domain::DomainError::business_rule(
+                    "Registrations belonging to different accounts can't be merged"
+                ));
+            }
+        }
+
+        for duplicate in duplicates {
+            fill(&mut kept.invitation_id, duplicate.invitation_id);
+            fill(&mut kept.user_id, duplicate.user_id);
+            fill(&mut kept.external_contact_id, duplicate.external_contact_id);
+            fill(&mut kept.registrant_email, duplicate.registrant_email.clone());
+            fill(&mut kept.registrant_name, duplicate.registrant_name.clone());
+            fill(&mut kept.registrant_phone, duplicate.registrant_phone.clone());
+            fill(&mut kept.registrant_company, duplicate.registrant_company.clone());
+            fill(&mut kept.custom_responses, duplicate.custom_responses.clone());
+            combine(&mut kept.dietary_restrictions, &duplicate.dietary_restrictions);
+            combine(&mut kept.accessibility_needs, &duplicate.accessibility_needs);
+            combine(&mut kept.special_requests, &duplicate.special_requests);
+
+            for name in &duplicate.guest_names {
+                if !kept.guest_names.iter().any(|known| known.trim().eq_ignore_ascii_case(name.trim())) {
+                    kept.guest_names.push(name.clone());
+                }
+            }
+            kept.guest_count = kept.guest_count.max(duplicate.guest_count);
+
+            if status_rank(&duplicate.status) > status_rank(&kept.status) {
+                kept.status = duplicate.status.clone();
+                kept.checked_in_at = duplicate.checked_in_at;
+                kept.cancelled_at = duplicate.cancelled_at;
+                kept.waitlist_position = duplicate.waitlist_position;
+                kept.waitlist_added_at = duplicate.waitlist_added_at;
+                kept.promotion_expires_at = duplicate.promotion_expires_at;
+            }
+        }
+        kept.guest_count = kept.guest_count.max(kept.guest_names.len() as i32);
+        kept.updated_at = now;
+
+        Ok(())
+    }
+}
+
+fn fill<T>(kept: &mut Option<T>, other: Option<T>) {
+    if kept.is_none() {
+        *kept = other;
+    }
+}
+
+// Free-text requirements from both registrations are kept, once each
+fn combine(kept: &mut Option<String>, other: &Option<String>) {
+    let Some(other) = other.as_deref().map(str::trim).filter(|other| !other.is_empty()) else {
+        return;
+    };
+    match kept.as_deref().map(str::trim).filter(|kept| !kept.is_empty()) {
+        None => *kept = Some(other.to_string()),
+        Some(known) if !known.to_lowercase().contains(&other.to_lowercase()) => {
+            *kept = Some(format!("{}; {}", known, other));
+        }
+        Some(_) => {}
+    }
+}
+
+fn status_rank(status: &RegistrationStatus) -> u8 {
+    match status {
+        RegistrationStatus::Attended => 4,
+        RegistrationStatus::Registered => 3,
+        RegistrationStatus::Waitlisted => 2,
+        RegistrationStatus::NoShow => 1,
+        RegistrationStatus::Cancelled => 0,
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -224,15 +417,373 @@ impl UserService {
     }
 }
 
+/// What a user may do. Platform roles grant their permissions everywhere,
+/// except `organization:manage`, which non-administrators hold for their own
+/// organization only. Custom roles grant theirs within the organization that
+/// defined them, and access grants theirs for one event while they last.
+#[derive(Debug, Clone, Default)]
+pub struct PermissionChecker {
+    platform: PermissionSet,
+    company_id: Option<Uuid>,
+    organization: PermissionSet,
+    events: BTreeMap<Uuid, PermissionSet>,
+}
+
+impl PermissionChecker {
+    /// Permissions of `roles` alone, for a caller whose organization is unknown
+    pub fn for_roles(roles: &[UserRole]) -> Self {
+        let mut checker = Self::default();
+        for role in roles {
+            checker.grant(role);
+        }
+        checker
+    }
+
+    /// Permissions of the user's stored role and of their organization's
+    /// `custom_roles`; roles of other organizations are ignored
+    pub fn for_user(user: &User, custom_roles: &[OrganizationRole]) -> Self {
+        let mut checker = Self {
+            company_id: user.company_id,
+            ..Self::default()
+        };
+        checker.grant(&user.role);
+        for role in custom_roles {
+            if Some(role.company_id) == checker.company_id {
+                checker
+                    .organization
+                    .extend(role.permissions.iter().filter(Permission::is_assignable));
+            }
+        }
+        checker
+    }
+
+    /// Adds roles from the user's sign-in token
+    pub fn with_roles(mut self, roles: &[UserRole]) -> Self {
+        for role in roles {
+            self.grant(role);
+        }
+        self
+    }
+
+    /// Adds what the user's access `grants` delegate, counting only those
+    /// that hold at `now`
+    pub fn with_grants(mut self, grants: &[AccessGrant], now: DateTime<Utc>) -> Self {
+        for grant in grants.iter().filter(|grant| grant.is_active_at(now)) {
+            self.events
+                .entry(grant.event_id)
+                .or_default()
+                .extend(grant.permissions.iter().filter(Permission::is_delegable));
+        }
+        self
+    }
+
+    fn grant(&mut self, role: &UserRole) {
+        for permission in role.permissions().iter() {
+            let organization_only = permission == Permission::OrganizationManage && !matches!(role, UserRole::Admin);
+            if !organization_only {
+                self.platform.insert(permission);
+            } else if self.company_id.is_some() {
+                self.organization.insert(permission);
+            }
+        }
+    }
+
+    /// Whether the user may do this anywhere
+    pub fn has(&self, permission: Permission) -> bool {
+        self.platform.contains(permission)
+    }
+
+    /// Whether the user may do this for something belonging to `company_id`
+    pub fn has_in(&self, permission: Permission, company_id: Option<Uuid>) -> bool {
+        self.has(permission)
+            || (company_id.is_some() && company_id == self.company_id && self.organization.contains(permission))
+    }
+
+    /// Whether an access grant lets the user do this for the event; combine
+    /// with `has_in` for the event's organization
+    pub fn has_for_event(&self, permission: Permission, event_id: Uuid) -> bool {
+        self.events
+            .get(&event_id)
+            .is_some_and(|permissions| permissions.contains(permission))
+    }
+
+    /// Whether the user may do this anywhere or in their own organization,
+    /// for deciding which controls to show
+    pub fn has_any(&self, permission: Permission) -> bool {
+        self.has_in(permission, self.company_id)
+    }
+
+    /// Permissions that hold everywhere
+    pub fn platform_permissions(&self) -> &PermissionSet {
+        &self.platform
+    }
+
+    /// The user's organization and the permissions they hold only there
+    pub fn organization_permissions(&self) -> Option<(Uuid, &PermissionSet)> {
+        self.company_id
+            .filter(|_| !self.organization.is_empty())
+            .map(|company_id| (company_id, &self.organization))
+    }
+
+    /// Events the user holds access grants for right now, with what they allow
+    pub fn event_permissions(&self) -> impl Iterator<Item = (Uuid, &PermissionSet)> {
+        self.events.iter().map(|(event_id, permissions)| (*event_id, permissions))
+    }
+}
+
+/// Checks an event's dates against its organization's blackout periods and
+/// scheduling policy
+#[derive(Debug, Clone, Default)]
+pub struct SchedulingService;
+
+impl SchedulingService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Every rule the dates break, blackouts first. `same_day_events` is how many
+    /// other events the organization starts on the event's first day.
+    pub fn check(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        now: DateTime<Utc>,
+        policy: Option<&SchedulingPolicy>,
+        blackouts: &[BlackoutPeriod],
+        same_day_events: usize,
+    ) -> Vec<ScheduleConflict> {
+        let first_day = start.date_naive();
+        let last_day = end.date_naive().max(first_day);
+
+        let mut conflicts: Vec<ScheduleConflict> = blackouts
+            .iter()
+            .filter(|blackout| blackout.overlaps(first_day, last_day))
+            .map(|blackout| ScheduleConflict {
+                rule: ScheduleRule::Blackout,
+                enforcement: blackout.enforcement,
+                message: if blackout.start_date == blackout.end_date {
+                    format!("{} is a blackout date ({})", blackout.start_date, blackout.name)
+                } else {
+                    format!(
+                        "The event overlaps {} ({} to {})",
+                        blackout.name, blackout.start_date, blackout.end_date
+                    )
+                },
+            })
+            .collect();
+
+        let Some(policy) = policy else {
+            return conflicts;
+        };
+        let conflict = |rule, message: String| ScheduleConflict {
+            rule,
+            enforcement: policy.enforcement,
+            message,
+        };
+
+        if let Some(max) = policy.max_events_per_day {
+            if same_day_events as i64 >= max as i64 {
+                conflicts.push(conflict(
+                    ScheduleRule::DailyCapacity,
+                    format!(
+                        "{} other event(s) already start on {}; the limit is {} per day",
+                        same_day_events, first_day, max
+                    ),
+                ));
+            }
+        }
+
+        // A week or longer always contains a weekend
+        let covers_weekend = first_day
+            .iter_days()
+            .take_while(|day| *day <= last_day)
+            .take(7)
+            .any(|day| matches!(day.weekday(), Weekday::Sat | Weekday::Sun));
+        if !policy.allow_weekends && covers_weekend {
+            conflicts.push(conflict(
+                ScheduleRule::Weekend,
+                "Events are not held on weekends".to_string(),
+            ));
+        }
+
+        if let Some(days) = policy.min_notice_days {
+            if start < now + Duration::days(days as i64) {
+                conflicts.push(conflict(
+                    ScheduleRule::MinimumNotice,
+                    format!("Events need at least {} days' notice", days),
+                ));
+            }
+        }
+
+        conflicts
+    }
+}
+
+/// Validates organizations' sending domains and judges DNS lookups against
+/// the records they have to publish
+#[derive(Debug, Clone, Default)]
+pub struct SendingDomainService;
+
+impl SendingDomainService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn validate(&self, sending_domain: &SendingDomain) -> DomainResult<()> {
+        let domain = &sending_domain.domain;
+        let labels: Vec<&str> = domain.split('.').collect();
+        if domain.len() > 253 || labels.len() < 2 || !labels.iter().all(|label| is_dns_label(label)) {
+            return Err(crate::domain::DomainError::invalid_format("domain", "a domain such as mail.example.no", domain));
+        }
+        if !is_dns_label(&sending_domain.dkim_selector) {
+            return Err(crate::domain::DomainError::invalid_format(
+                "dkim_selector",
+                "letters, digits and hyphens",
+                &sending_domain.dkim_selector,
+            ));
+        }
+
+        let on_domain = sending_domain
+            .from_email
+            .rsplit_once('@')
+            .is_some_and(|(local, host)| !local.is_empty() && host.eq_ignore_ascii_case(domain));
+        if !on_domain {
+            return Err(crate::domain::DomainError::validation(
+                "from_email",
+                "The sender address must be on the sending domain",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Records which required records `lookup` contains. The domain is verified
+    /// only while all of them are found.
+    pub fn apply_lookup(
+        &self,
+        sending_domain: &mut SendingDomain,
+        platform: &MailPlatform,
+        lookup: &SendingDomainLookup,
+        now: DateTime<Utc>,
+    ) {
+        let verification = sending_domain.verification_record_value();
+        sending_domain.ownership_verified = lookup.verification_txt.iter().any(|txt| txt.trim() == verification);
+
+        let include = format!("include:{}", platform.spf_include);
+        sending_domain.spf_verified = lookup.domain_txt.iter().any(|txt| {
+            let mut terms = txt.split_whitespace();
+            terms.next().is_some_and(|version| version.eq_ignore_ascii_case("v=spf1"))
+                && terms.any(|term| term.eq_ignore_ascii_case(&include))
+        });
+
+        let target = sending_domain.dkim_record_target(platform);
+        sending_domain.dkim_verified = lookup
+            .dkim_cname
+            .iter()
+            .any(|cname| cname.trim_end_matches('.').eq_ignore_ascii_case(&target));
+
+        let all_found = sending_domain.ownership_verified && sending_domain.spf_verified && sending_domain.dkim_verified;
+        if all_found && !sending_domain.is_verified() {
+            sending_domain.verified_at = Some(now);
+        }
+        sending_domain.status = if all_found {
+            DomainVerificationStatus::Verified
+        } else {
+            DomainVerificationStatus::Failed
+        };
+        sending_domain.last_checked_at = Some(now);
+        sending_domain.updated_at = now;
+    }
+}
+
+/// Decides when users' digests are due and groups their pending items
+#[derive(Debug, Clone, Default)]
+pub struct NotificationDigestService;
+
+impl NotificationDigestService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// A digest is due a full period after the previous one, or right away
+    /// for a user who never got one
+    pub fn is_due(&self, preferences: &NotificationPreferences, now: DateTime<Utc>) -> bool {
+        match (preferences.digest_frequency.period(), preferences.last_digest_at) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(period), Some(last)) => now - last >= period,
+        }
+    }
+
+    /// Groups `items` per event, keeping the order they arrived in. `None`
+    /// when there is nothing to send.
+    pub fn compile(&self, preferences: &NotificationPreferences, mut items: Vec<DigestItem>) -> Option<NotificationDigest> {
+        if items.is_empty() {
+            return None;
+        }
+        items.sort_by_key(|item| item.created_at);
+
+        let mut groups: Vec<DigestGroup> = Vec::new();
+        for item in items {
+            match groups.iter_mut().find(|group| group.event_id == item.event_id) {
+                Some(group) => {
+                    // Later items carry the event's current title
+                    if item.event_title.is_some() {
+                        group.event_title = item.event_title.clone();
+                    }
+                    group.items.push(item);
+                }
+                None => groups.push(DigestGroup {
+                    event_id: item.event_id,
+                    event_title: item.event_title.clone(),
+                    items: vec![item],
+                }),
+            }
+        }
+
+        Some(NotificationDigest {
+            user_id: preferences.user_id,
+            frequency: preferences.digest_frequency,
+            groups,
+        })
+    }
+}
+
+fn is_dns_label(label: &str) -> bool {
+    (1..=63).contains(&label.len())
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{EventStatus, LocationType, InvitationMethod, RegistrationSource};
+    use crate::domain::{AttachmentCategory, DigestFrequency, EventAttachment, EventSponsor, EventStatus, GeoRadius, LocationType, InvitationMethod, RegistrationSource, RegistrationState, RuleEnforcement, SponsorTier};
+    use chrono::{NaiveDate, TimeZone};
+
+    fn create_test_user() -> User {
+        User {
+            id: Uuid::new_v4(),
+            keycloak_id: "keycloak_test".to_string(),
+            email: "test@example.com".to_string(),
+            name: "Test User".to_string(),
+            company_id: None,
+            role: crate::domain::UserRole::Organizer,
+            is_active: true,
+            timezone: None,
+            locale: None,
+            theme: None,
+            calendar_view: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
 
     fn create_test_event() -> Event {
         Event {
             id: Uuid::new_v4(),
             title: "Test Event".to_string(),
+            slug: "test-event".to_string(),
             description: "A test event".to_string(),
             category_id: "test-category".to_string(),
             start_date: Utc::now() + chrono::Duration::hours(24),
@@ -243,6 +794,9 @@ mod tests {
             address: None,
             virtual_link: Some("https://example.com".to_string()),
             virtual_access_code: None,
+            latitude: None,
+            longitude: None,
+            region: None,
             organizer_id: Uuid::new_v4(),
             co_organizers: vec![],
             is_private: false,
@@ -260,6 +814,7 @@ mod tests {
             image_url: None,
             custom_fields: None,
             status: EventStatus::Published,
+            publish_at: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -285,6 +840,131 @@ mod tests {
         assert!(service.should_add_to_waitlist(&event, 15));
     }
 
+    #[test]
+    fn test_event_service_registration_window() {
+        let service = EventService::new();
+        let mut event = create_test_event();
+        assert!(service.can_register_for_event(&event).is_ok());
+
+        event.registration_closes = Some(Utc::now() - chrono::Duration::hours(1));
+        assert!(service.can_register_for_event(&event).is_err());
+
+        event.registration_closes = None;
+        event.registration_opens = Some(Utc::now() + chrono::Duration::hours(1));
+        assert!(service.can_register_for_event(&event).is_err());
+
+        event.registration_opens = None;
+        event.status = EventStatus::Draft;
+        assert!(service.can_register_for_event(&event).is_err());
+    }
+
+    #[test]
+    fn test_event_slug_for() {
+        let id = Uuid::parse_str("1a2b3c4d-0000-0000-0000-000000000000").unwrap();
+        assert_eq!(
+            Event::slug_for("Sjømat & Havbruk: Årskonferanse 2025", id),
+            "sjomat-havbruk-arskonferanse-2025-1a2b3c4d"
+        );
+        assert_eq!(Event::slug_for("!!!", id), "1a2b3c4d");
+    }
+
+    #[test]
+    fn test_event_is_running() {
+        let mut event = create_test_event();
+        assert!(!event.is_running(Utc::now()));
+        assert!(event.is_running(event.start_date));
+        assert!(!event.is_running(event.end_date));
+
+        event.status = EventStatus::Cancelled;
+        assert!(!event.is_running(event.start_date));
+    }
+
+    #[test]
+    fn test_event_registration_state() {
+        let now = Utc::now();
+        let mut event = create_test_event();
+        assert_eq!(event.registration_state(now, None), RegistrationState::Open);
+        assert_eq!(event.registration_state(now, Some(10)), RegistrationState::WaitlistOnly);
+        event.allow_waitlist = false;
+        assert_eq!(event.registration_state(now, Some(10)), RegistrationState::Full);
+
+        event.registration_opens = Some(now + chrono::Duration::hours(1));
+        assert_eq!(event.registration_state(now, Some(0)), RegistrationState::NotYetOpen);
+        event.registration_opens = None;
+
+        // Registration can close no later than the start
+        event.registration_closes = Some(event.start_date + chrono::Duration::days(1));
+        assert_eq!(event.registration_closes_at(), event.start_date);
+        assert_eq!(event.registration_state(event.start_date + chrono::Duration::seconds(1), None), RegistrationState::Closed);
+
+        event.status = EventStatus::Cancelled;
+        assert_eq!(event.registration_state(now, None), RegistrationState::Closed);
+    }
+
+    #[test]
+    fn test_event_is_due_for_publishing() {
+        let now = Utc::now();
+        let mut event = create_test_event();
+        event.status = EventStatus::Draft;
+        assert!(!event.is_due_for_publishing(now));
+
+        event.publish_at = Some(now + chrono::Duration::minutes(5));
+        assert!(!event.is_due_for_publishing(now));
+        assert!(event.is_due_for_publishing(now + chrono::Duration::minutes(5)));
+
+        event.status = EventStatus::Published;
+        assert!(!event.is_due_for_publishing(now + chrono::Duration::minutes(5)));
+    }
+
+    #[test]
+    fn test_attachment_filename_and_category() {
+        assert_eq!(EventAttachment::sanitize_filename("C:\\Slides\\Lice treatment 2025.pptx"), "Lice_treatment_2025.pptx");
+        assert_eq!(EventAttachment::sanitize_filename("../../etc/passwd"), "passwd");
+        assert_eq!(EventAttachment::sanitize_filename("..hidden"), "hidden");
+        assert_eq!(EventAttachment::sanitize_filename("kart.pdf/"), "file");
+
+        assert_eq!(AttachmentCategory::from_mime_type("application/pdf"), AttachmentCategory::Document);
+        assert_eq!(
+            AttachmentCategory::from_mime_type("application/vnd.openxmlformats-officedocument.presentationml.presentation"),
+            AttachmentCategory::Presentation
+        );
+        assert_eq!(AttachmentCategory::from_mime_type("image/png"), AttachmentCategory::Photo);
+        assert_eq!(AttachmentCategory::from_mime_type("application/zip"), AttachmentCategory::Other);
+    }
+
+    #[test]
+    fn test_geo_radius() {
+        let bergen = GeoRadius::new(60.3913, 5.3221, 30.0).unwrap();
+
+        // Os is about 24 km away, Trondheim about 430 km
+        assert!((bergen.distance_km(60.1869, 5.4680) - 24.1).abs() < 1.0);
+        assert!(bergen.contains(60.1869, 5.4680));
+        assert!(!bergen.contains(63.4305, 10.3951));
+
+        let (min_lat, max_lat, min_lng, max_lng) = bergen.bounding_box();
+        assert!(min_lat < 60.1869 && 60.1869 < max_lat);
+        assert!(min_lng < 5.4680 && 5.4680 < max_lng);
+
+        assert!(GeoRadius::new(91.0, 5.0, 10.0).is_err());
+        assert!(GeoRadius::new(60.0, 5.0, 0.0).is_err());
+        assert!(GeoRadius::new(60.0, 5.0, GeoRadius::MAX_RADIUS_KM + 1.0).is_err());
+    }
+
+    #[test]
+    fn test_event_coordinates_validation() {
+        let event_service = EventService::new();
+        let mut event = create_test_event();
+
+        event.latitude = Some(60.39);
+        assert!(event_service.validate_event(&event).is_err());
+
+        event.longitude = Some(5.32);
+        assert!(event_service.validate_event(&event).is_ok());
+
+        event.longitude = Some(200.0);
+        assert!(event_service.validate_event(&event).is_err());
+    }
+
     #[test]
     fn test_invitation_service_generate_token() {
         let service = InvitationService::new();
@@ -296,6 +976,96 @@ mod tests {
         assert!(!token2.is_empty());
     }
 
+    #[test]
+    fn test_invitation_service_tracks_opens_and_clicks() {
+        let service = InvitationService::new();
+        let now = Utc::now();
+        let mut invitation = EventInvitation {
+            id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            invited_user_id: None,
+            invited_contact_id: None,
+            invited_email: Some("kari@example.no".to_string()),
+            invited_name: None,
+            inviter_id: Uuid::new_v4(),
+            invitation_method: InvitationMethod::Email,
+            personal_message: None,
+            status: InvitationStatus::Pending,
+            sent_at: None,
+            opened_at: None,
+            clicked_at: None,
+            responded_at: None,
+            delivery_failure: None,
+            delivery_failed_at: None,
+            invitation_token: Some(service.generate_invitation_token()),
+            expires_at: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        service.mark_as_opened(&mut invitation);
+        assert_eq!(invitation.status, InvitationStatus::Opened);
+        assert!(invitation.sent_at.is_some());
+        let first_open = invitation.opened_at;
+        assert!(first_open.is_some());
+
+        service.mark_as_clicked(&mut invitation);
+        assert_eq!(invitation.opened_at, first_open);
+        assert!(invitation.clicked_at.is_some());
+
+        let mut declined = invitation.clone();
+        declined.status = InvitationStatus::Declined;
+        declined.opened_at = None;
+        service.mark_as_opened(&mut declined);
+        assert_eq!(declined.status, InvitationStatus::Declined);
+        assert!(declined.opened_at.is_some());
+
+        let mut cancelled = invitation.clone();
+        cancelled.status = InvitationStatus::Cancelled;
+        let funnel = service.funnel(&[invitation, declined, cancelled]);
+        assert_eq!(funnel.invited, 2);
+        assert_eq!(funnel.opened, 2);
+        assert_eq!(funnel.clicked, 2);
+        assert_eq!(funnel.declined, 1);
+        assert_eq!(funnel.accepted, 0);
+    }
+
+    #[test]
+    fn test_invitation_service_keeps_first_delivery_failure() {
+        let service = InvitationService::new();
+        let now = Utc::now();
+        let mut invitation = EventInvitation {
+            id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            invited_user_id: None,
+            invited_contact_id: None,
+            invited_email: Some("kari@example.no".to_string()),
+            invited_name: None,
+            inviter_id: Uuid::new_v4(),
+            invitation_method: InvitationMethod::Email,
+            personal_message: None,
+            status: InvitationStatus::Sent,
+            sent_at: Some(now),
+            opened_at: None,
+            clicked_at: None,
+            responded_at: None,
+            delivery_failure: None,
+            delivery_failed_at: None,
+            invitation_token: Some(service.generate_invitation_token()),
+            expires_at: None,
+            created_at: now,
+            updated_at: now,
+        };
+        assert!(service.check_deliverable(&invitation).is_ok());
+
+        assert!(service.mark_as_undeliverable(&mut invitation, DeliveryFailure::Bounce, now));
+        assert!(!service.mark_as_undeliverable(&mut invitation, DeliveryFailure::Complaint, now));
+        assert_eq!(invitation.delivery_failure, Some(DeliveryFailure::Bounce));
+        assert_eq!(invitation.delivery_failed_at, Some(now));
+        assert_eq!(invitation.status, InvitationStatus::Sent);
+        assert!(service.check_deliverable(&invitation).is_err());
+    }
+
     #[test]
     fn test_registration_service_calculate_waitlist_position() {
         let service = RegistrationService::new();
@@ -303,4 +1073,611 @@ mod tests {
         assert_eq!(service.calculate_waitlist_position(0), 1);
         assert_eq!(service.calculate_waitlist_position(5), 6);
     }
-}
\ No newline at end of file
+
+    fn create_waitlisted_registration() -> EventRegistration {
+        EventRegistration {
+            id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            invitation_id: None,
+            user_id: Some(Uuid::new_v4()),
+            external_contact_id: None,
+            registrant_email: None,
+            registrant_name: None,
+            registrant_phone: None,
+            registrant_company: None,
+            status: RegistrationStatus::Waitlisted,
+            registration_source: RegistrationSource::Direct,
+            guest_count: 0,
+            guest_names: vec![],
+            dietary_restrictions: None,
+            accessibility_needs: None,
+            special_requests: None,
+            custom_responses: None,
+            registered_at: Utc::now(),
+            cancelled_at: None,
+            checked_in_at: None,
+            waitlist_position: Some(1),
+            waitlist_added_at: Some(Utc::now()),
+            promotion_expires_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_registration_service_promotion_must_be_confirmed_before_deadline() {
+        let service = RegistrationService::new();
+        let now = Utc::now();
+        let mut registration = create_waitlisted_registration();
+
+        assert!(service.confirm_promotion(&mut registration, now).is_err());
+
+        service
+            .promote_from_waitlist(&mut registration, now + chrono::Duration::hours(24))
+            .unwrap();
+        assert_eq!(registration.status, RegistrationStatus::Registered);
+        assert_eq!(registration.waitlist_position, None);
+        assert!(!service.promotion_expired(&registration, now));
+        assert!(service.promotion_expired(&registration, now + chrono::Duration::hours(25)));
+
+        let mut late = registration.clone();
+        assert!(service
+            .confirm_promotion(&mut late, now + chrono::Duration::hours(25))
+            .is_err());
+
+        service.confirm_promotion(&mut registration, now).unwrap();
+        assert_eq!(registration.promotion_expires_at, None);
+        assert!(!service.promotion_expired(&registration, now + chrono::Duration::hours(25)));
+    }
+
+    #[test]
+    fn test_registration_service_merge_duplicates() {
+        let service = RegistrationService::new();
+        let now = Utc::now();
+
+        let mut kept = create_waitlisted_registration();
+        kept.guest_count = 1;
+        kept.guest_names = vec!["Kari Nordmann".to_string()];
+        kept.dietary_restrictions = Some("Vegetarian".to_string());
+
+        let mut duplicate = create_waitlisted_registration();
+        duplicate.event_id = kept.event_id;
+        duplicate.user_id = None;
+        duplicate.invitation_id = Some(Uuid::new_v4());
+        duplicate.registration_source = RegistrationSource::Invitation;
+        duplicate.status = RegistrationStatus::Registered;
+        duplicate.waitlist_position = None;
+        duplicate.waitlist_added_at = None;
+        duplicate.guest_count = 2;
+        duplicate.guest_names = vec!["kari nordmann".to_string(), "Ola Nordmann".to_string()];
+        duplicate.dietary_restrictions = Some("Nut allergy".to_string());
+        duplicate.accessibility_needs = Some("Step-free access".to_string());
+
+        service.merge_duplicates(&mut kept, &[duplicate.clone()], now).unwrap();
+        assert_eq!(kept.invitation_id, duplicate.invitation_id);
+        assert_eq!(kept.status, RegistrationStatus::Registered);
+        assert_eq!(kept.waitlist_position, None);
+        assert_eq!(kept.guest_names.len(), 2);
+        assert_eq!(kept.guest_count, 2);
+        assert_eq!(kept.dietary_restrictions.as_deref(), Some("Vegetarian; Nut allergy"));
+        assert_eq!(kept.accessibility_needs.as_deref(), Some("Step-free access"));
+
+        let mut other_event = duplicate.clone();
+        other_event.event_id = Uuid::new_v4();
+        assert!(service.merge_duplicates(&mut kept, &[other_event], now).is_err());
+
+        let mut other_person = duplicate;
+        other_person.user_id = Some(Uuid::new_v4());
+        assert!(service.merge_duplicates(&mut kept, &[other_person], now).is_err());
+    }
+
+    #[test]
+    fn test_sending_domain_service_verifies_published_records() {
+        let service = SendingDomainService::new();
+        let platform = MailPlatform {
+            spf_include: "spf.aqio.no".to_string(),
+            dkim_host: "dkim.aqio.no".to_string(),
+        };
+        let mut sending_domain = SendingDomain::new(
+            Uuid::new_v4(),
+            "lakseforum.no",
+            "invitasjon@lakseforum.no",
+            None,
+            SendingDomain::DEFAULT_DKIM_SELECTOR,
+        );
+        assert!(service.validate(&sending_domain).is_ok());
+
+        let mut elsewhere = sending_domain.clone();
+        elsewhere.from_email = "invitasjon@gmail.com".to_string();
+        assert!(service.validate(&elsewhere).is_err());
+        elsewhere.domain = "-bad-.no".to_string();
+        assert!(service.validate(&elsewhere).is_err());
+
+        let mut lookup = SendingDomainLookup {
+            verification_txt: vec![sending_domain.verification_record_value()],
+            domain_txt: vec!["v=spf1 include:_spf.google.com include:spf.aqio.no ~all".to_string()],
+            dkim_cname: vec![],
+        };
+        let now = Utc::now();
+        service.apply_lookup(&mut sending_domain, &platform, &lookup, now);
+        assert_eq!(sending_domain.status, DomainVerificationStatus::Failed);
+        assert!(sending_domain.ownership_verified && sending_domain.spf_verified);
+        assert!(!sending_domain.dkim_verified);
+
+        lookup.dkim_cname = vec!["aqio._domainkey.dkim.aqio.no.".to_string()];
+        service.apply_lookup(&mut sending_domain, &platform, &lookup, now);
+        assert!(sending_domain.is_verified());
+        assert_eq!(sending_domain.verified_at, Some(now));
+        assert!(sending_domain.required_records(&platform).iter().all(|record| record.verified));
+    }
+
+    #[test]
+    fn test_legacy_event_type_maps_to_seeded_category() {
+        use crate::domain::EventCategory;
+
+        assert_eq!(EventCategory::id_for_legacy_type("Conference"), Some("conf"));
+        assert_eq!(EventCategory::id_for_legacy_type(" workshop "), Some("workshop"));
+        assert_eq!(EventCategory::id_for_legacy_type("NETWORKING"), Some("networking"));
+        assert_eq!(EventCategory::id_for_legacy_type("training"), Some("training"));
+        assert_eq!(EventCategory::id_for_legacy_type("other"), None);
+        assert_eq!(EventCategory::id_for_legacy_type("Fish farm visit"), None);
+    }
+
+    #[test]
+    fn test_budget_summary_totals_lines_and_unassigned_expenses() {
+        use crate::domain::{BudgetLine, BudgetSummary, EventExpense};
+
+        let event_id = Uuid::new_v4();
+        let user = create_test_user();
+        let on = chrono::NaiveDate::from_ymd_opt(2026, 3, 12).unwrap();
+        let venue = BudgetLine::new(event_id, "Venue", 5_000_000);
+        let catering = BudgetLine::new(event_id, "Catering", 2_000_000);
+
+        let mut deposit = EventExpense::new(event_id, &user, "Venue deposit", 3_000_000, on);
+        deposit.budget_line_id = Some(venue.id);
+        let mut lunch = EventExpense::new(event_id, &user, "Lunch", 2_250_000, on);
+        lunch.budget_line_id = Some(catering.id);
+        let taxi = EventExpense::new(event_id, &user, "Taxi", 45_000, on);
+
+        let summary = BudgetSummary::new(&[venue.clone(), catering.clone()], &[deposit, lunch, taxi]);
+        assert_eq!(summary.planned, 7_000_000);
+        assert_eq!(summary.spent, 5_295_000);
+        assert_eq!(summary.unassigned, 45_000);
+        assert_eq!(summary.expense_count, 3);
+        assert_eq!(summary.remaining(), 1_705_000);
+        assert!(!summary.is_over_budget());
+
+        assert_eq!(summary.lines[0].budget_line_id, venue.id);
+        assert_eq!(summary.lines[0].remaining(), 2_000_000);
+        assert_eq!(summary.lines[1].spent, 2_250_000);
+        assert_eq!(summary.lines[1].remaining(), -250_000);
+
+        let empty = BudgetSummary::new(&[], &[]);
+        assert_eq!((empty.planned, empty.spent, empty.lines.len()), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_sponsors_sort_by_tier_then_display_order() {
+        let event_id = Uuid::new_v4();
+        let sponsor = |name: &str, tier: SponsorTier, display_order: i32| {
+            let mut sponsor = EventSponsor::new(event_id, name, tier);
+            sponsor.display_order = display_order;
+            sponsor
+        };
+        let mut sponsors = vec![
+            sponsor("Fjord Media", SponsorTier::Partner, 0),
+            sponsor("Salmar", SponsorTier::Gold, 2),
+            sponsor("akva group", SponsorTier::Gold, 1),
+            sponsor("Mowi", SponsorTier::Platinum, 5),
+            sponsor("Benchmark", SponsorTier::Gold, 1),
+        ];
+
+        EventSponsor::sort_for_display(&mut sponsors);
+        let names: Vec<&str> = sponsors.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Mowi", "akva group", "Benchmark", "Salmar", "Fjord Media"]);
+    }
+
+    #[test]
+    fn test_scheduling_checks_blackouts_and_policy() {
+        let company_id = Uuid::new_v4();
+        let day = |d: u32| NaiveDate::from_ymd_opt(2026, 5, d).unwrap();
+        // Friday 15 May to Saturday 16 May
+        let start = Utc.with_ymd_and_hms(2026, 5, 15, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 5, 16, 15, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 5, 10, 12, 0, 0).unwrap();
+        let blackouts = vec![
+            BlackoutPeriod::new(company_id, "Constitution Day", day(17), day(17), RuleEnforcement::Block, Uuid::new_v4()),
+            BlackoutPeriod::new(company_id, "Nor-Fishing", day(12), day(15), RuleEnforcement::Warn, Uuid::new_v4()),
+        ];
+        let service = SchedulingService::new();
+
+        // Without a policy only the overlapping blackout counts
+        let conflicts = service.check(start, end, now, None, &blackouts, 5);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].rule, ScheduleRule::Blackout);
+        assert_eq!(conflicts[0].message, "The event overlaps Nor-Fishing (2026-05-12 to 2026-05-15)");
+        assert!(!conflicts[0].is_blocking());
+
+        let mut policy = SchedulingPolicy::new(company_id);
+        policy.max_events_per_day = Some(2);
+        policy.allow_weekends = false;
+        policy.min_notice_days = Some(7);
+        policy.enforcement = RuleEnforcement::Block;
+
+        let rules: Vec<ScheduleRule> = service
+            .check(start, end, now, Some(&policy), &blackouts, 2)
+            .iter()
+            .map(|conflict| conflict.rule)
+            .collect();
+        assert_eq!(
+            rules,
+            vec![ScheduleRule::Blackout, ScheduleRule::DailyCapacity, ScheduleRule::Weekend, ScheduleRule::MinimumNotice]
+        );
+
+        // A weekday two weeks out with room left on the day is fine
+        let start = Utc.with_ymd_and_hms(2026, 5, 26, 9, 0, 0).unwrap();
+        let conflicts = service.check(start, start + chrono::Duration::hours(3), now, Some(&policy), &blackouts, 1);
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_digest_is_due_and_groups_per_event() {
+        let service = NotificationDigestService::new();
+        let now = Utc.with_ymd_and_hms(2026, 5, 10, 7, 0, 0).unwrap();
+        let mut preferences = NotificationPreferences::new(Uuid::new_v4());
+
+        assert!(!service.is_due(&preferences, now));
+        preferences.digest_frequency = DigestFrequency::Daily;
+        assert!(service.is_due(&preferences, now));
+        preferences.last_digest_at = Some(now - chrono::Duration::hours(23));
+        assert!(!service.is_due(&preferences, now));
+        preferences.last_digest_at = Some(now - chrono::Duration::hours(24));
+        assert!(service.is_due(&preferences, now));
+        preferences.digest_frequency = DigestFrequency::Weekly;
+        assert!(!service.is_due(&preferences, now));
+
+        assert!(service.compile(&preferences, Vec::new()).is_none());
+
+        let seminar = Uuid::new_v4();
+        let summit = Uuid::new_v4();
+        let item = |event_id: Uuid, title: &str, message: &str, minutes: i64| {
+            let mut item = DigestItem::new(preferences.user_id, Some(event_id), Some(title.to_string()), "waitlist_moved", message);
+            item.created_at = now + chrono::Duration::minutes(minutes);
+            item
+        };
+        let items = vec![
+            item(summit, "Salmon Summit", "Summit published", 5),
+            item(seminar, "Lice Seminar", "Moved up the waitlist", 1),
+            item(seminar, "Sea Lice Seminar", "Waitlist offer expired", 9),
+        ];
+
+        let digest = service.compile(&preferences, items).unwrap();
+        assert_eq!(digest.frequency, DigestFrequency::Weekly);
+        assert_eq!(digest.item_count(), 3);
+        assert_eq!(digest.groups.iter().map(|g| g.event_id).collect::<Vec<_>>(), vec![Some(seminar), Some(summit)]);
+        assert_eq!(digest.groups[0].event_title.as_deref(), Some("Sea Lice Seminar"));
+        assert_eq!(
+            digest.groups[0].items.iter().map(|i| i.message.as_str()).collect::<Vec<_>>(),
+            vec!["Moved up the waitlist", "Waitlist offer expired"]
+        );
+    }
+
+    #[test]
+    fn test_user_locale_and_timezone() {
+        assert_eq!(crate::domain::Locale::from_tag("nb-NO"), Some(crate::domain::Locale::Nb));
+        assert_eq!(crate::domain::Locale::from_tag("nn"), Some(crate::domain::Locale::Nb));
+        assert_eq!(crate::domain::Locale::from_tag("en_GB"), Some(crate::domain::Locale::En));
+        assert_eq!(crate::domain::Locale::from_tag("sv-SE"), None);
+
+        let service = UserService::new();
+        let mut user = create_test_user();
+        user.timezone = Some("Europe/Oslo".to_string());
+        assert!(service.validate_user(&user).is_ok());
+        user.timezone = Some("Norway/Bergen".to_string());
+        assert!(service.validate_user(&user).is_err());
+    }
+
+    #[test]
+    fn test_organization_invitation_is_open_until_used_or_expired() {
+        let company_id = Uuid::new_v4();
+        let mut invitation =
+            crate::domain::OrganizationInvitation::new(company_id, " Kari@Example.NO ", crate::domain::UserRole::Participant, None);
+        let now = Utc::now();
+        assert_eq!(invitation.email, "kari@example.no");
+        assert!(invitation.is_open(now));
+        assert!(!invitation.is_open(invitation.expires_at));
+
+        let user_id = Uuid::new_v4();
+        invitation.accept(user_id, now);
+        assert!(!invitation.is_open(now));
+        assert_eq!(invitation.accepted_by, Some(user_id));
+    }
+
+    #[test]
+    fn test_permission_checker_scopes_organization_permissions() {
+        let company = Uuid::new_v4();
+        let other_company = Uuid::new_v4();
+        let mut organizer = create_test_user();
+        organizer.company_id = Some(company);
+
+        let checker = PermissionChecker::for_user(&organizer, &[]);
+        assert!(checker.has(Permission::RegistrationCheckIn));
+        assert!(!checker.has(Permission::OrganizationManage));
+        assert!(checker.has_in(Permission::OrganizationManage, Some(company)));
+        assert!(!checker.has_in(Permission::OrganizationManage, Some(other_company)));
+        assert!(!checker.has(Permission::AuditView));
+        // Organizers manage their own events, which the services check, not everyone's
+        assert!(!checker.has_any(Permission::EventManage));
+
+        let admin = checker.with_roles(&[UserRole::Admin]);
+        assert!(admin.has(Permission::OrganizationManage));
+        assert!(admin.has(Permission::EventManage));
+        assert!(admin.has(Permission::AuditView));
+
+        // Without an organization, organizers manage none
+        organizer.company_id = None;
+        let checker = PermissionChecker::for_user(&organizer, &[]);
+        assert!(!checker.has_any(Permission::OrganizationManage));
+        assert!(PermissionChecker::for_roles(&[UserRole::Organizer]).organization_permissions().is_none());
+    }
+
+    #[test]
+    fn test_permission_checker_applies_custom_roles_in_their_organization() {
+        let company = Uuid::new_v4();
+        let mut participant = create_test_user();
+        participant.role = UserRole::Participant;
+        participant.company_id = Some(company);
+
+        let door_staff = OrganizationRole::new(
+            company,
+            " Door staff ",
+            None,
+            [Permission::RegistrationCheckIn, Permission::AuditView].into_iter().collect(),
+        );
+        let elsewhere = OrganizationRole::new(
+            Uuid::new_v4(),
+            "Approvers",
+            None,
+            [Permission::RegistrationApprove].into_iter().collect(),
+        );
+        assert_eq!(door_staff.name, "Door staff");
+
+        let checker = PermissionChecker::for_user(&participant, &[door_staff, elsewhere]);
+        assert!(!checker.has(Permission::RegistrationCheckIn));
+        assert!(checker.has_in(Permission::RegistrationCheckIn, Some(company)));
+        assert!(!checker.has_in(Permission::RegistrationCheckIn, Some(Uuid::new_v4())));
+        // Platform permissions can't be handed out by an organization
+        assert!(!checker.has_any(Permission::AuditView));
+        assert!(!checker.has_any(Permission::RegistrationApprove));
+
+        let (organization, permissions) = checker.organization_permissions().unwrap();
+        assert_eq!(organization, company);
+        assert_eq!(permissions.iter().collect::<Vec<_>>(), vec![Permission::RegistrationCheckIn]);
+    }
+
+    #[test]
+    fn test_permission_checker_honours_access_grants_while_they_last() {
+        let event_day = Utc::now() + Duration::days(7);
+        let event_id = Uuid::new_v4();
+        let mut participant = create_test_user();
+        participant.role = UserRole::Participant;
+
+        let grant = AccessGrant::new(
+            event_id,
+            participant.id,
+            Uuid::new_v4(),
+            [Permission::RegistrationCheckIn, Permission::UserManage].into_iter().collect(),
+            event_day,
+            event_day + Duration::hours(12),
+        );
+        let at = |now| PermissionChecker::for_user(&participant, &[]).with_grants(std::slice::from_ref(&grant), now);
+
+        assert!(!at(event_day - Duration::minutes(1)).has_for_event(Permission::RegistrationCheckIn, event_id));
+        let during = at(event_day + Duration::hours(1));
+        assert!(during.has_for_event(Permission::RegistrationCheckIn, event_id));
+        assert!(!during.has_for_event(Permission::RegistrationCheckIn, Uuid::new_v4()));
+        assert!(!during.has(Permission::RegistrationCheckIn));
+        // Only event-level permissions can be delegated
+        assert!(!during.has_for_event(Permission::UserManage, event_id));
+        assert_eq!(during.event_permissions().count(), 1);
+        assert!(!at(event_day + Duration::hours(12)).has_for_event(Permission::RegistrationCheckIn, event_id));
+
+        let mut revoked = grant.clone();
+        revoked.revoked_at = Some(event_day);
+        assert!(!revoked.is_active_at(event_day + Duration::hours(1)));
+        assert!(revoked.has_ended(event_day - Duration::days(1)));
+        assert!(!grant.has_ended(event_day));
+    }
+
+    #[test]
+    fn test_permission_codes_round_trip() {
+        for permission in Permission::ALL {
+            assert_eq!(Permission::from_code(permission.code()), Some(permission));
+        }
+        assert_eq!(Permission::from_code("event:delete"), None);
+
+        let set: PermissionSet = [Permission::RegistrationCheckIn, Permission::EventCreate, Permission::EventCreate]
+            .into_iter()
+            .collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![Permission::EventCreate, Permission::RegistrationCheckIn]);
+        assert!(UserRole::Participant.permissions().is_empty());
+        assert_eq!(UserRole::Admin.permissions().iter().count(), Permission::ALL.len());
+    }
+
+    #[test]
+    fn test_cron_schedule_follows_wall_clock_time() {
+        use crate::domain::CronSchedule;
+        let at = |text: &str| text.parse::<DateTime<Utc>>().unwrap();
+        let oslo: chrono_tz::Tz = "Europe/Oslo".parse().unwrap();
+
+        for invalid in ["0 2 * *", "61 * * * *", "0 0 30 2 *", "*/0 * * * *", "5-1 * * * *"] {
+            assert!(CronSchedule::parse(invalid).is_err(), "{}", invalid);
+        }
+        assert_eq!(CronSchedule::parse("@weekly").unwrap(), CronSchedule::parse("0 0 * * 7").unwrap());
+
+        let nightly = CronSchedule::parse("0 2 * * *").unwrap();
+        assert_eq!(nightly.next_after(at("2026-01-10T12:00:00Z"), oslo), Some(at("2026-01-11T01:00:00Z")));
+        assert_eq!(nightly.next_after(at("2026-06-01T00:00:00Z"), oslo), Some(at("2026-06-02T00:00:00Z")));
+        // 02:00 is skipped when clocks go forward on March 29th
+        assert_eq!(nightly.next_after(at("2026-03-28T12:00:00Z"), oslo), Some(at("2026-03-29T01:00:00Z")));
+
+        // 02:30 comes twice when clocks go back on October 25th; run once
+        let half_past = CronSchedule::parse("30 2 * * *").unwrap();
+        let first = half_past.next_after(at("2026-10-24T12:00:00Z"), oslo).unwrap();
+        assert_eq!(first, at("2026-10-25T00:30:00Z"));
+        assert_eq!(half_past.next_after(first, oslo), Some(at("2026-10-26T01:30:00Z")));
+
+        let office_hours = CronSchedule::parse("*/15 9-17 * * 1-5").unwrap();
+        assert_eq!(office_hours.next_after(at("2026-10-16T17:50:00Z"), chrono_tz::UTC), Some(at("2026-10-19T09:00:00Z")));
+        assert_eq!(office_hours.next_after(at("2026-10-19T09:00:00Z"), chrono_tz::UTC), Some(at("2026-10-19T09:15:00Z")));
+
+        // With both day fields restricted, either one matching is enough
+        let first_or_monday = CronSchedule::parse("0 0 1 * 1").unwrap();
+        assert_eq!(first_or_monday.next_after(at("2026-10-02T00:00:00Z"), chrono_tz::UTC), Some(at("2026-10-05T00:00:00Z")));
+    }
+
+    #[test]
+    fn test_export_job_records_runs_and_schedules_the_next() {
+        use crate::domain::{ExportDestination, ExportEntity, ExportFilter, ExportFormat, ExportJob, ExportRunStatus};
+        let now: DateTime<Utc> = "2026-01-10T12:00:00Z".parse().unwrap();
+        let destination = ExportDestination::S3 {
+            bucket: "crm-drop".to_string(),
+            region: "eu-north-1".to_string(),
+            prefix: "aqio/".to_string(),
+            endpoint: None,
+        };
+        let job = |schedule: &str, timezone: &str| {
+            ExportJob::new(
+                "Nightly CRM",
+                ExportEntity::Registrations,
+                ExportFilter::default(),
+                ExportFormat::Csv,
+                destination.clone(),
+                schedule,
+                timezone,
+                Uuid::new_v4(),
+                now,
+            )
+        };
+        assert!(job("every night", "UTC").is_err());
+        assert!(job("0 2 * * *", "Europe/Nowhere").is_err());
+
+        let mut job = job("0 2 * * *", "Europe/Oslo").unwrap();
+        assert_eq!(job.next_run_at, Some("2026-01-11T01:00:00Z".parse().unwrap()));
+        assert!(job.file_name(now).ends_with("-20260110T120000Z.csv"));
+        assert!(job.file_name(now).starts_with("nightly-crm-"));
+
+        let run = job.next_run_at.unwrap();
+        job.record_run(run, Ok(12)).unwrap();
+        assert_eq!(job.last_status, Some(ExportRunStatus::Succeeded));
+        assert_eq!((job.last_row_count, job.last_success_at), (Some(12), Some(run)));
+        assert_eq!(job.next_run_at, Some("2026-01-12T01:00:00Z".parse().unwrap()));
+
+        let failed_run = job.next_run_at.unwrap();
+        job.record_run(failed_run, Err("Connection refused".to_string())).unwrap();
+        assert_eq!(job.last_status, Some(ExportRunStatus::Failed));
+        assert_eq!(job.last_success_at, Some(run));
+        assert_eq!(job.last_run_at, Some(failed_run));
+
+        job.is_active = false;
+        assert_eq!(job.next_run_after(now).unwrap(), None);
+    }
+
+    #[test]
+    fn test_crm_conflict_policies_treat_crm_edits_differently() {
+        use crate::domain::CrmConflictPolicy;
+        use std::collections::BTreeMap;
+        let values = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+            pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+        };
+        // Aqio wrote both names last time; since then someone fixed the last
+        // name in the CRM and the registrant changed their phone number in Aqio
+        let last_synced = values(&[("firstname", "Kari"), ("lastname", "Nordman"), ("phone", "")]);
+        let remote = values(&[("firstname", "Kari"), ("lastname", "Nordmann"), ("company", "Mowi")]);
+        let desired = values(&[("firstname", "Kari"), ("lastname", "Nordman"), ("phone", "912 34 567"), ("company", "Lerøy")]);
+
+        let aqio = CrmConflictPolicy::AqioWins.resolve(&desired, &last_synced, &remote);
+        assert_eq!(aqio.changes, values(&[("lastname", "Nordman"), ("phone", "912 34 567"), ("company", "Lerøy")]));
+        assert!(aqio.conflicts.is_empty());
+
+        let crm = CrmConflictPolicy::CrmWins.resolve(&desired, &last_synced, &remote);
+        assert_eq!(crm.changes, values(&[("phone", "912 34 567")]));
+        assert_eq!(crm.conflicts, vec!["company", "lastname"]);
+
+        let blanks = CrmConflictPolicy::FillBlanks.resolve(&desired, &last_synced, &remote);
+        assert_eq!(blanks.changes, values(&[("phone", "912 34 567")]));
+        assert!(blanks.conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_crm_connector_validates_mappings_and_schedules_syncs() {
+        use crate::domain::{CrmConnector, CrmFieldMapping, CrmProvider, CrmRecordKind, CrmSourceField, CrmSyncRunStatus, CrmSyncSummary};
+        let now: DateTime<Utc> = "2026-01-10T12:00:00Z".parse().unwrap();
+        let mut connector = CrmConnector::new("Sjømatrådet HubSpot", CrmProvider::HubSpot, Uuid::new_v4(), now);
+        assert!(connector.validate().is_ok());
+        assert_eq!(connector.next_sync_at, Some(now));
+
+        let mapping = |record, source, property: &str| CrmFieldMapping { record, source, property: property.to_string() };
+        connector.field_mappings.push(mapping(CrmRecordKind::Contact, CrmSourceField::EventTitle, "last_event"));
+        assert!(connector.validate().is_err());
+        connector.field_mappings.pop();
+        connector.field_mappings.push(mapping(CrmRecordKind::Contact, CrmSourceField::Email, "EMAIL"));
+        assert!(connector.validate().is_err());
+        connector.field_mappings.pop();
+        connector.field_mappings.push(mapping(CrmRecordKind::Registration, CrmSourceField::EventTitle, "event_name"));
+        assert!(connector.validate().is_err());
+        connector.registration_object = Some("2-4518833".to_string());
+        assert!(connector.validate().is_ok());
+
+        let mut dynamics = CrmConnector::new("Dynamics", CrmProvider::Dynamics, Uuid::new_v4(), now);
+        assert!(dynamics.validate().is_err());
+        dynamics.base_url = Some("https://sjomat.crm4.dynamics.com".to_string());
+        assert!(dynamics.validate().is_ok());
+        for url in ["https://dynamics.com", "https://sjomat.dynamics.com.example.org", "https://evil.example@sjomat.crm4.dynamics.com", "http://sjomat.crm4.dynamics.com"] {
+            dynamics.base_url = Some(url.to_string());
+            assert!(dynamics.validate().is_err(), "{}", url);
+        }
+
+        // The platform's HubSpot token only ever goes to HubSpot
+        connector.base_url = Some("https://crm.example.org".to_string());
+        assert!(connector.validate().is_err());
+        connector.base_url = Some("https://api.hubapi.com".to_string());
+        assert!(connector.validate().is_ok());
+        connector.base_url = None;
+
+        let partly = CrmSyncSummary { created: 3, failed: 1, ..Default::default() };
+        connector.record_sync(now, Ok(partly));
+        assert_eq!(connector.last_status, Some(CrmSyncRunStatus::PartiallyFailed));
+        assert_eq!(connector.sync_cursor, Some(now));
+        assert_eq!(connector.next_sync_at, Some(now + chrono::Duration::hours(1)));
+
+        let later = now + chrono::Duration::hours(1);
+        connector.record_sync(later, Err("HubSpot answered 401 Unauthorized".to_string()));
+        assert_eq!(connector.last_status, Some(CrmSyncRunStatus::Failed));
+        assert_eq!(connector.sync_cursor, Some(now));
+        connector.record_sync(later, Ok(CrmSyncSummary { failed: 2, ..Default::default() }));
+        assert_eq!(connector.last_status, Some(CrmSyncRunStatus::Failed));
+    }
+
+    #[test]
+    fn test_event_transfer_keeps_previous_owner_as_co_organizer() {
+        let mut event = create_test_event();
+        let previous = event.organizer_id;
+        let new_owner = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        event.co_organizers = vec![new_owner, other];
+
+        let now = Utc::now();
+        event.transfer_to(new_owner, now);
+        assert_eq!(event.organizer_id, new_owner);
+        assert_eq!(event.co_organizers, vec![other, previous]);
+        assert_eq!(event.updated_at, now);
+
+        let mut transfer = crate::domain::EventOwnershipTransfer::new(&event, previous, new_owner, None);
+        assert_eq!(transfer.from_user_id, new_owner);
+        assert!(transfer.is_pending());
+        transfer.respond(crate::domain::OwnershipTransferStatus::Declined, now);
+        assert!(!transfer.is_pending());
+        assert_eq!(transfer.responded_at, Some(now));
+    }
+}
@@ -0,0 +1,241 @@
+//! Event repository benchmarks
+//!
+//! Measures the query paths the API hits hardest so regressions in the
+//! repository layer (dynamic filter building, pagination, row mapping) show up
+//! before a release. Run with:
+//!
+//! ```bash
+//! cargo bench -p aqio-database --features test-utils
+//! ```
+
+use aqio_core::{EventFilter, EventRepository, EventStatus, LocationType, PaginationParams};
+use aqio_database::infrastructure::persistence::sqlite::SqliteEventRepository;
+use aqio_database::testing::TestDb;
+use chrono::{Duration, Utc};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use tokio::runtime::Runtime;
+use uuid::Uuid;
+
+const DATASET_SIZES: [i64; 2] = [10_000, 100_000];
+const PAGE_SIZE: i64 = 50;
+const MAPPING_BATCH: i64 = 1_000;
+
+const EVENT_COLUMNS: &str = "id, title, description, category_id, start_date, end_date, timezone, location_type, location_name, address, virtual_link, virtual_access_code, organizer_id, (SELECT json_group_array(user_id) FROM (SELECT user_id FROM event_co_organizers WHERE event_id = events.id ORDER BY position)) AS co_organizers, is_private, requires_approval, max_attendees, allow_guests, max_guests_per_person, registration_opens, registration_closes, registration_required, allow_waitlist, send_reminders, collect_dietary_info, collect_accessibility_info, image_url, custom_fields, status, created_at, updated_at";
+
+/// Build a database with `rows` events spread over categories, statuses,
+/// location types, visibility and a two-year date window
+async fn seeded_database(rows: i64) -> (TestDb, Uuid) {
+    let db = TestDb::in_memory().await;
+    let organizer_id = db.seed_user().await;
+
+    // Generate rows inside SQLite; inserting 100k rows through the repository
+    // would dominate the setup time without measuring anything useful.
+    sqlx::query(
+        r#"
+        WITH RECURSIVE seq(n) AS (
+            SELECT 0 UNION ALL SELECT n + 1 FROM seq WHERE n + 1 < ?1
+        ),
+        ids(n, hex) AS (
+            SELECT n, lower(hex(randomblob(16))) FROM seq
+        )
+        INSERT INTO events (
+            id, title, description, category_id, start_date, end_date,
+            location_type, location_name, organizer_id,
+            is_private, max_attendees, status
+        )
+        SELECT
+            substr(hex, 1, 8) || '-' || substr(hex, 9, 4) || '-' || substr(hex, 13, 4) || '-' || substr(hex, 17, 4) || '-' || substr(hex, 21, 12),
+            CASE n % 4
+                WHEN 0 THEN 'Salmon Health Seminar ' || n
+                WHEN 1 THEN 'Aquaculture Tech Workshop ' || n
+                WHEN 2 THEN 'Feed Innovation Meetup ' || n
+                ELSE 'Sustainability Forum ' || n
+            END,
+            'Benchmark event ' || n,
+            CASE n % 6
+                WHEN 0 THEN 'conf'
+                WHEN 1 THEN 'workshop'
+                WHEN 2 THEN 'networking'
+                WHEN 3 THEN 'training'
+                WHEN 4 THEN 'personal'
+                ELSE 'meeting'
+            END,
+            datetime('now', '-365 days', '+' || (n % 730) || ' days', '+' || (n % 24) || ' hours'),
+            datetime('now', '-365 days', '+' || (n % 730) || ' days', '+' || (n % 24 + 2) || ' hours'),
+            CASE n % 3 WHEN 0 THEN 'physical' WHEN 1 THEN 'virtual' ELSE 'hybrid' END,
+            'Venue ' || (n % 50),
+            ?2,
+            n % 5 = 0,
+            CASE WHEN n % 2 = 0 THEN 100 ELSE NULL END,
+            CASE n % 10 WHEN 0 THEN 'draft' WHEN 1 THEN 'cancelled' WHEN 2 THEN 'completed' ELSE 'published' END
+        FROM ids
+        "#,
+    )
+    .bind(rows)
+    .bind(organizer_id.to_string())
+    .execute(db.pool())
+    .await
+    .expect("failed to seed benchmark events");
+
+    sqlx::query("ANALYZE")
+        .execute(db.pool())
+        .await
+        .expect("failed to analyze benchmark database");
+
+    // Fail fast if the generated rows don't map cleanly, rather than
+    // benchmarking the error path
+    SqliteEventRepository::new(db.pool().clone())
+        .find_by_filter(&empty_filter(), page(0, MAPPING_BATCH))
+        .await
+        .expect("benchmark events should map to Event");
+
+    (db, organizer_id)
+}
+
+fn empty_filter() -> EventFilter {
+    EventFilter {
+        title_contains: None,
+        category_id: None,
+        organizer_id: None,
+        is_private: None,
+        status: None,
+        location_type: None,
+        start_date_from: None,
+        start_date_to: None,
+        near: None,
+        organizer_company_id: None,
+        co_organizer_id: None,
+        field: None,
+        county: None,
+        municipality: None,
+    }
+}
+
+fn page(offset: i64, limit: i64) -> PaginationParams {
+    PaginationParams::new(offset, limit).expect("valid pagination")
+}
+
+/// Listing pages of events at different table sizes and offsets
+fn bench_event_list(c: &mut Criterion) {
+    let rt = Runtime::new().expect("tokio runtime");
+    let mut group = c.benchmark_group("event_list");
+    group.sample_size(20);
+
+    for rows in DATASET_SIZES {
+        let (db, _) = rt.block_on(seeded_database(rows));
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let filter = empty_filter();
+
+        group.bench_with_input(BenchmarkId::new("list_all_first_page", rows), &rows, |b, _| {
+            b.to_async(&rt).iter(|| repository.list_all(page(0, PAGE_SIZE)));
+        });
+
+        group.bench_with_input(BenchmarkId::new("list_all_last_page", rows), &rows, |b, &rows| {
+            b.to_async(&rt).iter(|| repository.list_all(page(rows - PAGE_SIZE, PAGE_SIZE)));
+        });
+
+        group.bench_with_input(BenchmarkId::new("find_by_filter_unfiltered", rows), &rows, |b, _| {
+            b.to_async(&rt).iter(|| repository.find_by_filter(&filter, page(0, PAGE_SIZE)));
+        });
+    }
+
+    group.finish();
+}
+
+/// Filter combinations through the dynamically built `find_by_filter` query
+fn bench_event_filters(c: &mut Criterion) {
+    let rt = Runtime::new().expect("tokio runtime");
+    let mut group = c.benchmark_group("event_filter");
+    group.sample_size(20);
+
+    for rows in DATASET_SIZES {
+        let (db, organizer_id) = rt.block_on(seeded_database(rows));
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let now = Utc::now();
+
+        let filters = [
+            ("title", EventFilter {
+                title_contains: Some("Workshop".to_string()),
+                ..empty_filter()
+            }),
+            ("category", EventFilter {
+                category_id: Some("conf".to_string()),
+                ..empty_filter()
+            }),
+            ("status_private", EventFilter {
+                status: Some(EventStatus::Published),
+                is_private: Some(false),
+                ..empty_filter()
+            }),
+            ("date_range", EventFilter {
+                start_date_from: Some(now),
+                start_date_to: Some(now + Duration::days(30)),
+                ..empty_filter()
+            }),
+            ("organizer_location", EventFilter {
+                organizer_id: Some(organizer_id),
+                location_type: Some(LocationType::Hybrid),
+                ..empty_filter()
+            }),
+            ("combined", EventFilter {
+                title_contains: Some("Seminar".to_string()),
+                category_id: Some("conf".to_string()),
+                organizer_id: Some(organizer_id),
+                is_private: Some(false),
+                status: Some(EventStatus::Published),
+                location_type: Some(LocationType::Physical),
+                start_date_from: Some(now - Duration::days(180)),
+                start_date_to: Some(now + Duration::days(180)),
+                ..empty_filter()
+            }),
+        ];
+
+        for (name, filter) in &filters {
+            group.bench_with_input(BenchmarkId::new(*name, rows), &rows, |b, _| {
+                b.to_async(&rt).iter(|| repository.find_by_filter(filter, page(0, PAGE_SIZE)));
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Cost of converting rows into `Event`s
+///
+/// Compares fetching a batch of raw rows (plus the count query `find_by_filter`
+/// also runs) against the same batch mapped through the repository, so the
+/// difference is the `SafeRowGet` mapping overhead.
+fn bench_row_mapping(c: &mut Criterion) {
+    let rt = Runtime::new().expect("tokio runtime");
+    let mut group = c.benchmark_group("event_row_mapping");
+    group.sample_size(20);
+    group.throughput(Throughput::Elements(MAPPING_BATCH as u64));
+
+    let (db, _) = rt.block_on(seeded_database(DATASET_SIZES[0]));
+    let repository = SqliteEventRepository::new(db.pool().clone());
+    let filter = empty_filter();
+    let raw_query = format!(
+        "SELECT {} FROM events WHERE 1=1 ORDER BY start_date DESC LIMIT {} OFFSET 0",
+        EVENT_COLUMNS, MAPPING_BATCH
+    );
+
+    group.bench_function("raw_rows", |b| {
+        b.to_async(&rt).iter(|| async {
+            let rows = sqlx::query(&raw_query).fetch_all(db.pool()).await.unwrap();
+            let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events WHERE 1=1")
+                .fetch_one(db.pool())
+                .await
+                .unwrap();
+            (rows, count)
+        });
+    });
+
+    group.bench_function("mapped_events", |b| {
+        b.to_async(&rt).iter(|| repository.find_by_filter(&filter, page(0, MAPPING_BATCH)));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_event_list, bench_event_filters, bench_row_mapping);
+criterion_main!(benches);
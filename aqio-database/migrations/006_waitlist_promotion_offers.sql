@@ -0,0 +1,7 @@
+-- Waitlist promotion offers
+
+-- A promoted registration holds its spot until this deadline; it's cleared once
+-- the attendee confirms. NULL means there's no outstanding offer.
+ALTER TABLE event_registrations ADD COLUMN promotion_expires_at DATETIME;
+
+CREATE INDEX idx_registrations_promotion_expires_at ON event_registrations(promotion_expires_at);
@@ -0,0 +1,9 @@
+-- Public event pages
+
+-- Slugs are generated from the title when an event is created and never change,
+-- so shared links keep working. Existing events fall back to their id.
+ALTER TABLE events ADD COLUMN slug TEXT;
+
+UPDATE events SET slug = id WHERE slug IS NULL;
+
+CREATE UNIQUE INDEX idx_events_slug ON events(slug);
@@ -0,0 +1,7 @@
+-- Event coordinates for the map view and proximity search
+
+-- WGS84 degrees; both are NULL for virtual events and events not yet placed on the map
+ALTER TABLE events ADD COLUMN latitude REAL;
+ALTER TABLE events ADD COLUMN longitude REAL;
+
+CREATE INDEX idx_events_coordinates ON events(latitude, longitude);
@@ -0,0 +1,7 @@
+-- Soft delete for event comments, so replies to a removed comment keep their place in the thread
+
+-- deleted_by is the author or the moderator who removed the comment
+ALTER TABLE event_comments ADD COLUMN deleted_at DATETIME;
+ALTER TABLE event_comments ADD COLUMN deleted_by TEXT REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_event_comments_event_created ON event_comments(event_id, created_at);
@@ -0,0 +1,59 @@
+-- Live Q&A and polls, open to attendees while an event is running
+
+CREATE TABLE event_questions (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    author_id TEXT REFERENCES users(id) ON DELETE SET NULL,
+    author_name TEXT, -- Kept so the question stays attributed after the author is deleted
+    content TEXT NOT NULL,
+
+    -- Set by the organizer; answering again replaces the answer
+    answer TEXT,
+    answered_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    answered_at DATETIME,
+
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_event_questions_event_created ON event_questions(event_id, created_at);
+
+-- One upvote per attendee and question
+CREATE TABLE event_question_upvotes (
+    question_id TEXT NOT NULL REFERENCES event_questions(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (question_id, user_id)
+);
+
+CREATE TABLE event_polls (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    question TEXT NOT NULL,
+    created_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    closed_at DATETIME, -- NULL while the poll accepts votes
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_event_polls_event_created ON event_polls(event_id, created_at);
+
+CREATE TABLE event_poll_options (
+    id TEXT PRIMARY KEY,
+    poll_id TEXT NOT NULL REFERENCES event_polls(id) ON DELETE CASCADE,
+    label TEXT NOT NULL,
+    position INTEGER NOT NULL
+);
+
+CREATE INDEX idx_event_poll_options_poll ON event_poll_options(poll_id, position);
+
+-- Single choice: the primary key keeps one vote per attendee and poll
+CREATE TABLE event_poll_votes (
+    poll_id TEXT NOT NULL REFERENCES event_polls(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    option_id TEXT NOT NULL REFERENCES event_poll_options(id) ON DELETE CASCADE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (poll_id, user_id)
+);
+
+CREATE INDEX idx_event_poll_votes_option ON event_poll_votes(option_id);
@@ -0,0 +1,38 @@
+-- Event budgets: planned amounts per line and the expenses booked against them
+-- Amounts are integers in minor currency units (øre)
+
+CREATE TABLE event_budget_lines (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    planned_amount INTEGER NOT NULL CHECK(planned_amount >= 0),
+    notes TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_event_budget_lines_event ON event_budget_lines(event_id, created_at);
+
+CREATE TABLE event_expenses (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    budget_line_id TEXT REFERENCES event_budget_lines(id) ON DELETE SET NULL,
+    description TEXT NOT NULL,
+    vendor TEXT,
+    amount INTEGER NOT NULL CHECK(amount > 0),
+    incurred_on DATE NOT NULL,
+
+    -- Receipt file in media storage; all NULL when no receipt was uploaded
+    receipt_filename TEXT,
+    receipt_storage_key TEXT,
+    receipt_size INTEGER,
+    receipt_mime_type TEXT,
+
+    recorded_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    recorded_by_name TEXT, -- Kept so the ledger stays readable after the user is deleted
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_event_expenses_event ON event_expenses(event_id, incurred_on);
+CREATE INDEX idx_event_expenses_budget_line ON event_expenses(budget_line_id);
@@ -0,0 +1,16 @@
+-- Event sponsors, listed tier by tier on the public page and printed program
+
+CREATE TABLE event_sponsors (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    tier TEXT NOT NULL CHECK(tier IN ('platinum', 'gold', 'silver', 'bronze', 'partner')),
+    logo_url TEXT,
+    website_url TEXT,
+    description TEXT,
+    display_order INTEGER NOT NULL DEFAULT 0, -- Position within the tier, lowest first
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_event_sponsors_event ON event_sponsors(event_id, display_order);
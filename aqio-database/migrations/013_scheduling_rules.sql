@@ -0,0 +1,26 @@
+-- Organization-wide scheduling rules, checked when events are created or moved
+
+CREATE TABLE company_blackout_periods (
+    id TEXT PRIMARY KEY,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL, -- Last day, inclusive
+    enforcement TEXT NOT NULL CHECK(enforcement IN ('warn', 'block')),
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK(end_date >= start_date)
+);
+
+CREATE INDEX idx_company_blackout_periods_company ON company_blackout_periods(company_id, start_date);
+
+-- At most one policy per company; companies without one have no limits
+CREATE TABLE company_scheduling_policies (
+    company_id TEXT PRIMARY KEY REFERENCES companies(id) ON DELETE CASCADE,
+    max_events_per_day INTEGER CHECK(max_events_per_day IS NULL OR max_events_per_day > 0),
+    allow_weekends BOOLEAN NOT NULL DEFAULT TRUE,
+    min_notice_days INTEGER CHECK(min_notice_days IS NULL OR min_notice_days >= 0),
+    enforcement TEXT NOT NULL CHECK(enforcement IN ('warn', 'block')),
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
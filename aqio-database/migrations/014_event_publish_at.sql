@@ -0,0 +1,6 @@
+-- Scheduled publishing: drafts with a publish time are published by the API's scheduler
+
+-- NULL leaves publishing to the organizer
+ALTER TABLE events ADD COLUMN publish_at DATETIME;
+
+CREATE INDEX idx_events_publish_at ON events(status, publish_at);
@@ -0,0 +1,5 @@
+-- Invitation tracking: opens come from the email's tracking pixel, clicks from
+-- its signed redirect links
+
+-- First click on a tracked link; NULL until one is followed
+ALTER TABLE event_invitations ADD COLUMN clicked_at DATETIME;
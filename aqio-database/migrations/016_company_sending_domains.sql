@@ -0,0 +1,19 @@
+-- Per-organization sending domains, so invitations can come from the organization's own domain
+
+-- At most one per company; used for sending only while status is 'verified'
+CREATE TABLE company_sending_domains (
+    company_id TEXT PRIMARY KEY REFERENCES companies(id) ON DELETE CASCADE,
+    domain TEXT NOT NULL,
+    from_email TEXT NOT NULL,
+    from_name TEXT,
+    dkim_selector TEXT NOT NULL,
+    verification_token TEXT NOT NULL,
+    status TEXT NOT NULL CHECK(status IN ('pending', 'verified', 'failed')) DEFAULT 'pending',
+    ownership_verified BOOLEAN NOT NULL DEFAULT FALSE,
+    spf_verified BOOLEAN NOT NULL DEFAULT FALSE,
+    dkim_verified BOOLEAN NOT NULL DEFAULT FALSE,
+    last_checked_at DATETIME,
+    verified_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
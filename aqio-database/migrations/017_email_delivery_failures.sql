@@ -0,0 +1,15 @@
+-- Bounce and complaint handling: the mail provider's webhooks mark invitation
+-- emails undeliverable and suppress the address for later sends
+
+-- 'bounce' or 'complaint'; NULL while the email is deliverable
+ALTER TABLE event_invitations ADD COLUMN delivery_failure TEXT CHECK(delivery_failure IN ('bounce', 'complaint'));
+ALTER TABLE event_invitations ADD COLUMN delivery_failed_at DATETIME;
+
+-- Addresses no email is sent to; stored lowercased
+CREATE TABLE email_suppressions (
+    email TEXT PRIMARY KEY,
+    reason TEXT NOT NULL CHECK(reason IN ('bounce', 'complaint')),
+    provider TEXT NOT NULL,
+    detail TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
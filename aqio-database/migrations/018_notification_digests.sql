@@ -0,0 +1,19 @@
+-- Notification digests: users can have non-urgent notifications batched into
+-- a daily or weekly email instead of getting them one at a time
+
+-- 'immediate' means no digest
+ALTER TABLE user_notification_preferences ADD COLUMN digest_frequency TEXT NOT NULL DEFAULT 'immediate' CHECK(digest_frequency IN ('immediate', 'daily', 'weekly'));
+ALTER TABLE user_notification_preferences ADD COLUMN last_digest_at DATETIME;
+
+-- Notifications waiting for the user's next digest; deleted once sent
+CREATE TABLE notification_digest_items (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    event_id TEXT REFERENCES events(id) ON DELETE CASCADE,
+    event_title TEXT,
+    kind TEXT NOT NULL,
+    message TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_notification_digest_items_user ON notification_digest_items(user_id, created_at);
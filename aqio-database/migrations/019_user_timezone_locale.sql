@@ -0,0 +1,5 @@
+-- Per-user time zone and language for emails, calendar files and digests.
+-- NULL falls back to the browser's language and the platform time zone.
+
+ALTER TABLE users ADD COLUMN timezone TEXT;
+ALTER TABLE users ADD COLUMN locale TEXT CHECK(locale IN ('en', 'nb'));
@@ -0,0 +1,20 @@
+-- Invitations to join a company: colleagues get an emailed link and join the
+-- company with the invitation's role when they accept it
+
+CREATE TABLE organization_invitations (
+    id TEXT PRIMARY KEY,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    -- Stored lowercased
+    email TEXT NOT NULL,
+    role TEXT NOT NULL CHECK(role IN ('admin', 'organizer', 'participant')),
+    token TEXT NOT NULL UNIQUE,
+    invited_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'accepted', 'revoked')),
+    expires_at DATETIME NOT NULL,
+    accepted_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    accepted_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_organization_invitations_status ON organization_invitations(status, company_id);
+CREATE INDEX idx_organization_invitations_email ON organization_invitations(email);
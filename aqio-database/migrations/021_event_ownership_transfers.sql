@@ -0,0 +1,18 @@
+-- Requests to hand an event to another organizer; the event only changes
+-- hands when the new owner accepts
+
+CREATE TABLE event_ownership_transfers (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    from_user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    to_user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    requested_by TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    message TEXT,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'accepted', 'declined', 'cancelled')),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    responded_at DATETIME
+);
+
+-- At most one open request per event
+CREATE UNIQUE INDEX idx_event_ownership_transfers_pending ON event_ownership_transfers(event_id) WHERE status = 'pending';
+CREATE INDEX idx_event_ownership_transfers_to_user ON event_ownership_transfers(to_user_id, status);
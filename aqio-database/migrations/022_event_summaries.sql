@@ -0,0 +1,153 @@
+-- Denormalized read model for list and dashboard views: one row per event
+-- with the category and organizer names and the registration totals, so
+-- listings don't join and aggregate per request. Kept up to date by the
+-- triggers below; the registration totals are recounted for the affected
+-- event on every change, so they can't drift.
+
+CREATE TABLE event_summaries (
+    event_id TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    category_id TEXT NOT NULL,
+    category_name TEXT,
+    organizer_id TEXT NOT NULL,
+    organizer_name TEXT,
+    start_date DATETIME NOT NULL,
+    end_date DATETIME NOT NULL,
+    status TEXT NOT NULL,
+    registered_count INTEGER NOT NULL DEFAULT 0,
+    waitlisted_count INTEGER NOT NULL DEFAULT 0,
+    attended_count INTEGER NOT NULL DEFAULT 0,
+    cancelled_count INTEGER NOT NULL DEFAULT 0,
+    checked_in_count INTEGER NOT NULL DEFAULT 0,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_event_summaries_organizer ON event_summaries(organizer_id);
+CREATE INDEX idx_event_summaries_start_date ON event_summaries(start_date);
+
+-- Events
+
+CREATE TRIGGER event_summaries_event_insert AFTER INSERT ON events
+BEGIN
+    INSERT INTO event_summaries (event_id, title, category_id, category_name, organizer_id, organizer_name, start_date, end_date, status)
+    VALUES (
+        NEW.id, NEW.title, NEW.category_id,
+        (SELECT name FROM event_categories WHERE id = NEW.category_id),
+        NEW.organizer_id,
+        (SELECT name FROM users WHERE id = NEW.organizer_id),
+        NEW.start_date, NEW.end_date, NEW.status
+    );
+END;
+
+CREATE TRIGGER event_summaries_event_update AFTER UPDATE OF title, category_id, organizer_id, start_date, end_date, status ON events
+BEGIN
+    UPDATE event_summaries
+    SET title = NEW.title,
+        category_id = NEW.category_id,
+        category_name = (SELECT name FROM event_categories WHERE id = NEW.category_id),
+        organizer_id = NEW.organizer_id,
+        organizer_name = (SELECT name FROM users WHERE id = NEW.organizer_id),
+        start_date = NEW.start_date,
+        end_date = NEW.end_date,
+        status = NEW.status,
+        updated_at = CURRENT_TIMESTAMP
+    WHERE event_id = NEW.id;
+END;
+
+CREATE TRIGGER event_summaries_event_delete AFTER DELETE ON events
+BEGIN
+    DELETE FROM event_summaries WHERE event_id = OLD.id;
+END;
+
+-- Registrations
+
+CREATE TRIGGER event_summaries_registration_insert AFTER INSERT ON event_registrations
+BEGIN
+    UPDATE event_summaries
+    SET (registered_count, waitlisted_count, attended_count, cancelled_count, checked_in_count) = (
+        SELECT
+            COALESCE(SUM(status = 'registered'), 0),
+            COALESCE(SUM(status = 'waitlisted'), 0),
+            COALESCE(SUM(status = 'attended'), 0),
+            COALESCE(SUM(status = 'cancelled'), 0),
+            COALESCE(SUM(checked_in_at IS NOT NULL), 0)
+        FROM event_registrations
+        WHERE event_id = NEW.event_id
+    ), updated_at = CURRENT_TIMESTAMP
+    WHERE event_id = NEW.event_id;
+END;
+
+CREATE TRIGGER event_summaries_registration_update AFTER UPDATE OF event_id, status, checked_in_at ON event_registrations
+BEGIN
+    UPDATE event_summaries
+    SET (registered_count, waitlisted_count, attended_count, cancelled_count, checked_in_count) = (
+        SELECT
+            COALESCE(SUM(status = 'registered'), 0),
+            COALESCE(SUM(status = 'waitlisted'), 0),
+            COALESCE(SUM(status = 'attended'), 0),
+            COALESCE(SUM(status = 'cancelled'), 0),
+            COALESCE(SUM(checked_in_at IS NOT NULL), 0)
+        FROM event_registrations
+        WHERE event_id = NEW.event_id
+    ), updated_at = CURRENT_TIMESTAMP
+    WHERE event_id = NEW.event_id;
+    UPDATE event_summaries
+    SET (registered_count, waitlisted_count, attended_count, cancelled_count, checked_in_count) = (
+        SELECT
+            COALESCE(SUM(status = 'registered'), 0),
+            COALESCE(SUM(status = 'waitlisted'), 0),
+            COALESCE(SUM(status = 'attended'), 0),
+            COALESCE(SUM(status = 'cancelled'), 0),
+            COALESCE(SUM(checked_in_at IS NOT NULL), 0)
+        FROM event_registrations
+        WHERE event_id = OLD.event_id
+    ), updated_at = CURRENT_TIMESTAMP
+    WHERE event_id = OLD.event_id;
+END;
+
+CREATE TRIGGER event_summaries_registration_delete AFTER DELETE ON event_registrations
+BEGIN
+    UPDATE event_summaries
+    SET (registered_count, waitlisted_count, attended_count, cancelled_count, checked_in_count) = (
+        SELECT
+            COALESCE(SUM(status = 'registered'), 0),
+            COALESCE(SUM(status = 'waitlisted'), 0),
+            COALESCE(SUM(status = 'attended'), 0),
+            COALESCE(SUM(status = 'cancelled'), 0),
+            COALESCE(SUM(checked_in_at IS NOT NULL), 0)
+        FROM event_registrations
+        WHERE event_id = OLD.event_id
+    ), updated_at = CURRENT_TIMESTAMP
+    WHERE event_id = OLD.event_id;
+END;
+
+-- Renamed categories and organizers
+
+CREATE TRIGGER event_summaries_category_rename AFTER UPDATE OF name ON event_categories
+BEGIN
+    UPDATE event_summaries SET category_name = NEW.name, updated_at = CURRENT_TIMESTAMP WHERE category_id = NEW.id;
+END;
+
+CREATE TRIGGER event_summaries_organizer_rename AFTER UPDATE OF name ON users
+BEGIN
+    UPDATE event_summaries SET organizer_name = NEW.name, updated_at = CURRENT_TIMESTAMP WHERE organizer_id = NEW.id;
+END;
+
+-- Existing events
+
+INSERT INTO event_summaries (
+    event_id, title, category_id, category_name, organizer_id, organizer_name, start_date, end_date, status,
+    registered_count, waitlisted_count, attended_count, cancelled_count, checked_in_count
+)
+SELECT
+    e.id, e.title, e.category_id, c.name, e.organizer_id, u.name, e.start_date, e.end_date, e.status,
+    COALESCE(SUM(r.status = 'registered'), 0),
+    COALESCE(SUM(r.status = 'waitlisted'), 0),
+    COALESCE(SUM(r.status = 'attended'), 0),
+    COALESCE(SUM(r.status = 'cancelled'), 0),
+    COALESCE(SUM(r.checked_in_at IS NOT NULL), 0)
+FROM events e
+LEFT JOIN event_categories c ON c.id = e.category_id
+LEFT JOIN users u ON u.id = e.organizer_id
+LEFT JOIN event_registrations r ON r.event_id = e.id
+GROUP BY e.id;
@@ -0,0 +1,43 @@
+-- Co-organizers and guest names move out of JSON columns into their own
+-- tables, so they can be queried and indexed ("events where this user is a
+-- co-organizer") and are cleaned up with the rows they point at. `position`
+-- keeps the order the lists were entered in.
+
+CREATE TABLE event_co_organizers (
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (event_id, user_id)
+);
+
+CREATE INDEX idx_event_co_organizers_user ON event_co_organizers(user_id);
+
+CREATE TABLE registration_guests (
+    registration_id TEXT NOT NULL REFERENCES event_registrations(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (registration_id, position)
+);
+
+-- Backfill. Malformed JSON is skipped rather than failing the migration, as
+-- the old columns were read leniently too; co-organizers whose account no
+-- longer exists are dropped.
+
+INSERT OR IGNORE INTO event_co_organizers (event_id, user_id, position)
+SELECT events.id, co_organizer.value, co_organizer.key
+FROM events, json_each(
+    CASE WHEN json_valid(events.co_organizers) AND json_type(events.co_organizers) = 'array'
+        THEN events.co_organizers ELSE '[]' END
+) AS co_organizer
+WHERE co_organizer.value IN (SELECT id FROM users);
+
+INSERT INTO registration_guests (registration_id, position, name)
+SELECT event_registrations.id, guest.key, guest.value
+FROM event_registrations, json_each(
+    CASE WHEN json_valid(event_registrations.guest_names) AND json_type(event_registrations.guest_names) = 'array'
+        THEN event_registrations.guest_names ELSE '[]' END
+) AS guest
+WHERE guest.type = 'text';
+
+ALTER TABLE events DROP COLUMN co_organizers;
+ALTER TABLE event_registrations DROP COLUMN guest_names;
@@ -0,0 +1,19 @@
+-- Events proposed by community members for moderators to approve or reject.
+-- The event itself is a draft organized by the submitter; drafts with
+-- submissions but no approved one are never published by the scheduler.
+
+CREATE TABLE event_submissions (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    submitted_by TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'approved', 'rejected')),
+    review_comment TEXT,
+    reviewed_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    reviewed_at DATETIME
+);
+
+-- At most one submission per event waits for a moderator
+CREATE UNIQUE INDEX idx_event_submissions_pending ON event_submissions(event_id) WHERE status = 'pending';
+CREATE INDEX idx_event_submissions_status ON event_submissions(status, created_at);
+CREATE INDEX idx_event_submissions_submitter ON event_submissions(submitted_by, created_at);
@@ -0,0 +1,49 @@
+-- Spam and abuse reports on comments, events and profiles, and the content
+-- hidden while moderators look at it. `target_id` points at a row in
+-- event_comments, events or users depending on `target_type`; triggers clean
+-- up after those rows since a foreign key can't follow the type.
+
+CREATE TABLE content_reports (
+    id TEXT PRIMARY KEY,
+    target_type TEXT NOT NULL CHECK(target_type IN ('comment', 'event', 'profile')),
+    target_id TEXT NOT NULL,
+    reporter_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reason TEXT NOT NULL CHECK(reason IN ('spam', 'harassment', 'inappropriate', 'other')),
+    details TEXT,
+    status TEXT NOT NULL DEFAULT 'open' CHECK(status IN ('open', 'actioned', 'dismissed')),
+    action TEXT CHECK(action IN ('hide', 'warn', 'ban', 'dismiss')),
+    resolved_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    resolved_at DATETIME
+);
+
+-- One open report per user and target; repeated reports don't count twice
+CREATE UNIQUE INDEX idx_content_reports_open_reporter ON content_reports(target_type, target_id, reporter_id) WHERE status = 'open';
+CREATE INDEX idx_content_reports_status ON content_reports(status, created_at);
+
+-- `hidden_by` is NULL when the content was hidden automatically after enough reports
+CREATE TABLE hidden_content (
+    target_type TEXT NOT NULL CHECK(target_type IN ('comment', 'event', 'profile')),
+    target_id TEXT NOT NULL,
+    hidden_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    hidden_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (target_type, target_id)
+);
+
+CREATE TRIGGER content_reports_comment_deleted AFTER DELETE ON event_comments
+BEGIN
+    DELETE FROM content_reports WHERE target_type = 'comment' AND target_id = OLD.id;
+    DELETE FROM hidden_content WHERE target_type = 'comment' AND target_id = OLD.id;
+END;
+
+CREATE TRIGGER content_reports_event_deleted AFTER DELETE ON events
+BEGIN
+    DELETE FROM content_reports WHERE target_type = 'event' AND target_id = OLD.id;
+    DELETE FROM hidden_content WHERE target_type = 'event' AND target_id = OLD.id;
+END;
+
+CREATE TRIGGER content_reports_user_deleted AFTER DELETE ON users
+BEGIN
+    DELETE FROM content_reports WHERE target_type = 'profile' AND target_id = OLD.id;
+    DELETE FROM hidden_content WHERE target_type = 'profile' AND target_id = OLD.id;
+END;
@@ -0,0 +1,27 @@
+-- Consent checkboxes shown when registering for an event, and the answers
+-- given. Answers keep the version and text of the checkbox as it was shown,
+-- and stay on record when the organizer later changes or removes it.
+
+CREATE TABLE event_consents (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK(kind IN ('photo', 'data_processing', 'marketing')),
+    text TEXT NOT NULL,
+    required BOOLEAN NOT NULL DEFAULT FALSE,
+    version INTEGER NOT NULL DEFAULT 1,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(event_id, kind)
+);
+
+-- No foreign key on `consent_id`, so answers outlive a removed checkbox
+CREATE TABLE registration_consents (
+    registration_id TEXT NOT NULL REFERENCES event_registrations(id) ON DELETE CASCADE,
+    consent_id TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK(kind IN ('photo', 'data_processing', 'marketing')),
+    version INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    accepted BOOLEAN NOT NULL,
+    answered_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (registration_id, consent_id)
+);
@@ -0,0 +1,8 @@
+-- Organizations whose media storage quota differs from the platform default.
+-- Usage is summed from event_files and event_expenses on every check.
+
+CREATE TABLE company_storage_quotas (
+    company_id TEXT PRIMARY KEY REFERENCES companies(id) ON DELETE CASCADE,
+    quota_bytes INTEGER NOT NULL CHECK(quota_bytes >= 0),
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
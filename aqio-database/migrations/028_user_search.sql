@@ -0,0 +1,44 @@
+-- Trigram index over user names, emails and company names for the user
+-- picker. Kept in step with users and companies by triggers; rows are found
+-- by user_id, which is a scan of the index, but users change rarely.
+
+CREATE VIRTUAL TABLE user_search USING fts5(
+    user_id UNINDEXED,
+    name,
+    email,
+    company_name,
+    tokenize = 'trigram'
+);
+
+CREATE TRIGGER user_search_user_insert AFTER INSERT ON users
+BEGIN
+    INSERT INTO user_search (user_id, name, email, company_name)
+    VALUES (NEW.id, NEW.name, NEW.email, (SELECT name FROM companies WHERE id = NEW.company_id));
+END;
+
+CREATE TRIGGER user_search_user_update AFTER UPDATE OF name, email, company_id ON users
+BEGIN
+    UPDATE user_search
+    SET name = NEW.name,
+        email = NEW.email,
+        company_name = (SELECT name FROM companies WHERE id = NEW.company_id)
+    WHERE user_id = NEW.id;
+END;
+
+CREATE TRIGGER user_search_user_delete AFTER DELETE ON users
+BEGIN
+    DELETE FROM user_search WHERE user_id = OLD.id;
+END;
+
+CREATE TRIGGER user_search_company_rename AFTER UPDATE OF name ON companies
+BEGIN
+    UPDATE user_search SET company_name = NEW.name
+    WHERE user_id IN (SELECT id FROM users WHERE company_id = NEW.id);
+END;
+
+-- Existing users
+
+INSERT INTO user_search (user_id, name, email, company_name)
+SELECT u.id, u.name, u.email, c.name
+FROM users u
+LEFT JOIN companies c ON c.id = u.company_id;
@@ -0,0 +1,23 @@
+-- Messages organizers send to an event's registrants, right away or at a
+-- scheduled time. Delivery counts are filled in when the broadcast goes out.
+
+CREATE TABLE event_broadcasts (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    sender_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    audience TEXT NOT NULL CHECK(audience IN ('all', 'confirmed', 'waitlisted', 'checked_in')),
+    subject TEXT NOT NULL,
+    message TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'scheduled' CHECK(status IN ('scheduled', 'sent', 'cancelled')),
+    scheduled_for DATETIME,
+    sent_at DATETIME,
+    recipients INTEGER NOT NULL DEFAULT 0,
+    delivered INTEGER NOT NULL DEFAULT 0,
+    suppressed INTEGER NOT NULL DEFAULT 0,
+    failed INTEGER NOT NULL DEFAULT 0,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_event_broadcasts_event ON event_broadcasts(event_id, created_at);
+CREATE INDEX idx_event_broadcasts_due ON event_broadcasts(status, scheduled_for);
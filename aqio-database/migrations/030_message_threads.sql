@@ -0,0 +1,27 @@
+-- Conversations about an event between its organizers and one attendee, with
+-- an unread count kept per side so inboxes don't have to count messages.
+
+CREATE TABLE message_threads (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    attendee_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    attendee_unread INTEGER NOT NULL DEFAULT 0,
+    organizer_unread INTEGER NOT NULL DEFAULT 0,
+    last_message_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(event_id, attendee_id)
+);
+
+CREATE INDEX idx_message_threads_attendee ON message_threads(attendee_id, last_message_at);
+
+CREATE TABLE thread_messages (
+    id TEXT PRIMARY KEY,
+    thread_id TEXT NOT NULL REFERENCES message_threads(id) ON DELETE CASCADE,
+    sender_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    from_organizer BOOLEAN NOT NULL,
+    body TEXT NOT NULL,
+    broadcast_id TEXT REFERENCES event_broadcasts(id) ON DELETE SET NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_thread_messages_thread ON thread_messages(thread_id, created_at);
@@ -0,0 +1,24 @@
+-- Post-event photo galleries. An event's album settings live in event_albums
+-- (from 004), now limited to one album per event. Photos get a table of their
+-- own rather than event_files, so they stay out of the event's materials and
+-- can wait for an organizer's approval.
+
+CREATE UNIQUE INDEX idx_event_albums_event ON event_albums(event_id);
+
+CREATE TABLE event_photos (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    uploader_id TEXT REFERENCES users(id) ON DELETE SET NULL,
+    uploader_name TEXT,
+    filename TEXT NOT NULL,
+    original_filename TEXT NOT NULL,
+    storage_key TEXT NOT NULL,
+    file_size INTEGER NOT NULL,
+    mime_type TEXT NOT NULL,
+    caption TEXT,
+    status TEXT NOT NULL CHECK(status IN ('pending', 'approved', 'rejected')),
+    uploaded_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    reviewed_at DATETIME
+);
+
+CREATE INDEX idx_event_photos_event ON event_photos(event_id, uploaded_at);
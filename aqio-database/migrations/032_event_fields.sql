@@ -0,0 +1,29 @@
+-- Custom event fields. Organizations define typed fields for their members'
+-- events ("Fish species focus", "Regulatory topic"); each event keeps one
+-- value per field, stored as text in the field type's canonical form so the
+-- event list can filter on it.
+
+CREATE TABLE event_field_definitions (
+    id TEXT PRIMARY KEY,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    key TEXT NOT NULL,
+    label TEXT NOT NULL,
+    field_type TEXT NOT NULL CHECK(field_type IN ('text', 'number', 'date', 'boolean', 'select')),
+    options TEXT NOT NULL DEFAULT '[]', -- JSON array of choices for select fields
+    is_required BOOLEAN NOT NULL DEFAULT FALSE,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(company_id, key)
+);
+
+CREATE INDEX idx_event_field_definitions_company ON event_field_definitions(company_id, position);
+
+CREATE TABLE event_field_values (
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    field_id TEXT NOT NULL REFERENCES event_field_definitions(id) ON DELETE CASCADE,
+    value TEXT NOT NULL,
+    PRIMARY KEY (event_id, field_id)
+);
+
+CREATE INDEX idx_event_field_values_field ON event_field_values(field_id, value COLLATE NOCASE);
@@ -0,0 +1,13 @@
+-- Where in Norway events and companies are. The municipality (kommune) and
+-- county (fylke) are looked up from the postal code in the address when it is
+-- saved, so lists can be filtered and archives grouped by region.
+
+ALTER TABLE events ADD COLUMN postal_code TEXT;
+ALTER TABLE events ADD COLUMN municipality TEXT;
+ALTER TABLE events ADD COLUMN county TEXT;
+
+CREATE INDEX idx_events_county ON events(county COLLATE NOCASE, municipality COLLATE NOCASE);
+
+ALTER TABLE companies ADD COLUMN postal_code TEXT;
+ALTER TABLE companies ADD COLUMN municipality TEXT;
+ALTER TABLE companies ADD COLUMN county TEXT;
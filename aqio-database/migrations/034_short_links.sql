@@ -0,0 +1,30 @@
+-- Short links for sharing events. Each link sends visitors to the event's
+-- public page with the organizer's campaign (utm_*) parameters, and every
+-- visit is kept so organizers can see which newsletter or post brought people
+-- in. Visits through an invitation's personal copy of a link are tied to the
+-- invitation.
+
+CREATE TABLE short_links (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    code TEXT NOT NULL UNIQUE,
+    label TEXT,
+    utm_source TEXT,
+    utm_medium TEXT,
+    utm_campaign TEXT,
+    utm_content TEXT,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_short_links_event ON short_links(event_id, created_at);
+
+CREATE TABLE short_link_clicks (
+    id TEXT PRIMARY KEY,
+    link_id TEXT NOT NULL REFERENCES short_links(id) ON DELETE CASCADE,
+    invitation_id TEXT REFERENCES event_invitations(id) ON DELETE SET NULL,
+    referrer_host TEXT,
+    clicked_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_short_link_clicks_link ON short_link_clicks(link_id, clicked_at);
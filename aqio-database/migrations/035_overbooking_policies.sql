@@ -0,0 +1,12 @@
+-- Overbooking: an event may accept more registrations than it seats, since
+-- some registrants never show up. The percentage is set by the organizers,
+-- usually from the no-show rate of their past events, and the hard ceiling
+-- caps it at what the venue can take in the worst case.
+
+CREATE TABLE event_overbooking_policies (
+    event_id TEXT PRIMARY KEY REFERENCES events(id) ON DELETE CASCADE,
+    percent INTEGER NOT NULL CHECK (percent >= 0),
+    hard_ceiling INTEGER CHECK (hard_ceiling > 0),
+    updated_by TEXT NOT NULL REFERENCES users(id),
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
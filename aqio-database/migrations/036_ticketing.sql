@@ -0,0 +1,41 @@
+-- Ticketing: the ticket types an event sells and the orders for them.
+-- Prices are in minor units (øre, cents) of the ticket type's currency.
+-- Orders copy the price and currency and keep the exchange rate to NOK of
+-- the day they were placed, so revenue can be added up in NOK later without
+-- being moved by rate changes.
+
+CREATE TABLE ticket_types (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    price INTEGER NOT NULL CHECK (price >= 0),
+    currency TEXT NOT NULL CHECK (currency IN ('NOK', 'EUR', 'USD')),
+    quantity INTEGER CHECK (quantity > 0),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_ticket_types_event ON ticket_types(event_id);
+
+CREATE TABLE ticket_orders (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    ticket_type_id TEXT NOT NULL REFERENCES ticket_types(id),
+    buyer_id TEXT NOT NULL REFERENCES users(id),
+    quantity INTEGER NOT NULL CHECK (quantity > 0),
+    unit_price INTEGER NOT NULL CHECK (unit_price >= 0),
+    currency TEXT NOT NULL CHECK (currency IN ('NOK', 'EUR', 'USD')),
+    total INTEGER NOT NULL,
+    exchange_rate REAL NOT NULL CHECK (exchange_rate > 0),
+    exchange_rate_date DATE NOT NULL,
+    total_nok INTEGER NOT NULL,
+    status TEXT NOT NULL DEFAULT 'confirmed' CHECK (status IN ('confirmed', 'cancelled')),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    cancelled_at DATETIME
+);
+
+CREATE INDEX idx_ticket_orders_event ON ticket_orders(event_id, created_at);
+CREATE INDEX idx_ticket_orders_buyer ON ticket_orders(buyer_id, created_at);
+CREATE INDEX idx_ticket_orders_type ON ticket_orders(ticket_type_id, status);
@@ -0,0 +1,27 @@
+-- Invoices for ticket orders bought by organizations. Seller and buyer are
+-- stored as they were when the invoice was issued (JSON), since an invoice
+-- can't change afterwards. Invoice numbers are sequential without gaps.
+
+CREATE TABLE invoices (
+    id TEXT PRIMARY KEY,
+    invoice_number INTEGER NOT NULL UNIQUE,
+    order_id TEXT NOT NULL UNIQUE REFERENCES ticket_orders(id),
+    event_id TEXT NOT NULL REFERENCES events(id),
+    seller TEXT NOT NULL,
+    buyer TEXT NOT NULL,
+    buyer_reference TEXT NOT NULL,
+    bank_account TEXT,
+    currency TEXT NOT NULL CHECK (currency IN ('NOK', 'EUR', 'USD')),
+    description TEXT NOT NULL,
+    quantity INTEGER NOT NULL CHECK (quantity > 0),
+    unit_price INTEGER NOT NULL,
+    total INTEGER NOT NULL,
+    issue_date DATE NOT NULL,
+    due_date DATE NOT NULL,
+    status TEXT NOT NULL DEFAULT 'issued' CHECK (status IN ('issued', 'paid', 'voided')),
+    paid_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_invoices_event ON invoices(event_id, due_date);
+CREATE INDEX idx_invoices_status_due ON invoices(status, due_date);
@@ -0,0 +1,17 @@
+-- VAT on ticket sales. Ticket prices include VAT at the ticket type's rate
+-- (0, 12, 15 or 25 percent); orders and invoices copy the rate and keep the
+-- VAT contained in their totals, so reports don't depend on later changes.
+-- Existing types, orders and invoices were sold without VAT.
+
+ALTER TABLE ticket_types ADD COLUMN vat_rate INTEGER NOT NULL DEFAULT 0 CHECK (vat_rate IN (0, 12, 15, 25));
+
+ALTER TABLE ticket_orders ADD COLUMN vat_rate INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE ticket_orders ADD COLUMN vat_amount INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE ticket_orders ADD COLUMN vat_amount_nok INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE invoices ADD COLUMN vat_rate INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE invoices ADD COLUMN vat_amount INTEGER NOT NULL DEFAULT 0;
+
+-- Monthly reports look orders up by when they were placed and cancelled
+CREATE INDEX idx_ticket_orders_created ON ticket_orders(created_at);
+CREATE INDEX idx_ticket_orders_cancelled ON ticket_orders(cancelled_at) WHERE cancelled_at IS NOT NULL;
@@ -0,0 +1,22 @@
+-- Refunds of ticket orders through the payment provider, created for every
+-- paid order when an event is cancelled. Failed attempts are retried with a
+-- growing delay until the refund succeeds or is given up on.
+
+CREATE TABLE ticket_refunds (
+    id TEXT PRIMARY KEY,
+    order_id TEXT NOT NULL UNIQUE REFERENCES ticket_orders(id),
+    event_id TEXT NOT NULL REFERENCES events(id),
+    buyer_id TEXT NOT NULL REFERENCES users(id),
+    amount INTEGER NOT NULL CHECK (amount > 0),
+    currency TEXT NOT NULL CHECK (currency IN ('NOK', 'EUR', 'USD')),
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'succeeded', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at DATETIME,
+    last_error TEXT,
+    provider_reference TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_ticket_refunds_event ON ticket_refunds(event_id, created_at);
+CREATE INDEX idx_ticket_refunds_due ON ticket_refunds(next_attempt_at) WHERE status = 'pending';
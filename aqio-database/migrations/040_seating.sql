@@ -0,0 +1,25 @@
+-- Seating for gala dinners and other seated events: tables with a number of
+-- seats, and the table each registration (with its guests) is seated at.
+-- Deleting a table or a registration unseats whoever sat there.
+
+CREATE TABLE seating_tables (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    capacity INTEGER NOT NULL CHECK (capacity > 0),
+    accessible BOOLEAN NOT NULL DEFAULT 0,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (event_id, name)
+);
+
+CREATE TABLE seat_assignments (
+    registration_id TEXT PRIMARY KEY REFERENCES event_registrations(id) ON DELETE CASCADE,
+    table_id TEXT NOT NULL REFERENCES seating_tables(id) ON DELETE CASCADE,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    assigned_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_seat_assignments_event ON seat_assignments(event_id);
+CREATE INDEX idx_seat_assignments_table ON seat_assignments(table_id);
@@ -0,0 +1,38 @@
+-- Bookable equipment and facilities (projectors, boats, demo tanks). A
+-- resource can be limited to availability windows; without any it can be
+-- booked at any time. Bookings reserve a resource for an event's time slot,
+-- with the end exclusive so back-to-back bookings don't overlap.
+
+CREATE TABLE resources (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    description TEXT,
+    location TEXT,
+    is_active BOOLEAN NOT NULL DEFAULT 1,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE resource_availability (
+    resource_id TEXT NOT NULL REFERENCES resources(id) ON DELETE CASCADE,
+    starts_at DATETIME NOT NULL,
+    ends_at DATETIME NOT NULL,
+    CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX idx_resource_availability_resource ON resource_availability(resource_id, starts_at);
+
+CREATE TABLE resource_bookings (
+    id TEXT PRIMARY KEY,
+    resource_id TEXT NOT NULL REFERENCES resources(id) ON DELETE CASCADE,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    starts_at DATETIME NOT NULL,
+    ends_at DATETIME NOT NULL,
+    booked_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX idx_resource_bookings_resource ON resource_bookings(resource_id, starts_at);
+CREATE INDEX idx_resource_bookings_event ON resource_bookings(event_id);
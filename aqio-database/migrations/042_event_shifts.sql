@@ -0,0 +1,27 @@
+-- Staff shifts at events: a role, a time window and how many people it
+-- needs, and the staff users signed up for each. Removing a shift or a user
+-- removes their signups.
+
+CREATE TABLE event_shifts (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    role TEXT NOT NULL,
+    description TEXT,
+    starts_at DATETIME NOT NULL,
+    ends_at DATETIME NOT NULL,
+    headcount INTEGER NOT NULL CHECK (headcount > 0),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX idx_event_shifts_event ON event_shifts(event_id, starts_at);
+
+CREATE TABLE shift_signups (
+    shift_id TEXT NOT NULL REFERENCES event_shifts(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    signed_up_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (shift_id, user_id)
+);
+
+CREATE INDEX idx_shift_signups_user ON shift_signups(user_id);
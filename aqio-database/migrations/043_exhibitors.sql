@@ -0,0 +1,33 @@
+-- Trade-fair style expos: the booths on an event's floor plan, positioned in
+-- metres, and the exhibitors taking part. A booth holds at most one
+-- exhibitor; deleting a booth leaves its exhibitor without one.
+
+CREATE TABLE booths (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    number TEXT NOT NULL,
+    hall TEXT,
+    x REAL NOT NULL CHECK (x >= 0),
+    y REAL NOT NULL CHECK (y >= 0),
+    width REAL NOT NULL CHECK (width > 0),
+    depth REAL NOT NULL CHECK (depth > 0),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (event_id, number)
+);
+
+CREATE TABLE exhibitors (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    company_name TEXT NOT NULL,
+    contact_name TEXT,
+    contact_email TEXT,
+    website_url TEXT,
+    description TEXT,
+    booth_id TEXT REFERENCES booths(id) ON DELETE SET NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_exhibitors_event ON exhibitors(event_id);
+CREATE UNIQUE INDEX idx_exhibitors_booth ON exhibitors(booth_id);
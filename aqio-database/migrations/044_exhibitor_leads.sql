@@ -0,0 +1,59 @@
+-- Lead scanning at expos: exhibitors scan attendee badges to keep the
+-- contact details of attendees who agreed to share them. Members of the
+-- exhibitor's registered company do the scanning, and a new consent kind,
+-- `lead_sharing`, records the attendee's agreement.
+
+ALTER TABLE exhibitors ADD COLUMN company_id TEXT REFERENCES companies(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_exhibitors_company ON exhibitors(company_id);
+
+-- SQLite can't change a CHECK constraint in place, so the consent tables are
+-- rebuilt with `lead_sharing` added to the allowed kinds
+CREATE TABLE event_consents_new (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK(kind IN ('photo', 'data_processing', 'marketing', 'lead_sharing')),
+    text TEXT NOT NULL,
+    required BOOLEAN NOT NULL DEFAULT FALSE,
+    version INTEGER NOT NULL DEFAULT 1,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(event_id, kind)
+);
+
+INSERT INTO event_consents_new (id, event_id, kind, text, required, version, created_at, updated_at)
+SELECT id, event_id, kind, text, required, version, created_at, updated_at FROM event_consents;
+
+DROP TABLE event_consents;
+ALTER TABLE event_consents_new RENAME TO event_consents;
+
+CREATE TABLE registration_consents_new (
+    registration_id TEXT NOT NULL REFERENCES event_registrations(id) ON DELETE CASCADE,
+    consent_id TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK(kind IN ('photo', 'data_processing', 'marketing', 'lead_sharing')),
+    version INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    accepted BOOLEAN NOT NULL,
+    answered_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (registration_id, consent_id)
+);
+
+INSERT INTO registration_consents_new (registration_id, consent_id, kind, version, text, accepted, answered_at)
+SELECT registration_id, consent_id, kind, version, text, accepted, answered_at FROM registration_consents;
+
+DROP TABLE registration_consents;
+ALTER TABLE registration_consents_new RENAME TO registration_consents;
+
+-- A lead stays when the scanning user is removed; the exhibitor keeps it
+CREATE TABLE exhibitor_leads (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    exhibitor_id TEXT NOT NULL REFERENCES exhibitors(id) ON DELETE CASCADE,
+    registration_id TEXT NOT NULL REFERENCES event_registrations(id) ON DELETE CASCADE,
+    scanned_by TEXT NOT NULL,
+    notes TEXT,
+    scanned_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (exhibitor_id, registration_id)
+);
+
+CREATE INDEX idx_exhibitor_leads_event ON exhibitor_leads(event_id, scanned_at);
@@ -0,0 +1,59 @@
+-- B2B matchmaking: attendees opt in with their interests and the windows
+-- they're free in, and request 1:1 meetings with each other. Meetings are
+-- held in the rooms organizers set aside for the event; a meeting's room is
+-- a resource booking, so rooms can't be double-booked with anything else.
+
+CREATE TABLE matchmaking_profiles (
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    bio TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (event_id, user_id)
+);
+
+CREATE TABLE matchmaking_interests (
+    event_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    interest TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (event_id, user_id, position),
+    FOREIGN KEY (event_id, user_id) REFERENCES matchmaking_profiles(event_id, user_id) ON DELETE CASCADE
+);
+
+CREATE TABLE matchmaking_availability (
+    event_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    starts_at DATETIME NOT NULL,
+    ends_at DATETIME NOT NULL,
+    CHECK (ends_at > starts_at),
+    FOREIGN KEY (event_id, user_id) REFERENCES matchmaking_profiles(event_id, user_id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_matchmaking_availability_profile ON matchmaking_availability(event_id, user_id, starts_at);
+
+CREATE TABLE meeting_rooms (
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    resource_id TEXT NOT NULL REFERENCES resources(id) ON DELETE CASCADE,
+    PRIMARY KEY (event_id, resource_id)
+);
+
+CREATE TABLE meetings (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    requester_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    invitee_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    message TEXT,
+    starts_at DATETIME NOT NULL,
+    ends_at DATETIME NOT NULL,
+    resource_id TEXT REFERENCES resources(id) ON DELETE SET NULL,
+    booking_id TEXT REFERENCES resource_bookings(id) ON DELETE SET NULL,
+    status TEXT NOT NULL DEFAULT 'requested'
+        CHECK (status IN ('requested', 'accepted', 'declined', 'cancelled')),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (ends_at > starts_at),
+    CHECK (requester_id <> invitee_id)
+);
+
+CREATE INDEX idx_meetings_event ON meetings(event_id, starts_at);
@@ -0,0 +1,16 @@
+-- Web vitals reported by browsers running the web app: page load and
+-- interaction timings plus API round trips, tagged with the app release so
+-- percentiles can be compared between releases. Samples are pruned after a
+-- retention period, so the table only holds recent traffic.
+
+CREATE TABLE web_vitals (
+    id TEXT PRIMARY KEY,
+    metric TEXT NOT NULL
+        CHECK (metric IN ('lcp', 'fid', 'inp', 'cls', 'api_latency')),
+    value REAL NOT NULL CHECK (value >= 0),
+    release TEXT NOT NULL,
+    route TEXT,
+    recorded_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_web_vitals_recorded_at ON web_vitals(recorded_at);
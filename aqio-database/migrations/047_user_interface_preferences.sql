@@ -0,0 +1,6 @@
+-- How the web app looks for each user: colour scheme and the view the event
+-- calendar opens on. NULL follows the device's colour scheme and opens on the
+-- month.
+
+ALTER TABLE users ADD COLUMN theme TEXT CHECK(theme IN ('light', 'dark'));
+ALTER TABLE users ADD COLUMN calendar_view TEXT CHECK(calendar_view IN ('month', 'week', 'day'));
@@ -0,0 +1,28 @@
+-- Roles organizations define for their own members, e.g. door staff who may
+-- only check people in. Each role is a named set of permission codes such as
+-- `registration:check_in`, held only within the organization.
+
+CREATE TABLE organization_roles (
+    id TEXT PRIMARY KEY,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (company_id, name)
+);
+
+CREATE TABLE organization_role_permissions (
+    role_id TEXT NOT NULL REFERENCES organization_roles(id) ON DELETE CASCADE,
+    permission TEXT NOT NULL,
+    PRIMARY KEY (role_id, permission)
+);
+
+CREATE TABLE organization_role_members (
+    role_id TEXT NOT NULL REFERENCES organization_roles(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    assigned_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (role_id, user_id)
+);
+
+CREATE INDEX idx_organization_role_members_user ON organization_role_members(user_id);
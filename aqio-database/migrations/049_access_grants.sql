@@ -0,0 +1,24 @@
+-- Access to one event that its organizer delegates for a limited time, e.g.
+-- check-in rights for the day of the event. A grant holds from `starts_at`
+-- until `expires_at` unless it's revoked first; ended grants are kept.
+
+CREATE TABLE access_grants (
+    id TEXT PRIMARY KEY,
+    event_id TEXT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    granted_by TEXT NOT NULL REFERENCES users(id),
+    starts_at DATETIME NOT NULL,
+    expires_at DATETIME NOT NULL,
+    revoked_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (expires_at > starts_at)
+);
+
+CREATE TABLE access_grant_permissions (
+    grant_id TEXT NOT NULL REFERENCES access_grants(id) ON DELETE CASCADE,
+    permission TEXT NOT NULL,
+    PRIMARY KEY (grant_id, permission)
+);
+
+CREATE INDEX idx_access_grants_event ON access_grants(event_id);
+CREATE INDEX idx_access_grants_user ON access_grants(user_id, expires_at);
@@ -0,0 +1,26 @@
+-- Exports admins schedule to push registrations or events to external
+-- systems over SFTP or S3. The filter and destination are JSON; credentials
+-- for the destinations come from the server's environment, not this table.
+
+CREATE TABLE export_jobs (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    entity TEXT NOT NULL CHECK (entity IN ('registrations', 'events')),
+    filter TEXT NOT NULL DEFAULT '{}',
+    format TEXT NOT NULL CHECK (format IN ('csv', 'json')),
+    destination TEXT NOT NULL,
+    schedule TEXT NOT NULL,
+    timezone TEXT NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    next_run_at DATETIME,
+    last_run_at DATETIME,
+    last_status TEXT CHECK (last_status IN ('succeeded', 'failed')),
+    last_error TEXT,
+    last_row_count INTEGER,
+    last_success_at DATETIME,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_export_jobs_due ON export_jobs(is_active, next_run_at);
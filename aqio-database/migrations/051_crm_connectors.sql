@@ -0,0 +1,42 @@
+-- CRM connectors that sync registrants to HubSpot or Dynamics, and what each
+-- connector last wrote to every contact and registration record. Field
+-- mappings are JSON; API credentials come from the server's environment.
+
+CREATE TABLE crm_connectors (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    provider TEXT NOT NULL CHECK (provider IN ('hubspot', 'dynamics')),
+    base_url TEXT,
+    company_id TEXT REFERENCES companies(id) ON DELETE CASCADE,
+    registration_object TEXT,
+    field_mappings TEXT NOT NULL DEFAULT '[]',
+    conflict_policy TEXT NOT NULL CHECK (conflict_policy IN ('aqio_wins', 'crm_wins', 'fill_blanks')),
+    sync_interval_minutes INTEGER NOT NULL CHECK (sync_interval_minutes BETWEEN 5 AND 1440),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    next_sync_at DATETIME,
+    last_sync_at DATETIME,
+    last_status TEXT CHECK (last_status IN ('succeeded', 'partially_failed', 'failed')),
+    last_error TEXT,
+    last_summary TEXT,
+    sync_cursor DATETIME,
+    created_by TEXT NOT NULL REFERENCES users(id),
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_crm_connectors_due ON crm_connectors(is_active, next_sync_at);
+
+CREATE TABLE crm_sync_records (
+    connector_id TEXT NOT NULL REFERENCES crm_connectors(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (kind IN ('contact', 'registration')),
+    local_key TEXT NOT NULL,
+    remote_id TEXT,
+    state TEXT NOT NULL CHECK (state IN ('synced', 'conflict', 'failed')),
+    synced_values TEXT NOT NULL DEFAULT '{}',
+    conflicts TEXT NOT NULL DEFAULT '[]',
+    error TEXT,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (connector_id, kind, local_key)
+);
+
+CREATE INDEX idx_crm_sync_records_state ON crm_sync_records(connector_id, state, updated_at);
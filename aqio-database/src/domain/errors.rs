@@ -212,6 +212,23 @@ impl InfrastructureError {
             
             // Invitation constraints
             ("event_invitations", _, "unique") => "This person has already been invited to this event.".to_string(),
+
+            // Invoice constraints
+            ("invoices", "order_id", "unique") => "This order has already been invoiced.".to_string(),
+            ("ticket_refunds", "order_id", "unique") => "This order is already being refunded.".to_string(),
+
+            // Seating constraints
+            ("seating_tables", _, "unique") => "The event already has a table with this name.".to_string(),
+
+            // Resource constraints
+            ("resources", "name", "unique") => "There is already a resource with this name.".to_string(),
+
+            // Shift constraints
+            ("shift_signups", _, "unique") => "You are already signed up for this shift.".to_string(),
+
+            // Exhibitor constraints
+            ("booths", _, "unique") => "The event already has a booth with this number.".to_string(),
+            ("exhibitors", "booth_id", "unique") => "This booth is already allocated to another exhibitor.".to_string(),
             
             // Generic fallbacks
             (_, _, "unique") => format!("This {} is already taken. Please choose a different value.", field.replace('_', " ")),
@@ -580,7 +597,7 @@ impl SqliteForeignKeyDiagnostic {
 mod tests {
     use super::*;
     use uuid::Uuid;
-    use sqlx::SqlitePool;
+    use crate::testing::TestDb;
 
     #[test]
     fn test_domain_error_construction() {
@@ -624,63 +641,21 @@ mod tests {
 
     #[tokio::test]
     async fn test_foreign_key_diagnostic() {
-        // Create in-memory database
-        let pool = SqlitePool::connect(":memory:").await.unwrap();
-        
-        // Create minimal schema for testing
-        sqlx::query(r#"
-            CREATE TABLE users (
-                id TEXT PRIMARY KEY,
-                is_active BOOLEAN DEFAULT TRUE
-            )
-        "#)
-        .execute(&pool)
-        .await
-        .unwrap();
-
-        sqlx::query(r#"
-            CREATE TABLE events (
-                id TEXT PRIMARY KEY
-            )
-        "#)
-        .execute(&pool)
-        .await
-        .unwrap();
-
-        sqlx::query(r#"
-            CREATE TABLE event_categories (
-                id TEXT PRIMARY KEY,
-                is_active BOOLEAN DEFAULT TRUE
-            )
-        "#)
-        .execute(&pool)
-        .await
-        .unwrap();
+        // Isolated database with the real schema
+        let db = TestDb::in_memory().await;
 
         // Insert test data
-        let user_id = Uuid::new_v4();
-        let event_id = Uuid::new_v4();
-        
-        sqlx::query("INSERT INTO users (id, is_active) VALUES (?, TRUE)")
-            .bind(user_id.to_string())
-            .execute(&pool)
-            .await
-            .unwrap();
-
-        sqlx::query("INSERT INTO events (id) VALUES (?)")
-            .bind(event_id.to_string())
-            .execute(&pool)
-            .await
-            .unwrap();
+        let user_id = db.seed_user().await;
+        let event_id = db.seed_event(user_id).await;
 
-        sqlx::query("INSERT INTO event_categories (id, is_active) VALUES (?, TRUE)")
+        sqlx::query("INSERT INTO event_categories (id, name, is_active) VALUES (?, 'Test Category', TRUE)")
             .bind("test_category")
-            .execute(&pool)
+            .execute(db.pool())
             .await
             .unwrap();
 
         // Test diagnostic functionality
-        let diagnostic = SqliteForeignKeyDiagnostic::new(pool);
+        let diagnostic = SqliteForeignKeyDiagnostic::new(db.pool().clone());
 
         // Test existing entities
         assert!(diagnostic.check_user_exists(user_id).await, "User should exist");
@@ -2,5 +2,15 @@
 pub use aqio_core::{
     UserRepository, EventRepository, EventCategoryRepository, 
     EventInvitationRepository, EventRegistrationRepository, 
-    ExternalContactRepository
+    ExternalContactRepository, AuditLogRepository, CompanyRepository, EventCommentRepository,
+    EventQuestionRepository, EventPollRepository, EventAttachmentRepository, EventBudgetRepository,
+    EventSponsorRepository, SchedulingPolicyRepository, SendingDomainRepository,
+    EmailSuppressionRepository, NotificationDigestRepository,
+    OrganizationInvitationRepository, EventOwnershipTransferRepository, EventSummaryRepository,
+    EventSubmissionRepository, ContentReportRepository, EventConsentRepository, StorageQuotaRepository,
+    EventBroadcastRepository, MessageThreadRepository, EventPhotoRepository, AnalyticsRepository,
+    EventFieldRepository, ShortLinkRepository, OverbookingPolicyRepository,
+    TicketRepository, InvoiceRepository, TicketRefundRepository, SeatingRepository,
+    ResourceRepository, ShiftRepository, ExhibitorRepository, LeadRepository, MatchmakingRepository, WebVitalRepository,
+    OrganizationRoleRepository, AccessGrantRepository, ExportJobRepository, CrmConnectorRepository,
 };
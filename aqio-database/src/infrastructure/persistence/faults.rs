@@ -0,0 +1,435 @@
+// Fault injection for dev and staging servers. A `FaultInjector` holds a plan
+// of latency, dropped connections and constraint violations; `FaultInjected`
+// wraps a repository and applies it before each call, and the API's outbound
+// adapters consult the same injector, so error handling and retries can be
+// exercised without breaking the database or the services it calls.
+
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+use aqio_core::{
+    ArchiveGrouping, DomainError, DomainResult, Event, EventFilter, EventInvitation, EventRegistration,
+    EventRegistrationRepository, EventInvitationRepository, EventRepository, EventSummary, EventSummaryRepository,
+    InvitationStatus, PaginatedResult, PaginationParams, PastEventFilter, RegistrationCounts, User, UserMatch,
+    UserRepository, YearlyAttendance,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// What to inject, and where
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FaultPlan {
+    /// Each call is delayed by up to this much, uniformly at random
+    pub max_latency: Duration,
+    /// Share of calls, 0.0 to 1.0, that fail as if the connection dropped
+    pub failure_rate: f64,
+    /// Share of repository writes, 0.0 to 1.0, that fail with a unique
+    /// constraint violation
+    pub conflict_rate: f64,
+    /// Repositories (`events`) and outbound destinations (`sendgrid`, or
+    /// `webhook` for every `webhook:<host>`) to disturb; empty for all
+    pub targets: Vec<String>,
+}
+
+impl FaultPlan {
+    /// Whether the plan injects anything at all
+    pub fn is_active(&self) -> bool {
+        !self.max_latency.is_zero() || self.failure_rate > 0.0 || self.conflict_rate > 0.0
+    }
+
+    fn covers(&self, target: &str) -> bool {
+        self.targets.is_empty()
+            || self.targets.iter().any(|covered| {
+                target == covered || target.strip_prefix(covered.as_str()).is_some_and(|rest| rest.starts_with(':'))
+            })
+    }
+}
+
+/// A fault drawn for one call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The connection dropped before an answer came back
+    Unavailable,
+    /// A write broke a unique constraint
+    Conflict,
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::Unavailable => write!(f, "connection dropped"),
+            Fault::Conflict => write!(f, "unique constraint violated"),
+        }
+    }
+}
+
+/// Applies a `FaultPlan`; clones share it
+#[derive(Debug, Clone)]
+pub struct FaultInjector {
+    plan: Arc<FaultPlan>,
+}
+
+impl FaultInjector {
+    pub fn new(plan: FaultPlan) -> Self {
+        Self { plan: Arc::new(plan) }
+    }
+
+    pub fn plan(&self) -> &FaultPlan {
+        &self.plan
+    }
+
+    /// Delays a call to `target` as planned and draws whether it fails;
+    /// only writes can hit a constraint violation
+    pub async fn disturb(&self, target: &str, write: bool) -> Option<Fault> {
+        if !self.plan.is_active() || !self.plan.covers(target) {
+            return None;
+        }
+        if !self.plan.max_latency.is_zero() {
+            tokio::time::sleep(self.plan.max_latency.mul_f64(chance())).await;
+        }
+        let fault = if happens(self.plan.failure_rate) {
+            Some(Fault::Unavailable)
+        } else if write && happens(self.plan.conflict_rate) {
+            Some(Fault::Conflict)
+        } else {
+            None
+        };
+        if let Some(fault) = fault {
+            tracing::warn!(component = target, %fault, "Injected fault");
+        }
+        fault
+    }
+}
+
+/// A number from 0.0 to 1.0, different on every call
+fn chance() -> f64 {
+    RandomState::new().build_hasher().finish() as f64 / u64::MAX as f64
+}
+
+fn happens(rate: f64) -> bool {
+    rate >= 1.0 || (rate > 0.0 && chance() < rate)
+}
+
+/// A repository whose calls are disturbed by a `FaultInjector`
+///
+/// Implements the same port as the repository it wraps, so services see the
+/// injected faults exactly like real ones.
+#[derive(Clone)]
+pub struct FaultInjected<R> {
+    inner: R,
+    repository: &'static str,
+    injector: FaultInjector,
+}
+
+impl<R> FaultInjected<R> {
+    /// `repository` names the wrapped repository in the plan's targets, e.g. `events`
+    pub fn new(inner: R, repository: &'static str, injector: FaultInjector) -> Self {
+        Self {
+            inner,
+            repository,
+            injector,
+        }
+    }
+
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    async fn read<T>(&self, method: &'static str, call: impl Future<Output = DomainResult<T>>) -> DomainResult<T> {
+        self.disturb(method, false, call).await
+    }
+
+    async fn write<T>(&self, method: &'static str, call: impl Future<Output = DomainResult<T>>) -> DomainResult<T> {
+        self.disturb(method, true, call).await
+    }
+
+    async fn disturb<T>(
+        &self,
+        method: &'static str,
+        write: bool,
+        call: impl Future<Output = DomainResult<T>>,
+    ) -> DomainResult<T> {
+        match self.injector.disturb(self.repository, write).await {
+            Some(Fault::Unavailable) => Err(DomainError::database_unavailable(&format!(
+                "Injected fault: {}.{} lost its database connection",
+                self.repository, method
+            ))),
+            Some(Fault::Conflict) => Err(DomainError::unique_constraint(
+                "id",
+                &format!("Injected fault: {}.{} violated a unique constraint", self.repository, method),
+                None,
+            )),
+            None => call.await,
+        }
+    }
+}
+
+#[async_trait]
+impl<R: UserRepository> UserRepository for FaultInjected<R> {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<User>> {
+        self.read("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_email(&self, email: &str) -> DomainResult<Option<User>> {
+        self.read("find_by_email", self.inner.find_by_email(email)).await
+    }
+
+    async fn find_by_keycloak_id(&self, keycloak_id: &str) -> DomainResult<Option<User>> {
+        self.read("find_by_keycloak_id", self.inner.find_by_keycloak_id(keycloak_id)).await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<User>> {
+        self.read("find_by_ids", self.inner.find_by_ids(ids)).await
+    }
+
+    async fn create(&self, user: &User) -> DomainResult<()> {
+        self.write("create", self.inner.create(user)).await
+    }
+
+    async fn update(&self, user: &User) -> DomainResult<()> {
+        self.write("update", self.inner.update(user)).await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.write("delete", self.inner.delete(id)).await
+    }
+
+    async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<User>> {
+        self.read("list_all", self.inner.list_all(pagination)).await
+    }
+
+    async fn search(&self, query: &str, limit: i64) -> DomainResult<Vec<UserMatch>> {
+        self.read("search", self.inner.search(query, limit)).await
+    }
+
+    async fn exists(&self, id: Uuid) -> DomainResult<bool> {
+        self.read("exists", self.inner.exists(id)).await
+    }
+
+    async fn email_exists(&self, email: &str) -> DomainResult<bool> {
+        self.read("email_exists", self.inner.email_exists(email)).await
+    }
+}
+
+#[async_trait]
+impl<R: EventRepository> EventRepository for FaultInjected<R> {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Event>> {
+        self.read("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> DomainResult<Option<Event>> {
+        self.read("find_by_slug", self.inner.find_by_slug(slug)).await
+    }
+
+    async fn find_by_filter(
+        &self,
+        filter: &EventFilter,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<Event>> {
+        self.read("find_by_filter", self.inner.find_by_filter(filter, pagination)).await
+    }
+
+    async fn find_by_organizer(
+        &self,
+        organizer_id: Uuid,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<Event>> {
+        self.read("find_by_organizer", self.inner.find_by_organizer(organizer_id, pagination)).await
+    }
+
+    async fn find_by_category(&self, category_id: &str) -> DomainResult<Vec<Event>> {
+        self.read("find_by_category", self.inner.find_by_category(category_id)).await
+    }
+
+    async fn create(&self, event: &Event) -> DomainResult<()> {
+        self.write("create", self.inner.create(event)).await
+    }
+
+    async fn update(&self, event: &Event) -> DomainResult<()> {
+        self.write("update", self.inner.update(event)).await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.write("delete", self.inner.delete(id)).await
+    }
+
+    async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<Event>> {
+        self.read("list_all", self.inner.list_all(pagination)).await
+    }
+
+    async fn exists(&self, id: Uuid) -> DomainResult<bool> {
+        self.read("exists", self.inner.exists(id)).await
+    }
+
+    async fn find_due_for_publishing(&self, now: DateTime<Utc>) -> DomainResult<Vec<Event>> {
+        self.read("find_due_for_publishing", self.inner.find_due_for_publishing(now)).await
+    }
+}
+
+#[async_trait]
+impl<R: EventInvitationRepository> EventInvitationRepository for FaultInjected<R> {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventInvitation>> {
+        self.read("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventInvitation>> {
+        self.read("find_by_event_id", self.inner.find_by_event_id(event_id)).await
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> DomainResult<Vec<EventInvitation>> {
+        self.read("find_by_user_id", self.inner.find_by_user_id(user_id)).await
+    }
+
+    async fn find_by_token(&self, token: &str) -> DomainResult<Option<EventInvitation>> {
+        self.read("find_by_token", self.inner.find_by_token(token)).await
+    }
+
+    async fn find_by_email(&self, email: &str) -> DomainResult<Vec<EventInvitation>> {
+        self.read("find_by_email", self.inner.find_by_email(email)).await
+    }
+
+    async fn create(&self, invitation: &EventInvitation) -> DomainResult<()> {
+        self.write("create", self.inner.create(invitation)).await
+    }
+
+    async fn update(&self, invitation: &EventInvitation) -> DomainResult<()> {
+        self.write("update", self.inner.update(invitation)).await
+    }
+
+    async fn update_status(&self, invitation_id: Uuid, status: InvitationStatus) -> DomainResult<()> {
+        self.write("update_status", self.inner.update_status(invitation_id, status)).await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.write("delete", self.inner.delete(id)).await
+    }
+
+    async fn exists(&self, id: Uuid) -> DomainResult<bool> {
+        self.read("exists", self.inner.exists(id)).await
+    }
+
+    async fn user_invited_to_event(&self, user_id: Uuid, event_id: Uuid) -> DomainResult<bool> {
+        self.read("user_invited_to_event", self.inner.user_invited_to_event(user_id, event_id)).await
+    }
+
+    async fn email_invited_to_event(&self, email: &str, event_id: Uuid) -> DomainResult<bool> {
+        self.read("email_invited_to_event", self.inner.email_invited_to_event(email, event_id)).await
+    }
+}
+
+#[async_trait]
+impl<R: EventRegistrationRepository> EventRegistrationRepository for FaultInjected<R> {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventRegistration>> {
+        self.read("find_by_id", self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventRegistration>> {
+        self.read("find_by_event_id", self.inner.find_by_event_id(event_id)).await
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> DomainResult<Vec<EventRegistration>> {
+        self.read("find_by_user_id", self.inner.find_by_user_id(user_id)).await
+    }
+
+    async fn find_by_event_and_user(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Option<EventRegistration>> {
+        self.read("find_by_event_and_user", self.inner.find_by_event_and_user(event_id, user_id)).await
+    }
+
+    async fn count_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<RegistrationCounts>> {
+        self.read("count_by_event_ids", self.inner.count_by_event_ids(event_ids)).await
+    }
+
+    async fn create(&self, registration: &EventRegistration) -> DomainResult<()> {
+        self.write("create", self.inner.create(registration)).await
+    }
+
+    async fn create_within_capacity(&self, registration: &EventRegistration, capacity: i32) -> DomainResult<bool> {
+        self.write("create_within_capacity", self.inner.create_within_capacity(registration, capacity)).await
+    }
+
+    async fn update(&self, registration: &EventRegistration) -> DomainResult<()> {
+        self.write("update", self.inner.update(registration)).await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.write("delete", self.inner.delete(id)).await
+    }
+}
+
+#[async_trait]
+impl<R: EventSummaryRepository> EventSummaryRepository for FaultInjected<R> {
+    async fn find_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<EventSummary>> {
+        self.read("find_by_event_ids", self.inner.find_by_event_ids(event_ids)).await
+    }
+
+    async fn find_past(
+        &self,
+        filter: &PastEventFilter,
+        now: DateTime<Utc>,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<EventSummary>> {
+        self.read("find_past", self.inner.find_past(filter, now, pagination)).await
+    }
+
+    async fn yearly_attendance(
+        &self,
+        filter: &PastEventFilter,
+        grouping: ArchiveGrouping,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Vec<YearlyAttendance>> {
+        self.read("yearly_attendance", self.inner.yearly_attendance(filter, grouping, now)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::sqlite::SqliteUserRepository;
+    use crate::testing::TestDb;
+
+    #[test]
+    fn test_targets_match_repositories_and_destination_kinds() {
+        let plan = FaultPlan {
+            failure_rate: 1.0,
+            targets: vec!["registrations".to_string(), "webhook".to_string()],
+            ..FaultPlan::default()
+        };
+        assert!(plan.is_active());
+        assert!(plan.covers("registrations"));
+        assert!(plan.covers("webhook:hooks.example.no"));
+        assert!(!plan.covers("webhooks"));
+        assert!(!plan.covers("events"));
+        assert!(FaultPlan { targets: Vec::new(), ..plan }.covers("sendgrid"));
+        assert!(!FaultPlan::default().is_active());
+    }
+
+    #[tokio::test]
+    async fn test_faults_reach_callers_as_domain_errors() {
+        let db = TestDb::in_memory().await;
+        let user_id = db.seed_user().await;
+        let users = |plan: FaultPlan| {
+            FaultInjected::new(SqliteUserRepository::new(db.pool().clone()), "users", FaultInjector::new(plan))
+        };
+
+        let dropping = users(FaultPlan { failure_rate: 1.0, ..FaultPlan::default() });
+        assert!(matches!(
+            dropping.find_by_id(user_id).await,
+            Err(DomainError::SystemUnavailable { component: Some(ref component), .. }) if component == "database"
+        ));
+
+        // Constraint violations only hit writes
+        let conflicting = users(FaultPlan { conflict_rate: 1.0, ..FaultPlan::default() });
+        assert!(conflicting.find_by_id(user_id).await.unwrap().is_some());
+        assert!(matches!(conflicting.delete(user_id).await, Err(DomainError::ConflictError { .. })));
+        assert!(conflicting.inner().exists(user_id).await.unwrap());
+
+        // Other targets pass straight through
+        let elsewhere = users(FaultPlan { failure_rate: 1.0, targets: vec!["events".to_string()], ..FaultPlan::default() });
+        assert!(elsewhere.find_by_id(user_id).await.unwrap().is_some());
+    }
+}
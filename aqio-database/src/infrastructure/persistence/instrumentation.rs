@@ -0,0 +1,593 @@
+// Repository call timing. `Instrumented` wraps a repository, records how long
+// each of its methods takes in a shared `QueryMetrics`, and logs calls slower
+// than the threshold together with their arguments. Free text in those
+// arguments (emails, names, tokens, search terms) is never logged, only its
+// length.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use aqio_core::{
+    ArchiveGrouping, DomainResult, Event, EventFilter, EventInvitation, EventRegistration,
+    EventRegistrationRepository, EventInvitationRepository, EventRepository, EventSummary, EventSummaryRepository,
+    InvitationStatus, PaginatedResult, PaginationParams, PastEventFilter, RegistrationCounts, User, UserMatch,
+    UserRepository, YearlyAttendance,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Calls taking longer than this are logged when no threshold is configured
+pub const DEFAULT_SLOW_QUERY_THRESHOLD: Duration = Duration::from_millis(250);
+
+/// A repository method argument, as it may appear in the slow-call log
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryParam {
+    Id(Uuid),
+    Number(i64),
+    Flag(bool),
+    Time(DateTime<Utc>),
+    /// Free text; only the length is kept
+    Text(usize),
+    /// A list; only the length is kept
+    List(usize),
+    /// A record or filter, left out entirely
+    Redacted,
+}
+
+impl fmt::Display for QueryParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryParam::Id(id) => write!(f, "{}", id),
+            QueryParam::Number(number) => write!(f, "{}", number),
+            QueryParam::Flag(flag) => write!(f, "{}", flag),
+            QueryParam::Time(time) => write!(f, "{}", time.to_rfc3339()),
+            QueryParam::Text(chars) => write!(f, "<redacted, {} chars>", chars),
+            QueryParam::List(items) => write!(f, "<{} items>", items),
+            QueryParam::Redacted => write!(f, "<redacted>"),
+        }
+    }
+}
+
+impl From<Uuid> for QueryParam {
+    fn from(id: Uuid) -> Self {
+        QueryParam::Id(id)
+    }
+}
+
+impl From<i32> for QueryParam {
+    fn from(number: i32) -> Self {
+        QueryParam::Number(number.into())
+    }
+}
+
+impl From<i64> for QueryParam {
+    fn from(number: i64) -> Self {
+        QueryParam::Number(number)
+    }
+}
+
+impl From<bool> for QueryParam {
+    fn from(flag: bool) -> Self {
+        QueryParam::Flag(flag)
+    }
+}
+
+impl From<DateTime<Utc>> for QueryParam {
+    fn from(time: DateTime<Utc>) -> Self {
+        QueryParam::Time(time)
+    }
+}
+
+impl From<&str> for QueryParam {
+    fn from(text: &str) -> Self {
+        QueryParam::Text(text.chars().count())
+    }
+}
+
+impl<T> From<&[T]> for QueryParam {
+    fn from(items: &[T]) -> Self {
+        QueryParam::List(items.len())
+    }
+}
+
+/// Totals for one repository method since startup
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryMethodStats {
+    pub repository: &'static str,
+    pub method: &'static str,
+    pub calls: u64,
+    pub errors: u64,
+    /// Calls that took longer than the slow-query threshold
+    pub slow_calls: u64,
+    pub total_time: Duration,
+    pub max_time: Duration,
+}
+
+#[derive(Debug, Default)]
+struct MethodTotals {
+    calls: u64,
+    errors: u64,
+    slow_calls: u64,
+    total_time: Duration,
+    max_time: Duration,
+}
+
+/// Per-method call counts and timings, shared by every instrumented
+/// repository of a process
+#[derive(Debug, Clone)]
+pub struct QueryMetrics {
+    slow_threshold: Duration,
+    methods: Arc<Mutex<BTreeMap<(&'static str, &'static str), MethodTotals>>>,
+}
+
+impl QueryMetrics {
+    pub fn new(slow_threshold: Duration) -> Self {
+        Self {
+            slow_threshold,
+            methods: Arc::default(),
+        }
+    }
+
+    pub fn slow_threshold(&self) -> Duration {
+        self.slow_threshold
+    }
+
+    /// Adds one call to the method's totals; returns whether it was slow
+    pub fn record(&self, repository: &'static str, method: &'static str, elapsed: Duration, failed: bool) -> bool {
+        let slow = elapsed > self.slow_threshold;
+        let mut methods = self.methods.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let totals = methods.entry((repository, method)).or_default();
+        totals.calls += 1;
+        totals.errors += u64::from(failed);
+        totals.slow_calls += u64::from(slow);
+        totals.total_time += elapsed;
+        totals.max_time = totals.max_time.max(elapsed);
+        slow
+    }
+
+    /// Every method called so far, by repository and method name
+    pub fn snapshot(&self) -> Vec<QueryMethodStats> {
+        let methods = self.methods.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        methods
+            .iter()
+            .map(|(&(repository, method), totals)| QueryMethodStats {
+                repository,
+                method,
+                calls: totals.calls,
+                errors: totals.errors,
+                slow_calls: totals.slow_calls,
+                total_time: totals.total_time,
+                max_time: totals.max_time,
+            })
+            .collect()
+    }
+}
+
+impl Default for QueryMetrics {
+    fn default() -> Self {
+        Self::new(DEFAULT_SLOW_QUERY_THRESHOLD)
+    }
+}
+
+/// A repository whose calls are timed into `QueryMetrics`
+///
+/// Implements the same port as the repository it wraps, so services don't
+/// know it is there.
+#[derive(Clone)]
+pub struct Instrumented<R> {
+    inner: R,
+    repository: &'static str,
+    metrics: QueryMetrics,
+}
+
+impl<R> Instrumented<R> {
+    /// `repository` names the wrapped repository in logs and metrics, e.g. `events`
+    pub fn new(inner: R, repository: &'static str, metrics: QueryMetrics) -> Self {
+        Self {
+            inner,
+            repository,
+            metrics,
+        }
+    }
+
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    async fn observe<T>(
+        &self,
+        method: &'static str,
+        params: &[(&'static str, QueryParam)],
+        call: impl Future<Output = DomainResult<T>>,
+    ) -> DomainResult<T> {
+        let started = Instant::now();
+        let result = call.await;
+        let elapsed = started.elapsed();
+
+        if self.metrics.record(self.repository, method, elapsed, result.is_err()) {
+            let params = params
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect::<Vec<_>>()
+                .join(", ");
+            tracing::warn!(
+                repository = self.repository,
+                method,
+                elapsed_ms = elapsed.as_millis() as u64,
+                threshold_ms = self.metrics.slow_threshold().as_millis() as u64,
+                params = %params,
+                "Slow repository call"
+            );
+        }
+        result
+    }
+}
+
+fn pagination_params(pagination: &PaginationParams) -> [(&'static str, QueryParam); 2] {
+    [
+        ("offset", pagination.offset.into()),
+        ("limit", pagination.limit.into()),
+    ]
+}
+
+#[async_trait]
+impl<R: UserRepository> UserRepository for Instrumented<R> {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<User>> {
+        self.observe("find_by_id", &[("id", id.into())], self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_email(&self, email: &str) -> DomainResult<Option<User>> {
+        self.observe("find_by_email", &[("email", email.into())], self.inner.find_by_email(email)).await
+    }
+
+    async fn find_by_keycloak_id(&self, keycloak_id: &str) -> DomainResult<Option<User>> {
+        self.observe(
+            "find_by_keycloak_id",
+            &[("keycloak_id", keycloak_id.into())],
+            self.inner.find_by_keycloak_id(keycloak_id),
+        )
+        .await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<User>> {
+        self.observe("find_by_ids", &[("ids", ids.into())], self.inner.find_by_ids(ids)).await
+    }
+
+    async fn create(&self, user: &User) -> DomainResult<()> {
+        self.observe("create", &[("id", user.id.into())], self.inner.create(user)).await
+    }
+
+    async fn update(&self, user: &User) -> DomainResult<()> {
+        self.observe("update", &[("id", user.id.into())], self.inner.update(user)).await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.observe("delete", &[("id", id.into())], self.inner.delete(id)).await
+    }
+
+    async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<User>> {
+        self.observe("list_all", &pagination_params(&pagination), self.inner.list_all(pagination)).await
+    }
+
+    async fn search(&self, query: &str, limit: i64) -> DomainResult<Vec<UserMatch>> {
+        self.observe(
+            "search",
+            &[("query", query.into()), ("limit", limit.into())],
+            self.inner.search(query, limit),
+        )
+        .await
+    }
+
+    async fn exists(&self, id: Uuid) -> DomainResult<bool> {
+        self.observe("exists", &[("id", id.into())], self.inner.exists(id)).await
+    }
+
+    async fn email_exists(&self, email: &str) -> DomainResult<bool> {
+        self.observe("email_exists", &[("email", email.into())], self.inner.email_exists(email)).await
+    }
+}
+
+#[async_trait]
+impl<R: EventRepository> EventRepository for Instrumented<R> {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Event>> {
+        self.observe("find_by_id", &[("id", id.into())], self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> DomainResult<Option<Event>> {
+        self.observe("find_by_slug", &[("slug", slug.into())], self.inner.find_by_slug(slug)).await
+    }
+
+    async fn find_by_filter(
+        &self,
+        filter: &EventFilter,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<Event>> {
+        let [offset, limit] = pagination_params(&pagination);
+        self.observe(
+            "find_by_filter",
+            &[("filter", QueryParam::Redacted), offset, limit],
+            self.inner.find_by_filter(filter, pagination),
+        )
+        .await
+    }
+
+    async fn find_by_organizer(
+        &self,
+        organizer_id: Uuid,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<Event>> {
+        let [offset, limit] = pagination_params(&pagination);
+        self.observe(
+            "find_by_organizer",
+            &[("organizer_id", organizer_id.into()), offset, limit],
+            self.inner.find_by_organizer(organizer_id, pagination),
+        )
+        .await
+    }
+
+    async fn find_by_category(&self, category_id: &str) -> DomainResult<Vec<Event>> {
+        self.observe(
+            "find_by_category",
+            &[("category_id", category_id.into())],
+            self.inner.find_by_category(category_id),
+        )
+        .await
+    }
+
+    async fn create(&self, event: &Event) -> DomainResult<()> {
+        self.observe("create", &[("id", event.id.into())], self.inner.create(event)).await
+    }
+
+    async fn update(&self, event: &Event) -> DomainResult<()> {
+        self.observe("update", &[("id", event.id.into())], self.inner.update(event)).await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.observe("delete", &[("id", id.into())], self.inner.delete(id)).await
+    }
+
+    async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<Event>> {
+        self.observe("list_all", &pagination_params(&pagination), self.inner.list_all(pagination)).await
+    }
+
+    async fn exists(&self, id: Uuid) -> DomainResult<bool> {
+        self.observe("exists", &[("id", id.into())], self.inner.exists(id)).await
+    }
+
+    async fn find_due_for_publishing(&self, now: DateTime<Utc>) -> DomainResult<Vec<Event>> {
+        self.observe(
+            "find_due_for_publishing",
+            &[("now", now.into())],
+            self.inner.find_due_for_publishing(now),
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl<R: EventInvitationRepository> EventInvitationRepository for Instrumented<R> {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventInvitation>> {
+        self.observe("find_by_id", &[("id", id.into())], self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventInvitation>> {
+        self.observe(
+            "find_by_event_id",
+            &[("event_id", event_id.into())],
+            self.inner.find_by_event_id(event_id),
+        )
+        .await
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> DomainResult<Vec<EventInvitation>> {
+        self.observe(
+            "find_by_user_id",
+            &[("user_id", user_id.into())],
+            self.inner.find_by_user_id(user_id),
+        )
+        .await
+    }
+
+    async fn find_by_token(&self, token: &str) -> DomainResult<Option<EventInvitation>> {
+        self.observe("find_by_token", &[("token", token.into())], self.inner.find_by_token(token)).await
+    }
+
+    async fn find_by_email(&self, email: &str) -> DomainResult<Vec<EventInvitation>> {
+        self.observe("find_by_email", &[("email", email.into())], self.inner.find_by_email(email)).await
+    }
+
+    async fn create(&self, invitation: &EventInvitation) -> DomainResult<()> {
+        self.observe("create", &[("id", invitation.id.into())], self.inner.create(invitation)).await
+    }
+
+    async fn update(&self, invitation: &EventInvitation) -> DomainResult<()> {
+        self.observe("update", &[("id", invitation.id.into())], self.inner.update(invitation)).await
+    }
+
+    async fn update_status(&self, invitation_id: Uuid, status: InvitationStatus) -> DomainResult<()> {
+        self.observe(
+            "update_status",
+            &[("invitation_id", invitation_id.into())],
+            self.inner.update_status(invitation_id, status),
+        )
+        .await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.observe("delete", &[("id", id.into())], self.inner.delete(id)).await
+    }
+
+    async fn exists(&self, id: Uuid) -> DomainResult<bool> {
+        self.observe("exists", &[("id", id.into())], self.inner.exists(id)).await
+    }
+
+    async fn user_invited_to_event(&self, user_id: Uuid, event_id: Uuid) -> DomainResult<bool> {
+        self.observe(
+            "user_invited_to_event",
+            &[("user_id", user_id.into()), ("event_id", event_id.into())],
+            self.inner.user_invited_to_event(user_id, event_id),
+        )
+        .await
+    }
+
+    async fn email_invited_to_event(&self, email: &str, event_id: Uuid) -> DomainResult<bool> {
+        self.observe(
+            "email_invited_to_event",
+            &[("email", email.into()), ("event_id", event_id.into())],
+            self.inner.email_invited_to_event(email, event_id),
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl<R: EventRegistrationRepository> EventRegistrationRepository for Instrumented<R> {
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventRegistration>> {
+        self.observe("find_by_id", &[("id", id.into())], self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventRegistration>> {
+        self.observe(
+            "find_by_event_id",
+            &[("event_id", event_id.into())],
+            self.inner.find_by_event_id(event_id),
+        )
+        .await
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> DomainResult<Vec<EventRegistration>> {
+        self.observe(
+            "find_by_user_id",
+            &[("user_id", user_id.into())],
+            self.inner.find_by_user_id(user_id),
+        )
+        .await
+    }
+
+    async fn find_by_event_and_user(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Option<EventRegistration>> {
+        self.observe(
+            "find_by_event_and_user",
+            &[("event_id", event_id.into()), ("user_id", user_id.into())],
+            self.inner.find_by_event_and_user(event_id, user_id),
+        )
+        .await
+    }
+
+    async fn count_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<RegistrationCounts>> {
+        self.observe(
+            "count_by_event_ids",
+            &[("event_ids", event_ids.into())],
+            self.inner.count_by_event_ids(event_ids),
+        )
+        .await
+    }
+
+    async fn create(&self, registration: &EventRegistration) -> DomainResult<()> {
+        self.observe("create", &[("id", registration.id.into())], self.inner.create(registration)).await
+    }
+
+    async fn create_within_capacity(&self, registration: &EventRegistration, capacity: i32) -> DomainResult<bool> {
+        self.observe(
+            "create_within_capacity",
+            &[("id", registration.id.into()), ("capacity", capacity.into())],
+            self.inner.create_within_capacity(registration, capacity),
+        )
+        .await
+    }
+
+    async fn update(&self, registration: &EventRegistration) -> DomainResult<()> {
+        self.observe("update", &[("id", registration.id.into())], self.inner.update(registration)).await
+    }
+
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        self.observe("delete", &[("id", id.into())], self.inner.delete(id)).await
+    }
+}
+
+#[async_trait]
+impl<R: EventSummaryRepository> EventSummaryRepository for Instrumented<R> {
+    async fn find_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<EventSummary>> {
+        self.observe(
+            "find_by_event_ids",
+            &[("event_ids", event_ids.into())],
+            self.inner.find_by_event_ids(event_ids),
+        )
+        .await
+    }
+
+    async fn find_past(
+        &self,
+        filter: &PastEventFilter,
+        now: DateTime<Utc>,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<EventSummary>> {
+        let [offset, limit] = pagination_params(&pagination);
+        self.observe(
+            "find_past",
+            &[("filter", QueryParam::Redacted), ("now", now.into()), offset, limit],
+            self.inner.find_past(filter, now, pagination),
+        )
+        .await
+    }
+
+    async fn yearly_attendance(
+        &self,
+        filter: &PastEventFilter,
+        grouping: ArchiveGrouping,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Vec<YearlyAttendance>> {
+        self.observe(
+            "yearly_attendance",
+            &[("filter", QueryParam::Redacted), ("now", now.into())],
+            self.inner.yearly_attendance(filter, grouping, now),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::sqlite::SqliteUserRepository;
+    use crate::testing::TestDb;
+
+    #[test]
+    fn test_params_never_show_free_text() {
+        let id = Uuid::new_v4();
+        assert_eq!(QueryParam::from(id).to_string(), id.to_string());
+        assert_eq!(QueryParam::from("kari@example.no").to_string(), "<redacted, 15 chars>");
+        assert_eq!(QueryParam::from(&[id, id][..]).to_string(), "<2 items>");
+        assert_eq!(QueryParam::Redacted.to_string(), "<redacted>");
+    }
+
+    #[tokio::test]
+    async fn test_calls_are_counted_per_method() {
+        let db = TestDb::in_memory().await;
+        let user_id = db.seed_user().await;
+        // Every call counts as slow with a zero threshold
+        let metrics = QueryMetrics::new(Duration::ZERO);
+        let users = Instrumented::new(SqliteUserRepository::new(db.pool().clone()), "users", metrics.clone());
+
+        assert!(users.find_by_id(user_id).await.unwrap().is_some());
+        assert!(users.find_by_id(Uuid::new_v4()).await.unwrap().is_none());
+        assert!(users.email_exists("nobody@example.no").await.is_ok());
+
+        let stats = metrics.snapshot();
+        assert_eq!(stats.len(), 2);
+        let find_by_id = stats.iter().find(|s| s.method == "find_by_id").unwrap();
+        assert_eq!(find_by_id.repository, "users");
+        assert_eq!(find_by_id.calls, 2);
+        assert_eq!(find_by_id.slow_calls, 2);
+        assert_eq!(find_by_id.errors, 0);
+        assert!(find_by_id.max_time <= find_by_id.total_time);
+
+        let relaxed = QueryMetrics::new(Duration::from_secs(60));
+        assert!(!relaxed.record("users", "find_by_id", Duration::from_millis(5), true));
+        assert_eq!(relaxed.snapshot()[0].errors, 1);
+        assert_eq!(relaxed.snapshot()[0].slow_calls, 0);
+    }
+}
@@ -49,6 +49,251 @@ pub fn invitation_status_to_string(status: &InvitationStatus) -> String {
     }
 }
 
+pub fn delivery_failure_to_string(failure: DeliveryFailure) -> &'static str {
+    match failure {
+        DeliveryFailure::Bounce => "bounce",
+        DeliveryFailure::Complaint => "complaint",
+    }
+}
+
+pub fn digest_frequency_to_string(frequency: DigestFrequency) -> &'static str {
+    match frequency {
+        DigestFrequency::Immediate => "immediate",
+        DigestFrequency::Daily => "daily",
+        DigestFrequency::Weekly => "weekly",
+    }
+}
+
+pub fn locale_to_string(locale: Locale) -> &'static str {
+    match locale {
+        Locale::En => "en",
+        Locale::Nb => "nb",
+    }
+}
+
+pub fn theme_to_string(theme: Theme) -> &'static str {
+    match theme {
+        Theme::Light => "light",
+        Theme::Dark => "dark",
+    }
+}
+
+pub fn calendar_view_to_string(view: CalendarView) -> &'static str {
+    match view {
+        CalendarView::Month => "month",
+        CalendarView::Week => "week",
+        CalendarView::Day => "day",
+    }
+}
+
+pub fn organization_invitation_status_to_string(status: OrganizationInvitationStatus) -> &'static str {
+    match status {
+        OrganizationInvitationStatus::Pending => "pending",
+        OrganizationInvitationStatus::Accepted => "accepted",
+        OrganizationInvitationStatus::Revoked => "revoked",
+    }
+}
+
+pub fn ownership_transfer_status_to_string(status: OwnershipTransferStatus) -> &'static str {
+    match status {
+        OwnershipTransferStatus::Pending => "pending",
+        OwnershipTransferStatus::Accepted => "accepted",
+        OwnershipTransferStatus::Declined => "declined",
+        OwnershipTransferStatus::Cancelled => "cancelled",
+    }
+}
+
+pub fn event_submission_status_to_string(status: EventSubmissionStatus) -> &'static str {
+    match status {
+        EventSubmissionStatus::Pending => "pending",
+        EventSubmissionStatus::Approved => "approved",
+        EventSubmissionStatus::Rejected => "rejected",
+    }
+}
+
+pub fn report_target_to_string(target: ReportTarget) -> &'static str {
+    match target {
+        ReportTarget::Comment => "comment",
+        ReportTarget::Event => "event",
+        ReportTarget::Profile => "profile",
+    }
+}
+
+pub fn report_reason_to_string(reason: ReportReason) -> &'static str {
+    match reason {
+        ReportReason::Spam => "spam",
+        ReportReason::Harassment => "harassment",
+        ReportReason::Inappropriate => "inappropriate",
+        ReportReason::Other => "other",
+    }
+}
+
+pub fn report_status_to_string(status: ReportStatus) -> &'static str {
+    match status {
+        ReportStatus::Open => "open",
+        ReportStatus::Actioned => "actioned",
+        ReportStatus::Dismissed => "dismissed",
+    }
+}
+
+pub fn moderation_action_to_string(action: ModerationAction) -> &'static str {
+    match action {
+        ModerationAction::Hide => "hide",
+        ModerationAction::Warn => "warn",
+        ModerationAction::Ban => "ban",
+        ModerationAction::Dismiss => "dismiss",
+    }
+}
+
+pub fn consent_kind_to_string(kind: ConsentKind) -> &'static str {
+    match kind {
+        ConsentKind::Photo => "photo",
+        ConsentKind::DataProcessing => "data_processing",
+        ConsentKind::Marketing => "marketing",
+        ConsentKind::LeadSharing => "lead_sharing",
+    }
+}
+
+pub fn broadcast_audience_to_string(audience: BroadcastAudience) -> &'static str {
+    match audience {
+        BroadcastAudience::All => "all",
+        BroadcastAudience::Confirmed => "confirmed",
+        BroadcastAudience::Waitlisted => "waitlisted",
+        BroadcastAudience::CheckedIn => "checked_in",
+    }
+}
+
+pub fn broadcast_status_to_string(status: BroadcastStatus) -> &'static str {
+    match status {
+        BroadcastStatus::Scheduled => "scheduled",
+        BroadcastStatus::Sent => "sent",
+        BroadcastStatus::Cancelled => "cancelled",
+    }
+}
+
+pub fn photo_status_to_string(status: PhotoStatus) -> &'static str {
+    match status {
+        PhotoStatus::Pending => "pending",
+        PhotoStatus::Approved => "approved",
+        PhotoStatus::Rejected => "rejected",
+    }
+}
+
+pub fn event_field_type_to_string(field_type: EventFieldType) -> &'static str {
+    match field_type {
+        EventFieldType::Text => "text",
+        EventFieldType::Number => "number",
+        EventFieldType::Date => "date",
+        EventFieldType::Boolean => "boolean",
+        EventFieldType::Select => "select",
+    }
+}
+
+pub fn currency_to_string(currency: Currency) -> &'static str {
+    currency.code()
+}
+
+pub fn order_status_to_string(status: OrderStatus) -> &'static str {
+    match status {
+        OrderStatus::Confirmed => "confirmed",
+        OrderStatus::Cancelled => "cancelled",
+    }
+}
+
+pub fn invoice_status_to_string(status: InvoiceStatus) -> &'static str {
+    match status {
+        InvoiceStatus::Issued => "issued",
+        InvoiceStatus::Paid => "paid",
+        InvoiceStatus::Voided => "voided",
+    }
+}
+
+pub fn refund_status_to_string(status: RefundStatus) -> &'static str {
+    match status {
+        RefundStatus::Pending => "pending",
+        RefundStatus::Succeeded => "succeeded",
+        RefundStatus::Failed => "failed",
+    }
+}
+
+pub fn meeting_status_to_string(status: MeetingStatus) -> &'static str {
+    match status {
+        MeetingStatus::Requested => "requested",
+        MeetingStatus::Accepted => "accepted",
+        MeetingStatus::Declined => "declined",
+        MeetingStatus::Cancelled => "cancelled",
+    }
+}
+
+pub fn web_vital_metric_to_string(metric: WebVitalMetric) -> &'static str {
+    match metric {
+        WebVitalMetric::Lcp => "lcp",
+        WebVitalMetric::Fid => "fid",
+        WebVitalMetric::Inp => "inp",
+        WebVitalMetric::Cls => "cls",
+        WebVitalMetric::ApiLatency => "api_latency",
+    }
+}
+
+pub fn export_entity_to_string(entity: ExportEntity) -> &'static str {
+    match entity {
+        ExportEntity::Registrations => "registrations",
+        ExportEntity::Events => "events",
+    }
+}
+
+pub fn export_format_to_string(format: ExportFormat) -> &'static str {
+    match format {
+        ExportFormat::Csv => "csv",
+        ExportFormat::Json => "json",
+    }
+}
+
+pub fn export_run_status_to_string(status: ExportRunStatus) -> &'static str {
+    match status {
+        ExportRunStatus::Succeeded => "succeeded",
+        ExportRunStatus::Failed => "failed",
+    }
+}
+
+pub fn crm_provider_to_string(provider: CrmProvider) -> &'static str {
+    match provider {
+        CrmProvider::HubSpot => "hubspot",
+        CrmProvider::Dynamics => "dynamics",
+    }
+}
+
+pub fn crm_conflict_policy_to_string(policy: CrmConflictPolicy) -> &'static str {
+    match policy {
+        CrmConflictPolicy::AqioWins => "aqio_wins",
+        CrmConflictPolicy::CrmWins => "crm_wins",
+        CrmConflictPolicy::FillBlanks => "fill_blanks",
+    }
+}
+
+pub fn crm_sync_run_status_to_string(status: CrmSyncRunStatus) -> &'static str {
+    match status {
+        CrmSyncRunStatus::Succeeded => "succeeded",
+        CrmSyncRunStatus::PartiallyFailed => "partially_failed",
+        CrmSyncRunStatus::Failed => "failed",
+    }
+}
+
+pub fn crm_record_kind_to_string(kind: CrmRecordKind) -> &'static str {
+    match kind {
+        CrmRecordKind::Contact => "contact",
+        CrmRecordKind::Registration => "registration",
+    }
+}
+
+pub fn crm_sync_state_to_string(state: CrmSyncState) -> &'static str {
+    match state {
+        CrmSyncState::Synced => "synced",
+        CrmSyncState::Conflict => "conflict",
+        CrmSyncState::Failed => "failed",
+    }
+}
+
 pub fn map_invitation_method(method_str: &str) -> InvitationMethod {
     match method_str {
         "email" => InvitationMethod::Email,
@@ -77,6 +322,23 @@ pub fn map_user_role(role_str: &str) -> UserRole {
     }
 }
 
+pub fn map_theme(theme_str: &str) -> Option<Theme> {
+    match theme_str {
+        "light" => Some(Theme::Light),
+        "dark" => Some(Theme::Dark),
+        _ => None,
+    }
+}
+
+pub fn map_calendar_view(view_str: &str) -> Option<CalendarView> {
+    match view_str {
+        "month" => Some(CalendarView::Month),
+        "week" => Some(CalendarView::Week),
+        "day" => Some(CalendarView::Day),
+        _ => None,
+    }
+}
+
 pub fn user_role_to_string(role: &UserRole) -> String {
     match role {
         UserRole::Admin => "admin".to_string(),
@@ -85,6 +347,14 @@ pub fn user_role_to_string(role: &UserRole) -> String {
     }
 }
 
+pub fn audit_action_to_string(action: &AuditAction) -> String {
+    match action {
+        AuditAction::Insert => "insert".to_string(),
+        AuditAction::Update => "update".to_string(),
+        AuditAction::Delete => "delete".to_string(),
+    }
+}
+
 // Database row structures for type-safe mapping
 #[derive(Debug)]
 pub struct EventRow {
@@ -108,11 +378,15 @@ impl TryFrom<EventRow> for Event {
     type Error = InfrastructureError;
 
     fn try_from(row: EventRow) -> InfrastructureResult<Self> {
+        let id = parse_uuid(row.id.as_deref().unwrap_or(""))?;
         Ok(Event {
-            id: parse_uuid(row.id.as_deref().unwrap_or(""))?,
+            id,
+            slug: Event::slug_for(&row.title, id),
             title: row.title,
             description: row.description,
-            category_id: "general".to_string(), // TODO: Add category_id to EventRow
+            category_id: EventCategory::id_for_legacy_type(&row.event_type)
+                .unwrap_or(EventCategory::LEGACY_FALLBACK_ID)
+                .to_string(),
             start_date: datetime_from_naive(row.start_date),
             end_date: datetime_from_naive(row.end_date),
             timezone: "UTC".to_string(), // TODO: Add timezone to EventRow
@@ -121,6 +395,9 @@ impl TryFrom<EventRow> for Event {
             address: None, // Will be properly mapped when all fields are available
             virtual_link: None,
             virtual_access_code: None,
+            latitude: None,
+            longitude: None,
+            region: None,
             organizer_id: parse_uuid(&row.organizer_id)?,
             co_organizers: Vec::new(), // Will be properly mapped when JSON field is available
             is_private: row.is_private,
@@ -138,6 +415,7 @@ impl TryFrom<EventRow> for Event {
             image_url: None,
             custom_fields: None,
             status: EventStatus::Draft, // Default value
+            publish_at: None,
             created_at: datetime_from_naive(row.created_at),
             updated_at: datetime_from_naive(row.updated_at),
         })
@@ -153,6 +431,10 @@ pub struct UserRow {
     pub company_id: Option<String>,
     pub role: String,
     pub is_active: bool,
+    pub timezone: Option<String>,
+    pub locale: Option<String>,
+    pub theme: Option<String>,
+    pub calendar_view: Option<String>,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
@@ -169,6 +451,10 @@ impl TryFrom<UserRow> for User {
             company_id: parse_optional_uuid(row.company_id.as_ref())?,
             role: map_user_role(&row.role),
             is_active: row.is_active,
+            timezone: row.timezone,
+            locale: row.locale.as_deref().and_then(Locale::from_tag),
+            theme: row.theme.as_deref().and_then(map_theme),
+            calendar_view: row.calendar_view.as_deref().and_then(map_calendar_view),
             created_at: datetime_from_naive(row.created_at),
             updated_at: datetime_from_naive(row.updated_at),
         })
@@ -206,7 +492,10 @@ impl TryFrom<InvitationRow> for EventInvitation {
             status: map_invitation_status(&row.status),
             sent_at: Some(datetime_from_naive(row.invited_at)),
             opened_at: None, // TODO: Add opened_at to EventInvitationRow
+            clicked_at: None,
             responded_at: optional_datetime_from_naive(row.responded_at),
+            delivery_failure: None,
+            delivery_failed_at: None,
             invitation_token: None, // TODO: Add invitation_token to EventInvitationRow  
             expires_at: None, // TODO: Add expires_at to EventInvitationRow
             created_at: datetime_from_naive(row.invited_at), // Use invited_at as created_at for now
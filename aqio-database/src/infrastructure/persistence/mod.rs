@@ -1,3 +1,5 @@
+pub mod faults;
+pub mod instrumentation;
 pub mod mapping;
 // pub mod memory;  // TODO: Implement in-memory adapter for testing
 pub mod sqlite;
@@ -0,0 +1,223 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::AccessGrantRepository,
+};
+use crate::infrastructure::persistence::sqlite::organization_role_repository::parse_permissions;
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{AccessGrant, DomainResult};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+// Permissions come along as a comma-separated list, one row per grant
+const GRANT_SELECT: &str = "SELECT g.id, g.event_id, g.user_id, g.granted_by, g.starts_at, g.expires_at, \
+     g.revoked_at, g.created_at, GROUP_CONCAT(p.permission) AS permissions \
+     FROM access_grants g LEFT JOIN access_grant_permissions p ON p.grant_id = g.id";
+
+#[derive(Clone)]
+pub struct SqliteAccessGrantRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteAccessGrantRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to AccessGrant using SafeRowGet
+    fn row_to_grant(row: &sqlx::sqlite::SqliteRow) -> Result<AccessGrant, RowConversionError> {
+        Ok(AccessGrant {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            user_id: row.get_uuid("user_id")?,
+            granted_by: row.get_uuid("granted_by")?,
+            permissions: parse_permissions(row.get_optional_string("permissions")?)?,
+            starts_at: row.get_datetime("starts_at")?,
+            expires_at: row.get_datetime("expires_at")?,
+            revoked_at: row.get_optional_datetime("revoked_at")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+
+    fn rows_to_grants(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<AccessGrant>> {
+        rows.iter()
+            .map(|row| Self::row_to_grant(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AccessGrantRepository for SqliteAccessGrantRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<AccessGrant>> {
+        debug!("Finding access grant by ID: {}", id);
+
+        let row = sqlx::query(&format!("{} WHERE g.id = ? GROUP BY g.id", GRANT_SELECT))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_grant(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event(&self, event_id: Uuid) -> DomainResult<Vec<AccessGrant>> {
+        debug!("Listing access grants for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "{} WHERE g.event_id = ? GROUP BY g.id ORDER BY g.starts_at DESC, g.rowid DESC",
+            GRANT_SELECT
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_grants(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_active_for_user(&self, user_id: Uuid, now: DateTime<Utc>) -> DomainResult<Vec<AccessGrant>> {
+        debug!("Listing active access grants of user: {}", user_id);
+
+        let rows = sqlx::query(&format!(
+            "{} WHERE g.user_id = ? AND g.revoked_at IS NULL AND g.starts_at <= ? AND g.expires_at > ? \
+             GROUP BY g.id ORDER BY g.starts_at",
+            GRANT_SELECT
+        ))
+        .bind(user_id.to_string())
+        .bind(now.naive_utc())
+        .bind(now.naive_utc())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_grants(&rows)
+    }
+
+    #[instrument(skip(self, grant))]
+    async fn create(&self, grant: &AccessGrant) -> DomainResult<()> {
+        debug!("Granting user {} access to event {}", grant.user_id, grant.event_id);
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        sqlx::query(
+            "INSERT INTO access_grants (id, event_id, user_id, granted_by, starts_at, expires_at, revoked_at, created_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(grant.id.to_string())
+        .bind(grant.event_id.to_string())
+        .bind(grant.user_id.to_string())
+        .bind(grant.granted_by.to_string())
+        .bind(grant.starts_at.naive_utc())
+        .bind(grant.expires_at.naive_utc())
+        .bind(grant.revoked_at.map(|dt| dt.naive_utc()))
+        .bind(grant.created_at.naive_utc())
+        .execute(&mut *tx)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        for permission in grant.permissions.iter() {
+            sqlx::query("INSERT INTO access_grant_permissions (grant_id, permission) VALUES (?, ?)")
+                .bind(grant.id.to_string())
+                .bind(permission.code())
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn revoke(&self, id: Uuid, revoked_at: DateTime<Utc>) -> DomainResult<()> {
+        debug!("Revoking access grant: {}", id);
+
+        let result = sqlx::query("UPDATE access_grants SET revoked_at = ? WHERE id = ?")
+            .bind(revoked_at.naive_utc())
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("AccessGrant", id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::{Permission, PermissionSet};
+    use chrono::Duration;
+
+    fn check_in() -> PermissionSet {
+        [Permission::RegistrationCheckIn].into_iter().collect()
+    }
+
+    #[tokio::test]
+    async fn test_grants_are_active_between_start_and_expiry() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteAccessGrantRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let helper = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+        let event_day = Utc::now() + Duration::days(7);
+
+        let grant = AccessGrant::new(event, helper, organizer, check_in(), event_day, event_day + Duration::hours(12));
+        let earlier = AccessGrant::new(
+            event,
+            helper,
+            organizer,
+            [Permission::RegistrationApprove, Permission::InvitationSend].into_iter().collect(),
+            event_day - Duration::days(3),
+            event_day - Duration::days(2),
+        );
+        repository.create(&earlier).await.unwrap();
+        repository.create(&grant).await.unwrap();
+
+        let found = repository.find_by_id(earlier.id).await.unwrap().unwrap();
+        assert_eq!(found.permissions, earlier.permissions);
+        assert_eq!(found.granted_by, organizer);
+        assert!(found.revoked_at.is_none());
+
+        let listed = repository.find_by_event(event).await.unwrap();
+        assert_eq!(listed.iter().map(|g| g.id).collect::<Vec<_>>(), vec![grant.id, earlier.id]);
+
+        let active = |at| repository.find_active_for_user(helper, at);
+        assert!(active(event_day - Duration::hours(1)).await.unwrap().is_empty());
+        let during = active(event_day + Duration::hours(1)).await.unwrap();
+        assert_eq!(during.len(), 1);
+        assert_eq!(during[0].permissions, check_in());
+        assert!(active(event_day + Duration::hours(12)).await.unwrap().is_empty());
+        assert!(repository.find_active_for_user(organizer, event_day).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_revoked_grants_stop_applying() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteAccessGrantRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let helper = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+        let now = Utc::now();
+
+        let grant = AccessGrant::new(event, helper, organizer, check_in(), now - Duration::hours(1), now + Duration::hours(1));
+        repository.create(&grant).await.unwrap();
+        assert_eq!(repository.find_active_for_user(helper, now).await.unwrap().len(), 1);
+
+        repository.revoke(grant.id, now).await.unwrap();
+        assert!(repository.find_active_for_user(helper, now).await.unwrap().is_empty());
+        assert!(repository.find_by_id(grant.id).await.unwrap().unwrap().revoked_at.is_some());
+        assert!(repository.revoke(Uuid::new_v4(), now).await.is_err());
+    }
+}
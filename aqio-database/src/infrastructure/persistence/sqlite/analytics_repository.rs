@@ -0,0 +1,232 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::AnalyticsRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{CategoryAttendance, DomainResult, OrganizationYear, SurveySatisfaction};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite, sqlite::SqliteRow};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+// The organization's past events in one year. An event belongs to the
+// organization of its organizer, as for storage quotas; binds the company ID,
+// `now` and the year.
+const YEAR_EVENTS: &str = "\
+    WITH year_events AS ( \
+        SELECT s.* FROM event_summaries s JOIN users u ON u.id = s.organizer_id \
+        WHERE u.company_id = ? AND s.status NOT IN ('draft', 'cancelled') AND s.end_date < ? \
+        AND CAST(strftime('%Y', s.start_date) AS INTEGER) = ? \
+    ) ";
+
+const TOTALS: &str = "\
+    SELECT COUNT(*) AS events, COALESCE(SUM(registered_count + attended_count), 0) AS registrations, \
+        COALESCE(SUM(checked_in_count), 0) AS checked_in, COALESCE(SUM(cancelled_count), 0) AS cancelled \
+    FROM year_events";
+
+const CATEGORIES: &str = "\
+    SELECT category_id, MAX(category_name) AS category_name, COUNT(*) AS events, \
+        SUM(registered_count + attended_count) AS registrations, SUM(checked_in_count) AS checked_in \
+    FROM year_events GROUP BY category_id ORDER BY checked_in DESC, events DESC, category_id";
+
+const SATISFACTION: &str = "\
+    SELECT COUNT(*) AS responses, AVG(r.overall_rating) AS average_rating, \
+        AVG(r.would_recommend) AS recommend_share, AVG(r.likelihood_to_attend_again) AS average_return_likelihood \
+    FROM survey_responses r JOIN event_surveys v ON v.id = r.survey_id \
+    WHERE v.event_id IN (SELECT event_id FROM year_events)";
+
+/// Reporting figures over the `event_summaries` read model and the survey
+/// responses of migration 004
+#[derive(Clone)]
+pub struct SqliteAnalyticsRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteAnalyticsRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    async fn fetch_year(&self, select: &str, company_id: Uuid, year: i32, now: DateTime<Utc>) -> Result<Vec<SqliteRow>, sqlx::Error> {
+        let query = format!("{}{}", YEAR_EVENTS, select);
+        sqlx::query(&query)
+            .bind(company_id.to_string())
+            .bind(now.naive_utc())
+            .bind(year)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    // Helper method to convert a per-category row to CategoryAttendance using SafeRowGet
+    fn row_to_category(row: &SqliteRow) -> Result<CategoryAttendance, RowConversionError> {
+        Ok(CategoryAttendance {
+            category_id: row.get_string("category_id")?,
+            category_name: row.get_optional_string("category_name")?,
+            events: row.get_i64("events")?,
+            registrations: row.get_i64("registrations")?,
+            checked_in: row.get_i64("checked_in")?,
+        })
+    }
+
+    fn row_to_satisfaction(row: &SqliteRow) -> Result<SurveySatisfaction, RowConversionError> {
+        Ok(SurveySatisfaction {
+            responses: row.get_i64("responses")?,
+            average_rating: row.get_optional_f64("average_rating")?,
+            recommend_share: row.get_optional_f64("recommend_share")?,
+            average_return_likelihood: row.get_optional_f64("average_return_likelihood")?,
+        })
+    }
+}
+
+#[async_trait]
+impl AnalyticsRepository for SqliteAnalyticsRepository {
+    #[instrument(skip(self))]
+    async fn organization_year(&self, company_id: Uuid, year: i32, now: DateTime<Utc>) -> DomainResult<OrganizationYear> {
+        debug!("Totalling {} for company: {}", year, company_id);
+
+        let mut figures = OrganizationYear::empty(company_id, year);
+
+        let totals = self
+            .fetch_year(TOTALS, company_id, year, now)
+            .await
+            .map_err(InfrastructureError::from)?;
+        if let Some(row) = totals.first() {
+            let count = |field: &'static str| row.get_i64(field).map_err(InfrastructureError::from);
+            figures.events = count("events")?;
+            figures.registrations = count("registrations")?;
+            figures.checked_in = count("checked_in")?;
+            figures.cancelled = count("cancelled")?;
+        }
+        if figures.events == 0 {
+            return Ok(figures);
+        }
+
+        let categories = self
+            .fetch_year(CATEGORIES, company_id, year, now)
+            .await
+            .map_err(InfrastructureError::from)?;
+        figures.categories = categories
+            .iter()
+            .map(Self::row_to_category)
+            .collect::<Result<_, _>>()
+            .map_err(InfrastructureError::from)?;
+
+        let satisfaction = self
+            .fetch_year(SATISFACTION, company_id, year, now)
+            .await
+            .map_err(InfrastructureError::from)?;
+        if let Some(row) = satisfaction.first() {
+            figures.satisfaction = Self::row_to_satisfaction(row).map_err(InfrastructureError::from)?;
+        }
+
+        Ok(figures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    async fn move_to(db: &TestDb, event_id: Uuid, start: &str, extra: &str) {
+        db.execute(&format!(
+            "UPDATE events SET start_date = '{0} 18:00:00', end_date = '{0} 21:00:00'{1} WHERE id = '{2}'",
+            start, extra, event_id
+        ))
+        .await;
+    }
+
+    async fn register(db: &TestDb, event_id: Uuid, status: &str) {
+        let id = Uuid::new_v4();
+        db.execute(&format!(
+            "INSERT INTO event_registrations (id, event_id, registrant_email, registrant_name, status) \
+             VALUES ('{}', '{}', 'guest-{}@example.no', 'Guest', '{}')",
+            id, event_id, id, status
+        ))
+        .await;
+    }
+
+    async fn respond(db: &TestDb, survey_id: Uuid, rating: i64, recommend: &str, again: i64) {
+        db.execute(&format!(
+            "INSERT INTO survey_responses (id, survey_id, responses, overall_rating, would_recommend, likelihood_to_attend_again) \
+             VALUES ('{}', '{}', '{{}}', {}, {}, {})",
+            Uuid::new_v4(),
+            survey_id,
+            rating,
+            recommend,
+            again
+        ))
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_year_counts_the_members_past_events_and_their_surveys() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteAnalyticsRepository::new(db.pool().clone());
+        let company = Uuid::new_v4();
+        db.execute(&format!(
+            "INSERT INTO companies (id, name, industry_type) VALUES ('{}', 'Havbruk AS', 'Salmon')",
+            company
+        ))
+        .await;
+        let member = db.seed_user().await;
+        let outsider = db.seed_user().await;
+        db.execute(&format!("UPDATE users SET company_id = '{}' WHERE id = '{}'", company, member)).await;
+
+        let summit = db.seed_event(member).await;
+        move_to(&db, summit, "2025-05-13", "").await;
+        let workshop = db.seed_event(member).await;
+        move_to(&db, workshop, "2025-09-02", ", category_id = 'workshop', status = 'completed'").await;
+        let last_year = db.seed_event(member).await;
+        move_to(&db, last_year, "2024-05-14", "").await;
+        let draft = db.seed_event(member).await;
+        move_to(&db, draft, "2025-10-01", ", status = 'draft'").await;
+        let elsewhere = db.seed_event(outsider).await;
+        move_to(&db, elsewhere, "2025-06-01", "").await;
+
+        for (event_id, status) in [
+            (summit, "attended"),
+            (summit, "attended"),
+            (summit, "registered"),
+            (summit, "cancelled"),
+            (workshop, "attended"),
+            (last_year, "attended"),
+            (draft, "registered"),
+            (elsewhere, "attended"),
+        ] {
+            register(&db, event_id, status).await;
+        }
+        db.execute("UPDATE event_registrations SET checked_in_at = CURRENT_TIMESTAMP WHERE status = 'attended'").await;
+
+        let survey = Uuid::new_v4();
+        let other_survey = Uuid::new_v4();
+        for (id, event_id, created_by) in [(survey, summit, member), (other_survey, elsewhere, outsider)] {
+            db.execute(&format!(
+                "INSERT INTO event_surveys (id, event_id, title, questions, created_by) VALUES ('{}', '{}', 'Feedback', '[]', '{}')",
+                id, event_id, created_by
+            ))
+            .await;
+        }
+        respond(&db, survey, 5, "1", 9).await;
+        respond(&db, survey, 4, "0", 7).await;
+        respond(&db, survey, 3, "NULL", 5).await;
+        respond(&db, other_survey, 1, "0", 1).await;
+
+        let year = repository.organization_year(company, 2025, Utc::now()).await.unwrap();
+        assert_eq!((year.events, year.registrations, year.checked_in, year.cancelled), (2, 4, 3, 1));
+        let categories: Vec<(&str, i64, i64, i64)> = year
+            .categories
+            .iter()
+            .map(|c| (c.category_id.as_str(), c.events, c.registrations, c.checked_in))
+            .collect();
+        assert_eq!(categories, vec![("conf", 1, 3, 2), ("workshop", 1, 1, 1)]);
+        assert_eq!(year.satisfaction.responses, 3);
+        assert_eq!(year.satisfaction.average_rating, Some(4.0));
+        assert_eq!(year.satisfaction.recommend_share, Some(0.5));
+        assert_eq!(year.satisfaction.average_return_likelihood, Some(7.0));
+
+        let quiet = repository.organization_year(company, 2023, Utc::now()).await.unwrap();
+        assert_eq!(quiet, OrganizationYear::empty(company, 2023));
+    }
+}
@@ -0,0 +1,250 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::AuditLogRepository,
+};
+use crate::infrastructure::persistence::mapping::audit_action_to_string;
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{AuditLogEntry, AuditLogFilter, DomainResult, PaginatedResult, PaginationParams};
+use async_trait::async_trait;
+use sqlx::{Pool, Row, Sqlite};
+use tracing::{debug, instrument};
+
+const AUDIT_LOG_COLUMNS: &str = "id, table_name, record_id, action, user_id, user_name, user_email, old_values, new_values, changed_fields, event_id, created_at";
+
+#[derive(Clone)]
+pub struct SqliteAuditLogRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteAuditLogRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to AuditLogEntry using SafeRowGet
+    fn row_to_entry(row: &sqlx::sqlite::SqliteRow) -> Result<AuditLogEntry, RowConversionError> {
+        let changed_fields = match row.get_optional_string("changed_fields")? {
+            Some(raw) if !raw.trim().is_empty() => serde_json::from_str(&raw)
+                .map_err(|cause| RowConversionError::InvalidJson { field: "changed_fields", cause })?,
+            _ => Vec::new(),
+        };
+
+        Ok(AuditLogEntry {
+            id: row.get_uuid("id")?,
+            table_name: row.get_string("table_name")?,
+            record_id: row.get_string("record_id")?,
+            action: row.get_audit_action("action")?,
+            user_id: row.get_optional_uuid("user_id")?,
+            user_name: row.get_optional_string("user_name")?,
+            user_email: row.get_optional_string("user_email")?,
+            old_values: row.get_optional_string("old_values")?,
+            new_values: row.get_optional_string("new_values")?,
+            changed_fields,
+            event_id: row.get_optional_uuid("event_id")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+
+    fn apply_filter<'a>(query_builder: &mut sqlx::QueryBuilder<'a, Sqlite>, filter: &'a AuditLogFilter) {
+        if let Some(ref table_name) = filter.table_name {
+            query_builder.push(" AND table_name = ");
+            query_builder.push_bind(table_name);
+        }
+
+        if let Some(ref record_id) = filter.record_id {
+            query_builder.push(" AND record_id = ");
+            query_builder.push_bind(record_id);
+        }
+
+        if let Some(user_id) = filter.user_id {
+            query_builder.push(" AND user_id = ");
+            query_builder.push_bind(user_id.to_string());
+        }
+
+        if let Some(ref action) = filter.action {
+            query_builder.push(" AND action = ");
+            query_builder.push_bind(audit_action_to_string(action));
+        }
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for SqliteAuditLogRepository {
+    #[instrument(skip(self, entry))]
+    async fn create(&self, entry: &AuditLogEntry) -> DomainResult<()> {
+        debug!("Recording audit log entry {} for {}/{}", entry.id, entry.table_name, entry.record_id);
+
+        // The actor may not have a local user row (e.g. a Keycloak account that
+        // hasn't been provisioned yet); keep the denormalized name/email and
+        // store NULL instead of violating the users foreign key.
+        let result = sqlx::query(
+            "INSERT INTO audit_logs (id, table_name, record_id, action, user_id, user_name, user_email, old_values, new_values, changed_fields, event_id, created_at) VALUES (?, ?, ?, ?, (SELECT id FROM users WHERE id = ?), ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(entry.id.to_string())
+        .bind(&entry.table_name)
+        .bind(&entry.record_id)
+        .bind(audit_action_to_string(&entry.action))
+        .bind(entry.user_id.map(|id| id.to_string()))
+        .bind(entry.user_name.as_deref())
+        .bind(entry.user_email.as_deref())
+        .bind(entry.old_values.as_deref())
+        .bind(entry.new_values.as_deref())
+        .bind(serde_json::to_string(&entry.changed_fields).unwrap_or_default())
+        .bind(entry.event_id.map(|id| id.to_string()))
+        .bind(entry.created_at.naive_utc())
+        .execute(&self.pool)
+        .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                let infrastructure_error = InfrastructureError::from(e);
+                match infrastructure_error {
+                    InfrastructureError::DomainError { source } => Err(source),
+                    other => Err(other.into()),
+                }
+            }
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_filter(
+        &self,
+        filter: &AuditLogFilter,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<AuditLogEntry>> {
+        debug!("Listing audit log entries with filter and pagination");
+
+        let mut count_builder = sqlx::QueryBuilder::new("SELECT COUNT(*) as count FROM audit_logs WHERE 1=1");
+        Self::apply_filter(&mut count_builder, filter);
+
+        let total_count = match count_builder.build().fetch_one(&self.pool).await {
+            Ok(row) => row.try_get::<i64, _>("count").unwrap_or(0),
+            Err(e) => return Err(InfrastructureError::from(e).into()),
+        };
+
+        let mut query_builder = sqlx::QueryBuilder::new(format!("SELECT {} FROM audit_logs WHERE 1=1", AUDIT_LOG_COLUMNS));
+        Self::apply_filter(&mut query_builder, filter);
+        query_builder.push(" ORDER BY created_at DESC, rowid DESC LIMIT ");
+        query_builder.push_bind(pagination.limit);
+        query_builder.push(" OFFSET ");
+        query_builder.push_bind(pagination.offset);
+
+        match query_builder.build().fetch_all(&self.pool).await {
+            Ok(rows) => {
+                let entries: Result<Vec<AuditLogEntry>, RowConversionError> = rows.iter()
+                    .map(Self::row_to_entry)
+                    .collect();
+                let entries = entries.map_err(InfrastructureError::from)?;
+
+                debug!("Listed {} audit log entries (total: {})", entries.len(), total_count);
+                Ok(PaginatedResult::new(entries, total_count, pagination))
+            }
+            Err(e) => {
+                let infrastructure_error = InfrastructureError::from(e);
+                match infrastructure_error {
+                    InfrastructureError::DomainError { source } => Err(source),
+                    other => Err(other.into()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::AuditAction;
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_create_and_list_newest_first() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteAuditLogRepository::new(db.pool().clone());
+        let admin_id = db.seed_user().await;
+
+        let mut older = AuditLogEntry::new("event_categories", "conf", AuditAction::Update);
+        older.user_id = Some(admin_id);
+        older.changed_fields = vec!["name".to_string(), "color_hex".to_string()];
+        older.old_values = Some(r#"{"name":"Conference"}"#.to_string());
+        older.new_values = Some(r#"{"name":"Conferences"}"#.to_string());
+        older.created_at = Utc::now() - Duration::minutes(5);
+        repository.create(&older).await.unwrap();
+
+        let newer = AuditLogEntry::new("users", Uuid::new_v4().to_string(), AuditAction::Delete);
+        repository.create(&newer).await.unwrap();
+
+        let result = repository
+            .find_by_filter(&AuditLogFilter::default(), PaginationParams::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.total_count, 2);
+        assert_eq!(result.items[0].id, newer.id);
+        assert_eq!(result.items[1].id, older.id);
+        assert_eq!(result.items[1].user_id, Some(admin_id));
+        assert_eq!(result.items[1].action, AuditAction::Update);
+        assert_eq!(result.items[1].changed_fields, vec!["name", "color_hex"]);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_actor_is_stored_without_user_reference() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteAuditLogRepository::new(db.pool().clone());
+
+        let mut entry = AuditLogEntry::new("users", "someone", AuditAction::Update);
+        entry.user_id = Some(Uuid::new_v4());
+        entry.user_name = Some("Keycloak Admin".to_string());
+        repository.create(&entry).await.unwrap();
+
+        let result = repository
+            .find_by_filter(&AuditLogFilter::default(), PaginationParams::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.items[0].user_id, None);
+        assert_eq!(result.items[0].user_name.as_deref(), Some("Keycloak Admin"));
+    }
+
+    #[tokio::test]
+    async fn test_filter_and_pagination() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteAuditLogRepository::new(db.pool().clone());
+
+        for i in 0..3 {
+            repository
+                .create(&AuditLogEntry::new("event_categories", format!("cat-{}", i), AuditAction::Insert))
+                .await
+                .unwrap();
+        }
+        repository
+            .create(&AuditLogEntry::new("users", "user-1", AuditAction::Update))
+            .await
+            .unwrap();
+
+        let filter = AuditLogFilter {
+            table_name: Some("event_categories".to_string()),
+            ..Default::default()
+        };
+        let page = repository
+            .find_by_filter(&filter, PaginationParams::new(0, 2).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(page.total_count, 3);
+        assert_eq!(page.items.len(), 2);
+        assert!(page.has_next);
+
+        let filter = AuditLogFilter {
+            action: Some(AuditAction::Update),
+            ..Default::default()
+        };
+        let updates = repository
+            .find_by_filter(&filter, PaginationParams::default())
+            .await
+            .unwrap();
+        assert_eq!(updates.total_count, 1);
+        assert_eq!(updates.items[0].record_id, "user-1");
+    }
+}
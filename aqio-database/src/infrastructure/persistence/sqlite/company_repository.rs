@@ -0,0 +1,387 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::CompanyRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{
+    Company, CompanyFilter, CompanyMemberCount, DomainResult, IndustryType, PaginatedResult,
+    PaginationParams,
+};
+use async_trait::async_trait;
+use sqlx::{Pool, Row, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const COMPANY_COLUMNS: &str = "id, name, org_number, location, industry_type, industry_type_other, website, phone, postal_code, municipality, county, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteCompanyRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteCompanyRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // The description of an `Other` industry lives in its own column
+    fn industry_type_to_columns(industry_type: &IndustryType) -> (&'static str, Option<&str>) {
+        match industry_type {
+            IndustryType::Salmon => ("Salmon", None),
+            IndustryType::Trout => ("Trout", None),
+            IndustryType::Other(description) => ("Other", Some(description)),
+        }
+    }
+
+    // Helper method to convert database row to Company using SafeRowGet
+    fn row_to_company(row: &sqlx::sqlite::SqliteRow) -> Result<Company, RowConversionError> {
+        let industry_type = match row.get_string("industry_type")?.as_str() {
+            "Salmon" => IndustryType::Salmon,
+            "Trout" => IndustryType::Trout,
+            "Other" => IndustryType::Other(row.get_optional_string("industry_type_other")?.unwrap_or_default()),
+            other => {
+                return Err(RowConversionError::InvalidEnum {
+                    field: "industry_type",
+                    value: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Company {
+            id: row.get_uuid("id")?,
+            name: row.get_string("name")?,
+            org_number: row.get_optional_string("org_number")?,
+            location: row.get_optional_string("location")?,
+            industry_type,
+            website: row.get_optional_string("website")?,
+            phone: row.get_optional_string("phone")?,
+            region: row.get_optional_address_region()?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn apply_filter(query_builder: &mut sqlx::QueryBuilder<'_, Sqlite>, filter: &CompanyFilter) {
+        if let Some(ref industry_type) = filter.industry_type {
+            let (industry_type, _) = Self::industry_type_to_columns(industry_type);
+            query_builder.push(" AND industry_type = ");
+            query_builder.push_bind(industry_type);
+        }
+    }
+
+    async fn find_one(&self, column: &'static str, value: String) -> DomainResult<Option<Company>> {
+        let row = sqlx::query(&format!("SELECT {} FROM companies WHERE {} = ?", COMPANY_COLUMNS, column))
+            .bind(value)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_company(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl CompanyRepository for SqliteCompanyRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Company>> {
+        debug!("Finding company by id: {}", id);
+        self.find_one("id", id.to_string()).await
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_org_number(&self, org_number: &str) -> DomainResult<Option<Company>> {
+        debug!("Finding company by organization number: {}", org_number);
+        self.find_one("org_number", org_number.to_string()).await
+    }
+
+    #[instrument(skip(self, company))]
+    async fn create(&self, company: &Company) -> DomainResult<()> {
+        debug!("Creating company with id: {}", company.id);
+
+        let (industry_type, industry_type_other) = Self::industry_type_to_columns(&company.industry_type);
+        sqlx::query(
+            "INSERT INTO companies (id, name, org_number, location, industry_type, industry_type_other, website, phone, postal_code, municipality, county, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(company.id.to_string())
+        .bind(&company.name)
+        .bind(company.org_number.as_deref())
+        .bind(company.location.as_deref())
+        .bind(industry_type)
+        .bind(industry_type_other)
+        .bind(company.website.as_deref())
+        .bind(company.phone.as_deref())
+        .bind(company.region.as_ref().map(|r| r.postal_code.as_str()))
+        .bind(company.region.as_ref().map(|r| r.municipality.as_str()))
+        .bind(company.region.as_ref().map(|r| r.county.as_str()))
+        .bind(company.created_at.naive_utc())
+        .bind(company.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created company with id: {}", company.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self, company))]
+    async fn update(&self, company: &Company) -> DomainResult<()> {
+        debug!("Updating company with id: {}", company.id);
+
+        let (industry_type, industry_type_other) = Self::industry_type_to_columns(&company.industry_type);
+        let result = sqlx::query(
+            "UPDATE companies SET name = ?, org_number = ?, location = ?, industry_type = ?, industry_type_other = ?, website = ?, phone = ?, postal_code = ?, municipality = ?, county = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(&company.name)
+        .bind(company.org_number.as_deref())
+        .bind(company.location.as_deref())
+        .bind(industry_type)
+        .bind(industry_type_other)
+        .bind(company.website.as_deref())
+        .bind(company.phone.as_deref())
+        .bind(company.region.as_ref().map(|r| r.postal_code.as_str()))
+        .bind(company.region.as_ref().map(|r| r.municipality.as_str()))
+        .bind(company.region.as_ref().map(|r| r.county.as_str()))
+        .bind(company.updated_at.naive_utc())
+        .bind(company.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("Company", company.id));
+        }
+        debug!("Successfully updated company with id: {}", company.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting company with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM companies WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("Company", id));
+        }
+        debug!("Successfully deleted company with id: {}", id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn list_all(&self, pagination: PaginationParams) -> DomainResult<PaginatedResult<Company>> {
+        self.find_by_filter(&CompanyFilter::default(), pagination).await
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_filter(
+        &self,
+        filter: &CompanyFilter,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<Company>> {
+        debug!("Listing companies with filter and pagination");
+
+        let mut count_builder = sqlx::QueryBuilder::new("SELECT COUNT(*) as count FROM companies WHERE 1=1");
+        Self::apply_filter(&mut count_builder, filter);
+        let total_count = count_builder
+            .build()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?
+            .try_get::<i64, _>("count")
+            .unwrap_or(0);
+
+        let mut query_builder = sqlx::QueryBuilder::new(format!("SELECT {} FROM companies WHERE 1=1", COMPANY_COLUMNS));
+        Self::apply_filter(&mut query_builder, filter);
+        query_builder.push(" ORDER BY name COLLATE NOCASE, id LIMIT ");
+        query_builder.push_bind(pagination.limit);
+        query_builder.push(" OFFSET ");
+        query_builder.push_bind(pagination.offset);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+        let companies: Result<Vec<Company>, RowConversionError> = rows.iter()
+            .map(Self::row_to_company)
+            .collect();
+        let companies = companies.map_err(InfrastructureError::from)?;
+
+        debug!("Listed {} companies (total: {})", companies.len(), total_count);
+        Ok(PaginatedResult::new(companies, total_count, pagination))
+    }
+
+    #[instrument(skip(self, ids))]
+    async fn count_members_by_company_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<CompanyMemberCount>> {
+        debug!("Counting members of {} companies", ids.len());
+
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new(
+            "SELECT company_id, COUNT(*) AS members FROM users WHERE is_active = TRUE AND company_id IN ("
+        );
+        let mut separated = query_builder.separated(", ");
+        for id in ids {
+            separated.push_bind(id.to_string());
+        }
+        query_builder.push(") GROUP BY company_id");
+
+        let rows = query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        let counts: Result<Vec<CompanyMemberCount>, RowConversionError> = rows.iter()
+            .map(|row| {
+                Ok(CompanyMemberCount {
+                    company_id: row.get_uuid("company_id")?,
+                    members: row.try_get("members")
+                        .map_err(|cause| RowConversionError::MissingField { field: "members", cause })?,
+                })
+            })
+            .collect();
+        Ok(counts.map_err(InfrastructureError::from)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::sqlite::SqliteEventRepository;
+    use crate::testing::TestDb;
+    use aqio_core::{EventFilter, EventRepository};
+    use chrono::Utc;
+
+    fn create_test_company(name: &str, industry_type: IndustryType) -> Company {
+        let now = Utc::now();
+        Company {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            org_number: None,
+            location: Some("Bergen".to_string()),
+            industry_type,
+            website: None,
+            phone: None,
+            region: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    async fn join_company(db: &TestDb, user_id: Uuid, company_id: Uuid) {
+        sqlx::query("UPDATE users SET company_id = ? WHERE id = ?")
+            .bind(company_id.to_string())
+            .bind(user_id.to_string())
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_and_find_round_trips_other_industry() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteCompanyRepository::new(db.pool().clone());
+
+        let mut company = create_test_company("Nordic Shellfish", IndustryType::Other("mussels".to_string()));
+        company.org_number = Some("987654321".to_string());
+        repository.create(&company).await.unwrap();
+
+        let found = repository.find_by_id(company.id).await.unwrap().unwrap();
+        assert_eq!(found.name, "Nordic Shellfish");
+        assert_eq!(found.industry_type.other_description(), Some("mussels"));
+
+        let by_org_number = repository.find_by_org_number("987654321").await.unwrap().unwrap();
+        assert_eq!(by_org_number.id, company.id);
+
+        assert!(repository.find_by_id(Uuid::new_v4()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_filter_by_industry_sorted_by_name() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteCompanyRepository::new(db.pool().clone());
+
+        for company in [
+            create_test_company("Vestland Salmon", IndustryType::Salmon),
+            create_test_company("Fjord Trout", IndustryType::Trout),
+            create_test_company("arctic Salmon", IndustryType::Salmon),
+            create_test_company("Cod Farmers", IndustryType::Other("cod".to_string())),
+        ] {
+            repository.create(&company).await.unwrap();
+        }
+
+        let filter = CompanyFilter { industry_type: Some(IndustryType::Salmon) };
+        let salmon = repository.find_by_filter(&filter, PaginationParams::default()).await.unwrap();
+        let names: Vec<&str> = salmon.items.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["arctic Salmon", "Vestland Salmon"]);
+        assert_eq!(salmon.total_count, 2);
+
+        // Any description matches `Other`
+        let filter = CompanyFilter { industry_type: Some(IndustryType::Other(String::new())) };
+        let other = repository.find_by_filter(&filter, PaginationParams::default()).await.unwrap();
+        assert_eq!(other.items.len(), 1);
+        assert_eq!(other.items[0].name, "Cod Farmers");
+
+        let page = repository.list_all(PaginationParams::new(0, 3).unwrap()).await.unwrap();
+        assert_eq!(page.total_count, 4);
+        assert!(page.has_next);
+    }
+
+    #[tokio::test]
+    async fn test_member_counts_and_company_events() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteCompanyRepository::new(db.pool().clone());
+        let events = SqliteEventRepository::new(db.pool().clone());
+
+        let company = create_test_company("Vestland Salmon", IndustryType::Salmon);
+        let empty = create_test_company("Fjord Trout", IndustryType::Trout);
+        repository.create(&company).await.unwrap();
+        repository.create(&empty).await.unwrap();
+
+        let member = db.seed_user().await;
+        let inactive = db.seed_user().await;
+        let outsider = db.seed_user().await;
+        join_company(&db, member, company.id).await;
+        join_company(&db, inactive, company.id).await;
+        db.execute(&format!("UPDATE users SET is_active = FALSE WHERE id = '{}'", inactive)).await;
+
+        let counts = repository
+            .count_members_by_company_ids(&[company.id, empty.id])
+            .await
+            .unwrap();
+        assert_eq!(counts, vec![CompanyMemberCount { company_id: company.id, members: 1 }]);
+
+        let company_event = db.seed_event(member).await;
+        db.seed_event(outsider).await;
+
+        let filter = EventFilter {
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: None,
+            status: None,
+            location_type: None,
+            start_date_from: None,
+            start_date_to: None,
+            near: None,
+            organizer_company_id: Some(company.id),
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
+        };
+        let result = events.find_by_filter(&filter, PaginationParams::default()).await.unwrap();
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.items[0].id, company_event);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::ContentReportRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::{moderation_action_to_string, report_reason_to_string, report_status_to_string, report_target_to_string},
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{ContentReport, DomainResult, ModerationAction, ReportStatus, ReportTarget};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const REPORT_COLUMNS: &str = "id, target_type, target_id, reporter_id, reason, details, status, action, resolved_by, created_at, resolved_at";
+
+#[derive(Clone)]
+pub struct SqliteContentReportRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteContentReportRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to ContentReport using SafeRowGet
+    fn row_to_report(row: &sqlx::sqlite::SqliteRow) -> Result<ContentReport, RowConversionError> {
+        Ok(ContentReport {
+            id: row.get_uuid("id")?,
+            target: row.get_report_target("target_type")?,
+            target_id: row.get_uuid("target_id")?,
+            reporter_id: row.get_uuid("reporter_id")?,
+            reason: row.get_report_reason("reason")?,
+            details: row.get_optional_string("details")?,
+            status: row.get_report_status("status")?,
+            action: row.get_optional_moderation_action("action")?,
+            resolved_by: row.get_optional_uuid("resolved_by")?,
+            created_at: row.get_datetime("created_at")?,
+            resolved_at: row.get_optional_datetime("resolved_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl ContentReportRepository for SqliteContentReportRepository {
+    #[instrument(skip(self, report))]
+    async fn create(&self, report: &ContentReport) -> DomainResult<()> {
+        debug!("Creating report {} of {:?} {}", report.id, report.target, report.target_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO content_reports ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            REPORT_COLUMNS
+        ))
+        .bind(report.id.to_string())
+        .bind(report_target_to_string(report.target))
+        .bind(report.target_id.to_string())
+        .bind(report.reporter_id.to_string())
+        .bind(report_reason_to_string(report.reason))
+        .bind(&report.details)
+        .bind(report_status_to_string(report.status))
+        .bind(report.action.map(moderation_action_to_string))
+        .bind(report.resolved_by.map(|id| id.to_string()))
+        .bind(report.created_at.naive_utc())
+        .bind(report.resolved_at.map(|at| at.naive_utc()))
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_open(&self) -> DomainResult<Vec<ContentReport>> {
+        debug!("Finding open content reports");
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM content_reports WHERE status = 'open' ORDER BY created_at",
+            REPORT_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_report(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn count_open_reporters(&self, target: ReportTarget, target_id: Uuid) -> DomainResult<i64> {
+        debug!("Counting reporters of {:?} {}", target, target_id);
+
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(DISTINCT reporter_id) FROM content_reports WHERE target_type = ? AND target_id = ? AND status = 'open'",
+        )
+        .bind(report_target_to_string(target))
+        .bind(target_id.to_string())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(count)
+    }
+
+    #[instrument(skip(self))]
+    async fn resolve_open(
+        &self,
+        target: ReportTarget,
+        target_id: Uuid,
+        status: ReportStatus,
+        action: ModerationAction,
+        resolved_by: Uuid,
+        resolved_at: DateTime<Utc>,
+    ) -> DomainResult<u64> {
+        debug!("Resolving open reports of {:?} {} as {:?}", target, target_id, status);
+
+        let result = sqlx::query(
+            "UPDATE content_reports SET status = ?, action = ?, resolved_by = ?, resolved_at = ? \
+             WHERE target_type = ? AND target_id = ? AND status = 'open'",
+        )
+        .bind(report_status_to_string(status))
+        .bind(moderation_action_to_string(action))
+        .bind(resolved_by.to_string())
+        .bind(resolved_at.naive_utc())
+        .bind(report_target_to_string(target))
+        .bind(target_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip(self))]
+    async fn hide(&self, target: ReportTarget, target_id: Uuid, hidden_by: Option<Uuid>, hidden_at: DateTime<Utc>) -> DomainResult<()> {
+        debug!("Hiding {:?} {}", target, target_id);
+
+        // A moderator confirming an automatic hide takes it over
+        sqlx::query(
+            "INSERT INTO hidden_content (target_type, target_id, hidden_by, hidden_at) VALUES (?, ?, ?, ?) \
+             ON CONFLICT (target_type, target_id) DO UPDATE SET hidden_by = COALESCE(excluded.hidden_by, hidden_by)",
+        )
+        .bind(report_target_to_string(target))
+        .bind(target_id.to_string())
+        .bind(hidden_by.map(|id| id.to_string()))
+        .bind(hidden_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn unhide(&self, target: ReportTarget, target_id: Uuid) -> DomainResult<()> {
+        debug!("Showing {:?} {} again", target, target_id);
+
+        sqlx::query("DELETE FROM hidden_content WHERE target_type = ? AND target_id = ?")
+            .bind(report_target_to_string(target))
+            .bind(target_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, target_ids))]
+    async fn find_hidden(&self, target: ReportTarget, target_ids: &[Uuid]) -> DomainResult<Vec<Uuid>> {
+        debug!("Checking {} {:?} targets for hidden ones", target_ids.len(), target);
+
+        if target_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new("SELECT target_id FROM hidden_content WHERE target_type = ");
+        query_builder.push_bind(report_target_to_string(target));
+        query_builder.push(" AND target_id IN (");
+        let mut separated = query_builder.separated(", ");
+        for id in target_ids {
+            separated.push_bind(id.to_string());
+        }
+        query_builder.push(")");
+
+        let ids: Vec<String> = query_builder
+            .build_query_scalar()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        ids.iter()
+            .map(|id| {
+                Uuid::parse_str(id).map_err(|cause| {
+                    InfrastructureError::from(RowConversionError::InvalidUuid {
+                        field: "target_id",
+                        value: id.clone(),
+                        cause,
+                    })
+                    .into()
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::ReportReason;
+
+    #[tokio::test]
+    async fn test_reports_count_reporters_once_and_resolve_together() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteContentReportRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let first = db.seed_user().await;
+        let second = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+
+        let report = ContentReport::new(ReportTarget::Event, event_id, first, ReportReason::Spam, Some("Ads".to_string()));
+        repository.create(&report).await.unwrap();
+        // Reporting again while the first report is open is refused
+        let again = ContentReport::new(ReportTarget::Event, event_id, first, ReportReason::Other, None);
+        assert!(repository.create(&again).await.is_err());
+        repository
+            .create(&ContentReport::new(ReportTarget::Event, event_id, second, ReportReason::Harassment, None))
+            .await
+            .unwrap();
+        assert_eq!(repository.count_open_reporters(ReportTarget::Event, event_id).await.unwrap(), 2);
+        assert_eq!(repository.count_open_reporters(ReportTarget::Profile, event_id).await.unwrap(), 0);
+
+        let open = repository.find_open().await.unwrap();
+        assert_eq!(open.len(), 2);
+        assert_eq!(open[0].id, report.id);
+
+        let resolved = repository
+            .resolve_open(ReportTarget::Event, event_id, ReportStatus::Actioned, ModerationAction::Hide, organizer, Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(resolved, 2);
+        assert!(repository.find_open().await.unwrap().is_empty());
+        // Once resolved, the same user can report again
+        repository.create(&again).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_hidden_content_is_found_and_cleaned_up_with_its_row() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteContentReportRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let reporter = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+        let other_event = db.seed_event(organizer).await;
+
+        repository.hide(ReportTarget::Event, event_id, None, Utc::now()).await.unwrap();
+        repository.hide(ReportTarget::Event, event_id, Some(organizer), Utc::now()).await.unwrap();
+        assert_eq!(
+            repository.find_hidden(ReportTarget::Event, &[event_id, other_event]).await.unwrap(),
+            vec![event_id]
+        );
+        assert!(repository.find_hidden(ReportTarget::Comment, &[event_id]).await.unwrap().is_empty());
+
+        repository
+            .create(&ContentReport::new(ReportTarget::Event, event_id, reporter, ReportReason::Spam, None))
+            .await
+            .unwrap();
+        db.execute(&format!("DELETE FROM events WHERE id = '{}'", event_id)).await;
+        assert!(repository.find_open().await.unwrap().is_empty());
+        assert!(repository.find_hidden(ReportTarget::Event, &[event_id]).await.unwrap().is_empty());
+
+        repository.hide(ReportTarget::Event, other_event, None, Utc::now()).await.unwrap();
+        repository.unhide(ReportTarget::Event, other_event).await.unwrap();
+        assert!(repository.find_hidden(ReportTarget::Event, &[other_event]).await.unwrap().is_empty());
+    }
+}
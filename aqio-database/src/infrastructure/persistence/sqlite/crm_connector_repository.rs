@@ -0,0 +1,412 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::CrmConnectorRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::{
+        crm_conflict_policy_to_string, crm_provider_to_string, crm_record_kind_to_string, crm_sync_run_status_to_string,
+        crm_sync_state_to_string,
+    },
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{
+    CrmConnector, CrmFieldMapping, CrmRecordKind, CrmSyncCounts, CrmSyncRecord, CrmSyncState, DomainResult,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Row, Sqlite};
+use std::collections::BTreeMap;
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const CONNECTOR_COLUMNS: &str = "id, name, provider, base_url, company_id, registration_object, field_mappings, \
+     conflict_policy, sync_interval_minutes, is_active, next_sync_at, last_sync_at, last_status, last_error, \
+     last_summary, sync_cursor, created_by, created_at, updated_at";
+
+const RECORD_COLUMNS: &str = "connector_id, kind, local_key, remote_id, state, synced_values, conflicts, error, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteCrmConnectorRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteCrmConnectorRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to CrmConnector using SafeRowGet
+    fn row_to_connector(row: &sqlx::sqlite::SqliteRow) -> Result<CrmConnector, RowConversionError> {
+        let last_summary = row
+            .get_optional_string("last_summary")?
+            .map(|summary| serde_json::from_str(&summary))
+            .transpose()
+            .map_err(|cause| RowConversionError::InvalidJson { field: "last_summary", cause })?;
+
+        Ok(CrmConnector {
+            id: row.get_uuid("id")?,
+            name: row.get_string("name")?,
+            provider: row.get_crm_provider("provider")?,
+            base_url: row.get_optional_string("base_url")?,
+            company_id: row.get_optional_uuid("company_id")?,
+            registration_object: row.get_optional_string("registration_object")?,
+            field_mappings: row.get_json::<Vec<CrmFieldMapping>>("field_mappings")?,
+            conflict_policy: row.get_crm_conflict_policy("conflict_policy")?,
+            sync_interval_minutes: row.get_i32("sync_interval_minutes")?,
+            is_active: row.get_bool("is_active")?,
+            next_sync_at: row.get_optional_datetime("next_sync_at")?,
+            last_sync_at: row.get_optional_datetime("last_sync_at")?,
+            last_status: row.get_optional_crm_sync_run_status("last_status")?,
+            last_error: row.get_optional_string("last_error")?,
+            last_summary,
+            sync_cursor: row.get_optional_datetime("sync_cursor")?,
+            created_by: row.get_uuid("created_by")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn row_to_record(row: &sqlx::sqlite::SqliteRow) -> Result<CrmSyncRecord, RowConversionError> {
+        Ok(CrmSyncRecord {
+            connector_id: row.get_uuid("connector_id")?,
+            kind: row.get_crm_record_kind("kind")?,
+            local_key: row.get_string("local_key")?,
+            remote_id: row.get_optional_string("remote_id")?,
+            state: row.get_crm_sync_state("state")?,
+            synced_values: row.get_json::<BTreeMap<String, String>>("synced_values")?,
+            conflicts: row.get_json::<Vec<String>>("conflicts")?,
+            error: row.get_optional_string("error")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn rows_to_connectors(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<CrmConnector>> {
+        rows.iter()
+            .map(|row| Self::row_to_connector(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl CrmConnectorRepository for SqliteCrmConnectorRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<CrmConnector>> {
+        debug!("Finding CRM connector by ID: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM crm_connectors WHERE id = ?", CONNECTOR_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_connector(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_all(&self) -> DomainResult<Vec<CrmConnector>> {
+        debug!("Listing CRM connectors");
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM crm_connectors ORDER BY name COLLATE NOCASE",
+            CONNECTOR_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_connectors(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_due(&self, now: DateTime<Utc>) -> DomainResult<Vec<CrmConnector>> {
+        debug!("Finding CRM connectors due at {}", now);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM crm_connectors WHERE is_active = TRUE AND next_sync_at <= ? ORDER BY next_sync_at",
+            CONNECTOR_COLUMNS
+        ))
+        .bind(now.naive_utc())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_connectors(&rows)
+    }
+
+    #[instrument(skip(self, connector))]
+    async fn create(&self, connector: &CrmConnector) -> DomainResult<()> {
+        debug!("Creating CRM connector {} ({})", connector.id, connector.name);
+
+        sqlx::query(&format!(
+            "INSERT INTO crm_connectors ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            CONNECTOR_COLUMNS
+        ))
+        .bind(connector.id.to_string())
+        .bind(&connector.name)
+        .bind(crm_provider_to_string(connector.provider))
+        .bind(&connector.base_url)
+        .bind(connector.company_id.map(|id| id.to_string()))
+        .bind(&connector.registration_object)
+        .bind(serde_json::to_string(&connector.field_mappings).unwrap_or_default())
+        .bind(crm_conflict_policy_to_string(connector.conflict_policy))
+        .bind(connector.sync_interval_minutes)
+        .bind(connector.is_active)
+        .bind(connector.next_sync_at.map(|at| at.naive_utc()))
+        .bind(connector.last_sync_at.map(|at| at.naive_utc()))
+        .bind(connector.last_status.map(crm_sync_run_status_to_string))
+        .bind(&connector.last_error)
+        .bind(connector.last_summary.map(|summary| serde_json::to_string(&summary).unwrap_or_default()))
+        .bind(connector.sync_cursor.map(|at| at.naive_utc()))
+        .bind(connector.created_by.to_string())
+        .bind(connector.created_at.naive_utc())
+        .bind(connector.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, connector))]
+    async fn update(&self, connector: &CrmConnector) -> DomainResult<()> {
+        debug!("Updating CRM connector: {}", connector.id);
+
+        let result = sqlx::query(
+            "UPDATE crm_connectors SET name = ?, provider = ?, base_url = ?, company_id = ?, registration_object = ?, \
+             field_mappings = ?, conflict_policy = ?, sync_interval_minutes = ?, is_active = ?, next_sync_at = ?, \
+             last_sync_at = ?, last_status = ?, last_error = ?, last_summary = ?, sync_cursor = ?, updated_at = ? \
+             WHERE id = ?",
+        )
+        .bind(&connector.name)
+        .bind(crm_provider_to_string(connector.provider))
+        .bind(&connector.base_url)
+        .bind(connector.company_id.map(|id| id.to_string()))
+        .bind(&connector.registration_object)
+        .bind(serde_json::to_string(&connector.field_mappings).unwrap_or_default())
+        .bind(crm_conflict_policy_to_string(connector.conflict_policy))
+        .bind(connector.sync_interval_minutes)
+        .bind(connector.is_active)
+        .bind(connector.next_sync_at.map(|at| at.naive_utc()))
+        .bind(connector.last_sync_at.map(|at| at.naive_utc()))
+        .bind(connector.last_status.map(crm_sync_run_status_to_string))
+        .bind(&connector.last_error)
+        .bind(connector.last_summary.map(|summary| serde_json::to_string(&summary).unwrap_or_default()))
+        .bind(connector.sync_cursor.map(|at| at.naive_utc()))
+        .bind(connector.updated_at.naive_utc())
+        .bind(connector.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("CrmConnector", connector.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting CRM connector: {}", id);
+
+        // Delete explicitly rather than relying on the foreign key pragma being on
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        sqlx::query("DELETE FROM crm_sync_records WHERE connector_id = ?")
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        let result = sqlx::query("DELETE FROM crm_connectors WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("CrmConnector", id));
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_record(
+        &self,
+        connector_id: Uuid,
+        kind: CrmRecordKind,
+        local_key: &str,
+    ) -> DomainResult<Option<CrmSyncRecord>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM crm_sync_records WHERE connector_id = ? AND kind = ? AND local_key = ?",
+            RECORD_COLUMNS
+        ))
+        .bind(connector_id.to_string())
+        .bind(crm_record_kind_to_string(kind))
+        .bind(local_key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_record(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_records(&self, connector_id: Uuid, state: CrmSyncState, limit: i64) -> DomainResult<Vec<CrmSyncRecord>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM crm_sync_records WHERE connector_id = ? AND state = ? ORDER BY updated_at DESC LIMIT ?",
+            RECORD_COLUMNS
+        ))
+        .bind(connector_id.to_string())
+        .bind(crm_sync_state_to_string(state))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_record(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, record))]
+    async fn save_record(&self, record: &CrmSyncRecord) -> DomainResult<()> {
+        sqlx::query(&format!(
+            "INSERT INTO crm_sync_records ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?) \
+             ON CONFLICT (connector_id, kind, local_key) DO UPDATE SET \
+             remote_id = excluded.remote_id, state = excluded.state, synced_values = excluded.synced_values, \
+             conflicts = excluded.conflicts, error = excluded.error, updated_at = excluded.updated_at",
+            RECORD_COLUMNS
+        ))
+        .bind(record.connector_id.to_string())
+        .bind(crm_record_kind_to_string(record.kind))
+        .bind(&record.local_key)
+        .bind(&record.remote_id)
+        .bind(crm_sync_state_to_string(record.state))
+        .bind(serde_json::to_string(&record.synced_values).unwrap_or_default())
+        .bind(serde_json::to_string(&record.conflicts).unwrap_or_default())
+        .bind(&record.error)
+        .bind(record.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn count_records(&self, connector_id: Uuid) -> DomainResult<CrmSyncCounts> {
+        let rows = sqlx::query("SELECT state, COUNT(*) AS count FROM crm_sync_records WHERE connector_id = ? GROUP BY state")
+            .bind(connector_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        let mut counts = CrmSyncCounts::default();
+        for row in &rows {
+            let state = row.get_crm_sync_state("state").map_err(InfrastructureError::from)?;
+            let count: i64 = row.try_get("count").map_err(InfrastructureError::from)?;
+            match state {
+                CrmSyncState::Synced => counts.synced = count,
+                CrmSyncState::Conflict => counts.conflicts = count,
+                CrmSyncState::Failed => counts.failed = count,
+            }
+        }
+        Ok(counts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::{CrmConflictPolicy, CrmProvider, CrmSyncRunStatus, CrmSyncSummary};
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_crm_connectors_round_trip_and_come_due() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteCrmConnectorRepository::new(db.pool().clone());
+        let admin = db.seed_user().await;
+        let now = Utc::now();
+
+        let mut hubspot = CrmConnector::new("HubSpot", CrmProvider::HubSpot, admin, now - Duration::minutes(1));
+        hubspot.conflict_policy = CrmConflictPolicy::FillBlanks;
+        hubspot.registration_object = Some("2-4518833".to_string());
+        repository.create(&hubspot).await.unwrap();
+        let mut dynamics = CrmConnector::new("Dynamics", CrmProvider::Dynamics, admin, now);
+        dynamics.base_url = Some("https://contoso.crm4.dynamics.com".to_string());
+        dynamics.next_sync_at = Some(now + Duration::hours(1));
+        repository.create(&dynamics).await.unwrap();
+
+        let found = repository.find_by_id(hubspot.id).await.unwrap().unwrap();
+        assert_eq!(found.field_mappings, hubspot.field_mappings);
+        assert_eq!(found.conflict_policy, CrmConflictPolicy::FillBlanks);
+        assert_eq!(found.registration_object.as_deref(), Some("2-4518833"));
+
+        let names: Vec<String> = repository.find_all().await.unwrap().into_iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["Dynamics", "HubSpot"]);
+        let due: Vec<Uuid> = repository.find_due(now).await.unwrap().into_iter().map(|c| c.id).collect();
+        assert_eq!(due, vec![hubspot.id]);
+
+        let mut synced = found;
+        synced.record_sync(now, Ok(CrmSyncSummary { created: 2, failed: 1, ..Default::default() }));
+        repository.update(&synced).await.unwrap();
+        let updated = repository.find_by_id(hubspot.id).await.unwrap().unwrap();
+        assert_eq!(updated.last_status, Some(CrmSyncRunStatus::PartiallyFailed));
+        assert_eq!(updated.last_summary.unwrap().created, 2);
+        assert!(updated.sync_cursor.is_some());
+        assert!(repository.find_due(now).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_crm_sync_records_are_upserted_counted_and_deleted_with_the_connector() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteCrmConnectorRepository::new(db.pool().clone());
+        let admin = db.seed_user().await;
+        let now = Utc::now();
+        let connector = CrmConnector::new("HubSpot", CrmProvider::HubSpot, admin, now);
+        repository.create(&connector).await.unwrap();
+
+        let mut kari = CrmSyncRecord::new(connector.id, CrmRecordKind::Contact, "kari@example.org", now);
+        kari.error = Some("HubSpot answered 429 Too Many Requests".to_string());
+        repository.save_record(&kari).await.unwrap();
+        let ola = CrmSyncRecord {
+            remote_id: Some("151".to_string()),
+            state: CrmSyncState::Conflict,
+            synced_values: BTreeMap::from([("lastname".to_string(), "Nordmann".to_string())]),
+            conflicts: vec!["company".to_string()],
+            ..CrmSyncRecord::new(connector.id, CrmRecordKind::Contact, "ola@example.org", now)
+        };
+        repository.save_record(&ola).await.unwrap();
+
+        kari.state = CrmSyncState::Synced;
+        kari.remote_id = Some("152".to_string());
+        kari.error = None;
+        repository.save_record(&kari).await.unwrap();
+
+        let found = repository
+            .find_record(connector.id, CrmRecordKind::Contact, "ola@example.org")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((found.remote_id, found.state), (ola.remote_id, CrmSyncState::Conflict));
+        assert_eq!((found.synced_values, found.conflicts), (ola.synced_values, ola.conflicts));
+        let counts = repository.count_records(connector.id).await.unwrap();
+        assert_eq!(counts, CrmSyncCounts { synced: 1, conflicts: 1, failed: 0 });
+        let conflicts = repository.find_records(connector.id, CrmSyncState::Conflict, 10).await.unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert!(repository
+            .find_record(connector.id, CrmRecordKind::Registration, "ola@example.org")
+            .await
+            .unwrap()
+            .is_none());
+
+        repository.delete(connector.id).await.unwrap();
+        assert_eq!(repository.count_records(connector.id).await.unwrap(), CrmSyncCounts::default());
+        assert!(repository.delete(connector.id).await.is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EmailSuppressionRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::delivery_failure_to_string,
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{DomainResult, EmailSuppression};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+
+const EMAIL_SUPPRESSION_COLUMNS: &str = "email, reason, provider, detail, created_at";
+
+#[derive(Clone)]
+pub struct SqliteEmailSuppressionRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEmailSuppressionRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EmailSuppression using SafeRowGet
+    fn row_to_suppression(row: &sqlx::sqlite::SqliteRow) -> Result<EmailSuppression, RowConversionError> {
+        Ok(EmailSuppression {
+            email: row.get_string("email")?,
+            reason: row.get_delivery_failure("reason")?,
+            provider: row.get_string("provider")?,
+            detail: row.get_optional_string("detail")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl EmailSuppressionRepository for SqliteEmailSuppressionRepository {
+    #[instrument(skip(self))]
+    async fn find_by_email(&self, email: &str) -> DomainResult<Option<EmailSuppression>> {
+        debug!("Finding email suppression for: {}", email);
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM email_suppressions WHERE email = ?",
+            EMAIL_SUPPRESSION_COLUMNS
+        ))
+        .bind(email.trim().to_lowercase())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_suppression(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_all(&self) -> DomainResult<Vec<EmailSuppression>> {
+        debug!("Listing email suppressions");
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM email_suppressions ORDER BY created_at DESC, email",
+            EMAIL_SUPPRESSION_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_suppression(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, suppression))]
+    async fn save(&self, suppression: &EmailSuppression) -> DomainResult<()> {
+        debug!("Suppressing email: {}", suppression.email);
+
+        sqlx::query(&format!(
+            "INSERT INTO email_suppressions ({}) VALUES (?, ?, ?, ?, ?) ON CONFLICT (email) DO NOTHING",
+            EMAIL_SUPPRESSION_COLUMNS
+        ))
+        .bind(suppression.email.trim().to_lowercase())
+        .bind(delivery_failure_to_string(suppression.reason))
+        .bind(&suppression.provider)
+        .bind(&suppression.detail)
+        .bind(suppression.created_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, email: &str) -> DomainResult<()> {
+        debug!("Lifting email suppression for: {}", email);
+
+        let result = sqlx::query("DELETE FROM email_suppressions WHERE email = ?")
+            .bind(email.trim().to_lowercase())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found_by_field("EmailSuppression", "email", email));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::DeliveryFailure;
+
+    #[tokio::test]
+    async fn test_suppression_keeps_first_reason_and_can_be_lifted() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEmailSuppressionRepository::new(db.pool().clone());
+
+        assert!(repository.find_by_email("kari@example.no").await.unwrap().is_none());
+
+        let bounce = EmailSuppression::new(
+            "Kari@Example.no",
+            DeliveryFailure::Bounce,
+            "ses",
+            Some("550 5.1.1 user unknown".to_string()),
+        );
+        repository.save(&bounce).await.unwrap();
+        let complaint = EmailSuppression::new("kari@example.no", DeliveryFailure::Complaint, "sendgrid", None);
+        repository.save(&complaint).await.unwrap();
+
+        let found = repository.find_by_email("KARI@example.no").await.unwrap().unwrap();
+        assert_eq!(found.email, "kari@example.no");
+        assert_eq!(found.reason, DeliveryFailure::Bounce);
+        assert_eq!(found.provider, "ses");
+        assert_eq!(found.detail.as_deref(), Some("550 5.1.1 user unknown"));
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+
+        repository.delete("kari@example.no").await.unwrap();
+        assert!(repository.find_by_email("kari@example.no").await.unwrap().is_none());
+        assert!(repository.delete("kari@example.no").await.is_err());
+    }
+}
@@ -0,0 +1,247 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventAttachmentRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{AttachmentCategory, AttachmentVisibility, DomainResult, EventAttachment};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Row, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+// Attachments are stored in the `event_files` table; `file_path` holds the media storage key
+const ATTACHMENT_COLUMNS: &str = "id, event_id, uploader_id, uploader_name, filename, original_filename, file_path, file_size, mime_type, title, description, category, is_public, download_count, uploaded_at";
+
+#[derive(Clone)]
+pub struct SqliteEventAttachmentRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventAttachmentRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventAttachment using SafeRowGet
+    fn row_to_attachment(row: &sqlx::sqlite::SqliteRow) -> Result<EventAttachment, RowConversionError> {
+        let visibility = if row.get_bool("is_public")? {
+            AttachmentVisibility::Public
+        } else {
+            AttachmentVisibility::AttendeesOnly
+        };
+
+        Ok(EventAttachment {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            uploader_id: row.get_optional_uuid("uploader_id")?,
+            uploader_name: row.get_optional_string("uploader_name")?,
+            original_filename: row.get_string("original_filename")?,
+            filename: row.get_string("filename")?,
+            storage_key: row.get_string("file_path")?,
+            file_size: row.try_get("file_size")
+                .map_err(|cause| RowConversionError::MissingField { field: "file_size", cause })?,
+            mime_type: row.get_string("mime_type")?,
+            title: row.get_optional_string("title")?,
+            description: row.get_optional_string("description")?,
+            category: row.get_attachment_category("category")?,
+            visibility,
+            download_count: row.try_get("download_count")
+                .map_err(|cause| RowConversionError::MissingField { field: "download_count", cause })?,
+            uploaded_at: row.get_datetime("uploaded_at")?,
+        })
+    }
+
+    fn category_to_string(category: AttachmentCategory) -> &'static str {
+        match category {
+            AttachmentCategory::Presentation => "presentation",
+            AttachmentCategory::Document => "document",
+            AttachmentCategory::Photo => "photo",
+            AttachmentCategory::Video => "video",
+            AttachmentCategory::Audio => "audio",
+            AttachmentCategory::Other => "other",
+        }
+    }
+}
+
+#[async_trait]
+impl EventAttachmentRepository for SqliteEventAttachmentRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventAttachment>> {
+        debug!("Finding attachment by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_files WHERE id = ?", ATTACHMENT_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_attachment(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventAttachment>> {
+        debug!("Listing attachments for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_files WHERE event_id = ? ORDER BY uploaded_at, rowid",
+            ATTACHMENT_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let attachments: Result<Vec<EventAttachment>, RowConversionError> = rows.iter()
+            .map(Self::row_to_attachment)
+            .collect();
+        let attachments = attachments.map_err(InfrastructureError::from)?;
+
+        debug!("Listed {} attachments for event {}", attachments.len(), event_id);
+        Ok(attachments)
+    }
+
+    #[instrument(skip(self, attachment))]
+    async fn create(&self, attachment: &EventAttachment) -> DomainResult<()> {
+        debug!("Creating attachment {} on event {}", attachment.id, attachment.event_id);
+
+        sqlx::query(
+            "INSERT INTO event_files (id, event_id, uploader_id, uploader_name, filename, original_filename, file_path, file_size, mime_type, title, description, category, is_public, download_count, uploaded_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(attachment.id.to_string())
+        .bind(attachment.event_id.to_string())
+        .bind(attachment.uploader_id.map(|id| id.to_string()))
+        .bind(attachment.uploader_name.as_deref())
+        .bind(&attachment.filename)
+        .bind(&attachment.original_filename)
+        .bind(&attachment.storage_key)
+        .bind(attachment.file_size)
+        .bind(&attachment.mime_type)
+        .bind(attachment.title.as_deref())
+        .bind(attachment.description.as_deref())
+        .bind(Self::category_to_string(attachment.category))
+        .bind(attachment.is_public())
+        .bind(attachment.download_count)
+        .bind(attachment.uploaded_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created attachment with id: {}", attachment.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting attachment with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM event_files WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventAttachment", id));
+        }
+        debug!("Successfully deleted attachment with id: {}", id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn record_download(&self, id: Uuid, downloaded_at: DateTime<Utc>) -> DomainResult<()> {
+        debug!("Recording download of attachment: {}", id);
+
+        sqlx::query(
+            "UPDATE event_files SET download_count = download_count + 1, last_downloaded_at = ? WHERE id = ?"
+        )
+        .bind(downloaded_at.naive_utc())
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    fn create_test_attachment(event_id: Uuid, uploader_id: Uuid, name: &str, visibility: AttachmentVisibility) -> EventAttachment {
+        let id = Uuid::new_v4();
+        EventAttachment {
+            id,
+            event_id,
+            uploader_id: Some(uploader_id),
+            uploader_name: Some("Test User".to_string()),
+            original_filename: name.to_string(),
+            filename: EventAttachment::sanitize_filename(name),
+            storage_key: format!("events/{}/attachments/{}", event_id, id),
+            file_size: 2048,
+            mime_type: "application/pdf".to_string(),
+            title: Some("Site map".to_string()),
+            description: None,
+            category: AttachmentCategory::Document,
+            visibility,
+            download_count: 0,
+            uploaded_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_attachments_round_trip_with_visibility() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventAttachmentRepository::new(db.pool().clone());
+
+        let organizer = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+        let other_event = db.seed_event(organizer).await;
+
+        let map = create_test_attachment(event, organizer, "Site map.pdf", AttachmentVisibility::Public);
+        let slides = create_test_attachment(event, organizer, "slides.pdf", AttachmentVisibility::AttendeesOnly);
+        repository.create(&map).await.unwrap();
+        repository.create(&slides).await.unwrap();
+        repository
+            .create(&create_test_attachment(other_event, organizer, "other.pdf", AttachmentVisibility::Public))
+            .await
+            .unwrap();
+
+        let listed = repository.find_by_event_id(event).await.unwrap();
+        let ids: Vec<Uuid> = listed.iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![map.id, slides.id]);
+        assert!(listed[0].is_public());
+        assert_eq!(listed[1].visibility, AttachmentVisibility::AttendeesOnly);
+        assert_eq!(listed[0].filename, "Site_map.pdf");
+        assert_eq!(listed[0].original_filename, "Site map.pdf");
+        assert_eq!(listed[0].storage_key, map.storage_key);
+        assert_eq!(listed[0].category, AttachmentCategory::Document);
+        assert_eq!(listed[0].file_size, 2048);
+    }
+
+    #[tokio::test]
+    async fn test_download_count_and_delete() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventAttachmentRepository::new(db.pool().clone());
+
+        let organizer = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+
+        let attachment = create_test_attachment(event, organizer, "agenda.pdf", AttachmentVisibility::Public);
+        repository.create(&attachment).await.unwrap();
+
+        repository.record_download(attachment.id, Utc::now()).await.unwrap();
+        repository.record_download(attachment.id, Utc::now()).await.unwrap();
+        let found = repository.find_by_id(attachment.id).await.unwrap().unwrap();
+        assert_eq!(found.download_count, 2);
+
+        repository.delete(attachment.id).await.unwrap();
+        assert!(repository.find_by_id(attachment.id).await.unwrap().is_none());
+        assert!(repository.delete(attachment.id).await.is_err());
+    }
+}
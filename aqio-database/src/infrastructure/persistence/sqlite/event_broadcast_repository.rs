@@ -0,0 +1,216 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventBroadcastRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::{broadcast_audience_to_string, broadcast_status_to_string},
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{BroadcastDelivery, DomainResult, EventBroadcast};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const BROADCAST_COLUMNS: &str = "id, event_id, sender_id, audience, subject, message, status, scheduled_for, sent_at, \
+     recipients, delivered, suppressed, failed, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteEventBroadcastRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventBroadcastRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventBroadcast using SafeRowGet
+    fn row_to_broadcast(row: &sqlx::sqlite::SqliteRow) -> Result<EventBroadcast, RowConversionError> {
+        Ok(EventBroadcast {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            sender_id: row.get_uuid("sender_id")?,
+            audience: row.get_broadcast_audience("audience")?,
+            subject: row.get_string("subject")?,
+            message: row.get_string("message")?,
+            status: row.get_broadcast_status("status")?,
+            scheduled_for: row.get_optional_datetime("scheduled_for")?,
+            sent_at: row.get_optional_datetime("sent_at")?,
+            delivery: BroadcastDelivery {
+                recipients: row.get_i32("recipients")?,
+                delivered: row.get_i32("delivered")?,
+                suppressed: row.get_i32("suppressed")?,
+                failed: row.get_i32("failed")?,
+            },
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl EventBroadcastRepository for SqliteEventBroadcastRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventBroadcast>> {
+        debug!("Finding broadcast by ID: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_broadcasts WHERE id = ?", BROADCAST_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_broadcast(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventBroadcast>> {
+        debug!("Finding broadcasts of event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_broadcasts WHERE event_id = ? ORDER BY created_at DESC",
+            BROADCAST_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_broadcast(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn find_due(&self, now: DateTime<Utc>) -> DomainResult<Vec<EventBroadcast>> {
+        debug!("Finding broadcasts due at {}", now);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_broadcasts WHERE status = 'scheduled' AND scheduled_for <= ? ORDER BY scheduled_for",
+            BROADCAST_COLUMNS
+        ))
+        .bind(now.naive_utc())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_broadcast(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, broadcast))]
+    async fn create(&self, broadcast: &EventBroadcast) -> DomainResult<()> {
+        debug!("Creating broadcast {} of event {}", broadcast.id, broadcast.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO event_broadcasts ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            BROADCAST_COLUMNS
+        ))
+        .bind(broadcast.id.to_string())
+        .bind(broadcast.event_id.to_string())
+        .bind(broadcast.sender_id.to_string())
+        .bind(broadcast_audience_to_string(broadcast.audience))
+        .bind(&broadcast.subject)
+        .bind(&broadcast.message)
+        .bind(broadcast_status_to_string(broadcast.status))
+        .bind(broadcast.scheduled_for.map(|at| at.naive_utc()))
+        .bind(broadcast.sent_at.map(|at| at.naive_utc()))
+        .bind(broadcast.delivery.recipients)
+        .bind(broadcast.delivery.delivered)
+        .bind(broadcast.delivery.suppressed)
+        .bind(broadcast.delivery.failed)
+        .bind(broadcast.created_at.naive_utc())
+        .bind(broadcast.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, broadcast))]
+    async fn update(&self, broadcast: &EventBroadcast) -> DomainResult<()> {
+        debug!("Updating broadcast: {}", broadcast.id);
+
+        sqlx::query(
+            "UPDATE event_broadcasts SET status = ?, sent_at = ?, recipients = ?, delivered = ?, suppressed = ?, \
+             failed = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(broadcast_status_to_string(broadcast.status))
+        .bind(broadcast.sent_at.map(|at| at.naive_utc()))
+        .bind(broadcast.delivery.recipients)
+        .bind(broadcast.delivery.delivered)
+        .bind(broadcast.delivery.suppressed)
+        .bind(broadcast.delivery.failed)
+        .bind(broadcast.updated_at.naive_utc())
+        .bind(broadcast.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::{BroadcastAudience, BroadcastStatus};
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_broadcasts_round_trip_and_only_scheduled_ones_come_due() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventBroadcastRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+        let now = Utc::now();
+
+        let mut sent = EventBroadcast::new(event_id, organizer, BroadcastAudience::CheckedIn, "Parking", "Use gate B", None);
+        sent.mark_sent(BroadcastDelivery { recipients: 3, delivered: 1, suppressed: 1, failed: 1 }, now);
+        repository.create(&sent).await.unwrap();
+        let due = EventBroadcast::new(
+            event_id,
+            organizer,
+            BroadcastAudience::Waitlisted,
+            "Still waiting?",
+            "Spots may open up",
+            Some(now - Duration::minutes(5)),
+        );
+        repository.create(&due).await.unwrap();
+        let mut later = EventBroadcast::new(
+            event_id,
+            organizer,
+            BroadcastAudience::All,
+            "Tomorrow",
+            "See you there",
+            Some(now + Duration::hours(1)),
+        );
+        repository.create(&later).await.unwrap();
+
+        let found = repository.find_by_id(sent.id).await.unwrap().unwrap();
+        assert_eq!(found.audience, BroadcastAudience::CheckedIn);
+        assert_eq!(found.status, BroadcastStatus::Sent);
+        assert_eq!(found.delivery, sent.delivery);
+        assert_eq!((found.subject.as_str(), found.message.as_str()), ("Parking", "Use gate B"));
+        assert_eq!(repository.find_by_event_id(event_id).await.unwrap().len(), 3);
+
+        let ids = |broadcasts: Vec<EventBroadcast>| broadcasts.iter().map(|b| b.id).collect::<Vec<_>>();
+        assert_eq!(ids(repository.find_due(now).await.unwrap()), vec![due.id]);
+        assert_eq!(ids(repository.find_due(now + Duration::hours(2)).await.unwrap()), vec![due.id, later.id]);
+
+        later.status = BroadcastStatus::Cancelled;
+        repository.update(&later).await.unwrap();
+        assert_eq!(ids(repository.find_due(now + Duration::hours(2)).await.unwrap()), vec![due.id]);
+
+        db.execute(&format!("DELETE FROM events WHERE id = '{}'", event_id)).await;
+        assert!(repository.find_by_event_id(event_id).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,411 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventBudgetRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{BudgetLine, DomainResult, EventExpense, ExpenseReceipt};
+use async_trait::async_trait;
+use sqlx::{Pool, Row, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const LINE_COLUMNS: &str = "id, event_id, name, planned_amount, notes, created_at, updated_at";
+const EXPENSE_COLUMNS: &str = "id, event_id, budget_line_id, description, vendor, amount, incurred_on, receipt_filename, receipt_storage_key, receipt_size, receipt_mime_type, recorded_by, recorded_by_name, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteEventBudgetRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventBudgetRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to BudgetLine using SafeRowGet
+    fn row_to_line(row: &sqlx::sqlite::SqliteRow) -> Result<BudgetLine, RowConversionError> {
+        Ok(BudgetLine {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            name: row.get_string("name")?,
+            planned_amount: row.try_get("planned_amount")
+                .map_err(|cause| RowConversionError::MissingField { field: "planned_amount", cause })?,
+            notes: row.get_optional_string("notes")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    // Helper method to convert database row to EventExpense using SafeRowGet
+    fn row_to_expense(row: &sqlx::sqlite::SqliteRow) -> Result<EventExpense, RowConversionError> {
+        // The receipt columns are set and cleared together
+        let receipt = match row.get_optional_string("receipt_storage_key")? {
+            Some(storage_key) => Some(ExpenseReceipt {
+                original_filename: row.get_optional_string("receipt_filename")?.unwrap_or_default(),
+                storage_key,
+                file_size: row.try_get::<Option<i64>, _>("receipt_size")
+                    .map_err(|cause| RowConversionError::MissingField { field: "receipt_size", cause })?
+                    .unwrap_or_default(),
+                mime_type: row.get_optional_string("receipt_mime_type")?
+                    .unwrap_or_else(|| "application/octet-stream".to_string()),
+            }),
+            None => None,
+        };
+
+        Ok(EventExpense {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            budget_line_id: row.get_optional_uuid("budget_line_id")?,
+            description: row.get_string("description")?,
+            vendor: row.get_optional_string("vendor")?,
+            amount: row.try_get("amount")
+                .map_err(|cause| RowConversionError::MissingField { field: "amount", cause })?,
+            incurred_on: row.get_date("incurred_on")?,
+            receipt,
+            recorded_by: row.get_optional_uuid("recorded_by")?,
+            recorded_by_name: row.get_optional_string("recorded_by_name")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl EventBudgetRepository for SqliteEventBudgetRepository {
+    #[instrument(skip(self))]
+    async fn find_line_by_id(&self, id: Uuid) -> DomainResult<Option<BudgetLine>> {
+        debug!("Finding budget line by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_budget_lines WHERE id = ?", LINE_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_line(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_lines_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<BudgetLine>> {
+        debug!("Listing budget lines for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_budget_lines WHERE event_id = ? ORDER BY created_at, rowid",
+            LINE_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let lines: Result<Vec<BudgetLine>, RowConversionError> = rows.iter()
+            .map(Self::row_to_line)
+            .collect();
+        let lines = lines.map_err(InfrastructureError::from)?;
+
+        debug!("Listed {} budget lines for event {}", lines.len(), event_id);
+        Ok(lines)
+    }
+
+    #[instrument(skip(self, line))]
+    async fn create_line(&self, line: &BudgetLine) -> DomainResult<()> {
+        debug!("Creating budget line {} on event {}", line.id, line.event_id);
+
+        sqlx::query(
+            "INSERT INTO event_budget_lines (id, event_id, name, planned_amount, notes, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(line.id.to_string())
+        .bind(line.event_id.to_string())
+        .bind(&line.name)
+        .bind(line.planned_amount)
+        .bind(line.notes.as_deref())
+        .bind(line.created_at.naive_utc())
+        .bind(line.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created budget line with id: {}", line.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self, line))]
+    async fn update_line(&self, line: &BudgetLine) -> DomainResult<()> {
+        debug!("Updating budget line: {}", line.id);
+
+        let result = sqlx::query(
+            "UPDATE event_budget_lines SET name = ?, planned_amount = ?, notes = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(&line.name)
+        .bind(line.planned_amount)
+        .bind(line.notes.as_deref())
+        .bind(line.updated_at.naive_utc())
+        .bind(line.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("BudgetLine", line.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_line(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting budget line with id: {}", id);
+
+        // Unassign explicitly rather than relying on the foreign key pragma being on
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        sqlx::query("UPDATE event_expenses SET budget_line_id = NULL WHERE budget_line_id = ?")
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        let result = sqlx::query("DELETE FROM event_budget_lines WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("BudgetLine", id));
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        debug!("Successfully deleted budget line with id: {}", id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_expense_by_id(&self, id: Uuid) -> DomainResult<Option<EventExpense>> {
+        debug!("Finding expense by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_expenses WHERE id = ?", EXPENSE_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_expense(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_expenses_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventExpense>> {
+        debug!("Listing expenses for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_expenses WHERE event_id = ? ORDER BY incurred_on, created_at, rowid",
+            EXPENSE_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let expenses: Result<Vec<EventExpense>, RowConversionError> = rows.iter()
+            .map(Self::row_to_expense)
+            .collect();
+        let expenses = expenses.map_err(InfrastructureError::from)?;
+
+        debug!("Listed {} expenses for event {}", expenses.len(), event_id);
+        Ok(expenses)
+    }
+
+    #[instrument(skip(self, expense))]
+    async fn create_expense(&self, expense: &EventExpense) -> DomainResult<()> {
+        debug!("Creating expense {} on event {}", expense.id, expense.event_id);
+
+        let receipt = expense.receipt.as_ref();
+        sqlx::query(
+            "INSERT INTO event_expenses (id, event_id, budget_line_id, description, vendor, amount, incurred_on, receipt_filename, receipt_storage_key, receipt_size, receipt_mime_type, recorded_by, recorded_by_name, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(expense.id.to_string())
+        .bind(expense.event_id.to_string())
+        .bind(expense.budget_line_id.map(|id| id.to_string()))
+        .bind(&expense.description)
+        .bind(expense.vendor.as_deref())
+        .bind(expense.amount)
+        .bind(expense.incurred_on)
+        .bind(receipt.map(|r| r.original_filename.as_str()))
+        .bind(receipt.map(|r| r.storage_key.as_str()))
+        .bind(receipt.map(|r| r.file_size))
+        .bind(receipt.map(|r| r.mime_type.as_str()))
+        .bind(expense.recorded_by.map(|id| id.to_string()))
+        .bind(expense.recorded_by_name.as_deref())
+        .bind(expense.created_at.naive_utc())
+        .bind(expense.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created expense with id: {}", expense.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self, expense))]
+    async fn update_expense(&self, expense: &EventExpense) -> DomainResult<()> {
+        debug!("Updating expense: {}", expense.id);
+
+        let receipt = expense.receipt.as_ref();
+        let result = sqlx::query(
+            "UPDATE event_expenses SET budget_line_id = ?, description = ?, vendor = ?, amount = ?, incurred_on = ?, receipt_filename = ?, receipt_storage_key = ?, receipt_size = ?, receipt_mime_type = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(expense.budget_line_id.map(|id| id.to_string()))
+        .bind(&expense.description)
+        .bind(expense.vendor.as_deref())
+        .bind(expense.amount)
+        .bind(expense.incurred_on)
+        .bind(receipt.map(|r| r.original_filename.as_str()))
+        .bind(receipt.map(|r| r.storage_key.as_str()))
+        .bind(receipt.map(|r| r.file_size))
+        .bind(receipt.map(|r| r.mime_type.as_str()))
+        .bind(expense.updated_at.naive_utc())
+        .bind(expense.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventExpense", expense.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_expense(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting expense with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM event_expenses WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventExpense", id));
+        }
+        debug!("Successfully deleted expense with id: {}", id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::{BudgetSummary, User, UserRole};
+    use chrono::{NaiveDate, Utc};
+
+    fn create_test_user(id: Uuid) -> User {
+        User {
+            id,
+            keycloak_id: format!("keycloak_{}", id),
+            email: "organizer@example.com".to_string(),
+            name: "Test Organizer".to_string(),
+            company_id: None,
+            role: UserRole::Organizer,
+            is_active: true,
+            timezone: None,
+            locale: None,
+            theme: None,
+            calendar_view: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_lines_and_expenses_round_trip() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventBudgetRepository::new(db.pool().clone());
+
+        let organizer = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+        let user = create_test_user(organizer);
+
+        let mut venue = BudgetLine::new(event, "Venue", 5_000_000);
+        venue.notes = Some("Incl. AV".to_string());
+        repository.create_line(&venue).await.unwrap();
+
+        let mut deposit = EventExpense::new(event, &user, "Deposit", 2_000_000, day(12));
+        deposit.budget_line_id = Some(venue.id);
+        deposit.vendor = Some("Hotel Bergen".to_string());
+        let taxi = EventExpense::new(event, &user, "Taxi", 35_000, day(3));
+        repository.create_expense(&deposit).await.unwrap();
+        repository.create_expense(&taxi).await.unwrap();
+
+        let lines = repository.find_lines_by_event_id(event).await.unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].planned_amount, 5_000_000);
+        assert_eq!(lines[0].notes.as_deref(), Some("Incl. AV"));
+
+        // Oldest first by date incurred
+        let expenses = repository.find_expenses_by_event_id(event).await.unwrap();
+        let ids: Vec<Uuid> = expenses.iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![taxi.id, deposit.id]);
+        assert_eq!(expenses[1].incurred_on, day(12));
+        assert_eq!(expenses[1].budget_line_id, Some(venue.id));
+        assert_eq!(expenses[1].recorded_by_name.as_deref(), Some("Test Organizer"));
+        assert!(expenses[1].receipt.is_none());
+
+        let summary = BudgetSummary::new(&lines, &expenses);
+        assert_eq!((summary.planned, summary.spent, summary.unassigned), (5_000_000, 2_035_000, 35_000));
+
+        venue.planned_amount = 4_500_000;
+        repository.update_line(&venue).await.unwrap();
+        assert_eq!(
+            repository.find_line_by_id(venue.id).await.unwrap().unwrap().planned_amount,
+            4_500_000
+        );
+    }
+
+    #[tokio::test]
+    async fn test_receipt_update_and_line_delete_unassigns_expenses() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventBudgetRepository::new(db.pool().clone());
+
+        let organizer = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+        let user = create_test_user(organizer);
+
+        let line = BudgetLine::new(event, "Catering", 1_000_000);
+        repository.create_line(&line).await.unwrap();
+        let mut lunch = EventExpense::new(event, &user, "Lunch", 800_000, day(12));
+        lunch.budget_line_id = Some(line.id);
+        repository.create_expense(&lunch).await.unwrap();
+
+        lunch.receipt = Some(ExpenseReceipt {
+            original_filename: "kvittering.pdf".to_string(),
+            storage_key: lunch.receipt_key("kvittering.pdf"),
+            file_size: 1024,
+            mime_type: "application/pdf".to_string(),
+        });
+        repository.update_expense(&lunch).await.unwrap();
+        let found = repository.find_expense_by_id(lunch.id).await.unwrap().unwrap();
+        let receipt = found.receipt.unwrap();
+        assert_eq!(receipt.original_filename, "kvittering.pdf");
+        assert_eq!(receipt.file_size, 1024);
+        assert!(receipt.storage_key.starts_with(&format!("events/{}/receipts/", event)));
+
+        repository.delete_line(line.id).await.unwrap();
+        assert!(repository.find_line_by_id(line.id).await.unwrap().is_none());
+        let found = repository.find_expense_by_id(lunch.id).await.unwrap().unwrap();
+        assert_eq!(found.budget_line_id, None);
+        assert!(repository.delete_line(line.id).await.is_err());
+
+        repository.delete_expense(lunch.id).await.unwrap();
+        assert!(repository.find_expense_by_id(lunch.id).await.unwrap().is_none());
+        assert!(repository.delete_expense(lunch.id).await.is_err());
+    }
+}
@@ -106,6 +106,42 @@ impl EventCategoryRepository for SqliteEventCategoryRepository {
         }
     }
 
+    #[instrument(skip(self, ids))]
+    async fn find_by_ids(&self, ids: &[String]) -> DomainResult<Vec<EventCategory>> {
+        debug!("Finding {} event categories by id", ids.len());
+
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new(
+            "SELECT id, name, description, color_hex, icon_name, is_active, created_at FROM event_categories WHERE id IN ("
+        );
+        let mut separated = query_builder.separated(", ");
+        for id in ids {
+            separated.push_bind(id);
+        }
+        query_builder.push(")");
+
+        let result = query_builder.build().fetch_all(&self.pool).await;
+
+        match result {
+            Ok(rows) => {
+                let categories: Result<Vec<EventCategory>, RowConversionError> = rows.iter()
+                    .map(Self::row_to_category)
+                    .collect();
+                categories.map_err(|e| Self::conversion_error_to_infrastructure_error(e).into())
+            }
+            Err(e) => {
+                let infrastructure_error = InfrastructureError::from(e);
+                match infrastructure_error {
+                    InfrastructureError::DomainError { source } => Err(source),
+                    other => Err(other.into()),
+                }
+            }
+        }
+    }
+
     #[instrument(skip(self))]
     async fn find_by_id(&self, id: &str) -> DomainResult<Option<EventCategory>> {
         debug!("Finding event category by id: {}", id);
@@ -241,29 +277,15 @@ impl EventCategoryRepository for SqliteEventCategoryRepository {
 mod tests {
     use super::*;
     use chrono::Utc;
-    use sqlx::{Pool, Sqlite};
-
-    // Test helper to create an in-memory SQLite database with schema
-    async fn create_test_db() -> Pool<Sqlite> {
-        let pool = sqlx::SqlitePool::connect(":memory:").await.unwrap();
-        
-        // Create the event_categories table
-        sqlx::query(r#"
-            CREATE TABLE event_categories (
-                id TEXT PRIMARY KEY,
-                name TEXT NOT NULL UNIQUE,
-                description TEXT,
-                color_hex TEXT,
-                icon_name TEXT,
-                is_active BOOLEAN NOT NULL DEFAULT TRUE,
-                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
-            )
-        "#)
-        .execute(&pool)
-        .await
-        .unwrap();
-        
-        pool
+    use crate::testing::TestDb;
+
+    // Test helper to create an isolated database with the real schema.
+    // The default categories seeded by the migrations are cleared so each test
+    // starts from an empty table.
+    async fn create_test_db() -> TestDb {
+        let db = TestDb::in_memory().await;
+        db.execute("DELETE FROM event_categories").await;
+        db
     }
     
     // Helper function to create a test category
@@ -282,8 +304,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_create_and_find_category() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventCategoryRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteEventCategoryRepository::new(db.pool().clone());
         let category = create_test_category("test", "Test Category");
 
         // Create the category
@@ -301,10 +323,29 @@ mod tests {
         assert!(found_category.is_active);
     }
 
+    #[tokio::test]
+    async fn test_find_by_ids() {
+        let db = create_test_db().await;
+        let repository = SqliteEventCategoryRepository::new(db.pool().clone());
+
+        for (id, name) in [("conf", "Conference"), ("workshop", "Workshop"), ("meetup", "Meetup")] {
+            repository.create(&create_test_category(id, name)).await.unwrap();
+        }
+
+        let ids = vec!["conf".to_string(), "meetup".to_string(), "missing".to_string()];
+        let found = repository.find_by_ids(&ids).await.unwrap();
+        let mut found_ids: Vec<_> = found.iter().map(|c| c.id.as_str()).collect();
+        found_ids.sort();
+        assert_eq!(found_ids, vec!["conf", "meetup"]);
+
+        let empty = repository.find_by_ids(&[]).await.unwrap();
+        assert!(empty.is_empty());
+    }
+
     #[tokio::test]
     async fn test_update_category() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventCategoryRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteEventCategoryRepository::new(db.pool().clone());
         let mut category = create_test_category("update_test", "Original Name");
 
         // Create the category
@@ -329,8 +370,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_active_categories() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventCategoryRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteEventCategoryRepository::new(db.pool().clone());
 
         // Create multiple categories, some active, some inactive
         let mut active_category = create_test_category("active", "Active Category");
@@ -357,8 +398,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_all_categories() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventCategoryRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteEventCategoryRepository::new(db.pool().clone());
 
         // Create multiple categories
         let category1 = create_test_category("cat1", "Category 1");
@@ -386,8 +427,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_find_nonexistent_category() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventCategoryRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteEventCategoryRepository::new(db.pool().clone());
 
         // Try to find a category that doesn't exist
         let result = repository.find_by_id("nonexistent").await.unwrap();
@@ -396,8 +437,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_update_nonexistent_category() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventCategoryRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteEventCategoryRepository::new(db.pool().clone());
         let category = create_test_category("nonexistent", "Non-existent Category");
 
         // Try to update a category that doesn't exist
@@ -407,8 +448,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_empty_lists() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventCategoryRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteEventCategoryRepository::new(db.pool().clone());
 
         // Test empty list_active
         let active_categories = repository.list_active().await.unwrap();
@@ -421,8 +462,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_category_ordering() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventCategoryRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteEventCategoryRepository::new(db.pool().clone());
 
         // Create categories in non-alphabetical order
         let category_z = create_test_category("z", "Z Category");
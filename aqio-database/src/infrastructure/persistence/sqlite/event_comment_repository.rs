@@ -0,0 +1,200 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventCommentRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, EventComment};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const COMMENT_COLUMNS: &str = "id, event_id, parent_comment_id, author_id, author_name, content, deleted_at, deleted_by, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteEventCommentRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventCommentRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventComment using SafeRowGet
+    fn row_to_comment(row: &sqlx::sqlite::SqliteRow) -> Result<EventComment, RowConversionError> {
+        Ok(EventComment {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            parent_comment_id: row.get_optional_uuid("parent_comment_id")?,
+            author_id: row.get_optional_uuid("author_id")?,
+            author_name: row.get_optional_string("author_name")?,
+            content: row.get_string("content")?,
+            deleted_at: row.get_optional_datetime("deleted_at")?,
+            deleted_by: row.get_optional_uuid("deleted_by")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl EventCommentRepository for SqliteEventCommentRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventComment>> {
+        debug!("Finding comment by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_comments WHERE id = ?", COMMENT_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_comment(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventComment>> {
+        debug!("Listing comments for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_comments WHERE event_id = ? ORDER BY created_at, rowid",
+            COMMENT_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let comments: Result<Vec<EventComment>, RowConversionError> = rows.iter()
+            .map(Self::row_to_comment)
+            .collect();
+        let comments = comments.map_err(InfrastructureError::from)?;
+
+        debug!("Listed {} comments for event {}", comments.len(), event_id);
+        Ok(comments)
+    }
+
+    #[instrument(skip(self, comment))]
+    async fn create(&self, comment: &EventComment) -> DomainResult<()> {
+        debug!("Creating comment {} on event {}", comment.id, comment.event_id);
+
+        sqlx::query(
+            "INSERT INTO event_comments (id, event_id, parent_comment_id, author_id, author_name, content, deleted_at, deleted_by, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(comment.id.to_string())
+        .bind(comment.event_id.to_string())
+        .bind(comment.parent_comment_id.map(|id| id.to_string()))
+        .bind(comment.author_id.map(|id| id.to_string()))
+        .bind(comment.author_name.as_deref())
+        .bind(&comment.content)
+        .bind(comment.deleted_at.map(|at| at.naive_utc()))
+        .bind(comment.deleted_by.map(|id| id.to_string()))
+        .bind(comment.created_at.naive_utc())
+        .bind(comment.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created comment with id: {}", comment.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn soft_delete(&self, id: Uuid, deleted_by: Uuid, deleted_at: DateTime<Utc>) -> DomainResult<()> {
+        debug!("Soft deleting comment with id: {}", id);
+
+        // Deleting twice keeps the original deletion
+        let result = sqlx::query(
+            "UPDATE event_comments SET deleted_at = COALESCE(deleted_at, ?), deleted_by = COALESCE(deleted_by, ?), updated_at = ? WHERE id = ?"
+        )
+        .bind(deleted_at.naive_utc())
+        .bind(deleted_by.to_string())
+        .bind(deleted_at.naive_utc())
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventComment", id));
+        }
+        debug!("Successfully soft deleted comment with id: {}", id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    fn create_test_comment(event_id: Uuid, author_id: Uuid, parent: Option<Uuid>, content: &str) -> EventComment {
+        let now = Utc::now();
+        EventComment {
+            id: Uuid::new_v4(),
+            event_id,
+            parent_comment_id: parent,
+            author_id: Some(author_id),
+            author_name: Some("Test User".to_string()),
+            content: content.to_string(),
+            deleted_at: None,
+            deleted_by: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_thread_is_listed_oldest_first() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventCommentRepository::new(db.pool().clone());
+
+        let author = db.seed_user().await;
+        let event = db.seed_event(author).await;
+        let other_event = db.seed_event(author).await;
+
+        let question = create_test_comment(event, author, None, "Is there parking?");
+        let answer = create_test_comment(event, author, Some(question.id), "Yes, behind the hall");
+        repository.create(&question).await.unwrap();
+        repository.create(&answer).await.unwrap();
+        repository
+            .create(&create_test_comment(other_event, author, None, "Elsewhere"))
+            .await
+            .unwrap();
+
+        let thread = repository.find_by_event_id(event).await.unwrap();
+        let ids: Vec<Uuid> = thread.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![question.id, answer.id]);
+        assert_eq!(thread[1].parent_comment_id, Some(question.id));
+        assert_eq!(thread[1].author_name.as_deref(), Some("Test User"));
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_keeps_row_and_first_deletion() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventCommentRepository::new(db.pool().clone());
+
+        let author = db.seed_user().await;
+        let moderator = db.seed_user().await;
+        let event = db.seed_event(moderator).await;
+
+        let comment = create_test_comment(event, author, None, "Wrong room number");
+        repository.create(&comment).await.unwrap();
+
+        repository.soft_delete(comment.id, moderator, Utc::now()).await.unwrap();
+        repository.soft_delete(comment.id, author, Utc::now()).await.unwrap();
+
+        let deleted = repository.find_by_id(comment.id).await.unwrap().unwrap();
+        assert!(deleted.is_deleted());
+        assert_eq!(deleted.deleted_by, Some(moderator));
+        assert_eq!(repository.find_by_event_id(event).await.unwrap().len(), 1);
+
+        let missing = repository.soft_delete(Uuid::new_v4(), author, Utc::now()).await;
+        assert!(missing.is_err());
+    }
+}
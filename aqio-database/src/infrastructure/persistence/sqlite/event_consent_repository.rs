@@ -0,0 +1,265 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventConsentRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::consent_kind_to_string,
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{DomainResult, EventConsent, RegistrationConsent};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const CONSENT_COLUMNS: &str = "id, event_id, kind, text, required, version, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteEventConsentRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventConsentRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventConsent using SafeRowGet
+    fn row_to_consent(row: &sqlx::sqlite::SqliteRow) -> Result<EventConsent, RowConversionError> {
+        Ok(EventConsent {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            kind: row.get_consent_kind("kind")?,
+            text: row.get_string("text")?,
+            required: row.get_bool("required")?,
+            version: row.get_i32("version")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn row_to_answer(row: &sqlx::sqlite::SqliteRow) -> Result<RegistrationConsent, RowConversionError> {
+        Ok(RegistrationConsent {
+            registration_id: row.get_uuid("registration_id")?,
+            consent_id: row.get_uuid("consent_id")?,
+            kind: row.get_consent_kind("kind")?,
+            version: row.get_i32("version")?,
+            text: row.get_string("text")?,
+            accepted: row.get_bool("accepted")?,
+            answered_at: row.get_datetime("answered_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl EventConsentRepository for SqliteEventConsentRepository {
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventConsent>> {
+        debug!("Finding consent checkboxes for event: {}", event_id);
+
+        let rows = sqlx::query(&format!("SELECT {} FROM event_consents WHERE event_id = ?", CONSENT_COLUMNS))
+            .bind(event_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        let mut consents = rows
+            .iter()
+            .map(|row| Self::row_to_consent(row).map_err(InfrastructureError::from))
+            .collect::<Result<Vec<_>, _>>()?;
+        consents.sort_by_key(|consent| consent.kind);
+        Ok(consents)
+    }
+
+    #[instrument(skip(self, consent))]
+    async fn create(&self, consent: &EventConsent) -> DomainResult<()> {
+        debug!("Creating {:?} consent on event {}", consent.kind, consent.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO event_consents ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            CONSENT_COLUMNS
+        ))
+        .bind(consent.id.to_string())
+        .bind(consent.event_id.to_string())
+        .bind(consent_kind_to_string(consent.kind))
+        .bind(&consent.text)
+        .bind(consent.required)
+        .bind(consent.version)
+        .bind(consent.created_at.naive_utc())
+        .bind(consent.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, consent))]
+    async fn update(&self, consent: &EventConsent) -> DomainResult<()> {
+        debug!("Updating consent: {}", consent.id);
+
+        let result = sqlx::query("UPDATE event_consents SET text = ?, required = ?, version = ?, updated_at = ? WHERE id = ?")
+            .bind(&consent.text)
+            .bind(consent.required)
+            .bind(consent.version)
+            .bind(consent.updated_at.naive_utc())
+            .bind(consent.id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventConsent", consent.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting consent with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM event_consents WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventConsent", id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self, answers))]
+    async fn record_answers(&self, answers: &[RegistrationConsent]) -> DomainResult<()> {
+        debug!("Recording {} consent answers", answers.len());
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        for answer in answers {
+            sqlx::query(
+                "INSERT INTO registration_consents (registration_id, consent_id, kind, version, text, accepted, answered_at) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?) \
+                 ON CONFLICT (registration_id, consent_id) DO UPDATE SET \
+                 version = excluded.version, text = excluded.text, accepted = excluded.accepted, answered_at = excluded.answered_at",
+            )
+            .bind(answer.registration_id.to_string())
+            .bind(answer.consent_id.to_string())
+            .bind(consent_kind_to_string(answer.kind))
+            .bind(answer.version)
+            .bind(&answer.text)
+            .bind(answer.accepted)
+            .bind(answer.answered_at.naive_utc())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_answers_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<RegistrationConsent>> {
+        debug!("Finding consent answers for event: {}", event_id);
+
+        let rows = sqlx::query(
+            "SELECT rc.registration_id, rc.consent_id, rc.kind, rc.version, rc.text, rc.accepted, rc.answered_at \
+             FROM registration_consents rc \
+             JOIN event_registrations r ON r.id = rc.registration_id \
+             WHERE r.event_id = ? \
+             ORDER BY rc.answered_at",
+        )
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_answer(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn find_answers_by_registration_id(&self, registration_id: Uuid) -> DomainResult<Vec<RegistrationConsent>> {
+        debug!("Finding consent answers of registration: {}", registration_id);
+
+        let rows = sqlx::query(
+            "SELECT registration_id, consent_id, kind, version, text, accepted, answered_at \
+             FROM registration_consents \
+             WHERE registration_id = ? \
+             ORDER BY answered_at",
+        )
+        .bind(registration_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_answer(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::ConsentKind;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_answers_keep_the_version_shown_and_outlive_the_checkbox() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventConsentRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+        let registration_id = Uuid::new_v4();
+        db.execute(&format!(
+            "INSERT INTO event_registrations (id, event_id, user_id, registration_source) VALUES ('{}', '{}', '{}', 'direct')",
+            registration_id, event_id, organizer
+        ))
+        .await;
+
+        let mut marketing = EventConsent::new(event_id, ConsentKind::Marketing, "Send me news", false);
+        let photo = EventConsent::new(event_id, ConsentKind::Photo, "Photos may be taken", true);
+        repository.create(&marketing).await.unwrap();
+        repository.create(&photo).await.unwrap();
+        // One checkbox of each kind per event
+        let duplicate = EventConsent::new(event_id, ConsentKind::Photo, "Again", false);
+        assert!(repository.create(&duplicate).await.is_err());
+
+        let consents = repository.find_by_event_id(event_id).await.unwrap();
+        let kinds: Vec<ConsentKind> = consents.iter().map(|c| c.kind).collect();
+        assert_eq!(kinds, vec![ConsentKind::Photo, ConsentKind::Marketing]);
+        assert!(consents[0].required);
+
+        let now = Utc::now();
+        repository
+            .record_answers(&[
+                RegistrationConsent::new(registration_id, &photo, true, now),
+                RegistrationConsent::new(registration_id, &marketing, false, now),
+            ])
+            .await
+            .unwrap();
+
+        marketing.revise("Send me news and offers", false, Utc::now());
+        repository.update(&marketing).await.unwrap();
+        assert_eq!(repository.find_by_event_id(event_id).await.unwrap()[1].version, 2);
+        repository
+            .record_answers(&[RegistrationConsent::new(registration_id, &marketing, true, Utc::now())])
+            .await
+            .unwrap();
+
+        repository.delete(photo.id).await.unwrap();
+        let answers = repository.find_answers_by_event_id(event_id).await.unwrap();
+        assert_eq!(answers.len(), 2);
+        let photo_answer = answers.iter().find(|a| a.kind == ConsentKind::Photo).unwrap();
+        assert!(photo_answer.accepted);
+        assert_eq!(photo_answer.text, "Photos may be taken");
+        let marketing_answer = answers.iter().find(|a| a.kind == ConsentKind::Marketing).unwrap();
+        assert_eq!((marketing_answer.version, marketing_answer.accepted), (2, true));
+
+        db.execute(&format!("DELETE FROM event_registrations WHERE id = '{}'", registration_id)).await;
+        assert!(repository.find_answers_by_event_id(event_id).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,295 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventFieldRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::event_field_type_to_string,
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{DomainResult, EventFieldDefinition, EventFieldValue};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const DEFINITION_COLUMNS: &str = "id, company_id, key, label, field_type, options, is_required, position, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteEventFieldRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventFieldRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventFieldDefinition using SafeRowGet
+    fn row_to_definition(row: &sqlx::sqlite::SqliteRow) -> Result<EventFieldDefinition, RowConversionError> {
+        Ok(EventFieldDefinition {
+            id: row.get_uuid("id")?,
+            company_id: row.get_uuid("company_id")?,
+            key: row.get_string("key")?,
+            label: row.get_string("label")?,
+            field_type: row.get_event_field_type("field_type")?,
+            options: row.get_json("options")?,
+            is_required: row.get_bool("is_required")?,
+            position: row.get_i32("position")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn row_to_value(row: &sqlx::sqlite::SqliteRow) -> Result<EventFieldValue, RowConversionError> {
+        Ok(EventFieldValue {
+            field_id: row.get_uuid("field_id")?,
+            value: row.get_string("value")?,
+        })
+    }
+}
+
+#[async_trait]
+impl EventFieldRepository for SqliteEventFieldRepository {
+    #[instrument(skip(self))]
+    async fn find_definition_by_id(&self, id: Uuid) -> DomainResult<Option<EventFieldDefinition>> {
+        debug!("Finding event field by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_field_definitions WHERE id = ?", DEFINITION_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_definition(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_definitions_by_company(&self, company_id: Uuid) -> DomainResult<Vec<EventFieldDefinition>> {
+        debug!("Listing event fields for company: {}", company_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_field_definitions WHERE company_id = ? ORDER BY position, label COLLATE NOCASE",
+            DEFINITION_COLUMNS
+        ))
+        .bind(company_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let definitions: Result<Vec<EventFieldDefinition>, RowConversionError> =
+            rows.iter().map(Self::row_to_definition).collect();
+        Ok(definitions.map_err(InfrastructureError::from)?)
+    }
+
+    #[instrument(skip(self, definition))]
+    async fn create_definition(&self, definition: &EventFieldDefinition) -> DomainResult<()> {
+        debug!("Creating event field '{}' for company {}", definition.key, definition.company_id);
+
+        sqlx::query(
+            "INSERT INTO event_field_definitions (id, company_id, key, label, field_type, options, is_required, position, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(definition.id.to_string())
+        .bind(definition.company_id.to_string())
+        .bind(&definition.key)
+        .bind(&definition.label)
+        .bind(event_field_type_to_string(definition.field_type))
+        .bind(serde_json::to_string(&definition.options).unwrap_or_default())
+        .bind(definition.is_required)
+        .bind(definition.position)
+        .bind(definition.created_at.naive_utc())
+        .bind(definition.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created event field with id: {}", definition.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self, definition))]
+    async fn update_definition(&self, definition: &EventFieldDefinition) -> DomainResult<()> {
+        debug!("Updating event field: {}", definition.id);
+
+        // The key and type stay as created
+        let result = sqlx::query(
+            "UPDATE event_field_definitions SET label = ?, options = ?, is_required = ?, position = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(&definition.label)
+        .bind(serde_json::to_string(&definition.options).unwrap_or_default())
+        .bind(definition.is_required)
+        .bind(definition.position)
+        .bind(definition.updated_at.naive_utc())
+        .bind(definition.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventFieldDefinition", definition.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_definition(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting event field with id: {}", id);
+
+        // Events' values for the field go with it (ON DELETE CASCADE)
+        let result = sqlx::query("DELETE FROM event_field_definitions WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventFieldDefinition", id));
+        }
+        debug!("Successfully deleted event field with id: {}", id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_values(&self, event_id: Uuid) -> DomainResult<Vec<EventFieldValue>> {
+        debug!("Finding custom field values for event: {}", event_id);
+
+        let rows = sqlx::query(
+            "SELECT v.field_id, v.value FROM event_field_values v \
+             JOIN event_field_definitions d ON d.id = v.field_id \
+             WHERE v.event_id = ? ORDER BY d.position, d.label COLLATE NOCASE"
+        )
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let values: Result<Vec<EventFieldValue>, RowConversionError> = rows.iter().map(Self::row_to_value).collect();
+        Ok(values.map_err(InfrastructureError::from)?)
+    }
+
+    #[instrument(skip(self, values))]
+    async fn replace_values(&self, event_id: Uuid, values: &[EventFieldValue]) -> DomainResult<()> {
+        debug!("Replacing {} custom field values for event: {}", values.len(), event_id);
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        sqlx::query("DELETE FROM event_field_values WHERE event_id = ?")
+            .bind(event_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        for value in values {
+            sqlx::query("INSERT INTO event_field_values (event_id, field_id, value) VALUES (?, ?, ?)")
+                .bind(event_id.to_string())
+                .bind(value.field_id.to_string())
+                .bind(&value.value)
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::sqlite::SqliteEventRepository;
+    use crate::testing::TestDb;
+    use aqio_core::{EventFieldMatch, EventFieldType, EventFilter, EventRepository, PaginationParams};
+
+    async fn seed_company(db: &TestDb) -> Uuid {
+        let id = Uuid::new_v4();
+        db.execute(&format!("INSERT INTO companies (id, name, industry_type) VALUES ('{}', 'Havbruk AS', 'Salmon')", id))
+            .await;
+        id
+    }
+
+    fn value(field: &EventFieldDefinition, value: &str) -> EventFieldValue {
+        EventFieldValue { field_id: field.id, value: value.to_string() }
+    }
+
+    #[tokio::test]
+    async fn test_fields_and_values_round_trip_and_filter_events() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventFieldRepository::new(db.pool().clone());
+        let events = SqliteEventRepository::new(db.pool().clone());
+        let company = seed_company(&db).await;
+        let organizer = db.seed_user().await;
+
+        let species = EventFieldDefinition::new(
+            company,
+            "species_focus",
+            "Fish species focus",
+            EventFieldType::Select,
+            vec!["Salmon".to_string(), "Cod".to_string()],
+            true,
+            1,
+        );
+        let mut topic = EventFieldDefinition::new(company, "topic", "Regulatory topic", EventFieldType::Text, Vec::new(), false, 0);
+        for definition in [&species, &topic] {
+            repository.create_definition(definition).await.unwrap();
+        }
+        // Keys are unique within the company
+        let duplicate = EventFieldDefinition::new(company, "topic", "Topic", EventFieldType::Text, Vec::new(), false, 2);
+        assert!(repository.create_definition(&duplicate).await.is_err());
+
+        let listed = repository.find_definitions_by_company(company).await.unwrap();
+        assert_eq!(listed.iter().map(|d| d.id).collect::<Vec<_>>(), vec![topic.id, species.id]);
+        assert_eq!(listed[1].field_type, EventFieldType::Select);
+        assert_eq!(listed[1].options, vec!["Salmon", "Cod"]);
+        assert!(listed[1].is_required);
+
+        topic.label = "Regulation".to_string();
+        topic.position = 3;
+        repository.update_definition(&topic).await.unwrap();
+        let found = repository.find_definition_by_id(topic.id).await.unwrap().unwrap();
+        assert_eq!((found.label.as_str(), found.position), ("Regulation", 3));
+
+        let salmon_event = db.seed_event(organizer).await;
+        let cod_event = db.seed_event(organizer).await;
+        repository
+            .replace_values(salmon_event, &[value(&topic, "Lice limits"), value(&species, "Salmon")])
+            .await
+            .unwrap();
+        repository.replace_values(cod_event, &[value(&species, "Cod")]).await.unwrap();
+        assert_eq!(
+            repository.find_values(salmon_event).await.unwrap(),
+            vec![value(&species, "Salmon"), value(&topic, "Lice limits")]
+        );
+
+        // Values are matched ignoring case
+        let filter = EventFilter {
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: None,
+            status: None,
+            location_type: None,
+            start_date_from: None,
+            start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: Some(EventFieldMatch { field_id: species.id, value: "salmon".to_string() }),
+            county: None,
+            municipality: None,
+        };
+        let pagination = PaginationParams { offset: 0, limit: 10 };
+        let result = events.find_by_filter(&filter, pagination.clone()).await.unwrap();
+        assert_eq!(result.items.iter().map(|e| e.id).collect::<Vec<_>>(), vec![salmon_event]);
+
+        // Replacing drops values left out
+        repository.replace_values(salmon_event, &[value(&species, "Cod")]).await.unwrap();
+        assert_eq!(repository.find_values(salmon_event).await.unwrap(), vec![value(&species, "Cod")]);
+        assert_eq!(events.find_by_filter(&filter, pagination).await.unwrap().total_count, 0);
+
+        // Deleting a field takes the events' values with it
+        repository.delete_definition(species.id).await.unwrap();
+        assert!(repository.find_values(cod_event).await.unwrap().is_empty());
+        assert!(repository.delete_definition(species.id).await.is_err());
+    }
+}
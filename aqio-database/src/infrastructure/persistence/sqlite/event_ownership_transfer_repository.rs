@@ -0,0 +1,196 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventOwnershipTransferRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::ownership_transfer_status_to_string,
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{DomainResult, EventOwnershipTransfer};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const TRANSFER_COLUMNS: &str = "id, event_id, from_user_id, to_user_id, requested_by, message, status, created_at, responded_at";
+
+#[derive(Clone)]
+pub struct SqliteEventOwnershipTransferRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventOwnershipTransferRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventOwnershipTransfer using SafeRowGet
+    fn row_to_transfer(row: &sqlx::sqlite::SqliteRow) -> Result<EventOwnershipTransfer, RowConversionError> {
+        Ok(EventOwnershipTransfer {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            from_user_id: row.get_uuid("from_user_id")?,
+            to_user_id: row.get_uuid("to_user_id")?,
+            requested_by: row.get_uuid("requested_by")?,
+            message: row.get_optional_string("message")?,
+            status: row.get_ownership_transfer_status("status")?,
+            created_at: row.get_datetime("created_at")?,
+            responded_at: row.get_optional_datetime("responded_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl EventOwnershipTransferRepository for SqliteEventOwnershipTransferRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventOwnershipTransfer>> {
+        debug!("Finding ownership transfer by ID: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_ownership_transfers WHERE id = ?", TRANSFER_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_transfer(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_pending_for_event(&self, event_id: Uuid) -> DomainResult<Option<EventOwnershipTransfer>> {
+        debug!("Finding pending ownership transfer of event: {}", event_id);
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM event_ownership_transfers WHERE event_id = ? AND status = 'pending'",
+            TRANSFER_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_transfer(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_pending_for_user(&self, user_id: Uuid) -> DomainResult<Vec<EventOwnershipTransfer>> {
+        debug!("Finding ownership transfers waiting for user: {}", user_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_ownership_transfers WHERE to_user_id = ? AND status = 'pending' ORDER BY created_at DESC",
+            TRANSFER_COLUMNS
+        ))
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_transfer(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, transfer))]
+    async fn create(&self, transfer: &EventOwnershipTransfer) -> DomainResult<()> {
+        debug!("Creating ownership transfer {} of event {}", transfer.id, transfer.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO event_ownership_transfers ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            TRANSFER_COLUMNS
+        ))
+        .bind(transfer.id.to_string())
+        .bind(transfer.event_id.to_string())
+        .bind(transfer.from_user_id.to_string())
+        .bind(transfer.to_user_id.to_string())
+        .bind(transfer.requested_by.to_string())
+        .bind(&transfer.message)
+        .bind(ownership_transfer_status_to_string(transfer.status))
+        .bind(transfer.created_at.naive_utc())
+        .bind(transfer.responded_at.map(|at| at.naive_utc()))
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, transfer))]
+    async fn update(&self, transfer: &EventOwnershipTransfer) -> DomainResult<()> {
+        debug!("Updating ownership transfer: {}", transfer.id);
+
+        // Only the answer changes after a transfer is requested
+        sqlx::query("UPDATE event_ownership_transfers SET status = ?, responded_at = ? WHERE id = ?")
+            .bind(ownership_transfer_status_to_string(transfer.status))
+            .bind(transfer.responded_at.map(|at| at.naive_utc()))
+            .bind(transfer.id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::OwnershipTransferStatus;
+
+    fn pending_transfer(event_id: Uuid, from: Uuid, to: Uuid) -> EventOwnershipTransfer {
+        EventOwnershipTransfer {
+            id: Uuid::new_v4(),
+            event_id,
+            from_user_id: from,
+            to_user_id: to,
+            requested_by: from,
+            message: None,
+            status: OwnershipTransferStatus::Pending,
+            created_at: chrono::Utc::now(),
+            responded_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transfer_round_trips_and_only_pending_ones_are_found() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventOwnershipTransferRepository::new(db.pool().clone());
+        let owner = db.seed_user().await;
+        let new_owner = db.seed_user().await;
+        let event_id = db.seed_event(owner).await;
+
+        let mut transfer = pending_transfer(event_id, owner, new_owner);
+        transfer.message = Some("You're running it now".to_string());
+        repository.create(&transfer).await.unwrap();
+
+        let found = repository.find_by_id(transfer.id).await.unwrap().unwrap();
+        assert_eq!(found.event_id, event_id);
+        assert_eq!((found.from_user_id, found.to_user_id, found.requested_by), (owner, new_owner, owner));
+        assert_eq!(found.message.as_deref(), Some("You're running it now"));
+        assert_eq!(found.status, OwnershipTransferStatus::Pending);
+        assert_eq!(repository.find_pending_for_event(event_id).await.unwrap().map(|t| t.id), Some(transfer.id));
+        let waiting = repository.find_pending_for_user(new_owner).await.unwrap();
+        assert_eq!(waiting.iter().map(|t| t.id).collect::<Vec<_>>(), vec![transfer.id]);
+        assert!(repository.find_pending_for_user(owner).await.unwrap().is_empty());
+
+        // Only one open request per event
+        assert!(repository.create(&pending_transfer(event_id, owner, owner)).await.is_err());
+
+        transfer.respond(OwnershipTransferStatus::Declined, chrono::Utc::now());
+        repository.update(&transfer).await.unwrap();
+
+        let found = repository.find_by_id(transfer.id).await.unwrap().unwrap();
+        assert_eq!(found.status, OwnershipTransferStatus::Declined);
+        assert!(found.responded_at.is_some());
+        assert!(repository.find_pending_for_event(event_id).await.unwrap().is_none());
+        assert!(repository.find_pending_for_user(new_owner).await.unwrap().is_empty());
+
+        // A declined request doesn't block a new one
+        repository.create(&pending_transfer(event_id, owner, new_owner)).await.unwrap();
+    }
+}
@@ -0,0 +1,290 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventPhotoRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::photo_status_to_string,
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{DomainResult, EventPhoto, PhotoAlbum, PhotoStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const PHOTO_COLUMNS: &str = "id, event_id, uploader_id, uploader_name, filename, original_filename, storage_key, \
+     file_size, mime_type, caption, status, uploaded_at, reviewed_at";
+
+// Albums are stored in `event_albums`, one per event; `is_collaborative` says whether attendees may add photos
+const ALBUM_COLUMNS: &str = "id, event_id, is_collaborative, requires_approval, is_public, created_by, created_at, updated_at";
+
+// The table's title is required, but albums are named after their event in the app
+const ALBUM_TITLE: &str = "Photos";
+
+#[derive(Clone)]
+pub struct SqliteEventPhotoRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventPhotoRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventPhoto using SafeRowGet
+    fn row_to_photo(row: &sqlx::sqlite::SqliteRow) -> Result<EventPhoto, RowConversionError> {
+        Ok(EventPhoto {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            uploader_id: row.get_optional_uuid("uploader_id")?,
+            uploader_name: row.get_optional_string("uploader_name")?,
+            original_filename: row.get_string("original_filename")?,
+            filename: row.get_string("filename")?,
+            storage_key: row.get_string("storage_key")?,
+            file_size: row.get_i64("file_size")?,
+            mime_type: row.get_string("mime_type")?,
+            caption: row.get_optional_string("caption")?,
+            status: row.get_photo_status("status")?,
+            uploaded_at: row.get_datetime("uploaded_at")?,
+            reviewed_at: row.get_optional_datetime("reviewed_at")?,
+        })
+    }
+
+    // Helper method to convert database row to PhotoAlbum using SafeRowGet
+    fn row_to_album(row: &sqlx::sqlite::SqliteRow) -> Result<PhotoAlbum, RowConversionError> {
+        Ok(PhotoAlbum {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            attendee_uploads: row.get_bool("is_collaborative")?,
+            requires_approval: row.get_bool("requires_approval")?,
+            is_public: row.get_bool("is_public")?,
+            created_by: row.get_uuid("created_by")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl EventPhotoRepository for SqliteEventPhotoRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventPhoto>> {
+        debug!("Finding photo by ID: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_photos WHERE id = ?", PHOTO_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_photo(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventPhoto>> {
+        debug!("Finding photos of event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_photos WHERE event_id = ? ORDER BY uploaded_at, rowid",
+            PHOTO_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_photo(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, photo))]
+    async fn create(&self, photo: &EventPhoto) -> DomainResult<()> {
+        debug!("Creating photo {} on event {}", photo.id, photo.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO event_photos ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            PHOTO_COLUMNS
+        ))
+        .bind(photo.id.to_string())
+        .bind(photo.event_id.to_string())
+        .bind(photo.uploader_id.map(|id| id.to_string()))
+        .bind(photo.uploader_name.as_deref())
+        .bind(&photo.filename)
+        .bind(&photo.original_filename)
+        .bind(&photo.storage_key)
+        .bind(photo.file_size)
+        .bind(&photo.mime_type)
+        .bind(photo.caption.as_deref())
+        .bind(photo_status_to_string(photo.status))
+        .bind(photo.uploaded_at.naive_utc())
+        .bind(photo.reviewed_at.map(|at| at.naive_utc()))
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn update_status(&self, id: Uuid, status: PhotoStatus, reviewed_at: DateTime<Utc>) -> DomainResult<()> {
+        debug!("Setting status of photo {} to {:?}", id, status);
+
+        sqlx::query("UPDATE event_photos SET status = ?, reviewed_at = ? WHERE id = ?")
+            .bind(photo_status_to_string(status))
+            .bind(reviewed_at.naive_utc())
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting photo: {}", id);
+
+        let result = sqlx::query("DELETE FROM event_photos WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventPhoto", id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_album(&self, event_id: Uuid) -> DomainResult<Option<PhotoAlbum>> {
+        debug!("Finding photo album of event: {}", event_id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_albums WHERE event_id = ?", ALBUM_COLUMNS))
+            .bind(event_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_album(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self, album))]
+    async fn save_album(&self, album: &PhotoAlbum) -> DomainResult<()> {
+        debug!("Saving photo album of event: {}", album.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO event_albums ({}, title) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?) \
+             ON CONFLICT (event_id) DO UPDATE SET is_collaborative = excluded.is_collaborative, \
+             requires_approval = excluded.requires_approval, is_public = excluded.is_public, \
+             updated_at = excluded.updated_at",
+            ALBUM_COLUMNS
+        ))
+        .bind(album.id.to_string())
+        .bind(album.event_id.to_string())
+        .bind(album.attendee_uploads)
+        .bind(album.requires_approval)
+        .bind(album.is_public)
+        .bind(album.created_by.to_string())
+        .bind(album.created_at.naive_utc())
+        .bind(album.updated_at.naive_utc())
+        .bind(ALBUM_TITLE)
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use chrono::Duration;
+
+    fn photo(event_id: Uuid, uploader_id: Uuid, name: &str, status: PhotoStatus) -> EventPhoto {
+        let id = Uuid::new_v4();
+        EventPhoto {
+            id,
+            event_id,
+            uploader_id: Some(uploader_id),
+            uploader_name: Some("Kari Nordmann".to_string()),
+            original_filename: name.to_string(),
+            filename: name.to_string(),
+            storage_key: format!("events/{}/photos/{}-{}", event_id, id, name),
+            file_size: 4096,
+            mime_type: "image/jpeg".to_string(),
+            caption: None,
+            status,
+            uploaded_at: Utc::now(),
+            reviewed_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_photos_round_trip_and_are_moderated() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventPhotoRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let attendee = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+
+        let mut stage = photo(event_id, organizer, "stage.jpg", PhotoStatus::Approved);
+        stage.caption = Some("Opening talk".to_string());
+        repository.create(&stage).await.unwrap();
+        let mut crowd = photo(event_id, attendee, "crowd.jpg", PhotoStatus::Pending);
+        crowd.uploaded_at = stage.uploaded_at + Duration::minutes(1);
+        repository.create(&crowd).await.unwrap();
+
+        let found = repository.find_by_id(stage.id).await.unwrap().unwrap();
+        assert_eq!(found.caption.as_deref(), Some("Opening talk"));
+        assert_eq!((found.status, found.file_size), (PhotoStatus::Approved, 4096));
+        let ids: Vec<Uuid> = repository.find_by_event_id(event_id).await.unwrap().iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![stage.id, crowd.id]);
+
+        repository.update_status(crowd.id, PhotoStatus::Rejected, Utc::now()).await.unwrap();
+        let found = repository.find_by_id(crowd.id).await.unwrap().unwrap();
+        assert_eq!(found.status, PhotoStatus::Rejected);
+        assert!(found.reviewed_at.is_some());
+
+        repository.delete(crowd.id).await.unwrap();
+        assert!(repository.delete(crowd.id).await.is_err());
+        assert_eq!(repository.find_by_event_id(event_id).await.unwrap().len(), 1);
+
+        db.execute(&format!("DELETE FROM events WHERE id = '{}'", event_id)).await;
+        assert!(repository.find_by_event_id(event_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_album_settings_are_saved_once_per_event() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventPhotoRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+        assert!(repository.find_album(event_id).await.unwrap().is_none());
+
+        let mut album = PhotoAlbum::new(event_id, organizer);
+        album.attendee_uploads = true;
+        repository.save_album(&album).await.unwrap();
+        // A second save with a fresh ID updates the same album
+        let mut changed = PhotoAlbum::new(event_id, organizer);
+        changed.attendee_uploads = true;
+        changed.requires_approval = false;
+        changed.is_public = true;
+        repository.save_album(&changed).await.unwrap();
+
+        let found = repository.find_album(event_id).await.unwrap().unwrap();
+        assert_eq!(found.id, album.id);
+        assert!(found.attendee_uploads && !found.requires_approval && found.is_public);
+        assert_eq!(found.created_by, organizer);
+    }
+}
@@ -0,0 +1,314 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventPollRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, EventPoll, PollOption, PollVote};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Row, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const POLL_COLUMNS: &str = "id, event_id, question, created_by, closed_at, created_at, updated_at";
+
+// Votes are counted on read, so results are always the live tally
+const OPTION_SELECT: &str = "SELECT o.id, o.poll_id, o.label, o.position, \
+     (SELECT COUNT(*) FROM event_poll_votes v WHERE v.option_id = o.id) AS vote_count \
+     FROM event_poll_options o";
+
+#[derive(Clone)]
+pub struct SqliteEventPollRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventPollRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Options are loaded separately and attached afterwards
+    fn row_to_poll(row: &sqlx::sqlite::SqliteRow) -> Result<EventPoll, RowConversionError> {
+        Ok(EventPoll {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            question: row.get_string("question")?,
+            options: Vec::new(),
+            created_by: row.get_optional_uuid("created_by")?,
+            closed_at: row.get_optional_datetime("closed_at")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn row_to_option(row: &sqlx::sqlite::SqliteRow) -> Result<PollOption, RowConversionError> {
+        Ok(PollOption {
+            id: row.get_uuid("id")?,
+            poll_id: row.get_uuid("poll_id")?,
+            label: row.get_string("label")?,
+            position: row.get_i32("position")?,
+            vote_count: row.try_get("vote_count")
+                .map_err(|cause| RowConversionError::MissingField { field: "vote_count", cause })?,
+        })
+    }
+
+    fn row_to_vote(row: &sqlx::sqlite::SqliteRow) -> Result<PollVote, RowConversionError> {
+        Ok(PollVote {
+            poll_id: row.get_uuid("poll_id")?,
+            option_id: row.get_uuid("option_id")?,
+            user_id: row.get_uuid("user_id")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+
+    fn attach_options(polls: &mut [EventPoll], options: Vec<PollOption>) {
+        for option in options {
+            if let Some(poll) = polls.iter_mut().find(|poll| poll.id == option.poll_id) {
+                poll.options.push(option);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventPollRepository for SqliteEventPollRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventPoll>> {
+        debug!("Finding poll by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_polls WHERE id = ?", POLL_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let mut poll = Self::row_to_poll(&row).map_err(InfrastructureError::from)?;
+
+        let option_rows = sqlx::query(&format!("{} WHERE o.poll_id = ? ORDER BY o.position", OPTION_SELECT))
+            .bind(id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        let options: Result<Vec<PollOption>, RowConversionError> = option_rows.iter()
+            .map(Self::row_to_option)
+            .collect();
+        poll.options = options.map_err(InfrastructureError::from)?;
+
+        Ok(Some(poll))
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventPoll>> {
+        debug!("Listing polls for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_polls WHERE event_id = ? ORDER BY created_at, rowid",
+            POLL_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let polls: Result<Vec<EventPoll>, RowConversionError> = rows.iter()
+            .map(Self::row_to_poll)
+            .collect();
+        let mut polls = polls.map_err(InfrastructureError::from)?;
+
+        let option_rows = sqlx::query(&format!(
+            "{} WHERE o.poll_id IN (SELECT id FROM event_polls WHERE event_id = ?) ORDER BY o.position",
+            OPTION_SELECT
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let options: Result<Vec<PollOption>, RowConversionError> = option_rows.iter()
+            .map(Self::row_to_option)
+            .collect();
+        Self::attach_options(&mut polls, options.map_err(InfrastructureError::from)?);
+
+        debug!("Listed {} polls for event {}", polls.len(), event_id);
+        Ok(polls)
+    }
+
+    #[instrument(skip(self, poll))]
+    async fn create(&self, poll: &EventPoll) -> DomainResult<()> {
+        debug!("Creating poll {} with {} options on event {}", poll.id, poll.options.len(), poll.event_id);
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+
+        sqlx::query(
+            "INSERT INTO event_polls (id, event_id, question, created_by, closed_at, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(poll.id.to_string())
+        .bind(poll.event_id.to_string())
+        .bind(&poll.question)
+        .bind(poll.created_by.map(|id| id.to_string()))
+        .bind(poll.closed_at.map(|at| at.naive_utc()))
+        .bind(poll.created_at.naive_utc())
+        .bind(poll.updated_at.naive_utc())
+        .execute(&mut *tx)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        for option in &poll.options {
+            sqlx::query("INSERT INTO event_poll_options (id, poll_id, label, position) VALUES (?, ?, ?, ?)")
+                .bind(option.id.to_string())
+                .bind(poll.id.to_string())
+                .bind(&option.label)
+                .bind(option.position)
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created poll with id: {}", poll.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn close(&self, id: Uuid, closed_at: DateTime<Utc>) -> DomainResult<()> {
+        debug!("Closing poll with id: {}", id);
+
+        // Closing twice keeps the original closing time
+        let result = sqlx::query(
+            "UPDATE event_polls SET closed_at = COALESCE(closed_at, ?), updated_at = ? WHERE id = ?"
+        )
+        .bind(closed_at.naive_utc())
+        .bind(closed_at.naive_utc())
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventPoll", id));
+        }
+        debug!("Successfully closed poll with id: {}", id);
+        Ok(())
+    }
+
+    #[instrument(skip(self, vote))]
+    async fn cast_vote(&self, vote: &PollVote) -> DomainResult<()> {
+        debug!("User {} voting on poll {}", vote.user_id, vote.poll_id);
+
+        sqlx::query(
+            "INSERT INTO event_poll_votes (poll_id, user_id, option_id, created_at) VALUES (?, ?, ?, ?) \
+             ON CONFLICT (poll_id, user_id) DO UPDATE SET option_id = excluded.option_id, created_at = excluded.created_at"
+        )
+        .bind(vote.poll_id.to_string())
+        .bind(vote.user_id.to_string())
+        .bind(vote.option_id.to_string())
+        .bind(vote.created_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_votes_by_user(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Vec<PollVote>> {
+        debug!("Listing votes on event {} by {}", event_id, user_id);
+
+        let rows = sqlx::query(
+            "SELECT v.poll_id, v.option_id, v.user_id, v.created_at FROM event_poll_votes v \
+             JOIN event_polls p ON p.id = v.poll_id WHERE p.event_id = ? AND v.user_id = ?"
+        )
+        .bind(event_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let votes: Result<Vec<PollVote>, RowConversionError> = rows.iter()
+            .map(Self::row_to_vote)
+            .collect();
+        Ok(votes.map_err(InfrastructureError::from)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    fn vote(poll: &EventPoll, option: usize, user_id: Uuid) -> PollVote {
+        PollVote {
+            poll_id: poll.id,
+            option_id: poll.options[option].id,
+            user_id,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_results_follow_changed_votes() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventPollRepository::new(db.pool().clone());
+
+        let organizer = db.seed_user().await;
+        let attendee = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+
+        let poll = EventPoll::new(
+            event,
+            organizer,
+            "Which session next?",
+            vec!["Aquaculture".to_string(), "Logistics".to_string(), "Energy".to_string()],
+        );
+        repository.create(&poll).await.unwrap();
+
+        repository.cast_vote(&vote(&poll, 0, organizer)).await.unwrap();
+        repository.cast_vote(&vote(&poll, 0, attendee)).await.unwrap();
+        repository.cast_vote(&vote(&poll, 2, attendee)).await.unwrap();
+
+        let results = repository.find_by_id(poll.id).await.unwrap().unwrap();
+        let labels: Vec<&str> = results.options.iter().map(|o| o.label.as_str()).collect();
+        assert_eq!(labels, vec!["Aquaculture", "Logistics", "Energy"]);
+        let counts: Vec<i64> = results.options.iter().map(|o| o.vote_count).collect();
+        assert_eq!(counts, vec![1, 0, 1]);
+        assert_eq!(results.total_votes(), 2);
+
+        let votes = repository.find_votes_by_user(event, attendee).await.unwrap();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].option_id, poll.options[2].id);
+    }
+
+    #[tokio::test]
+    async fn test_event_polls_listed_with_options_and_closed_once() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventPollRepository::new(db.pool().clone());
+
+        let organizer = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+        let other_event = db.seed_event(organizer).await;
+
+        let first = EventPoll::new(event, organizer, "Coffee or tea?", vec!["Coffee".to_string(), "Tea".to_string()]);
+        let second = EventPoll::new(event, organizer, "Stay for dinner?", vec!["Yes".to_string(), "No".to_string()]);
+        let elsewhere = EventPoll::new(other_event, organizer, "Elsewhere?", vec!["A".to_string(), "B".to_string()]);
+        for poll in [&first, &second, &elsewhere] {
+            repository.create(poll).await.unwrap();
+        }
+
+        let closed_at = Utc::now() - chrono::Duration::minutes(5);
+        repository.close(first.id, closed_at).await.unwrap();
+        repository.close(first.id, Utc::now()).await.unwrap();
+
+        let polls = repository.find_by_event_id(event).await.unwrap();
+        assert_eq!(polls.iter().map(|p| p.id).collect::<Vec<_>>(), vec![first.id, second.id]);
+        assert!(polls.iter().all(|p| p.options.len() == 2));
+        assert_eq!(polls[0].closed_at.unwrap().timestamp(), closed_at.timestamp());
+        assert!(!polls[1].is_closed());
+
+        assert!(repository.close(Uuid::new_v4(), Utc::now()).await.is_err());
+    }
+}
@@ -0,0 +1,259 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventQuestionRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, EventQuestion};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Row, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+// Upvotes are counted on read so concurrent votes never race on a counter column
+const QUESTION_SELECT: &str = "SELECT q.id, q.event_id, q.author_id, q.author_name, q.content, q.answer, q.answered_by, q.answered_at, q.created_at, q.updated_at, \
+     (SELECT COUNT(*) FROM event_question_upvotes u WHERE u.question_id = q.id) AS upvote_count \
+     FROM event_questions q";
+
+#[derive(Clone)]
+pub struct SqliteEventQuestionRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventQuestionRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventQuestion using SafeRowGet
+    fn row_to_question(row: &sqlx::sqlite::SqliteRow) -> Result<EventQuestion, RowConversionError> {
+        Ok(EventQuestion {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            author_id: row.get_optional_uuid("author_id")?,
+            author_name: row.get_optional_string("author_name")?,
+            content: row.get_string("content")?,
+            upvote_count: row.try_get("upvote_count")
+                .map_err(|cause| RowConversionError::MissingField { field: "upvote_count", cause })?,
+            answer: row.get_optional_string("answer")?,
+            answered_by: row.get_optional_uuid("answered_by")?,
+            answered_at: row.get_optional_datetime("answered_at")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl EventQuestionRepository for SqliteEventQuestionRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventQuestion>> {
+        debug!("Finding question by id: {}", id);
+
+        let row = sqlx::query(&format!("{} WHERE q.id = ?", QUESTION_SELECT))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_question(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventQuestion>> {
+        debug!("Listing questions for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "{} WHERE q.event_id = ? ORDER BY q.created_at, q.rowid",
+            QUESTION_SELECT
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let questions: Result<Vec<EventQuestion>, RowConversionError> = rows.iter()
+            .map(Self::row_to_question)
+            .collect();
+        let questions = questions.map_err(InfrastructureError::from)?;
+
+        debug!("Listed {} questions for event {}", questions.len(), event_id);
+        Ok(questions)
+    }
+
+    #[instrument(skip(self, question))]
+    async fn create(&self, question: &EventQuestion) -> DomainResult<()> {
+        debug!("Creating question {} on event {}", question.id, question.event_id);
+
+        sqlx::query(
+            "INSERT INTO event_questions (id, event_id, author_id, author_name, content, answer, answered_by, answered_at, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(question.id.to_string())
+        .bind(question.event_id.to_string())
+        .bind(question.author_id.map(|id| id.to_string()))
+        .bind(question.author_name.as_deref())
+        .bind(&question.content)
+        .bind(question.answer.as_deref())
+        .bind(question.answered_by.map(|id| id.to_string()))
+        .bind(question.answered_at.map(|at| at.naive_utc()))
+        .bind(question.created_at.naive_utc())
+        .bind(question.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created question with id: {}", question.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self, answer))]
+    async fn answer(&self, id: Uuid, answer: &str, answered_by: Uuid, answered_at: DateTime<Utc>) -> DomainResult<()> {
+        debug!("Answering question with id: {}", id);
+
+        let result = sqlx::query(
+            "UPDATE event_questions SET answer = ?, answered_by = ?, answered_at = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(answer)
+        .bind(answered_by.to_string())
+        .bind(answered_at.naive_utc())
+        .bind(answered_at.naive_utc())
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventQuestion", id));
+        }
+        debug!("Successfully answered question with id: {}", id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn add_upvote(&self, question_id: Uuid, user_id: Uuid) -> DomainResult<bool> {
+        debug!("User {} upvoting question {}", user_id, question_id);
+
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO event_question_upvotes (question_id, user_id, created_at) VALUES (?, ?, ?)"
+        )
+        .bind(question_id.to_string())
+        .bind(user_id.to_string())
+        .bind(Utc::now().naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn remove_upvote(&self, question_id: Uuid, user_id: Uuid) -> DomainResult<bool> {
+        debug!("User {} removing upvote from question {}", user_id, question_id);
+
+        let result = sqlx::query("DELETE FROM event_question_upvotes WHERE question_id = ? AND user_id = ?")
+            .bind(question_id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_upvoted_by_user(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Vec<Uuid>> {
+        debug!("Listing questions on event {} upvoted by {}", event_id, user_id);
+
+        let rows = sqlx::query(
+            "SELECT u.question_id FROM event_question_upvotes u JOIN event_questions q ON q.id = u.question_id WHERE q.event_id = ? AND u.user_id = ?"
+        )
+        .bind(event_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let ids: Result<Vec<Uuid>, RowConversionError> = rows.iter()
+            .map(|row| row.get_uuid("question_id"))
+            .collect();
+        Ok(ids.map_err(InfrastructureError::from)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    fn create_test_question(event_id: Uuid, author_id: Uuid, content: &str) -> EventQuestion {
+        let now = Utc::now();
+        EventQuestion {
+            id: Uuid::new_v4(),
+            event_id,
+            author_id: Some(author_id),
+            author_name: Some("Test User".to_string()),
+            content: content.to_string(),
+            upvote_count: 0,
+            answer: None,
+            answered_by: None,
+            answered_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upvotes_are_counted_once_per_user() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventQuestionRepository::new(db.pool().clone());
+
+        let author = db.seed_user().await;
+        let voter = db.seed_user().await;
+        let event = db.seed_event(author).await;
+
+        let question = create_test_question(event, author, "Will the slides be shared?");
+        let other = create_test_question(event, author, "Is lunch included?");
+        repository.create(&question).await.unwrap();
+        repository.create(&other).await.unwrap();
+
+        assert!(repository.add_upvote(question.id, author).await.unwrap());
+        assert!(repository.add_upvote(question.id, voter).await.unwrap());
+        assert!(!repository.add_upvote(question.id, voter).await.unwrap());
+
+        let listed = repository.find_by_event_id(event).await.unwrap();
+        assert_eq!(listed.iter().map(|q| q.id).collect::<Vec<_>>(), vec![question.id, other.id]);
+        assert_eq!(listed[0].upvote_count, 2);
+        assert_eq!(listed[1].upvote_count, 0);
+        assert_eq!(repository.find_upvoted_by_user(event, voter).await.unwrap(), vec![question.id]);
+
+        assert!(repository.remove_upvote(question.id, voter).await.unwrap());
+        assert!(!repository.remove_upvote(question.id, voter).await.unwrap());
+        assert_eq!(repository.find_by_id(question.id).await.unwrap().unwrap().upvote_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_answer_replaces_previous_answer() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventQuestionRepository::new(db.pool().clone());
+
+        let organizer = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+
+        let question = create_test_question(event, organizer, "Where is the coffee?");
+        repository.create(&question).await.unwrap();
+
+        repository.answer(question.id, "Second floor", organizer, Utc::now()).await.unwrap();
+        repository.answer(question.id, "Ground floor, by the stairs", organizer, Utc::now()).await.unwrap();
+
+        let answered = repository.find_by_id(question.id).await.unwrap().unwrap();
+        assert!(answered.is_answered());
+        assert_eq!(answered.answer.as_deref(), Some("Ground floor, by the stairs"));
+        assert_eq!(answered.answered_by, Some(organizer));
+
+        let missing = repository.answer(Uuid::new_v4(), "Nobody asked", organizer, Utc::now()).await;
+        assert!(missing.is_err());
+    }
+}
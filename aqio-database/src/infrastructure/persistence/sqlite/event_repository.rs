@@ -2,10 +2,15 @@ use crate::domain::errors::InfrastructureError;
 use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
 use aqio_core::{Event, EventFilter, PaginationParams, PaginatedResult, LocationType, EventStatus, DomainResult, EventRepository};
 use async_trait::async_trait;
-use sqlx::{Pool, Sqlite, Row};
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite, SqliteConnection, Row};
 use tracing::{instrument, debug};
 use uuid::Uuid;
 
+// Co-organizers live in event_co_organizers; they are aggregated back into the
+// JSON array `row_to_event` reads, in the order they were listed
+const SELECT_EVENTS: &str = "SELECT id, title, slug, description, category_id, start_date, end_date, timezone, location_type, location_name, address, virtual_link, virtual_access_code, latitude, longitude, postal_code, municipality, county, organizer_id, (SELECT json_group_array(user_id) FROM (SELECT user_id FROM event_co_organizers WHERE event_id = events.id ORDER BY position)) AS co_organizers, is_private, requires_approval, max_attendees, allow_guests, max_guests_per_person, registration_opens, registration_closes, registration_required, allow_waitlist, send_reminders, collect_dietary_info, collect_accessibility_info, image_url, custom_fields, status, publish_at, created_at, updated_at FROM events";
+
 #[derive(Clone)]
 pub struct SqliteEventRepository {
     pool: Pool<Sqlite>,
@@ -18,9 +23,14 @@ impl SqliteEventRepository {
 
     // Helper method to safely convert database row to Event
     fn row_to_event(row: &sqlx::sqlite::SqliteRow) -> Result<Event, RowConversionError> {
+        let id = row.get_uuid("id")?;
         Ok(Event {
-            id: row.get_uuid("id")?,
+            id,
             title: row.get_string("title")?,
+            // Events created outside the repository may not have a slug yet
+            slug: row
+                .get_optional_string("slug")?
+                .unwrap_or_else(|| id.to_string()),
             description: row.get_string("description")?,
             category_id: row.get_string("category_id")?,
             start_date: row.get_datetime("start_date")?,
@@ -31,6 +41,9 @@ impl SqliteEventRepository {
             address: row.get_optional_string("address")?,
             virtual_link: row.get_optional_string("virtual_link")?,
             virtual_access_code: row.get_optional_string("virtual_access_code")?,
+            latitude: row.get_optional_f64("latitude")?,
+            longitude: row.get_optional_f64("longitude")?,
+            region: row.get_optional_address_region()?,
             organizer_id: row.get_uuid("organizer_id")?,
             co_organizers: row.get_json("co_organizers").unwrap_or_default(),
             is_private: row.try_get("is_private").unwrap_or(false),
@@ -48,6 +61,7 @@ impl SqliteEventRepository {
             image_url: row.get_optional_string("image_url")?,
             custom_fields: row.get_optional_string("custom_fields")?,
             status: row.get_event_status("status")?,
+            publish_at: row.get_optional_datetime("publish_at")?,
             created_at: row.get_datetime("created_at")?,
             updated_at: row.get_datetime("updated_at")?,
         })
@@ -114,10 +128,44 @@ impl SqliteEventRepository {
             );
         }
 
+        for co_organizer_id in &event.co_organizers {
+            let co_organizer_exists = sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM users WHERE id = ?)")
+                .bind(co_organizer_id.to_string())
+                .fetch_one(&self.pool)
+                .await
+                .unwrap_or(false);
+
+            if !co_organizer_exists {
+                return aqio_core::DomainError::validation_constraint(
+                    "co_organizers",
+                    &format!("Co-organizer user '{}' does not exist", co_organizer_id),
+                    "FOREIGN KEY",
+                    Some(&co_organizer_id.to_string()),
+                );
+            }
+        }
+
         // If we get here, it's some other foreign key constraint we don't know about
         aqio_core::DomainError::business_rule("Foreign key constraint violation: Unknown referenced entity does not exist")
     }
 
+    // Replaces the event's co-organizer rows with `event.co_organizers`, in order
+    async fn replace_co_organizers(tx: &mut SqliteConnection, event: &Event) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM event_co_organizers WHERE event_id = ?")
+            .bind(event.id.to_string())
+            .execute(&mut *tx)
+            .await?;
+        for (position, user_id) in event.co_organizers.iter().enumerate() {
+            sqlx::query("INSERT OR IGNORE INTO event_co_organizers (event_id, user_id, position) VALUES (?, ?, ?)")
+                .bind(event.id.to_string())
+                .bind(user_id.to_string())
+                .bind(position as i64)
+                .execute(&mut *tx)
+                .await?;
+        }
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     async fn count_events_with_filter(&self, filter: &EventFilter) -> Result<i64, InfrastructureError> {
         let mut query_builder = sqlx::QueryBuilder::new("SELECT COUNT(*) FROM events WHERE 1=1");
@@ -170,6 +218,66 @@ impl SqliteEventRepository {
             query_builder.push(" AND start_date <= ");
             query_builder.push_bind(start_to.naive_utc());
         }
+
+        if let Some(near) = filter.near {
+            // The bounding box lets SQLite use idx_events_coordinates; the
+            // distance check matches GeoRadius::contains
+            let (min_lat, max_lat, min_lng, max_lng) = near.bounding_box();
+            let scale = near.longitude_scale();
+            let radius = near.radius_degrees();
+            query_builder.push(" AND latitude BETWEEN ");
+            query_builder.push_bind(min_lat);
+            query_builder.push(" AND ");
+            query_builder.push_bind(max_lat);
+            query_builder.push(" AND longitude BETWEEN ");
+            query_builder.push_bind(min_lng);
+            query_builder.push(" AND ");
+            query_builder.push_bind(max_lng);
+            query_builder.push(" AND (latitude - ");
+            query_builder.push_bind(near.latitude);
+            query_builder.push(") * (latitude - ");
+            query_builder.push_bind(near.latitude);
+            query_builder.push(") + (longitude - ");
+            query_builder.push_bind(near.longitude);
+            query_builder.push(") * (longitude - ");
+            query_builder.push_bind(near.longitude);
+            query_builder.push(") * ");
+            query_builder.push_bind(scale * scale);
+            query_builder.push(" <= ");
+            query_builder.push_bind(radius * radius);
+        }
+
+        if let Some(company_id) = filter.organizer_company_id {
+            query_builder.push(" AND organizer_id IN (SELECT id FROM users WHERE company_id = ");
+            query_builder.push_bind(company_id.to_string());
+            query_builder.push(")");
+        }
+
+        if let Some(co_organizer_id) = filter.co_organizer_id {
+            query_builder.push(" AND id IN (SELECT event_id FROM event_co_organizers WHERE user_id = ");
+            query_builder.push_bind(co_organizer_id.to_string());
+            query_builder.push(")");
+        }
+
+        if let Some(ref field) = filter.field {
+            query_builder.push(" AND id IN (SELECT event_id FROM event_field_values WHERE field_id = ");
+            query_builder.push_bind(field.field_id.to_string());
+            query_builder.push(" AND value = ");
+            query_builder.push_bind(&field.value);
+            query_builder.push(" COLLATE NOCASE)");
+        }
+
+        if let Some(ref county) = filter.county {
+            query_builder.push(" AND county = ");
+            query_builder.push_bind(county);
+            query_builder.push(" COLLATE NOCASE");
+        }
+
+        if let Some(ref municipality) = filter.municipality {
+            query_builder.push(" AND municipality = ");
+            query_builder.push_bind(municipality);
+            query_builder.push(" COLLATE NOCASE");
+        }
     }
 }
 
@@ -179,41 +287,54 @@ impl EventRepository for SqliteEventRepository {
     async fn create(&self, event: &Event) -> DomainResult<()> {
         debug!("Creating enhanced event with id: {}", event.id);
         
-        let result = sqlx::query(
-            "INSERT INTO events (id, title, description, category_id, start_date, end_date, timezone, location_type, location_name, address, virtual_link, virtual_access_code, organizer_id, co_organizers, is_private, requires_approval, max_attendees, allow_guests, max_guests_per_person, registration_opens, registration_closes, registration_required, allow_waitlist, send_reminders, collect_dietary_info, collect_accessibility_info, image_url, custom_fields, status, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
-        )
-        .bind(event.id.to_string())
-        .bind(&event.title)
-        .bind(&event.description)
-        .bind(&event.category_id)
-        .bind(event.start_date.naive_utc())
-        .bind(event.end_date.naive_utc())
-        .bind(&event.timezone)
-        .bind(Self::location_type_to_string(&event.location_type))
-        .bind(event.location_name.as_deref())
-        .bind(event.address.as_deref())
-        .bind(event.virtual_link.as_deref())
-        .bind(event.virtual_access_code.as_deref())
-        .bind(event.organizer_id.to_string())
-        .bind(serde_json::to_string(&event.co_organizers).unwrap_or_default())
-        .bind(event.is_private)
-        .bind(event.requires_approval)
-        .bind(event.max_attendees)
-        .bind(event.allow_guests)
-        .bind(event.max_guests_per_person)
-        .bind(event.registration_opens.map(|dt| dt.naive_utc()))
-        .bind(event.registration_closes.map(|dt| dt.naive_utc()))
-        .bind(event.registration_required)
-        .bind(event.allow_waitlist)
-        .bind(event.send_reminders)
-        .bind(event.collect_dietary_info)
-        .bind(event.collect_accessibility_info)
-        .bind(event.image_url.as_deref())
-        .bind(event.custom_fields.as_deref())
-        .bind(Self::event_status_to_string(&event.status))
-        .bind(event.created_at.naive_utc())
-        .bind(event.updated_at.naive_utc())
-        .execute(&self.pool)
+        // The event and its co-organizers are written together
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        let result = async move {
+            sqlx::query(
+                "INSERT INTO events (id, title, slug, description, category_id, start_date, end_date, timezone, location_type, location_name, address, virtual_link, virtual_access_code, latitude, longitude, postal_code, municipality, county, organizer_id, is_private, requires_approval, max_attendees, allow_guests, max_guests_per_person, registration_opens, registration_closes, registration_required, allow_waitlist, send_reminders, collect_dietary_info, collect_accessibility_info, image_url, custom_fields, status, publish_at, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            )
+            .bind(event.id.to_string())
+            .bind(&event.title)
+            .bind(&event.slug)
+            .bind(&event.description)
+            .bind(&event.category_id)
+            .bind(event.start_date.naive_utc())
+            .bind(event.end_date.naive_utc())
+            .bind(&event.timezone)
+            .bind(Self::location_type_to_string(&event.location_type))
+            .bind(event.location_name.as_deref())
+            .bind(event.address.as_deref())
+            .bind(event.virtual_link.as_deref())
+            .bind(event.virtual_access_code.as_deref())
+            .bind(event.latitude)
+            .bind(event.longitude)
+            .bind(event.region.as_ref().map(|r| r.postal_code.as_str()))
+            .bind(event.region.as_ref().map(|r| r.municipality.as_str()))
+            .bind(event.region.as_ref().map(|r| r.county.as_str()))
+            .bind(event.organizer_id.to_string())
+            .bind(event.is_private)
+            .bind(event.requires_approval)
+            .bind(event.max_attendees)
+            .bind(event.allow_guests)
+            .bind(event.max_guests_per_person)
+            .bind(event.registration_opens.map(|dt| dt.naive_utc()))
+            .bind(event.registration_closes.map(|dt| dt.naive_utc()))
+            .bind(event.registration_required)
+            .bind(event.allow_waitlist)
+            .bind(event.send_reminders)
+            .bind(event.collect_dietary_info)
+            .bind(event.collect_accessibility_info)
+            .bind(event.image_url.as_deref())
+            .bind(event.custom_fields.as_deref())
+            .bind(Self::event_status_to_string(&event.status))
+            .bind(event.publish_at.map(|dt| dt.naive_utc()))
+            .bind(event.created_at.naive_utc())
+            .bind(event.updated_at.naive_utc())
+            .execute(&mut *tx)
+            .await?;
+            Self::replace_co_organizers(&mut tx, event).await?;
+            tx.commit().await
+        }
         .await;
 
         match result {
@@ -241,7 +362,7 @@ impl EventRepository for SqliteEventRepository {
         debug!("Finding enhanced event by id: {}", id);
 
         let id_string = id.to_string();
-        let result = sqlx::query("SELECT id, title, description, category_id, start_date, end_date, timezone, location_type, location_name, address, virtual_link, virtual_access_code, organizer_id, co_organizers, is_private, requires_approval, max_attendees, allow_guests, max_guests_per_person, registration_opens, registration_closes, registration_required, allow_waitlist, send_reminders, collect_dietary_info, collect_accessibility_info, image_url, custom_fields, status, created_at, updated_at FROM events WHERE id = ?")
+        let result = sqlx::query(&format!("{} WHERE id = ?", SELECT_EVENTS))
             .bind(id_string)
             .fetch_optional(&self.pool)
             .await;
@@ -267,50 +388,99 @@ impl EventRepository for SqliteEventRepository {
         }
     }
 
+    #[instrument(skip(self))]
+    async fn find_by_slug(&self, slug: &str) -> DomainResult<Option<Event>> {
+        debug!("Finding event by slug: {}", slug);
+
+        // Events without a slug are addressed by their id
+        let result = sqlx::query(&format!("{} WHERE slug = ? OR (slug IS NULL AND id = ?)", SELECT_EVENTS))
+            .bind(slug)
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await;
+
+        match result {
+            Ok(Some(row)) => {
+                let event = Self::row_to_event(&row)
+                    .map_err(InfrastructureError::from)?;
+                Ok(Some(event))
+            }
+            Ok(None) => {
+                debug!("Event not found with slug: {}", slug);
+                Ok(None)
+            }
+            Err(e) => {
+                let infrastructure_error = InfrastructureError::from(e);
+                match infrastructure_error {
+                    InfrastructureError::DomainError { source } => Err(source),
+                    other => Err(other.into()),
+                }
+            }
+        }
+    }
+
     // TODO: Implement remaining methods...
+    // The slug is fixed at creation so shared links keep working
     #[instrument(skip(self, event))]
     async fn update(&self, event: &Event) -> DomainResult<()> {
         debug!("Updating event with id: {}", event.id);
         
-        let result = sqlx::query(
-            "UPDATE events SET title = ?, description = ?, category_id = ?, start_date = ?, end_date = ?, timezone = ?, location_type = ?, location_name = ?, address = ?, virtual_link = ?, virtual_access_code = ?, organizer_id = ?, co_organizers = ?, is_private = ?, requires_approval = ?, max_attendees = ?, allow_guests = ?, max_guests_per_person = ?, registration_opens = ?, registration_closes = ?, registration_required = ?, allow_waitlist = ?, send_reminders = ?, collect_dietary_info = ?, collect_accessibility_info = ?, image_url = ?, custom_fields = ?, status = ?, updated_at = ? WHERE id = ?"
-        )
-        .bind(&event.title)
-        .bind(&event.description)
-        .bind(&event.category_id)
-        .bind(event.start_date.naive_utc())
-        .bind(event.end_date.naive_utc())
-        .bind(&event.timezone)
-        .bind(Self::location_type_to_string(&event.location_type))
-        .bind(event.location_name.as_deref())
-        .bind(event.address.as_deref())
-        .bind(event.virtual_link.as_deref())
-        .bind(event.virtual_access_code.as_deref())
-        .bind(event.organizer_id.to_string())
-        .bind(serde_json::to_string(&event.co_organizers).unwrap_or_default())
-        .bind(event.is_private)
-        .bind(event.requires_approval)
-        .bind(event.max_attendees)
-        .bind(event.allow_guests)
-        .bind(event.max_guests_per_person)
-        .bind(event.registration_opens.map(|dt| dt.naive_utc()))
-        .bind(event.registration_closes.map(|dt| dt.naive_utc()))
-        .bind(event.registration_required)
-        .bind(event.allow_waitlist)
-        .bind(event.send_reminders)
-        .bind(event.collect_dietary_info)
-        .bind(event.collect_accessibility_info)
-        .bind(event.image_url.as_deref())
-        .bind(event.custom_fields.as_deref())
-        .bind(Self::event_status_to_string(&event.status))
-        .bind(event.updated_at.naive_utc())
-        .bind(event.id.to_string())
-        .execute(&self.pool)
+        // The event and its co-organizers are written together
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        let result = async move {
+            let updated = sqlx::query(
+                "UPDATE events SET title = ?, description = ?, category_id = ?, start_date = ?, end_date = ?, timezone = ?, location_type = ?, location_name = ?, address = ?, virtual_link = ?, virtual_access_code = ?, latitude = ?, longitude = ?, postal_code = ?, municipality = ?, county = ?, organizer_id = ?, is_private = ?, requires_approval = ?, max_attendees = ?, allow_guests = ?, max_guests_per_person = ?, registration_opens = ?, registration_closes = ?, registration_required = ?, allow_waitlist = ?, send_reminders = ?, collect_dietary_info = ?, collect_accessibility_info = ?, image_url = ?, custom_fields = ?, status = ?, publish_at = ?, updated_at = ? WHERE id = ?"
+            )
+            .bind(&event.title)
+            .bind(&event.description)
+            .bind(&event.category_id)
+            .bind(event.start_date.naive_utc())
+            .bind(event.end_date.naive_utc())
+            .bind(&event.timezone)
+            .bind(Self::location_type_to_string(&event.location_type))
+            .bind(event.location_name.as_deref())
+            .bind(event.address.as_deref())
+            .bind(event.virtual_link.as_deref())
+            .bind(event.virtual_access_code.as_deref())
+            .bind(event.latitude)
+            .bind(event.longitude)
+            .bind(event.region.as_ref().map(|r| r.postal_code.as_str()))
+            .bind(event.region.as_ref().map(|r| r.municipality.as_str()))
+            .bind(event.region.as_ref().map(|r| r.county.as_str()))
+            .bind(event.organizer_id.to_string())
+            .bind(event.is_private)
+            .bind(event.requires_approval)
+            .bind(event.max_attendees)
+            .bind(event.allow_guests)
+            .bind(event.max_guests_per_person)
+            .bind(event.registration_opens.map(|dt| dt.naive_utc()))
+            .bind(event.registration_closes.map(|dt| dt.naive_utc()))
+            .bind(event.registration_required)
+            .bind(event.allow_waitlist)
+            .bind(event.send_reminders)
+            .bind(event.collect_dietary_info)
+            .bind(event.collect_accessibility_info)
+            .bind(event.image_url.as_deref())
+            .bind(event.custom_fields.as_deref())
+            .bind(Self::event_status_to_string(&event.status))
+            .bind(event.publish_at.map(|dt| dt.naive_utc()))
+            .bind(event.updated_at.naive_utc())
+            .bind(event.id.to_string())
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+            // Nothing to attach the co-organizers to if the event is gone
+            if updated > 0 {
+                Self::replace_co_organizers(&mut tx, event).await?;
+                tx.commit().await?;
+            }
+            Ok::<_, sqlx::Error>(updated)
+        }
         .await;
 
         match result {
-            Ok(query_result) => {
-                if query_result.rows_affected() == 0 {
+            Ok(updated) => {
+                if updated == 0 {
                     Err(aqio_core::DomainError::not_found("Event", event.id))
                 } else {
                     debug!("Successfully updated event with id: {}", event.id);
@@ -321,6 +491,9 @@ impl EventRepository for SqliteEventRepository {
                 let infrastructure_error = InfrastructureError::from(e);
                 match infrastructure_error {
                     InfrastructureError::DomainError { source } => Err(source),
+                    InfrastructureError::ForeignKeyConstraintViolation { message } => {
+                        Err(self.diagnose_foreign_key_violation(event, &message).await)
+                    }
                     other => Err(other.into()),
                 }
             }
@@ -344,7 +517,7 @@ impl EventRepository for SqliteEventRepository {
         };
         
         // Fetch the actual events with pagination
-        let result = sqlx::query("SELECT id, title, description, category_id, start_date, end_date, timezone, location_type, location_name, address, virtual_link, virtual_access_code, organizer_id, co_organizers, is_private, requires_approval, max_attendees, allow_guests, max_guests_per_person, registration_opens, registration_closes, registration_required, allow_waitlist, send_reminders, collect_dietary_info, collect_accessibility_info, image_url, custom_fields, status, created_at, updated_at FROM events WHERE organizer_id = ? ORDER BY start_date DESC LIMIT ? OFFSET ?")
+        let result = sqlx::query(&format!("{} WHERE organizer_id = ? ORDER BY start_date DESC LIMIT ? OFFSET ?", SELECT_EVENTS))
             .bind(organizer_id_string)
             .bind(pagination.limit)
             .bind(pagination.offset)
@@ -374,7 +547,7 @@ impl EventRepository for SqliteEventRepository {
     async fn find_by_category(&self, category_id: &str) -> DomainResult<Vec<Event>> {
         debug!("Finding events by category id: {}", category_id);
         
-        let result = sqlx::query("SELECT id, title, description, category_id, start_date, end_date, timezone, location_type, location_name, address, virtual_link, virtual_access_code, organizer_id, co_organizers, is_private, requires_approval, max_attendees, allow_guests, max_guests_per_person, registration_opens, registration_closes, registration_required, allow_waitlist, send_reminders, collect_dietary_info, collect_accessibility_info, image_url, custom_fields, status, created_at, updated_at FROM events WHERE category_id = ? ORDER BY start_date DESC")
+        let result = sqlx::query(&format!("{} WHERE category_id = ? ORDER BY start_date DESC", SELECT_EVENTS))
             .bind(category_id)
             .fetch_all(&self.pool)
             .await;
@@ -407,7 +580,7 @@ impl EventRepository for SqliteEventRepository {
         debug!("Listing events with filter and pagination");
         
         // Build the main query using the query builder
-        let mut query_builder = sqlx::QueryBuilder::new("SELECT id, title, description, category_id, start_date, end_date, timezone, location_type, location_name, address, virtual_link, virtual_access_code, organizer_id, co_organizers, is_private, requires_approval, max_attendees, allow_guests, max_guests_per_person, registration_opens, registration_closes, registration_required, allow_waitlist, send_reminders, collect_dietary_info, collect_accessibility_info, image_url, custom_fields, status, created_at, updated_at FROM events WHERE 1=1");
+        let mut query_builder = sqlx::QueryBuilder::new(format!("{} WHERE 1=1", SELECT_EVENTS));
         
         // Apply filters using the helper method
         self.apply_filter(&mut query_builder, filter);
@@ -464,7 +637,7 @@ impl EventRepository for SqliteEventRepository {
         };
         
         // Fetch the events with pagination
-        let result = sqlx::query("SELECT id, title, description, category_id, start_date, end_date, timezone, location_type, location_name, address, virtual_link, virtual_access_code, organizer_id, co_organizers, is_private, requires_approval, max_attendees, allow_guests, max_guests_per_person, registration_opens, registration_closes, registration_required, allow_waitlist, send_reminders, collect_dietary_info, collect_accessibility_info, image_url, custom_fields, status, created_at, updated_at FROM events ORDER BY start_date DESC LIMIT ? OFFSET ?")
+        let result = sqlx::query(&format!("{} ORDER BY start_date DESC LIMIT ? OFFSET ?", SELECT_EVENTS))
             .bind(pagination.limit)
             .bind(pagination.offset)
             .fetch_all(&self.pool)
@@ -518,6 +691,40 @@ impl EventRepository for SqliteEventRepository {
         }
     }
 
+    #[instrument(skip(self))]
+    async fn find_due_for_publishing(&self, now: DateTime<Utc>) -> DomainResult<Vec<Event>> {
+        debug!("Finding drafts due for publishing at {}", now);
+
+        // Drafts submitted for moderation wait until a submission is approved
+        let result = sqlx::query(&format!(
+            "{} WHERE status = 'draft' AND publish_at <= ? \
+             AND id NOT IN (SELECT event_id FROM event_submissions GROUP BY event_id HAVING SUM(status = 'approved') = 0) \
+             ORDER BY publish_at",
+            SELECT_EVENTS
+        ))
+            .bind(now.naive_utc())
+            .fetch_all(&self.pool)
+            .await;
+
+        match result {
+            Ok(rows) => {
+                let events: Result<Vec<Event>, RowConversionError> = rows.iter()
+                    .map(Self::row_to_event)
+                    .collect();
+                let events = events.map_err(InfrastructureError::from)?;
+                debug!("Found {} drafts due for publishing", events.len());
+                Ok(events)
+            }
+            Err(e) => {
+                let infrastructure_error = InfrastructureError::from(e);
+                match infrastructure_error {
+                    InfrastructureError::DomainError { source } => Err(source),
+                    other => Err(other.into()),
+                }
+            }
+        }
+    }
+
     #[instrument(skip(self))]
     async fn exists(&self, id: Uuid) -> DomainResult<bool> {
         debug!("Checking if event exists with id: {}", id);
@@ -551,103 +758,26 @@ impl EventRepository for SqliteEventRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aqio_core::{EventFilter, PaginationParams, LocationType, EventStatus};
+    use aqio_core::{AddressRegion, EventFilter, GeoRadius, PaginationParams, LocationType, EventStatus};
     use chrono::{Utc, Duration};
-    use sqlx::{Pool, Sqlite};
+    use crate::testing::TestDb;
     use uuid::Uuid;
 
-    // Test helper to create an in-memory SQLite database with schema
-    async fn create_test_db() -> Pool<Sqlite> {
-        let pool = sqlx::SqlitePool::connect(":memory:").await.unwrap();
-        
-        // Create event categories table first
-        sqlx::query(r#"
-            CREATE TABLE event_categories (
-                id TEXT PRIMARY KEY,
-                name TEXT NOT NULL UNIQUE,
-                description TEXT,
-                color_hex TEXT,
-                icon_name TEXT,
-                is_active BOOLEAN NOT NULL DEFAULT TRUE,
-                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
-            )
-        "#)
-        .execute(&pool)
-        .await
-        .unwrap();
-
-        // Insert default categories
-        sqlx::query(r#"
-            INSERT INTO event_categories (id, name, description, color_hex, icon_name) VALUES
-            ('conf', 'Conference', 'Large industry conferences and seminars', '#3B82F6', 'presentation'),
-            ('workshop', 'Workshop', 'Hands-on training and educational sessions', '#10B981', 'tools'),
-            ('networking', 'Networking', 'Social and professional networking events', '#F59E0B', 'users'),
-            ('training', 'Training', 'Professional development and skill building', '#8B5CF6', 'academic-cap'),
-            ('personal', 'Personal', 'Private celebrations and social gatherings', '#EC4899', 'heart'),
-            ('meeting', 'Meeting', 'Business meetings and discussions', '#6B7280', 'clipboard')
-        "#)
-        .execute(&pool)
-        .await
-        .unwrap();
-        
-        // Create the events table with the new sophisticated schema
-        sqlx::query(r#"
-            CREATE TABLE events (
-                id TEXT PRIMARY KEY,
-                title TEXT NOT NULL,
-                description TEXT NOT NULL,
-                category_id TEXT NOT NULL REFERENCES event_categories(id),
-                
-                start_date DATETIME NOT NULL,
-                end_date DATETIME NOT NULL,
-                timezone TEXT DEFAULT 'UTC',
-                
-                location_type TEXT NOT NULL CHECK(location_type IN ('physical', 'virtual', 'hybrid')) DEFAULT 'physical',
-                location_name TEXT,
-                address TEXT,
-                virtual_link TEXT,
-                virtual_access_code TEXT,
-                
-                organizer_id TEXT NOT NULL,
-                co_organizers TEXT DEFAULT '[]',
-                
-                is_private BOOLEAN NOT NULL DEFAULT FALSE,
-                requires_approval BOOLEAN NOT NULL DEFAULT FALSE,
-                max_attendees INTEGER,
-                allow_guests BOOLEAN NOT NULL DEFAULT FALSE,
-                max_guests_per_person INTEGER,
-                
-                registration_opens DATETIME,
-                registration_closes DATETIME,
-                registration_required BOOLEAN NOT NULL DEFAULT FALSE,
-                
-                allow_waitlist BOOLEAN NOT NULL DEFAULT FALSE,
-                send_reminders BOOLEAN NOT NULL DEFAULT TRUE,
-                collect_dietary_info BOOLEAN NOT NULL DEFAULT FALSE,
-                collect_accessibility_info BOOLEAN NOT NULL DEFAULT FALSE,
-                
-                image_url TEXT,
-                custom_fields TEXT,
-                
-                status TEXT NOT NULL CHECK(status IN ('draft', 'published', 'cancelled', 'completed')) DEFAULT 'draft',
-                
-                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
-            )
-        "#)
-        .execute(&pool)
-        .await
-        .unwrap();
-        
-        pool
+    // Test helper to create an isolated database with the real schema and a seeded organizer
+    async fn create_test_db() -> (TestDb, Uuid) {
+        let db = TestDb::in_memory().await;
+        let organizer_id = db.seed_user().await;
+        (db, organizer_id)
     }
     
     // Helper function to create a test event
-    fn create_test_event(title: &str) -> Event {
+    fn create_test_event(title: &str, organizer_id: Uuid) -> Event {
         let now = Utc::now();
+        let id = Uuid::new_v4();
         Event {
-            id: Uuid::new_v4(),
+            id,
             title: title.to_string(),
+            slug: Event::slug_for(title, id),
             description: "Test event description".to_string(),
             category_id: "conf".to_string(),
             start_date: now + Duration::hours(24),
@@ -658,7 +788,10 @@ mod tests {
             address: None,
             virtual_link: None,
             virtual_access_code: None,
-            organizer_id: Uuid::new_v4(),
+            latitude: None,
+            longitude: None,
+            region: None,
+            organizer_id,
             co_organizers: Vec::new(),
             is_private: false,
             requires_approval: false,
@@ -675,6 +808,7 @@ mod tests {
             image_url: None,
             custom_fields: None,
             status: EventStatus::Draft,
+            publish_at: None,
             created_at: now,
             updated_at: now,
         }
@@ -682,9 +816,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_create_and_find_event() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
-        let event = create_test_event("Test Conference");
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let event = create_test_event("Test Conference", organizer_id);
         let event_id = event.id;
 
         // Create the event
@@ -701,11 +835,30 @@ mod tests {
         assert_eq!(found_event.location_name, Some("Test Location".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_find_event_by_slug() {
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let mut event = create_test_event("Havbruk Summit", organizer_id);
+        repository.create(&event).await.unwrap();
+
+        let found = repository.find_by_slug(&event.slug).await.unwrap().unwrap();
+        assert_eq!(found.id, event.id);
+
+        // Renaming the event keeps the original link working
+        event.title = "Havbruk Summit 2025".to_string();
+        repository.update(&event).await.unwrap();
+        let found = repository.find_by_slug(&event.slug).await.unwrap().unwrap();
+        assert_eq!(found.title, "Havbruk Summit 2025");
+
+        assert!(repository.find_by_slug("no-such-event").await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn test_update_event() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
-        let mut event = create_test_event("Original Title");
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let mut event = create_test_event("Original Title", organizer_id);
         let event_id = event.id;
 
         // Create the event
@@ -727,9 +880,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_delete_event() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
-        let event = create_test_event("Event to Delete");
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let event = create_test_event("Event to Delete", organizer_id);
         let event_id = event.id;
 
         // Create the event
@@ -751,8 +904,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_delete_nonexistent_event() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
+        let (db, _) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
         let nonexistent_id = Uuid::new_v4();
 
         // Try to delete a non-existent event
@@ -762,9 +915,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_update_nonexistent_event() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
-        let event = create_test_event("Nonexistent Event");
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let event = create_test_event("Nonexistent Event", organizer_id);
 
         // Try to update a non-existent event
         let result = repository.update(&event).await;
@@ -773,9 +926,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_exists() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
-        let event = create_test_event("Existence Test");
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let event = create_test_event("Existence Test", organizer_id);
         let event_id = event.id;
         let nonexistent_id = Uuid::new_v4();
 
@@ -791,19 +944,18 @@ mod tests {
 
     #[tokio::test]
     async fn test_find_by_organizer() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
         
-        let organizer_id = Uuid::new_v4();
-        let other_organizer_id = Uuid::new_v4();
+        let other_organizer_id = db.seed_user().await;
         
-        let mut event1 = create_test_event("Event 1");
+        let mut event1 = create_test_event("Event 1", organizer_id);
         event1.organizer_id = organizer_id;
         
-        let mut event2 = create_test_event("Event 2");
+        let mut event2 = create_test_event("Event 2", organizer_id);
         event2.organizer_id = organizer_id;
         
-        let mut event3 = create_test_event("Event 3");
+        let mut event3 = create_test_event("Event 3", organizer_id);
         event3.organizer_id = other_organizer_id;
 
         // Create all events
@@ -829,16 +981,16 @@ mod tests {
 
     #[tokio::test]
     async fn test_find_by_category() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
         
-        let mut event1 = create_test_event("Conference Event");
+        let mut event1 = create_test_event("Conference Event", organizer_id);
         event1.category_id = "conf".to_string();
         
-        let mut event2 = create_test_event("Workshop Event");
+        let mut event2 = create_test_event("Workshop Event", organizer_id);
         event2.category_id = "workshop".to_string();
         
-        let mut event3 = create_test_event("Another Conference");
+        let mut event3 = create_test_event("Another Conference", organizer_id);
         event3.category_id = "conf".to_string();
 
         // Create all events
@@ -862,12 +1014,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_with_pagination() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
         
         // Create multiple events
         for i in 1..=5 {
-            let event = create_test_event(&format!("Event {}", i));
+            let event = create_test_event(&format!("Event {}", i), organizer_id);
             repository.create(&event).await.unwrap();
         }
 
@@ -881,6 +1033,12 @@ mod tests {
             location_type: None,
             start_date_from: None,
             start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
         };
         
         let pagination = PaginationParams { offset: 0, limit: 3 };
@@ -905,13 +1063,13 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_with_title_filter() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
         
         // Create events with different titles
-        let event1 = create_test_event("Rust Conference 2024");
-        let event2 = create_test_event("Python Workshop");
-        let event3 = create_test_event("Advanced Rust Training");
+        let event1 = create_test_event("Rust Conference 2024", organizer_id);
+        let event2 = create_test_event("Python Workshop", organizer_id);
+        let event3 = create_test_event("Advanced Rust Training", organizer_id);
         
         repository.create(&event1).await.unwrap();
         repository.create(&event2).await.unwrap();
@@ -927,6 +1085,12 @@ mod tests {
             location_type: None,
             start_date_from: None,
             start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
         };
         
         let pagination = PaginationParams { offset: 0, limit: 10 };
@@ -942,16 +1106,16 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_with_category_filter() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
         
-        let mut event1 = create_test_event("Event 1");
+        let mut event1 = create_test_event("Event 1", organizer_id);
         event1.category_id = "conf".to_string();
         
-        let mut event2 = create_test_event("Event 2");
+        let mut event2 = create_test_event("Event 2", organizer_id);
         event2.category_id = "workshop".to_string();
         
-        let mut event3 = create_test_event("Event 3");
+        let mut event3 = create_test_event("Event 3", organizer_id);
         event3.category_id = "conf".to_string();
         
         repository.create(&event1).await.unwrap();
@@ -968,6 +1132,12 @@ mod tests {
             location_type: None,
             start_date_from: None,
             start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
         };
         
         let pagination = PaginationParams { offset: 0, limit: 10 };
@@ -983,16 +1153,16 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_with_combined_filters() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
         
-        let mut event1 = create_test_event("Rust Conference");
+        let mut event1 = create_test_event("Rust Conference", organizer_id);
         event1.category_id = "conf".to_string();
         
-        let mut event2 = create_test_event("Rust Workshop");
+        let mut event2 = create_test_event("Rust Workshop", organizer_id);
         event2.category_id = "workshop".to_string();
         
-        let mut event3 = create_test_event("Python Conference");
+        let mut event3 = create_test_event("Python Conference", organizer_id);
         event3.category_id = "conf".to_string();
         
         repository.create(&event1).await.unwrap();
@@ -1009,6 +1179,12 @@ mod tests {
             location_type: None,
             start_date_from: None,
             start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
         };
         
         let pagination = PaginationParams { offset: 0, limit: 10 };
@@ -1020,10 +1196,121 @@ mod tests {
         assert_eq!(result.items[0].category_id, "conf");
     }
 
+    #[tokio::test]
+    async fn test_list_with_proximity_filter() {
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+
+        let mut bergen = create_test_event("Bergen", organizer_id);
+        bergen.latitude = Some(60.3913);
+        bergen.longitude = Some(5.3221);
+
+        // About 20 km south of Bergen
+        let mut os = create_test_event("Os", organizer_id);
+        os.latitude = Some(60.1869);
+        os.longitude = Some(5.4680);
+
+        let mut trondheim = create_test_event("Trondheim", organizer_id);
+        trondheim.latitude = Some(63.4305);
+        trondheim.longitude = Some(10.3951);
+
+        let unplaced = create_test_event("Online", organizer_id);
+
+        for event in [&bergen, &os, &trondheim, &unplaced] {
+            repository.create(event).await.unwrap();
+        }
+
+        let found = repository.find_by_id(bergen.id).await.unwrap().unwrap();
+        assert_eq!(found.latitude, Some(60.3913));
+        assert_eq!(found.longitude, Some(5.3221));
+
+        let filter = EventFilter {
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: None,
+            status: None,
+            location_type: None,
+            start_date_from: None,
+            start_date_to: None,
+            near: Some(GeoRadius::new(60.3913, 5.3221, 30.0).unwrap()),
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
+        };
+
+        let pagination = PaginationParams { offset: 0, limit: 10 };
+        let result = repository.find_by_filter(&filter, pagination).await.unwrap();
+
+        let mut titles: Vec<&str> = result.items.iter().map(|e| e.title.as_str()).collect();
+        titles.sort();
+        assert_eq!(titles, vec!["Bergen", "Os"]);
+        assert_eq!(result.total_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_by_region() {
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+
+        let region = |postal_code: &str, municipality: &str, county: &str| AddressRegion {
+            postal_code: postal_code.to_string(),
+            municipality: municipality.to_string(),
+            county: county.to_string(),
+        };
+
+        let mut bergen = create_test_event("Bergen", organizer_id);
+        bergen.region = Some(region("5003", "Bergen", "Vestland"));
+
+        let mut voss = create_test_event("Voss", organizer_id);
+        voss.region = Some(region("5700", "Voss", "Vestland"));
+
+        let mut bodo = create_test_event("Bodø", organizer_id);
+        bodo.region = Some(region("8006", "Bodø", "Nordland"));
+
+        let unplaced = create_test_event("Online", organizer_id);
+
+        for event in [&bergen, &voss, &bodo, &unplaced] {
+            repository.create(event).await.unwrap();
+        }
+
+        let found = repository.find_by_id(voss.id).await.unwrap().unwrap();
+        assert_eq!(found.region, voss.region);
+
+        let mut filter = EventFilter {
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: None,
+            status: None,
+            location_type: None,
+            start_date_from: None,
+            start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: Some("vestland".to_string()),
+            municipality: None,
+        };
+
+        let pagination = PaginationParams { offset: 0, limit: 10 };
+        let result = repository.find_by_filter(&filter, pagination.clone()).await.unwrap();
+        let mut titles: Vec<&str> = result.items.iter().map(|e| e.title.as_str()).collect();
+        titles.sort();
+        assert_eq!(titles, vec!["Bergen", "Voss"]);
+
+        filter.municipality = Some("Voss".to_string());
+        let result = repository.find_by_filter(&filter, pagination).await.unwrap();
+        assert_eq!(result.items.iter().map(|e| e.id).collect::<Vec<_>>(), vec![voss.id]);
+    }
+
     #[tokio::test]
     async fn test_empty_list() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
+        let (db, _) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
         
         let filter = EventFilter {
             title_contains: None,
@@ -1034,6 +1321,12 @@ mod tests {
             location_type: None,
             start_date_from: None,
             start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
         };
         
         let pagination = PaginationParams { offset: 0, limit: 10 };
@@ -1046,19 +1339,19 @@ mod tests {
 
     #[tokio::test]
     async fn test_event_ordering() {
-        let pool = create_test_db().await;
-        let repository = SqliteEventRepository::new(pool);
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
         
         let now = Utc::now();
         
         // Create events with different start dates
-        let mut event1 = create_test_event("Earliest Event");
+        let mut event1 = create_test_event("Earliest Event", organizer_id);
         event1.start_date = now + Duration::hours(1);
         
-        let mut event2 = create_test_event("Latest Event");
+        let mut event2 = create_test_event("Latest Event", organizer_id);
         event2.start_date = now + Duration::hours(3);
         
-        let mut event3 = create_test_event("Middle Event");
+        let mut event3 = create_test_event("Middle Event", organizer_id);
         event3.start_date = now + Duration::hours(2);
         
         repository.create(&event1).await.unwrap();
@@ -1075,6 +1368,12 @@ mod tests {
             location_type: None,
             start_date_from: None,
             start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: None,
+            field: None,
+            county: None,
+            municipality: None,
         };
         
         let pagination = PaginationParams { offset: 0, limit: 10 };
@@ -1087,4 +1386,92 @@ mod tests {
         assert_eq!(result.items[1].title, "Middle Event");
         assert_eq!(result.items[2].title, "Earliest Event");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_find_due_for_publishing() {
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let now = Utc::now();
+
+        let mut due = create_test_event("Due Draft", organizer_id);
+        due.publish_at = Some(now - Duration::minutes(1));
+        let mut later = create_test_event("Later Draft", organizer_id);
+        later.publish_at = Some(now + Duration::hours(1));
+        let mut published = create_test_event("Already Published", organizer_id);
+        published.publish_at = Some(now - Duration::hours(1));
+        published.status = EventStatus::Published;
+        let unscheduled = create_test_event("Unscheduled Draft", organizer_id);
+
+        for event in [&due, &later, &published, &unscheduled] {
+            repository.create(event).await.unwrap();
+        }
+
+        let found = repository.find_due_for_publishing(now).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, due.id);
+        assert_eq!(found[0].publish_at.map(|at| at.timestamp()), due.publish_at.map(|at| at.timestamp()));
+    }
+
+    #[tokio::test]
+    async fn test_co_organizers_round_trip_and_filter() {
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+        let kari = db.seed_user().await;
+        let per = db.seed_user().await;
+
+        let mut event = create_test_event("Shared Event", organizer_id);
+        event.co_organizers = vec![per, kari];
+        repository.create(&event).await.unwrap();
+        repository.create(&create_test_event("Solo Event", organizer_id)).await.unwrap();
+
+        // Listed order survives the round trip
+        let found = repository.find_by_id(event.id).await.unwrap().unwrap();
+        assert_eq!(found.co_organizers, vec![per, kari]);
+
+        let filter = EventFilter {
+            title_contains: None,
+            category_id: None,
+            organizer_id: None,
+            is_private: None,
+            status: None,
+            location_type: None,
+            start_date_from: None,
+            start_date_to: None,
+            near: None,
+            organizer_company_id: None,
+            co_organizer_id: Some(kari),
+            field: None,
+            county: None,
+            municipality: None,
+        };
+        let pagination = PaginationParams { offset: 0, limit: 10 };
+        let result = repository.find_by_filter(&filter, pagination.clone()).await.unwrap();
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.items[0].id, event.id);
+
+        // Updating replaces the list
+        event.co_organizers = vec![kari];
+        repository.update(&event).await.unwrap();
+        assert_eq!(repository.find_by_id(event.id).await.unwrap().unwrap().co_organizers, vec![kari]);
+        let filter = EventFilter { co_organizer_id: Some(per), ..filter };
+        assert_eq!(repository.find_by_filter(&filter, pagination).await.unwrap().total_count, 0);
+
+        // Deleting the account takes the co-organizer row with it
+        db.execute(&format!("DELETE FROM users WHERE id = '{}'", kari)).await;
+        assert!(repository.find_by_id(event.id).await.unwrap().unwrap().co_organizers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_co_organizer_is_rejected() {
+        let (db, organizer_id) = create_test_db().await;
+        let repository = SqliteEventRepository::new(db.pool().clone());
+
+        let mut event = create_test_event("Ghost Co-organizer", organizer_id);
+        event.co_organizers = vec![Uuid::new_v4()];
+
+        let error = repository.create(&event).await.unwrap_err();
+        assert!(error.to_string().contains("Co-organizer"), "unexpected error: {}", error);
+        // Nothing is left half-written
+        assert!(!repository.exists(event.id).await.unwrap());
+    }
+}
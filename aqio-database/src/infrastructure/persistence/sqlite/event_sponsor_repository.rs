@@ -0,0 +1,215 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventSponsorRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, EventSponsor, SponsorTier};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const SPONSOR_COLUMNS: &str = "id, event_id, name, tier, logo_url, website_url, description, display_order, created_at, updated_at";
+
+// Highest tier first, matching `EventSponsor::sort_for_display`
+const DISPLAY_ORDER: &str = "CASE tier WHEN 'platinum' THEN 0 WHEN 'gold' THEN 1 WHEN 'silver' THEN 2 WHEN 'bronze' THEN 3 ELSE 4 END, display_order, name COLLATE NOCASE";
+
+#[derive(Clone)]
+pub struct SqliteEventSponsorRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventSponsorRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventSponsor using SafeRowGet
+    fn row_to_sponsor(row: &sqlx::sqlite::SqliteRow) -> Result<EventSponsor, RowConversionError> {
+        Ok(EventSponsor {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            name: row.get_string("name")?,
+            tier: row.get_sponsor_tier("tier")?,
+            logo_url: row.get_optional_string("logo_url")?,
+            website_url: row.get_optional_string("website_url")?,
+            description: row.get_optional_string("description")?,
+            display_order: row.get_i32("display_order")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn tier_to_string(tier: SponsorTier) -> &'static str {
+        match tier {
+            SponsorTier::Platinum => "platinum",
+            SponsorTier::Gold => "gold",
+            SponsorTier::Silver => "silver",
+            SponsorTier::Bronze => "bronze",
+            SponsorTier::Partner => "partner",
+        }
+    }
+}
+
+#[async_trait]
+impl EventSponsorRepository for SqliteEventSponsorRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventSponsor>> {
+        debug!("Finding sponsor by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_sponsors WHERE id = ?", SPONSOR_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_sponsor(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<EventSponsor>> {
+        debug!("Listing sponsors for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_sponsors WHERE event_id = ? ORDER BY {}",
+            SPONSOR_COLUMNS, DISPLAY_ORDER
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let sponsors: Result<Vec<EventSponsor>, RowConversionError> = rows.iter()
+            .map(Self::row_to_sponsor)
+            .collect();
+        let sponsors = sponsors.map_err(InfrastructureError::from)?;
+
+        debug!("Listed {} sponsors for event {}", sponsors.len(), event_id);
+        Ok(sponsors)
+    }
+
+    #[instrument(skip(self, sponsor))]
+    async fn create(&self, sponsor: &EventSponsor) -> DomainResult<()> {
+        debug!("Creating sponsor {} on event {}", sponsor.id, sponsor.event_id);
+
+        sqlx::query(
+            "INSERT INTO event_sponsors (id, event_id, name, tier, logo_url, website_url, description, display_order, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(sponsor.id.to_string())
+        .bind(sponsor.event_id.to_string())
+        .bind(&sponsor.name)
+        .bind(Self::tier_to_string(sponsor.tier))
+        .bind(sponsor.logo_url.as_deref())
+        .bind(sponsor.website_url.as_deref())
+        .bind(sponsor.description.as_deref())
+        .bind(sponsor.display_order)
+        .bind(sponsor.created_at.naive_utc())
+        .bind(sponsor.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created sponsor with id: {}", sponsor.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self, sponsor))]
+    async fn update(&self, sponsor: &EventSponsor) -> DomainResult<()> {
+        debug!("Updating sponsor: {}", sponsor.id);
+
+        let result = sqlx::query(
+            "UPDATE event_sponsors SET name = ?, tier = ?, logo_url = ?, website_url = ?, description = ?, display_order = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(&sponsor.name)
+        .bind(Self::tier_to_string(sponsor.tier))
+        .bind(sponsor.logo_url.as_deref())
+        .bind(sponsor.website_url.as_deref())
+        .bind(sponsor.description.as_deref())
+        .bind(sponsor.display_order)
+        .bind(sponsor.updated_at.naive_utc())
+        .bind(sponsor.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventSponsor", sponsor.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting sponsor with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM event_sponsors WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("EventSponsor", id));
+        }
+        debug!("Successfully deleted sponsor with id: {}", id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    #[tokio::test]
+    async fn test_sponsors_round_trip_in_display_order() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventSponsorRepository::new(db.pool().clone());
+
+        let organizer = db.seed_user().await;
+        let event = db.seed_event(organizer).await;
+        let other_event = db.seed_event(organizer).await;
+
+        let partner = EventSponsor::new(event, "Fjord Media", SponsorTier::Partner);
+        let mut salmar = EventSponsor::new(event, "Salmar", SponsorTier::Gold);
+        salmar.display_order = 2;
+        let mut akva = EventSponsor::new(event, "akva group", SponsorTier::Gold);
+        akva.display_order = 1;
+        akva.logo_url = Some("https://akvagroup.example/logo.png".to_string());
+        akva.website_url = Some("https://akvagroup.example".to_string());
+        let mowi = EventSponsor::new(event, "Mowi", SponsorTier::Platinum);
+        for sponsor in [&partner, &salmar, &akva, &mowi] {
+            repository.create(sponsor).await.unwrap();
+        }
+        repository
+            .create(&EventSponsor::new(other_event, "Elsewhere", SponsorTier::Platinum))
+            .await
+            .unwrap();
+
+        let listed = repository.find_by_event_id(event).await.unwrap();
+        let ids: Vec<Uuid> = listed.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![mowi.id, akva.id, salmar.id, partner.id]);
+        assert_eq!(listed[1].tier, SponsorTier::Gold);
+        assert_eq!(listed[1].logo_url.as_deref(), Some("https://akvagroup.example/logo.png"));
+
+        // The database and the domain agree on the order
+        let mut sorted = listed.clone();
+        EventSponsor::sort_for_display(&mut sorted);
+        assert_eq!(sorted.iter().map(|s| s.id).collect::<Vec<_>>(), ids);
+
+        salmar.tier = SponsorTier::Platinum;
+        salmar.description = Some("Main sponsor".to_string());
+        repository.update(&salmar).await.unwrap();
+        let found = repository.find_by_id(salmar.id).await.unwrap().unwrap();
+        assert_eq!(found.tier, SponsorTier::Platinum);
+        assert_eq!(found.description.as_deref(), Some("Main sponsor"));
+
+        repository.delete(partner.id).await.unwrap();
+        assert!(repository.find_by_id(partner.id).await.unwrap().is_none());
+        assert!(repository.delete(partner.id).await.is_err());
+        assert_eq!(repository.find_by_event_id(event).await.unwrap().len(), 3);
+    }
+}
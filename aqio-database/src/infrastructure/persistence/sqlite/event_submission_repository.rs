@@ -0,0 +1,193 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventSubmissionRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::event_submission_status_to_string,
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{DomainResult, EventSubmission};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const SUBMISSION_COLUMNS: &str = "id, event_id, submitted_by, status, review_comment, reviewed_by, created_at, reviewed_at";
+
+#[derive(Clone)]
+pub struct SqliteEventSubmissionRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventSubmissionRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventSubmission using SafeRowGet
+    fn row_to_submission(row: &sqlx::sqlite::SqliteRow) -> Result<EventSubmission, RowConversionError> {
+        Ok(EventSubmission {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            submitted_by: row.get_uuid("submitted_by")?,
+            status: row.get_event_submission_status("status")?,
+            review_comment: row.get_optional_string("review_comment")?,
+            reviewed_by: row.get_optional_uuid("reviewed_by")?,
+            created_at: row.get_datetime("created_at")?,
+            reviewed_at: row.get_optional_datetime("reviewed_at")?,
+        })
+    }
+
+    fn rows_to_submissions(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<EventSubmission>> {
+        rows.iter()
+            .map(|row| Self::row_to_submission(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl EventSubmissionRepository for SqliteEventSubmissionRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<EventSubmission>> {
+        debug!("Finding event submission by ID: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_submissions WHERE id = ?", SUBMISSION_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_submission(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_pending(&self) -> DomainResult<Vec<EventSubmission>> {
+        debug!("Finding event submissions waiting for a moderator");
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_submissions WHERE status = 'pending' ORDER BY created_at",
+            SUBMISSION_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_submissions(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_submitter(&self, user_id: Uuid) -> DomainResult<Vec<EventSubmission>> {
+        debug!("Finding event submissions by user: {}", user_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_submissions WHERE submitted_by = ? ORDER BY created_at DESC",
+            SUBMISSION_COLUMNS
+        ))
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_submissions(&rows)
+    }
+
+    #[instrument(skip(self, submission))]
+    async fn create(&self, submission: &EventSubmission) -> DomainResult<()> {
+        debug!("Creating submission {} of event {}", submission.id, submission.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO event_submissions ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            SUBMISSION_COLUMNS
+        ))
+        .bind(submission.id.to_string())
+        .bind(submission.event_id.to_string())
+        .bind(submission.submitted_by.to_string())
+        .bind(event_submission_status_to_string(submission.status))
+        .bind(&submission.review_comment)
+        .bind(submission.reviewed_by.map(|id| id.to_string()))
+        .bind(submission.created_at.naive_utc())
+        .bind(submission.reviewed_at.map(|at| at.naive_utc()))
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, submission))]
+    async fn update(&self, submission: &EventSubmission) -> DomainResult<()> {
+        debug!("Updating event submission: {}", submission.id);
+
+        // Only the review changes after an event is submitted
+        sqlx::query(
+            "UPDATE event_submissions SET status = ?, review_comment = ?, reviewed_by = ?, reviewed_at = ? WHERE id = ?",
+        )
+        .bind(event_submission_status_to_string(submission.status))
+        .bind(&submission.review_comment)
+        .bind(submission.reviewed_by.map(|id| id.to_string()))
+        .bind(submission.reviewed_at.map(|at| at.naive_utc()))
+        .bind(submission.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::sqlite::SqliteEventRepository;
+    use crate::testing::TestDb;
+    use aqio_core::{EventRepository, EventSubmissionStatus};
+
+    #[tokio::test]
+    async fn test_submission_round_trips_and_holds_the_draft_back() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventSubmissionRepository::new(db.pool().clone());
+        let events = SqliteEventRepository::new(db.pool().clone());
+        let submitter = db.seed_user().await;
+        let moderator = db.seed_user().await;
+        let event_id = db.seed_event(submitter).await;
+        db.execute(&format!(
+            "UPDATE events SET status = 'draft', publish_at = '2020-01-01 00:00:00' WHERE id = '{}'",
+            event_id
+        ))
+        .await;
+        let events = &events;
+        let due = || async move { events.find_due_for_publishing(chrono::Utc::now()).await.unwrap() };
+        assert_eq!(due().await.len(), 1);
+
+        let mut submission = EventSubmission::new(event_id, submitter);
+        repository.create(&submission).await.unwrap();
+        let found = repository.find_by_id(submission.id).await.unwrap().unwrap();
+        assert_eq!((found.event_id, found.submitted_by), (event_id, submitter));
+        assert!(found.is_pending() && found.reviewed_at.is_none());
+        assert_eq!(repository.find_pending().await.unwrap().len(), 1);
+        assert_eq!(repository.find_by_submitter(submitter).await.unwrap()[0].id, submission.id);
+        // A draft under review isn't published on schedule
+        assert!(due().await.is_empty());
+
+        // Only one submission per event waits for a moderator
+        assert!(repository.create(&EventSubmission::new(event_id, submitter)).await.is_err());
+
+        submission.review(EventSubmissionStatus::Rejected, moderator, Some("Missing venue".to_string()), chrono::Utc::now());
+        repository.update(&submission).await.unwrap();
+        let found = repository.find_by_id(submission.id).await.unwrap().unwrap();
+        assert_eq!(found.status, EventSubmissionStatus::Rejected);
+        assert_eq!(found.reviewed_by, Some(moderator));
+        assert_eq!(found.review_comment.as_deref(), Some("Missing venue"));
+        assert!(repository.find_pending().await.unwrap().is_empty());
+        assert!(due().await.is_empty());
+
+        let mut resubmission = EventSubmission::new(event_id, submitter);
+        repository.create(&resubmission).await.unwrap();
+        resubmission.review(EventSubmissionStatus::Approved, moderator, None, chrono::Utc::now());
+        repository.update(&resubmission).await.unwrap();
+        assert_eq!(due().await.len(), 1);
+    }
+}
@@ -0,0 +1,428 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::EventSummaryRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{
+    ArchiveGrouping, DomainResult, EventSummary, PaginatedResult, PaginationParams, PastEventFilter, RegistrationCounts,
+    YearlyAttendance,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, QueryBuilder, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const SUMMARY_COLUMNS: &str = "s.event_id, s.title, s.category_id, s.category_name, s.organizer_id, s.organizer_name, \
+     s.start_date, s.end_date, s.status, s.registered_count, s.waitlisted_count, s.attended_count, s.cancelled_count, \
+     s.checked_in_count, s.updated_at";
+
+const START_YEAR: &str = "CAST(strftime('%Y', s.start_date) AS INTEGER)";
+
+/// Reads the `event_summaries` table. The rows are written by the triggers in
+/// migration 022, never by this repository.
+#[derive(Clone)]
+pub struct SqliteEventSummaryRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteEventSummaryRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventSummary using SafeRowGet
+    fn row_to_summary(row: &sqlx::sqlite::SqliteRow) -> Result<EventSummary, RowConversionError> {
+        let event_id = row.get_uuid("event_id")?;
+        Ok(EventSummary {
+            event_id,
+            title: row.get_string("title")?,
+            category_id: row.get_string("category_id")?,
+            category_name: row.get_optional_string("category_name")?,
+            organizer_id: row.get_uuid("organizer_id")?,
+            organizer_name: row.get_optional_string("organizer_name")?,
+            start_date: row.get_datetime("start_date")?,
+            end_date: row.get_datetime("end_date")?,
+            status: row.get_event_status("status")?,
+            registration_counts: RegistrationCounts {
+                event_id,
+                registered: row.get_i64("registered_count")?,
+                waitlisted: row.get_i64("waitlisted_count")?,
+                attended: row.get_i64("attended_count")?,
+                cancelled: row.get_i64("cancelled_count")?,
+            },
+            checked_in: row.get_i64("checked_in_count")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    // Helper method to convert an aggregated row to YearlyAttendance using SafeRowGet
+    fn row_to_yearly_attendance(row: &sqlx::sqlite::SqliteRow) -> Result<YearlyAttendance, RowConversionError> {
+        Ok(YearlyAttendance {
+            year: row.get_i32("year")?,
+            group_id: row.get_string("group_id")?,
+            group_name: row.get_optional_string("group_name")?,
+            events: row.get_i64("events")?,
+            registrations: row.get_i64("registrations")?,
+            checked_in: row.get_i64("checked_in")?,
+            cancelled: row.get_i64("cancelled")?,
+        })
+    }
+
+    // FROM and WHERE clauses for the past events `filter` covers. Privacy isn't
+    // part of the read model, so the events themselves are joined for it.
+    fn push_past_events<'a>(query_builder: &mut QueryBuilder<'a, Sqlite>, filter: &'a PastEventFilter, now: DateTime<Utc>) {
+        query_builder.push(
+            " FROM event_summaries s JOIN events e ON e.id = s.event_id \
+             WHERE s.status NOT IN ('draft', 'cancelled') AND s.end_date < ",
+        );
+        query_builder.push_bind(now.naive_utc());
+
+        if let Some(ref title) = filter.title_contains {
+            query_builder.push(" AND s.title LIKE ");
+            query_builder.push_bind(format!("%{}%", title));
+        }
+        if let Some(ref category_id) = filter.category_id {
+            query_builder.push(" AND s.category_id = ");
+            query_builder.push_bind(category_id);
+        }
+        if let Some(organizer_id) = filter.organizer_id {
+            query_builder.push(" AND s.organizer_id = ");
+            query_builder.push_bind(organizer_id.to_string());
+        }
+        if let Some(from_year) = filter.from_year {
+            query_builder.push(format!(" AND {} >= ", START_YEAR));
+            query_builder.push_bind(from_year);
+        }
+        if let Some(to_year) = filter.to_year {
+            query_builder.push(format!(" AND {} <= ", START_YEAR));
+            query_builder.push_bind(to_year);
+        }
+        if let Some(viewer_id) = filter.visible_to {
+            query_builder.push(" AND (e.is_private = 0 OR s.organizer_id = ");
+            query_builder.push_bind(viewer_id.to_string());
+            query_builder.push(" OR EXISTS (SELECT 1 FROM event_co_organizers c WHERE c.event_id = s.event_id AND c.user_id = ");
+            query_builder.push_bind(viewer_id.to_string());
+            query_builder.push("))");
+        }
+    }
+}
+
+#[async_trait]
+impl EventSummaryRepository for SqliteEventSummaryRepository {
+    #[instrument(skip(self, event_ids), fields(count = event_ids.len()))]
+    async fn find_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<EventSummary>> {
+        if event_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        debug!("Finding summaries of {} events", event_ids.len());
+
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {} FROM event_summaries s WHERE s.event_id IN (",
+            SUMMARY_COLUMNS
+        ));
+        let mut separated = query_builder.separated(", ");
+        for event_id in event_ids {
+            separated.push_bind(event_id.to_string());
+        }
+        query_builder.push(")");
+
+        let rows = query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_summary(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, filter))]
+    async fn find_past(
+        &self,
+        filter: &PastEventFilter,
+        now: DateTime<Utc>,
+        pagination: PaginationParams,
+    ) -> DomainResult<PaginatedResult<EventSummary>> {
+        debug!("Listing past events");
+
+        let mut count_builder = QueryBuilder::new("SELECT COUNT(*) AS count");
+        Self::push_past_events(&mut count_builder, filter, now);
+        let total_count: i64 = count_builder
+            .build()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?
+            .get_i64("count")
+            .map_err(InfrastructureError::from)?;
+
+        let mut query_builder = QueryBuilder::new(format!("SELECT {}", SUMMARY_COLUMNS));
+        Self::push_past_events(&mut query_builder, filter, now);
+        query_builder.push(" ORDER BY s.start_date DESC LIMIT ");
+        query_builder.push_bind(pagination.limit);
+        query_builder.push(" OFFSET ");
+        query_builder.push_bind(pagination.offset);
+        let rows = query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        let summaries = rows
+            .iter()
+            .map(Self::row_to_summary)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(InfrastructureError::from)?;
+        debug!("Listed {} past events (total: {})", summaries.len(), total_count);
+        Ok(PaginatedResult::new(summaries, total_count, pagination))
+    }
+
+    #[instrument(skip(self, filter))]
+    async fn yearly_attendance(
+        &self,
+        filter: &PastEventFilter,
+        grouping: ArchiveGrouping,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Vec<YearlyAttendance>> {
+        debug!("Totalling past events per year by {:?}", grouping);
+
+        // The county isn't in the read model; it comes from the joined event
+        let (group_id, group_name) = match grouping {
+            ArchiveGrouping::Category => ("s.category_id", "MAX(s.category_name)"),
+            ArchiveGrouping::Organizer => ("s.organizer_id", "MAX(s.organizer_name)"),
+            ArchiveGrouping::County => ("COALESCE(e.county, '')", "MAX(e.county)"),
+        };
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT {year} AS year, {group_id} AS group_id, {group_name} AS group_name, COUNT(*) AS events, \
+             SUM(s.registered_count + s.attended_count) AS registrations, SUM(s.checked_in_count) AS checked_in, \
+             SUM(s.cancelled_count) AS cancelled",
+            year = START_YEAR,
+            group_id = group_id,
+            group_name = group_name
+        ));
+        Self::push_past_events(&mut query_builder, filter, now);
+        query_builder.push(" GROUP BY group_id, year ORDER BY group_id, year");
+
+        let rows = query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_yearly_attendance(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::EventStatus;
+
+    async fn register(db: &TestDb, event_id: Uuid, status: &str) -> String {
+        let id = Uuid::new_v4().to_string();
+        // Guests register once per event, so each needs an address of their own
+        sqlx::query("INSERT INTO event_registrations (id, event_id, registrant_email, registrant_name, status) VALUES (?, ?, ?, 'Guest', ?)")
+            .bind(&id)
+            .bind(event_id.to_string())
+            .bind(format!("guest-{}@example.no", id))
+            .bind(status)
+            .execute(db.pool())
+            .await
+            .unwrap();
+        id
+    }
+
+    async fn summary(repository: &SqliteEventSummaryRepository, event_id: Uuid) -> Option<EventSummary> {
+        repository.find_by_event_ids(&[event_id]).await.unwrap().pop()
+    }
+
+    #[tokio::test]
+    async fn test_summary_follows_event_changes() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventSummaryRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+
+        let created = summary(&repository, event_id).await.unwrap();
+        assert_eq!(created.title, "Seeded Event");
+        assert_eq!(created.organizer_id, organizer);
+        assert!(created.organizer_name.is_some());
+        assert_eq!(created.category_id, "conf");
+        assert!(matches!(created.status, EventStatus::Published));
+        assert_eq!(created.registration_counts, RegistrationCounts::empty(event_id));
+
+        sqlx::query("UPDATE events SET title = 'Renamed', status = 'cancelled' WHERE id = ?")
+            .bind(event_id.to_string())
+            .execute(db.pool())
+            .await
+            .unwrap();
+        sqlx::query("UPDATE users SET name = 'Kari Nordmann' WHERE id = ?")
+            .bind(organizer.to_string())
+            .execute(db.pool())
+            .await
+            .unwrap();
+        let updated = summary(&repository, event_id).await.unwrap();
+        assert_eq!(updated.title, "Renamed");
+        assert!(matches!(updated.status, EventStatus::Cancelled));
+        assert_eq!(updated.organizer_name.as_deref(), Some("Kari Nordmann"));
+
+        sqlx::query("DELETE FROM events WHERE id = ?")
+            .bind(event_id.to_string())
+            .execute(db.pool())
+            .await
+            .unwrap();
+        assert!(summary(&repository, event_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_summary_counts_follow_registrations() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventSummaryRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+        let other_event = db.seed_event(organizer).await;
+
+        let first = register(&db, event_id, "registered").await;
+        register(&db, event_id, "registered").await;
+        let waitlisted = register(&db, event_id, "waitlisted").await;
+        register(&db, other_event, "registered").await;
+
+        // Check-in, promotion and moving a registration to another event
+        sqlx::query("UPDATE event_registrations SET status = 'attended', checked_in_at = CURRENT_TIMESTAMP WHERE id = ?")
+            .bind(&first)
+            .execute(db.pool())
+            .await
+            .unwrap();
+        sqlx::query("UPDATE event_registrations SET event_id = ? WHERE id = ?")
+            .bind(other_event.to_string())
+            .bind(&waitlisted)
+            .execute(db.pool())
+            .await
+            .unwrap();
+
+        let summaries = repository.find_by_event_ids(&[event_id, other_event]).await.unwrap();
+        let counts = |id: Uuid| summaries.iter().find(|s| s.event_id == id).unwrap().clone();
+        let busy = counts(event_id);
+        assert_eq!((busy.registration_counts.registered, busy.registration_counts.attended), (1, 1));
+        assert_eq!(busy.registration_counts.waitlisted, 0);
+        assert_eq!(busy.checked_in, 1);
+        let other = counts(other_event);
+        assert_eq!((other.registration_counts.registered, other.registration_counts.waitlisted), (1, 1));
+
+        sqlx::query("DELETE FROM event_registrations WHERE id = ?")
+            .bind(&first)
+            .execute(db.pool())
+            .await
+            .unwrap();
+        let busy = summary(&repository, event_id).await.unwrap();
+        assert_eq!(busy.registration_counts.attended, 0);
+        assert_eq!(busy.checked_in, 0);
+
+        assert!(repository.find_by_event_ids(&[]).await.unwrap().is_empty());
+    }
+
+    async fn move_to(db: &TestDb, event_id: Uuid, start: &str, extra: &str) {
+        db.execute(&format!(
+            "UPDATE events SET start_date = '{0} 18:00:00', end_date = '{0} 21:00:00'{1} WHERE id = '{2}'",
+            start, extra, event_id
+        ))
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_past_events_are_browsed_and_totalled_per_year() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventSummaryRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let other = db.seed_user().await;
+
+        let summit_2024 = db.seed_event(organizer).await;
+        move_to(&db, summit_2024, "2024-05-14", "").await;
+        let summit_2025 = db.seed_event(organizer).await;
+        move_to(&db, summit_2025, "2025-05-13", ", status = 'completed'").await;
+        let meetup_2025 = db.seed_event(other).await;
+        move_to(&db, meetup_2025, "2025-09-02", ", category_id = 'workshop', is_private = 1").await;
+        let cancelled = db.seed_event(organizer).await;
+        move_to(&db, cancelled, "2025-10-01", ", status = 'cancelled'").await;
+        let draft = db.seed_event(organizer).await;
+        move_to(&db, draft, "2025-11-01", ", status = 'draft'").await;
+        let upcoming = db.seed_event(organizer).await;
+
+        for (event_id, status) in [
+            (summit_2024, "registered"),
+            (summit_2024, "registered"),
+            (summit_2025, "registered"),
+            (summit_2025, "registered"),
+            (summit_2025, "registered"),
+            (summit_2025, "cancelled"),
+            (meetup_2025, "registered"),
+            (upcoming, "registered"),
+        ] {
+            register(&db, event_id, status).await;
+        }
+        db.execute(&format!(
+            "UPDATE event_registrations SET status = 'attended', checked_in_at = CURRENT_TIMESTAMP \
+             WHERE id IN (SELECT id FROM event_registrations WHERE event_id = '{}' AND status = 'registered' LIMIT 2)",
+            summit_2025
+        ))
+        .await;
+
+        let now = Utc::now();
+        let everything = PastEventFilter::default();
+        let page = repository.find_past(&everything, now, PaginationParams::default()).await.unwrap();
+        let ids: Vec<Uuid> = page.items.iter().map(|s| s.event_id).collect();
+        assert_eq!(ids, vec![meetup_2025, summit_2025, summit_2024]);
+        assert_eq!(page.total_count, 3);
+
+        // Private events only for their organizers
+        let public = PastEventFilter { visible_to: Some(organizer), ..Default::default() };
+        let page = repository.find_past(&public, now, PaginationParams::default()).await.unwrap();
+        assert_eq!(page.items.iter().map(|s| s.event_id).collect::<Vec<_>>(), vec![summit_2025, summit_2024]);
+        let own = PastEventFilter { visible_to: Some(other), ..Default::default() };
+        assert_eq!(repository.find_past(&own, now, PaginationParams::default()).await.unwrap().total_count, 3);
+
+        let in_2025 = PastEventFilter { from_year: Some(2025), to_year: Some(2025), ..Default::default() };
+        let page = repository.find_past(&in_2025, now, PaginationParams::new(1, 1).unwrap()).await.unwrap();
+        assert_eq!((page.total_count, page.items[0].event_id), (2, summit_2025));
+
+        let by_category = repository
+            .yearly_attendance(&everything, ArchiveGrouping::Category, now)
+            .await
+            .unwrap();
+        let totals: Vec<(&str, i32, i64, i64, i64, i64)> = by_category
+            .iter()
+            .map(|y| (y.group_id.as_str(), y.year, y.events, y.registrations, y.checked_in, y.cancelled))
+            .collect();
+        assert_eq!(
+            totals,
+            vec![("conf", 2024, 1, 2, 0, 0), ("conf", 2025, 1, 3, 2, 1), ("workshop", 2025, 1, 1, 0, 0)]
+        );
+        assert!(by_category[0].group_name.is_some());
+
+        let by_organizer = repository
+            .yearly_attendance(&public, ArchiveGrouping::Organizer, now)
+            .await
+            .unwrap();
+        assert_eq!(by_organizer.len(), 2);
+        assert!(by_organizer.iter().all(|y| y.group_id == organizer.to_string()));
+
+        sqlx::query("UPDATE events SET postal_code = '5003', municipality = 'Bergen', county = 'Vestland' WHERE id = ?")
+            .bind(summit_2025.to_string())
+            .execute(db.pool())
+            .await
+            .unwrap();
+        let by_county = repository
+            .yearly_attendance(&in_2025, ArchiveGrouping::County, now)
+            .await
+            .unwrap();
+        let counties: Vec<(&str, Option<&str>, i64)> = by_county
+            .iter()
+            .map(|y| (y.group_id.as_str(), y.group_name.as_deref(), y.events))
+            .collect();
+        assert_eq!(counties, vec![("", None, 1), ("Vestland", Some("Vestland"), 1)]);
+    }
+}
@@ -0,0 +1,342 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::ExhibitorRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{Booth, DomainError, DomainResult, Exhibitor};
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const BOOTH_COLUMNS: &str = "id, event_id, number, hall, x, y, width, depth, created_at, updated_at";
+const EXHIBITOR_COLUMNS: &str = "id, event_id, company_name, company_id, contact_name, contact_email, website_url, description, booth_id, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteExhibitorRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteExhibitorRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to Booth using SafeRowGet
+    fn row_to_booth(row: &sqlx::sqlite::SqliteRow) -> Result<Booth, RowConversionError> {
+        Ok(Booth {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            number: row.get_string("number")?,
+            hall: row.get_optional_string("hall")?,
+            x: row.get_f64("x")?,
+            y: row.get_f64("y")?,
+            width: row.get_f64("width")?,
+            depth: row.get_f64("depth")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn row_to_exhibitor(row: &sqlx::sqlite::SqliteRow) -> Result<Exhibitor, RowConversionError> {
+        Ok(Exhibitor {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            company_name: row.get_string("company_name")?,
+            company_id: row.get_optional_uuid("company_id")?,
+            contact_name: row.get_optional_string("contact_name")?,
+            contact_email: row.get_optional_string("contact_email")?,
+            website_url: row.get_optional_string("website_url")?,
+            description: row.get_optional_string("description")?,
+            booth_id: row.get_optional_uuid("booth_id")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl ExhibitorRepository for SqliteExhibitorRepository {
+    #[instrument(skip(self))]
+    async fn find_booth(&self, id: Uuid) -> DomainResult<Option<Booth>> {
+        debug!("Finding booth by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM booths WHERE id = ?", BOOTH_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_booth(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_booths(&self, event_id: Uuid) -> DomainResult<Vec<Booth>> {
+        debug!("Listing booths for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM booths WHERE event_id = ? ORDER BY hall COLLATE NOCASE, number COLLATE NOCASE",
+            BOOTH_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let booths: Result<Vec<Booth>, RowConversionError> = rows.iter().map(Self::row_to_booth).collect();
+        let booths = booths.map_err(InfrastructureError::from)?;
+        Ok(booths)
+    }
+
+    #[instrument(skip(self, booth))]
+    async fn create_booth(&self, booth: &Booth) -> DomainResult<()> {
+        debug!("Creating booth {} on event {}", booth.number, booth.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO booths ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            BOOTH_COLUMNS
+        ))
+        .bind(booth.id.to_string())
+        .bind(booth.event_id.to_string())
+        .bind(&booth.number)
+        .bind(booth.hall.as_deref())
+        .bind(booth.x)
+        .bind(booth.y)
+        .bind(booth.width)
+        .bind(booth.depth)
+        .bind(booth.created_at.naive_utc())
+        .bind(booth.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, booth))]
+    async fn update_booth(&self, booth: &Booth) -> DomainResult<()> {
+        debug!("Updating booth: {}", booth.id);
+
+        let result = sqlx::query(
+            "UPDATE booths SET number = ?, hall = ?, x = ?, y = ?, width = ?, depth = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(&booth.number)
+        .bind(booth.hall.as_deref())
+        .bind(booth.x)
+        .bind(booth.y)
+        .bind(booth.width)
+        .bind(booth.depth)
+        .bind(booth.updated_at.naive_utc())
+        .bind(booth.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("Booth", booth.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_booth(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting booth with id: {}", id);
+
+        // The exhibitor loses its booth (ON DELETE SET NULL)
+        let result = sqlx::query("DELETE FROM booths WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("Booth", id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_exhibitor(&self, id: Uuid) -> DomainResult<Option<Exhibitor>> {
+        debug!("Finding exhibitor by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM exhibitors WHERE id = ?", EXHIBITOR_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_exhibitor(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_exhibitors(&self, event_id: Uuid) -> DomainResult<Vec<Exhibitor>> {
+        debug!("Listing exhibitors for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM exhibitors WHERE event_id = ? ORDER BY company_name COLLATE NOCASE",
+            EXHIBITOR_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let exhibitors: Result<Vec<Exhibitor>, RowConversionError> = rows.iter().map(Self::row_to_exhibitor).collect();
+        let exhibitors = exhibitors.map_err(InfrastructureError::from)?;
+        Ok(exhibitors)
+    }
+
+    #[instrument(skip(self, exhibitor))]
+    async fn create_exhibitor(&self, exhibitor: &Exhibitor) -> DomainResult<()> {
+        debug!("Creating exhibitor {} on event {}", exhibitor.id, exhibitor.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO exhibitors ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            EXHIBITOR_COLUMNS
+        ))
+        .bind(exhibitor.id.to_string())
+        .bind(exhibitor.event_id.to_string())
+        .bind(&exhibitor.company_name)
+        .bind(exhibitor.company_id.map(|id| id.to_string()))
+        .bind(exhibitor.contact_name.as_deref())
+        .bind(exhibitor.contact_email.as_deref())
+        .bind(exhibitor.website_url.as_deref())
+        .bind(exhibitor.description.as_deref())
+        .bind(exhibitor.booth_id.map(|id| id.to_string()))
+        .bind(exhibitor.created_at.naive_utc())
+        .bind(exhibitor.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, exhibitor))]
+    async fn update_exhibitor(&self, exhibitor: &Exhibitor) -> DomainResult<()> {
+        debug!("Updating exhibitor: {}", exhibitor.id);
+
+        let result = sqlx::query(
+            "UPDATE exhibitors
+             SET company_name = ?, company_id = ?, contact_name = ?, contact_email = ?, website_url = ?, description = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&exhibitor.company_name)
+        .bind(exhibitor.company_id.map(|id| id.to_string()))
+        .bind(exhibitor.contact_name.as_deref())
+        .bind(exhibitor.contact_email.as_deref())
+        .bind(exhibitor.website_url.as_deref())
+        .bind(exhibitor.description.as_deref())
+        .bind(exhibitor.updated_at.naive_utc())
+        .bind(exhibitor.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("Exhibitor", exhibitor.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_exhibitor(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting exhibitor with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM exhibitors WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("Exhibitor", id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn allocate(&self, exhibitor_id: Uuid, booth_id: Option<Uuid>) -> DomainResult<()> {
+        debug!("Allocating booth {:?} to exhibitor {}", booth_id, exhibitor_id);
+
+        // The unique index on booth_id refuses a booth that is already taken
+        let result = sqlx::query("UPDATE exhibitors SET booth_id = ?, updated_at = ? WHERE id = ?")
+            .bind(booth_id.map(|id| id.to_string()))
+            .bind(Utc::now().naive_utc())
+            .bind(exhibitor_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("Exhibitor", exhibitor_id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    #[tokio::test]
+    async fn test_a_booth_holds_one_exhibitor_and_deleting_it_frees_the_exhibitor() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteExhibitorRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+
+        let b12 = Booth {
+            hall: Some("Hall B".to_string()),
+            ..Booth::new(event_id, "B12", 12.0, 4.5, 3.0, 2.0)
+        };
+        let a1 = Booth::new(event_id, "A1", 0.0, 0.0, 6.0, 4.0);
+        repository.create_booth(&b12).await.unwrap();
+        repository.create_booth(&a1).await.unwrap();
+        // Booth numbers are unique within the event
+        assert!(repository.create_booth(&Booth::new(event_id, "B12", 20.0, 0.0, 3.0, 3.0)).await.is_err());
+        let booths = repository.find_booths(event_id).await.unwrap();
+        assert_eq!(booths.iter().map(|b| b.number.as_str()).collect::<Vec<_>>(), vec!["A1", "B12"]);
+        assert_eq!((booths[1].x, booths[1].y, booths[1].area()), (12.0, 4.5, 6.0));
+
+        let salmon = Exhibitor {
+            website_url: Some("https://salmon.example".to_string()),
+            ..Exhibitor::new(event_id, "Salmon Feeds AS")
+        };
+        let nets = Exhibitor::new(event_id, "Aqua Nets");
+        repository.create_exhibitor(&salmon).await.unwrap();
+        repository.create_exhibitor(&nets).await.unwrap();
+
+        repository.allocate(salmon.id, Some(b12.id)).await.unwrap();
+        assert!(matches!(
+            repository.allocate(nets.id, Some(b12.id)).await,
+            Err(DomainError::ConflictError { .. })
+        ));
+        repository.allocate(nets.id, Some(a1.id)).await.unwrap();
+        let exhibitors = repository.find_exhibitors(event_id).await.unwrap();
+        assert_eq!(
+            exhibitors.iter().map(|e| (e.company_name.as_str(), e.booth_id)).collect::<Vec<_>>(),
+            vec![("Aqua Nets", Some(a1.id)), ("Salmon Feeds AS", Some(b12.id))]
+        );
+
+        repository.delete_booth(b12.id).await.unwrap();
+        let salmon = repository.find_exhibitor(salmon.id).await.unwrap().unwrap();
+        assert_eq!(salmon.booth_id, None);
+        assert_eq!(salmon.website_url.as_deref(), Some("https://salmon.example"));
+
+        repository.allocate(nets.id, None).await.unwrap();
+        assert_eq!(repository.find_exhibitor(nets.id).await.unwrap().unwrap().booth_id, None);
+        assert!(matches!(
+            repository.allocate(Uuid::new_v4(), None).await,
+            Err(DomainError::NotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,273 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::ExportJobRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::{export_entity_to_string, export_format_to_string, export_run_status_to_string},
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{DomainResult, ExportFilter, ExportJob};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const EXPORT_JOB_COLUMNS: &str = "id, name, entity, filter, format, destination, schedule, timezone, is_active, \
+     next_run_at, last_run_at, last_status, last_error, last_row_count, last_success_at, created_by, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteExportJobRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteExportJobRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to ExportJob using SafeRowGet
+    fn row_to_job(row: &sqlx::sqlite::SqliteRow) -> Result<ExportJob, RowConversionError> {
+        let destination: String = row.get_string("destination")?;
+
+        Ok(ExportJob {
+            id: row.get_uuid("id")?,
+            name: row.get_string("name")?,
+            entity: row.get_export_entity("entity")?,
+            filter: row.get_json::<ExportFilter>("filter")?,
+            format: row.get_export_format("format")?,
+            destination: serde_json::from_str(&destination)
+                .map_err(|cause| RowConversionError::InvalidJson { field: "destination", cause })?,
+            schedule: row.get_string("schedule")?,
+            timezone: row.get_string("timezone")?,
+            is_active: row.get_bool("is_active")?,
+            next_run_at: row.get_optional_datetime("next_run_at")?,
+            last_run_at: row.get_optional_datetime("last_run_at")?,
+            last_status: row.get_optional_export_run_status("last_status")?,
+            last_error: row.get_optional_string("last_error")?,
+            last_row_count: row.get_optional_i32("last_row_count")?,
+            last_success_at: row.get_optional_datetime("last_success_at")?,
+            created_by: row.get_uuid("created_by")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn rows_to_jobs(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<ExportJob>> {
+        rows.iter()
+            .map(|row| Self::row_to_job(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ExportJobRepository for SqliteExportJobRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<ExportJob>> {
+        debug!("Finding export job by ID: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM export_jobs WHERE id = ?", EXPORT_JOB_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_job(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_all(&self) -> DomainResult<Vec<ExportJob>> {
+        debug!("Listing export jobs");
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM export_jobs ORDER BY name COLLATE NOCASE",
+            EXPORT_JOB_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_jobs(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_due(&self, now: DateTime<Utc>) -> DomainResult<Vec<ExportJob>> {
+        debug!("Finding export jobs due at {}", now);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM export_jobs WHERE is_active = TRUE AND next_run_at <= ? ORDER BY next_run_at",
+            EXPORT_JOB_COLUMNS
+        ))
+        .bind(now.naive_utc())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_jobs(&rows)
+    }
+
+    #[instrument(skip(self, job))]
+    async fn create(&self, job: &ExportJob) -> DomainResult<()> {
+        debug!("Creating export job {} ({})", job.id, job.name);
+
+        sqlx::query(&format!(
+            "INSERT INTO export_jobs ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            EXPORT_JOB_COLUMNS
+        ))
+        .bind(job.id.to_string())
+        .bind(&job.name)
+        .bind(export_entity_to_string(job.entity))
+        .bind(serde_json::to_string(&job.filter).unwrap_or_default())
+        .bind(export_format_to_string(job.format))
+        .bind(serde_json::to_string(&job.destination).unwrap_or_default())
+        .bind(&job.schedule)
+        .bind(&job.timezone)
+        .bind(job.is_active)
+        .bind(job.next_run_at.map(|at| at.naive_utc()))
+        .bind(job.last_run_at.map(|at| at.naive_utc()))
+        .bind(job.last_status.map(export_run_status_to_string))
+        .bind(&job.last_error)
+        .bind(job.last_row_count)
+        .bind(job.last_success_at.map(|at| at.naive_utc()))
+        .bind(job.created_by.to_string())
+        .bind(job.created_at.naive_utc())
+        .bind(job.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, job))]
+    async fn update(&self, job: &ExportJob) -> DomainResult<()> {
+        debug!("Updating export job: {}", job.id);
+
+        let result = sqlx::query(
+            "UPDATE export_jobs SET name = ?, entity = ?, filter = ?, format = ?, destination = ?, schedule = ?, \
+             timezone = ?, is_active = ?, next_run_at = ?, last_run_at = ?, last_status = ?, last_error = ?, \
+             last_row_count = ?, last_success_at = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(&job.name)
+        .bind(export_entity_to_string(job.entity))
+        .bind(serde_json::to_string(&job.filter).unwrap_or_default())
+        .bind(export_format_to_string(job.format))
+        .bind(serde_json::to_string(&job.destination).unwrap_or_default())
+        .bind(&job.schedule)
+        .bind(&job.timezone)
+        .bind(job.is_active)
+        .bind(job.next_run_at.map(|at| at.naive_utc()))
+        .bind(job.last_run_at.map(|at| at.naive_utc()))
+        .bind(job.last_status.map(export_run_status_to_string))
+        .bind(&job.last_error)
+        .bind(job.last_row_count)
+        .bind(job.last_success_at.map(|at| at.naive_utc()))
+        .bind(job.updated_at.naive_utc())
+        .bind(job.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("ExportJob", job.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting export job: {}", id);
+
+        let result = sqlx::query("DELETE FROM export_jobs WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("ExportJob", id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::{ExportDestination, ExportEntity, ExportFormat, ExportRunStatus};
+    use chrono::Duration;
+
+    fn nightly(name: &str, created_by: Uuid, now: DateTime<Utc>) -> ExportJob {
+        ExportJob::new(
+            name,
+            ExportEntity::Registrations,
+            ExportFilter { company_id: Some(Uuid::new_v4()), event_id: None, changed_since_last_run: true },
+            ExportFormat::Csv,
+            ExportDestination::Sftp {
+                host: "crm.example.org".to_string(),
+                port: 2222,
+                username: "aqio".to_string(),
+                directory: "incoming".to_string(),
+            },
+            "0 2 * * *",
+            "Europe/Oslo",
+            created_by,
+            now,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_export_jobs_round_trip_and_come_due() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteExportJobRepository::new(db.pool().clone());
+        let admin = db.seed_user().await;
+        let now = Utc::now();
+
+        let mut due = nightly("Nightly CRM", admin, now);
+        due.next_run_at = Some(now - Duration::minutes(1));
+        repository.create(&due).await.unwrap();
+        let later = nightly("Archive", admin, now);
+        repository.create(&later).await.unwrap();
+        let mut paused = nightly("Paused", admin, now);
+        paused.is_active = false;
+        paused.next_run_at = Some(now - Duration::hours(1));
+        repository.create(&paused).await.unwrap();
+
+        let found = repository.find_by_id(due.id).await.unwrap().unwrap();
+        assert_eq!(found.filter, due.filter);
+        assert_eq!(found.destination, due.destination);
+        assert_eq!(found.entity, ExportEntity::Registrations);
+        assert!(found.last_status.is_none());
+
+        let names: Vec<String> = repository.find_all().await.unwrap().into_iter().map(|job| job.name).collect();
+        assert_eq!(names, vec!["Archive", "Nightly CRM", "Paused"]);
+        let due_now: Vec<Uuid> = repository.find_due(now).await.unwrap().into_iter().map(|job| job.id).collect();
+        assert_eq!(due_now, vec![due.id]);
+
+        let mut run = found;
+        run.record_run(now, Err("Connection refused".to_string())).unwrap();
+        repository.update(&run).await.unwrap();
+        let updated = repository.find_by_id(due.id).await.unwrap().unwrap();
+        assert_eq!(updated.last_status, Some(ExportRunStatus::Failed));
+        assert_eq!(updated.last_error.as_deref(), Some("Connection refused"));
+        assert!(repository.find_due(now).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_deleting_a_missing_export_job_is_not_found() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteExportJobRepository::new(db.pool().clone());
+        let admin = db.seed_user().await;
+
+        let job = nightly("Nightly CRM", admin, Utc::now());
+        repository.create(&job).await.unwrap();
+        repository.delete(job.id).await.unwrap();
+        assert!(repository.find_by_id(job.id).await.unwrap().is_none());
+        assert!(repository.delete(job.id).await.is_err());
+    }
+}
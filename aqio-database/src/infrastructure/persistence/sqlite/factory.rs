@@ -6,6 +6,46 @@ use super::{
     SqliteInvitationRepository,
     SqliteEventCategoryRepository,
     SqliteEventRegistrationRepository,
+    SqliteAuditLogRepository,
+    SqliteCompanyRepository,
+    SqliteEventCommentRepository,
+    SqliteEventQuestionRepository,
+    SqliteEventPollRepository,
+    SqliteEventAttachmentRepository,
+    SqliteEventBudgetRepository,
+    SqliteEventSponsorRepository,
+    SqliteSchedulingPolicyRepository,
+    SqliteSendingDomainRepository,
+    SqliteEmailSuppressionRepository,
+    SqliteNotificationDigestRepository,
+    SqliteOrganizationInvitationRepository,
+    SqliteEventOwnershipTransferRepository,
+    SqliteEventSummaryRepository,
+    SqliteEventSubmissionRepository,
+    SqliteContentReportRepository,
+    SqliteEventConsentRepository,
+    SqliteStorageQuotaRepository,
+    SqliteEventBroadcastRepository,
+    SqliteMessageThreadRepository,
+    SqliteEventPhotoRepository,
+    SqliteAnalyticsRepository,
+    SqliteEventFieldRepository,
+    SqliteShortLinkRepository,
+    SqliteOverbookingPolicyRepository,
+    SqliteTicketRepository,
+    SqliteInvoiceRepository,
+    SqliteTicketRefundRepository,
+    SqliteSeatingRepository,
+    SqliteResourceRepository,
+    SqliteShiftRepository,
+    SqliteExhibitorRepository,
+    SqliteLeadRepository,
+    SqliteMatchmakingRepository,
+    SqliteWebVitalRepository,
+    SqliteOrganizationRoleRepository,
+    SqliteAccessGrantRepository,
+    SqliteExportJobRepository,
+    SqliteCrmConnectorRepository,
 };
 
 /// Central factory for creating repository instances
@@ -52,6 +92,206 @@ impl RepositoryFactory {
         SqliteEventRegistrationRepository::new(self.pool.clone())
     }
 
+    /// Create an audit log repository instance
+    pub fn audit_log_repository(&self) -> SqliteAuditLogRepository {
+        SqliteAuditLogRepository::new(self.pool.clone())
+    }
+
+    /// Create a company repository instance
+    pub fn company_repository(&self) -> SqliteCompanyRepository {
+        SqliteCompanyRepository::new(self.pool.clone())
+    }
+
+    /// Create an event comment repository instance
+    pub fn event_comment_repository(&self) -> SqliteEventCommentRepository {
+        SqliteEventCommentRepository::new(self.pool.clone())
+    }
+
+    /// Create an event question repository instance
+    pub fn event_question_repository(&self) -> SqliteEventQuestionRepository {
+        SqliteEventQuestionRepository::new(self.pool.clone())
+    }
+
+    /// Create an event poll repository instance
+    pub fn event_poll_repository(&self) -> SqliteEventPollRepository {
+        SqliteEventPollRepository::new(self.pool.clone())
+    }
+
+    /// Create an event attachment repository instance
+    pub fn event_attachment_repository(&self) -> SqliteEventAttachmentRepository {
+        SqliteEventAttachmentRepository::new(self.pool.clone())
+    }
+
+    /// Create an event budget repository instance
+    pub fn event_budget_repository(&self) -> SqliteEventBudgetRepository {
+        SqliteEventBudgetRepository::new(self.pool.clone())
+    }
+
+    /// Create an event sponsor repository instance
+    pub fn event_sponsor_repository(&self) -> SqliteEventSponsorRepository {
+        SqliteEventSponsorRepository::new(self.pool.clone())
+    }
+
+    /// Create a scheduling policy repository instance
+    pub fn scheduling_policy_repository(&self) -> SqliteSchedulingPolicyRepository {
+        SqliteSchedulingPolicyRepository::new(self.pool.clone())
+    }
+
+    /// Create a sending domain repository instance
+    pub fn sending_domain_repository(&self) -> SqliteSendingDomainRepository {
+        SqliteSendingDomainRepository::new(self.pool.clone())
+    }
+
+    /// Create an email suppression repository instance
+    pub fn email_suppression_repository(&self) -> SqliteEmailSuppressionRepository {
+        SqliteEmailSuppressionRepository::new(self.pool.clone())
+    }
+
+    /// Create a notification digest repository instance
+    pub fn notification_digest_repository(&self) -> SqliteNotificationDigestRepository {
+        SqliteNotificationDigestRepository::new(self.pool.clone())
+    }
+
+    /// Create an organization invitation repository instance
+    pub fn organization_invitation_repository(&self) -> SqliteOrganizationInvitationRepository {
+        SqliteOrganizationInvitationRepository::new(self.pool.clone())
+    }
+
+    /// Create an event ownership transfer repository instance
+    pub fn event_ownership_transfer_repository(&self) -> SqliteEventOwnershipTransferRepository {
+        SqliteEventOwnershipTransferRepository::new(self.pool.clone())
+    }
+
+    /// Create an event summary (read model) repository instance
+    pub fn event_summary_repository(&self) -> SqliteEventSummaryRepository {
+        SqliteEventSummaryRepository::new(self.pool.clone())
+    }
+
+    /// Create an event submission repository instance
+    pub fn event_submission_repository(&self) -> SqliteEventSubmissionRepository {
+        SqliteEventSubmissionRepository::new(self.pool.clone())
+    }
+
+    /// Create a content report repository instance
+    pub fn content_report_repository(&self) -> SqliteContentReportRepository {
+        SqliteContentReportRepository::new(self.pool.clone())
+    }
+
+    /// Create an event consent repository instance
+    pub fn event_consent_repository(&self) -> SqliteEventConsentRepository {
+        SqliteEventConsentRepository::new(self.pool.clone())
+    }
+
+    /// Create a storage quota repository instance
+    pub fn storage_quota_repository(&self) -> SqliteStorageQuotaRepository {
+        SqliteStorageQuotaRepository::new(self.pool.clone())
+    }
+
+    /// Create an event broadcast repository instance
+    pub fn event_broadcast_repository(&self) -> SqliteEventBroadcastRepository {
+        SqliteEventBroadcastRepository::new(self.pool.clone())
+    }
+
+    /// Create a message thread repository instance
+    pub fn message_thread_repository(&self) -> SqliteMessageThreadRepository {
+        SqliteMessageThreadRepository::new(self.pool.clone())
+    }
+
+    /// Create an event photo repository instance
+    pub fn event_photo_repository(&self) -> SqliteEventPhotoRepository {
+        SqliteEventPhotoRepository::new(self.pool.clone())
+    }
+
+    /// Create an analytics repository instance
+    pub fn analytics_repository(&self) -> SqliteAnalyticsRepository {
+        SqliteAnalyticsRepository::new(self.pool.clone())
+    }
+
+    /// Create a custom event field repository instance
+    pub fn event_field_repository(&self) -> SqliteEventFieldRepository {
+        SqliteEventFieldRepository::new(self.pool.clone())
+    }
+
+    /// Create a short link repository instance
+    pub fn short_link_repository(&self) -> SqliteShortLinkRepository {
+        SqliteShortLinkRepository::new(self.pool.clone())
+    }
+
+    /// Create an overbooking policy repository instance
+    pub fn overbooking_policy_repository(&self) -> SqliteOverbookingPolicyRepository {
+        SqliteOverbookingPolicyRepository::new(self.pool.clone())
+    }
+
+    /// Create a ticket repository instance
+    pub fn ticket_repository(&self) -> SqliteTicketRepository {
+        SqliteTicketRepository::new(self.pool.clone())
+    }
+
+    /// Create an invoice repository instance
+    pub fn invoice_repository(&self) -> SqliteInvoiceRepository {
+        SqliteInvoiceRepository::new(self.pool.clone())
+    }
+
+    /// Create a ticket refund repository instance
+    pub fn ticket_refund_repository(&self) -> SqliteTicketRefundRepository {
+        SqliteTicketRefundRepository::new(self.pool.clone())
+    }
+
+    /// Create a seating repository instance
+    pub fn seating_repository(&self) -> SqliteSeatingRepository {
+        SqliteSeatingRepository::new(self.pool.clone())
+    }
+
+    /// Create a resource repository instance
+    pub fn resource_repository(&self) -> SqliteResourceRepository {
+        SqliteResourceRepository::new(self.pool.clone())
+    }
+
+    /// Create a shift repository instance
+    pub fn shift_repository(&self) -> SqliteShiftRepository {
+        SqliteShiftRepository::new(self.pool.clone())
+    }
+
+    /// Create an exhibitor repository instance
+    pub fn exhibitor_repository(&self) -> SqliteExhibitorRepository {
+        SqliteExhibitorRepository::new(self.pool.clone())
+    }
+
+    /// Create a lead repository instance
+    pub fn lead_repository(&self) -> SqliteLeadRepository {
+        SqliteLeadRepository::new(self.pool.clone())
+    }
+
+    /// Create a matchmaking repository instance
+    pub fn matchmaking_repository(&self) -> SqliteMatchmakingRepository {
+        SqliteMatchmakingRepository::new(self.pool.clone())
+    }
+
+    /// Create a web vital repository instance
+    pub fn web_vital_repository(&self) -> SqliteWebVitalRepository {
+        SqliteWebVitalRepository::new(self.pool.clone())
+    }
+
+    /// Create an organization role repository instance
+    pub fn organization_role_repository(&self) -> SqliteOrganizationRoleRepository {
+        SqliteOrganizationRoleRepository::new(self.pool.clone())
+    }
+
+    /// Create an access grant repository instance
+    pub fn access_grant_repository(&self) -> SqliteAccessGrantRepository {
+        SqliteAccessGrantRepository::new(self.pool.clone())
+    }
+
+    /// Create an export job repository instance
+    pub fn export_job_repository(&self) -> SqliteExportJobRepository {
+        SqliteExportJobRepository::new(self.pool.clone())
+    }
+
+    /// Create a CRM connector repository instance
+    pub fn crm_connector_repository(&self) -> SqliteCrmConnectorRepository {
+        SqliteCrmConnectorRepository::new(self.pool.clone())
+    }
+
     /// Get access to the underlying database pool
     /// 
     /// This is useful for custom queries or transactions that span multiple repositories
@@ -69,6 +309,46 @@ impl RepositoryFactory {
             event_category: self.event_category_repository(),
             invitation: self.invitation_repository(),
             registration: self.registration_repository(),
+            audit_log: self.audit_log_repository(),
+            company: self.company_repository(),
+            event_comment: self.event_comment_repository(),
+            event_question: self.event_question_repository(),
+            event_poll: self.event_poll_repository(),
+            event_attachment: self.event_attachment_repository(),
+            event_budget: self.event_budget_repository(),
+            event_sponsor: self.event_sponsor_repository(),
+            scheduling_policy: self.scheduling_policy_repository(),
+            sending_domain: self.sending_domain_repository(),
+            email_suppression: self.email_suppression_repository(),
+            notification_digest: self.notification_digest_repository(),
+            organization_invitation: self.organization_invitation_repository(),
+            event_ownership_transfer: self.event_ownership_transfer_repository(),
+            event_summary: self.event_summary_repository(),
+            event_submission: self.event_submission_repository(),
+            content_report: self.content_report_repository(),
+            event_consent: self.event_consent_repository(),
+            storage_quota: self.storage_quota_repository(),
+            event_broadcast: self.event_broadcast_repository(),
+            message_thread: self.message_thread_repository(),
+            event_photo: self.event_photo_repository(),
+            analytics: self.analytics_repository(),
+            event_field: self.event_field_repository(),
+            short_link: self.short_link_repository(),
+            overbooking_policy: self.overbooking_policy_repository(),
+            ticket: self.ticket_repository(),
+            invoice: self.invoice_repository(),
+            ticket_refund: self.ticket_refund_repository(),
+            seating: self.seating_repository(),
+            resources: self.resource_repository(),
+            shifts: self.shift_repository(),
+            exhibitors: self.exhibitor_repository(),
+            leads: self.lead_repository(),
+            matchmaking: self.matchmaking_repository(),
+            web_vitals: self.web_vital_repository(),
+            organization_roles: self.organization_role_repository(),
+            access_grants: self.access_grant_repository(),
+            export_jobs: self.export_job_repository(),
+            crm_connectors: self.crm_connector_repository(),
         }
     }
 }
@@ -82,6 +362,46 @@ pub struct AllRepositories {
     pub event_category: SqliteEventCategoryRepository,
     pub invitation: SqliteInvitationRepository,
     pub registration: SqliteEventRegistrationRepository,
+    pub audit_log: SqliteAuditLogRepository,
+    pub company: SqliteCompanyRepository,
+    pub event_comment: SqliteEventCommentRepository,
+    pub event_question: SqliteEventQuestionRepository,
+    pub event_poll: SqliteEventPollRepository,
+    pub event_attachment: SqliteEventAttachmentRepository,
+    pub event_budget: SqliteEventBudgetRepository,
+    pub event_sponsor: SqliteEventSponsorRepository,
+    pub scheduling_policy: SqliteSchedulingPolicyRepository,
+    pub sending_domain: SqliteSendingDomainRepository,
+    pub email_suppression: SqliteEmailSuppressionRepository,
+    pub notification_digest: SqliteNotificationDigestRepository,
+    pub organization_invitation: SqliteOrganizationInvitationRepository,
+    pub event_ownership_transfer: SqliteEventOwnershipTransferRepository,
+    pub event_summary: SqliteEventSummaryRepository,
+    pub event_submission: SqliteEventSubmissionRepository,
+    pub content_report: SqliteContentReportRepository,
+    pub event_consent: SqliteEventConsentRepository,
+    pub storage_quota: SqliteStorageQuotaRepository,
+    pub event_broadcast: SqliteEventBroadcastRepository,
+    pub message_thread: SqliteMessageThreadRepository,
+    pub event_photo: SqliteEventPhotoRepository,
+    pub analytics: SqliteAnalyticsRepository,
+    pub event_field: SqliteEventFieldRepository,
+    pub short_link: SqliteShortLinkRepository,
+    pub overbooking_policy: SqliteOverbookingPolicyRepository,
+    pub ticket: SqliteTicketRepository,
+    pub invoice: SqliteInvoiceRepository,
+    pub ticket_refund: SqliteTicketRefundRepository,
+    pub seating: SqliteSeatingRepository,
+    pub resources: SqliteResourceRepository,
+    pub shifts: SqliteShiftRepository,
+    pub exhibitors: SqliteExhibitorRepository,
+    pub leads: SqliteLeadRepository,
+    pub matchmaking: SqliteMatchmakingRepository,
+    pub web_vitals: SqliteWebVitalRepository,
+    pub organization_roles: SqliteOrganizationRoleRepository,
+    pub access_grants: SqliteAccessGrantRepository,
+    pub export_jobs: SqliteExportJobRepository,
+    pub crm_connectors: SqliteCrmConnectorRepository,
 }
 
 impl AllRepositories {
@@ -112,6 +432,46 @@ mod tests {
         let _category_repo = factory.event_category_repository();
         let _invitation_repo = factory.invitation_repository();
         let _registration_repo = factory.registration_repository();
+        let _audit_log_repo = factory.audit_log_repository();
+        let _company_repo = factory.company_repository();
+        let _event_comment_repo = factory.event_comment_repository();
+        let _event_question_repo = factory.event_question_repository();
+        let _event_poll_repo = factory.event_poll_repository();
+        let _event_attachment_repo = factory.event_attachment_repository();
+        let _event_budget_repo = factory.event_budget_repository();
+        let _event_sponsor_repo = factory.event_sponsor_repository();
+        let _scheduling_policy_repo = factory.scheduling_policy_repository();
+        let _sending_domain_repo = factory.sending_domain_repository();
+        let _email_suppression_repo = factory.email_suppression_repository();
+        let _notification_digest_repo = factory.notification_digest_repository();
+        let _organization_invitation_repo = factory.organization_invitation_repository();
+        let _event_ownership_transfer_repo = factory.event_ownership_transfer_repository();
+        let _event_summary_repo = factory.event_summary_repository();
+        let _event_submission_repo = factory.event_submission_repository();
+        let _content_report_repo = factory.content_report_repository();
+        let _event_consent_repo = factory.event_consent_repository();
+        let _storage_quota_repo = factory.storage_quota_repository();
+        let _event_broadcast_repo = factory.event_broadcast_repository();
+        let _message_thread_repo = factory.message_thread_repository();
+        let _event_photo_repo = factory.event_photo_repository();
+        let _analytics_repo = factory.analytics_repository();
+        let _event_field_repo = factory.event_field_repository();
+        let _short_link_repo = factory.short_link_repository();
+        let _overbooking_policy_repo = factory.overbooking_policy_repository();
+        let _ticket_repo = factory.ticket_repository();
+        let _invoice_repo = factory.invoice_repository();
+        let _ticket_refund_repo = factory.ticket_refund_repository();
+        let _seating_repo = factory.seating_repository();
+        let _resource_repo = factory.resource_repository();
+        let _shift_repo = factory.shift_repository();
+        let _exhibitor_repo = factory.exhibitor_repository();
+        let _lead_repo = factory.lead_repository();
+        let _matchmaking_repo = factory.matchmaking_repository();
+        let _web_vital_repo = factory.web_vital_repository();
+        let _organization_role_repo = factory.organization_role_repository();
+        let _access_grant_repo = factory.access_grant_repository();
+        let _export_job_repo = factory.export_job_repository();
+        let _crm_connector_repo = factory.crm_connector_repository();
     }
 
     #[tokio::test]
@@ -5,6 +5,7 @@ use aqio_core::{EventInvitation, InvitationStatus, DomainValidation, DomainResul
 use crate::infrastructure::persistence::mapping::{
     invitation_status_to_string,
     invitation_method_to_string,
+    delivery_failure_to_string,
 };
 use async_trait::async_trait;
 use sqlx::{Pool, Sqlite};
@@ -36,7 +37,10 @@ impl SqliteInvitationRepository {
             status: row.get_invitation_status("status")?,
             sent_at: row.get_optional_datetime("sent_at")?,
             opened_at: row.get_optional_datetime("opened_at")?,
+            clicked_at: row.get_optional_datetime("clicked_at")?,
             responded_at: row.get_optional_datetime("responded_at")?,
+            delivery_failure: row.get_optional_delivery_failure("delivery_failure")?,
+            delivery_failed_at: row.get_optional_datetime("delivery_failed_at")?,
             invitation_token: row.get_optional_string("invitation_token")?,
             expires_at: row.get_optional_datetime("expires_at")?,
             created_at: row.get_datetime("created_at")?,
@@ -104,10 +108,11 @@ impl EventInvitationRepository for SqliteInvitationRepository {
             INSERT INTO event_invitations (
                 id, event_id, invited_user_id, invited_contact_id, 
                 invited_email, invited_name, inviter_id, invitation_method,
-                personal_message, status, sent_at, opened_at, responded_at,
+                personal_message, status, sent_at, opened_at, clicked_at, responded_at,
+                delivery_failure, delivery_failed_at,
                 invitation_token, expires_at, created_at, updated_at
             ) VALUES (
-                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
             )
         "#;
 
@@ -124,7 +129,10 @@ impl EventInvitationRepository for SqliteInvitationRepository {
             .bind(invitation_status_to_string(&invitation.status))
             .bind(invitation.sent_at.map(|dt| dt.naive_utc()))
             .bind(invitation.opened_at.map(|dt| dt.naive_utc()))
+            .bind(invitation.clicked_at.map(|dt| dt.naive_utc()))
             .bind(invitation.responded_at.map(|dt| dt.naive_utc()))
+            .bind(invitation.delivery_failure.map(delivery_failure_to_string))
+            .bind(invitation.delivery_failed_at.map(|dt| dt.naive_utc()))
             .bind(&invitation.invitation_token)
             .bind(invitation.expires_at.map(|dt| dt.naive_utc()))
             .bind(invitation.created_at.naive_utc())
@@ -163,7 +171,8 @@ impl EventInvitationRepository for SqliteInvitationRepository {
                 event_id = ?, invited_user_id = ?, invited_contact_id = ?,
                 invited_email = ?, invited_name = ?, inviter_id = ?,
                 invitation_method = ?, personal_message = ?, status = ?,
-                sent_at = ?, opened_at = ?, responded_at = ?,
+                sent_at = ?, opened_at = ?, clicked_at = ?, responded_at = ?,
+                delivery_failure = ?, delivery_failed_at = ?,
                 invitation_token = ?, expires_at = ?, updated_at = ?
             WHERE id = ?
         "#;
@@ -180,7 +189,10 @@ impl EventInvitationRepository for SqliteInvitationRepository {
             .bind(invitation_status_to_string(&invitation.status))
             .bind(invitation.sent_at.map(|dt| dt.naive_utc()))
             .bind(invitation.opened_at.map(|dt| dt.naive_utc()))
+            .bind(invitation.clicked_at.map(|dt| dt.naive_utc()))
             .bind(invitation.responded_at.map(|dt| dt.naive_utc()))
+            .bind(invitation.delivery_failure.map(delivery_failure_to_string))
+            .bind(invitation.delivery_failed_at.map(|dt| dt.naive_utc()))
             .bind(&invitation.invitation_token)
             .bind(invitation.expires_at.map(|dt| dt.naive_utc()))
             .bind(invitation.updated_at.naive_utc())
@@ -327,7 +339,7 @@ impl EventInvitationRepository for SqliteInvitationRepository {
     async fn find_by_email(&self, email: &str) -> DomainResult<Vec<EventInvitation>> {
         debug!("Finding invitations by email: {}", email);
 
-        let query = "SELECT * FROM event_invitations WHERE invited_email = ? ORDER BY created_at DESC";
+        let query = "SELECT * FROM event_invitations WHERE invited_email = ? COLLATE NOCASE ORDER BY created_at DESC";
         
         let rows = sqlx::query(query)
             .bind(email)
@@ -455,51 +467,24 @@ impl EventInvitationRepository for SqliteInvitationRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aqio_core::{InvitationMethod, InvitationStatus};
-    use sqlx::SqlitePool;
+    use aqio_core::{DeliveryFailure, InvitationMethod, InvitationStatus};
+    use crate::testing::TestDb;
     use uuid::Uuid;
     use chrono::Utc;
 
-    async fn setup_test_db() -> Pool<Sqlite> {
-        let pool = SqlitePool::connect(":memory:").await.unwrap();
-        
-        // Create required tables for testing (without foreign key constraints for simplicity)
-        sqlx::query(r#"
-            CREATE TABLE event_invitations (
-                id TEXT PRIMARY KEY,
-                event_id TEXT NOT NULL,
-                invited_user_id TEXT,
-                invited_contact_id TEXT,
-                invited_email TEXT,
-                invited_name TEXT,
-                inviter_id TEXT NOT NULL,
-                invitation_method TEXT NOT NULL DEFAULT 'email',
-                personal_message TEXT,
-                status TEXT NOT NULL DEFAULT 'pending',
-                sent_at DATETIME,
-                opened_at DATETIME,
-                responded_at DATETIME,
-                invitation_token TEXT UNIQUE,
-                expires_at DATETIME,
-                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
-            )
-        "#)
-        .execute(&pool)
-        .await
-        .unwrap();
-
-        pool
+    // Isolated database with the real schema, including foreign keys on events and users
+    async fn setup_test_db() -> TestDb {
+        TestDb::in_memory().await
     }
 
     #[tokio::test]
     async fn test_create_and_find_invitation() {
-        let pool = setup_test_db().await;
-        let repo = SqliteInvitationRepository::new(pool);
+        let db = setup_test_db().await;
+        let repo = SqliteInvitationRepository::new(db.pool().clone());
         
         let invitation_id = Uuid::new_v4();
-        let event_id = Uuid::new_v4();
-        let inviter_id = Uuid::new_v4();
+        let inviter_id = db.seed_user().await;
+        let event_id = db.seed_event(inviter_id).await;
         
         let invitation = EventInvitation {
             id: invitation_id,
@@ -514,7 +499,10 @@ mod tests {
             status: InvitationStatus::Pending,
             sent_at: None,
             opened_at: None,
+            clicked_at: None,
             responded_at: None,
+            delivery_failure: None,
+            delivery_failed_at: None,
             invitation_token: None,
             expires_at: None,
             created_at: Utc::now(),
@@ -535,19 +523,28 @@ mod tests {
         assert_eq!(event_invitations[0].id, invitation_id);
         
         // Test finding by email
-        let email_invitations = repo.find_by_email("test@example.com").await.unwrap();
+        let email_invitations = repo.find_by_email("Test@Example.com").await.unwrap();
         assert_eq!(email_invitations.len(), 1);
         assert_eq!(email_invitations[0].id, invitation_id);
+
+        // Delivery failures reported by the mail provider are kept
+        let mut bounced = found;
+        bounced.delivery_failure = Some(DeliveryFailure::Bounce);
+        bounced.delivery_failed_at = Some(Utc::now());
+        repo.update(&bounced).await.unwrap();
+        let found = repo.find_by_id(invitation_id).await.unwrap().unwrap();
+        assert_eq!(found.delivery_failure, Some(DeliveryFailure::Bounce));
+        assert!(found.delivery_failed_at.is_some());
     }
 
     #[tokio::test]
     async fn test_update_status() {
-        let pool = setup_test_db().await;
-        let repo = SqliteInvitationRepository::new(pool);
+        let db = setup_test_db().await;
+        let repo = SqliteInvitationRepository::new(db.pool().clone());
         
         let invitation_id = Uuid::new_v4();
-        let event_id = Uuid::new_v4();
-        let inviter_id = Uuid::new_v4();
+        let inviter_id = db.seed_user().await;
+        let event_id = db.seed_event(inviter_id).await;
         
         let invitation = EventInvitation {
             id: invitation_id,
@@ -562,7 +559,10 @@ mod tests {
             status: InvitationStatus::Pending,
             sent_at: None,
             opened_at: None,
+            clicked_at: None,
             responded_at: None,
+            delivery_failure: None,
+            delivery_failed_at: None,
             invitation_token: None,
             expires_at: None,
             created_at: Utc::now(),
@@ -582,12 +582,12 @@ mod tests {
     
     #[tokio::test]
     async fn test_duplicate_invitations() {
-        let pool = setup_test_db().await;
-        let repo = SqliteInvitationRepository::new(pool);
+        let db = setup_test_db().await;
+        let repo = SqliteInvitationRepository::new(db.pool().clone());
         
-        let event_id = Uuid::new_v4();
-        let user_id = Uuid::new_v4();
-        let inviter_id = Uuid::new_v4();
+        let inviter_id = db.seed_user().await;
+        let event_id = db.seed_event(inviter_id).await;
+        let user_id = db.seed_user().await;
         
         let invitation = EventInvitation {
             id: Uuid::new_v4(),
@@ -602,7 +602,10 @@ mod tests {
             status: InvitationStatus::Pending,
             sent_at: None,
             opened_at: None,
+            clicked_at: None,
             responded_at: None,
+            delivery_failure: None,
+            delivery_failed_at: None,
             invitation_token: None,
             expires_at: None,
             created_at: Utc::now(),
@@ -0,0 +1,286 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::InvoiceRepository,
+};
+use crate::infrastructure::persistence::mapping::{currency_to_string, invoice_status_to_string};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainError, DomainResult, Invoice, InvoiceFilter, InvoiceStatus};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const INVOICE_COLUMNS: &str = "id, invoice_number, order_id, event_id, seller, buyer, buyer_reference, bank_account, currency, description, quantity, unit_price, total, vat_rate, vat_amount, issue_date, due_date, status, paid_at, created_at";
+
+#[derive(Clone)]
+pub struct SqliteInvoiceRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteInvoiceRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to Invoice using SafeRowGet
+    fn row_to_invoice(row: &sqlx::sqlite::SqliteRow) -> Result<Invoice, RowConversionError> {
+        Ok(Invoice {
+            id: row.get_uuid("id")?,
+            invoice_number: row.get_i64("invoice_number")?,
+            order_id: row.get_uuid("order_id")?,
+            event_id: row.get_uuid("event_id")?,
+            seller: row.get_json("seller")?,
+            buyer: row.get_json("buyer")?,
+            buyer_reference: row.get_string("buyer_reference")?,
+            bank_account: row.get_optional_string("bank_account")?,
+            currency: row.get_currency("currency")?,
+            description: row.get_string("description")?,
+            quantity: row.get_i32("quantity")?,
+            unit_price: row.get_i64("unit_price")?,
+            total: row.get_i64("total")?,
+            vat_rate: row.get_i32("vat_rate")?,
+            vat_amount: row.get_i64("vat_amount")?,
+            issue_date: row.get_date("issue_date")?,
+            due_date: row.get_date("due_date")?,
+            status: row.get_invoice_status("status")?,
+            paid_at: row.get_optional_datetime("paid_at")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+
+    async fn fetch_one_by(&self, column: &str, id: Uuid) -> DomainResult<Option<Invoice>> {
+        let row = sqlx::query(&format!("SELECT {} FROM invoices WHERE {} = ?", INVOICE_COLUMNS, column))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_invoice(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl InvoiceRepository for SqliteInvoiceRepository {
+    #[instrument(skip(self, invoice))]
+    async fn create_numbered(&self, invoice: &Invoice) -> DomainResult<i64> {
+        debug!("Creating invoice for ticket order: {}", invoice.order_id);
+
+        // One statement, so two invoices issued at once can't take the same number
+        let invoice_number: i64 = sqlx::query_scalar(&format!(
+            "INSERT INTO invoices ({})
+             SELECT ?, COALESCE(MAX(invoice_number), 0) + 1, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ? FROM invoices
+             RETURNING invoice_number",
+            INVOICE_COLUMNS
+        ))
+        .bind(invoice.id.to_string())
+        .bind(invoice.order_id.to_string())
+        .bind(invoice.event_id.to_string())
+        .bind(serde_json::to_string(&invoice.seller).unwrap_or_default())
+        .bind(serde_json::to_string(&invoice.buyer).unwrap_or_default())
+        .bind(&invoice.buyer_reference)
+        .bind(invoice.bank_account.as_deref())
+        .bind(currency_to_string(invoice.currency))
+        .bind(&invoice.description)
+        .bind(invoice.quantity)
+        .bind(invoice.unit_price)
+        .bind(invoice.total)
+        .bind(invoice.vat_rate)
+        .bind(invoice.vat_amount)
+        .bind(invoice.issue_date)
+        .bind(invoice.due_date)
+        .bind(invoice_status_to_string(invoice.status))
+        .bind(invoice.paid_at.map(|at| at.naive_utc()))
+        .bind(invoice.created_at.naive_utc())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(invoice_number)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Invoice>> {
+        debug!("Finding invoice with id: {}", id);
+        self.fetch_one_by("id", id).await
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_order(&self, order_id: Uuid) -> DomainResult<Option<Invoice>> {
+        debug!("Finding invoice for ticket order: {}", order_id);
+        self.fetch_one_by("order_id", order_id).await
+    }
+
+    #[instrument(skip(self, filter))]
+    async fn find(&self, filter: &InvoiceFilter) -> DomainResult<Vec<Invoice>> {
+        debug!("Listing invoices");
+
+        let mut query_builder = sqlx::QueryBuilder::<Sqlite>::new(format!("SELECT {} FROM invoices WHERE 1=1", INVOICE_COLUMNS));
+        if let Some(event_id) = filter.event_id {
+            query_builder.push(" AND event_id = ");
+            query_builder.push_bind(event_id.to_string());
+        }
+        if let Some(status) = filter.status {
+            query_builder.push(" AND status = ");
+            query_builder.push_bind(invoice_status_to_string(status));
+        }
+        if let Some(day) = filter.overdue_on {
+            query_builder.push(" AND status = ");
+            query_builder.push_bind(invoice_status_to_string(InvoiceStatus::Issued));
+            query_builder.push(" AND due_date < ");
+            query_builder.push_bind(day);
+        }
+        query_builder.push(" ORDER BY due_date ASC, invoice_number ASC");
+
+        let rows = query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_invoice(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, invoice))]
+    async fn update(&self, invoice: &Invoice) -> DomainResult<()> {
+        debug!("Updating invoice with id: {}", invoice.id);
+
+        let result = sqlx::query("UPDATE invoices SET status = ?, paid_at = ? WHERE id = ?")
+            .bind(invoice_status_to_string(invoice.status))
+            .bind(invoice.paid_at.map(|at| at.naive_utc()))
+            .bind(invoice.id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("Invoice", invoice.id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::TicketRepository;
+    use crate::infrastructure::persistence::sqlite::SqliteTicketRepository;
+    use crate::testing::TestDb;
+    use aqio_core::{Currency, ExchangeRate, InvoiceParty, TicketOrder, TicketType};
+    use chrono::{NaiveDate, Utc};
+
+    async fn order(db: &TestDb) -> TicketOrder {
+        let tickets = SqliteTicketRepository::new(db.pool().clone());
+        let buyer = db.seed_user().await;
+        let event_id = db.seed_event(buyer).await;
+        let ticket_type = TicketType {
+            id: Uuid::new_v4(),
+            event_id,
+            name: "Standard".to_string(),
+            description: None,
+            price: 150_000,
+            currency: Currency::Nok,
+            vat_rate: 25,
+            quantity: None,
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        tickets.create_ticket_type(&ticket_type).await.unwrap();
+        let order = TicketOrder::new(&ticket_type, buyer, 2, &ExchangeRate::nok(Utc::now().date_naive()));
+        tickets.create_order_within_quantity(&order).await.unwrap();
+        order
+    }
+
+    fn invoice(order: &TicketOrder, due_date: NaiveDate) -> Invoice {
+        Invoice {
+            id: Uuid::new_v4(),
+            invoice_number: 0,
+            order_id: order.id,
+            event_id: order.event_id,
+            seller: InvoiceParty {
+                name: "Aqio AS".to_string(),
+                org_number: "923609016".to_string(),
+                country: "NO".to_string(),
+                ..Default::default()
+            },
+            buyer: InvoiceParty {
+                name: "Havbruk AS".to_string(),
+                org_number: "974760673".to_string(),
+                city: Some("Bergen".to_string()),
+                country: "NO".to_string(),
+                ..Default::default()
+            },
+            buyer_reference: "Kostnadssted 4410".to_string(),
+            bank_account: Some("15032080119".to_string()),
+            currency: order.currency,
+            description: "Standard – Seeded Event".to_string(),
+            quantity: order.quantity,
+            unit_price: order.unit_price,
+            total: order.total,
+            vat_rate: order.vat_rate,
+            vat_amount: order.vat_amount,
+            issue_date: due_date - chrono::Duration::days(14),
+            due_date,
+            status: InvoiceStatus::Issued,
+            paid_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invoices_are_numbered_in_sequence_once_per_order() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteInvoiceRepository::new(db.pool().clone());
+        let due = NaiveDate::from_ymd_opt(2026, 3, 16).unwrap();
+        let first = order(&db).await;
+        let second = order(&db).await;
+
+        assert_eq!(repository.create_numbered(&invoice(&first, due)).await.unwrap(), 1);
+        assert_eq!(repository.create_numbered(&invoice(&second, due)).await.unwrap(), 2);
+        assert!(matches!(
+            repository.create_numbered(&invoice(&first, due)).await,
+            Err(DomainError::ConflictError { .. })
+        ));
+
+        let stored = repository.find_by_order(second.id).await.unwrap().unwrap();
+        assert_eq!(stored.invoice_number, 2);
+        assert_eq!(stored.buyer.city.as_deref(), Some("Bergen"));
+        assert_eq!(stored.due_date, due);
+        assert_eq!((stored.vat_rate, stored.vat_amount), (25, 60_000));
+    }
+
+    #[tokio::test]
+    async fn test_overdue_invoices_are_unpaid_past_their_due_date() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteInvoiceRepository::new(db.pool().clone());
+        let early = order(&db).await;
+        let late = order(&db).await;
+        repository
+            .create_numbered(&invoice(&early, NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()))
+            .await
+            .unwrap();
+        repository
+            .create_numbered(&invoice(&late, NaiveDate::from_ymd_opt(2026, 4, 1).unwrap()))
+            .await
+            .unwrap();
+
+        let march_10 = InvoiceFilter {
+            overdue_on: NaiveDate::from_ymd_opt(2026, 3, 10),
+            ..Default::default()
+        };
+        let overdue = repository.find(&march_10).await.unwrap();
+        assert_eq!(overdue.iter().map(|i| i.order_id).collect::<Vec<_>>(), vec![early.id]);
+
+        let mut paid = overdue[0].clone();
+        paid.status = InvoiceStatus::Paid;
+        paid.paid_at = Some(Utc::now());
+        repository.update(&paid).await.unwrap();
+        assert!(repository.find(&march_10).await.unwrap().is_empty());
+        assert_eq!(repository.find(&InvoiceFilter::default()).await.unwrap().len(), 2);
+    }
+}
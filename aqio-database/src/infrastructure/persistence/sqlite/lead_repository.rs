@@ -0,0 +1,182 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::LeadRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainError, DomainResult, Lead};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const LEAD_COLUMNS: &str = "id, event_id, exhibitor_id, registration_id, scanned_by, notes, scanned_at";
+
+#[derive(Clone)]
+pub struct SqliteLeadRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteLeadRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to Lead using SafeRowGet
+    fn row_to_lead(row: &sqlx::sqlite::SqliteRow) -> Result<Lead, RowConversionError> {
+        Ok(Lead {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            exhibitor_id: row.get_uuid("exhibitor_id")?,
+            registration_id: row.get_uuid("registration_id")?,
+            scanned_by: row.get_uuid("scanned_by")?,
+            notes: row.get_optional_string("notes")?,
+            scanned_at: row.get_datetime("scanned_at")?,
+        })
+    }
+
+    fn rows_to_leads(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<Lead>> {
+        rows.iter()
+            .map(|row| Self::row_to_lead(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl LeadRepository for SqliteLeadRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Lead>> {
+        debug!("Finding lead by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM exhibitor_leads WHERE id = ?", LEAD_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_lead(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self, lead))]
+    async fn capture(&self, lead: &Lead) -> DomainResult<bool> {
+        debug!("Capturing registration {} as a lead of exhibitor {}", lead.registration_id, lead.exhibitor_id);
+
+        let result = sqlx::query(&format!(
+            "INSERT INTO exhibitor_leads ({}) VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT (exhibitor_id, registration_id) DO NOTHING",
+            LEAD_COLUMNS
+        ))
+        .bind(lead.id.to_string())
+        .bind(lead.event_id.to_string())
+        .bind(lead.exhibitor_id.to_string())
+        .bind(lead.registration_id.to_string())
+        .bind(lead.scanned_by.to_string())
+        .bind(lead.notes.as_deref())
+        .bind(lead.scanned_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_exhibitor(&self, exhibitor_id: Uuid) -> DomainResult<Vec<Lead>> {
+        debug!("Listing leads of exhibitor: {}", exhibitor_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM exhibitor_leads WHERE exhibitor_id = ? ORDER BY scanned_at, id",
+            LEAD_COLUMNS
+        ))
+        .bind(exhibitor_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_leads(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event(&self, event_id: Uuid) -> DomainResult<Vec<Lead>> {
+        debug!("Listing leads at event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM exhibitor_leads WHERE event_id = ? ORDER BY scanned_at, id",
+            LEAD_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_leads(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting lead with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM exhibitor_leads WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("Lead", id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::ExhibitorRepository;
+    use crate::infrastructure::persistence::sqlite::SqliteExhibitorRepository;
+    use crate::testing::TestDb;
+    use aqio_core::Exhibitor;
+
+    #[tokio::test]
+    async fn test_a_registration_is_one_lead_per_exhibitor() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteLeadRepository::new(db.pool().clone());
+        let exhibitors = SqliteExhibitorRepository::new(db.pool().clone());
+        let scanner = db.seed_user().await;
+        let event_id = db.seed_event(scanner).await;
+        let registration_id = Uuid::new_v4();
+        db.execute(&format!(
+            "INSERT INTO event_registrations (id, event_id, user_id, registration_source) VALUES ('{}', '{}', '{}', 'direct')",
+            registration_id, event_id, scanner
+        ))
+        .await;
+        let salmon = Exhibitor::new(event_id, "Salmon Feeds AS");
+        let nets = Exhibitor::new(event_id, "Aqua Nets");
+        exhibitors.create_exhibitor(&salmon).await.unwrap();
+        exhibitors.create_exhibitor(&nets).await.unwrap();
+
+        let lead = Lead {
+            notes: Some("Wants a demo of the feed barge".to_string()),
+            ..Lead::new(&salmon, registration_id, scanner)
+        };
+        assert!(repository.capture(&lead).await.unwrap());
+        // Scanning the same badge again keeps the first lead
+        assert!(!repository.capture(&Lead::new(&salmon, registration_id, scanner)).await.unwrap());
+        assert!(repository.capture(&Lead::new(&nets, registration_id, scanner)).await.unwrap());
+
+        let salmon_leads = repository.find_by_exhibitor(salmon.id).await.unwrap();
+        assert_eq!(salmon_leads.len(), 1);
+        assert_eq!(salmon_leads[0].notes.as_deref(), Some("Wants a demo of the feed barge"));
+        assert_eq!(repository.find_by_event(event_id).await.unwrap().len(), 2);
+
+        repository.delete(lead.id).await.unwrap();
+        assert!(repository.find_by_id(lead.id).await.unwrap().is_none());
+        assert!(matches!(repository.delete(lead.id).await, Err(DomainError::NotFound { .. })));
+
+        // Leads go with the exhibitor
+        exhibitors.delete_exhibitor(nets.id).await.unwrap();
+        assert!(repository.find_by_event(event_id).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,416 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::MatchmakingRepository,
+};
+use crate::infrastructure::persistence::mapping::meeting_status_to_string;
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{AvailabilityWindow, DomainError, DomainResult, MatchmakingProfile, Meeting};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const PROFILE_COLUMNS: &str = "event_id, user_id, bio, created_at, updated_at";
+const MEETING_COLUMNS: &str = "id, event_id, requester_id, invitee_id, message, starts_at, ends_at, resource_id, booking_id, status, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteMatchmakingRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteMatchmakingRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to a profile without its interests and availability
+    fn row_to_profile(row: &sqlx::sqlite::SqliteRow) -> Result<MatchmakingProfile, RowConversionError> {
+        Ok(MatchmakingProfile {
+            event_id: row.get_uuid("event_id")?,
+            user_id: row.get_uuid("user_id")?,
+            interests: Vec::new(),
+            bio: row.get_optional_string("bio")?,
+            availability: Vec::new(),
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn row_to_meeting(row: &sqlx::sqlite::SqliteRow) -> Result<Meeting, RowConversionError> {
+        Ok(Meeting {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            requester_id: row.get_uuid("requester_id")?,
+            invitee_id: row.get_uuid("invitee_id")?,
+            message: row.get_optional_string("message")?,
+            starts_at: row.get_datetime("starts_at")?,
+            ends_at: row.get_datetime("ends_at")?,
+            resource_id: row.get_optional_uuid("resource_id")?,
+            booking_id: row.get_optional_uuid("booking_id")?,
+            status: row.get_meeting_status("status")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    /// Fills in the interests and availability of the event's profiles
+    async fn with_details(&self, event_id: Uuid, profiles: &mut [MatchmakingProfile]) -> DomainResult<()> {
+        let mut interests: HashMap<Uuid, Vec<String>> = HashMap::new();
+        let rows = sqlx::query(
+            "SELECT user_id, interest FROM matchmaking_interests WHERE event_id = ? ORDER BY user_id, position",
+        )
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+        for row in &rows {
+            let user_id = row.get_uuid("user_id").map_err(InfrastructureError::from)?;
+            let interest = row.get_string("interest").map_err(InfrastructureError::from)?;
+            interests.entry(user_id).or_default().push(interest);
+        }
+
+        let mut availability: HashMap<Uuid, Vec<AvailabilityWindow>> = HashMap::new();
+        let rows = sqlx::query(
+            "SELECT user_id, starts_at, ends_at FROM matchmaking_availability WHERE event_id = ? ORDER BY user_id, starts_at, ends_at",
+        )
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+        for row in &rows {
+            let user_id = row.get_uuid("user_id").map_err(InfrastructureError::from)?;
+            let window = AvailabilityWindow {
+                starts_at: row.get_datetime("starts_at").map_err(InfrastructureError::from)?,
+                ends_at: row.get_datetime("ends_at").map_err(InfrastructureError::from)?,
+            };
+            availability.entry(user_id).or_default().push(window);
+        }
+
+        for profile in profiles {
+            profile.interests = interests.remove(&profile.user_id).unwrap_or_default();
+            profile.availability = availability.remove(&profile.user_id).unwrap_or_default();
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MatchmakingRepository for SqliteMatchmakingRepository {
+    #[instrument(skip(self))]
+    async fn find_profile(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<Option<MatchmakingProfile>> {
+        debug!("Finding matchmaking profile of user {} at event {}", user_id, event_id);
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM matchmaking_profiles WHERE event_id = ? AND user_id = ?",
+            PROFILE_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let Some(row) = row else { return Ok(None) };
+        let mut profile = Self::row_to_profile(&row).map_err(InfrastructureError::from)?;
+
+        let rows = sqlx::query(
+            "SELECT interest FROM matchmaking_interests WHERE event_id = ? AND user_id = ? ORDER BY position",
+        )
+        .bind(event_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+        let interests: Result<Vec<String>, RowConversionError> = rows.iter().map(|row| row.get_string("interest")).collect();
+        profile.interests = interests.map_err(InfrastructureError::from)?;
+
+        let rows = sqlx::query(
+            "SELECT starts_at, ends_at FROM matchmaking_availability WHERE event_id = ? AND user_id = ? ORDER BY starts_at, ends_at",
+        )
+        .bind(event_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+        let availability: Result<Vec<AvailabilityWindow>, RowConversionError> = rows
+            .iter()
+            .map(|row| {
+                Ok(AvailabilityWindow {
+                    starts_at: row.get_datetime("starts_at")?,
+                    ends_at: row.get_datetime("ends_at")?,
+                })
+            })
+            .collect();
+        profile.availability = availability.map_err(InfrastructureError::from)?;
+
+        Ok(Some(profile))
+    }
+
+    #[instrument(skip(self))]
+    async fn find_profiles(&self, event_id: Uuid) -> DomainResult<Vec<MatchmakingProfile>> {
+        debug!("Listing matchmaking profiles at event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM matchmaking_profiles WHERE event_id = ? ORDER BY created_at, user_id",
+            PROFILE_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let profiles: Result<Vec<MatchmakingProfile>, RowConversionError> = rows.iter().map(Self::row_to_profile).collect();
+        let mut profiles = profiles.map_err(InfrastructureError::from)?;
+        self.with_details(event_id, &mut profiles).await?;
+        Ok(profiles)
+    }
+
+    #[instrument(skip(self, profile))]
+    async fn save_profile(&self, profile: &MatchmakingProfile) -> DomainResult<()> {
+        debug!("Saving matchmaking profile of user {} at event {}", profile.user_id, profile.event_id);
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        sqlx::query(&format!(
+            "INSERT INTO matchmaking_profiles ({}) VALUES (?, ?, ?, ?, ?) \
+             ON CONFLICT (event_id, user_id) DO UPDATE SET bio = excluded.bio, updated_at = excluded.updated_at",
+            PROFILE_COLUMNS
+        ))
+        .bind(profile.event_id.to_string())
+        .bind(profile.user_id.to_string())
+        .bind(profile.bio.as_deref())
+        .bind(profile.created_at.naive_utc())
+        .bind(profile.updated_at.naive_utc())
+        .execute(&mut *tx)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        for table in ["matchmaking_interests", "matchmaking_availability"] {
+            sqlx::query(&format!("DELETE FROM {} WHERE event_id = ? AND user_id = ?", table))
+                .bind(profile.event_id.to_string())
+                .bind(profile.user_id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        for (position, interest) in profile.interests.iter().enumerate() {
+            sqlx::query("INSERT INTO matchmaking_interests (event_id, user_id, interest, position) VALUES (?, ?, ?, ?)")
+                .bind(profile.event_id.to_string())
+                .bind(profile.user_id.to_string())
+                .bind(interest)
+                .bind(position as i64)
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        for window in &profile.availability {
+            sqlx::query("INSERT INTO matchmaking_availability (event_id, user_id, starts_at, ends_at) VALUES (?, ?, ?, ?)")
+                .bind(profile.event_id.to_string())
+                .bind(profile.user_id.to_string())
+                .bind(window.starts_at.naive_utc())
+                .bind(window.ends_at.naive_utc())
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_profile(&self, event_id: Uuid, user_id: Uuid) -> DomainResult<()> {
+        debug!("Deleting matchmaking profile of user {} at event {}", user_id, event_id);
+
+        let result = sqlx::query("DELETE FROM matchmaking_profiles WHERE event_id = ? AND user_id = ?")
+            .bind(event_id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("MatchmakingProfile", user_id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_rooms(&self, event_id: Uuid) -> DomainResult<Vec<Uuid>> {
+        debug!("Finding meeting rooms of event: {}", event_id);
+
+        let rows = sqlx::query("SELECT resource_id FROM meeting_rooms WHERE event_id = ? ORDER BY resource_id")
+            .bind(event_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        let rooms: Result<Vec<Uuid>, RowConversionError> = rows.iter().map(|row| row.get_uuid("resource_id")).collect();
+        let rooms = rooms.map_err(InfrastructureError::from)?;
+        Ok(rooms)
+    }
+
+    #[instrument(skip(self, resource_ids))]
+    async fn replace_rooms(&self, event_id: Uuid, resource_ids: &[Uuid]) -> DomainResult<()> {
+        debug!("Replacing meeting rooms of event {} with {} rooms", event_id, resource_ids.len());
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        sqlx::query("DELETE FROM meeting_rooms WHERE event_id = ?")
+            .bind(event_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        for resource_id in resource_ids {
+            sqlx::query("INSERT OR IGNORE INTO meeting_rooms (event_id, resource_id) VALUES (?, ?)")
+                .bind(event_id.to_string())
+                .bind(resource_id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_meeting(&self, id: Uuid) -> DomainResult<Option<Meeting>> {
+        debug!("Finding meeting by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM meetings WHERE id = ?", MEETING_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_meeting(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_meetings(&self, event_id: Uuid) -> DomainResult<Vec<Meeting>> {
+        debug!("Listing meetings at event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM meetings WHERE event_id = ? ORDER BY starts_at, id",
+            MEETING_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_meeting(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, meeting))]
+    async fn create_meeting(&self, meeting: &Meeting) -> DomainResult<()> {
+        debug!("Creating meeting {} between {} and {}", meeting.id, meeting.requester_id, meeting.invitee_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO meetings ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            MEETING_COLUMNS
+        ))
+        .bind(meeting.id.to_string())
+        .bind(meeting.event_id.to_string())
+        .bind(meeting.requester_id.to_string())
+        .bind(meeting.invitee_id.to_string())
+        .bind(meeting.message.as_deref())
+        .bind(meeting.starts_at.naive_utc())
+        .bind(meeting.ends_at.naive_utc())
+        .bind(meeting.resource_id.map(|id| id.to_string()))
+        .bind(meeting.booking_id.map(|id| id.to_string()))
+        .bind(meeting_status_to_string(meeting.status))
+        .bind(meeting.created_at.naive_utc())
+        .bind(meeting.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, meeting))]
+    async fn update_meeting(&self, meeting: &Meeting) -> DomainResult<()> {
+        debug!("Updating meeting with id: {}", meeting.id);
+
+        let result = sqlx::query(
+            "UPDATE meetings SET status = ?, resource_id = ?, booking_id = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(meeting_status_to_string(meeting.status))
+        .bind(meeting.resource_id.map(|id| id.to_string()))
+        .bind(meeting.booking_id.map(|id| id.to_string()))
+        .bind(meeting.updated_at.naive_utc())
+        .bind(meeting.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("Meeting", meeting.id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::MeetingStatus;
+    use chrono::{Duration, Utc};
+
+    #[tokio::test]
+    async fn test_profiles_keep_interests_and_availability_and_meetings_change_status() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteMatchmakingRepository::new(db.pool().clone());
+        let kari = db.seed_user().await;
+        let ola = db.seed_user().await;
+        let event_id = db.seed_event(kari).await;
+        let start = Utc::now() + Duration::days(7);
+
+        let profile = MatchmakingProfile {
+            interests: vec!["Feed".to_string(), "Sensors".to_string()],
+            bio: Some("Buying sensors for three sites".to_string()),
+            availability: vec![AvailabilityWindow {
+                starts_at: start,
+                ends_at: start + Duration::hours(2),
+            }],
+            ..MatchmakingProfile::new(event_id, kari)
+        };
+        repository.save_profile(&profile).await.unwrap();
+        repository.save_profile(&MatchmakingProfile::new(event_id, ola)).await.unwrap();
+        // Saving again replaces the interests rather than adding to them
+        let profile = MatchmakingProfile {
+            interests: vec!["Sensors".to_string()],
+            ..profile
+        };
+        repository.save_profile(&profile).await.unwrap();
+
+        let stored = repository.find_profile(event_id, kari).await.unwrap().unwrap();
+        assert_eq!(stored.interests, vec!["Sensors".to_string()]);
+        assert_eq!(stored.availability.len(), 1);
+        let profiles = repository.find_profiles(event_id).await.unwrap();
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(profiles.iter().find(|p| p.user_id == kari).unwrap().interests, vec!["Sensors".to_string()]);
+
+        let mut meeting = Meeting::new(event_id, kari, ola, start, start + Duration::minutes(30));
+        repository.create_meeting(&meeting).await.unwrap();
+        meeting.status = MeetingStatus::Accepted;
+        repository.update_meeting(&meeting).await.unwrap();
+        let meetings = repository.find_meetings(event_id).await.unwrap();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].status, MeetingStatus::Accepted);
+
+        repository.delete_profile(event_id, kari).await.unwrap();
+        assert!(repository.find_profile(event_id, kari).await.unwrap().is_none());
+        assert!(matches!(
+            repository.delete_profile(event_id, kari).await,
+            Err(DomainError::NotFound { .. })
+        ));
+    }
+}
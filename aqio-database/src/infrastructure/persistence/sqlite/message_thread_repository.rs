@@ -0,0 +1,279 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::MessageThreadRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, MessageThread, ThreadMessage};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const THREAD_COLUMNS: &str = "id, event_id, attendee_id, attendee_unread, organizer_unread, last_message_at, created_at";
+const MESSAGE_COLUMNS: &str = "id, thread_id, sender_id, from_organizer, body, broadcast_id, created_at";
+
+#[derive(Clone)]
+pub struct SqliteMessageThreadRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteMessageThreadRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to MessageThread using SafeRowGet
+    fn row_to_thread(row: &sqlx::sqlite::SqliteRow) -> Result<MessageThread, RowConversionError> {
+        Ok(MessageThread {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            attendee_id: row.get_uuid("attendee_id")?,
+            attendee_unread: row.get_i32("attendee_unread")?,
+            organizer_unread: row.get_i32("organizer_unread")?,
+            last_message_at: row.get_datetime("last_message_at")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+
+    // Helper method to convert database row to ThreadMessage using SafeRowGet
+    fn row_to_message(row: &sqlx::sqlite::SqliteRow) -> Result<ThreadMessage, RowConversionError> {
+        Ok(ThreadMessage {
+            id: row.get_uuid("id")?,
+            thread_id: row.get_uuid("thread_id")?,
+            sender_id: row.get_uuid("sender_id")?,
+            from_organizer: row.get_bool("from_organizer")?,
+            body: row.get_string("body")?,
+            broadcast_id: row.get_optional_uuid("broadcast_id")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+
+    fn rows_to_threads(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<MessageThread>> {
+        rows.iter()
+            .map(|row| Self::row_to_thread(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl MessageThreadRepository for SqliteMessageThreadRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<MessageThread>> {
+        debug!("Finding message thread by ID: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM message_threads WHERE id = ?", THREAD_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_thread(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event_and_attendee(&self, event_id: Uuid, attendee_id: Uuid) -> DomainResult<Option<MessageThread>> {
+        debug!("Finding message thread of attendee {} about event {}", attendee_id, event_id);
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM message_threads WHERE event_id = ? AND attendee_id = ?",
+            THREAD_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .bind(attendee_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_thread(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_attendee(&self, attendee_id: Uuid) -> DomainResult<Vec<MessageThread>> {
+        debug!("Finding message threads of attendee: {}", attendee_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM message_threads WHERE attendee_id = ? ORDER BY last_message_at DESC",
+            THREAD_COLUMNS
+        ))
+        .bind(attendee_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_threads(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_for_organizer(&self, user_id: Uuid) -> DomainResult<Vec<MessageThread>> {
+        debug!("Finding message threads organized by: {}", user_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM message_threads WHERE event_id IN ( \
+                 SELECT id FROM events WHERE organizer_id = ? \
+                 UNION SELECT event_id FROM event_co_organizers WHERE user_id = ? \
+             ) ORDER BY last_message_at DESC",
+            THREAD_COLUMNS
+        ))
+        .bind(user_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_threads(&rows)
+    }
+
+    #[instrument(skip(self, thread))]
+    async fn create(&self, thread: &MessageThread) -> DomainResult<()> {
+        debug!("Creating message thread of attendee {} about event {}", thread.attendee_id, thread.event_id);
+
+        sqlx::query(&format!("INSERT INTO message_threads ({}) VALUES (?, ?, ?, ?, ?, ?, ?)", THREAD_COLUMNS))
+            .bind(thread.id.to_string())
+            .bind(thread.event_id.to_string())
+            .bind(thread.attendee_id.to_string())
+            .bind(thread.attendee_unread)
+            .bind(thread.organizer_unread)
+            .bind(thread.last_message_at.naive_utc())
+            .bind(thread.created_at.naive_utc())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_messages(&self, thread_id: Uuid) -> DomainResult<Vec<ThreadMessage>> {
+        debug!("Finding messages of thread: {}", thread_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM thread_messages WHERE thread_id = ? ORDER BY created_at",
+            MESSAGE_COLUMNS
+        ))
+        .bind(thread_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_message(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, message))]
+    async fn add_message(&self, message: &ThreadMessage) -> DomainResult<()> {
+        debug!("Adding message {} to thread {}", message.id, message.thread_id);
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        sqlx::query(&format!("INSERT INTO thread_messages ({}) VALUES (?, ?, ?, ?, ?, ?, ?)", MESSAGE_COLUMNS))
+            .bind(message.id.to_string())
+            .bind(message.thread_id.to_string())
+            .bind(message.sender_id.to_string())
+            .bind(message.from_organizer)
+            .bind(&message.body)
+            .bind(message.broadcast_id.map(|id| id.to_string()))
+            .bind(message.created_at.naive_utc())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        // The message is unread for whoever didn't write it
+        let unread_column = if message.from_organizer { "attendee_unread" } else { "organizer_unread" };
+        sqlx::query(&format!(
+            "UPDATE message_threads SET {0} = {0} + 1, last_message_at = ? WHERE id = ?",
+            unread_column
+        ))
+        .bind(message.created_at.naive_utc())
+        .bind(message.thread_id.to_string())
+        .execute(&mut *tx)
+        .await
+        .map_err(InfrastructureError::from)?;
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn mark_read(&self, thread_id: Uuid, by_organizer: bool) -> DomainResult<()> {
+        debug!("Marking thread {} read", thread_id);
+
+        let unread_column = if by_organizer { "organizer_unread" } else { "attendee_unread" };
+        sqlx::query(&format!("UPDATE message_threads SET {} = 0 WHERE id = ?", unread_column))
+            .bind(thread_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use chrono::{Duration, Utc};
+
+    #[tokio::test]
+    async fn test_messages_count_as_unread_for_the_other_side_until_read() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteMessageThreadRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let co_organizer = db.seed_user().await;
+        let attendee = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+        let other_event_id = db.seed_event(attendee).await;
+        db.execute(&format!(
+            "INSERT INTO event_co_organizers (event_id, user_id, position) VALUES ('{}', '{}', 0)",
+            event_id, co_organizer
+        ))
+        .await;
+
+        let thread = MessageThread::new(event_id, attendee);
+        repository.create(&thread).await.unwrap();
+        assert!(repository.create(&MessageThread::new(event_id, attendee)).await.is_err());
+
+        let question = ThreadMessage::new(thread.id, attendee, false, "Is there parking?", None);
+        repository.add_message(&question).await.unwrap();
+        let mut answer = ThreadMessage::new(thread.id, co_organizer, true, "Yes, at gate B", None);
+        answer.created_at = question.created_at + Duration::minutes(1);
+        repository.add_message(&answer).await.unwrap();
+        let mut thanks = ThreadMessage::new(thread.id, attendee, false, "Thanks!", None);
+        thanks.created_at = question.created_at + Duration::minutes(2);
+        repository.add_message(&thanks).await.unwrap();
+
+        let found = repository.find_by_event_and_attendee(event_id, attendee).await.unwrap().unwrap();
+        assert_eq!((found.organizer_unread, found.attendee_unread), (2, 1));
+        assert!(found.last_message_at > Utc::now() - Duration::hours(1));
+        let bodies: Vec<String> = repository
+            .find_messages(thread.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|message| message.body)
+            .collect();
+        assert_eq!(bodies, vec!["Is there parking?", "Yes, at gate B", "Thanks!"]);
+
+        repository.mark_read(thread.id, true).await.unwrap();
+        let found = repository.find_by_id(thread.id).await.unwrap().unwrap();
+        assert_eq!((found.organizer_unread, found.attendee_unread), (0, 1));
+
+        // Threads about the attendee's own event show up in their organizer inbox instead
+        let own = MessageThread::new(other_event_id, organizer);
+        repository.create(&own).await.unwrap();
+        let ids = |threads: Vec<MessageThread>| threads.iter().map(|t| t.id).collect::<Vec<_>>();
+        assert_eq!(ids(repository.find_for_organizer(organizer).await.unwrap()), vec![thread.id]);
+        assert_eq!(ids(repository.find_for_organizer(co_organizer).await.unwrap()), vec![thread.id]);
+        assert_eq!(ids(repository.find_for_organizer(attendee).await.unwrap()), vec![own.id]);
+        assert_eq!(ids(repository.find_by_attendee(attendee).await.unwrap()), vec![thread.id]);
+
+        db.execute(&format!("DELETE FROM events WHERE id = '{}'", event_id)).await;
+        assert!(repository.find_messages(thread.id).await.unwrap().is_empty());
+        assert!(repository.find_by_attendee(attendee).await.unwrap().is_empty());
+    }
+}
@@ -3,6 +3,46 @@ pub mod user_repository;
 pub mod invitation_repository;
 pub mod event_category_repository;
 pub mod registration_repository;
+pub mod audit_log_repository;
+pub mod company_repository;
+pub mod event_comment_repository;
+pub mod event_question_repository;
+pub mod event_poll_repository;
+pub mod event_attachment_repository;
+pub mod event_budget_repository;
+pub mod event_sponsor_repository;
+pub mod scheduling_policy_repository;
+pub mod sending_domain_repository;
+pub mod email_suppression_repository;
+pub mod notification_digest_repository;
+pub mod organization_invitation_repository;
+pub mod event_ownership_transfer_repository;
+pub mod event_summary_repository;
+pub mod event_submission_repository;
+pub mod content_report_repository;
+pub mod event_consent_repository;
+pub mod storage_quota_repository;
+pub mod event_broadcast_repository;
+pub mod message_thread_repository;
+pub mod event_photo_repository;
+pub mod analytics_repository;
+pub mod event_field_repository;
+pub mod short_link_repository;
+pub mod overbooking_policy_repository;
+pub mod ticket_repository;
+pub mod invoice_repository;
+pub mod ticket_refund_repository;
+pub mod seating_repository;
+pub mod resource_repository;
+pub mod shift_repository;
+pub mod exhibitor_repository;
+pub mod lead_repository;
+pub mod matchmaking_repository;
+pub mod web_vital_repository;
+pub mod organization_role_repository;
+pub mod access_grant_repository;
+pub mod export_job_repository;
+pub mod crm_connector_repository;
 pub mod types;
 pub mod factory;
 
@@ -11,4 +51,44 @@ pub use user_repository::SqliteUserRepository;
 pub use invitation_repository::SqliteInvitationRepository;
 pub use event_category_repository::SqliteEventCategoryRepository;
 pub use registration_repository::SqliteEventRegistrationRepository;
+pub use audit_log_repository::SqliteAuditLogRepository;
+pub use company_repository::SqliteCompanyRepository;
+pub use event_comment_repository::SqliteEventCommentRepository;
+pub use event_question_repository::SqliteEventQuestionRepository;
+pub use event_poll_repository::SqliteEventPollRepository;
+pub use event_attachment_repository::SqliteEventAttachmentRepository;
+pub use event_budget_repository::SqliteEventBudgetRepository;
+pub use event_sponsor_repository::SqliteEventSponsorRepository;
+pub use scheduling_policy_repository::SqliteSchedulingPolicyRepository;
+pub use sending_domain_repository::SqliteSendingDomainRepository;
+pub use email_suppression_repository::SqliteEmailSuppressionRepository;
+pub use notification_digest_repository::SqliteNotificationDigestRepository;
+pub use organization_invitation_repository::SqliteOrganizationInvitationRepository;
+pub use event_ownership_transfer_repository::SqliteEventOwnershipTransferRepository;
+pub use event_summary_repository::SqliteEventSummaryRepository;
+pub use event_submission_repository::SqliteEventSubmissionRepository;
+pub use content_report_repository::SqliteContentReportRepository;
+pub use event_consent_repository::SqliteEventConsentRepository;
+pub use storage_quota_repository::SqliteStorageQuotaRepository;
+pub use event_broadcast_repository::SqliteEventBroadcastRepository;
+pub use message_thread_repository::SqliteMessageThreadRepository;
+pub use event_photo_repository::SqliteEventPhotoRepository;
+pub use analytics_repository::SqliteAnalyticsRepository;
+pub use event_field_repository::SqliteEventFieldRepository;
+pub use short_link_repository::SqliteShortLinkRepository;
+pub use overbooking_policy_repository::SqliteOverbookingPolicyRepository;
+pub use ticket_repository::SqliteTicketRepository;
+pub use invoice_repository::SqliteInvoiceRepository;
+pub use ticket_refund_repository::SqliteTicketRefundRepository;
+pub use seating_repository::SqliteSeatingRepository;
+pub use resource_repository::SqliteResourceRepository;
+pub use shift_repository::SqliteShiftRepository;
+pub use exhibitor_repository::SqliteExhibitorRepository;
+pub use lead_repository::SqliteLeadRepository;
+pub use matchmaking_repository::SqliteMatchmakingRepository;
+pub use web_vital_repository::SqliteWebVitalRepository;
+pub use organization_role_repository::SqliteOrganizationRoleRepository;
+pub use access_grant_repository::SqliteAccessGrantRepository;
+pub use export_job_repository::SqliteExportJobRepository;
+pub use crm_connector_repository::SqliteCrmConnectorRepository;
 pub use factory::{RepositoryFactory, AllRepositories};
\ No newline at end of file
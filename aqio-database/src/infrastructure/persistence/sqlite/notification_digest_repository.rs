@@ -0,0 +1,229 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::NotificationDigestRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::digest_frequency_to_string,
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{DigestItem, DomainResult, NotificationPreferences};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const PREFERENCE_COLUMNS: &str = "user_id, digest_frequency, last_digest_at, updated_at";
+
+const DIGEST_ITEM_COLUMNS: &str = "id, user_id, event_id, event_title, kind, message, created_at";
+
+#[derive(Clone)]
+pub struct SqliteNotificationDigestRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteNotificationDigestRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to NotificationPreferences using SafeRowGet
+    fn row_to_preferences(row: &sqlx::sqlite::SqliteRow) -> Result<NotificationPreferences, RowConversionError> {
+        Ok(NotificationPreferences {
+            user_id: row.get_uuid("user_id")?,
+            digest_frequency: row.get_digest_frequency("digest_frequency")?,
+            last_digest_at: row.get_optional_datetime("last_digest_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    // Helper method to convert database row to DigestItem using SafeRowGet
+    fn row_to_item(row: &sqlx::sqlite::SqliteRow) -> Result<DigestItem, RowConversionError> {
+        Ok(DigestItem {
+            id: row.get_uuid("id")?,
+            user_id: row.get_uuid("user_id")?,
+            event_id: row.get_optional_uuid("event_id")?,
+            event_title: row.get_optional_string("event_title")?,
+            kind: row.get_string("kind")?,
+            message: row.get_string("message")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationDigestRepository for SqliteNotificationDigestRepository {
+    #[instrument(skip(self))]
+    async fn find_preferences(&self, user_id: Uuid) -> DomainResult<Option<NotificationPreferences>> {
+        debug!("Finding notification preferences for user: {}", user_id);
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM user_notification_preferences WHERE user_id = ?",
+            PREFERENCE_COLUMNS
+        ))
+        .bind(user_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_preferences(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_digest_subscribers(&self) -> DomainResult<Vec<NotificationPreferences>> {
+        debug!("Listing users who get notification digests");
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM user_notification_preferences WHERE digest_frequency <> 'immediate' ORDER BY user_id",
+            PREFERENCE_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_preferences(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, preferences))]
+    async fn save_preferences(&self, preferences: &NotificationPreferences) -> DomainResult<()> {
+        debug!("Saving notification preferences for user: {}", preferences.user_id);
+
+        // Only the digest columns are ours; the table's other settings keep their defaults
+        sqlx::query(
+            "INSERT INTO user_notification_preferences (user_id, digest_frequency, last_digest_at, updated_at) VALUES (?, ?, ?, ?)
+             ON CONFLICT (user_id) DO UPDATE SET digest_frequency = excluded.digest_frequency, last_digest_at = excluded.last_digest_at, updated_at = excluded.updated_at"
+        )
+        .bind(preferences.user_id.to_string())
+        .bind(digest_frequency_to_string(preferences.digest_frequency))
+        .bind(preferences.last_digest_at.map(|at| at.naive_utc()))
+        .bind(preferences.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, item))]
+    async fn add_item(&self, item: &DigestItem) -> DomainResult<()> {
+        debug!("Queueing digest item {} for user {}", item.id, item.user_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO notification_digest_items ({}) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            DIGEST_ITEM_COLUMNS
+        ))
+        .bind(item.id.to_string())
+        .bind(item.user_id.to_string())
+        .bind(item.event_id.map(|id| id.to_string()))
+        .bind(&item.event_title)
+        .bind(&item.kind)
+        .bind(&item.message)
+        .bind(item.created_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_pending_items(&self, user_id: Uuid) -> DomainResult<Vec<DigestItem>> {
+        debug!("Finding pending digest items for user: {}", user_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM notification_digest_items WHERE user_id = ? ORDER BY created_at, id",
+            DIGEST_ITEM_COLUMNS
+        ))
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_item(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, ids))]
+    async fn delete_items(&self, ids: &[Uuid]) -> DomainResult<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        debug!("Deleting {} digest items", ids.len());
+
+        let placeholders = vec!["?"; ids.len()].join(", ");
+        let sql = format!("DELETE FROM notification_digest_items WHERE id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for id in ids {
+            query = query.bind(id.to_string());
+        }
+        query.execute(&self.pool).await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::DigestFrequency;
+
+    #[tokio::test]
+    async fn test_preferences_are_created_then_replaced() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteNotificationDigestRepository::new(db.pool().clone());
+        let user = db.seed_user().await;
+        let other_user = db.seed_user().await;
+
+        assert!(repository.find_preferences(user).await.unwrap().is_none());
+
+        let mut preferences = NotificationPreferences::new(user);
+        repository.save_preferences(&preferences).await.unwrap();
+        repository.save_preferences(&NotificationPreferences::new(other_user)).await.unwrap();
+        assert!(repository.find_digest_subscribers().await.unwrap().is_empty());
+
+        let sent_at = chrono::Utc::now();
+        preferences.digest_frequency = DigestFrequency::Weekly;
+        preferences.last_digest_at = Some(sent_at);
+        repository.save_preferences(&preferences).await.unwrap();
+
+        let found = repository.find_preferences(user).await.unwrap().unwrap();
+        assert_eq!(found.digest_frequency, DigestFrequency::Weekly);
+        assert_eq!(found.last_digest_at.map(|at| at.timestamp()), Some(sent_at.timestamp()));
+        let subscribers = repository.find_digest_subscribers().await.unwrap();
+        assert_eq!(subscribers.iter().map(|p| p.user_id).collect::<Vec<_>>(), vec![user]);
+    }
+
+    #[tokio::test]
+    async fn test_digest_items_queue_per_user() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteNotificationDigestRepository::new(db.pool().clone());
+        let user = db.seed_user().await;
+        let other_user = db.seed_user().await;
+        let event = db.seed_event(user).await;
+
+        let mut first = DigestItem::new(user, Some(event), Some("Salmon Summit".to_string()), "waitlist_moved", "You moved up the waitlist");
+        first.created_at -= chrono::Duration::hours(2);
+        let second = DigestItem::new(user, None, None, "event_published", "An event was published");
+        let elsewhere = DigestItem::new(other_user, Some(event), None, "event_published", "An event was published");
+        for item in [&second, &first, &elsewhere] {
+            repository.add_item(item).await.unwrap();
+        }
+
+        let pending = repository.find_pending_items(user).await.unwrap();
+        assert_eq!(pending.iter().map(|i| i.id).collect::<Vec<_>>(), vec![first.id, second.id]);
+        assert_eq!(pending[0].event_id, Some(event));
+        assert_eq!(pending[0].event_title.as_deref(), Some("Salmon Summit"));
+        assert_eq!(pending[1].event_id, None);
+
+        repository.delete_items(&[first.id, second.id]).await.unwrap();
+        repository.delete_items(&[]).await.unwrap();
+        assert!(repository.find_pending_items(user).await.unwrap().is_empty());
+        assert_eq!(repository.find_pending_items(other_user).await.unwrap().len(), 1);
+    }
+}
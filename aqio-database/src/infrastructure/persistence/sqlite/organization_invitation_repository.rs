@@ -0,0 +1,231 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::OrganizationInvitationRepository,
+};
+use crate::infrastructure::persistence::{
+    mapping::{organization_invitation_status_to_string, user_role_to_string},
+    sqlite::types::{SafeRowGet, RowConversionError},
+};
+use aqio_core::{DomainResult, OrganizationInvitation};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const INVITATION_COLUMNS: &str = "id, company_id, email, role, token, invited_by, status, expires_at, accepted_by, accepted_at, created_at";
+
+#[derive(Clone)]
+pub struct SqliteOrganizationInvitationRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteOrganizationInvitationRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to OrganizationInvitation using SafeRowGet
+    fn row_to_invitation(row: &sqlx::sqlite::SqliteRow) -> Result<OrganizationInvitation, RowConversionError> {
+        Ok(OrganizationInvitation {
+            id: row.get_uuid("id")?,
+            company_id: row.get_uuid("company_id")?,
+            email: row.get_string("email")?,
+            role: row.get_user_role("role")?,
+            token: row.get_string("token")?,
+            invited_by: row.get_optional_uuid("invited_by")?,
+            status: row.get_organization_invitation_status("status")?,
+            expires_at: row.get_datetime("expires_at")?,
+            accepted_by: row.get_optional_uuid("accepted_by")?,
+            accepted_at: row.get_optional_datetime("accepted_at")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+
+    fn rows_to_invitations(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<OrganizationInvitation>> {
+        rows.iter()
+            .map(|row| Self::row_to_invitation(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    async fn find_one(&self, column: &str, value: String) -> DomainResult<Option<OrganizationInvitation>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM organization_invitations WHERE {} = ?",
+            INVITATION_COLUMNS, column
+        ))
+        .bind(value)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_invitation(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl OrganizationInvitationRepository for SqliteOrganizationInvitationRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<OrganizationInvitation>> {
+        debug!("Finding organization invitation by ID: {}", id);
+        self.find_one("id", id.to_string()).await
+    }
+
+    #[instrument(skip(self, token))]
+    async fn find_by_token(&self, token: &str) -> DomainResult<Option<OrganizationInvitation>> {
+        debug!("Finding organization invitation by token");
+        self.find_one("token", token.to_string()).await
+    }
+
+    #[instrument(skip(self))]
+    async fn find_pending_by_email(&self, email: &str) -> DomainResult<Vec<OrganizationInvitation>> {
+        debug!("Finding pending organization invitations for: {}", email);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM organization_invitations WHERE email = ? AND status = 'pending' ORDER BY created_at DESC",
+            INVITATION_COLUMNS
+        ))
+        .bind(email.trim().to_lowercase())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_invitations(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_pending(&self, company_id: Option<Uuid>) -> DomainResult<Vec<OrganizationInvitation>> {
+        debug!("Listing pending organization invitations for company: {:?}", company_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM organization_invitations WHERE status = 'pending' AND (? IS NULL OR company_id = ?) ORDER BY created_at DESC",
+            INVITATION_COLUMNS
+        ))
+        .bind(company_id.map(|id| id.to_string()))
+        .bind(company_id.map(|id| id.to_string()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_invitations(&rows)
+    }
+
+    #[instrument(skip(self, invitation))]
+    async fn create(&self, invitation: &OrganizationInvitation) -> DomainResult<()> {
+        debug!("Creating organization invitation {} to company {}", invitation.id, invitation.company_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO organization_invitations ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            INVITATION_COLUMNS
+        ))
+        .bind(invitation.id.to_string())
+        .bind(invitation.company_id.to_string())
+        .bind(&invitation.email)
+        .bind(user_role_to_string(&invitation.role))
+        .bind(&invitation.token)
+        .bind(invitation.invited_by.map(|id| id.to_string()))
+        .bind(organization_invitation_status_to_string(invitation.status))
+        .bind(invitation.expires_at.naive_utc())
+        .bind(invitation.accepted_by.map(|id| id.to_string()))
+        .bind(invitation.accepted_at.map(|at| at.naive_utc()))
+        .bind(invitation.created_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, invitation))]
+    async fn update(&self, invitation: &OrganizationInvitation) -> DomainResult<()> {
+        debug!("Updating organization invitation: {}", invitation.id);
+
+        // The company, address and token never change
+        sqlx::query(
+            "UPDATE organization_invitations SET role = ?, status = ?, expires_at = ?, accepted_by = ?, accepted_at = ? WHERE id = ?"
+        )
+        .bind(user_role_to_string(&invitation.role))
+        .bind(organization_invitation_status_to_string(invitation.status))
+        .bind(invitation.expires_at.naive_utc())
+        .bind(invitation.accepted_by.map(|id| id.to_string()))
+        .bind(invitation.accepted_at.map(|at| at.naive_utc()))
+        .bind(invitation.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::{OrganizationInvitationStatus, UserRole};
+
+    async fn seed_company(db: &TestDb) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO companies (id, name, industry_type) VALUES (?, ?, 'Salmon')")
+            .bind(id.to_string())
+            .bind(format!("Company {}", id))
+            .execute(db.pool())
+            .await
+            .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn test_invitation_round_trips_and_is_found_by_token() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteOrganizationInvitationRepository::new(db.pool().clone());
+        let company = seed_company(&db).await;
+        let inviter = db.seed_user().await;
+
+        let mut invitation = OrganizationInvitation::new(company, "Kari@Example.no", UserRole::Organizer, Some(inviter));
+        repository.create(&invitation).await.unwrap();
+
+        let found = repository.find_by_token(&invitation.token).await.unwrap().unwrap();
+        assert_eq!(found.id, invitation.id);
+        assert_eq!(found.email, "kari@example.no");
+        assert!(matches!(found.role, UserRole::Organizer));
+        assert_eq!(found.invited_by, Some(inviter));
+        assert_eq!(found.status, OrganizationInvitationStatus::Pending);
+        assert!(repository.find_by_token("unknown").await.unwrap().is_none());
+
+        let joined = db.seed_user().await;
+        invitation.accept(joined, chrono::Utc::now());
+        repository.update(&invitation).await.unwrap();
+
+        let found = repository.find_by_id(invitation.id).await.unwrap().unwrap();
+        assert_eq!(found.status, OrganizationInvitationStatus::Accepted);
+        assert_eq!(found.accepted_by, Some(joined));
+        assert!(found.accepted_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_pending_invitations_per_company_and_address() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteOrganizationInvitationRepository::new(db.pool().clone());
+        let company = seed_company(&db).await;
+        let other_company = seed_company(&db).await;
+
+        let mut older = OrganizationInvitation::new(company, "kari@example.no", UserRole::Participant, None);
+        older.created_at -= chrono::Duration::days(1);
+        let newer = OrganizationInvitation::new(other_company, "kari@example.no", UserRole::Participant, None);
+        let mut revoked = OrganizationInvitation::new(company, "ola@example.no", UserRole::Participant, None);
+        revoked.status = OrganizationInvitationStatus::Revoked;
+        for invitation in [&older, &newer, &revoked] {
+            repository.create(invitation).await.unwrap();
+        }
+
+        let for_kari = repository.find_pending_by_email("KARI@example.no").await.unwrap();
+        assert_eq!(for_kari.iter().map(|i| i.id).collect::<Vec<_>>(), vec![newer.id, older.id]);
+
+        let all = repository.find_pending(None).await.unwrap();
+        assert_eq!(all.iter().map(|i| i.id).collect::<Vec<_>>(), vec![newer.id, older.id]);
+        let for_company = repository.find_pending(Some(company)).await.unwrap();
+        assert_eq!(for_company.iter().map(|i| i.id).collect::<Vec<_>>(), vec![older.id]);
+    }
+}
@@ -0,0 +1,340 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::OrganizationRoleRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, OrganizationRole, Permission, PermissionSet};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+// Permissions come along as a comma-separated list, one row per role
+const ROLE_SELECT: &str = "SELECT r.id, r.company_id, r.name, r.description, r.created_at, r.updated_at, \
+     GROUP_CONCAT(p.permission) AS permissions \
+     FROM organization_roles r LEFT JOIN organization_role_permissions p ON p.role_id = r.id";
+
+/// Reads a `GROUP_CONCAT` of permission codes; unknown codes are an error
+pub(super) fn parse_permissions(codes: Option<String>) -> Result<PermissionSet, RowConversionError> {
+    codes
+        .unwrap_or_default()
+        .split(',')
+        .filter(|code| !code.is_empty())
+        .map(|code| {
+            Permission::from_code(code).ok_or_else(|| RowConversionError::InvalidEnum {
+                field: "permission",
+                value: code.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct SqliteOrganizationRoleRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteOrganizationRoleRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to OrganizationRole using SafeRowGet
+    fn row_to_role(row: &sqlx::sqlite::SqliteRow) -> Result<OrganizationRole, RowConversionError> {
+        let permissions = parse_permissions(row.get_optional_string("permissions")?)?;
+
+        Ok(OrganizationRole {
+            id: row.get_uuid("id")?,
+            company_id: row.get_uuid("company_id")?,
+            name: row.get_string("name")?,
+            description: row.get_optional_string("description")?,
+            permissions,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn rows_to_roles(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<OrganizationRole>> {
+        rows.iter()
+            .map(|row| Self::row_to_role(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    async fn insert_permissions(
+        tx: &mut sqlx::Transaction<'_, Sqlite>,
+        role: &OrganizationRole,
+    ) -> DomainResult<()> {
+        for permission in role.permissions.iter() {
+            sqlx::query("INSERT INTO organization_role_permissions (role_id, permission) VALUES (?, ?)")
+                .bind(role.id.to_string())
+                .bind(permission.code())
+                .execute(&mut **tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrganizationRoleRepository for SqliteOrganizationRoleRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<OrganizationRole>> {
+        debug!("Finding organization role by ID: {}", id);
+
+        let row = sqlx::query(&format!("{} WHERE r.id = ? GROUP BY r.id", ROLE_SELECT))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_role(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_company(&self, company_id: Uuid) -> DomainResult<Vec<OrganizationRole>> {
+        debug!("Listing organization roles of company: {}", company_id);
+
+        let rows = sqlx::query(&format!(
+            "{} WHERE r.company_id = ? GROUP BY r.id ORDER BY r.name COLLATE NOCASE",
+            ROLE_SELECT
+        ))
+        .bind(company_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_roles(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_user(&self, user_id: Uuid) -> DomainResult<Vec<OrganizationRole>> {
+        debug!("Listing organization roles held by user: {}", user_id);
+
+        let rows = sqlx::query(&format!(
+            "{} JOIN organization_role_members m ON m.role_id = r.id WHERE m.user_id = ? \
+             GROUP BY r.id ORDER BY r.name COLLATE NOCASE",
+            ROLE_SELECT
+        ))
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_roles(&rows)
+    }
+
+    #[instrument(skip(self, role))]
+    async fn create(&self, role: &OrganizationRole) -> DomainResult<()> {
+        debug!("Creating organization role {} for company {}", role.id, role.company_id);
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        sqlx::query(
+            "INSERT INTO organization_roles (id, company_id, name, description, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)"
+        )
+        .bind(role.id.to_string())
+        .bind(role.company_id.to_string())
+        .bind(&role.name)
+        .bind(&role.description)
+        .bind(role.created_at.naive_utc())
+        .bind(role.updated_at.naive_utc())
+        .execute(&mut *tx)
+        .await
+        .map_err(InfrastructureError::from)?;
+        Self::insert_permissions(&mut tx, role).await?;
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, role))]
+    async fn update(&self, role: &OrganizationRole) -> DomainResult<()> {
+        debug!("Updating organization role: {}", role.id);
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        let result = sqlx::query("UPDATE organization_roles SET name = ?, description = ?, updated_at = ? WHERE id = ?")
+            .bind(&role.name)
+            .bind(&role.description)
+            .bind(role.updated_at.naive_utc())
+            .bind(role.id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("OrganizationRole", role.id));
+        }
+
+        sqlx::query("DELETE FROM organization_role_permissions WHERE role_id = ?")
+            .bind(role.id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        Self::insert_permissions(&mut tx, role).await?;
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting organization role: {}", id);
+
+        // Delete explicitly rather than relying on the foreign key pragma being on
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        for table in ["organization_role_members", "organization_role_permissions"] {
+            sqlx::query(&format!("DELETE FROM {} WHERE role_id = ?", table))
+                .bind(id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        let result = sqlx::query("DELETE FROM organization_roles WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("OrganizationRole", id));
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_member_ids(&self, role_id: Uuid) -> DomainResult<Vec<Uuid>> {
+        debug!("Listing members of organization role: {}", role_id);
+
+        let rows = sqlx::query(
+            "SELECT user_id FROM organization_role_members WHERE role_id = ? ORDER BY assigned_at, rowid"
+        )
+        .bind(role_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| row.get_uuid("user_id").map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn add_member(&self, role_id: Uuid, user_id: Uuid) -> DomainResult<()> {
+        debug!("Assigning organization role {} to user {}", role_id, user_id);
+
+        sqlx::query("INSERT OR IGNORE INTO organization_role_members (role_id, user_id) VALUES (?, ?)")
+            .bind(role_id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn remove_member(&self, role_id: Uuid, user_id: Uuid) -> DomainResult<bool> {
+        debug!("Removing organization role {} from user {}", role_id, user_id);
+
+        let result = sqlx::query("DELETE FROM organization_role_members WHERE role_id = ? AND user_id = ?")
+            .bind(role_id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    async fn seed_company(db: &TestDb) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO companies (id, name, industry_type) VALUES (?, ?, 'Salmon')")
+            .bind(id.to_string())
+            .bind(format!("Company {}", id))
+            .execute(db.pool())
+            .await
+            .unwrap();
+        id
+    }
+
+    fn permissions(list: &[Permission]) -> PermissionSet {
+        list.iter().copied().collect()
+    }
+
+    #[tokio::test]
+    async fn test_role_round_trips_with_its_permissions() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteOrganizationRoleRepository::new(db.pool().clone());
+        let company = seed_company(&db).await;
+
+        let mut role = OrganizationRole::new(
+            company,
+            "Door staff",
+            Some("Checks people in at the entrance".to_string()),
+            permissions(&[Permission::RegistrationCheckIn]),
+        );
+        let empty = OrganizationRole::new(company, "auditors", None, PermissionSet::default());
+        repository.create(&role).await.unwrap();
+        repository.create(&empty).await.unwrap();
+
+        let found = repository.find_by_id(role.id).await.unwrap().unwrap();
+        assert_eq!(found.name, "Door staff");
+        assert_eq!(found.description.as_deref(), Some("Checks people in at the entrance"));
+        assert_eq!(found.permissions, role.permissions);
+
+        role.permissions = permissions(&[Permission::RegistrationApprove, Permission::InvitationSend]);
+        role.name = "Hosts".to_string();
+        repository.update(&role).await.unwrap();
+
+        let listed = repository.find_by_company(company).await.unwrap();
+        assert_eq!(listed.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(), vec!["auditors", "Hosts"]);
+        assert!(listed[0].permissions.is_empty());
+        assert_eq!(listed[1].permissions, role.permissions);
+
+        // Names are unique within a company
+        let duplicate = OrganizationRole::new(company, "Hosts", None, PermissionSet::default());
+        assert!(repository.create(&duplicate).await.is_err());
+        let other_company = seed_company(&db).await;
+        let elsewhere = OrganizationRole::new(other_company, "Hosts", None, PermissionSet::default());
+        repository.create(&elsewhere).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_members_and_roles_per_user() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteOrganizationRoleRepository::new(db.pool().clone());
+        let company = seed_company(&db).await;
+        let role = OrganizationRole::new(company, "Door staff", None, permissions(&[Permission::RegistrationCheckIn]));
+        repository.create(&role).await.unwrap();
+        let kari = db.seed_user().await;
+        let ola = db.seed_user().await;
+
+        repository.add_member(role.id, kari).await.unwrap();
+        repository.add_member(role.id, ola).await.unwrap();
+        repository.add_member(role.id, kari).await.unwrap();
+        assert_eq!(repository.find_member_ids(role.id).await.unwrap(), vec![kari, ola]);
+
+        let held = repository.find_by_user(kari).await.unwrap();
+        assert_eq!(held.len(), 1);
+        assert_eq!(held[0].permissions, role.permissions);
+
+        assert!(repository.remove_member(role.id, kari).await.unwrap());
+        assert!(!repository.remove_member(role.id, kari).await.unwrap());
+        assert!(repository.find_by_user(kari).await.unwrap().is_empty());
+
+        repository.delete(role.id).await.unwrap();
+        assert!(repository.find_by_id(role.id).await.unwrap().is_none());
+        assert!(repository.find_by_user(ola).await.unwrap().is_empty());
+        assert!(repository.delete(role.id).await.is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::OverbookingPolicyRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, OverbookingPolicy};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const POLICY_COLUMNS: &str = "event_id, percent, hard_ceiling, updated_by, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteOverbookingPolicyRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteOverbookingPolicyRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to OverbookingPolicy using SafeRowGet
+    fn row_to_policy(row: &sqlx::sqlite::SqliteRow) -> Result<OverbookingPolicy, RowConversionError> {
+        Ok(OverbookingPolicy {
+            event_id: row.get_uuid("event_id")?,
+            percent: row.get_i32("percent")?,
+            hard_ceiling: row.get_optional_i32("hard_ceiling")?,
+            updated_by: row.get_uuid("updated_by")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl OverbookingPolicyRepository for SqliteOverbookingPolicyRepository {
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Option<OverbookingPolicy>> {
+        debug!("Finding overbooking policy for event: {}", event_id);
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM event_overbooking_policies WHERE event_id = ?",
+            POLICY_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_policy(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self, policy))]
+    async fn save(&self, policy: &OverbookingPolicy) -> DomainResult<()> {
+        debug!("Saving overbooking policy for event: {}", policy.event_id);
+
+        sqlx::query(
+            "INSERT INTO event_overbooking_policies (event_id, percent, hard_ceiling, updated_by, updated_at) VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT (event_id) DO UPDATE SET percent = excluded.percent, hard_ceiling = excluded.hard_ceiling, updated_by = excluded.updated_by, updated_at = excluded.updated_at"
+        )
+        .bind(policy.event_id.to_string())
+        .bind(policy.percent)
+        .bind(policy.hard_ceiling)
+        .bind(policy.updated_by.to_string())
+        .bind(policy.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, event_id: Uuid) -> DomainResult<()> {
+        debug!("Deleting overbooking policy for event: {}", event_id);
+
+        sqlx::query("DELETE FROM event_overbooking_policies WHERE event_id = ?")
+            .bind(event_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_policy_is_replaced_and_removed() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteOverbookingPolicyRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+        assert!(repository.find_by_event_id(event_id).await.unwrap().is_none());
+
+        let mut policy = OverbookingPolicy {
+            event_id,
+            percent: 10,
+            hard_ceiling: None,
+            updated_by: organizer,
+            updated_at: Utc::now(),
+        };
+        repository.save(&policy).await.unwrap();
+        policy.percent = 15;
+        policy.hard_ceiling = Some(120);
+        repository.save(&policy).await.unwrap();
+
+        let found = repository.find_by_event_id(event_id).await.unwrap().unwrap();
+        assert_eq!((found.percent, found.hard_ceiling, found.updated_by), (15, Some(120), organizer));
+
+        repository.delete(event_id).await.unwrap();
+        repository.delete(event_id).await.unwrap();
+        assert!(repository.find_by_event_id(event_id).await.unwrap().is_none());
+    }
+}
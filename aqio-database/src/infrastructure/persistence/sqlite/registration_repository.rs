@@ -1,12 +1,12 @@
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDateTime, Utc};
-use sqlx::SqlitePool;
+use sqlx::{Row, SqliteConnection, SqlitePool};
 use uuid::Uuid;
 
 use crate::domain::errors::{InfrastructureError, SqliteForeignKeyDiagnostic};
 use aqio_core::{
     DomainError, DomainResult, EventRegistration, EventRegistrationRepository,
-    RegistrationSource, RegistrationStatus
+    RegistrationCounts, RegistrationSource, RegistrationStatus
 };
 
 #[derive(Clone)]
@@ -97,6 +97,7 @@ impl SqliteEventRegistrationRepository {
         checked_in_at: Option<NaiveDateTime>,
         waitlist_position: Option<i64>,
         waitlist_added_at: Option<NaiveDateTime>,
+        promotion_expires_at: Option<NaiveDateTime>,
         created_at: NaiveDateTime,         // NOT NULL
         updated_at: NaiveDateTime,         // NOT NULL
     ) -> DomainResult<EventRegistration> {
@@ -123,7 +124,7 @@ impl SqliteEventRegistrationRepository {
             None => None,
         };
 
-        // Parse guest_names JSON
+        // Guest names arrive aggregated from registration_guests as a JSON array
         let guest_names: Vec<String> = match guest_names {
             Some(names_json) => serde_json::from_str(&names_json).unwrap_or_default(),
             None => vec![],
@@ -152,11 +153,123 @@ impl SqliteEventRegistrationRepository {
             checked_in_at: Self::optional_naive_to_utc(checked_in_at),
             waitlist_position: waitlist_position.map(|pos| pos as i32),
             waitlist_added_at: Self::optional_naive_to_utc(waitlist_added_at),
+            promotion_expires_at: Self::optional_naive_to_utc(promotion_expires_at),
             created_at: Self::naive_to_utc(created_at),
             updated_at: Self::naive_to_utc(updated_at),
         })
     }
 
+    // Insert a registration; with a `capacity` the row is only written while
+    // the event's seat count in `event_summaries` (kept current by triggers)
+    // is below it. Check and insert are one statement, and SQLite takes the
+    // write lock before evaluating it, so concurrent inserts are serialized
+    // and cannot overbook. Returns whether the row was written.
+    async fn insert(&self, registration: &EventRegistration, capacity: Option<i32>) -> DomainResult<bool> {
+        let mut sql = String::from(
+            r#"
+            INSERT INTO event_registrations (
+                id, event_id, invitation_id, user_id, external_contact_id,
+                registrant_email, registrant_name, registrant_phone, registrant_company,
+                status, registration_source,
+                guest_count,
+                dietary_restrictions, accessibility_needs, special_requests, custom_responses,
+                registered_at, cancelled_at, checked_in_at,
+                waitlist_position, waitlist_added_at, promotion_expires_at,
+                created_at, updated_at
+            )
+            SELECT
+                ?, ?, ?, ?, ?,
+                ?, ?, ?, ?,
+                ?, ?,
+                ?,
+                ?, ?, ?, ?,
+                ?, ?, ?,
+                ?, ?, ?,
+                ?, ?
+            "#,
+        );
+        if capacity.is_some() {
+            sql.push_str(
+                "WHERE COALESCE((SELECT registered_count + attended_count FROM event_summaries WHERE event_id = ?), 0) < ?",
+            );
+        }
+
+        let event_id_str = registration.event_id.to_string();
+        let mut query = sqlx::query(&sql)
+            .bind(registration.id.to_string())
+            .bind(&event_id_str)
+            .bind(registration.invitation_id.map(|id| id.to_string()))
+            .bind(registration.user_id.map(|id| id.to_string()))
+            .bind(registration.external_contact_id.map(|id| id.to_string()))
+            .bind(&registration.registrant_email)
+            .bind(&registration.registrant_name)
+            .bind(&registration.registrant_phone)
+            .bind(&registration.registrant_company)
+            .bind(Self::status_to_string(&registration.status))
+            .bind(Self::source_to_string(&registration.registration_source))
+            .bind(registration.guest_count as i64)
+            .bind(&registration.dietary_restrictions)
+            .bind(&registration.accessibility_needs)
+            .bind(&registration.special_requests)
+            .bind(&registration.custom_responses)
+            .bind(registration.registered_at.naive_utc())
+            .bind(registration.cancelled_at.map(|dt| dt.naive_utc()))
+            .bind(registration.checked_in_at.map(|dt| dt.naive_utc()))
+            .bind(registration.waitlist_position.map(|pos| pos as i64))
+            .bind(registration.waitlist_added_at.map(|dt| dt.naive_utc()))
+            .bind(registration.promotion_expires_at.map(|dt| dt.naive_utc()))
+            .bind(registration.created_at.naive_utc())
+            .bind(registration.updated_at.naive_utc());
+        if let Some(capacity) = capacity {
+            query = query.bind(&event_id_str).bind(capacity as i64);
+        }
+
+        // The registration and its guests are written together
+        let result = async move {
+            let mut tx = self.pool.begin().await?;
+            let inserted = query.execute(&mut *tx).await?.rows_affected() == 1;
+            if inserted {
+                Self::replace_guests(&mut tx, registration).await?;
+                tx.commit().await?;
+            }
+            Ok::<_, sqlx::Error>(inserted)
+        }
+        .await;
+
+        match result {
+            Ok(inserted) => Ok(inserted),
+            Err(e) => {
+                let infrastructure_error = InfrastructureError::from(e);
+                match infrastructure_error {
+                    InfrastructureError::DomainError { source } => Err(source),
+                    InfrastructureError::ForeignKeyConstraintViolation { message } => {
+                        // We have context about what we were trying to insert
+                        let specific_error = self.diagnose_foreign_key_violation(registration, &message).await;
+                        Err(specific_error)
+                    }
+                    other => Err(other.into()),
+                }
+            }
+        }
+    }
+
+    // Replaces the registration's guest rows with `registration.guest_names`, in order
+    async fn replace_guests(tx: &mut SqliteConnection, registration: &EventRegistration) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM registration_guests WHERE registration_id = ?")
+            .bind(registration.id.to_string())
+            .execute(&mut *tx)
+            .await?;
+        for (position, name) in registration.guest_names.iter().enumerate() {
+            sqlx::query("INSERT INTO registration_guests (registration_id, position, name) VALUES (?, ?, ?)")
+                .bind(registration.id.to_string())
+                .bind(position as i64)
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+        }
+        Ok(())
+    }
+
     // Diagnose which foreign key constraint is failing by checking if referenced entities exist
     async fn diagnose_foreign_key_violation(&self, registration: &EventRegistration, _db_message: &str) -> aqio_core::DomainError {
         let diagnostic = SqliteForeignKeyDiagnostic::new(self.pool.clone());
@@ -212,10 +325,11 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
                 id, event_id, invitation_id, user_id, external_contact_id,
                 registrant_email, registrant_name, registrant_phone, registrant_company,
                 status, registration_source,
-                guest_count, guest_names,
+                guest_count,
+                (SELECT json_group_array(name) FROM (SELECT name FROM registration_guests WHERE registration_id = event_registrations.id ORDER BY position)) AS "guest_names?: String",
                 dietary_restrictions, accessibility_needs, special_requests, custom_responses,
                 registered_at, cancelled_at, checked_in_at,
-                waitlist_position, waitlist_added_at,
+                waitlist_position, waitlist_added_at, promotion_expires_at,
                 created_at, updated_at
             FROM event_registrations 
             WHERE id = ?
@@ -251,6 +365,7 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
                     row.checked_in_at,
                     row.waitlist_position,
                     row.waitlist_added_at,
+                    row.promotion_expires_at,
                     row.created_at,
                     row.updated_at,
                 )?;
@@ -268,10 +383,11 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
                 id, event_id, invitation_id, user_id, external_contact_id,
                 registrant_email, registrant_name, registrant_phone, registrant_company,
                 status, registration_source,
-                guest_count, guest_names,
+                guest_count,
+                (SELECT json_group_array(name) FROM (SELECT name FROM registration_guests WHERE registration_id = event_registrations.id ORDER BY position)) AS "guest_names?: String",
                 dietary_restrictions, accessibility_needs, special_requests, custom_responses,
                 registered_at, cancelled_at, checked_in_at,
-                waitlist_position, waitlist_added_at,
+                waitlist_position, waitlist_added_at, promotion_expires_at,
                 created_at, updated_at
             FROM event_registrations 
             WHERE event_id = ?
@@ -308,6 +424,7 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
                 row.checked_in_at,
                 row.waitlist_position,
                 row.waitlist_added_at,
+                row.promotion_expires_at,
                 row.created_at,
                 row.updated_at,
             )?;
@@ -325,10 +442,11 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
                 id, event_id, invitation_id, user_id, external_contact_id,
                 registrant_email, registrant_name, registrant_phone, registrant_company,
                 status, registration_source,
-                guest_count, guest_names,
+                guest_count,
+                (SELECT json_group_array(name) FROM (SELECT name FROM registration_guests WHERE registration_id = event_registrations.id ORDER BY position)) AS "guest_names?: String",
                 dietary_restrictions, accessibility_needs, special_requests, custom_responses,
                 registered_at, cancelled_at, checked_in_at,
-                waitlist_position, waitlist_added_at,
+                waitlist_position, waitlist_added_at, promotion_expires_at,
                 created_at, updated_at
             FROM event_registrations 
             WHERE user_id = ?
@@ -365,6 +483,7 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
                 row.checked_in_at,
                 row.waitlist_position,
                 row.waitlist_added_at,
+                row.promotion_expires_at,
                 row.created_at,
                 row.updated_at,
             )?;
@@ -383,10 +502,11 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
                 id, event_id, invitation_id, user_id, external_contact_id,
                 registrant_email, registrant_name, registrant_phone, registrant_company,
                 status, registration_source,
-                guest_count, guest_names,
+                guest_count,
+                (SELECT json_group_array(name) FROM (SELECT name FROM registration_guests WHERE registration_id = event_registrations.id ORDER BY position)) AS "guest_names?: String",
                 dietary_restrictions, accessibility_needs, special_requests, custom_responses,
                 registered_at, cancelled_at, checked_in_at,
-                waitlist_position, waitlist_added_at,
+                waitlist_position, waitlist_added_at, promotion_expires_at,
                 created_at, updated_at
             FROM event_registrations 
             WHERE event_id = ? AND user_id = ?
@@ -423,6 +543,7 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
                     row.checked_in_at,
                     row.waitlist_position,
                     row.waitlist_added_at,
+                    row.promotion_expires_at,
                     row.created_at,
                     row.updated_at,
                 )?;
@@ -432,98 +553,59 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
         }
     }
 
-    async fn create(&self, registration: &EventRegistration) -> DomainResult<()> {
-        let guest_names_json = serde_json::to_string(&registration.guest_names)
-            .map_err(|e| DomainError::business_rule(&format!("Failed to serialize guest names: {}", e)))?;
-
-        // Convert values to proper types and create owned strings for lifetimes
-        let id_str = registration.id.to_string();
-        let event_id_str = registration.event_id.to_string();
-        let invitation_id_str = registration.invitation_id.as_ref().map(|id| id.to_string());
-        let user_id_str = registration.user_id.as_ref().map(|id| id.to_string());
-        let external_contact_id_str = registration.external_contact_id.as_ref().map(|id| id.to_string());
-        let status_str = Self::status_to_string(&registration.status).to_string();
-        let source_str = Self::source_to_string(&registration.registration_source).to_string();
-        let guest_count_i64 = registration.guest_count as i64;
-        let registered_at_naive = registration.registered_at.naive_utc();
-        let cancelled_at_naive = registration.cancelled_at.map(|dt| dt.naive_utc());
-        let checked_in_at_naive = registration.checked_in_at.map(|dt| dt.naive_utc());
-        let waitlist_position_i64 = registration.waitlist_position.map(|pos| pos as i64);
-        let waitlist_added_at_naive = registration.waitlist_added_at.map(|dt| dt.naive_utc());
-        let created_at_naive = registration.created_at.naive_utc();
-        let updated_at_naive = registration.updated_at.naive_utc();
+    async fn count_by_event_ids(&self, event_ids: &[Uuid]) -> DomainResult<Vec<RegistrationCounts>> {
+        if event_ids.is_empty() {
+            return Ok(Vec::new());
+        }
 
-        let result = sqlx::query!(
+        let mut query_builder = sqlx::QueryBuilder::new(
             r#"
-            INSERT INTO event_registrations (
-                id, event_id, invitation_id, user_id, external_contact_id,
-                registrant_email, registrant_name, registrant_phone, registrant_company,
-                status, registration_source,
-                guest_count, guest_names,
-                dietary_restrictions, accessibility_needs, special_requests, custom_responses,
-                registered_at, cancelled_at, checked_in_at,
-                waitlist_position, waitlist_added_at,
-                created_at, updated_at
-            ) VALUES (
-                ?, ?, ?, ?, ?,
-                ?, ?, ?, ?,
-                ?, ?,
-                ?, ?,
-                ?, ?, ?, ?,
-                ?, ?, ?,
-                ?, ?,
-                ?, ?
-            )
-            "#,
-            id_str,
-            event_id_str,
-            invitation_id_str,
-            user_id_str,
-            external_contact_id_str,
-            registration.registrant_email,
-            registration.registrant_name,
-            registration.registrant_phone,
-            registration.registrant_company,
-            status_str,
-            source_str,
-            guest_count_i64,
-            guest_names_json,
-            registration.dietary_restrictions,
-            registration.accessibility_needs,
-            registration.special_requests,
-            registration.custom_responses,
-            registered_at_naive,
-            cancelled_at_naive,
-            checked_in_at_naive,
-            waitlist_position_i64,
-            waitlist_added_at_naive,
-            created_at_naive,
-            updated_at_naive,
-        )
-        .execute(&self.pool)
-        .await;
-
-        match result {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                let infrastructure_error = InfrastructureError::from(e);
-                match infrastructure_error {
-                    InfrastructureError::DomainError { source } => Err(source),
-                    InfrastructureError::ForeignKeyConstraintViolation { message } => {
-                        // We have context about what we were trying to insert
-                        let specific_error = self.diagnose_foreign_key_violation(&registration, &message).await;
-                        Err(specific_error)
-                    }
-                    other => Err(other.into()),
-                }
-            }
+            SELECT
+                event_id,
+                SUM(CASE WHEN status = 'registered' THEN 1 ELSE 0 END) AS registered,
+                SUM(CASE WHEN status = 'waitlisted' THEN 1 ELSE 0 END) AS waitlisted,
+                SUM(CASE WHEN status = 'attended' THEN 1 ELSE 0 END) AS attended,
+                SUM(CASE WHEN status = 'cancelled' THEN 1 ELSE 0 END) AS cancelled
+            FROM event_registrations
+            WHERE event_id IN ("#
+        );
+        let mut separated = query_builder.separated(", ");
+        for event_id in event_ids {
+            separated.push_bind(event_id.to_string());
         }
+        query_builder.push(") GROUP BY event_id");
+
+        let rows = query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::business_rule(&format!("Failed to count registrations by event: {}", e)))?;
+
+        rows.iter()
+            .map(|row| {
+                let event_id: String = row.get("event_id");
+                let event_id = Uuid::parse_str(&event_id)
+                    .map_err(|e| DomainError::business_rule(&format!("Invalid UUID format for event ID: {}", e)))?;
+                Ok(RegistrationCounts {
+                    event_id,
+                    registered: row.get("registered"),
+                    waitlisted: row.get("waitlisted"),
+                    attended: row.get("attended"),
+                    cancelled: row.get("cancelled"),
+                })
+            })
+            .collect()
     }
 
-    async fn update(&self, registration: &EventRegistration) -> DomainResult<()> {
-        let guest_names_json = serde_json::to_string(&registration.guest_names)
-            .map_err(|e| DomainError::business_rule(&format!("Failed to serialize guest names: {}", e)))?;
+    async fn create(&self, registration: &EventRegistration) -> DomainResult<()> {
+        self.insert(registration, None).await.map(|_| ())
+    }
+
+    async fn create_within_capacity(&self, registration: &EventRegistration, capacity: i32) -> DomainResult<bool> {
+        self.insert(registration, Some(capacity)).await
+    }
 
+    async fn update(&self, registration: &EventRegistration) -> DomainResult<()> {
         // Convert values to proper types and create owned strings for lifetimes
         let id_str = registration.id.to_string();
         let invitation_id_str = registration.invitation_id.as_ref().map(|id| id.to_string());
@@ -537,50 +619,63 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
         let checked_in_at_naive = registration.checked_in_at.map(|dt| dt.naive_utc());
         let waitlist_position_i64 = registration.waitlist_position.map(|pos| pos as i64);
         let waitlist_added_at_naive = registration.waitlist_added_at.map(|dt| dt.naive_utc());
+        let promotion_expires_at_naive = registration.promotion_expires_at.map(|dt| dt.naive_utc());
         let updated_at_naive = registration.updated_at.naive_utc();
 
-        let result = sqlx::query!(
-            r#"
-            UPDATE event_registrations SET
-                invitation_id = ?, user_id = ?, external_contact_id = ?,
-                registrant_email = ?, registrant_name = ?, registrant_phone = ?, registrant_company = ?,
-                status = ?, registration_source = ?,
-                guest_count = ?, guest_names = ?,
-                dietary_restrictions = ?, accessibility_needs = ?, special_requests = ?, custom_responses = ?,
-                registered_at = ?, cancelled_at = ?, checked_in_at = ?,
-                waitlist_position = ?, waitlist_added_at = ?,
-                updated_at = ?
-            WHERE id = ?
-            "#,
-            invitation_id_str,
-            user_id_str,
-            external_contact_id_str,
-            registration.registrant_email,
-            registration.registrant_name,
-            registration.registrant_phone,
-            registration.registrant_company,
-            status_str,
-            source_str,
-            guest_count_i64,
-            guest_names_json,
-            registration.dietary_restrictions,
-            registration.accessibility_needs,
-            registration.special_requests,
-            registration.custom_responses,
-            registered_at_naive,
-            cancelled_at_naive,
-            checked_in_at_naive,
-            waitlist_position_i64,
-            waitlist_added_at_naive,
-            updated_at_naive,
-            id_str,
-        )
-        .execute(&self.pool)
+        // The registration and its guests are written together
+        let result = async move {
+            let mut tx = self.pool.begin().await?;
+            let updated = sqlx::query!(
+                r#"
+                UPDATE event_registrations SET
+                    invitation_id = ?, user_id = ?, external_contact_id = ?,
+                    registrant_email = ?, registrant_name = ?, registrant_phone = ?, registrant_company = ?,
+                    status = ?, registration_source = ?,
+                    guest_count = ?,
+                    dietary_restrictions = ?, accessibility_needs = ?, special_requests = ?, custom_responses = ?,
+                    registered_at = ?, cancelled_at = ?, checked_in_at = ?,
+                    waitlist_position = ?, waitlist_added_at = ?, promotion_expires_at = ?,
+                    updated_at = ?
+                WHERE id = ?
+                "#,
+                invitation_id_str,
+                user_id_str,
+                external_contact_id_str,
+                registration.registrant_email,
+                registration.registrant_name,
+                registration.registrant_phone,
+                registration.registrant_company,
+                status_str,
+                source_str,
+                guest_count_i64,
+                registration.dietary_restrictions,
+                registration.accessibility_needs,
+                registration.special_requests,
+                registration.custom_responses,
+                registered_at_naive,
+                cancelled_at_naive,
+                checked_in_at_naive,
+                waitlist_position_i64,
+                waitlist_added_at_naive,
+                promotion_expires_at_naive,
+                updated_at_naive,
+                id_str,
+            )
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+            // Nothing to attach the guests to if the registration is gone
+            if updated > 0 {
+                Self::replace_guests(&mut tx, registration).await?;
+                tx.commit().await?;
+            }
+            Ok::<_, sqlx::Error>(updated)
+        }
         .await;
 
         match result {
-            Ok(rows_affected) => {
-                if rows_affected.rows_affected() == 0 {
+            Ok(updated) => {
+                if updated == 0 {
                     Err(DomainError::not_found("EventRegistration", registration.id))
                 } else {
                     Ok(())
@@ -617,4 +712,178 @@ impl EventRegistrationRepository for SqliteEventRegistrationRepository {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    async fn insert_registration(db: &TestDb, event_id: Uuid, status: &str) {
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO event_registrations (id, event_id, registrant_email, registrant_name, status) VALUES (?, ?, ?, ?, ?)")
+            .bind(id.to_string())
+            .bind(event_id.to_string())
+            .bind(format!("{}@example.com", id))
+            .bind("Test Registrant")
+            .bind(status)
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    fn guest_registration(event_id: Uuid) -> EventRegistration {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        EventRegistration {
+            id,
+            event_id,
+            invitation_id: None,
+            user_id: None,
+            external_contact_id: None,
+            registrant_email: Some(format!("{}@example.com", id)),
+            registrant_name: Some("Test Registrant".to_string()),
+            registrant_phone: None,
+            registrant_company: None,
+            status: RegistrationStatus::Registered,
+            registration_source: RegistrationSource::Direct,
+            guest_count: 0,
+            guest_names: Vec::new(),
+            dietary_restrictions: None,
+            accessibility_needs: None,
+            special_requests: None,
+            custom_responses: None,
+            registered_at: now,
+            cancelled_at: None,
+            checked_in_at: None,
+            waitlist_position: None,
+            waitlist_added_at: None,
+            promotion_expires_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_within_capacity_counts_seats() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventRegistrationRepository::new(db.pool().clone());
+        let organizer_id = db.seed_user().await;
+        let event_id = db.seed_event(organizer_id).await;
+
+        // Waitlisted and cancelled registrations don't hold a seat
+        for status in ["attended", "waitlisted", "cancelled"] {
+            insert_registration(&db, event_id, status).await;
+        }
+
+        let second = guest_registration(event_id);
+        assert!(repository.create_within_capacity(&second, 2).await.unwrap());
+        assert!(repository.find_by_id(second.id).await.unwrap().is_some());
+
+        let third = guest_registration(event_id);
+        assert!(!repository.create_within_capacity(&third, 2).await.unwrap());
+        assert!(repository.find_by_id(third.id).await.unwrap().is_none());
+
+        // Unknown events still surface the foreign key error
+        assert!(repository.create_within_capacity(&guest_registration(Uuid::new_v4()), 2).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_guest_names_round_trip_in_order() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventRegistrationRepository::new(db.pool().clone());
+        let organizer_id = db.seed_user().await;
+        let event_id = db.seed_event(organizer_id).await;
+
+        let mut registration = guest_registration(event_id);
+        registration.guest_count = 2;
+        registration.guest_names = vec!["Per".to_string(), "Anne".to_string()];
+        repository.create(&registration).await.unwrap();
+        let found = repository.find_by_id(registration.id).await.unwrap().unwrap();
+        assert_eq!(found.guest_names, vec!["Per", "Anne"]);
+
+        registration.guest_count = 1;
+        registration.guest_names = vec!["Anne".to_string()];
+        repository.update(&registration).await.unwrap();
+        let found = repository.find_by_event_id(event_id).await.unwrap();
+        assert_eq!(found[0].guest_names, vec!["Anne"]);
+
+        // Guests go with the registration
+        repository.delete(registration.id).await.unwrap();
+        let guests: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM registration_guests")
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+        assert_eq!(guests, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn test_create_within_capacity_does_not_overbook_under_load() {
+        const CAPACITY: i32 = 10;
+        const ATTEMPTS: usize = 64;
+
+        // File-backed so the pool hands out separate connections that race
+        let db = TestDb::temp_file().await;
+        let repository = SqliteEventRegistrationRepository::new(db.pool().clone());
+        let organizer_id = db.seed_user().await;
+        let event_id = db.seed_event(organizer_id).await;
+
+        let attempts: Vec<_> = (0..ATTEMPTS)
+            .map(|_| {
+                let repository = repository.clone();
+                tokio::spawn(async move {
+                    repository
+                        .create_within_capacity(&guest_registration(event_id), CAPACITY)
+                        .await
+                        .unwrap()
+                })
+            })
+            .collect();
+
+        let mut accepted = 0;
+        for attempt in attempts {
+            if attempt.await.unwrap() {
+                accepted += 1;
+            }
+        }
+
+        let counts = repository.count_by_event_ids(&[event_id]).await.unwrap();
+        assert_eq!(accepted, CAPACITY);
+        assert_eq!(counts[0].registered, CAPACITY as i64);
+    }
+
+    #[tokio::test]
+    async fn test_count_by_event_ids() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteEventRegistrationRepository::new(db.pool().clone());
+        let organizer_id = db.seed_user().await;
+        let busy_event = db.seed_event(organizer_id).await;
+        let quiet_event = db.seed_event(organizer_id).await;
+        let empty_event = db.seed_event(organizer_id).await;
+
+        for status in ["registered", "registered", "waitlisted", "attended", "cancelled"] {
+            insert_registration(&db, busy_event, status).await;
+        }
+        insert_registration(&db, quiet_event, "registered").await;
+
+        let counts = repository
+            .count_by_event_ids(&[busy_event, quiet_event, empty_event])
+            .await
+            .unwrap();
+
+        // Events without registrations are omitted
+        assert_eq!(counts.len(), 2);
+
+        let busy = counts.iter().find(|c| c.event_id == busy_event).unwrap();
+        assert_eq!(busy.registered, 2);
+        assert_eq!(busy.waitlisted, 1);
+        assert_eq!(busy.attended, 1);
+        assert_eq!(busy.cancelled, 1);
+        assert_eq!(busy.confirmed(), 3);
+
+        let quiet = counts.iter().find(|c| c.event_id == quiet_event).unwrap();
+        assert_eq!(quiet.registered, 1);
+
+        assert!(repository.count_by_event_ids(&[]).await.unwrap().is_empty());
+    }
+}
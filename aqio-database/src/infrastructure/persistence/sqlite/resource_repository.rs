@@ -0,0 +1,379 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::ResourceRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{AvailabilityWindow, DomainError, DomainResult, Resource, ResourceBooking};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const RESOURCE_COLUMNS: &str = "id, name, description, location, is_active, created_by, created_at, updated_at";
+const BOOKING_COLUMNS: &str = "id, resource_id, event_id, starts_at, ends_at, booked_by, created_at";
+
+#[derive(Clone)]
+pub struct SqliteResourceRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteResourceRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to Resource using SafeRowGet
+    fn row_to_resource(row: &sqlx::sqlite::SqliteRow) -> Result<Resource, RowConversionError> {
+        Ok(Resource {
+            id: row.get_uuid("id")?,
+            name: row.get_string("name")?,
+            description: row.get_optional_string("description")?,
+            location: row.get_optional_string("location")?,
+            is_active: row.get_bool("is_active")?,
+            created_by: row.get_uuid("created_by")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn row_to_window(row: &sqlx::sqlite::SqliteRow) -> Result<AvailabilityWindow, RowConversionError> {
+        Ok(AvailabilityWindow {
+            starts_at: row.get_datetime("starts_at")?,
+            ends_at: row.get_datetime("ends_at")?,
+        })
+    }
+
+    fn row_to_booking(row: &sqlx::sqlite::SqliteRow) -> Result<ResourceBooking, RowConversionError> {
+        Ok(ResourceBooking {
+            id: row.get_uuid("id")?,
+            resource_id: row.get_uuid("resource_id")?,
+            event_id: row.get_uuid("event_id")?,
+            starts_at: row.get_datetime("starts_at")?,
+            ends_at: row.get_datetime("ends_at")?,
+            booked_by: row.get_uuid("booked_by")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+
+    fn rows_to_bookings(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<ResourceBooking>> {
+        rows.iter()
+            .map(|row| Self::row_to_booking(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ResourceRepository for SqliteResourceRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<Resource>> {
+        debug!("Finding resource by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM resources WHERE id = ?", RESOURCE_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_resource(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn list(&self, include_inactive: bool) -> DomainResult<Vec<Resource>> {
+        debug!("Listing resources (include inactive: {})", include_inactive);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM resources WHERE is_active = 1 OR ? ORDER BY name COLLATE NOCASE",
+            RESOURCE_COLUMNS
+        ))
+        .bind(include_inactive)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let resources: Result<Vec<Resource>, RowConversionError> = rows.iter().map(Self::row_to_resource).collect();
+        let resources = resources.map_err(InfrastructureError::from)?;
+        Ok(resources)
+    }
+
+    #[instrument(skip(self, resource))]
+    async fn create(&self, resource: &Resource) -> DomainResult<()> {
+        debug!("Creating resource: {}", resource.name);
+
+        sqlx::query(&format!(
+            "INSERT INTO resources ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            RESOURCE_COLUMNS
+        ))
+        .bind(resource.id.to_string())
+        .bind(&resource.name)
+        .bind(resource.description.as_deref())
+        .bind(resource.location.as_deref())
+        .bind(resource.is_active)
+        .bind(resource.created_by.to_string())
+        .bind(resource.created_at.naive_utc())
+        .bind(resource.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, resource))]
+    async fn update(&self, resource: &Resource) -> DomainResult<()> {
+        debug!("Updating resource: {}", resource.id);
+
+        let result = sqlx::query(
+            "UPDATE resources SET name = ?, description = ?, location = ?, is_active = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(&resource.name)
+        .bind(resource.description.as_deref())
+        .bind(resource.location.as_deref())
+        .bind(resource.is_active)
+        .bind(resource.updated_at.naive_utc())
+        .bind(resource.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("Resource", resource.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_windows(&self, resource_id: Uuid) -> DomainResult<Vec<AvailabilityWindow>> {
+        debug!("Finding availability of resource: {}", resource_id);
+
+        let rows = sqlx::query(
+            "SELECT starts_at, ends_at FROM resource_availability WHERE resource_id = ? ORDER BY starts_at, ends_at",
+        )
+        .bind(resource_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let windows: Result<Vec<AvailabilityWindow>, RowConversionError> = rows.iter().map(Self::row_to_window).collect();
+        let windows = windows.map_err(InfrastructureError::from)?;
+        Ok(windows)
+    }
+
+    #[instrument(skip(self, windows))]
+    async fn replace_windows(&self, resource_id: Uuid, windows: &[AvailabilityWindow]) -> DomainResult<()> {
+        debug!("Replacing {} availability windows of resource: {}", windows.len(), resource_id);
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        sqlx::query("DELETE FROM resource_availability WHERE resource_id = ?")
+            .bind(resource_id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+        for window in windows {
+            sqlx::query("INSERT INTO resource_availability (resource_id, starts_at, ends_at) VALUES (?, ?, ?)")
+                .bind(resource_id.to_string())
+                .bind(window.starts_at.naive_utc())
+                .bind(window.ends_at.naive_utc())
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_booking(&self, id: Uuid) -> DomainResult<Option<ResourceBooking>> {
+        debug!("Finding resource booking by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM resource_bookings WHERE id = ?", BOOKING_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_booking(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self, resource_ids))]
+    async fn find_overlapping_bookings(
+        &self,
+        resource_ids: &[Uuid],
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> DomainResult<Vec<ResourceBooking>> {
+        debug!("Finding bookings of {} resources between {} and {}", resource_ids.len(), starts_at, ends_at);
+
+        if resource_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; resource_ids.len()].join(", ");
+        let sql = format!(
+            "SELECT {} FROM resource_bookings
+             WHERE resource_id IN ({}) AND starts_at < ? AND ends_at > ?
+             ORDER BY starts_at, created_at",
+            BOOKING_COLUMNS, placeholders
+        );
+        let mut query = sqlx::query(&sql);
+        for id in resource_ids {
+            query = query.bind(id.to_string());
+        }
+        let rows = query
+            .bind(ends_at.naive_utc())
+            .bind(starts_at.naive_utc())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_bookings(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_bookings_by_event(&self, event_id: Uuid) -> DomainResult<Vec<ResourceBooking>> {
+        debug!("Finding resource bookings of event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM resource_bookings WHERE event_id = ? ORDER BY starts_at, created_at",
+            BOOKING_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_bookings(&rows)
+    }
+
+    #[instrument(skip(self, bookings))]
+    async fn create_bookings_if_free(&self, bookings: &[ResourceBooking]) -> DomainResult<bool> {
+        debug!("Booking {} resources", bookings.len());
+
+        // Each insert only happens when its resource has no overlapping
+        // booking, including the ones inserted earlier in this transaction.
+        // Dropping the transaction without committing rolls all of them back.
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        for booking in bookings {
+            let result = sqlx::query(&format!(
+                "INSERT INTO resource_bookings ({})
+                 SELECT ?, ?, ?, ?, ?, ?, ?
+                 WHERE NOT EXISTS (
+                     SELECT 1 FROM resource_bookings WHERE resource_id = ? AND starts_at < ? AND ends_at > ?
+                 )",
+                BOOKING_COLUMNS
+            ))
+            .bind(booking.id.to_string())
+            .bind(booking.resource_id.to_string())
+            .bind(booking.event_id.to_string())
+            .bind(booking.starts_at.naive_utc())
+            .bind(booking.ends_at.naive_utc())
+            .bind(booking.booked_by.to_string())
+            .bind(booking.created_at.naive_utc())
+            .bind(booking.resource_id.to_string())
+            .bind(booking.ends_at.naive_utc())
+            .bind(booking.starts_at.naive_utc())
+            .execute(&mut *tx)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+            if result.rows_affected() == 0 {
+                debug!("Resource {} is already booked", booking.resource_id);
+                return Ok(false);
+            }
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(true)
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_booking(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting resource booking with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM resource_bookings WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("ResourceBooking", id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use chrono::{Duration, TimeZone};
+
+    #[tokio::test]
+    async fn test_bookings_are_all_or_nothing_and_never_overlap() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteResourceRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+
+        let boat = Resource::new("RIB boat", organizer);
+        let projector = Resource {
+            location: Some("Storage room B".to_string()),
+            ..Resource::new("Projector", organizer)
+        };
+        repository.create(&boat).await.unwrap();
+        repository.create(&projector).await.unwrap();
+        // Names are unique, whatever the case
+        assert!(repository.create(&Resource::new("rib boat", organizer)).await.is_err());
+
+        let at = |hour| Utc.with_ymd_and_hms(2026, 6, 1, hour, 0, 0).unwrap();
+        let window = AvailabilityWindow { starts_at: at(8), ends_at: at(20) };
+        repository.replace_windows(boat.id, &[window]).await.unwrap();
+        assert_eq!(repository.find_windows(boat.id).await.unwrap(), vec![window]);
+
+        let morning = ResourceBooking::new(boat.id, event_id, at(9), at(12), organizer);
+        assert!(repository.create_bookings_if_free(std::slice::from_ref(&morning)).await.unwrap());
+
+        // The boat is taken, so the projector isn't booked either
+        let overlapping = [
+            ResourceBooking::new(projector.id, event_id, at(11), at(13), organizer),
+            ResourceBooking::new(boat.id, event_id, at(11), at(13), organizer),
+        ];
+        assert!(!repository.create_bookings_if_free(&overlapping).await.unwrap());
+        assert!(repository
+            .find_overlapping_bookings(&[projector.id], at(0), at(23))
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Back to back is fine
+        let afternoon = ResourceBooking::new(boat.id, event_id, at(12), at(15), organizer);
+        assert!(repository.create_bookings_if_free(std::slice::from_ref(&afternoon)).await.unwrap());
+        let found = repository.find_overlapping_bookings(&[boat.id, projector.id], at(11), at(13)).await.unwrap();
+        assert_eq!(found.iter().map(|b| b.id).collect::<Vec<_>>(), vec![morning.id, afternoon.id]);
+        assert_eq!(repository.find_bookings_by_event(event_id).await.unwrap().len(), 2);
+
+        repository.delete_booking(morning.id).await.unwrap();
+        assert!(repository.find_booking(morning.id).await.unwrap().is_none());
+        assert!(matches!(
+            repository.delete_booking(morning.id).await,
+            Err(DomainError::NotFound { .. })
+        ));
+
+        let retired = Resource {
+            is_active: false,
+            updated_at: Utc::now() + Duration::seconds(1),
+            ..boat
+        };
+        repository.update(&retired).await.unwrap();
+        assert_eq!(repository.list(false).await.unwrap().len(), 1);
+        assert_eq!(repository.list(true).await.unwrap().len(), 2);
+    }
+}
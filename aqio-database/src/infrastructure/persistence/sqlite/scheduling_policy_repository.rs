@@ -0,0 +1,317 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::SchedulingPolicyRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{BlackoutPeriod, DomainResult, RuleEnforcement, SchedulingPolicy};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const POLICY_COLUMNS: &str = "company_id, max_events_per_day, allow_weekends, min_notice_days, enforcement, updated_at";
+
+const BLACKOUT_COLUMNS: &str = "id, company_id, name, start_date, end_date, enforcement, created_by, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteSchedulingPolicyRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteSchedulingPolicyRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to SchedulingPolicy using SafeRowGet
+    fn row_to_policy(row: &sqlx::sqlite::SqliteRow) -> Result<SchedulingPolicy, RowConversionError> {
+        Ok(SchedulingPolicy {
+            company_id: row.get_uuid("company_id")?,
+            max_events_per_day: row.get_optional_i32("max_events_per_day")?,
+            allow_weekends: row.get_bool("allow_weekends")?,
+            min_notice_days: row.get_optional_i32("min_notice_days")?,
+            enforcement: row.get_rule_enforcement("enforcement")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    // Helper method to convert database row to BlackoutPeriod using SafeRowGet
+    fn row_to_blackout(row: &sqlx::sqlite::SqliteRow) -> Result<BlackoutPeriod, RowConversionError> {
+        Ok(BlackoutPeriod {
+            id: row.get_uuid("id")?,
+            company_id: row.get_uuid("company_id")?,
+            name: row.get_string("name")?,
+            start_date: row.get_date("start_date")?,
+            end_date: row.get_date("end_date")?,
+            enforcement: row.get_rule_enforcement("enforcement")?,
+            created_by: row.get_uuid("created_by")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn enforcement_to_string(enforcement: RuleEnforcement) -> &'static str {
+        match enforcement {
+            RuleEnforcement::Warn => "warn",
+            RuleEnforcement::Block => "block",
+        }
+    }
+
+    fn rows_to_blackouts(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<BlackoutPeriod>> {
+        let blackouts: Result<Vec<BlackoutPeriod>, RowConversionError> = rows.iter()
+            .map(Self::row_to_blackout)
+            .collect();
+        Ok(blackouts.map_err(InfrastructureError::from)?)
+    }
+}
+
+#[async_trait]
+impl SchedulingPolicyRepository for SqliteSchedulingPolicyRepository {
+    #[instrument(skip(self))]
+    async fn find_policy(&self, company_id: Uuid) -> DomainResult<Option<SchedulingPolicy>> {
+        debug!("Finding scheduling policy for company: {}", company_id);
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM company_scheduling_policies WHERE company_id = ?",
+            POLICY_COLUMNS
+        ))
+        .bind(company_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_policy(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self, policy))]
+    async fn save_policy(&self, policy: &SchedulingPolicy) -> DomainResult<()> {
+        debug!("Saving scheduling policy for company: {}", policy.company_id);
+
+        sqlx::query(
+            "INSERT INTO company_scheduling_policies (company_id, max_events_per_day, allow_weekends, min_notice_days, enforcement, updated_at) VALUES (?, ?, ?, ?, ?, ?)
+             ON CONFLICT (company_id) DO UPDATE SET max_events_per_day = excluded.max_events_per_day, allow_weekends = excluded.allow_weekends, min_notice_days = excluded.min_notice_days, enforcement = excluded.enforcement, updated_at = excluded.updated_at"
+        )
+        .bind(policy.company_id.to_string())
+        .bind(policy.max_events_per_day)
+        .bind(policy.allow_weekends)
+        .bind(policy.min_notice_days)
+        .bind(Self::enforcement_to_string(policy.enforcement))
+        .bind(policy.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_blackout_by_id(&self, id: Uuid) -> DomainResult<Option<BlackoutPeriod>> {
+        debug!("Finding blackout period by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM company_blackout_periods WHERE id = ?", BLACKOUT_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_blackout(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_blackouts_by_company(&self, company_id: Uuid) -> DomainResult<Vec<BlackoutPeriod>> {
+        debug!("Listing blackout periods for company: {}", company_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM company_blackout_periods WHERE company_id = ? ORDER BY start_date, end_date, name COLLATE NOCASE",
+            BLACKOUT_COLUMNS
+        ))
+        .bind(company_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let blackouts = Self::rows_to_blackouts(&rows)?;
+        debug!("Listed {} blackout periods for company {}", blackouts.len(), company_id);
+        Ok(blackouts)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_blackouts_overlapping(
+        &self,
+        company_id: Uuid,
+        first_day: NaiveDate,
+        last_day: NaiveDate,
+    ) -> DomainResult<Vec<BlackoutPeriod>> {
+        debug!("Finding blackout periods for company {} between {} and {}", company_id, first_day, last_day);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM company_blackout_periods WHERE company_id = ? AND start_date <= ? AND end_date >= ? ORDER BY start_date, end_date, name COLLATE NOCASE",
+            BLACKOUT_COLUMNS
+        ))
+        .bind(company_id.to_string())
+        .bind(last_day)
+        .bind(first_day)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_blackouts(&rows)
+    }
+
+    #[instrument(skip(self, blackout))]
+    async fn create_blackout(&self, blackout: &BlackoutPeriod) -> DomainResult<()> {
+        debug!("Creating blackout period {} for company {}", blackout.id, blackout.company_id);
+
+        sqlx::query(
+            "INSERT INTO company_blackout_periods (id, company_id, name, start_date, end_date, enforcement, created_by, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(blackout.id.to_string())
+        .bind(blackout.company_id.to_string())
+        .bind(&blackout.name)
+        .bind(blackout.start_date)
+        .bind(blackout.end_date)
+        .bind(Self::enforcement_to_string(blackout.enforcement))
+        .bind(blackout.created_by.to_string())
+        .bind(blackout.created_at.naive_utc())
+        .bind(blackout.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        debug!("Successfully created blackout period with id: {}", blackout.id);
+        Ok(())
+    }
+
+    #[instrument(skip(self, blackout))]
+    async fn update_blackout(&self, blackout: &BlackoutPeriod) -> DomainResult<()> {
+        debug!("Updating blackout period: {}", blackout.id);
+
+        let result = sqlx::query(
+            "UPDATE company_blackout_periods SET name = ?, start_date = ?, end_date = ?, enforcement = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(&blackout.name)
+        .bind(blackout.start_date)
+        .bind(blackout.end_date)
+        .bind(Self::enforcement_to_string(blackout.enforcement))
+        .bind(blackout.updated_at.naive_utc())
+        .bind(blackout.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("BlackoutPeriod", blackout.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_blackout(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting blackout period with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM company_blackout_periods WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("BlackoutPeriod", id));
+        }
+        debug!("Successfully deleted blackout period with id: {}", id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 5, d).unwrap()
+    }
+
+    async fn seed_company(db: &TestDb) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO companies (id, name, industry_type) VALUES (?, ?, 'Salmon')")
+            .bind(id.to_string())
+            .bind(format!("Company {}", id))
+            .execute(db.pool())
+            .await
+            .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn test_policy_is_created_then_replaced() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteSchedulingPolicyRepository::new(db.pool().clone());
+        let company = seed_company(&db).await;
+
+        assert!(repository.find_policy(company).await.unwrap().is_none());
+
+        let mut policy = SchedulingPolicy::new(company);
+        policy.max_events_per_day = Some(2);
+        repository.save_policy(&policy).await.unwrap();
+
+        policy.allow_weekends = false;
+        policy.min_notice_days = Some(14);
+        policy.enforcement = RuleEnforcement::Block;
+        repository.save_policy(&policy).await.unwrap();
+
+        let found = repository.find_policy(company).await.unwrap().unwrap();
+        assert_eq!(found.max_events_per_day, Some(2));
+        assert!(!found.allow_weekends);
+        assert_eq!(found.min_notice_days, Some(14));
+        assert_eq!(found.enforcement, RuleEnforcement::Block);
+    }
+
+    #[tokio::test]
+    async fn test_blackouts_round_trip_and_overlap() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteSchedulingPolicyRepository::new(db.pool().clone());
+        let company = seed_company(&db).await;
+        let other_company = seed_company(&db).await;
+        let user = db.seed_user().await;
+
+        let constitution_day = BlackoutPeriod::new(company, "Constitution Day", day(17), day(17), RuleEnforcement::Block, user);
+        let mut fair = BlackoutPeriod::new(company, "Nor-Fishing", day(12), day(15), RuleEnforcement::Warn, user);
+        for blackout in [&constitution_day, &fair] {
+            repository.create_blackout(blackout).await.unwrap();
+        }
+        repository
+            .create_blackout(&BlackoutPeriod::new(other_company, "Elsewhere", day(1), day(31), RuleEnforcement::Block, user))
+            .await
+            .unwrap();
+
+        let listed = repository.find_blackouts_by_company(company).await.unwrap();
+        assert_eq!(listed.iter().map(|b| b.id).collect::<Vec<_>>(), vec![fair.id, constitution_day.id]);
+        assert_eq!(listed[0].start_date, day(12));
+        assert_eq!(listed[0].end_date, day(15));
+
+        // Touching either end of a period counts as overlapping
+        let overlapping = repository.find_blackouts_overlapping(company, day(15), day(17)).await.unwrap();
+        assert_eq!(overlapping.len(), 2);
+        let overlapping = repository.find_blackouts_overlapping(company, day(16), day(16)).await.unwrap();
+        assert!(overlapping.is_empty());
+
+        fair.end_date = day(16);
+        fair.enforcement = RuleEnforcement::Block;
+        repository.update_blackout(&fair).await.unwrap();
+        let found = repository.find_blackout_by_id(fair.id).await.unwrap().unwrap();
+        assert_eq!(found.end_date, day(16));
+        assert_eq!(found.enforcement, RuleEnforcement::Block);
+
+        repository.delete_blackout(constitution_day.id).await.unwrap();
+        assert!(repository.find_blackout_by_id(constitution_day.id).await.unwrap().is_none());
+        assert!(repository.delete_blackout(constitution_day.id).await.is_err());
+    }
+}
@@ -0,0 +1,258 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::SeatingRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainError, DomainResult, SeatAssignment, SeatingTable};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const TABLE_COLUMNS: &str = "id, event_id, name, capacity, accessible, position, created_at, updated_at";
+const ASSIGNMENT_COLUMNS: &str = "registration_id, table_id, event_id, assigned_at";
+
+#[derive(Clone)]
+pub struct SqliteSeatingRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteSeatingRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to SeatingTable using SafeRowGet
+    fn row_to_table(row: &sqlx::sqlite::SqliteRow) -> Result<SeatingTable, RowConversionError> {
+        Ok(SeatingTable {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            name: row.get_string("name")?,
+            capacity: row.get_i32("capacity")?,
+            accessible: row.get_bool("accessible")?,
+            position: row.get_i32("position")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn row_to_assignment(row: &sqlx::sqlite::SqliteRow) -> Result<SeatAssignment, RowConversionError> {
+        Ok(SeatAssignment {
+            registration_id: row.get_uuid("registration_id")?,
+            table_id: row.get_uuid("table_id")?,
+            event_id: row.get_uuid("event_id")?,
+            assigned_at: row.get_datetime("assigned_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl SeatingRepository for SqliteSeatingRepository {
+    #[instrument(skip(self))]
+    async fn find_table(&self, id: Uuid) -> DomainResult<Option<SeatingTable>> {
+        debug!("Finding seating table by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM seating_tables WHERE id = ?", TABLE_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_table(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_tables(&self, event_id: Uuid) -> DomainResult<Vec<SeatingTable>> {
+        debug!("Listing seating tables for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM seating_tables WHERE event_id = ? ORDER BY position, name COLLATE NOCASE",
+            TABLE_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let tables: Result<Vec<SeatingTable>, RowConversionError> = rows.iter().map(Self::row_to_table).collect();
+        let tables = tables.map_err(InfrastructureError::from)?;
+        Ok(tables)
+    }
+
+    #[instrument(skip(self, table))]
+    async fn create_table(&self, table: &SeatingTable) -> DomainResult<()> {
+        debug!("Creating seating table {} on event {}", table.id, table.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO seating_tables ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            TABLE_COLUMNS
+        ))
+        .bind(table.id.to_string())
+        .bind(table.event_id.to_string())
+        .bind(&table.name)
+        .bind(table.capacity)
+        .bind(table.accessible)
+        .bind(table.position)
+        .bind(table.created_at.naive_utc())
+        .bind(table.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, table))]
+    async fn update_table(&self, table: &SeatingTable) -> DomainResult<()> {
+        debug!("Updating seating table: {}", table.id);
+
+        let result = sqlx::query(
+            "UPDATE seating_tables SET name = ?, capacity = ?, accessible = ?, position = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(&table.name)
+        .bind(table.capacity)
+        .bind(table.accessible)
+        .bind(table.position)
+        .bind(table.updated_at.naive_utc())
+        .bind(table.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("SeatingTable", table.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_table(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting seating table with id: {}", id);
+
+        // Assignments go with the table (ON DELETE CASCADE)
+        let result = sqlx::query("DELETE FROM seating_tables WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("SeatingTable", id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_assignments(&self, event_id: Uuid) -> DomainResult<Vec<SeatAssignment>> {
+        debug!("Listing seat assignments for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM seat_assignments WHERE event_id = ? ORDER BY assigned_at",
+            ASSIGNMENT_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let assignments: Result<Vec<SeatAssignment>, RowConversionError> =
+            rows.iter().map(Self::row_to_assignment).collect();
+        let assignments = assignments.map_err(InfrastructureError::from)?;
+        Ok(assignments)
+    }
+
+    #[instrument(skip(self, assignment))]
+    async fn assign(&self, assignment: &SeatAssignment) -> DomainResult<()> {
+        debug!("Seating registration {} at table {}", assignment.registration_id, assignment.table_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO seat_assignments ({}) VALUES (?, ?, ?, ?)
+             ON CONFLICT (registration_id) DO UPDATE SET table_id = excluded.table_id, assigned_at = excluded.assigned_at",
+            ASSIGNMENT_COLUMNS
+        ))
+        .bind(assignment.registration_id.to_string())
+        .bind(assignment.table_id.to_string())
+        .bind(assignment.event_id.to_string())
+        .bind(assignment.assigned_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn unassign(&self, registration_id: Uuid) -> DomainResult<()> {
+        debug!("Unseating registration: {}", registration_id);
+
+        sqlx::query("DELETE FROM seat_assignments WHERE registration_id = ?")
+            .bind(registration_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_registrations_move_between_tables_and_leave_with_them() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteSeatingRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+        let registration_id = Uuid::new_v4();
+        db.execute(&format!(
+            "INSERT INTO event_registrations (id, event_id, user_id, registration_source) VALUES ('{}', '{}', '{}', 'direct')",
+            registration_id, event_id, organizer
+        ))
+        .await;
+
+        let head = SeatingTable {
+            accessible: true,
+            ..SeatingTable::new(event_id, "Head table", 10, 0)
+        };
+        let second = SeatingTable::new(event_id, "Table 2", 8, 1);
+        repository.create_table(&second).await.unwrap();
+        repository.create_table(&head).await.unwrap();
+        // Table names are unique within the event
+        assert!(repository.create_table(&SeatingTable::new(event_id, "Table 2", 6, 2)).await.is_err());
+        let tables = repository.find_tables(event_id).await.unwrap();
+        assert_eq!(tables.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(), vec!["Head table", "Table 2"]);
+        assert!(tables[0].accessible);
+
+        let seat = |table_id| SeatAssignment {
+            registration_id,
+            table_id,
+            event_id,
+            assigned_at: Utc::now(),
+        };
+        repository.assign(&seat(head.id)).await.unwrap();
+        repository.assign(&seat(second.id)).await.unwrap();
+        let assignments = repository.find_assignments(event_id).await.unwrap();
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(assignments[0].table_id, second.id);
+
+        // Deleting the table unseats the registration
+        repository.delete_table(second.id).await.unwrap();
+        assert!(repository.find_assignments(event_id).await.unwrap().is_empty());
+        assert!(matches!(
+            repository.delete_table(second.id).await,
+            Err(DomainError::NotFound { .. })
+        ));
+
+        repository.assign(&seat(head.id)).await.unwrap();
+        repository.unassign(registration_id).await.unwrap();
+        repository.unassign(registration_id).await.unwrap();
+        assert!(repository.find_assignments(event_id).await.unwrap().is_empty());
+    }
+}
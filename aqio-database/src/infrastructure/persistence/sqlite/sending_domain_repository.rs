@@ -0,0 +1,174 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::SendingDomainRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, DomainVerificationStatus, SendingDomain};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const SENDING_DOMAIN_COLUMNS: &str = "company_id, domain, from_email, from_name, dkim_selector, verification_token, status, ownership_verified, spf_verified, dkim_verified, last_checked_at, verified_at, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteSendingDomainRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteSendingDomainRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to SendingDomain using SafeRowGet
+    fn row_to_sending_domain(row: &sqlx::sqlite::SqliteRow) -> Result<SendingDomain, RowConversionError> {
+        Ok(SendingDomain {
+            company_id: row.get_uuid("company_id")?,
+            domain: row.get_string("domain")?,
+            from_email: row.get_string("from_email")?,
+            from_name: row.get_optional_string("from_name")?,
+            dkim_selector: row.get_string("dkim_selector")?,
+            verification_token: row.get_string("verification_token")?,
+            status: row.get_domain_verification_status("status")?,
+            ownership_verified: row.get_bool("ownership_verified")?,
+            spf_verified: row.get_bool("spf_verified")?,
+            dkim_verified: row.get_bool("dkim_verified")?,
+            last_checked_at: row.get_optional_datetime("last_checked_at")?,
+            verified_at: row.get_optional_datetime("verified_at")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn status_to_string(status: DomainVerificationStatus) -> &'static str {
+        match status {
+            DomainVerificationStatus::Pending => "pending",
+            DomainVerificationStatus::Verified => "verified",
+            DomainVerificationStatus::Failed => "failed",
+        }
+    }
+}
+
+#[async_trait]
+impl SendingDomainRepository for SqliteSendingDomainRepository {
+    #[instrument(skip(self))]
+    async fn find_by_company(&self, company_id: Uuid) -> DomainResult<Option<SendingDomain>> {
+        debug!("Finding sending domain for company: {}", company_id);
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM company_sending_domains WHERE company_id = ?",
+            SENDING_DOMAIN_COLUMNS
+        ))
+        .bind(company_id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_sending_domain(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self, sending_domain))]
+    async fn save(&self, sending_domain: &SendingDomain) -> DomainResult<()> {
+        debug!("Saving sending domain for company: {}", sending_domain.company_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO company_sending_domains ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT (company_id) DO UPDATE SET domain = excluded.domain, from_email = excluded.from_email, from_name = excluded.from_name, dkim_selector = excluded.dkim_selector, verification_token = excluded.verification_token, status = excluded.status, ownership_verified = excluded.ownership_verified, spf_verified = excluded.spf_verified, dkim_verified = excluded.dkim_verified, last_checked_at = excluded.last_checked_at, verified_at = excluded.verified_at, updated_at = excluded.updated_at",
+            SENDING_DOMAIN_COLUMNS
+        ))
+        .bind(sending_domain.company_id.to_string())
+        .bind(&sending_domain.domain)
+        .bind(&sending_domain.from_email)
+        .bind(&sending_domain.from_name)
+        .bind(&sending_domain.dkim_selector)
+        .bind(&sending_domain.verification_token)
+        .bind(Self::status_to_string(sending_domain.status))
+        .bind(sending_domain.ownership_verified)
+        .bind(sending_domain.spf_verified)
+        .bind(sending_domain.dkim_verified)
+        .bind(sending_domain.last_checked_at.map(|dt| dt.naive_utc()))
+        .bind(sending_domain.verified_at.map(|dt| dt.naive_utc()))
+        .bind(sending_domain.created_at.naive_utc())
+        .bind(sending_domain.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, company_id: Uuid) -> DomainResult<()> {
+        debug!("Deleting sending domain for company: {}", company_id);
+
+        let result = sqlx::query("DELETE FROM company_sending_domains WHERE company_id = ?")
+            .bind(company_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("SendingDomain", company_id));
+        }
+        debug!("Successfully deleted sending domain for company: {}", company_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    async fn seed_company(db: &TestDb) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO companies (id, name, industry_type) VALUES (?, ?, 'Salmon')")
+            .bind(id.to_string())
+            .bind(format!("Company {}", id))
+            .execute(db.pool())
+            .await
+            .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn test_sending_domain_is_saved_replaced_and_deleted() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteSendingDomainRepository::new(db.pool().clone());
+        let company = seed_company(&db).await;
+
+        assert!(repository.find_by_company(company).await.unwrap().is_none());
+
+        let mut sending_domain = SendingDomain::new(
+            company,
+            "lakseforum.no",
+            "invitasjon@lakseforum.no",
+            Some("Lakseforum".to_string()),
+            SendingDomain::DEFAULT_DKIM_SELECTOR,
+        );
+        repository.save(&sending_domain).await.unwrap();
+
+        sending_domain.status = DomainVerificationStatus::Verified;
+        sending_domain.ownership_verified = true;
+        sending_domain.spf_verified = true;
+        sending_domain.dkim_verified = true;
+        sending_domain.verified_at = Some(chrono::Utc::now());
+        repository.save(&sending_domain).await.unwrap();
+
+        let found = repository.find_by_company(company).await.unwrap().unwrap();
+        assert_eq!(found.domain, "lakseforum.no");
+        assert_eq!(found.from_name.as_deref(), Some("Lakseforum"));
+        assert_eq!(found.verification_token, sending_domain.verification_token);
+        assert!(found.is_verified());
+        assert!(found.dkim_verified);
+        assert!(found.verified_at.is_some());
+
+        repository.delete(company).await.unwrap();
+        assert!(repository.find_by_company(company).await.unwrap().is_none());
+        assert!(repository.delete(company).await.is_err());
+    }
+}
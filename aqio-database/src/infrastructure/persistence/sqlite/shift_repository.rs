@@ -0,0 +1,282 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::ShiftRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainError, DomainResult, EventShift, ShiftSignup};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const SHIFT_COLUMNS: &str = "id, event_id, role, description, starts_at, ends_at, headcount, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteShiftRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteShiftRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to EventShift using SafeRowGet
+    fn row_to_shift(row: &sqlx::sqlite::SqliteRow) -> Result<EventShift, RowConversionError> {
+        Ok(EventShift {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            role: row.get_string("role")?,
+            description: row.get_optional_string("description")?,
+            starts_at: row.get_datetime("starts_at")?,
+            ends_at: row.get_datetime("ends_at")?,
+            headcount: row.get_i32("headcount")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn row_to_signup(row: &sqlx::sqlite::SqliteRow) -> Result<ShiftSignup, RowConversionError> {
+        Ok(ShiftSignup {
+            shift_id: row.get_uuid("shift_id")?,
+            user_id: row.get_uuid("user_id")?,
+            signed_up_at: row.get_datetime("signed_up_at")?,
+        })
+    }
+
+    fn rows_to_shifts(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<EventShift>> {
+        rows.iter()
+            .map(|row| Self::row_to_shift(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ShiftRepository for SqliteShiftRepository {
+    #[instrument(skip(self))]
+    async fn find_shift(&self, id: Uuid) -> DomainResult<Option<EventShift>> {
+        debug!("Finding shift by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM event_shifts WHERE id = ?", SHIFT_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_shift(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_shifts(&self, event_id: Uuid) -> DomainResult<Vec<EventShift>> {
+        debug!("Listing shifts for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_shifts WHERE event_id = ? ORDER BY starts_at, role COLLATE NOCASE",
+            SHIFT_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_shifts(&rows)
+    }
+
+    #[instrument(skip(self, shift))]
+    async fn create_shift(&self, shift: &EventShift) -> DomainResult<()> {
+        debug!("Creating shift {} on event {}", shift.id, shift.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO event_shifts ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            SHIFT_COLUMNS
+        ))
+        .bind(shift.id.to_string())
+        .bind(shift.event_id.to_string())
+        .bind(&shift.role)
+        .bind(shift.description.as_deref())
+        .bind(shift.starts_at.naive_utc())
+        .bind(shift.ends_at.naive_utc())
+        .bind(shift.headcount)
+        .bind(shift.created_at.naive_utc())
+        .bind(shift.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, shift))]
+    async fn update_shift(&self, shift: &EventShift) -> DomainResult<()> {
+        debug!("Updating shift: {}", shift.id);
+
+        let result = sqlx::query(
+            "UPDATE event_shifts
+             SET role = ?, description = ?, starts_at = ?, ends_at = ?, headcount = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&shift.role)
+        .bind(shift.description.as_deref())
+        .bind(shift.starts_at.naive_utc())
+        .bind(shift.ends_at.naive_utc())
+        .bind(shift.headcount)
+        .bind(shift.updated_at.naive_utc())
+        .bind(shift.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("EventShift", shift.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_shift(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting shift with id: {}", id);
+
+        // Signups go with the shift (ON DELETE CASCADE)
+        let result = sqlx::query("DELETE FROM event_shifts WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("EventShift", id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_signups(&self, event_id: Uuid) -> DomainResult<Vec<ShiftSignup>> {
+        debug!("Listing shift signups for event: {}", event_id);
+
+        let rows = sqlx::query(
+            "SELECT su.shift_id, su.user_id, su.signed_up_at FROM shift_signups su
+             JOIN event_shifts s ON s.id = su.shift_id
+             WHERE s.event_id = ?
+             ORDER BY su.signed_up_at, su.user_id",
+        )
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let signups: Result<Vec<ShiftSignup>, RowConversionError> = rows.iter().map(Self::row_to_signup).collect();
+        let signups = signups.map_err(InfrastructureError::from)?;
+        Ok(signups)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_shifts_by_user(&self, user_id: Uuid) -> DomainResult<Vec<EventShift>> {
+        debug!("Listing shifts of user: {}", user_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM event_shifts
+             WHERE id IN (SELECT shift_id FROM shift_signups WHERE user_id = ?)
+             ORDER BY starts_at, role COLLATE NOCASE",
+            SHIFT_COLUMNS
+        ))
+        .bind(user_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_shifts(&rows)
+    }
+
+    #[instrument(skip(self, signup))]
+    async fn sign_up_within_headcount(&self, signup: &ShiftSignup) -> DomainResult<bool> {
+        debug!("Signing up user {} for shift {}", signup.user_id, signup.shift_id);
+
+        let result = sqlx::query(
+            "INSERT INTO shift_signups (shift_id, user_id, signed_up_at)
+             SELECT ?, ?, ?
+             FROM event_shifts s
+             WHERE s.id = ?
+               AND s.headcount > (SELECT COUNT(*) FROM shift_signups WHERE shift_id = s.id)",
+        )
+        .bind(signup.shift_id.to_string())
+        .bind(signup.user_id.to_string())
+        .bind(signup.signed_up_at.naive_utc())
+        .bind(signup.shift_id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn cancel_signup(&self, shift_id: Uuid, user_id: Uuid) -> DomainResult<()> {
+        debug!("Removing user {} from shift {}", user_id, shift_id);
+
+        let result = sqlx::query("DELETE FROM shift_signups WHERE shift_id = ? AND user_id = ?")
+            .bind(shift_id.to_string())
+            .bind(user_id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("ShiftSignup", shift_id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use chrono::{Duration, Utc};
+
+    #[tokio::test]
+    async fn test_signups_stop_at_the_headcount() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteShiftRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let volunteer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+
+        let start = Utc::now() + Duration::days(7);
+        let evening = EventShift::new(event_id, "Coat check", start + Duration::hours(6), start + Duration::hours(9), 2);
+        let morning = EventShift {
+            description: Some("Hand out badges".to_string()),
+            ..EventShift::new(event_id, "Registration desk", start, start + Duration::hours(3), 1)
+        };
+        repository.create_shift(&evening).await.unwrap();
+        repository.create_shift(&morning).await.unwrap();
+        let shifts = repository.find_shifts(event_id).await.unwrap();
+        assert_eq!(shifts.iter().map(|s| s.role.as_str()).collect::<Vec<_>>(), vec!["Registration desk", "Coat check"]);
+
+        let signup = |shift_id, user_id| ShiftSignup {
+            shift_id,
+            user_id,
+            signed_up_at: Utc::now(),
+        };
+        assert!(repository.sign_up_within_headcount(&signup(morning.id, volunteer)).await.unwrap());
+        // Full
+        assert!(!repository.sign_up_within_headcount(&signup(morning.id, organizer)).await.unwrap());
+        // Already signed up
+        assert!(repository.sign_up_within_headcount(&signup(evening.id, volunteer)).await.unwrap());
+        assert!(repository.sign_up_within_headcount(&signup(evening.id, volunteer)).await.is_err());
+
+        assert_eq!(repository.find_signups(event_id).await.unwrap().len(), 2);
+        let mine = repository.find_shifts_by_user(volunteer).await.unwrap();
+        assert_eq!(mine.iter().map(|s| s.id).collect::<Vec<_>>(), vec![morning.id, evening.id]);
+
+        repository.cancel_signup(morning.id, volunteer).await.unwrap();
+        assert!(matches!(
+            repository.cancel_signup(morning.id, volunteer).await,
+            Err(DomainError::NotFound { .. })
+        ));
+        repository.delete_shift(evening.id).await.unwrap();
+        assert!(repository.find_signups(event_id).await.unwrap().is_empty());
+    }
+}
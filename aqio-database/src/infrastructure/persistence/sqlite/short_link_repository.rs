@@ -0,0 +1,344 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::ShortLinkRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{
+    CampaignParameters, DailyClicks, DomainResult, ReferrerClicks, ShortLink, ShortLinkClick, ShortLinkClicks,
+    ShortLinkStats,
+};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const LINK_COLUMNS: &str = "id, event_id, code, label, utm_source, utm_medium, utm_campaign, utm_content, created_by, created_at";
+
+#[derive(Clone)]
+pub struct SqliteShortLinkRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteShortLinkRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to ShortLink using SafeRowGet
+    fn row_to_link(row: &sqlx::sqlite::SqliteRow) -> Result<ShortLink, RowConversionError> {
+        Ok(ShortLink {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            code: row.get_string("code")?,
+            label: row.get_optional_string("label")?,
+            campaign: CampaignParameters {
+                source: row.get_optional_string("utm_source")?,
+                medium: row.get_optional_string("utm_medium")?,
+                campaign: row.get_optional_string("utm_campaign")?,
+                content: row.get_optional_string("utm_content")?,
+            },
+            created_by: row.get_uuid("created_by")?,
+            created_at: row.get_datetime("created_at")?,
+        })
+    }
+
+    fn row_to_clicks(row: &sqlx::sqlite::SqliteRow) -> Result<ShortLinkClicks, RowConversionError> {
+        Ok(ShortLinkClicks {
+            link_id: row.get_uuid("link_id")?,
+            clicks: row.get_i64("clicks")?,
+            last_clicked_at: row.get_optional_datetime("last_clicked_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl ShortLinkRepository for SqliteShortLinkRepository {
+    #[instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> DomainResult<Option<ShortLink>> {
+        debug!("Finding short link by id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM short_links WHERE id = ?", LINK_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_link(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_code(&self, code: &str) -> DomainResult<Option<ShortLink>> {
+        debug!("Finding short link by code: {}", code);
+
+        let row = sqlx::query(&format!("SELECT {} FROM short_links WHERE code = ?", LINK_COLUMNS))
+            .bind(code)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_link(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event_id(&self, event_id: Uuid) -> DomainResult<Vec<ShortLink>> {
+        debug!("Listing short links for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM short_links WHERE event_id = ? ORDER BY created_at DESC, code",
+            LINK_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let links: Result<Vec<ShortLink>, RowConversionError> = rows.iter().map(Self::row_to_link).collect();
+        Ok(links.map_err(InfrastructureError::from)?)
+    }
+
+    #[instrument(skip(self, link))]
+    async fn create(&self, link: &ShortLink) -> DomainResult<()> {
+        debug!("Creating short link {} on event {}", link.code, link.event_id);
+
+        sqlx::query(
+            "INSERT INTO short_links (id, event_id, code, label, utm_source, utm_medium, utm_campaign, utm_content, created_by, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(link.id.to_string())
+        .bind(link.event_id.to_string())
+        .bind(&link.code)
+        .bind(link.label.as_deref())
+        .bind(link.campaign.source.as_deref())
+        .bind(link.campaign.medium.as_deref())
+        .bind(link.campaign.campaign.as_deref())
+        .bind(link.campaign.content.as_deref())
+        .bind(link.created_by.to_string())
+        .bind(link.created_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting short link with id: {}", id);
+
+        let result = sqlx::query("DELETE FROM short_links WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(aqio_core::DomainError::not_found("ShortLink", id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self, click))]
+    async fn record_click(&self, click: &ShortLinkClick) -> DomainResult<()> {
+        sqlx::query(
+            "INSERT INTO short_link_clicks (id, link_id, invitation_id, referrer_host, clicked_at) VALUES (?, ?, ?, ?, ?)"
+        )
+        .bind(click.id.to_string())
+        .bind(click.link_id.to_string())
+        .bind(click.invitation_id.map(|id| id.to_string()))
+        .bind(click.referrer_host.as_deref())
+        .bind(click.clicked_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn click_totals(&self, event_id: Uuid) -> DomainResult<Vec<ShortLinkClicks>> {
+        let rows = sqlx::query(
+            "SELECT c.link_id, COUNT(*) AS clicks, MAX(c.clicked_at) AS last_clicked_at \
+             FROM short_link_clicks c JOIN short_links l ON l.id = c.link_id \
+             WHERE l.event_id = ? GROUP BY c.link_id"
+        )
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let totals: Result<Vec<ShortLinkClicks>, RowConversionError> = rows.iter().map(Self::row_to_clicks).collect();
+        Ok(totals.map_err(InfrastructureError::from)?)
+    }
+
+    #[instrument(skip(self))]
+    async fn stats(&self, link_id: Uuid, max_referrers: i64) -> DomainResult<ShortLinkStats> {
+        let link_id_text = link_id.to_string();
+
+        let totals = sqlx::query(
+            "SELECT COUNT(*) AS clicks, MAX(clicked_at) AS last_clicked_at, \
+             COUNT(invitation_id) AS invitation_clicks, COUNT(DISTINCT invitation_id) AS invitations_clicked \
+             FROM short_link_clicks WHERE link_id = ?"
+        )
+        .bind(&link_id_text)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let daily = sqlx::query(
+            "SELECT date(clicked_at) AS day, COUNT(*) AS clicks FROM short_link_clicks \
+             WHERE link_id = ? GROUP BY day ORDER BY day"
+        )
+        .bind(&link_id_text)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let referrers = sqlx::query(
+            "SELECT COALESCE(referrer_host, '') AS host, COUNT(*) AS clicks FROM short_link_clicks \
+             WHERE link_id = ? GROUP BY host ORDER BY clicks DESC, host LIMIT ?"
+        )
+        .bind(&link_id_text)
+        .bind(max_referrers)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        let daily: Result<Vec<DailyClicks>, RowConversionError> = daily
+            .iter()
+            .map(|row| Ok(DailyClicks { date: row.get_date("day")?, clicks: row.get_i64("clicks")? }))
+            .collect();
+        let referrers: Result<Vec<ReferrerClicks>, RowConversionError> = referrers
+            .iter()
+            .map(|row| Ok(ReferrerClicks { host: row.get_string("host")?, clicks: row.get_i64("clicks")? }))
+            .collect();
+        let stats = ShortLinkStats {
+            link_id,
+            clicks: totals.get_i64("clicks").map_err(InfrastructureError::from)?,
+            last_clicked_at: totals.get_optional_datetime("last_clicked_at").map_err(InfrastructureError::from)?,
+            invitation_clicks: totals.get_i64("invitation_clicks").map_err(InfrastructureError::from)?,
+            invitations_clicked: totals.get_i64("invitations_clicked").map_err(InfrastructureError::from)?,
+            daily: daily.map_err(InfrastructureError::from)?,
+            referrers: referrers.map_err(InfrastructureError::from)?,
+        };
+
+        debug!("Short link {} has {} clicks", link_id, stats.clicks);
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn link(event_id: Uuid, created_by: Uuid, code: &str) -> ShortLink {
+        ShortLink {
+            id: Uuid::new_v4(),
+            event_id,
+            code: code.to_string(),
+            label: None,
+            campaign: CampaignParameters::default(),
+            created_by,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn click(link_id: Uuid, invitation_id: Option<Uuid>, referrer: Option<&str>, day: u32) -> ShortLinkClick {
+        ShortLinkClick {
+            id: Uuid::new_v4(),
+            link_id,
+            invitation_id,
+            referrer_host: referrer.map(str::to_string),
+            clicked_at: Utc.with_ymd_and_hms(2026, 4, day, 9, 30, 0).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_links_round_trip_and_codes_are_unique() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteShortLinkRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+
+        let mut newsletter = link(event_id, organizer, "Kx7mQ2p");
+        newsletter.label = Some("April newsletter".to_string());
+        newsletter.campaign = CampaignParameters {
+            source: Some("newsletter".to_string()),
+            medium: Some("email".to_string()),
+            campaign: Some("spring-2026".to_string()),
+            content: None,
+        };
+        repository.create(&newsletter).await.unwrap();
+        let mut linkedin = link(event_id, organizer, "Rt4vW9z");
+        linkedin.created_at = newsletter.created_at + Duration::minutes(5);
+        repository.create(&linkedin).await.unwrap();
+        assert!(repository.create(&link(event_id, organizer, "Kx7mQ2p")).await.is_err());
+
+        let found = repository.find_by_code("Kx7mQ2p").await.unwrap().unwrap();
+        assert_eq!(found.id, newsletter.id);
+        assert_eq!(found.campaign, newsletter.campaign);
+        assert_eq!(found.label.as_deref(), Some("April newsletter"));
+        assert!(repository.find_by_code("kx7mq2p").await.unwrap().is_none());
+        let ids: Vec<Uuid> = repository.find_by_event_id(event_id).await.unwrap().iter().map(|l| l.id).collect();
+        assert_eq!(ids, vec![linkedin.id, newsletter.id]);
+
+        repository.delete(linkedin.id).await.unwrap();
+        assert!(repository.delete(linkedin.id).await.is_err());
+        assert!(repository.find_by_id(linkedin.id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_clicks_are_counted_per_day_referrer_and_invitation() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteShortLinkRepository::new(db.pool().clone());
+        let organizer = db.seed_user().await;
+        let guest = db.seed_user().await;
+        let event_id = db.seed_event(organizer).await;
+        let invitation_id = Uuid::new_v4();
+        db.execute(&format!(
+            "INSERT INTO event_invitations (id, event_id, invited_user_id, inviter_id) VALUES ('{}', '{}', '{}', '{}')",
+            invitation_id, event_id, guest, organizer
+        ))
+        .await;
+
+        let shared = link(event_id, organizer, "Kx7mQ2p");
+        let unused = link(event_id, organizer, "Rt4vW9z");
+        repository.create(&shared).await.unwrap();
+        repository.create(&unused).await.unwrap();
+        for click in [
+            click(shared.id, None, Some("www.linkedin.com"), 1),
+            click(shared.id, None, Some("www.linkedin.com"), 1),
+            click(shared.id, Some(invitation_id), None, 2),
+            click(shared.id, Some(invitation_id), None, 3),
+        ] {
+            repository.record_click(&click).await.unwrap();
+        }
+
+        let stats = repository.stats(shared.id, 10).await.unwrap();
+        assert_eq!((stats.clicks, stats.invitation_clicks, stats.invitations_clicked), (4, 2, 1));
+        assert_eq!(stats.last_clicked_at, Some(Utc.with_ymd_and_hms(2026, 4, 3, 9, 30, 0).unwrap()));
+        let days: Vec<(u32, i64)> = stats.daily.iter().map(|d| (chrono::Datelike::day(&d.date), d.clicks)).collect();
+        assert_eq!(days, vec![(1, 2), (2, 1), (3, 1)]);
+        assert_eq!(stats.referrers[0], ReferrerClicks { host: "".to_string(), clicks: 2 });
+        assert_eq!(stats.referrers[1].host, "www.linkedin.com");
+        assert_eq!(repository.stats(shared.id, 1).await.unwrap().referrers.len(), 1);
+
+        let totals = repository.click_totals(event_id).await.unwrap();
+        assert_eq!(totals.len(), 1);
+        assert_eq!((totals[0].link_id, totals[0].clicks), (shared.id, 4));
+        let untouched = repository.stats(unused.id, 10).await.unwrap();
+        assert_eq!((untouched.clicks, untouched.last_clicked_at), (0, None));
+
+        // Deleting the invitation keeps its clicks, unattributed
+        db.execute(&format!("DELETE FROM event_invitations WHERE id = '{}'", invitation_id)).await;
+        assert_eq!(repository.stats(shared.id, 10).await.unwrap().invitation_clicks, 0);
+        repository.delete(shared.id).await.unwrap();
+        assert!(repository.click_totals(event_id).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::StorageQuotaRepository,
+};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, StorageUsage};
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+// An event belongs to the organization of its organizer, as for scheduling rules
+const USAGE_QUERY: &str = "\
+    WITH company_events AS ( \
+        SELECT e.id FROM events e JOIN users u ON u.id = e.organizer_id WHERE u.company_id = ? \
+    ) \
+    SELECT \
+        (SELECT COALESCE(SUM(file_size), 0) FROM event_files \
+         WHERE event_id IN (SELECT id FROM company_events)) AS attachment_bytes, \
+        (SELECT COUNT(*) FROM event_files \
+         WHERE event_id IN (SELECT id FROM company_events)) AS attachments, \
+        (SELECT COALESCE(SUM(receipt_size), 0) FROM event_expenses \
+         WHERE receipt_storage_key IS NOT NULL AND event_id IN (SELECT id FROM company_events)) AS receipt_bytes, \
+        (SELECT COUNT(*) FROM event_expenses \
+         WHERE receipt_storage_key IS NOT NULL AND event_id IN (SELECT id FROM company_events)) AS receipts, \
+        (SELECT COALESCE(SUM(file_size), 0) FROM event_photos \
+         WHERE event_id IN (SELECT id FROM company_events)) AS photo_bytes, \
+        (SELECT COUNT(*) FROM event_photos \
+         WHERE event_id IN (SELECT id FROM company_events)) AS photos";
+
+#[derive(Clone)]
+pub struct SqliteStorageQuotaRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteStorageQuotaRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_usage(row: &sqlx::sqlite::SqliteRow) -> Result<StorageUsage, RowConversionError> {
+        Ok(StorageUsage {
+            attachment_bytes: row.get_i64("attachment_bytes")?,
+            receipt_bytes: row.get_i64("receipt_bytes")?,
+            photo_bytes: row.get_i64("photo_bytes")?,
+            files: row.get_i64("attachments")? + row.get_i64("receipts")? + row.get_i64("photos")?,
+        })
+    }
+}
+
+#[async_trait]
+impl StorageQuotaRepository for SqliteStorageQuotaRepository {
+    #[instrument(skip(self))]
+    async fn usage_by_company(&self, company_id: Uuid) -> DomainResult<StorageUsage> {
+        debug!("Summing media storage used by company: {}", company_id);
+
+        let row = sqlx::query(USAGE_QUERY)
+            .bind(company_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(Self::row_to_usage(&row).map_err(InfrastructureError::from)?)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_quota(&self, company_id: Uuid) -> DomainResult<Option<i64>> {
+        debug!("Finding storage quota for company: {}", company_id);
+
+        let quota: Option<i64> = sqlx::query_scalar("SELECT quota_bytes FROM company_storage_quotas WHERE company_id = ?")
+            .bind(company_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(quota)
+    }
+
+    #[instrument(skip(self))]
+    async fn save_quota(&self, company_id: Uuid, quota_bytes: Option<i64>) -> DomainResult<()> {
+        debug!("Setting storage quota for company {} to {:?}", company_id, quota_bytes);
+
+        match quota_bytes {
+            Some(quota_bytes) => sqlx::query(
+                "INSERT INTO company_storage_quotas (company_id, quota_bytes, updated_at) VALUES (?, ?, ?) \
+                 ON CONFLICT (company_id) DO UPDATE SET quota_bytes = excluded.quota_bytes, updated_at = excluded.updated_at",
+            )
+            .bind(company_id.to_string())
+            .bind(quota_bytes)
+            .bind(Utc::now().naive_utc())
+            .execute(&self.pool)
+            .await,
+            None => sqlx::query("DELETE FROM company_storage_quotas WHERE company_id = ?")
+                .bind(company_id.to_string())
+                .execute(&self.pool)
+                .await,
+        }
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+
+    async fn seed_company(db: &TestDb) -> Uuid {
+        let id = Uuid::new_v4();
+        db.execute(&format!(
+            "INSERT INTO companies (id, name, industry_type) VALUES ('{}', 'Company {}', 'Salmon')",
+            id, id
+        ))
+        .await;
+        id
+    }
+
+    async fn seed_file(db: &TestDb, event_id: Uuid, size: i64) {
+        let id = Uuid::new_v4();
+        db.execute(&format!(
+            "INSERT INTO event_files (id, event_id, uploader_name, filename, original_filename, file_path, file_size, mime_type) \
+             VALUES ('{id}', '{event_id}', 'Organizer', '{id}.pdf', 'map.pdf', 'events/{event_id}/{id}.pdf', {size}, 'application/pdf')"
+        ))
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_usage_counts_the_members_events_and_quota_overrides_the_default() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteStorageQuotaRepository::new(db.pool().clone());
+        let company = seed_company(&db).await;
+        let member = db.seed_user().await;
+        let outsider = db.seed_user().await;
+        db.execute(&format!("UPDATE users SET company_id = '{}' WHERE id = '{}'", company, member)).await;
+
+        assert_eq!(repository.usage_by_company(company).await.unwrap(), StorageUsage::default());
+
+        let event = db.seed_event(member).await;
+        let other_event = db.seed_event(outsider).await;
+        seed_file(&db, event, 1_000).await;
+        seed_file(&db, event, 500).await;
+        seed_file(&db, other_event, 9_999).await;
+        db.execute(&format!(
+            "INSERT INTO event_expenses (id, event_id, description, amount, incurred_on, receipt_filename, receipt_storage_key, receipt_size, receipt_mime_type) \
+             VALUES ('{}', '{}', 'Venue', 100, '2026-05-01', 'r.pdf', 'receipts/r.pdf', 250, 'application/pdf')",
+            Uuid::new_v4(),
+            event
+        ))
+        .await;
+        db.execute(&format!(
+            "INSERT INTO event_expenses (id, event_id, description, amount, incurred_on) VALUES ('{}', '{}', 'Coffee', 10, '2026-05-01')",
+            Uuid::new_v4(),
+            event
+        ))
+        .await;
+
+        db.execute(&format!(
+            "INSERT INTO event_photos (id, event_id, uploader_name, filename, original_filename, storage_key, file_size, mime_type, status) \
+             VALUES ('{}', '{}', 'Organizer', 'stage.jpg', 'stage.jpg', 'photos/stage.jpg', 40, 'image/jpeg', 'pending')",
+            Uuid::new_v4(),
+            event
+        ))
+        .await;
+
+        let usage = repository.usage_by_company(company).await.unwrap();
+        assert_eq!(usage, StorageUsage { attachment_bytes: 1_500, receipt_bytes: 250, photo_bytes: 40, files: 4 });
+        assert_eq!(usage.total_bytes(), 1_790);
+
+        assert_eq!(repository.find_quota(company).await.unwrap(), None);
+        repository.save_quota(company, Some(1_000)).await.unwrap();
+        repository.save_quota(company, Some(2_000)).await.unwrap();
+        assert_eq!(repository.find_quota(company).await.unwrap(), Some(2_000));
+        repository.save_quota(company, None).await.unwrap();
+        assert_eq!(repository.find_quota(company).await.unwrap(), None);
+    }
+}
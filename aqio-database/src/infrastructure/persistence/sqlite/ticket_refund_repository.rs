@@ -0,0 +1,211 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::TicketRefundRepository,
+};
+use crate::infrastructure::persistence::mapping::{currency_to_string, refund_status_to_string};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainError, DomainResult, RefundStatus, TicketRefund};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const REFUND_COLUMNS: &str = "id, order_id, event_id, buyer_id, amount, currency, status, attempts, next_attempt_at, last_error, provider_reference, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteTicketRefundRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteTicketRefundRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to TicketRefund using SafeRowGet
+    fn row_to_refund(row: &sqlx::sqlite::SqliteRow) -> Result<TicketRefund, RowConversionError> {
+        Ok(TicketRefund {
+            id: row.get_uuid("id")?,
+            order_id: row.get_uuid("order_id")?,
+            event_id: row.get_uuid("event_id")?,
+            buyer_id: row.get_uuid("buyer_id")?,
+            amount: row.get_i64("amount")?,
+            currency: row.get_currency("currency")?,
+            status: row.get_refund_status("status")?,
+            attempts: row.get_i32("attempts")?,
+            next_attempt_at: row.get_optional_datetime("next_attempt_at")?,
+            last_error: row.get_optional_string("last_error")?,
+            provider_reference: row.get_optional_string("provider_reference")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    fn rows_to_refunds(rows: &[sqlx::sqlite::SqliteRow]) -> DomainResult<Vec<TicketRefund>> {
+        rows.iter()
+            .map(|row| Self::row_to_refund(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl TicketRefundRepository for SqliteTicketRefundRepository {
+    #[instrument(skip(self, refund))]
+    async fn create(&self, refund: &TicketRefund) -> DomainResult<()> {
+        debug!("Creating refund for ticket order: {}", refund.order_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO ticket_refunds ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            REFUND_COLUMNS
+        ))
+        .bind(refund.id.to_string())
+        .bind(refund.order_id.to_string())
+        .bind(refund.event_id.to_string())
+        .bind(refund.buyer_id.to_string())
+        .bind(refund.amount)
+        .bind(currency_to_string(refund.currency))
+        .bind(refund_status_to_string(refund.status))
+        .bind(refund.attempts)
+        .bind(refund.next_attempt_at.map(|at| at.naive_utc()))
+        .bind(refund.last_error.as_deref())
+        .bind(refund.provider_reference.as_deref())
+        .bind(refund.created_at.naive_utc())
+        .bind(refund.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_by_event(&self, event_id: Uuid) -> DomainResult<Vec<TicketRefund>> {
+        debug!("Finding refunds of event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM ticket_refunds WHERE event_id = ? ORDER BY created_at ASC, id ASC",
+            REFUND_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_refunds(&rows)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_due(&self, now: DateTime<Utc>, limit: i64) -> DomainResult<Vec<TicketRefund>> {
+        debug!("Finding refunds due at {}", now);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM ticket_refunds
+             WHERE status = ? AND next_attempt_at <= ?
+             ORDER BY next_attempt_at ASC, created_at ASC
+             LIMIT ?",
+            REFUND_COLUMNS
+        ))
+        .bind(refund_status_to_string(RefundStatus::Pending))
+        .bind(now.naive_utc())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Self::rows_to_refunds(&rows)
+    }
+
+    #[instrument(skip(self, refund))]
+    async fn update(&self, refund: &TicketRefund) -> DomainResult<()> {
+        debug!("Updating refund with id: {}", refund.id);
+
+        let result = sqlx::query(
+            "UPDATE ticket_refunds
+             SET status = ?, attempts = ?, next_attempt_at = ?, last_error = ?, provider_reference = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(refund_status_to_string(refund.status))
+        .bind(refund.attempts)
+        .bind(refund.next_attempt_at.map(|at| at.naive_utc()))
+        .bind(refund.last_error.as_deref())
+        .bind(refund.provider_reference.as_deref())
+        .bind(refund.updated_at.naive_utc())
+        .bind(refund.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("TicketRefund", refund.id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::repositories::TicketRepository;
+    use crate::infrastructure::persistence::sqlite::SqliteTicketRepository;
+    use crate::testing::TestDb;
+    use aqio_core::{Currency, ExchangeRate, TicketOrder, TicketType};
+
+    async fn order(db: &TestDb) -> TicketOrder {
+        let tickets = SqliteTicketRepository::new(db.pool().clone());
+        let buyer = db.seed_user().await;
+        let event_id = db.seed_event(buyer).await;
+        let ticket_type = TicketType {
+            id: Uuid::new_v4(),
+            event_id,
+            name: "Standard".to_string(),
+            description: None,
+            price: 150_000,
+            currency: Currency::Nok,
+            vat_rate: 0,
+            quantity: None,
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        tickets.create_ticket_type(&ticket_type).await.unwrap();
+        let order = TicketOrder::new(&ticket_type, buyer, 1, &ExchangeRate::nok(Utc::now().date_naive()));
+        tickets.create_order_within_quantity(&order).await.unwrap();
+        order
+    }
+
+    #[tokio::test]
+    async fn test_one_refund_per_order_and_due_ones_only() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteTicketRefundRepository::new(db.pool().clone());
+        let now = Utc::now();
+        let due = TicketRefund::new(&order(&db).await, now - chrono::Duration::minutes(1));
+        let later = TicketRefund {
+            next_attempt_at: Some(now + chrono::Duration::minutes(10)),
+            ..TicketRefund::new(&order(&db).await, now)
+        };
+        repository.create(&due).await.unwrap();
+        repository.create(&later).await.unwrap();
+        assert!(matches!(
+            repository.create(&TicketRefund { id: Uuid::new_v4(), ..due.clone() }).await,
+            Err(DomainError::ConflictError { .. })
+        ));
+
+        let found = repository.find_due(now, 10).await.unwrap();
+        assert_eq!(found.iter().map(|r| r.id).collect::<Vec<_>>(), vec![due.id]);
+        assert_eq!((found[0].amount, found[0].currency), (150_000, Currency::Nok));
+
+        let mut succeeded = found[0].clone();
+        succeeded.status = RefundStatus::Succeeded;
+        succeeded.attempts = 1;
+        succeeded.next_attempt_at = None;
+        succeeded.provider_reference = Some("psp-42".to_string());
+        repository.update(&succeeded).await.unwrap();
+        assert!(repository.find_due(now, 10).await.unwrap().is_empty());
+
+        let stored = repository.find_by_event(due.event_id).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!((stored[0].status, stored[0].attempts), (RefundStatus::Succeeded, 1));
+        assert_eq!(stored[0].provider_reference.as_deref(), Some("psp-42"));
+    }
+}
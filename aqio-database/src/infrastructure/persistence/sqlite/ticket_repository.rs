@@ -0,0 +1,480 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::TicketRepository,
+};
+use crate::infrastructure::persistence::mapping::{currency_to_string, order_status_to_string};
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{CurrencyRevenue, DomainError, DomainResult, TicketOrder, TicketType, VatBreakdown};
+use chrono::{DateTime, Utc};
+use async_trait::async_trait;
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+const TYPE_COLUMNS: &str = "id, event_id, name, description, price, currency, vat_rate, quantity, is_active, created_at, updated_at";
+const ORDER_COLUMNS: &str = "id, event_id, ticket_type_id, buyer_id, quantity, unit_price, currency, total, exchange_rate, exchange_rate_date, total_nok, vat_rate, vat_amount, vat_amount_nok, status, created_at, cancelled_at";
+
+#[derive(Clone)]
+pub struct SqliteTicketRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteTicketRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to TicketType using SafeRowGet
+    fn row_to_ticket_type(row: &sqlx::sqlite::SqliteRow) -> Result<TicketType, RowConversionError> {
+        Ok(TicketType {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            name: row.get_string("name")?,
+            description: row.get_optional_string("description")?,
+            price: row.get_i64("price")?,
+            currency: row.get_currency("currency")?,
+            vat_rate: row.get_i32("vat_rate")?,
+            quantity: row.get_optional_i32("quantity")?,
+            is_active: row.get_bool("is_active")?,
+            created_at: row.get_datetime("created_at")?,
+            updated_at: row.get_datetime("updated_at")?,
+        })
+    }
+
+    // Helper method to convert database row to TicketOrder using SafeRowGet
+    fn row_to_order(row: &sqlx::sqlite::SqliteRow) -> Result<TicketOrder, RowConversionError> {
+        Ok(TicketOrder {
+            id: row.get_uuid("id")?,
+            event_id: row.get_uuid("event_id")?,
+            ticket_type_id: row.get_uuid("ticket_type_id")?,
+            buyer_id: row.get_uuid("buyer_id")?,
+            quantity: row.get_i32("quantity")?,
+            unit_price: row.get_i64("unit_price")?,
+            currency: row.get_currency("currency")?,
+            total: row.get_i64("total")?,
+            exchange_rate: row.get_f64("exchange_rate")?,
+            exchange_rate_date: row.get_date("exchange_rate_date")?,
+            total_nok: row.get_i64("total_nok")?,
+            vat_rate: row.get_i32("vat_rate")?,
+            vat_amount: row.get_i64("vat_amount")?,
+            vat_amount_nok: row.get_i64("vat_amount_nok")?,
+            status: row.get_order_status("status")?,
+            created_at: row.get_datetime("created_at")?,
+            cancelled_at: row.get_optional_datetime("cancelled_at")?,
+        })
+    }
+
+    async fn fetch_orders(&self, column: &str, id: Uuid) -> DomainResult<Vec<TicketOrder>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM ticket_orders WHERE {} = ? ORDER BY created_at DESC",
+            ORDER_COLUMNS, column
+        ))
+        .bind(id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_order(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl TicketRepository for SqliteTicketRepository {
+    #[instrument(skip(self))]
+    async fn find_ticket_type(&self, id: Uuid) -> DomainResult<Option<TicketType>> {
+        debug!("Finding ticket type with id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM ticket_types WHERE id = ?", TYPE_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_ticket_type(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_ticket_types_by_event(&self, event_id: Uuid) -> DomainResult<Vec<TicketType>> {
+        debug!("Finding ticket types for event: {}", event_id);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM ticket_types WHERE event_id = ? ORDER BY price ASC, name ASC",
+            TYPE_COLUMNS
+        ))
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_ticket_type(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self, ticket_type))]
+    async fn create_ticket_type(&self, ticket_type: &TicketType) -> DomainResult<()> {
+        debug!("Creating ticket type '{}' for event: {}", ticket_type.name, ticket_type.event_id);
+
+        sqlx::query(&format!(
+            "INSERT INTO ticket_types ({}) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            TYPE_COLUMNS
+        ))
+        .bind(ticket_type.id.to_string())
+        .bind(ticket_type.event_id.to_string())
+        .bind(&ticket_type.name)
+        .bind(ticket_type.description.as_deref())
+        .bind(ticket_type.price)
+        .bind(currency_to_string(ticket_type.currency))
+        .bind(ticket_type.vat_rate)
+        .bind(ticket_type.quantity)
+        .bind(ticket_type.is_active)
+        .bind(ticket_type.created_at.naive_utc())
+        .bind(ticket_type.updated_at.naive_utc())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, ticket_type))]
+    async fn update_ticket_type(&self, ticket_type: &TicketType) -> DomainResult<()> {
+        debug!("Updating ticket type with id: {}", ticket_type.id);
+
+        let result = sqlx::query(
+            "UPDATE ticket_types SET name = ?, description = ?, price = ?, currency = ?, vat_rate = ?, quantity = ?, is_active = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(&ticket_type.name)
+        .bind(ticket_type.description.as_deref())
+        .bind(ticket_type.price)
+        .bind(currency_to_string(ticket_type.currency))
+        .bind(ticket_type.vat_rate)
+        .bind(ticket_type.quantity)
+        .bind(ticket_type.is_active)
+        .bind(ticket_type.updated_at.naive_utc())
+        .bind(ticket_type.id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("TicketType", ticket_type.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_ticket_type(&self, id: Uuid) -> DomainResult<()> {
+        debug!("Deleting ticket type with id: {}", id);
+
+        // Orders keep pointing at their type, so a sold type can only be deactivated
+        let result = sqlx::query(
+            "DELETE FROM ticket_types WHERE id = ? AND NOT EXISTS (SELECT 1 FROM ticket_orders WHERE ticket_type_id = ?)"
+        )
+        .bind(id.to_string())
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return match self.find_ticket_type(id).await? {
+                Some(_) => Err(DomainError::conflict("Ticket type has orders; deactivate it instead")),
+                None => Err(DomainError::not_found("TicketType", id)),
+            };
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn count_sold(&self, ticket_type_id: Uuid) -> DomainResult<i64> {
+        let sold: i64 = sqlx::query_scalar(
+            "SELECT COALESCE(SUM(quantity), 0) FROM ticket_orders WHERE ticket_type_id = ? AND status = 'confirmed'"
+        )
+        .bind(ticket_type_id.to_string())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(sold)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_order(&self, id: Uuid) -> DomainResult<Option<TicketOrder>> {
+        debug!("Finding ticket order with id: {}", id);
+
+        let row = sqlx::query(&format!("SELECT {} FROM ticket_orders WHERE id = ?", ORDER_COLUMNS))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::row_to_order(&row).map_err(InfrastructureError::from)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn find_orders_by_event(&self, event_id: Uuid) -> DomainResult<Vec<TicketOrder>> {
+        debug!("Finding ticket orders for event: {}", event_id);
+        self.fetch_orders("event_id", event_id).await
+    }
+
+    #[instrument(skip(self))]
+    async fn find_orders_by_buyer(&self, buyer_id: Uuid) -> DomainResult<Vec<TicketOrder>> {
+        debug!("Finding ticket orders for buyer: {}", buyer_id);
+        self.fetch_orders("buyer_id", buyer_id).await
+    }
+
+    #[instrument(skip(self, order))]
+    async fn create_order_within_quantity(&self, order: &TicketOrder) -> DomainResult<bool> {
+        debug!("Creating ticket order for ticket type: {}", order.ticket_type_id);
+
+        let result = sqlx::query(&format!(
+            "INSERT INTO ticket_orders ({})
+             SELECT ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+             FROM ticket_types t
+             WHERE t.id = ?
+               AND (t.quantity IS NULL
+                    OR t.quantity >= ? + (SELECT COALESCE(SUM(o.quantity), 0) FROM ticket_orders o
+                                          WHERE o.ticket_type_id = t.id AND o.status = 'confirmed'))",
+            ORDER_COLUMNS
+        ))
+        .bind(order.id.to_string())
+        .bind(order.event_id.to_string())
+        .bind(order.ticket_type_id.to_string())
+        .bind(order.buyer_id.to_string())
+        .bind(order.quantity)
+        .bind(order.unit_price)
+        .bind(currency_to_string(order.currency))
+        .bind(order.total)
+        .bind(order.exchange_rate)
+        .bind(order.exchange_rate_date)
+        .bind(order.total_nok)
+        .bind(order.vat_rate)
+        .bind(order.vat_amount)
+        .bind(order.vat_amount_nok)
+        .bind(order_status_to_string(order.status))
+        .bind(order.created_at.naive_utc())
+        .bind(order.cancelled_at.map(|at| at.naive_utc()))
+        .bind(order.ticket_type_id.to_string())
+        .bind(order.quantity)
+        .execute(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self, order))]
+    async fn update_order(&self, order: &TicketOrder) -> DomainResult<()> {
+        debug!("Updating ticket order with id: {}", order.id);
+
+        let result = sqlx::query("UPDATE ticket_orders SET status = ?, cancelled_at = ? WHERE id = ?")
+            .bind(order_status_to_string(order.status))
+            .bind(order.cancelled_at.map(|at| at.naive_utc()))
+            .bind(order.id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::not_found("TicketOrder", order.id));
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn revenue_by_currency(&self, event_id: Uuid) -> DomainResult<Vec<CurrencyRevenue>> {
+        debug!("Summing ticket revenue for event: {}", event_id);
+
+        let rows = sqlx::query(
+            "SELECT currency, COUNT(*) AS orders, SUM(quantity) AS tickets, SUM(total) AS gross, SUM(total_nok) AS gross_nok
+             FROM ticket_orders
+             WHERE event_id = ? AND status = 'confirmed'
+             GROUP BY currency
+             ORDER BY currency"
+        )
+        .bind(event_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(CurrencyRevenue {
+                    currency: row.get_currency("currency")?,
+                    orders: row.get_i64("orders")?,
+                    tickets: row.get_i64("tickets")?,
+                    gross: row.get_i64("gross")?,
+                    gross_nok: row.get_i64("gross_nok")?,
+                })
+            })
+            .collect::<Result<Vec<_>, RowConversionError>>()
+            .map_err(|e| InfrastructureError::from(e).into())
+    }
+
+    #[instrument(skip(self))]
+    async fn vat_breakdown(
+        &self,
+        event_id: Option<Uuid>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<VatBreakdown>> {
+        debug!("Summing ticket sales from {} to {}", from, to);
+
+        // ?1 and ?2 bound the period, ?3 is the event or NULL for all events
+        let rows = sqlx::query(
+            "SELECT vat_rate,
+                    SUM(CASE WHEN created_at >= ?1 AND created_at < ?2 THEN 1 ELSE 0 END) AS orders,
+                    SUM(CASE WHEN created_at >= ?1 AND created_at < ?2 THEN total_nok ELSE 0 END) AS sales_nok,
+                    SUM(CASE WHEN created_at >= ?1 AND created_at < ?2 THEN vat_amount_nok ELSE 0 END) AS sales_vat_nok,
+                    SUM(CASE WHEN cancelled_at >= ?1 AND cancelled_at < ?2 THEN 1 ELSE 0 END) AS refunded_orders,
+                    SUM(CASE WHEN cancelled_at >= ?1 AND cancelled_at < ?2 THEN total_nok ELSE 0 END) AS refunds_nok,
+                    SUM(CASE WHEN cancelled_at >= ?1 AND cancelled_at < ?2 THEN vat_amount_nok ELSE 0 END) AS refunds_vat_nok
+             FROM ticket_orders
+             WHERE (?3 IS NULL OR event_id = ?3)
+               AND ((created_at >= ?1 AND created_at < ?2) OR (cancelled_at >= ?1 AND cancelled_at < ?2))
+             GROUP BY vat_rate
+             ORDER BY vat_rate"
+        )
+        .bind(from.naive_utc())
+        .bind(to.naive_utc())
+        .bind(event_id.map(|id| id.to_string()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(VatBreakdown {
+                    vat_rate: row.get_i32("vat_rate")?,
+                    orders: row.get_i64("orders")?,
+                    sales_nok: row.get_i64("sales_nok")?,
+                    sales_vat_nok: row.get_i64("sales_vat_nok")?,
+                    refunded_orders: row.get_i64("refunded_orders")?,
+                    refunds_nok: row.get_i64("refunds_nok")?,
+                    refunds_vat_nok: row.get_i64("refunds_vat_nok")?,
+                })
+            })
+            .collect::<Result<Vec<_>, RowConversionError>>()
+            .map_err(|e| InfrastructureError::from(e).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::{Currency, ExchangeRate, OrderStatus};
+    use chrono::{NaiveDate, Utc};
+
+    fn ticket_type(event_id: Uuid, price: i64, currency: Currency, quantity: Option<i32>) -> TicketType {
+        TicketType {
+            id: Uuid::new_v4(),
+            event_id,
+            name: format!("{} ticket", currency.code()),
+            description: None,
+            price,
+            currency,
+            vat_rate: 0,
+            quantity,
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_orders_stop_at_the_ticket_quantity() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteTicketRepository::new(db.pool().clone());
+        let buyer = db.seed_user().await;
+        let event_id = db.seed_event(buyer).await;
+        let limited = ticket_type(event_id, 50_000, Currency::Nok, Some(3));
+        repository.create_ticket_type(&limited).await.unwrap();
+        let rate = ExchangeRate::nok(NaiveDate::from_ymd_opt(2026, 3, 2).unwrap());
+
+        assert!(repository.create_order_within_quantity(&TicketOrder::new(&limited, buyer, 2, &rate)).await.unwrap());
+        assert!(!repository.create_order_within_quantity(&TicketOrder::new(&limited, buyer, 2, &rate)).await.unwrap());
+        assert_eq!(repository.count_sold(limited.id).await.unwrap(), 2);
+
+        let mut order = repository.find_orders_by_buyer(buyer).await.unwrap().remove(0);
+        order.status = OrderStatus::Cancelled;
+        order.cancelled_at = Some(Utc::now());
+        repository.update_order(&order).await.unwrap();
+        assert!(repository.create_order_within_quantity(&TicketOrder::new(&limited, buyer, 3, &rate)).await.unwrap());
+
+        assert!(matches!(
+            repository.delete_ticket_type(limited.id).await,
+            Err(DomainError::ConflictError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_revenue_is_grouped_by_currency() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteTicketRepository::new(db.pool().clone());
+        let buyer = db.seed_user().await;
+        let event_id = db.seed_event(buyer).await;
+        let nok = ticket_type(event_id, 150_000, Currency::Nok, None);
+        let eur = ticket_type(event_id, 12_000, Currency::Eur, None);
+        repository.create_ticket_type(&nok).await.unwrap();
+        repository.create_ticket_type(&eur).await.unwrap();
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let euro = ExchangeRate { currency: Currency::Eur, nok_per_unit: 11.5, date: day };
+
+        repository.create_order_within_quantity(&TicketOrder::new(&nok, buyer, 2, &ExchangeRate::nok(day))).await.unwrap();
+        repository.create_order_within_quantity(&TicketOrder::new(&eur, buyer, 1, &euro)).await.unwrap();
+        let stored = repository.find_orders_by_event(event_id).await.unwrap();
+        assert_eq!(stored.iter().find(|o| o.currency == Currency::Eur).unwrap().exchange_rate_date, day);
+
+        let revenue = repository.revenue_by_currency(event_id).await.unwrap();
+        assert_eq!(revenue.len(), 2);
+        assert_eq!((revenue[0].currency, revenue[0].gross, revenue[0].gross_nok), (Currency::Eur, 12_000, 138_000));
+        assert_eq!((revenue[1].currency, revenue[1].tickets, revenue[1].gross_nok), (Currency::Nok, 2, 300_000));
+    }
+
+    #[tokio::test]
+    async fn test_vat_breakdown_counts_sales_and_refunds_by_month() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteTicketRepository::new(db.pool().clone());
+        let buyer = db.seed_user().await;
+        let event_id = db.seed_event(buyer).await;
+        let standard = TicketType { vat_rate: 25, ..ticket_type(event_id, 125_000, Currency::Nok, None) };
+        let exempt = ticket_type(event_id, 50_000, Currency::Nok, None);
+        repository.create_ticket_type(&standard).await.unwrap();
+        repository.create_ticket_type(&exempt).await.unwrap();
+        let at = |day: u32| NaiveDate::from_ymd_opt(2026, 3, day).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc();
+        let rate = ExchangeRate::nok(at(1).date_naive());
+        let order = |ticket_type: &TicketType, placed| TicketOrder { created_at: placed, ..TicketOrder::new(ticket_type, buyer, 1, &rate) };
+
+        // February's order is refunded in March
+        let february = TicketOrder { created_at: at(1) - chrono::Duration::days(5), ..order(&standard, at(1)) };
+        repository.create_order_within_quantity(&february).await.unwrap();
+        repository.create_order_within_quantity(&order(&standard, at(10))).await.unwrap();
+        repository.create_order_within_quantity(&order(&exempt, at(12))).await.unwrap();
+        let cancelled = TicketOrder {
+            status: OrderStatus::Cancelled,
+            cancelled_at: Some(at(20)),
+            ..february
+        };
+        repository.update_order(&cancelled).await.unwrap();
+
+        let march = repository.vat_breakdown(None, at(1), at(31) + chrono::Duration::days(1)).await.unwrap();
+        let rows: Vec<_> = march
+            .iter()
+            .map(|b| (b.vat_rate, b.orders, b.sales_nok, b.sales_vat_nok, b.refunded_orders, b.refunds_vat_nok))
+            .collect();
+        assert_eq!(rows, vec![(0, 1, 50_000, 0, 0, 0), (25, 1, 125_000, 25_000, 1, 25_000)]);
+
+        let other_event = db.seed_event(buyer).await;
+        assert!(repository.vat_breakdown(Some(other_event), at(1), at(31)).await.unwrap().is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 // Safe row conversion helpers that don't leak SQLx into the domain layer
-use aqio_core::{LocationType, EventStatus, UserRole, InvitationStatus, InvitationMethod, RegistrationStatus, RegistrationSource};
-use chrono::{DateTime, Utc};
+use aqio_core::{LocationType, EventStatus, UserRole, InvitationStatus, InvitationMethod, RegistrationStatus, RegistrationSource, AuditAction, AttachmentCategory, SponsorTier, RuleEnforcement, DomainVerificationStatus, DeliveryFailure, DigestFrequency, Locale, Theme, CalendarView, OrganizationInvitationStatus, OwnershipTransferStatus, EventSubmissionStatus, ReportTarget, ReportReason, ReportStatus, ModerationAction, ConsentKind, BroadcastAudience, BroadcastStatus, PhotoStatus, EventFieldType, AddressRegion, Currency, OrderStatus, InvoiceStatus, RefundStatus, MeetingStatus, WebVitalMetric, ExportEntity, ExportFormat, ExportRunStatus, CrmProvider, CrmConflictPolicy, CrmSyncRunStatus, CrmRecordKind, CrmSyncState};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde_json;
 use sqlx::Row;
 use uuid::Uuid;
@@ -32,6 +32,7 @@ pub trait SafeRowGet {
     fn get_optional_string(&self, field: &'static str) -> Result<Option<String>, RowConversionError>;
     fn get_datetime(&self, field: &'static str) -> Result<DateTime<Utc>, RowConversionError>;
     fn get_optional_datetime(&self, field: &'static str) -> Result<Option<DateTime<Utc>>, RowConversionError>;
+    fn get_date(&self, field: &'static str) -> Result<NaiveDate, RowConversionError>;
     fn get_json<T: serde::de::DeserializeOwned + Default>(&self, field: &'static str) -> Result<T, RowConversionError>;
     fn get_location_type(&self, field: &'static str) -> Result<LocationType, RowConversionError>;
     fn get_event_status(&self, field: &'static str) -> Result<EventStatus, RowConversionError>;
@@ -40,9 +41,51 @@ pub trait SafeRowGet {
     fn get_invitation_method(&self, field: &'static str) -> Result<InvitationMethod, RowConversionError>;
     fn get_registration_status(&self, field: &'static str) -> Result<RegistrationStatus, RowConversionError>;
     fn get_registration_source(&self, field: &'static str) -> Result<RegistrationSource, RowConversionError>;
+    fn get_audit_action(&self, field: &'static str) -> Result<AuditAction, RowConversionError>;
+    fn get_attachment_category(&self, field: &'static str) -> Result<AttachmentCategory, RowConversionError>;
+    fn get_sponsor_tier(&self, field: &'static str) -> Result<SponsorTier, RowConversionError>;
+    fn get_rule_enforcement(&self, field: &'static str) -> Result<RuleEnforcement, RowConversionError>;
+    fn get_domain_verification_status(&self, field: &'static str) -> Result<DomainVerificationStatus, RowConversionError>;
+    fn get_delivery_failure(&self, field: &'static str) -> Result<DeliveryFailure, RowConversionError>;
+    fn get_optional_delivery_failure(&self, field: &'static str) -> Result<Option<DeliveryFailure>, RowConversionError>;
+    fn get_digest_frequency(&self, field: &'static str) -> Result<DigestFrequency, RowConversionError>;
+    fn get_optional_locale(&self, field: &'static str) -> Result<Option<Locale>, RowConversionError>;
+    fn get_optional_theme(&self, field: &'static str) -> Result<Option<Theme>, RowConversionError>;
+    fn get_optional_calendar_view(&self, field: &'static str) -> Result<Option<CalendarView>, RowConversionError>;
+    fn get_organization_invitation_status(&self, field: &'static str) -> Result<OrganizationInvitationStatus, RowConversionError>;
+    fn get_ownership_transfer_status(&self, field: &'static str) -> Result<OwnershipTransferStatus, RowConversionError>;
+    fn get_event_submission_status(&self, field: &'static str) -> Result<EventSubmissionStatus, RowConversionError>;
+    fn get_report_target(&self, field: &'static str) -> Result<ReportTarget, RowConversionError>;
+    fn get_report_reason(&self, field: &'static str) -> Result<ReportReason, RowConversionError>;
+    fn get_report_status(&self, field: &'static str) -> Result<ReportStatus, RowConversionError>;
+    fn get_optional_moderation_action(&self, field: &'static str) -> Result<Option<ModerationAction>, RowConversionError>;
+    fn get_consent_kind(&self, field: &'static str) -> Result<ConsentKind, RowConversionError>;
+    fn get_broadcast_audience(&self, field: &'static str) -> Result<BroadcastAudience, RowConversionError>;
+    fn get_broadcast_status(&self, field: &'static str) -> Result<BroadcastStatus, RowConversionError>;
+    fn get_photo_status(&self, field: &'static str) -> Result<PhotoStatus, RowConversionError>;
+    fn get_event_field_type(&self, field: &'static str) -> Result<EventFieldType, RowConversionError>;
+    fn get_currency(&self, field: &'static str) -> Result<Currency, RowConversionError>;
+    fn get_order_status(&self, field: &'static str) -> Result<OrderStatus, RowConversionError>;
+    fn get_invoice_status(&self, field: &'static str) -> Result<InvoiceStatus, RowConversionError>;
+    fn get_refund_status(&self, field: &'static str) -> Result<RefundStatus, RowConversionError>;
+    fn get_meeting_status(&self, field: &'static str) -> Result<MeetingStatus, RowConversionError>;
+    fn get_web_vital_metric(&self, field: &'static str) -> Result<WebVitalMetric, RowConversionError>;
+    fn get_export_entity(&self, field: &'static str) -> Result<ExportEntity, RowConversionError>;
+    fn get_export_format(&self, field: &'static str) -> Result<ExportFormat, RowConversionError>;
+    fn get_optional_export_run_status(&self, field: &'static str) -> Result<Option<ExportRunStatus>, RowConversionError>;
+    fn get_crm_provider(&self, field: &'static str) -> Result<CrmProvider, RowConversionError>;
+    fn get_crm_conflict_policy(&self, field: &'static str) -> Result<CrmConflictPolicy, RowConversionError>;
+    fn get_optional_crm_sync_run_status(&self, field: &'static str) -> Result<Option<CrmSyncRunStatus>, RowConversionError>;
+    fn get_crm_record_kind(&self, field: &'static str) -> Result<CrmRecordKind, RowConversionError>;
+    fn get_crm_sync_state(&self, field: &'static str) -> Result<CrmSyncState, RowConversionError>;
     fn get_bool(&self, field: &'static str) -> Result<bool, RowConversionError>;
     fn get_i32(&self, field: &'static str) -> Result<i32, RowConversionError>;
+    fn get_i64(&self, field: &'static str) -> Result<i64, RowConversionError>;
     fn get_optional_i32(&self, field: &'static str) -> Result<Option<i32>, RowConversionError>;
+    fn get_f64(&self, field: &'static str) -> Result<f64, RowConversionError>;
+    fn get_optional_f64(&self, field: &'static str) -> Result<Option<f64>, RowConversionError>;
+    /// Reads the `postal_code`, `municipality` and `county` columns together
+    fn get_optional_address_region(&self) -> Result<Option<AddressRegion>, RowConversionError>;
 }
 
 impl SafeRowGet for sqlx::sqlite::SqliteRow {
@@ -94,6 +137,11 @@ impl SafeRowGet for sqlx::sqlite::SqliteRow {
             .map_err(|cause| RowConversionError::MissingField { field, cause })
     }
 
+    fn get_date(&self, field: &'static str) -> Result<NaiveDate, RowConversionError> {
+        self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })
+    }
+
     fn get_json<T: serde::de::DeserializeOwned + Default>(&self, field: &'static str) -> Result<T, RowConversionError> {
         let raw_json: String = self.try_get(field)
             .map_err(|cause| RowConversionError::MissingField { field, cause })?;
@@ -219,6 +267,563 @@ impl SafeRowGet for sqlx::sqlite::SqliteRow {
         }
     }
 
+    fn get_audit_action(&self, field: &'static str) -> Result<AuditAction, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "insert" => Ok(AuditAction::Insert),
+            "update" => Ok(AuditAction::Update),
+            "delete" => Ok(AuditAction::Delete),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_attachment_category(&self, field: &'static str) -> Result<AttachmentCategory, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "presentation" => Ok(AttachmentCategory::Presentation),
+            "document" => Ok(AttachmentCategory::Document),
+            "photo" => Ok(AttachmentCategory::Photo),
+            "video" => Ok(AttachmentCategory::Video),
+            "audio" => Ok(AttachmentCategory::Audio),
+            "other" => Ok(AttachmentCategory::Other),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_sponsor_tier(&self, field: &'static str) -> Result<SponsorTier, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "platinum" => Ok(SponsorTier::Platinum),
+            "gold" => Ok(SponsorTier::Gold),
+            "silver" => Ok(SponsorTier::Silver),
+            "bronze" => Ok(SponsorTier::Bronze),
+            "partner" => Ok(SponsorTier::Partner),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_rule_enforcement(&self, field: &'static str) -> Result<RuleEnforcement, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "warn" => Ok(RuleEnforcement::Warn),
+            "block" => Ok(RuleEnforcement::Block),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_domain_verification_status(&self, field: &'static str) -> Result<DomainVerificationStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "pending" => Ok(DomainVerificationStatus::Pending),
+            "verified" => Ok(DomainVerificationStatus::Verified),
+            "failed" => Ok(DomainVerificationStatus::Failed),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_delivery_failure(&self, field: &'static str) -> Result<DeliveryFailure, RowConversionError> {
+        self.get_optional_delivery_failure(field)?
+            .ok_or_else(|| RowConversionError::InvalidEnum { field, value: "NULL".to_string() })
+    }
+
+    fn get_optional_delivery_failure(&self, field: &'static str) -> Result<Option<DeliveryFailure>, RowConversionError> {
+        let raw_value: Option<String> = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_deref() {
+            None => Ok(None),
+            Some("bounce") => Ok(Some(DeliveryFailure::Bounce)),
+            Some("complaint") => Ok(Some(DeliveryFailure::Complaint)),
+            Some(other) => Err(RowConversionError::InvalidEnum {
+                field,
+                value: other.to_string()
+            }),
+        }
+    }
+
+    fn get_digest_frequency(&self, field: &'static str) -> Result<DigestFrequency, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "immediate" => Ok(DigestFrequency::Immediate),
+            "daily" => Ok(DigestFrequency::Daily),
+            "weekly" => Ok(DigestFrequency::Weekly),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_optional_locale(&self, field: &'static str) -> Result<Option<Locale>, RowConversionError> {
+        let raw_value: Option<String> = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_deref() {
+            None => Ok(None),
+            Some("en") => Ok(Some(Locale::En)),
+            Some("nb") => Ok(Some(Locale::Nb)),
+            Some(other) => Err(RowConversionError::InvalidEnum {
+                field,
+                value: other.to_string()
+            }),
+        }
+    }
+
+    fn get_optional_theme(&self, field: &'static str) -> Result<Option<Theme>, RowConversionError> {
+        let raw_value: Option<String> = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_deref() {
+            None => Ok(None),
+            Some("light") => Ok(Some(Theme::Light)),
+            Some("dark") => Ok(Some(Theme::Dark)),
+            Some(other) => Err(RowConversionError::InvalidEnum {
+                field,
+                value: other.to_string()
+            }),
+        }
+    }
+
+    fn get_optional_calendar_view(&self, field: &'static str) -> Result<Option<CalendarView>, RowConversionError> {
+        let raw_value: Option<String> = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_deref() {
+            None => Ok(None),
+            Some("month") => Ok(Some(CalendarView::Month)),
+            Some("week") => Ok(Some(CalendarView::Week)),
+            Some("day") => Ok(Some(CalendarView::Day)),
+            Some(other) => Err(RowConversionError::InvalidEnum {
+                field,
+                value: other.to_string()
+            }),
+        }
+    }
+
+    fn get_organization_invitation_status(&self, field: &'static str) -> Result<OrganizationInvitationStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "pending" => Ok(OrganizationInvitationStatus::Pending),
+            "accepted" => Ok(OrganizationInvitationStatus::Accepted),
+            "revoked" => Ok(OrganizationInvitationStatus::Revoked),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_ownership_transfer_status(&self, field: &'static str) -> Result<OwnershipTransferStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "pending" => Ok(OwnershipTransferStatus::Pending),
+            "accepted" => Ok(OwnershipTransferStatus::Accepted),
+            "declined" => Ok(OwnershipTransferStatus::Declined),
+            "cancelled" => Ok(OwnershipTransferStatus::Cancelled),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_event_submission_status(&self, field: &'static str) -> Result<EventSubmissionStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "pending" => Ok(EventSubmissionStatus::Pending),
+            "approved" => Ok(EventSubmissionStatus::Approved),
+            "rejected" => Ok(EventSubmissionStatus::Rejected),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_consent_kind(&self, field: &'static str) -> Result<ConsentKind, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "photo" => Ok(ConsentKind::Photo),
+            "data_processing" => Ok(ConsentKind::DataProcessing),
+            "marketing" => Ok(ConsentKind::Marketing),
+            "lead_sharing" => Ok(ConsentKind::LeadSharing),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_broadcast_audience(&self, field: &'static str) -> Result<BroadcastAudience, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "all" => Ok(BroadcastAudience::All),
+            "confirmed" => Ok(BroadcastAudience::Confirmed),
+            "waitlisted" => Ok(BroadcastAudience::Waitlisted),
+            "checked_in" => Ok(BroadcastAudience::CheckedIn),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_broadcast_status(&self, field: &'static str) -> Result<BroadcastStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "scheduled" => Ok(BroadcastStatus::Scheduled),
+            "sent" => Ok(BroadcastStatus::Sent),
+            "cancelled" => Ok(BroadcastStatus::Cancelled),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_photo_status(&self, field: &'static str) -> Result<PhotoStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "pending" => Ok(PhotoStatus::Pending),
+            "approved" => Ok(PhotoStatus::Approved),
+            "rejected" => Ok(PhotoStatus::Rejected),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_event_field_type(&self, field: &'static str) -> Result<EventFieldType, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "text" => Ok(EventFieldType::Text),
+            "number" => Ok(EventFieldType::Number),
+            "date" => Ok(EventFieldType::Date),
+            "boolean" => Ok(EventFieldType::Boolean),
+            "select" => Ok(EventFieldType::Select),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_currency(&self, field: &'static str) -> Result<Currency, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "NOK" => Ok(Currency::Nok),
+            "EUR" => Ok(Currency::Eur),
+            "USD" => Ok(Currency::Usd),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_order_status(&self, field: &'static str) -> Result<OrderStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "confirmed" => Ok(OrderStatus::Confirmed),
+            "cancelled" => Ok(OrderStatus::Cancelled),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_invoice_status(&self, field: &'static str) -> Result<InvoiceStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "issued" => Ok(InvoiceStatus::Issued),
+            "paid" => Ok(InvoiceStatus::Paid),
+            "voided" => Ok(InvoiceStatus::Voided),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_refund_status(&self, field: &'static str) -> Result<RefundStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "pending" => Ok(RefundStatus::Pending),
+            "succeeded" => Ok(RefundStatus::Succeeded),
+            "failed" => Ok(RefundStatus::Failed),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_meeting_status(&self, field: &'static str) -> Result<MeetingStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "requested" => Ok(MeetingStatus::Requested),
+            "accepted" => Ok(MeetingStatus::Accepted),
+            "declined" => Ok(MeetingStatus::Declined),
+            "cancelled" => Ok(MeetingStatus::Cancelled),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_web_vital_metric(&self, field: &'static str) -> Result<WebVitalMetric, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "lcp" => Ok(WebVitalMetric::Lcp),
+            "fid" => Ok(WebVitalMetric::Fid),
+            "inp" => Ok(WebVitalMetric::Inp),
+            "cls" => Ok(WebVitalMetric::Cls),
+            "api_latency" => Ok(WebVitalMetric::ApiLatency),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_export_entity(&self, field: &'static str) -> Result<ExportEntity, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "registrations" => Ok(ExportEntity::Registrations),
+            "events" => Ok(ExportEntity::Events),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_export_format(&self, field: &'static str) -> Result<ExportFormat, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_optional_export_run_status(&self, field: &'static str) -> Result<Option<ExportRunStatus>, RowConversionError> {
+        let raw_value: Option<String> = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_deref() {
+            None => Ok(None),
+            Some("succeeded") => Ok(Some(ExportRunStatus::Succeeded)),
+            Some("failed") => Ok(Some(ExportRunStatus::Failed)),
+            Some(other) => Err(RowConversionError::InvalidEnum {
+                field,
+                value: other.to_string()
+            }),
+        }
+    }
+
+    fn get_crm_provider(&self, field: &'static str) -> Result<CrmProvider, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "hubspot" => Ok(CrmProvider::HubSpot),
+            "dynamics" => Ok(CrmProvider::Dynamics),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_crm_conflict_policy(&self, field: &'static str) -> Result<CrmConflictPolicy, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "aqio_wins" => Ok(CrmConflictPolicy::AqioWins),
+            "crm_wins" => Ok(CrmConflictPolicy::CrmWins),
+            "fill_blanks" => Ok(CrmConflictPolicy::FillBlanks),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_optional_crm_sync_run_status(&self, field: &'static str) -> Result<Option<CrmSyncRunStatus>, RowConversionError> {
+        let raw_value: Option<String> = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_deref() {
+            None => Ok(None),
+            Some("succeeded") => Ok(Some(CrmSyncRunStatus::Succeeded)),
+            Some("partially_failed") => Ok(Some(CrmSyncRunStatus::PartiallyFailed)),
+            Some("failed") => Ok(Some(CrmSyncRunStatus::Failed)),
+            Some(other) => Err(RowConversionError::InvalidEnum {
+                field,
+                value: other.to_string()
+            }),
+        }
+    }
+
+    fn get_crm_record_kind(&self, field: &'static str) -> Result<CrmRecordKind, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "contact" => Ok(CrmRecordKind::Contact),
+            "registration" => Ok(CrmRecordKind::Registration),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_crm_sync_state(&self, field: &'static str) -> Result<CrmSyncState, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "synced" => Ok(CrmSyncState::Synced),
+            "conflict" => Ok(CrmSyncState::Conflict),
+            "failed" => Ok(CrmSyncState::Failed),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_report_target(&self, field: &'static str) -> Result<ReportTarget, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "comment" => Ok(ReportTarget::Comment),
+            "event" => Ok(ReportTarget::Event),
+            "profile" => Ok(ReportTarget::Profile),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_report_reason(&self, field: &'static str) -> Result<ReportReason, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "spam" => Ok(ReportReason::Spam),
+            "harassment" => Ok(ReportReason::Harassment),
+            "inappropriate" => Ok(ReportReason::Inappropriate),
+            "other" => Ok(ReportReason::Other),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_report_status(&self, field: &'static str) -> Result<ReportStatus, RowConversionError> {
+        let raw_value: String = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_str() {
+            "open" => Ok(ReportStatus::Open),
+            "actioned" => Ok(ReportStatus::Actioned),
+            "dismissed" => Ok(ReportStatus::Dismissed),
+            _ => Err(RowConversionError::InvalidEnum {
+                field,
+                value: raw_value
+            }),
+        }
+    }
+
+    fn get_optional_moderation_action(&self, field: &'static str) -> Result<Option<ModerationAction>, RowConversionError> {
+        let raw_value: Option<String> = self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })?;
+
+        match raw_value.as_deref() {
+            None => Ok(None),
+            Some("hide") => Ok(Some(ModerationAction::Hide)),
+            Some("warn") => Ok(Some(ModerationAction::Warn)),
+            Some("ban") => Ok(Some(ModerationAction::Ban)),
+            Some("dismiss") => Ok(Some(ModerationAction::Dismiss)),
+            Some(other) => Err(RowConversionError::InvalidEnum {
+                field,
+                value: other.to_string()
+            }),
+        }
+    }
+
     fn get_bool(&self, field: &'static str) -> Result<bool, RowConversionError> {
         self.try_get(field)
             .map_err(|cause| RowConversionError::MissingField { field, cause })
@@ -230,9 +835,35 @@ impl SafeRowGet for sqlx::sqlite::SqliteRow {
         Ok(raw_value as i32)
     }
 
+    fn get_i64(&self, field: &'static str) -> Result<i64, RowConversionError> {
+        self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })
+    }
+
     fn get_optional_i32(&self, field: &'static str) -> Result<Option<i32>, RowConversionError> {
         let raw_value: Option<i64> = self.try_get(field)
             .map_err(|cause| RowConversionError::MissingField { field, cause })?;
         Ok(raw_value.map(|v| v as i32))
     }
+
+    fn get_f64(&self, field: &'static str) -> Result<f64, RowConversionError> {
+        self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })
+    }
+
+    fn get_optional_f64(&self, field: &'static str) -> Result<Option<f64>, RowConversionError> {
+        self.try_get(field)
+            .map_err(|cause| RowConversionError::MissingField { field, cause })
+    }
+
+    fn get_optional_address_region(&self) -> Result<Option<AddressRegion>, RowConversionError> {
+        let postal_code = self.get_optional_string("postal_code")?;
+        let municipality = self.get_optional_string("municipality")?;
+        let county = self.get_optional_string("county")?;
+
+        Ok(match (postal_code, municipality, county) {
+            (Some(postal_code), Some(municipality), Some(county)) => Some(AddressRegion { postal_code, municipality, county }),
+            _ => None,
+        })
+    }
 }
\ No newline at end of file
@@ -1,8 +1,8 @@
 use crate::domain::errors::{InfrastructureError, SqliteForeignKeyDiagnostic};
 use crate::domain::repositories::UserRepository;
-use crate::infrastructure::persistence::mapping::user_role_to_string;
+use crate::infrastructure::persistence::mapping::{calendar_view_to_string, locale_to_string, theme_to_string, user_role_to_string};
 use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
-use aqio_core::{DomainResult, PaginatedResult, PaginationParams, User};
+use aqio_core::{DomainResult, PaginatedResult, PaginationParams, User, UserMatch};
 use async_trait::async_trait;
 use sqlx::{Pool, Row, Sqlite};
 use tracing::{debug, instrument};
@@ -28,11 +28,22 @@ impl SqliteUserRepository {
             company_id: row.get_optional_uuid("company_id")?,
             role: row.get_user_role("role")?,
             is_active: row.get_bool("is_active")?,
+            timezone: row.get_optional_string("timezone")?,
+            locale: row.get_optional_locale("locale")?,
+            theme: row.get_optional_theme("theme")?,
+            calendar_view: row.get_optional_calendar_view("calendar_view")?,
             created_at: row.get_datetime("created_at")?,
             updated_at: row.get_datetime("updated_at")?,
         })
     }
 
+    fn row_to_match(row: &sqlx::sqlite::SqliteRow) -> Result<UserMatch, RowConversionError> {
+        Ok(UserMatch {
+            user: Self::row_to_user(row)?,
+            company_name: row.get_optional_string("company_name")?,
+        })
+    }
+
     // Helper method to convert RowConversionError to InfrastructureError
     fn conversion_error_to_infrastructure_error(error: RowConversionError) -> InfrastructureError {
         InfrastructureError::from(error)
@@ -67,7 +78,7 @@ impl UserRepository for SqliteUserRepository {
         debug!("Creating user with id: {}", user.id);
 
         let result = sqlx::query(
-            "INSERT INTO users (id, keycloak_id, email, name, company_id, role, is_active, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            "INSERT INTO users (id, keycloak_id, email, name, company_id, role, is_active, timezone, locale, theme, calendar_view, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
         )
         .bind(user.id.to_string())
         .bind(&user.keycloak_id)
@@ -76,6 +87,10 @@ impl UserRepository for SqliteUserRepository {
         .bind(user.company_id.map(|id| id.to_string()))
         .bind(user_role_to_string(&user.role))
         .bind(user.is_active)
+        .bind(&user.timezone)
+        .bind(user.locale.map(locale_to_string))
+        .bind(user.theme.map(theme_to_string))
+        .bind(user.calendar_view.map(calendar_view_to_string))
         .bind(user.created_at.naive_utc())
         .bind(user.updated_at.naive_utc())
         .execute(&self.pool)
@@ -106,7 +121,7 @@ impl UserRepository for SqliteUserRepository {
         debug!("Updating user with id: {}", user.id);
 
         let result = sqlx::query(
-            "UPDATE users SET keycloak_id = ?, email = ?, name = ?, company_id = ?, role = ?, is_active = ?, updated_at = ? WHERE id = ?"
+            "UPDATE users SET keycloak_id = ?, email = ?, name = ?, company_id = ?, role = ?, is_active = ?, timezone = ?, locale = ?, theme = ?, calendar_view = ?, updated_at = ? WHERE id = ?"
         )
         .bind(&user.keycloak_id)
         .bind(&user.email)
@@ -114,6 +129,10 @@ impl UserRepository for SqliteUserRepository {
         .bind(user.company_id.map(|id| id.to_string()))
         .bind(user_role_to_string(&user.role))
         .bind(user.is_active)
+        .bind(&user.timezone)
+        .bind(user.locale.map(locale_to_string))
+        .bind(user.theme.map(theme_to_string))
+        .bind(user.calendar_view.map(calendar_view_to_string))
         .bind(user.updated_at.naive_utc())
         .bind(user.id.to_string())
         .execute(&self.pool)
@@ -149,7 +168,7 @@ impl UserRepository for SqliteUserRepository {
         debug!("Finding user by id: {}", id);
 
         let id_string = id.to_string();
-        let result = sqlx::query("SELECT id, keycloak_id, email, name, company_id, role, is_active, created_at, updated_at FROM users WHERE id = ?")
+        let result = sqlx::query("SELECT id, keycloak_id, email, name, company_id, role, is_active, timezone, locale, theme, calendar_view, created_at, updated_at FROM users WHERE id = ?")
             .bind(id_string)
             .fetch_optional(&self.pool)
             .await;
@@ -181,11 +200,47 @@ impl UserRepository for SqliteUserRepository {
         }
     }
 
+    #[instrument(skip(self, ids))]
+    async fn find_by_ids(&self, ids: &[Uuid]) -> DomainResult<Vec<User>> {
+        debug!("Finding {} users by id", ids.len());
+
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new(
+            "SELECT id, keycloak_id, email, name, company_id, role, is_active, timezone, locale, theme, calendar_view, created_at, updated_at FROM users WHERE id IN ("
+        );
+        let mut separated = query_builder.separated(", ");
+        for id in ids {
+            separated.push_bind(id.to_string());
+        }
+        query_builder.push(")");
+
+        let result = query_builder.build().fetch_all(&self.pool).await;
+
+        match result {
+            Ok(rows) => {
+                let users: Result<Vec<User>, RowConversionError> = rows.iter()
+                    .map(Self::row_to_user)
+                    .collect();
+                users.map_err(|e| Self::conversion_error_to_infrastructure_error(e).into())
+            }
+            Err(e) => {
+                let infrastructure_error = InfrastructureError::from(e);
+                match infrastructure_error {
+                    InfrastructureError::DomainError { source } => Err(source),
+                    other => Err(other.into()),
+                }
+            }
+        }
+    }
+
     #[instrument(skip(self))]
     async fn find_by_keycloak_id(&self, keycloak_id: &str) -> DomainResult<Option<User>> {
         debug!("Finding user by keycloak_id: {}", keycloak_id);
 
-        let result = sqlx::query("SELECT id, keycloak_id, email, name, company_id, role, is_active, created_at, updated_at FROM users WHERE keycloak_id = ?")
+        let result = sqlx::query("SELECT id, keycloak_id, email, name, company_id, role, is_active, timezone, locale, theme, calendar_view, created_at, updated_at FROM users WHERE keycloak_id = ?")
             .bind(keycloak_id)
             .fetch_optional(&self.pool)
             .await;
@@ -221,7 +276,7 @@ impl UserRepository for SqliteUserRepository {
     async fn find_by_email(&self, email: &str) -> DomainResult<Option<User>> {
         debug!("Finding user by email: {}", email);
 
-        let result = sqlx::query("SELECT id, keycloak_id, email, name, company_id, role, is_active, created_at, updated_at FROM users WHERE email = ?")
+        let result = sqlx::query("SELECT id, keycloak_id, email, name, company_id, role, is_active, timezone, locale, theme, calendar_view, created_at, updated_at FROM users WHERE email = ?")
             .bind(email)
             .fetch_optional(&self.pool)
             .await;
@@ -297,7 +352,7 @@ impl UserRepository for SqliteUserRepository {
         };
 
         // Fetch the users with pagination
-        let result = sqlx::query("SELECT id, keycloak_id, email, name, company_id, role, is_active, created_at, updated_at FROM users ORDER BY created_at DESC LIMIT ? OFFSET ?")
+        let result = sqlx::query("SELECT id, keycloak_id, email, name, company_id, role, is_active, timezone, locale, theme, calendar_view, created_at, updated_at FROM users ORDER BY created_at DESC LIMIT ? OFFSET ?")
             .bind(pagination.limit)
             .bind(pagination.offset)
             .fetch_all(&self.pool)
@@ -328,6 +383,46 @@ impl UserRepository for SqliteUserRepository {
         }
     }
 
+    #[instrument(skip(self))]
+    async fn search(&self, query: &str, limit: i64) -> DomainResult<Vec<UserMatch>> {
+        debug!("Searching users for: {}", query);
+
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        // The trigram index needs three characters; shorter queries match
+        // prefixes only, by scanning the index
+        let condition = if query.chars().count() >= 3 {
+            "user_search MATCH ?1"
+        } else {
+            "(user_search.name LIKE ?2 ESCAPE '\\' OR user_search.email LIKE ?2 ESCAPE '\\' OR user_search.company_name LIKE ?2 ESCAPE '\\')"
+        };
+        let sql = format!(
+            "SELECT u.id, u.keycloak_id, u.email, u.name, u.company_id, u.role, u.is_active, u.timezone, u.locale, u.theme, u.calendar_view, u.created_at, u.updated_at, \
+                    user_search.company_name \
+             FROM user_search JOIN users u ON u.id = user_search.user_id \
+             WHERE {} AND u.is_active = 1 \
+             ORDER BY CASE WHEN user_search.name LIKE ?2 ESCAPE '\\' THEN 0 WHEN user_search.email LIKE ?2 ESCAPE '\\' THEN 1 ELSE 2 END, u.name \
+             LIMIT ?3",
+            condition
+        );
+        let prefix = format!("{}%", query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"));
+
+        let rows = sqlx::query(&sql)
+            // A quoted phrase, so the query's own quotes and operators are literal
+            .bind(format!("\"{}\"", query.replace('"', "\"\"")))
+            .bind(prefix)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        let matches: Result<Vec<UserMatch>, RowConversionError> = rows.iter().map(Self::row_to_match).collect();
+        debug!("Found {} users", rows.len());
+        matches.map_err(|e| Self::conversion_error_to_infrastructure_error(e).into())
+    }
+
     #[instrument(skip(self))]
     async fn exists(&self, id: Uuid) -> DomainResult<bool> {
         debug!("Checking if user exists with id: {}", id);
@@ -390,53 +485,12 @@ mod tests {
     use super::*;
     use aqio_core::UserRole;
     use chrono::Utc;
-    use sqlx::{Pool, Sqlite};
+    use crate::testing::TestDb;
     use uuid::Uuid;
 
-    // Test helper to create an in-memory SQLite database with schema
-    async fn create_test_db() -> Pool<Sqlite> {
-        let pool = sqlx::SqlitePool::connect(":memory:").await.unwrap();
-
-        // Create companies table first (users reference it)
-        sqlx::query(
-            r#"
-            CREATE TABLE companies (
-                id TEXT PRIMARY KEY,
-                name TEXT NOT NULL,
-                org_number TEXT UNIQUE,
-                location TEXT,
-                industry_type TEXT NOT NULL CHECK(industry_type IN ('Salmon', 'Trout', 'Other')),
-                industry_type_other TEXT,
-                website TEXT,
-                phone TEXT,
-                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
-            )
-        "#,
-        )
-        .execute(&pool)
-        .await
-        .unwrap();
-
-        // Create users table with the full schema
-        sqlx::query(r#"
-            CREATE TABLE users (
-                id TEXT PRIMARY KEY,
-                keycloak_id TEXT UNIQUE NOT NULL,
-                email TEXT UNIQUE NOT NULL,
-                name TEXT NOT NULL,
-                company_id TEXT REFERENCES companies(id),
-                role TEXT NOT NULL CHECK(role IN ('admin', 'organizer', 'participant')) DEFAULT 'participant',
-                is_active BOOLEAN NOT NULL DEFAULT TRUE,
-                created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
-            )
-        "#)
-        .execute(&pool)
-        .await
-        .unwrap();
-
-        pool
+    // Test helper to create an isolated database with the real schema
+    async fn create_test_db() -> TestDb {
+        TestDb::in_memory().await
     }
 
     // Helper function to create a test user
@@ -450,6 +504,10 @@ mod tests {
             company_id: None,
             role: UserRole::Participant,
             is_active: true,
+            timezone: None,
+            locale: None,
+            theme: None,
+            calendar_view: None,
             created_at: now,
             updated_at: now,
         }
@@ -457,8 +515,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_create_and_find_user() {
-        let pool = create_test_db().await;
-        let repository = SqliteUserRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
         let user = create_test_user("John Doe", "john@example.com");
         let user_id = user.id;
 
@@ -478,10 +536,32 @@ mod tests {
         assert!(found_user.is_active);
     }
 
+    #[tokio::test]
+    async fn test_find_by_ids() {
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
+        let alice = create_test_user("Alice", "alice@example.com");
+        let bob = create_test_user("Bob", "bob@example.com");
+        let carol = create_test_user("Carol", "carol@example.com");
+
+        for user in [&alice, &bob, &carol] {
+            repository.create(user).await.unwrap();
+        }
+
+        // Unknown IDs are skipped rather than failing the batch
+        let found = repository.find_by_ids(&[alice.id, carol.id, Uuid::new_v4()]).await.unwrap();
+        let mut names: Vec<_> = found.iter().map(|u| u.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["Alice", "Carol"]);
+
+        let empty = repository.find_by_ids(&[]).await.unwrap();
+        assert!(empty.is_empty());
+    }
+
     #[tokio::test]
     async fn test_find_by_keycloak_id() {
-        let pool = create_test_db().await;
-        let repository = SqliteUserRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
         let user = create_test_user("Jane Doe", "jane@example.com");
 
         repository.create(&user).await.unwrap();
@@ -496,8 +576,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_find_by_email() {
-        let pool = create_test_db().await;
-        let repository = SqliteUserRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
         let user = create_test_user("Bob Smith", "bob@example.com");
 
         repository.create(&user).await.unwrap();
@@ -507,10 +587,54 @@ mod tests {
         assert_eq!(found_user.unwrap().name, "Bob Smith");
     }
 
+    #[tokio::test]
+    async fn test_search_matches_name_email_and_company() {
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
+        let company = Uuid::new_v4();
+        db.execute(&format!(
+            "INSERT INTO companies (id, name, industry_type) VALUES ('{}', 'SalMar', 'Salmon')",
+            company
+        ))
+        .await;
+        let hanne = create_test_user("Hanne Berg", "hanne@example.com");
+        let mut ola = create_test_user("Ola Hansen", "ola@fjord.no");
+        ola.company_id = Some(company);
+        let mut retired = create_test_user("Hans Retired", "hans@example.com");
+        retired.is_active = false;
+        for user in [&hanne, &ola, &retired] {
+            repository.create(user).await.unwrap();
+        }
+
+        let names = |matches: Vec<UserMatch>| matches.into_iter().map(|m| m.user.name).collect::<Vec<_>>();
+        // Names starting with the query come first; inactive users are left out
+        assert_eq!(names(repository.search("HAN", 10).await.unwrap()), vec!["Hanne Berg", "Ola Hansen"]);
+        assert_eq!(names(repository.search("fjord", 10).await.unwrap()), vec!["Ola Hansen"]);
+        let by_company = repository.search("salm", 10).await.unwrap();
+        assert_eq!(by_company[0].user.id, ola.id);
+        assert_eq!(by_company[0].company_name.as_deref(), Some("SalMar"));
+        assert_eq!(repository.search("han", 1).await.unwrap().len(), 1);
+
+        // Short queries match prefixes only
+        assert_eq!(names(repository.search("ol", 10).await.unwrap()), vec!["Ola Hansen"]);
+        assert!(repository.search("la", 10).await.unwrap().is_empty());
+        assert!(repository.search("%", 10).await.unwrap().is_empty());
+        assert!(repository.search("\"han", 10).await.unwrap().is_empty());
+
+        // The index follows renames and deletes
+        db.execute(&format!("UPDATE companies SET name = 'Lerøy' WHERE id = '{}'", company)).await;
+        assert_eq!(names(repository.search("lerøy", 10).await.unwrap()), vec!["Ola Hansen"]);
+        ola.name = "Ola Nilsen".to_string();
+        repository.update(&ola).await.unwrap();
+        assert_eq!(names(repository.search("han", 10).await.unwrap()), vec!["Hanne Berg"]);
+        repository.delete(hanne.id).await.unwrap();
+        assert!(repository.search("hanne", 10).await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_update_user() {
-        let pool = create_test_db().await;
-        let repository = SqliteUserRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
         let mut user = create_test_user("Alice Johnson", "alice@example.com");
         let user_id = user.id;
 
@@ -521,6 +645,10 @@ mod tests {
         user.name = "Alice Smith".to_string();
         user.role = UserRole::Organizer;
         user.is_active = false;
+        user.timezone = Some("America/Halifax".to_string());
+        user.locale = Some(aqio_core::Locale::Nb);
+        user.theme = Some(aqio_core::Theme::Dark);
+        user.calendar_view = Some(aqio_core::CalendarView::Week);
         user.updated_at = Utc::now();
 
         let result = repository.update(&user).await;
@@ -531,12 +659,16 @@ mod tests {
         assert_eq!(found_user.name, "Alice Smith");
         assert!(matches!(found_user.role, UserRole::Organizer));
         assert!(!found_user.is_active);
+        assert_eq!(found_user.timezone.as_deref(), Some("America/Halifax"));
+        assert_eq!(found_user.locale, Some(aqio_core::Locale::Nb));
+        assert_eq!(found_user.theme, Some(aqio_core::Theme::Dark));
+        assert_eq!(found_user.calendar_view, Some(aqio_core::CalendarView::Week));
     }
 
     #[tokio::test]
     async fn test_delete_user() {
-        let pool = create_test_db().await;
-        let repository = SqliteUserRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
         let user = create_test_user("Delete Me", "delete@example.com");
         let user_id = user.id;
 
@@ -557,8 +689,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_email_exists() {
-        let pool = create_test_db().await;
-        let repository = SqliteUserRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
         let user = create_test_user("Email Test", "email-test@example.com");
 
         // Check email doesn't exist initially
@@ -583,8 +715,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_user_roles() {
-        let pool = create_test_db().await;
-        let repository = SqliteUserRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
 
         // Test admin role
         let mut admin_user = create_test_user("Admin User", "admin@example.com");
@@ -613,8 +745,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_unique_email_constraint_violation() {
-        let pool = create_test_db().await;
-        let repository = SqliteUserRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
         
         // Create first user
         let user1 = create_test_user("John Doe", "test@example.com");
@@ -649,8 +781,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_unique_keycloak_id_constraint_violation() {
-        let pool = create_test_db().await;
-        let repository = SqliteUserRepository::new(pool);
+        let db = create_test_db().await;
+        let repository = SqliteUserRepository::new(db.pool().clone());
         
         // Create first user
         let user1 = create_test_user("John Doe", "john@example.com");
@@ -687,7 +819,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_check_constraint_violation() {
-        let pool = create_test_db().await;
+        let db = create_test_db().await;
         
         // Try to insert a user with invalid role directly via SQL to trigger check constraint
         let result = sqlx::query(
@@ -701,7 +833,7 @@ mod tests {
         .bind(true)
         .bind(Utc::now().naive_utc())
         .bind(Utc::now().naive_utc())
-        .execute(&pool)
+        .execute(db.pool())
         .await;
             
         assert!(result.is_err(), "Insert with invalid role should fail");
@@ -732,7 +864,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_not_null_constraint_violation() {
-        let pool = create_test_db().await;
+        let db = create_test_db().await;
         
         // Try to insert a user with NULL name field directly via SQL
         let result = sqlx::query(
@@ -746,7 +878,7 @@ mod tests {
         .bind(true)
         .bind(Utc::now().naive_utc())
         .bind(Utc::now().naive_utc())
-        .execute(&pool)
+        .execute(db.pool())
         .await;
             
         assert!(result.is_err(), "Insert with NULL name should fail");
@@ -0,0 +1,129 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    repositories::WebVitalRepository,
+};
+use crate::infrastructure::persistence::mapping::web_vital_metric_to_string;
+use crate::infrastructure::persistence::sqlite::types::{SafeRowGet, RowConversionError};
+use aqio_core::{DomainResult, WebVitalSample};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Sqlite};
+use tracing::{debug, instrument};
+
+const WEB_VITAL_COLUMNS: &str = "id, metric, value, release, route, recorded_at";
+
+#[derive(Clone)]
+pub struct SqliteWebVitalRepository {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteWebVitalRepository {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+
+    // Helper method to convert database row to WebVitalSample using SafeRowGet
+    fn row_to_sample(row: &sqlx::sqlite::SqliteRow) -> Result<WebVitalSample, RowConversionError> {
+        Ok(WebVitalSample {
+            id: row.get_uuid("id")?,
+            metric: row.get_web_vital_metric("metric")?,
+            value: row.get_f64("value")?,
+            release: row.get_string("release")?,
+            route: row.get_optional_string("route")?,
+            recorded_at: row.get_datetime("recorded_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl WebVitalRepository for SqliteWebVitalRepository {
+    #[instrument(skip(self, samples))]
+    async fn record(&self, samples: &[WebVitalSample]) -> DomainResult<()> {
+        debug!("Recording {} web vital sample(s)", samples.len());
+
+        let mut tx = self.pool.begin().await.map_err(InfrastructureError::from)?;
+        for sample in samples {
+            sqlx::query(&format!("INSERT INTO web_vitals ({}) VALUES (?, ?, ?, ?, ?, ?)", WEB_VITAL_COLUMNS))
+                .bind(sample.id.to_string())
+                .bind(web_vital_metric_to_string(sample.metric))
+                .bind(sample.value)
+                .bind(&sample.release)
+                .bind(sample.route.as_deref())
+                .bind(sample.recorded_at.naive_utc())
+                .execute(&mut *tx)
+                .await
+                .map_err(InfrastructureError::from)?;
+        }
+        tx.commit().await.map_err(InfrastructureError::from)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_since(&self, since: DateTime<Utc>) -> DomainResult<Vec<WebVitalSample>> {
+        debug!("Finding web vital samples since {}", since);
+
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM web_vitals WHERE recorded_at >= ? ORDER BY recorded_at",
+            WEB_VITAL_COLUMNS
+        ))
+        .bind(since.naive_utc())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(InfrastructureError::from)?;
+
+        rows.iter()
+            .map(|row| Self::row_to_sample(row).map_err(|e| InfrastructureError::from(e).into()))
+            .collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_before(&self, before: DateTime<Utc>) -> DomainResult<u64> {
+        debug!("Deleting web vital samples recorded before {}", before);
+
+        let result = sqlx::query("DELETE FROM web_vitals WHERE recorded_at < ?")
+            .bind(before.naive_utc())
+            .execute(&self.pool)
+            .await
+            .map_err(InfrastructureError::from)?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestDb;
+    use aqio_core::WebVitalMetric;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_samples_are_found_since_a_time_and_pruned_before_one() {
+        let db = TestDb::in_memory().await;
+        let repository = SqliteWebVitalRepository::new(db.pool().clone());
+        let now = Utc::now();
+
+        let old = WebVitalSample {
+            recorded_at: now - Duration::days(40),
+            ..WebVitalSample::new(WebVitalMetric::Lcp, 2400.0, "0.3.0")
+        };
+        let recent = WebVitalSample {
+            route: Some("/events".to_string()),
+            recorded_at: now - Duration::hours(1),
+            ..WebVitalSample::new(WebVitalMetric::ApiLatency, 85.5, "0.4.0")
+        };
+        repository.record(&[old.clone(), recent.clone()]).await.unwrap();
+
+        let found = repository.find_since(now - Duration::days(7)).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, recent.id);
+        assert_eq!(found[0].metric, WebVitalMetric::ApiLatency);
+        assert_eq!(found[0].value, 85.5);
+        assert_eq!(found[0].release, "0.4.0");
+        assert_eq!(found[0].route.as_deref(), Some("/events"));
+
+        assert_eq!(repository.delete_before(now - Duration::days(30)).await.unwrap(), 1);
+        assert_eq!(repository.find_since(now - Duration::days(60)).await.unwrap().len(), 1);
+    }
+}
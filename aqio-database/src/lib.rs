@@ -3,12 +3,17 @@ use sqlx::{Pool, Sqlite, SqlitePool};
 pub mod domain;
 pub mod infrastructure;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing;
+
 // Re-export commonly used types for convenience
 pub use aqio_core::*;
 pub use domain::{
     errors::{InfrastructureError, InfrastructureResult},
 };
 pub use infrastructure::persistence::sqlite::{RepositoryFactory, AllRepositories};
+pub use infrastructure::persistence::instrumentation::{Instrumented, QueryMetrics, QueryMethodStats};
+pub use infrastructure::persistence::faults::{Fault, FaultInjected, FaultInjector, FaultPlan};
 
 #[derive(Clone)]
 pub struct Database {
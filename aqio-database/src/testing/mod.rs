@@ -0,0 +1,6 @@
+// Testing utilities shared by repository tests and downstream crates
+// Enable the `test-utils` feature to use these outside of aqio-database's own tests
+
+pub mod test_db;
+
+pub use test_db::TestDb;
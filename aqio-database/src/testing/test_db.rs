@@ -0,0 +1,162 @@
+use std::str::FromStr;
+
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{Pool, Sqlite};
+use tempfile::TempDir;
+use uuid::Uuid;
+
+use crate::infrastructure::persistence::sqlite::RepositoryFactory;
+
+/// Isolated SQLite database for a single test, built from the real migrations
+///
+/// Every `TestDb` gets its own database, so tests can run in parallel without
+/// sharing state. Because the schema comes from `./migrations`, tests always
+/// exercise the same tables, constraints and seed data as production.
+pub struct TestDb {
+    pool: Pool<Sqlite>,
+    // Keeps the temp directory alive for file-backed databases
+    _temp_dir: Option<TempDir>,
+}
+
+impl TestDb {
+    /// Create a fresh in-memory database with all migrations applied
+    pub async fn in_memory() -> Self {
+        // `:memory:` gets a unique shared-cache name per options instance, so all
+        // pool connections see the same database. Keep one connection open so the
+        // database isn't dropped when the pool goes idle.
+        let options = SqliteConnectOptions::from_str(":memory:")
+            .expect("valid in-memory connection options");
+
+        let pool = SqlitePoolOptions::new()
+            .min_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await
+            .expect("failed to open in-memory test database");
+
+        Self::migrate(&pool).await;
+
+        Self { pool, _temp_dir: None }
+    }
+
+    /// Create a fresh file-backed database in a temp directory with all migrations applied
+    ///
+    /// Useful for tests that need real file semantics (multiple connections, WAL,
+    /// reopening the database). The file is removed when the `TestDb` is dropped.
+    pub async fn temp_file() -> Self {
+        let temp_dir = TempDir::new().expect("failed to create temp directory for test database");
+        let options = SqliteConnectOptions::new()
+            .filename(temp_dir.path().join("aqio-test.db"))
+            .create_if_missing(true);
+
+        let pool = SqlitePoolOptions::new()
+            .connect_with(options)
+            .await
+            .expect("failed to open file-backed test database");
+
+        Self::migrate(&pool).await;
+
+        Self { pool, _temp_dir: Some(temp_dir) }
+    }
+
+    async fn migrate(pool: &Pool<Sqlite>) {
+        sqlx::migrate!("./migrations")
+            .run(pool)
+            .await
+            .expect("failed to apply migrations to test database");
+    }
+
+    /// Get the underlying connection pool
+    pub fn pool(&self) -> &Pool<Sqlite> {
+        &self.pool
+    }
+
+    /// Create a repository factory backed by this database
+    pub fn repositories(&self) -> RepositoryFactory {
+        RepositoryFactory::new(self.pool.clone())
+    }
+
+    /// Execute a raw SQL statement, e.g. to clear seed data before a test
+    pub async fn execute(&self, sql: &str) {
+        sqlx::query(sql)
+            .execute(&self.pool)
+            .await
+            .unwrap_or_else(|e| panic!("failed to execute '{}': {}", sql, e));
+    }
+
+    /// Insert an active user and return its ID
+    ///
+    /// Satisfies `users(id)` foreign keys (organizers, inviters, registrants)
+    /// without going through the user repository under test.
+    pub async fn seed_user(&self) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO users (id, keycloak_id, email, name, role) VALUES (?, ?, ?, ?, 'organizer')")
+            .bind(id.to_string())
+            .bind(format!("keycloak-{}", id))
+            .bind(format!("{}@example.com", id))
+            .bind("Test User")
+            .execute(&self.pool)
+            .await
+            .expect("failed to seed test user");
+        id
+    }
+
+    /// Insert a published event organized by `organizer_id` and return its ID
+    pub async fn seed_event(&self, organizer_id: Uuid) -> Uuid {
+        let id = Uuid::new_v4();
+        let start = chrono::Utc::now() + chrono::Duration::days(7);
+        sqlx::query("INSERT INTO events (id, title, description, category_id, start_date, end_date, organizer_id, status) VALUES (?, ?, ?, 'conf', ?, ?, ?, 'published')")
+            .bind(id.to_string())
+            .bind("Seeded Event")
+            .bind("Event seeded for tests")
+            .bind(start.naive_utc())
+            .bind((start + chrono::Duration::hours(2)).naive_utc())
+            .bind(organizer_id.to_string())
+            .execute(&self.pool)
+            .await
+            .expect("failed to seed test event");
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn table_count(pool: &Pool<Sqlite>, table: &str) -> i64 {
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_applies_migrations() {
+        let db = TestDb::in_memory().await;
+
+        // Default categories come from the migrations
+        assert_eq!(table_count(db.pool(), "event_categories").await, 6);
+        assert_eq!(table_count(db.pool(), "events").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_databases_are_isolated() {
+        let first = TestDb::in_memory().await;
+        let second = TestDb::in_memory().await;
+
+        first.seed_user().await;
+
+        assert_eq!(table_count(first.pool(), "users").await, 1);
+        assert_eq!(table_count(second.pool(), "users").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_temp_file_database() {
+        let db = TestDb::temp_file().await;
+        let organizer_id = db.seed_user().await;
+        db.seed_event(organizer_id).await;
+
+        assert_eq!(table_count(db.pool(), "events").await, 1);
+    }
+}
@@ -0,0 +1,191 @@
+// A headless Chrome session over WebDriver, with the few steps the flows share:
+// signing in as a mock user, filling form fields by their label and waiting
+// for the web app to catch up.
+
+use std::env;
+use std::ops::Deref;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use serde_json::json;
+use thirtyfour::prelude::*;
+use uuid::Uuid;
+
+use crate::stack::Stack;
+
+const DEFAULT_WEBDRIVER_URL: &str = "http://localhost:4444";
+
+const NAVIGATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Same key and shape the web app reads its session from
+const SET_SESSION: &str = "localStorage.setItem('aqio_auth_state', JSON.stringify({ token: arguments[0] }));";
+
+// Dioxus listens for bubbling input and change events, which setting
+// `value` alone doesn't fire. Typing into date inputs also depends on the
+// browser's locale, so values are set directly.
+const SET_VALUE: &str = "const [field, value] = arguments;
+field.value = value;
+field.dispatchEvent(new Event('input', { bubbles: true }));
+field.dispatchEvent(new Event('change', { bubbles: true }));";
+
+/// The users the API's mock authentication knows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockUser {
+    /// Administrator and organizer
+    Admin,
+    Organizer,
+    Participant,
+}
+
+impl MockUser {
+    pub fn username(self) -> &'static str {
+        match self {
+            MockUser::Admin => "admin-user",
+            MockUser::Organizer => "john-doe",
+            MockUser::Participant => "jane-smith",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            MockUser::Admin => "Admin User",
+            MockUser::Organizer => "John Doe",
+            MockUser::Participant => "Jane Smith",
+        }
+    }
+
+    pub fn token(self) -> String {
+        format!("mock-{}", self.username())
+    }
+}
+
+pub struct Browser {
+    driver: WebDriver,
+}
+
+impl Browser {
+    /// Opens a session on the WebDriver server at `WEBDRIVER_URL`
+    pub async fn open() -> Result<Self> {
+        let url = env::var("WEBDRIVER_URL").unwrap_or_else(|_| DEFAULT_WEBDRIVER_URL.to_string());
+        let mut caps = DesiredCapabilities::chrome();
+        caps.set_headless()?;
+        caps.add_arg("--window-size=1280,960")?;
+        let driver = WebDriver::new(&url, caps).await?;
+        Ok(Self { driver })
+    }
+
+    /// Ends the session; call it whether the flow passed or not
+    pub async fn close(self) -> Result<()> {
+        self.driver.quit().await?;
+        Ok(())
+    }
+
+    /// Stores `user`'s session the way the login flow does, then checks the
+    /// API accepted it by opening their settings
+    pub async fn sign_in(&self, stack: &Stack, user: MockUser) -> Result<()> {
+        self.goto(stack.url("/")).await?;
+        self.execute(SET_SESSION, vec![json!(user.token())]).await?;
+        self.goto(stack.url("/settings")).await?;
+
+        let name = self.find_eventually(By::Css("#settings-name")).await?;
+        let started = Instant::now();
+        while name.value().await?.as_deref() != Some(user.name()) {
+            if started.elapsed() > NAVIGATION_TIMEOUT {
+                bail!("settings never showed {} as signed in", user.name());
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        Ok(())
+    }
+
+    /// The input, textarea or select labelled `label`, either wrapped in the
+    /// label's text or next to a `span` with it
+    pub async fn field(&self, label: &str) -> Result<WebElement> {
+        let xpath = format!(
+            "//label[text()[normalize-space()={0}] or span[normalize-space()={0}]]/*[self::input or self::textarea or self::select]",
+            xpath_literal(label)
+        );
+        self.find_eventually(By::XPath(xpath)).await
+    }
+
+    /// Fills the field labelled `label`
+    pub async fn fill(&self, label: &str, value: &str) -> Result<()> {
+        let field = self.field(label).await?;
+        self.set_value(&field, value).await
+    }
+
+    pub async fn set_value(&self, field: &WebElement, value: &str) -> Result<()> {
+        self.execute(SET_VALUE, vec![field.to_json()?, json!(value)]).await?;
+        Ok(())
+    }
+
+    /// Clicks the button reading `text` as soon as it's enabled
+    pub async fn click_button(&self, text: &str) -> Result<()> {
+        let xpath = format!("//button[normalize-space()={}]", xpath_literal(text));
+        self.query(By::XPath(xpath)).and_enabled().first().await?.click().await?;
+        Ok(())
+    }
+
+    pub async fn click_link(&self, text: &str) -> Result<()> {
+        self.find_eventually(By::LinkText(text)).await?.click().await?;
+        Ok(())
+    }
+
+    /// Waits until some element on the page contains `text`
+    pub async fn wait_for_text(&self, text: &str) -> Result<()> {
+        let xpath = format!("//body[contains(normalize-space(), {})]", xpath_literal(text));
+        self.find_eventually(By::XPath(xpath)).await?;
+        Ok(())
+    }
+
+    /// Waits for the router to reach `/events/{id}` and returns the id
+    pub async fn wait_for_event_page(&self) -> Result<Uuid> {
+        let started = Instant::now();
+        loop {
+            let url = self.current_url().await?;
+            if let Some(id) = url.path().strip_prefix("/events/").and_then(|id| Uuid::parse_str(id).ok()) {
+                return Ok(id);
+            }
+            if started.elapsed() > NAVIGATION_TIMEOUT {
+                bail!("still on {} instead of an event page", url);
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    async fn find_eventually(&self, by: By) -> Result<WebElement> {
+        Ok(self.query(by).first().await?)
+    }
+}
+
+impl Deref for Browser {
+    type Target = WebDriver;
+
+    fn deref(&self) -> &WebDriver {
+        &self.driver
+    }
+}
+
+// XPath 1.0 has no escapes; a string with both kinds of quote is pieced together
+fn xpath_literal(text: &str) -> String {
+    if !text.contains('\'') {
+        format!("'{}'", text)
+    } else if !text.contains('"') {
+        format!("\"{}\"", text)
+    } else {
+        let parts: Vec<String> = text.split('\'').map(|part| format!("'{}'", part)).collect();
+        format!("concat({})", parts.join(", \"'\", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xpath_literal_quotes_any_text() {
+        assert_eq!(xpath_literal("Create event"), "'Create event'");
+        assert_eq!(xpath_literal("You're registered!"), "\"You're registered!\"");
+        assert_eq!(xpath_literal(r#"It's "live""#), r#"concat('It', "'", 's "live"')"#);
+    }
+}
@@ -0,0 +1,19 @@
+// End-to-end tests of the whole stack: the aqio-api binary on an in-memory
+// database, serving the built web app, driven in a real browser over WebDriver.
+//
+// Nothing here builds the stack. Build the API and bundle the frontend first,
+// and start a WebDriver server (chromedriver or a Selenium container):
+//
+//     cargo build -p aqio-api
+//     (cd aqio-frontend && dx bundle --platform web --release)
+//     chromedriver --port=4444 &
+//     cargo test -p aqio-e2e -- --ignored
+//
+// `AQIO_API_BIN`, `AQIO_FRONTEND_DIR` and `WEBDRIVER_URL` point somewhere else
+// than those defaults.
+
+pub mod browser;
+pub mod stack;
+
+pub use browser::{Browser, MockUser};
+pub use stack::Stack;
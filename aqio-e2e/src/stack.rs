@@ -0,0 +1,122 @@
+// The API as a child process, with a fresh in-memory database and mock
+// authentication, serving the frontend bundle from the same origin.
+
+use std::env;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use tempfile::TempDir;
+use uuid::Uuid;
+
+use crate::browser::MockUser;
+
+// The web app calls the API on this address, so the stack has to listen on it
+// and tests can't run in parallel
+pub const BASE_URL: &str = "http://127.0.0.1:3000";
+const PORT: &str = "3000";
+
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct Stack {
+    api: Child,
+    http: reqwest::Client,
+    // Uploads land here and are removed with the stack
+    _media: TempDir,
+}
+
+impl Stack {
+    /// Starts the API and waits until `/health` answers
+    pub async fn start() -> Result<Self> {
+        let api_bin = env::var_os("AQIO_API_BIN")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_dir().join("target/debug/aqio-api"));
+        let frontend_dir = env::var_os("AQIO_FRONTEND_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_dir().join("target/dx/aqio-frontend/release/web/public"));
+        if !api_bin.is_file() {
+            bail!("no API binary at {}; run `cargo build -p aqio-api`", api_bin.display());
+        }
+        if !frontend_dir.join("index.html").is_file() {
+            bail!(
+                "no frontend bundle in {}; run `dx bundle --platform web --release` in aqio-frontend",
+                frontend_dir.display()
+            );
+        }
+
+        let media = TempDir::new().context("create media directory")?;
+        let api = Command::new(&api_bin)
+            .env("DATABASE_URL", "sqlite::memory:")
+            .env("MOCK_AUTH", "true")
+            .env("HOST", "127.0.0.1")
+            .env("PORT", PORT)
+            .env("STATIC_DIR", &frontend_dir)
+            .env("MEDIA_DIR", media.path())
+            .env("PUBLIC_URL", BASE_URL)
+            .stdout(Stdio::null())
+            .spawn()
+            .with_context(|| format!("start {}", api_bin.display()))?;
+
+        let stack = Self { api, http: reqwest::Client::new(), _media: media };
+        stack.wait_until_healthy().await?;
+        Ok(stack)
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", BASE_URL, path)
+    }
+
+    /// Registrations of an event, as the organizer sees them in the API
+    pub async fn registration_ids(&self, organizer: MockUser, event_id: Uuid) -> Result<Vec<Uuid>> {
+        let body: serde_json::Value = self
+            .http
+            .get(self.url(&format!("/api/v1/registrations/event/{}/list", event_id)))
+            .bearer_auth(organizer.token())
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        body["data"]
+            .as_array()
+            .context("registration list is an array")?
+            .iter()
+            .map(|registration| {
+                registration["id"]
+                    .as_str()
+                    .and_then(|id| Uuid::parse_str(id).ok())
+                    .context("registration has an id")
+            })
+            .collect()
+    }
+
+    async fn wait_until_healthy(&self) -> Result<()> {
+        let started = Instant::now();
+        loop {
+            if let Ok(response) = self.http.get(self.url("/health")).send().await {
+                if response.status().is_success() {
+                    return Ok(());
+                }
+            }
+            if started.elapsed() > STARTUP_TIMEOUT {
+                bail!("API didn't answer on {} within {:?}", BASE_URL, STARTUP_TIMEOUT);
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+    }
+}
+
+impl Drop for Stack {
+    fn drop(&mut self) {
+        let _ = self.api.kill();
+        let _ = self.api.wait();
+    }
+}
+
+fn workspace_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aqio-e2e lives in the workspace")
+        .to_path_buf()
+}
@@ -0,0 +1,156 @@
+// The flows the platform can't ship without, in a real browser against the real
+// stack. They need the built API and frontend and a WebDriver server, so plain
+// `cargo test` skips them; run them with `cargo test -p aqio-e2e -- --ignored`.
+//
+// Every test starts its own stack, so each one begins with an empty database.
+
+use std::future::Future;
+
+use anyhow::Result;
+use aqio_e2e::{Browser, MockUser, Stack};
+use chrono::{Duration, Local};
+use serial_test::serial;
+use thirtyfour::prelude::{By, ElementQueryable};
+use uuid::Uuid;
+
+// Runs `flow` in a fresh stack and browser, closing the browser either way
+async fn with_stack<F, Fut>(flow: F) -> Result<()>
+where
+    F: FnOnce(Stack, Browser) -> Fut,
+    Fut: Future<Output = (Browser, Result<()>)>,
+{
+    let stack = Stack::start().await?;
+    let browser = Browser::open().await?;
+    let (browser, result) = flow(stack, browser).await;
+    browser.close().await?;
+    result
+}
+
+async fn create_event(stack: &Stack, browser: &Browser, title: &str) -> Result<Uuid> {
+    let starts = Local::now() + Duration::days(30);
+    let ends = starts + Duration::hours(6);
+
+    browser.goto(stack.url("/events/new")).await?;
+    browser.fill("Title", title).await?;
+    browser.fill("Description", "Feed trials, lice counts and a tour of the hatchery").await?;
+    browser.fill("Category", "conf").await?;
+    browser.fill("Starts", &starts.format("%Y-%m-%dT%H:%M").to_string()).await?;
+    browser.fill("Ends", &ends.format("%Y-%m-%dT%H:%M").to_string()).await?;
+    browser.fill("Venue", "Havbruksstasjonen").await?;
+    browser.fill("Address", "Havbruksvegen 1, Tromsø").await?;
+    browser.click_button("Create event").await?;
+
+    let event_id = browser.wait_for_event_page().await?;
+    browser.wait_for_text(title).await?;
+    Ok(event_id)
+}
+
+// Registers through the event's public page, the way a guest without an account does
+async fn register_guest(stack: &Stack, browser: &Browser, event_id: Uuid, name: &str, email: &str) -> Result<()> {
+    browser.goto(stack.url(&format!("/events/{}", event_id))).await?;
+    browser.click_link("Public page").await?;
+    browser.fill("Name", name).await?;
+    browser.fill("Email", email).await?;
+    browser.click_button("Register").await?;
+    browser.wait_for_text("You're registered!").await
+}
+
+#[tokio::test]
+#[serial]
+#[ignore = "needs the built stack and a WebDriver server"]
+async fn test_mock_login_signs_in() -> Result<()> {
+    with_stack(|stack, browser| async move {
+        let result: Result<()> = async {
+            for user in [MockUser::Admin, MockUser::Participant] {
+                browser.sign_in(&stack, user).await?;
+            }
+            Ok(())
+        }
+        .await;
+        (browser, result)
+    })
+    .await
+}
+
+#[tokio::test]
+#[serial]
+#[ignore = "needs the built stack and a WebDriver server"]
+async fn test_organizer_creates_event() -> Result<()> {
+    with_stack(|stack, browser| async move {
+        let result: Result<()> = async {
+            browser.sign_in(&stack, MockUser::Admin).await?;
+            create_event(&stack, &browser, "Hatchery Open Day").await?;
+            browser.wait_for_text("You're not registered for this event.").await
+        }
+        .await;
+        (browser, result)
+    })
+    .await
+}
+
+#[tokio::test]
+#[serial]
+#[ignore = "needs the built stack and a WebDriver server"]
+async fn test_organizer_invites_guest() -> Result<()> {
+    with_stack(|stack, browser| async move {
+        let result: Result<()> = async {
+            browser.sign_in(&stack, MockUser::Admin).await?;
+            let event_id = create_event(&stack, &browser, "Smolt Quality Seminar").await?;
+
+            browser.goto(stack.url(&format!("/events/{}/invitations", event_id))).await?;
+            let name = browser.query(By::Css("input[placeholder='Name']")).first().await?;
+            browser.set_value(&name, "Kari Nordmann").await?;
+            let email = browser.query(By::Css("input[placeholder='Email']")).first().await?;
+            browser.set_value(&email, "kari@fjordlaks.no").await?;
+            browser.click_button("Invite").await?;
+            browser.wait_for_text("kari@fjordlaks.no").await
+        }
+        .await;
+        (browser, result)
+    })
+    .await
+}
+
+#[tokio::test]
+#[serial]
+#[ignore = "needs the built stack and a WebDriver server"]
+async fn test_guest_registers_on_public_page() -> Result<()> {
+    with_stack(|stack, browser| async move {
+        let result: Result<()> = async {
+            browser.sign_in(&stack, MockUser::Admin).await?;
+            let event_id = create_event(&stack, &browser, "Sea Lice Workshop").await?;
+            register_guest(&stack, &browser, event_id, "Ola Nordmann", "ola@kystfisk.no").await?;
+
+            let registrations = stack.registration_ids(MockUser::Admin, event_id).await?;
+            anyhow::ensure!(registrations.len() == 1, "expected one registration, got {}", registrations.len());
+            Ok(())
+        }
+        .await;
+        (browser, result)
+    })
+    .await
+}
+
+#[tokio::test]
+#[serial]
+#[ignore = "needs the built stack and a WebDriver server"]
+async fn test_organizer_checks_in_registered_guest() -> Result<()> {
+    with_stack(|stack, browser| async move {
+        let result: Result<()> = async {
+            browser.sign_in(&stack, MockUser::Admin).await?;
+            let event_id = create_event(&stack, &browser, "Aquaculture Tech Day").await?;
+            register_guest(&stack, &browser, event_id, "Ola Nordmann", "ola@kystfisk.no").await?;
+            let registration_id = stack.registration_ids(MockUser::Admin, event_id).await?[0];
+
+            // The code a scanner reads from the guest's ticket is the registration id
+            browser.goto(stack.url(&format!("/events/{}/check-in", event_id))).await?;
+            let code = browser.query(By::Css("input[placeholder='Or type a registration code']")).first().await?;
+            browser.set_value(&code, &registration_id.to_string()).await?;
+            browser.click_button("Check in").await?;
+            browser.wait_for_text("Checked in: ").await
+        }
+        .await;
+        (browser, result)
+    })
+    .await
+}
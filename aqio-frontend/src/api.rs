@@ -1 +0,0 @@
-// Deprecated: moved to infrastructure::api_client. Left empty to avoid compile conflicts if referenced.
@@ -0,0 +1,120 @@
+// Agenda layout: events grouped under day or week headings and flattened into
+// fixed-height rows, so the page only has to render the rows in view.
+
+use std::ops::Range;
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+
+use super::ports::EventListItem;
+
+/// Row heights in pixels; the agenda stylesheet uses the same values
+pub const HEADER_HEIGHT: f64 = 40.0;
+pub const EVENT_HEIGHT: f64 = 64.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgendaGrouping {
+    Day,
+    Week,
+}
+
+impl AgendaGrouping {
+    /// First day of the group `date` falls in; weeks start on Monday
+    fn group_start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            AgendaGrouping::Day => date,
+            AgendaGrouping::Week => date - Duration::days(i64::from(date.weekday().num_days_from_monday())),
+        }
+    }
+
+    fn heading(self, start: NaiveDate) -> String {
+        match self {
+            AgendaGrouping::Day => start.format("%A %-d %B %Y").to_string(),
+            AgendaGrouping::Week => {
+                let end = start + Duration::days(6);
+                format!(
+                    "Week {} · {} – {}",
+                    start.iso_week().week(),
+                    start.format("%-d %b"),
+                    end.format("%-d %b %Y")
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AgendaRow {
+    Heading(String),
+    Event(EventListItem),
+}
+
+impl AgendaRow {
+    pub fn height(&self) -> f64 {
+        match self {
+            AgendaRow::Heading(_) => HEADER_HEIGHT,
+            AgendaRow::Event(_) => EVENT_HEIGHT,
+        }
+    }
+}
+
+/// Rows of the agenda with where each one starts
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgendaLayout {
+    pub rows: Vec<AgendaRow>,
+    offsets: Vec<f64>,
+    headings: Vec<usize>,
+    pub height: f64,
+}
+
+impl AgendaLayout {
+    /// Groups events by their start in local time, earliest first
+    pub fn new(events: &[EventListItem], grouping: AgendaGrouping) -> Self {
+        let mut sorted: Vec<&EventListItem> = events.iter().collect();
+        sorted.sort_by_key(|event| event.start_date);
+
+        let mut layout = Self {
+            rows: Vec::new(),
+            offsets: Vec::new(),
+            headings: Vec::new(),
+            height: 0.0,
+        };
+        let mut current = None;
+        for event in sorted {
+            let start = grouping.group_start(event.start_date.with_timezone(&Local).date_naive());
+            if current != Some(start) {
+                current = Some(start);
+                layout.headings.push(layout.rows.len());
+                layout.push(AgendaRow::Heading(grouping.heading(start)));
+            }
+            layout.push(AgendaRow::Event(event.clone()));
+        }
+        layout
+    }
+
+    fn push(&mut self, row: AgendaRow) {
+        self.offsets.push(self.height);
+        self.height += row.height();
+        self.rows.push(row);
+    }
+
+    pub fn offset(&self, index: usize) -> f64 {
+        self.offsets[index]
+    }
+
+    /// Indexes of the rows overlapping `[top - overscan, top + viewport + overscan)`
+    pub fn visible(&self, top: f64, viewport: f64, overscan: f64) -> Range<usize> {
+        let first = self.offsets.partition_point(|&offset| offset <= top - overscan);
+        let last = self.offsets.partition_point(|&offset| offset < top + viewport + overscan);
+        first.saturating_sub(1)..last
+    }
+
+    /// Heading of the group shown at the top of the viewport
+    pub fn heading_at(&self, top: f64) -> Option<&str> {
+        let row = self.offsets.partition_point(|&offset| offset <= top).saturating_sub(1);
+        let heading = self.headings.partition_point(|&index| index <= row).checked_sub(1)?;
+        match &self.rows[self.headings[heading]] {
+            AgendaRow::Heading(text) => Some(text),
+            AgendaRow::Event(_) => None,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// CSV export of an event's registrations, one row per registration with the
+// same columns organizers see at the door.
+
+use super::ports::{Attendee, AttendeeStatus};
+
+const HEADER: &str = "name,email,guests,status";
+
+fn status_label(status: AttendeeStatus) -> &'static str {
+    match status {
+        AttendeeStatus::Expected => "Registered",
+        AttendeeStatus::CheckedIn => "Checked in",
+        AttendeeStatus::Waitlisted => "Waitlisted",
+        AttendeeStatus::Cancelled => "Cancelled",
+    }
+}
+
+/// Quotes a field when it holds a separator, quote or line break
+fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn attendees_csv(attendees: &[Attendee]) -> String {
+    let mut csv = String::from(HEADER);
+    csv.push_str("\r\n");
+    for attendee in attendees {
+        let row = [
+            field(attendee.name.as_deref().unwrap_or_default()),
+            field(attendee.email.as_deref().unwrap_or_default()),
+            attendee.guest_count.to_string(),
+            status_label(attendee.status).to_string(),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
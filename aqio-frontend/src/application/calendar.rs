@@ -0,0 +1,119 @@
+// Calendar maths for the Month/Week/Day views: which dates a view shows, how
+// to step between periods, and events bucketed by the local day they start on.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate, Utc};
+
+use super::ports::EventListItem;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarView {
+    Month,
+    Week,
+    Day,
+}
+
+impl CalendarView {
+    pub const ALL: [CalendarView; 3] = [CalendarView::Month, CalendarView::Week, CalendarView::Day];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CalendarView::Month => "Month",
+            CalendarView::Week => "Week",
+            CalendarView::Day => "Day",
+        }
+    }
+}
+
+/// Monday of the week `date` falls in
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+/// Dates `view` shows around `date` as `[first, end)`; a month is six full weeks
+/// from the Monday on or before the 1st, so the grid never changes height
+pub fn visible_dates(view: CalendarView, date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    match view {
+        CalendarView::Month => {
+            let first = week_start(date.with_day(1).unwrap_or(date));
+            (first, first + Duration::days(42))
+        }
+        CalendarView::Week => {
+            let first = week_start(date);
+            (first, first + Duration::days(7))
+        }
+        CalendarView::Day => (date, date + Duration::days(1)),
+    }
+}
+
+/// Start of a local calendar day
+pub fn local_midnight(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+        .map(|midnight| midnight.with_timezone(&Utc))
+        .unwrap_or_else(|| date.and_time(chrono::NaiveTime::MIN).and_utc())
+}
+
+/// The instants bounding local dates `[first, end)`
+pub fn utc_range((first, end): (NaiveDate, NaiveDate)) -> (DateTime<Utc>, DateTime<Utc>) {
+    (local_midnight(first), local_midnight(end))
+}
+
+/// The date one period before or after `date`
+pub fn step(view: CalendarView, date: NaiveDate, forward: bool) -> NaiveDate {
+    match view {
+        CalendarView::Month => {
+            let first = date.with_day(1).unwrap_or(date);
+            let moved = if forward {
+                first.checked_add_months(Months::new(1))
+            } else {
+                first.checked_sub_months(Months::new(1))
+            };
+            moved.unwrap_or(first)
+        }
+        CalendarView::Week => date + Duration::days(if forward { 7 } else { -7 }),
+        CalendarView::Day => date + Duration::days(if forward { 1 } else { -1 }),
+    }
+}
+
+pub fn title(view: CalendarView, date: NaiveDate) -> String {
+    match view {
+        CalendarView::Month => date.format("%B %Y").to_string(),
+        CalendarView::Week => {
+            let first = week_start(date);
+            let last = first + Duration::days(6);
+            format!("{} – {}", first.format("%-d %b"), last.format("%-d %b %Y"))
+        }
+        CalendarView::Day => date.format("%A %-d %B %Y").to_string(),
+    }
+}
+
+/// Indexes into an event list grouped by the local date each event starts on,
+/// so calendar cells can look up their events without scanning or cloning the list
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DayBuckets(BTreeMap<NaiveDate, Vec<usize>>);
+
+impl DayBuckets {
+    /// Buckets the events `include` accepts, each day in start order
+    pub fn new(events: &[EventListItem], include: impl Fn(&EventListItem) -> bool) -> Self {
+        let mut buckets: BTreeMap<NaiveDate, Vec<usize>> = BTreeMap::new();
+        for (index, event) in events.iter().enumerate().filter(|(_, event)| include(event)) {
+            let date = event.start_date.with_timezone(&Local).date_naive();
+            buckets.entry(date).or_default().push(index);
+        }
+        for indexes in buckets.values_mut() {
+            indexes.sort_by_key(|&index| events[index].start_date);
+        }
+        Self(buckets)
+    }
+
+    pub fn on(&self, date: NaiveDate) -> &[usize] {
+        self.0.get(&date).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Number of events across all days
+    pub fn count(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+}
@@ -0,0 +1,62 @@
+// Fuzzy matching for the command palette. Every character of the query has to
+// appear in the label in order; matches that run together or start a word rank
+// higher, so "ecal" finds "Events calendar" before "Special".
+
+const MATCH: i32 = 1;
+const CONSECUTIVE_BONUS: i32 = 4;
+const WORD_START_BONUS: i32 = 6;
+// Penalty per label character skipped before the first match
+const LEADING_GAP_PENALTY: i32 = 1;
+const MAX_LEADING_GAP_PENALTY: i32 = 5;
+
+/// How well `query` matches `label`, or `None` when it doesn't; an empty query matches everything equally
+pub fn fuzzy_score(query: &str, label: &str) -> Option<i32> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let mut wanted = query.iter().peekable();
+
+    let mut score = 0;
+    let mut previous_matched = false;
+    let mut previous: Option<char> = None;
+    let mut first_match = None;
+    for (index, c) in label.chars().enumerate() {
+        let Some(&&next) = wanted.peek() else { break };
+        let matched = c.to_lowercase().eq(std::iter::once(next));
+        if matched {
+            wanted.next();
+            first_match.get_or_insert(index);
+            score += MATCH;
+            if previous_matched {
+                score += CONSECUTIVE_BONUS;
+            }
+            let word_start = match previous {
+                None => true,
+                Some(p) => !p.is_alphanumeric() || (p.is_lowercase() && c.is_uppercase()),
+            };
+            if word_start {
+                score += WORD_START_BONUS;
+            }
+        }
+        previous_matched = matched;
+        previous = Some(c);
+    }
+
+    if wanted.peek().is_some() {
+        return None;
+    }
+    let leading_gap = first_match.unwrap_or(0) as i32;
+    Some(score - (leading_gap * LEADING_GAP_PENALTY).min(MAX_LEADING_GAP_PENALTY))
+}
+
+/// `items` matching `query`, best first; equal scores keep their original order
+pub fn rank<'a, T>(items: &'a [T], query: &str, label: impl Fn(&T) -> &str) -> Vec<&'a T> {
+    let mut scored: Vec<(i32, &T)> = items
+        .iter()
+        .filter_map(|item| fuzzy_score(query, label(item)).map(|score| (score, item)))
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(_, item)| item).collect()
+}
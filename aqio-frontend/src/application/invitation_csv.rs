@@ -0,0 +1,115 @@
+// CSV parsing for bulk invitation imports.
+// Accepts `email,name` rows with an optional header line; a header may list the
+// columns in any order (`name,email`, `Email Address`, ...).
+
+use super::ports::Invitee;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CsvImport {
+    pub invitees: Vec<Invitee>,
+    /// Human readable problems, prefixed with the 1-based line number
+    pub errors: Vec<String>,
+}
+
+pub fn parse_invitees(text: &str) -> CsvImport {
+    let mut result = CsvImport::default();
+    let mut email_column = 0;
+    let mut name_column = Some(1);
+
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .peekable();
+
+    // A first row without an address is treated as the header
+    if let Some((_, first)) = lines.peek() {
+        let fields = split_row(first);
+        if !fields.iter().any(|field| looks_like_email(field)) {
+            let position = |needle: &str| {
+                fields
+                    .iter()
+                    .position(|field| field.to_lowercase().contains(needle))
+            };
+            email_column = position("mail").unwrap_or(0);
+            name_column = position("name").filter(|column| *column != email_column);
+            lines.next();
+        }
+    }
+
+    for (line_number, line) in lines {
+        let fields = split_row(line);
+        let email = fields.get(email_column).map(|field| field.trim()).unwrap_or_default();
+
+        if !looks_like_email(email) {
+            result
+                .errors
+                .push(format!("Line {}: '{}' is not an email address", line_number, email));
+            continue;
+        }
+
+        if result
+            .invitees
+            .iter()
+            .any(|invitee| invitee.email.eq_ignore_ascii_case(email))
+        {
+            result
+                .errors
+                .push(format!("Line {}: {} is listed more than once", line_number, email));
+            continue;
+        }
+
+        let name = name_column
+            .and_then(|column| fields.get(column))
+            .map(|field| field.trim().to_string())
+            .filter(|name| !name.is_empty());
+
+        result.invitees.push(Invitee {
+            email: email.to_string(),
+            name,
+        });
+    }
+
+    result
+}
+
+pub fn looks_like_email(value: &str) -> bool {
+    let value = value.trim();
+    match value.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !value.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+// Splits on commas or semicolons (spreadsheet exports in some locales use `;`),
+// honouring double-quoted fields with `""` escapes.
+fn split_row(line: &str) -> Vec<String> {
+    let delimiter = if !line.contains(',') && line.contains(';') { ';' } else { ',' };
+
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    fields.push(current);
+
+    fields
+}
@@ -0,0 +1,240 @@
+// Slippy map maths for the event map: Web Mercator projection onto 256px
+// OpenStreetMap tiles, panning and zooming a viewport, and grouping markers
+// that would overlap into clusters.
+
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+
+use super::ports::LatLng;
+
+pub const TILE_SIZE: f64 = 256.0;
+pub const MIN_ZOOM: u8 = 2;
+pub const MAX_ZOOM: u8 = 18;
+
+// Web Mercator can't represent the poles
+const MAX_LATITUDE: f64 = 85.051_128;
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Roughly mainland Norway, shown when there is nothing to fit the map to
+pub const DEFAULT_CENTER: LatLng = LatLng {
+    latitude: 64.5,
+    longitude: 12.0,
+};
+pub const DEFAULT_ZOOM: u8 = 4;
+
+fn world_size(zoom: u8) -> f64 {
+    TILE_SIZE * f64::from(1u32 << zoom)
+}
+
+/// Position in pixels on the whole world map at `zoom`
+fn to_world(point: LatLng, zoom: u8) -> (f64, f64) {
+    let size = world_size(zoom);
+    let latitude = point.latitude.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+    let x = (point.longitude + 180.0) / 360.0 * size;
+    let y = (1.0 - (latitude.tan() + 1.0 / latitude.cos()).ln() / PI) / 2.0 * size;
+    (x, y)
+}
+
+fn from_world(x: f64, y: f64, zoom: u8) -> LatLng {
+    let size = world_size(zoom);
+    let longitude = x / size * 360.0 - 180.0;
+    let latitude = (PI * (1.0 - 2.0 * y / size)).sinh().atan().to_degrees();
+    LatLng {
+        latitude: latitude.clamp(-MAX_LATITUDE, MAX_LATITUDE),
+        // Keep longitude in range after panning across the date line
+        longitude: (longitude + 540.0).rem_euclid(360.0) - 180.0,
+    }
+}
+
+pub fn distance_km(a: LatLng, b: LatLng) -> f64 {
+    let dlat = (b.latitude - a.latitude).to_radians();
+    let dlng = (b.longitude - a.longitude).to_radians();
+    let h = (dlat / 2.0).sin().powi(2)
+        + a.latitude.to_radians().cos() * b.latitude.to_radians().cos() * (dlng / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().asin()
+}
+
+/// One map tile and where its top-left corner goes in the viewport
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tile {
+    /// Tile column before wrapping, so copies of the world side by side stay distinct
+    pub column: i64,
+    pub row: u32,
+    pub zoom: u8,
+    pub left: f64,
+    pub top: f64,
+}
+
+impl Tile {
+    pub fn key(&self) -> String {
+        format!("{}/{}/{}", self.zoom, self.column, self.row)
+    }
+
+    pub fn url(&self) -> String {
+        let x = self.column.rem_euclid(1i64 << self.zoom);
+        format!("https://tile.openstreetmap.org/{}/{}/{}.png", self.zoom, x, self.row)
+    }
+}
+
+/// The visible part of the map: what it's centred on, how far it's zoomed
+/// and how big the element showing it is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapView {
+    pub center: LatLng,
+    pub zoom: u8,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl MapView {
+    pub fn new(center: LatLng, zoom: u8, width: f64, height: f64) -> Self {
+        Self {
+            center,
+            zoom: zoom.clamp(MIN_ZOOM, MAX_ZOOM),
+            width,
+            height,
+        }
+    }
+
+    /// The closest zoom that shows every point, centred on their bounding box
+    pub fn fit(points: &[LatLng], width: f64, height: f64) -> Self {
+        let Some(first) = points.first() else {
+            return Self::new(DEFAULT_CENTER, DEFAULT_ZOOM, width, height);
+        };
+        let (mut south, mut north) = (first.latitude, first.latitude);
+        let (mut west, mut east) = (first.longitude, first.longitude);
+        for point in points {
+            south = south.min(point.latitude);
+            north = north.max(point.latitude);
+            west = west.min(point.longitude);
+            east = east.max(point.longitude);
+        }
+
+        let north_west = LatLng { latitude: north, longitude: west };
+        let south_east = LatLng { latitude: south, longitude: east };
+        let (left, top) = to_world(north_west, 0);
+        let (right, bottom) = to_world(south_east, 0);
+        let center = from_world((left + right) / 2.0, (top + bottom) / 2.0, 0);
+
+        // Leave room around the outermost markers; a single event gets a street-level view
+        let margin = 48.0;
+        let zoom = (MIN_ZOOM..=14)
+            .rev()
+            .find(|&zoom| {
+                let (left, top) = to_world(north_west, zoom);
+                let (right, bottom) = to_world(south_east, zoom);
+                right - left <= width - 2.0 * margin && bottom - top <= height - 2.0 * margin
+            })
+            .unwrap_or(MIN_ZOOM);
+
+        Self::new(center, zoom, width, height)
+    }
+
+    pub fn resized(self, width: f64, height: f64) -> Self {
+        Self { width, height, ..self }
+    }
+
+    fn top_left(self) -> (f64, f64) {
+        let (x, y) = to_world(self.center, self.zoom);
+        (x - self.width / 2.0, y - self.height / 2.0)
+    }
+
+    /// Viewport pixel position of a point
+    pub fn to_screen(self, point: LatLng) -> (f64, f64) {
+        let (left, top) = self.top_left();
+        let (x, y) = to_world(point, self.zoom);
+        (x - left, y - top)
+    }
+
+    pub fn to_lat_lng(self, x: f64, y: f64) -> LatLng {
+        let (left, top) = self.top_left();
+        from_world(left + x, top + y, self.zoom)
+    }
+
+    /// Moves the map along with a drag of `dx`, `dy` pixels
+    pub fn panned(self, dx: f64, dy: f64) -> Self {
+        let center = self.to_lat_lng(self.width / 2.0 - dx, self.height / 2.0 - dy);
+        Self { center, ..self }
+    }
+
+    pub fn zoomed(self, delta: i8) -> Self {
+        let zoom = (i16::from(self.zoom) + i16::from(delta)).clamp(i16::from(MIN_ZOOM), i16::from(MAX_ZOOM));
+        Self { zoom: zoom as u8, ..self }
+    }
+
+    /// Zooms in on `point`, e.g. to split up a cluster
+    pub fn zoomed_into(self, point: LatLng, delta: i8) -> Self {
+        Self { center: point, ..self }.zoomed(delta)
+    }
+
+    /// Tiles covering the viewport, wrapped horizontally around the globe
+    pub fn tiles(self) -> Vec<Tile> {
+        let (left, top) = self.top_left();
+        let rows = 1i64 << self.zoom;
+        let first_x = (left / TILE_SIZE).floor() as i64;
+        let last_x = ((left + self.width) / TILE_SIZE).floor() as i64;
+        let first_y = ((top / TILE_SIZE).floor() as i64).max(0);
+        let last_y = (((top + self.height) / TILE_SIZE).floor() as i64).min(rows - 1);
+
+        let mut tiles = Vec::new();
+        for y in first_y..=last_y {
+            for x in first_x..=last_x {
+                tiles.push(Tile {
+                    column: x,
+                    row: y as u32,
+                    zoom: self.zoom,
+                    left: x as f64 * TILE_SIZE - left,
+                    top: y as f64 * TILE_SIZE - top,
+                });
+            }
+        }
+        tiles
+    }
+
+    /// Radius of a circle around the centre that covers the whole viewport
+    pub fn radius_km(self) -> f64 {
+        distance_km(self.center, self.to_lat_lng(0.0, 0.0))
+            .max(distance_km(self.center, self.to_lat_lng(0.0, self.height)))
+    }
+}
+
+/// Markers close enough on screen to be drawn as one
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cluster<T> {
+    pub center: LatLng,
+    pub x: f64,
+    pub y: f64,
+    pub items: Vec<T>,
+}
+
+/// Groups items into clusters of roughly `cell` pixels at the current zoom,
+/// dropping anything outside the viewport
+pub fn cluster<T: Clone>(items: &[(LatLng, T)], view: &MapView, cell: f64) -> Vec<Cluster<T>> {
+    let mut cells: BTreeMap<(i64, i64), Vec<(LatLng, T)>> = BTreeMap::new();
+    for (point, item) in items {
+        let (x, y) = view.to_screen(*point);
+        if x < -cell || y < -cell || x > view.width + cell || y > view.height + cell {
+            continue;
+        }
+        let key = ((x / cell).floor() as i64, (y / cell).floor() as i64);
+        cells.entry(key).or_default().push((*point, item.clone()));
+    }
+
+    cells
+        .into_values()
+        .map(|members| {
+            let count = members.len() as f64;
+            let center = LatLng {
+                latitude: members.iter().map(|(point, _)| point.latitude).sum::<f64>() / count,
+                longitude: members.iter().map(|(point, _)| point.longitude).sum::<f64>() / count,
+            };
+            let (x, y) = view.to_screen(center);
+            Cluster {
+                center,
+                x,
+                y,
+                items: members.into_iter().map(|(_, item)| item).collect(),
+            }
+        })
+        .collect()
+}
@@ -1,2 +1,8 @@
+pub mod agenda;
+pub mod attendee_csv;
+pub mod calendar;
+pub mod command_palette;
+pub mod invitation_csv;
+pub mod map;
 pub mod ports;
 pub mod services;
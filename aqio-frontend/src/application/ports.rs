@@ -1,13 +1,196 @@
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+use super::calendar::CalendarView;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct EventListItem {
     pub id: Uuid,
     pub title: String,
+    pub category_id: String,
     pub start_date: DateTime<Utc>,
     pub location: Option<String>,
+    /// Public page slug; only known when the full event was fetched
+    pub slug: Option<String>,
+    /// Venue position; `None` for virtual events and events not placed on the map
+    pub coordinates: Option<LatLng>,
+    /// Only known when the full event was fetched
+    pub registration: Option<RegistrationWindow>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistrationState {
+    NotYetOpen,
+    Open,
+    Closed,
+    Full,
+    WaitlistOnly,
+}
+
+/// Where registration for an event stood when it was fetched, with the API's
+/// countdowns turned into instants so they can keep ticking locally
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegistrationWindow {
+    pub state: RegistrationState,
+    pub opens_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+}
+
+/// A WGS84 position in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatLng {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocationKind {
+    Physical,
+    Virtual,
+    Hybrid,
+}
+
+impl LocationKind {
+    pub const ALL: [LocationKind; 3] = [LocationKind::Physical, LocationKind::Virtual, LocationKind::Hybrid];
+
+    /// Wire value of the API's `LocationType`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LocationKind::Physical => "Physical",
+            LocationKind::Virtual => "Virtual",
+            LocationKind::Hybrid => "Hybrid",
+        }
+    }
+}
+
+/// What an organizer fills in to create or edit an event
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventDraft {
+    pub title: String,
+    pub description: String,
+    pub category_id: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub timezone: String,
+    pub location_kind: LocationKind,
+    pub location_name: Option<String>,
+    pub address: Option<String>,
+    pub virtual_link: Option<String>,
+    pub coordinates: Option<LatLng>,
+    pub max_attendees: Option<i32>,
+    pub is_private: bool,
+    /// Stays a draft until then, when the API publishes it
+    pub publish_at: Option<DateTime<Utc>>,
+    /// Users who can manage the event alongside the organizer
+    pub co_organizers: Vec<Uuid>,
+}
+
+impl EventDraft {
+    /// The checks the API applies, so mistakes show up before anything is sent
+    pub fn validate(&self) -> Result<(), String> {
+        let filled = |value: &Option<String>| value.as_deref().is_some_and(|value| !value.trim().is_empty());
+        if self.title.trim().is_empty() {
+            return Err("Please enter a title".to_string());
+        }
+        if self.title.chars().count() > 200 {
+            return Err("The title can be at most 200 characters".to_string());
+        }
+        if self.description.trim().is_empty() {
+            return Err("Please enter a description".to_string());
+        }
+        if self.category_id.is_empty() {
+            return Err("Please choose a category".to_string());
+        }
+        if self.start_date >= self.end_date {
+            return Err("The event has to end after it starts".to_string());
+        }
+        if self.publish_at.is_some_and(|publish_at| publish_at >= self.start_date) {
+            return Err("The event must be published before it starts".to_string());
+        }
+        if self.max_attendees.is_some_and(|max| max <= 0) {
+            return Err("Maximum attendees must be positive".to_string());
+        }
+        if self.location_kind != LocationKind::Virtual && !filled(&self.location_name) && !filled(&self.address) {
+            return Err("Please enter a venue or address".to_string());
+        }
+        if self.location_kind != LocationKind::Physical && !filled(&self.virtual_link) {
+            return Err("Please enter a link for joining online".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A scheduling rule of the organizer's company that an event's dates break
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleConflict {
+    pub message: String,
+    /// The API refuses to save the event rather than just warning
+    pub blocking: bool,
+}
+
+/// Forecast weather at the venue on the day of the event
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeatherForecast {
+    pub date: NaiveDate,
+    /// MET Norway symbol, e.g. `lightrainshowers_day`
+    pub symbol_code: Option<String>,
+    pub min_temperature_c: f64,
+    pub max_temperature_c: f64,
+    pub precipitation_mm: f64,
+    pub max_wind_speed_ms: f64,
+}
+
+impl WeatherForecast {
+    // Words the symbol codes are run together from; `lights` is MET's own spelling in a few codes
+    const SYMBOL_WORDS: [(&'static str, &'static str); 15] = [
+        ("partly", "partly"),
+        ("cloudy", "cloudy"),
+        ("clear", "clear"),
+        ("sky", "sky"),
+        ("fair", "fair"),
+        ("fog", "fog"),
+        ("heavy", "heavy"),
+        ("lights", "light"),
+        ("light", "light"),
+        ("rain", "rain"),
+        ("sleet", "sleet"),
+        ("snow", "snow"),
+        ("showers", "showers"),
+        ("and", "and"),
+        ("thunder", "thunder"),
+    ];
+
+    /// The symbol in words, e.g. `Light rain showers`
+    pub fn conditions(&self) -> Option<String> {
+        let code = self.symbol_code.as_deref()?;
+        let mut rest = code.split('_').next().unwrap_or(code);
+        let mut words = Vec::new();
+        while !rest.is_empty() {
+            let (prefix, word) = Self::SYMBOL_WORDS.iter().find(|(prefix, _)| rest.starts_with(prefix))?;
+            words.push(*word);
+            rest = &rest[prefix.len()..];
+        }
+        let text = words.join(" ");
+        let mut chars = text.chars();
+        chars.next().map(|first| first.to_uppercase().chain(chars).collect())
+    }
+}
+
+/// Program of an event as printed for attendees
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventProgram {
+    pub title: String,
+    pub description: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub location_name: Option<String>,
+    pub address: Option<String>,
+    pub agenda: Vec<AgendaItem>,
+    pub speakers: Vec<Speaker>,
+    /// Only for physical events in the coming week, when the API has forecasts
+    pub forecast: Option<WeatherForecast>,
 }
 
 // On wasm, futures and some types (e.g., reqwest::Response) are not Send.
@@ -15,4 +198,1696 @@ pub struct EventListItem {
 #[async_trait(?Send)]
 pub trait EventRepository {
     async fn list_events(&self) -> Result<Vec<EventListItem>, String>;
+    async fn get_event(&self, id: Uuid) -> Result<EventListItem, String>;
+    async fn list_events_near(&self, center: LatLng, radius_km: f64) -> Result<Vec<EventListItem>, String>;
+    async fn list_events_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<EventListItem>, String>;
+    async fn create_event(&self, draft: &EventDraft) -> Result<EventListItem, String>;
+    async fn update_event(&self, id: Uuid, draft: &EventDraft) -> Result<EventListItem, String>;
+    /// The API deletes events that haven't started yet; there is no separate cancelled state to keep
+    async fn cancel_event(&self, id: Uuid) -> Result<(), String>;
+    async fn get_program(&self, id: Uuid) -> Result<EventProgram, String>;
+    /// Events whose title contains `query`
+    async fn search_events(&self, query: &str, limit: u32) -> Result<Vec<EventListItem>, String>;
+    /// Categories a new event can be filed under
+    async fn list_active_categories(&self) -> Result<Vec<Category>, String>;
+    /// Scheduling rules an event organized by the signed-in user would break on these dates
+    async fn check_schedule(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        event_id: Option<Uuid>,
+    ) -> Result<Vec<ScheduleConflict>, String>;
+}
+
+// Admin console
+
+pub use aqio_core::{Permission, PermissionSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Admin,
+    Organizer,
+    Participant,
+}
+
+impl Role {
+    pub const ALL: [Role; 3] = [Role::Admin, Role::Organizer, Role::Participant];
+
+    /// Wire value accepted by the API (case insensitive there)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Organizer => "organizer",
+            Role::Participant => "participant",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Role::Admin => "Admin",
+            Role::Organizer => "Organizer",
+            Role::Participant => "Participant",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Role> {
+        Role::ALL
+            .into_iter()
+            .find(|role| role.as_str().eq_ignore_ascii_case(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserAccount {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub role: Role,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Administrative changes to a user; `None` leaves the field untouched
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccountChanges {
+    pub role: Option<Role>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Category {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub color_hex: Option<String>,
+    pub icon_name: Option<String>,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    pub table_name: String,
+    pub record_id: String,
+    pub action: String,
+    pub actor: Option<String>,
+    pub changed_fields: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An emailed invitation to join a company that hasn't been accepted yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrganizationInvite {
+    pub id: Uuid,
+    pub company_name: String,
+    pub email: String,
+    pub role: Role,
+    pub expires_at: DateTime<Utc>,
+    /// `false` once expired; the link no longer works
+    pub is_open: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A company as the Norwegian business register (Brønnøysundregistrene) has it
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisteredCompany {
+    pub org_number: String,
+    pub name: String,
+    pub address: Option<String>,
+    pub city: Option<String>,
+    pub website: Option<String>,
+    pub industry_description: Option<String>,
+    /// `false` once bankrupt, being wound up or deleted; it can't be added
+    pub is_active: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegistryLookup {
+    Found(RegisteredCompany),
+    NotFound,
+    /// The register couldn't be reached; details have to be entered by hand
+    Unavailable,
+}
+
+/// A company being added; blanks are filled in from the register
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompanyDraft {
+    pub org_number: String,
+    pub name: String,
+    pub location: String,
+    /// `None` takes the register's industry as `Other`
+    pub industry: Option<Industry>,
+    pub industry_description: String,
+    pub website: String,
+    pub phone: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatedCompany {
+    pub company: Company,
+    /// Whether the organization number was confirmed in the register
+    pub registry_verified: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: u32,
+    pub total_pages: u32,
+    pub total_count: i64,
+}
+
+#[async_trait(?Send)]
+pub trait AdminRepository {
+    async fn current_user(&self) -> Result<UserAccount, String>;
+    /// What the signed-in user may do anywhere on the platform
+    async fn my_permissions(&self) -> Result<PermissionSet, String>;
+    async fn list_users(&self, page: u32) -> Result<Page<UserAccount>, String>;
+    async fn update_user(&self, id: Uuid, changes: AccountChanges) -> Result<UserAccount, String>;
+    async fn list_categories(&self) -> Result<Vec<Category>, String>;
+    async fn create_category(&self, category: &Category) -> Result<Category, String>;
+    async fn update_category(&self, category: &Category) -> Result<Category, String>;
+    async fn delete_category(&self, id: &str) -> Result<(), String>;
+    async fn list_audit_log(&self, page: u32, table_name: Option<String>) -> Result<Page<AuditEntry>, String>;
+    async fn list_organization_invites(&self) -> Result<Vec<OrganizationInvite>, String>;
+    async fn revoke_organization_invite(&self, id: Uuid) -> Result<(), String>;
+    async fn lookup_company(&self, org_number: &str) -> Result<RegistryLookup, String>;
+    async fn create_company(&self, draft: &CompanyDraft) -> Result<CreatedCompany, String>;
+    /// Percentiles per release and metric over the last `days` days
+    async fn web_vitals(&self, days: u32) -> Result<Vec<WebVitalSummary>, String>;
+}
+
+// People search
+
+/// A user offered when picking co-organizers and invitees
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserMatch {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub company_name: Option<String>,
+}
+
+#[async_trait(?Send)]
+pub trait UserSearchRepository {
+    /// Active users whose name, email or company contains `query`, prefix
+    /// matches first; only organizers and admins may search
+    async fn search_users(&self, query: &str, limit: u32) -> Result<Vec<UserMatch>, String>;
+}
+
+// Company directory
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Industry {
+    Salmon,
+    Trout,
+    Other,
+}
+
+impl Industry {
+    pub const ALL: [Industry; 3] = [Industry::Salmon, Industry::Trout, Industry::Other];
+
+    /// Wire value of the API's `industry_type`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Industry::Salmon => "salmon",
+            Industry::Trout => "trout",
+            Industry::Other => "other",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Industry::Salmon => "Salmon",
+            Industry::Trout => "Trout",
+            Industry::Other => "Other",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Industry> {
+        Industry::ALL
+            .into_iter()
+            .find(|industry| industry.as_str().eq_ignore_ascii_case(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Company {
+    pub id: Uuid,
+    pub name: String,
+    pub location: Option<String>,
+    pub industry: Industry,
+    /// What an `Other` company farms, when it said
+    pub industry_description: Option<String>,
+    pub website: Option<String>,
+    /// Active users belonging to the company
+    pub member_count: i64,
+}
+
+#[async_trait(?Send)]
+pub trait CompanyRepository {
+    async fn list_companies(&self, page: u32, industry: Option<Industry>) -> Result<Page<Company>, String>;
+    /// Public, published events organized by the company's members, soonest first
+    async fn upcoming_events(&self, company_id: Uuid) -> Result<Vec<EventListItem>, String>;
+    /// The invitation behind a join link; readable without signing in
+    async fn organization_invite(&self, token: &str) -> Result<OrganizationInvite, String>;
+    /// Joins the signed-in user to the invitation's company
+    async fn accept_organization_invite(&self, token: &str) -> Result<(), String>;
+}
+
+// Event discussion
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    pub id: Uuid,
+    /// The comment this one replies to
+    pub parent_id: Option<Uuid>,
+    pub author_name: Option<String>,
+    /// `None` for a deleted comment kept in place for its replies
+    pub content: Option<String>,
+    /// Whether the current user may delete it, as decided by the API
+    pub can_delete: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[async_trait(?Send)]
+pub trait CommentRepository {
+    /// The event's comments, oldest first
+    async fn list_comments(&self, event_id: Uuid) -> Result<Vec<Comment>, String>;
+    async fn post_comment(&self, event_id: Uuid, parent_id: Option<Uuid>, content: &str) -> Result<Comment, String>;
+    async fn delete_comment(&self, event_id: Uuid, id: Uuid) -> Result<(), String>;
+}
+
+// Live Q&A and polls while an event is running
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveSession {
+    /// Questions and votes are only accepted while the event is running
+    pub is_running: bool,
+    /// Whether the current user may answer questions and run polls
+    pub can_moderate: bool,
+    /// Most upvoted first
+    pub questions: Vec<LiveQuestion>,
+    /// Oldest first
+    pub polls: Vec<LivePoll>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveQuestion {
+    pub id: Uuid,
+    pub author_name: Option<String>,
+    pub content: String,
+    pub upvote_count: i64,
+    /// Whether the current user has upvoted it
+    pub has_upvoted: bool,
+    pub answer: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LivePoll {
+    pub id: Uuid,
+    pub question: String,
+    pub options: Vec<LivePollOption>,
+    pub is_closed: bool,
+    /// The option the current user voted for
+    pub voted_option_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LivePollOption {
+    pub id: Uuid,
+    pub label: String,
+    pub vote_count: i64,
+}
+
+/// A change pushed over the live stream. It carries the current tally but not
+/// the user's own upvote or vote, which the page keeps from its last response.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiveChange {
+    Question(LiveQuestion),
+    Poll(LivePoll),
+}
+
+#[async_trait(?Send)]
+pub trait LiveSessionRepository {
+    async fn load_session(&self, event_id: Uuid) -> Result<LiveSession, String>;
+    async fn ask_question(&self, event_id: Uuid, content: &str) -> Result<LiveQuestion, String>;
+    async fn set_upvote(&self, event_id: Uuid, question_id: Uuid, upvoted: bool) -> Result<LiveQuestion, String>;
+    async fn answer_question(&self, event_id: Uuid, question_id: Uuid, answer: &str) -> Result<LiveQuestion, String>;
+    async fn create_poll(&self, event_id: Uuid, question: &str, options: &[String]) -> Result<LivePoll, String>;
+    async fn vote(&self, event_id: Uuid, poll_id: Uuid, option_id: Uuid) -> Result<LivePoll, String>;
+    async fn close_poll(&self, event_id: Uuid, poll_id: Uuid) -> Result<LivePoll, String>;
+    /// Server-sent events endpoint with the event's question and poll changes
+    fn stream_url(&self, event_id: Uuid) -> String;
+}
+
+// Event materials (slides, handouts, maps)
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub filename: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub mime_type: String,
+    pub file_size: u64,
+    /// presentation, document, photo, video, audio or other
+    pub category: String,
+    /// Public files are also listed on the event's public page
+    pub is_public: bool,
+    pub uploader_name: Option<String>,
+    pub download_count: u64,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    pub fn display_name(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.filename)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttachmentLibrary {
+    /// Whether the current user may upload and remove files
+    pub can_manage: bool,
+    /// Only the files the current user may download, oldest first
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttachmentUpload {
+    pub filename: String,
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub is_public: bool,
+}
+
+#[async_trait(?Send)]
+pub trait AttachmentRepository {
+    async fn list_attachments(&self, event_id: Uuid) -> Result<AttachmentLibrary, String>;
+    async fn upload(&self, event_id: Uuid, upload: AttachmentUpload) -> Result<Attachment, String>;
+    async fn download(&self, event_id: Uuid, id: Uuid) -> Result<Vec<u8>, String>;
+    async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String>;
+}
+
+// Photos from events that are over, moderated by the organizers
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Photo {
+    pub id: Uuid,
+    pub filename: String,
+    pub caption: Option<String>,
+    pub mime_type: String,
+    pub file_size: u64,
+    /// pending, approved or rejected
+    pub status: String,
+    pub uploader_name: Option<String>,
+    /// Whether the current user may remove it
+    pub can_remove: bool,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+impl Photo {
+    pub fn is_pending(&self) -> bool {
+        self.status == "pending"
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhotoAlbumSettings {
+    pub attendee_uploads: bool,
+    pub requires_approval: bool,
+    /// Approved photos are also shown on the event's public page
+    pub is_public: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhotoGallery {
+    pub settings: PhotoAlbumSettings,
+    /// Whether the current user may change the settings and approve or reject photos
+    pub can_manage: bool,
+    /// Whether the current user may add photos; nobody can before the event is over
+    pub can_upload: bool,
+    /// Oldest first
+    pub photos: Vec<Photo>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhotoUpload {
+    pub filename: String,
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+    pub caption: Option<String>,
+}
+
+#[async_trait(?Send)]
+pub trait PhotoRepository {
+    async fn gallery(&self, event_id: Uuid) -> Result<PhotoGallery, String>;
+    async fn upload(&self, event_id: Uuid, upload: PhotoUpload) -> Result<Photo, String>;
+    async fn update_album(&self, event_id: Uuid, settings: PhotoAlbumSettings) -> Result<(), String>;
+    async fn moderate(&self, event_id: Uuid, id: Uuid, approve: bool) -> Result<Photo, String>;
+    async fn download(&self, event_id: Uuid, id: Uuid) -> Result<Vec<u8>, String>;
+    /// Every approved photo as one ZIP file
+    async fn download_all(&self, event_id: Uuid) -> Result<Vec<u8>, String>;
+    async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String>;
+}
+
+// Custom fields an organization defines for its events, like "Fish species focus"
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventFieldType {
+    Text,
+    Number,
+    /// A calendar day, `YYYY-MM-DD`
+    Date,
+    Boolean,
+    /// One of the field's options
+    Select,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventField {
+    pub id: Uuid,
+    pub key: String,
+    pub label: String,
+    pub field_type: EventFieldType,
+    pub options: Vec<String>,
+    pub is_required: bool,
+    /// In the type's canonical form: booleans are `true` or `false`
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventFieldValues {
+    /// The organizer's organization's fields in display order
+    pub fields: Vec<EventField>,
+    /// Whether the current user may change the values
+    pub can_edit: bool,
+}
+
+#[async_trait(?Send)]
+pub trait EventFieldRepository {
+    async fn event_fields(&self, event_id: Uuid) -> Result<EventFieldValues, String>;
+    /// Values keyed by field key; fields left out or blank are cleared
+    async fn save_values(&self, event_id: Uuid, values: HashMap<String, String>) -> Result<EventFieldValues, String>;
+}
+
+// Certificates of attendance
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CertificateCheck {
+    pub registration_id: Uuid,
+    /// The registration exists and was checked in
+    pub valid: bool,
+    pub attendee_name: Option<String>,
+    pub event_title: Option<String>,
+    pub event_start_date: Option<DateTime<Utc>>,
+    pub event_end_date: Option<DateTime<Utc>>,
+    pub location_name: Option<String>,
+    pub organizer_name: Option<String>,
+    pub checked_in_at: Option<DateTime<Utc>>,
+}
+
+#[async_trait(?Send)]
+pub trait CertificateRepository {
+    /// The PDF for one of the signed-in user's checked-in registrations
+    async fn download(&self, registration_id: Uuid) -> Result<Vec<u8>, String>;
+    async fn set_signature(&self, event_id: Uuid, png: Vec<u8>) -> Result<(), String>;
+    async fn remove_signature(&self, event_id: Uuid) -> Result<(), String>;
+    async fn verify(&self, registration_id: Uuid) -> Result<CertificateCheck, String>;
+}
+
+// Event budgets; every amount is in øre
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetLine {
+    pub id: Uuid,
+    pub name: String,
+    pub planned: i64,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Receipt {
+    pub filename: String,
+    pub mime_type: String,
+    pub file_size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expense {
+    pub id: Uuid,
+    /// `None` for spending not booked against any line
+    pub budget_line_id: Option<Uuid>,
+    pub description: String,
+    pub vendor: Option<String>,
+    pub amount: i64,
+    pub incurred_on: NaiveDate,
+    pub receipt: Option<Receipt>,
+    pub recorded_by_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetLineTotals {
+    pub budget_line_id: Uuid,
+    pub name: String,
+    pub planned: i64,
+    pub spent: i64,
+}
+
+impl BudgetLineTotals {
+    pub fn remaining(&self) -> i64 {
+        self.planned - self.spent
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetSummary {
+    pub planned: i64,
+    pub spent: i64,
+    /// Spending not booked against any line
+    pub unassigned: i64,
+    pub expense_count: usize,
+    pub lines: Vec<BudgetLineTotals>,
+}
+
+impl BudgetSummary {
+    pub fn remaining(&self) -> i64 {
+        self.planned - self.spent
+    }
+
+    pub fn is_over_budget(&self) -> bool {
+        self.spent > self.planned
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventBudget {
+    /// Oldest first
+    pub lines: Vec<BudgetLine>,
+    /// Oldest first by the date they were incurred
+    pub expenses: Vec<Expense>,
+    pub summary: BudgetSummary,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetLineDraft {
+    pub name: String,
+    pub planned: i64,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpenseDraft {
+    pub budget_line_id: Option<Uuid>,
+    pub description: String,
+    pub vendor: Option<String>,
+    pub amount: i64,
+    pub incurred_on: NaiveDate,
+}
+
+#[async_trait(?Send)]
+pub trait BudgetRepository {
+    async fn budget(&self, event_id: Uuid) -> Result<EventBudget, String>;
+    async fn add_line(&self, event_id: Uuid, line: &BudgetLineDraft) -> Result<BudgetLine, String>;
+    async fn update_line(&self, event_id: Uuid, id: Uuid, line: &BudgetLineDraft) -> Result<BudgetLine, String>;
+    async fn delete_line(&self, event_id: Uuid, id: Uuid) -> Result<(), String>;
+    async fn record_expense(&self, event_id: Uuid, expense: &ExpenseDraft) -> Result<Expense, String>;
+    async fn update_expense(&self, event_id: Uuid, id: Uuid, expense: &ExpenseDraft) -> Result<Expense, String>;
+    async fn delete_expense(&self, event_id: Uuid, id: Uuid) -> Result<(), String>;
+    async fn attach_receipt(
+        &self,
+        event_id: Uuid,
+        expense_id: Uuid,
+        filename: String,
+        mime_type: String,
+        bytes: Vec<u8>,
+    ) -> Result<Expense, String>;
+    async fn download_receipt(&self, event_id: Uuid, expense_id: Uuid) -> Result<Vec<u8>, String>;
+    async fn remove_receipt(&self, event_id: Uuid, expense_id: Uuid) -> Result<Expense, String>;
+}
+
+// Event sponsors
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SponsorTier {
+    Platinum,
+    Gold,
+    Silver,
+    Bronze,
+    Partner,
+}
+
+impl SponsorTier {
+    /// Highest first, the order sponsors are listed in
+    pub const ALL: [SponsorTier; 5] = [
+        SponsorTier::Platinum,
+        SponsorTier::Gold,
+        SponsorTier::Silver,
+        SponsorTier::Bronze,
+        SponsorTier::Partner,
+    ];
+
+    /// Wire value accepted by the API (case insensitive there)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SponsorTier::Platinum => "platinum",
+            SponsorTier::Gold => "gold",
+            SponsorTier::Silver => "silver",
+            SponsorTier::Bronze => "bronze",
+            SponsorTier::Partner => "partner",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SponsorTier::Platinum => "Platinum",
+            SponsorTier::Gold => "Gold",
+            SponsorTier::Silver => "Silver",
+            SponsorTier::Bronze => "Bronze",
+            SponsorTier::Partner => "Partners",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<SponsorTier> {
+        SponsorTier::ALL
+            .into_iter()
+            .find(|tier| tier.as_str().eq_ignore_ascii_case(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sponsor {
+    pub id: Uuid,
+    pub name: String,
+    pub tier: SponsorTier,
+    pub logo_url: Option<String>,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    /// Position within the tier, lowest first
+    pub display_order: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SponsorDraft {
+    pub name: String,
+    pub tier: SponsorTier,
+    pub logo_url: Option<String>,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+}
+
+#[async_trait(?Send)]
+pub trait SponsorRepository {
+    /// Highest tier first, then by display order
+    async fn list_sponsors(&self, event_id: Uuid) -> Result<Vec<Sponsor>, String>;
+    async fn add(&self, event_id: Uuid, sponsor: &SponsorDraft) -> Result<Sponsor, String>;
+    async fn update(&self, event_id: Uuid, id: Uuid, sponsor: &SponsorDraft) -> Result<Sponsor, String>;
+    async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String>;
+    async fn reorder(&self, event_id: Uuid, ids: Vec<Uuid>) -> Result<Vec<Sponsor>, String>;
+}
+
+// Catering report
+
+/// One dietary or accessibility need and how many confirmed attendees share it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CateringGroup {
+    pub label: String,
+    pub registrations: u32,
+    /// Registrants plus their guests
+    pub people: u32,
+    /// The answers as attendees wrote them
+    pub answers: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CateringReport {
+    pub confirmed_registrations: u32,
+    pub guests: u32,
+    pub total_people: u32,
+    /// Most people first
+    pub dietary: Vec<CateringGroup>,
+    pub accessibility: Vec<CateringGroup>,
+}
+
+#[async_trait(?Send)]
+pub trait CateringRepository {
+    async fn get_report(&self, event_id: Uuid) -> Result<CateringReport, String>;
+    /// The report as CSV, ready to hand to the venue
+    async fn download_csv(&self, event_id: Uuid) -> Result<String, String>;
+}
+
+// Seating planner
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatingTable {
+    pub id: Uuid,
+    pub name: String,
+    pub capacity: u32,
+    /// Reachable without steps, with room for a wheelchair
+    pub accessible: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatingTableDraft {
+    pub name: String,
+    pub capacity: u32,
+    pub accessible: bool,
+}
+
+/// A confirmed registration and the guests it brings, who always sit together
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatedParty {
+    pub registration_id: Uuid,
+    pub name: String,
+    pub company: Option<String>,
+    pub guest_names: Vec<String>,
+    pub seats: u32,
+    pub accessibility_needs: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedTable {
+    pub table: SeatingTable,
+    pub seats_taken: u32,
+    pub parties: Vec<SeatedParty>,
+}
+
+/// Advice for the organizer; nothing stops a plan with warnings
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatingWarning {
+    pub message: String,
+    pub table_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatingPlan {
+    pub capacity: u32,
+    pub seated_people: u32,
+    pub unassigned_people: u32,
+    pub tables: Vec<PlannedTable>,
+    pub unassigned: Vec<SeatedParty>,
+    pub warnings: Vec<SeatingWarning>,
+}
+
+#[async_trait(?Send)]
+pub trait SeatingRepository {
+    async fn get_plan(&self, event_id: Uuid) -> Result<SeatingPlan, String>;
+    async fn add_table(&self, event_id: Uuid, table: &SeatingTableDraft) -> Result<SeatingTable, String>;
+    async fn update_table(&self, event_id: Uuid, id: Uuid, table: &SeatingTableDraft) -> Result<SeatingTable, String>;
+    /// Whoever sat at the table goes back to the unassigned list
+    async fn delete_table(&self, event_id: Uuid, id: Uuid) -> Result<(), String>;
+    /// Seats a registration at a table, or unseats it with `None`
+    async fn assign(&self, event_id: Uuid, registration_id: Uuid, table_id: Option<Uuid>) -> Result<SeatingPlan, String>;
+    /// The printable chart as PDF
+    async fn download_chart(&self, event_id: Uuid) -> Result<Vec<u8>, String>;
+}
+
+// Past events archive
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArchiveGrouping {
+    #[default]
+    Category,
+    Organizer,
+    County,
+}
+
+impl ArchiveGrouping {
+    pub const ALL: [ArchiveGrouping; 3] = [ArchiveGrouping::Category, ArchiveGrouping::Organizer, ArchiveGrouping::County];
+
+    /// Wire value of the API's `group_by`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArchiveGrouping::Category => "category",
+            ArchiveGrouping::Organizer => "organizer",
+            ArchiveGrouping::County => "county",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ArchiveGrouping::Category => "By category",
+            ArchiveGrouping::Organizer => "By organizer",
+            ArchiveGrouping::County => "By county",
+        }
+    }
+}
+
+/// What the archive is narrowed down to; blank fields don't filter
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PastEventQuery {
+    pub title: String,
+    pub category_id: Option<String>,
+    /// Calendar year the events started in
+    pub year: Option<i32>,
+}
+
+/// An event that is over, with how its registrations turned out
+#[derive(Debug, Clone, PartialEq)]
+pub struct PastEvent {
+    pub id: Uuid,
+    pub title: String,
+    pub category: String,
+    pub organizer: String,
+    pub start_date: DateTime<Utc>,
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub no_shows: i64,
+    pub cancelled: i64,
+    /// Percent of registrations that checked in
+    pub attendance_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendYear {
+    pub year: i32,
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub attendance_rate: Option<f64>,
+    /// Percent change from the year before; `None` without events that year
+    pub registrations_change: Option<f64>,
+}
+
+/// One category's or organizer's years, oldest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttendanceTrend {
+    pub group_id: String,
+    pub name: String,
+    pub events: i64,
+    pub registrations: i64,
+    pub attendance_rate: Option<f64>,
+    pub years: Vec<TrendYear>,
+}
+
+#[async_trait(?Send)]
+pub trait PastEventRepository {
+    /// Most recent first; private events only show up for their organizers
+    async fn list_past_events(&self, page: u32, query: &PastEventQuery) -> Result<Page<PastEvent>, String>;
+    async fn attendance_trends(
+        &self,
+        grouping: ArchiveGrouping,
+        category_id: Option<&str>,
+        from_year: i32,
+        to_year: i32,
+    ) -> Result<Vec<AttendanceTrend>, String>;
+}
+
+// Accommodation and travel information
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportMode {
+    Flight,
+    Train,
+    Bus,
+    Ferry,
+    Taxi,
+    Shuttle,
+    Other,
+}
+
+impl TransportMode {
+    pub const ALL: [TransportMode; 7] = [
+        TransportMode::Flight,
+        TransportMode::Train,
+        TransportMode::Bus,
+        TransportMode::Ferry,
+        TransportMode::Taxi,
+        TransportMode::Shuttle,
+        TransportMode::Other,
+    ];
+
+    /// Wire value used by the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransportMode::Flight => "flight",
+            TransportMode::Train => "train",
+            TransportMode::Bus => "bus",
+            TransportMode::Ferry => "ferry",
+            TransportMode::Taxi => "taxi",
+            TransportMode::Shuttle => "shuttle",
+            TransportMode::Other => "other",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransportMode::Flight => "Flight",
+            TransportMode::Train => "Train",
+            TransportMode::Bus => "Bus",
+            TransportMode::Ferry => "Ferry",
+            TransportMode::Taxi => "Taxi",
+            TransportMode::Shuttle => "Shuttle",
+            TransportMode::Other => "Other",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<TransportMode> {
+        TransportMode::ALL
+            .into_iter()
+            .find(|mode| mode.as_str().eq_ignore_ascii_case(value))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Hotel {
+    pub name: String,
+    pub address: Option<String>,
+    pub website_url: Option<String>,
+    pub walking_minutes: Option<u32>,
+    /// Code attendees quote for the event rate
+    pub booking_code: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportOption {
+    pub mode: TransportMode,
+    pub title: String,
+    pub details: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParkingInfo {
+    pub address: Option<String>,
+    pub free_of_charge: bool,
+    pub ev_charging: bool,
+    pub notes: Option<String>,
+}
+
+/// Hotels, transport and parking for attendees; every section is optional
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TravelInfo {
+    pub hotels: Vec<Hotel>,
+    pub transport: Vec<TransportOption>,
+    pub parking: Option<ParkingInfo>,
+}
+
+impl TravelInfo {
+    pub fn is_empty(&self) -> bool {
+        self.hotels.is_empty() && self.transport.is_empty() && self.parking.is_none()
+    }
+}
+
+#[async_trait(?Send)]
+pub trait TravelRepository {
+    async fn get_travel(&self, event_id: Uuid) -> Result<TravelInfo, String>;
+    /// Replaces everything; an empty `TravelInfo` clears it
+    async fn update_travel(&self, event_id: Uuid, travel: &TravelInfo) -> Result<TravelInfo, String>;
+}
+
+// Messages between organizers and attendees
+
+/// A conversation about an event between its organizers and one attendee
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageThread {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_title: String,
+    pub attendee_name: String,
+    /// The user answers on behalf of the organizers
+    pub as_organizer: bool,
+    pub unread: u32,
+    pub last_message_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub id: Uuid,
+    pub thread_id: Uuid,
+    pub from_organizer: bool,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[async_trait(?Send)]
+pub trait MessageRepository {
+    /// Most recent activity first
+    async fn list_threads(&self) -> Result<Vec<MessageThread>, String>;
+    async fn unread_count(&self) -> Result<u32, String>;
+    /// The thread with its messages, oldest first; the API marks it read
+    async fn get_thread(&self, thread_id: Uuid) -> Result<(MessageThread, Vec<Message>), String>;
+    async fn reply(&self, thread_id: Uuid, body: &str) -> Result<Message, String>;
+    /// Starts the user's thread about the event on the first message
+    async fn contact_organizers(&self, event_id: Uuid, body: &str) -> Result<Message, String>;
+}
+
+// Networking directory and meetings between attendees
+
+/// A time the attendee is free for meetings, within the event
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AvailabilityWindow {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// The user's entry in the event's networking directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkingProfile {
+    pub user_id: Uuid,
+    pub interests: Vec<String>,
+    pub bio: Option<String>,
+    pub availability: Vec<AvailabilityWindow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NetworkingProfileDraft {
+    pub interests: Vec<String>,
+    pub bio: Option<String>,
+    pub availability: Vec<AvailabilityWindow>,
+}
+
+/// Another attendee listed in the directory, having opted in too
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkingContact {
+    pub user_id: Uuid,
+    pub name: String,
+    pub company: Option<String>,
+    pub bio: Option<String>,
+    pub interests: Vec<String>,
+    /// The interests the user has too
+    pub shared_interests: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeetingStatus {
+    Requested,
+    Accepted,
+    Declined,
+    Cancelled,
+}
+
+impl MeetingStatus {
+    /// Requested or accepted, so still in both calendars
+    pub fn is_active(self) -> bool {
+        matches!(self, MeetingStatus::Requested | MeetingStatus::Accepted)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MeetingStatus::Requested => "Waiting for an answer",
+            MeetingStatus::Accepted => "Accepted",
+            MeetingStatus::Declined => "Declined",
+            MeetingStatus::Cancelled => "Cancelled",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Meeting {
+    pub id: Uuid,
+    pub requester_id: Uuid,
+    pub requester_name: String,
+    pub invitee_id: Uuid,
+    pub invitee_name: String,
+    pub message: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// The meeting room's name, when the event has rooms
+    pub room: Option<String>,
+    pub status: MeetingStatus,
+}
+
+#[async_trait(?Send)]
+pub trait NetworkingRepository {
+    /// `None` until the user opts in
+    async fn get_profile(&self, event_id: Uuid) -> Result<Option<NetworkingProfile>, String>;
+    async fn save_profile(&self, event_id: Uuid, profile: &NetworkingProfileDraft) -> Result<NetworkingProfile, String>;
+    /// Opts out again; the user's meetings are cancelled
+    async fn leave(&self, event_id: Uuid) -> Result<(), String>;
+    /// Most shared interests first
+    async fn list_contacts(&self, event_id: Uuid) -> Result<Vec<NetworkingContact>, String>;
+    /// The user's meetings, earliest first
+    async fn list_meetings(&self, event_id: Uuid) -> Result<Vec<Meeting>, String>;
+    /// The API schedules it into the first slot both are free
+    async fn request_meeting(&self, event_id: Uuid, invitee_id: Uuid, message: Option<&str>) -> Result<(), String>;
+    async fn answer_meeting(&self, event_id: Uuid, meeting_id: Uuid, accept: bool) -> Result<(), String>;
+    async fn cancel_meeting(&self, event_id: Uuid, meeting_id: Uuid) -> Result<(), String>;
+    /// The meeting as an `.ics` invite
+    async fn download_invite(&self, event_id: Uuid, meeting_id: Uuid) -> Result<String, String>;
+}
+
+// Invitation management
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvitationStatus {
+    Pending,
+    Sent,
+    Delivered,
+    Opened,
+    Accepted,
+    Declined,
+    Cancelled,
+}
+
+impl InvitationStatus {
+    pub const ALL: [InvitationStatus; 7] = [
+        InvitationStatus::Pending,
+        InvitationStatus::Sent,
+        InvitationStatus::Delivered,
+        InvitationStatus::Opened,
+        InvitationStatus::Accepted,
+        InvitationStatus::Declined,
+        InvitationStatus::Cancelled,
+    ];
+
+    /// Wire value accepted by the API (case insensitive there)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvitationStatus::Pending => "pending",
+            InvitationStatus::Sent => "sent",
+            InvitationStatus::Delivered => "delivered",
+            InvitationStatus::Opened => "opened",
+            InvitationStatus::Accepted => "accepted",
+            InvitationStatus::Declined => "declined",
+            InvitationStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InvitationStatus::Pending => "Pending",
+            InvitationStatus::Sent => "Sent",
+            InvitationStatus::Delivered => "Delivered",
+            InvitationStatus::Opened => "Opened",
+            InvitationStatus::Accepted => "Accepted",
+            InvitationStatus::Declined => "Declined",
+            InvitationStatus::Cancelled => "Cancelled",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<InvitationStatus> {
+        InvitationStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str().eq_ignore_ascii_case(value))
+    }
+
+    /// Whether the invitee is still waiting on us, i.e. the invitation can be sent again
+    pub fn can_resend(&self) -> bool {
+        matches!(
+            self,
+            InvitationStatus::Pending
+                | InvitationStatus::Sent
+                | InvitationStatus::Delivered
+                | InvitationStatus::Opened
+        )
+    }
+}
+
+/// Why the mail provider couldn't deliver an invitation email
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryFailure {
+    Bounce,
+    Complaint,
+}
+
+impl DeliveryFailure {
+    pub fn parse(value: &str) -> Option<DeliveryFailure> {
+        match value.to_ascii_lowercase().as_str() {
+            "bounce" => Some(DeliveryFailure::Bounce),
+            "complaint" => Some(DeliveryFailure::Complaint),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeliveryFailure::Bounce => "Bounced",
+            DeliveryFailure::Complaint => "Marked as spam",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Invitation {
+    pub id: Uuid,
+    pub invited_name: Option<String>,
+    pub invited_email: Option<String>,
+    pub personal_message: Option<String>,
+    pub status: InvitationStatus,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub responded_at: Option<DateTime<Utc>>,
+    /// Set once the email bounced or was reported as spam
+    pub delivery_failure: Option<DeliveryFailure>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    /// Still waiting on the invitee, and the address takes email
+    pub fn can_resend(&self) -> bool {
+        self.status.can_resend() && self.delivery_failure.is_none()
+    }
+}
+
+/// Someone to invite by email, typed in or read from a CSV row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Invitee {
+    pub email: String,
+    pub name: Option<String>,
+}
+
+/// How an invitation was created, mirrors the API's `invitation_method`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InviteSource {
+    Manual,
+    CsvImport,
+}
+
+#[async_trait(?Send)]
+pub trait InvitationRepository {
+    async fn list_invitations(&self, event_id: Uuid) -> Result<Vec<Invitation>, String>;
+    async fn create_invitation(
+        &self,
+        event_id: Uuid,
+        invitee: &Invitee,
+        personal_message: Option<&str>,
+        source: InviteSource,
+    ) -> Result<Invitation, String>;
+    async fn update_invitation_status(&self, id: Uuid, status: InvitationStatus) -> Result<(), String>;
+}
+
+// Event check-in
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttendeeStatus {
+    Expected,
+    CheckedIn,
+    Waitlisted,
+    Cancelled,
+}
+
+/// A registration as seen by the door scanner
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attendee {
+    pub registration_id: Uuid,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub guest_count: i32,
+    pub status: AttendeeStatus,
+}
+
+impl Attendee {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .or_else(|| self.email.clone())
+            .unwrap_or_else(|| self.registration_id.to_string())
+    }
+}
+
+/// Everyone registered for an event, kept on the device while scanning
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Roster {
+    pub attendees: Vec<Attendee>,
+    /// Loaded from local storage because the API couldn't be reached
+    pub from_cache: bool,
+}
+
+impl Roster {
+    pub fn find(&self, registration_id: Uuid) -> Option<&Attendee> {
+        self.attendees
+            .iter()
+            .find(|attendee| attendee.registration_id == registration_id)
+    }
+
+    pub fn mark_checked_in(&mut self, registration_id: Uuid) {
+        if let Some(attendee) = self
+            .attendees
+            .iter_mut()
+            .find(|attendee| attendee.registration_id == registration_id)
+        {
+            attendee.status = AttendeeStatus::CheckedIn;
+        }
+    }
+
+    pub fn checked_in_count(&self) -> usize {
+        self.count(AttendeeStatus::CheckedIn)
+    }
+
+    /// Registrations that can be admitted, whether or not they've arrived yet
+    pub fn admissible_count(&self) -> usize {
+        self.count(AttendeeStatus::Expected) + self.count(AttendeeStatus::CheckedIn)
+    }
+
+    fn count(&self, status: AttendeeStatus) -> usize {
+        self.attendees
+            .iter()
+            .filter(|attendee| attendee.status == status)
+            .count()
+    }
+}
+
+/// A check-in scanned while the API was unreachable, replayed later
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedCheckIn {
+    pub event_id: Uuid,
+    pub registration_id: Uuid,
+    pub scanned_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckInError {
+    /// The request never reached the API; worth retrying later
+    Unreachable(String),
+    /// The API answered with an error; retrying won't help
+    Rejected(String),
+}
+
+impl std::fmt::Display for CheckInError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckInError::Unreachable(message) => write!(f, "API unreachable: {}", message),
+            CheckInError::Rejected(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+pub trait CheckInRepository {
+    async fn list_attendees(&self, event_id: Uuid) -> Result<Vec<Attendee>, CheckInError>;
+    async fn check_in(&self, registration_id: Uuid) -> Result<(), CheckInError>;
+}
+
+/// Device-local storage so queued check-ins and the roster survive a reload without network
+pub trait CheckInStore {
+    fn load_queue(&self) -> Vec<QueuedCheckIn>;
+    fn save_queue(&self, queue: &[QueuedCheckIn]);
+    fn load_roster(&self, event_id: Uuid) -> Option<Vec<Attendee>>;
+    fn save_roster(&self, event_id: Uuid, attendees: &[Attendee]);
+}
+
+// Attendee's own registration
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpotState {
+    Waitlisted { position: usize, waitlist_size: usize },
+    /// Promoted from the waitlist; the spot goes to the next person unless confirmed in time
+    Offered { confirm_by: DateTime<Utc> },
+    Confirmed,
+    /// Checked in at the event; a certificate of attendance can be downloaded
+    CheckedIn,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MyRegistration {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub state: SpotState,
+}
+
+#[async_trait(?Send)]
+pub trait RegistrationRepository {
+    async fn find_my_registration(&self, event_id: Uuid) -> Result<Option<MyRegistration>, String>;
+    async fn confirm_spot(&self, registration_id: Uuid) -> Result<(), String>;
+    /// Server-sent events endpoint for the signed-in user's registration updates
+    fn notification_stream_url(&self) -> String;
+}
+
+// Public event page
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgendaItem {
+    pub time: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    pub speaker: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Speaker {
+    pub name: String,
+    pub title: Option<String>,
+    pub company: Option<String>,
+    pub bio: Option<String>,
+    pub photo_url: Option<String>,
+}
+
+/// A company exhibiting at an expo, as listed on the public event page
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicExhibitor {
+    pub id: Uuid,
+    pub company_name: String,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    /// `None` until the organizers allocate a booth
+    pub booth_number: Option<String>,
+    pub hall: Option<String>,
+}
+
+impl PublicExhibitor {
+    /// e.g. "Hall B, booth B12"
+    pub fn location(&self) -> Option<String> {
+        let booth = self.booth_number.as_ref()?;
+        Some(match &self.hall {
+            Some(hall) => format!("{}, booth {}", hall, booth),
+            None => format!("Booth {}", booth),
+        })
+    }
+}
+
+/// A published event as shown to visitors who aren't signed in
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicEvent {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub location_name: Option<String>,
+    pub address: Option<String>,
+    pub image_url: Option<String>,
+    pub agenda: Vec<AgendaItem>,
+    pub speakers: Vec<Speaker>,
+    pub max_guests: u32,
+    pub collect_dietary_info: bool,
+    pub collect_accessibility_info: bool,
+    pub registration_open: bool,
+    /// `None` when the event has no capacity limit
+    pub spots_left: Option<u32>,
+    pub waitlist_available: bool,
+    /// Files the organizer shared publicly
+    pub attachments: Vec<Attachment>,
+    /// Approved photos, when the organizers made the album public
+    pub photos: Vec<Photo>,
+    /// Highest tier first
+    pub sponsors: Vec<Sponsor>,
+    /// By company name
+    pub exhibitors: Vec<PublicExhibitor>,
+    pub travel: TravelInfo,
+    /// schema.org `Event` markup as JSON-LD text, safe to place in a script tag
+    pub structured_data: Option<String>,
+}
+
+impl PublicEvent {
+    pub fn is_full(&self) -> bool {
+        self.spots_left == Some(0)
+    }
+
+    /// Where the map embed should point: the street address if there is one
+    pub fn map_query(&self) -> Option<&str> {
+        self.address.as_deref().or(self.location_name.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GuestRegistration {
+    pub name: String,
+    pub email: String,
+    pub phone: Option<String>,
+    pub company: Option<String>,
+    pub guest_count: u32,
+    pub dietary_restrictions: Option<String>,
+    pub accessibility_needs: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GuestRegistrationOutcome {
+    Registered,
+    Waitlisted { position: Option<u32> },
+}
+
+#[async_trait(?Send)]
+pub trait PublicEventRepository {
+    async fn get_public_event(&self, slug: &str) -> Result<PublicEvent, String>;
+    async fn register_guest(
+        &self,
+        slug: &str,
+        registration: &GuestRegistration,
+    ) -> Result<GuestRegistrationOutcome, String>;
+    /// Direct download link for one of the event's public files
+    fn attachment_url(&self, slug: &str, id: Uuid) -> String;
+    /// Link to one of the photos in the event's public gallery
+    fn photo_url(&self, slug: &str, id: Uuid) -> String;
+    /// The event as an `.ics` file for adding it to a calendar
+    fn calendar_url(&self, slug: &str) -> String;
+    /// Where a short link (`/e/{code}`) is followed, which counts the click
+    /// and redirects to the event page
+    fn short_link_url(&self, code: &str) -> String;
+}
+
+// Error reporting
+
+/// An error shown to the user, reported so support can find it by its reference
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorReport {
+    /// Short code shown to the user, e.g. `ERR-1A2B3C4D`
+    pub reference: String,
+    pub message: String,
+    pub route: String,
+}
+
+impl ErrorReport {
+    pub fn new(message: impl Into<String>, route: impl Into<String>) -> Self {
+        let id = Uuid::new_v4().simple().to_string();
+        Self {
+            reference: format!("ERR-{}", id[..8].to_uppercase()),
+            message: message.into(),
+            route: route.into(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+pub trait ErrorReporter {
+    async fn report_error(&self, report: &ErrorReport) -> Result<(), String>;
+}
+
+// Web vitals
+
+pub use aqio_core::WebVitalMetric;
+
+/// One measurement taken in this browser
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebVital {
+    /// Route the measurement was taken on
+    pub route: String,
+    pub metric: WebVitalMetric,
+    /// Milliseconds, except for the unitless layout shift
+    pub value: f64,
+}
+
+/// Measurements taken on one route by this release of the app
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebVitalsReport {
+    pub release: String,
+    pub route: String,
+    pub measurements: Vec<(WebVitalMetric, f64)>,
+}
+
+#[async_trait(?Send)]
+pub trait WebVitalsReporter {
+    async fn report_web_vitals(&self, report: &WebVitalsReport) -> Result<(), String>;
+}
+
+/// Percentiles of one metric as reported by one release, for the admin
+/// performance dashboard
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebVitalSummary {
+    pub release: String,
+    pub metric: WebVitalMetric,
+    pub samples: u64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+    pub first_seen: DateTime<Utc>,
+}
+
+// Client logging
+
+/// A log line written by this app, kept until it's shipped to the API
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub level: log::Level,
+    pub target: String,
+    pub message: String,
+    /// Request ID of the API call the line is about, as the API logged it
+    pub request_id: Option<String>,
+    pub route: String,
+}
+
+#[async_trait(?Send)]
+pub trait LogSink {
+    async fn send_logs(&self, release: &str, entries: &[LogEntry]) -> Result<(), String>;
+}
+
+// Server-driven configuration
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Branding {
+    pub name: String,
+    pub logo_url: Option<String>,
+    /// `#rrggbb`
+    pub primary_color: Option<String>,
+}
+
+/// Settings fetched from the API at startup, so a deployment can change them
+/// without rebuilding the bundle
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientConfig {
+    pub branding: Branding,
+    pub max_upload_bytes: u64,
+    /// Lowest level of log line shipped to the API; `None` keeps logs in the
+    /// browser console
+    pub remote_log_level: Option<log::Level>,
+}
+
+impl Default for ClientConfig {
+    /// Used until the API has answered, or when it can't be reached
+    fn default() -> Self {
+        Self {
+            branding: Branding {
+                name: "Aqio".to_string(),
+                logo_url: None,
+                primary_color: None,
+            },
+            max_upload_bytes: 25 * 1024 * 1024,
+            remote_log_level: None,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+pub trait ConfigRepository {
+    async fn client_config(&self) -> Result<ClientConfig, String>;
+}
+
+// Account settings
+
+pub use aqio_core::{DigestFrequency, Locale, Theme};
+
+/// The signed-in user's profile and preferences
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSettings {
+    pub id: Uuid,
+    pub name: String,
+    /// Comes from the identity provider; shown but not edited here
+    pub email: String,
+    /// IANA time zone such as `Europe/Oslo`; the browser's when unset
+    pub timezone: Option<String>,
+    /// The browser's language when unset
+    pub locale: Option<Locale>,
+    /// Follows the device's colour scheme when unset
+    pub theme: Option<Theme>,
+    /// The event calendar opens on the month when unset
+    pub calendar_view: Option<CalendarView>,
+    /// `Immediate` keeps notifications in the app; the others email a digest
+    pub digest_frequency: DigestFrequency,
+    pub next_digest_at: Option<DateTime<Utc>>,
+}
+
+#[async_trait(?Send)]
+pub trait SettingsRepository {
+    async fn load_settings(&self) -> Result<UserSettings, String>;
+    /// Saves everything but the email, returning the settings as stored
+    async fn save_settings(&self, settings: &UserSettings) -> Result<UserSettings, String>;
 }
@@ -1,17 +1,2211 @@
-use super::ports::{EventListItem, EventRepository};
+use super::invitation_csv::looks_like_email;
+use super::map::distance_km;
+use super::ports::{
+    AccountChanges, AdminRepository, Attachment, AttachmentLibrary, AttachmentRepository,
+    AttachmentUpload, Attendee, AttendeeStatus, AuditEntry, BudgetLine, BudgetLineDraft, BudgetRepository,
+    ArchiveGrouping, AttendanceTrend, CateringReport, CateringRepository, Category, CertificateCheck,
+    CertificateRepository, CheckInError, ClientConfig, ConfigRepository, CheckInRepository, CheckInStore, Comment, CommentRepository, Company, CompanyDraft, CompanyRepository, CreatedCompany,
+    ErrorReport, ErrorReporter, EventBudget, EventDraft, EventField, EventFieldRepository, EventFieldType, EventFieldValues, EventListItem, EventProgram, EventRepository, Expense,
+    ExpenseDraft,
+    GuestRegistration, GuestRegistrationOutcome, Hotel, Industry, Invitation, InvitationRepository,
+    InvitationStatus, Invitee, InviteSource, LatLng, LiveChange, LivePoll, LiveQuestion, LiveSession,
+    LiveSessionRepository, LogEntry, LogSink, Meeting, Message, MessageRepository, MessageThread, MyRegistration, NetworkingContact, NetworkingProfile, NetworkingProfileDraft, NetworkingRepository, OrganizationInvite, Page, ParkingInfo, PermissionSet, PastEvent, PastEventQuery, PastEventRepository, Photo, PhotoAlbumSettings, PhotoGallery, PhotoRepository, PhotoUpload, PublicEvent,
+    PublicEventRepository, QueuedCheckIn, RegistrationRepository, Roster, ScheduleConflict, SeatingPlan, SeatingRepository, SeatingTable, SeatingTableDraft, SettingsRepository, Sponsor, SponsorDraft, SponsorRepository,
+    RegistryLookup, SponsorTier, TransportOption, TravelInfo, TravelRepository, UserAccount, UserMatch, UserSearchRepository, UserSettings,
+    WebVital, WebVitalMetric, WebVitalSummary, WebVitalsReport, WebVitalsReporter,
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::{Rc, Weak};
 use std::sync::Arc;
+use uuid::Uuid;
 
+// Largest proximity search radius the API accepts
+const MAX_SEARCH_RADIUS_KM: f64 = 1000.0;
+// Longest title search the API accepts
+const MAX_TITLE_QUERY_CHARS: usize = 100;
+const SEARCH_RESULTS: u32 = 8;
+// Shortest people search the API accepts
+const MIN_USER_QUERY_CHARS: usize = 2;
+
+/// Which events a cached list holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum EventQuery {
+    /// Every event, newest first as the API returns them
+    All,
+    /// Events starting in `[from, to)`, earliest first
+    Between(DateTime<Utc>, DateTime<Utc>),
+    /// `f64` isn't hashable, so the centre and radius are kept as bit patterns
+    Near { latitude: u64, longitude: u64, radius_km: u64 },
+}
+
+impl EventQuery {
+    fn near(center: LatLng, radius_km: f64) -> Self {
+        EventQuery::Near {
+            latitude: center.latitude.to_bits(),
+            longitude: center.longitude.to_bits(),
+            radius_km: radius_km.to_bits(),
+        }
+    }
+
+    /// Where `event` goes among the `ids` of this list, or `None` when the API wouldn't return it for the query
+    fn position(&self, ids: &[Uuid], events: &HashMap<Uuid, EventListItem>, event: &EventListItem) -> Option<usize> {
+        let start = |id: &Uuid| events.get(id).map(|other| other.start_date);
+        match *self {
+            EventQuery::All => Some(ids.partition_point(|id| start(id).is_some_and(|s| s >= event.start_date))),
+            EventQuery::Between(from, to) => (from <= event.start_date && event.start_date < to)
+                .then(|| ids.partition_point(|id| start(id).is_some_and(|s| s <= event.start_date))),
+            EventQuery::Near { latitude, longitude, radius_km } => {
+                let center = LatLng {
+                    latitude: f64::from_bits(latitude),
+                    longitude: f64::from_bits(longitude),
+                };
+                let point = event.coordinates?;
+                (distance_km(center, point) <= f64::from_bits(radius_km)).then_some(ids.len())
+            }
+        }
+    }
+}
+
+type SharedFetch<T> = Shared<LocalBoxFuture<'static, Result<T, String>>>;
+
+/// How long the event store answers from its cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachePolicy {
+    /// Cached lists are served as they are for this long after being fetched
+    pub fresh_for: Duration,
+    /// After that they're still served, while a fresh copy is fetched in the
+    /// background, until they're this old
+    pub stale_for: Duration,
+}
+
+struct CachedList {
+    ids: Vec<Uuid>,
+    fetched_at: DateTime<Utc>,
+    /// Set once a mutation is confirmed; the next read fetches the list again in the background
+    stale: bool,
+}
+
+#[derive(Default)]
+struct EventCache {
+    events: HashMap<Uuid, EventListItem>,
+    /// Events fetched on their own, which carries fields the summaries leave out
+    detailed: HashSet<Uuid>,
+    lists: HashMap<EventQuery, CachedList>,
+    list_fetches: HashMap<EventQuery, SharedFetch<Vec<EventListItem>>>,
+    event_fetches: HashMap<Uuid, SharedFetch<EventListItem>>,
+    categories: Option<(Vec<Category>, DateTime<Utc>)>,
+    /// Bumped by every mutation so responses to requests made before it don't overwrite optimistic changes
+    generation: u64,
+    listeners: Vec<(u64, Rc<dyn Fn()>)>,
+    next_listener: u64,
+}
+
+impl EventCache {
+    /// The cached list unless it's too old to serve, and whether it should be
+    /// fetched again
+    fn list(&self, query: EventQuery, policy: CachePolicy, now: DateTime<Utc>) -> Option<(Vec<EventListItem>, bool)> {
+        let list = self.lists.get(&query)?;
+        let age = now - list.fetched_at;
+        if age > policy.stale_for {
+            return None;
+        }
+        let events = list.ids.iter().filter_map(|id| self.events.get(id).cloned()).collect();
+        Some((events, list.stale || age > policy.fresh_for))
+    }
+
+    fn remember(&mut self, event: EventListItem) {
+        // Summaries leave out the slug and registration; keep what a detail fetch found
+        let known = self.events.get(&event.id);
+        let slug = event.slug.clone().or_else(|| known.and_then(|known| known.slug.clone()));
+        let registration = event.registration.or_else(|| known.and_then(|known| known.registration));
+        self.events.insert(event.id, EventListItem { slug, registration, ..event });
+    }
+
+    fn store_list(&mut self, query: EventQuery, events: Vec<EventListItem>) {
+        let ids = events.iter().map(|event| event.id).collect();
+        for event in events {
+            self.remember(event);
+        }
+        self.lists.insert(
+            query,
+            CachedList {
+                ids,
+                fetched_at: Utc::now(),
+                stale: false,
+            },
+        );
+    }
+
+    /// Replaces any copy of `event` and moves it into every cached list it now belongs in
+    fn place(&mut self, event: EventListItem) {
+        let previous = self.remove(event.id);
+        let slug = event.slug.clone().or_else(|| previous.as_ref().and_then(|previous| previous.slug.clone()));
+        let registration = event.registration.or_else(|| previous.and_then(|previous| previous.registration));
+        let event = EventListItem { slug, registration, ..event };
+        for (query, list) in self.lists.iter_mut() {
+            if let Some(index) = query.position(&list.ids, &self.events, &event) {
+                list.ids.insert(index, event.id);
+            }
+        }
+        self.events.insert(event.id, event);
+    }
+
+    fn remove(&mut self, id: Uuid) -> Option<EventListItem> {
+        for list in self.lists.values_mut() {
+            list.ids.retain(|listed| *listed != id);
+        }
+        self.detailed.remove(&id);
+        self.events.remove(&id)
+    }
+
+    fn invalidate(&mut self) {
+        for list in self.lists.values_mut() {
+            list.stale = true;
+        }
+        self.detailed.clear();
+    }
+}
+
+/// Registers a store listener until dropped
+pub struct EventSubscription {
+    cache: Weak<RefCell<EventCache>>,
+    id: u64,
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        if let Some(cache) = self.cache.upgrade() {
+            cache.borrow_mut().listeners.retain(|(id, _)| *id != self.id);
+        }
+    }
+}
+
+/// Shared cache of events for every page. Identical requests in flight are sent
+/// once, and create/update/cancel show up immediately, are rolled back if the
+/// API rejects them and make cached lists refetch once it accepts them.
+///
+/// Lists are stale-while-revalidate: past `CachePolicy::fresh_for`, or after a
+/// mutation, the cached list is still returned straight away while a fresh one
+/// is fetched, and listeners are told when it arrives.
 #[derive(Clone)]
-pub struct EventService {
+pub struct EventStore {
     repo: Arc<dyn EventRepository>,
+    cache: Rc<RefCell<EventCache>>,
+    policy: CachePolicy,
 }
 
-impl EventService {
-    pub fn new(repo: Arc<dyn EventRepository>) -> Self {
-        Self { repo }
+impl EventStore {
+    pub fn new(repo: Arc<dyn EventRepository>, policy: CachePolicy) -> Self {
+        Self {
+            repo,
+            cache: Rc::new(RefCell::new(EventCache::default())),
+            policy,
+        }
+    }
+
+    /// Calls `listener` after every change to the cached events
+    pub fn subscribe(&self, listener: impl Fn() + 'static) -> EventSubscription {
+        let mut cache = self.cache.borrow_mut();
+        cache.next_listener += 1;
+        let id = cache.next_listener;
+        cache.listeners.push((id, Rc::new(listener)));
+        EventSubscription {
+            cache: Rc::downgrade(&self.cache),
+            id,
+        }
     }
 
     pub async fn list(&self) -> Result<Vec<EventListItem>, String> {
-        self.repo.list_events().await
+        self.load(EventQuery::All).await
+    }
+
+    pub async fn get(&self, id: Uuid) -> Result<EventListItem, String> {
+        let fetch = {
+            let mut cache = self.cache.borrow_mut();
+            if cache.detailed.contains(&id) {
+                if let Some(event) = cache.events.get(&id) {
+                    return Ok(event.clone());
+                }
+            }
+            match cache.event_fetches.get(&id) {
+                Some(fetch) => fetch.clone(),
+                None => {
+                    let repo = self.repo.clone();
+                    let weak = Rc::downgrade(&self.cache);
+                    let generation = cache.generation;
+                    let fetch = async move {
+                        let result = repo.get_event(id).await;
+                        if let Some(cache) = weak.upgrade() {
+                            let mut cache = cache.borrow_mut();
+                            cache.event_fetches.remove(&id);
+                            if let (Ok(event), true) = (&result, cache.generation == generation) {
+                                cache.remember(event.clone());
+                                cache.detailed.insert(id);
+                            }
+                        }
+                        result
+                    }
+                    .boxed_local()
+                    .shared();
+                    cache.event_fetches.insert(id, fetch.clone());
+                    fetch
+                }
+            }
+        };
+        fetch.await
+    }
+
+    /// Events within `radius_km` of `center`, capped at the largest radius the API accepts
+    pub async fn near(&self, center: LatLng, radius_km: f64) -> Result<Vec<EventListItem>, String> {
+        self.load(EventQuery::near(center, radius_km.clamp(1.0, MAX_SEARCH_RADIUS_KM)))
+            .await
+    }
+
+    /// Events starting in `[from, to)`, earliest first
+    pub async fn between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<EventListItem>, String> {
+        self.load(EventQuery::Between(from, to)).await
+    }
+
+    async fn load(&self, query: EventQuery) -> Result<Vec<EventListItem>, String> {
+        let (cached, fetch, started) = {
+            let mut cache = self.cache.borrow_mut();
+            let cached = match cache.list(query, self.policy, Utc::now()) {
+                Some((events, false)) => return Ok(events),
+                Some((events, true)) => Some(events),
+                None => None,
+            };
+            let (fetch, started) = match cache.list_fetches.get(&query) {
+                Some(fetch) => (fetch.clone(), false),
+                None => {
+                    let repo = self.repo.clone();
+                    let weak = Rc::downgrade(&self.cache);
+                    let generation = cache.generation;
+                    let fetch = async move {
+                        let result = fetch_list(repo.as_ref(), query).await;
+                        if let Some(cache) = weak.upgrade() {
+                            let mut cache = cache.borrow_mut();
+                            cache.list_fetches.remove(&query);
+                            if let (Ok(events), true) = (&result, cache.generation == generation) {
+                                cache.store_list(query, events.clone());
+                            }
+                        }
+                        result
+                    }
+                    .boxed_local()
+                    .shared();
+                    cache.list_fetches.insert(query, fetch.clone());
+                    (fetch, true)
+                }
+            };
+            (cached, fetch, started)
+        };
+        match cached {
+            Some(events) => {
+                // Whoever started the fetch tells the listeners, once
+                if started {
+                    let store = self.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        if fetch.await.is_ok() {
+                            store.notify();
+                        }
+                    });
+                }
+                Ok(events)
+            }
+            None => fetch.await,
+        }
+    }
+
+    /// Forgets the requests in flight, so those no page is waiting for any more
+    /// are dropped, which aborts them; called when the route changes
+    pub fn cancel_fetches(&self) {
+        let mut cache = self.cache.borrow_mut();
+        cache.list_fetches.clear();
+        cache.event_fetches.clear();
+    }
+
+    /// Agenda and speakers for printing; only needed there, so not cached
+    pub async fn program(&self, id: Uuid) -> Result<EventProgram, String> {
+        self.repo.get_program(id).await
+    }
+
+    /// Title search for quick lookups; bypasses the cache since every keystroke is a new query
+    pub async fn search(&self, query: &str) -> Result<Vec<EventListItem>, String> {
+        let query: String = query.trim().chars().take(MAX_TITLE_QUERY_CHARS).collect();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.repo.search_events(&query, SEARCH_RESULTS).await
+    }
+
+    /// Active categories, kept for as long as cached lists are fresh
+    pub async fn categories(&self) -> Result<Vec<Category>, String> {
+        if let Some((categories, fetched_at)) = &self.cache.borrow().categories {
+            if Utc::now() - *fetched_at <= self.policy.fresh_for {
+                return Ok(categories.clone());
+            }
+        }
+        let categories = self.repo.list_active_categories().await?;
+        self.cache.borrow_mut().categories = Some((categories.clone(), Utc::now()));
+        Ok(categories)
+    }
+
+    /// The organization's blackout dates and scheduling limits the dates run into;
+    /// blocking ones keep the event from being saved
+    pub async fn check_schedule(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        event_id: Option<Uuid>,
+    ) -> Result<Vec<ScheduleConflict>, String> {
+        if end < start {
+            return Ok(Vec::new());
+        }
+        self.repo.check_schedule(start, end, event_id).await
+    }
+
+    /// Shows the new event right away under a temporary ID until the API returns the real one
+    pub async fn create(&self, draft: &EventDraft) -> Result<EventListItem, String> {
+        draft.validate()?;
+        let placeholder = draft_preview(Uuid::new_v4(), draft, None);
+        let placeholder_id = placeholder.id;
+        self.mutate(|cache| cache.place(placeholder));
+        let result = self.repo.create_event(draft).await;
+        self.mutate(|cache| {
+            cache.remove(placeholder_id);
+            if let Ok(created) = &result {
+                cache.place(created.clone());
+                cache.invalidate();
+            }
+        });
+        result
+    }
+
+    pub async fn update(&self, id: Uuid, draft: &EventDraft) -> Result<EventListItem, String> {
+        draft.validate()?;
+        let previous = self.cache.borrow().events.get(&id).cloned();
+        let slug = previous.as_ref().and_then(|event| event.slug.clone());
+        self.mutate(|cache| cache.place(draft_preview(id, draft, slug)));
+        let result = self.repo.update_event(id, draft).await;
+        self.mutate(|cache| match (&result, previous) {
+            (Ok(updated), _) => {
+                cache.place(updated.clone());
+                cache.invalidate();
+            }
+            (Err(_), Some(previous)) => cache.place(previous),
+            (Err(_), None) => {
+                cache.remove(id);
+            }
+        });
+        result
+    }
+
+    pub async fn cancel(&self, id: Uuid) -> Result<(), String> {
+        let mut removed = None;
+        self.mutate(|cache| removed = cache.remove(id));
+        let result = self.repo.cancel_event(id).await;
+        self.mutate(|cache| match (&result, removed) {
+            (Ok(()), _) => cache.invalidate(),
+            (Err(_), Some(event)) => cache.place(event),
+            (Err(_), None) => {}
+        });
+        result
+    }
+
+    fn mutate(&self, change: impl FnOnce(&mut EventCache)) {
+        {
+            let mut cache = self.cache.borrow_mut();
+            cache.generation += 1;
+            change(&mut cache);
+        }
+        self.notify();
+    }
+
+    fn notify(&self) {
+        let listeners: Vec<Rc<dyn Fn()>> = self
+            .cache
+            .borrow()
+            .listeners
+            .iter()
+            .map(|(_, listener)| listener.clone())
+            .collect();
+        // Listeners may read the store, so they run after the cache is released
+        for listener in listeners {
+            listener();
+        }
+    }
+}
+
+async fn fetch_list(repo: &dyn EventRepository, query: EventQuery) -> Result<Vec<EventListItem>, String> {
+    match query {
+        EventQuery::All => repo.list_events().await,
+        EventQuery::Between(from, to) => {
+            let mut events = repo.list_events_between(from, to).await?;
+            // The API's range is inclusive at both ends; drop the overlap so consecutive ranges don't repeat events
+            events.retain(|event| event.start_date < to);
+            events.sort_by_key(|event| event.start_date);
+            Ok(events)
+        }
+        EventQuery::Near { latitude, longitude, radius_km } => {
+            let center = LatLng {
+                latitude: f64::from_bits(latitude),
+                longitude: f64::from_bits(longitude),
+            };
+            repo.list_events_near(center, f64::from_bits(radius_km)).await
+        }
+    }
+}
+
+/// How an event being saved looks until the API answers
+fn draft_preview(id: Uuid, draft: &EventDraft, slug: Option<String>) -> EventListItem {
+    EventListItem {
+        id,
+        title: draft.title.clone(),
+        category_id: draft.category_id.clone(),
+        start_date: draft.start_date,
+        location: draft.location_name.clone(),
+        slug,
+        coordinates: draft.coordinates,
+        registration: None,
+    }
+}
+
+#[derive(Clone)]
+pub struct AdminService {
+    repo: Arc<dyn AdminRepository>,
+}
+
+impl AdminService {
+    pub fn new(repo: Arc<dyn AdminRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn current_user(&self) -> Result<UserAccount, String> {
+        self.repo.current_user().await
+    }
+
+    pub async fn my_permissions(&self) -> Result<PermissionSet, String> {
+        self.repo.my_permissions().await
+    }
+
+    pub async fn list_users(&self, page: u32) -> Result<Page<UserAccount>, String> {
+        self.repo.list_users(page).await
+    }
+
+    pub async fn update_user(&self, id: Uuid, changes: AccountChanges) -> Result<UserAccount, String> {
+        self.repo.update_user(id, changes).await
+    }
+
+    pub async fn list_categories(&self) -> Result<Vec<Category>, String> {
+        self.repo.list_categories().await
+    }
+
+    /// Create or update depending on whether the category already exists
+    pub async fn save_category(&self, category: &Category, is_new: bool) -> Result<Category, String> {
+        if category.name.trim().is_empty() {
+            return Err("Category name cannot be empty".to_string());
+        }
+        if is_new {
+            if category.id.trim().is_empty() {
+                return Err("Category ID cannot be empty".to_string());
+            }
+            self.repo.create_category(category).await
+        } else {
+            self.repo.update_category(category).await
+        }
+    }
+
+    pub async fn delete_category(&self, id: &str) -> Result<(), String> {
+        self.repo.delete_category(id).await
+    }
+
+    pub async fn list_audit_log(&self, page: u32, table_name: Option<String>) -> Result<Page<AuditEntry>, String> {
+        self.repo.list_audit_log(page, table_name).await
+    }
+
+    pub async fn list_organization_invites(&self) -> Result<Vec<OrganizationInvite>, String> {
+        self.repo.list_organization_invites().await
+    }
+
+    pub async fn revoke_organization_invite(&self, id: Uuid) -> Result<(), String> {
+        self.repo.revoke_organization_invite(id).await
+    }
+
+    /// `None` until nine digits have been typed, so lookups aren't sent while
+    /// the number is still being entered
+    pub async fn lookup_company(&self, org_number: &str) -> Option<Result<RegistryLookup, String>> {
+        let digits: String = org_number.chars().filter(|c| !c.is_whitespace()).collect();
+        if digits.len() != 9 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        Some(self.repo.lookup_company(&digits).await)
+    }
+
+    pub async fn create_company(&self, draft: &CompanyDraft) -> Result<CreatedCompany, String> {
+        if draft.org_number.trim().is_empty() && draft.name.trim().is_empty() {
+            return Err("Enter an organization number or a name".to_string());
+        }
+        self.repo.create_company(draft).await
+    }
+
+    pub async fn web_vitals(&self, days: u32) -> Result<Vec<WebVitalSummary>, String> {
+        self.repo.web_vitals(days).await
+    }
+}
+
+/// Outcome of an operation applied to several invitations
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BulkOutcome {
+    pub succeeded: usize,
+    /// (email or id, error message) for each item that failed
+    pub failed: Vec<(String, String)>,
+}
+
+#[derive(Clone)]
+pub struct InvitationService {
+    repo: Arc<dyn InvitationRepository>,
+}
+
+impl InvitationService {
+    pub fn new(repo: Arc<dyn InvitationRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn list(&self, event_id: Uuid) -> Result<Vec<Invitation>, String> {
+        self.repo.list_invitations(event_id).await
+    }
+
+    pub async fn invite(
+        &self,
+        event_id: Uuid,
+        invitee: &Invitee,
+        personal_message: Option<&str>,
+    ) -> Result<Invitation, String> {
+        if !looks_like_email(&invitee.email) {
+            return Err(format!("'{}' is not a valid email address", invitee.email));
+        }
+        self.repo
+            .create_invitation(event_id, invitee, personal_message, InviteSource::Manual)
+            .await
+    }
+
+    /// Creates one invitation per invitee; failures don't stop the rest of the import
+    pub async fn import(
+        &self,
+        event_id: Uuid,
+        invitees: &[Invitee],
+        personal_message: Option<&str>,
+    ) -> BulkOutcome {
+        let mut outcome = BulkOutcome::default();
+        for invitee in invitees {
+            match self
+                .repo
+                .create_invitation(event_id, invitee, personal_message, InviteSource::CsvImport)
+                .await
+            {
+                Ok(_) => outcome.succeeded += 1,
+                Err(e) => outcome.failed.push((invitee.email.clone(), e)),
+            }
+        }
+        outcome
+    }
+
+    /// Marks the given invitations as sent again, skipping ones that were already
+    /// answered or whose email bounced
+    pub async fn resend(&self, invitations: &[Invitation]) -> BulkOutcome {
+        let mut outcome = BulkOutcome::default();
+        for invitation in invitations.iter().filter(|i| i.can_resend()) {
+            match self
+                .repo
+                .update_invitation_status(invitation.id, InvitationStatus::Sent)
+                .await
+            {
+                Ok(()) => outcome.succeeded += 1,
+                Err(e) => {
+                    let who = invitation
+                        .invited_email
+                        .clone()
+                        .unwrap_or_else(|| invitation.id.to_string());
+                    outcome.failed.push((who, e));
+                }
+            }
+        }
+        outcome
+    }
+}
+
+/// What happened to a scanned code
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanResult {
+    CheckedIn(Attendee),
+    /// Accepted on the device; sent to the API once it's reachable again
+    Queued(Attendee),
+    AlreadyCheckedIn(Attendee),
+    /// Waitlisted or cancelled registrations
+    NotAdmitted(Attendee),
+    Unknown(String),
+    Failed(String),
+}
+
+/// Pulls the registration ID out of a QR payload: either a bare UUID or a URL ending in one
+pub fn parse_registration_code(code: &str) -> Option<Uuid> {
+    code.trim()
+        .rsplit(|c: char| !(c.is_ascii_hexdigit() || c == '-'))
+        .find_map(|token| Uuid::parse_str(token).ok())
+}
+
+#[derive(Clone)]
+pub struct CheckInService {
+    repo: Arc<dyn CheckInRepository>,
+    store: Arc<dyn CheckInStore>,
+}
+
+impl CheckInService {
+    pub fn new(repo: Arc<dyn CheckInRepository>, store: Arc<dyn CheckInStore>) -> Self {
+        Self { repo, store }
+    }
+
+    /// Fetches the roster, falling back to the last copy saved on this device when offline
+    pub async fn load_roster(&self, event_id: Uuid) -> Result<Roster, String> {
+        let (attendees, from_cache) = match self.repo.list_attendees(event_id).await {
+            Ok(attendees) => (attendees, false),
+            Err(CheckInError::Unreachable(e)) => match self.store.load_roster(event_id) {
+                Some(attendees) => (attendees, true),
+                None => {
+                    return Err(format!(
+                        "Can't reach the server and no roster is saved on this device ({})",
+                        e
+                    ))
+                }
+            },
+            Err(CheckInError::Rejected(e)) => return Err(e),
+        };
+
+        // Check-ins still waiting in the queue aren't reflected by the API yet
+        let mut roster = Roster { attendees, from_cache };
+        for queued in self.queued_for(event_id) {
+            roster.mark_checked_in(queued.registration_id);
+        }
+
+        self.store.save_roster(event_id, &roster.attendees);
+        Ok(roster)
+    }
+
+    /// Who's coming, straight from the API; unlike the roster nothing is kept on the device
+    pub async fn attendees(&self, event_id: Uuid) -> Result<Vec<Attendee>, String> {
+        self.repo
+            .list_attendees(event_id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn scan(&self, event_id: Uuid, roster: &Roster, code: &str) -> ScanResult {
+        let Some(registration_id) = parse_registration_code(code) else {
+            return ScanResult::Unknown("Not a registration code".to_string());
+        };
+        let Some(attendee) = roster.find(registration_id).cloned() else {
+            return ScanResult::Unknown("No registration for this event".to_string());
+        };
+
+        match attendee.status {
+            AttendeeStatus::CheckedIn => return ScanResult::AlreadyCheckedIn(attendee),
+            AttendeeStatus::Waitlisted | AttendeeStatus::Cancelled => {
+                return ScanResult::NotAdmitted(attendee)
+            }
+            AttendeeStatus::Expected => {}
+        }
+
+        let result = match self.repo.check_in(registration_id).await {
+            Ok(()) => ScanResult::CheckedIn(attendee),
+            Err(CheckInError::Unreachable(_)) => {
+                let mut queue = self.store.load_queue();
+                queue.push(QueuedCheckIn {
+                    event_id,
+                    registration_id,
+                    scanned_at: Utc::now(),
+                });
+                self.store.save_queue(&queue);
+                ScanResult::Queued(attendee)
+            }
+            Err(CheckInError::Rejected(e)) => return ScanResult::Failed(e),
+        };
+
+        let mut updated = roster.clone();
+        updated.mark_checked_in(registration_id);
+        self.store.save_roster(event_id, &updated.attendees);
+
+        result
+    }
+
+    pub fn pending_count(&self, event_id: Uuid) -> usize {
+        self.queued_for(event_id).len()
+    }
+
+    /// Replays queued check-ins for the event, stopping at the first one that can't be delivered
+    pub async fn sync(&self, event_id: Uuid) -> BulkOutcome {
+        let mut outcome = BulkOutcome::default();
+        let mut remaining = Vec::new();
+        let mut offline = false;
+
+        for queued in self.store.load_queue() {
+            if offline || queued.event_id != event_id {
+                remaining.push(queued);
+                continue;
+            }
+            match self.repo.check_in(queued.registration_id).await {
+                Ok(()) => outcome.succeeded += 1,
+                Err(CheckInError::Unreachable(_)) => {
+                    offline = true;
+                    remaining.push(queued);
+                }
+                Err(CheckInError::Rejected(e)) => {
+                    outcome.failed.push((queued.registration_id.to_string(), e))
+                }
+            }
+        }
+
+        self.store.save_queue(&remaining);
+        outcome
+    }
+
+    fn queued_for(&self, event_id: Uuid) -> Vec<QueuedCheckIn> {
+        self.store
+            .load_queue()
+            .into_iter()
+            .filter(|queued| queued.event_id == event_id)
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct RegistrationService {
+    repo: Arc<dyn RegistrationRepository>,
+}
+
+impl RegistrationService {
+    pub fn new(repo: Arc<dyn RegistrationRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn my_registration(&self, event_id: Uuid) -> Result<Option<MyRegistration>, String> {
+        self.repo.find_my_registration(event_id).await
+    }
+
+    pub async fn confirm_spot(&self, registration_id: Uuid) -> Result<(), String> {
+        self.repo.confirm_spot(registration_id).await
+    }
+
+    pub fn notification_stream_url(&self) -> String {
+        self.repo.notification_stream_url()
+    }
+}
+
+#[derive(Clone)]
+pub struct PublicEventService {
+    repo: Arc<dyn PublicEventRepository>,
+}
+
+impl PublicEventService {
+    pub fn new(repo: Arc<dyn PublicEventRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, slug: &str) -> Result<PublicEvent, String> {
+        self.repo.get_public_event(slug).await
+    }
+
+    pub fn attachment_url(&self, slug: &str, id: Uuid) -> String {
+        self.repo.attachment_url(slug, id)
+    }
+
+    pub fn photo_url(&self, slug: &str, id: Uuid) -> String {
+        self.repo.photo_url(slug, id)
+    }
+
+    pub fn calendar_url(&self, slug: &str) -> String {
+        self.repo.calendar_url(slug)
+    }
+
+    pub fn short_link_url(&self, code: &str) -> String {
+        self.repo.short_link_url(code)
+    }
+
+    pub async fn register(
+        &self,
+        event: &PublicEvent,
+        registration: &GuestRegistration,
+    ) -> Result<GuestRegistrationOutcome, String> {
+        if registration.name.trim().is_empty() {
+            return Err("Please enter your name".to_string());
+        }
+        if !looks_like_email(&registration.email) {
+            return Err(format!("'{}' is not a valid email address", registration.email.trim()));
+        }
+        if registration.guest_count > event.max_guests {
+            return Err(format!("You can bring at most {} guests", event.max_guests));
+        }
+
+        let trimmed = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let cleaned = GuestRegistration {
+            name: registration.name.trim().to_string(),
+            email: registration.email.trim().to_string(),
+            phone: trimmed(&registration.phone),
+            company: trimmed(&registration.company),
+            guest_count: registration.guest_count,
+            dietary_restrictions: trimmed(&registration.dietary_restrictions),
+            accessibility_needs: trimmed(&registration.accessibility_needs),
+        };
+        self.repo.register_guest(&event.slug, &cleaned).await
+    }
+}
+
+// Longest comment the API accepts
+const MAX_COMMENT_CHARS: usize = 2000;
+
+/// A comment with its replies, oldest first at every level
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentThread {
+    pub comment: Comment,
+    pub replies: Vec<CommentThread>,
+}
+
+/// Nests a flat, oldest-first list of comments under their parents; replies whose
+/// parent is missing are shown at the top level rather than dropped
+pub fn comment_threads(comments: Vec<Comment>) -> Vec<CommentThread> {
+    let ids: HashSet<Uuid> = comments.iter().map(|comment| comment.id).collect();
+    let mut children: HashMap<Option<Uuid>, Vec<Comment>> = HashMap::new();
+    for comment in comments {
+        let parent = comment.parent_id.filter(|parent| ids.contains(parent));
+        children.entry(parent).or_default().push(comment);
+    }
+
+    fn build(parent: Option<Uuid>, children: &mut HashMap<Option<Uuid>, Vec<Comment>>) -> Vec<CommentThread> {
+        children
+            .remove(&parent)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|comment| CommentThread {
+                replies: build(Some(comment.id), children),
+                comment,
+            })
+            .collect()
+    }
+    build(None, &mut children)
+}
+
+#[derive(Clone)]
+pub struct DiscussionService {
+    repo: Arc<dyn CommentRepository>,
+}
+
+impl DiscussionService {
+    pub fn new(repo: Arc<dyn CommentRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn threads(&self, event_id: Uuid) -> Result<Vec<CommentThread>, String> {
+        Ok(comment_threads(self.repo.list_comments(event_id).await?))
+    }
+
+    /// Posts a new comment, or a reply when `parent_id` is set
+    pub async fn post(&self, event_id: Uuid, parent_id: Option<Uuid>, content: &str) -> Result<Comment, String> {
+        let content = content.trim();
+        if content.is_empty() {
+            return Err("Please write something first".to_string());
+        }
+        if content.chars().count() > MAX_COMMENT_CHARS {
+            return Err(format!("Comments can be at most {} characters", MAX_COMMENT_CHARS));
+        }
+        self.repo.post_comment(event_id, parent_id, content).await
+    }
+
+    pub async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.repo.delete_comment(event_id, id).await
+    }
+}
+
+// Limits the API enforces for live Q&A and polls
+const MAX_QUESTION_CHARS: usize = 500;
+const MAX_POLL_OPTIONS: usize = 10;
+
+/// Merges a pushed change into the page's lists. The user's own upvote and vote
+/// aren't in the push, so they're kept from the copy being replaced.
+pub fn apply_live_change(questions: &mut Vec<LiveQuestion>, polls: &mut Vec<LivePoll>, change: LiveChange) {
+    match change {
+        LiveChange::Question(mut question) => {
+            match questions.iter_mut().find(|existing| existing.id == question.id) {
+                Some(existing) => {
+                    question.has_upvoted = existing.has_upvoted;
+                    *existing = question;
+                }
+                None => questions.push(question),
+            }
+            // Stable, so ties keep the order they were asked in
+            questions.sort_by_key(|question| std::cmp::Reverse(question.upvote_count));
+        }
+        LiveChange::Poll(mut poll) => match polls.iter_mut().find(|existing| existing.id == poll.id) {
+            Some(existing) => {
+                poll.voted_option_id = existing.voted_option_id;
+                *existing = poll;
+            }
+            None => polls.push(poll),
+        },
+    }
+}
+
+/// Replaces the page's copy with a response to the user's own action, which
+/// does include their upvote or vote
+pub fn apply_own_change(questions: &mut Vec<LiveQuestion>, polls: &mut Vec<LivePoll>, change: LiveChange) {
+    match change {
+        LiveChange::Question(question) => {
+            let has_upvoted = question.has_upvoted;
+            let id = question.id;
+            apply_live_change(questions, polls, LiveChange::Question(question));
+            if let Some(existing) = questions.iter_mut().find(|existing| existing.id == id) {
+                existing.has_upvoted = has_upvoted;
+            }
+        }
+        LiveChange::Poll(poll) => {
+            let voted_option_id = poll.voted_option_id;
+            let id = poll.id;
+            apply_live_change(questions, polls, LiveChange::Poll(poll));
+            if let Some(existing) = polls.iter_mut().find(|existing| existing.id == id) {
+                existing.voted_option_id = voted_option_id;
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LiveSessionService {
+    repo: Arc<dyn LiveSessionRepository>,
+}
+
+impl LiveSessionService {
+    pub fn new(repo: Arc<dyn LiveSessionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn session(&self, event_id: Uuid) -> Result<LiveSession, String> {
+        self.repo.load_session(event_id).await
+    }
+
+    pub async fn ask(&self, event_id: Uuid, content: &str) -> Result<LiveQuestion, String> {
+        let content = content.trim();
+        if content.is_empty() {
+            return Err("Please write a question first".to_string());
+        }
+        if content.chars().count() > MAX_QUESTION_CHARS {
+            return Err(format!("Questions can be at most {} characters", MAX_QUESTION_CHARS));
+        }
+        self.repo.ask_question(event_id, content).await
+    }
+
+    pub async fn set_upvote(&self, event_id: Uuid, question_id: Uuid, upvoted: bool) -> Result<LiveQuestion, String> {
+        self.repo.set_upvote(event_id, question_id, upvoted).await
+    }
+
+    pub async fn answer(&self, event_id: Uuid, question_id: Uuid, answer: &str) -> Result<LiveQuestion, String> {
+        let answer = answer.trim();
+        if answer.is_empty() {
+            return Err("Please write an answer first".to_string());
+        }
+        self.repo.answer_question(event_id, question_id, answer).await
+    }
+
+    /// Options are given one per line; blank lines are ignored
+    pub async fn create_poll(&self, event_id: Uuid, question: &str, options: &str) -> Result<LivePoll, String> {
+        let question = question.trim();
+        if question.is_empty() {
+            return Err("Please write the poll question".to_string());
+        }
+        let options: Vec<String> = options
+            .lines()
+            .map(str::trim)
+            .filter(|option| !option.is_empty())
+            .map(str::to_string)
+            .collect();
+        if options.len() < 2 || options.len() > MAX_POLL_OPTIONS {
+            return Err(format!("A poll needs between 2 and {} options", MAX_POLL_OPTIONS));
+        }
+        self.repo.create_poll(event_id, question, &options).await
+    }
+
+    pub async fn vote(&self, event_id: Uuid, poll_id: Uuid, option_id: Uuid) -> Result<LivePoll, String> {
+        self.repo.vote(event_id, poll_id, option_id).await
+    }
+
+    pub async fn close_poll(&self, event_id: Uuid, poll_id: Uuid) -> Result<LivePoll, String> {
+        self.repo.close_poll(event_id, poll_id).await
+    }
+
+    pub fn stream_url(&self, event_id: Uuid) -> String {
+        self.repo.stream_url(event_id)
+    }
+}
+
+// Limits the API enforces for event attachments
+const MAX_ATTACHMENT_BYTES: usize = 25 * 1024 * 1024;
+const MAX_ATTACHMENT_TITLE_CHARS: usize = 200;
+
+/// The browser doesn't report a file's type through the file picker, so it's
+/// guessed from the extension; the API sorts files into categories by it
+pub fn mime_type_for(filename: &str) -> &'static str {
+    let extension = filename.rsplit_once('.').map(|(_, ext)| ext.to_lowercase()).unwrap_or_default();
+    match extension.as_str() {
+        "pdf" => "application/pdf",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "key" => "application/vnd.apple.keynote",
+        "odp" => "application/vnd.oasis.opendocument.presentation",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "odt" => "application/vnd.oasis.opendocument.text",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "txt" => "text/plain",
+        "csv" => "text/csv",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mov" => "video/quicktime",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "zip" => "application/zip",
+        _ => "application/octet-stream",
+    }
+}
+
+/// "2.4 MB", "830 KB", "512 B"
+pub fn format_file_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    let size = bytes as f64;
+    if size >= KB * KB {
+        format!("{:.1} MB", size / (KB * KB))
+    } else if size >= KB {
+        format!("{:.0} KB", size / KB)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+#[derive(Clone)]
+pub struct AttachmentService {
+    repo: Arc<dyn AttachmentRepository>,
+}
+
+impl AttachmentService {
+    pub fn new(repo: Arc<dyn AttachmentRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn library(&self, event_id: Uuid) -> Result<AttachmentLibrary, String> {
+        self.repo.list_attachments(event_id).await
+    }
+
+    /// Checks the size up front so a large file isn't sent only to be refused
+    pub async fn upload(&self, event_id: Uuid, upload: AttachmentUpload) -> Result<Attachment, String> {
+        if upload.bytes.is_empty() {
+            return Err(format!("'{}' is empty", upload.filename));
+        }
+        if upload.bytes.len() > MAX_ATTACHMENT_BYTES {
+            return Err(format!(
+                "'{}' is {}; files can be at most {}",
+                upload.filename,
+                format_file_size(upload.bytes.len() as u64),
+                format_file_size(MAX_ATTACHMENT_BYTES as u64)
+            ));
+        }
+
+        let trimmed = |value: Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let title = trimmed(upload.title);
+        if title.as_ref().is_some_and(|title| title.chars().count() > MAX_ATTACHMENT_TITLE_CHARS) {
+            return Err(format!("Titles can be at most {} characters", MAX_ATTACHMENT_TITLE_CHARS));
+        }
+        let mime_type = if upload.mime_type.is_empty() {
+            mime_type_for(&upload.filename).to_string()
+        } else {
+            upload.mime_type
+        };
+
+        let cleaned = AttachmentUpload {
+            mime_type,
+            title,
+            description: trimmed(upload.description),
+            ..upload
+        };
+        self.repo.upload(event_id, cleaned).await
+    }
+
+    pub async fn download(&self, event_id: Uuid, id: Uuid) -> Result<Vec<u8>, String> {
+        self.repo.download(event_id, id).await
     }
+
+    pub async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.repo.delete(event_id, id).await
+    }
+}
+
+// Limits the API enforces for event photos
+const MAX_PHOTO_BYTES: usize = 15 * 1024 * 1024;
+const MAX_PHOTO_CAPTION_CHARS: usize = 500;
+const PHOTO_TYPES: [&str; 4] = ["image/jpeg", "image/png", "image/webp", "image/gif"];
+
+#[derive(Clone)]
+pub struct PhotoService {
+    repo: Arc<dyn PhotoRepository>,
+}
+
+impl PhotoService {
+    pub fn new(repo: Arc<dyn PhotoRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn gallery(&self, event_id: Uuid) -> Result<PhotoGallery, String> {
+        self.repo.gallery(event_id).await
+    }
+
+    /// Checks size and type up front so a photo isn't sent only to be refused
+    pub async fn upload(&self, event_id: Uuid, upload: PhotoUpload) -> Result<Photo, String> {
+        if upload.bytes.is_empty() {
+            return Err(format!("'{}' is empty", upload.filename));
+        }
+        if upload.bytes.len() > MAX_PHOTO_BYTES {
+            return Err(format!(
+                "'{}' is {}; photos can be at most {}",
+                upload.filename,
+                format_file_size(upload.bytes.len() as u64),
+                format_file_size(MAX_PHOTO_BYTES as u64)
+            ));
+        }
+        let mime_type = if upload.mime_type.is_empty() {
+            mime_type_for(&upload.filename).to_string()
+        } else {
+            upload.mime_type
+        };
+        if !PHOTO_TYPES.contains(&mime_type.as_str()) {
+            return Err(format!("'{}' isn't a JPEG, PNG, WebP or GIF image", upload.filename));
+        }
+        let caption = upload
+            .caption
+            .as_deref()
+            .map(str::trim)
+            .filter(|caption| !caption.is_empty())
+            .map(str::to_string);
+        if caption.as_ref().is_some_and(|caption| caption.chars().count() > MAX_PHOTO_CAPTION_CHARS) {
+            return Err(format!("Captions can be at most {} characters", MAX_PHOTO_CAPTION_CHARS));
+        }
+
+        let cleaned = PhotoUpload {
+            mime_type,
+            caption,
+            ..upload
+        };
+        self.repo.upload(event_id, cleaned).await
+    }
+
+    pub async fn update_album(&self, event_id: Uuid, settings: PhotoAlbumSettings) -> Result<(), String> {
+        self.repo.update_album(event_id, settings).await
+    }
+
+    pub async fn approve(&self, event_id: Uuid, id: Uuid) -> Result<Photo, String> {
+        self.repo.moderate(event_id, id, true).await
+    }
+
+    pub async fn reject(&self, event_id: Uuid, id: Uuid) -> Result<Photo, String> {
+        self.repo.moderate(event_id, id, false).await
+    }
+
+    pub async fn download(&self, event_id: Uuid, id: Uuid) -> Result<Vec<u8>, String> {
+        self.repo.download(event_id, id).await
+    }
+
+    pub async fn download_all(&self, event_id: Uuid) -> Result<Vec<u8>, String> {
+        self.repo.download_all(event_id).await
+    }
+
+    pub async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.repo.delete(event_id, id).await
+    }
+}
+
+#[derive(Clone)]
+pub struct EventFieldService {
+    repo: Arc<dyn EventFieldRepository>,
+}
+
+impl EventFieldService {
+    pub fn new(repo: Arc<dyn EventFieldRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn event_fields(&self, event_id: Uuid) -> Result<EventFieldValues, String> {
+        self.repo.event_fields(event_id).await
+    }
+
+    /// Checks required fields up front; the API checks each value against its type
+    pub async fn save_values(&self, event_id: Uuid, fields: &[EventField]) -> Result<EventFieldValues, String> {
+        let mut values = HashMap::new();
+        for field in fields {
+            let value = field.value.as_deref().map(str::trim).unwrap_or_default();
+            if value.is_empty() {
+                if field.is_required {
+                    return Err(format!("{} is required", field.label));
+                }
+                continue;
+            }
+            values.insert(field.key.clone(), value.to_string());
+        }
+        self.repo.save_values(event_id, values).await
+    }
+}
+
+/// A field's value as shown on the event page; `None` when it has none
+pub fn format_field_value(field: &EventField) -> Option<String> {
+    let value = field.value.as_deref()?;
+    Some(match field.field_type {
+        EventFieldType::Boolean => if value == "true" { "Yes" } else { "No" }.to_string(),
+        EventFieldType::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(|date| date.format("%-d %B %Y").to_string())
+            .unwrap_or_else(|_| value.to_string()),
+        EventFieldType::Text | EventFieldType::Number | EventFieldType::Select => value.to_string(),
+    })
+}
+
+// Limit the API enforces for certificate signature images
+const MAX_SIGNATURE_BYTES: usize = 1024 * 1024;
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+#[derive(Clone)]
+pub struct CertificateService {
+    repo: Arc<dyn CertificateRepository>,
+}
+
+impl CertificateService {
+    pub fn new(repo: Arc<dyn CertificateRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn download(&self, registration_id: Uuid) -> Result<Vec<u8>, String> {
+        self.repo.download(registration_id).await
+    }
+
+    /// Only PNG is embedded in the PDF, so anything else is refused before uploading
+    pub async fn set_signature(&self, event_id: Uuid, filename: &str, png: Vec<u8>) -> Result<(), String> {
+        if !png.starts_with(PNG_SIGNATURE) {
+            return Err(format!("'{}' is not a PNG image", filename));
+        }
+        if png.len() > MAX_SIGNATURE_BYTES {
+            return Err(format!(
+                "'{}' is {}; signatures can be at most {}",
+                filename,
+                format_file_size(png.len() as u64),
+                format_file_size(MAX_SIGNATURE_BYTES as u64)
+            ));
+        }
+        self.repo.set_signature(event_id, png).await
+    }
+
+    pub async fn remove_signature(&self, event_id: Uuid) -> Result<(), String> {
+        self.repo.remove_signature(event_id).await
+    }
+
+    pub async fn verify(&self, registration_id: Uuid) -> Result<CertificateCheck, String> {
+        self.repo.verify(registration_id).await
+    }
+}
+
+// Limits the API enforces for budgets and receipts
+const MAX_RECEIPT_BYTES: usize = 10 * 1024 * 1024;
+const MAX_BUDGET_LINE_NAME_CHARS: usize = 100;
+const MAX_EXPENSE_DESCRIPTION_CHARS: usize = 200;
+
+/// Parses kroner as typed in Norway ("1 234,50", "1234.5", "800") into øre
+pub fn parse_amount(text: &str) -> Option<i64> {
+    let cleaned: String = text
+        .trim()
+        .trim_end_matches("kr")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| if c == ',' { '.' } else { c })
+        .collect();
+    let (kroner, ore) = cleaned.split_once('.').unwrap_or((&cleaned, ""));
+    if kroner.is_empty() || ore.len() > 2 || !kroner.chars().chain(ore.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let ore = format!("{:0<2}", ore).parse::<i64>().ok()?;
+    kroner.parse::<i64>().ok()?.checked_mul(100)?.checked_add(ore)
+}
+
+/// "1 234,50 kr", "-500 kr"; øre are left out when there are none
+pub fn format_amount(ore: i64) -> String {
+    let sign = if ore < 0 { "-" } else { "" };
+    let (kroner, rest) = (ore.unsigned_abs() / 100, ore.unsigned_abs() % 100);
+    let digits = kroner.to_string();
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push('\u{a0}');
+        }
+        grouped.push(digit);
+    }
+    if rest == 0 {
+        format!("{}{} kr", sign, grouped)
+    } else {
+        format!("{}{},{:02} kr", sign, grouped, rest)
+    }
+}
+
+#[derive(Clone)]
+pub struct BudgetService {
+    repo: Arc<dyn BudgetRepository>,
+}
+
+impl BudgetService {
+    pub fn new(repo: Arc<dyn BudgetRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn budget(&self, event_id: Uuid) -> Result<EventBudget, String> {
+        self.repo.budget(event_id).await
+    }
+
+    /// Adds a line, or updates `id` when given
+    pub async fn save_line(&self, event_id: Uuid, id: Option<Uuid>, line: BudgetLineDraft) -> Result<BudgetLine, String> {
+        let name = line.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Give the budget line a name".to_string());
+        }
+        if name.chars().count() > MAX_BUDGET_LINE_NAME_CHARS {
+            return Err(format!("Names can be at most {} characters", MAX_BUDGET_LINE_NAME_CHARS));
+        }
+        if line.planned < 0 {
+            return Err("The planned amount can't be negative".to_string());
+        }
+        let line = BudgetLineDraft {
+            name,
+            notes: line.notes.map(|notes| notes.trim().to_string()).filter(|notes| !notes.is_empty()),
+            ..line
+        };
+        match id {
+            Some(id) => self.repo.update_line(event_id, id, &line).await,
+            None => self.repo.add_line(event_id, &line).await,
+        }
+    }
+
+    pub async fn delete_line(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.repo.delete_line(event_id, id).await
+    }
+
+    /// Records an expense, or updates `id` when given
+    pub async fn save_expense(&self, event_id: Uuid, id: Option<Uuid>, expense: ExpenseDraft) -> Result<Expense, String> {
+        let description = expense.description.trim().to_string();
+        if description.is_empty() {
+            return Err("Describe what the money was spent on".to_string());
+        }
+        if description.chars().count() > MAX_EXPENSE_DESCRIPTION_CHARS {
+            return Err(format!("Descriptions can be at most {} characters", MAX_EXPENSE_DESCRIPTION_CHARS));
+        }
+        if expense.amount <= 0 {
+            return Err("The amount must be greater than zero".to_string());
+        }
+        let expense = ExpenseDraft {
+            description,
+            vendor: expense.vendor.map(|vendor| vendor.trim().to_string()).filter(|vendor| !vendor.is_empty()),
+            ..expense
+        };
+        match id {
+            Some(id) => self.repo.update_expense(event_id, id, &expense).await,
+            None => self.repo.record_expense(event_id, &expense).await,
+        }
+    }
+
+    pub async fn delete_expense(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.repo.delete_expense(event_id, id).await
+    }
+
+    /// Receipts are scans or photos, so only images and PDFs are sent
+    pub async fn attach_receipt(
+        &self,
+        event_id: Uuid,
+        expense_id: Uuid,
+        filename: String,
+        bytes: Vec<u8>,
+    ) -> Result<Expense, String> {
+        let mime_type = mime_type_for(&filename);
+        if !mime_type.starts_with("image/") && mime_type != "application/pdf" {
+            return Err(format!("'{}' is not an image or a PDF", filename));
+        }
+        if bytes.is_empty() {
+            return Err(format!("'{}' is empty", filename));
+        }
+        if bytes.len() > MAX_RECEIPT_BYTES {
+            return Err(format!(
+                "'{}' is {}; receipts can be at most {}",
+                filename,
+                format_file_size(bytes.len() as u64),
+                format_file_size(MAX_RECEIPT_BYTES as u64)
+            ));
+        }
+        self.repo
+            .attach_receipt(event_id, expense_id, filename, mime_type.to_string(), bytes)
+            .await
+    }
+
+    pub async fn download_receipt(&self, event_id: Uuid, expense_id: Uuid) -> Result<Vec<u8>, String> {
+        self.repo.download_receipt(event_id, expense_id).await
+    }
+
+    pub async fn remove_receipt(&self, event_id: Uuid, expense_id: Uuid) -> Result<Expense, String> {
+        self.repo.remove_receipt(event_id, expense_id).await
+    }
+}
+
+// Limits the API enforces for sponsors
+const MAX_SPONSOR_NAME_CHARS: usize = 100;
+const MAX_URL_CHARS: usize = 500;
+const MAX_SPONSOR_DESCRIPTION_CHARS: usize = 500;
+
+/// Sponsors grouped under their tier, highest tier first; empty tiers are left out
+pub fn sponsors_by_tier(sponsors: &[Sponsor]) -> Vec<(SponsorTier, Vec<Sponsor>)> {
+    SponsorTier::ALL
+        .into_iter()
+        .map(|tier| {
+            let mut in_tier: Vec<Sponsor> = sponsors.iter().filter(|s| s.tier == tier).cloned().collect();
+            in_tier.sort_by_key(|s| (s.display_order, s.name.to_lowercase()));
+            (tier, in_tier)
+        })
+        .filter(|(_, in_tier)| !in_tier.is_empty())
+        .collect()
+}
+
+fn optional_text(text: Option<String>) -> Option<String> {
+    text.map(|text| text.trim().to_string()).filter(|text| !text.is_empty())
+}
+
+fn check_web_address(what: &str, url: Option<&str>) -> Result<(), String> {
+    let Some(url) = url else { return Ok(()) };
+    let lower = url.to_ascii_lowercase();
+    let has_host = ["https://", "http://"]
+        .iter()
+        .any(|scheme| lower.strip_prefix(scheme).is_some_and(|rest| !rest.is_empty()));
+    if !has_host || url.chars().any(char::is_whitespace) {
+        return Err(format!("The {} must start with http:// or https://", what));
+    }
+    if url.chars().count() > MAX_URL_CHARS {
+        return Err(format!("The {} can be at most {} characters", what, MAX_URL_CHARS));
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct SponsorService {
+    repo: Arc<dyn SponsorRepository>,
+}
+
+impl SponsorService {
+    pub fn new(repo: Arc<dyn SponsorRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn list(&self, event_id: Uuid) -> Result<Vec<Sponsor>, String> {
+        self.repo.list_sponsors(event_id).await
+    }
+
+    /// Adds a sponsor, or updates `id` when given
+    pub async fn save(&self, event_id: Uuid, id: Option<Uuid>, sponsor: SponsorDraft) -> Result<Sponsor, String> {
+        let name = sponsor.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Give the sponsor a name".to_string());
+        }
+        if name.chars().count() > MAX_SPONSOR_NAME_CHARS {
+            return Err(format!("Names can be at most {} characters", MAX_SPONSOR_NAME_CHARS));
+        }
+        let sponsor = SponsorDraft {
+            name,
+            tier: sponsor.tier,
+            logo_url: optional_text(sponsor.logo_url),
+            website_url: optional_text(sponsor.website_url),
+            description: optional_text(sponsor.description),
+        };
+        check_web_address("logo address", sponsor.logo_url.as_deref())?;
+        check_web_address("website address", sponsor.website_url.as_deref())?;
+        if sponsor.description.as_deref().is_some_and(|d| d.chars().count() > MAX_SPONSOR_DESCRIPTION_CHARS) {
+            return Err(format!("Descriptions can be at most {} characters", MAX_SPONSOR_DESCRIPTION_CHARS));
+        }
+        match id {
+            Some(id) => self.repo.update(event_id, id, &sponsor).await,
+            None => self.repo.add(event_id, &sponsor).await,
+        }
+    }
+
+    pub async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.repo.delete(event_id, id).await
+    }
+
+    /// Swaps `id` with its neighbour in the same tier; `None` when it's already at that end
+    pub async fn shift(
+        &self,
+        event_id: Uuid,
+        sponsors: &[Sponsor],
+        id: Uuid,
+        earlier: bool,
+    ) -> Option<Result<Vec<Sponsor>, String>> {
+        let tier = sponsors.iter().find(|s| s.id == id)?.tier;
+        let (_, in_tier) = sponsors_by_tier(sponsors).into_iter().find(|(t, _)| *t == tier)?;
+        let mut ids: Vec<Uuid> = in_tier.iter().map(|s| s.id).collect();
+        let position = ids.iter().position(|other| *other == id)?;
+        let target = if earlier { position.checked_sub(1)? } else { position + 1 };
+        if target >= ids.len() {
+            return None;
+        }
+        ids.swap(position, target);
+        Some(self.repo.reorder(event_id, ids).await)
+    }
+}
+
+#[derive(Clone)]
+pub struct CateringService {
+    repo: Arc<dyn CateringRepository>,
+}
+
+impl CateringService {
+    pub fn new(repo: Arc<dyn CateringRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn report(&self, event_id: Uuid) -> Result<CateringReport, String> {
+        self.repo.get_report(event_id).await
+    }
+
+    pub async fn csv(&self, event_id: Uuid) -> Result<String, String> {
+        self.repo.download_csv(event_id).await
+    }
+}
+
+// Same limits as the API
+const MAX_TABLE_NAME_CHARS: usize = 100;
+const MAX_TABLE_CAPACITY: u32 = 100;
+
+#[derive(Clone)]
+pub struct SeatingService {
+    repo: Arc<dyn SeatingRepository>,
+}
+
+impl SeatingService {
+    pub fn new(repo: Arc<dyn SeatingRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn plan(&self, event_id: Uuid) -> Result<SeatingPlan, String> {
+        self.repo.get_plan(event_id).await
+    }
+
+    /// Adds a table, or updates `id` when given
+    pub async fn save_table(&self, event_id: Uuid, id: Option<Uuid>, table: SeatingTableDraft) -> Result<SeatingTable, String> {
+        let name = table.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Give the table a name".to_string());
+        }
+        if name.chars().count() > MAX_TABLE_NAME_CHARS {
+            return Err(format!("Names can be at most {} characters", MAX_TABLE_NAME_CHARS));
+        }
+        if !(1..=MAX_TABLE_CAPACITY).contains(&table.capacity) {
+            return Err(format!("A table seats between 1 and {} people", MAX_TABLE_CAPACITY));
+        }
+        let table = SeatingTableDraft { name, ..table };
+        match id {
+            Some(id) => self.repo.update_table(event_id, id, &table).await,
+            None => self.repo.add_table(event_id, &table).await,
+        }
+    }
+
+    pub async fn delete_table(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.repo.delete_table(event_id, id).await
+    }
+
+    /// Moves a registration and its guests to `table_id`, or back to the unassigned list
+    pub async fn assign(&self, event_id: Uuid, registration_id: Uuid, table_id: Option<Uuid>) -> Result<SeatingPlan, String> {
+        self.repo.assign(event_id, registration_id, table_id).await
+    }
+
+    pub async fn chart(&self, event_id: Uuid) -> Result<Vec<u8>, String> {
+        self.repo.download_chart(event_id).await
+    }
+}
+
+#[derive(Clone)]
+pub struct PastEventService {
+    repo: Arc<dyn PastEventRepository>,
+}
+
+impl PastEventService {
+    /// Years shown side by side in the attendance trends
+    pub const TREND_YEARS: i32 = 5;
+
+    pub fn new(repo: Arc<dyn PastEventRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn list(&self, page: u32, query: &PastEventQuery) -> Result<Page<PastEvent>, String> {
+        let query = PastEventQuery {
+            title: query.title.trim().chars().take(MAX_TITLE_QUERY_CHARS).collect(),
+            ..query.clone()
+        };
+        self.repo.list_past_events(page, &query).await
+    }
+
+    /// The `TREND_YEARS` years up to and including `last_year`
+    pub async fn trends(
+        &self,
+        grouping: ArchiveGrouping,
+        category_id: Option<&str>,
+        last_year: i32,
+    ) -> Result<Vec<AttendanceTrend>, String> {
+        self.repo
+            .attendance_trends(grouping, category_id, last_year - (Self::TREND_YEARS - 1), last_year)
+            .await
+    }
+}
+
+const MAX_TRAVEL_ENTRIES: usize = 10;
+const MAX_TRAVEL_TEXT_CHARS: usize = 200;
+const MAX_TRAVEL_NOTES_CHARS: usize = 500;
+
+fn check_length(what: &str, text: Option<&str>, max_chars: usize) -> Result<(), String> {
+    if text.is_some_and(|text| text.chars().count() > max_chars) {
+        return Err(format!("The {} can be at most {} characters", what, max_chars));
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct TravelService {
+    repo: Arc<dyn TravelRepository>,
+}
+
+impl TravelService {
+    pub fn new(repo: Arc<dyn TravelRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn get(&self, event_id: Uuid) -> Result<TravelInfo, String> {
+        self.repo.get_travel(event_id).await
+    }
+
+    /// Trims every field and replaces the event's travel information with the result
+    pub async fn save(&self, event_id: Uuid, travel: TravelInfo) -> Result<TravelInfo, String> {
+        if travel.hotels.len() > MAX_TRAVEL_ENTRIES || travel.transport.len() > MAX_TRAVEL_ENTRIES {
+            return Err(format!("List at most {} hotels and {} ways of getting there", MAX_TRAVEL_ENTRIES, MAX_TRAVEL_ENTRIES));
+        }
+        let hotels = travel
+            .hotels
+            .into_iter()
+            .map(|hotel| {
+                let hotel = Hotel {
+                    name: hotel.name.trim().to_string(),
+                    address: optional_text(hotel.address),
+                    website_url: optional_text(hotel.website_url),
+                    walking_minutes: hotel.walking_minutes,
+                    booking_code: optional_text(hotel.booking_code),
+                    notes: optional_text(hotel.notes),
+                };
+                if hotel.name.is_empty() {
+                    return Err("Give every hotel a name".to_string());
+                }
+                check_length("hotel name", Some(&hotel.name), MAX_TRAVEL_TEXT_CHARS)?;
+                check_length("hotel address", hotel.address.as_deref(), MAX_TRAVEL_TEXT_CHARS)?;
+                check_length("booking code", hotel.booking_code.as_deref(), MAX_TRAVEL_TEXT_CHARS)?;
+                check_length("hotel notes", hotel.notes.as_deref(), MAX_TRAVEL_NOTES_CHARS)?;
+                check_web_address("hotel website", hotel.website_url.as_deref())?;
+                Ok(hotel)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let transport = travel
+            .transport
+            .into_iter()
+            .map(|option| {
+                let option = TransportOption {
+                    mode: option.mode,
+                    title: option.title.trim().to_string(),
+                    details: optional_text(option.details),
+                    url: optional_text(option.url),
+                };
+                if option.title.is_empty() {
+                    return Err("Give every way of getting there a title".to_string());
+                }
+                check_length("transport title", Some(&option.title), MAX_TRAVEL_TEXT_CHARS)?;
+                check_length("transport details", option.details.as_deref(), MAX_TRAVEL_NOTES_CHARS)?;
+                check_web_address("transport link", option.url.as_deref())?;
+                Ok(option)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let parking = travel
+            .parking
+            .map(|parking| {
+                let parking = ParkingInfo {
+                    address: optional_text(parking.address),
+                    free_of_charge: parking.free_of_charge,
+                    ev_charging: parking.ev_charging,
+                    notes: optional_text(parking.notes),
+                };
+                check_length("parking address", parking.address.as_deref(), MAX_TRAVEL_TEXT_CHARS)?;
+                check_length("parking notes", parking.notes.as_deref(), MAX_TRAVEL_NOTES_CHARS)?;
+                Ok::<_, String>(parking)
+            })
+            .transpose()?;
+
+        self.repo
+            .update_travel(event_id, &TravelInfo { hotels, transport, parking })
+            .await
+    }
+}
+
+// Longest message the API accepts
+const MAX_MESSAGE_CHARS: usize = 5000;
+
+/// Conversations with the organizers of events the user attends, and with
+/// attendees of events they organize
+#[derive(Clone)]
+pub struct MessagingService {
+    repo: Arc<dyn MessageRepository>,
+}
+
+impl MessagingService {
+    pub fn new(repo: Arc<dyn MessageRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn inbox(&self) -> Result<Vec<MessageThread>, String> {
+        self.repo.list_threads().await
+    }
+
+    pub async fn unread_count(&self) -> Result<u32, String> {
+        self.repo.unread_count().await
+    }
+
+    /// Opening a thread marks it read
+    pub async fn thread(&self, thread_id: Uuid) -> Result<(MessageThread, Vec<Message>), String> {
+        self.repo.get_thread(thread_id).await
+    }
+
+    pub async fn reply(&self, thread_id: Uuid, body: &str) -> Result<Message, String> {
+        self.repo.reply(thread_id, &Self::checked(body)?).await
+    }
+
+    pub async fn contact_organizers(&self, event_id: Uuid, body: &str) -> Result<Message, String> {
+        self.repo.contact_organizers(event_id, &Self::checked(body)?).await
+    }
+
+    fn checked(body: &str) -> Result<String, String> {
+        let body = body.trim();
+        if body.is_empty() {
+            return Err("Please write something first".to_string());
+        }
+        if body.chars().count() > MAX_MESSAGE_CHARS {
+            return Err(format!("Messages can be at most {} characters", MAX_MESSAGE_CHARS));
+        }
+        Ok(body.to_string())
+    }
+}
+
+// Same limits as the API
+const MAX_INTERESTS: usize = 15;
+const MAX_INTEREST_CHARS: usize = 50;
+const MAX_BIO_CHARS: usize = 1000;
+const MAX_MEETING_MESSAGE_CHARS: usize = 500;
+
+/// Splits comma-separated interests, dropping empty ones and repeats in any case
+pub fn parse_interests(text: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    text.split(',')
+        .map(|interest| interest.trim().to_string())
+        .filter(|interest| !interest.is_empty() && seen.insert(interest.to_lowercase()))
+        .collect()
+}
+
+/// Every interest in the directory once, alphabetically
+pub fn directory_interests(contacts: &[NetworkingContact]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut interests: Vec<String> = contacts
+        .iter()
+        .flat_map(|contact| contact.interests.iter())
+        .filter(|interest| seen.insert(interest.to_lowercase()))
+        .cloned()
+        .collect();
+    interests.sort_by_key(|interest| interest.to_lowercase());
+    interests
+}
+
+/// The contacts whose name, company or interests contain `query`, and who
+/// have `interest` when one is picked; both ignore case
+pub fn filter_contacts(contacts: &[NetworkingContact], query: &str, interest: Option<&str>) -> Vec<NetworkingContact> {
+    let query = query.trim().to_lowercase();
+    let interest = interest.map(str::to_lowercase);
+    contacts
+        .iter()
+        .filter(|contact| {
+            query.is_empty()
+                || contact.name.to_lowercase().contains(&query)
+                || contact.company.as_ref().is_some_and(|company| company.to_lowercase().contains(&query))
+                || contact.interests.iter().any(|i| i.to_lowercase().contains(&query))
+        })
+        .filter(|contact| match &interest {
+            Some(interest) => contact.interests.iter().any(|i| i.to_lowercase() == *interest),
+            None => true,
+        })
+        .cloned()
+        .collect()
+}
+
+/// The networking directory of an event's attendees and the 1:1 meetings
+/// they request through it
+#[derive(Clone)]
+pub struct NetworkingService {
+    repo: Arc<dyn NetworkingRepository>,
+}
+
+impl NetworkingService {
+    pub fn new(repo: Arc<dyn NetworkingRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn profile(&self, event_id: Uuid) -> Result<Option<NetworkingProfile>, String> {
+        self.repo.get_profile(event_id).await
+    }
+
+    /// Lists the user in the directory, or updates their entry
+    pub async fn opt_in(&self, event_id: Uuid, profile: NetworkingProfileDraft) -> Result<NetworkingProfile, String> {
+        if profile.interests.len() > MAX_INTERESTS {
+            return Err(format!("List at most {} interests", MAX_INTERESTS));
+        }
+        if profile.interests.iter().any(|interest| interest.chars().count() > MAX_INTEREST_CHARS) {
+            return Err(format!("Interests can be at most {} characters", MAX_INTEREST_CHARS));
+        }
+        let bio = optional_text(profile.bio);
+        check_length("bio", bio.as_deref(), MAX_BIO_CHARS)?;
+        if profile.availability.iter().any(|window| window.ends_at <= window.starts_at) {
+            return Err("Each time you're free must end after it starts".to_string());
+        }
+        let mut availability = profile.availability;
+        availability.sort_by_key(|window| window.starts_at);
+        self.repo
+            .save_profile(event_id, &NetworkingProfileDraft { bio, availability, ..profile })
+            .await
+    }
+
+    /// Takes the user out of the directory and cancels their meetings
+    pub async fn leave(&self, event_id: Uuid) -> Result<(), String> {
+        self.repo.leave(event_id).await
+    }
+
+    pub async fn directory(&self, event_id: Uuid) -> Result<Vec<NetworkingContact>, String> {
+        self.repo.list_contacts(event_id).await
+    }
+
+    pub async fn meetings(&self, event_id: Uuid) -> Result<Vec<Meeting>, String> {
+        self.repo.list_meetings(event_id).await
+    }
+
+    pub async fn request_meeting(&self, event_id: Uuid, invitee_id: Uuid, message: &str) -> Result<(), String> {
+        let message = optional_text(Some(message.to_string()));
+        check_length("message", message.as_deref(), MAX_MEETING_MESSAGE_CHARS)?;
+        self.repo.request_meeting(event_id, invitee_id, message.as_deref()).await
+    }
+
+    pub async fn answer(&self, event_id: Uuid, meeting_id: Uuid, accept: bool) -> Result<(), String> {
+        self.repo.answer_meeting(event_id, meeting_id, accept).await
+    }
+
+    pub async fn cancel(&self, event_id: Uuid, meeting_id: Uuid) -> Result<(), String> {
+        self.repo.cancel_meeting(event_id, meeting_id).await
+    }
+
+    pub async fn invite(&self, event_id: Uuid, meeting_id: Uuid) -> Result<String, String> {
+        self.repo.download_invite(event_id, meeting_id).await
+    }
+}
+
+/// Finds people to pick as co-organizers and invitees
+#[derive(Clone)]
+pub struct UserSearchService {
+    repo: Arc<dyn UserSearchRepository>,
+}
+
+impl UserSearchService {
+    pub fn new(repo: Arc<dyn UserSearchRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Queries too short for the API find nothing without asking it
+    pub async fn search(&self, query: &str) -> Result<Vec<UserMatch>, String> {
+        let query: String = query.trim().chars().take(MAX_TITLE_QUERY_CHARS).collect();
+        if query.chars().count() < MIN_USER_QUERY_CHARS {
+            return Ok(Vec::new());
+        }
+        self.repo.search_users(&query, SEARCH_RESULTS).await
+    }
+}
+
+#[derive(Clone)]
+pub struct CompanyDirectoryService {
+    repo: Arc<dyn CompanyRepository>,
+}
+
+impl CompanyDirectoryService {
+    pub fn new(repo: Arc<dyn CompanyRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn list(&self, page: u32, industry: Option<Industry>) -> Result<Page<Company>, String> {
+        self.repo.list_companies(page, industry).await
+    }
+
+    pub async fn upcoming_events(&self, company_id: Uuid) -> Result<Vec<EventListItem>, String> {
+        self.repo.upcoming_events(company_id).await
+    }
+
+    pub async fn invite(&self, token: &str) -> Result<OrganizationInvite, String> {
+        self.repo.organization_invite(token).await
+    }
+
+    pub async fn accept_invite(&self, token: &str) -> Result<(), String> {
+        self.repo.accept_organization_invite(token).await
+    }
+}
+
+// Longest message the API accepts in an error report
+const MAX_REPORTED_MESSAGE_CHARS: usize = 4000;
+
+#[derive(Clone)]
+pub struct ErrorReportingService {
+    reporter: Arc<dyn ErrorReporter>,
+}
+
+impl ErrorReportingService {
+    pub fn new(reporter: Arc<dyn ErrorReporter>) -> Self {
+        Self { reporter }
+    }
+
+    /// Sends `report`, cutting overly long messages down to what the API accepts
+    pub async fn report(&self, report: &ErrorReport) -> Result<(), String> {
+        if report.message.chars().count() <= MAX_REPORTED_MESSAGE_CHARS {
+            return self.reporter.report_error(report).await;
+        }
+        let shortened = ErrorReport {
+            message: report.message.chars().take(MAX_REPORTED_MESSAGE_CHARS).collect(),
+            ..report.clone()
+        };
+        self.reporter.report_error(&shortened).await
+    }
+}
+
+/// Version of the app sent with web vitals, so they can be compared between releases
+pub const RELEASE: &str = env!("CARGO_PKG_VERSION");
+
+// Most measurements the API accepts in one report
+const MAX_REPORTED_WEB_VITALS: usize = 50;
+
+#[derive(Clone)]
+pub struct WebVitalsService {
+    reporter: Arc<dyn WebVitalsReporter>,
+}
+
+impl WebVitalsService {
+    pub fn new(reporter: Arc<dyn WebVitalsReporter>) -> Self {
+        Self { reporter }
+    }
+
+    /// Sends the measurements as one report per route, split up into reports
+    /// the API accepts; stops at the first that fails
+    pub async fn report(&self, vitals: Vec<WebVital>) -> Result<(), String> {
+        let mut by_route: Vec<(String, Vec<(WebVitalMetric, f64)>)> = Vec::new();
+        for vital in vitals {
+            match by_route.iter_mut().find(|(route, _)| *route == vital.route) {
+                Some((_, measurements)) => measurements.push((vital.metric, vital.value)),
+                None => by_route.push((vital.route, vec![(vital.metric, vital.value)])),
+            }
+        }
+        for (route, measurements) in by_route {
+            for chunk in measurements.chunks(MAX_REPORTED_WEB_VITALS) {
+                let report = WebVitalsReport {
+                    release: RELEASE.to_string(),
+                    route: route.clone(),
+                    measurements: chunk.to_vec(),
+                };
+                self.reporter.report_web_vitals(&report).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Most log lines the API accepts in one batch
+const MAX_SHIPPED_LOG_ENTRIES: usize = 100;
+
+#[derive(Clone)]
+pub struct LoggingService {
+    sink: Arc<dyn LogSink>,
+}
+
+impl LoggingService {
+    pub fn new(sink: Arc<dyn LogSink>) -> Self {
+        Self { sink }
+    }
+
+    /// Sends the lines in batches the API accepts; stops at the first that fails
+    pub async fn ship(&self, entries: Vec<LogEntry>) -> Result<(), String> {
+        for chunk in entries.chunks(MAX_SHIPPED_LOG_ENTRIES) {
+            self.sink.send_logs(RELEASE, chunk).await?;
+        }
+        Ok(())
+    }
+}
+
+/// How a metric's 75th percentile compares to its published thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebVitalRating {
+    Good,
+    NeedsImprovement,
+    Poor,
+}
+
+impl WebVitalRating {
+    /// `None` for API latency, which has no published thresholds
+    pub fn of(metric: WebVitalMetric, p75: f64) -> Option<Self> {
+        let (good, poor) = metric.thresholds()?;
+        Some(if p75 <= good {
+            WebVitalRating::Good
+        } else if p75 <= poor {
+            WebVitalRating::NeedsImprovement
+        } else {
+            WebVitalRating::Poor
+        })
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            WebVitalRating::Good => "Good",
+            WebVitalRating::NeedsImprovement => "Needs improvement",
+            WebVitalRating::Poor => "Poor",
+        }
+    }
+}
+
+pub fn web_vital_label(metric: WebVitalMetric) -> &'static str {
+    match metric {
+        WebVitalMetric::Lcp => "Largest contentful paint",
+        WebVitalMetric::Fid => "First input delay",
+        WebVitalMetric::Inp => "Interaction to next paint",
+        WebVitalMetric::Cls => "Cumulative layout shift",
+        WebVitalMetric::ApiLatency => "API latency",
+    }
+}
+
+pub fn web_vital_abbreviation(metric: WebVitalMetric) -> &'static str {
+    match metric {
+        WebVitalMetric::Lcp => "LCP",
+        WebVitalMetric::Fid => "FID",
+        WebVitalMetric::Inp => "INP",
+        WebVitalMetric::Cls => "CLS",
+        WebVitalMetric::ApiLatency => "API",
+    }
+}
+
+/// Milliseconds below a second as `180 ms`, longer ones as `2.4 s`; layout
+/// shift is unitless
+pub fn format_web_vital(metric: WebVitalMetric, value: f64) -> String {
+    match metric {
+        WebVitalMetric::Cls => format!("{:.2}", value),
+        _ if value < 1000.0 => format!("{:.0} ms", value),
+        _ => format!("{:.1} s", value / 1000.0),
+    }
+}
+
+/// The summaries of each release, newest release first
+pub fn web_vitals_by_release(summaries: &[WebVitalSummary]) -> Vec<(String, Vec<WebVitalSummary>)> {
+    let mut releases: Vec<(String, Vec<WebVitalSummary>)> = Vec::new();
+    for summary in summaries {
+        match releases.iter_mut().find(|(release, _)| *release == summary.release) {
+            Some((_, metrics)) => metrics.push(summary.clone()),
+            None => releases.push((summary.release.clone(), vec![summary.clone()])),
+        }
+    }
+    let first_seen = |metrics: &[WebVitalSummary]| metrics.iter().map(|summary| summary.first_seen).min();
+    releases.sort_by(|(_, a), (_, b)| first_seen(b).cmp(&first_seen(a)));
+    releases
+}
+
+// Longest name the settings page accepts
+const MAX_NAME_CHARS: usize = 100;
+
+/// The signed-in user's profile and preferences
+#[derive(Clone)]
+pub struct SettingsService {
+    repo: Arc<dyn SettingsRepository>,
+}
+
+impl SettingsService {
+    pub fn new(repo: Arc<dyn SettingsRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn load(&self) -> Result<UserSettings, String> {
+        self.repo.load_settings().await
+    }
+
+    /// Trims the name and time zone; a blank time zone goes back to the browser's
+    pub async fn save(&self, settings: UserSettings) -> Result<UserSettings, String> {
+        let name = settings.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Enter your name".to_string());
+        }
+        check_length("name", Some(name.as_str()), MAX_NAME_CHARS)?;
+        let timezone = optional_text(settings.timezone);
+        self.repo
+            .save_settings(&UserSettings { name, timezone, ..settings })
+            .await
+    }
+}
+
+#[derive(Clone)]
+pub struct ConfigService {
+    repo: Arc<dyn ConfigRepository>,
+}
+
+impl ConfigService {
+    pub fn new(repo: Arc<dyn ConfigRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// The server's settings, or the built-in defaults when it can't be
+    /// reached, so the app still starts
+    pub async fn load(&self) -> ClientConfig {
+        self.repo.client_config().await.unwrap_or_default()
+    }
+}
+
+/// Time left to confirm an offered spot, as `HH:MM:SS`
+pub fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.num_seconds().max(0);
+    format!("{:02}:{:02}:{:02}", seconds / 3600, seconds % 3600 / 60, seconds % 60)
 }
@@ -1,8 +0,0 @@
-pub mod event_list;
-pub mod event_form;
-pub mod event_card;
-pub mod event_detail;
-pub mod navigation;
-pub mod auth;
-pub mod calendar;
-pub mod calendar_enhanced;
\ No newline at end of file
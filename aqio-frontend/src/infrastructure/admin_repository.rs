@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{
+    AccountChanges, AdminRepository, AuditEntry, Category, CompanyDraft, CreatedCompany, OrganizationInvite, Page,
+    PermissionSet, RegisteredCompany, RegistryLookup, Role, UserAccount, WebVitalSummary,
+};
+
+use super::api_client::{
+    ApiClient, AuditLogEntryResponse, CategoryResponse, CompanyRegistryResponse, CreateCompanyRequest,
+    OrganizationInvitationResponse, Paginated, UpdateCategoryRequest, UpdateUserRequest, UserResponse,
+    WebVitalSummaryResponse,
+};
+use super::company_repository::map_company;
+
+#[derive(Clone)]
+pub struct ApiAdminRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiAdminRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_user(user: UserResponse) -> Result<UserAccount, String> {
+    let role = Role::parse(&user.role).ok_or_else(|| format!("Unknown role '{}'", user.role))?;
+    Ok(UserAccount {
+        id: user.id,
+        name: user.name,
+        email: user.email,
+        role,
+        is_active: user.is_active,
+        created_at: user.created_at,
+    })
+}
+
+pub(super) fn map_category(category: CategoryResponse) -> Category {
+    Category {
+        id: category.id,
+        name: category.name,
+        description: category.description,
+        color_hex: category.color_hex,
+        icon_name: category.icon_name,
+        is_active: category.is_active,
+    }
+}
+
+fn to_category_response(category: &Category) -> CategoryResponse {
+    CategoryResponse {
+        id: category.id.clone(),
+        name: category.name.clone(),
+        description: category.description.clone(),
+        color_hex: category.color_hex.clone(),
+        icon_name: category.icon_name.clone(),
+        is_active: category.is_active,
+    }
+}
+
+fn map_audit_entry(entry: AuditLogEntryResponse) -> AuditEntry {
+    AuditEntry {
+        id: entry.id,
+        table_name: entry.table_name,
+        record_id: entry.record_id,
+        action: entry.action,
+        actor: entry.user_name.or(entry.user_email),
+        changed_fields: entry.changed_fields,
+        created_at: entry.created_at,
+    }
+}
+
+pub(super) fn map_organization_invite(invitation: OrganizationInvitationResponse) -> Result<OrganizationInvite, String> {
+    let role = Role::parse(&invitation.role).ok_or_else(|| format!("Unknown role '{}'", invitation.role))?;
+    Ok(OrganizationInvite {
+        id: invitation.id,
+        company_name: invitation.company_name,
+        email: invitation.email,
+        role,
+        expires_at: invitation.expires_at,
+        is_open: invitation.is_open,
+        created_at: invitation.created_at,
+    })
+}
+
+fn map_web_vital_summary(summary: WebVitalSummaryResponse) -> WebVitalSummary {
+    WebVitalSummary {
+        release: summary.release,
+        metric: summary.metric,
+        samples: summary.samples,
+        p50: summary.p50,
+        p75: summary.p75,
+        p95: summary.p95,
+        first_seen: summary.first_seen,
+    }
+}
+
+fn map_registry_lookup(lookup: CompanyRegistryResponse) -> Result<RegistryLookup, String> {
+    match (lookup.status.as_str(), lookup.company) {
+        ("found", Some(company)) => Ok(RegistryLookup::Found(RegisteredCompany {
+            org_number: company.org_number,
+            name: company.name,
+            address: company.address,
+            city: company.city,
+            website: company.website,
+            industry_description: company.industry_description,
+            is_active: company.is_active,
+        })),
+        ("not_found", _) => Ok(RegistryLookup::NotFound),
+        ("unavailable", _) => Ok(RegistryLookup::Unavailable),
+        (status, _) => Err(format!("Unknown register status '{}'", status)),
+    }
+}
+
+// Blank fields are left out so the API fills them from the register
+fn to_create_company_request(draft: &CompanyDraft) -> CreateCompanyRequest {
+    let text = |value: &str| Some(value.trim().to_string()).filter(|value| !value.is_empty());
+    CreateCompanyRequest {
+        org_number: text(&draft.org_number),
+        name: text(&draft.name),
+        location: text(&draft.location),
+        industry_type: draft.industry.map(|industry| industry.as_str().to_string()),
+        industry_type_other: text(&draft.industry_description),
+        website: text(&draft.website),
+        phone: text(&draft.phone),
+    }
+}
+
+pub(super) fn map_page<T, U>(page: Paginated<T>, map: impl FnMut(T) -> Result<U, String>) -> Result<Page<U>, String> {
+    Ok(Page {
+        items: page.items.into_iter().map(map).collect::<Result<_, _>>()?,
+        page: page.pagination.page,
+        total_pages: page.pagination.total_pages,
+        total_count: page.pagination.total_count,
+    })
+}
+
+#[async_trait::async_trait(?Send)]
+impl AdminRepository for ApiAdminRepository {
+    async fn current_user(&self) -> Result<UserAccount, String> {
+        map_user(self.api.current_user().await?)
+    }
+
+    async fn my_permissions(&self) -> Result<PermissionSet, String> {
+        Ok(self.api.my_permissions().await?.permissions)
+    }
+
+    async fn list_users(&self, page: u32) -> Result<Page<UserAccount>, String> {
+        map_page(self.api.list_users(page).await?, map_user)
+    }
+
+    async fn update_user(&self, id: Uuid, changes: AccountChanges) -> Result<UserAccount, String> {
+        let request = UpdateUserRequest {
+            role: changes.role.map(|role| role.as_str().to_string()),
+            is_active: changes.is_active,
+        };
+        map_user(self.api.update_user(id, &request).await?)
+    }
+
+    async fn list_categories(&self) -> Result<Vec<Category>, String> {
+        let categories = self.api.list_all_categories().await?;
+        Ok(categories.into_iter().map(map_category).collect())
+    }
+
+    async fn create_category(&self, category: &Category) -> Result<Category, String> {
+        let created = self.api.create_category(&to_category_response(category)).await?;
+        Ok(map_category(created))
+    }
+
+    async fn update_category(&self, category: &Category) -> Result<Category, String> {
+        let request = UpdateCategoryRequest {
+            name: category.name.clone(),
+            description: Some(category.description.clone()),
+            color_hex: Some(category.color_hex.clone()),
+            icon_name: Some(category.icon_name.clone()),
+            is_active: category.is_active,
+        };
+        let updated = self.api.update_category(&category.id, &request).await?;
+        Ok(map_category(updated))
+    }
+
+    async fn delete_category(&self, id: &str) -> Result<(), String> {
+        self.api.delete_category(id).await
+    }
+
+    async fn list_audit_log(&self, page: u32, table_name: Option<String>) -> Result<Page<AuditEntry>, String> {
+        let entries = self.api.list_audit_log(page, table_name.as_deref()).await?;
+        map_page(entries, |entry| Ok(map_audit_entry(entry)))
+    }
+
+    async fn list_organization_invites(&self) -> Result<Vec<OrganizationInvite>, String> {
+        let invitations = self.api.list_organization_invitations().await?;
+        invitations.into_iter().map(map_organization_invite).collect()
+    }
+
+    async fn revoke_organization_invite(&self, id: Uuid) -> Result<(), String> {
+        self.api.revoke_organization_invitation(id).await
+    }
+
+    async fn lookup_company(&self, org_number: &str) -> Result<RegistryLookup, String> {
+        map_registry_lookup(self.api.lookup_company_registry(org_number).await?)
+    }
+
+    async fn create_company(&self, draft: &CompanyDraft) -> Result<CreatedCompany, String> {
+        let created = self.api.create_company(&to_create_company_request(draft)).await?;
+        Ok(CreatedCompany {
+            company: map_company(created.company)?,
+            registry_verified: created.registry_verified,
+        })
+    }
+
+    async fn web_vitals(&self, days: u32) -> Result<Vec<WebVitalSummary>, String> {
+        let summaries = self.api.list_web_vitals(days).await?;
+        Ok(summaries.into_iter().map(map_web_vital_summary).collect())
+    }
+}
@@ -1,68 +1,2343 @@
-use chrono::{DateTime, Utc};
-use reqwest::Client;
-use serde::Deserialize;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use gloo_timers::future::TimeoutFuture;
+use reqwest::{
+    multipart::{Form, Part},
+    Client, Method, RequestBuilder, Response, StatusCode,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 const API_BASE_URL: &str = "http://127.0.0.1:3000";
+// Sent with each call and echoed by the API, which logs it with the request
+const REQUEST_ID_HEADER: &str = "x-request-id";
 
 #[derive(Clone)]
 pub struct ApiClient {
     client: Client,
     base_url: String,
     auth_token: Option<String>,
+    retry: RetryPolicy,
 }
 
+/// How reads are retried when the API can't be reached or is briefly
+/// unavailable (`502`, `503` or `504`); writes are never retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts after the first
+    pub retries: u32,
+    /// Pause before the first retry, doubled for each one after it
+    pub backoff_ms: u32,
+}
+
+impl RetryPolicy {
+    pub const NONE: RetryPolicy = RetryPolicy { retries: 0, backoff_ms: 0 };
+}
+
+// Compact event shape returned by `GET /api/v1/events?view=summary`
 #[derive(Debug, Deserialize, Clone, PartialEq)]
-pub struct EventResponse {
+pub struct EventSummaryResponse {
     pub id: Uuid,
     pub title: String,
+    pub category_id: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub location_name: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    // Not part of the summary view
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub registration_state: Option<String>,
+    #[serde(default)]
+    pub seconds_until_registration_opens: Option<i64>,
+    #[serde(default)]
+    pub seconds_until_registration_closes: Option<i64>,
+}
+
+// Fields the API doesn't receive fall back to its defaults, also when updating
+#[derive(Debug, Serialize)]
+pub struct CreateEventRequest {
+    pub title: String,
+    pub description: String,
+    pub category_id: String,
     pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub timezone: String,
+    pub location_type: &'static str,
     pub location_name: Option<String>,
+    pub address: Option<String>,
+    pub virtual_link: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub max_attendees: Option<i32>,
+    pub is_private: Option<bool>,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub co_organizers: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct UserResponse {
+    pub id: Uuid,
+    pub email: String,
+    pub name: String,
+    pub role: String,
+    pub is_active: bool,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub locale: Option<aqio_core::Locale>,
+    #[serde(default)]
+    pub theme: Option<aqio_core::Theme>,
+    #[serde(default)]
+    pub calendar_view: Option<aqio_core::CalendarView>,
+    pub created_at: DateTime<Utc>,
+}
+
+// `GET /api/v1/users/me/permissions`; the organization-scoped part isn't needed here
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PermissionsResponse {
+    pub permissions: aqio_core::PermissionSet,
+}
+
+// `GET /api/v1/users/search`; organizers and admins only
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct UserSearchResultResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    pub company_name: Option<String>,
+}
+
+// Only the administrative fields; omitted fields are left unchanged by the API
+#[derive(Debug, Serialize, Default)]
+pub struct UpdateUserRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
+
+// A user's own settings, sent in full; `null` puts a preference back to its default
+#[derive(Debug, Serialize)]
+pub struct UpdateProfileRequest<'a> {
+    pub name: &'a str,
+    pub timezone: Option<&'a str>,
+    pub locale: Option<aqio_core::Locale>,
+    pub theme: Option<aqio_core::Theme>,
+    pub calendar_view: Option<aqio_core::CalendarView>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct NotificationPreferencesResponse {
+    pub digest_frequency: aqio_core::DigestFrequency,
+    pub next_digest_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NotificationPreferencesRequest {
+    pub digest_frequency: aqio_core::DigestFrequency,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct CategoryResponse {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub color_hex: Option<String>,
+    pub icon_name: Option<String>,
+    pub is_active: bool,
+}
+
+// `PUT /categories/{id}` body; `Some(None)` clears an optional field
+#[derive(Debug, Serialize)]
+pub struct UpdateCategoryRequest {
+    pub name: String,
+    pub description: Option<Option<String>>,
+    pub color_hex: Option<Option<String>>,
+    pub icon_name: Option<Option<String>>,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct AuditLogEntryResponse {
+    pub id: Uuid,
+    pub table_name: String,
+    pub record_id: String,
+    pub action: String,
+    pub user_name: Option<String>,
+    pub user_email: Option<String>,
+    pub changed_fields: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct OrganizationInvitationResponse {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub company_name: String,
+    pub email: String,
+    pub role: String,
+    pub status: String,
+    pub expires_at: DateTime<Utc>,
+    pub is_open: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct CompanyResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub location: Option<String>,
+    pub industry_type: String,
+    pub industry_type_other: Option<String>,
+    pub website: Option<String>,
+    pub member_count: i64,
+}
+
+// `POST /admin/companies` body
+#[derive(Debug, Serialize)]
+pub struct CreateCompanyRequest {
+    pub org_number: Option<String>,
+    pub name: Option<String>,
+    pub location: Option<String>,
+    pub industry_type: Option<String>,
+    pub industry_type_other: Option<String>,
+    pub website: Option<String>,
+    pub phone: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct CreatedCompanyResponse {
+    pub company: CompanyResponse,
+    pub registry_verified: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct RegisteredCompanyResponse {
+    pub org_number: String,
+    pub name: String,
+    pub address: Option<String>,
+    pub city: Option<String>,
+    pub website: Option<String>,
+    pub industry_code: Option<String>,
+    pub industry_description: Option<String>,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct CompanyRegistryResponse {
+    pub org_number: String,
+    /// `found`, `not_found` or `unavailable`
+    pub status: String,
+    pub company: Option<RegisteredCompanyResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct CommentResponse {
+    pub id: Uuid,
+    pub parent_comment_id: Option<Uuid>,
+    pub author_name: Option<String>,
+    // `None` once deleted
+    pub content: Option<String>,
+    pub can_delete: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateCommentRequest<'a> {
+    pub content: &'a str,
+    pub parent_comment_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct LiveSessionResponse {
+    pub is_running: bool,
+    pub can_moderate: bool,
+    pub questions: Vec<QuestionResponse>,
+    pub polls: Vec<PollResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct QuestionResponse {
+    pub id: Uuid,
+    pub author_name: Option<String>,
+    pub content: String,
+    pub upvote_count: i64,
+    pub has_upvoted: bool,
+    pub answer: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PollResponse {
+    pub id: Uuid,
+    pub question: String,
+    pub options: Vec<PollOptionResponse>,
+    pub is_closed: bool,
+    pub voted_option_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PollOptionResponse {
+    pub id: Uuid,
+    pub label: String,
+    pub vote_count: i64,
+}
+
+#[derive(Debug, Serialize)]
+struct AskQuestionRequest<'a> {
+    content: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct AnswerQuestionRequest<'a> {
+    answer: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct PollVoteRequest {
+    option_id: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreatePollRequest<'a> {
+    pub question: &'a str,
+    pub options: &'a [String],
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    // The name it was uploaded with
+    pub filename: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub mime_type: String,
+    pub file_size: i64,
+    pub category: String,
+    pub visibility: String,
+    pub uploader_name: Option<String>,
+    pub download_count: i64,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct EventAttachmentsResponse {
+    pub can_manage: bool,
+    pub attachments: Vec<AttachmentResponse>,
+}
+
+// Sent as multipart/form-data next to the file itself
+pub struct UploadAttachmentRequest {
+    pub filename: String,
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub visibility: &'static str,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PhotoResponse {
+    pub id: Uuid,
+    // The name it was uploaded with
+    pub filename: String,
+    pub caption: Option<String>,
+    pub mime_type: String,
+    pub file_size: i64,
+    pub status: String,
+    pub uploader_name: Option<String>,
+    pub can_remove: bool,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PhotoGalleryResponse {
+    pub attendee_uploads: bool,
+    pub requires_approval: bool,
+    pub is_public: bool,
+    pub can_manage: bool,
+    pub can_upload: bool,
+    pub photos: Vec<PhotoResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PhotoAlbumRequest {
+    pub attendee_uploads: bool,
+    pub requires_approval: bool,
+    pub is_public: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModeratePhotoRequest {
+    pub status: &'static str,
+}
+
+// Sent as multipart/form-data next to the file itself
+pub struct UploadPhotoRequest {
+    pub filename: String,
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+    pub caption: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct EventFieldResponse {
+    pub id: Uuid,
+    pub key: String,
+    pub label: String,
+    // The enum variant name, e.g. `Select`
+    pub field_type: String,
+    pub options: Vec<String>,
+    pub is_required: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct EventFieldValueResponse {
+    pub field: EventFieldResponse,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct EventFieldsResponse {
+    pub fields: Vec<EventFieldValueResponse>,
+    pub can_edit: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EventFieldValuesRequest {
+    pub values: HashMap<String, String>,
+}
+
+// Budget amounts are whole øre
+#[derive(Debug, Serialize)]
+pub struct BudgetLineRequest<'a> {
+    pub name: &'a str,
+    pub planned_amount: i64,
+    pub notes: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExpenseRequest<'a> {
+    pub budget_line_id: Option<Uuid>,
+    pub description: &'a str,
+    pub vendor: Option<&'a str>,
+    pub amount: i64,
+    pub incurred_on: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct BudgetLineResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub planned_amount: i64,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ReceiptResponse {
+    pub filename: String,
+    pub mime_type: String,
+    pub file_size: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ExpenseResponse {
+    pub id: Uuid,
+    pub budget_line_id: Option<Uuid>,
+    pub description: String,
+    pub vendor: Option<String>,
+    pub amount: i64,
+    pub incurred_on: NaiveDate,
+    pub receipt: Option<ReceiptResponse>,
+    pub recorded_by_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct BudgetLineTotalsResponse {
+    pub budget_line_id: Uuid,
+    pub name: String,
+    pub planned: i64,
+    pub spent: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct BudgetSummaryResponse {
+    pub planned: i64,
+    pub spent: i64,
+    pub unassigned: i64,
+    pub expense_count: usize,
+    pub lines: Vec<BudgetLineTotalsResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct EventBudgetResponse {
+    pub lines: Vec<BudgetLineResponse>,
+    pub expenses: Vec<ExpenseResponse>,
+    pub summary: BudgetSummaryResponse,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SponsorRequest<'a> {
+    pub name: &'a str,
+    pub tier: &'a str,
+    pub logo_url: Option<&'a str>,
+    pub website_url: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub display_order: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReorderSponsorsRequest {
+    pub sponsor_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct SponsorResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub tier: String,
+    pub logo_url: Option<String>,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    pub display_order: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScheduleCheckRequest {
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub event_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ScheduleConflictResponse {
+    pub enforcement: String,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ScheduleCheckResponse {
+    pub conflicts: Vec<ScheduleConflictResponse>,
+    pub blocked: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct CateringGroupResponse {
+    pub label: String,
+    pub registrations: u32,
+    pub people: u32,
+    pub answers: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct CateringReportResponse {
+    pub confirmed_registrations: u32,
+    pub guests: u32,
+    pub total_people: u32,
+    pub dietary: Vec<CateringGroupResponse>,
+    pub accessibility: Vec<CateringGroupResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SeatingTableRequest<'a> {
+    pub name: &'a str,
+    pub capacity: u32,
+    pub accessible: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SeatAssignmentRequest {
+    pub table_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct SeatingTableResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub capacity: u32,
+    pub accessible: bool,
+    pub position: i32,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct SeatedPartyResponse {
+    pub registration_id: Uuid,
+    pub name: String,
+    pub company: Option<String>,
+    pub guest_names: Vec<String>,
+    pub seats: u32,
+    pub accessibility_needs: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PlannedTableResponse {
+    pub table: SeatingTableResponse,
+    pub seats_taken: u32,
+    pub parties: Vec<SeatedPartyResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct SeatingWarningResponse {
+    pub kind: String,
+    pub message: String,
+    pub table_ids: Vec<Uuid>,
+    pub registration_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct SeatingPlanResponse {
+    pub capacity: u32,
+    pub seated_people: u32,
+    pub unassigned_people: u32,
+    pub tables: Vec<PlannedTableResponse>,
+    pub unassigned: Vec<SeatedPartyResponse>,
+    pub warnings: Vec<SeatingWarningResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PastEventResponse {
+    pub event_id: Uuid,
+    pub title: String,
+    pub category_id: String,
+    pub category_name: Option<String>,
+    pub organizer_name: Option<String>,
+    pub start_date: DateTime<Utc>,
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub no_shows: i64,
+    pub cancelled: i64,
+    pub attendance_rate: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct TrendYearResponse {
+    pub year: i32,
+    pub events: i64,
+    pub registrations: i64,
+    pub checked_in: i64,
+    pub attendance_rate: Option<f64>,
+    pub registrations_change: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct AttendanceTrendResponse {
+    pub group_id: String,
+    pub group_name: Option<String>,
+    pub events: i64,
+    pub registrations: i64,
+    pub attendance_rate: Option<f64>,
+    pub years: Vec<TrendYearResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageThreadResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_title: String,
+    pub attendee_name: String,
+    pub as_organizer: bool,
+    pub unread: u32,
+    pub last_message_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageResponse {
+    pub id: Uuid,
+    pub thread_id: Uuid,
+    pub from_organizer: bool,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MessageThreadDetailsResponse {
+    pub thread: MessageThreadResponse,
+    pub messages: Vec<MessageResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UnreadMessagesResponse {
+    pub unread: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SendMessageRequest<'a> {
+    pub body: &'a str,
+}
+
+// Availability is sent back in the same shape it's read in
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub struct AvailabilityWindowResponse {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MatchmakingProfileResponse {
+    pub user_id: Uuid,
+    pub interests: Vec<String>,
+    pub bio: Option<String>,
+    pub availability: Vec<AvailabilityWindowResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MatchmakingProfileRequest<'a> {
+    pub interests: &'a [String],
+    pub bio: Option<&'a str>,
+    pub availability: Vec<AvailabilityWindowResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MatchResponse {
+    pub user_id: Uuid,
+    pub name: String,
+    pub company: Option<String>,
+    pub bio: Option<String>,
+    pub interests: Vec<String>,
+    pub shared_interests: Vec<String>,
+    pub availability: Vec<AvailabilityWindowResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RequestMeetingRequest<'a> {
+    pub invitee_id: Uuid,
+    pub message: Option<&'a str>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct MeetingResponse {
+    pub id: Uuid,
+    pub requester_id: Uuid,
+    pub requester_name: String,
+    pub invitee_id: Uuid,
+    pub invitee_name: String,
+    pub message: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub room: Option<String>,
+    // The enum variant name, e.g. `Requested`
+    pub status: String,
+}
+
+// Travel information is sent back in the same shape it's read in
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct HotelResponse {
+    pub name: String,
+    pub address: Option<String>,
+    pub website_url: Option<String>,
+    pub walking_minutes: Option<u32>,
+    pub booking_code: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct TransportOptionResponse {
+    pub mode: String,
+    pub title: String,
+    pub details: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ParkingInfoResponse {
+    pub address: Option<String>,
+    #[serde(default)]
+    pub free_of_charge: bool,
+    #[serde(default)]
+    pub ev_charging: bool,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+pub struct TravelInfoResponse {
+    #[serde(default)]
+    pub hotels: Vec<HotelResponse>,
+    #[serde(default)]
+    pub transport: Vec<TransportOptionResponse>,
+    pub parking: Option<ParkingInfoResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct InvitationResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub invited_email: Option<String>,
+    pub invited_name: Option<String>,
+    pub personal_message: Option<String>,
+    pub status: String,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub responded_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub delivery_failure: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateInvitationRequest {
+    pub invited_email: Option<String>,
+    pub invited_name: Option<String>,
+    pub invitation_method: String,
+    pub personal_message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct UpdateInvitationStatusRequest<'a> {
+    status: &'a str,
+}
+
+// Only the fields the check-in scanner and waitlist view need
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct RegistrationResponse {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub registrant_name: Option<String>,
+    pub registrant_email: Option<String>,
+    pub status: String,
+    pub guest_count: i32,
+    pub promotion_expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct WaitlistStatusResponse {
+    pub registration: RegistrationResponse,
+    pub position: Option<usize>,
+    pub waitlist_size: usize,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct AgendaItemResponse {
+    pub time: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    pub speaker: Option<String>,
 }
 
-impl ApiClient {
-    pub fn new() -> Self {
-        Self {
-            client: Client::new(),
-            base_url: API_BASE_URL.to_string(),
-            auth_token: None,
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct SpeakerResponse {
+    pub name: String,
+    pub title: Option<String>,
+    pub company: Option<String>,
+    pub bio: Option<String>,
+    pub photo_url: Option<String>,
+}
+
+// The full event response; the program lives in its `custom_fields` JSON
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct EventProgramResponse {
+    pub title: String,
+    pub description: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub location_name: Option<String>,
+    pub address: Option<String>,
+    pub custom_fields: Option<String>,
+    #[serde(default)]
+    pub forecast: Option<WeatherForecastResponse>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct WeatherForecastResponse {
+    pub date: NaiveDate,
+    pub symbol_code: Option<String>,
+    pub min_temperature_c: f64,
+    pub max_temperature_c: f64,
+    pub precipitation_mm: f64,
+    pub max_wind_speed_ms: f64,
+}
+
+// Same shape the API reads for the public event page
+#[derive(Debug, Deserialize, Default)]
+pub struct ProgramFields {
+    #[serde(default)]
+    pub agenda: Vec<AgendaItemResponse>,
+    #[serde(default)]
+    pub speakers: Vec<SpeakerResponse>,
+}
+
+// Public check behind the QR code on a certificate of attendance
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct CertificateVerificationResponse {
+    pub registration_id: Uuid,
+    pub valid: bool,
+    pub attendee_name: Option<String>,
+    pub event_title: Option<String>,
+    pub event_start_date: Option<DateTime<Utc>>,
+    pub event_end_date: Option<DateTime<Utc>>,
+    pub location_name: Option<String>,
+    pub organizer_name: Option<String>,
+    pub checked_in_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PublicEventResponse {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub location_name: Option<String>,
+    pub address: Option<String>,
+    pub image_url: Option<String>,
+    pub agenda: Vec<AgendaItemResponse>,
+    pub speakers: Vec<SpeakerResponse>,
+    pub allow_guests: bool,
+    pub max_guests_per_person: Option<i32>,
+    pub collect_dietary_info: bool,
+    pub collect_accessibility_info: bool,
+    pub registration_open: bool,
+    pub spots_left: Option<i32>,
+    pub waitlist_available: bool,
+    #[serde(default)]
+    pub attachments: Vec<AttachmentResponse>,
+    #[serde(default)]
+    pub photos: Vec<PhotoResponse>,
+    #[serde(default)]
+    pub sponsors: Vec<SponsorResponse>,
+    #[serde(default)]
+    pub exhibitors: Vec<PublicExhibitorResponse>,
+    #[serde(default)]
+    pub travel: TravelInfoResponse,
+    #[serde(default)]
+    pub structured_data: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PublicExhibitorResponse {
+    pub id: Uuid,
+    pub company_name: String,
+    pub website_url: Option<String>,
+    pub description: Option<String>,
+    pub booth_number: Option<String>,
+    pub hall: Option<String>,
+}
+
+// Same body as the signed-in registration endpoint; name and email are required here
+#[derive(Debug, Serialize)]
+pub struct GuestRegistrationRequest {
+    pub registrant_name: String,
+    pub registrant_email: String,
+    pub registrant_phone: Option<String>,
+    pub registrant_company: Option<String>,
+    pub guest_count: Option<i32>,
+    pub dietary_restrictions: Option<String>,
+    pub accessibility_needs: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClientErrorReportRequest {
+    pub reference: String,
+    pub message: String,
+    pub route: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebVitalMeasurementRequest {
+    pub metric: aqio_core::WebVitalMetric,
+    pub value: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebVitalsReportRequest<'a> {
+    pub release: &'a str,
+    pub route: &'a str,
+    pub measurements: Vec<WebVitalMeasurementRequest>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClientLogEntryRequest<'a> {
+    pub level: &'a str,
+    pub target: &'a str,
+    pub message: &'a str,
+    pub request_id: Option<&'a str>,
+    pub route: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClientLogBatchRequest<'a> {
+    pub release: &'a str,
+    pub entries: Vec<ClientLogEntryRequest<'a>>,
+}
+
+// `GET /api/v1/admin/web-vitals`
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct WebVitalSummaryResponse {
+    pub release: String,
+    pub metric: aqio_core::WebVitalMetric,
+    pub samples: u64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p95: f64,
+    pub first_seen: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct BrandingResponse {
+    pub name: String,
+    pub logo_url: Option<String>,
+    pub primary_color: Option<String>,
+}
+
+// `GET /api/v1/config`; auth mode, locales and feature flags aren't used yet
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ClientConfigResponse {
+    pub branding: BrandingResponse,
+    pub max_upload_bytes: u64,
+    /// `error`, `warn`, `info` or `debug`
+    #[serde(default)]
+    pub remote_log_level: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct GuestRegistrationResponse {
+    pub registration_id: Uuid,
+    pub status: String,
+    pub waitlist_position: Option<i32>,
+}
+
+/// Separates "couldn't reach the API" from "the API refused" for callers that retry later
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestError {
+    Network(String),
+    Api(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiEnvelope<T> {
+    data: T,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub pagination: PaginationInfo,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaginationInfo {
+    pub page: u32,
+    pub total_count: i64,
+    pub total_pages: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiErrorBody {
+    error: ApiErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiErrorDetail {
+    message: String,
+}
+
+impl ApiClient {
+    pub fn new() -> Self {
+        Self {
+            client: Client::new(),
+            base_url: API_BASE_URL.to_string(),
+            auth_token: None,
+            retry: RetryPolicy::NONE,
+        }
+    }
+
+    pub fn with_auth_token(mut self, token: String) -> Self {
+        self.auth_token = Some(token);
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub async fn health_check(&self) -> Result<String, String> {
+        let response = self
+            .client
+            .get(&format!("{}/health", self.base_url))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let text = response.text().await.map_err(|e| e.to_string())?;
+        Ok(text)
+    }
+
+    pub async fn list_events(&self) -> Result<Vec<EventSummaryResponse>, String> {
+        // List and calendar views only need the summary fields
+        self.list_event_summaries("view=summary").await
+    }
+
+    // Proximity search for the map; enough results to cover a busy region in one go
+    pub async fn list_events_near(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        radius_km: f64,
+    ) -> Result<Vec<EventSummaryResponse>, String> {
+        self.list_event_summaries(&format!(
+            "view=summary&limit=500&latitude={}&longitude={}&radius_km={}",
+            latitude, longitude, radius_km
+        ))
+        .await
+    }
+
+    // One slice of the agenda; ranges are kept short enough to fit in a single page
+    pub async fn list_events_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<EventSummaryResponse>, String> {
+        self.list_event_summaries(&format!(
+            "view=summary&limit=1000&start_date_from={}&start_date_to={}",
+            from.to_rfc3339_opts(SecondsFormat::Secs, true),
+            to.to_rfc3339_opts(SecondsFormat::Secs, true)
+        ))
+        .await
+    }
+
+    pub async fn search_events(&self, title: &str, limit: u32) -> Result<Vec<EventSummaryResponse>, String> {
+        let title = String::from(js_sys::encode_uri_component(title));
+        self.list_event_summaries(&format!("view=summary&limit={}&title_contains={}", limit, title))
+            .await
+    }
+
+    async fn list_event_summaries(&self, query: &str) -> Result<Vec<EventSummaryResponse>, String> {
+        let url = format!("{}/api/v1/events?{}", self.base_url, query);
+        let response = self.send_with_retry(|| self.client.get(&url)).await?;
+
+        if !response.status().is_success() {
+            return Err(format!("API Error: {}", response.status()));
+        }
+
+        let envelope: ApiEnvelope<Paginated<EventSummaryResponse>> =
+            response.json().await.map_err(|e| e.to_string())?;
+        Ok(envelope.data.items)
+    }
+
+    // The full event response carries every summary field
+    pub async fn get_event(&self, id: Uuid) -> Result<EventSummaryResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}", id)).await
+    }
+
+    pub async fn get_event_program(&self, id: Uuid) -> Result<EventProgramResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}", id)).await
+    }
+
+    // Event management; the full event response carries every summary field
+
+    pub async fn create_event(&self, request: &CreateEventRequest) -> Result<EventSummaryResponse, String> {
+        let response = self
+            .request(Method::POST, "/api/v1/events")
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn update_event(&self, id: Uuid, request: &CreateEventRequest) -> Result<EventSummaryResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}", id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn check_schedule(&self, request: &ScheduleCheckRequest) -> Result<ScheduleCheckResponse, String> {
+        let response = self
+            .request(Method::POST, "/api/v1/events/schedule-check")
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn delete_event(&self, id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}", id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // Admin endpoints
+
+    pub async fn current_user(&self) -> Result<UserResponse, String> {
+        self.get_json("/api/v1/users/me").await
+    }
+
+    pub async fn my_permissions(&self) -> Result<PermissionsResponse, String> {
+        self.get_json("/api/v1/users/me/permissions").await
+    }
+
+    pub async fn list_users(&self, page: u32) -> Result<Paginated<UserResponse>, String> {
+        self.get_json(&format!("/api/v1/users?page={}&limit=25", page)).await
+    }
+
+    pub async fn update_user(&self, id: Uuid, request: &UpdateUserRequest) -> Result<UserResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/users/{}", id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Settings endpoints; users change their own account
+
+    pub async fn update_profile(&self, id: Uuid, request: &UpdateProfileRequest<'_>) -> Result<UserResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/users/{}", id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn notification_preferences(&self) -> Result<NotificationPreferencesResponse, String> {
+        self.get_json("/api/v1/users/me/notification-preferences").await
+    }
+
+    pub async fn update_notification_preferences(
+        &self,
+        request: &NotificationPreferencesRequest,
+    ) -> Result<NotificationPreferencesResponse, String> {
+        let response = self
+            .request(Method::PUT, "/api/v1/users/me/notification-preferences")
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn list_active_categories(&self) -> Result<Vec<CategoryResponse>, String> {
+        self.get_json("/api/v1/categories").await
+    }
+
+    pub async fn list_all_categories(&self) -> Result<Vec<CategoryResponse>, String> {
+        self.get_json("/api/v1/categories/all").await
+    }
+
+    pub async fn create_category(&self, category: &CategoryResponse) -> Result<CategoryResponse, String> {
+        let response = self
+            .request(Method::POST, "/api/v1/categories")
+            .json(category)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn update_category(&self, id: &str, request: &UpdateCategoryRequest) -> Result<CategoryResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/categories/{}", id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn delete_category(&self, id: &str) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/categories/{}", id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn list_audit_log(
+        &self,
+        page: u32,
+        table_name: Option<&str>,
+    ) -> Result<Paginated<AuditLogEntryResponse>, String> {
+        let mut path = format!("/api/v1/admin/audit-log?page={}&limit=50", page);
+        if let Some(table_name) = table_name {
+            path.push_str(&format!("&table_name={}", table_name));
+        }
+        self.get_json(&path).await
+    }
+
+    pub async fn list_organization_invitations(&self) -> Result<Vec<OrganizationInvitationResponse>, String> {
+        self.get_json("/api/v1/admin/organization-invitations").await
+    }
+
+    pub async fn list_web_vitals(&self, days: u32) -> Result<Vec<WebVitalSummaryResponse>, String> {
+        self.get_json(&format!("/api/v1/admin/web-vitals?days={}", days)).await
+    }
+
+    pub async fn revoke_organization_invitation(&self, id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/admin/organization-invitations/{}", id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn lookup_company_registry(&self, org_number: &str) -> Result<CompanyRegistryResponse, String> {
+        self.get_json(&format!("/api/v1/admin/company-registry/{}", org_number)).await
+    }
+
+    pub async fn create_company(&self, request: &CreateCompanyRequest) -> Result<CreatedCompanyResponse, String> {
+        let response = self
+            .request(Method::POST, "/api/v1/admin/companies")
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Company directory endpoints
+
+    pub async fn search_users(&self, query: &str, limit: u32) -> Result<Vec<UserSearchResultResponse>, String> {
+        let query = String::from(js_sys::encode_uri_component(query));
+        self.get_json(&format!("/api/v1/users/search?q={}&limit={}", query, limit)).await
+    }
+
+    pub async fn list_companies(
+        &self,
+        page: u32,
+        industry_type: Option<&str>,
+    ) -> Result<Paginated<CompanyResponse>, String> {
+        let mut path = format!("/api/v1/companies?page={}&limit=24", page);
+        if let Some(industry_type) = industry_type {
+            path.push_str(&format!("&industry_type={}", industry_type));
+        }
+        self.get_json(&path).await
+    }
+
+    pub async fn list_company_events(&self, id: Uuid) -> Result<Vec<EventSummaryResponse>, String> {
+        self.get_json(&format!("/api/v1/companies/{}/events", id)).await
+    }
+
+    pub async fn get_organization_invitation(&self, token: &str) -> Result<OrganizationInvitationResponse, String> {
+        self.get_json(&format!("/api/v1/public/organization-invitations/{}", token)).await
+    }
+
+    pub async fn accept_organization_invitation(&self, token: &str) -> Result<(), String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/organization-invitations/{}/accept", token))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // Discussion endpoints
+
+    pub async fn list_event_comments(&self, event_id: Uuid) -> Result<Vec<CommentResponse>, String> {
+        self.get_json(&format!("/api/v1/events/{}/comments", event_id)).await
+    }
+
+    pub async fn create_comment(
+        &self,
+        event_id: Uuid,
+        request: &CreateCommentRequest<'_>,
+    ) -> Result<CommentResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/comments", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn delete_comment(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/comments/{}", event_id, id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // Live Q&A and polls
+
+    pub async fn get_live_session(&self, event_id: Uuid) -> Result<LiveSessionResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}/live", event_id)).await
+    }
+
+    pub async fn ask_question(&self, event_id: Uuid, content: &str) -> Result<QuestionResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/questions", event_id))
+            .json(&AskQuestionRequest { content })
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn set_question_upvote(
+        &self,
+        event_id: Uuid,
+        question_id: Uuid,
+        upvoted: bool,
+    ) -> Result<QuestionResponse, String> {
+        let method = if upvoted { Method::PUT } else { Method::DELETE };
+        let response = self
+            .request(method, &format!("/api/v1/events/{}/questions/{}/upvote", event_id, question_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn answer_question(
+        &self,
+        event_id: Uuid,
+        question_id: Uuid,
+        answer: &str,
+    ) -> Result<QuestionResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/questions/{}/answer", event_id, question_id))
+            .json(&AnswerQuestionRequest { answer })
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn create_poll(&self, event_id: Uuid, request: &CreatePollRequest<'_>) -> Result<PollResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/polls", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn vote_in_poll(&self, event_id: Uuid, poll_id: Uuid, option_id: Uuid) -> Result<PollResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/polls/{}/vote", event_id, poll_id))
+            .json(&PollVoteRequest { option_id })
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn close_poll(&self, event_id: Uuid, poll_id: Uuid) -> Result<PollResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/polls/{}/close", event_id, poll_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Event attachments
+
+    pub async fn list_event_attachments(&self, event_id: Uuid) -> Result<EventAttachmentsResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}/attachments", event_id)).await
+    }
+
+    pub async fn upload_attachment(
+        &self,
+        event_id: Uuid,
+        request: UploadAttachmentRequest,
+    ) -> Result<AttachmentResponse, String> {
+        let file = Part::bytes(request.bytes)
+            .file_name(request.filename)
+            .mime_str(&request.mime_type)
+            .map_err(|e| e.to_string())?;
+        let mut form = Form::new()
+            .part("file", file)
+            .text("visibility", request.visibility);
+        if let Some(title) = request.title {
+            form = form.text("title", title);
+        }
+        if let Some(description) = request.description {
+            form = form.text("description", description);
+        }
+
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/attachments", event_id))
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Fetched with the bearer token rather than linked, since most files are attendees-only
+    pub async fn download_attachment(&self, event_id: Uuid, id: Uuid) -> Result<Vec<u8>, String> {
+        let response = self
+            .request(Method::GET, &format!("/api/v1/events/{}/attachments/{}/download", event_id, id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let bytes = Self::check_status(response).await?.bytes().await.map_err(|e| e.to_string())?;
+        Ok(bytes.to_vec())
+    }
+
+    pub async fn delete_attachment(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/attachments/{}", event_id, id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // Event photos
+
+    pub async fn get_photo_gallery(&self, event_id: Uuid) -> Result<PhotoGalleryResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}/photos", event_id)).await
+    }
+
+    pub async fn upload_photo(&self, event_id: Uuid, request: UploadPhotoRequest) -> Result<PhotoResponse, String> {
+        let file = Part::bytes(request.bytes)
+            .file_name(request.filename)
+            .mime_str(&request.mime_type)
+            .map_err(|e| e.to_string())?;
+        let mut form = Form::new().part("file", file);
+        if let Some(caption) = request.caption {
+            form = form.text("caption", caption);
+        }
+
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/photos", event_id))
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn update_photo_album(&self, event_id: Uuid, request: &PhotoAlbumRequest) -> Result<(), String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/photos/album", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn moderate_photo(
+        &self,
+        event_id: Uuid,
+        id: Uuid,
+        request: &ModeratePhotoRequest,
+    ) -> Result<PhotoResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/photos/{}/status", event_id, id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Fetched with the bearer token, since photos waiting for approval are only shown to some
+    pub async fn download_photo(&self, event_id: Uuid, id: Uuid) -> Result<Vec<u8>, String> {
+        let response = self
+            .request(Method::GET, &format!("/api/v1/events/{}/photos/{}", event_id, id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let bytes = Self::check_status(response).await?.bytes().await.map_err(|e| e.to_string())?;
+        Ok(bytes.to_vec())
+    }
+
+    pub async fn download_photo_album(&self, event_id: Uuid) -> Result<Vec<u8>, String> {
+        let response = self
+            .request(Method::GET, &format!("/api/v1/events/{}/photos/download", event_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let bytes = Self::check_status(response).await?.bytes().await.map_err(|e| e.to_string())?;
+        Ok(bytes.to_vec())
+    }
+
+    pub async fn delete_photo(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/photos/{}", event_id, id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // Custom event fields of the organizer's organization
+
+    pub async fn get_event_fields(&self, event_id: Uuid) -> Result<EventFieldsResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}/fields", event_id)).await
+    }
+
+    pub async fn update_event_fields(
+        &self,
+        event_id: Uuid,
+        request: &EventFieldValuesRequest,
+    ) -> Result<EventFieldsResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/fields", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Event budgets, organizers only
+
+    pub async fn get_event_budget(&self, event_id: Uuid) -> Result<EventBudgetResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}/budget", event_id)).await
+    }
+
+    pub async fn create_budget_line(
+        &self,
+        event_id: Uuid,
+        request: &BudgetLineRequest<'_>,
+    ) -> Result<BudgetLineResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/budget/lines", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn update_budget_line(
+        &self,
+        event_id: Uuid,
+        id: Uuid,
+        request: &BudgetLineRequest<'_>,
+    ) -> Result<BudgetLineResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/budget/lines/{}", event_id, id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn delete_budget_line(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/budget/lines/{}", event_id, id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn create_expense(&self, event_id: Uuid, request: &ExpenseRequest<'_>) -> Result<ExpenseResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/budget/expenses", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn update_expense(
+        &self,
+        event_id: Uuid,
+        id: Uuid,
+        request: &ExpenseRequest<'_>,
+    ) -> Result<ExpenseResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/budget/expenses/{}", event_id, id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn delete_expense(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/budget/expenses/{}", event_id, id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn upload_receipt(
+        &self,
+        event_id: Uuid,
+        expense_id: Uuid,
+        filename: String,
+        mime_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<ExpenseResponse, String> {
+        let file = Part::bytes(bytes)
+            .file_name(filename)
+            .mime_str(mime_type)
+            .map_err(|e| e.to_string())?;
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/budget/expenses/{}/receipt", event_id, expense_id))
+            .multipart(Form::new().part("file", file))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn download_receipt(&self, event_id: Uuid, expense_id: Uuid) -> Result<Vec<u8>, String> {
+        let response = self
+            .request(Method::GET, &format!("/api/v1/events/{}/budget/expenses/{}/receipt", event_id, expense_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let bytes = Self::check_status(response).await?.bytes().await.map_err(|e| e.to_string())?;
+        Ok(bytes.to_vec())
+    }
+
+    pub async fn delete_receipt(&self, event_id: Uuid, expense_id: Uuid) -> Result<ExpenseResponse, String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/budget/expenses/{}/receipt", event_id, expense_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Event sponsors
+
+    pub async fn list_sponsors(&self, event_id: Uuid) -> Result<Vec<SponsorResponse>, String> {
+        self.get_json(&format!("/api/v1/events/{}/sponsors", event_id)).await
+    }
+
+    pub async fn create_sponsor(&self, event_id: Uuid, request: &SponsorRequest<'_>) -> Result<SponsorResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/sponsors", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn update_sponsor(
+        &self,
+        event_id: Uuid,
+        id: Uuid,
+        request: &SponsorRequest<'_>,
+    ) -> Result<SponsorResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/sponsors/{}", event_id, id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn delete_sponsor(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/sponsors/{}", event_id, id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn reorder_sponsors(&self, event_id: Uuid, sponsor_ids: Vec<Uuid>) -> Result<Vec<SponsorResponse>, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/sponsors/order", event_id))
+            .json(&ReorderSponsorsRequest { sponsor_ids })
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Accommodation and travel information
+
+    pub async fn get_travel(&self, event_id: Uuid) -> Result<TravelInfoResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}/travel", event_id)).await
+    }
+
+    pub async fn update_travel(&self, event_id: Uuid, travel: &TravelInfoResponse) -> Result<TravelInfoResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/travel", event_id))
+            .json(travel)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Catering report
+
+    pub async fn get_catering_report(&self, event_id: Uuid) -> Result<CateringReportResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}/catering-report", event_id)).await
+    }
+
+    pub async fn download_catering_report(&self, event_id: Uuid) -> Result<String, String> {
+        let response = self
+            .request(Method::GET, &format!("/api/v1/events/{}/catering-report.csv", event_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await?.text().await.map_err(|e| e.to_string())
+    }
+
+    // Seating planner
+
+    pub async fn get_seating_plan(&self, event_id: Uuid) -> Result<SeatingPlanResponse, String> {
+        self.get_json(&format!("/api/v1/events/{}/seating", event_id)).await
+    }
+
+    pub async fn create_seating_table(
+        &self,
+        event_id: Uuid,
+        request: &SeatingTableRequest<'_>,
+    ) -> Result<SeatingTableResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/seating/tables", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn update_seating_table(
+        &self,
+        event_id: Uuid,
+        id: Uuid,
+        request: &SeatingTableRequest<'_>,
+    ) -> Result<SeatingTableResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/seating/tables/{}", event_id, id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn delete_seating_table(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/seating/tables/{}", event_id, id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn assign_seat(
+        &self,
+        event_id: Uuid,
+        registration_id: Uuid,
+        table_id: Option<Uuid>,
+    ) -> Result<SeatingPlanResponse, String> {
+        let response = self
+            .request(
+                Method::PUT,
+                &format!("/api/v1/events/{}/seating/assignments/{}", event_id, registration_id),
+            )
+            .json(&SeatAssignmentRequest { table_id })
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn download_seating_chart(&self, event_id: Uuid) -> Result<Vec<u8>, String> {
+        let response = self
+            .request(Method::GET, &format!("/api/v1/events/{}/seating/chart.pdf", event_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let bytes = Self::check_status(response).await?.bytes().await.map_err(|e| e.to_string())?;
+        Ok(bytes.to_vec())
+    }
+
+    // Past events archive
+
+    pub async fn list_past_events(
+        &self,
+        page: u32,
+        title: &str,
+        category_id: Option<&str>,
+        year: Option<i32>,
+    ) -> Result<Paginated<PastEventResponse>, String> {
+        let mut path = format!("/api/v1/events/past?page={}&limit=25", page);
+        if !title.is_empty() {
+            path.push_str(&format!("&title_contains={}", String::from(js_sys::encode_uri_component(title))));
+        }
+        if let Some(category_id) = category_id {
+            path.push_str(&format!("&category_id={}", String::from(js_sys::encode_uri_component(category_id))));
+        }
+        if let Some(year) = year {
+            path.push_str(&format!("&year={}", year));
         }
+        self.get_json(&path).await
     }
 
-    pub fn with_auth_token(mut self, token: String) -> Self {
-        self.auth_token = Some(token);
-        self
+    pub async fn get_attendance_trends(
+        &self,
+        group_by: &str,
+        category_id: Option<&str>,
+        from_year: i32,
+        to_year: i32,
+    ) -> Result<Vec<AttendanceTrendResponse>, String> {
+        let mut path = format!(
+            "/api/v1/events/past/trends?group_by={}&from_year={}&to_year={}",
+            group_by, from_year, to_year
+        );
+        if let Some(category_id) = category_id {
+            path.push_str(&format!("&category_id={}", String::from(js_sys::encode_uri_component(category_id))));
+        }
+        self.get_json(&path).await
     }
 
-    pub async fn health_check(&self) -> Result<String, String> {
+    // Messages between organizers and attendees
+
+    pub async fn list_message_threads(&self) -> Result<Vec<MessageThreadResponse>, String> {
+        self.get_json("/api/v1/messages").await
+    }
+
+    pub async fn count_unread_messages(&self) -> Result<UnreadMessagesResponse, String> {
+        self.get_json("/api/v1/messages/unread").await
+    }
+
+    pub async fn get_message_thread(&self, thread_id: Uuid) -> Result<MessageThreadDetailsResponse, String> {
+        self.get_json(&format!("/api/v1/messages/{}", thread_id)).await
+    }
+
+    pub async fn reply_to_message_thread(
+        &self,
+        thread_id: Uuid,
+        request: &SendMessageRequest<'_>,
+    ) -> Result<MessageResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/messages/{}", thread_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn contact_organizers(&self, event_id: Uuid, request: &SendMessageRequest<'_>) -> Result<MessageResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/messages", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Networking directory and meetings between attendees
+
+    // Not found until the user joins the event's matchmaking
+    pub async fn get_matchmaking_profile(&self, event_id: Uuid) -> Result<Option<MatchmakingProfileResponse>, String> {
+        let response = self
+            .request(Method::GET, &format!("/api/v1/events/{}/matchmaking/profile", event_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Self::read_data(response).await.map(Some)
+    }
+
+    pub async fn save_matchmaking_profile(
+        &self,
+        event_id: Uuid,
+        request: &MatchmakingProfileRequest<'_>,
+    ) -> Result<MatchmakingProfileResponse, String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/matchmaking/profile", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn leave_matchmaking(&self, event_id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/matchmaking/profile", event_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn list_matches(&self, event_id: Uuid) -> Result<Vec<MatchResponse>, String> {
+        self.get_json(&format!("/api/v1/events/{}/matchmaking/matches", event_id)).await
+    }
+
+    pub async fn list_meetings(&self, event_id: Uuid) -> Result<Vec<MeetingResponse>, String> {
+        self.get_json(&format!("/api/v1/events/{}/meetings", event_id)).await
+    }
+
+    pub async fn request_meeting(&self, event_id: Uuid, request: &RequestMeetingRequest<'_>) -> Result<(), String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/meetings", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // `action` is `accept`, `decline` or `cancel`
+    pub async fn answer_meeting(&self, event_id: Uuid, meeting_id: Uuid, action: &str) -> Result<(), String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/events/{}/meetings/{}/{}", event_id, meeting_id, action))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn download_meeting_invite(&self, event_id: Uuid, meeting_id: Uuid) -> Result<String, String> {
+        let response = self
+            .request(Method::GET, &format!("/api/v1/events/{}/meetings/{}/invite.ics", event_id, meeting_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await?.text().await.map_err(|e| e.to_string())
+    }
+
+    // Certificates of attendance
+
+    pub async fn download_certificate(&self, registration_id: Uuid) -> Result<Vec<u8>, String> {
+        let response = self
+            .request(Method::GET, &format!("/api/v1/registrations/{}/certificate", registration_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        let bytes = Self::check_status(response).await?.bytes().await.map_err(|e| e.to_string())?;
+        Ok(bytes.to_vec())
+    }
+
+    // The signature is sent as the raw PNG body
+    pub async fn set_certificate_signature(&self, event_id: Uuid, png: Vec<u8>) -> Result<(), String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/events/{}/certificate/signature", event_id))
+            .header("Content-Type", "image/png")
+            .body(png)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    pub async fn remove_certificate_signature(&self, event_id: Uuid) -> Result<(), String> {
+        let response = self
+            .request(Method::DELETE, &format!("/api/v1/events/{}/certificate/signature", event_id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // Invitation endpoints
+
+    pub async fn list_event_invitations(&self, event_id: Uuid) -> Result<Vec<InvitationResponse>, String> {
+        self.get_json(&format!("/api/v1/invitations/event/{}", event_id)).await
+    }
+
+    pub async fn create_invitation(
+        &self,
+        event_id: Uuid,
+        request: &CreateInvitationRequest,
+    ) -> Result<InvitationResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/invitations/event/{}", event_id))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    pub async fn update_invitation_status(&self, id: Uuid, status: &str) -> Result<(), String> {
+        let response = self
+            .request(Method::PUT, &format!("/api/v1/invitations/{}/status", id))
+            .json(&UpdateInvitationStatusRequest { status })
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // Check-in endpoints
+
+    pub async fn list_event_registrations(&self, event_id: Uuid) -> Result<Vec<RegistrationResponse>, RequestError> {
+        let response = Self::send(self.request(
+            Method::GET,
+            &format!("/api/v1/registrations/event/{}/list", event_id),
+        ))
+        .await?;
+        let envelope: ApiEnvelope<Vec<RegistrationResponse>> =
+            response.json().await.map_err(|e| RequestError::Api(e.to_string()))?;
+        Ok(envelope.data)
+    }
+
+    pub async fn check_in_registration(&self, id: Uuid) -> Result<(), RequestError> {
+        Self::send(self.request(Method::POST, &format!("/api/v1/registrations/{}/checkin", id)))
+            .await
+            .map(|_| ())
+    }
+
+    // Attendee registration endpoints
+
+    pub async fn my_registrations(&self) -> Result<Vec<RegistrationResponse>, String> {
+        self.get_json("/api/v1/registrations/me").await
+    }
+
+    pub async fn waitlist_status(&self, id: Uuid) -> Result<WaitlistStatusResponse, String> {
+        self.get_json(&format!("/api/v1/registrations/{}/waitlist", id)).await
+    }
+
+    pub async fn confirm_promotion(&self, id: Uuid) -> Result<RegistrationResponse, String> {
+        let response = self
+            .request(Method::POST, &format!("/api/v1/registrations/{}/confirm", id))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // EventSource can't send headers, so the token goes in the query string
+    pub fn registration_stream_url(&self) -> String {
+        self.stream_url("/api/v1/registrations/me/stream")
+    }
+
+    pub fn live_stream_url(&self, event_id: Uuid) -> String {
+        self.stream_url(&format!("/api/v1/events/{}/live/stream", event_id))
+    }
+
+    fn stream_url(&self, path: &str) -> String {
+        let url = format!("{}{}", self.base_url, path);
+        match &self.auth_token {
+            Some(token) => format!("{}?access_token={}", url, token),
+            None => url,
+        }
+    }
+
+    // Public event page; no credentials are sent
+
+    pub async fn get_public_event(&self, slug: &str) -> Result<PublicEventResponse, String> {
         let response = self
             .client
-            .get(&format!("{}/health", self.base_url))
+            .get(format!("{}/api/v1/public/events/{}", self.base_url, slug))
             .send()
             .await
             .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
 
-        let text = response.text().await.map_err(|e| e.to_string())?;
-        Ok(text)
+    // Public files need no credentials, so a plain link does
+    pub fn public_attachment_url(&self, slug: &str, id: Uuid) -> String {
+        format!("{}/api/v1/public/events/{}/attachments/{}", self.base_url, slug, id)
+    }
+
+    pub fn public_photo_url(&self, slug: &str, id: Uuid) -> String {
+        format!("{}/api/v1/public/events/{}/photos/{}", self.base_url, slug, id)
+    }
+
+    pub fn public_calendar_url(&self, slug: &str) -> String {
+        format!("{}/api/v1/public/events/{}/calendar.ics", self.base_url, slug)
+    }
+
+    pub fn public_short_link_url(&self, code: &str) -> String {
+        format!("{}/api/v1/public/links/{}", self.base_url, code)
     }
 
-    pub async fn list_events(&self) -> Result<Vec<EventResponse>, String> {
+    pub async fn verify_certificate(&self, registration_id: Uuid) -> Result<CertificateVerificationResponse, String> {
         let response = self
             .client
-            .get(&format!("{}/events", self.base_url))
+            .get(format!("{}/api/v1/public/certificates/{}", self.base_url, registration_id))
             .send()
             .await
             .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
 
-        if !response.status().is_success() {
-            return Err(format!("API Error: {}", response.status()));
+    pub async fn register_guest(
+        &self,
+        slug: &str,
+        request: &GuestRegistrationRequest,
+    ) -> Result<GuestRegistrationResponse, String> {
+        let response = self
+            .client
+            .post(format!("{}/api/v1/public/events/{}/registrations", self.base_url, slug))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Startup configuration; public, since it's needed before signing in
+
+    pub async fn client_config(&self) -> Result<ClientConfigResponse, String> {
+        let response = self
+            .client
+            .get(format!("{}/api/v1/config", self.base_url))
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::read_data(response).await
+    }
+
+    // Error reports; accepted without credentials so public pages can report too
+
+    pub async fn report_client_error(&self, request: &ClientErrorReportRequest) -> Result<(), String> {
+        let response = self
+            .client
+            .post(format!("{}/api/v1/client-errors", self.base_url))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // Web vitals; accepted without credentials like error reports
+
+    pub async fn report_web_vitals(&self, request: &WebVitalsReportRequest<'_>) -> Result<(), String> {
+        let response = self
+            .client
+            .post(format!("{}/api/v1/telemetry/web-vitals", self.base_url))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    // Log lines, shipped when the API's configuration asks for them. The status
+    // isn't checked through `check_status`, whose own log line would be shipped
+    // with the next batch.
+
+    pub async fn report_client_logs(&self, request: &ClientLogBatchRequest<'_>) -> Result<(), String> {
+        let response = self
+            .client
+            .post(format!("{}/api/v1/telemetry/logs", self.base_url))
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("API Error: {}", response.status()))
+        }
+    }
+
+    async fn send(builder: RequestBuilder) -> Result<Response, RequestError> {
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| RequestError::Network(e.to_string()))?;
+        Self::check_status(response).await.map_err(RequestError::Api)
+    }
+
+    // The request ID lets a call that never gets an answer still be found in the
+    // API's logs
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let request_id = Uuid::new_v4().simple().to_string();
+        log::debug!(target: "aqio::api", request_id = request_id.as_str(); "{} {}", method, path);
+        let builder = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path))
+            .header(REQUEST_ID_HEADER, request_id);
+        match &self.auth_token {
+            Some(token) => builder.bearer_auth(token),
+            None => builder,
+        }
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
+        let response = self.send_with_retry(|| self.request(Method::GET, path)).await?;
+        Self::read_data(response).await
+    }
+
+    // Sends the request built by `build` again, after a pause, while the API
+    // can't be reached or is briefly unavailable; only for requests that are
+    // safe to repeat
+    async fn send_with_retry(&self, build: impl Fn() -> RequestBuilder) -> Result<Response, String> {
+        let mut attempt = 0;
+        loop {
+            let result = build().send().await;
+            let transient = match &result {
+                Ok(response) => matches!(
+                    response.status(),
+                    StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+                ),
+                Err(_) => true,
+            };
+            if !transient || attempt >= self.retry.retries {
+                return result.map_err(|e| e.to_string());
+            }
+            let pause = self.retry.backoff_ms.saturating_mul(1 << attempt.min(16));
+            match &result {
+                Ok(response) => log::info!(target: "aqio::api", "{} answered {}, retrying in {} ms", response.url().path(), response.status().as_u16(), pause),
+                Err(e) => log::info!(target: "aqio::api", "Request failed ({}), retrying in {} ms", e, pause),
+            }
+            TimeoutFuture::new(pause).await;
+            attempt += 1;
         }
+    }
 
-        let events: Vec<EventResponse> = response.json().await.map_err(|e| e.to_string())?;
-        Ok(events)
+    // Unwrap the `{ "success": true, "data": ... }` envelope
+    async fn read_data<T: DeserializeOwned>(response: Response) -> Result<T, String> {
+        let response = Self::check_status(response).await?;
+        let envelope: ApiEnvelope<T> = response.json().await.map_err(|e| e.to_string())?;
+        Ok(envelope.data)
     }
 
-    // Additional endpoints can be added as needed
+    // Surface the API's error message rather than just the status code. The
+    // request ID the API echoes is logged, so the call can be found in its logs.
+    async fn check_status(response: Response) -> Result<Response, String> {
+        let status = response.status();
+        let path = response.url().path().to_string();
+        let request_id = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        if status.is_success() {
+            log::debug!(target: "aqio::api", request_id = request_id.as_deref(); "{} answered {}", path, status.as_u16());
+            return Ok(response);
+        }
+
+        let message = match response.json::<ApiErrorBody>().await {
+            Ok(body) => body.error.message,
+            Err(_) => format!("API Error: {}", status),
+        };
+        if status.is_server_error() {
+            log::error!(target: "aqio::api", request_id = request_id.as_deref(); "{} answered {}: {}", path, status.as_u16(), message);
+        } else {
+            log::info!(target: "aqio::api", request_id = request_id.as_deref(); "{} answered {}: {}", path, status.as_u16(), message);
+        }
+        Err(message)
+    }
 }
 
 impl Default for ApiClient {
@@ -70,3 +2345,75 @@ impl Default for ApiClient {
         Self::new()
     }
 }
+
+// The API's responses, as recorded in aqio-contract, still read into these
+// models. Fields with serde defaults are checked by value, since a renamed one
+// would silently fall back to its default.
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use crate::application::ports::{DeliveryFailure, InvitationStatus, Role};
+    use aqio_contract::{assert_consumes, fixture};
+
+    const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+    fn variants(name: &str) -> Vec<String> {
+        serde_json::from_value(fixture(VERSION, "enums")[name].clone()).expect("enum variants are strings")
+    }
+
+    #[test]
+    fn test_reads_api_users() {
+        let user: UserResponse = assert_consumes(VERSION, "user");
+        assert_eq!(user.name, "Admin User");
+        assert_eq!(Role::parse(&user.role), Some(Role::Organizer));
+        assert_eq!(user.timezone.as_deref(), Some("Europe/Oslo"));
+        assert_eq!(user.locale, Some(aqio_core::Locale::Nb));
+        assert_eq!(user.theme, Some(aqio_core::Theme::Dark));
+        assert_eq!(user.calendar_view, Some(aqio_core::CalendarView::Week));
+    }
+
+    #[test]
+    fn test_reads_api_categories_events_and_comments() {
+        let category: CategoryResponse = assert_consumes(VERSION, "category");
+        assert_eq!(category.color_hex.as_deref(), Some("#3B82F6"));
+
+        let event: EventSummaryResponse = assert_consumes(VERSION, "event_summary");
+        assert_eq!(event.location_name.as_deref(), Some("Clarion Hotel Trondheim"));
+        assert!(event.latitude.is_some() && event.longitude.is_some());
+
+        let comment: CommentResponse = assert_consumes(VERSION, "comment");
+        assert!(comment.parent_comment_id.is_some() && comment.can_delete);
+    }
+
+    #[test]
+    fn test_reads_api_invitations_and_registrations() {
+        let invitation: InvitationResponse = assert_consumes(VERSION, "invitation");
+        assert_eq!(InvitationStatus::parse(&invitation.status), Some(InvitationStatus::Sent));
+        assert_eq!(
+            invitation.delivery_failure.as_deref().and_then(DeliveryFailure::parse),
+            Some(DeliveryFailure::Bounce)
+        );
+
+        let registration: RegistrationResponse = assert_consumes(VERSION, "registration");
+        assert_eq!(registration.status, "Waitlisted");
+        assert_eq!(registration.guest_count, 1);
+        assert!(registration.promotion_expires_at.is_some());
+    }
+
+    #[test]
+    fn test_understands_every_api_enum_variant() {
+        for role in variants("UserRole") {
+            assert!(Role::parse(&role).is_some(), "unknown role {}", role);
+        }
+        for status in variants("InvitationStatus") {
+            assert!(InvitationStatus::parse(&status).is_some(), "unknown invitation status {}", status);
+        }
+        for failure in variants("DeliveryFailure") {
+            assert!(DeliveryFailure::parse(&failure).is_some(), "unknown delivery failure {}", failure);
+        }
+        let enums = fixture(VERSION, "enums");
+        serde_json::from_value::<Vec<aqio_core::Locale>>(enums["Locale"].clone()).expect("locales");
+        serde_json::from_value::<Vec<aqio_core::Theme>>(enums["Theme"].clone()).expect("themes");
+        serde_json::from_value::<Vec<aqio_core::CalendarView>>(enums["CalendarView"].clone()).expect("calendar views");
+    }
+}
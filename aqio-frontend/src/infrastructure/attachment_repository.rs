@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{Attachment, AttachmentLibrary, AttachmentRepository, AttachmentUpload};
+
+use super::api_client::{ApiClient, AttachmentResponse, UploadAttachmentRequest};
+
+#[derive(Clone)]
+pub struct ApiAttachmentRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiAttachmentRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+pub(super) fn map_attachment(attachment: AttachmentResponse) -> Attachment {
+    Attachment {
+        id: attachment.id,
+        filename: attachment.filename,
+        title: attachment.title,
+        description: attachment.description,
+        mime_type: attachment.mime_type,
+        file_size: attachment.file_size.max(0) as u64,
+        category: attachment.category.to_lowercase(),
+        // The API serializes enum variants as-is (`Public`, `AttendeesOnly`)
+        is_public: attachment.visibility.eq_ignore_ascii_case("public"),
+        uploader_name: attachment.uploader_name,
+        download_count: attachment.download_count.max(0) as u64,
+        uploaded_at: attachment.uploaded_at,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl AttachmentRepository for ApiAttachmentRepository {
+    async fn list_attachments(&self, event_id: Uuid) -> Result<AttachmentLibrary, String> {
+        let library = self.api.list_event_attachments(event_id).await?;
+        Ok(AttachmentLibrary {
+            can_manage: library.can_manage,
+            attachments: library.attachments.into_iter().map(map_attachment).collect(),
+        })
+    }
+
+    async fn upload(&self, event_id: Uuid, upload: AttachmentUpload) -> Result<Attachment, String> {
+        let request = UploadAttachmentRequest {
+            filename: upload.filename,
+            mime_type: upload.mime_type,
+            bytes: upload.bytes,
+            title: upload.title,
+            description: upload.description,
+            visibility: if upload.is_public { "public" } else { "attendees_only" },
+        };
+        Ok(map_attachment(self.api.upload_attachment(event_id, request).await?))
+    }
+
+    async fn download(&self, event_id: Uuid, id: Uuid) -> Result<Vec<u8>, String> {
+        self.api.download_attachment(event_id, id).await
+    }
+
+    async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.api.delete_attachment(event_id, id).await
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{
+    BudgetLine, BudgetLineDraft, BudgetLineTotals, BudgetRepository, BudgetSummary, EventBudget, Expense, ExpenseDraft,
+    Receipt,
+};
+
+use super::api_client::{
+    ApiClient, BudgetLineRequest, BudgetLineResponse, BudgetSummaryResponse, ExpenseRequest, ExpenseResponse,
+};
+
+#[derive(Clone)]
+pub struct ApiBudgetRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiBudgetRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_line(line: BudgetLineResponse) -> BudgetLine {
+    BudgetLine {
+        id: line.id,
+        name: line.name,
+        planned: line.planned_amount,
+        notes: line.notes,
+    }
+}
+
+fn map_expense(expense: ExpenseResponse) -> Expense {
+    Expense {
+        id: expense.id,
+        budget_line_id: expense.budget_line_id,
+        description: expense.description,
+        vendor: expense.vendor,
+        amount: expense.amount,
+        incurred_on: expense.incurred_on,
+        receipt: expense.receipt.map(|receipt| Receipt {
+            filename: receipt.filename,
+            mime_type: receipt.mime_type,
+            file_size: receipt.file_size.max(0) as u64,
+        }),
+        recorded_by_name: expense.recorded_by_name,
+    }
+}
+
+fn map_summary(summary: BudgetSummaryResponse) -> BudgetSummary {
+    BudgetSummary {
+        planned: summary.planned,
+        spent: summary.spent,
+        unassigned: summary.unassigned,
+        expense_count: summary.expense_count,
+        lines: summary
+            .lines
+            .into_iter()
+            .map(|line| BudgetLineTotals {
+                budget_line_id: line.budget_line_id,
+                name: line.name,
+                planned: line.planned,
+                spent: line.spent,
+            })
+            .collect(),
+    }
+}
+
+fn line_request(line: &BudgetLineDraft) -> BudgetLineRequest<'_> {
+    BudgetLineRequest {
+        name: &line.name,
+        planned_amount: line.planned,
+        notes: line.notes.as_deref(),
+    }
+}
+
+fn expense_request(expense: &ExpenseDraft) -> ExpenseRequest<'_> {
+    ExpenseRequest {
+        budget_line_id: expense.budget_line_id,
+        description: &expense.description,
+        vendor: expense.vendor.as_deref(),
+        amount: expense.amount,
+        incurred_on: expense.incurred_on,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl BudgetRepository for ApiBudgetRepository {
+    async fn budget(&self, event_id: Uuid) -> Result<EventBudget, String> {
+        let budget = self.api.get_event_budget(event_id).await?;
+        Ok(EventBudget {
+            lines: budget.lines.into_iter().map(map_line).collect(),
+            expenses: budget.expenses.into_iter().map(map_expense).collect(),
+            summary: map_summary(budget.summary),
+        })
+    }
+
+    async fn add_line(&self, event_id: Uuid, line: &BudgetLineDraft) -> Result<BudgetLine, String> {
+        Ok(map_line(self.api.create_budget_line(event_id, &line_request(line)).await?))
+    }
+
+    async fn update_line(&self, event_id: Uuid, id: Uuid, line: &BudgetLineDraft) -> Result<BudgetLine, String> {
+        Ok(map_line(self.api.update_budget_line(event_id, id, &line_request(line)).await?))
+    }
+
+    async fn delete_line(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.api.delete_budget_line(event_id, id).await
+    }
+
+    async fn record_expense(&self, event_id: Uuid, expense: &ExpenseDraft) -> Result<Expense, String> {
+        Ok(map_expense(self.api.create_expense(event_id, &expense_request(expense)).await?))
+    }
+
+    async fn update_expense(&self, event_id: Uuid, id: Uuid, expense: &ExpenseDraft) -> Result<Expense, String> {
+        Ok(map_expense(self.api.update_expense(event_id, id, &expense_request(expense)).await?))
+    }
+
+    async fn delete_expense(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.api.delete_expense(event_id, id).await
+    }
+
+    async fn attach_receipt(
+        &self,
+        event_id: Uuid,
+        expense_id: Uuid,
+        filename: String,
+        mime_type: String,
+        bytes: Vec<u8>,
+    ) -> Result<Expense, String> {
+        let expense = self
+            .api
+            .upload_receipt(event_id, expense_id, filename, &mime_type, bytes)
+            .await?;
+        Ok(map_expense(expense))
+    }
+
+    async fn download_receipt(&self, event_id: Uuid, expense_id: Uuid) -> Result<Vec<u8>, String> {
+        self.api.download_receipt(event_id, expense_id).await
+    }
+
+    async fn remove_receipt(&self, event_id: Uuid, expense_id: Uuid) -> Result<Expense, String> {
+        Ok(map_expense(self.api.delete_receipt(event_id, expense_id).await?))
+    }
+}
@@ -0,0 +1,127 @@
+// QR code scanning from the device camera.
+// getUserMedia feeds a <video> element; frames are copied to an offscreen canvas
+// and decoded with `rqrr`, so no browser BarcodeDetector support is required.
+
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    CanvasRenderingContext2d, HtmlCanvasElement, HtmlVideoElement, MediaStream,
+    MediaStreamConstraints, MediaStreamTrack,
+};
+
+fn js_error(context: &str, error: JsValue) -> String {
+    format!("{}: {}", context, error.as_string().unwrap_or_else(|| format!("{:?}", error)))
+}
+
+pub struct QrCamera {
+    stream: MediaStream,
+    video: HtmlVideoElement,
+    canvas: HtmlCanvasElement,
+    context: CanvasRenderingContext2d,
+}
+
+impl QrCamera {
+    /// Opens the rear camera (when there is one) and streams it into the `<video>` with `video_id`
+    pub async fn start(video_id: &str) -> Result<Self, String> {
+        let window = web_sys::window().ok_or("No browser window")?;
+        let document = window.document().ok_or("No document")?;
+
+        let video = document
+            .get_element_by_id(video_id)
+            .ok_or_else(|| format!("Missing video element '{}'", video_id))?
+            .dyn_into::<HtmlVideoElement>()
+            .map_err(|_| format!("Element '{}' is not a video", video_id))?;
+
+        let media_devices = window
+            .navigator()
+            .media_devices()
+            .map_err(|e| js_error("Camera access is not available", e))?;
+
+        let video_constraints = js_sys::Object::new();
+        js_sys::Reflect::set(&video_constraints, &"facingMode".into(), &"environment".into())
+            .map_err(|e| js_error("Invalid camera constraints", e))?;
+        let constraints = MediaStreamConstraints::new();
+        constraints.set_video(&video_constraints);
+        constraints.set_audio(&JsValue::FALSE);
+
+        let promise = media_devices
+            .get_user_media_with_constraints(&constraints)
+            .map_err(|e| js_error("Camera access failed", e))?;
+        let stream = JsFuture::from(promise)
+            .await
+            .map_err(|e| js_error("Camera permission denied", e))?
+            .dyn_into::<MediaStream>()
+            .map_err(|e| js_error("Unexpected camera stream", e))?;
+
+        video.set_src_object(Some(&stream));
+        // Required by iOS Safari to play inline without going fullscreen
+        let _ = video.set_attribute("playsinline", "true");
+        video.set_muted(true);
+        let play = video.play().map_err(|e| js_error("Can't start video", e))?;
+        JsFuture::from(play)
+            .await
+            .map_err(|e| js_error("Can't start video", e))?;
+
+        let canvas = document
+            .create_element("canvas")
+            .map_err(|e| js_error("Can't create canvas", e))?
+            .dyn_into::<HtmlCanvasElement>()
+            .map_err(|_| "Can't create canvas".to_string())?;
+        let context = canvas
+            .get_context("2d")
+            .map_err(|e| js_error("Can't create canvas context", e))?
+            .ok_or("Canvas 2D is not supported")?
+            .dyn_into::<CanvasRenderingContext2d>()
+            .map_err(|_| "Canvas 2D is not supported".to_string())?;
+
+        Ok(Self {
+            stream,
+            video,
+            canvas,
+            context,
+        })
+    }
+
+    /// Decodes the current video frame, returning the first QR payload found
+    pub fn scan(&self) -> Option<String> {
+        let width = self.video.video_width();
+        let height = self.video.video_height();
+        if width == 0 || height == 0 {
+            // No frame yet
+            return None;
+        }
+
+        self.canvas.set_width(width);
+        self.canvas.set_height(height);
+        self.context
+            .draw_image_with_html_video_element(&self.video, 0.0, 0.0)
+            .ok()?;
+        let image = self
+            .context
+            .get_image_data(0.0, 0.0, width as f64, height as f64)
+            .ok()?;
+        let rgba = image.data();
+
+        let width = width as usize;
+        let height = height as usize;
+        let mut prepared = rqrr::PreparedImage::prepare_from_greyscale(width, height, |x, y| {
+            let i = (y * width + x) * 4;
+            // Integer approximation of Rec. 601 luma
+            ((rgba[i] as u32 * 299 + rgba[i + 1] as u32 * 587 + rgba[i + 2] as u32 * 114) / 1000) as u8
+        });
+
+        prepared
+            .detect_grids()
+            .into_iter()
+            .find_map(|grid| grid.decode().ok().map(|(_, content)| content))
+    }
+
+    pub fn stop(&self) {
+        for track in self.stream.get_tracks().iter() {
+            if let Ok(track) = track.dyn_into::<MediaStreamTrack>() {
+                track.stop();
+            }
+        }
+        self.video.set_src_object(None);
+    }
+}
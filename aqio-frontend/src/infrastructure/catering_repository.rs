@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{CateringGroup, CateringReport, CateringRepository};
+
+use super::api_client::{ApiClient, CateringGroupResponse};
+
+#[derive(Clone)]
+pub struct ApiCateringRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiCateringRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_groups(groups: Vec<CateringGroupResponse>) -> Vec<CateringGroup> {
+    groups
+        .into_iter()
+        .map(|group| CateringGroup {
+            label: group.label,
+            registrations: group.registrations,
+            people: group.people,
+            answers: group.answers,
+        })
+        .collect()
+}
+
+#[async_trait::async_trait(?Send)]
+impl CateringRepository for ApiCateringRepository {
+    async fn get_report(&self, event_id: Uuid) -> Result<CateringReport, String> {
+        let report = self.api.get_catering_report(event_id).await?;
+        Ok(CateringReport {
+            confirmed_registrations: report.confirmed_registrations,
+            guests: report.guests,
+            total_people: report.total_people,
+            dietary: map_groups(report.dietary),
+            accessibility: map_groups(report.accessibility),
+        })
+    }
+
+    async fn download_csv(&self, event_id: Uuid) -> Result<String, String> {
+        self.api.download_catering_report(event_id).await
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{CertificateCheck, CertificateRepository};
+
+use super::api_client::ApiClient;
+
+#[derive(Clone)]
+pub struct ApiCertificateRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiCertificateRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl CertificateRepository for ApiCertificateRepository {
+    async fn download(&self, registration_id: Uuid) -> Result<Vec<u8>, String> {
+        self.api.download_certificate(registration_id).await
+    }
+
+    async fn set_signature(&self, event_id: Uuid, png: Vec<u8>) -> Result<(), String> {
+        self.api.set_certificate_signature(event_id, png).await
+    }
+
+    async fn remove_signature(&self, event_id: Uuid) -> Result<(), String> {
+        self.api.remove_certificate_signature(event_id).await
+    }
+
+    async fn verify(&self, registration_id: Uuid) -> Result<CertificateCheck, String> {
+        let check = self.api.verify_certificate(registration_id).await?;
+        Ok(CertificateCheck {
+            registration_id: check.registration_id,
+            valid: check.valid,
+            attendee_name: check.attendee_name,
+            event_title: check.event_title,
+            event_start_date: check.event_start_date,
+            event_end_date: check.event_end_date,
+            location_name: check.location_name,
+            organizer_name: check.organizer_name,
+            checked_in_at: check.checked_in_at,
+        })
+    }
+}
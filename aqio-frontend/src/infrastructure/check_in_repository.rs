@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{Attendee, AttendeeStatus, CheckInError, CheckInRepository};
+
+use super::api_client::{ApiClient, RegistrationResponse, RequestError};
+
+#[derive(Clone)]
+pub struct ApiCheckInRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiCheckInRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_error(error: RequestError) -> CheckInError {
+    match error {
+        RequestError::Network(e) => CheckInError::Unreachable(e),
+        RequestError::Api(e) => CheckInError::Rejected(e),
+    }
+}
+
+fn map_attendee(registration: RegistrationResponse) -> Attendee {
+    // The API serializes enum variants as-is (`Registered`, `NoShow`, ...)
+    let status = match registration.status.to_lowercase().as_str() {
+        "attended" => AttendeeStatus::CheckedIn,
+        "waitlisted" => AttendeeStatus::Waitlisted,
+        "cancelled" => AttendeeStatus::Cancelled,
+        _ => AttendeeStatus::Expected,
+    };
+    Attendee {
+        registration_id: registration.id,
+        name: registration.registrant_name,
+        email: registration.registrant_email,
+        guest_count: registration.guest_count,
+        status,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl CheckInRepository for ApiCheckInRepository {
+    async fn list_attendees(&self, event_id: Uuid) -> Result<Vec<Attendee>, CheckInError> {
+        let registrations = self
+            .api
+            .list_event_registrations(event_id)
+            .await
+            .map_err(map_error)?;
+        Ok(registrations.into_iter().map(map_attendee).collect())
+    }
+
+    async fn check_in(&self, registration_id: Uuid) -> Result<(), CheckInError> {
+        self.api
+            .check_in_registration(registration_id)
+            .await
+            .map_err(map_error)
+    }
+}
@@ -0,0 +1,115 @@
+// Persists the check-in queue and event rosters in LocalStorage so a scanner
+// that loses Wi-Fi (or is reloaded) keeps working.
+
+use chrono::{DateTime, Utc};
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::ports::{Attendee, AttendeeStatus, CheckInStore, QueuedCheckIn};
+
+const QUEUE_KEY: &str = "aqio_checkin_queue";
+const ROSTER_KEY_PREFIX: &str = "aqio_checkin_roster_";
+
+#[derive(Serialize, Deserialize)]
+struct StoredCheckIn {
+    event_id: Uuid,
+    registration_id: Uuid,
+    scanned_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredAttendee {
+    registration_id: Uuid,
+    name: Option<String>,
+    email: Option<String>,
+    guest_count: i32,
+    status: String,
+}
+
+fn status_to_str(status: AttendeeStatus) -> &'static str {
+    match status {
+        AttendeeStatus::Expected => "expected",
+        AttendeeStatus::CheckedIn => "checked_in",
+        AttendeeStatus::Waitlisted => "waitlisted",
+        AttendeeStatus::Cancelled => "cancelled",
+    }
+}
+
+fn status_from_str(value: &str) -> AttendeeStatus {
+    match value {
+        "checked_in" => AttendeeStatus::CheckedIn,
+        "waitlisted" => AttendeeStatus::Waitlisted,
+        "cancelled" => AttendeeStatus::Cancelled,
+        _ => AttendeeStatus::Expected,
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct LocalCheckInStore;
+
+impl CheckInStore for LocalCheckInStore {
+    fn load_queue(&self) -> Vec<QueuedCheckIn> {
+        LocalStorage::get::<Vec<StoredCheckIn>>(QUEUE_KEY)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|stored| QueuedCheckIn {
+                event_id: stored.event_id,
+                registration_id: stored.registration_id,
+                scanned_at: stored.scanned_at,
+            })
+            .collect()
+    }
+
+    fn save_queue(&self, queue: &[QueuedCheckIn]) {
+        if queue.is_empty() {
+            LocalStorage::delete(QUEUE_KEY);
+            return;
+        }
+
+        let stored: Vec<StoredCheckIn> = queue
+            .iter()
+            .map(|queued| StoredCheckIn {
+                event_id: queued.event_id,
+                registration_id: queued.registration_id,
+                scanned_at: queued.scanned_at,
+            })
+            .collect();
+        if let Err(e) = LocalStorage::set(QUEUE_KEY, stored) {
+            log::error!("Failed to persist check-in queue: {}", e);
+        }
+    }
+
+    fn load_roster(&self, event_id: Uuid) -> Option<Vec<Attendee>> {
+        let key = format!("{}{}", ROSTER_KEY_PREFIX, event_id);
+        let stored = LocalStorage::get::<Vec<StoredAttendee>>(key).ok()?;
+        Some(
+            stored
+                .into_iter()
+                .map(|attendee| Attendee {
+                    registration_id: attendee.registration_id,
+                    name: attendee.name,
+                    email: attendee.email,
+                    guest_count: attendee.guest_count,
+                    status: status_from_str(&attendee.status),
+                })
+                .collect(),
+        )
+    }
+
+    fn save_roster(&self, event_id: Uuid, attendees: &[Attendee]) {
+        let stored: Vec<StoredAttendee> = attendees
+            .iter()
+            .map(|attendee| StoredAttendee {
+                registration_id: attendee.registration_id,
+                name: attendee.name.clone(),
+                email: attendee.email.clone(),
+                guest_count: attendee.guest_count,
+                status: status_to_str(attendee.status).to_string(),
+            })
+            .collect();
+        if let Err(e) = LocalStorage::set(format!("{}{}", ROSTER_KEY_PREFIX, event_id), stored) {
+            log::error!("Failed to cache check-in roster: {}", e);
+        }
+    }
+}
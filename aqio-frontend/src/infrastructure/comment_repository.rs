@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{Comment, CommentRepository};
+
+use super::api_client::{ApiClient, CommentResponse, CreateCommentRequest};
+
+#[derive(Clone)]
+pub struct ApiCommentRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiCommentRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_comment(comment: CommentResponse) -> Comment {
+    Comment {
+        id: comment.id,
+        parent_id: comment.parent_comment_id,
+        author_name: comment.author_name,
+        content: comment.content,
+        can_delete: comment.can_delete,
+        created_at: comment.created_at,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl CommentRepository for ApiCommentRepository {
+    async fn list_comments(&self, event_id: Uuid) -> Result<Vec<Comment>, String> {
+        let comments = self.api.list_event_comments(event_id).await?;
+        Ok(comments.into_iter().map(map_comment).collect())
+    }
+
+    async fn post_comment(&self, event_id: Uuid, parent_id: Option<Uuid>, content: &str) -> Result<Comment, String> {
+        let request = CreateCommentRequest { content, parent_comment_id: parent_id };
+        Ok(map_comment(self.api.create_comment(event_id, &request).await?))
+    }
+
+    async fn delete_comment(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.api.delete_comment(event_id, id).await
+    }
+}
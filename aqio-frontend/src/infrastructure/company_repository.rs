@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{Company, CompanyRepository, EventListItem, Industry, OrganizationInvite, Page};
+
+use super::admin_repository::{map_organization_invite, map_page};
+use super::api_client::{ApiClient, CompanyResponse};
+use super::event_repository::map_event_response;
+
+#[derive(Clone)]
+pub struct ApiCompanyRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiCompanyRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+pub(super) fn map_company(company: CompanyResponse) -> Result<Company, String> {
+    let industry = Industry::parse(&company.industry_type)
+        .ok_or_else(|| format!("Unknown industry '{}'", company.industry_type))?;
+    Ok(Company {
+        id: company.id,
+        name: company.name,
+        location: company.location,
+        industry,
+        industry_description: company.industry_type_other,
+        website: company.website,
+        member_count: company.member_count,
+    })
+}
+
+#[async_trait::async_trait(?Send)]
+impl CompanyRepository for ApiCompanyRepository {
+    async fn list_companies(&self, page: u32, industry: Option<Industry>) -> Result<Page<Company>, String> {
+        let companies = self
+            .api
+            .list_companies(page, industry.map(|industry| industry.as_str()))
+            .await?;
+        map_page(companies, map_company)
+    }
+
+    async fn upcoming_events(&self, company_id: Uuid) -> Result<Vec<EventListItem>, String> {
+        let events = self.api.list_company_events(company_id).await?;
+        Ok(events.into_iter().map(map_event_response).collect())
+    }
+
+    async fn organization_invite(&self, token: &str) -> Result<OrganizationInvite, String> {
+        map_organization_invite(self.api.get_organization_invitation(token).await?)
+    }
+
+    async fn accept_organization_invite(&self, token: &str) -> Result<(), String> {
+        self.api.accept_organization_invitation(token).await
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::application::ports::{Branding, ClientConfig, ConfigRepository};
+
+use super::api_client::ApiClient;
+
+#[derive(Clone)]
+pub struct ApiConfigRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiConfigRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl ConfigRepository for ApiConfigRepository {
+    async fn client_config(&self) -> Result<ClientConfig, String> {
+        let config = self.api.client_config().await?;
+        Ok(ClientConfig {
+            branding: Branding {
+                name: config.branding.name,
+                logo_url: config.branding.logo_url,
+                primary_color: config.branding.primary_color,
+            },
+            max_upload_bytes: config.max_upload_bytes,
+            // An unknown level leaves shipping off rather than failing startup
+            remote_log_level: config.remote_log_level.and_then(|level| level.parse().ok()),
+        })
+    }
+}
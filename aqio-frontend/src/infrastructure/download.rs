@@ -0,0 +1,63 @@
+// Saving generated files (CSV exports and the like) and fetched attachments through
+// the browser's download prompt, and showing fetched images in the page
+
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+fn js_error(context: &str, error: JsValue) -> String {
+    format!("{}: {:?}", context, error)
+}
+
+/// Offers `contents` to the user as a file called `filename`
+pub fn save_file(filename: &str, mime_type: &str, contents: &str) -> Result<(), String> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)
+        .map_err(|e| js_error("Could not create the file", e))?;
+    offer_blob(filename, &blob)
+}
+
+/// Offers binary `contents`, such as a downloaded attachment, as a file called `filename`
+pub fn save_bytes(filename: &str, mime_type: &str, contents: &[u8]) -> Result<(), String> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+        .map_err(|e| js_error("Could not create the file", e))?;
+    offer_blob(filename, &blob)
+}
+
+/// A `blob:` URL for showing fetched bytes, such as a photo, in the page; release it
+/// with [`revoke_object_url`] once it's no longer shown
+pub fn object_url(mime_type: &str, contents: &[u8]) -> Result<String, String> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+        .map_err(|e| js_error("Could not create the file", e))?;
+    Url::create_object_url_with_blob(&blob).map_err(|e| js_error("Could not create the file", e))
+}
+
+pub fn revoke_object_url(url: &str) {
+    let _ = Url::revoke_object_url(url);
+}
+
+fn offer_blob(filename: &str, blob: &Blob) -> Result<(), String> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or("No document")?;
+
+    let url = Url::create_object_url_with_blob(blob).map_err(|e| js_error("Could not create the file", e))?;
+
+    let link = document
+        .create_element("a")
+        .map_err(|e| js_error("Could not start the download", e))?
+        .dyn_into::<HtmlAnchorElement>()
+        .map_err(|_| "Could not start the download".to_string())?;
+    link.set_href(&url);
+    link.set_download(filename);
+    link.click();
+
+    Url::revoke_object_url(&url).map_err(|e| js_error("Could not release the file", e))
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::application::ports::{ErrorReport, ErrorReporter};
+
+use super::api_client::{ApiClient, ClientErrorReportRequest};
+
+#[derive(Clone)]
+pub struct ApiErrorReporter {
+    api: Arc<ApiClient>,
+}
+
+impl ApiErrorReporter {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl ErrorReporter for ApiErrorReporter {
+    async fn report_error(&self, report: &ErrorReport) -> Result<(), String> {
+        let user_agent = web_sys::window().and_then(|window| window.navigator().user_agent().ok());
+        let request = ClientErrorReportRequest {
+            reference: report.reference.clone(),
+            message: report.message.clone(),
+            route: Some(report.route.clone()).filter(|route| !route.is_empty()),
+            user_agent,
+        };
+        self.api.report_client_error(&request).await
+    }
+}
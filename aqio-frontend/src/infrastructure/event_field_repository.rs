@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{EventField, EventFieldRepository, EventFieldType, EventFieldValues};
+
+use super::api_client::{ApiClient, EventFieldValuesRequest, EventFieldsResponse};
+
+#[derive(Clone)]
+pub struct ApiEventFieldRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiEventFieldRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_field_type(field_type: &str) -> EventFieldType {
+    // The API serializes enum variants as-is (`Text`, `Number`, ...)
+    match field_type.to_lowercase().as_str() {
+        "number" => EventFieldType::Number,
+        "date" => EventFieldType::Date,
+        "boolean" => EventFieldType::Boolean,
+        "select" => EventFieldType::Select,
+        _ => EventFieldType::Text,
+    }
+}
+
+fn map_fields(response: EventFieldsResponse) -> EventFieldValues {
+    EventFieldValues {
+        fields: response
+            .fields
+            .into_iter()
+            .map(|entry| EventField {
+                id: entry.field.id,
+                field_type: map_field_type(&entry.field.field_type),
+                key: entry.field.key,
+                label: entry.field.label,
+                options: entry.field.options,
+                is_required: entry.field.is_required,
+                value: entry.value,
+            })
+            .collect(),
+        can_edit: response.can_edit,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl EventFieldRepository for ApiEventFieldRepository {
+    async fn event_fields(&self, event_id: Uuid) -> Result<EventFieldValues, String> {
+        Ok(map_fields(self.api.get_event_fields(event_id).await?))
+    }
+
+    async fn save_values(&self, event_id: Uuid, values: HashMap<String, String>) -> Result<EventFieldValues, String> {
+        let request = EventFieldValuesRequest { values };
+        Ok(map_fields(self.api.update_event_fields(event_id, &request).await?))
+    }
+}
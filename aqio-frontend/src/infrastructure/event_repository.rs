@@ -1,8 +1,16 @@
 use std::sync::Arc;
 
-use crate::application::ports::{EventListItem, EventRepository};
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
 
-use super::api_client::ApiClient;
+use crate::application::ports::{
+    Category, EventDraft, EventListItem, EventProgram, EventRepository, LatLng, RegistrationState, RegistrationWindow,
+    ScheduleConflict, WeatherForecast,
+};
+
+use super::api_client::{ApiClient, CreateEventRequest, ProgramFields, ScheduleCheckRequest};
+use super::admin_repository::map_category;
+use super::public_event_repository::{map_agenda_item, map_speaker};
 
 #[derive(Clone)]
 pub struct ApiEventRepository {
@@ -15,12 +23,58 @@ impl ApiEventRepository {
     }
 }
 
-fn map_event_response(er: super::api_client::EventResponse) -> EventListItem {
+fn map_registration_window(er: &super::api_client::EventSummaryResponse) -> Option<RegistrationWindow> {
+    let state = match er.registration_state.as_deref()? {
+        "NotYetOpen" => RegistrationState::NotYetOpen,
+        "Open" => RegistrationState::Open,
+        "Closed" => RegistrationState::Closed,
+        "Full" => RegistrationState::Full,
+        "WaitlistOnly" => RegistrationState::WaitlistOnly,
+        _ => return None,
+    };
+    let now = Utc::now();
+    let at = |seconds: Option<i64>| seconds.map(|seconds| now + Duration::seconds(seconds));
+    Some(RegistrationWindow {
+        state,
+        opens_at: at(er.seconds_until_registration_opens),
+        closes_at: at(er.seconds_until_registration_closes),
+    })
+}
+
+pub(super) fn map_event_response(er: super::api_client::EventSummaryResponse) -> EventListItem {
     EventListItem {
+        registration: map_registration_window(&er),
         id: er.id,
         title: er.title,
+        category_id: er.category_id,
         start_date: er.start_date,
         location: er.location_name,
+        slug: er.slug,
+        coordinates: match (er.latitude, er.longitude) {
+            (Some(latitude), Some(longitude)) => Some(LatLng { latitude, longitude }),
+            _ => None,
+        },
+    }
+}
+
+fn to_request(draft: &EventDraft) -> CreateEventRequest {
+    CreateEventRequest {
+        title: draft.title.clone(),
+        description: draft.description.clone(),
+        category_id: draft.category_id.clone(),
+        start_date: draft.start_date,
+        end_date: draft.end_date,
+        timezone: draft.timezone.clone(),
+        location_type: draft.location_kind.as_str(),
+        location_name: draft.location_name.clone(),
+        address: draft.address.clone(),
+        virtual_link: draft.virtual_link.clone(),
+        latitude: draft.coordinates.map(|point| point.latitude),
+        longitude: draft.coordinates.map(|point| point.longitude),
+        max_attendees: draft.max_attendees,
+        is_private: Some(draft.is_private),
+        publish_at: draft.publish_at,
+        co_organizers: draft.co_organizers.clone(),
     }
 }
 
@@ -30,4 +84,97 @@ impl EventRepository for ApiEventRepository {
         let events = self.api.list_events().await?;
         Ok(events.into_iter().map(map_event_response).collect())
     }
+
+    async fn get_event(&self, id: Uuid) -> Result<EventListItem, String> {
+        self.api.get_event(id).await.map(map_event_response)
+    }
+
+    async fn list_events_near(&self, center: LatLng, radius_km: f64) -> Result<Vec<EventListItem>, String> {
+        let events = self
+            .api
+            .list_events_near(center.latitude, center.longitude, radius_km)
+            .await?;
+        Ok(events.into_iter().map(map_event_response).collect())
+    }
+
+    async fn list_events_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<EventListItem>, String> {
+        let events = self.api.list_events_between(from, to).await?;
+        Ok(events.into_iter().map(map_event_response).collect())
+    }
+
+    async fn create_event(&self, draft: &EventDraft) -> Result<EventListItem, String> {
+        self.api.create_event(&to_request(draft)).await.map(map_event_response)
+    }
+
+    async fn update_event(&self, id: Uuid, draft: &EventDraft) -> Result<EventListItem, String> {
+        self.api.update_event(id, &to_request(draft)).await.map(map_event_response)
+    }
+
+    async fn cancel_event(&self, id: Uuid) -> Result<(), String> {
+        self.api.delete_event(id).await
+    }
+
+    async fn get_program(&self, id: Uuid) -> Result<EventProgram, String> {
+        let event = self.api.get_event_program(id).await?;
+        // Free-form JSON kept by organizers; anything unreadable is treated as no program
+        let program: ProgramFields = event
+            .custom_fields
+            .as_deref()
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default();
+        Ok(EventProgram {
+            title: event.title,
+            description: event.description,
+            start_date: event.start_date,
+            end_date: event.end_date,
+            location_name: event.location_name,
+            address: event.address,
+            agenda: program.agenda.into_iter().map(map_agenda_item).collect(),
+            speakers: program.speakers.into_iter().map(map_speaker).collect(),
+            forecast: event.forecast.map(|forecast| WeatherForecast {
+                date: forecast.date,
+                symbol_code: forecast.symbol_code,
+                min_temperature_c: forecast.min_temperature_c,
+                max_temperature_c: forecast.max_temperature_c,
+                precipitation_mm: forecast.precipitation_mm,
+                max_wind_speed_ms: forecast.max_wind_speed_ms,
+            }),
+        })
+    }
+
+    async fn search_events(&self, query: &str, limit: u32) -> Result<Vec<EventListItem>, String> {
+        let events = self.api.search_events(query, limit).await?;
+        Ok(events.into_iter().map(map_event_response).collect())
+    }
+
+    async fn list_active_categories(&self) -> Result<Vec<Category>, String> {
+        let categories = self.api.list_active_categories().await?;
+        Ok(categories.into_iter().map(map_category).collect())
+    }
+
+    async fn check_schedule(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        event_id: Option<Uuid>,
+    ) -> Result<Vec<ScheduleConflict>, String> {
+        let request = ScheduleCheckRequest {
+            start_date: start,
+            end_date: end,
+            event_id,
+        };
+        let response = self.api.check_schedule(&request).await?;
+        Ok(response
+            .conflicts
+            .into_iter()
+            .map(|conflict| ScheduleConflict {
+                blocking: conflict.enforcement == "Block",
+                message: conflict.message,
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{
+    DeliveryFailure, Invitation, InvitationRepository, InvitationStatus, Invitee, InviteSource,
+};
+
+use super::api_client::{ApiClient, CreateInvitationRequest, InvitationResponse};
+
+#[derive(Clone)]
+pub struct ApiInvitationRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiInvitationRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_invitation(invitation: InvitationResponse) -> Result<Invitation, String> {
+    let status = InvitationStatus::parse(&invitation.status)
+        .ok_or_else(|| format!("Unknown invitation status '{}'", invitation.status))?;
+    Ok(Invitation {
+        id: invitation.id,
+        invited_name: invitation.invited_name,
+        invited_email: invitation.invited_email,
+        personal_message: invitation.personal_message,
+        status,
+        sent_at: invitation.sent_at,
+        responded_at: invitation.responded_at,
+        delivery_failure: invitation.delivery_failure.as_deref().and_then(DeliveryFailure::parse),
+        created_at: invitation.created_at,
+    })
+}
+
+fn invitation_method(source: InviteSource) -> &'static str {
+    match source {
+        InviteSource::Manual => "email",
+        InviteSource::CsvImport => "bulkimport",
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl InvitationRepository for ApiInvitationRepository {
+    async fn list_invitations(&self, event_id: Uuid) -> Result<Vec<Invitation>, String> {
+        let invitations = self.api.list_event_invitations(event_id).await?;
+        invitations.into_iter().map(map_invitation).collect()
+    }
+
+    async fn create_invitation(
+        &self,
+        event_id: Uuid,
+        invitee: &Invitee,
+        personal_message: Option<&str>,
+        source: InviteSource,
+    ) -> Result<Invitation, String> {
+        let request = CreateInvitationRequest {
+            invited_email: Some(invitee.email.trim().to_string()),
+            invited_name: invitee.name.clone(),
+            invitation_method: invitation_method(source).to_string(),
+            personal_message: personal_message
+                .map(str::trim)
+                .filter(|message| !message.is_empty())
+                .map(str::to_string),
+        };
+        map_invitation(self.api.create_invitation(event_id, &request).await?)
+    }
+
+    async fn update_invitation_status(&self, id: Uuid, status: InvitationStatus) -> Result<(), String> {
+        self.api.update_invitation_status(id, status.as_str()).await
+    }
+}
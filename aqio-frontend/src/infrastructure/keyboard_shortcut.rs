@@ -0,0 +1,37 @@
+// Document-wide keyboard shortcuts, for keys that should work wherever focus is
+
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Document, KeyboardEvent};
+
+pub struct KeyboardShortcut {
+    document: Document,
+    listener: Closure<dyn FnMut(KeyboardEvent)>,
+}
+
+impl KeyboardShortcut {
+    /// Calls `on_press` on Ctrl+`key`, or Cmd+`key` on macOS, instead of the browser's own binding
+    pub fn ctrl(key: &'static str, mut on_press: impl FnMut() + 'static) -> Result<Self, String> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or("No document")?;
+        let listener = Closure::<dyn FnMut(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+            if (event.ctrl_key() || event.meta_key()) && event.key().eq_ignore_ascii_case(key) {
+                event.prevent_default();
+                on_press();
+            }
+        });
+        document
+            .add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
+            .map_err(|e| format!("Could not listen for Ctrl+{}: {:?}", key, e))?;
+
+        Ok(Self { document, listener })
+    }
+}
+
+impl Drop for KeyboardShortcut {
+    fn drop(&mut self) {
+        let _ = self
+            .document
+            .remove_event_listener_with_callback("keydown", self.listener.as_ref().unchecked_ref());
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{LivePoll, LivePollOption, LiveQuestion, LiveSession, LiveSessionRepository};
+
+use super::api_client::{ApiClient, CreatePollRequest, PollResponse, QuestionResponse};
+
+#[derive(Clone)]
+pub struct ApiLiveSessionRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiLiveSessionRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_question(question: QuestionResponse) -> LiveQuestion {
+    LiveQuestion {
+        id: question.id,
+        author_name: question.author_name,
+        content: question.content,
+        upvote_count: question.upvote_count,
+        has_upvoted: question.has_upvoted,
+        answer: question.answer,
+        created_at: question.created_at,
+    }
+}
+
+fn map_poll(poll: PollResponse) -> LivePoll {
+    LivePoll {
+        id: poll.id,
+        question: poll.question,
+        options: poll
+            .options
+            .into_iter()
+            .map(|option| LivePollOption {
+                id: option.id,
+                label: option.label,
+                vote_count: option.vote_count,
+            })
+            .collect(),
+        is_closed: poll.is_closed,
+        voted_option_id: poll.voted_option_id,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl LiveSessionRepository for ApiLiveSessionRepository {
+    async fn load_session(&self, event_id: Uuid) -> Result<LiveSession, String> {
+        let session = self.api.get_live_session(event_id).await?;
+        Ok(LiveSession {
+            is_running: session.is_running,
+            can_moderate: session.can_moderate,
+            questions: session.questions.into_iter().map(map_question).collect(),
+            polls: session.polls.into_iter().map(map_poll).collect(),
+        })
+    }
+
+    async fn ask_question(&self, event_id: Uuid, content: &str) -> Result<LiveQuestion, String> {
+        Ok(map_question(self.api.ask_question(event_id, content).await?))
+    }
+
+    async fn set_upvote(&self, event_id: Uuid, question_id: Uuid, upvoted: bool) -> Result<LiveQuestion, String> {
+        Ok(map_question(self.api.set_question_upvote(event_id, question_id, upvoted).await?))
+    }
+
+    async fn answer_question(&self, event_id: Uuid, question_id: Uuid, answer: &str) -> Result<LiveQuestion, String> {
+        Ok(map_question(self.api.answer_question(event_id, question_id, answer).await?))
+    }
+
+    async fn create_poll(&self, event_id: Uuid, question: &str, options: &[String]) -> Result<LivePoll, String> {
+        let request = CreatePollRequest { question, options };
+        Ok(map_poll(self.api.create_poll(event_id, &request).await?))
+    }
+
+    async fn vote(&self, event_id: Uuid, poll_id: Uuid, option_id: Uuid) -> Result<LivePoll, String> {
+        Ok(map_poll(self.api.vote_in_poll(event_id, poll_id, option_id).await?))
+    }
+
+    async fn close_poll(&self, event_id: Uuid, poll_id: Uuid) -> Result<LivePoll, String> {
+        Ok(map_poll(self.api.close_poll(event_id, poll_id).await?))
+    }
+
+    fn stream_url(&self, event_id: Uuid) -> String {
+        self.api.live_stream_url(event_id)
+    }
+}
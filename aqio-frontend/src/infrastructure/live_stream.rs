@@ -0,0 +1,113 @@
+// Live Q&A and poll changes for one event over server-sent events.
+// Like the registration stream, the browser reconnects on its own.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{EventSource, MessageEvent};
+
+use crate::application::ports::{LiveChange, LivePoll, LivePollOption, LiveQuestion};
+
+// Event names sent by `GET /api/v1/events/{id}/live/stream`
+const EVENT_NAMES: [&str; 2] = ["question_changed", "poll_changed"];
+
+// The stream carries the API's domain models rather than the REST responses
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum LiveUpdatePayload {
+    QuestionChanged { question: QuestionPayload },
+    PollChanged { poll: PollPayload },
+}
+
+#[derive(Deserialize)]
+struct QuestionPayload {
+    id: Uuid,
+    author_name: Option<String>,
+    content: String,
+    upvote_count: i64,
+    answer: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct PollPayload {
+    id: Uuid,
+    question: String,
+    options: Vec<PollOptionPayload>,
+    closed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize)]
+struct PollOptionPayload {
+    id: Uuid,
+    label: String,
+    vote_count: i64,
+}
+
+fn parse_change(data: &str) -> Result<LiveChange, serde_json::Error> {
+    Ok(match serde_json::from_str(data)? {
+        LiveUpdatePayload::QuestionChanged { question } => LiveChange::Question(LiveQuestion {
+            id: question.id,
+            author_name: question.author_name,
+            content: question.content,
+            upvote_count: question.upvote_count,
+            has_upvoted: false,
+            answer: question.answer,
+            created_at: question.created_at,
+        }),
+        LiveUpdatePayload::PollChanged { poll } => LiveChange::Poll(LivePoll {
+            id: poll.id,
+            question: poll.question,
+            options: poll
+                .options
+                .into_iter()
+                .map(|option| LivePollOption {
+                    id: option.id,
+                    label: option.label,
+                    vote_count: option.vote_count,
+                })
+                .collect(),
+            is_closed: poll.closed_at.is_some(),
+            voted_option_id: None,
+        }),
+    })
+}
+
+pub struct LiveStream {
+    source: EventSource,
+    // Must outlive the listeners registered on `source`
+    _listener: Closure<dyn FnMut(MessageEvent)>,
+}
+
+impl LiveStream {
+    /// Calls `on_change` with each question or poll change as it arrives
+    pub fn open(url: &str, mut on_change: impl FnMut(LiveChange) + 'static) -> Result<Self, String> {
+        let source = EventSource::new(url).map_err(|e| format!("Could not open live stream: {:?}", e))?;
+        let listener = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let Some(data) = event.data().as_string() else {
+                return;
+            };
+            match parse_change(&data) {
+                Ok(change) => on_change(change),
+                Err(e) => log::warn!("Ignoring malformed live update: {}", e),
+            }
+        });
+        for name in EVENT_NAMES {
+            source
+                .add_event_listener_with_callback(name, listener.as_ref().unchecked_ref())
+                .map_err(|e| format!("Could not listen for {}: {:?}", name, e))?;
+        }
+
+        Ok(Self {
+            source,
+            _listener: listener,
+        })
+    }
+}
+
+impl Drop for LiveStream {
+    fn drop(&mut self) {
+        self.source.close();
+    }
+}
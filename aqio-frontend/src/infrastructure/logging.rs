@@ -0,0 +1,190 @@
+// Logging for the app: the `log` macros write to the browser console and, once
+// the API's configuration sets a remote level, lines at that level or above are
+// buffered until they're shipped to the API. Calls to the API log the request ID
+// it echoes (`request_id` key), so a line here matches the server's own logs.
+//
+// The console shows debug lines in development builds and info lines otherwise;
+// setting `aqio.log_level` in local storage (e.g. to `trace` or `warn`) overrides
+// that for one browser.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use gloo_storage::{LocalStorage, Storage};
+use log::kv::Key;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use wasm_bindgen::JsValue;
+
+use crate::application::ports::{LogEntry, LogSink};
+
+use super::api_client::{ApiClient, ClientLogBatchRequest, ClientLogEntryRequest};
+
+const CONSOLE_LEVEL_KEY: &str = "aqio.log_level";
+// Lines kept between shipments; a noisy page drops the rest
+const MAX_BUFFERED_ENTRIES: usize = 200;
+/// Target for lines about shipping logs, which are never shipped themselves so
+/// a failing API can't feed its own backlog
+pub const LOGGING_TARGET: &str = "aqio::logging";
+
+struct Logger {
+    // `LevelFilter` as a number, so `Off` (0) disables the output
+    console_level: AtomicUsize,
+    remote_level: AtomicUsize,
+    buffer: Mutex<Vec<LogEntry>>,
+}
+
+static LOGGER: Logger = Logger {
+    console_level: AtomicUsize::new(LevelFilter::Info as usize),
+    remote_level: AtomicUsize::new(LevelFilter::Off as usize),
+    buffer: Mutex::new(Vec::new()),
+};
+
+impl Logger {
+    fn to_console(&self, level: Level) -> bool {
+        level as usize <= self.console_level.load(Ordering::Relaxed)
+    }
+
+    fn to_remote(&self, level: Level) -> bool {
+        level as usize <= self.remote_level.load(Ordering::Relaxed)
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.to_console(metadata.level()) || self.to_remote(metadata.level())
+    }
+
+    fn log(&self, record: &Record) {
+        let level = record.level();
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let message = record.args().to_string();
+        let request_id = record
+            .key_values()
+            .get(Key::from_str("request_id"))
+            .and_then(|value| value.to_borrowed_str().map(str::to_string));
+
+        if self.to_console(level) {
+            let line = match &request_id {
+                Some(request_id) => format!("[{}] {} (request {})", record.target(), message, request_id),
+                None => format!("[{}] {}", record.target(), message),
+            };
+            write_to_console(level, &line);
+        }
+
+        if self.to_remote(level) && record.target() != LOGGING_TARGET {
+            if let Ok(mut buffer) = self.buffer.lock() {
+                if buffer.len() < MAX_BUFFERED_ENTRIES {
+                    buffer.push(LogEntry {
+                        level,
+                        target: record.target().to_string(),
+                        message,
+                        request_id,
+                        route: current_route(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn write_to_console(level: Level, line: &str) {
+    let line = JsValue::from_str(line);
+    match level {
+        Level::Error => web_sys::console::error_1(&line),
+        Level::Warn => web_sys::console::warn_1(&line),
+        Level::Info => web_sys::console::info_1(&line),
+        Level::Debug | Level::Trace => web_sys::console::debug_1(&line),
+    }
+}
+
+fn current_route() -> String {
+    web_sys::window()
+        .and_then(|window| window.location().pathname().ok())
+        .unwrap_or_default()
+}
+
+fn update_max_level() {
+    let console = LOGGER.console_level.load(Ordering::Relaxed);
+    let remote = LOGGER.remote_level.load(Ordering::Relaxed);
+    let max = LevelFilter::iter().nth(console.max(remote)).unwrap_or(LevelFilter::Trace);
+    log::set_max_level(max);
+}
+
+/// Installs the logger; call once, before the app is launched
+pub fn init() {
+    let default = if cfg!(debug_assertions) { LevelFilter::Debug } else { LevelFilter::Info };
+    let console_level = LocalStorage::raw()
+        .get_item(CONSOLE_LEVEL_KEY)
+        .ok()
+        .flatten()
+        .and_then(|level| level.parse::<LevelFilter>().ok())
+        .unwrap_or(default);
+    LOGGER.console_level.store(console_level as usize, Ordering::Relaxed);
+
+    if log::set_logger(&LOGGER).is_ok() {
+        update_max_level();
+    }
+}
+
+/// Starts or stops buffering lines for the API, from the level the API's
+/// configuration asks for
+pub fn set_remote_level(level: Option<Level>) {
+    let filter = level.map_or(LevelFilter::Off, |level| level.to_level_filter());
+    LOGGER.remote_level.store(filter as usize, Ordering::Relaxed);
+    if filter == LevelFilter::Off {
+        if let Ok(mut buffer) = LOGGER.buffer.lock() {
+            buffer.clear();
+        }
+    }
+    update_max_level();
+}
+
+/// Lines buffered for the API since the last call
+pub fn take_remote_entries() -> Vec<LogEntry> {
+    LOGGER
+        .buffer
+        .lock()
+        .map(|mut buffer| std::mem::take(&mut *buffer))
+        .unwrap_or_default()
+}
+
+#[derive(Clone)]
+pub struct ApiLogSink {
+    api: Arc<ApiClient>,
+}
+
+impl ApiLogSink {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl LogSink for ApiLogSink {
+    async fn send_logs(&self, release: &str, entries: &[LogEntry]) -> Result<(), String> {
+        let request = ClientLogBatchRequest {
+            release,
+            entries: entries
+                .iter()
+                .map(|entry| ClientLogEntryRequest {
+                    // The API knows the levels it stores; trace lines go in as debug
+                    level: match entry.level {
+                        Level::Error => "error",
+                        Level::Warn => "warn",
+                        Level::Info => "info",
+                        Level::Debug | Level::Trace => "debug",
+                    },
+                    target: &entry.target,
+                    message: &entry.message,
+                    request_id: entry.request_id.as_deref(),
+                    route: &entry.route,
+                })
+                .collect(),
+        };
+        self.api.report_client_logs(&request).await
+    }
+}
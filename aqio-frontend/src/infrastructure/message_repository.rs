@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{Message, MessageRepository, MessageThread};
+
+use super::api_client::{ApiClient, MessageResponse, MessageThreadResponse, SendMessageRequest};
+
+#[derive(Clone)]
+pub struct ApiMessageRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiMessageRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_thread(thread: MessageThreadResponse) -> MessageThread {
+    MessageThread {
+        id: thread.id,
+        event_id: thread.event_id,
+        event_title: thread.event_title,
+        attendee_name: thread.attendee_name,
+        as_organizer: thread.as_organizer,
+        unread: thread.unread,
+        last_message_at: thread.last_message_at,
+    }
+}
+
+fn map_message(message: MessageResponse) -> Message {
+    Message {
+        id: message.id,
+        thread_id: message.thread_id,
+        from_organizer: message.from_organizer,
+        body: message.body,
+        created_at: message.created_at,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl MessageRepository for ApiMessageRepository {
+    async fn list_threads(&self) -> Result<Vec<MessageThread>, String> {
+        let threads = self.api.list_message_threads().await?;
+        Ok(threads.into_iter().map(map_thread).collect())
+    }
+
+    async fn unread_count(&self) -> Result<u32, String> {
+        Ok(self.api.count_unread_messages().await?.unread)
+    }
+
+    async fn get_thread(&self, thread_id: Uuid) -> Result<(MessageThread, Vec<Message>), String> {
+        let details = self.api.get_message_thread(thread_id).await?;
+        Ok((
+            map_thread(details.thread),
+            details.messages.into_iter().map(map_message).collect(),
+        ))
+    }
+
+    async fn reply(&self, thread_id: Uuid, body: &str) -> Result<Message, String> {
+        let request = SendMessageRequest { body };
+        Ok(map_message(self.api.reply_to_message_thread(thread_id, &request).await?))
+    }
+
+    async fn contact_organizers(&self, event_id: Uuid, body: &str) -> Result<Message, String> {
+        let request = SendMessageRequest { body };
+        Ok(map_message(self.api.contact_organizers(event_id, &request).await?))
+    }
+}
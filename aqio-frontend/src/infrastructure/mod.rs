@@ -1,2 +1,35 @@
+pub mod admin_repository;
 pub mod api_client;
+pub mod attachment_repository;
+pub mod budget_repository;
+pub mod camera;
+pub mod catering_repository;
+pub mod certificate_repository;
+pub mod check_in_repository;
+pub mod check_in_store;
+pub mod comment_repository;
+pub mod company_repository;
+pub mod config_repository;
+pub mod download;
+pub mod error_reporter;
+pub mod event_field_repository;
 pub mod event_repository;
+pub mod invitation_repository;
+pub mod keyboard_shortcut;
+pub mod live_session_repository;
+pub mod live_stream;
+pub mod logging;
+pub mod message_repository;
+pub mod networking_repository;
+pub mod past_event_repository;
+pub mod photo_repository;
+pub mod public_event_repository;
+pub mod registration_repository;
+pub mod registration_stream;
+pub mod seating_repository;
+pub mod session;
+pub mod settings_repository;
+pub mod sponsor_repository;
+pub mod travel_repository;
+pub mod user_search_repository;
+pub mod web_vitals;
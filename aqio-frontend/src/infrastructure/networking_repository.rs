@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{
+    AvailabilityWindow, Meeting, MeetingStatus, NetworkingContact, NetworkingProfile, NetworkingProfileDraft,
+    NetworkingRepository,
+};
+
+use super::api_client::{
+    ApiClient, AvailabilityWindowResponse, MatchmakingProfileRequest, MatchmakingProfileResponse, MeetingResponse,
+    RequestMeetingRequest,
+};
+
+#[derive(Clone)]
+pub struct ApiNetworkingRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiNetworkingRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_window(window: AvailabilityWindowResponse) -> AvailabilityWindow {
+    AvailabilityWindow {
+        starts_at: window.starts_at,
+        ends_at: window.ends_at,
+    }
+}
+
+fn map_profile(profile: MatchmakingProfileResponse) -> NetworkingProfile {
+    NetworkingProfile {
+        user_id: profile.user_id,
+        interests: profile.interests,
+        bio: profile.bio,
+        availability: profile.availability.into_iter().map(map_window).collect(),
+    }
+}
+
+fn parse_status(status: &str) -> MeetingStatus {
+    match status {
+        "Accepted" => MeetingStatus::Accepted,
+        "Declined" => MeetingStatus::Declined,
+        "Cancelled" => MeetingStatus::Cancelled,
+        _ => MeetingStatus::Requested,
+    }
+}
+
+fn map_meeting(meeting: MeetingResponse) -> Meeting {
+    Meeting {
+        id: meeting.id,
+        requester_id: meeting.requester_id,
+        requester_name: meeting.requester_name,
+        invitee_id: meeting.invitee_id,
+        invitee_name: meeting.invitee_name,
+        message: meeting.message,
+        starts_at: meeting.starts_at,
+        ends_at: meeting.ends_at,
+        room: meeting.room,
+        status: parse_status(&meeting.status),
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl NetworkingRepository for ApiNetworkingRepository {
+    async fn get_profile(&self, event_id: Uuid) -> Result<Option<NetworkingProfile>, String> {
+        Ok(self.api.get_matchmaking_profile(event_id).await?.map(map_profile))
+    }
+
+    async fn save_profile(&self, event_id: Uuid, profile: &NetworkingProfileDraft) -> Result<NetworkingProfile, String> {
+        let request = MatchmakingProfileRequest {
+            interests: &profile.interests,
+            bio: profile.bio.as_deref(),
+            availability: profile
+                .availability
+                .iter()
+                .map(|window| AvailabilityWindowResponse {
+                    starts_at: window.starts_at,
+                    ends_at: window.ends_at,
+                })
+                .collect(),
+        };
+        Ok(map_profile(self.api.save_matchmaking_profile(event_id, &request).await?))
+    }
+
+    async fn leave(&self, event_id: Uuid) -> Result<(), String> {
+        self.api.leave_matchmaking(event_id).await
+    }
+
+    async fn list_contacts(&self, event_id: Uuid) -> Result<Vec<NetworkingContact>, String> {
+        let matches = self.api.list_matches(event_id).await?;
+        Ok(matches
+            .into_iter()
+            .map(|found| NetworkingContact {
+                user_id: found.user_id,
+                name: found.name,
+                company: found.company,
+                bio: found.bio,
+                interests: found.interests,
+                shared_interests: found.shared_interests,
+            })
+            .collect())
+    }
+
+    async fn list_meetings(&self, event_id: Uuid) -> Result<Vec<Meeting>, String> {
+        let meetings = self.api.list_meetings(event_id).await?;
+        Ok(meetings.into_iter().map(map_meeting).collect())
+    }
+
+    async fn request_meeting(&self, event_id: Uuid, invitee_id: Uuid, message: Option<&str>) -> Result<(), String> {
+        self.api
+            .request_meeting(event_id, &RequestMeetingRequest { invitee_id, message })
+            .await
+    }
+
+    async fn answer_meeting(&self, event_id: Uuid, meeting_id: Uuid, accept: bool) -> Result<(), String> {
+        let action = if accept { "accept" } else { "decline" };
+        self.api.answer_meeting(event_id, meeting_id, action).await
+    }
+
+    async fn cancel_meeting(&self, event_id: Uuid, meeting_id: Uuid) -> Result<(), String> {
+        self.api.answer_meeting(event_id, meeting_id, "cancel").await
+    }
+
+    async fn download_invite(&self, event_id: Uuid, meeting_id: Uuid) -> Result<String, String> {
+        self.api.download_meeting_invite(event_id, meeting_id).await
+    }
+}
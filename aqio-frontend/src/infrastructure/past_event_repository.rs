@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::application::ports::{
+    ArchiveGrouping, AttendanceTrend, Page, PastEvent, PastEventQuery, PastEventRepository, TrendYear,
+};
+
+use super::admin_repository::map_page;
+use super::api_client::{ApiClient, AttendanceTrendResponse, PastEventResponse};
+
+#[derive(Clone)]
+pub struct ApiPastEventRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiPastEventRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_past_event(event: PastEventResponse) -> Result<PastEvent, String> {
+    Ok(PastEvent {
+        id: event.event_id,
+        title: event.title,
+        // Names are missing once the category or organizer has been removed
+        category: event.category_name.unwrap_or(event.category_id),
+        organizer: event.organizer_name.unwrap_or_else(|| "Unknown organizer".to_string()),
+        start_date: event.start_date,
+        registrations: event.registrations,
+        checked_in: event.checked_in,
+        no_shows: event.no_shows,
+        cancelled: event.cancelled,
+        attendance_rate: event.attendance_rate,
+    })
+}
+
+fn map_trend(trend: AttendanceTrendResponse) -> AttendanceTrend {
+    AttendanceTrend {
+        // Events without a known county are grouped under an empty ID
+        name: trend.group_name.unwrap_or_else(|| {
+            if trend.group_id.is_empty() { "Unknown".to_string() } else { trend.group_id.clone() }
+        }),
+        group_id: trend.group_id,
+        events: trend.events,
+        registrations: trend.registrations,
+        attendance_rate: trend.attendance_rate,
+        years: trend
+            .years
+            .into_iter()
+            .map(|year| TrendYear {
+                year: year.year,
+                events: year.events,
+                registrations: year.registrations,
+                checked_in: year.checked_in,
+                attendance_rate: year.attendance_rate,
+                registrations_change: year.registrations_change,
+            })
+            .collect(),
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl PastEventRepository for ApiPastEventRepository {
+    async fn list_past_events(&self, page: u32, query: &PastEventQuery) -> Result<Page<PastEvent>, String> {
+        let events = self
+            .api
+            .list_past_events(page, &query.title, query.category_id.as_deref(), query.year)
+            .await?;
+        map_page(events, map_past_event)
+    }
+
+    async fn attendance_trends(
+        &self,
+        grouping: ArchiveGrouping,
+        category_id: Option<&str>,
+        from_year: i32,
+        to_year: i32,
+    ) -> Result<Vec<AttendanceTrend>, String> {
+        let trends = self
+            .api
+            .get_attendance_trends(grouping.as_str(), category_id, from_year, to_year)
+            .await?;
+        Ok(trends.into_iter().map(map_trend).collect())
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{Photo, PhotoAlbumSettings, PhotoGallery, PhotoRepository, PhotoUpload};
+
+use super::api_client::{ApiClient, ModeratePhotoRequest, PhotoAlbumRequest, PhotoResponse, UploadPhotoRequest};
+
+#[derive(Clone)]
+pub struct ApiPhotoRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiPhotoRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+pub(super) fn map_photo(photo: PhotoResponse) -> Photo {
+    Photo {
+        id: photo.id,
+        filename: photo.filename,
+        caption: photo.caption,
+        mime_type: photo.mime_type,
+        file_size: photo.file_size.max(0) as u64,
+        // The API serializes enum variants as-is (`Pending`, `Approved`, `Rejected`)
+        status: photo.status.to_lowercase(),
+        uploader_name: photo.uploader_name,
+        can_remove: photo.can_remove,
+        uploaded_at: photo.uploaded_at,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl PhotoRepository for ApiPhotoRepository {
+    async fn gallery(&self, event_id: Uuid) -> Result<PhotoGallery, String> {
+        let gallery = self.api.get_photo_gallery(event_id).await?;
+        Ok(PhotoGallery {
+            settings: PhotoAlbumSettings {
+                attendee_uploads: gallery.attendee_uploads,
+                requires_approval: gallery.requires_approval,
+                is_public: gallery.is_public,
+            },
+            can_manage: gallery.can_manage,
+            can_upload: gallery.can_upload,
+            photos: gallery.photos.into_iter().map(map_photo).collect(),
+        })
+    }
+
+    async fn upload(&self, event_id: Uuid, upload: PhotoUpload) -> Result<Photo, String> {
+        let request = UploadPhotoRequest {
+            filename: upload.filename,
+            mime_type: upload.mime_type,
+            bytes: upload.bytes,
+            caption: upload.caption,
+        };
+        Ok(map_photo(self.api.upload_photo(event_id, request).await?))
+    }
+
+    async fn update_album(&self, event_id: Uuid, settings: PhotoAlbumSettings) -> Result<(), String> {
+        let request = PhotoAlbumRequest {
+            attendee_uploads: settings.attendee_uploads,
+            requires_approval: settings.requires_approval,
+            is_public: settings.is_public,
+        };
+        self.api.update_photo_album(event_id, &request).await
+    }
+
+    async fn moderate(&self, event_id: Uuid, id: Uuid, approve: bool) -> Result<Photo, String> {
+        let request = ModeratePhotoRequest {
+            status: if approve { "approved" } else { "rejected" },
+        };
+        Ok(map_photo(self.api.moderate_photo(event_id, id, &request).await?))
+    }
+
+    async fn download(&self, event_id: Uuid, id: Uuid) -> Result<Vec<u8>, String> {
+        self.api.download_photo(event_id, id).await
+    }
+
+    async fn download_all(&self, event_id: Uuid) -> Result<Vec<u8>, String> {
+        self.api.download_photo_album(event_id).await
+    }
+
+    async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.api.delete_photo(event_id, id).await
+    }
+}
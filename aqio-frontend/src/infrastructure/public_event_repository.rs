@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{
+    AgendaItem, GuestRegistration, GuestRegistrationOutcome, PublicEvent, PublicEventRepository,
+    PublicExhibitor, Speaker,
+};
+
+use super::api_client::{
+    AgendaItemResponse, ApiClient, GuestRegistrationRequest, PublicEventResponse, PublicExhibitorResponse,
+    SpeakerResponse,
+};
+use super::attachment_repository::map_attachment;
+use super::photo_repository::map_photo;
+use super::sponsor_repository::map_sponsors;
+use super::travel_repository::map_travel;
+
+#[derive(Clone)]
+pub struct ApiPublicEventRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiPublicEventRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+pub(super) fn map_agenda_item(item: AgendaItemResponse) -> AgendaItem {
+    AgendaItem {
+        time: item.time,
+        title: item.title,
+        description: item.description,
+        speaker: item.speaker,
+    }
+}
+
+pub(super) fn map_speaker(speaker: SpeakerResponse) -> Speaker {
+    Speaker {
+        name: speaker.name,
+        title: speaker.title,
+        company: speaker.company,
+        bio: speaker.bio,
+        photo_url: speaker.photo_url,
+    }
+}
+
+fn map_exhibitor(exhibitor: PublicExhibitorResponse) -> PublicExhibitor {
+    PublicExhibitor {
+        id: exhibitor.id,
+        company_name: exhibitor.company_name,
+        website_url: exhibitor.website_url,
+        description: exhibitor.description,
+        booth_number: exhibitor.booth_number,
+        hall: exhibitor.hall,
+    }
+}
+
+fn map_public_event(event: PublicEventResponse) -> Result<PublicEvent, String> {
+    let max_guests = if event.allow_guests {
+        event.max_guests_per_person.unwrap_or(0).max(0) as u32
+    } else {
+        0
+    };
+    Ok(PublicEvent {
+        slug: event.slug,
+        title: event.title,
+        description: event.description,
+        start_date: event.start_date,
+        end_date: event.end_date,
+        location_name: event.location_name,
+        address: event.address,
+        image_url: event.image_url,
+        agenda: event.agenda.into_iter().map(map_agenda_item).collect(),
+        speakers: event.speakers.into_iter().map(map_speaker).collect(),
+        max_guests,
+        collect_dietary_info: event.collect_dietary_info,
+        collect_accessibility_info: event.collect_accessibility_info,
+        registration_open: event.registration_open,
+        spots_left: event.spots_left.map(|spots| spots.max(0) as u32),
+        waitlist_available: event.waitlist_available,
+        attachments: event.attachments.into_iter().map(map_attachment).collect(),
+        photos: event.photos.into_iter().map(map_photo).collect(),
+        sponsors: map_sponsors(event.sponsors)?,
+        exhibitors: event.exhibitors.into_iter().map(map_exhibitor).collect(),
+        travel: map_travel(event.travel)?,
+        // `</` would end the script element the markup is placed in
+        structured_data: event.structured_data.map(|markup| markup.to_string().replace("</", "<\\/")),
+    })
+}
+
+#[async_trait::async_trait(?Send)]
+impl PublicEventRepository for ApiPublicEventRepository {
+    async fn get_public_event(&self, slug: &str) -> Result<PublicEvent, String> {
+        self.api.get_public_event(slug).await.and_then(map_public_event)
+    }
+
+    async fn register_guest(
+        &self,
+        slug: &str,
+        registration: &GuestRegistration,
+    ) -> Result<GuestRegistrationOutcome, String> {
+        let request = GuestRegistrationRequest {
+            registrant_name: registration.name.clone(),
+            registrant_email: registration.email.clone(),
+            registrant_phone: registration.phone.clone(),
+            registrant_company: registration.company.clone(),
+            guest_count: Some(registration.guest_count as i32).filter(|count| *count > 0),
+            dietary_restrictions: registration.dietary_restrictions.clone(),
+            accessibility_needs: registration.accessibility_needs.clone(),
+        };
+        let response = self.api.register_guest(slug, &request).await?;
+        // The API serializes enum variants as-is (`Registered`, `Waitlisted`, ...)
+        if response.status.eq_ignore_ascii_case("waitlisted") {
+            Ok(GuestRegistrationOutcome::Waitlisted {
+                position: response.waitlist_position.map(|position| position.max(0) as u32),
+            })
+        } else {
+            Ok(GuestRegistrationOutcome::Registered)
+        }
+    }
+
+    fn attachment_url(&self, slug: &str, id: Uuid) -> String {
+        self.api.public_attachment_url(slug, id)
+    }
+
+    fn photo_url(&self, slug: &str, id: Uuid) -> String {
+        self.api.public_photo_url(slug, id)
+    }
+
+    fn calendar_url(&self, slug: &str) -> String {
+        self.api.public_calendar_url(slug)
+    }
+
+    fn short_link_url(&self, code: &str) -> String {
+        self.api.public_short_link_url(code)
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{MyRegistration, RegistrationRepository, SpotState};
+
+use super::api_client::{ApiClient, WaitlistStatusResponse};
+
+#[derive(Clone)]
+pub struct ApiRegistrationRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiRegistrationRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_waitlist_status(status: WaitlistStatusResponse) -> MyRegistration {
+    let registration = status.registration;
+    // The API serializes enum variants as-is (`Registered`, `Waitlisted`, ...)
+    let state = match (registration.status.to_lowercase().as_str(), registration.promotion_expires_at) {
+        ("waitlisted", _) => SpotState::Waitlisted {
+            position: status.position.unwrap_or(status.waitlist_size),
+            waitlist_size: status.waitlist_size,
+        },
+        ("registered", Some(confirm_by)) => SpotState::Offered { confirm_by },
+        ("attended", _) => SpotState::CheckedIn,
+        ("registered", None) => SpotState::Confirmed,
+        _ => SpotState::Cancelled,
+    };
+    MyRegistration {
+        id: registration.id,
+        event_id: registration.event_id,
+        state,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl RegistrationRepository for ApiRegistrationRepository {
+    async fn find_my_registration(&self, event_id: Uuid) -> Result<Option<MyRegistration>, String> {
+        let registrations = self.api.my_registrations().await?;
+        // Prefer an active registration over an earlier cancelled one
+        let Some(registration) = registrations
+            .iter()
+            .filter(|registration| registration.event_id == event_id)
+            .min_by_key(|registration| registration.status.eq_ignore_ascii_case("cancelled"))
+        else {
+            return Ok(None);
+        };
+
+        // Also releases an offer whose deadline has passed
+        let status = self.api.waitlist_status(registration.id).await?;
+        Ok(Some(map_waitlist_status(status)))
+    }
+
+    async fn confirm_spot(&self, registration_id: Uuid) -> Result<(), String> {
+        self.api.confirm_promotion(registration_id).await.map(|_| ())
+    }
+
+    fn notification_stream_url(&self) -> String {
+        self.api.registration_stream_url()
+    }
+}
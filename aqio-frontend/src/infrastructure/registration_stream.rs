@@ -0,0 +1,40 @@
+// Live registration updates over server-sent events.
+// The browser's EventSource reconnects on its own after the connection drops.
+
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{EventSource, MessageEvent};
+
+// Event names sent by `GET /api/v1/registrations/me/stream`
+const EVENT_NAMES: [&str; 3] = ["waitlist_moved", "promoted", "promotion_expired"];
+
+pub struct RegistrationStream {
+    source: EventSource,
+    // Must outlive the listeners registered on `source`
+    _listener: Closure<dyn FnMut(MessageEvent)>,
+}
+
+impl RegistrationStream {
+    /// Calls `on_event` with the event name each time a notification arrives
+    pub fn open(url: &str, mut on_event: impl FnMut(String) + 'static) -> Result<Self, String> {
+        let source = EventSource::new(url).map_err(|e| format!("Could not open update stream: {:?}", e))?;
+        let listener = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            on_event(event.type_());
+        });
+        for name in EVENT_NAMES {
+            source
+                .add_event_listener_with_callback(name, listener.as_ref().unchecked_ref())
+                .map_err(|e| format!("Could not listen for {}: {:?}", name, e))?;
+        }
+
+        Ok(Self {
+            source,
+            _listener: listener,
+        })
+    }
+}
+
+impl Drop for RegistrationStream {
+    fn drop(&mut self) {
+        self.source.close();
+    }
+}
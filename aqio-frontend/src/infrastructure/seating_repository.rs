@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{
+    PlannedTable, SeatedParty, SeatingPlan, SeatingRepository, SeatingTable, SeatingTableDraft, SeatingWarning,
+};
+
+use super::api_client::{ApiClient, SeatedPartyResponse, SeatingPlanResponse, SeatingTableRequest, SeatingTableResponse};
+
+#[derive(Clone)]
+pub struct ApiSeatingRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiSeatingRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+fn map_table(table: SeatingTableResponse) -> SeatingTable {
+    SeatingTable {
+        id: table.id,
+        name: table.name,
+        capacity: table.capacity,
+        accessible: table.accessible,
+    }
+}
+
+fn map_parties(parties: Vec<SeatedPartyResponse>) -> Vec<SeatedParty> {
+    parties
+        .into_iter()
+        .map(|party| SeatedParty {
+            registration_id: party.registration_id,
+            name: party.name,
+            company: party.company,
+            guest_names: party.guest_names,
+            seats: party.seats,
+            accessibility_needs: party.accessibility_needs,
+        })
+        .collect()
+}
+
+fn map_plan(plan: SeatingPlanResponse) -> SeatingPlan {
+    SeatingPlan {
+        capacity: plan.capacity,
+        seated_people: plan.seated_people,
+        unassigned_people: plan.unassigned_people,
+        tables: plan
+            .tables
+            .into_iter()
+            .map(|planned| PlannedTable {
+                table: map_table(planned.table),
+                seats_taken: planned.seats_taken,
+                parties: map_parties(planned.parties),
+            })
+            .collect(),
+        unassigned: map_parties(plan.unassigned),
+        warnings: plan
+            .warnings
+            .into_iter()
+            .map(|warning| SeatingWarning {
+                message: warning.message,
+                table_ids: warning.table_ids,
+            })
+            .collect(),
+    }
+}
+
+fn table_request(table: &SeatingTableDraft) -> SeatingTableRequest<'_> {
+    SeatingTableRequest {
+        name: &table.name,
+        capacity: table.capacity,
+        accessible: table.accessible,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl SeatingRepository for ApiSeatingRepository {
+    async fn get_plan(&self, event_id: Uuid) -> Result<SeatingPlan, String> {
+        Ok(map_plan(self.api.get_seating_plan(event_id).await?))
+    }
+
+    async fn add_table(&self, event_id: Uuid, table: &SeatingTableDraft) -> Result<SeatingTable, String> {
+        Ok(map_table(self.api.create_seating_table(event_id, &table_request(table)).await?))
+    }
+
+    async fn update_table(&self, event_id: Uuid, id: Uuid, table: &SeatingTableDraft) -> Result<SeatingTable, String> {
+        Ok(map_table(self.api.update_seating_table(event_id, id, &table_request(table)).await?))
+    }
+
+    async fn delete_table(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.api.delete_seating_table(event_id, id).await
+    }
+
+    async fn assign(&self, event_id: Uuid, registration_id: Uuid, table_id: Option<Uuid>) -> Result<SeatingPlan, String> {
+        Ok(map_plan(self.api.assign_seat(event_id, registration_id, table_id).await?))
+    }
+
+    async fn download_chart(&self, event_id: Uuid) -> Result<Vec<u8>, String> {
+        self.api.download_seating_chart(event_id).await
+    }
+}
@@ -0,0 +1,18 @@
+// Reads the session persisted by the login flow so API calls can authenticate
+
+use gloo_storage::{LocalStorage, Storage};
+use serde::Deserialize;
+
+// Same key and shape the login modal writes
+const AUTH_STORAGE_KEY: &str = "aqio_auth_state";
+
+#[derive(Deserialize, Default)]
+struct StoredAuthState {
+    token: Option<String>,
+}
+
+pub fn stored_auth_token() -> Option<String> {
+    LocalStorage::get::<StoredAuthState>(AUTH_STORAGE_KEY)
+        .ok()
+        .and_then(|state| state.token)
+}
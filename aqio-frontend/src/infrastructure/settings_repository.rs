@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::application::calendar::CalendarView;
+use crate::application::ports::{SettingsRepository, UserSettings};
+
+use super::api_client::{
+    ApiClient, NotificationPreferencesRequest, NotificationPreferencesResponse, UpdateProfileRequest, UserResponse,
+};
+
+#[derive(Clone)]
+pub struct ApiSettingsRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiSettingsRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+// The app's calendar view and the API's are the same three layouts
+fn map_calendar_view(view: aqio_core::CalendarView) -> CalendarView {
+    match view {
+        aqio_core::CalendarView::Month => CalendarView::Month,
+        aqio_core::CalendarView::Week => CalendarView::Week,
+        aqio_core::CalendarView::Day => CalendarView::Day,
+    }
+}
+
+fn to_calendar_view(view: CalendarView) -> aqio_core::CalendarView {
+    match view {
+        CalendarView::Month => aqio_core::CalendarView::Month,
+        CalendarView::Week => aqio_core::CalendarView::Week,
+        CalendarView::Day => aqio_core::CalendarView::Day,
+    }
+}
+
+fn map_settings(user: UserResponse, notifications: NotificationPreferencesResponse) -> UserSettings {
+    UserSettings {
+        id: user.id,
+        name: user.name,
+        email: user.email,
+        timezone: user.timezone,
+        locale: user.locale,
+        theme: user.theme,
+        calendar_view: user.calendar_view.map(map_calendar_view),
+        digest_frequency: notifications.digest_frequency,
+        next_digest_at: notifications.next_digest_at,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl SettingsRepository for ApiSettingsRepository {
+    async fn load_settings(&self) -> Result<UserSettings, String> {
+        let (user, notifications) =
+            futures::try_join!(self.api.current_user(), self.api.notification_preferences())?;
+        Ok(map_settings(user, notifications))
+    }
+
+    async fn save_settings(&self, settings: &UserSettings) -> Result<UserSettings, String> {
+        let profile = UpdateProfileRequest {
+            name: &settings.name,
+            timezone: settings.timezone.as_deref(),
+            locale: settings.locale,
+            theme: settings.theme,
+            calendar_view: settings.calendar_view.map(to_calendar_view),
+        };
+        let user = self.api.update_profile(settings.id, &profile).await?;
+        let notifications = self
+            .api
+            .update_notification_preferences(&NotificationPreferencesRequest {
+                digest_frequency: settings.digest_frequency,
+            })
+            .await?;
+        Ok(map_settings(user, notifications))
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{Sponsor, SponsorDraft, SponsorRepository, SponsorTier};
+
+use super::api_client::{ApiClient, SponsorRequest, SponsorResponse};
+
+#[derive(Clone)]
+pub struct ApiSponsorRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiSponsorRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+pub(super) fn map_sponsor(sponsor: SponsorResponse) -> Result<Sponsor, String> {
+    let tier = SponsorTier::parse(&sponsor.tier).ok_or_else(|| format!("Unknown sponsor tier '{}'", sponsor.tier))?;
+    Ok(Sponsor {
+        id: sponsor.id,
+        name: sponsor.name,
+        tier,
+        logo_url: sponsor.logo_url,
+        website_url: sponsor.website_url,
+        description: sponsor.description,
+        display_order: sponsor.display_order,
+    })
+}
+
+pub(super) fn map_sponsors(sponsors: Vec<SponsorResponse>) -> Result<Vec<Sponsor>, String> {
+    sponsors.into_iter().map(map_sponsor).collect()
+}
+
+fn sponsor_request(sponsor: &SponsorDraft) -> SponsorRequest<'_> {
+    SponsorRequest {
+        name: &sponsor.name,
+        tier: sponsor.tier.as_str(),
+        logo_url: sponsor.logo_url.as_deref(),
+        website_url: sponsor.website_url.as_deref(),
+        description: sponsor.description.as_deref(),
+        // Edits keep the sponsor's place; new ones go last in their tier
+        display_order: None,
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl SponsorRepository for ApiSponsorRepository {
+    async fn list_sponsors(&self, event_id: Uuid) -> Result<Vec<Sponsor>, String> {
+        map_sponsors(self.api.list_sponsors(event_id).await?)
+    }
+
+    async fn add(&self, event_id: Uuid, sponsor: &SponsorDraft) -> Result<Sponsor, String> {
+        map_sponsor(self.api.create_sponsor(event_id, &sponsor_request(sponsor)).await?)
+    }
+
+    async fn update(&self, event_id: Uuid, id: Uuid, sponsor: &SponsorDraft) -> Result<Sponsor, String> {
+        map_sponsor(self.api.update_sponsor(event_id, id, &sponsor_request(sponsor)).await?)
+    }
+
+    async fn delete(&self, event_id: Uuid, id: Uuid) -> Result<(), String> {
+        self.api.delete_sponsor(event_id, id).await
+    }
+
+    async fn reorder(&self, event_id: Uuid, ids: Vec<Uuid>) -> Result<Vec<Sponsor>, String> {
+        map_sponsors(self.api.reorder_sponsors(event_id, ids).await?)
+    }
+}
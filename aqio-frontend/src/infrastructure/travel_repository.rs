@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::ports::{Hotel, ParkingInfo, TransportMode, TransportOption, TravelInfo, TravelRepository};
+
+use super::api_client::{
+    ApiClient, HotelResponse, ParkingInfoResponse, TransportOptionResponse, TravelInfoResponse,
+};
+
+#[derive(Clone)]
+pub struct ApiTravelRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiTravelRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+pub(super) fn map_travel(travel: TravelInfoResponse) -> Result<TravelInfo, String> {
+    let transport = travel
+        .transport
+        .into_iter()
+        .map(|option| {
+            let mode = TransportMode::parse(&option.mode)
+                .ok_or_else(|| format!("Unknown transport mode '{}'", option.mode))?;
+            Ok(TransportOption {
+                mode,
+                title: option.title,
+                details: option.details,
+                url: option.url,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(TravelInfo {
+        hotels: travel
+            .hotels
+            .into_iter()
+            .map(|hotel| Hotel {
+                name: hotel.name,
+                address: hotel.address,
+                website_url: hotel.website_url,
+                walking_minutes: hotel.walking_minutes,
+                booking_code: hotel.booking_code,
+                notes: hotel.notes,
+            })
+            .collect(),
+        transport,
+        parking: travel.parking.map(|parking| ParkingInfo {
+            address: parking.address,
+            free_of_charge: parking.free_of_charge,
+            ev_charging: parking.ev_charging,
+            notes: parking.notes,
+        }),
+    })
+}
+
+fn travel_request(travel: &TravelInfo) -> TravelInfoResponse {
+    TravelInfoResponse {
+        hotels: travel
+            .hotels
+            .iter()
+            .map(|hotel| HotelResponse {
+                name: hotel.name.clone(),
+                address: hotel.address.clone(),
+                website_url: hotel.website_url.clone(),
+                walking_minutes: hotel.walking_minutes,
+                booking_code: hotel.booking_code.clone(),
+                notes: hotel.notes.clone(),
+            })
+            .collect(),
+        transport: travel
+            .transport
+            .iter()
+            .map(|option| TransportOptionResponse {
+                mode: option.mode.as_str().to_string(),
+                title: option.title.clone(),
+                details: option.details.clone(),
+                url: option.url.clone(),
+            })
+            .collect(),
+        parking: travel.parking.as_ref().map(|parking| ParkingInfoResponse {
+            address: parking.address.clone(),
+            free_of_charge: parking.free_of_charge,
+            ev_charging: parking.ev_charging,
+            notes: parking.notes.clone(),
+        }),
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl TravelRepository for ApiTravelRepository {
+    async fn get_travel(&self, event_id: Uuid) -> Result<TravelInfo, String> {
+        map_travel(self.api.get_travel(event_id).await?)
+    }
+
+    async fn update_travel(&self, event_id: Uuid, travel: &TravelInfo) -> Result<TravelInfo, String> {
+        map_travel(self.api.update_travel(event_id, &travel_request(travel)).await?)
+    }
+}
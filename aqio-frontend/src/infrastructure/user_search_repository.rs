@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::application::ports::{UserMatch, UserSearchRepository};
+
+use super::api_client::ApiClient;
+
+#[derive(Clone)]
+pub struct ApiUserSearchRepository {
+    api: Arc<ApiClient>,
+}
+
+impl ApiUserSearchRepository {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl UserSearchRepository for ApiUserSearchRepository {
+    async fn search_users(&self, query: &str, limit: u32) -> Result<Vec<UserMatch>, String> {
+        let found = self.api.search_users(query, limit).await?;
+        Ok(found
+            .into_iter()
+            .map(|user| UserMatch {
+                id: user.id,
+                name: user.name,
+                email: user.email,
+                company_name: user.company_name,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,275 @@
+// Web vitals measured with the browser's PerformanceObserver: largest
+// contentful paint, first input delay, interaction to next paint, layout
+// shift and the duration of each request to the API. Measurements are
+// buffered and handed over every half minute and whenever the tab is hidden.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use gloo_timers::callback::Interval;
+use js_sys::{Function, Object, Reflect};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{Document, PerformanceEntry, PerformanceObserver, PerformanceObserverEntryList};
+
+use crate::application::ports::{WebVital, WebVitalMetric, WebVitalsReport, WebVitalsReporter};
+
+use super::api_client::{ApiClient, WebVitalMeasurementRequest, WebVitalsReportRequest};
+
+#[derive(Clone)]
+pub struct ApiWebVitalsReporter {
+    api: Arc<ApiClient>,
+}
+
+impl ApiWebVitalsReporter {
+    pub fn new(api: ApiClient) -> Self {
+        Self { api: Arc::new(api) }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl WebVitalsReporter for ApiWebVitalsReporter {
+    async fn report_web_vitals(&self, report: &WebVitalsReport) -> Result<(), String> {
+        let request = WebVitalsReportRequest {
+            release: &report.release,
+            route: &report.route,
+            measurements: report
+                .measurements
+                .iter()
+                .map(|&(metric, value)| WebVitalMeasurementRequest { metric, value })
+                .collect(),
+        };
+        self.api.report_web_vitals(&request).await
+    }
+}
+
+const FLUSH_INTERVAL_MS: u32 = 30_000;
+// API timings kept between flushes; a busy page drops the rest
+const MAX_BUFFERED_LATENCIES: usize = 200;
+// Interactions shorter than this can't be the slowest one worth reporting
+const EVENT_DURATION_THRESHOLD_MS: f64 = 40.0;
+
+#[derive(Default)]
+struct Buffer {
+    vitals: Vec<WebVital>,
+    latencies: usize,
+    /// Latest candidate, reported at the first flush after it appeared
+    lcp: Option<WebVital>,
+    lcp_reported: bool,
+    /// Slowest interaction since the tab was last hidden
+    inp: Option<WebVital>,
+    /// Layout shift since the tab was last hidden
+    cls: f64,
+}
+
+impl Buffer {
+    /// Everything measured since the last call; INP and layout shift only
+    /// settle when the visitor leaves the page, so they wait for `page_hidden`
+    fn take(&mut self, route: &str, page_hidden: bool) -> Vec<WebVital> {
+        if !self.lcp_reported {
+            if let Some(lcp) = self.lcp.take() {
+                self.vitals.push(lcp);
+                self.lcp_reported = true;
+            }
+        }
+        if page_hidden {
+            self.vitals.extend(self.inp.take());
+            if self.cls > 0.0 {
+                self.vitals.push(WebVital {
+                    route: route.to_string(),
+                    metric: WebVitalMetric::Cls,
+                    value: std::mem::take(&mut self.cls),
+                });
+            }
+        }
+        self.latencies = 0;
+        std::mem::take(&mut self.vitals)
+    }
+}
+
+fn current_route() -> String {
+    web_sys::window()
+        .and_then(|window| window.location().pathname().ok())
+        .unwrap_or_default()
+}
+
+fn number(entry: &PerformanceEntry, field: &str) -> f64 {
+    Reflect::get(entry, &JsValue::from_str(field))
+        .ok()
+        .and_then(|value| value.as_f64())
+        .unwrap_or_default()
+}
+
+fn flag(entry: &PerformanceEntry, field: &str) -> bool {
+    Reflect::get(entry, &JsValue::from_str(field))
+        .ok()
+        .and_then(|value| value.as_bool())
+        .unwrap_or_default()
+}
+
+fn text(entry: &PerformanceEntry, field: &str) -> String {
+    Reflect::get(entry, &JsValue::from_str(field))
+        .ok()
+        .and_then(|value| value.as_string())
+        .unwrap_or_default()
+}
+
+/// Observes the page for as long as it's kept; dropping it stops observing
+pub struct WebVitalsCollector {
+    observers: Vec<PerformanceObserver>,
+    callbacks: Vec<Closure<dyn FnMut(PerformanceObserverEntryList)>>,
+    document: Document,
+    on_hidden: Closure<dyn FnMut()>,
+    _interval: Interval,
+}
+
+impl WebVitalsCollector {
+    /// Starts observing; `on_flush` gets what was measured since its last call.
+    /// Requests to `api_base_url` are timed as API latency.
+    pub fn start(api_base_url: &str, on_flush: impl Fn(Vec<WebVital>) + 'static) -> Result<Self, String> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or("No document")?;
+        let buffer = Rc::new(RefCell::new(Buffer::default()));
+        let flush = {
+            let buffer = buffer.clone();
+            let document = document.clone();
+            Rc::new(move || {
+                let vitals = buffer.borrow_mut().take(&current_route(), document.hidden());
+                if !vitals.is_empty() {
+                    on_flush(vitals);
+                }
+            })
+        };
+
+        let on_hidden = {
+            let flush = flush.clone();
+            let document = document.clone();
+            Closure::<dyn FnMut()>::new(move || {
+                if document.hidden() {
+                    flush();
+                }
+            })
+        };
+        document
+            .add_event_listener_with_callback("visibilitychange", on_hidden.as_ref().unchecked_ref())
+            .map_err(|e| format!("Could not listen for the page being hidden: {:?}", e))?;
+        let interval = Interval::new(FLUSH_INTERVAL_MS, move || flush());
+
+        let mut collector = Self {
+            observers: Vec::new(),
+            callbacks: Vec::new(),
+            document,
+            on_hidden,
+            _interval: interval,
+        };
+
+        let lcp_buffer = buffer.clone();
+        collector.observe("largest-contentful-paint", &[], move |entry| {
+            lcp_buffer.borrow_mut().lcp = Some(WebVital {
+                route: current_route(),
+                metric: WebVitalMetric::Lcp,
+                value: entry.start_time(),
+            });
+        });
+
+        let fid_buffer = buffer.clone();
+        collector.observe("first-input", &[], move |entry| {
+            fid_buffer.borrow_mut().vitals.push(WebVital {
+                route: current_route(),
+                metric: WebVitalMetric::Fid,
+                value: number(&entry, "processingStart") - entry.start_time(),
+            });
+        });
+
+        let inp_buffer = buffer.clone();
+        let threshold = [("durationThreshold", JsValue::from_f64(EVENT_DURATION_THRESHOLD_MS))];
+        collector.observe("event", &threshold, move |entry| {
+            // Only entries that are part of a click, tap or key press count
+            if number(&entry, "interactionId") <= 0.0 {
+                return;
+            }
+            let mut buffer = inp_buffer.borrow_mut();
+            if buffer.inp.as_ref().is_some_and(|inp| inp.value >= entry.duration()) {
+                return;
+            }
+            buffer.inp = Some(WebVital {
+                route: current_route(),
+                metric: WebVitalMetric::Inp,
+                value: entry.duration(),
+            });
+        });
+
+        let cls_buffer = buffer.clone();
+        collector.observe("layout-shift", &[], move |entry| {
+            // Shifts right after the visitor's own input are expected
+            if !flag(&entry, "hadRecentInput") {
+                cls_buffer.borrow_mut().cls += number(&entry, "value");
+            }
+        });
+
+        let api_prefix = format!("{}/api/", api_base_url);
+        collector.observe("resource", &[], move |entry| {
+            if text(&entry, "initiatorType") != "fetch" || !entry.name().starts_with(&api_prefix) {
+                return;
+            }
+            let mut buffer = buffer.borrow_mut();
+            if buffer.latencies >= MAX_BUFFERED_LATENCIES {
+                return;
+            }
+            buffer.latencies += 1;
+            buffer.vitals.push(WebVital {
+                route: current_route(),
+                metric: WebVitalMetric::ApiLatency,
+                value: entry.duration(),
+            });
+        });
+
+        Ok(collector)
+    }
+
+    /// Browsers that don't report `entry_type` are skipped, leaving that metric out
+    fn observe(
+        &mut self,
+        entry_type: &str,
+        options: &[(&str, JsValue)],
+        mut on_entry: impl FnMut(PerformanceEntry) + 'static,
+    ) {
+        let callback = Closure::<dyn FnMut(PerformanceObserverEntryList)>::new(move |list: PerformanceObserverEntryList| {
+            for entry in list.get_entries().iter() {
+                on_entry(entry.unchecked_into());
+            }
+        });
+        let Ok(observer) = PerformanceObserver::new(callback.as_ref().unchecked_ref()) else {
+            return;
+        };
+
+        // Entries from before the app started are delivered too
+        let init = Object::new();
+        let _ = Reflect::set(&init, &JsValue::from_str("type"), &JsValue::from_str(entry_type));
+        let _ = Reflect::set(&init, &JsValue::from_str("buffered"), &JsValue::TRUE);
+        for (key, value) in options {
+            let _ = Reflect::set(&init, &JsValue::from_str(key), value);
+        }
+        // Called through Reflect so an unsupported type fails here rather than
+        // depending on how the bindings declare `observe`
+        let observed = Reflect::get(&observer, &JsValue::from_str("observe"))
+            .and_then(|observe| observe.dyn_into::<Function>())
+            .and_then(|observe| observe.call1(&observer, &init));
+        if observed.is_ok() {
+            self.observers.push(observer);
+            self.callbacks.push(callback);
+        }
+    }
+}
+
+impl Drop for WebVitalsCollector {
+    fn drop(&mut self) {
+        for observer in &self.observers {
+            observer.disconnect();
+        }
+        let _ = self
+            .document
+            .remove_event_listener_with_callback("visibilitychange", self.on_hidden.as_ref().unchecked_ref());
+    }
+}
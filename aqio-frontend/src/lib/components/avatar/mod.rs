@@ -0,0 +1,222 @@
+use dioxus::prelude::*;
+
+// Import the CSS for our avatar components
+const AQIO_AVATAR_CSS: Asset = asset!("/assets/aqio-avatar.css");
+
+// Background colours for initials, picked by name so a person keeps theirs
+const INITIALS_COLORS: usize = 8;
+
+/// Avatar size variants
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AvatarSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl AvatarSize {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Small => "sm",
+            Self::Medium => "md",
+            Self::Large => "lg",
+        }
+    }
+}
+
+/// Presence shown as a dot on the avatar's corner
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Presence {
+    Online,
+    Away,
+    Busy,
+    Offline,
+}
+
+impl Presence {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Online => "online",
+            Self::Away => "away",
+            Self::Busy => "busy",
+            Self::Offline => "offline",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Online => "Online",
+            Self::Away => "Away",
+            Self::Busy => "Busy",
+            Self::Offline => "Offline",
+        }
+    }
+}
+
+/// First letters of the first and last word, e.g. "Kari Nordmann" → "KN"
+fn initials(name: &str) -> String {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let letters: Vec<&str> = match words.as_slice() {
+        [] => vec![],
+        [only] => vec![only],
+        [first, .., last] => vec![first, last],
+    };
+    letters
+        .iter()
+        .filter_map(|word| word.chars().next())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+fn initials_color(name: &str) -> usize {
+    name.bytes().map(usize::from).sum::<usize>() % INITIALS_COLORS
+}
+
+/// Props for the Avatar component
+#[derive(Props, Clone, PartialEq)]
+pub struct AvatarProps {
+    /// Person's name; used for the initials and as the accessible name
+    pub name: String,
+
+    /// Photo URL; initials are shown when missing or when the image fails to load
+    #[props(default)]
+    pub src: Option<String>,
+
+    /// Avatar size
+    #[props(default)]
+    pub size: AvatarSize,
+
+    /// Presence badge, if any
+    #[props(default)]
+    pub presence: Option<Presence>,
+
+    /// Additional CSS classes to apply
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// # Avatar
+///
+/// A round photo of a person, falling back to their initials
+#[component]
+pub fn Avatar(props: AvatarProps) -> Element {
+    let mut failed = use_signal(|| None::<String>);
+    let class = format!("aqio-avatar {}", props.class.unwrap_or_default());
+    // Remember which URL failed so a new one gets its own chance
+    let src = props.src.clone().filter(|src| failed().as_ref() != Some(src));
+    let label = match props.presence {
+        Some(presence) => format!("{} ({})", props.name, presence.label()),
+        None => props.name.clone(),
+    };
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_AVATAR_CSS,
+        }
+
+        span {
+            class,
+            role: "img",
+            aria_label: "{label}",
+            title: "{props.name}",
+            "data-size": props.size.as_str(),
+            "data-color": initials_color(&props.name),
+            if let Some(src) = src {
+                img {
+                    class: "aqio-avatar-image",
+                    src: "{src}",
+                    alt: "",
+                    onerror: move |_| failed.set(Some(src.clone())),
+                }
+            } else {
+                span { class: "aqio-avatar-initials", aria_hidden: "true", {initials(&props.name)} }
+            }
+            if let Some(presence) = props.presence {
+                span { class: "aqio-avatar-presence", "data-presence": presence.as_str() }
+            }
+        }
+    }
+}
+
+/// One person in an AvatarGroup
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvatarPerson {
+    pub name: String,
+    pub src: Option<String>,
+}
+
+impl AvatarPerson {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            src: None,
+        }
+    }
+
+    pub fn with_src(mut self, src: Option<String>) -> Self {
+        self.src = src;
+        self
+    }
+}
+
+/// Props for the AvatarGroup component
+#[derive(Props, Clone, PartialEq)]
+pub struct AvatarGroupProps {
+    /// Everyone in the group
+    pub people: Vec<AvatarPerson>,
+
+    /// How many avatars to show before collapsing the rest into "+N"
+    #[props(default = 5)]
+    pub max: usize,
+
+    /// Size of every avatar in the group
+    #[props(default)]
+    pub size: AvatarSize,
+
+    /// Additional CSS classes to apply
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// # AvatarGroup
+///
+/// Overlapping avatars with a "+N" chip for everyone who doesn't fit
+#[component]
+pub fn AvatarGroup(props: AvatarGroupProps) -> Element {
+    let class = format!("aqio-avatar-group {}", props.class.unwrap_or_default());
+    let shown = props.people.len().min(props.max);
+    let hidden: Vec<&str> = props.people[shown..]
+        .iter()
+        .map(|person| person.name.as_str())
+        .collect();
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_AVATAR_CSS,
+        }
+
+        div { class,
+            role: "group",
+            aria_label: "{props.people.len()} people",
+            for (index, person) in props.people[..shown].iter().enumerate() {
+                Avatar {
+                    key: "{index}-{person.name}",
+                    name: person.name.clone(),
+                    src: person.src.clone(),
+                    size: props.size,
+                }
+            }
+            if !hidden.is_empty() {
+                span {
+                    class: "aqio-avatar aqio-avatar-overflow",
+                    "data-size": props.size.as_str(),
+                    title: hidden.join(", "),
+                    "+{hidden.len()}"
+                }
+            }
+        }
+    }
+}
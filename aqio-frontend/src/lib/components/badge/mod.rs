@@ -0,0 +1,147 @@
+use dioxus::prelude::*;
+
+// Import the CSS for our badge components
+const AQIO_BADGE_CSS: Asset = asset!("/assets/aqio-badge.css");
+
+/// Badge colour variants
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BadgeVariant {
+    #[default]
+    Neutral,
+    Primary,
+    Success,
+    Warning,
+    Error,
+    Info,
+}
+
+impl BadgeVariant {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Neutral => "neutral",
+            Self::Primary => "primary",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Info => "info",
+        }
+    }
+}
+
+/// Badge size variants
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BadgeSize {
+    Small,
+    #[default]
+    Medium,
+}
+
+impl BadgeSize {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Small => "sm",
+            Self::Medium => "md",
+        }
+    }
+}
+
+/// Props for the Badge component
+#[derive(Props, Clone, PartialEq)]
+pub struct BadgeProps {
+    /// Colour variant
+    #[props(default)]
+    pub variant: BadgeVariant,
+
+    /// Custom colour (any CSS colour) overriding the variant, e.g. a category's colour
+    #[props(default)]
+    pub color: Option<String>,
+
+    /// Badge size
+    #[props(default)]
+    pub size: BadgeSize,
+
+    /// Icon or emoji shown before the text
+    #[props(default)]
+    pub icon: Option<String>,
+
+    /// Turns the badge into a toggle chip; pair with `selected`
+    #[props(default)]
+    pub on_click: Option<EventHandler<()>>,
+
+    /// Whether a toggle chip is switched on
+    #[props(default = false)]
+    pub selected: bool,
+
+    /// Shows a remove button, e.g. for editing a list of tags
+    #[props(default)]
+    pub on_remove: Option<EventHandler<()>>,
+
+    /// Accessible name of the remove button
+    #[props(default = "Remove".to_string())]
+    pub remove_label: String,
+
+    /// Additional CSS classes to apply
+    #[props(default)]
+    pub class: Option<String>,
+
+    /// Badge text
+    pub children: Element,
+}
+
+/// # Badge
+///
+/// A small label for statuses, categories and tags.
+/// With `on_click` it becomes a toggle chip for filters, with `on_remove` a removable tag.
+#[component]
+pub fn Badge(props: BadgeProps) -> Element {
+    let class = format!("aqio-badge {}", props.class.unwrap_or_default());
+    let style = props
+        .color
+        .as_ref()
+        .map(|color| format!("--aqio-badge-color: {};", color));
+    let on_remove = props.on_remove;
+
+    let content = rsx! {
+        if let Some(icon) = props.icon.clone() {
+            span { class: "aqio-badge-icon", aria_hidden: "true", "{icon}" }
+        }
+        span { class: "aqio-badge-label", {props.children} }
+    };
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_BADGE_CSS,
+        }
+
+        span {
+            class,
+            style,
+            "data-variant": props.variant.as_str(),
+            "data-size": props.size.as_str(),
+            "data-custom-color": props.color.is_some(),
+            "data-selected": props.selected,
+            if let Some(on_click) = props.on_click {
+                button {
+                    r#type: "button",
+                    class: "aqio-badge-toggle",
+                    aria_pressed: props.selected,
+                    onclick: move |_| on_click.call(()),
+                    {content}
+                }
+            } else {
+                {content}
+            }
+            if let Some(on_remove) = on_remove {
+                button {
+                    r#type: "button",
+                    class: "aqio-badge-remove",
+                    aria_label: "{props.remove_label}",
+                    title: "{props.remove_label}",
+                    onclick: move |_| on_remove.call(()),
+                    "×"
+                }
+            }
+        }
+    }
+}
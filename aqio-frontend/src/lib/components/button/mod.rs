@@ -254,4 +254,41 @@ pub fn ButtonGroup(props: ButtonGroupProps) -> Element {
             {props.children}
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::lib::testing::TestDom;
+
+    #[derive(Clone, Default)]
+    struct Clicks(Rc<Cell<u32>>);
+
+    fn buttons() -> Element {
+        let clicks = use_context::<Clicks>();
+        let count = move |_: MouseEvent| clicks.0.set(clicks.0.get() + 1);
+        rsx! {
+            Button { variant: ButtonVariant::Danger, size: ButtonSize::Small, onclick: count.clone(), "Delete" }
+            Button { disabled: true, onclick: count.clone(), "Disabled" }
+            Button { loading: true, onclick: count, "Saving" }
+        }
+    }
+
+    #[test]
+    fn test_button_renders_variant_and_ignores_clicks_while_disabled_or_loading() {
+        let clicks = Clicks::default();
+        let mut dom = TestDom::with_context(buttons, clicks.clone());
+        let html = dom.html();
+        assert!(html.contains(r#"type="button""#));
+        assert!(html.contains(r#"data-variant="danger""#) && html.contains(r#"data-size="small""#));
+        assert_eq!(html.matches("aqio-button-spinner").count(), 1);
+
+        dom.click(0);
+        assert_eq!(clicks.0.get(), 1);
+        dom.click(1);
+        dom.click(2);
+        assert_eq!(clicks.0.get(), 1);
+    }
+}
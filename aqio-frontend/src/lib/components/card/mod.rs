@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
-use aqio_core::models::EventType;
+use aqio_core::models::{EventCategory, EventStatus, LocationType};
+
+use super::badge::{Badge, BadgeSize, BadgeVariant};
 
 #[component]
 pub fn Card(
@@ -36,7 +38,28 @@ pub struct EventCardModel {
     pub location: String,
     pub start_date: chrono::DateTime<chrono::Utc>,
     pub max_attendees: Option<u32>,
-    pub event_type: EventType,
+    /// `None` while categories are loading or when the event's category was deleted
+    pub category: Option<EventCategory>,
+    pub location_type: LocationType,
+    pub status: EventStatus,
+}
+
+fn location_badge(location_type: &LocationType) -> (&'static str, &'static str) {
+    match location_type {
+        LocationType::Physical => ("📍", "In person"),
+        LocationType::Virtual => ("💻", "Online"),
+        LocationType::Hybrid => ("🔀", "Hybrid"),
+    }
+}
+
+/// Published events need no status badge
+fn status_badge(status: &EventStatus) -> Option<(BadgeVariant, &'static str)> {
+    match status {
+        EventStatus::Draft => Some((BadgeVariant::Warning, "Draft")),
+        EventStatus::Published => None,
+        EventStatus::Cancelled => Some((BadgeVariant::Error, "Cancelled")),
+        EventStatus::Completed => Some((BadgeVariant::Neutral, "Completed")),
+    }
 }
 
 #[component] 
@@ -44,22 +67,8 @@ pub fn EventCard(
     event: EventCardModel,
     #[props(default)] on_click: EventHandler<EventCardModel>,
 ) -> Element {
-    let event_type_color = match event.event_type {
-        EventType::Conference => "blue",
-        EventType::Workshop => "green", 
-        EventType::Networking => "purple",
-        EventType::Training => "orange",
-        EventType::Other(_) => "gray",
-    };
-
-    let event_type_display = match &event.event_type {
-        EventType::Conference => "Conference",
-        EventType::Workshop => "Workshop",
-        EventType::Networking => "Networking",
-        EventType::Training => "Training", 
-        EventType::Other(s) => s,
-    };
-
+    let (location_icon, location_label) = location_badge(&event.location_type);
+    let status = status_badge(&event.status);
     let start_date = event.start_date.format("%B %d, %Y at %H:%M");
     let event_clone = event.clone();
 
@@ -71,8 +80,25 @@ pub fn EventCard(
                 class: "aqio-event-card",
             
             div { class: "aqio-event-card-header",
-                div { class: "aqio-event-card-badge aqio-event-badge-{event_type_color}",
-                    "{event_type_display}"
+                div {
+                    class: "aqio-event-card-badges",
+                    style: "display: flex; flex-wrap: wrap; gap: 0.25rem;",
+                    match &event.category {
+                        Some(category) => rsx! {
+                            Badge { color: category.color_hex.clone(), size: BadgeSize::Small,
+                                "{category.name}"
+                            }
+                        },
+                        None => rsx! {
+                            Badge { size: BadgeSize::Small, "Uncategorized" }
+                        },
+                    }
+                    Badge { size: BadgeSize::Small, icon: location_icon.to_string(),
+                        "{location_label}"
+                    }
+                    if let Some((variant, label)) = status {
+                        Badge { variant, size: BadgeSize::Small, "{label}" }
+                    }
                 }
                 div { class: "aqio-event-card-date",
                     "📅 {start_date}"
@@ -90,7 +116,7 @@ pub fn EventCard(
             
             div { class: "aqio-event-card-footer",
                 div { class: "aqio-event-card-location",
-                    "📍 {event.location}"
+                    "{location_icon} {event.location}"
                 }
                 if let Some(max) = event.max_attendees {
                     div { class: "aqio-event-card-attendees",
@@ -101,4 +127,48 @@ pub fn EventCard(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::lib::testing::{assert_snapshot, TestDom};
+
+    fn model(category: Option<EventCategory>, location_type: LocationType, status: EventStatus) -> EventCardModel {
+        EventCardModel {
+            id: Uuid::from_u128(1),
+            title: "Fish health seminar".to_string(),
+            description: "Sea lice treatments that worked this season".to_string(),
+            location: "Bergen".to_string(),
+            start_date: Utc.with_ymd_and_hms(2026, 9, 3, 9, 30, 0).unwrap(),
+            max_attendees: Some(80),
+            category,
+            location_type,
+            status,
+        }
+    }
+
+    fn cards() -> Element {
+        let conference = EventCategory {
+            id: "conference".to_string(),
+            name: "Conference".to_string(),
+            description: None,
+            color_hex: Some("#1B4D8C".to_string()),
+            icon_name: None,
+            is_active: true,
+            created_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+        };
+        rsx! {
+            EventCard { event: model(Some(conference), LocationType::Physical, EventStatus::Published) }
+            EventCard { event: EventCardModel { max_attendees: None, ..model(None, LocationType::Virtual, EventStatus::Draft) } }
+        }
+    }
+
+    #[test]
+    fn test_event_card_markup() {
+        assert_snapshot("event_card", &TestDom::new(cards).html());
+    }
+}
@@ -0,0 +1,358 @@
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dioxus::prelude::*;
+
+// Import the CSS for our disclosure components
+const AQIO_DISCLOSURE_CSS: Asset = asset!("/assets/aqio-disclosure.css");
+
+static NEXT_KEY: AtomicUsize = AtomicUsize::new(0);
+
+/// A number unique to this component instance, for ids linking controls to the panels they show
+fn use_instance_key() -> usize {
+    use_hook(|| NEXT_KEY.fetch_add(1, Ordering::Relaxed))
+}
+
+#[derive(Clone, Copy)]
+enum Move {
+    First,
+    Last,
+    Next,
+    Previous,
+}
+
+impl Move {
+    /// Arrow keys along the direction the items are laid out, plus Home and End
+    fn from_key(key: &Key, vertical: bool) -> Option<Self> {
+        match key {
+            Key::Home => Some(Self::First),
+            Key::End => Some(Self::Last),
+            Key::ArrowRight if !vertical => Some(Self::Next),
+            Key::ArrowLeft if !vertical => Some(Self::Previous),
+            Key::ArrowDown if vertical => Some(Self::Next),
+            Key::ArrowUp if vertical => Some(Self::Previous),
+            _ => None,
+        }
+    }
+
+    /// Where focus goes among `items` from `from`, wrapping around the ends
+    fn within(self, items: &[usize], from: usize) -> Option<usize> {
+        let position = items.iter().position(|&item| item == from);
+        match self {
+            Self::First => items.first().copied(),
+            Self::Last => items.last().copied(),
+            Self::Next => position.map(|p| items[(p + 1) % items.len()]),
+            Self::Previous => position.map(|p| items[(p + items.len() - 1) % items.len()]),
+        }
+    }
+}
+
+fn focus(element: Option<Rc<MountedData>>) {
+    if let Some(element) = element {
+        spawn(async move {
+            let _ = element.set_focus(true).await;
+        });
+    }
+}
+
+/// One tab of a Tabs component
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    pub id: String,
+    pub label: String,
+    pub disabled: bool,
+}
+
+impl Tab {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+/// Props for the Tabs component
+#[derive(Props, Clone, PartialEq)]
+pub struct TabsProps {
+    /// The tabs in order
+    pub tabs: Vec<Tab>,
+
+    /// Id of the selected tab
+    pub active: String,
+
+    /// Called with the id of the tab the user selects
+    pub on_change: EventHandler<String>,
+
+    /// Accessible name of the tab list
+    #[props(default)]
+    pub label: Option<String>,
+
+    /// Additional CSS classes to apply
+    #[props(default)]
+    pub class: Option<String>,
+
+    /// Content of the selected tab
+    pub children: Element,
+}
+
+/// # Tabs
+///
+/// A tab list with a single panel showing the selected tab's content.
+/// Arrow keys, Home and End move between enabled tabs and select them as they go.
+#[component]
+pub fn Tabs(props: TabsProps) -> Element {
+    let key = use_instance_key();
+    let mut buttons = use_signal(HashMap::<usize, Rc<MountedData>>::new);
+    let class = format!("aqio-tabs {}", props.class.unwrap_or_default());
+    let active = props.tabs.iter().position(|tab| tab.id == props.active);
+    let on_change = props.on_change;
+
+    let onkeydown = {
+        let tabs = props.tabs.clone();
+        move |evt: KeyboardEvent| {
+            let enabled: Vec<usize> = (0..tabs.len()).filter(|&i| !tabs[i].disabled).collect();
+            let target = Move::from_key(&evt.key(), false)
+                .and_then(|movement| movement.within(&enabled, active.unwrap_or(0)));
+            if let Some(target) = target {
+                evt.prevent_default();
+                on_change.call(tabs[target].id.clone());
+                focus(buttons.peek().get(&target).cloned());
+            }
+        }
+    };
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_DISCLOSURE_CSS,
+        }
+
+        div { class,
+            div {
+                class: "aqio-tab-list",
+                role: "tablist",
+                aria_label: props.label.clone(),
+                onkeydown,
+                for (index, tab) in props.tabs.iter().enumerate() {
+                    button {
+                        key: "{tab.id}",
+                        id: "aqio-tabs-{key}-tab-{index}",
+                        r#type: "button",
+                        class: "aqio-tab",
+                        role: "tab",
+                        aria_selected: active == Some(index),
+                        aria_controls: "aqio-tabs-{key}-panel",
+                        tabindex: if active == Some(index) { "0" } else { "-1" },
+                        disabled: tab.disabled,
+                        onmounted: move |evt| {
+                            buttons.write().insert(index, evt.data());
+                        },
+                        onclick: {
+                            let id = tab.id.clone();
+                            move |_| on_change.call(id.clone())
+                        },
+                        "{tab.label}"
+                    }
+                }
+            }
+            div {
+                id: "aqio-tabs-{key}-panel",
+                class: "aqio-tab-panel",
+                role: "tabpanel",
+                aria_labelledby: active.map(|index| format!("aqio-tabs-{key}-tab-{index}")),
+                tabindex: "0",
+                {props.children}
+            }
+        }
+    }
+}
+
+/// Props for the Disclosure component
+#[derive(Props, Clone, PartialEq)]
+pub struct DisclosureProps {
+    /// Text of the button that shows and hides the content
+    pub summary: String,
+
+    /// Whether the content starts out shown
+    #[props(default = false)]
+    pub open: bool,
+
+    /// Additional CSS classes to apply
+    #[props(default)]
+    pub class: Option<String>,
+
+    /// The content shown and hidden
+    pub children: Element,
+}
+
+/// # Disclosure
+///
+/// A button that shows and hides a section of content, such as details most readers can skip
+#[component]
+pub fn Disclosure(props: DisclosureProps) -> Element {
+    let key = use_instance_key();
+    let mut open = use_signal(|| props.open);
+    let class = format!("aqio-disclosure {}", props.class.unwrap_or_default());
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_DISCLOSURE_CSS,
+        }
+
+        div { class,
+            "data-open": open(),
+            button {
+                r#type: "button",
+                class: "aqio-disclosure-button",
+                aria_expanded: open(),
+                aria_controls: "aqio-disclosure-{key}",
+                onclick: move |_| open.toggle(),
+                span { class: "aqio-disclosure-icon", aria_hidden: "true" }
+                "{props.summary}"
+            }
+            div {
+                id: "aqio-disclosure-{key}",
+                class: "aqio-disclosure-panel",
+                hidden: !open(),
+                {props.children}
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct AccordionContext {
+    allow_multiple: bool,
+    /// Item opened most recently, which closes the others unless several may be open
+    last_opened: Signal<Option<usize>>,
+    /// Item headers in the order they were mounted, for arrow key navigation
+    headers: Signal<BTreeMap<usize, Rc<MountedData>>>,
+}
+
+/// Props for the Accordion component
+#[derive(Props, Clone, PartialEq)]
+pub struct AccordionProps {
+    /// Let several items be open at once instead of one closing the others
+    #[props(default = false)]
+    pub allow_multiple: bool,
+
+    /// Additional CSS classes to apply
+    #[props(default)]
+    pub class: Option<String>,
+
+    /// AccordionItem children
+    pub children: Element,
+}
+
+/// # Accordion
+///
+/// A stack of AccordionItem sections that expand one at a time.
+/// Up and Down arrows, Home and End move focus between the item headers.
+#[component]
+pub fn Accordion(props: AccordionProps) -> Element {
+    use_context_provider(|| AccordionContext {
+        allow_multiple: props.allow_multiple,
+        last_opened: Signal::new(None),
+        headers: Signal::new(BTreeMap::new()),
+    });
+    let class = format!("aqio-accordion {}", props.class.unwrap_or_default());
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_DISCLOSURE_CSS,
+        }
+
+        div { class, {props.children} }
+    }
+}
+
+/// Props for the AccordionItem component
+#[derive(Props, Clone, PartialEq)]
+pub struct AccordionItemProps {
+    /// Header text
+    pub title: String,
+
+    /// Whether the item starts out open
+    #[props(default = false)]
+    pub open: bool,
+
+    /// The content shown when the item is open
+    pub children: Element,
+}
+
+/// # AccordionItem
+///
+/// One expandable section of an Accordion
+#[component]
+pub fn AccordionItem(props: AccordionItemProps) -> Element {
+    let context = use_context::<AccordionContext>();
+    let key = use_instance_key();
+    let mut open = use_signal(|| props.open);
+    let mut last_opened = context.last_opened;
+    let mut headers = context.headers;
+
+    use_effect(move || {
+        let opened = last_opened();
+        if !context.allow_multiple && opened.is_some_and(|opened| opened != key) {
+            open.set(false);
+        }
+    });
+
+    use_drop(move || {
+        headers.write().remove(&key);
+    });
+
+    let onkeydown = move |evt: KeyboardEvent| {
+        let order: Vec<usize> = headers.peek().keys().copied().collect();
+        let target = Move::from_key(&evt.key(), true).and_then(|movement| movement.within(&order, key));
+        if let Some(target) = target {
+            evt.prevent_default();
+            focus(headers.peek().get(&target).cloned());
+        }
+    };
+
+    rsx! {
+        div { class: "aqio-accordion-item",
+            "data-open": open(),
+            h3 { class: "aqio-accordion-heading",
+                button {
+                    id: "aqio-accordion-{key}-header",
+                    r#type: "button",
+                    class: "aqio-disclosure-button",
+                    aria_expanded: open(),
+                    aria_controls: "aqio-accordion-{key}-panel",
+                    onmounted: move |evt| {
+                        headers.write().insert(key, evt.data());
+                    },
+                    onclick: move |_| {
+                        open.toggle();
+                        if open() {
+                            last_opened.set(Some(key));
+                        }
+                    },
+                    onkeydown,
+                    span { class: "aqio-disclosure-icon", aria_hidden: "true" }
+                    "{props.title}"
+                }
+            }
+            div {
+                id: "aqio-accordion-{key}-panel",
+                class: "aqio-disclosure-panel",
+                role: "region",
+                aria_labelledby: "aqio-accordion-{key}-header",
+                hidden: !open(),
+                {props.children}
+            }
+        }
+    }
+}
@@ -1,4 +1,139 @@
+use dioxus::prelude::*;
+
+use super::overlay::use_overlay_id;
+
+// Import the CSS for our feedback components
+const AQIO_MODAL_CSS: Asset = asset!("/assets/aqio-modal.css");
+
 // Feedback components - stubs for now
 pub struct Toast;
-pub struct Modal;
-pub struct Loading;
\ No newline at end of file
+pub struct Loading;
+
+/// Props for the Modal component
+#[derive(Props, Clone, PartialEq)]
+pub struct ModalProps {
+    /// Whether the dialog is shown
+    pub open: bool,
+
+    /// Heading of the dialog, also its accessible name
+    pub title: String,
+
+    /// Called when the close button, the backdrop or Escape asks to close
+    pub on_close: EventHandler<()>,
+
+    /// Additional CSS classes to apply to the dialog
+    #[props(default)]
+    pub class: Option<String>,
+
+    /// Content of the dialog
+    pub children: Element,
+}
+
+/// # Modal
+///
+/// A dialog over the page that takes focus when it opens.
+/// The parent owns `open`; closing only asks it to through `on_close`.
+#[component]
+pub fn Modal(props: ModalProps) -> Element {
+    let title_id = use_overlay_id("modal-title");
+    let class = format!("aqio-modal {}", props.class.unwrap_or_default());
+    let on_close = props.on_close;
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_MODAL_CSS,
+        }
+
+        if props.open {
+            div { class: "aqio-modal-backdrop", onclick: move |_| on_close.call(()),
+                div {
+                    class,
+                    role: "dialog",
+                    aria_modal: "true",
+                    aria_labelledby: "{title_id}",
+                    tabindex: "-1",
+                    onmounted: move |evt| async move {
+                        let _ = evt.data().set_focus(true).await;
+                    },
+                    // Clicks inside the dialog don't reach the backdrop
+                    onclick: move |evt| evt.stop_propagation(),
+                    onkeydown: move |evt| {
+                        if evt.key() == Key::Escape {
+                            evt.stop_propagation();
+                            on_close.call(());
+                        }
+                    },
+                    div { class: "aqio-modal-header",
+                        h2 { id: "{title_id}", class: "aqio-modal-title", "{props.title}" }
+                        button {
+                            r#type: "button",
+                            class: "aqio-modal-close",
+                            aria_label: "Close",
+                            title: "Close",
+                            onclick: move |_| on_close.call(()),
+                            "×"
+                        }
+                    }
+                    div { class: "aqio-modal-body", {props.children} }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::lib::testing::TestDom;
+
+    #[derive(Clone, Default)]
+    struct Closes(Rc<Cell<u32>>);
+
+    fn modal() -> Element {
+        let closes = use_context::<Closes>();
+        let mut open = use_signal(|| true);
+        rsx! {
+            Modal {
+                open: open(),
+                title: "Delete event".to_string(),
+                on_close: move |_| {
+                    closes.0.set(closes.0.get() + 1);
+                    open.set(false);
+                },
+                p { "The attendees are told by email." }
+            }
+        }
+    }
+
+    #[test]
+    fn test_modal_is_a_labelled_dialog_that_closes_from_button_or_backdrop() {
+        let closes = Closes::default();
+        let mut dom = TestDom::with_context(modal, closes.clone());
+        let html = dom.html();
+        assert!(html.contains(r#"role="dialog""#) && html.contains(r#"aria-modal="true""#));
+        let title_id = html
+            .split(r#"aria-labelledby=""#)
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .expect("dialog is labelled");
+        assert!(html.contains(&format!(r#"<h2 id="{}" class="aqio-modal-title">Delete event</h2>"#, title_id)));
+        assert!(html.contains(r#"aria-label="Close""#));
+
+        // Backdrop, dialog, close button; a click inside the dialog stays there
+        assert_eq!(dom.listener_count("click"), 3);
+        dom.click(1);
+        assert_eq!(closes.0.get(), 0);
+        dom.click(2);
+        assert_eq!(closes.0.get(), 1);
+        assert!(!dom.html().contains(r#"role="dialog""#));
+
+        let closes = Closes::default();
+        let mut dom = TestDom::with_context(modal, closes.clone());
+        dom.click(0);
+        assert_eq!(closes.0.get(), 1);
+    }
+}
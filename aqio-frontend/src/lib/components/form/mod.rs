@@ -1,5 +1,319 @@
-// Form components - stubs for now
-pub struct Input;
-pub struct Checkbox;
-pub struct Select;
-pub struct FormField;
\ No newline at end of file
+use dioxus::prelude::*;
+
+// Import the CSS for our form components
+const AQIO_FORM_CSS: Asset = asset!("/assets/aqio-form.css");
+
+/// Props for the FormField component
+#[derive(Props, Clone, PartialEq)]
+pub struct FormFieldProps {
+    /// Label text shown above the control
+    pub label: String,
+
+    /// `id` of the control inside, so clicking the label focuses it
+    pub id: String,
+
+    /// Help text shown below the control
+    #[props(default)]
+    pub hint: Option<String>,
+
+    /// Validation message; replaces the hint and marks the field invalid
+    #[props(default)]
+    pub error: Option<String>,
+
+    /// Shows a required marker next to the label
+    #[props(default = false)]
+    pub required: bool,
+
+    /// Additional CSS classes to apply
+    #[props(default)]
+    pub class: Option<String>,
+
+    /// The control, usually an Input or Select with the same `id`
+    pub children: Element,
+}
+
+/// # FormField
+///
+/// A labelled form control with a hint or validation message. Give the control
+/// inside the same `id` and pass `describedby` from [`field_message_id`] so
+/// screen readers read the message with it.
+#[component]
+pub fn FormField(props: FormFieldProps) -> Element {
+    let class = format!("aqio-form-field {}", props.class.unwrap_or_default());
+    let message_id = field_message_id(&props.id);
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_FORM_CSS,
+        }
+
+        div {
+            class,
+            "data-invalid": props.error.is_some(),
+            label { class: "aqio-form-label", r#for: "{props.id}",
+                "{props.label}"
+                if props.required {
+                    span { class: "aqio-form-required", aria_hidden: "true", " *" }
+                }
+            }
+            {props.children}
+            if let Some(error) = props.error {
+                p { id: "{message_id}", class: "aqio-form-error", role: "alert", "{error}" }
+            } else if let Some(hint) = props.hint {
+                p { id: "{message_id}", class: "aqio-form-hint", "{hint}" }
+            }
+        }
+    }
+}
+
+/// `id` of a FormField's hint or error, for the control's `describedby`
+pub fn field_message_id(id: &str) -> String {
+    format!("{}-message", id)
+}
+
+/// Props for the Input component
+#[derive(Props, Clone, PartialEq)]
+pub struct InputProps {
+    /// Element id, matching the FormField around it
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// HTML input type
+    #[props(default = "text".to_string())]
+    pub input_type: String,
+
+    /// Current value
+    pub value: String,
+
+    #[props(default)]
+    pub placeholder: Option<String>,
+
+    #[props(default = false)]
+    pub disabled: bool,
+
+    #[props(default = false)]
+    pub required: bool,
+
+    /// Marks the value invalid for assistive technology
+    #[props(default = false)]
+    pub invalid: bool,
+
+    /// `id` of the element describing the input
+    #[props(default)]
+    pub describedby: Option<String>,
+
+    /// Called with the new value on every keystroke
+    pub on_input: EventHandler<String>,
+}
+
+/// # Input
+///
+/// A single-line text input styled to match the design system.
+#[component]
+pub fn Input(props: InputProps) -> Element {
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_FORM_CSS,
+        }
+
+        input {
+            class: "aqio-input",
+            id: props.id,
+            r#type: "{props.input_type}",
+            value: "{props.value}",
+            placeholder: props.placeholder,
+            disabled: props.disabled,
+            required: props.required,
+            aria_invalid: props.invalid,
+            aria_describedby: props.describedby,
+            oninput: move |evt| props.on_input.call(evt.value()),
+        }
+    }
+}
+
+/// One choice in a Select
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectOption {
+    pub value: String,
+    pub label: String,
+}
+
+impl SelectOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// Props for the Select component
+#[derive(Props, Clone, PartialEq)]
+pub struct SelectProps {
+    /// Element id, matching the FormField around it
+    #[props(default)]
+    pub id: Option<String>,
+
+    /// Value of the selected option
+    pub value: String,
+
+    pub options: Vec<SelectOption>,
+
+    /// First option, with an empty value, for "no choice"
+    #[props(default)]
+    pub placeholder: Option<String>,
+
+    #[props(default = false)]
+    pub disabled: bool,
+
+    /// `id` of the element describing the select
+    #[props(default)]
+    pub describedby: Option<String>,
+
+    /// Called with the value of the option picked
+    pub on_change: EventHandler<String>,
+}
+
+/// # Select
+///
+/// A native dropdown; the placeholder option stands for an unset value.
+#[component]
+pub fn Select(props: SelectProps) -> Element {
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_FORM_CSS,
+        }
+
+        select {
+            class: "aqio-select",
+            id: props.id,
+            disabled: props.disabled,
+            aria_describedby: props.describedby,
+            onchange: move |evt| props.on_change.call(evt.value()),
+            if let Some(placeholder) = props.placeholder {
+                option { value: "", selected: props.value.is_empty(), "{placeholder}" }
+            }
+            for choice in props.options {
+                option {
+                    key: "{choice.value}",
+                    value: "{choice.value}",
+                    selected: choice.value == props.value,
+                    "{choice.label}"
+                }
+            }
+        }
+    }
+}
+
+/// Props for the Checkbox component
+#[derive(Props, Clone, PartialEq)]
+pub struct CheckboxProps {
+    /// Label text shown next to the box
+    pub label: String,
+
+    pub checked: bool,
+
+    #[props(default = false)]
+    pub disabled: bool,
+
+    /// Help text shown below the label
+    #[props(default)]
+    pub hint: Option<String>,
+
+    /// Called with the new state when toggled
+    pub on_change: EventHandler<bool>,
+}
+
+/// # Checkbox
+///
+/// A checkbox wrapped in its label, so the whole row toggles it.
+#[component]
+pub fn Checkbox(props: CheckboxProps) -> Element {
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_FORM_CSS,
+        }
+
+        label { class: "aqio-checkbox",
+            input {
+                r#type: "checkbox",
+                checked: props.checked,
+                disabled: props.disabled,
+                onchange: move |evt| props.on_change.call(evt.checked()),
+            }
+            span { class: "aqio-checkbox-text",
+                span { class: "aqio-checkbox-label", "{props.label}" }
+                if let Some(hint) = props.hint {
+                    span { class: "aqio-form-hint", "{hint}" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::lib::testing::TestDom;
+
+    #[derive(Clone, Default)]
+    struct Changes(Rc<RefCell<Vec<String>>>);
+
+    fn fields() -> Element {
+        let changes = use_context::<Changes>();
+        let mut name = use_signal(String::new);
+        let mut view = use_signal(String::new);
+        let name_error = name().is_empty().then(|| "Enter your name".to_string());
+        rsx! {
+            FormField { label: "Name".to_string(), id: "name".to_string(), required: true, error: name_error.clone(),
+                Input {
+                    id: "name".to_string(),
+                    value: name(),
+                    invalid: name_error.is_some(),
+                    describedby: field_message_id("name"),
+                    on_input: move |value: String| name.set(value),
+                }
+            }
+            FormField { label: "Calendar opens on".to_string(), id: "view".to_string(), hint: "Month when unset".to_string(),
+                Select {
+                    id: "view".to_string(),
+                    value: view(),
+                    options: vec![SelectOption::new("month", "Month"), SelectOption::new("week", "Week")],
+                    placeholder: "Default".to_string(),
+                    describedby: field_message_id("view"),
+                    on_change: move |picked: String| {
+                        changes.0.borrow_mut().push(picked.clone());
+                        view.set(picked);
+                    },
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fields_are_labelled_and_described_and_report_changes() {
+        let changes = Changes::default();
+        let mut dom = TestDom::with_context(fields, changes.clone());
+        let html = dom.html();
+        assert!(html.contains(r#"for="name""#) && html.contains(r#"for="view""#));
+        assert!(html.contains(r#"aria-describedby="name-message""#));
+        assert!(html.contains(r#"<p id="name-message" class="aqio-form-error" role="alert">Enter your name</p>"#));
+        assert!(html.contains(r#"<p id="view-message" class="aqio-form-hint">Month when unset</p>"#));
+        assert!(html.contains(r#"<option value="">Default</option>"#) || html.contains(r#"<option value="" selected"#));
+
+        dom.input(0, "Kari");
+        let html = dom.html();
+        assert!(!html.contains("Enter your name"));
+        assert!(html.contains(r#"value="Kari""#));
+
+        dom.change(0, "week");
+        assert_eq!(*changes.0.borrow(), vec!["week".to_string()]);
+    }
+}
@@ -5,12 +5,24 @@ pub mod navigation;
 pub mod layout;
 pub mod typography;
 pub mod feedback;
+pub mod print;
+pub mod disclosure;
+pub mod avatar;
+pub mod badge;
+pub mod overlay;
+pub mod progress;
 
 // Re-exports for convenience
 pub use button::Button;
 pub use card::{Card, EventCard};
-pub use form::{Input, Checkbox, Select, FormField};
+pub use form::{Input, Checkbox, Select, SelectOption, FormField, field_message_id};
 pub use navigation::{Navbar, Breadcrumb};
 pub use layout::{Container, Grid, Stack, Spacer, ContainerSize, GridColumns, StackDirection, StackAlign, StackJustify};
 pub use typography::{Text, Heading, Paragraph, TextSize, TextWeight, TextColor, HeadingLevel};
-pub use feedback::{Toast, Modal, Loading};
\ No newline at end of file
+pub use feedback::{Toast, Modal, Loading};
+pub use print::{PrintDocument, PrintSection, PrintTable, KeepTogether, PageBreak};
+pub use disclosure::{Tabs, Tab, Accordion, AccordionItem, Disclosure};
+pub use avatar::{Avatar, AvatarGroup, AvatarPerson, AvatarSize, Presence};
+pub use badge::{Badge, BadgeVariant, BadgeSize};
+pub use overlay::{Tooltip, Popover, Placement};
+pub use progress::{ProgressBar, ProgressCircle, Stepper, Step, ProgressVariant, StepperOrientation};
\ No newline at end of file
@@ -0,0 +1,328 @@
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+// Import the CSS for our overlay components
+const AQIO_OVERLAY_CSS: Asset = asset!("/assets/aqio-overlay.css");
+
+// Space between the anchor and the floating element
+const GAP: f64 = 8.0;
+// Closest the floating element may come to the edge of the window
+const VIEWPORT_MARGIN: f64 = 8.0;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn use_overlay_id(prefix: &str) -> String {
+    use_hook(|| format!("aqio-{}-{}", prefix, NEXT_ID.fetch_add(1, Ordering::Relaxed)))
+}
+
+/// Side of the anchor a floating element prefers
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Placement {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Placement {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Bottom => "bottom",
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+
+    fn opposite(&self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// A rectangle in window coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bounds {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+/// Where a floating element ends up, and on which side after flipping
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Position {
+    x: f64,
+    y: f64,
+    placement: Placement,
+}
+
+/// Places `floating` next to `anchor`: flips to the opposite side when the
+/// preferred one lacks room and the opposite has it, then shifts along the
+/// anchor so it stays inside the window.
+fn place(anchor: Bounds, floating: (f64, f64), viewport: (f64, f64), preferred: Placement) -> Position {
+    let (width, height) = floating;
+    let (viewport_width, viewport_height) = viewport;
+    let fits = |placement: Placement| match placement {
+        Placement::Top => anchor.y - GAP - height >= VIEWPORT_MARGIN,
+        Placement::Bottom => anchor.y + anchor.height + GAP + height <= viewport_height - VIEWPORT_MARGIN,
+        Placement::Left => anchor.x - GAP - width >= VIEWPORT_MARGIN,
+        Placement::Right => anchor.x + anchor.width + GAP + width <= viewport_width - VIEWPORT_MARGIN,
+    };
+    let placement = if !fits(preferred) && fits(preferred.opposite()) {
+        preferred.opposite()
+    } else {
+        preferred
+    };
+
+    let center_x = anchor.x + (anchor.width - width) / 2.0;
+    let center_y = anchor.y + (anchor.height - height) / 2.0;
+    // Floating elements larger than the window stick to its start
+    let shift = |start: f64, size: f64, limit: f64| {
+        start
+            .min(limit - size - VIEWPORT_MARGIN)
+            .max(VIEWPORT_MARGIN)
+    };
+    let (x, y) = match placement {
+        Placement::Top => (shift(center_x, width, viewport_width), anchor.y - GAP - height),
+        Placement::Bottom => (shift(center_x, width, viewport_width), anchor.y + anchor.height + GAP),
+        Placement::Left => (anchor.x - GAP - width, shift(center_y, height, viewport_height)),
+        Placement::Right => (anchor.x + anchor.width + GAP, shift(center_y, height, viewport_height)),
+    };
+    Position { x, y, placement }
+}
+
+async fn bounds(element: &MountedData) -> Option<Bounds> {
+    let rect = element.get_client_rect().await.ok()?;
+    Some(Bounds {
+        x: rect.origin.x,
+        y: rect.origin.y,
+        width: rect.size.width,
+        height: rect.size.height,
+    })
+}
+
+/// Measures both elements and the window and works out where `floating` goes
+async fn measure(anchor: Option<Rc<MountedData>>, floating: Rc<MountedData>, preferred: Placement) -> Option<Position> {
+    let anchor = bounds(&*anchor?).await?;
+    let floating = bounds(&floating).await?;
+    let window = web_sys::window()?;
+    let viewport = (
+        window.inner_width().ok()?.as_f64()?,
+        window.inner_height().ok()?.as_f64()?,
+    );
+    Some(place(anchor, (floating.width, floating.height), viewport, preferred))
+}
+
+/// Inline style for a floating element; hidden until it has been measured
+fn floating_style(position: Option<Position>) -> String {
+    match position {
+        Some(position) => format!("left: {}px; top: {}px;", position.x, position.y),
+        None => "left: 0; top: 0; visibility: hidden;".to_string(),
+    }
+}
+
+/// Props for the Tooltip component
+#[derive(Props, Clone, PartialEq)]
+pub struct TooltipProps {
+    /// What the tooltip shows; keep it free of links and buttons, it can't be reached by keyboard
+    pub content: Element,
+
+    /// Preferred side; flips when there's no room
+    #[props(default)]
+    pub placement: Placement,
+
+    /// How long the pointer has to rest on the anchor before the tooltip shows
+    #[props(default = 400)]
+    pub delay_ms: u32,
+
+    /// The element the tooltip describes
+    pub children: Element,
+}
+
+/// # Tooltip
+///
+/// A short description shown after hovering or immediately on keyboard focus.
+/// Escape, moving away or losing focus hides it.
+#[component]
+pub fn Tooltip(props: TooltipProps) -> Element {
+    let id = use_overlay_id("tooltip");
+    let mut visible = use_signal(|| false);
+    // Bumped on every enter and leave so a pending delayed show can tell it's stale
+    let mut generation = use_signal(|| 0u32);
+    let mut anchor = use_signal(|| None::<Rc<MountedData>>);
+    let mut position = use_signal(|| None::<Position>);
+    let placement = props.placement;
+    let delay_ms = props.delay_ms;
+
+    let mut show_now = move || {
+        generation += 1;
+        position.set(None);
+        visible.set(true);
+    };
+    let mut hide = move || {
+        generation += 1;
+        visible.set(false);
+    };
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_OVERLAY_CSS,
+        }
+
+        span {
+            class: "aqio-tooltip-anchor",
+            aria_describedby: if visible() { Some(id.clone()) } else { None },
+            onmounted: move |evt| anchor.set(Some(evt.data())),
+            onmouseenter: move |_| {
+                generation += 1;
+                let scheduled = *generation.peek();
+                spawn(async move {
+                    TimeoutFuture::new(delay_ms).await;
+                    if *generation.peek() == scheduled {
+                        position.set(None);
+                        visible.set(true);
+                    }
+                });
+            },
+            onmouseleave: move |_| hide(),
+            onfocusin: move |_| show_now(),
+            onfocusout: move |_| hide(),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape && visible() {
+                    hide();
+                }
+            },
+            {props.children}
+            if visible() {
+                div {
+                    id: "{id}",
+                    class: "aqio-tooltip",
+                    role: "tooltip",
+                    style: floating_style(position()),
+                    "data-placement": position().map(|position| position.placement.as_str()),
+                    onmounted: move |evt| async move {
+                        position.set(measure(anchor(), evt.data(), placement).await);
+                    },
+                    {props.content}
+                }
+            }
+        }
+    }
+}
+
+/// Props for the Popover component
+#[derive(Props, Clone, PartialEq)]
+pub struct PopoverProps {
+    /// Content of the button that opens the popover
+    pub trigger: Element,
+
+    /// Accessible name of the trigger button, when its content is only an icon
+    #[props(default)]
+    pub label: Option<String>,
+
+    /// Heading shown at the top of the popover and used as its accessible name
+    #[props(default)]
+    pub title: Option<String>,
+
+    /// Preferred side; flips when there's no room
+    #[props(default = Placement::Bottom)]
+    pub placement: Placement,
+
+    /// Additional CSS classes to apply to the popover
+    #[props(default)]
+    pub class: Option<String>,
+
+    /// Content of the popover
+    pub children: Element,
+}
+
+/// # Popover
+///
+/// A panel opened by clicking its trigger, for hints and details that may contain links.
+/// Escape or clicking elsewhere closes it and Escape returns focus to the trigger.
+#[component]
+pub fn Popover(props: PopoverProps) -> Element {
+    let id = use_overlay_id("popover");
+    let mut open = use_signal(|| false);
+    let mut trigger = use_signal(|| None::<Rc<MountedData>>);
+    let mut position = use_signal(|| None::<Position>);
+    let placement = props.placement;
+    let class = format!("aqio-popover {}", props.class.unwrap_or_default());
+
+    let mut close = move |return_focus: bool| {
+        open.set(false);
+        if return_focus {
+            if let Some(trigger) = trigger() {
+                spawn(async move {
+                    let _ = trigger.set_focus(true).await;
+                });
+            }
+        }
+    };
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_OVERLAY_CSS,
+        }
+
+        span { class: "aqio-popover-anchor",
+            button {
+                r#type: "button",
+                class: "aqio-popover-trigger",
+                aria_label: props.label.clone(),
+                aria_haspopup: "dialog",
+                aria_expanded: open(),
+                aria_controls: "{id}",
+                onmounted: move |evt| trigger.set(Some(evt.data())),
+                onclick: move |_| {
+                    position.set(None);
+                    open.toggle();
+                },
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Escape && open() {
+                        close(false);
+                    }
+                },
+                {props.trigger}
+            }
+            if open() {
+                div { class: "aqio-popover-dismiss", onclick: move |_| close(false) }
+                div {
+                    id: "{id}",
+                    class,
+                    role: "dialog",
+                    aria_label: props.title.clone(),
+                    tabindex: "-1",
+                    style: floating_style(position()),
+                    "data-placement": position().map(|position| position.placement.as_str()),
+                    onmounted: move |evt| async move {
+                        let popover = evt.data();
+                        position.set(measure(trigger(), popover.clone(), placement).await);
+                        let _ = popover.set_focus(true).await;
+                    },
+                    onkeydown: move |evt| {
+                        if evt.key() == Key::Escape {
+                            evt.stop_propagation();
+                            close(true);
+                        }
+                    },
+                    if let Some(title) = props.title.clone() {
+                        h4 { class: "aqio-popover-title", "{title}" }
+                    }
+                    {props.children}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use dioxus::prelude::*;
+
+// Import the CSS for our print components
+const AQIO_PRINT_CSS: Asset = asset!("/assets/aqio-print.css");
+
+/// Props for the PrintDocument component
+#[derive(Props, Clone, PartialEq)]
+pub struct PrintDocumentProps {
+    /// Title printed at the top of the first page
+    pub title: String,
+
+    /// Line printed under the title, e.g. date and venue
+    #[props(default)]
+    pub subtitle: Option<String>,
+
+    /// The document content
+    pub children: Element,
+}
+
+/// # PrintDocument
+///
+/// A page laid out for paper. On screen it shows a preview with a print button;
+/// when printed, the app header, footer and anything marked `aqio-no-print` are hidden
+#[component]
+pub fn PrintDocument(props: PrintDocumentProps) -> Element {
+    let print = move |_| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.print();
+        }
+    };
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_PRINT_CSS,
+        }
+
+        div { class: "aqio-print-toolbar aqio-no-print",
+            button { r#type: "button", class: "aqio-print-button", onclick: print, "Print" }
+            span { "Check the preview below, then print or save as PDF." }
+        }
+
+        article { class: "aqio-print-document",
+            header { class: "aqio-print-header",
+                h1 { "{props.title}" }
+                if let Some(subtitle) = props.subtitle {
+                    p { class: "aqio-print-subtitle", "{subtitle}" }
+                }
+            }
+            {props.children}
+        }
+    }
+}
+
+/// Props for the PrintSection component
+#[derive(Props, Clone, PartialEq)]
+pub struct PrintSectionProps {
+    /// Heading kept on the same page as the start of the section
+    #[props(default)]
+    pub title: Option<String>,
+
+    /// Start the section on a new page
+    #[props(default = false)]
+    pub page_break_before: bool,
+
+    /// The section content
+    pub children: Element,
+}
+
+/// # PrintSection
+///
+/// A titled part of a printed document whose heading never ends up alone at the bottom of a page
+#[component]
+pub fn PrintSection(props: PrintSectionProps) -> Element {
+    rsx! {
+        section {
+            class: "aqio-print-section",
+            "data-break-before": props.page_break_before,
+            if let Some(title) = props.title {
+                h2 { class: "aqio-print-section-title", "{title}" }
+            }
+            {props.children}
+        }
+    }
+}
+
+/// Props for the PrintTable component
+#[derive(Props, Clone, PartialEq)]
+pub struct PrintTableProps {
+    /// Column headings, repeated at the top of every printed page
+    pub columns: Vec<String>,
+
+    /// Table rows (`tr` elements); a row is never split across pages
+    pub children: Element,
+}
+
+/// # PrintTable
+///
+/// A table that breaks between rows and repeats its header on each page
+#[component]
+pub fn PrintTable(props: PrintTableProps) -> Element {
+    rsx! {
+        table { class: "aqio-print-table",
+            thead {
+                tr {
+                    for column in props.columns {
+                        th { "{column}" }
+                    }
+                }
+            }
+            tbody { {props.children} }
+        }
+    }
+}
+
+/// # KeepTogether
+///
+/// Content moved to the next page as a whole rather than split, e.g. a speaker bio
+#[component]
+pub fn KeepTogether(children: Element) -> Element {
+    rsx! {
+        div { class: "aqio-print-keep-together", {children} }
+    }
+}
+
+/// # PageBreak
+///
+/// Forces the following content onto a new printed page; invisible on screen
+#[component]
+pub fn PageBreak() -> Element {
+    rsx! {
+        div { class: "aqio-print-page-break" }
+    }
+}
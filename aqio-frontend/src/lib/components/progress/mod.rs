@@ -0,0 +1,295 @@
+use dioxus::prelude::*;
+
+// Import the CSS for our progress components
+const AQIO_PROGRESS_CSS: Asset = asset!("/assets/aqio-progress.css");
+
+/// Colour variants for progress indicators
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ProgressVariant {
+    #[default]
+    Primary,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ProgressVariant {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Share of `max` that `value` represents, as a percentage clamped to 0–100
+fn percent(value: f64, max: f64) -> f64 {
+    if max <= 0.0 {
+        return 0.0;
+    }
+    (value / max * 100.0).clamp(0.0, 100.0)
+}
+
+/// Props for the ProgressBar component
+#[derive(Props, Clone, PartialEq)]
+pub struct ProgressBarProps {
+    /// Current value; `None` shows an indeterminate animation
+    #[props(default)]
+    pub value: Option<f64>,
+
+    /// Value that counts as complete
+    #[props(default = 100.0)]
+    pub max: f64,
+
+    /// Text shown above the bar and used as the accessible name
+    #[props(default)]
+    pub label: Option<String>,
+
+    /// Show the percentage next to the label
+    #[props(default = false)]
+    pub show_value: bool,
+
+    /// Colour of the filled part
+    #[props(default)]
+    pub variant: ProgressVariant,
+
+    /// Additional CSS classes to apply
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// # ProgressBar
+///
+/// A horizontal bar for upload progress, capacity and other measurable progress
+#[component]
+pub fn ProgressBar(props: ProgressBarProps) -> Element {
+    let class = format!("aqio-progress-bar {}", props.class.unwrap_or_default());
+    let filled = props.value.map(|value| percent(value, props.max));
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_PROGRESS_CSS,
+        }
+
+        div {
+            class,
+            "data-variant": props.variant.as_str(),
+            "data-indeterminate": filled.is_none(),
+            if props.label.is_some() || (props.show_value && filled.is_some()) {
+                div { class: "aqio-progress-bar-label",
+                    span { {props.label.clone().unwrap_or_default()} }
+                    if let (true, Some(filled)) = (props.show_value, filled) {
+                        span { {format!("{:.0}%", filled)} }
+                    }
+                }
+            }
+            div {
+                class: "aqio-progress-bar-track",
+                role: "progressbar",
+                aria_label: props.label.clone(),
+                aria_valuemin: "0",
+                aria_valuemax: "{props.max}",
+                aria_valuenow: props.value.map(|value| value.to_string()),
+                div {
+                    class: "aqio-progress-bar-fill",
+                    style: filled.map(|filled| format!("width: {}%;", filled)),
+                }
+            }
+        }
+    }
+}
+
+/// Props for the ProgressCircle component
+#[derive(Props, Clone, PartialEq)]
+pub struct ProgressCircleProps {
+    /// Current value; `None` shows a spinning arc
+    #[props(default)]
+    pub value: Option<f64>,
+
+    /// Value that counts as complete
+    #[props(default = 100.0)]
+    pub max: f64,
+
+    /// Diameter in pixels
+    #[props(default = 48.0)]
+    pub size: f64,
+
+    /// Ring thickness in pixels
+    #[props(default = 4.0)]
+    pub thickness: f64,
+
+    /// Accessible name, also shown in the middle instead of the percentage
+    #[props(default)]
+    pub label: Option<String>,
+
+    /// Colour of the filled arc
+    #[props(default)]
+    pub variant: ProgressVariant,
+}
+
+/// # ProgressCircle
+///
+/// A ring-shaped progress indicator for compact spaces such as event cards
+#[component]
+pub fn ProgressCircle(props: ProgressCircleProps) -> Element {
+    let radius = (props.size - props.thickness) / 2.0;
+    let circumference = 2.0 * std::f64::consts::PI * radius;
+    let filled = props.value.map(|value| percent(value, props.max));
+    // Indeterminate circles show a quarter arc that spins
+    let offset = circumference * (1.0 - filled.unwrap_or(25.0) / 100.0);
+    let center = props.size / 2.0;
+    let text = props
+        .label
+        .clone()
+        .or_else(|| filled.map(|filled| format!("{:.0}%", filled)));
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_PROGRESS_CSS,
+        }
+
+        div {
+            class: "aqio-progress-circle",
+            "data-variant": props.variant.as_str(),
+            "data-indeterminate": filled.is_none(),
+            style: "width: {props.size}px; height: {props.size}px;",
+            role: "progressbar",
+            aria_label: props.label.clone(),
+            aria_valuemin: "0",
+            aria_valuemax: "{props.max}",
+            aria_valuenow: props.value.map(|value| value.to_string()),
+            svg {
+                width: "{props.size}",
+                height: "{props.size}",
+                view_box: "0 0 {props.size} {props.size}",
+                circle {
+                    class: "aqio-progress-circle-track",
+                    cx: "{center}",
+                    cy: "{center}",
+                    r: "{radius}",
+                    stroke_width: "{props.thickness}",
+                    fill: "none",
+                }
+                circle {
+                    class: "aqio-progress-circle-fill",
+                    cx: "{center}",
+                    cy: "{center}",
+                    r: "{radius}",
+                    stroke_width: "{props.thickness}",
+                    stroke_dasharray: "{circumference}",
+                    stroke_dashoffset: "{offset}",
+                    fill: "none",
+                    transform: "rotate(-90 {center} {center})",
+                }
+            }
+            if let Some(text) = text {
+                span { class: "aqio-progress-circle-text", "{text}" }
+            }
+        }
+    }
+}
+
+/// Stepper orientation variants
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum StepperOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl StepperOrientation {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+}
+
+/// One step of a Stepper
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub label: String,
+    pub description: Option<String>,
+}
+
+impl Step {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Props for the Stepper component
+#[derive(Props, Clone, PartialEq)]
+pub struct StepperProps {
+    /// The steps in order
+    pub steps: Vec<Step>,
+
+    /// Index of the step in progress; steps before it are shown as done
+    pub current: usize,
+
+    /// Lay the steps out in a row or a column
+    #[props(default)]
+    pub orientation: StepperOrientation,
+
+    /// Called with the index of a completed step when it's clicked, to go back to it
+    #[props(default)]
+    pub on_select: Option<EventHandler<usize>>,
+}
+
+/// # Stepper
+///
+/// Shows where the user is in a multi-step form
+#[component]
+pub fn Stepper(props: StepperProps) -> Element {
+    let on_select = props.on_select;
+
+    rsx! {
+        document::Link {
+            rel: "stylesheet",
+            href: AQIO_PROGRESS_CSS,
+        }
+
+        ol {
+            class: "aqio-stepper",
+            "data-orientation": props.orientation.as_str(),
+            for (index, step) in props.steps.iter().enumerate() {
+                li {
+                    key: "{index}",
+                    class: "aqio-step",
+                    "data-state": if index < props.current { "complete" } else if index == props.current { "current" } else { "upcoming" },
+                    aria_current: if index == props.current { "step" },
+                    button {
+                        r#type: "button",
+                        class: "aqio-step-marker",
+                        disabled: index >= props.current || on_select.is_none(),
+                        onclick: move |_| {
+                            if let Some(handler) = on_select {
+                                handler.call(index);
+                            }
+                        },
+                        if index < props.current { "✓" } else { "{index + 1}" }
+                    }
+                    div { class: "aqio-step-text",
+                        span { class: "aqio-step-label", "{step.label}" }
+                        if let Some(description) = &step.description {
+                            span { class: "aqio-step-description", "{description}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
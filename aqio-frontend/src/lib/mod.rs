@@ -1,6 +1,8 @@
 pub mod icons;
 pub mod theme;
 pub mod components;
+#[cfg(test)]
+pub mod testing;
 
 pub use icons::AqioIcon;
 pub use theme::{AqioTheme, Theme, ThemeProvider};
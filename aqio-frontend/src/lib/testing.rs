@@ -0,0 +1,160 @@
+// Test harness for components: renders a component tree to HTML without a
+// browser, fires DOM events at its listeners, and compares markup with
+// snapshots kept in `snapshots/`.
+//
+// Listeners are found by event name, counted in the order the elements were
+// created, which for a first render is document order: `click(0)` clicks the
+// first element with an `onclick`.
+//
+// A missing snapshot fails the test, so a fresh checkout can't pass by recording
+// its own expectations. Run with `UPDATE_SNAPSHOTS=1` to record new snapshots or
+// record all of them again after an intended markup change, then review and
+// commit the files.
+
+use std::any::Any;
+use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use dioxus::dioxus_core::{ElementId, Event, Mutation, Mutations};
+use dioxus::history::{History, MemoryHistory};
+use dioxus::prelude::*;
+use dioxus_html::{
+    set_event_converter, PlatformEventData, SerializedFormData, SerializedHtmlEventConverter, SerializedMouseData,
+};
+
+pub struct TestDom {
+    dom: VirtualDom,
+    // Event name and element of every live listener, in creation order
+    listeners: Vec<(String, ElementId)>,
+}
+
+impl TestDom {
+    pub fn new(root: fn() -> Element) -> Self {
+        Self::start(VirtualDom::new(root))
+    }
+
+    /// For roots that read something from context, e.g. a log of the calls
+    /// a component's handlers made
+    pub fn with_context<T: Clone + 'static>(root: fn() -> Element, context: T) -> Self {
+        Self::start(VirtualDom::new(root).with_root_context(context))
+    }
+
+    /// For components with router links; `root` renders on the `/` route of
+    /// an in-memory history
+    pub fn with_router(root: fn() -> Element) -> Self {
+        let dom = VirtualDom::new(RoutedRoot)
+            .with_root_context(TestBody(root))
+            .with_root_context(Rc::new(MemoryHistory::default()) as Rc<dyn History>);
+        Self::start(dom)
+    }
+
+    fn start(mut dom: VirtualDom) -> Self {
+        set_event_converter(Box::new(SerializedHtmlEventConverter));
+        let mutations = dom.rebuild_to_vec();
+        let mut test_dom = Self { dom, listeners: Vec::new() };
+        test_dom.track(mutations);
+        test_dom
+    }
+
+    pub fn html(&self) -> String {
+        dioxus_ssr::render(&self.dom)
+    }
+
+    /// Clicks the `nth` element listening for clicks
+    pub fn click(&mut self, nth: usize) {
+        self.fire("click", nth, SerializedMouseData::default());
+    }
+
+    /// Types `value` into the `nth` element listening for input
+    pub fn input(&mut self, nth: usize, value: &str) {
+        self.fire("input", nth, SerializedFormData::new(value.to_string(), Default::default()));
+    }
+
+    /// Picks `value` in the `nth` element listening for changes
+    pub fn change(&mut self, nth: usize, value: &str) {
+        self.fire("change", nth, SerializedFormData::new(value.to_string(), Default::default()));
+    }
+
+    /// Number of elements listening for `name`, e.g. `click`
+    pub fn listener_count(&self, name: &str) -> usize {
+        self.listeners.iter().filter(|(listener, _)| listener == name).count()
+    }
+
+    fn fire(&mut self, name: &str, nth: usize, data: impl Any) {
+        let element = self
+            .listeners
+            .iter()
+            .filter(|(listener, _)| listener == name)
+            .nth(nth)
+            .map(|(_, element)| *element)
+            .unwrap_or_else(|| panic!("no element number {} listens for {}", nth, name));
+        let data = Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>;
+        self.dom.runtime().handle_event(name, Event::new(data, true), element);
+        let mutations = self.dom.render_immediate_to_vec();
+        self.track(mutations);
+    }
+
+    fn track(&mut self, mutations: Mutations) {
+        for edit in mutations.edits {
+            match edit {
+                Mutation::NewEventListener { name, id } => self.listeners.push((name, id)),
+                Mutation::RemoveEventListener { name, id } => {
+                    self.listeners.retain(|listener| *listener != (name.clone(), id))
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TestBody(fn() -> Element);
+
+#[derive(Clone, Routable, PartialEq)]
+enum TestRoute {
+    #[route("/")]
+    Body {},
+}
+
+#[component]
+fn RoutedRoot() -> Element {
+    rsx! { Router::<TestRoute> {} }
+}
+
+#[component]
+fn Body() -> Element {
+    let body = use_context::<TestBody>();
+    (body.0)()
+}
+
+/// Compares `html` with the snapshot called `name`; `UPDATE_SNAPSHOTS=1` records it instead
+pub fn assert_snapshot(name: &str, html: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{}.html", name));
+    // One tag per line keeps the diff of a changed snapshot readable
+    let actual = format!("{}\n", html.replace("><", ">\n<"));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().expect("snapshot directory")).expect("create snapshot directory");
+        fs::write(&path, actual).expect("write snapshot");
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "no snapshot {}; run with UPDATE_SNAPSHOTS=1 to record it\n--- rendered\n{}",
+            path.display(),
+            actual
+        )
+    });
+    assert!(
+        expected == actual,
+        "markup of {} changed; run with UPDATE_SNAPSHOTS=1 if that's intended\n--- {}\n{}\n--- rendered\n{}",
+        name,
+        path.display(),
+        expected,
+        actual
+    );
+}
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use std::rc::Rc;
 use std::sync::Arc;
 
 mod application;
@@ -7,49 +8,232 @@ mod infrastructure;
 mod lib;
 mod presentation;
 
-use application::services::EventService;
-use infrastructure::{api_client::ApiClient, event_repository::ApiEventRepository};
+use application::ports::{ClientConfig, Theme, UserSettings};
+use application::services::{
+    AdminService, AttachmentService, BudgetService, CachePolicy, CateringService, CertificateService, CheckInService, CompanyDirectoryService, ConfigService, DiscussionService, ErrorReportingService,
+    EventFieldService, EventStore, InvitationService, LiveSessionService, LoggingService, MessagingService, NetworkingService, PastEventService, PhotoService, PublicEventService, RegistrationService, SeatingService, SettingsService, SponsorService,
+    TravelService, UserSearchService, WebVitalsService,
+};
+use infrastructure::{
+    admin_repository::ApiAdminRepository, api_client::{ApiClient, RetryPolicy},
+    attachment_repository::ApiAttachmentRepository, budget_repository::ApiBudgetRepository,
+    catering_repository::ApiCateringRepository,
+    certificate_repository::ApiCertificateRepository,
+    check_in_repository::ApiCheckInRepository, check_in_store::LocalCheckInStore,
+    comment_repository::ApiCommentRepository, company_repository::ApiCompanyRepository,
+    config_repository::ApiConfigRepository,
+    error_reporter::ApiErrorReporter, event_field_repository::ApiEventFieldRepository,
+    event_repository::ApiEventRepository,
+    invitation_repository::ApiInvitationRepository, live_session_repository::ApiLiveSessionRepository,
+    logging::{self, ApiLogSink},
+    message_repository::ApiMessageRepository, networking_repository::ApiNetworkingRepository, past_event_repository::ApiPastEventRepository,
+    photo_repository::ApiPhotoRepository,
+    public_event_repository::ApiPublicEventRepository,
+    registration_repository::ApiRegistrationRepository, seating_repository::ApiSeatingRepository,
+    session::stored_auth_token, settings_repository::ApiSettingsRepository, sponsor_repository::ApiSponsorRepository,
+    travel_repository::ApiTravelRepository, user_search_repository::ApiUserSearchRepository,
+    web_vitals::{ApiWebVitalsReporter, WebVitalsCollector},
+};
 use lib::theme::{AqioTheme, ThemeProvider};
 
 #[derive(Clone)]
 pub struct AppContainer {
-    pub events: EventService,
+    pub events: EventStore,
+    pub admin: AdminService,
+    pub invitations: InvitationService,
+    pub check_in: CheckInService,
+    pub registrations: RegistrationService,
+    pub public_events: PublicEventService,
+    pub companies: CompanyDirectoryService,
+    pub discussion: DiscussionService,
+    pub live: LiveSessionService,
+    pub attachments: AttachmentService,
+    pub photos: PhotoService,
+    pub event_fields: EventFieldService,
+    pub certificates: CertificateService,
+    pub budgets: BudgetService,
+    pub sponsors: SponsorService,
+    pub travel: TravelService,
+    pub catering: CateringService,
+    pub seating: SeatingService,
+    pub past_events: PastEventService,
+    pub errors: ErrorReportingService,
+    pub config: ConfigService,
+    pub users: UserSearchService,
+    pub messages: MessagingService,
+    pub networking: NetworkingService,
+    pub settings: SettingsService,
 }
 
 impl PartialEq for AppContainer {
     fn eq(&self, other: &Self) -> bool {
-        // Services are cheap to clone and stateless wrappers over Arc repos; compare addr
+        // Services are cheap to clone (Arc repos, the event store shares its cache); compare addr
         std::ptr::eq(self as *const _, other as *const _)
     }
 }
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
 const MAIN_CSS: Asset = asset!("/assets/main.css");
+const LOG_SHIPPING_INTERVAL_MS: u32 = 15_000;
 
 fn main() {
     // Initialize panic hook for better error messages in WASM
     #[cfg(target_arch = "wasm32")]
     console_error_panic_hook::set_once();
 
+    logging::init();
+
     dioxus::launch(app);
 }
 
 #[component]
 fn app() -> Element {
     // Composition root: wire ports -> services -> UI
-    let api = ApiClient::new();
-    let repo = Arc::new(ApiEventRepository::new(api));
-    let events = EventService::new(repo);
-    let container = AppContainer { events };
+    let api = match stored_auth_token() {
+        Some(token) => ApiClient::new().with_auth_token(token),
+        None => ApiClient::new(),
+    }
+    .with_retry_policy(RetryPolicy { retries: 2, backoff_ms: 300 });
+    // Returning to a page shows its events at once; they're refreshed in the
+    // background once older than half a minute
+    let events = EventStore::new(
+        Arc::new(ApiEventRepository::new(api.clone())),
+        CachePolicy {
+            fresh_for: chrono::Duration::seconds(30),
+            stale_for: chrono::Duration::minutes(10),
+        },
+    );
+    let admin = AdminService::new(Arc::new(ApiAdminRepository::new(api.clone())));
+    let invitations = InvitationService::new(Arc::new(ApiInvitationRepository::new(api.clone())));
+    let registrations = RegistrationService::new(Arc::new(ApiRegistrationRepository::new(api.clone())));
+    let public_events = PublicEventService::new(Arc::new(ApiPublicEventRepository::new(api.clone())));
+    let companies = CompanyDirectoryService::new(Arc::new(ApiCompanyRepository::new(api.clone())));
+    let discussion = DiscussionService::new(Arc::new(ApiCommentRepository::new(api.clone())));
+    let live = LiveSessionService::new(Arc::new(ApiLiveSessionRepository::new(api.clone())));
+    let attachments = AttachmentService::new(Arc::new(ApiAttachmentRepository::new(api.clone())));
+    let photos = PhotoService::new(Arc::new(ApiPhotoRepository::new(api.clone())));
+    let event_fields = EventFieldService::new(Arc::new(ApiEventFieldRepository::new(api.clone())));
+    let certificates = CertificateService::new(Arc::new(ApiCertificateRepository::new(api.clone())));
+    let budgets = BudgetService::new(Arc::new(ApiBudgetRepository::new(api.clone())));
+    let sponsors = SponsorService::new(Arc::new(ApiSponsorRepository::new(api.clone())));
+    let travel = TravelService::new(Arc::new(ApiTravelRepository::new(api.clone())));
+    let catering = CateringService::new(Arc::new(ApiCateringRepository::new(api.clone())));
+    let seating = SeatingService::new(Arc::new(ApiSeatingRepository::new(api.clone())));
+    let past_events = PastEventService::new(Arc::new(ApiPastEventRepository::new(api.clone())));
+    let errors = ErrorReportingService::new(Arc::new(ApiErrorReporter::new(api.clone())));
+    let config = ConfigService::new(Arc::new(ApiConfigRepository::new(api.clone())));
+    let users = UserSearchService::new(Arc::new(ApiUserSearchRepository::new(api.clone())));
+    let messages = MessagingService::new(Arc::new(ApiMessageRepository::new(api.clone())));
+    let networking = NetworkingService::new(Arc::new(ApiNetworkingRepository::new(api.clone())));
+    let settings = SettingsService::new(Arc::new(ApiSettingsRepository::new(api.clone())));
+    let web_vitals = WebVitalsService::new(Arc::new(ApiWebVitalsReporter::new(api.clone())));
+    let logs = LoggingService::new(Arc::new(ApiLogSink::new(api.clone())));
+    let api_base_url = api.base_url().to_string();
+    let check_in = CheckInService::new(
+        Arc::new(ApiCheckInRepository::new(api)),
+        Arc::new(LocalCheckInStore),
+    );
+    let container = AppContainer {
+        events,
+        admin,
+        invitations,
+        check_in,
+        registrations,
+        public_events,
+        companies,
+        discussion,
+        live,
+        attachments,
+        photos,
+        event_fields,
+        certificates,
+        budgets,
+        sponsors,
+        travel,
+        catering,
+        seating,
+        past_events,
+        errors,
+        config,
+        users,
+        messages,
+        networking,
+        settings,
+    };
 
     // Provide DI container to the component tree
     use_context_provider(|| container.clone());
 
+    // Server-driven settings; the built-in defaults apply until the API answers
+    let mut client_config = use_context_provider(|| Signal::new(ClientConfig::default()));
+    let config = container.config.clone();
+    use_future(move || {
+        let config = config.clone();
+        async move {
+            let loaded = config.load().await;
+            logging::set_remote_level(loaded.remote_log_level);
+            client_config.set(loaded);
+        }
+    });
+
+    // The signed-in user's preferences, applied across the app and updated by
+    // the settings page; `None` until loaded and for visitors
+    let mut user_settings = use_context_provider(|| Signal::new(None::<UserSettings>));
+    let settings = container.settings.clone();
+    let signed_in = stored_auth_token().is_some();
+    use_future(move || {
+        let settings = settings.clone();
+        async move {
+            if !signed_in {
+                return;
+            }
+            match settings.load().await {
+                Ok(loaded) => user_settings.set(Some(loaded)),
+                Err(e) => log::warn!("Failed to load user settings: {}", e),
+            }
+        }
+    });
+    let theme = match user_settings.read().as_ref().and_then(|settings| settings.theme) {
+        Some(Theme::Light) => AqioTheme::Light,
+        Some(Theme::Dark) => AqioTheme::Dark,
+        None => AqioTheme::Auto,
+    };
+
+    // Ship buffered log lines while the configuration asks for them
+    use_future(move || {
+        let logs = logs.clone();
+        async move {
+            loop {
+                gloo_timers::future::TimeoutFuture::new(LOG_SHIPPING_INTERVAL_MS).await;
+                let entries = logging::take_remote_entries();
+                if entries.is_empty() {
+                    continue;
+                }
+                if let Err(e) = logs.ship(entries).await {
+                    log::warn!(target: logging::LOGGING_TARGET, "Failed to ship log lines: {}", e);
+                }
+            }
+        }
+    });
+
+    // Measure this browser's web vitals for as long as the app runs
+    use_hook(move || {
+        let collector = WebVitalsCollector::start(&api_base_url, move |vitals| {
+            let web_vitals = web_vitals.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Err(e) = web_vitals.report(vitals).await {
+                    log::warn!("Failed to report web vitals: {}", e);
+                }
+            });
+        });
+        Rc::new(collector.map_err(|e| log::warn!("{}", e)).ok())
+    });
+
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
         document::Link { rel: "stylesheet", href: MAIN_CSS }
 
-        ThemeProvider { theme: AqioTheme::Auto,
+        ThemeProvider { theme,
             presentation::routes::Root {}
         }
     }
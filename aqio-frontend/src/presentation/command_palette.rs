@@ -0,0 +1,283 @@
+// Ctrl+K command palette: jump to any page, find events by title and run
+// actions without reaching for the mouse. Events are looked up through the
+// events endpoint's title search as the user types.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use uuid::Uuid;
+
+use crate::application::attendee_csv::attendees_csv;
+use crate::application::command_palette::rank;
+use crate::infrastructure::download::save_file;
+use crate::infrastructure::keyboard_shortcut::KeyboardShortcut;
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+const COMMAND_PALETTE_CSS: Asset = asset!("/assets/command_palette.css");
+
+// Wait for a pause in typing before searching
+const SEARCH_DEBOUNCE_MS: u32 = 200;
+const MIN_SEARCH_CHARS: usize = 2;
+
+#[derive(Clone, PartialEq)]
+enum CommandKind {
+    Go(Route),
+    ExportRegistrations(Uuid),
+}
+
+#[derive(Clone, PartialEq)]
+struct Command {
+    section: &'static str,
+    label: String,
+    kind: CommandKind,
+}
+
+impl Command {
+    fn go(section: &'static str, label: impl Into<String>, route: Route) -> Self {
+        Self {
+            section,
+            label: label.into(),
+            kind: CommandKind::Go(route),
+        }
+    }
+}
+
+/// The event the current page is about, if any
+fn current_event(route: &Route) -> Option<Uuid> {
+    match route {
+        Route::EventDetail { event_id }
+        | Route::EventInvitations { event_id }
+        | Route::EventCheckIn { event_id }
+        | Route::EventBudget { event_id }
+        | Route::EventSponsors { event_id }
+        | Route::EventTravel { event_id }
+        | Route::EventCatering { event_id }
+        | Route::EventSeating { event_id }
+        | Route::PrintAttendees { event_id }
+        | Route::PrintProgram { event_id } => Some(*event_id),
+        _ => None,
+    }
+}
+
+/// Pages and actions, with those for the event on screen listed first
+fn commands(route: &Route) -> Vec<Command> {
+    let mut commands = Vec::new();
+    if let Some(event_id) = current_event(route) {
+        commands.extend([
+            Command {
+                section: "This event",
+                label: "Export registrations (CSV)".to_string(),
+                kind: CommandKind::ExportRegistrations(event_id),
+            },
+            Command::go("This event", "Check-in", Route::EventCheckIn { event_id }),
+            Command::go("This event", "Invitations", Route::EventInvitations { event_id }),
+            Command::go("This event", "Budget", Route::EventBudget { event_id }),
+            Command::go("This event", "Sponsors", Route::EventSponsors { event_id }),
+            Command::go("This event", "Travel & accommodation", Route::EventTravel { event_id }),
+            Command::go("This event", "Catering report", Route::EventCatering { event_id }),
+            Command::go("This event", "Seating plan", Route::EventSeating { event_id }),
+            Command::go("This event", "Print attendee list", Route::PrintAttendees { event_id }),
+            Command::go("This event", "Print program", Route::PrintProgram { event_id }),
+            Command::go("This event", "Event details", Route::EventDetail { event_id }),
+        ]);
+    }
+    commands.extend([
+        Command::go("Actions", "Create event", Route::NewEvent {}),
+        Command::go("Go to", "Events", Route::Events {}),
+        Command::go("Go to", "Events calendar", Route::EventsCalendar {}),
+        Command::go("Go to", "Events agenda", Route::EventsAgenda {}),
+        Command::go("Go to", "Events map", Route::EventsMap {}),
+        Command::go("Go to", "Event archive", Route::PastEvents {}),
+        Command::go("Go to", "Companies", Route::Companies {}),
+        Command::go("Go to", "Messages", Route::Messages {}),
+        Command::go("Go to", "Settings", Route::Settings {}),
+        Command::go("Go to", "Admin: Users", Route::AdminUsers {}),
+        Command::go("Go to", "Admin: Categories", Route::AdminCategories {}),
+        Command::go("Go to", "Admin: Audit log", Route::AdminAuditLog {}),
+        Command::go("Go to", "Admin: Performance", Route::AdminPerformance {}),
+        Command::go("Go to", "Home", Route::Home {}),
+    ]);
+    commands
+}
+
+#[component]
+pub fn CommandPalette(container: AppContainer, route: Route) -> Element {
+    let mut open = use_signal(|| false);
+
+    // Removed again when the palette unmounts and the hook is dropped
+    use_hook(|| {
+        let shortcut = KeyboardShortcut::ctrl("k", move || {
+            let was_open = *open.peek();
+            open.set(!was_open);
+        });
+        if let Err(e) = &shortcut {
+            log::warn!("{}", e);
+        }
+        Rc::new(shortcut.ok())
+    });
+
+    rsx! {
+        if open() {
+            PaletteDialog { container, route, on_close: move |_| open.set(false) }
+        }
+    }
+}
+
+/// Mounted each time the palette opens, so it starts empty and focused
+#[component]
+fn PaletteDialog(container: AppContainer, route: Route, on_close: EventHandler<()>) -> Element {
+    let mut query = use_signal(String::new);
+    let mut selected = use_signal(|| 0usize);
+    let mut status = use_signal(|| None::<String>);
+    let mut busy = use_signal(|| false);
+
+    let found = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            let text = query();
+            async move {
+                if text.trim().chars().count() < MIN_SEARCH_CHARS {
+                    return Ok(Vec::new());
+                }
+                // A newer keystroke restarts the resource and drops this wait
+                TimeoutFuture::new(SEARCH_DEBOUNCE_MS).await;
+                svc.search(&text).await
+            }
+        }
+    });
+
+    let all = commands(&route);
+    let mut results: Vec<Command> = rank(&all, &query(), |command| &command.label)
+        .into_iter()
+        .cloned()
+        .collect();
+    let search_error = match &*found.read() {
+        Some(Ok(events)) => {
+            results.extend(events.iter().map(|event| {
+                let label = format!("{} · {}", event.title, event.start_date.format("%Y-%m-%d"));
+                Command::go("Events", label, Route::EventDetail { event_id: event.id })
+            }));
+            None
+        }
+        Some(Err(e)) => Some(e.clone()),
+        None => None,
+    };
+    let active = selected().min(results.len().saturating_sub(1));
+
+    let run = {
+        let check_in = container.check_in.clone();
+        move |command: Command| match command.kind {
+            CommandKind::Go(target) => {
+                navigator().push(target);
+                on_close.call(());
+            }
+            CommandKind::ExportRegistrations(event_id) => {
+                let check_in = check_in.clone();
+                spawn(async move {
+                    busy.set(true);
+                    status.set(Some("Exporting registrations...".to_string()));
+                    let saved = match check_in.load_roster(event_id).await {
+                        Ok(roster) => save_file(
+                            &format!("registrations-{}.csv", event_id),
+                            "text/csv;charset=utf-8",
+                            &attendees_csv(&roster.attendees),
+                        ),
+                        Err(e) => Err(e),
+                    };
+                    busy.set(false);
+                    match saved {
+                        Ok(()) => on_close.call(()),
+                        Err(e) => status.set(Some(format!("Export failed: {}", e))),
+                    }
+                });
+            }
+        }
+    };
+
+    let onkeydown = {
+        let results = results.clone();
+        let run = run.clone();
+        move |evt: KeyboardEvent| match evt.key() {
+            Key::ArrowDown if !results.is_empty() => {
+                evt.prevent_default();
+                selected.set((active + 1) % results.len());
+            }
+            Key::ArrowUp if !results.is_empty() => {
+                evt.prevent_default();
+                selected.set((active + results.len() - 1) % results.len());
+            }
+            Key::Enter => {
+                evt.prevent_default();
+                if let Some(command) = results.get(active) {
+                    if !busy() {
+                        run(command.clone());
+                    }
+                }
+            }
+            Key::Escape => on_close.call(()),
+            _ => {}
+        }
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: COMMAND_PALETTE_CSS }
+
+        div { class: "command-palette-backdrop", onclick: move |_| on_close.call(()),
+            div {
+                class: "command-palette",
+                role: "dialog",
+                aria_label: "Command palette",
+                onclick: move |evt| evt.stop_propagation(),
+
+                input {
+                    class: "command-palette-input",
+                    placeholder: "Search pages, actions and events...",
+                    value: "{query}",
+                    onmounted: move |evt| async move {
+                        let _ = evt.set_focus(true).await;
+                    },
+                    oninput: move |evt| {
+                        query.set(evt.value());
+                        selected.set(0);
+                        status.set(None);
+                    },
+                    onkeydown,
+                }
+
+                if let Some(message) = status().or(search_error) {
+                    p { class: "command-palette-status", "{message}" }
+                }
+
+                ul { class: "command-palette-results", role: "listbox",
+                    for (index, command) in results.into_iter().enumerate() {
+                        li {
+                            key: "{index}-{command.label}",
+                            role: "option",
+                            aria_selected: index == active,
+                            class: if index == active { "command-palette-item active" } else { "command-palette-item" },
+                            onmouseenter: move |_| selected.set(index),
+                            onclick: {
+                                let run = run.clone();
+                                let command = command.clone();
+                                move |_| {
+                                    if !busy() {
+                                        run(command.clone());
+                                    }
+                                }
+                            },
+                            span { class: "command-palette-label", "{command.label}" }
+                            span { class: "command-palette-section", "{command.section}" }
+                        }
+                    }
+                }
+
+                p { class: "command-palette-hint",
+                    kbd { "↑" } kbd { "↓" } " to move · " kbd { "Enter" } " to run · " kbd { "Esc" } " to close"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Error boundary around the routed pages. An error a page propagates while
+// rendering or loading replaces the page with a message, a retry action and a
+// reference the user can quote; the error is reported to the API under it.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::ErrorReport;
+use crate::AppContainer;
+
+const ERROR_BOUNDARY_CSS: Asset = asset!("/assets/error_boundary.css");
+
+#[component]
+pub fn RouteErrorBoundary(container: AppContainer, route: String, children: Element) -> Element {
+    let mut attempt = use_signal(|| 0u32);
+
+    // Keyed by route and attempt: navigating away or retrying mounts a fresh
+    // boundary, so the page and its data loads start over
+    let key = format!("{route}#{}", attempt());
+
+    rsx! {
+        for key in std::iter::once(key) {
+            ErrorBoundary {
+                key: "{key}",
+                handle_error: {
+                    let container = container.clone();
+                    let route = route.clone();
+                    move |errors: ErrorContext| {
+                        let message = errors
+                            .error()
+                            .map(|error| error.to_string())
+                            .unwrap_or_else(|| "Unknown error".to_string());
+                        rsx! {
+                            ErrorFallback {
+                                container: container.clone(),
+                                route: route.clone(),
+                                message,
+                                on_retry: move |_| attempt += 1,
+                            }
+                        }
+                    }
+                },
+                {children.clone()}
+            }
+        }
+    }
+}
+
+#[component]
+fn ErrorFallback(
+    container: AppContainer,
+    route: String,
+    message: String,
+    on_retry: EventHandler<()>,
+) -> Element {
+    // One reference per error shown, reported once when the message appears
+    let report = use_hook(|| ErrorReport::new(message.clone(), route.clone()));
+    use_hook({
+        let report = report.clone();
+        let errors = container.errors.clone();
+        move || {
+            spawn(async move {
+                if let Err(e) = errors.report(&report).await {
+                    log::warn!("Failed to report error {}: {}", report.reference, e);
+                }
+            })
+        }
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: ERROR_BOUNDARY_CSS }
+
+        div { class: "error-boundary", role: "alert",
+            h2 { "Something went wrong" }
+            p { "This page couldn't be shown. Trying again usually helps; if it keeps happening, contact support and quote the reference below." }
+            p { class: "error-boundary-reference",
+                "Reference: "
+                code { "{report.reference}" }
+            }
+            details { class: "error-boundary-details",
+                summary { "Technical details" }
+                pre { "{message}" }
+            }
+            button {
+                r#type: "button",
+                class: "error-boundary-retry",
+                onclick: move |_| on_retry.call(()),
+                "Try again"
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// Hooks bridging application services into Dioxus reactivity
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::application::services::EventStore;
+
+/// A revision that changes whenever the shared event store does; read it inside
+/// a resource to load again (usually straight from the cache) after a mutation
+pub fn use_event_changes(events: &EventStore) -> Signal<u64> {
+    let revision = use_signal(|| 0u64);
+    // Unsubscribes when the component unmounts and drops the hook
+    use_hook(|| {
+        Rc::new(events.subscribe(move || {
+            let mut revision = revision;
+            revision += 1;
+        }))
+    });
+    revision
+}
+
+/// Logs how long each render of the component takes to reach the page, at
+/// debug level under `aqio::render`; does nothing in release builds
+pub fn use_render_timing(component: &'static str) {
+    let renders = use_hook(|| Rc::new(Cell::new(0u32)));
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    renders.set(renders.get() + 1);
+    let render = renders.get();
+    let started = now_ms();
+    // Runs once the current task, which applies the render's DOM changes, is done
+    wasm_bindgen_futures::spawn_local(async move {
+        log::debug!(target: "aqio::render", "{} render #{} took {:.1} ms", component, render, now_ms() - started);
+    });
+}
+
+fn now_ms() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map_or_else(js_sys::Date::now, |performance| performance.now())
+}
@@ -1,2 +1,6 @@
+pub mod command_palette;
+pub mod error_boundary;
+pub mod hooks;
 pub mod pages;
 pub mod routes;
+pub mod user_picker;
@@ -0,0 +1,83 @@
+use dioxus::prelude::*;
+
+use crate::lib::components::{Badge, BadgeSize};
+use crate::AppContainer;
+
+use super::Pager;
+
+// Tables that currently record audit entries, as (table_name, label)
+const TABLE_FILTERS: [(&str, &str); 2] = [("users", "Users"), ("event_categories", "Categories")];
+
+#[component]
+pub fn AdminAuditLogPage(container: AppContainer) -> Element {
+    let mut page = use_signal(|| 1u32);
+    let mut table_name = use_signal(|| None::<String>);
+
+    let entries = use_resource(move || {
+        let svc = container.admin.clone();
+        let page = page();
+        let table_name = table_name();
+        async move { svc.list_audit_log(page, table_name).await }
+    });
+
+    rsx! {
+        div { class: "admin-page",
+            div { class: "admin-page-header",
+                h1 { "Audit log" }
+                select {
+                    onchange: move |evt| {
+                        let value = evt.value();
+                        table_name.set(if value.is_empty() { None } else { Some(value) });
+                        page.set(1);
+                    },
+                    option { value: "", "All changes" }
+                    for (value, label) in TABLE_FILTERS {
+                        option { value, selected: table_name().as_deref() == Some(value), {label} }
+                    }
+                }
+            }
+
+            match &*entries.read() {
+                Some(Ok(result)) if result.items.is_empty() => rsx! {
+                    p { class: "admin-muted", "No changes recorded yet." }
+                },
+                Some(Ok(result)) => rsx! {
+                    table { class: "admin-table",
+                        thead {
+                            tr {
+                                th { "When" }
+                                th { "Who" }
+                                th { "Action" }
+                                th { "Record" }
+                                th { "Changed fields" }
+                            }
+                        }
+                        tbody {
+                            for entry in result.items.iter() {
+                                tr { key: "{entry.id}",
+                                    td { class: "admin-muted", {entry.created_at.format("%Y-%m-%d %H:%M").to_string()} }
+                                    td { {entry.actor.clone().unwrap_or_else(|| "System".to_string())} }
+                                    td { Badge { size: BadgeSize::Small, "{entry.action}" } }
+                                    td {
+                                        span { class: "admin-muted", "{entry.table_name} / " }
+                                        code { "{entry.record_id}" }
+                                    }
+                                    td {
+                                        if entry.changed_fields.is_empty() {
+                                            span { class: "admin-muted", "—" }
+                                        } else {
+                                            {entry.changed_fields.join(", ")}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Pager { page, total_pages: result.total_pages }
+                },
+                Some(Err(e)) => rsx! { p { class: "admin-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
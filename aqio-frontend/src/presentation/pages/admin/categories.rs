@@ -0,0 +1,337 @@
+use dioxus::prelude::*;
+
+use crate::application::ports::Category;
+use crate::lib::components::{Badge, BadgeSize, BadgeVariant};
+use crate::AppContainer;
+
+// Swatches offered by the color picker; any #RRGGBB value is accepted
+const COLOR_PRESETS: [&str; 8] = [
+    "#3B82F6", "#10B981", "#F59E0B", "#8B5CF6", "#EC4899", "#6B7280", "#EF4444", "#0EA5E9",
+];
+
+// Icon names stored on categories, with the glyph shown for each
+const ICON_CHOICES: [(&str, &str); 10] = [
+    ("presentation", "📊"),
+    ("tools", "🛠️"),
+    ("users", "👥"),
+    ("academic-cap", "🎓"),
+    ("heart", "❤️"),
+    ("clipboard", "📋"),
+    ("fish", "🐟"),
+    ("boat", "⛴️"),
+    ("calendar", "📅"),
+    ("globe", "🌍"),
+];
+
+pub fn icon_glyph(icon_name: Option<&str>) -> &'static str {
+    icon_name
+        .and_then(|name| ICON_CHOICES.iter().find(|(choice, _)| *choice == name))
+        .map(|(_, glyph)| *glyph)
+        .unwrap_or("🏷️")
+}
+
+fn empty_category() -> Category {
+    Category {
+        id: String::new(),
+        name: String::new(),
+        description: None,
+        color_hex: Some(COLOR_PRESETS[0].to_string()),
+        icon_name: None,
+        is_active: true,
+    }
+}
+
+// Empty inputs clear optional fields
+fn non_empty(value: String) -> Option<String> {
+    if value.trim().is_empty() { None } else { Some(value) }
+}
+
+#[derive(Clone, PartialEq)]
+struct Draft {
+    category: Category,
+    is_new: bool,
+}
+
+#[component]
+pub fn AdminCategoriesPage(container: AppContainer) -> Element {
+    let mut refresh = use_signal(|| 0u32);
+    let mut draft = use_signal(|| None::<Draft>);
+    let mut pending_delete = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+
+    let categories = use_resource({
+        let svc = container.admin.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.list_categories().await }
+        }
+    });
+
+    let delete = {
+        let svc = container.admin.clone();
+        move |id: String| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.delete_category(&id).await {
+                    Ok(()) => {
+                        error.set(None);
+                        refresh += 1;
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                pending_delete.set(None);
+            });
+        }
+    };
+
+    rsx! {
+        div { class: "admin-page",
+            div { class: "admin-page-header",
+                h1 { "Categories" }
+                button {
+                    r#type: "button",
+                    class: "admin-primary-button",
+                    onclick: move |_| draft.set(Some(Draft { category: empty_category(), is_new: true })),
+                    "New category"
+                }
+            }
+
+            if let Some(message) = error() {
+                p { class: "admin-error", "{message}" }
+            }
+
+            if draft().is_some() {
+                CategoryEditor {
+                    draft,
+                    on_saved: move |_| {
+                        draft.set(None);
+                        refresh += 1;
+                    },
+                }
+            }
+
+            match &*categories.read() {
+                Some(Ok(list)) => rsx! {
+                    table { class: "admin-table",
+                        thead {
+                            tr {
+                                th { "" }
+                                th { "Name" }
+                                th { "ID" }
+                                th { "Status" }
+                                th { "" }
+                            }
+                        }
+                        tbody {
+                            for category in list.iter().cloned() {
+                                tr { key: "{category.id}",
+                                    class: if !category.is_active { "admin-row-inactive" },
+                                    td {
+                                        span {
+                                            class: "admin-category-chip",
+                                            style: "background: {category.color_hex.as_deref().unwrap_or(\"#6B7280\")}",
+                                            {icon_glyph(category.icon_name.as_deref())}
+                                        }
+                                    }
+                                    td {
+                                        strong { "{category.name}" }
+                                        if let Some(description) = &category.description {
+                                            div { class: "admin-muted", "{description}" }
+                                        }
+                                    }
+                                    td { code { "{category.id}" } }
+                                    td {
+                                        Badge {
+                                            variant: if category.is_active { BadgeVariant::Success } else { BadgeVariant::Neutral },
+                                            size: BadgeSize::Small,
+                                            if category.is_active { "Active" } else { "Hidden" }
+                                        }
+                                    }
+                                    td { class: "admin-actions",
+                                        if pending_delete().as_deref() == Some(category.id.as_str()) {
+                                            span { "Delete?" }
+                                            button {
+                                                r#type: "button",
+                                                class: "admin-link-button admin-danger",
+                                                onclick: {
+                                                    let delete = delete.clone();
+                                                    let id = category.id.clone();
+                                                    move |_| delete(id.clone())
+                                                },
+                                                "Confirm"
+                                            }
+                                            button {
+                                                r#type: "button",
+                                                class: "admin-link-button",
+                                                onclick: move |_| pending_delete.set(None),
+                                                "Cancel"
+                                            }
+                                        } else {
+                                            button {
+                                                r#type: "button",
+                                                class: "admin-link-button",
+                                                onclick: {
+                                                    let category = category.clone();
+                                                    move |_| draft.set(Some(Draft { category: category.clone(), is_new: false }))
+                                                },
+                                                "Edit"
+                                            }
+                                            button {
+                                                r#type: "button",
+                                                class: "admin-link-button admin-danger",
+                                                onclick: {
+                                                    let id = category.id.clone();
+                                                    move |_| pending_delete.set(Some(id.clone()))
+                                                },
+                                                "Delete"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! { p { class: "admin-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+#[component]
+fn CategoryEditor(mut draft: Signal<Option<Draft>>, on_saved: EventHandler<()>) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let Some(Draft { category, is_new }) = draft() else {
+        return rsx! {};
+    };
+
+    // Apply an edit to the draft being edited
+    let mut edit = move |change: Box<dyn FnOnce(&mut Category)>| {
+        if let Some(current) = draft.write().as_mut() {
+            change(&mut current.category);
+        }
+    };
+
+    let save = move |_| {
+        let svc = container.admin.clone();
+        let Some(Draft { category, is_new }) = draft() else {
+            return;
+        };
+        spawn(async move {
+            saving.set(true);
+            match svc.save_category(&category, is_new).await {
+                Ok(_) => {
+                    error.set(None);
+                    on_saved.call(());
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            saving.set(false);
+        });
+    };
+
+    let color = category.color_hex.clone().unwrap_or_else(|| COLOR_PRESETS[0].to_string());
+
+    rsx! {
+        form { class: "admin-editor",
+            onsubmit: move |evt| evt.prevent_default(),
+
+            h2 { if is_new { "New category" } else { "Edit {category.name}" } }
+
+            if let Some(message) = error() {
+                p { class: "admin-error", "{message}" }
+            }
+
+            label { class: "admin-field",
+                span { "ID" }
+                input {
+                    value: "{category.id}",
+                    disabled: !is_new,
+                    placeholder: "e.g. seminar",
+                    oninput: move |evt| { let value = evt.value(); edit(Box::new(move |c| c.id = value)) },
+                }
+            }
+
+            label { class: "admin-field",
+                span { "Name" }
+                input {
+                    value: "{category.name}",
+                    oninput: move |evt| { let value = evt.value(); edit(Box::new(move |c| c.name = value)) },
+                }
+            }
+
+            label { class: "admin-field",
+                span { "Description" }
+                textarea {
+                    value: category.description.clone().unwrap_or_default(),
+                    oninput: move |evt| { let value = evt.value(); edit(Box::new(move |c| c.description = non_empty(value))) },
+                }
+            }
+
+            div { class: "admin-field",
+                span { "Color" }
+                div { class: "admin-color-picker",
+                    for preset in COLOR_PRESETS {
+                        button {
+                            r#type: "button",
+                            class: if preset.eq_ignore_ascii_case(&color) { "admin-swatch admin-swatch-selected" } else { "admin-swatch" },
+                            style: "background: {preset}",
+                            title: preset,
+                            onclick: move |_| edit(Box::new(move |c| c.color_hex = Some(preset.to_string()))),
+                        }
+                    }
+                    input {
+                        r#type: "color",
+                        value: "{color}",
+                        oninput: move |evt| { let value = evt.value().to_uppercase(); edit(Box::new(move |c| c.color_hex = Some(value))) },
+                    }
+                }
+            }
+
+            div { class: "admin-field",
+                span { "Icon" }
+                div { class: "admin-icon-picker",
+                    for (name, glyph) in ICON_CHOICES {
+                        button {
+                            r#type: "button",
+                            class: if category.icon_name.as_deref() == Some(name) { "admin-icon admin-icon-selected" } else { "admin-icon" },
+                            title: name,
+                            onclick: move |_| edit(Box::new(move |c| c.icon_name = Some(name.to_string()))),
+                            {glyph}
+                        }
+                    }
+                }
+            }
+
+            label { class: "admin-checkbox",
+                input {
+                    r#type: "checkbox",
+                    checked: category.is_active,
+                    onchange: move |evt| { let checked = evt.checked(); edit(Box::new(move |c| c.is_active = checked)) },
+                }
+                span { "Visible when creating events" }
+            }
+
+            div { class: "admin-editor-actions",
+                button {
+                    r#type: "button",
+                    class: "admin-primary-button",
+                    disabled: saving(),
+                    onclick: save,
+                    if saving() { "Saving..." } else { "Save" }
+                }
+                button {
+                    r#type: "button",
+                    class: "admin-link-button",
+                    onclick: move |_| draft.set(None),
+                    "Cancel"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use dioxus::prelude::*;
+
+use crate::application::ports::{CompanyDraft, Industry, RegistryLookup};
+use crate::lib::components::{Badge, BadgeSize, BadgeVariant};
+use crate::AppContainer;
+
+/// Adds a company to the directory. Typing a complete organization number
+/// looks it up in the business register and fills in the blank fields.
+#[component]
+pub fn AdminCompaniesPage(container: AppContainer) -> Element {
+    let mut draft = use_signal(CompanyDraft::default);
+    let mut lookup = use_signal(|| None::<RegistryLookup>);
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut added = use_signal(|| None::<(String, bool)>);
+
+    let look_up = {
+        let svc = container.admin.clone();
+        move |org_number: String| {
+            let svc = svc.clone();
+            spawn(async move {
+                let result = svc.lookup_company(&org_number).await;
+                // The number may have changed while the register answered
+                if draft.read().org_number != org_number {
+                    return;
+                }
+                match result {
+                    Some(Ok(found)) => {
+                        if let RegistryLookup::Found(company) = &found {
+                            let mut current = draft.write();
+                            if current.name.trim().is_empty() {
+                                current.name = company.name.clone();
+                            }
+                            if current.location.trim().is_empty() {
+                                current.location = company.city.clone().unwrap_or_default();
+                            }
+                            if current.website.trim().is_empty() {
+                                current.website = company.website.clone().unwrap_or_default();
+                            }
+                        }
+                        error.set(None);
+                        lookup.set(Some(found));
+                    }
+                    Some(Err(e)) => {
+                        error.set(Some(e));
+                        lookup.set(None);
+                    }
+                    None => lookup.set(None),
+                }
+            });
+        }
+    };
+
+    let save = {
+        let svc = container.admin.clone();
+        move |_| {
+            let svc = svc.clone();
+            let current = draft();
+            spawn(async move {
+                saving.set(true);
+                match svc.create_company(&current).await {
+                    Ok(created) => {
+                        added.set(Some((created.company.name, created.registry_verified)));
+                        draft.set(CompanyDraft::default());
+                        lookup.set(None);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    let current = draft();
+
+    rsx! {
+        div { class: "admin-page",
+            div { class: "admin-page-header",
+                h1 { "Add company" }
+            }
+
+            if let Some((name, verified)) = added() {
+                p { class: "admin-muted",
+                    "{name} was added"
+                    if !verified { " without being checked against the business register" }
+                    "."
+                }
+            }
+
+            form { class: "admin-editor",
+                onsubmit: move |evt| evt.prevent_default(),
+
+                if let Some(message) = error() {
+                    p { class: "admin-error", "{message}" }
+                }
+
+                label { class: "admin-field",
+                    span { "Organization number" }
+                    input {
+                        value: "{current.org_number}",
+                        placeholder: "e.g. 923 609 016",
+                        inputmode: "numeric",
+                        oninput: {
+                            let look_up = look_up.clone();
+                            move |evt: FormEvent| {
+                                let value = evt.value();
+                                draft.write().org_number = value.clone();
+                                look_up(value);
+                            }
+                        },
+                    }
+                }
+
+                match lookup() {
+                    Some(RegistryLookup::Found(company)) => rsx! {
+                        div { class: "admin-field",
+                            div {
+                                strong { "{company.name}" }
+                                " "
+                                if company.is_active {
+                                    Badge { variant: BadgeVariant::Success, size: BadgeSize::Small, "Registered" }
+                                } else {
+                                    Badge { variant: BadgeVariant::Error, size: BadgeSize::Small, "Closed" }
+                                }
+                            }
+                            if let Some(address) = &company.address {
+                                div { class: "admin-muted", "{address}" }
+                            }
+                            if let Some(industry) = &company.industry_description {
+                                div { class: "admin-muted", "{industry}" }
+                            }
+                        }
+                    },
+                    Some(RegistryLookup::NotFound) => rsx! {
+                        p { class: "admin-error", "No company is registered under this number." }
+                    },
+                    Some(RegistryLookup::Unavailable) => rsx! {
+                        p { class: "admin-muted", "The business register can't be reached right now; enter the details by hand." }
+                    },
+                    None => rsx! {},
+                }
+
+                label { class: "admin-field",
+                    span { "Name" }
+                    input {
+                        value: "{current.name}",
+                        oninput: move |evt| draft.write().name = evt.value(),
+                    }
+                }
+
+                label { class: "admin-field",
+                    span { "Location" }
+                    input {
+                        value: "{current.location}",
+                        oninput: move |evt| draft.write().location = evt.value(),
+                    }
+                }
+
+                label { class: "admin-field",
+                    span { "Industry" }
+                    select {
+                        value: current.industry.map(|industry| industry.as_str()).unwrap_or(""),
+                        onchange: move |evt| draft.write().industry = Industry::parse(&evt.value()),
+                        option { value: "", "From the business register" }
+                        for industry in Industry::ALL {
+                            option { value: industry.as_str(), {industry.label()} }
+                        }
+                    }
+                }
+
+                if current.industry == Some(Industry::Other) {
+                    label { class: "admin-field",
+                        span { "Industry description" }
+                        input {
+                            value: "{current.industry_description}",
+                            oninput: move |evt| draft.write().industry_description = evt.value(),
+                        }
+                    }
+                }
+
+                label { class: "admin-field",
+                    span { "Website" }
+                    input {
+                        r#type: "url",
+                        value: "{current.website}",
+                        oninput: move |evt| draft.write().website = evt.value(),
+                    }
+                }
+
+                label { class: "admin-field",
+                    span { "Phone" }
+                    input {
+                        r#type: "tel",
+                        value: "{current.phone}",
+                        oninput: move |evt| draft.write().phone = evt.value(),
+                    }
+                }
+
+                div { class: "admin-editor-actions",
+                    button {
+                        r#type: "button",
+                        class: "admin-primary-button",
+                        disabled: saving(),
+                        onclick: save,
+                        if saving() { "Adding..." } else { "Add company" }
+                    }
+                }
+            }
+        }
+    }
+}
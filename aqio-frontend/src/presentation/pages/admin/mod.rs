@@ -0,0 +1,87 @@
+// Admin console: user management, category management, adding companies,
+// pending organization invites, the audit log and web app performance.
+// Every page renders inside `AdminGuard`, which checks the signed-in user's permissions.
+
+pub mod audit_log;
+pub mod categories;
+pub mod companies;
+pub mod organization_invitations;
+pub mod performance;
+pub mod users;
+
+use dioxus::prelude::*;
+
+use crate::application::ports::Permission;
+use crate::AppContainer;
+
+const ADMIN_CSS: Asset = asset!("/assets/admin.css");
+
+/// Platform permissions behind the console's pages; holding any of them opens it
+const CONSOLE_PERMISSIONS: [Permission; 5] = [
+    Permission::UserManage,
+    Permission::CategoryManage,
+    Permission::AuditView,
+    Permission::EmailManage,
+    Permission::MetricsView,
+];
+
+/// Renders `children` only for signed-in users who may use the admin console
+///
+/// The API enforces the same rule per endpoint; this just avoids showing
+/// controls that would fail for everyone else.
+#[component]
+pub fn AdminGuard(container: AppContainer, children: Element) -> Element {
+    let permissions = use_resource(move || {
+        let svc = container.admin.clone();
+        async move { svc.my_permissions().await }
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: ADMIN_CSS }
+
+        match &*permissions.read() {
+            Some(Ok(permissions)) if CONSOLE_PERMISSIONS.iter().any(|p| permissions.contains(*p)) => {
+                rsx! { {children} }
+            }
+            Some(Ok(_)) => rsx! {
+                div { class: "admin-notice",
+                    h2 { "Not authorized" }
+                    p { "The admin console is only available to administrators." }
+                }
+            },
+            Some(Err(e)) => rsx! {
+                div { class: "admin-notice",
+                    h2 { "Sign in required" }
+                    p { "Sign in with an administrator account to continue." }
+                    p { class: "admin-error", "{e}" }
+                }
+            },
+            None => rsx! { p { "Loading..." } },
+        }
+    }
+}
+
+/// Previous/next controls for paginated admin tables
+#[component]
+pub fn Pager(mut page: Signal<u32>, total_pages: u32) -> Element {
+    let current = page();
+    let total_pages = total_pages.max(1);
+
+    rsx! {
+        div { class: "admin-pager",
+            button {
+                r#type: "button",
+                disabled: current <= 1,
+                onclick: move |_| page.set(current.saturating_sub(1).max(1)),
+                "Previous"
+            }
+            span { "Page {current} of {total_pages}" }
+            button {
+                r#type: "button",
+                disabled: current >= total_pages,
+                onclick: move |_| page.set(current + 1),
+                "Next"
+            }
+        }
+    }
+}
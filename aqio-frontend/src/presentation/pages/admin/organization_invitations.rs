@@ -0,0 +1,122 @@
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::lib::components::{Badge, BadgeSize, BadgeVariant};
+use crate::AppContainer;
+
+/// Invitations to join an organization that haven't been accepted yet
+#[component]
+pub fn AdminOrganizationInvitationsPage(container: AppContainer) -> Element {
+    let mut refresh = use_signal(|| 0u32);
+    let mut pending_revoke = use_signal(|| None::<Uuid>);
+    let mut error = use_signal(|| None::<String>);
+
+    let invitations = use_resource({
+        let svc = container.admin.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.list_organization_invites().await }
+        }
+    });
+
+    let revoke = {
+        let svc = container.admin.clone();
+        move |id: Uuid| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.revoke_organization_invite(id).await {
+                    Ok(()) => {
+                        error.set(None);
+                        refresh += 1;
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                pending_revoke.set(None);
+            });
+        }
+    };
+
+    rsx! {
+        div { class: "admin-page",
+            div { class: "admin-page-header",
+                h1 { "Organization invites" }
+            }
+
+            if let Some(message) = error() {
+                p { class: "admin-error", "{message}" }
+            }
+
+            match &*invitations.read() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "admin-muted", "No pending invitations." }
+                },
+                Some(Ok(list)) => rsx! {
+                    table { class: "admin-table",
+                        thead {
+                            tr {
+                                th { "Email" }
+                                th { "Organization" }
+                                th { "Role" }
+                                th { "Sent" }
+                                th { "Expires" }
+                                th { "" }
+                            }
+                        }
+                        tbody {
+                            for invitation in list.iter().cloned() {
+                                tr { key: "{invitation.id}",
+                                    class: if !invitation.is_open { "admin-row-inactive" },
+                                    td { "{invitation.email}" }
+                                    td { "{invitation.company_name}" }
+                                    td { Badge { size: BadgeSize::Small, {invitation.role.label()} } }
+                                    td { class: "admin-muted", {invitation.created_at.format("%Y-%m-%d").to_string()} }
+                                    td {
+                                        if invitation.is_open {
+                                            span { class: "admin-muted", {invitation.expires_at.format("%Y-%m-%d").to_string()} }
+                                        } else {
+                                            Badge { variant: BadgeVariant::Neutral, size: BadgeSize::Small, "Expired" }
+                                        }
+                                    }
+                                    td { class: "admin-actions",
+                                        if pending_revoke() == Some(invitation.id) {
+                                            span { "Withdraw?" }
+                                            button {
+                                                r#type: "button",
+                                                class: "admin-link-button admin-danger",
+                                                onclick: {
+                                                    let revoke = revoke.clone();
+                                                    let id = invitation.id;
+                                                    move |_| revoke(id)
+                                                },
+                                                "Confirm"
+                                            }
+                                            button {
+                                                r#type: "button",
+                                                class: "admin-link-button",
+                                                onclick: move |_| pending_revoke.set(None),
+                                                "Cancel"
+                                            }
+                                        } else {
+                                            button {
+                                                r#type: "button",
+                                                class: "admin-link-button admin-danger",
+                                                onclick: {
+                                                    let id = invitation.id;
+                                                    move |_| pending_revoke.set(Some(id))
+                                                },
+                                                "Withdraw"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! { p { class: "admin-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use dioxus::prelude::*;
+
+use crate::application::ports::WebVitalMetric;
+use crate::application::services::{
+    format_web_vital, web_vital_abbreviation, web_vital_label, web_vitals_by_release, WebVitalRating,
+};
+use crate::lib::components::{Badge, BadgeSize, BadgeVariant};
+use crate::AppContainer;
+
+// Periods the dashboard can cover, as (days, label); the API keeps 30 days
+const PERIODS: [(u32, &str); 3] = [(1, "Last 24 hours"), (7, "Last 7 days"), (30, "Last 30 days")];
+
+const METRICS: [WebVitalMetric; 5] = [
+    WebVitalMetric::Lcp,
+    WebVitalMetric::Inp,
+    WebVitalMetric::Fid,
+    WebVitalMetric::Cls,
+    WebVitalMetric::ApiLatency,
+];
+
+fn rating_variant(rating: WebVitalRating) -> BadgeVariant {
+    match rating {
+        WebVitalRating::Good => BadgeVariant::Success,
+        WebVitalRating::NeedsImprovement => BadgeVariant::Warning,
+        WebVitalRating::Poor => BadgeVariant::Error,
+    }
+}
+
+/// Web vitals measured in visitors' browsers, per release of the app, so a
+/// release that made the app slower stands out
+#[component]
+pub fn AdminPerformancePage(container: AppContainer) -> Element {
+    let mut days = use_signal(|| 7u32);
+
+    let summaries = use_resource(move || {
+        let svc = container.admin.clone();
+        let days = days();
+        async move { svc.web_vitals(days).await }
+    });
+
+    rsx! {
+        div { class: "admin-page",
+            div { class: "admin-page-header",
+                h1 { "Performance" }
+                select {
+                    onchange: move |evt| {
+                        if let Ok(value) = evt.value().parse() {
+                            days.set(value);
+                        }
+                    },
+                    for (value, label) in PERIODS {
+                        option { value: "{value}", selected: days() == value, {label} }
+                    }
+                }
+            }
+            p { class: "admin-muted",
+                "75th percentile of each measurement, with the median and 95th percentile below. Newest release first."
+            }
+
+            match &*summaries.read() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "admin-muted", "No measurements reported in this period." }
+                },
+                Some(Ok(list)) => rsx! {
+                    table { class: "admin-table admin-performance-table",
+                        thead {
+                            tr {
+                                th { "Release" }
+                                for metric in METRICS {
+                                    th { title: web_vital_label(metric), {web_vital_abbreviation(metric)} }
+                                }
+                            }
+                        }
+                        tbody {
+                            for (release, metrics) in web_vitals_by_release(list) {
+                                tr { key: "{release}",
+                                    td {
+                                        strong { "{release}" }
+                                        if let Some(first_seen) = metrics.iter().map(|summary| summary.first_seen).min() {
+                                            div { class: "admin-muted", {format!("since {}", first_seen.format("%Y-%m-%d"))} }
+                                        }
+                                    }
+                                    for metric in METRICS {
+                                        td { key: "{metric:?}",
+                                            match metrics.iter().find(|summary| summary.metric == metric) {
+                                                Some(summary) => rsx! {
+                                                    div { class: "admin-performance-value",
+                                                        span { {format_web_vital(metric, summary.p75)} }
+                                                        if let Some(rating) = WebVitalRating::of(metric, summary.p75) {
+                                                            Badge { variant: rating_variant(rating), size: BadgeSize::Small, {rating.label()} }
+                                                        }
+                                                    }
+                                                    div { class: "admin-muted",
+                                                        {format!(
+                                                            "p50 {} · p95 {} · {} samples",
+                                                            format_web_vital(metric, summary.p50),
+                                                            format_web_vital(metric, summary.p95),
+                                                            summary.samples
+                                                        )}
+                                                    }
+                                                },
+                                                None => rsx! { span { class: "admin-muted", "–" } },
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! { p { class: "admin-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use dioxus::prelude::*;
+
+use crate::application::ports::{AccountChanges, Role, UserAccount};
+use crate::lib::components::{Avatar, AvatarSize, Badge, BadgeSize, BadgeVariant};
+use crate::AppContainer;
+
+use super::Pager;
+
+#[component]
+pub fn AdminUsersPage(container: AppContainer) -> Element {
+    let page = use_signal(|| 1u32);
+    // Bumped after each change so the list is fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let error = use_signal(|| None::<String>);
+
+    let users = use_resource(move || {
+        let svc = container.admin.clone();
+        let page = page();
+        let _ = refresh();
+        async move { svc.list_users(page).await }
+    });
+
+    rsx! {
+        div { class: "admin-page",
+            h1 { "Users" }
+
+            if let Some(message) = error() {
+                p { class: "admin-error", "{message}" }
+            }
+
+            match &*users.read() {
+                Some(Ok(result)) => rsx! {
+                    table { class: "admin-table",
+                        thead {
+                            tr {
+                                th { "Name" }
+                                th { "Email" }
+                                th { "Role" }
+                                th { "Status" }
+                                th { "" }
+                            }
+                        }
+                        tbody {
+                            for user in result.items.iter() {
+                                UserRow {
+                                    key: "{user.id}",
+                                    user: user.clone(),
+                                    error,
+                                    on_changed: move |_| refresh += 1,
+                                }
+                            }
+                        }
+                    }
+                    p { class: "admin-muted", "{result.total_count} users" }
+                    Pager { page, total_pages: result.total_pages }
+                },
+                Some(Err(e)) => rsx! { p { class: "admin-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+#[component]
+fn UserRow(user: UserAccount, mut error: Signal<Option<String>>, on_changed: EventHandler<()>) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut saving = use_signal(|| false);
+
+    let user_id = user.id;
+
+    // `Callback` is Copy, so both the role select and the toggle can use it
+    let apply = use_callback(move |changes: AccountChanges| {
+        let svc = container.admin.clone();
+        spawn(async move {
+            saving.set(true);
+            match svc.update_user(user_id, changes).await {
+                Ok(_) => {
+                    error.set(None);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            saving.set(false);
+        });
+    });
+
+    let toggle_label = if user.is_active { "Deactivate" } else { "Activate" };
+    let is_active = user.is_active;
+
+    rsx! {
+        tr { class: if !user.is_active { "admin-row-inactive" },
+            td {
+                span { class: "admin-user-name",
+                    Avatar { name: user.name.clone(), size: AvatarSize::Small }
+                    "{user.name}"
+                }
+            }
+            td { "{user.email}" }
+            td {
+                select {
+                    disabled: saving(),
+                    onchange: move |evt| {
+                        if let Some(role) = Role::parse(&evt.value()) {
+                            apply.call(AccountChanges { role: Some(role), ..Default::default() });
+                        }
+                    },
+                    for role in Role::ALL {
+                        option {
+                            value: role.as_str(),
+                            selected: role == user.role,
+                            {role.label()}
+                        }
+                    }
+                }
+            }
+            td {
+                Badge {
+                    variant: if user.is_active { BadgeVariant::Success } else { BadgeVariant::Neutral },
+                    size: BadgeSize::Small,
+                    if user.is_active { "Active" } else { "Deactivated" }
+                }
+            }
+            td {
+                button {
+                    r#type: "button",
+                    class: "admin-link-button",
+                    disabled: saving(),
+                    onclick: move |_| apply.call(AccountChanges { is_active: Some(!is_active), ..Default::default() }),
+                    {toggle_label}
+                }
+            }
+        }
+    }
+}
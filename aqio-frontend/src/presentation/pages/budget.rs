@@ -0,0 +1,573 @@
+// Organizer view of an event's budget: planned amounts per budget line,
+// recorded expenses with their receipts, and totals against the plan.
+
+use chrono::{Local, NaiveDate};
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{BudgetLine, BudgetLineDraft, BudgetSummary, Expense, ExpenseDraft};
+use crate::application::services::{format_amount, format_file_size, parse_amount};
+use crate::infrastructure::download::save_bytes;
+use crate::lib::components::{ProgressBar, ProgressVariant};
+use crate::AppContainer;
+
+const BUDGET_CSS: Asset = asset!("/assets/budget.css");
+
+// What the amount fields show before the organizer edits them
+fn amount_input(ore: i64) -> String {
+    if ore % 100 == 0 {
+        (ore / 100).to_string()
+    } else {
+        format!("{},{:02}", ore / 100, ore % 100)
+    }
+}
+
+#[component]
+pub fn BudgetPage(container: AppContainer, event_id: Uuid) -> Element {
+    // Bumped after each change so the budget is fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let mut notice = use_signal(|| None::<String>);
+    let mut editing_line = use_signal(|| None::<BudgetLine>);
+    let mut editing_expense = use_signal(|| None::<Expense>);
+
+    let budget = use_resource({
+        let svc = container.budgets.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.budget(event_id).await }
+        }
+    });
+
+    let on_changed = move |message: String| {
+        notice.set(Some(message));
+        editing_line.set(None);
+        editing_expense.set(None);
+        refresh += 1;
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: BUDGET_CSS }
+
+        div { class: "budget-page",
+            h1 { "Budget" }
+
+            if let Some(message) = notice() {
+                p { class: "budget-notice", "{message}" }
+            }
+
+            match &*budget.read() {
+                Some(Ok(budget)) => {
+                    let line_key = editing_line().map(|line| line.id.to_string()).unwrap_or_default();
+                    let expense_key = editing_expense().map(|expense| expense.id.to_string()).unwrap_or_default();
+                    rsx! {
+                        SummaryCards { summary: budget.summary.clone() }
+
+                        section { class: "budget-section",
+                            h2 { "Budget lines" }
+                            if budget.summary.lines.is_empty() {
+                                p { class: "budget-muted", "No budget lines yet. Add one for each kind of cost you plan for." }
+                            } else {
+                                table { class: "budget-table",
+                                    thead {
+                                        tr {
+                                            th { "Line" }
+                                            th { class: "budget-amount", "Planned" }
+                                            th { class: "budget-amount", "Spent" }
+                                            th { class: "budget-amount", "Remaining" }
+                                            th {}
+                                        }
+                                    }
+                                    tbody {
+                                        for totals in budget.summary.lines.iter() {
+                                            {
+                                                let line = budget.lines.iter().find(|line| line.id == totals.budget_line_id).cloned();
+                                                let notes = line.as_ref().and_then(|line| line.notes.clone());
+                                                let over = totals.remaining() < 0;
+                                                let line_id = totals.budget_line_id;
+                                                let name = totals.name.clone();
+                                                rsx! {
+                                                    tr { key: "{line_id}",
+                                                        td {
+                                                            "{totals.name}"
+                                                            if let Some(notes) = notes {
+                                                                div { class: "budget-muted", "{notes}" }
+                                                            }
+                                                        }
+                                                        td { class: "budget-amount", {format_amount(totals.planned)} }
+                                                        td { class: "budget-amount", {format_amount(totals.spent)} }
+                                                        td {
+                                                            class: if over { "budget-amount budget-over" } else { "budget-amount" },
+                                                            {format_amount(totals.remaining())}
+                                                        }
+                                                        td { class: "budget-row-actions",
+                                                            button {
+                                                                r#type: "button",
+                                                                onclick: move |_| editing_line.set(line.clone()),
+                                                                "Edit"
+                                                            }
+                                                            DeleteLineButton { event_id, line_id, name, on_changed }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            LineForm {
+                                key: "{line_key}",
+                                event_id,
+                                line: editing_line(),
+                                on_changed,
+                                on_cancel: move |_| editing_line.set(None),
+                            }
+                        }
+
+                        section { class: "budget-section",
+                            h2 { "Expenses" }
+                            if budget.expenses.is_empty() {
+                                p { class: "budget-muted", "No expenses recorded yet." }
+                            } else {
+                                table { class: "budget-table",
+                                    thead {
+                                        tr {
+                                            th { "Date" }
+                                            th { "Description" }
+                                            th { "Line" }
+                                            th { class: "budget-amount", "Amount" }
+                                            th { "Receipt" }
+                                            th {}
+                                        }
+                                    }
+                                    tbody {
+                                        for expense in budget.expenses.iter() {
+                                            ExpenseRow {
+                                                key: "{expense.id}",
+                                                event_id,
+                                                expense: expense.clone(),
+                                                line_name: expense
+                                                    .budget_line_id
+                                                    .and_then(|id| budget.lines.iter().find(|line| line.id == id))
+                                                    .map(|line| line.name.clone()),
+                                                on_edit: move |expense| editing_expense.set(Some(expense)),
+                                                on_changed,
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            ExpenseForm {
+                                key: "{expense_key}",
+                                event_id,
+                                lines: budget.lines.clone(),
+                                expense: editing_expense(),
+                                on_changed,
+                                on_cancel: move |_| editing_expense.set(None),
+                            }
+                        }
+                    }
+                }
+                Some(Err(e)) => rsx! { p { class: "budget-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+#[component]
+fn SummaryCards(summary: BudgetSummary) -> Element {
+    let over = summary.is_over_budget();
+    let remaining_class = if over { "budget-card budget-card-over" } else { "budget-card" };
+
+    rsx! {
+        div { class: "budget-cards",
+            div { class: "budget-card",
+                span { class: "budget-card-label", "Planned" }
+                strong { {format_amount(summary.planned)} }
+            }
+            div { class: "budget-card",
+                span { class: "budget-card-label", "Spent" }
+                strong { {format_amount(summary.spent)} }
+                span { class: "budget-muted", "{summary.expense_count} expenses" }
+            }
+            div { class: remaining_class,
+                span { class: "budget-card-label",
+                    if over { "Over budget" } else { "Remaining" }
+                }
+                strong { {format_amount(summary.remaining().abs())} }
+            }
+            if summary.unassigned > 0 {
+                div { class: "budget-card",
+                    span { class: "budget-card-label", "Not on a line" }
+                    strong { {format_amount(summary.unassigned)} }
+                }
+            }
+        }
+        if summary.planned > 0 {
+            ProgressBar {
+                value: Some(summary.spent as f64),
+                max: summary.planned as f64,
+                label: Some("Share of the budget spent".to_string()),
+                show_value: true,
+                variant: if over { ProgressVariant::Error } else { ProgressVariant::Primary },
+                class: Some("budget-progress".to_string()),
+            }
+        }
+    }
+}
+
+#[component]
+fn DeleteLineButton(event_id: Uuid, line_id: Uuid, name: String, on_changed: EventHandler<String>) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut error = use_signal(|| None::<String>);
+
+    let delete = move |_| {
+        let svc = container.budgets.clone();
+        let name = name.clone();
+        spawn(async move {
+            match svc.delete_line(event_id, line_id).await {
+                Ok(()) => on_changed.call(format!("Removed '{}'; its expenses are now not on a line", name)),
+                Err(e) => error.set(Some(e)),
+            }
+        });
+    };
+
+    rsx! {
+        button { r#type: "button", onclick: delete, "Delete" }
+        if let Some(message) = error() {
+            span { class: "budget-error", "{message}" }
+        }
+    }
+}
+
+/// Adds a budget line, or edits `line` when given
+#[component]
+fn LineForm(
+    event_id: Uuid,
+    line: Option<BudgetLine>,
+    on_changed: EventHandler<String>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let container = use_context::<AppContainer>();
+    let editing = line.as_ref().map(|line| line.id);
+    let mut name = use_signal(|| line.as_ref().map(|line| line.name.clone()).unwrap_or_default());
+    let mut planned = use_signal(|| line.as_ref().map(|line| amount_input(line.planned)).unwrap_or_default());
+    let mut notes = use_signal(|| line.as_ref().and_then(|line| line.notes.clone()).unwrap_or_default());
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        let Some(amount) = parse_amount(&planned()) else {
+            error.set(Some("Enter the planned amount in kroner, e.g. 12 500 or 99,50".to_string()));
+            return;
+        };
+        let svc = container.budgets.clone();
+        let draft = BudgetLineDraft {
+            name: name(),
+            planned: amount,
+            notes: Some(notes()),
+        };
+        spawn(async move {
+            saving.set(true);
+            match svc.save_line(event_id, editing, draft).await {
+                Ok(line) => {
+                    error.set(None);
+                    name.set(String::new());
+                    planned.set(String::new());
+                    notes.set(String::new());
+                    on_changed.call(format!("Saved budget line '{}'", line.name));
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            saving.set(false);
+        });
+    };
+
+    rsx! {
+        form { class: "budget-form", onsubmit: submit,
+            h3 { if editing.is_some() { "Edit budget line" } else { "Add budget line" } }
+            div { class: "budget-form-fields",
+                input {
+                    placeholder: "Name, e.g. Catering",
+                    aria_label: "Name",
+                    value: "{name}",
+                    oninput: move |evt| name.set(evt.value()),
+                }
+                input {
+                    inputmode: "decimal",
+                    placeholder: "Planned (kr)",
+                    aria_label: "Planned amount in kroner",
+                    value: "{planned}",
+                    oninput: move |evt| planned.set(evt.value()),
+                }
+                input {
+                    placeholder: "Notes",
+                    aria_label: "Notes",
+                    value: "{notes}",
+                    oninput: move |evt| notes.set(evt.value()),
+                }
+            }
+            if let Some(message) = error() {
+                p { class: "budget-error", "{message}" }
+            }
+            div { class: "budget-form-actions",
+                button {
+                    r#type: "submit",
+                    class: "budget-button",
+                    disabled: saving() || name().trim().is_empty(),
+                    if saving() { "Saving..." } else if editing.is_some() { "Save line" } else { "Add line" }
+                }
+                if editing.is_some() {
+                    button { r#type: "button", onclick: move |_| on_cancel.call(()), "Cancel" }
+                }
+            }
+        }
+    }
+}
+
+/// Records an expense, or edits `expense` when given
+#[component]
+fn ExpenseForm(
+    event_id: Uuid,
+    lines: Vec<BudgetLine>,
+    expense: Option<Expense>,
+    on_changed: EventHandler<String>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let container = use_context::<AppContainer>();
+    let editing = expense.as_ref().map(|expense| expense.id);
+    let mut description = use_signal(|| expense.as_ref().map(|e| e.description.clone()).unwrap_or_default());
+    let mut vendor = use_signal(|| expense.as_ref().and_then(|e| e.vendor.clone()).unwrap_or_default());
+    let mut amount = use_signal(|| expense.as_ref().map(|e| amount_input(e.amount)).unwrap_or_default());
+    let mut incurred_on = use_signal(|| {
+        expense
+            .as_ref()
+            .map(|e| e.incurred_on)
+            .unwrap_or_else(|| Local::now().date_naive())
+    });
+    let mut line_id = use_signal(|| expense.as_ref().and_then(|e| e.budget_line_id));
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        let Some(parsed) = parse_amount(&amount()) else {
+            error.set(Some("Enter the amount in kroner, e.g. 1 250 or 99,50".to_string()));
+            return;
+        };
+        let svc = container.budgets.clone();
+        let draft = ExpenseDraft {
+            budget_line_id: line_id(),
+            description: description(),
+            vendor: Some(vendor()),
+            amount: parsed,
+            incurred_on: incurred_on(),
+        };
+        spawn(async move {
+            saving.set(true);
+            match svc.save_expense(event_id, editing, draft).await {
+                Ok(expense) => {
+                    error.set(None);
+                    description.set(String::new());
+                    vendor.set(String::new());
+                    amount.set(String::new());
+                    on_changed.call(format!("Saved expense '{}'", expense.description));
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            saving.set(false);
+        });
+    };
+
+    rsx! {
+        form { class: "budget-form", onsubmit: submit,
+            h3 { if editing.is_some() { "Edit expense" } else { "Record expense" } }
+            div { class: "budget-form-fields",
+                input {
+                    placeholder: "What was it for?",
+                    aria_label: "Description",
+                    value: "{description}",
+                    oninput: move |evt| description.set(evt.value()),
+                }
+                input {
+                    placeholder: "Vendor",
+                    aria_label: "Vendor",
+                    value: "{vendor}",
+                    oninput: move |evt| vendor.set(evt.value()),
+                }
+                input {
+                    inputmode: "decimal",
+                    placeholder: "Amount (kr)",
+                    aria_label: "Amount in kroner",
+                    value: "{amount}",
+                    oninput: move |evt| amount.set(evt.value()),
+                }
+                input {
+                    r#type: "date",
+                    aria_label: "Date",
+                    value: "{incurred_on}",
+                    oninput: move |evt| {
+                        if let Ok(date) = NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d") {
+                            incurred_on.set(date);
+                        }
+                    },
+                }
+                select {
+                    aria_label: "Budget line",
+                    onchange: move |evt| line_id.set(evt.value().parse().ok()),
+                    option { value: "", selected: line_id().is_none(), "Not on a line" }
+                    for line in lines.iter() {
+                        option {
+                            key: "{line.id}",
+                            value: "{line.id}",
+                            selected: line_id() == Some(line.id),
+                            "{line.name}"
+                        }
+                    }
+                }
+            }
+            if let Some(message) = error() {
+                p { class: "budget-error", "{message}" }
+            }
+            div { class: "budget-form-actions",
+                button {
+                    r#type: "submit",
+                    class: "budget-button",
+                    disabled: saving() || description().trim().is_empty(),
+                    if saving() { "Saving..." } else if editing.is_some() { "Save expense" } else { "Record expense" }
+                }
+                if editing.is_some() {
+                    button { r#type: "button", onclick: move |_| on_cancel.call(()), "Cancel" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ExpenseRow(
+    event_id: Uuid,
+    expense: Expense,
+    line_name: Option<String>,
+    on_edit: EventHandler<Expense>,
+    on_changed: EventHandler<String>,
+) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut busy = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let expense_id = expense.id;
+
+    let upload = {
+        let svc = container.budgets.clone();
+        move |evt: FormEvent| {
+            let svc = svc.clone();
+            async move {
+                let Some(engine) = evt.files() else { return };
+                let Some(file_name) = engine.files().first().cloned() else { return };
+                let Some(bytes) = engine.read_file(&file_name).await else {
+                    error.set(Some(format!("Could not read '{}'", file_name)));
+                    return;
+                };
+                busy.set(true);
+                match svc.attach_receipt(event_id, expense_id, file_name.clone(), bytes).await {
+                    Ok(_) => on_changed.call(format!("Attached receipt '{}'", file_name)),
+                    Err(e) => error.set(Some(e)),
+                }
+                busy.set(false);
+            }
+        }
+    };
+
+    let download = {
+        let svc = container.budgets.clone();
+        let receipt = expense.receipt.clone();
+        move |_| {
+            let svc = svc.clone();
+            let Some(receipt) = receipt.clone() else { return };
+            spawn(async move {
+                let saved = match svc.download_receipt(event_id, expense_id).await {
+                    Ok(bytes) => save_bytes(&receipt.filename, &receipt.mime_type, &bytes),
+                    Err(e) => Err(e),
+                };
+                error.set(saved.err());
+            });
+        }
+    };
+
+    let remove_receipt = {
+        let svc = container.budgets.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.remove_receipt(event_id, expense_id).await {
+                    Ok(_) => on_changed.call("Removed the receipt".to_string()),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let svc = container.budgets.clone();
+        let description = expense.description.clone();
+        move |_| {
+            let svc = svc.clone();
+            let description = description.clone();
+            spawn(async move {
+                match svc.delete_expense(event_id, expense_id).await {
+                    Ok(()) => on_changed.call(format!("Deleted expense '{}'", description)),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        tr {
+            td { class: "budget-muted", {expense.incurred_on.format("%Y-%m-%d").to_string()} }
+            td {
+                "{expense.description}"
+                if let Some(vendor) = expense.vendor.as_ref() {
+                    div { class: "budget-muted", "{vendor}" }
+                }
+                if let Some(message) = error() {
+                    div { class: "budget-error", "{message}" }
+                }
+            }
+            td { class: "budget-muted", {line_name.unwrap_or_else(|| "—".to_string())} }
+            td { class: "budget-amount", {format_amount(expense.amount)} }
+            td { class: "budget-receipt",
+                if let Some(receipt) = expense.receipt.as_ref() {
+                    button {
+                        r#type: "button",
+                        title: "{receipt.filename}",
+                        onclick: download,
+                        "Download ({format_file_size(receipt.file_size)})"
+                    }
+                    button { r#type: "button", onclick: remove_receipt, "Remove" }
+                } else {
+                    input {
+                        r#type: "file",
+                        accept: "image/*,application/pdf",
+                        aria_label: "Receipt for {expense.description}",
+                        disabled: busy(),
+                        onchange: upload,
+                    }
+                }
+            }
+            td { class: "budget-row-actions",
+                button {
+                    r#type: "button",
+                    onclick: {
+                        let expense = expense.clone();
+                        move |_| on_edit.call(expense.clone())
+                    },
+                    "Edit"
+                }
+                button { r#type: "button", onclick: delete, "Delete" }
+            }
+        }
+    }
+}
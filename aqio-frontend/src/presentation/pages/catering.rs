@@ -0,0 +1,128 @@
+// Catering report for organizers: dietary restrictions and accessibility needs
+// of confirmed attendees, grouped and counted, with a CSV export for the venue.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::CateringGroup;
+use crate::infrastructure::download::save_file;
+use crate::AppContainer;
+
+const CATERING_CSS: Asset = asset!("/assets/catering.css");
+
+#[component]
+pub fn CateringPage(container: AppContainer, event_id: Uuid) -> Element {
+    let mut error = use_signal(|| None::<String>);
+    let mut exporting = use_signal(|| false);
+
+    let report = use_resource({
+        let svc = container.catering.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.report(event_id).await }
+        }
+    });
+
+    let export = {
+        let svc = container.catering.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                exporting.set(true);
+                let saved = match svc.csv(event_id).await {
+                    Ok(csv) => save_file(&format!("catering-{}.csv", event_id), "text/csv;charset=utf-8", &csv),
+                    Err(e) => Err(e),
+                };
+                error.set(saved.err());
+                exporting.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: CATERING_CSS }
+
+        div { class: "catering-page",
+            div { class: "catering-header",
+                h1 { "Catering report" }
+                button {
+                    r#type: "button",
+                    class: "catering-button",
+                    disabled: exporting(),
+                    onclick: export,
+                    if exporting() { "Exporting..." } else { "Download CSV" }
+                }
+            }
+            p { class: "catering-muted",
+                "Needs are given per registration, so people counts include the guests each registrant brings."
+            }
+            if let Some(message) = error() {
+                p { class: "catering-error", "{message}" }
+            }
+
+            match &*report.read() {
+                Some(Ok(report)) => rsx! {
+                    div { class: "catering-totals",
+                        div {
+                            strong { "{report.total_people}" }
+                            span { class: "catering-muted", "people in total" }
+                        }
+                        div {
+                            strong { "{report.confirmed_registrations}" }
+                            span { class: "catering-muted", "confirmed registrations" }
+                        }
+                        div {
+                            strong { "{report.guests}" }
+                            span { class: "catering-muted", "guests" }
+                        }
+                    }
+                    NeedsTable {
+                        title: "Dietary restrictions",
+                        empty: "Nobody has mentioned dietary restrictions.",
+                        groups: report.dietary.clone(),
+                    }
+                    NeedsTable {
+                        title: "Accessibility needs",
+                        empty: "Nobody has mentioned accessibility needs.",
+                        groups: report.accessibility.clone(),
+                    }
+                },
+                Some(Err(e)) => rsx! { p { class: "catering-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+#[component]
+fn NeedsTable(title: &'static str, empty: &'static str, groups: Vec<CateringGroup>) -> Element {
+    rsx! {
+        section { class: "catering-section",
+            h2 { "{title}" }
+            if groups.is_empty() {
+                p { class: "catering-muted", "{empty}" }
+            } else {
+                table { class: "catering-table",
+                    thead {
+                        tr {
+                            th { "Need" }
+                            th { class: "catering-count", "Registrations" }
+                            th { class: "catering-count", "People" }
+                            th { "As written" }
+                        }
+                    }
+                    tbody {
+                        for group in groups.iter() {
+                            tr { key: "{group.label}",
+                                td { strong { "{group.label}" } }
+                                td { class: "catering-count", "{group.registrations}" }
+                                td { class: "catering-count", "{group.people}" }
+                                td { class: "catering-muted", {group.answers.join(" · ")} }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// Public check behind the QR code printed on certificates of attendance:
+// anyone holding a certificate can confirm who attended which event.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::CertificateCheck;
+use crate::AppContainer;
+
+const CERTIFICATE_CSS: Asset = asset!("/assets/certificate.css");
+
+fn format_dates(check: &CertificateCheck) -> Option<String> {
+    let start = check.event_start_date?;
+    let end = check.event_end_date.unwrap_or(start);
+    Some(if start.date_naive() == end.date_naive() {
+        start.format("%-d %B %Y").to_string()
+    } else {
+        format!("{} – {}", start.format("%-d %B %Y"), end.format("%-d %B %Y"))
+    })
+}
+
+#[component]
+pub fn CertificateVerificationPage(container: AppContainer, registration_id: Uuid) -> Element {
+    let check = use_resource({
+        let svc = container.certificates.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.verify(registration_id).await }
+        }
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: CERTIFICATE_CSS }
+
+        div { class: "certificate-page",
+            match &*check.read() {
+                Some(Ok(check)) if check.valid => rsx! {
+                    div { class: "certificate-result certificate-valid",
+                        h1 { "Valid certificate" }
+                        p {
+                            strong { {check.attendee_name.clone().unwrap_or_default()} }
+                            " attended "
+                            strong { {check.event_title.clone().unwrap_or_default()} }
+                        }
+                        dl { class: "certificate-facts",
+                            if let Some(dates) = format_dates(check) {
+                                dt { "Date" }
+                                dd { "{dates}" }
+                            }
+                            if let Some(location) = check.location_name.clone() {
+                                dt { "Location" }
+                                dd { "{location}" }
+                            }
+                            if let Some(organizer) = check.organizer_name.clone() {
+                                dt { "Organizer" }
+                                dd { "{organizer}" }
+                            }
+                            if let Some(checked_in_at) = check.checked_in_at {
+                                dt { "Checked in" }
+                                dd { {checked_in_at.format("%-d %B %Y, %H:%M UTC").to_string()} }
+                            }
+                            dt { "Certificate" }
+                            dd { class: "certificate-id", "{check.registration_id}" }
+                        }
+                    }
+                },
+                Some(Ok(_)) => rsx! {
+                    div { class: "certificate-result certificate-invalid",
+                        h1 { "Certificate not recognised" }
+                        p { "No checked-in attendance matches certificate {registration_id}." }
+                    }
+                },
+                Some(Err(e)) => rsx! { p { class: "certificate-error", "Error: {e}" } },
+                None => rsx! { p { "Checking certificate..." } },
+            }
+        }
+    }
+}
@@ -0,0 +1,268 @@
+// Door check-in: scans registration QR codes with the device camera and checks
+// attendees in. Check-ins made while the API is unreachable are queued on the
+// device and replayed in the background.
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use uuid::Uuid;
+
+use crate::application::ports::{Attendee, Roster};
+use crate::application::services::ScanResult;
+use crate::infrastructure::camera::QrCamera;
+use crate::lib::components::{ProgressBar, ProgressVariant};
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+const CHECK_IN_CSS: Asset = asset!("/assets/check_in.css");
+
+const VIDEO_ELEMENT_ID: &str = "check-in-video";
+const SCAN_INTERVAL_MS: u32 = 300;
+const SYNC_INTERVAL_MS: u32 = 15_000;
+// A code held in front of the camera is decoded many times; ignore repeats for this long
+const REPEAT_SCAN_WINDOW_MS: f64 = 4_000.0;
+const HISTORY_LENGTH: usize = 8;
+
+fn result_class(result: &ScanResult) -> &'static str {
+    match result {
+        ScanResult::CheckedIn(_) => "check-in-result check-in-result-ok",
+        ScanResult::Queued(_) => "check-in-result check-in-result-queued",
+        ScanResult::AlreadyCheckedIn(_) => "check-in-result check-in-result-warning",
+        ScanResult::NotAdmitted(_) | ScanResult::Unknown(_) | ScanResult::Failed(_) => {
+            "check-in-result check-in-result-error"
+        }
+    }
+}
+
+fn result_text(result: &ScanResult) -> String {
+    let with_guests = |attendee: &Attendee| match attendee.guest_count {
+        0 => attendee.display_name(),
+        guests => format!("{} (+{} guests)", attendee.display_name(), guests),
+    };
+    match result {
+        ScanResult::CheckedIn(attendee) => format!("Checked in: {}", with_guests(attendee)),
+        ScanResult::Queued(attendee) => format!("Checked in offline: {}", with_guests(attendee)),
+        ScanResult::AlreadyCheckedIn(attendee) => {
+            format!("Already checked in: {}", attendee.display_name())
+        }
+        ScanResult::NotAdmitted(attendee) => {
+            format!("Not admitted (waitlisted or cancelled): {}", attendee.display_name())
+        }
+        ScanResult::Unknown(reason) => reason.clone(),
+        ScanResult::Failed(error) => format!("Check-in failed: {}", error),
+    }
+}
+
+#[component]
+pub fn CheckInPage(container: AppContainer, event_id: Uuid) -> Element {
+    let mut roster = use_signal(|| None::<Roster>);
+    let mut load_error = use_signal(|| None::<String>);
+    let mut camera = use_signal(|| None::<QrCamera>);
+    let mut camera_error = use_signal(|| None::<String>);
+    let mut starting_camera = use_signal(|| false);
+    let mut history = use_signal(Vec::<ScanResult>::new);
+    let mut pending = use_signal(|| container.check_in.pending_count(event_id));
+    let mut sync_notice = use_signal(|| None::<String>);
+    let mut busy = use_signal(|| false);
+    let mut last_code = use_signal(|| None::<(String, f64)>);
+    let mut manual_code = use_signal(String::new);
+
+    use_future({
+        let svc = container.check_in.clone();
+        move || {
+            let svc = svc.clone();
+            async move {
+                match svc.load_roster(event_id).await {
+                    Ok(loaded) => roster.set(Some(loaded)),
+                    Err(e) => load_error.set(Some(e)),
+                }
+            }
+        }
+    });
+
+    let handle_code = use_callback({
+        let svc = container.check_in.clone();
+        move |code: String| {
+            let now = js_sys::Date::now();
+            if let Some((previous, at)) = last_code() {
+                if previous == code && now - at < REPEAT_SCAN_WINDOW_MS {
+                    return;
+                }
+            }
+            last_code.set(Some((code.clone(), now)));
+
+            let Some(current) = roster() else {
+                return;
+            };
+            let svc = svc.clone();
+            spawn(async move {
+                busy.set(true);
+                let result = svc.scan(event_id, &current, &code).await;
+                if let ScanResult::CheckedIn(attendee) | ScanResult::Queued(attendee) = &result {
+                    if let Some(roster) = roster.write().as_mut() {
+                        roster.mark_checked_in(attendee.registration_id);
+                    }
+                }
+                pending.set(svc.pending_count(event_id));
+                history.with_mut(|entries| {
+                    entries.insert(0, result);
+                    entries.truncate(HISTORY_LENGTH);
+                });
+                busy.set(false);
+            });
+        }
+    });
+
+    let sync = use_callback({
+        let svc = container.check_in.clone();
+        move |_: ()| {
+            let svc = svc.clone();
+            spawn(async move {
+                let outcome = svc.sync(event_id).await;
+                pending.set(svc.pending_count(event_id));
+                if outcome.succeeded > 0 || !outcome.failed.is_empty() {
+                    let mut text = format!("Synced {} queued check-ins", outcome.succeeded);
+                    if !outcome.failed.is_empty() {
+                        text.push_str(&format!(", {} rejected by the server", outcome.failed.len()));
+                    }
+                    sync_notice.set(Some(text));
+                }
+            });
+        }
+    });
+
+    // Decode frames while the camera is running
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(SCAN_INTERVAL_MS).await;
+            if busy() {
+                continue;
+            }
+            let code = camera.read().as_ref().and_then(|camera| camera.scan());
+            if let Some(code) = code {
+                handle_code.call(code);
+            }
+        }
+    });
+
+    // Replay queued check-ins whenever the connection comes back
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(SYNC_INTERVAL_MS).await;
+            if pending() > 0 {
+                sync.call(());
+            }
+        }
+    });
+
+    use_drop(move || {
+        if let Some(camera) = camera.peek().as_ref() {
+            camera.stop();
+        }
+    });
+
+    let start_camera = move |_| {
+        spawn(async move {
+            starting_camera.set(true);
+            match QrCamera::start(VIDEO_ELEMENT_ID).await {
+                Ok(started) => {
+                    camera_error.set(None);
+                    camera.set(Some(started));
+                }
+                Err(e) => camera_error.set(Some(e)),
+            }
+            starting_camera.set(false);
+        });
+    };
+
+    let stop_camera = move |_| {
+        if let Some(camera) = camera.take() {
+            camera.stop();
+        }
+    };
+
+    let camera_running = camera.read().is_some();
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: CHECK_IN_CSS }
+
+        div { class: "check-in-page",
+            h1 { "Check-in" }
+            Link { class: "check-in-print-link", to: Route::PrintAttendees { event_id }, "Print attendee list" }
+
+            if let Some(message) = load_error() {
+                p { class: "check-in-error", "{message}" }
+            }
+
+            if let Some(current) = roster() {
+                div { class: "check-in-counter",
+                    span { class: "check-in-counter-value", "{current.checked_in_count()}" }
+                    span { class: "check-in-counter-label", " of {current.admissible_count()} checked in" }
+                }
+                ProgressBar {
+                    value: Some(current.checked_in_count() as f64),
+                    max: current.admissible_count() as f64,
+                    variant: ProgressVariant::Success,
+                }
+                if current.from_cache {
+                    p { class: "check-in-offline", "Offline: using the roster saved on this device." }
+                }
+            }
+
+            if pending() > 0 {
+                div { class: "check-in-offline",
+                    span { "{pending()} check-ins waiting to sync" }
+                    button {
+                        r#type: "button",
+                        class: "check-in-link-button",
+                        onclick: move |_| sync.call(()),
+                        "Sync now"
+                    }
+                }
+            }
+            if let Some(message) = sync_notice() {
+                p { class: "check-in-muted", "{message}" }
+            }
+
+            div { class: "check-in-camera",
+                video { id: VIDEO_ELEMENT_ID, class: if !camera_running { "check-in-video-idle" } }
+                if camera_running {
+                    button { r#type: "button", class: "check-in-button", onclick: stop_camera, "Stop camera" }
+                } else {
+                    button {
+                        r#type: "button",
+                        class: "check-in-button",
+                        disabled: roster().is_none() || starting_camera(),
+                        onclick: start_camera,
+                        if starting_camera() { "Starting camera..." } else { "Start scanning" }
+                    }
+                }
+                if let Some(message) = camera_error() {
+                    p { class: "check-in-error", "{message}" }
+                }
+            }
+
+            form { class: "check-in-manual",
+                onsubmit: move |evt| {
+                    evt.prevent_default();
+                    let code = manual_code();
+                    if !code.trim().is_empty() {
+                        handle_code.call(code);
+                        manual_code.set(String::new());
+                    }
+                },
+                input {
+                    placeholder: "Or type a registration code",
+                    value: "{manual_code}",
+                    oninput: move |evt| manual_code.set(evt.value()),
+                }
+                button { r#type: "submit", class: "check-in-button", disabled: roster().is_none(), "Check in" }
+            }
+
+            ul { class: "check-in-history",
+                for result in history().iter() {
+                    li { class: result_class(result), {result_text(result)} }
+                }
+            }
+        }
+    }
+}
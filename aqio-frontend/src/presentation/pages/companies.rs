@@ -0,0 +1,244 @@
+// Directory of member companies, filterable by industry. Each card can expand to
+// show the company's upcoming public events, fetched when it's opened. Also the
+// join page emailed organization invitations link to.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{Company, Industry};
+use crate::lib::components::{Avatar, Badge, BadgeSize};
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+const COMPANIES_CSS: Asset = asset!("/assets/companies.css");
+
+#[component]
+pub fn CompaniesPage(container: AppContainer) -> Element {
+    let mut page = use_signal(|| 1u32);
+    let mut industry = use_signal(|| None::<Industry>);
+
+    let companies = use_resource({
+        let svc = container.companies.clone();
+        move || {
+            let svc = svc.clone();
+            let page = page();
+            let industry = industry();
+            async move { svc.list(page, industry).await }
+        }
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: COMPANIES_CSS }
+
+        div { class: "companies-page",
+            h1 { "Companies" }
+
+            div { class: "companies-filters", role: "group", aria_label: "Filter by industry",
+                Badge {
+                    selected: industry().is_none(),
+                    on_click: move |_| {
+                        industry.set(None);
+                        page.set(1);
+                    },
+                    "All"
+                }
+                for option in Industry::ALL {
+                    Badge {
+                        key: "{option.as_str()}",
+                        selected: industry() == Some(option),
+                        on_click: move |_| {
+                            industry.set(Some(option));
+                            page.set(1);
+                        },
+                        {option.label()}
+                    }
+                }
+            }
+
+            match &*companies.read() {
+                Some(Ok(result)) if result.items.is_empty() => rsx! {
+                    p { class: "companies-muted", "No companies found." }
+                },
+                Some(Ok(result)) => rsx! {
+                    p { class: "companies-muted", "{result.total_count} companies" }
+                    ul { class: "companies-grid",
+                        for company in result.items.iter() {
+                            CompanyCard { key: "{company.id}", container: container.clone(), company: company.clone() }
+                        }
+                    }
+                    if result.total_pages > 1 {
+                        div { class: "companies-pager",
+                            button {
+                                r#type: "button",
+                                disabled: page() <= 1,
+                                onclick: move |_| page.set(page() - 1),
+                                "Previous"
+                            }
+                            span { "Page {page} of {result.total_pages}" }
+                            button {
+                                r#type: "button",
+                                disabled: page() >= result.total_pages,
+                                onclick: move |_| page.set(page() + 1),
+                                "Next"
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! { p { class: "companies-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+#[component]
+fn CompanyCard(container: AppContainer, company: Company) -> Element {
+    let mut show_events = use_signal(|| false);
+    let industry = match (&company.industry, &company.industry_description) {
+        (Industry::Other, Some(description)) => description.clone(),
+        (industry, _) => industry.label().to_string(),
+    };
+    let members = if company.member_count == 1 {
+        "1 member".to_string()
+    } else {
+        format!("{} members", company.member_count)
+    };
+
+    rsx! {
+        li { class: "company-card",
+            div { class: "company-card-header",
+                Avatar { name: company.name.clone() }
+                div {
+                    h2 { class: "company-card-name", "{company.name}" }
+                    if let Some(location) = &company.location {
+                        p { class: "companies-muted", "{location}" }
+                    }
+                }
+            }
+            div { class: "company-card-meta",
+                Badge { size: BadgeSize::Small, "{industry}" }
+                span { class: "companies-muted", "{members}" }
+                if let Some(website) = &company.website {
+                    a { href: "{website}", target: "_blank", rel: "noopener noreferrer", "Website" }
+                }
+            }
+            button {
+                r#type: "button",
+                class: "company-card-toggle",
+                aria_expanded: show_events(),
+                onclick: move |_| show_events.toggle(),
+                if show_events() { "Hide upcoming events" } else { "Show upcoming events" }
+            }
+            // Mounted on demand so the directory doesn't fetch events for every card
+            if show_events() {
+                CompanyEvents { container, company_id: company.id }
+            }
+        }
+    }
+}
+
+#[component]
+fn CompanyEvents(container: AppContainer, company_id: Uuid) -> Element {
+    let events = use_resource(move || {
+        let svc = container.companies.clone();
+        async move { svc.upcoming_events(company_id).await }
+    });
+
+    match &*events.read() {
+        Some(Ok(events)) if events.is_empty() => rsx! {
+            p { class: "companies-muted", "No upcoming public events." }
+        },
+        Some(Ok(events)) => rsx! {
+            ul { class: "company-events",
+                for event in events.iter() {
+                    li { key: "{event.id}",
+                        span { class: "companies-muted", {event.start_date.format("%Y-%m-%d").to_string()} }
+                        Link { to: Route::EventDetail { event_id: event.id }, "{event.title}" }
+                    }
+                }
+            }
+        },
+        Some(Err(e)) => rsx! { p { class: "companies-error", "Error: {e}" } },
+        None => rsx! { p { class: "companies-muted", "Loading..." } },
+    }
+}
+
+/// Landing page of an emailed organization invitation. Accepting needs a
+/// signed-in account with the invited address; the API creates it on first login.
+#[component]
+pub fn JoinOrganizationPage(container: AppContainer, token: String) -> Element {
+    let mut joined = use_signal(|| false);
+    let mut accepting = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let invite = use_resource({
+        let svc = container.companies.clone();
+        let token = token.clone();
+        move || {
+            let svc = svc.clone();
+            let token = token.clone();
+            async move { svc.invite(&token).await }
+        }
+    });
+
+    let accept = {
+        let svc = container.companies.clone();
+        let token = token.clone();
+        move |_| {
+            let svc = svc.clone();
+            let token = token.clone();
+            accepting.set(true);
+            spawn(async move {
+                match svc.accept_invite(&token).await {
+                    Ok(()) => {
+                        error.set(None);
+                        joined.set(true);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                accepting.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: COMPANIES_CSS }
+
+        div { class: "companies-page join-organization",
+            match &*invite.read() {
+                Some(Ok(invite)) if joined() => rsx! {
+                    h1 { "Welcome to {invite.company_name}" }
+                    p { "You're now a member as {invite.role.label().to_lowercase()}." }
+                    Link { to: Route::Home {}, "Go to events" }
+                },
+                Some(Ok(invite)) if invite.is_open => rsx! {
+                    h1 { "Join {invite.company_name}" }
+                    p { "You've been invited to join {invite.company_name} on Aqio as {invite.role.label().to_lowercase()}." }
+                    p { class: "companies-muted",
+                        "Sign in as {invite.email} to accept. The invitation is valid until "
+                        {invite.expires_at.format("%-d %B %Y").to_string()}
+                        "."
+                    }
+                    if let Some(message) = error() {
+                        p { class: "companies-error", "{message}" }
+                    }
+                    button {
+                        r#type: "button",
+                        class: "aqio-button",
+                        disabled: accepting(),
+                        onclick: accept.clone(),
+                        if accepting() { "Joining..." } else { "Accept invitation" }
+                    }
+                },
+                Some(Ok(invite)) => rsx! {
+                    h1 { "Invitation no longer valid" }
+                    p { class: "companies-muted",
+                        "This invitation to {invite.company_name} has expired or was already used. Ask a colleague to send a new one."
+                    }
+                },
+                Some(Err(e)) => rsx! { p { class: "companies-error", "Error: {e}" } },
+                None => rsx! { p { class: "companies-muted", "Loading..." } },
+            }
+        }
+    }
+}
@@ -0,0 +1,1328 @@
+// Event detail for attendees: their registration, live waitlist position and,
+// once promoted, the offer to confirm before the deadline runs out. Below it
+// registrants can write to the organizers, and the event itself is split into
+// About (with the organization's custom fields), Agenda, Attendees, Networking,
+// Materials, Discussion, Photos and, for audience Q&A and polls while it runs,
+// Live tabs.
+
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use uuid::Uuid;
+
+use crate::application::ports::{
+    Attachment, AttachmentUpload, Attendee, AttendeeStatus, ClientConfig, EventProgram, LiveChange, LivePoll, LiveQuestion, MyRegistration,
+    RegistrationState, RegistrationWindow, SpotState,
+};
+use crate::application::services::{
+    apply_live_change, apply_own_change, format_countdown, format_file_size, CommentThread,
+};
+use crate::infrastructure::download::save_bytes;
+use crate::infrastructure::live_stream::LiveStream;
+use crate::infrastructure::registration_stream::RegistrationStream;
+use crate::lib::components::{
+    Accordion, AccordionItem, Avatar, AvatarGroup, AvatarPerson, AvatarSize, Tab, Tabs,
+};
+use crate::presentation::hooks::{use_event_changes, use_render_timing};
+use crate::presentation::pages::event_fields::EventFieldsSection;
+use crate::presentation::pages::messages::ContactOrganizersForm;
+use crate::presentation::pages::networking::NetworkingTab;
+use crate::presentation::pages::photos::PhotosTab;
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+const EVENT_DETAIL_CSS: Asset = asset!("/assets/event_detail.css");
+
+fn tabs() -> Vec<Tab> {
+    vec![
+        Tab::new("about", "About"),
+        Tab::new("agenda", "Agenda"),
+        Tab::new("attendees", "Attendees"),
+        Tab::new("networking", "Networking"),
+        Tab::new("materials", "Materials"),
+        Tab::new("discussion", "Discussion"),
+        Tab::new("photos", "Photos"),
+        Tab::new("live", "Live"),
+    ]
+}
+
+// Days once the deadline is far off, a ticking clock for the last two days
+fn time_until(at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let remaining = at - now;
+    if remaining.num_days() >= 2 {
+        format!("{} days", remaining.num_days())
+    } else {
+        format_countdown(remaining)
+    }
+}
+
+fn registration_status(window: RegistrationWindow, now: DateTime<Utc>) -> String {
+    match (window.state, window.opens_at, window.closes_at) {
+        (RegistrationState::NotYetOpen, Some(opens_at), _) if opens_at > now => {
+            format!("Registration opens in {}", time_until(opens_at, now))
+        }
+        (RegistrationState::NotYetOpen, Some(_), _) => "Registration has opened".to_string(),
+        (RegistrationState::NotYetOpen, None, _) => "Registration hasn't opened yet".to_string(),
+        (RegistrationState::Closed, _, _) => "Registration is closed".to_string(),
+        (_, _, Some(closes_at)) if closes_at <= now => "Registration is closed".to_string(),
+        (RegistrationState::Full, _, _) => "Fully booked".to_string(),
+        (RegistrationState::WaitlistOnly, _, _) => "Fully booked; new registrations join the waitlist".to_string(),
+        (RegistrationState::Open, _, Some(closes_at)) => {
+            format!("Registration is open and closes in {}", time_until(closes_at, now))
+        }
+        (RegistrationState::Open, _, None) => "Registration is open".to_string(),
+    }
+}
+
+#[component]
+pub fn EventDetailPage(container: AppContainer, event_id: Uuid) -> Element {
+    use_render_timing("EventDetailPage");
+    // Bumped whenever the server reports a change so the registration is fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let mut now = use_signal(Utc::now);
+    let mut notice = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+    let mut confirming = use_signal(|| false);
+    let mut stream = use_signal(|| None::<RegistrationStream>);
+    let mut tab = use_signal(|| "about".to_string());
+
+    let changes = use_event_changes(&container.events);
+    let event = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = changes();
+            async move { svc.get(event_id).await }
+        }
+    });
+
+    let program = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = changes();
+            async move { svc.program(event_id).await }
+        }
+    });
+
+    let registration = use_resource({
+        let svc = container.registrations.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.my_registration(event_id).await }
+        }
+    });
+
+    use_effect({
+        let url = container.registrations.notification_stream_url();
+        move || {
+            let opened = RegistrationStream::open(&url, move |name| {
+                match name.as_str() {
+                    "promoted" => notice.set(Some("A spot opened up for you!".to_string())),
+                    "promotion_expired" => notice.set(Some(
+                        "Your offer expired and the spot went to the next person on the waitlist.".to_string(),
+                    )),
+                    _ => {}
+                }
+                refresh += 1;
+            });
+            match opened {
+                Ok(opened) => stream.set(Some(opened)),
+                Err(e) => log::warn!("{}", e),
+            }
+        }
+    });
+
+    use_drop(move || {
+        // Dropping the stream closes the connection
+        stream.take();
+    });
+
+    // Drives the countdown
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(1_000).await;
+            now.set(Utc::now());
+        }
+    });
+
+    let confirm = {
+        let svc = container.registrations.clone();
+        move |registration_id: Uuid| {
+            let svc = svc.clone();
+            spawn(async move {
+                confirming.set(true);
+                match svc.confirm_spot(registration_id).await {
+                    Ok(()) => {
+                        error.set(None);
+                        notice.set(Some("Your spot is confirmed. See you there!".to_string()));
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                refresh += 1;
+                confirming.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: EVENT_DETAIL_CSS }
+
+        div { class: "event-detail-page",
+            match &*event.read() {
+                Some(Ok(ev)) => rsx! {
+                    h1 { "{ev.title}" }
+                    p { class: "event-detail-muted",
+                        {format!("{} @ {}", ev.start_date.format("%Y-%m-%d %H:%M"), ev.location.as_deref().unwrap_or("TBA"))}
+                    }
+                    if let Some(slug) = ev.slug.clone() {
+                        Link { class: "event-detail-muted", to: Route::PublicEvent { slug }, "Public page" }
+                        " · "
+                    }
+                    Link { class: "event-detail-muted", to: Route::PrintProgram { event_id }, "Print program" }
+                    if let Some(window) = ev.registration {
+                        p { class: "event-detail-registration-state", {registration_status(window, now())} }
+                    }
+                },
+                Some(Err(e)) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+
+            if let Some(message) = notice() {
+                p { class: "event-detail-notice", "{message}" }
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+
+            match &*registration.read() {
+                Some(Ok(Some(MyRegistration { id, state, .. }))) => {
+                    let id = *id;
+                    match state.clone() {
+                        SpotState::Waitlisted { position, waitlist_size } => rsx! {
+                            div { class: "event-detail-card",
+                                h2 { "You're on the waitlist" }
+                                p { class: "event-detail-position", "#{position}" }
+                                p { class: "event-detail-muted",
+                                    "of {waitlist_size} waiting. This updates live; we'll let you know when a spot opens up."
+                                }
+                            }
+                        },
+                        SpotState::Offered { confirm_by } => {
+                            let remaining = confirm_by - now();
+                            let expired = remaining.num_seconds() <= 0;
+                            rsx! {
+                                div { class: "event-detail-card event-detail-promoted",
+                                    h2 { "You've been promoted from the waitlist!" }
+                                    if expired {
+                                        p { "The offer has expired." }
+                                    } else {
+                                        p { "Confirm your spot before the offer runs out:" }
+                                        p { class: "event-detail-countdown", {format_countdown(remaining)} }
+                                        p { class: "event-detail-muted",
+                                            {format!("Deadline {}", confirm_by.format("%Y-%m-%d %H:%M UTC"))}
+                                        }
+                                    }
+                                    button {
+                                        r#type: "button",
+                                        class: "event-detail-button",
+                                        disabled: expired || confirming(),
+                                        onclick: {
+                                            let confirm = confirm.clone();
+                                            move |_| confirm(id)
+                                        },
+                                        if confirming() { "Confirming..." } else { "Confirm my spot" }
+                                    }
+                                }
+                            }
+                        }
+                        SpotState::Confirmed => rsx! {
+                            div { class: "event-detail-card",
+                                h2 { "You're registered" }
+                            }
+                        },
+                        SpotState::CheckedIn => rsx! {
+                            CertificateCard { container: container.clone(), registration_id: id }
+                        },
+                        SpotState::Cancelled => rsx! {
+                            div { class: "event-detail-card",
+                                p { class: "event-detail-muted", "Your registration for this event was cancelled." }
+                            }
+                        },
+                    }
+                }
+                Some(Ok(None)) => rsx! {
+                    p { class: "event-detail-muted", "You're not registered for this event." }
+                },
+                Some(Err(e)) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+                None => rsx! {},
+            }
+            if matches!(&*registration.read(), Some(Ok(Some(_)))) {
+                div { class: "event-detail-card",
+                    h2 { "Questions for the organizers?" }
+                    ContactOrganizersForm { container: container.clone(), event_id }
+                }
+            }
+
+            Tabs {
+                tabs: tabs(),
+                active: tab(),
+                on_change: move |id| tab.set(id),
+                label: "Event sections".to_string(),
+                match (tab().as_str(), &*program.read()) {
+                    ("attendees", _) => rsx! { AttendeesTab { container: container.clone(), event_id } },
+                    ("networking", _) => rsx! { NetworkingTab { container: container.clone(), event_id } },
+                    ("materials", _) => rsx! { MaterialsTab { container: container.clone(), event_id } },
+                    ("discussion", _) => rsx! { DiscussionTab { container: container.clone(), event_id } },
+                    ("photos", _) => rsx! { PhotosTab { container: container.clone(), event_id } },
+                    ("live", _) => rsx! { LiveTab { container: container.clone(), event_id } },
+                    ("agenda", Some(Ok(program))) => rsx! { AgendaTab { program: program.clone() } },
+                    (_, Some(Ok(program))) => rsx! { AboutTab { container: container.clone(), event_id, program: program.clone() } },
+                    (_, Some(Err(e))) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+                    (_, None) => rsx! { p { "Loading..." } },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CertificateCard(container: AppContainer, registration_id: Uuid) -> Element {
+    let mut downloading = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let download = {
+        let svc = container.certificates.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                downloading.set(true);
+                let saved = match svc.download(registration_id).await {
+                    Ok(bytes) => save_bytes("certificate-of-attendance.pdf", "application/pdf", &bytes),
+                    Err(e) => Err(e),
+                };
+                error.set(saved.err());
+                downloading.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div { class: "event-detail-card",
+            h2 { "You attended this event" }
+            p { class: "event-detail-muted", "Thanks for coming! Your certificate of attendance is ready." }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            button {
+                r#type: "button",
+                class: "event-detail-button",
+                disabled: downloading(),
+                onclick: download,
+                if downloading() { "Preparing..." } else { "Download certificate" }
+            }
+        }
+    }
+}
+
+#[component]
+fn AboutTab(container: AppContainer, event_id: Uuid, program: EventProgram) -> Element {
+    let place = [program.location_name.clone(), program.address.clone()]
+        .into_iter()
+        .flatten()
+        .reduce(|name, address| format!("{name}, {address}"));
+    let weather = program.forecast.as_ref().map(|forecast| {
+        let mut parts: Vec<String> = forecast.conditions().into_iter().collect();
+        parts.push(format!("{:.0}–{:.0} °C", forecast.min_temperature_c, forecast.max_temperature_c));
+        if forecast.precipitation_mm > 0.0 {
+            parts.push(format!("{:.1} mm", forecast.precipitation_mm));
+        }
+        parts.push(format!("wind up to {:.0} m/s", forecast.max_wind_speed_ms));
+        (forecast.date.format("%A %-d %B").to_string(), parts.join(", "))
+    });
+
+    rsx! {
+        dl { class: "event-detail-facts",
+            dt { "When" }
+            dd {
+                {format!("{} – {}", program.start_date.format("%A %-d %B %Y, %H:%M"), program.end_date.format("%H:%M"))}
+            }
+            if let Some(place) = place {
+                dt { "Where" }
+                dd { "{place}" }
+            }
+            if let Some((day, summary)) = weather {
+                dt { "Weather" }
+                dd {
+                    "{summary}"
+                    span { class: "event-detail-muted", " · {day}, forecast from MET Norway" }
+                }
+            }
+        }
+        for paragraph in program.description.split("\n\n").filter(|text| !text.trim().is_empty()) {
+            p { "{paragraph}" }
+        }
+        EventFieldsSection { container, event_id }
+    }
+}
+
+#[component]
+fn AgendaTab(program: EventProgram) -> Element {
+    rsx! {
+        if program.agenda.is_empty() {
+            p { class: "event-detail-muted", "The agenda hasn't been published yet." }
+        }
+        ol { class: "event-detail-agenda",
+            for item in program.agenda.iter() {
+                li {
+                    span { class: "event-detail-agenda-time", {item.time.clone().unwrap_or_default()} }
+                    div {
+                        strong { "{item.title}" }
+                        if let Some(speaker) = item.speaker.clone() {
+                            span { class: "event-detail-muted", " · {speaker}" }
+                        }
+                        if let Some(description) = item.description.clone() {
+                            p { "{description}" }
+                        }
+                    }
+                }
+            }
+        }
+        if !program.speakers.is_empty() {
+            h2 { "Speakers" }
+            AvatarGroup {
+                people: program
+                    .speakers
+                    .iter()
+                    .map(|speaker| AvatarPerson::new(speaker.name.clone()).with_src(speaker.photo_url.clone()))
+                    .collect::<Vec<_>>(),
+                size: AvatarSize::Large,
+            }
+            Accordion {
+                for speaker in program.speakers.iter() {
+                    AccordionItem {
+                        key: "{speaker.name}",
+                        title: [Some(speaker.name.clone()), speaker.title.clone(), speaker.company.clone()]
+                            .into_iter()
+                            .flatten()
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        p { {speaker.bio.clone().unwrap_or_else(|| "No bio yet.".to_string())} }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Mounted only while its tab is selected, so the list is fetched on demand
+#[component]
+fn AttendeesTab(container: AppContainer, event_id: Uuid) -> Element {
+    let attendees = use_resource({
+        let svc = container.check_in.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.attendees(event_id).await }
+        }
+    });
+
+    match &*attendees.read() {
+        Some(Ok(attendees)) => {
+            let mut coming: Vec<&Attendee> = attendees
+                .iter()
+                .filter(|attendee| matches!(attendee.status, AttendeeStatus::Expected | AttendeeStatus::CheckedIn))
+                .collect();
+            coming.sort_by_key(|attendee| attendee.display_name().to_lowercase());
+            let waitlisted = attendees
+                .iter()
+                .filter(|attendee| attendee.status == AttendeeStatus::Waitlisted)
+                .count();
+            let people: Vec<AvatarPerson> = coming
+                .iter()
+                .map(|attendee| AvatarPerson::new(attendee.display_name()))
+                .collect();
+            rsx! {
+                if coming.is_empty() {
+                    p { class: "event-detail-muted", "Nobody has registered yet." }
+                } else {
+                    AvatarGroup { people, max: 8 }
+                }
+                ul { class: "event-detail-attendees",
+                    for attendee in coming {
+                        li { key: "{attendee.registration_id}",
+                            Avatar { name: attendee.display_name(), size: AvatarSize::Small }
+                            span { {attendee.display_name()} }
+                            if attendee.guest_count > 0 {
+                                span { class: "event-detail-muted", "+{attendee.guest_count}" }
+                            }
+                        }
+                    }
+                }
+                if waitlisted > 0 {
+                    p { class: "event-detail-muted", "{waitlisted} more on the waitlist" }
+                }
+            }
+        }
+        Some(Err(e)) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+        None => rsx! { p { "Loading..." } },
+    }
+}
+
+/// Mounted only while its tab is selected, like the attendee list
+/// Mounted only while its tab is selected, so the files are listed on demand
+#[component]
+fn MaterialsTab(container: AppContainer, event_id: Uuid) -> Element {
+    // Bumped after uploading or removing so the list is fetched again
+    let mut refresh = use_signal(|| 0u32);
+
+    let library = use_resource({
+        let svc = container.attachments.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.library(event_id).await }
+        }
+    });
+
+    match &*library.read() {
+        Some(Ok(library)) => rsx! {
+            if library.can_manage {
+                UploadAttachmentForm { container: container.clone(), event_id, on_uploaded: move |_| refresh += 1 }
+                SignatureForm { container: container.clone(), event_id }
+            }
+            if library.attachments.is_empty() {
+                p { class: "event-detail-muted", "No slides, handouts or maps have been shared yet." }
+            }
+            ul { class: "event-detail-attachments",
+                for attachment in library.attachments.iter() {
+                    AttachmentItem {
+                        key: "{attachment.id}",
+                        container: container.clone(),
+                        event_id,
+                        attachment: attachment.clone(),
+                        can_manage: library.can_manage,
+                        on_removed: move |_| refresh += 1,
+                    }
+                }
+            }
+        },
+        Some(Err(e)) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+        None => rsx! { p { "Loading..." } },
+    }
+}
+
+#[component]
+fn AttachmentItem(
+    container: AppContainer,
+    event_id: Uuid,
+    attachment: Attachment,
+    can_manage: bool,
+    on_removed: EventHandler<()>,
+) -> Element {
+    let mut error = use_signal(|| None::<String>);
+    let mut downloading = use_signal(|| false);
+    let id = attachment.id;
+
+    let download = {
+        let svc = container.attachments.clone();
+        let attachment = attachment.clone();
+        move |_| {
+            let svc = svc.clone();
+            let attachment = attachment.clone();
+            spawn(async move {
+                downloading.set(true);
+                let saved = match svc.download(event_id, attachment.id).await {
+                    Ok(bytes) => save_bytes(&attachment.filename, &attachment.mime_type, &bytes),
+                    Err(e) => Err(e),
+                };
+                error.set(saved.err());
+                downloading.set(false);
+            });
+        }
+    };
+
+    let remove = {
+        let svc = container.attachments.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.delete(event_id, id).await {
+                    Ok(()) => on_removed.call(()),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        li { class: "event-detail-attachment",
+            div {
+                strong { {attachment.display_name().to_string()} }
+                span { class: "event-detail-muted",
+                    {format!(" · {} · {}", attachment.category, format_file_size(attachment.file_size))}
+                }
+                if can_manage {
+                    span { class: "event-detail-muted",
+                        if attachment.is_public { " · Public" } else { " · Attendees only" }
+                        {format!(" · {} downloads", attachment.download_count)}
+                    }
+                }
+                if let Some(description) = attachment.description.clone() {
+                    p { "{description}" }
+                }
+                if let Some(message) = error() {
+                    p { class: "event-detail-error", "{message}" }
+                }
+            }
+            div { class: "event-detail-comment-actions",
+                button { r#type: "button", disabled: downloading(), onclick: download,
+                    if downloading() { "Downloading..." } else { "Download" }
+                }
+                if can_manage {
+                    button { r#type: "button", onclick: remove, "Remove" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn UploadAttachmentForm(container: AppContainer, event_id: Uuid, on_uploaded: EventHandler<()>) -> Element {
+    // The chosen file's name and contents
+    let mut file = use_signal(|| None::<(String, Vec<u8>)>);
+    let mut title = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut is_public = use_signal(|| false);
+    let mut uploading = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let config = use_context::<Signal<ClientConfig>>();
+
+    let choose_file = move |evt: FormEvent| async move {
+        if let Some(engine) = evt.files() {
+            if let Some(file_name) = engine.files().first() {
+                let max_bytes = config.read().max_upload_bytes;
+                match engine.read_file(file_name).await {
+                    // Refused here rather than after sending the whole file
+                    Some(bytes) if bytes.len() as u64 > max_bytes => {
+                        file.set(None);
+                        error.set(Some(format!(
+                            "'{}' is larger than the {} MB upload limit",
+                            file_name,
+                            max_bytes / (1024 * 1024)
+                        )));
+                    }
+                    Some(bytes) => {
+                        file.set(Some((file_name.clone(), bytes)));
+                        error.set(None);
+                    }
+                    None => error.set(Some(format!("Could not read '{}'", file_name))),
+                }
+            }
+        }
+    };
+
+    let submit = {
+        let svc = container.attachments.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let Some((filename, bytes)) = file() else { return };
+            let svc = svc.clone();
+            let upload = AttachmentUpload {
+                filename,
+                // Guessed from the extension by the service
+                mime_type: String::new(),
+                bytes,
+                title: Some(title()),
+                description: Some(description()),
+                is_public: is_public(),
+            };
+            spawn(async move {
+                uploading.set(true);
+                match svc.upload(event_id, upload).await {
+                    Ok(_) => {
+                        file.set(None);
+                        title.set(String::new());
+                        description.set(String::new());
+                        error.set(None);
+                        on_uploaded.call(());
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                uploading.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "event-detail-comment-form", onsubmit: submit,
+            input {
+                r#type: "file",
+                aria_label: "File",
+                onchange: choose_file,
+            }
+            input {
+                r#type: "text",
+                aria_label: "Title",
+                placeholder: "Title (defaults to the file name)",
+                value: "{title}",
+                oninput: move |evt| title.set(evt.value()),
+            }
+            textarea {
+                rows: "2",
+                aria_label: "Description",
+                placeholder: "What's in it? (optional)",
+                value: "{description}",
+                oninput: move |evt| description.set(evt.value()),
+            }
+            label {
+                input { r#type: "checkbox", checked: is_public(), onchange: move |evt| is_public.set(evt.checked()) }
+                " Also show on the public event page"
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            button {
+                r#type: "submit",
+                disabled: uploading() || file().is_none(),
+                if uploading() { "Uploading..." } else { "Upload" }
+            }
+        }
+    }
+}
+
+/// Organizers' signature, printed on every certificate of attendance for the event
+#[component]
+fn SignatureForm(container: AppContainer, event_id: Uuid) -> Element {
+    let mut file = use_signal(|| None::<(String, Vec<u8>)>);
+    let mut saving = use_signal(|| false);
+    let mut notice = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+
+    let choose_file = move |evt: FormEvent| async move {
+        if let Some(engine) = evt.files() {
+            if let Some(file_name) = engine.files().first() {
+                match engine.read_file(file_name).await {
+                    Some(bytes) => file.set(Some((file_name.clone(), bytes))),
+                    None => error.set(Some(format!("Could not read '{}'", file_name))),
+                }
+            }
+        }
+    };
+
+    let submit = {
+        let svc = container.certificates.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let Some((filename, bytes)) = file() else { return };
+            let svc = svc.clone();
+            spawn(async move {
+                saving.set(true);
+                match svc.set_signature(event_id, &filename, bytes).await {
+                    Ok(()) => {
+                        file.set(None);
+                        error.set(None);
+                        notice.set(Some("Certificates will now carry this signature.".to_string()));
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    let remove = {
+        let svc = container.certificates.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.remove_signature(event_id).await {
+                    Ok(()) => {
+                        error.set(None);
+                        notice.set(Some("Certificates will be issued without a signature.".to_string()));
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "event-detail-comment-form", onsubmit: submit,
+            h3 { "Certificate signature" }
+            p { class: "event-detail-muted",
+                "Checked-in attendees can download a certificate of attendance. Upload a PNG of your signature to sign them."
+            }
+            input {
+                r#type: "file",
+                accept: "image/png",
+                aria_label: "Signature image",
+                onchange: choose_file,
+            }
+            if let Some(message) = notice() {
+                p { class: "event-detail-notice", "{message}" }
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            div { class: "event-detail-signature-actions",
+                button {
+                    r#type: "submit",
+                    disabled: saving() || file().is_none(),
+                    if saving() { "Saving..." } else { "Save signature" }
+                }
+                button { r#type: "button", onclick: remove, "Remove signature" }
+            }
+        }
+    }
+}
+
+#[component]
+fn DiscussionTab(container: AppContainer, event_id: Uuid) -> Element {
+    // Bumped after posting or deleting so the thread is fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let reply_to = use_signal(|| None::<Uuid>);
+
+    let threads = use_resource({
+        let svc = container.discussion.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.threads(event_id).await }
+        }
+    });
+
+    rsx! {
+        CommentForm {
+            container: container.clone(),
+            event_id,
+            parent_id: None,
+            on_posted: move |_| refresh += 1,
+        }
+        match &*threads.read() {
+            Some(Ok(threads)) if threads.is_empty() => rsx! {
+                p { class: "event-detail-muted", "No questions yet. Ask about parking, accommodation or anything else." }
+            },
+            Some(Ok(threads)) => rsx! {
+                ul { class: "event-detail-comments",
+                    for thread in threads.iter() {
+                        CommentItem {
+                            key: "{thread.comment.id}",
+                            container: container.clone(),
+                            event_id,
+                            thread: thread.clone(),
+                            reply_to,
+                            on_change: move |_| refresh += 1,
+                        }
+                    }
+                }
+            },
+            Some(Err(e)) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+            None => rsx! { p { "Loading..." } },
+        }
+    }
+}
+
+#[component]
+fn CommentItem(
+    container: AppContainer,
+    event_id: Uuid,
+    thread: CommentThread,
+    // Only one reply form is open at a time
+    mut reply_to: Signal<Option<Uuid>>,
+    on_change: EventHandler<()>,
+) -> Element {
+    let mut error = use_signal(|| None::<String>);
+    let comment = thread.comment.clone();
+    let id = comment.id;
+    let author = comment.author_name.clone().unwrap_or_else(|| "Former member".to_string());
+
+    let delete = {
+        let svc = container.discussion.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.delete(event_id, id).await {
+                    Ok(()) => on_change.call(()),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        li { class: "event-detail-comment",
+            match comment.content.clone() {
+                Some(content) => rsx! {
+                    div { class: "event-detail-comment-header",
+                        Avatar { name: author.clone(), size: AvatarSize::Small }
+                        strong { "{author}" }
+                        span { class: "event-detail-muted", {comment.created_at.format("%Y-%m-%d %H:%M").to_string()} }
+                    }
+                    p { class: "event-detail-comment-body", "{content}" }
+                    div { class: "event-detail-comment-actions",
+                        button {
+                            r#type: "button",
+                            onclick: move |_| reply_to.set(if reply_to() == Some(id) { None } else { Some(id) }),
+                            "Reply"
+                        }
+                        if comment.can_delete {
+                            button { r#type: "button", onclick: delete, "Delete" }
+                        }
+                    }
+                },
+                None => rsx! {
+                    p { class: "event-detail-muted", "This comment was deleted." }
+                },
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            if reply_to() == Some(id) {
+                CommentForm {
+                    container: container.clone(),
+                    event_id,
+                    parent_id: Some(id),
+                    on_posted: move |_| {
+                        reply_to.set(None);
+                        on_change.call(());
+                    },
+                }
+            }
+            if !thread.replies.is_empty() {
+                ul { class: "event-detail-comments event-detail-replies",
+                    for reply in thread.replies.iter() {
+                        CommentItem {
+                            key: "{reply.comment.id}",
+                            container: container.clone(),
+                            event_id,
+                            thread: reply.clone(),
+                            reply_to,
+                            on_change,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CommentForm(
+    container: AppContainer,
+    event_id: Uuid,
+    parent_id: Option<Uuid>,
+    on_posted: EventHandler<()>,
+) -> Element {
+    let mut content = use_signal(String::new);
+    let mut posting = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let submit = {
+        let svc = container.discussion.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let svc = svc.clone();
+            spawn(async move {
+                posting.set(true);
+                match svc.post(event_id, parent_id, &content()).await {
+                    Ok(_) => {
+                        content.set(String::new());
+                        error.set(None);
+                        on_posted.call(());
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                posting.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "event-detail-comment-form", onsubmit: submit,
+            textarea {
+                rows: if parent_id.is_some() { "2" } else { "3" },
+                aria_label: if parent_id.is_some() { "Reply" } else { "Ask a question" },
+                placeholder: if parent_id.is_some() { "Write a reply" } else { "Ask the organizer or other attendees" },
+                value: "{content}",
+                oninput: move |evt| content.set(evt.value()),
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            button {
+                r#type: "submit",
+                disabled: posting() || content().trim().is_empty(),
+                if posting() { "Posting..." } else if parent_id.is_some() { "Reply" } else { "Post" }
+            }
+        }
+    }
+}
+
+/// Mounted only while its tab is selected, so the stream is only open while
+/// someone is looking at it
+#[component]
+fn LiveTab(container: AppContainer, event_id: Uuid) -> Element {
+    let mut questions = use_signal(Vec::<LiveQuestion>::new);
+    let mut polls = use_signal(Vec::<LivePoll>::new);
+    // `None` while loading; otherwise whether the event is running and the user may moderate
+    let mut session = use_signal(|| None::<Result<(bool, bool), String>>);
+    let mut stream = use_signal(|| None::<LiveStream>);
+
+    use_future({
+        let svc = container.live.clone();
+        move || {
+            let svc = svc.clone();
+            async move {
+                // Subscribe first; the load below replaces anything that arrives
+                // meanwhile and later changes apply on top of it
+                let opened = LiveStream::open(&svc.stream_url(event_id), move |change| {
+                    apply_live_change(&mut questions.write(), &mut polls.write(), change);
+                });
+                match opened {
+                    Ok(opened) => stream.set(Some(opened)),
+                    Err(e) => log::warn!("{}", e),
+                }
+
+                match svc.session(event_id).await {
+                    Ok(loaded) => {
+                        questions.set(loaded.questions);
+                        polls.set(loaded.polls);
+                        session.set(Some(Ok((loaded.is_running, loaded.can_moderate))));
+                    }
+                    Err(e) => session.set(Some(Err(e))),
+                }
+            }
+        }
+    });
+
+    use_drop(move || {
+        // Dropping the stream closes the connection
+        stream.take();
+    });
+
+    let on_change = move |change: LiveChange| {
+        apply_own_change(&mut questions.write(), &mut polls.write(), change);
+    };
+
+    let (running, can_moderate) = match session() {
+        Some(Ok(flags)) => flags,
+        Some(Err(e)) => return rsx! { p { class: "event-detail-error", "Error: {e}" } },
+        None => return rsx! { p { "Loading..." } },
+    };
+
+    rsx! {
+        if !running {
+            p { class: "event-detail-muted", "Questions and polls open while the event is running." }
+        }
+
+        section { class: "event-detail-live-section", aria_label: "Polls",
+            h2 { "Polls" }
+            if running && can_moderate {
+                CreatePollForm { container: container.clone(), event_id, on_change }
+            }
+            if polls.read().is_empty() {
+                p { class: "event-detail-muted", "No polls yet." }
+            }
+            for poll in polls.read().iter().rev() {
+                LivePollCard {
+                    key: "{poll.id}",
+                    container: container.clone(),
+                    event_id,
+                    poll: poll.clone(),
+                    running,
+                    can_moderate,
+                    on_change,
+                }
+            }
+        }
+
+        section { class: "event-detail-live-section", aria_label: "Questions",
+            h2 { "Questions" }
+            if running {
+                AskQuestionForm { container: container.clone(), event_id, on_change }
+            }
+            if questions.read().is_empty() {
+                p { class: "event-detail-muted", "No questions yet." }
+            }
+            ul { class: "event-detail-questions",
+                for question in questions.read().iter() {
+                    LiveQuestionItem {
+                        key: "{question.id}",
+                        container: container.clone(),
+                        event_id,
+                        question: question.clone(),
+                        running,
+                        can_moderate,
+                        on_change,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn LiveQuestionItem(
+    container: AppContainer,
+    event_id: Uuid,
+    question: LiveQuestion,
+    running: bool,
+    can_moderate: bool,
+    on_change: EventHandler<LiveChange>,
+) -> Element {
+    let mut answering = use_signal(|| false);
+    let mut answer = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+    let id = question.id;
+    let author = question.author_name.clone().unwrap_or_else(|| "Former member".to_string());
+    let current_answer = question.answer.clone();
+
+    let toggle_upvote = {
+        let svc = container.live.clone();
+        let upvoted = !question.has_upvoted;
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.set_upvote(event_id, id, upvoted).await {
+                    Ok(question) => on_change.call(LiveChange::Question(question)),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let submit_answer = {
+        let svc = container.live.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.answer(event_id, id, &answer()).await {
+                    Ok(question) => {
+                        answering.set(false);
+                        error.set(None);
+                        on_change.call(LiveChange::Question(question));
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        li { class: "event-detail-question",
+            button {
+                r#type: "button",
+                class: if question.has_upvoted { "event-detail-upvote event-detail-upvoted" } else { "event-detail-upvote" },
+                aria_pressed: question.has_upvoted,
+                aria_label: "Upvote",
+                disabled: !running,
+                onclick: toggle_upvote,
+                "▲ {question.upvote_count}"
+            }
+            div {
+                p { class: "event-detail-comment-body", "{question.content}" }
+                p { class: "event-detail-muted", "{author}" }
+                if let Some(text) = &question.answer {
+                    p { class: "event-detail-answer", strong { "Answer: " } "{text}" }
+                }
+                if running && can_moderate {
+                    div { class: "event-detail-comment-actions",
+                        button {
+                            r#type: "button",
+                            onclick: move |_| {
+                                answer.set(current_answer.clone().unwrap_or_default());
+                                answering.toggle();
+                            },
+                            if question.answer.is_some() { "Edit answer" } else { "Answer" }
+                        }
+                    }
+                }
+                if answering() {
+                    form { class: "event-detail-comment-form", onsubmit: submit_answer,
+                        textarea {
+                            rows: "2",
+                            aria_label: "Answer",
+                            value: "{answer}",
+                            oninput: move |evt| answer.set(evt.value()),
+                        }
+                        button { r#type: "submit", disabled: answer().trim().is_empty(), "Save answer" }
+                    }
+                }
+                if let Some(message) = error() {
+                    p { class: "event-detail-error", "{message}" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn LivePollCard(
+    container: AppContainer,
+    event_id: Uuid,
+    poll: LivePoll,
+    running: bool,
+    can_moderate: bool,
+    on_change: EventHandler<LiveChange>,
+) -> Element {
+    let mut error = use_signal(|| None::<String>);
+    let poll_id = poll.id;
+    let total: i64 = poll.options.iter().map(|option| option.vote_count).sum();
+    let open = running && !poll.is_closed;
+
+    let vote = {
+        let svc = container.live.clone();
+        move |option_id: Uuid| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.vote(event_id, poll_id, option_id).await {
+                    Ok(poll) => on_change.call(LiveChange::Poll(poll)),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let close = {
+        let svc = container.live.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.close_poll(event_id, poll_id).await {
+                    Ok(poll) => on_change.call(LiveChange::Poll(poll)),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div { class: "event-detail-card event-detail-poll",
+            h3 { "{poll.question}" }
+            ul { class: "event-detail-poll-options",
+                for option in poll.options.iter() {
+                    {
+                        let option_id = option.id;
+                        let percent = if total > 0 { option.vote_count * 100 / total } else { 0 };
+                        let chosen = poll.voted_option_id == Some(option_id);
+                        let vote = vote.clone();
+                        rsx! {
+                            li { key: "{option_id}",
+                                button {
+                                    r#type: "button",
+                                    class: if chosen { "event-detail-poll-option event-detail-poll-chosen" } else { "event-detail-poll-option" },
+                                    aria_pressed: chosen,
+                                    disabled: !open,
+                                    onclick: move |_| vote(option_id),
+                                    span { class: "event-detail-poll-bar", style: "width: {percent}%" }
+                                    span { "{option.label}" }
+                                    span { class: "event-detail-muted", "{option.vote_count} ({percent}%)" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            p { class: "event-detail-muted",
+                if poll.is_closed { "Closed · {total} votes" } else { "{total} votes" }
+            }
+            if open && can_moderate {
+                button { r#type: "button", class: "event-detail-button", onclick: close, "Close poll" }
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn AskQuestionForm(container: AppContainer, event_id: Uuid, on_change: EventHandler<LiveChange>) -> Element {
+    let mut content = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let submit = {
+        let svc = container.live.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.ask(event_id, &content()).await {
+                    Ok(question) => {
+                        content.set(String::new());
+                        error.set(None);
+                        on_change.call(LiveChange::Question(question));
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "event-detail-comment-form", onsubmit: submit,
+            textarea {
+                rows: "2",
+                aria_label: "Ask a question",
+                placeholder: "Ask the speakers a question",
+                value: "{content}",
+                oninput: move |evt| content.set(evt.value()),
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            button { r#type: "submit", disabled: content().trim().is_empty(), "Ask" }
+        }
+    }
+}
+
+#[component]
+fn CreatePollForm(container: AppContainer, event_id: Uuid, on_change: EventHandler<LiveChange>) -> Element {
+    let mut question = use_signal(String::new);
+    let mut options = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let submit = {
+        let svc = container.live.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.create_poll(event_id, &question(), &options()).await {
+                    Ok(poll) => {
+                        question.set(String::new());
+                        options.set(String::new());
+                        error.set(None);
+                        on_change.call(LiveChange::Poll(poll));
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "event-detail-comment-form", onsubmit: submit,
+            input {
+                r#type: "text",
+                aria_label: "Poll question",
+                placeholder: "Poll question",
+                value: "{question}",
+                oninput: move |evt| question.set(evt.value()),
+            }
+            textarea {
+                rows: "3",
+                aria_label: "Options, one per line",
+                placeholder: "Options, one per line",
+                value: "{options}",
+                oninput: move |evt| options.set(evt.value()),
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            button { r#type: "submit", disabled: question().trim().is_empty(), "Start poll" }
+        }
+    }
+}
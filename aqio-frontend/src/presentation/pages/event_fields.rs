@@ -0,0 +1,176 @@
+// Custom fields on the About tab of the event page. Organizations define the
+// fields themselves, so each one is shown and edited by its type alone: a text
+// box, a number, a date picker, a checkbox or a list of the field's options.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{EventField, EventFieldType};
+use crate::application::services::format_field_value;
+use crate::AppContainer;
+
+const EVENT_FIELDS_CSS: Asset = asset!("/assets/event_fields.css");
+
+#[component]
+pub fn EventFieldsSection(container: AppContainer, event_id: Uuid) -> Element {
+    // Bumped after saving so the values are fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let mut editing = use_signal(|| false);
+
+    let fields = use_resource({
+        let svc = container.event_fields.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.event_fields(event_id).await }
+        }
+    });
+
+    let Some(Ok(fields)) = fields.read().clone() else {
+        // The description above stands on its own, so a failed fetch isn't shown
+        return rsx! {};
+    };
+    let filled: Vec<(String, String)> = fields
+        .fields
+        .iter()
+        .filter_map(|field| format_field_value(field).map(|value| (field.label.clone(), value)))
+        .collect();
+    if fields.fields.is_empty() || (filled.is_empty() && !fields.can_edit) {
+        return rsx! {};
+    }
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: EVENT_FIELDS_CSS }
+
+        section { class: "event-fields",
+            div { class: "event-fields-header",
+                h2 { "Details" }
+                if fields.can_edit && !editing() {
+                    button { r#type: "button", onclick: move |_| editing.set(true), "Edit details" }
+                }
+            }
+            if editing() {
+                EventFieldsForm {
+                    container: container.clone(),
+                    event_id,
+                    fields: fields.fields.clone(),
+                    on_done: move |saved: bool| {
+                        editing.set(false);
+                        if saved {
+                            refresh += 1;
+                        }
+                    },
+                }
+            } else if filled.is_empty() {
+                p { class: "event-detail-muted", "No details have been filled in yet." }
+            } else {
+                dl { class: "event-detail-facts",
+                    for (label, value) in filled {
+                        dt { "{label}" }
+                        dd { "{value}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn EventFieldsForm(
+    container: AppContainer,
+    event_id: Uuid,
+    fields: Vec<EventField>,
+    on_done: EventHandler<bool>,
+) -> Element {
+    let mut draft = use_signal(|| fields.clone());
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let mut set_value = move |index: usize, value: String| {
+        draft.write()[index].value = Some(value);
+    };
+
+    let save = {
+        let svc = container.event_fields.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let svc = svc.clone();
+            spawn(async move {
+                saving.set(true);
+                match svc.save_values(event_id, &draft()).await {
+                    Ok(_) => on_done.call(true),
+                    Err(e) => error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "event-fields-form", onsubmit: save,
+            for (index, field) in draft().into_iter().enumerate() {
+                div { key: "{field.id}", class: "event-fields-row",
+                    if field.field_type == EventFieldType::Boolean {
+                        label {
+                            input {
+                                r#type: "checkbox",
+                                checked: field.value.as_deref() == Some("true"),
+                                onchange: move |evt| set_value(index, evt.checked().to_string()),
+                            }
+                            " {field.label}"
+                        }
+                    } else {
+                        label { r#for: "event-field-{field.key}",
+                            "{field.label}"
+                            if field.is_required {
+                                span { class: "event-fields-required", " *" }
+                            }
+                        }
+                        match field.field_type {
+                            EventFieldType::Select => rsx! {
+                                select {
+                                    id: "event-field-{field.key}",
+                                    required: field.is_required,
+                                    onchange: move |evt| set_value(index, evt.value()),
+                                    option { value: "", selected: field.value.is_none(), "—" }
+                                    for choice in field.options.iter() {
+                                        option {
+                                            key: "{choice}",
+                                            value: "{choice}",
+                                            selected: field.value.as_deref() == Some(choice.as_str()),
+                                            "{choice}"
+                                        }
+                                    }
+                                }
+                            },
+                            field_type => rsx! {
+                                input {
+                                    id: "event-field-{field.key}",
+                                    r#type: match field_type {
+                                        EventFieldType::Number => "number",
+                                        EventFieldType::Date => "date",
+                                        _ => "text",
+                                    },
+                                    // Numbers may have decimals; other inputs ignore it
+                                    step: "any",
+                                    required: field.is_required,
+                                    value: field.value.clone().unwrap_or_default(),
+                                    oninput: move |evt| set_value(index, evt.value()),
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            div { class: "event-fields-actions",
+                button { r#type: "submit", class: "event-detail-button", disabled: saving(),
+                    if saving() { "Saving..." } else { "Save details" }
+                }
+                button { r#type: "button", disabled: saving(), onclick: move |_| on_done.call(false), "Cancel" }
+            }
+        }
+    }
+}
@@ -1,23 +1,84 @@
+use crate::presentation::hooks::{use_event_changes, use_render_timing};
+use crate::presentation::routes::Route;
 use crate::AppContainer;
 use dioxus::prelude::*;
+use uuid::Uuid;
 
 #[component]
 pub fn EventsPage(container: AppContainer) -> Element {
-    let events = use_resource(move || {
+    use_render_timing("EventsPage");
+    let changes = use_event_changes(&container.events);
+    let mut error = use_signal(|| None::<String>);
+
+    let events = use_resource({
         let svc = container.events.clone();
-        async move { svc.list().await }
+        move || {
+            let svc = svc.clone();
+            let _ = changes();
+            async move { svc.list().await }
+        }
     });
 
+    // The store drops the event from the list at once and puts it back if the API refuses
+    let cancel = {
+        let svc = container.events.clone();
+        move |(event_id, title): (Uuid, String)| {
+            let confirmed = web_sys::window()
+                .and_then(|window| window.confirm_with_message(&format!("Cancel \"{}\"?", title)).ok())
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.cancel(event_id).await {
+                    Ok(()) => error.set(None),
+                    Err(e) => error.set(Some(format!("Couldn't cancel \"{}\": {}", title, e))),
+                }
+            });
+        }
+    };
+
     rsx! {
         div { class: "container",
             h1 { "Events" }
+            EventsViewSwitch {}
+            " "
+            Link { to: Route::NewEvent {}, "Create event" }
+            if let Some(message) = error() {
+                p { style: "color:red;", "{message}" }
+            }
             match &*events.read() {
                 Some(Ok(list)) => rsx! {
                     ul {
                         for ev in list.iter() {
                             li { key: "{ev.id}",
-                                strong { "{ev.title}" }
+                                Link { to: Route::EventDetail { event_id: ev.id }, strong { "{ev.title}" } }
                                 span { {format!(" – {} @ {}", ev.start_date, ev.location.as_deref().unwrap_or("TBA"))} }
+                                " "
+                                Link { to: Route::EventInvitations { event_id: ev.id }, "Invitations" }
+                                " · "
+                                Link { to: Route::EventCheckIn { event_id: ev.id }, "Check-in" }
+                                " · "
+                                Link { to: Route::EventBudget { event_id: ev.id }, "Budget" }
+                                " · "
+                                Link { to: Route::EventSponsors { event_id: ev.id }, "Sponsors" }
+                                " · "
+                                Link { to: Route::EventTravel { event_id: ev.id }, "Travel" }
+                                " · "
+                                Link { to: Route::EventCatering { event_id: ev.id }, "Catering" }
+                                " · "
+                                Link { to: Route::EventSeating { event_id: ev.id }, "Seating" }
+                                " · "
+                                button {
+                                    r#type: "button",
+                                    onclick: {
+                                        let cancel = cancel.clone();
+                                        let target = (ev.id, ev.title.clone());
+                                        move |_| cancel(target.clone())
+                                    },
+                                    "Cancel event"
+                                }
                             }
                         }
                     }
@@ -28,3 +89,16 @@ pub fn EventsPage(container: AppContainer) -> Element {
         }
     }
 }
+
+/// Links between the list, calendar, agenda and map views of the events
+#[component]
+pub fn EventsViewSwitch() -> Element {
+    rsx! {
+        nav { class: "events-view-switch",
+            Link { to: Route::Events {}, active_class: "active", "List" }
+            Link { to: Route::EventsCalendar {}, active_class: "active", "Calendar" }
+            Link { to: Route::EventsAgenda {}, active_class: "active", "Agenda" }
+            Link { to: Route::EventsMap {}, active_class: "active", "Map" }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// Agenda view of upcoming events: an endless list grouped by day or week with
+// the current heading pinned to the top. Only rows near the viewport are
+// rendered, and further weeks are fetched as the list is scrolled.
+
+use chrono::{Duration, Local};
+use dioxus::prelude::*;
+
+use crate::application::agenda::{AgendaGrouping, AgendaLayout, AgendaRow};
+use crate::application::calendar::local_midnight;
+use crate::application::ports::EventListItem;
+use crate::presentation::pages::events::EventsViewSwitch;
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+const EVENTS_AGENDA_CSS: Asset = asset!("/assets/events_agenda.css");
+
+// Each request covers this many days
+const CHUNK_DAYS: i64 = 28;
+// Stop looking ahead after this many empty chunks in a row, about a year
+const MAX_EMPTY_CHUNKS: u32 = 13;
+// Rows rendered beyond the viewport so fast scrolling doesn't show blanks
+const OVERSCAN_PX: f64 = 600.0;
+// Fetch the next chunk once the end of the list is this close
+const LOAD_AHEAD_PX: f64 = 1200.0;
+
+#[component]
+pub fn EventsAgendaPage(container: AppContainer) -> Element {
+    let mut grouping = use_signal(|| AgendaGrouping::Day);
+    let mut events = use_signal(Vec::<EventListItem>::new);
+    let mut loaded_until = use_signal(|| local_midnight(Local::now().date_naive()));
+    let mut loading = use_signal(|| false);
+    let mut exhausted = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut scroll_top = use_signal(|| 0.0f64);
+    let mut viewport = use_signal(|| 600.0f64);
+
+    let layout = use_memo(move || AgendaLayout::new(&events.read(), grouping()));
+
+    let load_more = use_callback({
+        let svc = container.events.clone();
+        move |_: ()| {
+            if *loading.peek() || *exhausted.peek() {
+                return;
+            }
+            let svc = svc.clone();
+            loading.set(true);
+            spawn(async move {
+                let mut empty = 0;
+                loop {
+                    let from = *loaded_until.peek();
+                    let to = from + Duration::days(CHUNK_DAYS);
+                    match svc.between(from, to).await {
+                        Ok(found) => {
+                            loaded_until.set(to);
+                            if !found.is_empty() {
+                                events.write().extend(found);
+                                break;
+                            }
+                            empty += 1;
+                            if empty >= MAX_EMPTY_CHUNKS {
+                                exhausted.set(true);
+                                break;
+                            }
+                        }
+                        Err(e) => {
+                            error.set(Some(e));
+                            break;
+                        }
+                    }
+                }
+                loading.set(false);
+            });
+        }
+    });
+
+    // Keep the list filled past the bottom of the viewport, both initially and while scrolling
+    use_effect(move || {
+        let remaining = layout.read().height - scroll_top() - viewport();
+        if remaining < LOAD_AHEAD_PX && !loading() && !exhausted() && error().is_none() {
+            load_more.call(());
+        }
+    });
+
+    let current = layout.read();
+    let visible = current.visible(scroll_top(), viewport(), OVERSCAN_PX);
+    let heading = current.heading_at(scroll_top()).map(str::to_string);
+    let time_format = match grouping() {
+        AgendaGrouping::Day => "%H:%M",
+        AgendaGrouping::Week => "%a %H:%M",
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: EVENTS_AGENDA_CSS }
+
+        div { class: "container events-agenda-page",
+            div { class: "events-agenda-toolbar",
+                h1 { "Agenda" }
+                EventsViewSwitch {}
+            }
+            div { class: "events-agenda-grouping",
+                for (option, label) in [(AgendaGrouping::Day, "By day"), (AgendaGrouping::Week, "By week")] {
+                    button {
+                        r#type: "button",
+                        class: if grouping() == option { "active" } else { "" },
+                        onclick: move |_| grouping.set(option),
+                        "{label}"
+                    }
+                }
+            }
+
+            div {
+                class: "events-agenda-scroll",
+                onmounted: move |evt| async move {
+                    if let Ok(rect) = evt.get_client_rect().await {
+                        viewport.set(rect.height());
+                    }
+                },
+                onscroll: move |evt| scroll_top.set(evt.scroll_top()),
+
+                if let Some(text) = heading {
+                    div { class: "events-agenda-sticky", "{text}" }
+                }
+
+                div { class: "events-agenda-rows", style: "height: {current.height}px;",
+                    for index in visible {
+                        match &current.rows[index] {
+                            AgendaRow::Heading(text) => rsx! {
+                                div {
+                                    key: "{text}",
+                                    class: "events-agenda-heading",
+                                    style: "top: {current.offset(index)}px;",
+                                    "{text}"
+                                }
+                            },
+                            AgendaRow::Event(event) => rsx! {
+                                div {
+                                    key: "{event.id}",
+                                    class: "events-agenda-event",
+                                    style: "top: {current.offset(index)}px;",
+                                    span { class: "events-agenda-time",
+                                        {event.start_date.with_timezone(&Local).format(time_format).to_string()}
+                                    }
+                                    div { class: "events-agenda-details",
+                                        Link { to: Route::EventDetail { event_id: event.id }, "{event.title}" }
+                                        span { class: "events-agenda-muted", {event.location.clone().unwrap_or_else(|| "TBA".to_string())} }
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+
+                div { class: "events-agenda-status",
+                    if let Some(message) = error() {
+                        p { class: "events-agenda-error", "{message}" }
+                        button {
+                            r#type: "button",
+                            onclick: move |_| error.set(None),
+                            "Try again"
+                        }
+                    } else if loading() {
+                        p { "Loading..." }
+                    } else if exhausted() {
+                        p {
+                            if current.rows.is_empty() {
+                                "No upcoming events in the next year."
+                            } else {
+                                "Nothing more scheduled for the following year."
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,380 @@
+// Month/Week/Day calendar of events. Only the dates on screen are fetched, the
+// events are bucketed per day once per fetch or search change, and cells look
+// their events up by index instead of each receiving a copy.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Datelike, Duration, Local, NaiveDate, Timelike};
+use dioxus::prelude::*;
+
+use crate::application::calendar::{self, CalendarView, DayBuckets};
+use crate::application::ports::{EventListItem, UserSettings};
+use crate::lib::components::{Badge, BadgeVariant, Tooltip};
+use crate::presentation::hooks::{use_event_changes, use_render_timing};
+use crate::presentation::pages::events::EventsViewSwitch;
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+const EVENTS_CALENDAR_CSS: Asset = asset!("/assets/events_calendar.css");
+
+// Events listed in a month cell before it collapses the rest into "+N more"
+const MONTH_CELL_EVENTS: usize = 3;
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Inline style colouring an event by its category
+fn category_style(colors: &HashMap<String, String>, event: &EventListItem) -> Option<String> {
+    colors
+        .get(&event.category_id)
+        .map(|color| format!("background: {};", color))
+}
+
+fn matches_search(event: &EventListItem, query: &str) -> bool {
+    query.is_empty()
+        || event.title.to_lowercase().contains(query)
+        || event
+            .location
+            .as_deref()
+            .is_some_and(|location| location.to_lowercase().contains(query))
+}
+
+#[component]
+pub fn EventsCalendarPage(container: AppContainer) -> Element {
+    use_render_timing("EventsCalendarPage");
+    // Opens on the user's preferred view, also once their settings arrive
+    let user_settings = use_context::<Signal<Option<UserSettings>>>();
+    let preferred_view = move || {
+        user_settings
+            .read()
+            .as_ref()
+            .and_then(|settings| settings.calendar_view)
+            .unwrap_or(CalendarView::Month)
+    };
+    let mut view = use_signal(preferred_view);
+    use_effect(move || view.set(preferred_view()));
+    let mut date = use_signal(|| Local::now().date_naive());
+    let mut search = use_signal(String::new);
+    // Empty shows every category
+    let mut selected_categories = use_signal(HashSet::<String>::new);
+
+    // Stepping within the dates already shown (e.g. to another day of the same week) keeps this equal, so nothing is refetched
+    let range = use_memo(move || calendar::visible_dates(view(), date()));
+
+    let changes = use_event_changes(&container.events);
+    let fetched = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = changes();
+            let (from, to) = calendar::utc_range(range());
+            async move { svc.between(from, to).await }
+        }
+    });
+
+    let events = use_memo(move || match &*fetched.read() {
+        Some(Ok(list)) => list.clone(),
+        _ => Vec::new(),
+    });
+    let categories = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.categories().await }
+        }
+    });
+    let colors = use_memo(move || match &*categories.read() {
+        Some(Ok(list)) => list
+            .iter()
+            .filter_map(|category| Some((category.id.clone(), category.color_hex.clone()?)))
+            .collect(),
+        _ => HashMap::new(),
+    });
+
+    let buckets = use_memo(move || {
+        let query = search().trim().to_lowercase();
+        let selected = selected_categories.read();
+        DayBuckets::new(&events.read(), |event| {
+            matches_search(event, &query) && (selected.is_empty() || selected.contains(&event.category_id))
+        })
+    });
+
+    let open_day = move |day: NaiveDate| {
+        date.set(day);
+        view.set(CalendarView::Day);
+    };
+    let (first, end) = range();
+    let today = Local::now().date_naive();
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: EVENTS_CALENDAR_CSS }
+
+        div { class: "container events-calendar-page",
+            div { class: "events-calendar-toolbar",
+                h1 { "Calendar" }
+                EventsViewSwitch {}
+            }
+
+            div { class: "events-calendar-controls",
+                input {
+                    r#type: "search",
+                    placeholder: "Search events...",
+                    value: "{search}",
+                    oninput: move |evt| search.set(evt.value()),
+                }
+                if !search().trim().is_empty() {
+                    Badge {
+                        variant: BadgeVariant::Primary,
+                        icon: "🔍".to_string(),
+                        on_remove: move |_| search.set(String::new()),
+                        remove_label: "Clear search".to_string(),
+                        "{search().trim()}"
+                    }
+                }
+                div { class: "events-calendar-views",
+                    for option in CalendarView::ALL {
+                        button {
+                            r#type: "button",
+                            class: if view() == option { "active" } else { "" },
+                            onclick: move |_| view.set(option),
+                            {option.label()}
+                        }
+                    }
+                }
+            }
+
+            if let Some(Ok(list)) = &*categories.read() {
+                div { class: "events-calendar-filters", role: "group", aria_label: "Filter by category",
+                    for category in list.iter() {
+                        Badge {
+                            key: "{category.id}",
+                            color: category.color_hex.clone(),
+                            selected: selected_categories.read().contains(&category.id),
+                            on_click: {
+                                let id = category.id.clone();
+                                move |_| {
+                                    let mut selected = selected_categories.write();
+                                    if !selected.remove(&id) {
+                                        selected.insert(id.clone());
+                                    }
+                                }
+                            },
+                            "{category.name}"
+                        }
+                    }
+                    if !selected_categories.read().is_empty() {
+                        button {
+                            r#type: "button",
+                            class: "events-calendar-clear",
+                            onclick: move |_| selected_categories.write().clear(),
+                            "Show all"
+                        }
+                    }
+                }
+            }
+
+            div { class: "events-calendar-nav",
+                button {
+                    r#type: "button",
+                    onclick: move |_| date.set(calendar::step(view(), date(), false)),
+                    "←"
+                }
+                h2 { {calendar::title(view(), date())} }
+                button { r#type: "button", onclick: move |_| date.set(Local::now().date_naive()), "Today" }
+                button {
+                    r#type: "button",
+                    onclick: move |_| date.set(calendar::step(view(), date(), true)),
+                    "→"
+                }
+                span { class: "events-calendar-muted", {format!("{} events", buckets.read().count())} }
+            }
+
+            match &*fetched.read() {
+                Some(Err(e)) => rsx! { p { class: "events-calendar-error", "Error: {e}" } },
+                None => rsx! { p { class: "events-calendar-muted", "Loading..." } },
+                Some(Ok(_)) => rsx! {},
+            }
+
+            match view() {
+                CalendarView::Month => rsx! {
+                    div { class: "events-calendar-month",
+                        for name in WEEKDAYS {
+                            div { class: "events-calendar-weekday", "{name}" }
+                        }
+                        for offset in 0..(end - first).num_days() {
+                            MonthDayCell {
+                                key: "{first + Duration::days(offset)}",
+                                day: first + Duration::days(offset),
+                                in_month: (first + Duration::days(offset)).month() == date().month(),
+                                today,
+                                events,
+                                buckets,
+                                colors,
+                                on_open_day: open_day,
+                            }
+                        }
+                    }
+                },
+                CalendarView::Week | CalendarView::Day => rsx! {
+                    TimeGrid { first, days: (end - first).num_days(), today, events, buckets, colors }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn MonthDayCell(
+    day: NaiveDate,
+    in_month: bool,
+    today: NaiveDate,
+    events: Memo<Vec<EventListItem>>,
+    buckets: Memo<DayBuckets>,
+    colors: Memo<HashMap<String, String>>,
+    on_open_day: EventHandler<NaiveDate>,
+) -> Element {
+    let events = events.read();
+    let buckets = buckets.read();
+    let colors = colors.read();
+    let indexes = buckets.on(day);
+    let class = match (day == today, in_month) {
+        (true, _) => "events-calendar-cell today",
+        (false, true) => "events-calendar-cell",
+        (false, false) => "events-calendar-cell outside",
+    };
+
+    rsx! {
+        div { class, onclick: move |_| on_open_day.call(day),
+            span { class: "events-calendar-day-number", "{day.day()}" }
+            for &index in indexes.iter().take(MONTH_CELL_EVENTS) {
+                Tooltip {
+                    key: "{events[index].id}",
+                    content: rsx! { EventPreview { event: events[index].clone() } },
+                    Link {
+                        class: "events-calendar-event",
+                        style: category_style(&colors, &events[index]),
+                        to: Route::EventDetail { event_id: events[index].id },
+                        onclick: move |evt: MouseEvent| evt.stop_propagation(),
+                        "{events[index].title}"
+                    }
+                }
+            }
+            if indexes.len() > MONTH_CELL_EVENTS {
+                span { class: "events-calendar-more", "+{indexes.len() - MONTH_CELL_EVENTS} more" }
+            }
+        }
+    }
+}
+
+/// Shown when hovering or focusing an event in the calendar
+#[component]
+fn EventPreview(event: EventListItem) -> Element {
+    rsx! {
+        div { class: "events-calendar-preview",
+            strong { "{event.title}" }
+            span { {event.start_date.with_timezone(&Local).format("%A %-d %B, %H:%M").to_string()} }
+            span { {event.location.clone().unwrap_or_else(|| "Location TBA".to_string())} }
+        }
+    }
+}
+
+/// Hour-by-hour columns for the week and day views
+#[component]
+fn TimeGrid(
+    first: NaiveDate,
+    days: i64,
+    today: NaiveDate,
+    events: Memo<Vec<EventListItem>>,
+    buckets: Memo<DayBuckets>,
+    colors: Memo<HashMap<String, String>>,
+) -> Element {
+    let events = events.read();
+    let buckets = buckets.read();
+    let colors = colors.read();
+    let dates: Vec<NaiveDate> = (0..days).map(|offset| first + Duration::days(offset)).collect();
+
+    rsx! {
+        div {
+            class: "events-calendar-grid",
+            style: "grid-template-columns: 4rem repeat({days}, minmax(0, 1fr));",
+            div {}
+            for day in dates.iter().copied() {
+                div { class: if day == today { "events-calendar-column-head today" } else { "events-calendar-column-head" },
+                    {day.format("%a %-d").to_string()}
+                }
+            }
+            for hour in 0..24u32 {
+                div { class: "events-calendar-hour", {format!("{:02}:00", hour)} }
+                for day in dates.iter().copied() {
+                    div { class: "events-calendar-slot",
+                        for &index in buckets.on(day).iter().filter(|&&index| {
+                            events[index].start_date.with_timezone(&Local).hour() == hour
+                        }) {
+                            Tooltip {
+                                key: "{events[index].id}",
+                                content: rsx! { EventPreview { event: events[index].clone() } },
+                                Link {
+                                    class: "events-calendar-event",
+                                    style: category_style(&colors, &events[index]),
+                                    to: Route::EventDetail { event_id: events[index].id },
+                                    span { class: "events-calendar-time",
+                                        {events[index].start_date.with_timezone(&Local).format("%H:%M").to_string()}
+                                    }
+                                    " {events[index].title}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::lib::testing::{assert_snapshot, TestDom};
+
+    // Noon UTC falls on the same local day in any time zone the tests run in
+    fn event(id: u128, title: &str, day: u32, hour: u32) -> EventListItem {
+        EventListItem {
+            id: Uuid::from_u128(id),
+            title: title.to_string(),
+            category_id: if id == 1 { "conference".to_string() } else { "meeting".to_string() },
+            start_date: Utc.with_ymd_and_hms(2026, 9, day, hour, 0, 0).unwrap(),
+            location: Some("Bergen".to_string()),
+            slug: None,
+            coordinates: None,
+            registration: None,
+        }
+    }
+
+    fn month_cells() -> Element {
+        let events = use_memo(|| {
+            vec![
+                event(1, "Fish health seminar", 3, 12),
+                event(2, "Feed suppliers meetup", 3, 11),
+                event(3, "Board meeting", 3, 13),
+                event(4, "Site visit", 3, 10),
+            ]
+        });
+        let buckets = use_memo(move || DayBuckets::new(&events.read(), |_| true));
+        let colors = use_memo(|| HashMap::from([("conference".to_string(), "#1B4D8C".to_string())]));
+        let day = |day: u32| NaiveDate::from_ymd_opt(2026, 9, day).unwrap();
+        rsx! {
+            // Busy day, collapsing the fourth event
+            MonthDayCell { day: day(3), in_month: true, today: day(4), events, buckets, colors, on_open_day: |_| {} }
+            // Empty today
+            MonthDayCell { day: day(4), in_month: true, today: day(4), events, buckets, colors, on_open_day: |_| {} }
+            // Day of the next month shown in the last row
+            MonthDayCell { day: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(), in_month: false, today: day(4), events, buckets, colors, on_open_day: |_| {} }
+        }
+    }
+
+    #[test]
+    fn test_month_cell_markup() {
+        assert_snapshot("events_calendar_month_cells", &TestDom::with_router(month_cells).html());
+    }
+}
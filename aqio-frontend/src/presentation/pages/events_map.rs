@@ -0,0 +1,280 @@
+// Map browse mode for events: OpenStreetMap tiles with clustered markers,
+// popup cards and a "search this area" action backed by the API's proximity search.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::map::{cluster, MapView, DEFAULT_CENTER, DEFAULT_ZOOM};
+use crate::application::ports::{EventListItem, LatLng};
+use crate::presentation::hooks::{use_event_changes, use_render_timing};
+use crate::presentation::pages::events::EventsViewSwitch;
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+const EVENTS_MAP_CSS: Asset = asset!("/assets/events_map.css");
+
+// Markers closer than this on screen are drawn as one cluster
+const CLUSTER_CELL_PX: f64 = 56.0;
+// Trackpads send many small wheel events; zoom one level per this much scrolling
+const WHEEL_STEP: f64 = 100.0;
+
+/// Results of a "search this area" request
+#[derive(Clone, PartialEq)]
+struct AreaSearch {
+    radius_km: f64,
+    events: Vec<EventListItem>,
+}
+
+fn placed(events: &[EventListItem]) -> Vec<(LatLng, EventListItem)> {
+    events
+        .iter()
+        .filter_map(|event| event.coordinates.map(|point| (point, event.clone())))
+        .collect()
+}
+
+#[component]
+pub fn EventsMapPage(container: AppContainer) -> Element {
+    use_render_timing("EventsMapPage");
+    let mut view = use_signal(|| MapView::new(DEFAULT_CENTER, DEFAULT_ZOOM, 800.0, 480.0));
+    let mut map_size = use_signal(|| None::<(f64, f64)>);
+    let mut fitted = use_signal(|| false);
+    let mut moved = use_signal(|| false);
+    let mut drag_from = use_signal(|| None::<(f64, f64)>);
+    let mut wheel = use_signal(|| 0.0f64);
+    let mut selected = use_signal(|| None::<Uuid>);
+    let mut area = use_signal(|| None::<AreaSearch>);
+    let mut searching = use_signal(|| false);
+    let mut search_error = use_signal(|| None::<String>);
+
+    let changes = use_event_changes(&container.events);
+    let all_events = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = changes();
+            async move { svc.list().await }
+        }
+    });
+
+    // Fit the map to the events once both they and the element size are known
+    use_effect(move || {
+        if let (Some((width, height)), Some(Ok(events))) = (map_size(), &*all_events.read()) {
+            if !*fitted.peek() {
+                let points: Vec<LatLng> = placed(events).into_iter().map(|(point, _)| point).collect();
+                view.set(MapView::fit(&points, width, height));
+                fitted.set(true);
+            }
+        }
+    });
+
+    let change_view = use_callback(move |next: MapView| {
+        if next != *view.peek() {
+            view.set(next);
+            moved.set(true);
+        }
+    });
+
+    let search_area = {
+        let svc = container.events.clone();
+        move |_| {
+            let svc = svc.clone();
+            let current = view();
+            spawn(async move {
+                searching.set(true);
+                let radius_km = current.radius_km();
+                match svc.near(current.center, radius_km).await {
+                    Ok(events) => {
+                        search_error.set(None);
+                        selected.set(None);
+                        area.set(Some(AreaSearch { radius_km, events }));
+                        moved.set(false);
+                    }
+                    Err(e) => search_error.set(Some(e)),
+                }
+                searching.set(false);
+            });
+        }
+    };
+
+    let events: Vec<EventListItem> = match (area(), &*all_events.read()) {
+        (Some(search), _) => search.events,
+        (None, Some(Ok(events))) => events.clone(),
+        _ => Vec::new(),
+    };
+    let markers = placed(&events);
+    let unplaced = events.len() - markers.len();
+    let current = view();
+    let clusters = cluster(&markers, &current, CLUSTER_CELL_PX);
+    let popup = selected().and_then(|id| markers.iter().find(|(_, event)| event.id == id).cloned());
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: EVENTS_MAP_CSS }
+
+        div { class: "events-map-page",
+            div { class: "events-map-toolbar",
+                h1 { "Event map" }
+                EventsViewSwitch {}
+            }
+
+            p { class: "events-map-status",
+                match area() {
+                    Some(search) => rsx! {
+                        {format!("{} events within {:.0} km of the map centre. ", search.events.len(), search.radius_km)}
+                        button {
+                            r#type: "button",
+                            class: "events-map-link-button",
+                            onclick: move |_| {
+                                area.set(None);
+                                selected.set(None);
+                            },
+                            "Show all events"
+                        }
+                    },
+                    None => rsx! {
+                        {format!("{} events on the map", markers.len())}
+                        if unplaced > 0 {
+                            {format!(" ({} without a location are not shown)", unplaced)}
+                        }
+                    },
+                }
+            }
+            match &*all_events.read() {
+                Some(Err(e)) => rsx! { p { class: "events-map-error", "Error: {e}" } },
+                _ => rsx! {},
+            }
+            if let Some(message) = search_error() {
+                p { class: "events-map-error", "{message}" }
+            }
+
+            div {
+                class: "events-map",
+                onmounted: move |evt| async move {
+                    if let Ok(rect) = evt.get_client_rect().await {
+                        let (width, height) = (rect.width(), rect.height());
+                        view.set(view.peek().resized(width, height));
+                        map_size.set(Some((width, height)));
+                    }
+                },
+                onpointerdown: move |evt| {
+                    let point = evt.client_coordinates();
+                    drag_from.set(Some((point.x, point.y)));
+                },
+                onpointermove: move |evt| {
+                    if let Some((x, y)) = drag_from() {
+                        let point = evt.client_coordinates();
+                        change_view.call(view().panned(point.x - x, point.y - y));
+                        drag_from.set(Some((point.x, point.y)));
+                    }
+                },
+                onpointerup: move |_| drag_from.set(None),
+                onpointerleave: move |_| drag_from.set(None),
+                onwheel: move |evt| {
+                    evt.prevent_default();
+                    let total = wheel() + evt.delta().strip_units().y;
+                    if total.abs() >= WHEEL_STEP {
+                        change_view.call(view().zoomed(if total < 0.0 { 1 } else { -1 }));
+                        wheel.set(0.0);
+                    } else {
+                        wheel.set(total);
+                    }
+                },
+
+                for tile in current.tiles() {
+                    img {
+                        key: "{tile.key()}",
+                        class: "events-map-tile",
+                        src: tile.url(),
+                        alt: "",
+                        draggable: "false",
+                        style: "left: {tile.left}px; top: {tile.top}px;",
+                    }
+                }
+
+                for group in clusters {
+                    if group.items.len() > 1 {
+                        button {
+                            r#type: "button",
+                            class: "events-map-cluster",
+                            style: "left: {group.x}px; top: {group.y}px;",
+                            title: "Zoom in",
+                            onpointerdown: move |evt| evt.stop_propagation(),
+                            onclick: move |_| change_view.call(view().zoomed_into(group.center, 2)),
+                            "{group.items.len()}"
+                        }
+                    } else {
+                        {
+                            let event = group.items[0].clone();
+                            rsx! {
+                                button {
+                                    r#type: "button",
+                                    class: if selected() == Some(event.id) { "events-map-marker active" } else { "events-map-marker" },
+                                    style: "left: {group.x}px; top: {group.y}px;",
+                                    title: "{event.title}",
+                                    onpointerdown: move |evt| evt.stop_propagation(),
+                                    onclick: move |_| selected.set(Some(event.id)),
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some((point, event)) = popup {
+                    {
+                        let (x, y) = current.to_screen(point);
+                        rsx! {
+                            div {
+                                class: "events-map-popup",
+                                style: "left: {x}px; top: {y}px;",
+                                onpointerdown: move |evt| evt.stop_propagation(),
+                                button {
+                                    r#type: "button",
+                                    class: "events-map-popup-close",
+                                    onclick: move |_| selected.set(None),
+                                    "×"
+                                }
+                                strong { "{event.title}" }
+                                p { {event.start_date.format("%Y-%m-%d %H:%M").to_string()} }
+                                if let Some(location) = event.location.clone() {
+                                    p { "{location}" }
+                                }
+                                Link { to: Route::EventDetail { event_id: event.id }, "View event" }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "events-map-zoom",
+                    button {
+                        r#type: "button",
+                        onpointerdown: move |evt| evt.stop_propagation(),
+                        onclick: move |_| change_view.call(view().zoomed(1)),
+                        "+"
+                    }
+                    button {
+                        r#type: "button",
+                        onpointerdown: move |evt| evt.stop_propagation(),
+                        onclick: move |_| change_view.call(view().zoomed(-1)),
+                        "−"
+                    }
+                }
+
+                if moved() {
+                    button {
+                        r#type: "button",
+                        class: "events-map-search",
+                        disabled: searching(),
+                        onpointerdown: move |evt| evt.stop_propagation(),
+                        onclick: search_area,
+                        if searching() { "Searching..." } else { "Search this area" }
+                    }
+                }
+
+                div { class: "events-map-attribution",
+                    "© "
+                    a { href: "https://www.openstreetmap.org/copyright", target: "_blank", "OpenStreetMap" }
+                    " contributors"
+                }
+            }
+        }
+    }
+}
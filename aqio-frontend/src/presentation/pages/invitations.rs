@@ -0,0 +1,390 @@
+// Organizer view of an event's invitations: status overview, bulk resend,
+// a compose panel for the personal message, picking people who already have
+// an account, and CSV import.
+
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::invitation_csv::parse_invitees;
+use crate::application::ports::{Invitation, InvitationStatus, Invitee, UserMatch};
+use crate::application::services::BulkOutcome;
+use crate::presentation::user_picker::UserPicker;
+use crate::AppContainer;
+
+const INVITATIONS_CSS: Asset = asset!("/assets/invitations.css");
+
+fn describe(outcome: &BulkOutcome, verb: &str) -> String {
+    let mut text = format!("{} {}", outcome.succeeded, verb);
+    if !outcome.failed.is_empty() {
+        let failures: Vec<String> = outcome
+            .failed
+            .iter()
+            .map(|(who, error)| format!("{}: {}", who, error))
+            .collect();
+        text.push_str(&format!(", {} failed ({})", outcome.failed.len(), failures.join("; ")));
+    }
+    text
+}
+
+#[component]
+pub fn InvitationsPage(container: AppContainer, event_id: Uuid) -> Element {
+    // Bumped after each change so the list is fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let mut selected = use_signal(HashSet::<Uuid>::new);
+    let mut notice = use_signal(|| None::<String>);
+    let mut resending = use_signal(|| false);
+    // Shared by single invites and CSV imports
+    let personal_message = use_signal(String::new);
+
+    let invitations = use_resource({
+        let svc = container.invitations.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.list(event_id).await }
+        }
+    });
+
+    let on_changed = move |message: String| {
+        notice.set(Some(message));
+        refresh += 1;
+    };
+
+    let resend = {
+        let svc = container.invitations.clone();
+        move |chosen: Vec<Invitation>| {
+            let svc = svc.clone();
+            spawn(async move {
+                resending.set(true);
+                let outcome = svc.resend(&chosen).await;
+                selected.write().clear();
+                notice.set(Some(describe(&outcome, "invitations resent")));
+                refresh += 1;
+                resending.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: INVITATIONS_CSS }
+
+        div { class: "invitations-page",
+            h1 { "Invitations" }
+
+            if let Some(message) = notice() {
+                p { class: "invitations-notice", "{message}" }
+            }
+
+            div { class: "invitations-panels",
+                ComposePanel { event_id, personal_message, on_changed }
+                CsvImportPanel { event_id, personal_message, on_changed }
+            }
+
+            match &*invitations.read() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "invitations-muted", "No invitations yet." }
+                },
+                Some(Ok(list)) => {
+                    let chosen: Vec<Invitation> = list
+                        .iter()
+                        .filter(|invitation| selected().contains(&invitation.id))
+                        .cloned()
+                        .collect();
+                    let chosen_count = chosen.len();
+                    let resendable = chosen.iter().filter(|i| i.can_resend()).count();
+                    let all_ids: Vec<Uuid> = list.iter().map(|invitation| invitation.id).collect();
+                    let all_selected = all_ids.iter().all(|id| selected().contains(id));
+
+                    rsx! {
+                        div { class: "invitations-summary",
+                            for status in InvitationStatus::ALL {
+                                {
+                                    let count = list.iter().filter(|i| i.status == status).count();
+                                    rsx! {
+                                        if count > 0 {
+                                            span { class: "invitations-summary-item",
+                                                StatusChip { status }
+                                                " {count}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "invitations-toolbar",
+                            span { class: "invitations-muted", "{chosen_count} selected" }
+                            button {
+                                r#type: "button",
+                                class: "invitations-button",
+                                disabled: resendable == 0 || resending(),
+                                onclick: {
+                                    let resend = resend.clone();
+                                    move |_| resend(chosen.clone())
+                                },
+                                if resending() { "Resending..." } else { "Resend selected ({resendable})" }
+                            }
+                        }
+
+                        table { class: "invitations-table",
+                            thead {
+                                tr {
+                                    th {
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: all_selected,
+                                            onchange: move |evt| {
+                                                if evt.checked() {
+                                                    selected.set(all_ids.iter().copied().collect());
+                                                } else {
+                                                    selected.write().clear();
+                                                }
+                                            },
+                                        }
+                                    }
+                                    th { "Name" }
+                                    th { "Email" }
+                                    th { "Status" }
+                                    th { "Sent" }
+                                    th { "Responded" }
+                                }
+                            }
+                            tbody {
+                                for invitation in list.iter() {
+                                    InvitationRow {
+                                        key: "{invitation.id}",
+                                        invitation: invitation.clone(),
+                                        selected,
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                Some(Err(e)) => rsx! { p { class: "invitations-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+#[component]
+fn InvitationRow(invitation: Invitation, mut selected: Signal<HashSet<Uuid>>) -> Element {
+    let id = invitation.id;
+    let format_time = |time: Option<chrono::DateTime<chrono::Utc>>| {
+        time.map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "—".to_string())
+    };
+
+    rsx! {
+        tr {
+            td {
+                input {
+                    r#type: "checkbox",
+                    checked: selected().contains(&id),
+                    onchange: move |evt| {
+                        if evt.checked() {
+                            selected.write().insert(id);
+                        } else {
+                            selected.write().remove(&id);
+                        }
+                    },
+                }
+            }
+            td { {invitation.invited_name.clone().unwrap_or_default()} }
+            td { {invitation.invited_email.clone().unwrap_or_default()} }
+            td {
+                StatusChip { status: invitation.status }
+                if let Some(failure) = invitation.delivery_failure {
+                    " "
+                    span { class: "invitation-chip invitation-chip-undeliverable", {failure.label()} }
+                }
+            }
+            td { class: "invitations-muted", {format_time(invitation.sent_at)} }
+            td { class: "invitations-muted", {format_time(invitation.responded_at)} }
+        }
+    }
+}
+
+#[component]
+pub fn StatusChip(status: InvitationStatus) -> Element {
+    rsx! {
+        span { class: "invitation-chip invitation-chip-{status.as_str()}", {status.label()} }
+    }
+}
+
+/// Personal message shared by all invitations sent from this page, a picker for
+/// people with an account and a single invite form for anyone else
+#[component]
+fn ComposePanel(event_id: Uuid, mut personal_message: Signal<String>, on_changed: EventHandler<String>) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut picked = use_signal(Vec::<UserMatch>::new);
+    let mut name = use_signal(String::new);
+    let mut email = use_signal(String::new);
+    let mut sending = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let invite_picked = {
+        let svc = container.invitations.clone();
+        move |_| {
+            let svc = svc.clone();
+            let invitees: Vec<Invitee> = picked()
+                .into_iter()
+                .map(|user| Invitee { email: user.email, name: Some(user.name) })
+                .collect();
+            spawn(async move {
+                sending.set(true);
+                let outcome = svc.import(event_id, &invitees, Some(&personal_message())).await;
+                if outcome.failed.is_empty() {
+                    picked.write().clear();
+                }
+                on_changed.call(describe(&outcome, "invitations created"));
+                sending.set(false);
+            });
+        }
+    };
+
+    let send = move |_| {
+        let svc = container.invitations.clone();
+        let invitee = Invitee {
+            email: email().trim().to_string(),
+            name: Some(name().trim().to_string()).filter(|name| !name.is_empty()),
+        };
+        spawn(async move {
+            sending.set(true);
+            match svc.invite(event_id, &invitee, Some(&personal_message())).await {
+                Ok(_) => {
+                    error.set(None);
+                    name.set(String::new());
+                    email.set(String::new());
+                    on_changed.call(format!("Invitation created for {}", invitee.email));
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            sending.set(false);
+        });
+    };
+
+    rsx! {
+        section { class: "invitations-panel",
+            h2 { "Compose" }
+
+            label { class: "invitations-field",
+                span { "Personal message" }
+                textarea {
+                    rows: 4,
+                    placeholder: "Added to every invitation sent from this page",
+                    value: "{personal_message}",
+                    oninput: move |evt| personal_message.set(evt.value()),
+                }
+            }
+
+            UserPicker { label: "People with an account".to_string(), picked }
+            button {
+                r#type: "button",
+                class: "invitations-button",
+                disabled: sending() || picked().is_empty(),
+                onclick: invite_picked,
+                "Invite {picked().len()} picked"
+            }
+
+            if let Some(message) = error() {
+                p { class: "invitations-error", "{message}" }
+            }
+
+            div { class: "invitations-inline",
+                input {
+                    placeholder: "Name",
+                    value: "{name}",
+                    oninput: move |evt| name.set(evt.value()),
+                }
+                input {
+                    r#type: "email",
+                    placeholder: "Email",
+                    value: "{email}",
+                    oninput: move |evt| email.set(evt.value()),
+                }
+                button {
+                    r#type: "button",
+                    class: "invitations-button",
+                    disabled: sending() || email().trim().is_empty(),
+                    onclick: send,
+                    if sending() { "Sending..." } else { "Invite" }
+                }
+            }
+        }
+    }
+}
+
+/// Paste or upload a CSV of `email,name` rows, preview it, then create the invitations
+#[component]
+fn CsvImportPanel(event_id: Uuid, personal_message: Signal<String>, on_changed: EventHandler<String>) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut csv_text = use_signal(String::new);
+    let mut importing = use_signal(|| false);
+
+    let preview = use_memo(move || parse_invitees(&csv_text()));
+
+    let load_file = move |evt: FormEvent| async move {
+        if let Some(engine) = evt.files() {
+            if let Some(file_name) = engine.files().first() {
+                if let Some(contents) = engine.read_file_to_string(file_name).await {
+                    csv_text.set(contents);
+                }
+            }
+        }
+    };
+
+    let import = move |_| {
+        let svc = container.invitations.clone();
+        let invitees = preview().invitees;
+        spawn(async move {
+            importing.set(true);
+            let outcome = svc.import(event_id, &invitees, Some(&personal_message())).await;
+            if outcome.failed.is_empty() {
+                csv_text.set(String::new());
+            }
+            on_changed.call(describe(&outcome, "invitations imported"));
+            importing.set(false);
+        });
+    };
+
+    let parsed = preview();
+
+    rsx! {
+        section { class: "invitations-panel",
+            h2 { "Import from CSV" }
+            p { class: "invitations-muted", "One invitee per line: email, name. A header row is optional." }
+
+            input {
+                r#type: "file",
+                accept: ".csv,text/csv,text/plain",
+                onchange: load_file,
+            }
+            textarea {
+                rows: 5,
+                placeholder: "email,name\nkari@example.no,Kari Nordmann",
+                value: "{csv_text}",
+                oninput: move |evt| csv_text.set(evt.value()),
+            }
+
+            if !parsed.errors.is_empty() {
+                ul { class: "invitations-error",
+                    for problem in parsed.errors.iter() {
+                        li { "{problem}" }
+                    }
+                }
+            }
+
+            button {
+                r#type: "button",
+                class: "invitations-button",
+                disabled: parsed.invitees.is_empty() || importing(),
+                onclick: import,
+                if importing() { "Importing..." } else { "Import {parsed.invitees.len()} invitees" }
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+// Inbox of conversations about events: with the organizers of events the user
+// is registered for, and with attendees of events they organize. The list of
+// conversations sits next to the open one, which is marked read when opened.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{Message, MessageThread};
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+const MESSAGES_CSS: Asset = asset!("/assets/messages.css");
+
+// Who the user is talking to in the thread
+fn counterpart(thread: &MessageThread) -> String {
+    if thread.as_organizer {
+        thread.attendee_name.clone()
+    } else {
+        "The organizers".to_string()
+    }
+}
+
+#[component]
+pub fn MessagesPage(container: AppContainer, thread_id: Option<Uuid>) -> Element {
+    // Bumped when the open conversation is read or answered, so the list's
+    // order and unread counts are fetched again
+    let mut revision = use_signal(|| 0u32);
+
+    let threads = use_resource({
+        let svc = container.messages.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = revision();
+            async move { svc.inbox().await }
+        }
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: MESSAGES_CSS }
+
+        div { class: "messages-page",
+            h1 { "Messages" }
+            div { class: "messages-layout",
+                match &*threads.read() {
+                    Some(Ok(threads)) if threads.is_empty() => rsx! {
+                        p { class: "messages-muted",
+                            "No messages yet. Registrants can write to the organizers from the event page."
+                        }
+                    },
+                    Some(Ok(threads)) => rsx! {
+                        ul { class: "messages-list", aria_label: "Conversations",
+                            for thread in threads.iter() {
+                                li { key: "{thread.id}",
+                                    Link {
+                                        class: match (Some(thread.id) == thread_id, thread.unread > 0) {
+                                            (true, _) => "messages-list-item active",
+                                            (false, true) => "messages-list-item unread",
+                                            (false, false) => "messages-list-item",
+                                        },
+                                        to: Route::Conversation { thread_id: thread.id },
+                                        span { class: "messages-list-title", {counterpart(thread)} }
+                                        span { class: "messages-list-detail",
+                                            {format!("{} · {}", thread.event_title, thread.last_message_at.format("%Y-%m-%d %H:%M"))}
+                                        }
+                                        if thread.unread > 0 {
+                                            span { class: "messages-unread", aria_label: "{thread.unread} unread", "{thread.unread}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! { p { class: "messages-error", "Error: {e}" } },
+                    None => rsx! { p { "Loading..." } },
+                }
+
+                section { class: "messages-thread",
+                    if let Some(thread_id) = thread_id {
+                        ConversationView {
+                            key: "{thread_id}",
+                            container: container.clone(),
+                            thread_id,
+                            on_change: move |_| revision += 1,
+                        }
+                    } else {
+                        p { class: "messages-muted", "Pick a conversation to read it." }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ConversationView(container: AppContainer, thread_id: Uuid, on_change: EventHandler<()>) -> Element {
+    let mut revision = use_signal(|| 0u32);
+
+    let conversation = use_resource({
+        let svc = container.messages.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = revision();
+            async move {
+                let loaded = svc.thread(thread_id).await;
+                // Opening the thread marked it read
+                on_change.call(());
+                loaded
+            }
+        }
+    });
+
+    match &*conversation.read() {
+        Some(Ok((thread, messages))) => rsx! {
+            header { class: "messages-thread-header",
+                h2 { {counterpart(thread)} }
+                Link { to: Route::EventDetail { event_id: thread.event_id }, "{thread.event_title}" }
+            }
+            ol { class: "messages-bubbles",
+                for message in messages.iter() {
+                    li {
+                        key: "{message.id}",
+                        class: if message.from_organizer == thread.as_organizer { "messages-bubble mine" } else { "messages-bubble" },
+                        p { "{message.body}" }
+                        time { {message.created_at.format("%Y-%m-%d %H:%M").to_string()} }
+                    }
+                }
+            }
+            MessageForm {
+                container: container.clone(),
+                target: MessageTarget::Thread(thread_id),
+                on_sent: move |_| revision += 1,
+            }
+        },
+        Some(Err(e)) => rsx! { p { class: "messages-error", "Error: {e}" } },
+        None => rsx! { p { "Loading..." } },
+    }
+}
+
+/// Shown on the event page to its registrants; the first message starts their
+/// conversation with the organizers, which then opens in the inbox
+#[component]
+pub fn ContactOrganizersForm(container: AppContainer, event_id: Uuid) -> Element {
+    rsx! {
+        document::Link { rel: "stylesheet", href: MESSAGES_CSS }
+
+        MessageForm {
+            container,
+            target: MessageTarget::Event(event_id),
+            on_sent: move |message: Message| {
+                navigator().push(Route::Conversation { thread_id: message.thread_id });
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MessageTarget {
+    /// A reply in an existing conversation
+    Thread(Uuid),
+    /// A registrant writing to the event's organizers
+    Event(Uuid),
+}
+
+#[component]
+fn MessageForm(container: AppContainer, target: MessageTarget, on_sent: EventHandler<Message>) -> Element {
+    let mut body = use_signal(String::new);
+    let mut sending = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let label = match target {
+        MessageTarget::Thread(_) => "Reply",
+        MessageTarget::Event(_) => "Message the organizers",
+    };
+
+    let submit = {
+        let svc = container.messages.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let svc = svc.clone();
+            spawn(async move {
+                sending.set(true);
+                let sent = match target {
+                    MessageTarget::Thread(thread_id) => svc.reply(thread_id, &body()).await,
+                    MessageTarget::Event(event_id) => svc.contact_organizers(event_id, &body()).await,
+                };
+                match sent {
+                    Ok(message) => {
+                        body.set(String::new());
+                        error.set(None);
+                        on_sent.call(message);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                sending.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "messages-form", onsubmit: submit,
+            textarea {
+                rows: "3",
+                aria_label: label,
+                placeholder: label,
+                value: "{body}",
+                oninput: move |evt| body.set(evt.value()),
+            }
+            if let Some(message) = error() {
+                p { class: "messages-error", "{message}" }
+            }
+            button {
+                r#type: "submit",
+                disabled: sending() || body().trim().is_empty(),
+                if sending() { "Sending..." } else { "Send" }
+            }
+        }
+    }
+}
@@ -1 +1,24 @@
+pub mod admin;
+pub mod budget;
+pub mod catering;
+pub mod certificate;
+pub mod check_in;
+pub mod companies;
+pub mod event_detail;
+pub mod event_fields;
 pub mod events;
+pub mod events_agenda;
+pub mod events_calendar;
+pub mod events_map;
+pub mod invitations;
+pub mod messages;
+pub mod networking;
+pub mod new_event;
+pub mod past_events;
+pub mod photos;
+pub mod print;
+pub mod public_event;
+pub mod seating;
+pub mod settings;
+pub mod sponsors;
+pub mod travel;
@@ -0,0 +1,570 @@
+// Networking tab of the event page. Attendees opt in to a directory of the
+// event's participants with what they want to talk about and when they're
+// free. The directory can be searched and narrowed to one interest, and a
+// meeting requested with anyone in it is scheduled by the API into the first
+// slot both are free, in one of the event's meeting rooms. The user's meetings
+// are listed above it, to accept, decline, cancel or add to a calendar.
+
+use chrono::{DateTime, Duration, Local, NaiveDateTime, Utc};
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{AvailabilityWindow, Meeting, MeetingStatus, NetworkingContact, NetworkingProfile, NetworkingProfileDraft};
+use crate::application::services::{directory_interests, filter_contacts, parse_interests};
+use crate::infrastructure::download::save_bytes;
+use crate::lib::components::{Avatar, AvatarSize, Badge, BadgeSize, BadgeVariant};
+use crate::AppContainer;
+
+const NETWORKING_CSS: Asset = asset!("/assets/networking.css");
+
+// `<input type="datetime-local">` value format
+const DATETIME_LOCAL: &str = "%Y-%m-%dT%H:%M";
+
+fn parse_local(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value, DATETIME_LOCAL)
+        .ok()?
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|local| local.with_timezone(&Utc))
+}
+
+fn local_input(at: DateTime<Utc>) -> String {
+    at.with_timezone(&Local).format(DATETIME_LOCAL).to_string()
+}
+
+fn meeting_time(meeting: &Meeting) -> String {
+    format!(
+        "{}–{}",
+        meeting.starts_at.with_timezone(&Local).format("%a %-d %b, %H:%M"),
+        meeting.ends_at.with_timezone(&Local).format("%H:%M")
+    )
+}
+
+/// Mounted only while its tab is selected, so the directory is fetched on demand
+#[component]
+pub fn NetworkingTab(container: AppContainer, event_id: Uuid) -> Element {
+    // Bumped after joining, editing or leaving so the profile is fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let mut editing = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let profile = use_resource({
+        let svc = container.networking.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.profile(event_id).await }
+        }
+    });
+
+    let leave = {
+        let svc = container.networking.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.leave(event_id).await {
+                    Ok(()) => {
+                        error.set(None);
+                        refresh += 1;
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: NETWORKING_CSS }
+
+        if let Some(message) = error() {
+            p { class: "event-detail-error", "{message}" }
+        }
+        match &*profile.read() {
+            Some(Ok(Some(profile))) if !editing() => rsx! {
+                div { class: "networking-toolbar",
+                    p { class: "event-detail-muted",
+                        "You're in the networking directory"
+                        if !profile.interests.is_empty() {
+                            {format!(", interested in {}", profile.interests.join(", "))}
+                        }
+                        "."
+                    }
+                    button { r#type: "button", onclick: move |_| editing.set(true), "Edit" }
+                    button { r#type: "button", onclick: leave, "Leave the directory" }
+                }
+                Directory { container: container.clone(), event_id, user_id: profile.user_id }
+            },
+            Some(Ok(profile)) => rsx! {
+                if profile.is_none() {
+                    p { class: "event-detail-muted",
+                        "Join the networking directory to see who else is here and book 1:1 meetings with them. "
+                        "Others in the directory see your name, company and interests."
+                    }
+                }
+                ProfileForm {
+                    container: container.clone(),
+                    event_id,
+                    profile: profile.clone(),
+                    on_saved: move |_| {
+                        editing.set(false);
+                        refresh += 1;
+                    },
+                    on_cancel: move |_| editing.set(false),
+                }
+            },
+            Some(Err(e)) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+            None => rsx! { p { "Loading..." } },
+        }
+    }
+}
+
+/// Joins the directory, or edits the user's entry when `profile` is given
+#[component]
+fn ProfileForm(
+    container: AppContainer,
+    event_id: Uuid,
+    profile: Option<NetworkingProfile>,
+    on_saved: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let joined = profile.is_some();
+    let mut interests = use_signal(|| profile.as_ref().map(|p| p.interests.join(", ")).unwrap_or_default());
+    let mut bio = use_signal(|| profile.as_ref().and_then(|p| p.bio.clone()).unwrap_or_default());
+    // Start and end of each time the user is free, as `datetime-local` values
+    let mut windows = use_signal(|| {
+        profile
+            .as_ref()
+            .map(|p| {
+                p.availability
+                    .iter()
+                    .map(|window| (local_input(window.starts_at), local_input(window.ends_at)))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    });
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let event = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.get(event_id).await }
+        }
+    });
+
+    // New times follow the last one, or start when the event does
+    let add_window = move |_| {
+        let last_end = windows.read().last().and_then(|(_, ends_at)| parse_local(ends_at));
+        let event_start = match &*event.read() {
+            Some(Ok(event)) => Some(event.start_date),
+            _ => None,
+        };
+        let starts_at = last_end.or(event_start).unwrap_or_else(Utc::now);
+        windows.write().push((local_input(starts_at), local_input(starts_at + Duration::hours(1))));
+    };
+
+    let submit = {
+        let svc = container.networking.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let availability: Option<Vec<AvailabilityWindow>> = windows
+                .read()
+                .iter()
+                .map(|(starts_at, ends_at)| {
+                    Some(AvailabilityWindow {
+                        starts_at: parse_local(starts_at)?,
+                        ends_at: parse_local(ends_at)?,
+                    })
+                })
+                .collect();
+            let Some(availability) = availability else {
+                error.set(Some("Fill in when each time you're free starts and ends".to_string()));
+                return;
+            };
+            let draft = NetworkingProfileDraft {
+                interests: parse_interests(&interests()),
+                bio: Some(bio()),
+                availability,
+            };
+            let svc = svc.clone();
+            spawn(async move {
+                saving.set(true);
+                match svc.opt_in(event_id, draft).await {
+                    Ok(_) => {
+                        error.set(None);
+                        on_saved.call(());
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "networking-form", onsubmit: submit,
+            label {
+                "Interests"
+                input {
+                    r#type: "text",
+                    placeholder: "e.g. fish health, sensors, feed",
+                    value: "{interests}",
+                    oninput: move |evt| interests.set(evt.value()),
+                }
+                span { class: "event-detail-muted", "Separate them with commas" }
+            }
+            label {
+                "About you"
+                textarea {
+                    rows: 3,
+                    placeholder: "What you offer or are looking for",
+                    value: "{bio}",
+                    oninput: move |evt| bio.set(evt.value()),
+                }
+            }
+            fieldset { class: "networking-windows",
+                legend { "Free for meetings" }
+                if windows.read().is_empty() {
+                    p { class: "event-detail-muted", "Add the times you can meet; meetings are only booked then." }
+                }
+                for (index, (starts_at, ends_at)) in windows.read().clone().into_iter().enumerate() {
+                    div { key: "{index}", class: "networking-window",
+                        input {
+                            r#type: "datetime-local",
+                            aria_label: "From",
+                            value: "{starts_at}",
+                            oninput: move |evt| windows.write()[index].0 = evt.value(),
+                        }
+                        span { "to" }
+                        input {
+                            r#type: "datetime-local",
+                            aria_label: "Until",
+                            value: "{ends_at}",
+                            oninput: move |evt| windows.write()[index].1 = evt.value(),
+                        }
+                        button {
+                            r#type: "button",
+                            onclick: move |_| {
+                                windows.write().remove(index);
+                            },
+                            "Remove"
+                        }
+                    }
+                }
+                button { r#type: "button", onclick: add_window, "Add a time" }
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            div { class: "networking-actions",
+                button { r#type: "submit", disabled: saving(),
+                    if saving() { "Saving..." } else if joined { "Save" } else { "Join the directory" }
+                }
+                if joined {
+                    button { r#type: "button", onclick: move |_| on_cancel.call(()), "Cancel" }
+                }
+            }
+        }
+    }
+}
+
+/// The user's meetings and everyone else in the directory
+#[component]
+fn Directory(container: AppContainer, event_id: Uuid, user_id: Uuid) -> Element {
+    // Bumped after each request or answer so both lists are fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let mut search = use_signal(String::new);
+    let mut interest = use_signal(|| None::<String>);
+
+    let contacts = use_resource({
+        let svc = container.networking.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.directory(event_id).await }
+        }
+    });
+    let meetings = use_resource({
+        let svc = container.networking.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.meetings(event_id).await }
+        }
+    });
+
+    rsx! {
+        section { class: "networking-meetings",
+            h3 { "Your meetings" }
+            match &*meetings.read() {
+                Some(Ok(meetings)) if meetings.is_empty() => rsx! {
+                    p { class: "event-detail-muted", "No meetings yet. Request one with someone below." }
+                },
+                Some(Ok(meetings)) => rsx! {
+                    ul { class: "networking-meeting-list",
+                        for meeting in meetings.iter() {
+                            MeetingCard {
+                                key: "{meeting.id}",
+                                container: container.clone(),
+                                event_id,
+                                user_id,
+                                meeting: meeting.clone(),
+                                on_changed: move |_| refresh += 1,
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+
+        section { class: "networking-directory",
+            h3 { "Who's here" }
+            match &*contacts.read() {
+                Some(Ok(contacts)) if contacts.is_empty() => rsx! {
+                    p { class: "event-detail-muted", "Nobody else has joined the directory yet." }
+                },
+                Some(Ok(contacts)) => {
+                    let shown = filter_contacts(contacts, &search(), interest().as_deref());
+                    // Whom the user already meets, so they aren't offered another request
+                    let meeting_with: Vec<Uuid> = match &*meetings.read() {
+                        Some(Ok(meetings)) => meetings
+                            .iter()
+                            .filter(|meeting| meeting.status.is_active())
+                            .map(|meeting| if meeting.requester_id == user_id { meeting.invitee_id } else { meeting.requester_id })
+                            .collect(),
+                        _ => Vec::new(),
+                    };
+                    rsx! {
+                        div { class: "networking-filters",
+                            input {
+                                r#type: "search",
+                                placeholder: "Search by name, company or interest...",
+                                value: "{search}",
+                                oninput: move |evt| search.set(evt.value()),
+                            }
+                            div { role: "group", aria_label: "Filter by interest",
+                                for topic in directory_interests(contacts) {
+                                    Badge {
+                                        key: "{topic}",
+                                        size: BadgeSize::Small,
+                                        selected: interest().as_deref() == Some(topic.as_str()),
+                                        on_click: {
+                                            let topic = topic.clone();
+                                            move |_| {
+                                                let picked = interest() != Some(topic.clone());
+                                                interest.set(picked.then(|| topic.clone()));
+                                            }
+                                        },
+                                        "{topic}"
+                                    }
+                                }
+                            }
+                        }
+                        if shown.is_empty() {
+                            p { class: "event-detail-muted", "Nobody matches the search." }
+                        }
+                        ul { class: "networking-contacts",
+                            for contact in shown {
+                                ContactCard {
+                                    key: "{contact.user_id}",
+                                    container: container.clone(),
+                                    event_id,
+                                    meeting: meeting_with.contains(&contact.user_id),
+                                    contact: contact.clone(),
+                                    on_requested: move |_| refresh += 1,
+                                }
+                            }
+                        }
+                    }
+                }
+                Some(Err(e)) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+#[component]
+fn ContactCard(
+    container: AppContainer,
+    event_id: Uuid,
+    contact: NetworkingContact,
+    // Already has a meeting with the user
+    meeting: bool,
+    on_requested: EventHandler<()>,
+) -> Element {
+    let mut requesting = use_signal(|| false);
+    let mut message = use_signal(String::new);
+    let mut sending = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let invitee_id = contact.user_id;
+
+    let submit = {
+        let svc = container.networking.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let svc = svc.clone();
+            spawn(async move {
+                sending.set(true);
+                match svc.request_meeting(event_id, invitee_id, &message()).await {
+                    Ok(()) => {
+                        error.set(None);
+                        message.set(String::new());
+                        requesting.set(false);
+                        on_requested.call(());
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                sending.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        li { class: "networking-contact",
+            div { class: "networking-contact-header",
+                Avatar { name: contact.name.clone(), size: AvatarSize::Small }
+                div {
+                    strong { "{contact.name}" }
+                    if let Some(company) = contact.company.clone() {
+                        span { class: "event-detail-muted", " · {company}" }
+                    }
+                }
+            }
+            if let Some(bio) = contact.bio.clone() {
+                p { "{bio}" }
+            }
+            if !contact.interests.is_empty() {
+                div { class: "networking-interests",
+                    for interest in contact.interests.iter() {
+                        Badge {
+                            key: "{interest}",
+                            size: BadgeSize::Small,
+                            variant: if contact.shared_interests.contains(interest) { BadgeVariant::Primary } else { BadgeVariant::default() },
+                            "{interest}"
+                        }
+                    }
+                }
+            }
+            if meeting {
+                p { class: "event-detail-muted", "You have a meeting together." }
+            } else if requesting() {
+                form { class: "networking-request", onsubmit: submit,
+                    textarea {
+                        rows: 2,
+                        aria_label: "Message",
+                        placeholder: "What would you like to talk about? (optional)",
+                        value: "{message}",
+                        oninput: move |evt| message.set(evt.value()),
+                    }
+                    div { class: "networking-actions",
+                        button { r#type: "submit", disabled: sending(),
+                            if sending() { "Finding a time..." } else { "Send request" }
+                        }
+                        button { r#type: "button", onclick: move |_| requesting.set(false), "Cancel" }
+                    }
+                }
+            } else {
+                button { r#type: "button", onclick: move |_| requesting.set(true), "Request meeting" }
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn MeetingCard(
+    container: AppContainer,
+    event_id: Uuid,
+    user_id: Uuid,
+    meeting: Meeting,
+    on_changed: EventHandler<()>,
+) -> Element {
+    let mut error = use_signal(|| None::<String>);
+    let meeting_id = meeting.id;
+    let incoming = meeting.invitee_id == user_id;
+    let with = if incoming { meeting.requester_name.clone() } else { meeting.invitee_name.clone() };
+
+    let answer = {
+        let svc = container.networking.clone();
+        move |accept: bool| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.answer(event_id, meeting_id, accept).await {
+                    Ok(()) => on_changed.call(()),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+    let accept = {
+        let answer = answer.clone();
+        move |_| answer(true)
+    };
+    let decline = {
+        let answer = answer.clone();
+        move |_| answer(false)
+    };
+
+    let cancel = {
+        let svc = container.networking.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.cancel(event_id, meeting_id).await {
+                    Ok(()) => on_changed.call(()),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let add_to_calendar = {
+        let svc = container.networking.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                let saved = match svc.invite(event_id, meeting_id).await {
+                    Ok(ics) => save_bytes("meeting.ics", "text/calendar", ics.as_bytes()),
+                    Err(e) => Err(e),
+                };
+                error.set(saved.err());
+            });
+        }
+    };
+
+    rsx! {
+        li { class: if meeting.status.is_active() { "networking-meeting" } else { "networking-meeting networking-meeting-closed" },
+            div {
+                strong { "{with}" }
+                span { class: "event-detail-muted", " · {meeting_time(&meeting)}" }
+                if let Some(room) = meeting.room.clone() {
+                    span { class: "event-detail-muted", " · {room}" }
+                }
+            }
+            if let Some(text) = meeting.message.clone() {
+                p { class: "networking-meeting-message", "“{text}”" }
+            }
+            div { class: "networking-actions",
+                Badge { size: BadgeSize::Small, {meeting.status.label()} }
+                if incoming && meeting.status == MeetingStatus::Requested {
+                    button { r#type: "button", onclick: accept, "Accept" }
+                    button { r#type: "button", onclick: decline, "Decline" }
+                } else if meeting.status.is_active() {
+                    button { r#type: "button", onclick: cancel, "Cancel meeting" }
+                }
+                if meeting.status.is_active() {
+                    button { r#type: "button", onclick: add_to_calendar, "Add to calendar" }
+                }
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+        }
+    }
+}
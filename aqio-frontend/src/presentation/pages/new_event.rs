@@ -0,0 +1,269 @@
+// Form for organizers to create an event. Only the essentials are asked for;
+// everything else starts at the API's defaults.
+
+use chrono::{Local, NaiveDateTime, Utc};
+use dioxus::prelude::*;
+
+use crate::application::ports::{EventDraft, LocationKind, UserMatch};
+use crate::lib::components::Popover;
+use crate::presentation::routes::Route;
+use crate::presentation::user_picker::UserPicker;
+use crate::AppContainer;
+
+const NEW_EVENT_CSS: Asset = asset!("/assets/new_event.css");
+
+// `<input type="datetime-local">` value format
+const DATETIME_LOCAL: &str = "%Y-%m-%dT%H:%M";
+// Events are organized in Norway; the API only stores the name
+const DEFAULT_TIMEZONE: &str = "Europe/Oslo";
+
+fn parse_local(value: &str) -> Option<chrono::DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value, DATETIME_LOCAL)
+        .ok()?
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|local| local.with_timezone(&Utc))
+}
+
+fn optional(value: String) -> Option<String> {
+    Some(value.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+#[component]
+pub fn NewEventPage(container: AppContainer) -> Element {
+    let mut title = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut category_id = use_signal(String::new);
+    let mut start = use_signal(String::new);
+    let mut end = use_signal(String::new);
+    let mut location_kind = use_signal(|| LocationKind::Physical);
+    let mut location_name = use_signal(String::new);
+    let mut address = use_signal(String::new);
+    let mut virtual_link = use_signal(String::new);
+    let mut max_attendees = use_signal(String::new);
+    let mut is_private = use_signal(|| false);
+    let mut publish_at = use_signal(String::new);
+    let co_organizers = use_signal(Vec::<UserMatch>::new);
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let categories = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.categories().await }
+        }
+    });
+
+    // Rechecked whenever the dates change; the API has the final say when saving
+    let schedule = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            let dates = (parse_local(&start()), parse_local(&end()));
+            async move {
+                match dates {
+                    (Some(start_date), Some(end_date)) => {
+                        svc.check_schedule(start_date, end_date, None).await.unwrap_or_default()
+                    }
+                    _ => Vec::new(),
+                }
+            }
+        }
+    });
+
+    let submit = {
+        let svc = container.events.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let (Some(start_date), Some(end_date)) = (parse_local(&start()), parse_local(&end())) else {
+                error.set(Some("Please enter when the event starts and ends".to_string()));
+                return;
+            };
+            let max_attendees = match max_attendees().trim() {
+                "" => None,
+                value => match value.parse::<i32>() {
+                    Ok(max) => Some(max),
+                    Err(_) => {
+                        error.set(Some(format!("'{}' is not a number", value)));
+                        return;
+                    }
+                },
+            };
+            let publish_at = match publish_at().trim() {
+                "" => None,
+                value => match parse_local(value) {
+                    Some(publish_at) => Some(publish_at),
+                    None => {
+                        error.set(Some("Please enter a valid publish time".to_string()));
+                        return;
+                    }
+                },
+            };
+            let kind = location_kind();
+            let draft = EventDraft {
+                title: title().trim().to_string(),
+                description: description().trim().to_string(),
+                category_id: category_id(),
+                start_date,
+                end_date,
+                timezone: DEFAULT_TIMEZONE.to_string(),
+                location_kind: kind,
+                location_name: optional(location_name()).filter(|_| kind != LocationKind::Virtual),
+                address: optional(address()).filter(|_| kind != LocationKind::Virtual),
+                virtual_link: optional(virtual_link()).filter(|_| kind != LocationKind::Physical),
+                coordinates: None,
+                max_attendees,
+                is_private: is_private(),
+                publish_at,
+                co_organizers: co_organizers().iter().map(|user| user.id).collect(),
+            };
+            let svc = svc.clone();
+            spawn(async move {
+                saving.set(true);
+                match svc.create(&draft).await {
+                    Ok(created) => {
+                        navigator().push(Route::EventDetail { event_id: created.id });
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: NEW_EVENT_CSS }
+
+        div { class: "new-event-page",
+            h1 { "Create event" }
+
+            form { class: "new-event-form", onsubmit: submit,
+                label { "Title"
+                    input { value: "{title}", oninput: move |evt| title.set(evt.value()), required: true, maxlength: "200" }
+                }
+                label { "Description"
+                    textarea { rows: "5", value: "{description}", oninput: move |evt| description.set(evt.value()), required: true }
+                }
+                label { "Category"
+                    select {
+                        value: "{category_id}",
+                        onchange: move |evt| category_id.set(evt.value()),
+                        required: true,
+                        option { value: "", disabled: true, "Choose a category" }
+                        if let Some(Ok(list)) = &*categories.read() {
+                            for category in list.iter() {
+                                option { key: "{category.id}", value: "{category.id}", "{category.name}" }
+                            }
+                        }
+                    }
+                }
+                div { class: "new-event-row",
+                    label { "Starts"
+                        input { r#type: "datetime-local", value: "{start}", oninput: move |evt| start.set(evt.value()), required: true }
+                    }
+                    label { "Ends"
+                        input { r#type: "datetime-local", value: "{end}", oninput: move |evt| end.set(evt.value()), required: true }
+                    }
+                }
+                if let Some(conflicts) = &*schedule.read() {
+                    if !conflicts.is_empty() {
+                        ul { class: "new-event-schedule",
+                            for conflict in conflicts.iter() {
+                                li {
+                                    key: "{conflict.message}",
+                                    class: if conflict.blocking { "new-event-schedule-blocking" } else { "new-event-schedule-warning" },
+                                    "{conflict.message}"
+                                }
+                            }
+                        }
+                    }
+                }
+                fieldset { class: "new-event-location",
+                    legend { "Where" }
+                    for kind in LocationKind::ALL {
+                        label { class: "new-event-inline",
+                            input {
+                                r#type: "radio",
+                                name: "location_kind",
+                                checked: location_kind() == kind,
+                                onchange: move |_| location_kind.set(kind),
+                            }
+                            " {kind.as_str()}"
+                        }
+                    }
+                    if location_kind() != LocationKind::Virtual {
+                        label { "Venue"
+                            input { value: "{location_name}", oninput: move |evt| location_name.set(evt.value()) }
+                        }
+                        label { "Address"
+                            input { value: "{address}", oninput: move |evt| address.set(evt.value()) }
+                        }
+                    }
+                    if location_kind() != LocationKind::Physical {
+                        label { "Link for joining online"
+                            input { r#type: "url", value: "{virtual_link}", oninput: move |evt| virtual_link.set(evt.value()) }
+                        }
+                    }
+                }
+                div { class: "new-event-row",
+                    // Hints sit outside the labels so clicks inside them don't reach the inputs
+                    div { class: "new-event-field",
+                        span { class: "new-event-label-text",
+                            label { r#for: "new-event-max-attendees", "Maximum attendees" }
+                            Popover { label: "About maximum attendees".to_string(), trigger: rsx! { "ⓘ" },
+                                p { "Once the event is full, new registrations go on a waitlist." }
+                                p { "When someone cancels, the first person waiting is offered the spot and has a limited time to confirm it." }
+                            }
+                        }
+                        input {
+                            id: "new-event-max-attendees",
+                            r#type: "number",
+                            min: "1",
+                            placeholder: "No limit",
+                            value: "{max_attendees}",
+                            oninput: move |evt| max_attendees.set(evt.value()),
+                        }
+                    }
+                    span { class: "new-event-label-text",
+                        label { class: "new-event-inline",
+                            input { r#type: "checkbox", checked: is_private(), onchange: move |evt| is_private.set(evt.checked()) }
+                            " Private (invited guests only)"
+                        }
+                        Popover { label: "About private events".to_string(), trigger: rsx! { "ⓘ" },
+                            "Private events aren't listed publicly. Only people you invite can see and register for them."
+                        }
+                    }
+                }
+
+                div { class: "new-event-field",
+                    span { class: "new-event-label-text",
+                        label { r#for: "new-event-publish-at", "Publish automatically" }
+                        Popover { label: "About automatic publishing".to_string(), trigger: rsx! { "ⓘ" },
+                            p { "The event stays a hidden draft until this time, then it is published and invited guests are notified." }
+                            p { "Leave it empty to publish the event yourself." }
+                        }
+                    }
+                    input {
+                        id: "new-event-publish-at",
+                        r#type: "datetime-local",
+                        value: "{publish_at}",
+                        oninput: move |evt| publish_at.set(evt.value()),
+                    }
+                }
+
+                UserPicker { label: "Co-organizers".to_string(), picked: co_organizers }
+
+                if let Some(message) = error() {
+                    p { class: "new-event-error", "{message}" }
+                }
+                div { class: "new-event-actions",
+                    Link { to: Route::Events {}, "Cancel" }
+                    button { r#type: "submit", class: "new-event-submit", disabled: saving(),
+                        if saving() { "Creating..." } else { "Create event" }
+                    }
+                }
+            }
+        }
+    }
+}
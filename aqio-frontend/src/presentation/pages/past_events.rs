@@ -0,0 +1,262 @@
+// Archive of events that are over, for annual reporting: attendance per
+// category or organizer over the last five years, each year compared with the
+// one before, and the past events themselves with their turnout. Both follow
+// the category filter; the year filter picks the last year of the trends.
+
+use chrono::{Datelike, Utc};
+use dioxus::prelude::*;
+
+use crate::application::ports::{ArchiveGrouping, AttendanceTrend, PastEventQuery};
+use crate::application::services::PastEventService;
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+const PAST_EVENTS_CSS: Asset = asset!("/assets/past_events.css");
+
+// Years offered in the year filter, counting back from this one
+const SELECTABLE_YEARS: i32 = 10;
+
+fn percent(rate: Option<f64>) -> String {
+    rate.map(|rate| format!("{:.1}%", rate)).unwrap_or_else(|| "–".to_string())
+}
+
+fn signed_percent(change: Option<f64>) -> String {
+    change.map(|change| format!("{:+.1}%", change)).unwrap_or_else(|| "–".to_string())
+}
+
+#[component]
+pub fn PastEventsPage(container: AppContainer) -> Element {
+    let this_year = Utc::now().year();
+    let mut page = use_signal(|| 1u32);
+    let mut title = use_signal(String::new);
+    let mut category_id = use_signal(|| None::<String>);
+    let mut year = use_signal(|| None::<i32>);
+    let mut grouping = use_signal(ArchiveGrouping::default);
+    let last_year = year().unwrap_or(this_year);
+
+    let categories = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.categories().await }
+        }
+    });
+
+    let events = use_resource({
+        let svc = container.past_events.clone();
+        move || {
+            let svc = svc.clone();
+            let page = page();
+            let query = PastEventQuery {
+                title: title(),
+                category_id: category_id(),
+                year: year(),
+            };
+            async move { svc.list(page, &query).await }
+        }
+    });
+
+    let trends = use_resource({
+        let svc = container.past_events.clone();
+        move || {
+            let svc = svc.clone();
+            let grouping = grouping();
+            let category_id = category_id();
+            let last_year = year().unwrap_or(this_year);
+            async move { svc.trends(grouping, category_id.as_deref(), last_year).await }
+        }
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: PAST_EVENTS_CSS }
+
+        div { class: "past-events-page",
+            h1 { "Event archive" }
+
+            div { class: "past-events-filters",
+                input {
+                    r#type: "search",
+                    aria_label: "Search by title",
+                    placeholder: "Search by title",
+                    value: "{title}",
+                    oninput: move |evt| {
+                        title.set(evt.value());
+                        page.set(1);
+                    },
+                }
+                select {
+                    aria_label: "Category",
+                    onchange: move |evt| {
+                        category_id.set(Some(evt.value()).filter(|id| !id.is_empty()));
+                        page.set(1);
+                    },
+                    option { value: "", selected: category_id().is_none(), "All categories" }
+                    if let Some(Ok(list)) = &*categories.read() {
+                        for category in list.iter() {
+                            option {
+                                key: "{category.id}",
+                                value: "{category.id}",
+                                selected: category_id().as_deref() == Some(category.id.as_str()),
+                                "{category.name}"
+                            }
+                        }
+                    }
+                }
+                select {
+                    aria_label: "Year",
+                    onchange: move |evt| {
+                        year.set(evt.value().parse().ok());
+                        page.set(1);
+                    },
+                    option { value: "", selected: year().is_none(), "All years" }
+                    for option_year in (this_year - SELECTABLE_YEARS + 1..=this_year).rev() {
+                        option {
+                            key: "{option_year}",
+                            value: "{option_year}",
+                            selected: year() == Some(option_year),
+                            "{option_year}"
+                        }
+                    }
+                }
+            }
+
+            section { class: "past-events-section",
+                div { class: "past-events-section-header",
+                    h2 { {format!("Attendance {}–{}", last_year - PastEventService::TREND_YEARS + 1, last_year)} }
+                    div { class: "past-events-grouping", role: "group", aria_label: "Group totals",
+                        for option in ArchiveGrouping::ALL {
+                            button {
+                                key: "{option.as_str()}",
+                                r#type: "button",
+                                class: if grouping() == option { "active" } else { "" },
+                                aria_pressed: grouping() == option,
+                                onclick: move |_| grouping.set(option),
+                                {option.label()}
+                            }
+                        }
+                    }
+                }
+                match &*trends.read() {
+                    Some(Ok(trends)) if trends.is_empty() => rsx! {
+                        p { class: "past-events-muted", "No past events in these years." }
+                    },
+                    Some(Ok(trends)) => rsx! {
+                        for trend in trends.iter() {
+                            TrendTable { key: "{trend.group_id}", trend: trend.clone() }
+                        }
+                    },
+                    Some(Err(e)) => rsx! { p { class: "past-events-error", "Error: {e}" } },
+                    None => rsx! { p { "Loading..." } },
+                }
+            }
+
+            section { class: "past-events-section",
+                h2 { "Past events" }
+                match &*events.read() {
+                    Some(Ok(result)) if result.items.is_empty() => rsx! {
+                        p { class: "past-events-muted", "No past events match these filters." }
+                    },
+                    Some(Ok(result)) => rsx! {
+                        p { class: "past-events-muted", "{result.total_count} events" }
+                        table { class: "past-events-table",
+                            thead {
+                                tr {
+                                    th { "Date" }
+                                    th { "Event" }
+                                    th { "Category" }
+                                    th { "Organizer" }
+                                    th { class: "past-events-count", "Registered" }
+                                    th { class: "past-events-count", "Checked in" }
+                                    th { class: "past-events-count", "No-shows" }
+                                    th { class: "past-events-count", "Cancelled" }
+                                    th { class: "past-events-count", "Attendance" }
+                                }
+                            }
+                            tbody {
+                                for event in result.items.iter() {
+                                    tr { key: "{event.id}",
+                                        td { {event.start_date.format("%Y-%m-%d").to_string()} }
+                                        td {
+                                            Link { to: Route::EventDetail { event_id: event.id }, "{event.title}" }
+                                        }
+                                        td { "{event.category}" }
+                                        td { "{event.organizer}" }
+                                        td { class: "past-events-count", "{event.registrations}" }
+                                        td { class: "past-events-count", "{event.checked_in}" }
+                                        td { class: "past-events-count", "{event.no_shows}" }
+                                        td { class: "past-events-count", "{event.cancelled}" }
+                                        td { class: "past-events-count", {percent(event.attendance_rate)} }
+                                    }
+                                }
+                            }
+                        }
+                        if result.total_pages > 1 {
+                            div { class: "past-events-pager",
+                                button {
+                                    r#type: "button",
+                                    disabled: page() <= 1,
+                                    onclick: move |_| page.set(page() - 1),
+                                    "Previous"
+                                }
+                                span { "Page {page} of {result.total_pages}" }
+                                button {
+                                    r#type: "button",
+                                    disabled: page() >= result.total_pages,
+                                    onclick: move |_| page.set(page() + 1),
+                                    "Next"
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! { p { class: "past-events-error", "Error: {e}" } },
+                    None => rsx! { p { "Loading..." } },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TrendTable(trend: AttendanceTrend) -> Element {
+    rsx! {
+        table { class: "past-events-table past-events-trend",
+            caption {
+                strong { "{trend.name}" }
+                span { class: "past-events-muted",
+                    " · {trend.events} events, {trend.registrations} registrations, "
+                    {percent(trend.attendance_rate)}
+                    " attended"
+                }
+            }
+            thead {
+                tr {
+                    th { "Year" }
+                    th { class: "past-events-count", "Events" }
+                    th { class: "past-events-count", "Registered" }
+                    th { class: "past-events-count", "Checked in" }
+                    th { class: "past-events-count", "Attendance" }
+                    th { class: "past-events-count", "Registered vs. year before" }
+                }
+            }
+            tbody {
+                for year in trend.years.iter() {
+                    tr { key: "{year.year}",
+                        td { "{year.year}" }
+                        td { class: "past-events-count", "{year.events}" }
+                        td { class: "past-events-count", "{year.registrations}" }
+                        td { class: "past-events-count", "{year.checked_in}" }
+                        td { class: "past-events-count", {percent(year.attendance_rate)} }
+                        td {
+                            class: match year.registrations_change {
+                                Some(change) if change > 0.0 => "past-events-count past-events-up",
+                                Some(change) if change < 0.0 => "past-events-count past-events-down",
+                                _ => "past-events-count",
+                            },
+                            {signed_percent(year.registrations_change)}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,368 @@
+// Photos tab of the event page. Once the event is over organizers add photos,
+// and so do attendees when the album allows it; organizers approve or reject
+// what attendees send in and choose whether the gallery is public. Photos are
+// fetched with the user's credentials, since pending ones are only shown to
+// their uploader and the organizers.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{ClientConfig, Photo, PhotoAlbumSettings, PhotoUpload};
+use crate::infrastructure::download::{object_url, revoke_object_url, save_bytes};
+use crate::AppContainer;
+
+const PHOTOS_CSS: Asset = asset!("/assets/photos.css");
+
+/// Mounted only while its tab is selected, so the photos are fetched on demand
+#[component]
+pub fn PhotosTab(container: AppContainer, event_id: Uuid) -> Element {
+    // Bumped after each change so the gallery is fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let mut downloading = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let gallery = use_resource({
+        let svc = container.photos.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.gallery(event_id).await }
+        }
+    });
+
+    let download_all = {
+        let svc = container.photos.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                downloading.set(true);
+                let saved = match svc.download_all(event_id).await {
+                    Ok(bytes) => save_bytes("event-photos.zip", "application/zip", &bytes),
+                    Err(e) => Err(e),
+                };
+                error.set(saved.err());
+                downloading.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: PHOTOS_CSS }
+
+        match &*gallery.read() {
+            Some(Ok(gallery)) => rsx! {
+                if gallery.can_manage {
+                    AlbumSettingsForm {
+                        container: container.clone(),
+                        event_id,
+                        settings: gallery.settings,
+                        on_saved: move |_| refresh += 1,
+                    }
+                }
+                if gallery.can_upload {
+                    UploadPhotoForm { container: container.clone(), event_id, on_uploaded: move |_| refresh += 1 }
+                }
+                if gallery.photos.is_empty() {
+                    p { class: "event-detail-muted",
+                        if gallery.can_manage {
+                            "No photos yet. They can be added once the event is over."
+                        } else {
+                            "No photos have been shared yet."
+                        }
+                    }
+                } else {
+                    div { class: "photos-toolbar",
+                        button { r#type: "button", disabled: downloading(), onclick: download_all,
+                            if downloading() { "Preparing..." } else { "Download all" }
+                        }
+                        if let Some(message) = error() {
+                            p { class: "event-detail-error", "{message}" }
+                        }
+                    }
+                }
+                ul { class: "photos-grid",
+                    for photo in gallery.photos.iter() {
+                        PhotoCard {
+                            key: "{photo.id}",
+                            container: container.clone(),
+                            event_id,
+                            photo: photo.clone(),
+                            can_manage: gallery.can_manage,
+                            on_changed: move |_| refresh += 1,
+                        }
+                    }
+                }
+            },
+            Some(Err(e)) => rsx! { p { class: "event-detail-error", "Error: {e}" } },
+            None => rsx! { p { "Loading..." } },
+        }
+    }
+}
+
+#[component]
+fn PhotoCard(
+    container: AppContainer,
+    event_id: Uuid,
+    photo: Photo,
+    can_manage: bool,
+    on_changed: EventHandler<()>,
+) -> Element {
+    // A `blob:` URL for the fetched image, released when the card goes away
+    let mut image = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+    let id = photo.id;
+
+    use_future({
+        let svc = container.photos.clone();
+        let mime_type = photo.mime_type.clone();
+        move || {
+            let svc = svc.clone();
+            let mime_type = mime_type.clone();
+            async move {
+                match svc.download(event_id, id).await.and_then(|bytes| object_url(&mime_type, &bytes)) {
+                    Ok(url) => image.set(Some(url)),
+                    Err(e) => error.set(Some(e)),
+                }
+            }
+        }
+    });
+
+    use_drop(move || {
+        if let Some(url) = image.take() {
+            revoke_object_url(&url);
+        }
+    });
+
+    let moderate = {
+        let svc = container.photos.clone();
+        move |approve: bool| {
+            let svc = svc.clone();
+            spawn(async move {
+                let moderated = if approve {
+                    svc.approve(event_id, id).await
+                } else {
+                    svc.reject(event_id, id).await
+                };
+                match moderated {
+                    Ok(_) => on_changed.call(()),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+    let approve = {
+        let moderate = moderate.clone();
+        move |_| moderate(true)
+    };
+    let reject = {
+        let moderate = moderate.clone();
+        move |_| moderate(false)
+    };
+
+    let remove = {
+        let svc = container.photos.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.delete(event_id, id).await {
+                    Ok(()) => on_changed.call(()),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let alt = photo.caption.clone().unwrap_or_else(|| photo.filename.clone());
+
+    rsx! {
+        li { class: if photo.status == "approved" { "photos-card" } else { "photos-card photos-card-unapproved" },
+            figure {
+                if let Some(url) = image() {
+                    img { src: "{url}", alt: "{alt}", loading: "lazy" }
+                } else {
+                    div { class: "photos-placeholder", aria_hidden: "true" }
+                }
+                figcaption {
+                    if let Some(caption) = photo.caption.clone() {
+                        span { "{caption}" }
+                    }
+                    span { class: "event-detail-muted",
+                        {photo.uploader_name.clone().unwrap_or_else(|| "Unknown".to_string())}
+                        if photo.is_pending() { " · Waiting for approval" }
+                        if photo.status == "rejected" { " · Rejected" }
+                    }
+                }
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            if can_manage || photo.can_remove {
+                div { class: "event-detail-comment-actions",
+                    if can_manage && photo.status != "approved" {
+                        button { r#type: "button", onclick: approve, "Approve" }
+                    }
+                    if can_manage && photo.status != "rejected" {
+                        button { r#type: "button", onclick: reject, "Reject" }
+                    }
+                    if photo.can_remove {
+                        button { r#type: "button", onclick: remove, "Remove" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Saved as soon as a box is ticked
+#[component]
+fn AlbumSettingsForm(
+    container: AppContainer,
+    event_id: Uuid,
+    settings: PhotoAlbumSettings,
+    on_saved: EventHandler<()>,
+) -> Element {
+    let mut error = use_signal(|| None::<String>);
+
+    let save = {
+        let svc = container.photos.clone();
+        move |changed: PhotoAlbumSettings| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.update_album(event_id, changed).await {
+                    Ok(()) => {
+                        error.set(None);
+                        on_saved.call(());
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+    let save_uploads = {
+        let save = save.clone();
+        move |evt: FormEvent| save(PhotoAlbumSettings { attendee_uploads: evt.checked(), ..settings })
+    };
+    let save_approval = {
+        let save = save.clone();
+        move |evt: FormEvent| save(PhotoAlbumSettings { requires_approval: evt.checked(), ..settings })
+    };
+    let save_public = {
+        let save = save.clone();
+        move |evt: FormEvent| save(PhotoAlbumSettings { is_public: evt.checked(), ..settings })
+    };
+
+    rsx! {
+        fieldset { class: "photos-settings",
+            legend { "Album settings" }
+            label {
+                input { r#type: "checkbox", checked: settings.attendee_uploads, onchange: save_uploads }
+                " Attendees can add photos"
+            }
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: settings.requires_approval,
+                    disabled: !settings.attendee_uploads,
+                    onchange: save_approval,
+                }
+                " Attendees' photos wait for approval"
+            }
+            label {
+                input { r#type: "checkbox", checked: settings.is_public, onchange: save_public }
+                " Show approved photos on the public event page"
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn UploadPhotoForm(container: AppContainer, event_id: Uuid, on_uploaded: EventHandler<()>) -> Element {
+    // The chosen file's name and contents
+    let mut file = use_signal(|| None::<(String, Vec<u8>)>);
+    let mut caption = use_signal(String::new);
+    let mut uploading = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let config = use_context::<Signal<ClientConfig>>();
+
+    let choose_file = move |evt: FormEvent| async move {
+        if let Some(engine) = evt.files() {
+            if let Some(file_name) = engine.files().first() {
+                let max_bytes = config.read().max_upload_bytes;
+                match engine.read_file(file_name).await {
+                    // Refused here rather than after sending the whole file
+                    Some(bytes) if bytes.len() as u64 > max_bytes => {
+                        file.set(None);
+                        error.set(Some(format!(
+                            "'{}' is larger than the {} MB upload limit",
+                            file_name,
+                            max_bytes / (1024 * 1024)
+                        )));
+                    }
+                    Some(bytes) => {
+                        file.set(Some((file_name.clone(), bytes)));
+                        error.set(None);
+                    }
+                    None => error.set(Some(format!("Could not read '{}'", file_name))),
+                }
+            }
+        }
+    };
+
+    let submit = {
+        let svc = container.photos.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let Some((filename, bytes)) = file() else { return };
+            let svc = svc.clone();
+            let upload = PhotoUpload {
+                filename,
+                // Guessed from the extension by the service
+                mime_type: String::new(),
+                bytes,
+                caption: Some(caption()),
+            };
+            spawn(async move {
+                uploading.set(true);
+                match svc.upload(event_id, upload).await {
+                    Ok(_) => {
+                        file.set(None);
+                        caption.set(String::new());
+                        error.set(None);
+                        on_uploaded.call(());
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                uploading.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "event-detail-comment-form", onsubmit: submit,
+            input {
+                r#type: "file",
+                accept: "image/jpeg,image/png,image/webp,image/gif",
+                aria_label: "Photo",
+                onchange: choose_file,
+            }
+            input {
+                r#type: "text",
+                aria_label: "Caption",
+                placeholder: "Caption (optional)",
+                value: "{caption}",
+                oninput: move |evt| caption.set(evt.value()),
+            }
+            if let Some(message) = error() {
+                p { class: "event-detail-error", "{message}" }
+            }
+            button {
+                r#type: "submit",
+                disabled: uploading() || file().is_none(),
+                if uploading() { "Uploading..." } else { "Add photo" }
+            }
+        }
+    }
+}
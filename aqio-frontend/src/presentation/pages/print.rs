@@ -0,0 +1,217 @@
+// Printable views for organizers: the attendee list as a door sheet to tick off
+// by hand, and the event program to hand out on the day.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{Attendee, AttendeeStatus, EventProgram};
+use crate::application::services::sponsors_by_tier;
+use crate::lib::components::{KeepTogether, PrintDocument, PrintSection, PrintTable};
+use crate::presentation::routes::Route;
+use crate::AppContainer;
+
+/// Attendees with `status`, alphabetically as they'd be looked up at the door
+fn sorted_by_name(attendees: &[Attendee], status: &[AttendeeStatus]) -> Vec<Attendee> {
+    let mut selected: Vec<Attendee> = attendees
+        .iter()
+        .filter(|attendee| status.contains(&attendee.status))
+        .cloned()
+        .collect();
+    selected.sort_by_key(|attendee| attendee.display_name().to_lowercase());
+    selected
+}
+
+fn people(attendees: &[Attendee]) -> i32 {
+    attendees.iter().map(|attendee| 1 + attendee.guest_count).sum()
+}
+
+fn when_and_where(program: &EventProgram) -> String {
+    let when = format!(
+        "{} – {}",
+        program.start_date.format("%A %-d %B %Y, %H:%M"),
+        program.end_date.format("%H:%M")
+    );
+    match program.address.as_deref().or(program.location_name.as_deref()) {
+        Some(place) => format!("{} · {}", when, place),
+        None => when,
+    }
+}
+
+#[component]
+pub fn PrintAttendeesPage(container: AppContainer, event_id: Uuid) -> Element {
+    let event = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.get(event_id).await }
+        }
+    });
+    let roster = use_resource({
+        let svc = container.check_in.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.load_roster(event_id).await }
+        }
+    });
+
+    let (title, subtitle) = match &*event.read() {
+        Some(Ok(ev)) => (
+            format!("Attendees: {}", ev.title),
+            Some(format!(
+                "{} · {}",
+                ev.start_date.format("%A %-d %B %Y, %H:%M"),
+                ev.location.as_deref().unwrap_or("TBA")
+            )),
+        ),
+        _ => ("Attendees".to_string(), None),
+    };
+
+    rsx! {
+        p { class: "aqio-no-print",
+            Link { to: Route::EventCheckIn { event_id }, "← Back to check-in" }
+        }
+
+        match &*roster.read() {
+            Some(Ok(current)) => {
+                let admitted = sorted_by_name(&current.attendees, &[AttendeeStatus::Expected, AttendeeStatus::CheckedIn]);
+                let waitlisted = sorted_by_name(&current.attendees, &[AttendeeStatus::Waitlisted]);
+                rsx! {
+                    PrintDocument { title, subtitle,
+                        PrintSection {
+                            title: format!("{} registrations, {} people including guests", admitted.len(), people(&admitted)),
+                            PrintTable {
+                                columns: vec!["".to_string(), "Name".to_string(), "Email".to_string(), "Guests".to_string(), "Arrived".to_string()],
+                                for (number, attendee) in admitted.iter().enumerate() {
+                                    tr { key: "{attendee.registration_id}",
+                                        td { "{number + 1}" }
+                                        td { {attendee.display_name()} }
+                                        td { {attendee.email.clone().unwrap_or_default()} }
+                                        td { if attendee.guest_count > 0 { "+{attendee.guest_count}" } }
+                                        td { if attendee.status == AttendeeStatus::CheckedIn { "☑" } else { "☐" } }
+                                    }
+                                }
+                            }
+                        }
+                        if !waitlisted.is_empty() {
+                            PrintSection { title: format!("Waitlist ({})", waitlisted.len()),
+                                PrintTable {
+                                    columns: vec!["".to_string(), "Name".to_string(), "Email".to_string(), "Guests".to_string()],
+                                    for (number, attendee) in waitlisted.iter().enumerate() {
+                                        tr { key: "{attendee.registration_id}",
+                                            td { "{number + 1}" }
+                                            td { {attendee.display_name()} }
+                                            td { {attendee.email.clone().unwrap_or_default()} }
+                                            td { if attendee.guest_count > 0 { "+{attendee.guest_count}" } }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            Some(Err(e)) => rsx! { p { style: "color:red;", "Error: {e}" } },
+            None => rsx! { p { "Loading..." } },
+        }
+    }
+}
+
+#[component]
+pub fn PrintProgramPage(container: AppContainer, event_id: Uuid) -> Element {
+    let program = use_resource({
+        let svc = container.events.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.program(event_id).await }
+        }
+    });
+    // The program still prints if the sponsors can't be loaded
+    let sponsors = use_resource({
+        let svc = container.sponsors.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.list(event_id).await.unwrap_or_default() }
+        }
+    });
+
+    rsx! {
+        p { class: "aqio-no-print",
+            Link { to: Route::EventDetail { event_id }, "← Back to event" }
+        }
+
+        match &*program.read() {
+            Some(Ok(program)) => rsx! {
+                PrintDocument { title: program.title.clone(), subtitle: when_and_where(program),
+                    PrintSection {
+                        for paragraph in program.description.split("\n\n").filter(|text| !text.trim().is_empty()) {
+                            p { "{paragraph}" }
+                        }
+                    }
+                    if !program.agenda.is_empty() {
+                        PrintSection { title: "Agenda".to_string(),
+                            PrintTable {
+                                columns: vec!["Time".to_string(), "Session".to_string(), "Speaker".to_string()],
+                                for item in program.agenda.iter() {
+                                    tr {
+                                        td { {item.time.clone().unwrap_or_default()} }
+                                        td {
+                                            strong { "{item.title}" }
+                                            if let Some(description) = item.description.clone() {
+                                                br {}
+                                                "{description}"
+                                            }
+                                        }
+                                        td { {item.speaker.clone().unwrap_or_default()} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if !program.speakers.is_empty() {
+                        PrintSection { title: "Speakers".to_string(),
+                            for speaker in program.speakers.iter() {
+                                KeepTogether {
+                                    h3 { "{speaker.name}" }
+                                    if let Some(role) = [speaker.title.clone(), speaker.company.clone()]
+                                        .into_iter()
+                                        .flatten()
+                                        .reduce(|title, company| format!("{title}, {company}"))
+                                    {
+                                        p { em { "{role}" } }
+                                    }
+                                    if let Some(bio) = speaker.bio.clone() {
+                                        p { "{bio}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if let Some(sponsors) = sponsors.read().as_ref().filter(|sponsors| !sponsors.is_empty()) {
+                        PrintSection { title: "Sponsors".to_string(),
+                            for (tier, in_tier) in sponsors_by_tier(sponsors) {
+                                KeepTogether {
+                                    h3 { {tier.label()} }
+                                    div { style: "display:flex; flex-wrap:wrap; gap:1.5rem;",
+                                        for sponsor in in_tier.iter() {
+                                            div { style: "display:flex; flex-direction:column; max-width:12rem;",
+                                                if let Some(url) = sponsor.logo_url.clone() {
+                                                    img { src: "{url}", alt: "{sponsor.name}", style: "max-width:10rem; max-height:3rem; object-fit:contain;" }
+                                                }
+                                                strong { "{sponsor.name}" }
+                                                if let Some(website) = sponsor.website_url.clone() {
+                                                    span { "{website}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            Some(Err(e)) => rsx! { p { style: "color:red;", "Error: {e}" } },
+            None => rsx! { p { "Loading..." } },
+        }
+    }
+}
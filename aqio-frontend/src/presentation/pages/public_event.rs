@@ -0,0 +1,589 @@
+// Public event page, reachable by slug without signing in: hero, program,
+// speakers, shared materials, photos from the event, sponsors, the exhibitors at
+// expos, a map of the venue, travel information and a registration form for guests.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::{GuestRegistration, GuestRegistrationOutcome, ParkingInfo, PublicEvent, TravelInfo};
+use crate::application::services::{format_file_size, sponsors_by_tier};
+use crate::AppContainer;
+
+const PUBLIC_EVENT_CSS: Asset = asset!("/assets/public_event.css");
+
+// Search engines show roughly this many characters of a description
+const META_DESCRIPTION_LENGTH: usize = 160;
+
+fn meta_description(description: &str) -> String {
+    let collapsed = description.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= META_DESCRIPTION_LENGTH {
+        return collapsed;
+    }
+    let truncated: String = collapsed.chars().take(META_DESCRIPTION_LENGTH - 1).collect();
+    format!("{}…", truncated.trim_end())
+}
+
+fn map_embed_url(query: &str) -> String {
+    let encoded: String = js_sys::encode_uri_component(query).into();
+    format!("https://www.google.com/maps?q={}&output=embed", encoded)
+}
+
+fn format_when(event: &PublicEvent) -> String {
+    if event.start_date.date_naive() == event.end_date.date_naive() {
+        format!(
+            "{} – {}",
+            event.start_date.format("%A %-d %B %Y, %H:%M"),
+            event.end_date.format("%H:%M")
+        )
+    } else {
+        format!(
+            "{} – {}",
+            event.start_date.format("%-d %B %Y, %H:%M"),
+            event.end_date.format("%-d %B %Y, %H:%M")
+        )
+    }
+}
+
+#[component]
+pub fn PublicEventPage(container: AppContainer, slug: String) -> Element {
+    let event = use_resource({
+        let svc = container.public_events.clone();
+        let slug = slug.clone();
+        move || {
+            let svc = svc.clone();
+            let slug = slug.clone();
+            async move { svc.get(&slug).await }
+        }
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: PUBLIC_EVENT_CSS }
+
+        div { class: "public-event-page",
+            match &*event.read() {
+                Some(Ok(ev)) => rsx! { PublicEventContent { event: ev.clone() } },
+                Some(Err(e)) => rsx! {
+                    document::Title { "Event not found" }
+                    p { class: "public-event-error", "{e}" }
+                },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+/// Sends a visitor who opened a short link through the API, which counts the
+/// click and redirects to the event page. The invitation token (`?i=`) and the
+/// page the visitor came from are passed along.
+#[component]
+pub fn ShortLinkRedirect(container: AppContainer, code: String) -> Element {
+    let target = container.public_events.short_link_url(&code);
+
+    use_effect(move || {
+        let Some(window) = web_sys::window() else { return };
+        let mut params = Vec::new();
+        let search = window.location().search().unwrap_or_default();
+        if let Some(token) = search
+            .trim_start_matches('?')
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("i="))
+            .filter(|token| !token.is_empty())
+        {
+            params.push(format!("i={}", token));
+        }
+        let referrer = window.document().map(|document| document.referrer()).unwrap_or_default();
+        if !referrer.is_empty() {
+            let encoded: String = js_sys::encode_uri_component(&referrer).into();
+            params.push(format!("r={}", encoded));
+        }
+        let url = if params.is_empty() {
+            target.clone()
+        } else {
+            format!("{}?{}", target, params.join("&"))
+        };
+        // Replaced, so the back button skips the short link
+        let _ = window.location().replace(&url);
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: PUBLIC_EVENT_CSS }
+        div { class: "public-event-page",
+            p { "Opening the event..." }
+        }
+    }
+}
+
+#[component]
+fn PublicEventContent(event: PublicEvent) -> Element {
+    let container = use_context::<AppContainer>();
+    let description = meta_description(&event.description);
+    let when = format_when(&event);
+
+    rsx! {
+        document::Title { "{event.title}" }
+        document::Meta { name: "description", content: "{description}" }
+        document::Meta { property: "og:type", content: "website" }
+        document::Meta { property: "og:title", content: "{event.title}" }
+        document::Meta { property: "og:description", content: "{description}" }
+        if let Some(image) = event.image_url.clone() {
+            document::Meta { property: "og:image", content: "{image}" }
+        }
+        if let Some(markup) = event.structured_data.clone() {
+            document::Script { r#type: "application/ld+json", "{markup}" }
+        }
+
+        header {
+            class: "public-event-hero",
+            style: event
+                .image_url
+                .as_ref()
+                .map(|image| format!("background-image: url('{}')", image))
+                .unwrap_or_default(),
+            div { class: "public-event-hero-text",
+                h1 { "{event.title}" }
+                p { "{when}" }
+                if let Some(location) = event.location_name.clone() {
+                    p { "{location}" }
+                }
+                a {
+                    class: "public-event-calendar-link",
+                    href: container.public_events.calendar_url(&event.slug),
+                    download: "{event.slug}.ics",
+                    "Add to calendar"
+                }
+            }
+        }
+
+        div { class: "public-event-columns",
+            div { class: "public-event-main",
+                section {
+                    h2 { "About" }
+                    for paragraph in event.description.split("\n\n").filter(|p| !p.trim().is_empty()) {
+                        p { "{paragraph}" }
+                    }
+                }
+
+                if !event.agenda.is_empty() {
+                    section {
+                        h2 { "Agenda" }
+                        ol { class: "public-event-agenda",
+                            for item in event.agenda.iter() {
+                                li {
+                                    span { class: "public-event-agenda-time", {item.time.clone().unwrap_or_default()} }
+                                    div {
+                                        strong { "{item.title}" }
+                                        if let Some(speaker) = item.speaker.clone() {
+                                            span { class: "public-event-muted", " — {speaker}" }
+                                        }
+                                        if let Some(details) = item.description.clone() {
+                                            p { class: "public-event-muted", "{details}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !event.speakers.is_empty() {
+                    section {
+                        h2 { "Speakers" }
+                        div { class: "public-event-speakers",
+                            for speaker in event.speakers.iter() {
+                                div { class: "public-event-speaker",
+                                    if let Some(photo) = speaker.photo_url.clone() {
+                                        img { src: "{photo}", alt: "{speaker.name}" }
+                                    }
+                                    strong { "{speaker.name}" }
+                                    {
+                                        let role: Vec<String> = [speaker.title.clone(), speaker.company.clone()]
+                                            .into_iter()
+                                            .flatten()
+                                            .collect();
+                                        rsx! {
+                                            if !role.is_empty() {
+                                                span { class: "public-event-muted", {role.join(", ")} }
+                                            }
+                                        }
+                                    }
+                                    if let Some(bio) = speaker.bio.clone() {
+                                        p { "{bio}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !event.attachments.is_empty() {
+                    section {
+                        h2 { "Materials" }
+                        ul { class: "public-event-attachments",
+                            for attachment in event.attachments.iter() {
+                                li { key: "{attachment.id}",
+                                    a {
+                                        href: container.public_events.attachment_url(&event.slug, attachment.id),
+                                        download: "{attachment.filename}",
+                                        {attachment.display_name().to_string()}
+                                    }
+                                    span { class: "public-event-muted", {format!(" · {}", format_file_size(attachment.file_size))} }
+                                    if let Some(description) = attachment.description.clone() {
+                                        p { class: "public-event-muted", "{description}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !event.photos.is_empty() {
+                    section {
+                        h2 { "Photos" }
+                        ul { class: "public-event-photos",
+                            for photo in event.photos.iter() {
+                                li { key: "{photo.id}",
+                                    figure {
+                                        a { href: container.public_events.photo_url(&event.slug, photo.id), target: "_blank", rel: "noopener",
+                                            img {
+                                                src: container.public_events.photo_url(&event.slug, photo.id),
+                                                alt: photo.caption.clone().unwrap_or_else(|| photo.filename.clone()),
+                                                loading: "lazy",
+                                            }
+                                        }
+                                        if let Some(caption) = photo.caption.clone() {
+                                            figcaption { class: "public-event-muted", "{caption}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !event.sponsors.is_empty() {
+                    section {
+                        h2 { "Sponsors" }
+                        for (tier, sponsors) in sponsors_by_tier(&event.sponsors) {
+                            div { key: "{tier.as_str()}", class: "public-event-sponsor-tier",
+                                h3 { {tier.label()} }
+                                ul { class: "public-event-sponsors",
+                                    for sponsor in sponsors.iter() {
+                                        li { key: "{sponsor.id}", class: "public-event-sponsor",
+                                            if let Some(url) = sponsor.website_url.clone() {
+                                                a { href: "{url}", target: "_blank", rel: "noopener",
+                                                    SponsorMark { name: sponsor.name.clone(), logo_url: sponsor.logo_url.clone() }
+                                                }
+                                            } else {
+                                                SponsorMark { name: sponsor.name.clone(), logo_url: sponsor.logo_url.clone() }
+                                            }
+                                            if let Some(description) = sponsor.description.clone() {
+                                                p { class: "public-event-muted", "{description}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !event.exhibitors.is_empty() {
+                    section {
+                        h2 { "Exhibitors" }
+                        ul { class: "public-event-exhibitors",
+                            for exhibitor in event.exhibitors.iter() {
+                                li { key: "{exhibitor.id}", class: "public-event-exhibitor",
+                                    div { class: "public-event-exhibitor-header",
+                                        if let Some(url) = exhibitor.website_url.clone() {
+                                            a { href: "{url}", target: "_blank", rel: "noopener", strong { "{exhibitor.company_name}" } }
+                                        } else {
+                                            strong { "{exhibitor.company_name}" }
+                                        }
+                                        if let Some(location) = exhibitor.location() {
+                                            span { class: "public-event-exhibitor-booth", "{location}" }
+                                        }
+                                    }
+                                    if let Some(description) = exhibitor.description.clone() {
+                                        p { class: "public-event-muted", "{description}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(query) = event.map_query() {
+                    section {
+                        h2 { "Location" }
+                        if let Some(address) = event.address.clone() {
+                            p { class: "public-event-muted", "{address}" }
+                        }
+                        iframe {
+                            class: "public-event-map",
+                            title: "Map",
+                            src: map_embed_url(query),
+                            loading: "lazy",
+                            referrerpolicy: "no-referrer-when-downgrade",
+                        }
+                    }
+                }
+
+                if !event.travel.is_empty() {
+                    TravelSections { travel: event.travel.clone() }
+                }
+            }
+
+            aside { class: "public-event-sidebar",
+                GuestRegistrationForm { event: event.clone() }
+            }
+        }
+    }
+}
+
+#[component]
+fn TravelSections(travel: TravelInfo) -> Element {
+    rsx! {
+        if !travel.hotels.is_empty() {
+            section {
+                h2 { "Where to stay" }
+                ul { class: "public-event-travel",
+                    for hotel in travel.hotels.iter() {
+                        li {
+                            if let Some(url) = hotel.website_url.clone() {
+                                a { href: "{url}", target: "_blank", rel: "noopener", strong { "{hotel.name}" } }
+                            } else {
+                                strong { "{hotel.name}" }
+                            }
+                            if let Some(minutes) = hotel.walking_minutes {
+                                span { class: "public-event-muted", " · {minutes} min walk" }
+                            }
+                            if let Some(address) = hotel.address.clone() {
+                                p { class: "public-event-muted", "{address}" }
+                            }
+                            if let Some(booking_code) = hotel.booking_code.clone() {
+                                p { "Booking code: " code { "{booking_code}" } }
+                            }
+                            if let Some(notes) = hotel.notes.clone() {
+                                p { "{notes}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if !travel.transport.is_empty() {
+            section {
+                h2 { "Getting there" }
+                ul { class: "public-event-travel",
+                    for option in travel.transport.iter() {
+                        li {
+                            span { class: "public-event-travel-mode", {option.mode.label()} }
+                            if let Some(url) = option.url.clone() {
+                                a { href: "{url}", target: "_blank", rel: "noopener", strong { "{option.title}" } }
+                            } else {
+                                strong { "{option.title}" }
+                            }
+                            if let Some(details) = option.details.clone() {
+                                p { "{details}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(parking) = travel.parking.clone() {
+            section {
+                h2 { "Parking" }
+                ParkingDetails { parking }
+            }
+        }
+    }
+}
+
+#[component]
+fn ParkingDetails(parking: ParkingInfo) -> Element {
+    rsx! {
+        if let Some(address) = parking.address.clone() {
+            p { strong { "{address}" } }
+        }
+        p { class: "public-event-muted",
+            if parking.free_of_charge { "Free of charge" } else { "Paid parking" }
+            if parking.ev_charging { " · EV charging available" }
+        }
+        if let Some(notes) = parking.notes.clone() {
+            p { "{notes}" }
+        }
+    }
+}
+
+/// Logo when the sponsor has one, otherwise its name
+#[component]
+fn SponsorMark(name: String, logo_url: Option<String>) -> Element {
+    rsx! {
+        if let Some(url) = logo_url {
+            img { src: "{url}", alt: "{name}", loading: "lazy" }
+        } else {
+            strong { "{name}" }
+        }
+    }
+}
+
+#[component]
+fn GuestRegistrationForm(event: PublicEvent) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut form = use_signal(GuestRegistration::default);
+    let mut submitting = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut outcome = use_signal(|| None::<GuestRegistrationOutcome>);
+
+    if !event.registration_open {
+        return rsx! {
+            div { class: "public-event-card",
+                h2 { "Registration" }
+                p { class: "public-event-muted", "Registration for this event is closed." }
+            }
+        };
+    }
+
+    if let Some(done) = outcome() {
+        return rsx! {
+            div { class: "public-event-card public-event-success",
+                match done {
+                    GuestRegistrationOutcome::Registered => rsx! {
+                        h2 { "You're registered!" }
+                        p { "See you there, {form().name.trim()}!" }
+                    },
+                    GuestRegistrationOutcome::Waitlisted { position } => rsx! {
+                        h2 { "You're on the waitlist" }
+                        if let Some(position) = position {
+                            p { "You are number {position} in line for a spot." }
+                        } else {
+                            p { "You'll be offered a spot if one opens up." }
+                        }
+                    },
+                }
+            }
+        };
+    }
+
+    let full = event.is_full();
+    if full && !event.waitlist_available {
+        return rsx! {
+            div { class: "public-event-card",
+                h2 { "Registration" }
+                p { class: "public-event-muted", "This event is fully booked." }
+            }
+        };
+    }
+
+    let submit = {
+        let event = event.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let svc = container.public_events.clone();
+            let event = event.clone();
+            spawn(async move {
+                submitting.set(true);
+                match svc.register(&event, &form()).await {
+                    Ok(result) => {
+                        error.set(None);
+                        outcome.set(Some(result));
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                submitting.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        form { class: "public-event-card public-event-form", onsubmit: submit,
+            h2 { "Register" }
+            match event.spots_left {
+                Some(0) => rsx! { p { class: "public-event-muted", "The event is full, but you can join the waitlist." } },
+                Some(spots) => rsx! { p { class: "public-event-muted", "{spots} spots left" } },
+                None => rsx! {},
+            }
+
+            label {
+                span { "Name" }
+                input {
+                    required: true,
+                    autocomplete: "name",
+                    value: "{form().name}",
+                    oninput: move |evt| form.write().name = evt.value(),
+                }
+            }
+            label {
+                span { "Email" }
+                input {
+                    r#type: "email",
+                    required: true,
+                    autocomplete: "email",
+                    value: "{form().email}",
+                    oninput: move |evt| form.write().email = evt.value(),
+                }
+            }
+            label {
+                span { "Phone (optional)" }
+                input {
+                    r#type: "tel",
+                    autocomplete: "tel",
+                    value: form().phone.unwrap_or_default(),
+                    oninput: move |evt| form.write().phone = Some(evt.value()),
+                }
+            }
+            label {
+                span { "Company (optional)" }
+                input {
+                    autocomplete: "organization",
+                    value: form().company.unwrap_or_default(),
+                    oninput: move |evt| form.write().company = Some(evt.value()),
+                }
+            }
+            if event.max_guests > 0 {
+                label {
+                    span { "Guests you're bringing" }
+                    input {
+                        r#type: "number",
+                        min: 0,
+                        max: event.max_guests as i64,
+                        value: "{form().guest_count}",
+                        oninput: move |evt| form.write().guest_count = evt.value().parse().unwrap_or(0),
+                    }
+                }
+            }
+            if event.collect_dietary_info {
+                label {
+                    span { "Dietary restrictions" }
+                    input {
+                        value: form().dietary_restrictions.unwrap_or_default(),
+                        oninput: move |evt| form.write().dietary_restrictions = Some(evt.value()),
+                    }
+                }
+            }
+            if event.collect_accessibility_info {
+                label {
+                    span { "Accessibility needs" }
+                    input {
+                        value: form().accessibility_needs.unwrap_or_default(),
+                        oninput: move |evt| form.write().accessibility_needs = Some(evt.value()),
+                    }
+                }
+            }
+
+            if let Some(message) = error() {
+                p { class: "public-event-error", "{message}" }
+            }
+
+            button { r#type: "submit", class: "public-event-button", disabled: submitting(),
+                if submitting() {
+                    "Registering..."
+                } else if full {
+                    "Join the waitlist"
+                } else {
+                    "Register"
+                }
+            }
+        }
+    }
+}
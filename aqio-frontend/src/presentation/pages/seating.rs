@@ -0,0 +1,440 @@
+// Seating planner for organizers of seated dinners: a grid of tables to drag
+// confirmed registrations onto (or pick a table from the list, without a
+// mouse), warnings about full tables, split companies and accessibility, and
+// the printable chart.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{PlannedTable, SeatedParty, SeatingPlan, SeatingTable, SeatingTableDraft};
+use crate::infrastructure::download::save_bytes;
+use crate::AppContainer;
+
+const SEATING_CSS: Asset = asset!("/assets/seating.css");
+
+#[component]
+pub fn SeatingPage(container: AppContainer, event_id: Uuid) -> Element {
+    // Bumped after each table change so the plan is fetched again
+    let mut refresh = use_signal(|| 0u32);
+    // Kept in a signal so an assignment can swap in the plan it returns
+    let mut plan = use_signal(|| None::<Result<SeatingPlan, String>>);
+    let mut error = use_signal(|| None::<String>);
+    let mut editing = use_signal(|| None::<SeatingTable>);
+    let mut printing = use_signal(|| false);
+    // The registration being dragged
+    let dragging = use_signal(|| None::<Uuid>);
+
+    let _loader = use_resource({
+        let svc = container.seating.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { plan.set(Some(svc.plan(event_id).await)) }
+        }
+    });
+
+    let assign = {
+        let svc = container.seating.clone();
+        move |(registration_id, table_id): (Uuid, Option<Uuid>)| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.assign(event_id, registration_id, table_id).await {
+                    Ok(updated) => {
+                        error.set(None);
+                        plan.set(Some(Ok(updated)));
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let print = {
+        let svc = container.seating.clone();
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                printing.set(true);
+                let saved = match svc.chart(event_id).await {
+                    Ok(pdf) => save_bytes(&format!("seating-{}.pdf", event_id), "application/pdf", &pdf),
+                    Err(e) => Err(e),
+                };
+                error.set(saved.err());
+                printing.set(false);
+            });
+        }
+    };
+
+    let on_changed = move |_| {
+        editing.set(None);
+        refresh += 1;
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: SEATING_CSS }
+
+        div { class: "seating-page",
+            div { class: "seating-header",
+                h1 { "Seating plan" }
+                button {
+                    r#type: "button",
+                    class: "seating-button",
+                    disabled: printing(),
+                    onclick: print,
+                    if printing() { "Preparing..." } else { "Print seating chart" }
+                }
+            }
+            p { class: "seating-muted",
+                "Drag a registration onto a table, or pick its table from the list. Guests sit with whoever registered them."
+            }
+            if let Some(message) = error() {
+                p { class: "seating-error", "{message}" }
+            }
+
+            match plan() {
+                Some(Ok(current)) => {
+                    let tables: Vec<(Uuid, String)> = current
+                        .tables
+                        .iter()
+                        .map(|planned| (planned.table.id, planned.table.name.clone()))
+                        .collect();
+                    let flagged: Vec<Uuid> = current.warnings.iter().flat_map(|w| w.table_ids.clone()).collect();
+                    let form_key = editing().map(|table| table.id.to_string()).unwrap_or_default();
+                    rsx! {
+                        div { class: "seating-totals",
+                            span { "{current.seated_people} of {current.seated_people + current.unassigned_people} people seated" }
+                            span { class: "seating-muted", "{current.capacity} seats at {current.tables.len()} tables" }
+                        }
+                        if !current.warnings.is_empty() {
+                            ul { class: "seating-warnings",
+                                for warning in current.warnings.iter() {
+                                    li { "{warning.message}" }
+                                }
+                            }
+                        }
+                        div { class: "seating-layout",
+                            PartyList {
+                                title: "Without a seat",
+                                empty: "Everyone confirmed has a seat.",
+                                parties: current.unassigned.clone(),
+                                tables: tables.clone(),
+                                dragging,
+                                on_assign: assign.clone(),
+                            }
+                            div { class: "seating-grid",
+                                if current.tables.is_empty() {
+                                    p { class: "seating-muted", "No tables yet. Add the first one below." }
+                                }
+                                for planned in current.tables.iter() {
+                                    TableCard {
+                                        key: "{planned.table.id}",
+                                        event_id,
+                                        planned: planned.clone(),
+                                        tables: tables.clone(),
+                                        flagged: flagged.contains(&planned.table.id),
+                                        dragging,
+                                        on_assign: assign.clone(),
+                                        on_edit: move |table| editing.set(Some(table)),
+                                        on_changed,
+                                    }
+                                }
+                            }
+                        }
+                        TableForm {
+                            key: "{form_key}",
+                            event_id,
+                            table: editing(),
+                            on_changed,
+                            on_cancel: move |_| editing.set(None),
+                        }
+                    }
+                }
+                Some(Err(e)) => rsx! { p { class: "seating-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+/// The unassigned registrations, which tables can be dropped back onto
+#[component]
+fn PartyList(
+    title: &'static str,
+    empty: &'static str,
+    parties: Vec<SeatedParty>,
+    tables: Vec<(Uuid, String)>,
+    dragging: Signal<Option<Uuid>>,
+    on_assign: EventHandler<(Uuid, Option<Uuid>)>,
+) -> Element {
+    let mut dragging = dragging;
+    rsx! {
+        section {
+            class: "seating-unassigned",
+            ondragover: move |evt: DragEvent| evt.prevent_default(),
+            ondrop: move |evt: DragEvent| {
+                evt.prevent_default();
+                if let Some(registration_id) = dragging.take() {
+                    on_assign.call((registration_id, None));
+                }
+            },
+            h2 { "{title}" }
+            if parties.is_empty() {
+                p { class: "seating-muted", "{empty}" }
+            }
+            ul { class: "seating-parties",
+                for party in parties {
+                    PartyItem {
+                        key: "{party.registration_id}",
+                        party: party.clone(),
+                        tables: tables.clone(),
+                        current: None,
+                        dragging,
+                        on_assign,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TableCard(
+    event_id: Uuid,
+    planned: PlannedTable,
+    tables: Vec<(Uuid, String)>,
+    /// Mentioned by one of the warnings
+    flagged: bool,
+    dragging: Signal<Option<Uuid>>,
+    on_assign: EventHandler<(Uuid, Option<Uuid>)>,
+    on_edit: EventHandler<SeatingTable>,
+    on_changed: EventHandler<()>,
+) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut dragging = dragging;
+    let mut over = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let table = planned.table.clone();
+    let count_class = if planned.seats_taken >= table.capacity { "seating-count seating-count-full" } else { "seating-count" };
+
+    let delete = {
+        let svc = container.seating.clone();
+        let id = table.id;
+        move |_| {
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.delete_table(event_id, id).await {
+                    Ok(()) => on_changed.call(()),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let mut class = String::from("seating-table");
+    if flagged {
+        class.push_str(" seating-table-flagged");
+    }
+    if over() {
+        class.push_str(" seating-table-over");
+    }
+
+    rsx! {
+        section {
+            class: "{class}",
+            ondragover: move |evt: DragEvent| {
+                evt.prevent_default();
+                over.set(true);
+            },
+            ondragleave: move |_| over.set(false),
+            ondrop: {
+                let id = table.id;
+                move |evt: DragEvent| {
+                    evt.prevent_default();
+                    over.set(false);
+                    if let Some(registration_id) = dragging.take() {
+                        on_assign.call((registration_id, Some(id)));
+                    }
+                }
+            },
+            div { class: "seating-table-header",
+                h3 { "{table.name}" }
+                span { class: count_class,
+                    "{planned.seats_taken}/{table.capacity}"
+                }
+            }
+            if table.accessible {
+                span { class: "seating-badge", "Accessible" }
+            }
+            if planned.parties.is_empty() {
+                p { class: "seating-muted", "Drop registrations here" }
+            }
+            ul { class: "seating-parties",
+                for party in planned.parties.iter() {
+                    PartyItem {
+                        key: "{party.registration_id}",
+                        party: party.clone(),
+                        tables: tables.clone(),
+                        current: Some(table.id),
+                        dragging,
+                        on_assign,
+                    }
+                }
+            }
+            if let Some(message) = error() {
+                p { class: "seating-error", "{message}" }
+            }
+            div { class: "seating-table-actions",
+                button {
+                    r#type: "button",
+                    onclick: {
+                        let table = table.clone();
+                        move |_| on_edit.call(table.clone())
+                    },
+                    "Edit"
+                }
+                button { r#type: "button", onclick: delete, "Remove" }
+            }
+        }
+    }
+}
+
+/// A registration with its guests; dragged to move it, or moved with the list
+#[component]
+fn PartyItem(
+    party: SeatedParty,
+    tables: Vec<(Uuid, String)>,
+    current: Option<Uuid>,
+    dragging: Signal<Option<Uuid>>,
+    on_assign: EventHandler<(Uuid, Option<Uuid>)>,
+) -> Element {
+    let mut dragging = dragging;
+    let id = party.registration_id;
+    let guests = party.seats.saturating_sub(1);
+    let current_value = current.map(|table_id| table_id.to_string()).unwrap_or_default();
+
+    rsx! {
+        li {
+            class: "seating-party",
+            draggable: "true",
+            ondragstart: move |_| dragging.set(Some(id)),
+            ondragend: move |_| dragging.set(None),
+            div { class: "seating-party-body",
+                strong { "{party.name}" }
+                if guests > 0 {
+                    span { class: "seating-muted", " +{guests}" }
+                }
+                if let Some(company) = party.company.clone() {
+                    span { class: "seating-muted", "{company}" }
+                }
+                if !party.accessibility_needs.is_empty() {
+                    span { class: "seating-badge", {party.accessibility_needs.join(", ")} }
+                }
+            }
+            select {
+                aria_label: "Table for {party.name}",
+                onchange: move |evt| {
+                    let table_id = Uuid::parse_str(&evt.value()).ok();
+                    if table_id != current {
+                        on_assign.call((id, table_id));
+                    }
+                },
+                option { value: "", selected: current.is_none(), "No table" }
+                for (table_id, name) in tables {
+                    option {
+                        value: "{table_id}",
+                        selected: current_value == table_id.to_string(),
+                        "{name}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Adds a table, or edits `table` when given
+#[component]
+fn TableForm(
+    event_id: Uuid,
+    table: Option<SeatingTable>,
+    on_changed: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let container = use_context::<AppContainer>();
+    let editing = table.as_ref().map(|table| table.id);
+    let mut name = use_signal(|| table.as_ref().map(|t| t.name.clone()).unwrap_or_default());
+    let mut capacity = use_signal(|| table.as_ref().map(|t| t.capacity.to_string()).unwrap_or_else(|| "8".to_string()));
+    let mut accessible = use_signal(|| table.as_ref().is_some_and(|t| t.accessible));
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        let svc = container.seating.clone();
+        let Ok(seats) = capacity().trim().parse::<u32>() else {
+            error.set(Some("Give the number of seats as a whole number".to_string()));
+            return;
+        };
+        let draft = SeatingTableDraft {
+            name: name(),
+            capacity: seats,
+            accessible: accessible(),
+        };
+        spawn(async move {
+            saving.set(true);
+            match svc.save_table(event_id, editing, draft).await {
+                Ok(_) => {
+                    error.set(None);
+                    name.set(String::new());
+                    accessible.set(false);
+                    on_changed.call(());
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            saving.set(false);
+        });
+    };
+
+    rsx! {
+        form { class: "seating-form", onsubmit: submit,
+            h3 { if editing.is_some() { "Edit table" } else { "Add table" } }
+            div { class: "seating-form-fields",
+                input {
+                    placeholder: "Name, e.g. Table 1",
+                    aria_label: "Name",
+                    value: "{name}",
+                    oninput: move |evt| name.set(evt.value()),
+                }
+                input {
+                    r#type: "number",
+                    min: "1",
+                    max: "100",
+                    aria_label: "Seats",
+                    value: "{capacity}",
+                    oninput: move |evt| capacity.set(evt.value()),
+                }
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: accessible(),
+                        onchange: move |evt| accessible.set(evt.checked()),
+                    }
+                    " Step-free, with room for a wheelchair"
+                }
+            }
+            if let Some(message) = error() {
+                p { class: "seating-error", "{message}" }
+            }
+            div { class: "seating-form-actions",
+                button {
+                    r#type: "submit",
+                    class: "seating-button",
+                    disabled: saving() || name().trim().is_empty(),
+                    if saving() { "Saving..." } else if editing.is_some() { "Save table" } else { "Add table" }
+                }
+                if editing.is_some() {
+                    button { r#type: "button", onclick: move |_| on_cancel.call(()), "Cancel" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,258 @@
+// The signed-in user's settings: their name, time zone and language, how
+// notifications reach them, and how the app looks. Everything is saved at once
+// and applied across the app as soon as the API has stored it.
+
+use dioxus::prelude::*;
+
+use crate::application::calendar::CalendarView;
+use crate::application::ports::{DigestFrequency, Locale, Theme, UserSettings};
+use crate::lib::components::{field_message_id, FormField, Input, Select, SelectOption};
+use crate::AppContainer;
+
+const SETTINGS_CSS: Asset = asset!("/assets/settings.css");
+
+// Offered in the time zone picker, next to the user's own when it isn't listed
+const TIME_ZONES: [&str; 12] = [
+    "Europe/Oslo",
+    "Europe/Stockholm",
+    "Europe/Copenhagen",
+    "Europe/Helsinki",
+    "Atlantic/Reykjavik",
+    "Europe/London",
+    "Europe/Berlin",
+    "UTC",
+    "America/New_York",
+    "America/Vancouver",
+    "Asia/Singapore",
+    "Australia/Hobart",
+];
+
+fn time_zone_options(current: Option<&str>) -> Vec<SelectOption> {
+    let mut zones: Vec<&str> = TIME_ZONES.to_vec();
+    if let Some(current) = current.filter(|current| !zones.contains(current)) {
+        zones.insert(0, current);
+    }
+    zones.into_iter().map(|zone| SelectOption::new(zone, zone.replace('_', " "))).collect()
+}
+
+fn locale_label(locale: Locale) -> &'static str {
+    match locale {
+        Locale::En => "English",
+        Locale::Nb => "Norsk bokmål",
+    }
+}
+
+fn theme_value(theme: Theme) -> &'static str {
+    match theme {
+        Theme::Light => "light",
+        Theme::Dark => "dark",
+    }
+}
+
+fn parse_theme(value: &str) -> Option<Theme> {
+    [Theme::Light, Theme::Dark].into_iter().find(|theme| theme_value(*theme) == value)
+}
+
+fn parse_calendar_view(value: &str) -> Option<CalendarView> {
+    CalendarView::ALL.into_iter().find(|view| view.label() == value)
+}
+
+// Digests are the only channel besides the app itself, so the frequency is the choice
+fn digest_value(frequency: DigestFrequency) -> &'static str {
+    match frequency {
+        DigestFrequency::Immediate => "immediate",
+        DigestFrequency::Daily => "daily",
+        DigestFrequency::Weekly => "weekly",
+    }
+}
+
+fn digest_label(frequency: DigestFrequency) -> &'static str {
+    match frequency {
+        DigestFrequency::Immediate => "In the app only",
+        DigestFrequency::Daily => "In the app and a daily email digest",
+        DigestFrequency::Weekly => "In the app and a weekly email digest",
+    }
+}
+
+fn parse_digest(value: &str) -> Option<DigestFrequency> {
+    DigestFrequency::ALL.into_iter().find(|frequency| digest_value(*frequency) == value)
+}
+
+#[component]
+pub fn SettingsPage(container: AppContainer) -> Element {
+    let settings = use_resource({
+        let svc = container.settings.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.load().await }
+        }
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: SETTINGS_CSS }
+
+        div { class: "settings-page",
+            h1 { "Settings" }
+            match &*settings.read() {
+                Some(Ok(settings)) => rsx! {
+                    SettingsForm { key: "{settings.id}", container: container.clone(), settings: settings.clone() }
+                },
+                Some(Err(e)) => rsx! { p { class: "settings-error", "Sign in to change your settings ({e})" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+#[component]
+fn SettingsForm(container: AppContainer, settings: UserSettings) -> Element {
+    let mut draft = use_signal(|| settings.clone());
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut saved = use_signal(|| false);
+    let mut user_settings = use_context::<Signal<Option<UserSettings>>>();
+
+    let mut edit = move |change: &dyn Fn(&mut UserSettings)| {
+        change(&mut draft.write());
+        saved.set(false);
+    };
+
+    let submit = {
+        let svc = container.settings.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let svc = svc.clone();
+            spawn(async move {
+                saving.set(true);
+                match svc.save(draft()).await {
+                    Ok(stored) => {
+                        draft.set(stored.clone());
+                        user_settings.set(Some(stored));
+                        error.set(None);
+                        saved.set(true);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    let current = draft();
+    let name_error = current.name.trim().is_empty().then(|| "Enter your name".to_string());
+    let digest_hint = match current.next_digest_at {
+        Some(at) if current.digest_frequency == settings.digest_frequency => {
+            format!("The next digest goes out after {}", at.with_timezone(&chrono::Local).format("%A %-d %B, %H:%M"))
+        }
+        _ => "Urgent notifications never wait for a digest".to_string(),
+    };
+
+    rsx! {
+        form { class: "settings-form", onsubmit: submit,
+            section { class: "settings-section",
+                h2 { "Profile" }
+                FormField { label: "Name".to_string(), id: "settings-name".to_string(), required: true, error: name_error.clone(),
+                    Input {
+                        id: "settings-name".to_string(),
+                        value: current.name.clone(),
+                        required: true,
+                        invalid: name_error.is_some(),
+                        describedby: field_message_id("settings-name"),
+                        on_input: move |value: String| edit(&|settings| settings.name = value.clone()),
+                    }
+                }
+                FormField { label: "Email".to_string(), id: "settings-email".to_string(), hint: "Managed by your sign-in provider".to_string(),
+                    Input {
+                        id: "settings-email".to_string(),
+                        input_type: "email".to_string(),
+                        value: current.email.clone(),
+                        disabled: true,
+                        describedby: field_message_id("settings-email"),
+                        on_input: move |_| {},
+                    }
+                }
+            }
+
+            section { class: "settings-section",
+                h2 { "Region" }
+                FormField { label: "Time zone".to_string(), id: "settings-timezone".to_string(), hint: "Used for times in emails and calendar files".to_string(),
+                    Select {
+                        id: "settings-timezone".to_string(),
+                        value: current.timezone.clone().unwrap_or_default(),
+                        options: time_zone_options(current.timezone.as_deref()),
+                        placeholder: "This browser's time zone".to_string(),
+                        describedby: field_message_id("settings-timezone"),
+                        on_change: move |value: String| {
+                            edit(&|settings| settings.timezone = Some(value.clone()).filter(|zone| !zone.is_empty()))
+                        },
+                    }
+                }
+                FormField { label: "Language".to_string(), id: "settings-locale".to_string(), hint: "Used for emails and calendar files".to_string(),
+                    Select {
+                        id: "settings-locale".to_string(),
+                        value: current.locale.map(|locale| locale.tag()).unwrap_or_default().to_string(),
+                        options: Locale::ALL.into_iter().map(|locale| SelectOption::new(locale.tag(), locale_label(locale))).collect::<Vec<_>>(),
+                        placeholder: "This browser's language".to_string(),
+                        describedby: field_message_id("settings-locale"),
+                        on_change: move |value: String| edit(&|settings| settings.locale = Locale::from_tag(&value)),
+                    }
+                }
+            }
+
+            section { class: "settings-section",
+                h2 { "Notifications" }
+                FormField { label: "Notify me".to_string(), id: "settings-digest".to_string(), hint: digest_hint,
+                    Select {
+                        id: "settings-digest".to_string(),
+                        value: digest_value(current.digest_frequency).to_string(),
+                        options: DigestFrequency::ALL.into_iter().map(|frequency| SelectOption::new(digest_value(frequency), digest_label(frequency))).collect::<Vec<_>>(),
+                        describedby: field_message_id("settings-digest"),
+                        on_change: move |value: String| {
+                            if let Some(frequency) = parse_digest(&value) {
+                                edit(&|settings| settings.digest_frequency = frequency);
+                            }
+                        },
+                    }
+                }
+            }
+
+            section { class: "settings-section",
+                h2 { "Appearance" }
+                FormField { label: "Theme".to_string(), id: "settings-theme".to_string(),
+                    Select {
+                        id: "settings-theme".to_string(),
+                        value: current.theme.map(theme_value).unwrap_or_default().to_string(),
+                        options: vec![SelectOption::new("light", "Light"), SelectOption::new("dark", "Dark")],
+                        placeholder: "Match the device".to_string(),
+                        on_change: move |value: String| edit(&|settings| settings.theme = parse_theme(&value)),
+                    }
+                }
+                FormField { label: "Calendar opens on".to_string(), id: "settings-calendar-view".to_string(),
+                    Select {
+                        id: "settings-calendar-view".to_string(),
+                        value: current.calendar_view.unwrap_or(CalendarView::Month).label().to_string(),
+                        options: CalendarView::ALL.into_iter().map(|view| SelectOption::new(view.label(), view.label())).collect::<Vec<_>>(),
+                        on_change: move |value: String| {
+                            edit(&|settings| settings.calendar_view = parse_calendar_view(&value))
+                        },
+                    }
+                }
+            }
+
+            if let Some(message) = error() {
+                p { class: "settings-error", role: "alert", "{message}" }
+            }
+            div { class: "settings-actions",
+                button {
+                    r#type: "submit",
+                    class: "settings-save",
+                    disabled: saving() || name_error.is_some(),
+                    if saving() { "Saving..." } else { "Save settings" }
+                }
+                if saved() {
+                    span { class: "settings-saved", role: "status", "Settings saved" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+// Organizer view of an event's sponsors: add, edit and remove them, and
+// arrange the order they're shown in within each tier.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{Sponsor, SponsorDraft, SponsorTier};
+use crate::application::services::sponsors_by_tier;
+use crate::AppContainer;
+
+const SPONSORS_CSS: Asset = asset!("/assets/sponsors.css");
+
+#[component]
+pub fn SponsorsPage(container: AppContainer, event_id: Uuid) -> Element {
+    // Bumped after each change so the sponsors are fetched again
+    let mut refresh = use_signal(|| 0u32);
+    let mut notice = use_signal(|| None::<String>);
+    let mut editing = use_signal(|| None::<Sponsor>);
+
+    let sponsors = use_resource({
+        let svc = container.sponsors.clone();
+        move || {
+            let svc = svc.clone();
+            let _ = refresh();
+            async move { svc.list(event_id).await }
+        }
+    });
+
+    let on_changed = move |message: String| {
+        notice.set(Some(message));
+        editing.set(None);
+        refresh += 1;
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: SPONSORS_CSS }
+
+        div { class: "sponsors-page",
+            h1 { "Sponsors" }
+            p { class: "sponsors-muted",
+                "Sponsors are shown on the public event page and the printed program, highest tier first."
+            }
+
+            if let Some(message) = notice() {
+                p { class: "sponsors-notice", "{message}" }
+            }
+            match &*sponsors.read() {
+                Some(Ok(list)) => {
+                    let form_key = editing().map(|sponsor| sponsor.id.to_string()).unwrap_or_default();
+                    rsx! {
+                        if list.is_empty() {
+                            p { class: "sponsors-muted", "No sponsors yet." }
+                        }
+                        for (tier, in_tier) in sponsors_by_tier(list) {
+                            section { key: "{tier.as_str()}", class: "sponsors-tier",
+                                h2 { {tier.label()} }
+                                ul { class: "sponsors-list",
+                                    for (index, sponsor) in in_tier.iter().enumerate() {
+                                        SponsorRow {
+                                            key: "{sponsor.id}",
+                                            event_id,
+                                            sponsor: sponsor.clone(),
+                                            all: list.clone(),
+                                            first: index == 0,
+                                            last: index + 1 == in_tier.len(),
+                                            on_edit: move |sponsor| editing.set(Some(sponsor)),
+                                            on_changed,
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        SponsorForm {
+                            key: "{form_key}",
+                            event_id,
+                            sponsor: editing(),
+                            on_changed,
+                            on_cancel: move |_| editing.set(None),
+                        }
+                    }
+                }
+                Some(Err(e)) => rsx! { p { class: "sponsors-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+#[component]
+fn SponsorRow(
+    event_id: Uuid,
+    sponsor: Sponsor,
+    /// Every sponsor on the event, so moves can be worked out within the tier
+    all: Vec<Sponsor>,
+    first: bool,
+    last: bool,
+    on_edit: EventHandler<Sponsor>,
+    on_changed: EventHandler<String>,
+) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut error = use_signal(|| None::<String>);
+    let id = sponsor.id;
+
+    let shift = {
+        let svc = container.sponsors.clone();
+        let name = sponsor.name.clone();
+        move |earlier: bool| {
+            let svc = svc.clone();
+            let all = all.clone();
+            let name = name.clone();
+            spawn(async move {
+                match svc.shift(event_id, &all, id, earlier).await {
+                    Some(Ok(_)) => on_changed.call(format!("Moved '{}'", name)),
+                    Some(Err(e)) => error.set(Some(e)),
+                    None => {}
+                }
+            });
+        }
+    };
+    let move_up = {
+        let shift = shift.clone();
+        move |_| shift(true)
+    };
+    let move_down = move |_| shift(false);
+
+    let delete = {
+        let svc = container.sponsors.clone();
+        let name = sponsor.name.clone();
+        move |_| {
+            let svc = svc.clone();
+            let name = name.clone();
+            spawn(async move {
+                match svc.delete(event_id, id).await {
+                    Ok(()) => on_changed.call(format!("Removed '{}'", name)),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        li { class: "sponsors-item",
+            SponsorLogo { sponsor: sponsor.clone() }
+            div { class: "sponsors-item-body",
+                strong { "{sponsor.name}" }
+                if let Some(url) = sponsor.website_url.clone() {
+                    a { href: "{url}", target: "_blank", rel: "noopener", "{url}" }
+                }
+                if let Some(description) = sponsor.description.clone() {
+                    span { class: "sponsors-muted", "{description}" }
+                }
+                if let Some(message) = error() {
+                    span { class: "sponsors-error", "{message}" }
+                }
+            }
+            div { class: "sponsors-item-actions",
+                button {
+                    r#type: "button",
+                    aria_label: "Move {sponsor.name} up",
+                    disabled: first,
+                    onclick: move_up,
+                    "↑"
+                }
+                button {
+                    r#type: "button",
+                    aria_label: "Move {sponsor.name} down",
+                    disabled: last,
+                    onclick: move_down,
+                    "↓"
+                }
+                button {
+                    r#type: "button",
+                    onclick: {
+                        let sponsor = sponsor.clone();
+                        move |_| on_edit.call(sponsor.clone())
+                    },
+                    "Edit"
+                }
+                button { r#type: "button", onclick: delete, "Delete" }
+            }
+        }
+    }
+}
+
+/// The sponsor's logo, or its initial when it has none
+#[component]
+fn SponsorLogo(sponsor: Sponsor) -> Element {
+    let initial = sponsor.name.chars().next().map(|c| c.to_uppercase().to_string()).unwrap_or_default();
+    rsx! {
+        if let Some(url) = sponsor.logo_url.clone() {
+            img { class: "sponsors-logo", src: "{url}", alt: "{sponsor.name}" }
+        } else {
+            span { class: "sponsors-logo sponsors-logo-placeholder", aria_hidden: "true", "{initial}" }
+        }
+    }
+}
+
+/// Adds a sponsor, or edits `sponsor` when given
+#[component]
+fn SponsorForm(
+    event_id: Uuid,
+    sponsor: Option<Sponsor>,
+    on_changed: EventHandler<String>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let container = use_context::<AppContainer>();
+    let editing = sponsor.as_ref().map(|sponsor| sponsor.id);
+    let mut name = use_signal(|| sponsor.as_ref().map(|s| s.name.clone()).unwrap_or_default());
+    let mut tier = use_signal(|| sponsor.as_ref().map(|s| s.tier).unwrap_or(SponsorTier::Gold));
+    let mut logo_url = use_signal(|| sponsor.as_ref().and_then(|s| s.logo_url.clone()).unwrap_or_default());
+    let mut website_url = use_signal(|| sponsor.as_ref().and_then(|s| s.website_url.clone()).unwrap_or_default());
+    let mut description = use_signal(|| sponsor.as_ref().and_then(|s| s.description.clone()).unwrap_or_default());
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        let svc = container.sponsors.clone();
+        let draft = SponsorDraft {
+            name: name(),
+            tier: tier(),
+            logo_url: Some(logo_url()),
+            website_url: Some(website_url()),
+            description: Some(description()),
+        };
+        spawn(async move {
+            saving.set(true);
+            match svc.save(event_id, editing, draft).await {
+                Ok(sponsor) => {
+                    error.set(None);
+                    name.set(String::new());
+                    logo_url.set(String::new());
+                    website_url.set(String::new());
+                    description.set(String::new());
+                    on_changed.call(format!("Saved sponsor '{}'", sponsor.name));
+                }
+                Err(e) => error.set(Some(e)),
+            }
+            saving.set(false);
+        });
+    };
+
+    rsx! {
+        form { class: "sponsors-form", onsubmit: submit,
+            h3 { if editing.is_some() { "Edit sponsor" } else { "Add sponsor" } }
+            div { class: "sponsors-form-fields",
+                input {
+                    placeholder: "Name",
+                    aria_label: "Name",
+                    value: "{name}",
+                    oninput: move |evt| name.set(evt.value()),
+                }
+                select {
+                    aria_label: "Tier",
+                    onchange: move |evt| {
+                        if let Some(selected) = SponsorTier::parse(&evt.value()) {
+                            tier.set(selected);
+                        }
+                    },
+                    for option_tier in SponsorTier::ALL {
+                        option {
+                            value: option_tier.as_str(),
+                            selected: tier() == option_tier,
+                            {option_tier.label()}
+                        }
+                    }
+                }
+                input {
+                    r#type: "url",
+                    placeholder: "Logo URL",
+                    aria_label: "Logo URL",
+                    value: "{logo_url}",
+                    oninput: move |evt| logo_url.set(evt.value()),
+                }
+                input {
+                    r#type: "url",
+                    placeholder: "Website",
+                    aria_label: "Website",
+                    value: "{website_url}",
+                    oninput: move |evt| website_url.set(evt.value()),
+                }
+                input {
+                    placeholder: "Short description",
+                    aria_label: "Description",
+                    value: "{description}",
+                    oninput: move |evt| description.set(evt.value()),
+                }
+            }
+            if let Some(message) = error() {
+                p { class: "sponsors-error", "{message}" }
+            }
+            div { class: "sponsors-form-actions",
+                button {
+                    r#type: "submit",
+                    class: "sponsors-button",
+                    disabled: saving() || name().trim().is_empty(),
+                    if saving() { "Saving..." } else if editing.is_some() { "Save sponsor" } else { "Add sponsor" }
+                }
+                if editing.is_some() {
+                    button { r#type: "button", onclick: move |_| on_cancel.call(()), "Cancel" }
+                }
+            }
+        }
+    }
+}
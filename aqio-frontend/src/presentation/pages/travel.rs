@@ -0,0 +1,285 @@
+// Organizer view of an event's accommodation and travel information: recommended
+// hotels, ways of getting there and parking, shown on the public page and in the
+// calendar file.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::application::ports::{Hotel, ParkingInfo, TransportMode, TransportOption, TravelInfo};
+use crate::AppContainer;
+
+const TRAVEL_CSS: Asset = asset!("/assets/travel.css");
+
+#[component]
+pub fn TravelPage(container: AppContainer, event_id: Uuid) -> Element {
+    let travel = use_resource({
+        let svc = container.travel.clone();
+        move || {
+            let svc = svc.clone();
+            async move { svc.get(event_id).await }
+        }
+    });
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: TRAVEL_CSS }
+
+        div { class: "travel-page",
+            h1 { "Travel & accommodation" }
+            p { class: "travel-muted",
+                "Shown on the public event page and included in the calendar file attendees download."
+            }
+
+            match &*travel.read() {
+                Some(Ok(info)) => rsx! { TravelForm { event_id, travel: info.clone() } },
+                Some(Err(e)) => rsx! { p { class: "travel-error", "Error: {e}" } },
+                None => rsx! { p { "Loading..." } },
+            }
+        }
+    }
+}
+
+fn non_empty(value: String) -> Option<String> {
+    Some(value).filter(|value| !value.is_empty())
+}
+
+#[component]
+fn TravelForm(event_id: Uuid, travel: TravelInfo) -> Element {
+    let container = use_context::<AppContainer>();
+    let mut draft = use_signal(|| travel.clone());
+    let mut saving = use_signal(|| false);
+    let mut notice = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        let svc = container.travel.clone();
+        let travel = draft();
+        spawn(async move {
+            saving.set(true);
+            match svc.save(event_id, travel).await {
+                Ok(saved) => {
+                    error.set(None);
+                    notice.set(Some(if saved.is_empty() {
+                        "Travel information removed".to_string()
+                    } else {
+                        "Travel information saved".to_string()
+                    }));
+                    draft.set(saved);
+                }
+                Err(e) => {
+                    notice.set(None);
+                    error.set(Some(e));
+                }
+            }
+            saving.set(false);
+        });
+    };
+
+    let hotels = draft.read().hotels.clone();
+    let transport = draft.read().transport.clone();
+    let parking = draft.read().parking.clone();
+
+    rsx! {
+        form { class: "travel-form", onsubmit: submit,
+            section { class: "travel-section",
+                h2 { "Where to stay" }
+                for (index, hotel) in hotels.into_iter().enumerate() {
+                    fieldset { key: "hotel-{index}", class: "travel-entry",
+                        input {
+                            placeholder: "Hotel name",
+                            aria_label: "Hotel name",
+                            value: "{hotel.name}",
+                            oninput: move |evt| draft.write().hotels[index].name = evt.value(),
+                        }
+                        input {
+                            placeholder: "Address",
+                            aria_label: "Address",
+                            value: hotel.address.clone().unwrap_or_default(),
+                            oninput: move |evt| draft.write().hotels[index].address = non_empty(evt.value()),
+                        }
+                        input {
+                            r#type: "url",
+                            placeholder: "Website",
+                            aria_label: "Website",
+                            value: hotel.website_url.clone().unwrap_or_default(),
+                            oninput: move |evt| draft.write().hotels[index].website_url = non_empty(evt.value()),
+                        }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            placeholder: "Minutes on foot",
+                            aria_label: "Minutes on foot",
+                            value: hotel.walking_minutes.map(|m| m.to_string()).unwrap_or_default(),
+                            oninput: move |evt| draft.write().hotels[index].walking_minutes = evt.value().parse().ok(),
+                        }
+                        input {
+                            placeholder: "Booking code",
+                            aria_label: "Booking code",
+                            value: hotel.booking_code.clone().unwrap_or_default(),
+                            oninput: move |evt| draft.write().hotels[index].booking_code = non_empty(evt.value()),
+                        }
+                        input {
+                            class: "travel-wide",
+                            placeholder: "Notes, e.g. book before 1 March",
+                            aria_label: "Notes",
+                            value: hotel.notes.clone().unwrap_or_default(),
+                            oninput: move |evt| draft.write().hotels[index].notes = non_empty(evt.value()),
+                        }
+                        button {
+                            r#type: "button",
+                            onclick: move |_| {
+                                draft.write().hotels.remove(index);
+                            },
+                            "Remove"
+                        }
+                    }
+                }
+                button {
+                    r#type: "button",
+                    onclick: move |_| draft.write().hotels.push(Hotel::default()),
+                    "Add hotel"
+                }
+            }
+
+            section { class: "travel-section",
+                h2 { "Getting there" }
+                for (index, entry) in transport.into_iter().enumerate() {
+                    fieldset { key: "transport-{index}", class: "travel-entry",
+                        select {
+                            aria_label: "Mode",
+                            onchange: move |evt| {
+                                if let Some(mode) = TransportMode::parse(&evt.value()) {
+                                    draft.write().transport[index].mode = mode;
+                                }
+                            },
+                            for mode in TransportMode::ALL {
+                                option {
+                                    value: mode.as_str(),
+                                    selected: entry.mode == mode,
+                                    {mode.label()}
+                                }
+                            }
+                        }
+                        input {
+                            placeholder: "Title, e.g. Airport bus from Flesland",
+                            aria_label: "Title",
+                            value: "{entry.title}",
+                            oninput: move |evt| draft.write().transport[index].title = evt.value(),
+                        }
+                        input {
+                            r#type: "url",
+                            placeholder: "Link to timetable or tickets",
+                            aria_label: "Link",
+                            value: entry.url.clone().unwrap_or_default(),
+                            oninput: move |evt| draft.write().transport[index].url = non_empty(evt.value()),
+                        }
+                        input {
+                            class: "travel-wide",
+                            placeholder: "Details",
+                            aria_label: "Details",
+                            value: entry.details.clone().unwrap_or_default(),
+                            oninput: move |evt| draft.write().transport[index].details = non_empty(evt.value()),
+                        }
+                        button {
+                            r#type: "button",
+                            onclick: move |_| {
+                                draft.write().transport.remove(index);
+                            },
+                            "Remove"
+                        }
+                    }
+                }
+                button {
+                    r#type: "button",
+                    onclick: move |_| {
+                        draft.write().transport.push(TransportOption {
+                            mode: TransportMode::Bus,
+                            title: String::new(),
+                            details: None,
+                            url: None,
+                        })
+                    },
+                    "Add way of getting there"
+                }
+            }
+
+            section { class: "travel-section",
+                h2 { "Parking" }
+                label { class: "travel-check",
+                    input {
+                        r#type: "checkbox",
+                        checked: parking.is_some(),
+                        onchange: move |evt| {
+                            draft.write().parking = evt.checked().then(ParkingInfo::default);
+                        },
+                    }
+                    "Show parking information"
+                }
+                if let Some(parking) = parking {
+                    fieldset { class: "travel-entry",
+                        input {
+                            placeholder: "Address or garage",
+                            aria_label: "Parking address",
+                            value: parking.address.clone().unwrap_or_default(),
+                            oninput: move |evt| {
+                                if let Some(parking) = draft.write().parking.as_mut() {
+                                    parking.address = non_empty(evt.value());
+                                }
+                            },
+                        }
+                        label { class: "travel-check",
+                            input {
+                                r#type: "checkbox",
+                                checked: parking.free_of_charge,
+                                onchange: move |evt| {
+                                    if let Some(parking) = draft.write().parking.as_mut() {
+                                        parking.free_of_charge = evt.checked();
+                                    }
+                                },
+                            }
+                            "Free of charge"
+                        }
+                        label { class: "travel-check",
+                            input {
+                                r#type: "checkbox",
+                                checked: parking.ev_charging,
+                                onchange: move |evt| {
+                                    if let Some(parking) = draft.write().parking.as_mut() {
+                                        parking.ev_charging = evt.checked();
+                                    }
+                                },
+                            }
+                            "EV charging"
+                        }
+                        input {
+                            class: "travel-wide",
+                            placeholder: "Notes",
+                            aria_label: "Parking notes",
+                            value: parking.notes.clone().unwrap_or_default(),
+                            oninput: move |evt| {
+                                if let Some(parking) = draft.write().parking.as_mut() {
+                                    parking.notes = non_empty(evt.value());
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+
+            if let Some(message) = notice() {
+                p { class: "travel-notice", "{message}" }
+            }
+            if let Some(message) = error() {
+                p { class: "travel-error", "{message}" }
+            }
+            div { class: "travel-actions",
+                button {
+                    r#type: "submit",
+                    class: "travel-button",
+                    disabled: saving(),
+                    if saving() { "Saving..." } else { "Save travel information" }
+                }
+            }
+        }
+    }
+}
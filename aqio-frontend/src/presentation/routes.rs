@@ -1,25 +1,152 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use dioxus::prelude::*;
+use uuid::Uuid;
 
+use crate::application::ports::ClientConfig;
 use crate::AppContainer;
 
+use super::command_palette::CommandPalette;
+use super::error_boundary::RouteErrorBoundary;
+use super::pages::admin::{
+    audit_log::AdminAuditLogPage, categories::AdminCategoriesPage, companies::AdminCompaniesPage,
+    organization_invitations::AdminOrganizationInvitationsPage, performance::AdminPerformancePage, users::AdminUsersPage,
+    AdminGuard,
+};
+use super::pages::budget::BudgetPage;
+use super::pages::certificate::CertificateVerificationPage;
+use super::pages::check_in::CheckInPage;
+use super::pages::companies::{CompaniesPage, JoinOrganizationPage};
+use super::pages::event_detail::EventDetailPage;
 use super::pages::events::EventsPage;
+use super::pages::events_agenda::EventsAgendaPage;
+use super::pages::events_calendar::EventsCalendarPage;
+use super::pages::events_map::EventsMapPage;
+use super::pages::invitations::InvitationsPage;
+use super::pages::messages::MessagesPage;
+use super::pages::new_event::NewEventPage;
+use super::pages::past_events::PastEventsPage;
+use super::pages::print::{PrintAttendeesPage, PrintProgramPage};
+use super::pages::public_event::{PublicEventPage, ShortLinkRedirect};
+use super::pages::sponsors::SponsorsPage;
+use super::pages::catering::CateringPage;
+use super::pages::seating::SeatingPage;
+use super::pages::settings::SettingsPage;
+use super::pages::travel::TravelPage;
 
 #[derive(Clone, Routable, PartialEq)]
 pub enum Route {
-    #[route("/")]
-    Home {},
-    #[route("/events")]
-    Events {},
+    // Every page renders inside the error boundary, with the command palette on Ctrl+K
+    #[layout(AppLayout)]
+        #[route("/")]
+        Home {},
+        #[route("/events")]
+        Events {},
+        #[route("/events/new")]
+        NewEvent {},
+        #[route("/events/calendar")]
+        EventsCalendar {},
+        #[route("/events/agenda")]
+        EventsAgenda {},
+        #[route("/events/map")]
+        EventsMap {},
+        #[route("/events/past")]
+        PastEvents {},
+        #[route("/events/:event_id")]
+        EventDetail { event_id: Uuid },
+        #[route("/events/:event_id/invitations")]
+        EventInvitations { event_id: Uuid },
+        #[route("/events/:event_id/check-in")]
+        EventCheckIn { event_id: Uuid },
+        #[route("/events/:event_id/budget")]
+        EventBudget { event_id: Uuid },
+        #[route("/events/:event_id/sponsors")]
+        EventSponsors { event_id: Uuid },
+        #[route("/events/:event_id/travel")]
+        EventTravel { event_id: Uuid },
+        #[route("/events/:event_id/catering")]
+        EventCatering { event_id: Uuid },
+        #[route("/events/:event_id/seating")]
+        EventSeating { event_id: Uuid },
+        #[route("/events/:event_id/print/attendees")]
+        PrintAttendees { event_id: Uuid },
+        #[route("/events/:event_id/print/program")]
+        PrintProgram { event_id: Uuid },
+        // Shareable page for visitors who aren't signed in
+        #[route("/e/:slug")]
+        PublicEvent { slug: String },
+        // Target of the QR code on certificates of attendance
+        #[route("/certificates/:registration_id")]
+        CertificateVerification { registration_id: Uuid },
+        #[route("/companies")]
+        Companies {},
+        #[route("/messages")]
+        Messages {},
+        #[route("/messages/:thread_id")]
+        Conversation { thread_id: Uuid },
+        #[route("/settings")]
+        Settings {},
+        // Link in organization invitation emails
+        #[route("/join/:token")]
+        JoinOrganization { token: String },
+        #[nest("/admin")]
+            #[redirect("/", || Route::AdminUsers {})]
+            #[layout(AdminLayout)]
+                #[route("/users")]
+                AdminUsers {},
+                #[route("/categories")]
+                AdminCategories {},
+                #[route("/companies")]
+                AdminCompanies {},
+                #[route("/organization-invites")]
+                AdminOrganizationInvitations {},
+                #[route("/audit-log")]
+                AdminAuditLog {},
+                #[route("/performance")]
+                AdminPerformance {},
+            #[end_layout]
+        #[end_nest]
+    #[end_layout]
 }
 
 #[component]
 pub fn Root() -> Element {
+    let config = use_context::<Signal<ClientConfig>>();
+    let branding = config.read().branding.clone();
+    // Fetched once when the app loads; fails quietly for visitors who aren't signed in
+    let container = use_context::<AppContainer>();
+    let unread_messages = use_resource(move || {
+        let svc = container.messages.clone();
+        async move { svc.unread_count().await }
+    });
+    let unread = unread_messages.read().as_ref().and_then(|count| count.as_ref().ok().copied()).unwrap_or(0);
     rsx! {
+        // Validated as `#rrggbb` by the API
+        if let Some(color) = branding.primary_color.as_ref() {
+            style { ":root {{ --aqio-blue-primary: {color}; }}" }
+        }
         header { class: "aqio-header",
             div { class: "container aqio-header-inner",
-                a { class: "aqio-brand", href: "/", "🐟 AQIO" }
+                a { class: "aqio-brand", href: "/",
+                    if let Some(logo_url) = branding.logo_url.as_ref() {
+                        img { class: "aqio-brand-logo", src: "{logo_url}", alt: "" }
+                    } else {
+                        "🐟 "
+                    }
+                    "{branding.name}"
+                }
                 nav { class: "aqio-nav",
                     Link { class: "aqio-nav-link", to: Route::Home {}, "Events" }
+                    Link { class: "aqio-nav-link", to: Route::PastEvents {}, "Archive" }
+                    Link { class: "aqio-nav-link", to: Route::Companies {}, "Companies" }
+                    Link { class: "aqio-nav-link", to: Route::Messages {}, "Messages"
+                        if unread > 0 {
+                            span { class: "aqio-nav-badge", aria_label: "{unread} unread", "{unread}" }
+                        }
+                    }
+                    Link { class: "aqio-nav-link", to: Route::Settings {}, "Settings" }
+                    Link { class: "aqio-nav-link", to: Route::AdminUsers {}, "Admin" }
                 }
             }
         }
@@ -34,6 +161,25 @@ pub fn Root() -> Element {
     }
 }
 
+#[component]
+pub fn AppLayout() -> Element {
+    let container = use_context::<AppContainer>();
+    let route = use_route::<Route>();
+    // Forget the event requests in flight when the route changes, so the ones
+    // the previous page started are aborted once it unmounts
+    let previous_route = use_hook(|| Rc::new(RefCell::new(route.clone())));
+    if *previous_route.borrow() != route {
+        previous_route.replace(route.clone());
+        container.events.cancel_fetches();
+    }
+    rsx! {
+        CommandPalette { container: container.clone(), route: route.clone() }
+        RouteErrorBoundary { container, route: route.to_string(),
+            Outlet::<Route> {}
+        }
+    }
+}
+
 #[component]
 pub fn Home() -> Element {
     rsx! {
@@ -58,3 +204,193 @@ pub fn Events() -> Element {
     let container = use_context::<AppContainer>();
     rsx! { EventsPage { container } }
 }
+
+#[component]
+pub fn NewEvent() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { NewEventPage { container } }
+}
+
+#[component]
+pub fn EventsCalendar() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { EventsCalendarPage { container } }
+}
+
+#[component]
+pub fn EventsAgenda() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { EventsAgendaPage { container } }
+}
+
+#[component]
+pub fn EventsMap() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { EventsMapPage { container } }
+}
+
+#[component]
+pub fn PastEvents() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { PastEventsPage { container } }
+}
+
+#[component]
+pub fn EventDetail(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { EventDetailPage { container, event_id } }
+}
+
+#[component]
+pub fn EventInvitations(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { InvitationsPage { container, event_id } }
+}
+
+#[component]
+pub fn EventCheckIn(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { CheckInPage { container, event_id } }
+}
+
+#[component]
+pub fn EventBudget(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { BudgetPage { container, event_id } }
+}
+
+#[component]
+pub fn EventSponsors(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { SponsorsPage { container, event_id } }
+}
+
+#[component]
+pub fn EventTravel(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { TravelPage { container, event_id } }
+}
+
+#[component]
+pub fn EventCatering(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { CateringPage { container, event_id } }
+}
+
+#[component]
+pub fn EventSeating(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { SeatingPage { container, event_id } }
+}
+
+#[component]
+pub fn PrintAttendees(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { PrintAttendeesPage { container, event_id } }
+}
+
+#[component]
+pub fn PrintProgram(event_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { PrintProgramPage { container, event_id } }
+}
+
+#[component]
+pub fn PublicEvent(slug: String) -> Element {
+    let container = use_context::<AppContainer>();
+    // Short link codes share `/e/` with slugs; slugs are lowercase
+    if aqio_core::ShortLink::is_code(&slug) {
+        return rsx! { ShortLinkRedirect { container, code: slug } };
+    }
+    rsx! { PublicEventPage { container, slug } }
+}
+
+#[component]
+pub fn CertificateVerification(registration_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { CertificateVerificationPage { container, registration_id } }
+}
+
+#[component]
+pub fn Companies() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { CompaniesPage { container } }
+}
+
+#[component]
+pub fn Messages() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { MessagesPage { container, thread_id: None } }
+}
+
+#[component]
+pub fn Conversation(thread_id: Uuid) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { MessagesPage { container, thread_id: Some(thread_id) } }
+}
+
+#[component]
+pub fn Settings() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { SettingsPage { container } }
+}
+
+#[component]
+pub fn JoinOrganization(token: String) -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { JoinOrganizationPage { container, token } }
+}
+
+#[component]
+pub fn AdminLayout() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! {
+        AdminGuard { container,
+            nav { class: "admin-nav",
+                Link { class: "admin-nav-link", active_class: "active", to: Route::AdminUsers {}, "Users" }
+                Link { class: "admin-nav-link", active_class: "active", to: Route::AdminCategories {}, "Categories" }
+                Link { class: "admin-nav-link", active_class: "active", to: Route::AdminCompanies {}, "Companies" }
+                Link { class: "admin-nav-link", active_class: "active", to: Route::AdminOrganizationInvitations {}, "Organization invites" }
+                Link { class: "admin-nav-link", active_class: "active", to: Route::AdminAuditLog {}, "Audit log" }
+                Link { class: "admin-nav-link", active_class: "active", to: Route::AdminPerformance {}, "Performance" }
+            }
+            Outlet::<Route> {}
+        }
+    }
+}
+
+#[component]
+pub fn AdminUsers() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { AdminUsersPage { container } }
+}
+
+#[component]
+pub fn AdminCategories() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { AdminCategoriesPage { container } }
+}
+
+#[component]
+pub fn AdminCompanies() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { AdminCompaniesPage { container } }
+}
+
+#[component]
+pub fn AdminOrganizationInvitations() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { AdminOrganizationInvitationsPage { container } }
+}
+
+#[component]
+pub fn AdminAuditLog() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { AdminAuditLogPage { container } }
+}
+
+#[component]
+pub fn AdminPerformance() -> Element {
+    let container = use_context::<AppContainer>();
+    rsx! { AdminPerformancePage { container } }
+}
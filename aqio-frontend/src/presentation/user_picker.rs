@@ -0,0 +1,180 @@
+// Multi-select for people with an account, used to pick co-organizers and
+// invitees. Users are looked up through the user search endpoint as the
+// organizer types; the picked ones show as removable chips above the input.
+//
+// Keyboard: ↑/↓ move through the matches, Enter picks, Escape closes the list
+// and Backspace in an empty input removes the last pick.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+use crate::application::ports::UserMatch;
+use crate::AppContainer;
+
+const USER_PICKER_CSS: Asset = asset!("/assets/user_picker.css");
+
+// Wait for a pause in typing before searching
+const SEARCH_DEBOUNCE_MS: u32 = 200;
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[component]
+pub fn UserPicker(label: String, mut picked: Signal<Vec<UserMatch>>) -> Element {
+    let container = use_context::<AppContainer>();
+    let id = use_hook(|| format!("user-picker-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed)));
+    let mut query = use_signal(String::new);
+    let mut active = use_signal(|| 0usize);
+    let mut open = use_signal(|| false);
+
+    let found = use_resource(move || {
+        let svc = container.users.clone();
+        let text = query();
+        async move {
+            // A newer keystroke restarts the resource and drops this wait
+            TimeoutFuture::new(SEARCH_DEBOUNCE_MS).await;
+            svc.search(&text).await
+        }
+    });
+
+    let (options, problem) = match &*found.read() {
+        Some(Ok(users)) => (
+            users
+                .iter()
+                .filter(|user| !picked().iter().any(|chosen| chosen.id == user.id))
+                .cloned()
+                .collect::<Vec<_>>(),
+            None,
+        ),
+        Some(Err(e)) => (Vec::new(), Some(e.clone())),
+        None => (Vec::new(), None),
+    };
+    let expanded = open() && !options.is_empty();
+    let current = active().min(options.len().saturating_sub(1));
+    let listbox_id = format!("{}-listbox", id);
+    let option_id = {
+        let id = id.clone();
+        move |index: usize| format!("{}-option-{}", id, index)
+    };
+
+    let mut pick = move |user: UserMatch| {
+        picked.write().push(user);
+        query.set(String::new());
+        active.set(0);
+    };
+
+    let onkeydown = {
+        let options = options.clone();
+        move |evt: KeyboardEvent| match evt.key() {
+            Key::ArrowDown if !options.is_empty() => {
+                evt.prevent_default();
+                if open() {
+                    active.set((current + 1) % options.len());
+                } else {
+                    open.set(true);
+                }
+            }
+            Key::ArrowUp if !options.is_empty() => {
+                evt.prevent_default();
+                open.set(true);
+                active.set((current + options.len() - 1) % options.len());
+            }
+            // Never submits the surrounding form
+            Key::Enter => {
+                evt.prevent_default();
+                if let Some(user) = options.get(current).filter(|_| open()) {
+                    pick(user.clone());
+                }
+            }
+            Key::Escape if open() => {
+                evt.stop_propagation();
+                open.set(false);
+            }
+            Key::Backspace if query().is_empty() => {
+                picked.write().pop();
+            }
+            _ => {}
+        }
+    };
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: USER_PICKER_CSS }
+
+        div { class: "user-picker",
+            label { class: "user-picker-label", r#for: "{id}", "{label}" }
+
+            if !picked().is_empty() {
+                ul { class: "user-picker-chips", aria_label: "Picked: {label}",
+                    for user in picked() {
+                        li { key: "{user.id}", class: "user-picker-chip",
+                            span { "{user.name}" }
+                            button {
+                                r#type: "button",
+                                class: "user-picker-remove",
+                                aria_label: "Remove {user.name}",
+                                onclick: {
+                                    let removed = user.id;
+                                    move |_| picked.write().retain(|chosen| chosen.id != removed)
+                                },
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+
+            input {
+                id: "{id}",
+                class: "user-picker-input",
+                role: "combobox",
+                autocomplete: "off",
+                placeholder: "Search by name, email or company",
+                aria_autocomplete: "list",
+                aria_expanded: expanded,
+                aria_controls: "{listbox_id}",
+                aria_activedescendant: if expanded { Some(option_id(current)) } else { None },
+                value: "{query}",
+                oninput: move |evt| {
+                    query.set(evt.value());
+                    active.set(0);
+                    open.set(true);
+                },
+                onfocusout: move |_| open.set(false),
+                onkeydown,
+            }
+
+            if let Some(message) = problem {
+                p { class: "user-picker-error", role: "status", "{message}" }
+            }
+
+            if expanded {
+                ul { id: "{listbox_id}", class: "user-picker-options", role: "listbox",
+                    for (index, user) in options.into_iter().enumerate() {
+                        li {
+                            key: "{user.id}",
+                            id: option_id(index),
+                            role: "option",
+                            aria_selected: index == current,
+                            class: if index == current { "user-picker-option active" } else { "user-picker-option" },
+                            onmouseenter: move |_| active.set(index),
+                            // Keeps focus in the input so the list isn't closed before the click lands
+                            onmousedown: move |evt| evt.prevent_default(),
+                            onclick: {
+                                let user = user.clone();
+                                move |_| pick(user.clone())
+                            },
+                            span { class: "user-picker-name", "{user.name}" }
+                            span { class: "user-picker-detail",
+                                "{user.email}"
+                                if let Some(company) = &user.company_name {
+                                    " · {company}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// The few API calls the scenarios make. Calls under load return a `Sample`
+// whatever the outcome; setup and verification calls fail the run instead.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use reqwest::{Client, RequestBuilder};
+use serde::Deserialize;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::report::Sample;
+
+#[derive(Clone)]
+pub struct ApiClient {
+    http: Client,
+    base_url: String,
+    token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreatedEvent {
+    pub id: Uuid,
+    pub slug: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Registration {
+    pub status: String,
+    pub guest_count: i32,
+}
+
+impl Registration {
+    /// Whether it holds seats at the event, i.e. isn't waitlisted or cancelled
+    pub fn is_seated(&self) -> bool {
+        matches!(self.status.as_str(), "Registered" | "Attended")
+    }
+}
+
+#[derive(Deserialize)]
+struct Envelope<T> {
+    data: T,
+}
+
+impl ApiClient {
+    pub fn new(base_url: &str, token: &str) -> Self {
+        Self {
+            http: Client::builder()
+                .timeout(Duration::from_secs(60))
+                .build()
+                .expect("HTTP client builds"),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// A published event a month from now, taking waitlist registrations
+    /// once `capacity` seats are taken
+    pub async fn create_event(&self, title: &str, capacity: Option<i32>) -> Result<CreatedEvent> {
+        let starts = Utc::now() + chrono::Duration::days(30);
+        let body = json!({
+            "title": title,
+            "description": "Created by aqio-load",
+            "category_id": "conf",
+            "start_date": starts,
+            "end_date": starts + chrono::Duration::hours(6),
+            "timezone": "Europe/Oslo",
+            "location_type": "Physical",
+            "location_name": "Load test venue",
+            "max_attendees": capacity,
+            "allow_waitlist": true,
+        });
+        let response = self
+            .http
+            .post(self.url("/api/v1/events"))
+            .bearer_auth(&self.token)
+            .json(&body)
+            .send()
+            .await
+            .context("create the event")?;
+        let event: Envelope<CreatedEvent> = response
+            .error_for_status()
+            .context("create the event")?
+            .json()
+            .await?;
+        Ok(event.data)
+    }
+
+    /// Registers guest number `n` on the event's public page, like a visitor
+    /// without an account
+    pub async fn register_guest(&self, slug: &str, n: usize) -> Sample {
+        let body = json!({
+            "registrant_name": format!("Load Guest {}", n),
+            "registrant_email": format!("guest-{}@load.aqio.no", n),
+            "guest_count": 0,
+        });
+        self.measure(
+            self.http
+                .post(self.url(&format!("/api/v1/public/events/{}/registrations", slug)))
+                .json(&body),
+        )
+        .await
+    }
+
+    pub async fn get(&self, path: &str) -> Sample {
+        self.measure(self.http.get(self.url(path))).await
+    }
+
+    /// Every registration for the event, as its organizer sees them
+    pub async fn registrations(&self, event_id: Uuid) -> Result<Vec<Registration>> {
+        let registrations: Envelope<Vec<Registration>> = self
+            .http
+            .get(self.url(&format!("/api/v1/registrations/event/{}/list", event_id)))
+            .bearer_auth(&self.token)
+            .send()
+            .await?
+            .error_for_status()
+            .context("list the registrations")?
+            .json()
+            .await?;
+        Ok(registrations.data)
+    }
+
+    async fn measure(&self, request: RequestBuilder) -> Sample {
+        let started = Instant::now();
+        let status = match request.send().await {
+            // Read the body too, so the latency covers the whole response
+            Ok(response) => {
+                let status = response.status().as_u16();
+                response.bytes().await.ok().map(|_| status)
+            }
+            Err(_) => None,
+        };
+        Sample { status, latency: started.elapsed() }
+    }
+}
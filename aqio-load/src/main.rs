@@ -0,0 +1,112 @@
+// Load tests against a running API. A scenario scripts what many users do at
+// once, reports throughput and latency, and checks what must still hold
+// afterwards, e.g. that a registration rush didn't overbook the event.
+//
+//     DATABASE_URL=sqlite::memory: cargo run --release --bin aqio-api
+//     cargo run --release -p aqio-load -- registration-rush
+//
+// Settings come from the environment:
+//   AQIO_API_URL      API under load (default http://127.0.0.1:3000)
+//   AQIO_TOKEN        Bearer token of an organizer (default mock-admin-user)
+//   LOAD_USERS        Simulated users (default 1000)
+//   LOAD_CONCURRENCY  Requests in flight at once (default: all users together)
+//   LOAD_CAPACITY     Seats of the event registered for (default 100)
+
+mod client;
+mod report;
+mod scenarios;
+
+use std::env;
+use std::process::ExitCode;
+
+use anyhow::{bail, Context, Result};
+
+use crate::client::ApiClient;
+use crate::scenarios::Scenario;
+
+pub struct Config {
+    pub api_url: String,
+    pub token: String,
+    pub users: usize,
+    pub concurrency: usize,
+    pub capacity: i32,
+}
+
+impl Config {
+    fn from_env() -> Result<Self> {
+        let users = number("LOAD_USERS", 1000)?;
+        let concurrency = number("LOAD_CONCURRENCY", users)?;
+        let capacity = number("LOAD_CAPACITY", 100)?;
+        if users == 0 || concurrency == 0 || capacity == 0 {
+            bail!("LOAD_USERS, LOAD_CONCURRENCY and LOAD_CAPACITY must be at least 1");
+        }
+        Ok(Self {
+            api_url: env::var("AQIO_API_URL").unwrap_or_else(|_| "http://127.0.0.1:3000".to_string()),
+            token: env::var("AQIO_TOKEN").unwrap_or_else(|_| "mock-admin-user".to_string()),
+            users,
+            concurrency,
+            capacity: i32::try_from(capacity).context("LOAD_CAPACITY is too large")?,
+        })
+    }
+}
+
+fn number(name: &str, default: usize) -> Result<usize> {
+    match env::var(name) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .with_context(|| format!("{} must be a positive number, got '{}'", name, value)),
+        Err(_) => Ok(default),
+    }
+}
+
+fn usage() -> String {
+    let scenarios: Vec<String> = Scenario::ALL
+        .iter()
+        .map(|scenario| format!("  {:<20}{}", scenario.name(), scenario.description()))
+        .collect();
+    format!("Usage: aqio-load <scenario>\n\nScenarios:\n{}", scenarios.join("\n"))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let name = env::args().nth(1).unwrap_or_default();
+    let Some(scenario) = Scenario::find(&name) else {
+        eprintln!("{}", usage());
+        return ExitCode::from(2);
+    };
+    let config = match Config::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ {:#}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    println!(
+        "🚦 {}: {} users, {} at a time, against {}",
+        scenario.name(),
+        config.users,
+        config.concurrency,
+        config.api_url
+    );
+    let client = ApiClient::new(&config.api_url, &config.token);
+    match scenario.run(&config, &client).await {
+        Ok(outcome) => {
+            println!("{}", outcome.report);
+            if outcome.failures.is_empty() {
+                println!("✅ {} passed", scenario.name());
+                ExitCode::SUCCESS
+            } else {
+                for failure in &outcome.failures {
+                    eprintln!("❌ {}", failure);
+                }
+                ExitCode::FAILURE
+            }
+        }
+        Err(e) => {
+            eprintln!("❌ {} couldn't run: {:#}", scenario.name(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,149 @@
+// What a scenario measured: one sample per request, summarized as throughput,
+// latency percentiles and a count per response status.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    /// HTTP status, or `None` when no response arrived
+    pub status: Option<u16>,
+    pub latency: Duration,
+}
+
+impl Sample {
+    pub fn is_success(&self) -> bool {
+        self.status.is_some_and(|status| (200..300).contains(&status))
+    }
+
+    /// The API failed, or didn't answer at all
+    pub fn is_server_error(&self) -> bool {
+        match self.status {
+            Some(status) => status >= 500,
+            None => true,
+        }
+    }
+}
+
+pub struct Report {
+    pub title: String,
+    pub elapsed: Duration,
+    samples: Vec<Sample>,
+}
+
+impl Report {
+    pub fn new(title: impl Into<String>, elapsed: Duration, mut samples: Vec<Sample>) -> Self {
+        samples.sort_by_key(|sample| sample.latency);
+        Self { title: title.into(), elapsed, samples }
+    }
+
+    pub fn samples(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    /// Requests completed per second
+    pub fn throughput(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds == 0.0 {
+            return 0.0;
+        }
+        self.samples.len() as f64 / seconds
+    }
+
+    /// Latency under which `percent` of the requests completed, nearest rank
+    pub fn percentile(&self, percent: f64) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        let rank = ((percent / 100.0) * self.samples.len() as f64).ceil() as usize;
+        self.samples[rank.clamp(1, self.samples.len()) - 1].latency
+    }
+
+    /// Requests per status, `error` for those without a response
+    pub fn statuses(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for sample in &self.samples {
+            let status = sample.status.map_or_else(|| "error".to_string(), |status| status.to_string());
+            *counts.entry(status).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    pub fn server_errors(&self) -> usize {
+        self.samples.iter().filter(|sample| sample.is_server_error()).count()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let millis = |duration: Duration| duration.as_secs_f64() * 1000.0;
+        writeln!(f, "\n📊 {}", self.title)?;
+        writeln!(
+            f,
+            "  requests    {} in {:.2}s ({:.1}/s)",
+            self.samples.len(),
+            self.elapsed.as_secs_f64(),
+            self.throughput()
+        )?;
+        writeln!(
+            f,
+            "  latency     p50 {:.1}ms  p95 {:.1}ms  p99 {:.1}ms  max {:.1}ms",
+            millis(self.percentile(50.0)),
+            millis(self.percentile(95.0)),
+            millis(self.percentile(99.0)),
+            millis(self.percentile(100.0))
+        )?;
+        let statuses: Vec<String> = self
+            .statuses()
+            .into_iter()
+            .map(|(status, count)| format!("{} × {}", count, status))
+            .collect();
+        write!(f, "  responses   {}", statuses.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(status: Option<u16>, millis: u64) -> Sample {
+        Sample { status, latency: Duration::from_millis(millis) }
+    }
+
+    #[test]
+    fn test_percentiles_use_nearest_rank() {
+        let samples = (1..=100).rev().map(|millis| sample(Some(201), millis)).collect();
+        let report = Report::new("rush", Duration::from_secs(2), samples);
+        assert_eq!(report.percentile(50.0), Duration::from_millis(50));
+        assert_eq!(report.percentile(95.0), Duration::from_millis(95));
+        assert_eq!(report.percentile(100.0), Duration::from_millis(100));
+        assert_eq!(report.percentile(0.0), Duration::from_millis(1));
+        assert_eq!(report.throughput(), 50.0);
+    }
+
+    #[test]
+    fn test_counts_statuses_and_server_errors() {
+        let samples = vec![
+            sample(Some(201), 5),
+            sample(Some(201), 7),
+            sample(Some(409), 3),
+            sample(Some(503), 9),
+            sample(None, 30),
+        ];
+        let report = Report::new("rush", Duration::from_secs(1), samples);
+        let statuses = report.statuses();
+        assert_eq!(statuses["201"], 2);
+        assert_eq!(statuses["409"], 1);
+        assert_eq!(statuses["error"], 1);
+        assert_eq!(report.server_errors(), 2);
+        assert_eq!(report.samples().iter().filter(|sample| sample.is_success()).count(), 2);
+    }
+
+    #[test]
+    fn test_empty_report_has_zero_latency() {
+        let report = Report::new("rush", Duration::ZERO, Vec::new());
+        assert_eq!(report.percentile(99.0), Duration::ZERO);
+        assert_eq!(report.throughput(), 0.0);
+    }
+}
@@ -0,0 +1,45 @@
+// Browsing: the traffic around a popular event. Every user opens its public
+// page, then polls its availability a few times the way the registration
+// button does. All of it must be answered.
+
+use anyhow::Result;
+
+use super::{drive, Outcome};
+use crate::client::ApiClient;
+use crate::report::Report;
+use crate::Config;
+
+const AVAILABILITY_POLLS: usize = 3;
+
+pub async fn run(config: &Config, client: &ApiClient) -> Result<Outcome> {
+    let event = client.create_event("Browsing", Some(config.capacity)).await?;
+    let page = format!("/api/v1/public/events/{}", event.slug);
+    let availability = format!("/api/v1/events/{}/availability", event.id);
+
+    let (elapsed, samples) = drive(config.users, config.concurrency, |_| {
+        let client = client.clone();
+        let page = page.clone();
+        let availability = availability.clone();
+        async move {
+            let mut samples = vec![client.get(&page).await];
+            for _ in 0..AVAILABILITY_POLLS {
+                samples.push(client.get(&availability).await);
+            }
+            samples
+        }
+    })
+    .await;
+    let report = Report::new(
+        format!("{} visitors, {} requests each", config.users, 1 + AVAILABILITY_POLLS),
+        elapsed,
+        samples,
+    );
+
+    let unanswered = report.samples().iter().filter(|sample| !sample.is_success()).count();
+    let failures = if unanswered > 0 {
+        vec![format!("{} requests weren't answered successfully", unanswered)]
+    } else {
+        Vec::new()
+    };
+    Ok(Outcome { report, failures })
+}
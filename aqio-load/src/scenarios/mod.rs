@@ -0,0 +1,84 @@
+// Scripted scenarios. Each one sets up what it needs through the API, drives
+// `LOAD_USERS` simulated users, and checks the API's state afterwards.
+
+mod browse;
+mod registration_rush;
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::client::ApiClient;
+use crate::report::{Report, Sample};
+use crate::Config;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scenario {
+    RegistrationRush,
+    Browse,
+}
+
+pub struct Outcome {
+    pub report: Report,
+    /// What didn't hold after the run; empty when the scenario passed
+    pub failures: Vec<String>,
+}
+
+impl Scenario {
+    pub const ALL: [Scenario; 2] = [Scenario::RegistrationRush, Scenario::Browse];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Scenario::RegistrationRush => "registration-rush",
+            Scenario::Browse => "browse",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Scenario::RegistrationRush => "Everyone registers for one event at once; the event mustn't be overbooked",
+            Scenario::Browse => "Everyone opens one event's public page and polls its availability",
+        }
+    }
+
+    pub fn find(name: &str) -> Option<Scenario> {
+        Scenario::ALL.into_iter().find(|scenario| scenario.name() == name)
+    }
+
+    pub async fn run(self, config: &Config, client: &ApiClient) -> Result<Outcome> {
+        match self {
+            Scenario::RegistrationRush => registration_rush::run(config, client).await,
+            Scenario::Browse => browse::run(config, client).await,
+        }
+    }
+}
+
+/// Runs `user(n)` for each of `users` users, at most `concurrency` at a time,
+/// and returns how long that took with every request's sample
+pub async fn drive<F, Fut>(users: usize, concurrency: usize, user: F) -> (Duration, Vec<Sample>)
+where
+    F: Fn(usize) -> Fut,
+    Fut: Future<Output = Vec<Sample>> + Send + 'static,
+{
+    let permits = Arc::new(Semaphore::new(concurrency));
+    let mut tasks = JoinSet::new();
+    let started = Instant::now();
+    for n in 0..users {
+        let permits = permits.clone();
+        let requests = user(n);
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await.expect("semaphore stays open");
+            requests.await
+        });
+    }
+
+    let mut samples = Vec::with_capacity(users);
+    while let Some(result) = tasks.join_next().await {
+        samples.extend(result.expect("simulated user doesn't panic"));
+    }
+    (started.elapsed(), samples)
+}
@@ -0,0 +1,66 @@
+// Registration rush: the moment registration for a popular event opens. Every
+// user registers for the same event at once; the first `LOAD_CAPACITY` get a
+// seat and the rest go on the waitlist. Afterwards, no more seats may be taken
+// than the event has, and every acknowledged registration must be stored.
+
+use anyhow::Result;
+
+use super::{drive, Outcome};
+use crate::client::ApiClient;
+use crate::report::Report;
+use crate::Config;
+
+pub async fn run(config: &Config, client: &ApiClient) -> Result<Outcome> {
+    let event = client.create_event("Registration rush", Some(config.capacity)).await?;
+
+    let (elapsed, samples) = drive(config.users, config.concurrency, |n| {
+        let client = client.clone();
+        let slug = event.slug.clone();
+        async move { vec![client.register_guest(&slug, n).await] }
+    })
+    .await;
+    let report = Report::new(
+        format!("{} registrations for {} seats", config.users, config.capacity),
+        elapsed,
+        samples,
+    );
+
+    let registrations = client.registrations(event.id).await?;
+    let seats_taken: i32 = registrations
+        .iter()
+        .filter(|registration| registration.is_seated())
+        .map(|registration| 1 + registration.guest_count)
+        .sum();
+    let waitlisted = registrations.iter().filter(|registration| registration.status == "Waitlisted").count();
+    let acknowledged = report.samples().iter().filter(|sample| sample.is_success()).count();
+    println!(
+        "🎟️  {} of {} seats taken, {} on the waitlist",
+        seats_taken, config.capacity, waitlisted
+    );
+
+    let mut failures = Vec::new();
+    if seats_taken > config.capacity {
+        failures.push(format!("overbooked: {} seats taken of {}", seats_taken, config.capacity));
+    }
+    if registrations.len() != acknowledged {
+        failures.push(format!(
+            "{} registrations acknowledged but {} stored",
+            acknowledged,
+            registrations.len()
+        ));
+    }
+    let server_errors = report.server_errors();
+    if server_errors > 0 {
+        failures.push(format!("{} registrations failed without an answer or with a server error", server_errors));
+    }
+    // Only a full rush with every request answered has to fill the event
+    let expected_seats = config.capacity.min(i32::try_from(config.users).unwrap_or(i32::MAX));
+    if acknowledged == config.users && seats_taken < expected_seats {
+        failures.push(format!(
+            "only {} of {} seats taken although everyone got through",
+            seats_taken, expected_seats
+        ));
+    }
+
+    Ok(Outcome { report, failures })
+}